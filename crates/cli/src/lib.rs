@@ -8,9 +8,13 @@
 //! ## Commands
 //!
 //! - `new` - Create a new Immortal Engine project
-//! - `generate` - Generate code from a project file
+//! - `generate` - Generate code from a project file (`--check` compares it
+//!   with the committed output instead, for CI)
+//! - `diff` - Compare two project files
 //! - `validate` - Validate a project file
-//! - `info` - Display information about a project
+//! - `info` - Display information about a project (`--graph` adds the
+//!   entity dependency graph, `--graph dot` prints it as Graphviz DOT)
+//! - `export` - Export the database schema as DBML, Mermaid or PlantUML
 //!
 
 // Re-export dependencies for use in main.rs
@@ -2,7 +2,787 @@
 //!
 //! Command-line interface for Immortal Engine operations.
 
-fn main() {
-    println!("Immortal Engine CLI v0.1.0");
-    println!("Use --help for available commands");
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::builder::PossibleValuesParser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use colored::Colorize;
+
+use imortal_cli::imortal_codegen::migrations::schema_snapshot_path;
+use imortal_cli::imortal_codegen::templates::project_templates_dir;
+use imortal_cli::imortal_codegen::{
+    FileStatus, FileType, GenerationWarning, Generator, GeneratorConfig, PostHook, SchemaFormat,
+    WorkspaceMember, check_output, export_schema, summarize,
+};
+use imortal_cli::imortal_ir::serialization::default_file_name;
+use imortal_cli::imortal_ir::{
+    ApiStyle, ChangeKind, DatabaseType, DependencyGraph, Orm, ProjectGraph, ProjectStats,
+    ProjectTemplate, WebFramework, diff_projects, import_openapi, import_sql, load_project,
+    save_project,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "imortal",
+    version,
+    about = "Immortal Engine command-line tools"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create a new project file, optionally from a template
+    New(NewArgs),
+    /// Generate code from a project file
+    Generate(GenerateArgs),
+    /// Compare two project files
+    Diff(DiffArgs),
+    /// Show a project's settings, counts and entity dependencies
+    Info(InfoArgs),
+    /// Create a project file from an existing database's SQL schema dump or
+    /// an OpenAPI 3 document
+    Import(ImportArgs),
+    /// Export a project's database schema as DBML, Mermaid or PlantUML
+    Export(ExportArgs),
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// Project name
+    name: String,
+
+    /// Starter template
+    #[arg(
+        short,
+        long,
+        default_value = "blank",
+        value_parser = PossibleValuesParser::new(ProjectTemplate::all().iter().map(|t| t.key())),
+    )]
+    template: String,
+
+    /// Where to write the project file (defaults to `<name>.ieng`)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Overwrite an existing project file
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// Path to the .ieng project file
+    project: PathBuf,
+
+    /// Output directory for the generated code
+    #[arg(short, long, default_value = "./generated")]
+    output: PathBuf,
+
+    /// Treat generation warnings as errors
+    #[arg(long, env = "IMORTAL_STRICT")]
+    strict: bool,
+
+    /// Overwrite files edited since they were generated (by default they are
+    /// left alone and reported)
+    #[arg(long)]
+    overwrite: bool,
+
+    /// Leave the generated Rust files as generated instead of running rustfmt
+    #[arg(long)]
+    no_format: bool,
+
+    /// Directory of template overrides (defaults to `templates/` next to the
+    /// project file, when there is one)
+    #[arg(long, value_name = "DIR")]
+    templates: Option<PathBuf>,
+
+    /// Also write the OpenAPI spec as openapi.json
+    #[arg(long)]
+    openapi_json: bool,
+
+    /// Also generate a Dockerfile, docker-compose.yml and .dockerignore
+    #[arg(long)]
+    docker: bool,
+
+    /// Also generate seed SQL files and a `seed` binary that loads them
+    #[arg(long)]
+    seeds: bool,
+
+    /// Also generate a k6 load test script for the REST endpoints
+    #[arg(long)]
+    load_tests: bool,
+
+    /// Also generate a typed Rust client crate for the REST API under client/
+    #[arg(long)]
+    client_sdk: bool,
+
+    /// Generate a member crate into the Cargo workspace enclosing the output
+    /// directory and add it to `[workspace.members]`
+    #[arg(long)]
+    workspace_member: bool,
+
+    /// Command to run in the output directory after generation (repeatable)
+    #[arg(long, value_name = "COMMAND")]
+    hook: Vec<String>,
+
+    /// Command to run on every generated file with an extension, e.g.
+    /// `sql=sqlfluff fix` (repeatable)
+    #[arg(long, value_name = "EXT=COMMAND")]
+    file_hook: Vec<String>,
+
+    /// ORM to generate for, overriding the project's setting
+    #[arg(long, value_parser = PossibleValuesParser::new(["sea-orm", "diesel"]))]
+    orm: Option<String>,
+
+    /// Web framework to generate for, overriding the project's setting
+    #[arg(long, value_parser = PossibleValuesParser::new(["axum", "actix-web"]))]
+    framework: Option<String>,
+
+    /// API style to generate, overriding the project's setting
+    #[arg(long, value_parser = PossibleValuesParser::new(["rest", "graphql", "both"]))]
+    api_style: Option<String>,
+
+    /// Regenerate every CREATE TABLE migration instead of migrating from
+    /// the schema snapshot in the output directory
+    #[arg(long)]
+    full_migrations: bool,
+
+    /// Generate into a temporary directory and compare it with the output
+    /// directory instead of writing to it; exits with status 1 when the
+    /// committed code is out of date
+    #[arg(long)]
+    check: bool,
+
+    /// List the files that would be written, their sizes and any edited
+    /// files they would replace, without writing anything; exits with
+    /// status 1 when a file conflicts and --overwrite is not set
+    #[arg(long, conflicts_with = "check")]
+    dry_run: bool,
+}
+
+#[derive(Debug, Args)]
+struct DiffArgs {
+    /// The earlier project file
+    old: PathBuf,
+
+    /// The later project file
+    new: PathBuf,
+
+    /// Exit with status 1 when the projects differ
+    #[arg(long)]
+    exit_code: bool,
+}
+
+#[derive(Debug, Args)]
+struct InfoArgs {
+    /// Path to the .ieng project file
+    project: PathBuf,
+
+    /// Also print the entity dependency graph (plain text unless `dot` is given)
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "ascii")]
+    graph: Option<GraphFormat>,
+}
+
+#[derive(Debug, Args)]
+struct ImportArgs {
+    /// SQL DDL dump, e.g. from `pg_dump --schema-only` or `mysqldump --no-data`,
+    /// or an OpenAPI 3 document (`.yaml`, `.yml` or `.json`)
+    file: PathBuf,
+
+    /// Project name (defaults to the file name)
+    #[arg(short, long)]
+    name: Option<String>,
+
+    /// SQL dialect of a dump (detected when omitted)
+    #[arg(short, long, value_enum)]
+    dialect: Option<SqlDialect>,
+
+    /// Where to write the project file (defaults to `<name>.ieng`)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Overwrite an existing project file
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Debug, Args)]
+struct ExportArgs {
+    /// Path to the .ieng project file
+    project: PathBuf,
+
+    /// Schema format to write
+    #[arg(short, long, value_enum)]
+    format: ExportFormat,
+
+    /// File to write the schema to (defaults to stdout)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Overwrite an existing output file
+    #[arg(long)]
+    force: bool,
+}
+
+/// Dialect for `import --dialect`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum SqlDialect {
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl From<SqlDialect> for DatabaseType {
+    fn from(dialect: SqlDialect) -> Self {
+        match dialect {
+            SqlDialect::Postgres => DatabaseType::PostgreSQL,
+            SqlDialect::Mysql => DatabaseType::MySQL,
+            SqlDialect::Sqlite => DatabaseType::SQLite,
+        }
+    }
+}
+
+/// Format for `export --format`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// DBML, for dbdiagram.io and dbdocs
+    Dbml,
+    /// Mermaid `erDiagram`
+    Mermaid,
+    /// PlantUML entity diagram
+    Plantuml,
+}
+
+impl From<ExportFormat> for SchemaFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Dbml => SchemaFormat::Dbml,
+            ExportFormat::Mermaid => SchemaFormat::Mermaid,
+            ExportFormat::Plantuml => SchemaFormat::PlantUml,
+        }
+    }
+}
+
+/// Output format for `info --graph`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum GraphFormat {
+    /// Indented text, one entity per line
+    Ascii,
+    /// Graphviz DOT, e.g. for `dot -Tsvg`
+    Dot,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::New(args) => new(args).map(|()| ExitCode::SUCCESS),
+        Command::Generate(args) => generate(args),
+        Command::Diff(args) => diff(args),
+        Command::Info(args) => info(args).map(|()| ExitCode::SUCCESS),
+        Command::Import(args) => import(args).map(|()| ExitCode::SUCCESS),
+        Command::Export(args) => export(args).map(|()| ExitCode::SUCCESS),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{} {}", "error:".red().bold(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: NewArgs) -> anyhow::Result<()> {
+    let template: ProjectTemplate = args.template.parse()?;
+    let path = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(default_file_name(&args.name)));
+
+    if path.exists() && !args.force {
+        anyhow::bail!(
+            "{} already exists (use --force to overwrite)",
+            path.display()
+        );
+    }
+
+    let project = template.build(&args.name);
+    save_project(&project, &path)?;
+
+    println!(
+        "{} {} from the {} template ({} entities, {} relationships, {} endpoints)",
+        "Created".green().bold(),
+        path.display(),
+        template.display_name(),
+        project.entity_count(),
+        project.relationship_count(),
+        project.endpoint_count(),
+    );
+    println!("  Next: imortal generate {}", path.display());
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> anyhow::Result<ExitCode> {
+    let project = load_project(&args.project)?;
+
+    // With --check, generate next to the committed output and compare
+    let scratch = if args.check {
+        Some(tempfile::tempdir()?)
+    } else {
+        None
+    };
+    let target = scratch
+        .as_ref()
+        .map_or(args.output.as_path(), |dir| dir.path());
+
+    let mut config = GeneratorConfig::new().with_output_dir(target);
+    if args.strict {
+        config = config.fail_on_warnings();
+    }
+    if args.overwrite {
+        config = config.allow_overwrite();
+    }
+    if args.no_format {
+        config = config.without_formatting();
+    }
+    let templates = args
+        .templates
+        .clone()
+        .or_else(|| Some(project_templates_dir(&args.project)).filter(|dir| dir.is_dir()));
+    if let Some(dir) = templates {
+        config = config.with_templates_dir(dir);
+    }
+    if args.openapi_json {
+        config = config.with_openapi_json();
+    }
+    if args.docker {
+        config = config.with_docker();
+    }
+    if args.seeds {
+        config = config.with_seeds();
+    }
+    if args.load_tests {
+        config = config.with_load_tests();
+    }
+    if args.client_sdk {
+        config = config.with_client_sdk();
+    }
+    if args.workspace_member {
+        config = config.as_workspace_member();
+    }
+    if let Some(orm) = &args.orm {
+        config = config.with_orm(orm.parse::<Orm>()?);
+    }
+    if let Some(framework) = &args.framework {
+        config = config.with_framework(framework.parse::<WebFramework>()?);
+    }
+    if let Some(api_style) = &args.api_style {
+        config = config.with_api_style(api_style.parse::<ApiStyle>()?);
+    }
+    for command in &args.hook {
+        config = config.with_hook(PostHook::once(command));
+    }
+    for spec in &args.file_hook {
+        let Some((ext, command)) = spec.split_once('=') else {
+            anyhow::bail!("--file-hook expects EXT=COMMAND, got '{}'", spec);
+        };
+        let Some(file_type) = FileType::from_extension(ext.trim()) else {
+            anyhow::bail!("--file-hook: unknown file extension '{}'", ext);
+        };
+        config = config.with_hook(PostHook::per_file(file_type, command));
+    }
+
+    let snapshot = args.output.join(schema_snapshot_path(&project));
+    if !args.full_migrations && snapshot.exists() {
+        config = config.with_baseline(load_project(&snapshot)?);
+        println!(
+            "{} changes since {}",
+            "Migrating".cyan().bold(),
+            snapshot.display()
+        );
+    }
+
+    // Refuse before writing anything that would clobber another crate
+    let member = if args.workspace_member && !args.check {
+        Some(WorkspaceMember::locate(
+            &args.output,
+            &project.config.package_name,
+        )?)
+    } else {
+        None
+    };
+
+    if args.dry_run {
+        return dry_run(&Generator::new(config), &project);
+    }
+
+    let output = Generator::new(config).generate_and_write(&project)?;
+
+    if let Some(member) = &member
+        && member.register()?
+    {
+        println!(
+            "{} {} to {}",
+            "Added".green().bold(),
+            member.path,
+            member.manifest_path().display()
+        );
+    }
+
+    print_warnings(&output.warnings);
+
+    if args.check {
+        let check = check_output(&output.files, target, &args.output)?;
+        for (paths, label) in [
+            (&check.modified, "modified".yellow()),
+            (&check.missing, "missing".red()),
+            (&check.stale, "stale".red()),
+        ] {
+            for path in paths {
+                println!("  {:<9} {}", label, path.display());
+            }
+        }
+
+        let summary = format!("{}: {}", args.output.display(), check.summary());
+        if check.is_up_to_date() {
+            println!("{} {}", "Checked".green().bold(), summary);
+            return Ok(ExitCode::SUCCESS);
+        }
+        println!("{} {}", "Outdated".red().bold(), summary);
+        println!("  Regenerate with the same options, without --check, and commit the result");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    print!("{}", summarize(&output));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// `generate --dry-run`: print the manifest instead of writing it.
+fn dry_run(generator: &Generator, project: &ProjectGraph) -> anyhow::Result<ExitCode> {
+    let plan = generator.plan(project)?;
+    print_warnings(&plan.project.warnings);
+
+    for entry in plan.manifest.iter().filter(|e| e.status.is_change()) {
+        let label = match entry.status {
+            FileStatus::Added => "added".green(),
+            FileStatus::Removed => "removed".red(),
+            _ => "changed".yellow(),
+        };
+        let conflict = if entry.conflict {
+            " (conflict)".red().bold().to_string()
+        } else {
+            String::new()
+        };
+        println!(
+            "  {:<9} {:>9} {}{}",
+            label,
+            format!("{} B", entry.size),
+            entry.path.display(),
+            conflict
+        );
+    }
+
+    let summary = format!(
+        "{}: {} ({} B to write)",
+        plan.output_dir.display(),
+        plan.summary(),
+        plan.total_bytes()
+    );
+    if plan.has_conflicts() {
+        println!("{} {}", "Conflicts".red().bold(), summary);
+        println!("  Rerun with --overwrite to replace the edited files");
+        return Ok(ExitCode::FAILURE);
+    }
+    println!("{} {}", "Dry run".cyan().bold(), summary);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_warnings(warnings: &[GenerationWarning]) {
+    for warning in warnings {
+        eprintln!("{} {}", "warning:".yellow().bold(), warning);
+        if let Some(suggestion) = &warning.suggestion {
+            eprintln!("  {} {}", "help:".cyan().bold(), suggestion);
+        }
+    }
+}
+
+fn diff(args: DiffArgs) -> anyhow::Result<ExitCode> {
+    let old = load_project(&args.old)?;
+    let new = load_project(&args.new)?;
+    let diff = diff_projects(&old, &new);
+
+    for change in &diff.changes {
+        let header = format!(
+            "{} {} {}",
+            change.kind.symbol(),
+            change.item.label(),
+            change.name
+        );
+        let header = match change.kind {
+            ChangeKind::Added => header.green(),
+            ChangeKind::Removed => header.red(),
+            ChangeKind::Modified => header.yellow(),
+        };
+        println!("{}", header);
+        for detail in &change.details {
+            println!("    {}", detail);
+        }
+    }
+    println!("{}", diff.summary().bold());
+
+    if args.exit_code && !diff.is_empty() {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn info(args: InfoArgs) -> anyhow::Result<()> {
+    let project = load_project(&args.project)?;
+
+    // DOT goes to stdout on its own so it can be piped straight into Graphviz
+    if let Some(GraphFormat::Dot) = args.graph {
+        print!("{}", DependencyGraph::of(&project).to_dot());
+        return Ok(());
+    }
+
+    print_project_info(&project);
+
+    if args.graph.is_some() {
+        println!();
+        println!("{}", "Dependencies".bold());
+        let graph = DependencyGraph::of(&project);
+        if graph.entities.is_empty() {
+            println!("  (no entities)");
+        }
+        for line in graph.to_ascii().lines() {
+            if line.is_empty() {
+                println!();
+            } else {
+                println!("  {}", line);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn import(args: ImportArgs) -> anyhow::Result<()> {
+    let source = std::fs::read_to_string(&args.file)?;
+    let name = args.name.unwrap_or_else(|| {
+        args.file.file_stem().map_or_else(
+            || "imported".to_string(),
+            |s| s.to_string_lossy().into_owned(),
+        )
+    });
+    let path = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(default_file_name(&name)));
+
+    if path.exists() && !args.force {
+        anyhow::bail!(
+            "{} already exists (use --force to overwrite)",
+            path.display()
+        );
+    }
+
+    let openapi = args
+        .file
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| matches!(e.to_ascii_lowercase().as_str(), "yaml" | "yml" | "json"));
+    if openapi && args.dialect.is_some() {
+        anyhow::bail!("--dialect only applies to SQL dumps");
+    }
+
+    let (project, source_kind) = if openapi {
+        let api = import_openapi(&source)?;
+        for warning in &api.warnings {
+            eprintln!("{} {}", "warning:".yellow().bold(), warning);
+        }
+        (api.into_project(&name), "OpenAPI".to_string())
+    } else {
+        let schema = import_sql(&source, args.dialect.map(DatabaseType::from))?;
+        for warning in &schema.warnings {
+            eprintln!("{} {}", "warning:".yellow().bold(), warning);
+        }
+        let dialect = schema.dialect;
+        (
+            schema.into_project(&name),
+            format!("{} schema", dialect.display_name()),
+        )
+    };
+    save_project(&project, &path)?;
+
+    let endpoints = if openapi {
+        format!(", {} endpoint groups", project.endpoints().count())
+    } else {
+        String::new()
+    };
+    println!(
+        "{} {} from {} ({}: {} entities, {} relationships, {} enums{})",
+        "Imported".green().bold(),
+        path.display(),
+        args.file.display(),
+        source_kind,
+        project.entity_count(),
+        project.relationship_count(),
+        project.enums().len(),
+        endpoints,
+    );
+    println!("  Next: imortal generate {}", path.display());
+
+    Ok(())
+}
+
+fn export(args: ExportArgs) -> anyhow::Result<()> {
+    let project = load_project(&args.project)?;
+    let format = SchemaFormat::from(args.format);
+    let schema = export_schema(&project, format);
+
+    // Without --output the schema goes to stdout on its own, for piping
+    let Some(path) = args.output else {
+        print!("{}", schema);
+        return Ok(());
+    };
+
+    if path.exists() && !args.force {
+        anyhow::bail!(
+            "{} already exists (use --force to overwrite)",
+            path.display()
+        );
+    }
+    std::fs::write(&path, schema)?;
+
+    println!(
+        "{} {} schema of {} to {} ({} entities, {} relationships)",
+        "Exported".green().bold(),
+        format.display_name(),
+        project.meta.name,
+        path.display(),
+        project.entity_count(),
+        project.relationship_count(),
+    );
+
+    Ok(())
+}
+
+fn print_project_info(project: &ProjectGraph) {
+    let meta = &project.meta;
+    let config = &project.config;
+
+    println!(
+        "{} {}",
+        meta.name.bold(),
+        format!("v{}", meta.version).dimmed()
+    );
+    if let Some(description) = &meta.description {
+        println!("{}", description);
+    }
+    println!();
+
+    let auth = if config.auth.enabled {
+        let roles = if config.auth.available_roles.is_empty() {
+            String::new()
+        } else {
+            format!(" (roles: {})", config.auth.available_roles.join(", "))
+        };
+        let principal = config
+            .auth
+            .principal
+            .as_ref()
+            .map(|p| format!(", signing in as {}.{}", p.entity, p.identifier_field))
+            .unwrap_or_default();
+        format!(
+            "{}{}{}",
+            config.auth.strategy.display_name(),
+            roles,
+            principal
+        )
+    } else {
+        "Disabled".to_string()
+    };
+
+    let mut settings = vec![
+        ("Package", config.package_name.clone()),
+        ("Type", config.project_type.display_name().to_string()),
+        ("Database", config.database.display_name().to_string()),
+        ("ORM", config.orm.display_name().to_string()),
+        ("Framework", config.framework.display_name().to_string()),
+        ("API", config.api_style.display_name().to_string()),
+        ("Auth", auth),
+        ("Pagination", config.pagination.display_name().to_string()),
+        (
+            "Routes",
+            format!(
+                "{}, {}, {}",
+                config.route_style.plurality.display_name(),
+                config.route_style.case.display_name(),
+                config.route_style.nesting.display_name()
+            ),
+        ),
+    ];
+    let authors = meta.authors();
+    if !authors.is_empty() {
+        settings.push(("Authors", authors.join(", ")));
+    }
+    if let Some(license) = &meta.license {
+        settings.push(("License", license.clone()));
+    }
+    if let Some(repository) = &meta.repository {
+        settings.push(("Repository", repository.clone()));
+    }
+    if !meta.keywords.is_empty() {
+        settings.push(("Keywords", meta.keywords.join(", ")));
+    }
+    settings.push((
+        "Modified",
+        meta.modified_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+    ));
+    for (label, value) in settings {
+        println!(
+            "  {}{}",
+            format!("{:<16}", format!("{}:", label)).dimmed(),
+            value
+        );
+    }
+    println!();
+
+    let stats = ProjectStats::of(project);
+    let by_type = stats
+        .relationships_by_type
+        .iter()
+        .map(|(kind, n)| format!("{} {}", n, kind.to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let counts = [
+        ("Entities", stats.entities.to_string()),
+        ("Fields", stats.fields.to_string()),
+        (
+            "Relationships",
+            if by_type.is_empty() {
+                stats.relationships.to_string()
+            } else {
+                format!("{} ({})", stats.relationships, by_type)
+            },
+        ),
+        (
+            "Endpoints",
+            format!("{} ({} routes)", stats.endpoints, stats.routes),
+        ),
+        ("Queries", stats.queries.to_string()),
+    ];
+    for (label, value) in counts {
+        println!(
+            "  {}{}",
+            format!("{:<16}", format!("{}:", label)).dimmed(),
+            value
+        );
+    }
 }
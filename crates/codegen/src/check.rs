@@ -0,0 +1,389 @@
+//! # Output Check
+//!
+//! Compares a fresh generation with a committed output directory, so CI can
+//! fail when the generated code in a repository no longer matches its
+//! `.ieng` model (`imortal generate --check`).
+//!
+//! The fresh files are read back from the directory they were written to,
+//! after post-generation hooks ran, so formatter hooks don't show up as
+//! differences. Line endings are normalised before comparing.
+//!
+//! Every generated file is reported as unchanged, **modified** or
+//! **missing**. Files in the committed directory that the generator no
+//! longer produces are reported as **stale** when they carry the
+//! auto-generated header — hand-written files are left alone. Code inside
+//! protected regions of the committed files is not a difference.
+//!
+//! Migration file names start with the date they were generated on
+//! (`20250101000001_create_users.sql`). A generated migration matches a
+//! committed one with the same name on another date, so a full migration
+//! run doesn't go out of date at midnight.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use imortal_core::{EngineError, EngineResult};
+use walkdir::WalkDir;
+
+use crate::GeneratedFile;
+use crate::regions::{KEEP_OPEN, merge_regions};
+
+/// Text in the header of every generated source file
+pub const GENERATED_MARKER: &str = "Auto-generated by Immortal Engine";
+
+/// Directories never searched for stale files
+const SKIPPED_DIRS: &[&str] = &["target", ".git", "node_modules", "dist"];
+
+/// Length of the date prefix on migration names (`YYYYMMDD`)
+const MIGRATION_DATE_LEN: usize = 8;
+
+// ============================================================================
+// OutputCheck
+// ============================================================================
+
+/// Result of comparing fresh generated output with a committed directory
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputCheck {
+    /// Generated files whose committed copy differs
+    pub modified: Vec<PathBuf>,
+
+    /// Generated files with no committed copy
+    pub missing: Vec<PathBuf>,
+
+    /// Committed generated files the generator no longer produces
+    pub stale: Vec<PathBuf>,
+
+    /// Number of generated files that match
+    pub unchanged: usize,
+}
+
+impl OutputCheck {
+    /// Whether the committed output matches the fresh generation
+    pub fn is_up_to_date(&self) -> bool {
+        self.difference_count() == 0
+    }
+
+    /// Number of files that differ in any way
+    pub fn difference_count(&self) -> usize {
+        self.modified.len() + self.missing.len() + self.stale.len()
+    }
+
+    /// One-line summary, e.g. `out of date: 2 modified, 1 missing (40 unchanged)`
+    pub fn summary(&self) -> String {
+        if self.is_up_to_date() {
+            return format!("up to date ({} files)", self.unchanged);
+        }
+
+        let mut parts = Vec::new();
+        for (count, label) in [
+            (self.modified.len(), "modified"),
+            (self.missing.len(), "missing"),
+            (self.stale.len(), "stale"),
+        ] {
+            if count > 0 {
+                parts.push(format!("{} {}", count, label));
+            }
+        }
+        format!(
+            "out of date: {} ({} unchanged)",
+            parts.join(", "),
+            self.unchanged
+        )
+    }
+}
+
+// ============================================================================
+// Comparison
+// ============================================================================
+
+/// Compare `files`, freshly written to `fresh_dir`, with `committed_dir`.
+///
+/// A missing `committed_dir` counts as empty, so every file is reported
+/// missing.
+pub fn check_output(
+    files: &[GeneratedFile],
+    fresh_dir: &Path,
+    committed_dir: &Path,
+) -> EngineResult<OutputCheck> {
+    let committed = committed_files(committed_dir);
+    let by_migration_key: HashMap<PathBuf, &PathBuf> = committed
+        .iter()
+        .filter_map(|path| undated_migration(path).map(|key| (key, path)))
+        .collect();
+
+    let mut check = OutputCheck::default();
+    let mut seen: HashSet<&Path> = HashSet::new();
+
+    for file in files {
+        let counterpart = if committed.contains(&file.path) {
+            Some(&file.path)
+        } else {
+            undated_migration(&file.path).and_then(|key| by_migration_key.get(&key).copied())
+        };
+        let Some(counterpart) = counterpart else {
+            check.missing.push(file.path.clone());
+            continue;
+        };
+        seen.insert(counterpart);
+
+        let mut fresh = std::fs::read(fresh_dir.join(&file.path))
+            .unwrap_or_else(|_| file.content.clone().into_bytes());
+        let path = committed_dir.join(counterpart);
+        let existing = std::fs::read(&path).map_err(|e| EngineError::FileRead {
+            path: path.clone(),
+            message: e.to_string(),
+        })?;
+
+        // Hand-written code in protected regions is not a difference
+        if let (Ok(committed), Ok(generated)) =
+            (std::str::from_utf8(&existing), std::str::from_utf8(&fresh))
+            && committed.contains(KEEP_OPEN)
+        {
+            fresh = merge_regions(committed, generated).content.into_bytes();
+        }
+
+        if normalize_newlines(&fresh) == normalize_newlines(&existing) {
+            check.unchanged += 1;
+        } else {
+            check.modified.push(file.path.clone());
+        }
+    }
+
+    for path in &committed {
+        if seen.contains(path.as_path()) {
+            continue;
+        }
+        let is_generated = std::fs::read_to_string(committed_dir.join(path))
+            .is_ok_and(|content| content.contains(GENERATED_MARKER));
+        if is_generated {
+            check.stale.push(path.clone());
+        }
+    }
+
+    check.modified.sort();
+    check.missing.sort();
+    check.stale.sort();
+    Ok(check)
+}
+
+/// Relative paths of every file under `dir`, skipping build output
+pub(crate) fn committed_files(dir: &Path) -> HashSet<PathBuf> {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !(entry.file_type().is_dir()
+                    && SKIPPED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()))
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.path().strip_prefix(dir).ok().map(Path::to_path_buf))
+        .collect()
+}
+
+/// The path with the date removed from its migration name, for paths like
+/// `migrations/20250101000001_create_users.sql` or, with Diesel,
+/// `migrations/20250101000001_create_users/up.sql`
+pub(crate) fn undated_migration(path: &Path) -> Option<PathBuf> {
+    let components: Vec<Component> = path.components().collect();
+    let position = components
+        .iter()
+        .position(|c| c.as_os_str() == "migrations")?;
+    let name = components.get(position + 1)?.as_os_str().to_str()?;
+    let (date, rest) = name.split_at_checked(MIGRATION_DATE_LEN)?;
+    if !date.bytes().all(|b| b.is_ascii_digit()) || !rest.contains("_create_") {
+        return None;
+    }
+
+    let mut key = PathBuf::new();
+    for component in &components[..=position] {
+        key.push(component);
+    }
+    key.push(rest);
+    for component in &components[position + 2..] {
+        key.push(component);
+    }
+    Some(key)
+}
+
+pub(crate) fn normalize_newlines(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut iter = bytes.iter().peekable();
+    while let Some(&b) = iter.next() {
+        if b == b'\r' && iter.peek() == Some(&&b'\n') {
+            continue;
+        }
+        out.push(b);
+    }
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FileType;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let full = dir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(full, content).unwrap();
+    }
+
+    fn generated(dir: &Path, files: &[(&str, &str)]) -> Vec<GeneratedFile> {
+        files
+            .iter()
+            .map(|(path, content)| {
+                write(dir, path, content);
+                GeneratedFile::new(*path, *content, FileType::Rust)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_up_to_date() {
+        let fresh = tempfile::tempdir().unwrap();
+        let committed = tempfile::tempdir().unwrap();
+        let files = generated(fresh.path(), &[("src/main.rs", "fn main() {}\n")]);
+        write(committed.path(), "src/main.rs", "fn main() {}\r\n");
+        write(committed.path(), "src/custom.rs", "// hand-written\n");
+
+        let check = check_output(&files, fresh.path(), committed.path()).unwrap();
+        assert!(check.is_up_to_date());
+        assert_eq!(check.unchanged, 1);
+        assert_eq!(check.summary(), "up to date (1 files)");
+    }
+
+    #[test]
+    fn test_modified_missing_and_stale() {
+        let fresh = tempfile::tempdir().unwrap();
+        let committed = tempfile::tempdir().unwrap();
+        let files = generated(
+            fresh.path(),
+            &[("src/main.rs", "fn main() {}\n"), ("src/lib.rs", "")],
+        );
+        write(committed.path(), "src/main.rs", "fn main() { old() }\n");
+        write(
+            committed.path(),
+            "src/models/tag.rs",
+            "//! Tag model\n//!\n//! Auto-generated by Immortal Engine v2.0.\n",
+        );
+        write(
+            committed.path(),
+            "target/debug/build.rs",
+            "// Auto-generated by Immortal Engine\n",
+        );
+
+        let check = check_output(&files, fresh.path(), committed.path()).unwrap();
+        assert_eq!(check.modified, vec![PathBuf::from("src/main.rs")]);
+        assert_eq!(check.missing, vec![PathBuf::from("src/lib.rs")]);
+        assert_eq!(check.stale, vec![PathBuf::from("src/models/tag.rs")]);
+        assert_eq!(check.difference_count(), 3);
+        assert_eq!(
+            check.summary(),
+            "out of date: 1 modified, 1 missing, 1 stale (0 unchanged)"
+        );
+    }
+
+    #[test]
+    fn test_hook_output_is_compared() {
+        let fresh = tempfile::tempdir().unwrap();
+        let committed = tempfile::tempdir().unwrap();
+        let files = generated(fresh.path(), &[("src/main.rs", "fn main(){}")]);
+        // A formatter hook rewrote the fresh file after generation
+        write(fresh.path(), "src/main.rs", "fn main() {}\n");
+        write(committed.path(), "src/main.rs", "fn main() {}\n");
+
+        let check = check_output(&files, fresh.path(), committed.path()).unwrap();
+        assert!(check.is_up_to_date());
+    }
+
+    #[test]
+    fn test_protected_regions_are_not_differences() {
+        let fresh = tempfile::tempdir().unwrap();
+        let committed = tempfile::tempdir().unwrap();
+        let region = "// <immortal:keep custom>\n// </immortal:keep>\n";
+        let files = generated(fresh.path(), &[("src/a.rs", region), ("src/b.rs", region)]);
+        write(
+            committed.path(),
+            "src/a.rs",
+            "// <immortal:keep custom>\nfn mine() {}\n// </immortal:keep>\n",
+        );
+        write(
+            committed.path(),
+            "src/b.rs",
+            "fn edited() {}\n// <immortal:keep custom>\n// </immortal:keep>\n",
+        );
+
+        let check = check_output(&files, fresh.path(), committed.path()).unwrap();
+        assert_eq!(check.unchanged, 1);
+        assert_eq!(check.modified, vec![PathBuf::from("src/b.rs")]);
+    }
+
+    #[test]
+    fn test_migrations_match_across_dates() {
+        let fresh = tempfile::tempdir().unwrap();
+        let committed = tempfile::tempdir().unwrap();
+        let sql = "CREATE TABLE users ();\n";
+        let files = generated(
+            fresh.path(),
+            &[
+                ("migrations/20260102000001_create_users.sql", sql),
+                ("backend/migrations/20260102000001_create_users/up.sql", sql),
+            ],
+        );
+        write(
+            committed.path(),
+            "migrations/20250101000001_create_users.sql",
+            sql,
+        );
+        write(
+            committed.path(),
+            "backend/migrations/20250101000001_create_users/up.sql",
+            "CREATE TABLE people ();\n",
+        );
+
+        let check = check_output(&files, fresh.path(), committed.path()).unwrap();
+        assert_eq!(check.unchanged, 1);
+        assert_eq!(
+            check.modified,
+            vec![PathBuf::from(
+                "backend/migrations/20260102000001_create_users/up.sql"
+            )]
+        );
+        assert!(check.missing.is_empty());
+    }
+
+    #[test]
+    fn test_missing_committed_dir() {
+        let fresh = tempfile::tempdir().unwrap();
+        let files = generated(fresh.path(), &[("Cargo.toml", "[package]\n")]);
+
+        let check = check_output(&files, fresh.path(), &fresh.path().join("nope")).unwrap();
+        assert_eq!(check.missing, vec![PathBuf::from("Cargo.toml")]);
+    }
+
+    #[test]
+    fn test_undated_migration() {
+        assert_eq!(
+            undated_migration(Path::new("migrations/20250101000001_create_users.sql")),
+            Some(PathBuf::from("migrations/000001_create_users.sql"))
+        );
+        assert_eq!(
+            undated_migration(Path::new(
+                "backend/migrations/20250101000001_create_users/down.sql"
+            )),
+            Some(PathBuf::from(
+                "backend/migrations/000001_create_users/down.sql"
+            ))
+        );
+        assert_eq!(
+            undated_migration(Path::new("migrations/20250101120000_alter_schema.sql")),
+            None
+        );
+        assert_eq!(undated_migration(Path::new("src/main.rs")), None);
+    }
+}
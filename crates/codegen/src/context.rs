@@ -13,15 +13,27 @@
 //!
 
 use heck::{ToLowerCamelCase, ToPascalCase, ToSnakeCase};
-use imortal_core::{DataType, IdType};
+use imortal_core::{DataType, IdType, ReferentialAction, Validation};
+use imortal_ir::relationship_helpers::{
+    accessor_names, calculate_fk_info, dependent_entity, determine_fk_entity,
+    get_fk_field_to_entity, junction_keys, scope_key,
+};
 use imortal_ir::{
-    AuthConfig, AuthStrategy, DatabaseType, EndpointGroup, Entity, Field, ProjectConfig,
-    ProjectGraph, ProjectMeta, ProjectType, Relationship,
+    ApiStyle, ApiVersioning, AuthConfig, AuthStrategy, DatabaseType, EndpointGroup, Entity,
+    EnumDef, Field, HttpCaching, OAuthProvider, OperationType, Orm, PaginationStyle, ProjectConfig,
+    ProjectGraph, ProjectMeta, ProjectType, QueryDefinition, RateLimit, RealtimeTransport,
+    RelatedAggregate, Relationship, RouteStyle, UploadConfig, WebFramework,
 };
+use serde::Serialize;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::GeneratorConfig;
+use crate::rust::framework::{self, FrameworkBackend};
+use crate::rust::orm::{self, OrmBackend, OrmFeature};
+use crate::templates::{self, TEMPLATES_DIR_OPTION, Templates};
 
 // ============================================================================
 // GenerationContext
@@ -67,9 +79,27 @@ pub struct GenerationContext {
     /// Lookup: entity id → endpoint group index
     endpoint_by_entity: HashMap<Uuid, usize>,
 
+    // ── queries ──────────────────────────────────────────────────────────
+    /// Enabled custom queries, sorted by name
+    queries: Vec<QueryDefinition>,
+
+    // ── enums ────────────────────────────────────────────────────────────
+    /// Enum types: the project's plus inline ones fields declare, sorted by
+    /// name
+    enums: Vec<EnumDef>,
+
     // ── derived ──────────────────────────────────────────────────────────
     /// Timestamp prefix for migration files (YYYYMMDD)
     pub migration_date_prefix: String,
+
+    /// Full timestamp (YYYYMMDDHHMMSS) naming incremental migrations, so
+    /// each run's changes sort after everything generated before
+    pub migration_timestamp: String,
+
+    /// File templates, built-in unless replaced with [`with_templates`]
+    ///
+    /// [`with_templates`]: GenerationContext::with_templates
+    templates: Arc<Templates>,
 }
 
 impl GenerationContext {
@@ -78,7 +108,12 @@ impl GenerationContext {
     // ====================================================================
 
     /// Build a `GenerationContext` from a `ProjectGraph` and generator config.
+    ///
+    /// Field sets are expanded first, so generators see an entity's included
+    /// fields as its own.
     pub fn from_project(project: &ProjectGraph, generator_config: GeneratorConfig) -> Self {
+        let project = &project.expand_field_sets();
+
         // Collect and sort entities by dependency order
         let entities = Self::dependency_sorted_entities(project);
 
@@ -88,27 +123,66 @@ impl GenerationContext {
             .map(|(i, e)| (e.id, i))
             .collect();
 
-        // Collect relationships
-        let relationships: Vec<Relationship> = project.relationships.values().cloned().collect();
+        // Collect relationships (sorted for stable output)
+        let relationships = Self::sorted_relationships(project);
 
         let mut outgoing: HashMap<Uuid, Vec<usize>> = HashMap::new();
         let mut incoming: HashMap<Uuid, Vec<usize>> = HashMap::new();
+        // Polymorphic relationships have no foreign key to follow; they are
+        // resolved per association instead
         for (i, rel) in relationships.iter().enumerate() {
+            if rel.is_polymorphic() {
+                continue;
+            }
             outgoing.entry(rel.from_entity_id).or_default().push(i);
             incoming.entry(rel.to_entity_id).or_default().push(i);
         }
 
-        // Collect endpoints
-        let endpoints: Vec<EndpointGroup> = project.endpoints.values().cloned().collect();
+        // Collect endpoints in entity order (sorted for stable output)
+        let mut endpoints: Vec<EndpointGroup> = project.endpoints.values().cloned().collect();
+        endpoints.sort_by_key(|ep| {
+            (
+                entity_index
+                    .get(&ep.entity_id)
+                    .copied()
+                    .unwrap_or(usize::MAX),
+                ep.id,
+            )
+        });
         let endpoint_by_entity: HashMap<Uuid, usize> = endpoints
             .iter()
             .enumerate()
             .map(|(i, ep)| (ep.entity_id, i))
             .collect();
 
+        // Collect enabled custom queries (sorted for stable output). None
+        // are generated when the ORM backend cannot build them.
+        let orm = orm::backend(generator_config.orm.unwrap_or(project.config.orm));
+        let mut queries: Vec<QueryDefinition> = project
+            .queries
+            .values()
+            .filter(|q| q.enabled && orm.supports(OrmFeature::CustomQueries))
+            .cloned()
+            .collect();
+        queries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Collect enum types, adding inline enums no project enum defines
+        // (sorted for stable output)
+        let mut enums: Vec<EnumDef> = project.enums().into_iter().cloned().collect();
+        for field in entities.iter().flat_map(|e| &e.fields) {
+            if let Some(def) = EnumDef::from_data_type(&field.data_type)
+                && !enums.iter().any(|e| e.name == def.name)
+            {
+                enums.push(def);
+            }
+        }
+        enums.dedup_by(|a, b| a.name == b.name);
+        enums.sort_by(|a, b| a.name.cmp(&b.name));
+
         // Build migration date prefix from current time
         let now = chrono::Utc::now();
         let migration_date_prefix = now.format("%Y%m%d").to_string();
+        let migration_timestamp = now.format("%Y%m%d%H%M%S").to_string();
 
         Self {
             meta: project.meta.clone(),
@@ -121,10 +195,20 @@ impl GenerationContext {
             incoming,
             endpoints,
             endpoint_by_entity,
+            queries,
+            enums,
             migration_date_prefix,
+            migration_timestamp,
+            templates: Arc::new(Templates::builtin()),
         }
     }
 
+    /// Render templates from `templates` instead of the built-in ones.
+    pub fn with_templates(mut self, templates: Templates) -> Self {
+        self.templates = Arc::new(templates);
+        self
+    }
+
     /// Build with default generator config (convenience for tests).
     pub fn from_project_default(project: &ProjectGraph) -> Self {
         Self::from_project(project, GeneratorConfig::default())
@@ -157,6 +241,24 @@ impl GenerationContext {
             .find(|e| e.name.to_lowercase() == lower)
     }
 
+    /// Whether any entity has a decimal field (pulls in `rust_decimal`).
+    pub fn uses_decimal(&self) -> bool {
+        self.entities
+            .iter()
+            .flat_map(|e| &e.fields)
+            .any(|f| f.data_type.is_decimal())
+    }
+
+    /// Whether any field has a pattern rule (pulls in `regex` for the
+    /// compiled patterns the DTOs validate against).
+    pub fn uses_pattern_validation(&self) -> bool {
+        self.entities
+            .iter()
+            .flat_map(|e| &e.fields)
+            .flat_map(|f| &f.validations)
+            .any(|v| matches!(v, Validation::Pattern { .. }))
+    }
+
     /// Get the non-PK, non-generated fields that the user should provide
     /// when *creating* a resource.
     pub fn create_fields<'a>(&self, entity: &'a Entity) -> Vec<&'a Field> {
@@ -166,6 +268,8 @@ impl GenerationContext {
             .filter(|f| {
                 !f.is_primary_key
                     && !f.readonly
+                    && !f.is_computed()
+                    && !f.is_upload()
                     && f.name != "created_at"
                     && f.name != "updated_at"
                     && f.name != "deleted_at"
@@ -183,7 +287,9 @@ impl GenerationContext {
                 !f.is_primary_key
                     && !f.is_foreign_key
                     && !f.readonly
+                    && !f.is_computed()
                     && !f.secret
+                    && !f.is_upload()
                     && f.name != "created_at"
                     && f.name != "updated_at"
                     && f.name != "deleted_at"
@@ -192,9 +298,13 @@ impl GenerationContext {
     }
 
     /// Get the fields to include in a *response* DTO (everything except
-    /// secrets like password hashes).
+    /// secrets like password hashes and fields marked hidden).
     pub fn response_fields<'a>(&self, entity: &'a Entity) -> Vec<&'a Field> {
-        entity.fields.iter().filter(|f| !f.secret).collect()
+        entity
+            .fields
+            .iter()
+            .filter(|f| f.in_response_dto())
+            .collect()
     }
 
     /// Get the primary key field, or `None`.
@@ -248,6 +358,31 @@ impl GenerationContext {
             .map(|&i| &self.endpoints[i])
     }
 
+    /// Enabled custom queries, sorted by name. Empty when the ORM backend
+    /// cannot generate them.
+    pub fn queries(&self) -> &[QueryDefinition] {
+        &self.queries
+    }
+
+    // ====================================================================
+    // Enum accessors
+    // ====================================================================
+
+    /// Enum types to generate, sorted by name: the project's enums plus
+    /// inline enums declared by fields.
+    pub fn enums(&self) -> &[EnumDef] {
+        &self.enums
+    }
+
+    /// Whether a field's type is one of the enums, looking through
+    /// `Optional` and `Array`.
+    pub fn uses_enums(&self, entity: &Entity) -> bool {
+        entity
+            .fields
+            .iter()
+            .any(|f| imortal_ir::enum_of(&f.data_type).is_some())
+    }
+
     // ====================================================================
     // Project-level helpers
     // ====================================================================
@@ -282,14 +417,279 @@ impl GenerationContext {
         &self.config.auth
     }
 
+    /// Whether auth issues rotating refresh tokens backed by sessions.
+    pub fn refresh_tokens(&self) -> bool {
+        self.auth_enabled() && self.config.auth.refresh_tokens
+    }
+
+    /// Identity providers users sign in with; empty unless auth uses the
+    /// OAuth2 strategy.
+    pub fn oauth_providers(&self) -> &[OAuthProvider] {
+        if self.auth_enabled() && self.auth_strategy() == AuthStrategy::OAuth2 {
+            &self.config.auth.oauth_providers
+        } else {
+            &[]
+        }
+    }
+
+    /// Whether the router mounts routes under `/api/auth`: the session
+    /// routes of refresh tokens, or the OAuth2 sign-in routes.
+    pub fn auth_routes(&self) -> bool {
+        self.refresh_tokens() || !self.oauth_providers().is_empty()
+    }
+
+    /// Whether the auth config declares its roles, which then become a
+    /// `Role` enum; otherwise roles are plain names, managed as data.
+    pub fn role_enum(&self) -> bool {
+        !self.config.auth.available_roles.is_empty()
+    }
+
+    /// Every role the project names, without duplicates: the declared and
+    /// default roles, then those endpoints and queries ask for.
+    pub fn roles(&self) -> Vec<&str> {
+        let auth = &self.config.auth;
+        let endpoint_roles = self.endpoints.iter().flat_map(|ep| {
+            std::iter::once(&ep.global_security)
+                .chain(ep.operations.iter().filter_map(|op| op.security.as_ref()))
+                .flat_map(|security| &security.roles)
+        });
+        let query_roles = self.queries.iter().flat_map(|q| &q.security.roles);
+
+        let mut roles: Vec<&str> = Vec::new();
+        for role in auth
+            .available_roles
+            .iter()
+            .chain(&auth.default_roles)
+            .chain(endpoint_roles)
+            .chain(query_roles)
+        {
+            if !roles.contains(&role.as_str()) {
+                roles.push(role);
+            }
+        }
+        roles
+    }
+
+    /// The auth principal's password field, when the auth config maps one
+    /// that exists in the project.
+    pub fn principal_password_field(&self) -> Option<&Field> {
+        let principal = self.config.auth.principal.as_ref()?;
+        self.entity_by_name(&principal.entity)?
+            .get_field_by_name(&principal.password_field)
+    }
+
+    /// Whether `field` stores a password or secret that handlers hash before
+    /// storing: secret or password-like fields, and the principal's password
+    /// field whatever its name.
+    pub fn is_password_field(&self, field: &Field) -> bool {
+        field.secret
+            || field.name.contains("password")
+            || field.name.contains("secret")
+            || self
+                .principal_password_field()
+                .is_some_and(|f| f.id == field.id)
+    }
+
     /// Is this a fullstack project?
     pub fn is_fullstack(&self) -> bool {
         matches!(self.config.project_type, ProjectType::Fullstack)
     }
 
     /// Is OpenAPI generation enabled?
+    ///
+    /// Always off when no REST routes are generated — there is nothing to
+    /// document.
     pub fn openapi_enabled(&self) -> bool {
-        self.config.openapi_enabled
+        self.config.openapi_enabled && self.rest_enabled()
+    }
+
+    /// API style to generate: the generator override if set, otherwise the
+    /// project's choice.
+    pub fn api_style(&self) -> ApiStyle {
+        self.generator_config
+            .api_style
+            .unwrap_or(self.config.api_style)
+    }
+
+    /// Whether the `src/graphql/` schema is generated. Needs an ORM backend
+    /// that supports it.
+    pub fn graphql_enabled(&self) -> bool {
+        self.api_style().serves_graphql() && self.orm_supports(OrmFeature::GraphQl)
+    }
+
+    /// Whether the `src/realtime.rs` change feed is generated: some enabled
+    /// endpoint group pushes its changes. REST clients subscribe through the
+    /// group's WebSocket or SSE route, GraphQL clients through subscriptions.
+    pub fn realtime_enabled(&self) -> bool {
+        self.endpoints
+            .iter()
+            .any(|endpoint| endpoint.enabled && endpoint.realtime)
+    }
+
+    /// Whether some entity streams its changes to REST clients over
+    /// `transport`.
+    pub fn uses_realtime_transport(&self, transport: RealtimeTransport) -> bool {
+        self.entities
+            .iter()
+            .any(|entity| EntityInfo::new(entity, self).live_transport() == Some(transport))
+    }
+
+    /// Whether REST handlers and routes are generated. A GraphQL-only
+    /// project falls back to REST when the schema can't be generated, and
+    /// fullstack projects keep it because the frontend's API client calls it.
+    pub fn rest_enabled(&self) -> bool {
+        self.api_style().serves_rest() || !self.graphql_enabled() || self.is_fullstack()
+    }
+
+    /// List-response envelope used by every list endpoint.
+    pub fn pagination(&self) -> PaginationStyle {
+        self.config.pagination
+    }
+
+    /// Plurality and case of derived resource paths.
+    pub fn route_style(&self) -> RouteStyle {
+        self.config.route_style
+    }
+
+    /// How resource routes carry their API version.
+    pub fn api_versioning(&self) -> &ApiVersioning {
+        &self.config.api_versioning
+    }
+
+    /// Whether every foreign key column gets an index.
+    pub fn index_foreign_keys(&self) -> bool {
+        self.config.index_foreign_keys
+    }
+
+    /// Whether reads go through a separate replica connection.
+    pub fn read_replicas(&self) -> bool {
+        self.config.read_replicas
+    }
+
+    /// Whether hard deletes report `RESTRICT` blockers with a 409.
+    pub fn report_delete_blockers(&self) -> bool {
+        self.config.report_delete_blockers
+    }
+
+    /// Whether incremental migrations stage destructive changes.
+    pub fn safe_migrations(&self) -> bool {
+        self.config.safe_migrations
+    }
+
+    /// Whether the generated server exports OpenTelemetry traces.
+    pub fn telemetry(&self) -> bool {
+        self.config.telemetry
+    }
+
+    /// Whether the server answers `/healthz` and `/readyz`.
+    pub fn health_checks(&self) -> bool {
+        self.config.observability.health_checks
+    }
+
+    /// Whether the server exports Prometheus metrics on `/metrics`.
+    pub fn metrics(&self) -> bool {
+        self.config.observability.metrics
+    }
+
+    /// Storage backend and size limit for file uploads.
+    pub fn uploads(&self) -> &UploadConfig {
+        &self.config.uploads
+    }
+
+    /// Whether `src/storage.rs` is generated: some entity has an upload
+    /// endpoint.
+    pub fn uploads_enabled(&self) -> bool {
+        self.entities
+            .iter()
+            .any(|entity| !EntityInfo::new(entity, self).upload_fields().is_empty())
+    }
+
+    /// Whether `src/http_cache.rs` is generated: some read handler sends
+    /// ETag or Last-Modified headers.
+    pub fn http_caching_enabled(&self) -> bool {
+        self.entities.iter().any(|entity| {
+            let info = EntityInfo::new(entity, self);
+            [OperationType::Read, OperationType::ReadAll]
+                .into_iter()
+                .any(|op| info.caching(op).is_some())
+        })
+    }
+
+    /// The rate limit of a route setting `own`, or else the project default.
+    pub fn rate_limit<'b>(&'b self, own: Option<&'b RateLimit>) -> Option<&'b RateLimit> {
+        own.or(self.config.rate_limit.as_ref())
+    }
+
+    /// Whether `src/rate_limit.rs` is generated: some REST operation or
+    /// custom query is rate limited.
+    pub fn rate_limiting_enabled(&self) -> bool {
+        if !self.rest_enabled() {
+            return false;
+        }
+        let limits_operation = self.entities.iter().any(|entity| {
+            EntityInfo::new(entity, self)
+                .endpoint()
+                .filter(|ep| ep.enabled)
+                .is_some_and(|ep| {
+                    ep.enabled_operations()
+                        .iter()
+                        .any(|op| self.rate_limit(op.rate_limit.as_ref()).is_some())
+                })
+        });
+        limits_operation || (!self.queries.is_empty() && self.config.rate_limit.is_some())
+    }
+
+    /// Whether `src/jobs.rs` is generated: the project asks for background
+    /// jobs and the ORM backend can write them.
+    pub fn background_jobs(&self) -> bool {
+        self.config.background_jobs && self.orm_supports(OrmFeature::BackgroundJobs)
+    }
+
+    /// Whether `frontend/ts-client/` is generated: the project asks for it
+    /// and serves a REST API for it to call.
+    pub fn typescript_client(&self) -> bool {
+        self.config.typescript_client && self.rest_enabled()
+    }
+
+    /// ORM the data layer is generated for: the generator override if set,
+    /// otherwise the project's choice.
+    pub fn orm(&self) -> Orm {
+        self.generator_config.orm.unwrap_or(self.config.orm)
+    }
+
+    /// Backend generating the ORM-specific code for [`Self::orm`].
+    pub fn orm_backend(&self) -> &'static dyn OrmBackend {
+        orm::backend(self.orm())
+    }
+
+    /// Whether the selected ORM backend can generate `feature`.
+    pub fn orm_supports(&self, feature: OrmFeature) -> bool {
+        self.orm_backend().supports(feature)
+    }
+
+    /// Web framework the HTTP layer is generated for: the generator
+    /// override if set, otherwise the project's choice.
+    pub fn framework(&self) -> WebFramework {
+        self.generator_config
+            .framework
+            .unwrap_or(self.config.framework)
+    }
+
+    /// Backend generating the framework-specific code for [`Self::framework`].
+    pub fn framework_backend(&self) -> &'static dyn FrameworkBackend {
+        framework::backend(self.framework())
+    }
+
+    /// Connection expression read-only handlers should query through.
+    ///
+    /// `state.db_read` with read replicas enabled, otherwise the single
+    /// `state.db` pool.
+    pub fn read_db(&self) -> &'static str {
+        if self.read_replicas() {
+            "state.db_read"
+        } else {
+            "state.db"
+        }
     }
 
     /// Server host.
@@ -317,6 +717,77 @@ impl GenerationContext {
         self.generator_config.generate_migrations
     }
 
+    /// Whether to generate a member crate for an existing Cargo workspace.
+    pub fn workspace_member(&self) -> bool {
+        self.generator_config.workspace_member
+    }
+
+    /// Whether to generate seed data.
+    pub fn generate_seeds(&self) -> bool {
+        self.generator_config.generate_seeds
+    }
+
+    /// Whether to generate the k6 load test script, either through the
+    /// dedicated flag or the `load_tests = "true"` option.
+    pub fn generate_load_tests(&self) -> bool {
+        self.generator_config.generate_load_tests
+            || self
+                .generator_config
+                .options
+                .get("load_tests")
+                .is_some_and(|v| v == "true")
+    }
+
+    /// Whether to generate the client SDK crate, either through the
+    /// dedicated flag or the `client_sdk = "true"` option.
+    pub fn generate_client_sdk(&self) -> bool {
+        self.generator_config.client_sdk
+            || self
+                .generator_config
+                .options
+                .get("client_sdk")
+                .is_some_and(|v| v == "true")
+    }
+
+    /// Whether to generate Docker deployment files, either through the
+    /// dedicated flag or the `docker = "true"` option.
+    pub fn generate_docker(&self) -> bool {
+        self.generator_config.generate_docker
+            || self
+                .generator_config
+                .options
+                .get("docker")
+                .is_some_and(|v| v == "true")
+    }
+
+    /// Directory of template overrides: the `templates_dir` option, or else
+    /// a `templates/` directory next to the saved project file if there is
+    /// one.
+    pub fn templates_dir(&self) -> Option<PathBuf> {
+        if let Some(dir) = self.generator_config.options.get(TEMPLATES_DIR_OPTION) {
+            return Some(PathBuf::from(dir));
+        }
+        self.meta
+            .file_path
+            .as_ref()
+            .map(|file| templates::project_templates_dir(file.as_ref()))
+            .filter(|dir| dir.is_dir())
+    }
+
+    // ====================================================================
+    // Templates
+    // ====================================================================
+
+    /// The file templates of this generation.
+    pub fn templates(&self) -> &Templates {
+        &self.templates
+    }
+
+    /// Render the template `name` (see [`templates`](crate::templates)).
+    pub fn render<S: Serialize>(&self, name: &str, context: S) -> String {
+        self.templates.render(name, context)
+    }
+
     // ====================================================================
     // Naming helpers
     // ====================================================================
@@ -386,6 +857,31 @@ impl GenerationContext {
         format!("{}Response", Self::pascal(entity_name))
     }
 
+    pub fn list_item_dto_name(entity_name: &str) -> String {
+        format!("{}ListItem", Self::pascal(entity_name))
+    }
+
+    /// DTO for one child embedded in its parent's create payload
+    /// (e.g. "OrderItem" in "Order" → "CreateOrderItemInOrderDto").
+    pub fn nested_create_dto_name(child_name: &str, parent_name: &str) -> String {
+        format!(
+            "Create{}In{}Dto",
+            Self::pascal(child_name),
+            Self::pascal(parent_name)
+        )
+    }
+
+    /// Payload linking a row to another through a junction entity, named
+    /// after the owner and its accessor (e.g. "Post" + "tags" →
+    /// "PostTagsLinkDto").
+    pub fn link_dto_name(owner_name: &str, accessor: &str) -> String {
+        format!(
+            "{}{}LinkDto",
+            Self::pascal(owner_name),
+            Self::pascal(accessor)
+        )
+    }
+
     // ====================================================================
     // Type mapping helpers
     // ====================================================================
@@ -433,6 +929,7 @@ impl GenerationContext {
                 DatabaseType::MySQL => "DOUBLE".into(),
                 DatabaseType::SQLite => "REAL".into(),
             },
+            DataType::Decimal { .. } => dt.to_sql_type(db),
             DataType::Bool => match db {
                 DatabaseType::PostgreSQL => "BOOLEAN".into(),
                 DatabaseType::MySQL => "TINYINT(1)".into(),
@@ -477,7 +974,7 @@ impl GenerationContext {
             }
             DataType::Reference { .. } => Self::sql_type(&DataType::Uuid, db),
             DataType::Enum { name, variants } => match db {
-                DatabaseType::PostgreSQL => format!("VARCHAR(50) /* enum {} */", name),
+                DatabaseType::PostgreSQL => Self::enum_type_name(name),
                 DatabaseType::MySQL => {
                     let opts = variants
                         .iter()
@@ -491,6 +988,11 @@ impl GenerationContext {
         }
     }
 
+    /// Name of the PostgreSQL type created for an enum (`post_status`).
+    pub fn enum_type_name(enum_name: &str) -> String {
+        Self::snake(enum_name)
+    }
+
     /// Return the SQL `DEFAULT` clause string for a field, if any.
     pub fn sql_default(field: &Field, db: DatabaseType) -> Option<String> {
         use imortal_ir::DefaultValue;
@@ -585,13 +1087,31 @@ impl GenerationContext {
         )
     }
 
+    /// File name for the incremental migration generated against a baseline.
+    pub fn alter_migration_filename(&self) -> String {
+        format!("{}_alter_schema.sql", self.migration_timestamp)
+    }
+
     // ====================================================================
     // Internal: dependency-ordered entity sort
     // ====================================================================
 
+    /// Relationships ordered by name, then ID.
+    fn sorted_relationships(project: &ProjectGraph) -> Vec<Relationship> {
+        let mut relationships: Vec<Relationship> =
+            project.relationships.values().cloned().collect();
+        relationships.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        relationships
+    }
+
     /// Topological sort of entities so that referenced tables come first.
+    ///
+    /// Entities are visited by name (then ID), so the order — and with it
+    /// migration numbering and every generated list — is the same on every
+    /// run for the same project.
     fn dependency_sorted_entities(project: &ProjectGraph) -> Vec<Entity> {
-        let entities: Vec<Entity> = project.entities.values().cloned().collect();
+        let mut entities: Vec<Entity> = project.entities.values().cloned().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
         if entities.len() <= 1 {
             return entities;
         }
@@ -608,16 +1128,23 @@ impl GenerationContext {
         let mut in_degree = vec![0u32; n];
         let mut adj: Vec<Vec<usize>> = vec![vec![]; n];
 
-        for rel in project.relationships.values() {
-            // to_entity depends on from_entity for OneToMany etc.
-            if let (Some(&from_idx), Some(&to_idx)) = (
-                id_to_idx.get(&rel.from_entity_id),
-                id_to_idx.get(&rel.to_entity_id),
-            ) {
-                if from_idx != to_idx {
-                    adj[from_idx].push(to_idx);
-                    in_degree[to_idx] += 1;
-                }
+        for rel in Self::sorted_relationships(project) {
+            // The side holding the FK depends on the other one; many-to-many
+            // and polymorphic relationships hold none
+            let Some(holder) = determine_fk_entity(&rel) else {
+                continue;
+            };
+            let referenced = if holder == rel.from_entity_id {
+                rel.to_entity_id
+            } else {
+                rel.from_entity_id
+            };
+            if let (Some(&dep_idx), Some(&holder_idx)) =
+                (id_to_idx.get(&referenced), id_to_idx.get(&holder))
+                && dep_idx != holder_idx
+            {
+                adj[dep_idx].push(holder_idx);
+                in_degree[holder_idx] += 1;
             }
         }
 
@@ -695,6 +1222,15 @@ impl<'a> EntityInfo<'a> {
         }
     }
 
+    /// The database schema/namespace the table lives in, if the entity sets
+    /// one and the target database supports it (SQLite has no schemas).
+    pub fn schema(&self) -> Option<&'a str> {
+        if self.ctx.database() == DatabaseType::SQLite {
+            return None;
+        }
+        self.entity.config.schema_name()
+    }
+
     /// PascalCase entity name.
     pub fn pascal_name(&self) -> String {
         GenerationContext::pascal(&self.entity.name)
@@ -767,14 +1303,727 @@ impl<'a> EntityInfo<'a> {
         self.ctx.endpoint_for_entity(self.entity.id)
     }
 
-    /// The default API base path for this entity.
+    /// Whether changes to this entity are pushed to live subscribers.
+    pub fn is_realtime(&self) -> bool {
+        self.ctx.realtime_enabled() && self.endpoint().is_some_and(|ep| ep.enabled && ep.realtime)
+    }
+
+    /// Transport of the REST route streaming this entity's changes, if it
+    /// has one: it is realtime and the project serves REST.
+    pub fn live_transport(&self) -> Option<RealtimeTransport> {
+        if !self.is_realtime() || !self.ctx.rest_enabled() {
+            return None;
+        }
+        self.endpoint().map(|ep| ep.realtime_transport)
+    }
+
+    /// `File`/`Image` fields, each set through its own upload route
+    /// (`POST {base}/:id/{field}`). The routes ride on the update operation,
+    /// so there are none without it or without REST.
+    pub fn upload_fields(&self) -> Vec<&'a Field> {
+        let updates = self.ctx.rest_enabled()
+            && self.endpoint().is_some_and(|ep| {
+                ep.enabled
+                    && ep
+                        .enabled_operations()
+                        .iter()
+                        .any(|op| op.operation_type == OperationType::Update)
+            });
+        if !updates {
+            return Vec::new();
+        }
+        self.entity
+            .fields
+            .iter()
+            .filter(|f| f.is_upload())
+            .collect()
+    }
+
+    /// ETag / Last-Modified headers the REST handler of `op` sends, if any.
+    /// Headers derived from `updated_at` need timestamps, so an entity
+    /// without them sends none.
+    pub fn caching(&self, op: OperationType) -> Option<&'a HttpCaching> {
+        if !self.ctx.rest_enabled() || !op.is_read() {
+            return None;
+        }
+        let endpoint = self.endpoint().filter(|ep| ep.enabled)?;
+        endpoint
+            .operations
+            .iter()
+            .find(|o| o.enabled && o.operation_type == op)
+            .map(|o| &o.caching)
+            .filter(|c| c.is_enabled() && (self.has_timestamps() || !c.uses_updated_at()))
+    }
+
+    /// Whether the change stream needs a signed-in caller. It shares the
+    /// security of the list operation, else of get, else the group's.
+    pub fn live_auth_required(&self) -> bool {
+        let Some(endpoint) = self.endpoint() else {
+            return false;
+        };
+        let ops = endpoint.enabled_operations();
+        let security = [OperationType::ReadAll, OperationType::Read]
+            .into_iter()
+            .find_map(|kind| ops.iter().find(|op| op.operation_type == kind))
+            .and_then(|op| op.security.as_ref())
+            .unwrap_or(&endpoint.global_security);
+        self.ctx.auth_enabled() && security.auth_required
+    }
+
+    /// The API base path clients call for this entity.
     pub fn base_path(&self) -> String {
-        self.endpoint()
-            .map(|ep| ep.full_base_path())
-            .unwrap_or_else(|| GenerationContext::default_base_path(&self.entity.name))
+        let versioning = self.ctx.api_versioning();
+        match self.endpoint() {
+            Some(ep) => ep.public_path(versioning),
+            None => versioning.public_path(&self.derived_base_path(), None),
+        }
+    }
+
+    /// The path the router mounts this entity's routes at. Same as
+    /// [`base_path`](Self::base_path) unless the version travels in a header.
+    pub fn route_path(&self) -> String {
+        let versioning = self.ctx.api_versioning();
+        match self.endpoint() {
+            Some(ep) => ep.route_path(versioning),
+            None => versioning.route_path(&self.derived_base_path(), None),
+        }
+    }
+
+    /// API version of this entity's routes, if any.
+    pub fn api_version(&self) -> Option<&str> {
+        let own = self.endpoint().and_then(|ep| ep.api_version.as_deref());
+        self.ctx.api_versioning().resolve(own)
+    }
+
+    fn derived_base_path(&self) -> String {
+        self.ctx.route_style().base_path(&self.entity.name)
+    }
+
+    /// Effects of deleting a row of this entity on the rows that reference
+    /// it, one per foreign key pointing here (self-references included).
+    pub fn delete_effects(&self) -> Vec<DeleteEffect<'a>> {
+        let entity_id = self.entity.id;
+        self.ctx
+            .entities()
+            .iter()
+            .flat_map(|child| {
+                child.fields.iter().filter_map(move |field| {
+                    let fk = field.foreign_key_ref.as_ref()?;
+                    (field.is_foreign_key && fk.entity_id == entity_id).then_some(DeleteEffect {
+                        child,
+                        fk_field: field,
+                        action: fk.on_delete,
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// References that block a hard delete, when the project asks handlers
+    /// to report them. Empty for soft-deleted entities, whose rows are never
+    /// removed, and when the ORM backend cannot check for blockers.
+    pub fn delete_blockers(&self) -> Vec<DeleteEffect<'a>> {
+        if !self.ctx.report_delete_blockers()
+            || self.has_soft_delete()
+            || !self.ctx.orm_supports(OrmFeature::DeleteBlockers)
+        {
+            return Vec::new();
+        }
+        self.delete_effects()
+            .into_iter()
+            .filter(DeleteEffect::blocks)
+            .collect()
+    }
+
+    /// Related counts / existence flags requested for list responses.
+    ///
+    /// Aggregates whose relationship cannot be resolved are skipped; project
+    /// validation reports them. Empty when the ORM backend cannot load them.
+    pub fn related_aggregates(&self) -> Vec<ResolvedAggregate<'a>> {
+        let Some(endpoint) = self.endpoint() else {
+            return Vec::new();
+        };
+        if !self.ctx.orm_supports(OrmFeature::RelatedAggregates) {
+            return Vec::new();
+        }
+
+        endpoint
+            .related_aggregates
+            .iter()
+            .filter_map(|aggregate| {
+                let rel = self
+                    .ctx
+                    .relationships()
+                    .iter()
+                    .find(|r| r.id == aggregate.relationship_id)?;
+                let related = self
+                    .ctx
+                    .entity_by_id(dependent_entity(rel, self.entity.id)?)?;
+
+                let fk_field = related
+                    .fields
+                    .iter()
+                    .find(|f| {
+                        f.foreign_key_ref
+                            .as_ref()
+                            .is_some_and(|fk| fk.entity_id == self.entity.id)
+                    })
+                    .map(|f| f.name.clone())
+                    .or_else(|| {
+                        let from = self.ctx.entity_by_id(rel.from_entity_id)?;
+                        let to = self.ctx.entity_by_id(rel.to_entity_id)?;
+                        calculate_fk_info(rel, &from.name, &to.name).map(|i| i.field_name)
+                    })?;
+
+                Some(ResolvedAggregate {
+                    aggregate,
+                    related,
+                    fk_field,
+                })
+            })
+            .collect()
+    }
+
+    /// Children this entity's create endpoint accepts inline, one per
+    /// one-to-many relationship from it marked for nested creation. Empty
+    /// when the ORM backend cannot insert them.
+    pub fn nested_children(&self) -> Vec<NestedCreate<'a>> {
+        if !self.ctx.orm_supports(OrmFeature::NestedCreate) {
+            return Vec::new();
+        }
+        self.outgoing_relationships()
+            .into_iter()
+            .filter_map(|rel| NestedCreate::resolve(self.ctx, rel))
+            .collect()
+    }
+
+    /// Accessors to related rows, one per relationship end on this entity:
+    /// `author` where the entity holds the foreign key, `posts` where the
+    /// key points at it. Derived names that collide get the other end's
+    /// name appended (`posts_by_editor`); names set by hand are kept.
+    pub fn relation_accessors(&self) -> Vec<RelationAccessor<'a>> {
+        let mut accessors = Vec::new();
+        // Per accessor: whether its name was set by hand, and the name of
+        // the opposite end
+        let mut origins = Vec::new();
+
+        for rel in self.ctx.relationships() {
+            if !rel.involves_entity(self.entity.id) || rel.is_polymorphic() {
+                continue;
+            }
+            let (Some(from), Some(to)) = (
+                self.ctx.entity_by_id(rel.from_entity_id),
+                self.ctx.entity_by_id(rel.to_entity_id),
+            ) else {
+                continue;
+            };
+            let entity = |id: Uuid| if id == from.id { from } else { to };
+            let names = accessor_names(rel, from, to);
+            let holder = entity(names.accessor_entity_id);
+            let referenced = entity(names.inverse_entity_id);
+            let fk_field = if rel.is_many_to_many() {
+                None
+            } else {
+                holder
+                    .fields
+                    .iter()
+                    .find(|f| f.is_foreign_key && f.name == rel.from_field)
+                    .or_else(|| get_fk_field_to_entity(holder, referenced.id))
+            };
+
+            let ends = [
+                (
+                    holder,
+                    referenced,
+                    &names.accessor,
+                    &rel.accessor_name,
+                    &names.inverse,
+                    true,
+                ),
+                (
+                    referenced,
+                    holder,
+                    &names.inverse,
+                    &rel.inverse_name,
+                    &names.accessor,
+                    false,
+                ),
+            ];
+            for (on, target, name, stored, partner, fk_side) in ends {
+                if on.id != self.entity.id {
+                    continue;
+                }
+                accessors.push(RelationAccessor {
+                    relationship: rel,
+                    name: name.clone(),
+                    target,
+                    fk_field,
+                    holds_fk: fk_side && fk_field.is_some(),
+                    many: rel.is_many_to_many() || (!fk_side && !rel.is_one_to_one()),
+                    from_end: (holder.id == from.id) == fk_side,
+                });
+                let explicit = stored.as_deref().is_some_and(|n| !n.trim().is_empty());
+                origins.push((explicit, partner.clone()));
+            }
+        }
+
+        let names: Vec<String> = accessors.iter().map(|a| a.name.clone()).collect();
+        for (i, (accessor, (explicit, partner))) in accessors.iter_mut().zip(origins).enumerate() {
+            let clashes = names
+                .iter()
+                .enumerate()
+                .any(|(j, name)| j != i && *name == accessor.name);
+            if clashes && !explicit {
+                accessor.name = format!("{}_by_{}", accessor.name, partner);
+            }
+        }
+        accessors
+    }
+
+    /// Parents whose create endpoint accepts this entity inline.
+    pub fn nested_parents(&self) -> Vec<NestedCreate<'a>> {
+        if !self.ctx.orm_supports(OrmFeature::NestedCreate) {
+            return Vec::new();
+        }
+        self.incoming_relationships()
+            .into_iter()
+            .filter_map(|rel| NestedCreate::resolve(self.ctx, rel))
+            .collect()
+    }
+
+    /// Many-to-many links this entity manages through a promoted junction
+    /// entity, one per accessor. Empty when the ORM backend cannot
+    /// generate the routes.
+    pub fn associations(&self) -> Vec<Association<'a>> {
+        if !self.ctx.orm_supports(OrmFeature::Associations) {
+            return Vec::new();
+        }
+        self.relation_accessors()
+            .into_iter()
+            .filter_map(|accessor| Association::resolve(self.ctx, self.entity, accessor))
+            .collect()
+    }
+
+    /// Polymorphic associations this entity holds, one per discriminator,
+    /// with their targets ordered by name. Associations whose fields
+    /// are missing are left out; validation reports them.
+    pub fn polymorphic_associations(&self) -> Vec<PolymorphicAssociation<'a>> {
+        let mut associations: Vec<PolymorphicAssociation<'a>> = Vec::new();
+        for rel in self.ctx.relationships() {
+            if rel.from_entity_id != self.entity.id {
+                continue;
+            }
+            let (Some(discriminator), Some(target)) =
+                (rel.discriminator(), self.ctx.entity_by_id(rel.to_entity_id))
+            else {
+                continue;
+            };
+            if let Some(existing) = associations
+                .iter_mut()
+                .find(|a| a.discriminator.name == discriminator)
+            {
+                if !existing.targets.iter().any(|t| t.id == target.id) {
+                    existing.targets.push(target);
+                }
+                continue;
+            }
+            let field = |name: &str| self.entity.fields.iter().find(|f| f.name == name);
+            let (Some(discriminator), Some(key)) = (field(discriminator), field(&rel.from_field))
+            else {
+                continue;
+            };
+            associations.push(PolymorphicAssociation {
+                holder: self.entity,
+                name: rel
+                    .polymorphic_association()
+                    .unwrap_or_default()
+                    .to_string(),
+                discriminator,
+                key,
+                targets: vec![target],
+            });
+        }
+        for association in &mut associations {
+            association
+                .targets
+                .sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        }
+        associations
+    }
+
+    /// Polymorphic associations that may point at this entity, with the
+    /// accessor this entity uses for the rows pointing at it (`comments`).
+    pub fn polymorphic_inverses(&self) -> Vec<PolymorphicInverse<'a>> {
+        let mut inverses = Vec::new();
+        for rel in self.ctx.relationships() {
+            if rel.to_entity_id != self.entity.id {
+                continue;
+            }
+            let (Some(discriminator), Some(holder)) = (
+                rel.discriminator(),
+                self.ctx.entity_by_id(rel.from_entity_id),
+            ) else {
+                continue;
+            };
+            let Some(association) = EntityInfo::new(holder, self.ctx)
+                .polymorphic_associations()
+                .into_iter()
+                .find(|a| a.discriminator.name == discriminator)
+            else {
+                continue;
+            };
+            inverses.push(PolymorphicInverse {
+                name: accessor_names(rel, holder, self.entity).inverse,
+                association,
+            });
+        }
+        inverses
+    }
+
+    /// Fields unique within a relationship's parent, each paired with the
+    /// foreign key it is unique alongside. Scopes that don't resolve are
+    /// left out; validation reports them.
+    pub fn scoped_uniques(&self) -> Vec<ScopedUnique<'a>> {
+        let ctx = self.ctx;
+        self.entity
+            .fields
+            .iter()
+            .filter(|f| f.is_column() && !f.unique)
+            .filter_map(|field| {
+                let rel = ctx
+                    .relationships()
+                    .iter()
+                    .find(|r| Some(r.id) == field.unique_scope)?;
+                let key = scope_key(rel, self.entity).filter(|k| k.id != field.id)?;
+                let parent_id = rel.other_entity(self.entity.id)?;
+                let parent = ctx.entity_by_id(parent_id)?;
+                let (from, to) = if rel.from_entity_id == self.entity.id {
+                    (self.entity, parent)
+                } else {
+                    (parent, self.entity)
+                };
+                Some(ScopedUnique {
+                    field,
+                    key,
+                    parent_name: accessor_names(rel, from, to).accessor,
+                })
+            })
+            .collect()
+    }
+
+    /// Field sets whose combined values are unique: the entity's own
+    /// `unique_together`, then each scoped field after its parent key.
+    pub fn unique_together(&self) -> Vec<Vec<String>> {
+        let mut sets = self.entity.config.unique_together.clone();
+        for scoped in self.scoped_uniques() {
+            let set = vec![scoped.key.name.clone(), scoped.field.name.clone()];
+            if !sets.contains(&set) {
+                sets.push(set);
+            }
+        }
+        sets
     }
 }
 
+/// What deleting a row does to the rows of another entity that reference it.
+///
+/// Derived from the `ON DELETE` action of the referencing foreign key, which
+/// is also what the migrations emit, so docs and handlers can't drift from
+/// the schema.
+#[derive(Debug, Clone)]
+pub struct DeleteEffect<'a> {
+    /// The entity holding the foreign key.
+    pub child: &'a Entity,
+    /// The foreign key field on `child`.
+    pub fk_field: &'a Field,
+    /// The foreign key's `ON DELETE` action.
+    pub action: ReferentialAction,
+}
+
+impl DeleteEffect<'_> {
+    /// Whether referencing rows make the delete fail.
+    pub fn blocks(&self) -> bool {
+        matches!(
+            self.action,
+            ReferentialAction::Restrict | ReferentialAction::NoAction
+        )
+    }
+
+    /// One-line, human-readable description for docs.
+    pub fn describe(&self) -> String {
+        let child = &self.child.name;
+        let field = &self.fk_field.name;
+        match self.action {
+            ReferentialAction::Cascade => {
+                format!("deletes every `{child}` whose `{field}` references it (CASCADE)")
+            }
+            ReferentialAction::SetNull => {
+                format!("sets `{child}.{field}` to NULL on referencing rows (SET NULL)")
+            }
+            ReferentialAction::SetDefault => {
+                format!("resets `{child}.{field}` to its default on referencing rows (SET DEFAULT)")
+            }
+            ReferentialAction::Restrict | ReferentialAction::NoAction => format!(
+                "is refused while any `{child}` references it via `{field}` ({})",
+                self.action.to_sql()
+            ),
+        }
+    }
+}
+
+/// A one-to-many relationship whose children are created together with
+/// their parent, resolved against the project graph.
+#[derive(Debug, Clone)]
+pub struct NestedCreate<'a> {
+    pub relationship: &'a Relationship,
+    /// The "one" side, whose create payload embeds the children.
+    pub parent: &'a Entity,
+    /// The "many" side.
+    pub child: &'a Entity,
+    /// FK field on `child` pointing at `parent`, filled in by the handler.
+    pub fk_field: &'a Field,
+    /// The parent's accessor for the children.
+    accessor: String,
+}
+
+impl<'a> NestedCreate<'a> {
+    /// Resolve `rel` if it is marked for nested creation. Self-references
+    /// are skipped: the payload type would contain itself.
+    fn resolve(ctx: &'a GenerationContext, rel: &'a Relationship) -> Option<Self> {
+        if !rel.nested_create || !rel.is_one_to_many() || rel.from_entity_id == rel.to_entity_id {
+            return None;
+        }
+        let parent = ctx.entity_by_id(rel.from_entity_id)?;
+        let child = ctx.entity_by_id(rel.to_entity_id)?;
+        let fk_field = child
+            .fields
+            .iter()
+            .find(|f| f.is_foreign_key && f.name == rel.from_field)
+            .or_else(|| {
+                child.fields.iter().find(|f| {
+                    f.foreign_key_ref
+                        .as_ref()
+                        .is_some_and(|fk| fk.entity_id == parent.id)
+                })
+            })?;
+
+        let accessor = EntityInfo::new(parent, ctx)
+            .relation_accessors()
+            .into_iter()
+            .find(|a| a.relationship.id == rel.id && !a.holds_fk)
+            .map_or_else(
+                || GenerationContext::pluralize(&GenerationContext::snake(&child.name)),
+                |a| a.name,
+            );
+
+        Some(Self {
+            relationship: rel,
+            parent,
+            child,
+            fk_field,
+            accessor,
+        })
+    }
+
+    /// Payload field holding the children: the parent's accessor for them
+    /// (e.g. `items`, by default the child's plural, `order_items`).
+    pub fn payload_field(&self) -> String {
+        self.accessor.clone()
+    }
+
+    /// Name of the DTO for one embedded child.
+    pub fn dto_name(&self) -> String {
+        GenerationContext::nested_create_dto_name(&self.child.name, &self.parent.name)
+    }
+
+    /// The child's create fields, minus the FK to the parent.
+    pub fn fields(&self, ctx: &'a GenerationContext) -> Vec<&'a Field> {
+        ctx.create_fields(self.child)
+            .into_iter()
+            .filter(|f| f.id != self.fk_field.id)
+            .collect()
+    }
+}
+
+/// A many-to-many relationship with a promoted junction entity, seen from
+/// one side (the owner): its rows are linked to the other side's by
+/// inserting and deleting junction rows under the owner's routes, e.g.
+/// `POST /api/posts/:id/tags`.
+#[derive(Debug, Clone)]
+pub struct Association<'a> {
+    pub relationship: &'a Relationship,
+    /// The side whose routes manage the links.
+    pub owner: &'a Entity,
+    /// The side being linked to.
+    pub other: &'a Entity,
+    /// The entity holding one row per link.
+    pub junction: &'a Entity,
+    /// FK on `junction` to `owner`, filled in from the path.
+    pub owner_key: &'a Field,
+    /// FK on `junction` to `other`.
+    pub other_key: &'a Field,
+    /// The owner's accessor for the linked rows, used as the route segment.
+    pub accessor: String,
+}
+
+impl<'a> Association<'a> {
+    /// Resolve the many-to-many end `accessor` of `owner`, if its junction
+    /// is an entity with a key to each side.
+    fn resolve(
+        ctx: &'a GenerationContext,
+        owner: &'a Entity,
+        accessor: RelationAccessor<'a>,
+    ) -> Option<Self> {
+        let rel = accessor.relationship;
+        if !rel.is_many_to_many() {
+            return None;
+        }
+        let junction = ctx.entity_by_id(rel.junction_entity_id?)?;
+        let (from_key, to_key) = junction_keys(junction, rel)?;
+        let (owner_key, other_key) = if accessor.from_end {
+            (from_key, to_key)
+        } else {
+            (to_key, from_key)
+        };
+
+        Some(Self {
+            relationship: rel,
+            owner,
+            other: accessor.target,
+            junction,
+            owner_key,
+            other_key,
+            accessor: accessor.name,
+        })
+    }
+
+    /// Name of the DTO for one new link.
+    pub fn dto_name(&self) -> String {
+        GenerationContext::link_dto_name(&self.owner.name, &self.accessor)
+    }
+
+    /// The junction's create fields, minus the key to the owner.
+    pub fn fields(&self, ctx: &'a GenerationContext) -> Vec<&'a Field> {
+        ctx.create_fields(self.junction)
+            .into_iter()
+            .filter(|f| f.id != self.owner_key.id)
+            .collect()
+    }
+
+    /// Path parameter naming the linked row (e.g. `tag_id`).
+    pub fn other_param(&self) -> String {
+        GenerationContext::snake(&self.other_key.name)
+    }
+
+    /// Handler suffix, unique per owner (e.g. `post_tags`).
+    pub fn handler_suffix(&self) -> String {
+        format!(
+            "{}_{}",
+            GenerationContext::snake(&self.owner.name),
+            self.accessor
+        )
+    }
+}
+
+/// One end of a relationship seen from an entity, with the accessor name
+/// generated code uses for it.
+#[derive(Debug, Clone)]
+pub struct RelationAccessor<'a> {
+    pub relationship: &'a Relationship,
+    /// Accessor name, unique on the entity (e.g. `author`, `posts`).
+    pub name: String,
+    /// The entity whose rows the accessor returns.
+    pub target: &'a Entity,
+    /// The linking FK field; `None` for many-to-many.
+    pub fk_field: Option<&'a Field>,
+    /// Whether the entity holds `fk_field` (the accessor returns the row
+    /// the key points at) rather than being pointed at by it.
+    pub holds_fk: bool,
+    /// Whether the accessor returns several rows.
+    pub many: bool,
+    /// Whether the accessor sits on the relationship's "from" end (for a
+    /// self-reference, the first of its two accessors).
+    pub from_end: bool,
+}
+
+/// A polymorphic association: a discriminator naming one of several target
+/// entities and a key holding the primary key of a row in it, e.g.
+/// `commentable_type` and `commentable_id` on `Comment`. There is no
+/// foreign key, so generated code resolves the row per target.
+#[derive(Debug, Clone)]
+pub struct PolymorphicAssociation<'a> {
+    /// The entity holding the pair.
+    pub holder: &'a Entity,
+    /// Association name (e.g. `commentable`).
+    pub name: String,
+    /// Field naming the target entity.
+    pub discriminator: &'a Field,
+    /// Field holding the target row's primary key.
+    pub key: &'a Field,
+    /// The entities the pair may point at.
+    pub targets: Vec<&'a Entity>,
+}
+
+impl PolymorphicAssociation<'_> {
+    /// Enum over the target rows (e.g. `Commentable`).
+    pub fn enum_name(&self) -> String {
+        GenerationContext::pascal(&self.name)
+    }
+
+    /// Enum over the target entities (e.g. `CommentableType`).
+    pub fn type_name(&self) -> String {
+        format!("{}Type", self.enum_name())
+    }
+
+    /// The value the discriminator stores for `target` (e.g. `post`).
+    pub fn type_value(target: &Entity) -> String {
+        GenerationContext::snake(&target.name)
+    }
+}
+
+/// A polymorphic association seen from one of its targets.
+#[derive(Debug, Clone)]
+pub struct PolymorphicInverse<'a> {
+    /// Accessor for the rows pointing at the target (e.g. `comments`).
+    pub name: String,
+    pub association: PolymorphicAssociation<'a>,
+}
+
+/// A field unique among the rows sharing a parent, e.g. `slug` unique per
+/// `organization_id`.
+#[derive(Debug, Clone)]
+pub struct ScopedUnique<'a> {
+    pub field: &'a Field,
+    /// The foreign key naming the parent row.
+    pub key: &'a Field,
+    /// Accessor naming the parent, used in messages (e.g. `organization`).
+    pub parent_name: String,
+}
+
+impl ScopedUnique<'_> {
+    /// Message of the 409 answered when the value is taken, e.g. "a team
+    /// with this slug already exists in this organization".
+    pub fn conflict_message(&self, entity: &Entity) -> String {
+        let words = |name: &str| GenerationContext::snake(name).replace('_', " ");
+        format!(
+            "a {} with this {} already exists in this {}",
+            words(&entity.name),
+            words(&self.field.name),
+            words(&self.parent_name)
+        )
+    }
+}
+
+/// A related-row aggregate resolved against the project graph.
+#[derive(Debug, Clone)]
+pub struct ResolvedAggregate<'a> {
+    pub aggregate: &'a RelatedAggregate,
+    /// The entity whose rows are aggregated.
+    pub related: &'a Entity,
+    /// FK field on `related` pointing back at the listed entity.
+    pub fk_field: String,
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -783,6 +2032,61 @@ impl<'a> EntityInfo<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_entity_order_is_stable() {
+        let mut project = ProjectGraph::new("stable");
+        let mut user = imortal_ir::Entity::new("User");
+        let user_id = user.id;
+        user.add_field(Field::new("email", imortal_core::DataType::String));
+        project.add_entity(user);
+        for name in ["Tag", "Category", "Post"] {
+            let mut entity = imortal_ir::Entity::new(name);
+            if name == "Post" {
+                let mut fk = Field::foreign_key("user_id", "User", "id");
+                if let Some(fk_ref) = fk.foreign_key_ref.as_mut() {
+                    fk_ref.entity_id = user_id;
+                }
+                entity.add_field(fk);
+            }
+            project.add_entity(entity);
+        }
+
+        let names = |project: &ProjectGraph| -> Vec<String> {
+            GenerationContext::from_project(project, GeneratorConfig::default())
+                .entities()
+                .iter()
+                .map(|e| e.name.clone())
+                .collect()
+        };
+        let first = names(&project);
+        let user_pos = first.iter().position(|n| n == "User").unwrap();
+        let post_pos = first.iter().position(|n| n == "Post").unwrap();
+        assert!(user_pos < post_pos, "referenced tables come first");
+
+        // HashMap iteration order differs between map instances
+        for _ in 0..8 {
+            let mut rebuilt = project.clone();
+            rebuilt.entities = project.entities.clone().into_iter().collect();
+            assert_eq!(names(&rebuilt), first);
+        }
+    }
+
+    #[test]
+    fn test_entity_order_puts_junctions_last() {
+        let mut project = ProjectGraph::new("blog");
+        let post = project.add_entity(imortal_ir::Entity::new("Post"));
+        let tag = project.add_entity(imortal_ir::Entity::new("Tag"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(post, tag, "post_tags"))
+            .unwrap();
+        project.promote_junction(rel).unwrap();
+
+        // `PostTag` sorts before `Tag` by name, but holds a key to it
+        let ctx = GenerationContext::from_project(&project, GeneratorConfig::default());
+        let names: Vec<&str> = ctx.entities().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names.last(), Some(&"PostTag"), "{:?}", names);
+    }
+
     #[test]
     fn test_snake() {
         assert_eq!(GenerationContext::snake("BlogPost"), "blog_post");
@@ -834,6 +2138,10 @@ mod tests {
         assert_eq!(GenerationContext::create_dto_name("User"), "CreateUserDto");
         assert_eq!(GenerationContext::update_dto_name("User"), "UpdateUserDto");
         assert_eq!(GenerationContext::response_dto_name("User"), "UserResponse");
+        assert_eq!(
+            GenerationContext::list_item_dto_name("User"),
+            "UserListItem"
+        );
     }
 
     #[test]
@@ -851,6 +2159,10 @@ mod tests {
         );
         assert_eq!(GenerationContext::sql_type(&DataType::Bool, db), "BOOLEAN");
         assert_eq!(GenerationContext::sql_type(&DataType::Json, db), "JSONB");
+        assert_eq!(
+            GenerationContext::sql_type(&DataType::money(), db),
+            "NUMERIC(12,2)"
+        );
     }
 
     #[test]
@@ -940,6 +2252,228 @@ mod tests {
         assert_eq!(info.base_path(), "/api/products");
     }
 
+    #[test]
+    fn test_base_path_follows_route_style() {
+        use imortal_ir::{Entity, PathCase, PathPlurality};
+
+        let mut project = ProjectGraph::new("shop");
+        let entity = Entity::new("LineItem");
+        let eid = entity.id;
+        project.add_entity(entity);
+        project.config.route_style = RouteStyle::default()
+            .with_plurality(PathPlurality::Singular)
+            .with_case(PathCase::Kebab);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(eid).unwrap(), &ctx);
+        assert_eq!(info.base_path(), "/api/line-item");
+        assert_eq!(info.plural_name(), "line_items");
+    }
+
+    #[test]
+    fn test_route_path_follows_api_versioning() {
+        use imortal_ir::{ApiVersioning, Entity};
+
+        let mut project = ProjectGraph::new("shop");
+        let entity = Entity::new("Order");
+        let eid = entity.id;
+        project.add_entity(entity);
+
+        project.config.api_versioning = ApiVersioning::path_prefix("v1");
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(eid).unwrap(), &ctx);
+        assert_eq!(info.base_path(), "/api/v1/orders");
+        assert_eq!(info.route_path(), "/api/v1/orders");
+        assert_eq!(info.api_version(), Some("v1"));
+
+        project.config.api_versioning = ApiVersioning::header("v2");
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(eid).unwrap(), &ctx);
+        assert_eq!(info.base_path(), "/api/orders");
+        assert_eq!(info.route_path(), "/api/v2/orders");
+        assert_eq!(info.api_version(), Some("v2"));
+    }
+
+    #[test]
+    fn test_relation_accessors_disambiguate_derived_names() {
+        use imortal_ir::{Entity, Relationship};
+
+        let mut project = ProjectGraph::new("blog");
+        let user_id = project.add_entity(Entity::new("User"));
+        let post_id = project.add_entity(Entity::new("Post"));
+        for fk in ["author_id", "editor_id"] {
+            project
+                .create_relationship_with_fk(
+                    Relationship::one_to_many(user_id, post_id).with_from_field(fk),
+                )
+                .unwrap();
+        }
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let names = |id| {
+            let info = EntityInfo::new(ctx.entity_by_id(id).unwrap(), &ctx);
+            let mut names: Vec<(String, bool, bool)> = info
+                .relation_accessors()
+                .into_iter()
+                .map(|a| (a.name, a.holds_fk, a.many))
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(
+            names(post_id),
+            vec![
+                ("author".to_string(), true, false),
+                ("editor".to_string(), true, false)
+            ]
+        );
+        assert_eq!(
+            names(user_id),
+            vec![
+                ("posts_by_author".to_string(), false, true),
+                ("posts_by_editor".to_string(), false, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_polymorphic_associations() {
+        use imortal_ir::{Entity, Relationship};
+
+        let mut project = ProjectGraph::new("blog");
+        let comment_id = project.add_entity(Entity::new("Comment"));
+        let post_id = project.add_entity(Entity::new("Post"));
+        let photo_id = project.add_entity(Entity::new("Photo"));
+        for target in [post_id, photo_id] {
+            project
+                .create_relationship_with_fk(Relationship::polymorphic(
+                    comment_id,
+                    target,
+                    "commentable",
+                ))
+                .unwrap();
+        }
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let comment = EntityInfo::new(ctx.entity_by_id(comment_id).unwrap(), &ctx);
+        let associations = comment.polymorphic_associations();
+        assert_eq!(associations.len(), 1);
+        let commentable = &associations[0];
+        assert_eq!(commentable.name, "commentable");
+        assert_eq!(commentable.enum_name(), "Commentable");
+        assert_eq!(commentable.type_name(), "CommentableType");
+        assert_eq!(commentable.discriminator.name, "commentable_type");
+        assert_eq!(commentable.key.name, "commentable_id");
+        let targets: Vec<&str> = commentable
+            .targets
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(targets, ["Photo", "Post"]);
+
+        // No foreign key, so no ordinary relation ends
+        assert!(comment.relation_accessors().is_empty());
+        assert!(comment.outgoing_relationships().is_empty());
+
+        let post = EntityInfo::new(ctx.entity_by_id(post_id).unwrap(), &ctx);
+        let inverses = post.polymorphic_inverses();
+        assert_eq!(inverses.len(), 1);
+        assert_eq!(inverses[0].name, "comments");
+        assert_eq!(inverses[0].association.holder.name, "Comment");
+        assert!(comment.polymorphic_inverses().is_empty());
+    }
+
+    #[test]
+    fn test_scoped_uniques() {
+        use imortal_core::DataType;
+        use imortal_ir::{Entity, Field, Relationship};
+
+        let mut project = ProjectGraph::new("saas");
+        let org_id = project.add_entity(Entity::new("Organization"));
+        let mut team = Entity::new("Team");
+        team.add_field(Field::new("slug", DataType::String));
+        let team_id = project.add_entity(team);
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(org_id, team_id))
+            .unwrap();
+        let team = project.get_entity_mut(team_id).unwrap();
+        let slug = team.get_field_by_name("slug").unwrap().id;
+        team.get_field_mut(slug).unwrap().unique_scope = Some(rel_id);
+        team.config
+            .unique_together
+            .push(vec!["organization_id".into(), "slug".into()]);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let team = ctx.entity_by_id(team_id).unwrap();
+        let info = EntityInfo::new(team, &ctx);
+        let scoped = info.scoped_uniques();
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].key.name, "organization_id");
+        assert_eq!(
+            scoped[0].conflict_message(team),
+            "a team with this slug already exists in this organization"
+        );
+        // Already declared by hand, so not listed twice
+        assert_eq!(info.unique_together(), [["organization_id", "slug"]]);
+    }
+
+    #[test]
+    fn test_delete_effects() {
+        use imortal_core::ReferentialAction;
+        use imortal_ir::{Entity, Relationship};
+
+        let mut project = ProjectGraph::new("blog");
+        let user = Entity::new("User");
+        let uid = user.id;
+        project.add_entity(user);
+        let post = Entity::new("Post");
+        let pid = post.id;
+        project.add_entity(post);
+        let comment = Entity::new("Comment");
+        let cid = comment.id;
+        project.add_entity(comment);
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(uid, pid))
+            .unwrap();
+        project
+            .create_relationship_with_fk(
+                Relationship::one_to_many(uid, cid).on_delete(ReferentialAction::Cascade),
+            )
+            .unwrap();
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(uid).unwrap(), &ctx);
+        let mut effects: Vec<(String, ReferentialAction)> = info
+            .delete_effects()
+            .iter()
+            .map(|e| (e.child.name.clone(), e.action))
+            .collect();
+        effects.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            effects,
+            vec![
+                ("Comment".to_string(), ReferentialAction::Cascade),
+                ("Post".to_string(), ReferentialAction::Restrict),
+            ]
+        );
+
+        // Blockers are only reported when asked for
+        assert!(info.delete_blockers().is_empty());
+        project.config.report_delete_blockers = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(uid).unwrap(), &ctx);
+        let blockers = info.delete_blockers();
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].child.name, "Post");
+
+        // ...and never for soft-deleted entities
+        project.entities.get_mut(&uid).unwrap().config.soft_delete = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entity_by_id(uid).unwrap(), &ctx);
+        assert!(info.delete_blockers().is_empty());
+        assert_eq!(info.delete_effects().len(), 2);
+    }
+
     #[test]
     fn test_migration_filename() {
         let project = ProjectGraph::new("test");
@@ -948,4 +2482,25 @@ mod tests {
         assert!(name.ends_with("_create_users.sql"));
         assert!(name.contains("000001"));
     }
+
+    #[test]
+    fn test_templates_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(ctx.templates_dir(), None);
+
+        // Next to the saved project file, once it exists
+        project.meta.file_path = Some(dir.path().join("test.ieng").display().to_string());
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(ctx.templates_dir(), None);
+        std::fs::create_dir(dir.path().join("templates")).unwrap();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(ctx.templates_dir(), Some(dir.path().join("templates")));
+
+        // The option wins
+        let config = GeneratorConfig::new().with_templates_dir("/elsewhere");
+        let ctx = GenerationContext::from_project(&project, config);
+        assert_eq!(ctx.templates_dir(), Some(PathBuf::from("/elsewhere")));
+    }
 }
@@ -0,0 +1,303 @@
+//! # Deployment File Generator
+//!
+//! Generates the files needed to run the generated API in containers.
+//!
+//! ## Generated Files
+//!
+//! - `Dockerfile` — multi-stage build: compiles the server in a Rust image,
+//!   then copies the binary and migrations into a slim Debian runtime image
+//!   running as an unprivileged user
+//! - `docker-compose.yml` — the server plus a database service matching the
+//!   project's `DatabaseType` (PostgreSQL or MySQL, with a named volume and a
+//!   health check), or a data volume for the SQLite file
+//! - `.dockerignore` — keeps build output, VCS data and local `.env` files out
+//!   of the build context
+//!
+//! All three live at the project root. For fullstack projects that is the
+//! workspace root, and the image builds the backend crate only.
+//!
+//! Each file is rendered from an overridable template with the variables of
+//! [`DeployContext`]; see [`templates`](crate::templates).
+//!
+//! ## Usage
+//!
+//! Docker files are only generated when `ctx.generate_docker()` returns
+//! `true` — set with [`GeneratorConfig::with_docker`] or
+//! `with_option("docker", "true")`.
+//!
+//! ```bash
+//! docker compose up --build
+//! ```
+//!
+//! PostgreSQL and MySQL run the `.sql` files in `migrations/` when their
+//! volume is first created; later migrations must be applied by hand.
+//!
+//! [`GeneratorConfig::with_docker`]: crate::GeneratorConfig::with_docker
+
+use imortal_core::DatabaseType;
+use serde::Serialize;
+
+use crate::context::GenerationContext;
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `Dockerfile`, `docker-compose.yml` and `.dockerignore`.
+///
+/// Returns an empty `Vec` if Docker generation is disabled.
+pub fn generate_docker(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.generate_docker() {
+        return Vec::new();
+    }
+
+    let vars = DeployContext::new(ctx);
+    vec![
+        GeneratedFile::new(
+            "Dockerfile",
+            ctx.render("deploy/Dockerfile.jinja", &vars),
+            FileType::Other,
+        ),
+        GeneratedFile::new(
+            "docker-compose.yml",
+            ctx.render("deploy/docker-compose.yml.jinja", &vars),
+            FileType::Yaml,
+        ),
+        GeneratedFile::new(
+            ".dockerignore",
+            ctx.render("deploy/dockerignore.jinja", &vars),
+            FileType::Other,
+        ),
+    ]
+}
+
+// ============================================================================
+// Template context
+// ============================================================================
+
+/// Variables of the `deploy/` templates (see [`templates`](crate::templates))
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployContext {
+    /// Crate and binary name
+    pub package: String,
+    /// Port the server listens on
+    pub port: u16,
+    /// Directory of the backend crate relative to the project root, ending
+    /// in `/` (`backend/` for fullstack projects, otherwise empty)
+    pub backend_dir: &'static str,
+    /// Whether the project is fullstack
+    pub fullstack: bool,
+    /// Whether migrations are generated, and copied into the image
+    pub migrations: bool,
+    /// Whether authentication is enabled
+    pub auth: bool,
+    /// `postgresql`, `mysql` or `sqlite`
+    pub database: DatabaseType,
+    /// URL the server connects with from inside the compose network
+    pub database_url: String,
+    /// Database user
+    pub db_user: String,
+    /// Database password, `change-me` when the project sets none
+    pub db_password: String,
+    /// Database name
+    pub db_name: String,
+}
+
+impl DeployContext {
+    /// The variables for `ctx`.
+    pub fn new(ctx: &GenerationContext) -> Self {
+        let db = &ctx.config.db_config;
+        let database = ctx.database();
+        let password = if db.password.is_empty() {
+            "change-me"
+        } else {
+            db.password.as_str()
+        };
+        let database_url = match database {
+            DatabaseType::SQLite => format!("sqlite:///data/{}.db?mode=rwc", db.database_name),
+            _ => {
+                let mut service = db.clone();
+                service.host = "db".to_string();
+                service.port = default_port(database);
+                service.password = password.to_string();
+                service.connection_url(database)
+            }
+        };
+
+        Self {
+            package: ctx.package_name().to_string(),
+            port: ctx.server_port(),
+            backend_dir: if ctx.is_fullstack() { "backend/" } else { "" },
+            fullstack: ctx.is_fullstack(),
+            migrations: ctx.generate_migrations(),
+            auth: ctx.auth_enabled(),
+            database,
+            database_url,
+            db_user: db.username.clone(),
+            db_password: password.to_string(),
+            db_name: db.database_name.clone(),
+        }
+    }
+}
+
+/// Port the database listens on inside its container.
+fn default_port(database: DatabaseType) -> u16 {
+    match database {
+        DatabaseType::PostgreSQL => 5432,
+        DatabaseType::MySQL => 3306,
+        DatabaseType::SQLite => 0,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_core::DataType;
+    use imortal_ir::{DatabaseConfig, Entity, Field, ProjectGraph, ProjectType};
+
+    fn project(database: DatabaseType) -> ProjectGraph {
+        let mut project = ProjectGraph::new("shop_api");
+        project.config.package_name = "shop_api".to_string();
+        project.config.database = database;
+        project.config.db_config = DatabaseConfig::for_database(database);
+
+        let mut product = Entity::new("Product");
+        product.fields.push(Field::new("name", DataType::String));
+        project.add_entity(product);
+
+        project
+    }
+
+    fn docker_files(project: &ProjectGraph) -> Vec<GeneratedFile> {
+        let ctx = GenerationContext::from_project(project, GeneratorConfig::new().with_docker());
+        generate_docker(&ctx)
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn test_docker_disabled_by_default() {
+        let project = project(DatabaseType::PostgreSQL);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_docker(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_docker_enabled_by_option() {
+        let project = project(DatabaseType::PostgreSQL);
+        let config = GeneratorConfig::new().with_option("docker", "true");
+        let ctx = GenerationContext::from_project(&project, config);
+        assert_eq!(generate_docker(&ctx).len(), 3);
+    }
+
+    #[test]
+    fn test_docker_without_migrations() {
+        let project = project(DatabaseType::PostgreSQL);
+        let config = GeneratorConfig::new().with_docker().without_migrations();
+        let files = generate_docker(&GenerationContext::from_project(&project, config));
+
+        assert!(!file(&files, "Dockerfile").contains("migrations"));
+        assert!(!file(&files, "docker-compose.yml").contains("docker-entrypoint-initdb.d"));
+    }
+
+    #[test]
+    fn test_dockerfile_is_multi_stage() {
+        let files = docker_files(&project(DatabaseType::PostgreSQL));
+        let dockerfile = file(&files, "Dockerfile");
+
+        assert!(dockerfile.contains("AS builder"));
+        assert!(dockerfile.contains("cargo build --release -p shop_api"));
+        assert!(dockerfile.contains("FROM debian:bookworm-slim"));
+        assert!(dockerfile.contains("COPY --from=builder /usr/local/bin/shop_api"));
+        assert!(dockerfile.contains("COPY migrations ./migrations"));
+        assert!(dockerfile.contains("COPY locales ./locales"));
+        assert!(dockerfile.contains("USER app"));
+        assert!(dockerfile.contains("EXPOSE 8080"));
+        assert!(!dockerfile.contains("VOLUME /data"));
+    }
+
+    #[test]
+    fn test_compose_postgres() {
+        let files = docker_files(&project(DatabaseType::PostgreSQL));
+        let compose = file(&files, "docker-compose.yml");
+
+        assert!(compose.contains("image: postgres:16-alpine"));
+        assert!(compose.contains("DATABASE_URL: \"postgres://postgres:change-me@db:5432/my_app\""));
+        assert!(compose.contains("POSTGRES_PASSWORD: \"change-me\""));
+        assert!(compose.contains("- db-data:/var/lib/postgresql/data"));
+        assert!(compose.contains("- ./migrations:/docker-entrypoint-initdb.d:ro"));
+        assert!(compose.contains("condition: service_healthy"));
+        assert!(compose.contains("JWT_SECRET"));
+    }
+
+    #[test]
+    fn test_compose_mysql() {
+        let mut project = project(DatabaseType::MySQL);
+        project.config.db_config.username = "shop".to_string();
+        project.config.db_config.password = "s3cret".to_string();
+        let files = docker_files(&project);
+        let compose = file(&files, "docker-compose.yml");
+
+        assert!(compose.contains("image: mysql:8.4"));
+        assert!(compose.contains("DATABASE_URL: \"mysql://shop:s3cret@db:3306/my_app\""));
+        assert!(compose.contains("MYSQL_USER: \"shop\""));
+        assert!(compose.contains("MYSQL_PASSWORD: \"s3cret\""));
+        assert!(compose.contains("- db-data:/var/lib/mysql"));
+    }
+
+    #[test]
+    fn test_compose_mysql_root_user() {
+        let files = docker_files(&project(DatabaseType::MySQL));
+        let compose = file(&files, "docker-compose.yml");
+
+        assert!(compose.contains("MYSQL_ROOT_PASSWORD: \"change-me\""));
+        assert!(!compose.contains("MYSQL_USER"));
+    }
+
+    #[test]
+    fn test_compose_sqlite_uses_volume() {
+        let files = docker_files(&project(DatabaseType::SQLite));
+        let compose = file(&files, "docker-compose.yml");
+
+        assert!(compose.contains("DATABASE_URL: \"sqlite:///data/my_app.db?mode=rwc\""));
+        assert!(compose.contains("- app-data:/data"));
+        assert!(!compose.contains("  db:"));
+        assert!(file(&files, "Dockerfile").contains("VOLUME /data"));
+    }
+
+    #[test]
+    fn test_fullstack_builds_backend_crate() {
+        let mut project = project(DatabaseType::PostgreSQL);
+        project.config.project_type = ProjectType::Fullstack;
+        let files = docker_files(&project);
+
+        assert!(file(&files, "Dockerfile").contains("COPY backend/migrations ./migrations"));
+        assert!(
+            file(&files, "docker-compose.yml")
+                .contains("- ./backend/migrations:/docker-entrypoint-initdb.d:ro")
+        );
+        assert!(file(&files, ".dockerignore").contains("frontend/dist/"));
+    }
+
+    #[test]
+    fn test_dockerignore_excludes_build_output_and_env() {
+        let files = docker_files(&project(DatabaseType::PostgreSQL));
+        let ignore = file(&files, ".dockerignore");
+
+        assert!(ignore.contains("target/"));
+        assert!(ignore.contains(".git/"));
+        assert!(ignore.contains(".env"));
+    }
+}
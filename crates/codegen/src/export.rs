@@ -0,0 +1,760 @@
+//! # Schema Export
+//!
+//! Converts a project's database schema to text formats that documentation
+//! tooling reads, so a schema can be reviewed without the designer:
+//!
+//! - **DBML** — for dbdiagram.io and dbdocs
+//! - **Mermaid** — an `erDiagram`, rendered by GitHub, GitLab and most
+//!   documentation sites
+//! - **PlantUML** — an entity diagram in information-engineering notation
+//!
+//! The tables are the ones the migrations create ([`TableDef`]): the same
+//! table and column names, SQL types for the project's database, and the
+//! implied timestamp and soft-delete columns. Relationships follow the
+//! foreign keys; many-to-many relationships whose junction has not been
+//! promoted to an entity link the two tables directly. Polymorphic
+//! relationships have no foreign key and are left out.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use imortal_core::EngineError;
+use imortal_ir::{DatabaseType, ProjectGraph};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::migrations::sql::{TableDef, database_display_name, referential_action_sql};
+
+// ============================================================================
+// Format
+// ============================================================================
+
+/// Text format a schema can be exported as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaFormat {
+    Dbml,
+    Mermaid,
+    PlantUml,
+}
+
+impl SchemaFormat {
+    /// All formats, in menu order
+    pub fn all() -> &'static [SchemaFormat] {
+        &[
+            SchemaFormat::Dbml,
+            SchemaFormat::Mermaid,
+            SchemaFormat::PlantUml,
+        ]
+    }
+
+    /// Key used on the command line (`dbml`, `mermaid`, `plantuml`)
+    pub fn key(self) -> &'static str {
+        match self {
+            SchemaFormat::Dbml => "dbml",
+            SchemaFormat::Mermaid => "mermaid",
+            SchemaFormat::PlantUml => "plantuml",
+        }
+    }
+
+    /// Get display name
+    pub fn display_name(self) -> &'static str {
+        match self {
+            SchemaFormat::Dbml => "DBML",
+            SchemaFormat::Mermaid => "Mermaid",
+            SchemaFormat::PlantUml => "PlantUML",
+        }
+    }
+
+    /// File extension, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            SchemaFormat::Dbml => "dbml",
+            SchemaFormat::Mermaid => "mmd",
+            SchemaFormat::PlantUml => "puml",
+        }
+    }
+}
+
+impl FromStr for SchemaFormat {
+    type Err = EngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', '_'], "").as_str() {
+            "dbml" => Ok(SchemaFormat::Dbml),
+            "mermaid" => Ok(SchemaFormat::Mermaid),
+            "plantuml" => Ok(SchemaFormat::PlantUml),
+            _ => Err(EngineError::validation(format!(
+                "Unknown schema format '{}' (expected dbml, mermaid or plantuml)",
+                s
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Export the project's schema in the given format
+pub fn export_schema(project: &ProjectGraph, format: SchemaFormat) -> String {
+    let ctx = GenerationContext::from_project_default(project);
+    let schema = Schema::of(&ctx);
+    match format {
+        SchemaFormat::Dbml => to_dbml(&schema, &ctx),
+        SchemaFormat::Mermaid => to_mermaid(&schema),
+        SchemaFormat::PlantUml => to_plantuml(&schema, &ctx),
+    }
+}
+
+// ============================================================================
+// Schema
+// ============================================================================
+
+/// The tables and links every format is rendered from
+struct Schema {
+    tables: Vec<Table>,
+    links: Vec<Link>,
+}
+
+struct Table {
+    /// Table name, qualified by its schema when it has one (`blog.posts`)
+    name: String,
+    note: Option<String>,
+    columns: Vec<Column>,
+}
+
+struct Column {
+    name: String,
+    sql_type: String,
+    primary_key: bool,
+    foreign_key: bool,
+    unique: bool,
+    not_null: bool,
+    /// Default value as SQL, without the `DEFAULT` keyword
+    default: Option<String>,
+    note: Option<String>,
+}
+
+/// A relationship between two tables' columns
+struct Link {
+    /// The referencing side (the foreign key, for many-to-one)
+    from_table: String,
+    from_column: String,
+    to_table: String,
+    to_column: String,
+    /// The foreign key column, or the relationship's name for many-to-many
+    label: String,
+    kind: LinkKind,
+    /// Whether a row on the referencing side needs a referenced row
+    required: bool,
+    on_delete: Option<&'static str>,
+    on_update: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinkKind {
+    ManyToOne,
+    OneToOne,
+    ManyToMany,
+}
+
+impl Schema {
+    fn of(ctx: &GenerationContext) -> Self {
+        let defs: Vec<(EntityInfo, TableDef)> = ctx
+            .entities()
+            .iter()
+            .map(|e| {
+                let info = EntityInfo::new(e, ctx);
+                let def = TableDef::of(&info, ctx);
+                (info, def)
+            })
+            .collect();
+        let table_name = |def: &TableDef| match &def.schema {
+            Some(schema) => format!("{}.{}", schema, def.name),
+            None => def.name.clone(),
+        };
+
+        let mut tables = Vec::new();
+        let mut links = Vec::new();
+        for (info, def) in &defs {
+            let entity = info.entity;
+            let name = table_name(def);
+
+            let mut columns = Vec::new();
+            for column in &def.columns {
+                let field = column
+                    .field_id
+                    .and_then(|id| entity.fields.iter().find(|f| f.id == id));
+                let foreign_key = field.is_some_and(|f| f.is_foreign_key);
+                columns.push(Column {
+                    name: column.name.clone(),
+                    sql_type: column.sql_type.clone(),
+                    primary_key: column.primary_key,
+                    foreign_key,
+                    unique: column.unique,
+                    not_null: column.not_null || column.primary_key,
+                    default: column
+                        .default
+                        .as_deref()
+                        .and_then(|d| d.strip_prefix("DEFAULT "))
+                        .map(str::to_string),
+                    note: field.and_then(|f| f.description.clone()),
+                });
+
+                let Some(fk) = field
+                    .filter(|f| f.is_foreign_key)
+                    .and_then(|f| f.foreign_key_ref.as_ref())
+                else {
+                    continue;
+                };
+                let target = defs.iter().find(|(i, _)| i.entity.id == fk.entity_id);
+                let (to_table, to_column) = match target {
+                    Some((target, target_def)) => {
+                        let field = if fk.field_name.is_empty() {
+                            target.pk()
+                        } else {
+                            target
+                                .entity
+                                .fields
+                                .iter()
+                                .find(|f| f.name == fk.field_name)
+                        };
+                        let to_column = field
+                            .and_then(|f| {
+                                target_def.columns.iter().find(|c| c.field_id == Some(f.id))
+                            })
+                            .map_or_else(|| fk.field_name.clone(), |c| c.name.clone());
+                        (table_name(target_def), to_column)
+                    }
+                    None => (
+                        GenerationContext::table_name(&fk.entity_name),
+                        fk.field_name.clone(),
+                    ),
+                };
+                links.push(Link {
+                    from_table: name.clone(),
+                    from_column: column.name.clone(),
+                    to_table,
+                    to_column: if to_column.is_empty() {
+                        "id".to_string()
+                    } else {
+                        to_column
+                    },
+                    label: column.name.clone(),
+                    kind: if column.unique {
+                        LinkKind::OneToOne
+                    } else {
+                        LinkKind::ManyToOne
+                    },
+                    required: column.not_null,
+                    on_delete: Some(referential_action_sql(&fk.on_delete)),
+                    on_update: Some(referential_action_sql(&fk.on_update)),
+                });
+            }
+
+            tables.push(Table {
+                name,
+                note: entity.description.clone(),
+                columns,
+            });
+        }
+
+        // Implicit junctions: link the two primary keys
+        let pk_of = |id| {
+            defs.iter()
+                .find(|(i, _)| i.entity.id == id)
+                .map(|(_, def)| {
+                    let pk = def
+                        .columns
+                        .iter()
+                        .find(|c| c.primary_key)
+                        .map_or_else(|| "id".to_string(), |c| c.name.clone());
+                    (table_name(def), pk)
+                })
+        };
+        for rel in ctx.relationships() {
+            if !rel.relation_type.requires_junction_table() || rel.junction_entity_id.is_some() {
+                continue;
+            }
+            let (Some((from_table, from_column)), Some((to_table, to_column))) =
+                (pk_of(rel.from_entity_id), pk_of(rel.to_entity_id))
+            else {
+                continue;
+            };
+            links.push(Link {
+                from_table,
+                from_column,
+                to_table,
+                to_column,
+                label: if rel.name.is_empty() {
+                    "many-to-many".to_string()
+                } else {
+                    rel.name.clone()
+                },
+                kind: LinkKind::ManyToMany,
+                required: false,
+                on_delete: None,
+                on_update: None,
+            });
+        }
+
+        Self { tables, links }
+    }
+}
+
+// ============================================================================
+// DBML
+// ============================================================================
+
+fn to_dbml(schema: &Schema, ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(4096);
+
+    let _ = writeln!(out, "Project {} {{", dbml_name(&ctx.meta.name));
+    let _ = writeln!(
+        out,
+        "  database_type: '{}'",
+        database_display_name(ctx.database())
+    );
+    if let Some(description) = &ctx.meta.description {
+        let _ = writeln!(out, "  Note: {}", dbml_string(description));
+    }
+    out.push_str("}\n");
+
+    // Column types name PostgreSQL's enum types; other databases inline them
+    if ctx.database() == DatabaseType::PostgreSQL {
+        for def in ctx.enums() {
+            let _ = writeln!(
+                out,
+                "\nEnum {} {{",
+                dbml_name(&GenerationContext::enum_type_name(&def.name))
+            );
+            for variant in &def.variants {
+                let _ = writeln!(out, "  {}", dbml_name(variant));
+            }
+            out.push_str("}\n");
+        }
+    }
+
+    for table in &schema.tables {
+        let _ = writeln!(out, "\nTable {} {{", dbml_table(&table.name));
+        for column in &table.columns {
+            let mut settings = Vec::new();
+            if column.primary_key {
+                settings.push("pk".to_string());
+            }
+            if column.unique {
+                settings.push("unique".to_string());
+            }
+            if column.not_null && !column.primary_key {
+                settings.push("not null".to_string());
+            }
+            if let Some(default) = &column.default {
+                settings.push(format!("default: {}", dbml_default(default)));
+            }
+            if let Some(note) = &column.note {
+                settings.push(format!("note: {}", dbml_string(note)));
+            }
+            let settings = if settings.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", settings.join(", "))
+            };
+            let _ = writeln!(
+                out,
+                "  {} {}{}",
+                dbml_name(&column.name),
+                dbml_type(&column.sql_type),
+                settings
+            );
+        }
+        if let Some(note) = &table.note {
+            let _ = writeln!(out, "\n  Note: {}", dbml_string(note));
+        }
+        out.push_str("}\n");
+    }
+
+    if !schema.links.is_empty() {
+        out.push('\n');
+    }
+    for link in &schema.links {
+        let op = match link.kind {
+            LinkKind::ManyToOne => ">",
+            LinkKind::OneToOne => "-",
+            LinkKind::ManyToMany => "<>",
+        };
+        let actions: Vec<String> = [("delete", link.on_delete), ("update", link.on_update)]
+            .into_iter()
+            .filter_map(|(event, action)| {
+                action.map(|a| format!("{}: {}", event, a.to_lowercase()))
+            })
+            .collect();
+        let actions = if actions.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", actions.join(", "))
+        };
+        let _ = writeln!(
+            out,
+            "Ref: {}.{} {} {}.{}{}",
+            dbml_table(&link.from_table),
+            dbml_name(&link.from_column),
+            op,
+            dbml_table(&link.to_table),
+            dbml_name(&link.to_column),
+            actions
+        );
+    }
+
+    out
+}
+
+/// An identifier, double-quoted unless it is a plain word
+fn dbml_name(name: &str) -> String {
+    if is_word(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\\\""))
+    }
+}
+
+/// A table name; `schema.table` stays DBML's schema syntax
+fn dbml_table(name: &str) -> String {
+    match name.split_once('.') {
+        Some((schema, table)) => format!("{}.{}", dbml_name(schema), dbml_name(table)),
+        None => dbml_name(name),
+    }
+}
+
+/// A column type, double-quoted when it has spaces (`TIMESTAMP WITH TIME ZONE`)
+fn dbml_type(sql_type: &str) -> String {
+    if sql_type
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_()[],".contains(c))
+    {
+        sql_type.to_string()
+    } else {
+        format!("\"{}\"", sql_type.replace('"', "\\\""))
+    }
+}
+
+/// A column default: literals as they are, anything else as an expression
+fn dbml_default(sql: &str) -> String {
+    if sql.starts_with('\'') || sql.parse::<f64>().is_ok() {
+        sql.to_string()
+    } else if matches!(sql, "TRUE" | "FALSE" | "NULL") {
+        sql.to_lowercase()
+    } else {
+        format!("`{}`", sql)
+    }
+}
+
+/// A single-quoted string
+fn dbml_string(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+// ============================================================================
+// Mermaid
+// ============================================================================
+
+fn to_mermaid(schema: &Schema) -> String {
+    let mut out = String::with_capacity(4096);
+    out.push_str("erDiagram\n");
+
+    for table in &schema.tables {
+        let _ = writeln!(out, "    {} {{", mermaid_name(&table.name));
+        for column in &table.columns {
+            let keys: Vec<&str> = [
+                (column.primary_key, "PK"),
+                (column.foreign_key, "FK"),
+                (column.unique, "UK"),
+            ]
+            .into_iter()
+            .filter_map(|(set, key)| set.then_some(key))
+            .collect();
+            let mut line = format!(
+                "        {} {}",
+                mermaid_type(&column.sql_type),
+                mermaid_word(&column.name)
+            );
+            if !keys.is_empty() {
+                let _ = write!(line, " {}", keys.join(", "));
+            }
+            if let Some(note) = &column.note {
+                let _ = write!(line, " \"{}\"", note.replace('"', "'"));
+            }
+            let _ = writeln!(out, "{}", line);
+        }
+        out.push_str("    }\n");
+    }
+
+    for link in &schema.links {
+        // Written from the referenced table's side: `users ||--o{ posts`
+        let (left, right) = match link.kind {
+            LinkKind::ManyToOne => (if link.required { "||" } else { "|o" }, "o{"),
+            LinkKind::OneToOne => (if link.required { "||" } else { "|o" }, "o|"),
+            LinkKind::ManyToMany => ("}o", "o{"),
+        };
+        let _ = writeln!(
+            out,
+            "    {} {}--{} {} : \"{}\"",
+            mermaid_name(&link.to_table),
+            left,
+            right,
+            mermaid_name(&link.from_table),
+            link.label.replace('"', "'")
+        );
+    }
+
+    out
+}
+
+/// An entity name, double-quoted unless it is a plain word
+fn mermaid_name(name: &str) -> String {
+    if is_word(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "'"))
+    }
+}
+
+/// An attribute name, which Mermaid cannot quote
+fn mermaid_word(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// An attribute type: spaces become underscores and anything after a
+/// character Mermaid rejects is dropped (`ENUM('a', 'b')` → `ENUM`)
+fn mermaid_type(sql_type: &str) -> String {
+    let sql_type = sql_type.replace(' ', "_");
+    match sql_type.find(|c: char| !(c.is_ascii_alphanumeric() || "_-()[]".contains(c))) {
+        Some(end) => sql_type[..end].trim_end_matches('(').to_string(),
+        None => sql_type,
+    }
+}
+
+// ============================================================================
+// PlantUML
+// ============================================================================
+
+fn to_plantuml(schema: &Schema, ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(4096);
+    out.push_str("@startuml\n");
+    let _ = writeln!(out, "title {}", ctx.meta.name);
+    out.push_str("hide circle\n");
+    out.push_str("skinparam linetype ortho\n");
+
+    for table in &schema.tables {
+        let _ = writeln!(
+            out,
+            "\nentity \"{}\" as {} {{",
+            table.name,
+            plantuml_alias(&table.name)
+        );
+        let (keys, rest): (Vec<&Column>, Vec<&Column>) =
+            table.columns.iter().partition(|c| c.primary_key);
+        for column in &keys {
+            out.push_str(&plantuml_column(column));
+        }
+        if !keys.is_empty() {
+            out.push_str("  --\n");
+        }
+        for column in &rest {
+            out.push_str(&plantuml_column(column));
+        }
+        out.push_str("}\n");
+        if let Some(note) = &table.note {
+            let _ = writeln!(
+                out,
+                "note bottom of {}\n  {}\nend note",
+                plantuml_alias(&table.name),
+                note
+            );
+        }
+    }
+
+    if !schema.links.is_empty() {
+        out.push('\n');
+    }
+    for link in &schema.links {
+        let (left, right) = match link.kind {
+            LinkKind::ManyToOne => (if link.required { "||" } else { "|o" }, "o{"),
+            LinkKind::OneToOne => (if link.required { "||" } else { "|o" }, "o|"),
+            LinkKind::ManyToMany => ("}o", "o{"),
+        };
+        let _ = writeln!(
+            out,
+            "{} {}--{} {} : {}",
+            plantuml_alias(&link.to_table),
+            left,
+            right,
+            plantuml_alias(&link.from_table),
+            link.label
+        );
+    }
+
+    out.push_str("@enduml\n");
+    out
+}
+
+/// One attribute line; `*` marks mandatory columns
+fn plantuml_column(column: &Column) -> String {
+    let mut stereotypes = Vec::new();
+    if column.primary_key {
+        stereotypes.push("<<PK>>");
+    }
+    if column.foreign_key {
+        stereotypes.push("<<FK>>");
+    }
+    if column.unique {
+        stereotypes.push("<<unique>>");
+    }
+    let mut line = format!(
+        "  {}{} : {}",
+        if column.not_null { "* " } else { "" },
+        column.name,
+        column.sql_type
+    );
+    if !stereotypes.is_empty() {
+        let _ = write!(line, " {}", stereotypes.join(" "));
+    }
+    line.push('\n');
+    line
+}
+
+/// Alias the diagram refers to a table by
+fn plantuml_alias(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Letters, digits and underscores, not starting with a digit
+fn is_word(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::{DataType, ReferentialAction, RelationType};
+    use imortal_ir::{Entity, Field, ForeignKeyRef, Relationship};
+
+    fn make_blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+
+        let mut user = Entity::with_timestamps("User");
+        user.description = Some("A registered author".to_string());
+        let mut email = Field::new("email", DataType::String);
+        email.unique = true;
+        email.required = true;
+        user.add_field(email);
+        let user_id = project.add_entity(user);
+
+        let mut post = Entity::new("Post");
+        post.add_field(Field::new("title", DataType::String));
+        let mut author = Field::new("author_id", DataType::Uuid);
+        author.is_foreign_key = true;
+        author.required = true;
+        author.foreign_key_ref = Some(ForeignKeyRef {
+            entity_id: user_id,
+            entity_name: "User".to_string(),
+            field_name: "id".to_string(),
+            on_delete: ReferentialAction::Cascade,
+            on_update: ReferentialAction::NoAction,
+        });
+        post.add_field(author);
+        project.add_entity(post);
+
+        project
+    }
+
+    #[test]
+    fn test_schema_format_from_str() {
+        assert_eq!("dbml".parse::<SchemaFormat>().unwrap(), SchemaFormat::Dbml);
+        assert_eq!(
+            "PlantUML".parse::<SchemaFormat>().unwrap(),
+            SchemaFormat::PlantUml
+        );
+        assert!("graphviz".parse::<SchemaFormat>().is_err());
+        for format in SchemaFormat::all() {
+            assert_eq!(format.key().parse::<SchemaFormat>().unwrap(), *format);
+        }
+    }
+
+    #[test]
+    fn test_export_dbml() {
+        let dbml = export_schema(&make_blog(), SchemaFormat::Dbml);
+
+        assert!(dbml.starts_with("Project blog {\n  database_type: 'PostgreSQL'\n}"));
+        assert!(dbml.contains("Table users {\n  id UUID [pk]\n"));
+        assert!(dbml.contains("  email VARCHAR(255) [unique, not null]\n"));
+        assert!(dbml.contains(
+            "  created_at \"TIMESTAMP WITH TIME ZONE\" [not null, default: `CURRENT_TIMESTAMP`]\n"
+        ));
+        assert!(dbml.contains("  Note: 'A registered author'\n"));
+        assert!(
+            dbml.contains("Ref: posts.author_id > users.id [delete: cascade, update: no action]\n")
+        );
+        assert!(dbml.contains("Table posts {\n  id UUID [pk]\n"));
+    }
+
+    #[test]
+    fn test_export_mermaid() {
+        let mermaid = export_schema(&make_blog(), SchemaFormat::Mermaid);
+
+        assert!(mermaid.starts_with("erDiagram\n    users {\n        UUID id PK\n"));
+        assert!(mermaid.contains("        VARCHAR(255) email UK\n"));
+        assert!(mermaid.contains("        TIMESTAMP_WITH_TIME_ZONE created_at\n"));
+        assert!(mermaid.contains("        UUID author_id FK\n"));
+        assert!(mermaid.contains("    users ||--o{ posts : \"author_id\"\n"));
+        assert_eq!(mermaid_type("ENUM('draft', 'published')"), "ENUM");
+        assert_eq!(mermaid_type("UUID[]"), "UUID[]");
+    }
+
+    #[test]
+    fn test_export_plantuml() {
+        let plantuml = export_schema(&make_blog(), SchemaFormat::PlantUml);
+
+        assert!(plantuml.starts_with("@startuml\ntitle blog\n"));
+        assert!(plantuml.contains("entity \"users\" as users {\n  * id : UUID <<PK>>\n  --\n"));
+        assert!(plantuml.contains("  * author_id : UUID <<FK>>\n"));
+        assert!(plantuml.contains("users ||--o{ posts : author_id\n"));
+        assert!(plantuml.trim_end().ends_with("@enduml"));
+    }
+
+    #[test]
+    fn test_export_many_to_many() {
+        let mut project = ProjectGraph::new("shop");
+        let product = project.add_entity(Entity::new("Product"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        project.add_relationship(
+            Relationship::new(
+                product,
+                tag,
+                RelationType::ManyToMany {
+                    junction_table: "product_tags".to_string(),
+                },
+            )
+            .with_name("ProductTags"),
+        );
+
+        let dbml = export_schema(&project, SchemaFormat::Dbml);
+        assert!(dbml.contains("Ref: products.id <> tags.id\n"));
+        let mermaid = export_schema(&project, SchemaFormat::Mermaid);
+        assert!(mermaid.contains("    tags }o--o{ products : \"ProductTags\"\n"));
+    }
+}
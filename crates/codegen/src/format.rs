@@ -0,0 +1,337 @@
+//! # Formatting
+//!
+//! Runs the generated Rust files through `rustfmt` before they are returned,
+//! so the output matches what `cargo fmt` would leave behind.
+//!
+//! Files are written to a scratch directory under their output paths, with an
+//! empty `rustfmt.toml` at its root so no configuration from the surrounding
+//! filesystem applies. Each source tree (`src/`, `tests/`, `backend/src/`, …)
+//! is formatted by a single `rustfmt` invocation, and the trees are formatted
+//! side by side. Keeping a tree in one invocation matters: `rustfmt` follows
+//! `mod` declarations into child files, which must therefore exist and must
+//! not be rewritten by two processes at once.
+//!
+//! If an invocation fails, its files are retried one at a time over stdin to
+//! find the ones `rustfmt` cannot parse. Those keep their generated content
+//! and are listed in [`FormatReport::failed`]; every other file is formatted.
+//!
+//! The `RUSTFMT` environment variable selects the binary, as with `cargo fmt`.
+//! When it cannot be started, nothing is formatted and
+//! [`FormatReport::rustfmt_missing`] is set.
+
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{FileType, GeneratedFile};
+
+/// Directories that start a tree of modules `rustfmt` may follow.
+const TREE_ROOTS: &[&str] = &["src", "tests", "benches", "examples"];
+
+/// Counter keeping scratch directories of concurrent runs apart.
+static SCRATCH_ID: AtomicUsize = AtomicUsize::new(0);
+
+// ============================================================================
+// FormatReport
+// ============================================================================
+
+/// Result of formatting the generated Rust files
+#[derive(Debug, Clone, Default)]
+pub struct FormatReport {
+    /// Number of Rust files `rustfmt` accepted
+    pub formatted: usize,
+    /// Paths of the files `rustfmt` could not parse, left as generated
+    pub failed: Vec<PathBuf>,
+    /// `rustfmt` could not be started; no file was formatted
+    pub rustfmt_missing: bool,
+    /// Time spent formatting
+    pub duration: Duration,
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Format every [`FileType::Rust`] file in place with `rustfmt`.
+///
+/// `edition` is passed to `rustfmt --edition` and should be the edition of
+/// the generated crate. Other file types are left untouched.
+pub fn format_rust_files(files: &mut [GeneratedFile], edition: &str) -> FormatReport {
+    let started = Instant::now();
+    let mut report = FormatReport::default();
+
+    let mut trees: BTreeMap<PathBuf, Vec<&mut GeneratedFile>> = BTreeMap::new();
+    for file in files.iter_mut().filter(|f| f.file_type == FileType::Rust) {
+        trees.entry(source_tree(&file.path)).or_default().push(file);
+    }
+    if trees.is_empty() {
+        return report;
+    }
+
+    let scratch = scratch_dir();
+    let outcomes: Vec<TreeOutcome> = std::thread::scope(|scope| {
+        let handles: Vec<_> = trees
+            .into_values()
+            .map(|tree| scope.spawn(|| format_tree(&scratch, tree, edition)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+    let _ = std::fs::remove_dir_all(&scratch);
+
+    for outcome in outcomes {
+        report.formatted += outcome.formatted;
+        report.failed.extend(outcome.failed);
+        report.rustfmt_missing |= outcome.rustfmt_missing;
+    }
+    if report.rustfmt_missing {
+        report.formatted = 0;
+        report.failed.clear();
+    }
+    report.failed.sort();
+    report.duration = started.elapsed();
+    report
+}
+
+/// The tree of modules a file belongs to: its path up to the first
+/// `src`/`tests`/`benches`/`examples` directory, or its parent directory.
+fn source_tree(path: &Path) -> PathBuf {
+    let mut tree = PathBuf::new();
+    let mut components = path.components().peekable();
+    while let Some(component) = components.next() {
+        if components.peek().is_none() {
+            // The file name itself: not under a tree root
+            return path.parent().map(Path::to_path_buf).unwrap_or_default();
+        }
+        tree.push(component);
+        if TREE_ROOTS.iter().any(|root| component.as_os_str() == *root) {
+            return tree;
+        }
+    }
+    tree
+}
+
+// ============================================================================
+// Running rustfmt
+// ============================================================================
+
+#[derive(Default)]
+struct TreeOutcome {
+    formatted: usize,
+    failed: Vec<PathBuf>,
+    rustfmt_missing: bool,
+}
+
+fn rustfmt_command(edition: &str) -> Command {
+    let program = std::env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into());
+    let mut command = Command::new(program);
+    command.arg("--edition").arg(edition);
+    command
+}
+
+fn scratch_dir() -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "imortal-fmt-{}-{}",
+        std::process::id(),
+        SCRATCH_ID.fetch_add(1, Ordering::Relaxed),
+    ))
+}
+
+fn write_scratch(scratch: &Path, files: &[&mut GeneratedFile]) -> std::io::Result<()> {
+    std::fs::create_dir_all(scratch)?;
+    // An empty config stops rustfmt searching the directories above
+    std::fs::write(scratch.join("rustfmt.toml"), "")?;
+    for file in files {
+        let path = scratch.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, &file.content)?;
+    }
+    Ok(())
+}
+
+/// Format one source tree in a single `rustfmt` invocation, falling back to
+/// one file at a time when it fails.
+fn format_tree(scratch: &Path, mut files: Vec<&mut GeneratedFile>, edition: &str) -> TreeOutcome {
+    if let Err(e) = write_scratch(scratch, &files) {
+        tracing::warn!("could not prepare files for rustfmt: {}", e);
+        return format_each(scratch, files, edition);
+    }
+
+    let status = rustfmt_command(edition)
+        .args(files.iter().map(|f| &f.path))
+        .current_dir(scratch)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(_) => return format_each(scratch, files, edition),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return TreeOutcome {
+                rustfmt_missing: true,
+                ..TreeOutcome::default()
+            };
+        }
+        Err(e) => {
+            tracing::warn!("could not run rustfmt: {}", e);
+            return format_each(scratch, files, edition);
+        }
+    }
+
+    let mut outcome = TreeOutcome::default();
+    for file in &mut files {
+        match std::fs::read_to_string(scratch.join(&file.path)) {
+            Ok(content) => {
+                file.content = content;
+                outcome.formatted += 1;
+            }
+            Err(_) => outcome.failed.push(file.path.clone()),
+        }
+    }
+    outcome
+}
+
+/// Format files one at a time over stdin, where `rustfmt` does not follow
+/// `mod` declarations, so one broken file cannot fail another.
+fn format_each(scratch: &Path, files: Vec<&mut GeneratedFile>, edition: &str) -> TreeOutcome {
+    let mut outcome = TreeOutcome::default();
+    for file in files {
+        match format_stdin(scratch, &file.content, edition) {
+            Ok(Some(content)) => {
+                file.content = content;
+                outcome.formatted += 1;
+            }
+            Ok(None) => outcome.failed.push(file.path.clone()),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                outcome.rustfmt_missing = true;
+                break;
+            }
+            Err(_) => outcome.failed.push(file.path.clone()),
+        }
+    }
+    outcome
+}
+
+/// Format `source` with `rustfmt --emit stdout`; `None` if it cannot be parsed.
+fn format_stdin(dir: &Path, source: &str, edition: &str) -> std::io::Result<Option<String>> {
+    // Fall back to the working directory if the scratch tree is unusable
+    let dir = if dir.is_dir() { dir } else { Path::new(".") };
+    let mut child = rustfmt_command(edition)
+        .args(["--emit", "stdout"])
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Written from a thread so a full stdout pipe cannot stall the write
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = source.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    let _ = writer.join();
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+    Ok(String::from_utf8(output.stdout).ok())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rustfmt_available() -> bool {
+        rustfmt_command("2021").arg("--version").output().is_ok()
+    }
+
+    #[test]
+    fn test_source_tree() {
+        assert_eq!(source_tree(Path::new("src/main.rs")), PathBuf::from("src"));
+        assert_eq!(
+            source_tree(Path::new("src/models/user.rs")),
+            PathBuf::from("src")
+        );
+        assert_eq!(
+            source_tree(Path::new("backend/src/lib.rs")),
+            PathBuf::from("backend/src")
+        );
+        assert_eq!(
+            source_tree(Path::new("tests/common/mod.rs")),
+            PathBuf::from("tests")
+        );
+        assert_eq!(
+            source_tree(Path::new("migration/build.rs")),
+            PathBuf::from("migration")
+        );
+        assert_eq!(source_tree(Path::new("build.rs")), PathBuf::new());
+    }
+
+    #[test]
+    fn test_formats_rust_files_only() {
+        if !rustfmt_available() {
+            return;
+        }
+        let mut files = vec![
+            GeneratedFile::rust("src/main.rs", "mod models;\nfn  main( ){ }\n"),
+            GeneratedFile::rust("src/models/mod.rs", "pub struct  User{pub id:i64}\n"),
+            GeneratedFile::rust("tests/api.rs", "#[test]\nfn works(){assert!(true)}\n"),
+            GeneratedFile::new("Cargo.toml", "[package]\nname  =  \"x\"\n", FileType::Toml),
+        ];
+
+        let report = format_rust_files(&mut files, "2021");
+
+        assert_eq!(report.formatted, 3);
+        assert!(report.failed.is_empty());
+        assert!(!report.rustfmt_missing);
+        assert_eq!(files[0].content, "mod models;\nfn main() {}\n");
+        assert_eq!(files[1].content, "pub struct User {\n    pub id: i64,\n}\n");
+        assert_eq!(
+            files[2].content,
+            "#[test]\nfn works() {\n    assert!(true)\n}\n"
+        );
+        assert_eq!(files[3].content, "[package]\nname  =  \"x\"\n");
+    }
+
+    #[test]
+    fn test_unparseable_file_is_left_as_generated() {
+        if !rustfmt_available() {
+            return;
+        }
+        let mut files = vec![
+            GeneratedFile::rust("src/lib.rs", "pub mod broken;\npub fn  ok( ){}\n"),
+            GeneratedFile::rust("src/broken.rs", "fn broken( {\n"),
+        ];
+
+        let report = format_rust_files(&mut files, "2021");
+
+        assert_eq!(report.formatted, 1);
+        assert_eq!(report.failed, vec![PathBuf::from("src/broken.rs")]);
+        assert_eq!(files[0].content, "pub mod broken;\npub fn ok() {}\n");
+        assert_eq!(files[1].content, "fn broken( {\n");
+    }
+
+    #[test]
+    fn test_no_rust_files_is_a_no_op() {
+        let mut files = vec![GeneratedFile::new("README.md", "# x\n", FileType::Markdown)];
+        let report = format_rust_files(&mut files, "2021");
+        assert_eq!(report.formatted, 0);
+        assert!(!report.rustfmt_missing);
+        assert_eq!(files[0].content, "# x\n");
+    }
+}
@@ -26,7 +26,7 @@
 //! let created = client.create_user(&payload).await?;
 //! ```
 
-use imortal_ir::OperationType;
+use imortal_ir::{OperationType, PaginationStyle};
 
 use crate::context::{EntityInfo, GenerationContext};
 use crate::rust::file_header;
@@ -67,11 +67,18 @@ fn generate_api_mod(ctx: &GenerationContext) -> GeneratedFile {
 // ============================================================================
 
 fn generate_client(ctx: &GenerationContext) -> GeneratedFile {
-    let mut content = String::with_capacity(16384);
+    let mut content =
+        file_header("Type-safe API client for communicating with the backend REST API.");
+    content.push_str(&client_source(ctx, "shared"));
 
-    content.push_str(&file_header(
-        "Type-safe API client for communicating with the backend REST API.",
-    ));
+    GeneratedFile::new("frontend/src/api/client.rs", content, FileType::Rust)
+}
+
+/// The `ClientError` and `ApiClient` source, without a file header, taking
+/// the DTOs and `ApiError` from the `types` module path. The client SDK
+/// shares it with the frontend.
+pub(crate) fn client_source(ctx: &GenerationContext, types: &str) -> String {
+    let mut content = String::with_capacity(16384);
 
     // ── Imports ──────────────────────────────────────────────────────────
     content.push_str(
@@ -79,27 +86,44 @@ fn generate_client(ctx: &GenerationContext) -> GeneratedFile {
 use reqwest::Client;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::Value;
 use thiserror::Error;
 
-use shared::{ApiError, PaginatedResponse};
 ",
     );
+    content.push_str(&match ctx.pagination() {
+        PaginationStyle::Offset => format!("use {types}::{{ApiError, PaginatedResponse}};\n"),
+        PaginationStyle::Cursor => format!("use {types}::{{ApiError, CursorPage}};\n"),
+        PaginationStyle::None => format!("use {types}::ApiError;\n"),
+    });
 
-    // Import per-entity DTOs
+    // Import the per-entity DTOs the enabled operations take or return
     for entity in ctx.entities() {
         let info = EntityInfo::new(entity, ctx);
-        if info.endpoint().is_none() {
-            continue;
-        }
+        let ops: Vec<OperationType> = match info.endpoint() {
+            Some(ep) if ep.enabled => ep
+                .enabled_operations()
+                .iter()
+                .map(|op| op.operation_type)
+                .collect(),
+            _ => continue,
+        };
 
-        let create_dto = GenerationContext::create_dto_name(&entity.name);
-        let update_dto = GenerationContext::update_dto_name(&entity.name);
-        let response_dto = GenerationContext::response_dto_name(&entity.name);
+        let mut dtos = Vec::new();
+        if ops.contains(&OperationType::Create) {
+            dtos.push(GenerationContext::create_dto_name(&entity.name));
+        }
+        if ops.contains(&OperationType::Update) {
+            dtos.push(GenerationContext::update_dto_name(&entity.name));
+        }
+        if ops.iter().any(|op| *op != OperationType::Delete) {
+            dtos.push(GenerationContext::response_dto_name(&entity.name));
+        }
 
-        content.push_str(&format!(
-            "use shared::{{{create_dto}, {update_dto}, {response_dto}}};\n"
-        ));
+        match dtos.as_slice() {
+            [] => {}
+            [dto] => content.push_str(&format!("use {types}::{dto};\n")),
+            _ => content.push_str(&format!("use {types}::{{{}}};\n", dtos.join(", "))),
+        }
     }
 
     content.push('\n');
@@ -138,8 +162,8 @@ impl ClientError {
     fn from_api_error(status: u16, api_error: ApiError) -> Self {
         Self::Api {
             status,
-            code: api_error.error,
-            message: api_error.message,
+            code: api_error.code,
+            message: api_error.detail,
         }
     }
 
@@ -198,6 +222,29 @@ impl ClientError {
     // We default to localhost with the backend's configured port.
     let default_base_url = format!("http://127.0.0.1:{}", backend_port);
 
+    // Header versioning: each entity's methods send their group's version
+    let version_header = ctx.api_versioning().header_name();
+    let (version_field, version_init, version_method) = match version_header {
+        Some(header) => (
+            "    /// API version sent in the version header, if any.\n    api_version: Option<&'static str>,\n"
+                .to_string(),
+            "            api_version: None,\n".to_string(),
+            format!(
+                r#"
+    /// A copy of this client that asks for API `version` through the
+    /// `{header}` header.
+    fn at_version(&self, version: &'static str) -> Self {{
+        Self {{
+            api_version: Some(version),
+            ..self.clone()
+        }}
+    }}
+"#
+            ),
+        ),
+        None => Default::default(),
+    };
+
     content.push_str(&format!(
         r#"// ============================================================================
 // API Client
@@ -222,7 +269,7 @@ pub struct ApiClient {{
     base_url: String,
     /// Optional JWT token for authenticated requests.
     token: Option<String>,
-}}
+{version_field}}}
 
 impl ApiClient {{
     /// Create a new API client with the default base URL.
@@ -237,7 +284,7 @@ impl ApiClient {{
                 .expect("failed to create HTTP client"),
             base_url: "{default_base_url}".to_string(),
             token: None,
-        }}
+{version_init}        }}
     }}
 
     /// Create a client with a custom base URL.
@@ -259,7 +306,7 @@ impl ApiClient {{
     pub fn clear_token(&mut self) {{
         self.token = None;
     }}
-
+{version_method}
     /// Build the full URL for an API endpoint path.
     fn url(&self, path: &str) -> String {{
         format!("{{}}{{}}", self.base_url, path)
@@ -269,7 +316,7 @@ impl ApiClient {{
     ));
 
     // ── Generic request helpers ──────────────────────────────────────────
-    content.push_str(
+    let mut helpers = String::from(
         r#"    // ========================================================================
     // Generic request helpers
     // ========================================================================
@@ -332,8 +379,9 @@ impl ApiClient {{
                 .json::<ApiError>()
                 .await
                 .unwrap_or_else(|_| ApiError {
-                    error: "unknown".to_string(),
-                    message: "An unknown error occurred".to_string(),
+                    code: "unknown".to_string(),
+                    detail: "An unknown error occurred".to_string(),
+                    ..Default::default()
                 });
             Err(ClientError::from_api_error(status, body))
         }
@@ -357,8 +405,9 @@ impl ApiClient {{
                 .json::<ApiError>()
                 .await
                 .unwrap_or_else(|_| ApiError {
-                    error: "unknown".to_string(),
-                    message: format!("Server returned status {}", status_code),
+                    code: "unknown".to_string(),
+                    detail: format!("Server returned status {}", status_code),
+                    ..Default::default()
                 });
             Err(ClientError::from_api_error(status_code, body))
         }
@@ -366,6 +415,17 @@ impl ApiClient {{
 
 "#,
     );
+    if let Some(header) = version_header {
+        let token = "        if let Some(token) = &self.token {\n            req = req.bearer_auth(token);\n        }\n";
+        helpers = helpers.replace(
+            token,
+            &format!(
+                "{token}        if let Some(version) = self.api_version {{\n            req = req.header(\"{}\", version);\n        }}\n",
+                header.to_ascii_lowercase()
+            ),
+        );
+    }
+    content.push_str(&helpers);
 
     // ── Per-entity CRUD methods ──────────────────────────────────────────
 
@@ -391,6 +451,7 @@ impl ApiClient {{
         let update_dto = GenerationContext::update_dto_name(&entity.name);
         let response_dto = GenerationContext::response_dto_name(&entity.name);
 
+        let methods_start = content.len();
         content.push_str(&format!(
             "    // ========================================================================\n"
         ));
@@ -401,23 +462,11 @@ impl ApiClient {{
 
         // ── List (ReadAll) ───────────────────────────────────────────────
         if enabled_ops.contains(&OperationType::ReadAll) {
-            content.push_str(&format!(
-                r#"    /// List all {plural} with pagination.
-    ///
-    /// GET {base_path}?page={{page}}&per_page={{per_page}}
-    pub async fn list_{plural}(
-        &self,
-        page: u64,
-        per_page: u64,
-    ) -> Result<PaginatedResponse<{response_dto}>, ClientError> {{
-        let path = format!(
-            "{base_path}?page={{}}&per_page={{}}",
-            page, per_page,
-        );
-        self.get(&path).await
-    }}
-
-"#,
+            content.push_str(&generate_list_method(
+                ctx,
+                &plural,
+                &base_path,
+                &response_dto,
             ));
         }
 
@@ -492,6 +541,12 @@ impl ApiClient {{
 "#,
             ));
         }
+
+        // Route this entity's requests to its API version
+        if let (Some(_), Some(version)) = (version_header, info.api_version()) {
+            let methods = content.split_off(methods_start);
+            content.push_str(&at_version(&methods, version));
+        }
     }
 
     // ── Close impl block ─────────────────────────────────────────────────
@@ -508,7 +563,79 @@ impl Default for ApiClient {
 ",
     );
 
-    GeneratedFile::new("frontend/src/api/client.rs", content, FileType::Rust)
+    content
+}
+
+/// Make generated methods call the request helpers through
+/// `at_version(version)`, so they send that version's header.
+fn at_version(methods: &str, version: &str) -> String {
+    ["get", "post", "put", "delete"]
+        .iter()
+        .fold(methods.to_string(), |out, helper| {
+            out.replace(
+                &format!("self.{}(", helper),
+                &format!("self.at_version(\"{}\").{}(", version, helper),
+            )
+        })
+}
+
+/// Generate the list method for one entity, shaped by the pagination style.
+fn generate_list_method(
+    ctx: &GenerationContext,
+    plural: &str,
+    base_path: &str,
+    response_dto: &str,
+) -> String {
+    match ctx.pagination() {
+        PaginationStyle::Offset => format!(
+            r#"    /// List all {plural} with pagination.
+    ///
+    /// GET {base_path}?page={{page}}&per_page={{per_page}}
+    pub async fn list_{plural}(
+        &self,
+        page: u64,
+        per_page: u64,
+    ) -> Result<PaginatedResponse<{response_dto}>, ClientError> {{
+        let path = format!(
+            "{base_path}?page={{}}&per_page={{}}",
+            page, per_page,
+        );
+        self.get(&path).await
+    }}
+
+"#,
+        ),
+        PaginationStyle::Cursor => format!(
+            r#"    /// List {plural} one cursor page at a time.
+    ///
+    /// Pass `None` for the first page, then each page's `next_cursor`.
+    ///
+    /// GET {base_path}?cursor={{cursor}}&limit={{limit}}
+    pub async fn list_{plural}(
+        &self,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<CursorPage<{response_dto}>, ClientError> {{
+        let path = match cursor {{
+            Some(cursor) => format!("{base_path}?cursor={{}}&limit={{}}", cursor, limit),
+            None => format!("{base_path}?limit={{}}", limit),
+        }};
+        self.get(&path).await
+    }}
+
+"#,
+        ),
+        PaginationStyle::None => format!(
+            r#"    /// List all {plural}.
+    ///
+    /// GET {base_path}
+    pub async fn list_{plural}(&self) -> Result<Vec<{response_dto}>, ClientError> {{
+        self.get("{base_path}").await
+    }}
+
+"#,
+        ),
+    }
 }
 
 // ============================================================================
@@ -519,7 +646,9 @@ impl Default for ApiClient {
 mod tests {
     use super::*;
     use imortal_core::DataType;
-    use imortal_ir::{EndpointGroup, Entity, Field, OperationType, ProjectGraph, ProjectType};
+    use imortal_ir::{
+        ApiVersioning, EndpointGroup, Entity, Field, OperationType, ProjectGraph, ProjectType,
+    };
 
     /// Helper: fullstack project with a User entity and full CRUD endpoints.
     fn fullstack_project() -> ProjectGraph {
@@ -729,6 +858,31 @@ mod tests {
         assert!(content.contains("per_page="));
     }
 
+    #[test]
+    fn test_client_list_method_follows_pagination_style() {
+        let mut project = fullstack_project();
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_api_client(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy().contains("client.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains("use shared::{ApiError, CursorPage};"));
+        assert!(content.contains("cursor: Option<&str>"));
+        assert!(content.contains("CursorPage<UserResponse>"));
+
+        project.config.pagination = PaginationStyle::None;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_api_client(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy().contains("client.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains("use shared::ApiError;"));
+        assert!(content.contains("Result<Vec<UserResponse>, ClientError>"));
+    }
+
     #[test]
     fn test_client_get_method_takes_id() {
         let project = fullstack_project();
@@ -975,6 +1129,33 @@ mod tests {
         assert!(content.contains("token: Option<String>"));
     }
 
+    #[test]
+    fn test_client_sends_version_header() {
+        let mut project = fullstack_project();
+        project.endpoints.values_mut().next().unwrap().api_version = Some("v2".to_string());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_api_client(&ctx);
+        let client = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("client.rs"))
+            .unwrap()
+            .content;
+        assert!(!client.contains("at_version"));
+
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_api_client(&ctx);
+        let client = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("client.rs"))
+            .unwrap()
+            .content;
+        assert!(client.contains("api_version: Option<&'static str>,"));
+        assert!(client.contains("fn at_version(&self, version: &'static str) -> Self {"));
+        assert!(client.contains("req = req.header(\"x-api-version\", version);"));
+        assert!(client.contains("self.at_version(\"v2\").get("));
+    }
+
     #[test]
     fn test_client_has_timeout() {
         let project = fullstack_project();
@@ -1044,6 +1225,18 @@ mod tests {
         assert!(content.contains("use shared::{CreateUserDto, UpdateUserDto, UserResponse}"));
     }
 
+    #[test]
+    fn test_client_imports_only_used_dtos() {
+        let project = read_only_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = client_source(&ctx, "shared");
+
+        assert!(content.contains("use shared::ItemResponse;\n"));
+        assert!(!content.contains("CreateItemDto"));
+        assert!(!content.contains("UpdateItemDto"));
+        assert!(!content.contains("serde_json::Value"));
+    }
+
     #[test]
     fn test_client_uses_thiserror() {
         let project = fullstack_project();
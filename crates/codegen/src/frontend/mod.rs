@@ -51,7 +51,11 @@ pub mod api_client;
 pub mod dioxus;
 pub mod pages;
 
+use imortal_ir::PaginationStyle;
+
 use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::cargo::{RUST_DECIMAL_DEP, package_metadata};
+use crate::rust::enums::{self, EnumMapping};
 use crate::rust::file_header;
 use crate::{FileType, GeneratedFile};
 
@@ -134,9 +138,9 @@ members = [
 ]
 
 [workspace.package]
-version = "0.1.0"
+version = "{version}"
 edition = "{edition}"
-publish = false
+{metadata}publish = false
 
 [workspace.dependencies]
 # Shared dependencies used by multiple crates
@@ -144,7 +148,7 @@ serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
 uuid = {{ version = "1", features = ["v4", "serde"] }}
 chrono = {{ version = "0.4", features = ["serde"] }}
-thiserror = "2"
+{decimal}thiserror = "2"
 
 # Internal crates
 shared = {{ path = "shared" }}
@@ -155,7 +159,14 @@ lto = true
 strip = true
 codegen-units = 1
 "#,
+        version = ctx.meta.version,
         edition = ctx.rust_edition(),
+        metadata = package_metadata(&ctx.meta),
+        decimal = if ctx.uses_decimal() {
+            RUST_DECIMAL_DEP
+        } else {
+            ""
+        },
     );
 
     GeneratedFile::new("Cargo.toml", content, FileType::Toml)
@@ -177,14 +188,16 @@ fn generate_shared_crate(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 name = "shared"
 version.workspace = true
 edition.workspace = true
-publish.workspace = true
+{inherited}publish.workspace = true
 
 [dependencies]
 serde = {{ workspace = true }}
 serde_json = {{ workspace = true }}
 uuid = {{ workspace = true }}
 chrono = {{ workspace = true }}
-"#,
+{decimal}"#,
+        inherited = inherited_package_metadata(ctx),
+        decimal = workspace_decimal_dep(ctx),
     );
     files.push(GeneratedFile::new(
         "shared/Cargo.toml",
@@ -199,16 +212,46 @@ chrono = {{ workspace = true }}
         "Shared types (DTOs) used by both backend and frontend.",
     ));
 
-    lib_content.push_str("use serde::{Deserialize, Serialize};\n");
-    lib_content.push_str("use uuid::Uuid;\n");
+    lib_content.push_str(&shared_types(ctx));
+
+    files.push(GeneratedFile::new(
+        "shared/src/lib.rs",
+        lib_content,
+        FileType::Rust,
+    ));
+
+    files
+}
+
+/// The DTOs, enums, pagination envelope and `ApiError` of the shared crate,
+/// without a file header. The client SDK embeds the same types.
+pub(crate) fn shared_types(ctx: &GenerationContext) -> String {
+    let mut content = String::with_capacity(4096);
+
+    content.push_str("use serde::{Deserialize, Serialize};\n");
 
     // Check if we need chrono
     let needs_chrono = ctx.entities().iter().any(|e| e.config.timestamps);
     if needs_chrono {
-        lib_content.push_str("use chrono::{DateTime, Utc};\n");
+        content.push_str("use chrono::{DateTime, Utc};\n");
     }
 
-    lib_content.push('\n');
+    content.push('\n');
+
+    // Enum types, without the backend's database mapping
+    if !ctx.enums().is_empty() {
+        content.push_str(
+            "// ============================================================================\n",
+        );
+        content.push_str("// Enums\n");
+        content.push_str(
+            "// ============================================================================\n\n",
+        );
+        for def in ctx.enums() {
+            content.push_str(&enums::enum_definition(def, &EnumMapping::default(), ctx));
+            content.push('\n');
+        }
+    }
 
     // Generate DTOs for each entity
     for entity in ctx.entities() {
@@ -216,56 +259,56 @@ chrono = {{ workspace = true }}
         let pascal = info.pascal_name();
         let snake = info.snake_name();
 
-        lib_content.push_str(&format!(
+        content.push_str(&format!(
             "// ============================================================================\n"
         ));
-        lib_content.push_str(&format!("// {} DTOs\n", pascal));
-        lib_content.push_str(&format!(
+        content.push_str(&format!("// {} DTOs\n", pascal));
+        content.push_str(&format!(
             "// ============================================================================\n\n"
         ));
 
         // Create DTO
         let create_name = GenerationContext::create_dto_name(&entity.name);
-        lib_content.push_str(&format!("/// Payload for creating a new {}.\n", pascal));
-        lib_content.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        lib_content.push_str(&format!("pub struct {} {{\n", create_name));
+        content.push_str(&format!("/// Payload for creating a new {}.\n", pascal));
+        content.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+        content.push_str(&format!("pub struct {} {{\n", create_name));
 
         for field in ctx.create_fields(entity) {
             let field_name = GenerationContext::snake(&field.name);
             let rust_type = shared_rust_type(&field.data_type, field.required);
-            lib_content.push_str(&format!("    pub {}: {},\n", field_name, rust_type));
+            content.push_str(&format!("    pub {}: {},\n", field_name, rust_type));
         }
-        lib_content.push_str("}\n\n");
+        content.push_str("}\n\n");
 
         // Update DTO
         let update_name = GenerationContext::update_dto_name(&entity.name);
-        lib_content.push_str(&format!(
+        content.push_str(&format!(
             "/// Payload for updating an existing {}.\n",
             pascal
         ));
-        lib_content.push_str("#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n");
-        lib_content.push_str(&format!("pub struct {} {{\n", update_name));
+        content.push_str("#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n");
+        content.push_str(&format!("pub struct {} {{\n", update_name));
 
         for field in ctx.update_fields(entity) {
             let field_name = GenerationContext::snake(&field.name);
             let inner_type = shared_rust_type(&field.data_type, true);
-            lib_content.push_str(&format!(
+            content.push_str(&format!(
                 "    pub {}: Option<{}>,\n",
                 field_name, inner_type
             ));
         }
-        lib_content.push_str("}\n\n");
+        content.push_str("}\n\n");
 
         // Response DTO
         let response_name = GenerationContext::response_dto_name(&entity.name);
-        lib_content.push_str(&format!("/// Response representation of a {}.\n", pascal));
-        lib_content.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        lib_content.push_str(&format!("pub struct {} {{\n", response_name));
+        content.push_str(&format!("/// Response representation of a {}.\n", pascal));
+        content.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+        content.push_str(&format!("pub struct {} {{\n", response_name));
 
         for field in ctx.response_fields(entity) {
             let field_name = GenerationContext::snake(&field.name);
             let rust_type = shared_rust_type(&field.data_type, field.required);
-            lib_content.push_str(&format!("    pub {}: {},\n", field_name, rust_type));
+            content.push_str(&format!("    pub {}: {},\n", field_name, rust_type));
         }
 
         // Timestamps
@@ -273,19 +316,20 @@ chrono = {{ workspace = true }}
             let has_created = entity.fields.iter().any(|f| f.name == "created_at");
             let has_updated = entity.fields.iter().any(|f| f.name == "updated_at");
             if !has_created {
-                lib_content.push_str("    pub created_at: DateTime<Utc>,\n");
+                content.push_str("    pub created_at: DateTime<Utc>,\n");
             }
             if !has_updated {
-                lib_content.push_str("    pub updated_at: DateTime<Utc>,\n");
+                content.push_str("    pub updated_at: DateTime<Utc>,\n");
             }
         }
 
-        lib_content.push_str("}\n\n");
+        content.push_str("}\n\n");
     }
 
-    // Pagination types
-    lib_content.push_str(
-        r#"// ============================================================================
+    // Pagination types (mirror the backend's list envelope)
+    match ctx.pagination() {
+        PaginationStyle::Offset => content.push_str(
+            r#"// ============================================================================
 // Pagination
 // ============================================================================
 
@@ -306,26 +350,56 @@ pub struct PaginationParams {
     pub per_page: Option<u64>,
 }
 
+"#,
+        ),
+        PaginationStyle::Cursor => content.push_str(
+            r#"// ============================================================================
+// Pagination
 // ============================================================================
+
+/// Cursor-paginated API response wrapper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// Query parameters for cursor-paginated list endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaginationParams {
+    pub cursor: Option<String>,
+    pub limit: Option<u64>,
+}
+
+"#,
+        ),
+        PaginationStyle::None => {}
+    }
+
+    content.push_str(
+        r#"// ============================================================================
 // API Error
 // ============================================================================
 
-/// Standard API error response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Standard API error response: RFC 7807 problem details.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ApiError {
-    pub error: String,
-    pub message: String,
+    #[serde(rename = "type", default)]
+    pub problem_type: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub status: u16,
+    #[serde(default)]
+    pub detail: String,
+    /// Machine-readable error code, e.g. `not_found`.
+    pub code: String,
 }
 "#,
     );
 
-    files.push(GeneratedFile::new(
-        "shared/src/lib.rs",
-        lib_content,
-        FileType::Rust,
-    ));
-
-    files
+    content
 }
 
 // ============================================================================
@@ -340,7 +414,7 @@ fn generate_frontend_cargo_toml(ctx: &GenerationContext) -> GeneratedFile {
 name = "{pkg}-frontend"
 version.workspace = true
 edition.workspace = true
-publish.workspace = true
+{inherited}publish.workspace = true
 
 [dependencies]
 # UI Framework
@@ -358,7 +432,7 @@ uuid = {{ workspace = true }}
 
 # Date/Time
 chrono = {{ workspace = true }}
-
+{decimal}
 # Error handling
 thiserror = {{ workspace = true }}
 
@@ -369,6 +443,8 @@ web-sys = "0.3"
 # Internal
 shared = {{ workspace = true }}
 "#,
+        inherited = inherited_package_metadata(ctx),
+        decimal = workspace_decimal_dep(ctx),
     );
 
     GeneratedFile::new("frontend/Cargo.toml", content, FileType::Toml)
@@ -1014,6 +1090,27 @@ fn generate_frontend_router(ctx: &GenerationContext) -> GeneratedFile {
 // Helpers
 // ============================================================================
 
+/// `rust_decimal` member dependency, when any entity has a decimal field.
+fn workspace_decimal_dep(ctx: &GenerationContext) -> &'static str {
+    if ctx.uses_decimal() {
+        "rust_decimal = { workspace = true }\n"
+    } else {
+        ""
+    }
+}
+
+/// `key.workspace = true` for each project-wide key set in
+/// `[workspace.package]`. Members keep their own description, and a key
+/// can only be inherited when the workspace actually sets it.
+fn inherited_package_metadata(ctx: &GenerationContext) -> String {
+    package_metadata(&ctx.meta)
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .filter(|(key, _)| *key != "description")
+        .map(|(key, _)| format!("{}.workspace = true\n", key))
+        .collect()
+}
+
 /// Map a `DataType` to a Rust type string for use in the shared crate.
 /// This uses standard library types without SeaORM-specific wrappers.
 fn shared_rust_type(dt: &imortal_core::DataType, required: bool) -> String {
@@ -1025,6 +1122,7 @@ fn shared_rust_type(dt: &imortal_core::DataType, required: bool) -> String {
         DataType::Int64 => "i64".to_string(),
         DataType::Float32 => "f32".to_string(),
         DataType::Float64 => "f64".to_string(),
+        DataType::Decimal { .. } => "rust_decimal::Decimal".to_string(),
         DataType::Bool => "bool".to_string(),
         DataType::Uuid => "uuid::Uuid".to_string(),
         DataType::DateTime => "chrono::DateTime<chrono::Utc>".to_string(),
@@ -1039,7 +1137,7 @@ fn shared_rust_type(dt: &imortal_core::DataType, required: bool) -> String {
             return format!("Vec<{}>", shared_rust_type(inner, true));
         }
         DataType::Reference { .. } => "uuid::Uuid".to_string(),
-        DataType::Enum { name, .. } => name.clone(),
+        DataType::Enum { name, .. } => GenerationContext::pascal(name),
     };
 
     if !required {
@@ -1107,6 +1205,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_frontend_has_no_cdn_references() {
+        let project = fullstack_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_frontend(&ctx);
+
+        // Generated frontends must work offline: styles and icons are
+        // shipped as local assets, never pulled from a CDN.
+        for file in &files {
+            for host in ["cdn.", "unpkg.com", "jsdelivr", "googleapis.com", "cdnjs"] {
+                assert!(
+                    !file.content.contains(host),
+                    "{} references remote host '{}'",
+                    file.path.display(),
+                    host
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_generate_frontend_produces_files_for_fullstack() {
         let project = fullstack_project();
@@ -1178,6 +1296,22 @@ mod tests {
         assert!(content.contains("uuid"));
     }
 
+    #[test]
+    fn test_workspace_package_metadata_is_inherited() {
+        let mut project = fullstack_project();
+        project.meta.description = Some("Fullstack blog".to_string());
+        project.meta.license = Some("MIT".to_string());
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let workspace = generate_workspace_cargo_toml(&ctx).content;
+        assert!(workspace.contains("description = \"Fullstack blog\"\nlicense = \"MIT\"\n"));
+
+        let frontend = generate_frontend_cargo_toml(&ctx).content;
+        assert!(frontend.contains("license.workspace = true\n"));
+        assert!(!frontend.contains("description.workspace"));
+        assert!(!frontend.contains("repository.workspace"));
+    }
+
     #[test]
     fn test_shared_crate_cargo_toml() {
         let project = fullstack_project();
@@ -1196,6 +1330,33 @@ mod tests {
         assert!(content.contains("chrono"));
     }
 
+    #[test]
+    fn test_decimal_dependency_in_every_crate() {
+        let mut project = fullstack_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(
+            !generate_workspace_cargo_toml(&ctx)
+                .content
+                .contains("rust_decimal")
+        );
+
+        let mut order = Entity::new("Order");
+        order.fields.push(Field::new("total", DataType::money()));
+        project.add_entity(order);
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let workspace = generate_workspace_cargo_toml(&ctx).content;
+        assert!(workspace.contains("rust_decimal = { version = \"1\""));
+        let shared = generate_shared_crate(&ctx);
+        assert!(
+            shared[0]
+                .content
+                .contains("rust_decimal = { workspace = true }")
+        );
+        let frontend = generate_frontend_cargo_toml(&ctx).content;
+        assert!(frontend.contains("rust_decimal = { workspace = true }"));
+    }
+
     #[test]
     fn test_shared_crate_lib_has_dtos() {
         let project = fullstack_project();
@@ -1420,6 +1581,10 @@ mod tests {
         assert_eq!(shared_rust_type(&DataType::Int32, true), "i32");
         assert_eq!(shared_rust_type(&DataType::Int64, true), "i64");
         assert_eq!(shared_rust_type(&DataType::Float64, true), "f64");
+        assert_eq!(
+            shared_rust_type(&DataType::money(), true),
+            "rust_decimal::Decimal"
+        );
         assert_eq!(shared_rust_type(&DataType::Bool, true), "bool");
         assert_eq!(shared_rust_type(&DataType::Uuid, true), "uuid::Uuid");
         assert_eq!(
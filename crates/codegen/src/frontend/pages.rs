@@ -21,6 +21,8 @@
 //! Pages use the generated API client (`crate::api::client`) to communicate
 //! with the backend and the shared DTOs from the `shared` crate.
 
+use imortal_ir::PaginationStyle;
+
 use crate::context::{EntityInfo, GenerationContext};
 use crate::rust::file_header;
 use crate::{FileType, GeneratedFile};
@@ -259,6 +261,7 @@ fn generate_list_page(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFi
     let response_dto = GenerationContext::response_dto_name(&info.entity.name);
     let base_path = info.base_path();
     let path = format!("frontend/src/pages/{}_list.rs", snake);
+    let paging = ListPaging::new(ctx, &plural);
 
     // Determine columns from response fields
     let response_fields = info.response_fields();
@@ -275,9 +278,14 @@ fn generate_list_page(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFi
     content.push_str("use serde_json::{json, Value};\n\n");
 
     content.push_str("use crate::api::client::ApiClient;\n");
-    content.push_str(
-        "use crate::components::table::{DataTable, Column, Pagination, DeleteConfirmDialog};\n",
-    );
+    if ctx.pagination() == PaginationStyle::Offset {
+        content.push_str(
+            "use crate::components::table::{DataTable, Column, Pagination, DeleteConfirmDialog};\n",
+        );
+    } else {
+        content
+            .push_str("use crate::components::table::{DataTable, Column, DeleteConfirmDialog};\n");
+    }
     content.push_str("use crate::components::form::Alert;\n");
     content.push_str("use crate::router::Route;\n\n");
 
@@ -285,9 +293,9 @@ fn generate_list_page(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFi
     content.push_str(&format!(
         r#"/// {pascal} list page component.
 ///
-/// Fetches and displays all {snake}s in a paginated data table.
+/// Fetches and displays all {snake}s in a data table.
 /// Supports:
-/// - Pagination (page, per_page)
+/// - {paging_doc}
 /// - Row click to view details
 /// - Edit and delete actions per row
 /// - Create new {snake} via button
@@ -297,11 +305,7 @@ pub fn {pascal}ListPage() -> Element {{
     let mut rows = use_signal(Vec::<Value>::new);
     let mut loading = use_signal(|| true);
     let mut error_msg: Signal<Option<String>> = use_signal(|| None);
-    let mut page = use_signal(|| 1u64);
-    let mut total = use_signal(|| 0u64);
-    let mut total_pages = use_signal(|| 0u64);
-    let per_page = 20u64;
-
+{paging_state}
     // Delete confirmation state
     let mut delete_target: Signal<Option<String>> = use_signal(|| None);
     let mut delete_name = use_signal(|| String::new());
@@ -310,24 +314,20 @@ pub fn {pascal}ListPage() -> Element {{
     let navigator = use_navigator();
 
     // Fetch data
-    let current_page = *page.read();
-    use_effect(move || {{
-        spawn(async move {{
+{paging_before_fetch}    use_effect(move || {{
+{paging_in_effect}        spawn(async move {{
             loading.set(true);
             error_msg.set(None);
 
             let client = ApiClient::new();
-            match client.list_{plural}(current_page, per_page).await {{
+            match {paging_call}.await {{
                 Ok(response) => {{
                     // Convert items to JSON values for the generic table
-                    let items: Vec<Value> = response.items
+                    let items: Vec<Value> = {paging_items}
                         .into_iter()
                         .map(|item| serde_json::to_value(item).unwrap_or_default())
                         .collect();
-                    rows.set(items);
-                    total.set(response.total);
-                    total_pages.set(response.total_pages);
-                }}
+{paging_loaded}                }}
                 Err(e) => {{
                     error_msg.set(Some(format!("Failed to load {plural}: {{}}", e)));
                 }}
@@ -340,6 +340,13 @@ pub fn {pascal}ListPage() -> Element {{
     // Column definitions
     let columns = vec![
 "#,
+        paging_doc = paging.doc,
+        paging_state = paging.state,
+        paging_before_fetch = paging.before_fetch,
+        paging_in_effect = paging.in_effect,
+        paging_call = paging.call,
+        paging_items = paging.items,
+        paging_loaded = paging.loaded,
     ));
 
     // Add columns based on response fields (limit to reasonable number)
@@ -398,7 +405,7 @@ pub fn {pascal}ListPage() -> Element {{
                     button {{
                         class: "btn btn-secondary btn-sm",
                         onclick: move |_| {{
-                            page.set(*page.read()); // trigger re-fetch
+                            {paging_refetch}
                         }},
                         "\u{{21bb}} Refresh"
                     }}
@@ -451,17 +458,7 @@ pub fn {pascal}ListPage() -> Element {{
                 }},
             }}
 
-            // Pagination
-            if *total_pages.read() > 1 {{
-                Pagination {{
-                    page: *page.read(),
-                    total_pages: *total_pages.read(),
-                    total_items: *total.read(),
-                    per_page: per_page,
-                    on_page_change: move |new_page| page.set(new_page),
-                }}
-            }}
-
+{paging_footer}
             // Delete confirmation dialog
             DeleteConfirmDialog {{
                 visible: delete_target.read().is_some(),
@@ -478,7 +475,7 @@ pub fn {pascal}ListPage() -> Element {{
                                 Ok(_) => {{
                                     success_msg.set(Some("{pascal} deleted successfully.".to_string()));
                                     // Trigger re-fetch
-                                    page.set(*page.read());
+                                    {paging_refetch}
                                 }}
                                 Err(e) => {{
                                     error_msg.set(Some(format!("Failed to delete: {{}}", e)));
@@ -492,11 +489,120 @@ pub fn {pascal}ListPage() -> Element {{
     }}
 }}
 "#,
+        paging_refetch = paging.refetch,
+        paging_footer = paging.footer,
     ));
 
     GeneratedFile::new(path, content, FileType::Rust)
 }
 
+/// Pieces of the list page that depend on the project's pagination style.
+struct ListPaging {
+    /// Doc-comment bullet describing the paging behaviour
+    doc: &'static str,
+    /// Signal declarations
+    state: &'static str,
+    /// Statements run before the fetch effect
+    before_fetch: &'static str,
+    /// Statements run inside the effect, before spawning the request
+    in_effect: &'static str,
+    /// Client call (without `.await`)
+    call: String,
+    /// Expression yielding the fetched items
+    items: &'static str,
+    /// Statements storing a successful response
+    loaded: &'static str,
+    /// Statement that triggers a re-fetch
+    refetch: &'static str,
+    /// Paging controls rendered below the table
+    footer: &'static str,
+}
+
+impl ListPaging {
+    fn new(ctx: &GenerationContext, plural: &str) -> Self {
+        match ctx.pagination() {
+            PaginationStyle::Offset => Self {
+                doc: "Pagination (page, per_page)",
+                state: "    let mut page = use_signal(|| 1u64);
+    let mut total = use_signal(|| 0u64);
+    let mut total_pages = use_signal(|| 0u64);
+    let per_page = 20u64;
+",
+                before_fetch: "    let current_page = *page.read();\n",
+                in_effect: "",
+                call: format!("client.list_{}(current_page, per_page)", plural),
+                items: "response.items",
+                loaded: "                    rows.set(items);
+                    total.set(response.total);
+                    total_pages.set(response.total_pages);
+",
+                refetch: "page.set(*page.read()); // trigger re-fetch",
+                footer: "            // Pagination
+            if *total_pages.read() > 1 {
+                Pagination {
+                    page: *page.read(),
+                    total_pages: *total_pages.read(),
+                    total_items: *total.read(),
+                    per_page: per_page,
+                    on_page_change: move |new_page| page.set(new_page),
+                }
+            }
+",
+            },
+            PaginationStyle::Cursor => Self {
+                doc: "Cursor pagination (\"Load more\" appends the next page)",
+                state: "    let mut cursor: Signal<Option<String>> = use_signal(|| None);
+    let mut next_cursor: Signal<Option<String>> = use_signal(|| None);
+    let per_page = 20u64;
+",
+                before_fetch: "    let current_cursor = cursor.read().clone();\n",
+                in_effect: "        let current_cursor = current_cursor.clone();\n",
+                call: format!(
+                    "client.list_{}(current_cursor.as_deref(), per_page)",
+                    plural
+                ),
+                items: "response.items",
+                loaded: "                    if current_cursor.is_some() {
+                        rows.write().extend(items);
+                    } else {
+                        rows.set(items);
+                    }
+                    next_cursor.set(response.next_cursor);
+",
+                refetch: "cursor.set(None); // restart from the first page",
+                footer: "            // Load more
+            if next_cursor.read().is_some() {
+                div {
+                    class: \"flex justify-center mt-4\",
+                    button {
+                        class: \"btn btn-secondary btn-sm\",
+                        onclick: move |_| {
+                            let next = next_cursor.read().clone();
+                            cursor.set(next);
+                        },
+                        \"Load more\"
+                    }
+                }
+            }
+",
+            },
+            PaginationStyle::None => Self {
+                doc: "Full list (the API returns every row)",
+                state: "    let mut reload = use_signal(|| 0u64);
+",
+                before_fetch: "    let _reload = *reload.read();\n",
+                in_effect: "",
+                call: format!("client.list_{}()", plural),
+                items: "response",
+                loaded: "                    rows.set(items);
+",
+                refetch: "*reload.write() += 1; // trigger re-fetch",
+                footer: "",
+            },
+        }
+    }
+}
+
 // ============================================================================
 // Per-entity form page (create / edit)
 // ============================================================================
@@ -819,7 +925,7 @@ fn form_default_value(dt: &imortal_core::DataType) -> &'static str {
     match dt {
         DataType::String | DataType::Text | DataType::Uuid => "String::new()",
         DataType::Int32 | DataType::Int64 => "String::new()",
-        DataType::Float32 | DataType::Float64 => "String::new()",
+        DataType::Float32 | DataType::Float64 | DataType::Decimal { .. } => "String::new()",
         DataType::Bool => "false",
         DataType::DateTime | DataType::Date | DataType::Time => "String::new()",
         DataType::Json => "String::new()",
@@ -864,8 +970,12 @@ fn form_value_to_option(dt: &imortal_core::DataType, field_name: &str) -> String
         DataType::Int64 => format!("{field_name}.read().parse::<i64>().ok()"),
         DataType::Float32 => format!("{field_name}.read().parse::<f32>().ok()"),
         DataType::Float64 => format!("{field_name}.read().parse::<f64>().ok()"),
+        DataType::Decimal { .. } => {
+            format!("{field_name}.read().parse::<rust_decimal::Decimal>().ok()")
+        }
         DataType::Bool => format!("Some(*{field_name}.read())"),
         DataType::Uuid => format!("uuid::Uuid::parse_str(&{field_name}.read()).ok()"),
+        DataType::Enum { .. } => format!("{field_name}.read().parse().ok()"),
         _ => format!("Some({field_name}.read().clone())"),
     }
 }
@@ -903,6 +1013,13 @@ fn form_value_to_dto(dt: &imortal_core::DataType, field_name: &str, required: bo
                 format!("{field_name}.read().parse::<f64>().ok()")
             }
         }
+        DataType::Decimal { .. } => {
+            if required {
+                format!("{field_name}.read().parse::<rust_decimal::Decimal>().unwrap_or_default()")
+            } else {
+                format!("{field_name}.read().parse::<rust_decimal::Decimal>().ok()")
+            }
+        }
         DataType::Bool => format!("*{field_name}.read()"),
         DataType::Uuid => {
             if required {
@@ -924,6 +1041,14 @@ fn form_value_to_dto(dt: &imortal_core::DataType, field_name: &str, required: bo
                 format!("uuid::Uuid::parse_str(&{field_name}.read()).ok()")
             }
         }
+        DataType::Enum { name, .. } => {
+            if required {
+                let name = GenerationContext::pascal(name);
+                format!("{field_name}.read().parse().unwrap_or(shared::{name}::ALL[0])")
+            } else {
+                format!("{field_name}.read().parse().ok()")
+            }
+        }
         _ => format!("{field_name}.read().clone()"),
     }
 }
@@ -949,7 +1074,7 @@ fn form_input_type(dt: &imortal_core::DataType, field_name: &str) -> &'static st
     match dt {
         DataType::String | DataType::Text => "text",
         DataType::Int32 | DataType::Int64 => "number",
-        DataType::Float32 | DataType::Float64 => "number",
+        DataType::Float32 | DataType::Float64 | DataType::Decimal { .. } => "number",
         DataType::Bool => "checkbox",
         DataType::Uuid => "text",
         DataType::DateTime => "datetime-local",
@@ -984,6 +1109,7 @@ fn form_placeholder(dt: &imortal_core::DataType, field_name: &str) -> String {
         }
         DataType::Int32 | DataType::Int64 => "0".to_string(),
         DataType::Float32 | DataType::Float64 => "0.0".to_string(),
+        DataType::Decimal { scale, .. } => format!("{:.*}", *scale as usize, 0.0),
         DataType::Uuid => "00000000-0000-0000-0000-000000000000".to_string(),
         DataType::DateTime => "2026-01-29T12:00".to_string(),
         DataType::Date => "2026-01-29".to_string(),
@@ -1155,6 +1281,34 @@ mod tests {
         assert!(list.content.contains("on_page_change"));
     }
 
+    #[test]
+    fn test_list_page_follows_pagination_style() {
+        let mut project = fullstack_project();
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_pages(&ctx);
+        let list = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user_list.rs"))
+            .unwrap();
+        assert!(
+            list.content
+                .contains("client.list_users(current_cursor.as_deref(), per_page)")
+        );
+        assert!(list.content.contains("Load more"));
+        assert!(!list.content.contains("Pagination"));
+
+        project.config.pagination = PaginationStyle::None;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_pages(&ctx);
+        let list = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user_list.rs"))
+            .unwrap();
+        assert!(list.content.contains("client.list_users().await"));
+        assert!(!list.content.contains("total_pages"));
+    }
+
     #[test]
     fn test_list_page_has_delete_confirmation() {
         let project = fullstack_project();
@@ -1360,6 +1514,7 @@ mod tests {
         assert!(form_placeholder(&DataType::String, "name").contains("Enter name"));
         assert_eq!(form_placeholder(&DataType::Int32, "count"), "0");
         assert_eq!(form_placeholder(&DataType::Float64, "price"), "0.0");
+        assert_eq!(form_placeholder(&DataType::money(), "price"), "0.00");
         assert!(form_placeholder(&DataType::Uuid, "ref_id").contains("00000000"));
         assert!(form_placeholder(&DataType::DateTime, "ts").contains("2026"));
     }
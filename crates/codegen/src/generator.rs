@@ -15,9 +15,26 @@
 //!         │
 //!         ├──► rust::generate_rust_project()   → Vec<GeneratedFile>
 //!         ├──► migrations::generate_migrations() → Vec<GeneratedFile>
+//!         │      (or plan_migration() against config.baseline)
+//!         ├──► seeds::generate_seeds()         → Vec<GeneratedFile>
+//!         ├──► openapi::generate_openapi()     → Vec<GeneratedFile>
+//!         ├──► loadtest::generate_load_tests() → Vec<GeneratedFile>
+//!         ├──► frontend::generate_frontend()   → Vec<GeneratedFile>
+//!         ├──► sdk::generate_client_sdk()      → Vec<GeneratedFile>
+//!         ├──► typescript::generate_typescript_client() → Vec<GeneratedFile>
+//!         ├──► deploy::generate_docker()       → Vec<GeneratedFile>
+//!         │      (each on its own scoped thread, output kept in this order)
+//!         ▼
+//!   format::format_rust_files()   (when format_code is set)
+//!   regions::preserve_regions()   (against the files in output_dir)
 //!         │
 //!         ▼
-//!   GeneratedProject { files, warnings }
+//!   GeneratedProject { files, warnings, timings }
+//!         │
+//!         ├──► (generate_and_write) write_to_disk() → hooks::run_hooks()
+//!         │                          → GeneratedProject { hook_outcomes }
+//!         │
+//!         └──► (plan) preview_output() → GenerationPlan { manifest, conflicts }
 //! ```
 //!
 //! ## Usage
@@ -35,14 +52,36 @@
 //! result.write_to_disk("/path/to/output")?;
 //! ```
 
-use imortal_core::{EngineResult, Validatable};
-use imortal_ir::ProjectGraph;
+use imortal_core::{DatabaseType, EngineError, EngineResult, Validatable};
+use imortal_ir::validation::{
+    ColumnTypesRule, ForeignKeyIndexesRule, GeneratedNamesRule, SensitiveFieldsRule,
+};
+use imortal_ir::{Incompatibility, ProjectGraph, Validator, check_compatibility};
 
-use crate::context::GenerationContext;
+use crate::context::{EntityInfo, GenerationContext};
+use crate::deploy;
+use crate::format;
 use crate::frontend;
+use crate::hooks;
+use crate::loadtest;
+use crate::manifest::Manifest;
 use crate::migrations;
+use crate::openapi;
+use crate::plan::GenerationPlan;
+use crate::preview::FileStatus;
+use crate::regions;
 use crate::rust;
-use crate::{GeneratedProject, GeneratorConfig};
+use crate::rust::diesel;
+use crate::rust::orm::OrmFeature;
+use crate::sdk;
+use crate::seeds;
+use crate::templates::{self, Templates};
+use crate::typescript;
+use crate::warnings::{GenerationWarning, WarningCode};
+use crate::{FileType, GeneratedFile, GeneratedProject, GeneratorConfig};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 // ============================================================================
 // Generator
@@ -95,51 +134,110 @@ impl Generator {
     /// 1. **Validate** the project graph (entities, relationships, endpoints).
     /// 2. **Build** a [`GenerationContext`] with sorted entities, lookups, and
     ///    derived metadata.
+    ///    Stops with an error if the options cannot be generated together
+    ///    (see [`check_compatibility`]).
     /// 3. **Generate Rust source files** (models, handlers, routes, auth, config,
     ///    error, main, middleware, tests, Cargo.toml, .env, .gitignore, README).
     /// 4. **Generate SQL migrations** (one per entity, dependency-ordered).
-    /// 5. **Generate frontend** (Dioxus Web app, shared crate — fullstack only).
-    /// 6. **Collect warnings** from generators (e.g. entities without endpoints,
-    ///    unused relationships).
-    /// 7. Return the assembled [`GeneratedProject`].
+    /// 5. **Generate seed data** (one SQL file per entity plus the `seed`
+    ///    binary) when enabled.
+    /// 6. **Generate the OpenAPI spec** (`openapi.yaml`, plus `openapi.json`
+    ///    if requested) when OpenAPI is enabled.
+    /// 7. **Generate the load test script** (`loadtest/k6.js`, next to the
+    ///    OpenAPI spec) when enabled.
+    /// 8. **Generate frontend** (Dioxus Web app, shared crate — fullstack only).
+    /// 9. **Generate Docker files** (`Dockerfile`, `docker-compose.yml`,
+    ///    `.dockerignore` at the project root) when enabled.
+    ///
+    ///    Steps 3–9 and the other file generators run in parallel.
+    /// 10. **Format** the Rust files with rustfmt when
+    ///     [`GeneratorConfig::format_code`] is set. Files rustfmt cannot parse
+    ///     are left as generated, with a warning.
+    ///     Then carry the protected regions of the files already in the
+    ///     output directory over (see [`regions`]).
+    /// 11. **Collect warnings** from generators (e.g. entities without endpoints,
+    ///     unused relationships). In strict mode, any of them fails the run.
+    /// 12. Return the assembled [`GeneratedProject`], with the time each
+    ///     stage took in [`GeneratedProject::timings`].
     ///
     /// # Errors
     ///
-    /// Returns an `EngineError` if project validation fails. Individual
-    /// generators do not return errors — instead they add warnings to the
-    /// output.
+    /// Individual generators do not return errors — instead they add warnings
+    /// to the output. When [`GeneratorConfig::strict`] is set, validation
+    /// failures and warnings are instead collected into a single
+    /// `EngineError::CodeGeneration` report and no files are produced.
+    /// Incompatible options always fail that way, strict or not.
     pub fn generate(&self, project: &ProjectGraph) -> EngineResult<GeneratedProject> {
+        let started = Instant::now();
+
         // ── 1. Validate ──────────────────────────────────────────────────
-        if let Err(e) = project.validate() {
+        let validation_error = project.validate().err();
+        if let Some(e) = &validation_error {
             tracing::warn!("Project validation warning: {}", e);
             // We continue despite validation warnings so that partial
             // projects can still be generated. Only hard errors should
-            // prevent generation.
+            // prevent generation (or any warning, in strict mode).
         }
 
+        // Included field sets are generated as the entities' own fields
+        let project = &project.expand_field_sets();
+
         // ── 2. Build context ─────────────────────────────────────────────
-        let ctx = GenerationContext::from_project(project, self.config.clone());
+        let mut ctx = GenerationContext::from_project(project, self.config.clone());
+        if let Some(dir) = ctx.templates_dir() {
+            ctx = ctx.with_templates(Templates::load(&dir)?);
+        }
+
+        // Option combinations that would produce broken output are fatal,
+        // checked against the options in effect after overrides
+        let mut options = ctx.config.clone();
+        options.orm = ctx.orm();
+        options.framework = ctx.framework();
+        options.api_style = ctx.api_style();
+        let problems = check_compatibility(project, &options);
+        if !problems.is_empty() {
+            return Err(EngineError::CodeGeneration(compatibility_report(&problems)));
+        }
+        if self.config.workspace_member && ctx.is_fullstack() {
+            return Err(EngineError::CodeGeneration(
+                "A fullstack project is a Cargo workspace of its own and can't be generated as a member of another one".to_string(),
+            ));
+        }
 
         // ── 3. Collect warnings ──────────────────────────────────────────
-        let mut warnings: Vec<String> = Vec::new();
+        let mut warnings: Vec<GenerationWarning> = Vec::new();
 
         // Warn about entities without endpoints
         for entity in ctx.entities() {
             if ctx.endpoint_for_entity(entity.id).is_none() {
-                warnings.push(format!(
-                    "Entity '{}' has no endpoint group configured — no handlers or routes will be generated for it.",
-                    entity.name,
-                ));
+                warnings.push(
+                    GenerationWarning::new(
+                        WarningCode::EntityWithoutEndpoint,
+                        format!(
+                            "Entity '{}' has no endpoint group configured — no handlers or routes will be generated for it.",
+                            entity.name,
+                        ),
+                    )
+                    .with_entity(entity.id)
+                    .with_suggestion("Add an endpoint group for it on the Endpoints page"),
+                );
             }
         }
 
         // Warn about endpoints referencing missing entities
         for ep in ctx.endpoints() {
             if ctx.entity_by_id(ep.entity_id).is_none() {
-                warnings.push(format!(
-                    "Endpoint group '{}' references entity ID {} which does not exist.",
-                    ep.entity_name, ep.entity_id,
-                ));
+                warnings.push(
+                    GenerationWarning::new(
+                        WarningCode::OrphanEndpoint,
+                        format!(
+                            "Endpoint group '{}' references entity ID {} which does not exist.",
+                            ep.entity_name, ep.entity_id,
+                        ),
+                    )
+                    .with_endpoint(ep.id)
+                    .with_suggestion("Delete the endpoint group or recreate its entity"),
+                );
             }
         }
 
@@ -148,30 +246,134 @@ impl Generator {
             let any_secured = ctx.endpoints().iter().any(|ep| ep.requires_auth());
             if !any_secured {
                 warnings.push(
-                    "Authentication is enabled in project config but no endpoints require authentication. \
-                     Consider securing at least some endpoints or disabling auth.".to_string(),
+                    GenerationWarning::new(
+                        WarningCode::AuthWithoutSecuredEndpoints,
+                        "Authentication is enabled in project config but no endpoints require authentication.",
+                    )
+                    .with_suggestion(
+                        "Secure at least some endpoints or disable auth in Project Setup",
+                    ),
                 );
             }
         }
 
+        // Surface the validator's warnings about the generated code
+        warnings.extend(validation_warnings(project));
+
         // Warn about empty project
         if ctx.entity_count() == 0 {
             warnings.push(
-                "No entities defined — the generated project will be an empty server scaffold."
-                    .to_string(),
+                GenerationWarning::new(
+                    WarningCode::EmptyProject,
+                    "No entities defined — the generated project will be an empty server scaffold.",
+                )
+                .with_suggestion("Add entities on the Entity Design page"),
             );
         }
 
-        // ── 4. Generate Rust source files ────────────────────────────────
-        let rust_files = rust::generate_rust_project(&ctx);
+        // Warn about schemas the target database cannot honour
+        if ctx.database() == DatabaseType::SQLite {
+            for entity in ctx.entities() {
+                if let Some(schema) = entity.config.schema_name() {
+                    warnings.push(
+                        GenerationWarning::new(
+                            WarningCode::UnsupportedSchema,
+                            format!(
+                                "Entity '{}' is placed in schema '{}', but SQLite has no schemas — the table will be created without it.",
+                                entity.name, schema,
+                            ),
+                        )
+                        .with_entity(entity.id)
+                        .with_suggestion("Clear the schema or switch to PostgreSQL or MySQL"),
+                    );
+                }
+            }
+        }
+
+        // Warn about features the selected ORM backend cannot generate
+        warnings.extend(orm_warnings(project, &ctx));
+
+        // Warn about entities the seed data skips or only approximates
+        warnings.extend(seeds::seed_warnings(&ctx));
 
-        // ── 5. Generate SQL migrations ───────────────────────────────────
-        let migration_files = migrations::generate_migrations(&ctx);
+        // Warn about template files that override nothing, likely misnamed
+        for path in ctx.templates().unused() {
+            warnings.push(
+                GenerationWarning::new(
+                    WarningCode::UnusedTemplate,
+                    format!(
+                        "'{}' in the templates directory overrides no template and is ignored.",
+                        path.display(),
+                    ),
+                )
+                .with_suggestion(format!(
+                    "Name it after one of the built-in templates: {}",
+                    templates::builtin_names().collect::<Vec<_>>().join(", "),
+                )),
+            );
+        }
 
-        // ── 6. Generate frontend (fullstack only) ────────────────────────
-        let frontend_files = frontend::generate_frontend(&ctx);
+        // The schema snapshot is the one migration output that can fail
+        let snapshot = if ctx.generate_migrations() {
+            Some(imortal_ir::serialization::save_project_to_string(project)?)
+        } else {
+            None
+        };
+        let mut plan_warnings = Vec::new();
+
+        // ── 4–12. Generate files ─────────────────────────────────────────
+        // The generators only read the context, so they run side by side;
+        // each keeps its place in the output.
+        let (
+            [
+                rust_files,
+                migration_files,
+                seed_files,
+                openapi_files,
+                load_test_files,
+                frontend_files,
+                sdk_files,
+                ts_client_files,
+                docker_files,
+            ],
+            stage_timings,
+        ) = run_stages([
+            // 4. Rust source files
+            ("rust", Box::new(|| rust::generate_rust_project(&ctx))),
+            // 5. SQL migrations (or an incremental one against the baseline)
+            (
+                "migrations",
+                Box::new(|| self.migration_files(&ctx, snapshot, &mut plan_warnings)),
+            ),
+            // 6. Seed data
+            ("seeds", Box::new(|| seeds::generate_seeds(&ctx))),
+            // 7. OpenAPI spec
+            ("openapi", Box::new(|| openapi::generate_openapi(&ctx))),
+            // 8. Load test script
+            (
+                "load tests",
+                Box::new(|| loadtest::generate_load_tests(&ctx)),
+            ),
+            // 9. Frontend (fullstack only)
+            ("frontend", Box::new(|| frontend::generate_frontend(&ctx))),
+            // 10. Client SDK
+            ("client sdk", Box::new(|| sdk::generate_client_sdk(&ctx))),
+            // 11. TypeScript client
+            (
+                "typescript client",
+                Box::new(|| typescript::generate_typescript_client(&ctx)),
+            ),
+            // 12. Docker files
+            ("docker", Box::new(|| deploy::generate_docker(&ctx))),
+        ]);
+        warnings.extend(plan_warnings);
+
+        // A template that failed to render left its file empty
+        if let Some(error) = ctx.templates().take_failures().into_iter().next() {
+            return Err(error);
+        }
 
-        // ── 7. Assemble output ───────────────────────────────────────────
+        // ── 13. Assemble output ──────────────────────────────────────────
         let project_name = ctx.package_name().to_string();
         let mut output = GeneratedProject::new(&project_name);
 
@@ -195,8 +397,15 @@ impl Generator {
                 output.add_file(file);
             }
 
-            // Prefix migration files under backend/ as well
-            for mut file in migration_files {
+            // Prefix migration and seed files under backend/ as well
+            for mut file in migration_files.into_iter().chain(seed_files) {
+                let path_str = file.path.to_string_lossy().to_string();
+                file.path = format!("backend/{}", path_str).into();
+                output.add_file(file);
+            }
+
+            // The spec and load test describe the backend, so they live with it
+            for mut file in openapi_files.into_iter().chain(load_test_files) {
                 let path_str = file.path.to_string_lossy().to_string();
                 file.path = format!("backend/{}", path_str).into();
                 output.add_file(file);
@@ -211,25 +420,131 @@ impl Generator {
             for file in rust_files {
                 output.add_file(file);
             }
-            for file in migration_files {
+            for file in migration_files.into_iter().chain(seed_files) {
+                output.add_file(file);
+            }
+            for file in openapi_files.into_iter().chain(load_test_files) {
                 output.add_file(file);
             }
         }
 
+        // The client SDK, TypeScript client and Docker files sit at the
+        // project (or workspace) root either way
+        for file in sdk_files
+            .into_iter()
+            .chain(ts_client_files)
+            .chain(docker_files)
+        {
+            output.add_file(file);
+        }
+
+        // ── 14. Format Rust files ────────────────────────────────────────
+        let mut formatting = None;
+        if self.config.format_code {
+            let report = format::format_rust_files(&mut output.files, ctx.rust_edition());
+            if report.rustfmt_missing {
+                tracing::warn!("rustfmt not found — generated Rust files are left unformatted");
+            } else {
+                formatting = Some(report.duration);
+            }
+            for path in report.failed {
+                warnings.push(
+                    GenerationWarning::new(
+                        WarningCode::Unformatted,
+                        format!(
+                            "'{}' could not be parsed by rustfmt and is left unformatted.",
+                            path.display(),
+                        ),
+                    )
+                    .with_suggestion("Run `cargo build` on the output to see the syntax error"),
+                );
+            }
+        }
+
+        // ── 15. Keep protected regions ───────────────────────────────────
+        let report = regions::preserve_regions(&mut output.files, &self.config.output_dir);
+        for (path, name) in report.relocated {
+            let name = if name.is_empty() {
+                "An unnamed protected region".to_string()
+            } else {
+                format!("Protected region '{}'", name)
+            };
+            warnings.push(
+                GenerationWarning::new(
+                    WarningCode::RelocatedRegion,
+                    format!(
+                        "{} in '{}' has no place in the new output and was moved to the end of the file.",
+                        name,
+                        path.display(),
+                    ),
+                )
+                .with_suggestion("Move the code into another protected region of the file"),
+            );
+        }
+
+        // ── 16. Strict mode: any warning is fatal ────────────────────────
+        // Checked once every stage added its warnings, so migration, rustfmt
+        // and region warnings count too; nothing has been written yet.
+        if self.config.strict {
+            check_strict(validation_error.as_ref(), &warnings)?;
+        }
+
         for warning in warnings {
             output.add_warning(warning);
         }
 
+        output.timings = GenerationTimings {
+            stages: stage_timings,
+            formatting,
+            total: started.elapsed(),
+        };
+
         tracing::info!(
             files = output.file_count(),
             warnings = output.warnings.len(),
             project = %project_name,
+            elapsed_ms = output.timings.total.as_millis() as u64,
             "code generation complete",
         );
 
         Ok(output)
     }
 
+    /// Step 5: the full migration set, or a single incremental migration
+    /// against [`GeneratorConfig::baseline`] whose warnings are added to
+    /// `warnings`. `snapshot` is the serialized project, or `None` when
+    /// migrations are disabled.
+    fn migration_files(
+        &self,
+        ctx: &GenerationContext,
+        snapshot: Option<String>,
+        warnings: &mut Vec<GenerationWarning>,
+    ) -> Vec<GeneratedFile> {
+        let Some(snapshot) = snapshot else {
+            return Vec::new();
+        };
+
+        let plan = self.config.baseline.as_ref().map(|baseline| {
+            let baseline_ctx = GenerationContext::from_project(baseline, self.config.clone());
+            migrations::plan_migration(&baseline_ctx, ctx)
+        });
+
+        let mut files = match plan {
+            Some(plan) => {
+                warnings.extend(migration_warnings(&plan));
+                ctx.orm_backend().generate_alter_migration(&plan, ctx)
+            }
+            None => ctx.orm_backend().generate_migrations(ctx),
+        };
+
+        files.push(GeneratedFile::new(
+            migrations::SCHEMA_SNAPSHOT_FILE,
+            snapshot,
+            FileType::Json,
+        ));
+        files
+    }
+
     // ====================================================================
     // Convenience: generate and write to disk
     // ====================================================================
@@ -237,7 +552,15 @@ impl Generator {
     /// Generate code and write all files to the configured output directory.
     ///
     /// This is a convenience method that combines [`generate`](Generator::generate)
-    /// and [`GeneratedProject::write_to_disk`].
+    /// and [`GeneratedProject::write_to_disk`], then runs the configured
+    /// post-generation hooks over the output directory. Their results are
+    /// recorded in [`GeneratedProject::hook_outcomes`].
+    ///
+    /// Unless [`GeneratorConfig::overwrite`] is set, files the user edited
+    /// since they were generated are not overwritten; each one skipped gets
+    /// a [`WarningCode::UserModified`] warning, which in strict mode fails the
+    /// run before anything is written. The
+    /// [output manifest](crate::manifest) is updated once the hooks ran.
     ///
     /// # Arguments
     ///
@@ -250,16 +573,80 @@ impl Generator {
     /// # Errors
     ///
     /// Returns an error if generation fails or if any file cannot be written.
+    /// Failing hooks are reported in the outcomes, not as errors.
     pub fn generate_and_write(&self, project: &ProjectGraph) -> EngineResult<GeneratedProject> {
         let output = self.generate(project)?;
-        output.write_to_disk(&self.config.output_dir)?;
+        let dir = &self.config.output_dir;
+        let plan = GenerationPlan::new(output, dir, self.config.overwrite);
+        let skipped: HashSet<PathBuf> = plan
+            .conflicts()
+            .filter(|entry| entry.status == FileStatus::Changed)
+            .map(|entry| entry.path.clone())
+            .collect();
+        let mut output = plan.project;
+
+        let mut skipped_paths: Vec<&PathBuf> = skipped.iter().collect();
+        skipped_paths.sort();
+        for path in skipped_paths {
+            output.add_warning(
+                GenerationWarning::new(
+                    WarningCode::UserModified,
+                    format!(
+                        "'{}' was edited since it was generated and was not overwritten.",
+                        path.display(),
+                    ),
+                )
+                .with_suggestion(
+                    "Move the edits into a protected `immortal:keep` region, or regenerate with overwrite enabled to discard them",
+                ),
+            );
+        }
+
+        // In strict mode an edited file fails the run before anything is written
+        if self.config.strict {
+            check_strict(None, &output.warnings)?;
+        }
+
+        output.write_to_disk_except(dir, &skipped)?;
         tracing::info!(
-            output_dir = %self.config.output_dir.display(),
-            files = output.file_count(),
+            output_dir = %dir.display(),
+            files = output.file_count() - skipped.len(),
+            skipped = skipped.len(),
             "files written to disk",
         );
+
+        let hooks = hooks::configured_hooks(&self.config);
+        output.hook_outcomes = hooks::run_hooks(&hooks, dir, &output.files);
+        Manifest::record(dir, output.files.iter().map(|f| f.path.as_path()), &skipped)?;
         Ok(output)
     }
+
+    // ====================================================================
+    // Dry run
+    // ====================================================================
+
+    /// Generate code without writing it, and compare it with the configured
+    /// output directory.
+    ///
+    /// The returned [`GenerationPlan`] lists every file that would be
+    /// written or deleted, its size, and whether it conflicts with a file on
+    /// disk given [`GeneratorConfig::overwrite`]. Nothing on disk changes;
+    /// [`GenerationPlan::write`] writes the plan later.
+    ///
+    /// # Errors
+    ///
+    /// Fails when [`generate`](Generator::generate) would.
+    pub fn plan(&self, project: &ProjectGraph) -> EngineResult<GenerationPlan> {
+        let output = self.generate(project)?;
+        let plan = GenerationPlan::new(output, &self.config.output_dir, self.config.overwrite);
+        tracing::info!(
+            output_dir = %self.config.output_dir.display(),
+            conflicts = plan.conflicts().count(),
+            "planned {}",
+            plan.summary(),
+        );
+        Ok(plan)
+    }
 }
 
 impl Default for Generator {
@@ -291,6 +678,359 @@ pub fn generate_to_dir(
     Generator::new(config).generate_and_write(project)
 }
 
+/// Fail a strict-mode run on the validation error or any warning.
+fn check_strict(
+    validation_error: Option<&EngineError>,
+    warnings: &[GenerationWarning],
+) -> EngineResult<()> {
+    let mut issues = Vec::with_capacity(warnings.len() + 1);
+    if let Some(e) = validation_error {
+        issues.push(e.to_string());
+    }
+    issues.extend(warnings.iter().map(|w| w.to_string()));
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(EngineError::CodeGeneration(strict_report(&issues)))
+    }
+}
+
+/// Format the issues that failed a strict-mode run as one report.
+pub fn strict_report(issues: &[String]) -> String {
+    let mut out = format!(
+        "strict mode: generation produced {} warning(s)\n",
+        issues.len()
+    );
+    for (i, issue) in issues.iter().enumerate() {
+        out.push_str(&format!("  {}. {}\n", i + 1, issue));
+    }
+    out
+}
+
+/// Format the option combinations that stopped generation as one report,
+/// with the suggested fix under each.
+pub fn compatibility_report(problems: &[Incompatibility]) -> String {
+    let mut out = format!(
+        "incompatible options: {} problem(s) must be fixed before generating\n",
+        problems.len()
+    );
+    for (i, problem) in problems.iter().enumerate() {
+        out.push_str(&format!("  {}. {}\n", i + 1, problem));
+        out.push_str(&format!("     help: {}\n", problem.suggestion));
+    }
+    out
+}
+
+/// Warnings for the parts of an incremental migration that need review.
+fn migration_warnings(plan: &migrations::SchemaMigration) -> Vec<GenerationWarning> {
+    let mut warnings = Vec::new();
+    if !plan.destructive.is_empty() {
+        warnings.push(
+            GenerationWarning::new(
+                WarningCode::DestructiveMigration,
+                format!(
+                    "The migration drops {} — existing data there will be lost.",
+                    plan.destructive.join(", ")
+                ),
+            )
+            .with_suggestion("Back up the data, or rename instead of deleting to keep it"),
+        );
+    }
+    for note in &plan.manual {
+        warnings.push(
+            GenerationWarning::new(WarningCode::ManualMigration, format!("Migration: {}", note))
+                .with_suggestion("Edit the generated migration before applying it"),
+        );
+    }
+    for item in &plan.staged {
+        warnings.push(
+            GenerationWarning::new(
+                WarningCode::StagedMigration,
+                format!("Migration: {} is staged", item),
+            )
+            .with_suggestion(
+                "Move the DEFERRED statements into a later migration once every deployed version uses the new schema",
+            )
+            .info(),
+        );
+    }
+    warnings
+}
+
+/// Warnings of the validation rules that concern the generated code, so
+/// each issue is checked in one place (the rule) and reported in both the
+/// Problems panel and the generation output.
+fn validation_warnings(project: &ProjectGraph) -> Vec<GenerationWarning> {
+    let mut validator = Validator::new();
+    validator.add_rule(Box::new(SensitiveFieldsRule));
+    validator.add_rule(Box::new(ForeignKeyIndexesRule));
+    validator.add_rule(Box::new(GeneratedNamesRule));
+    validator.add_rule(Box::new(ColumnTypesRule));
+
+    // Rules walk the entity map, so order by path to keep output stable
+    let mut warnings = validator.validate(project).warnings;
+    warnings.sort_by(|a, b| (&a.path, &a.message).cmp(&(&b.path, &b.message)));
+    warnings.into_iter().map(GenerationWarning::from).collect()
+}
+
+/// Warnings for project features the selected ORM backend leaves out of
+/// the generated code, and column types it cannot map on the database.
+fn orm_warnings(project: &ProjectGraph, ctx: &GenerationContext) -> Vec<GenerationWarning> {
+    let orm = ctx.orm();
+    let backend = ctx.orm_backend();
+    let mut warnings = Vec::new();
+    let unsupported = |feature: OrmFeature, what: String| {
+        GenerationWarning::new(
+            WarningCode::UnsupportedByOrm,
+            format!(
+                "{} uses {}, which the {} backend does not generate — it will be left out.",
+                what,
+                feature.display_name(),
+                orm.display_name(),
+            ),
+        )
+        .with_suggestion("Switch the project's ORM to SeaORM to keep it")
+    };
+
+    if !backend.supports(OrmFeature::CustomQueries) {
+        for query in project.queries.values().filter(|q| q.enabled) {
+            warnings.push(
+                unsupported(OrmFeature::CustomQueries, format!("Query '{}'", query.name))
+                    .with_entity(query.entity_id),
+            );
+        }
+    }
+
+    if !backend.supports(OrmFeature::NestedCreate) {
+        for rel in project.relationships.values().filter(|r| r.nested_create) {
+            warnings.push(
+                unsupported(
+                    OrmFeature::NestedCreate,
+                    format!("Relationship '{}'", rel.name),
+                )
+                .with_entity(rel.from_entity_id),
+            );
+        }
+    }
+
+    if !backend.supports(OrmFeature::Associations) {
+        for rel in project
+            .relationships
+            .values()
+            .filter(|r| r.junction_entity_id.is_some())
+        {
+            // The junction entity keeps its own CRUD routes
+            warnings.push(
+                unsupported(
+                    OrmFeature::Associations,
+                    format!("Relationship '{}'", rel.name),
+                )
+                .with_entity(rel.from_entity_id),
+            );
+        }
+    }
+
+    if !backend.supports(OrmFeature::Polymorphic) {
+        for rel in project
+            .relationships
+            .values()
+            .filter(|r| r.is_polymorphic())
+        {
+            // The discriminator and key stay plain columns
+            warnings.push(
+                unsupported(
+                    OrmFeature::Polymorphic,
+                    format!("Relationship '{}'", rel.name),
+                )
+                .info()
+                .with_entity(rel.from_entity_id),
+            );
+        }
+    }
+
+    if !backend.supports(OrmFeature::ScopedUniqueChecks) {
+        for entity in ctx.entities() {
+            // The unique index still refuses duplicates with a generic 409
+            for scoped in EntityInfo::new(entity, ctx).scoped_uniques() {
+                warnings.push(
+                    unsupported(
+                        OrmFeature::ScopedUniqueChecks,
+                        format!("Field '{}.{}'", entity.name, scoped.field.name),
+                    )
+                    .info()
+                    .with_entity(entity.id),
+                );
+            }
+        }
+    }
+
+    for endpoint in ctx.endpoints().iter().filter(|ep| ep.enabled) {
+        let what = format!("Endpoint '{}'", endpoint.entity_name);
+        if !backend.supports(OrmFeature::RelatedAggregates)
+            && !endpoint.related_aggregates.is_empty()
+        {
+            warnings.push(
+                unsupported(OrmFeature::RelatedAggregates, what.clone()).with_endpoint(endpoint.id),
+            );
+        }
+        if !backend.supports(OrmFeature::ListQueryParams)
+            && endpoint
+                .enabled_operations()
+                .iter()
+                .any(|op| op.list.sorting || op.list.filtering)
+        {
+            // On by default, and lists still work without it
+            warnings.push(
+                unsupported(OrmFeature::ListQueryParams, what)
+                    .info()
+                    .with_endpoint(endpoint.id),
+            );
+        }
+    }
+
+    if !backend.supports(OrmFeature::DeleteBlockers) && ctx.report_delete_blockers() {
+        warnings.push(unsupported(
+            OrmFeature::DeleteBlockers,
+            "The project".to_string(),
+        ));
+    }
+
+    if !backend.supports(OrmFeature::BackgroundJobs) && project.config.background_jobs {
+        warnings.push(unsupported(
+            OrmFeature::BackgroundJobs,
+            "The project".to_string(),
+        ));
+    }
+
+    if !backend.supports(OrmFeature::GraphQl) && ctx.api_style().serves_graphql() {
+        warnings.push(
+            unsupported(OrmFeature::GraphQl, "The project".to_string())
+                .with_suggestion("Switch the project's ORM to SeaORM, or the API style to REST"),
+        );
+    }
+
+    if orm == imortal_ir::Orm::Diesel {
+        for entity in ctx.entities() {
+            for field in &entity.fields {
+                if let Some(reason) =
+                    diesel::unsupported_column_reason(&field.data_type, ctx.database())
+                {
+                    warnings.push(
+                        GenerationWarning::new(
+                            WarningCode::UnsupportedColumnType,
+                            format!(
+                                "Field '{}.{}' is a {}, but {} — the generated models will not compile.",
+                                entity.name,
+                                field.name,
+                                field.data_type.display_name(),
+                                reason,
+                            ),
+                        )
+                        .with_field(entity.id, field.id)
+                        .with_suggestion("Change the field type or switch the project's ORM to SeaORM"),
+                    );
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Whether a type is stored as an array (possibly optional).
+// ============================================================================
+// Stages and timings
+// ============================================================================
+
+/// A named step of the pipeline producing a batch of files.
+type Stage<'a> = (
+    &'static str,
+    Box<dyn FnOnce() -> Vec<GeneratedFile> + Send + 'a>,
+);
+
+/// Run independent generation stages on scoped threads.
+///
+/// The files come back in the order the stages were given, whatever order
+/// they finish in, so the output is the same as running them one by one.
+/// A panicking stage panics the caller.
+fn run_stages<const N: usize>(
+    stages: [Stage<'_>; N],
+) -> ([Vec<GeneratedFile>; N], Vec<StageTiming>) {
+    let results: Vec<(Vec<GeneratedFile>, StageTiming)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = stages
+            .into_iter()
+            .map(|(stage, generate)| {
+                scope.spawn(move || {
+                    let started = Instant::now();
+                    let files = generate();
+                    let timing = StageTiming {
+                        stage,
+                        files: files.len(),
+                        duration: started.elapsed(),
+                    };
+                    (files, timing)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+
+    let (files, timings): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+    let files = files
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("one result per stage"));
+    (files, timings)
+}
+
+/// How long one generation stage took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageTiming {
+    /// Stage name (`rust`, `migrations`, `openapi`, …).
+    pub stage: &'static str,
+    /// Number of files the stage produced.
+    pub files: usize,
+    /// Wall-clock time of the stage.
+    pub duration: Duration,
+}
+
+/// Where the time of a generation run went.
+///
+/// Stages run in parallel, so their durations add up to more than `total`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationTimings {
+    /// Per-stage timings, in pipeline order.
+    pub stages: Vec<StageTiming>,
+    /// Time spent in rustfmt, or `None` if formatting was off or rustfmt
+    /// was not found.
+    pub formatting: Option<Duration>,
+    /// Wall-clock time of the whole run.
+    pub total: Duration,
+}
+
+impl GenerationTimings {
+    /// The slowest stage, if any ran.
+    pub fn slowest_stage(&self) -> Option<&StageTiming> {
+        self.stages.iter().max_by_key(|s| s.duration)
+    }
+
+    /// The total time, with the rustfmt share, e.g. `1.5 s (rustfmt 120 ms)`.
+    pub fn describe(&self) -> String {
+        let mut out = format_duration(self.total);
+        if let Some(formatting) = self.formatting {
+            out.push_str(&format!(" (rustfmt {})", format_duration(formatting)));
+        }
+        out
+    }
+}
+
 // ============================================================================
 // GenerationSummary — human-readable report
 // ============================================================================
@@ -314,6 +1054,12 @@ pub struct GenerationSummary {
     pub warning_count: usize,
     /// Total bytes of generated content.
     pub total_bytes: usize,
+    /// Number of post-generation hook runs.
+    pub hooks_run: usize,
+    /// Descriptions and output of the hook runs that failed.
+    pub hook_failures: Vec<String>,
+    /// Where the generation time went.
+    pub timings: GenerationTimings,
 }
 
 impl GenerationSummary {
@@ -326,6 +1072,20 @@ impl GenerationSummary {
         let other_files = project.file_count() - rust_files - sql_files;
         let total_bytes: usize = project.files.iter().map(|f| f.content.len()).sum();
 
+        let hook_failures = project
+            .hook_outcomes
+            .iter()
+            .filter(|o| !o.succeeded())
+            .map(|o| {
+                let mut report = o.describe();
+                for line in o.output_tail(5) {
+                    report.push_str("\n    ");
+                    report.push_str(line);
+                }
+                report
+            })
+            .collect();
+
         Self {
             project_name: project.name.clone(),
             total_files: project.file_count(),
@@ -334,6 +1094,9 @@ impl GenerationSummary {
             other_files,
             warning_count: project.warnings.len(),
             total_bytes,
+            hooks_run: project.hook_outcomes.len(),
+            hook_failures,
+            timings: project.timings.clone(),
         }
     }
 
@@ -359,8 +1122,26 @@ impl GenerationSummary {
             format!("{:.1} MB", self.total_bytes as f64 / (1024.0 * 1024.0))
         };
         out.push_str(&format!("║  Total Size:  {:<35}║\n", size_str));
+        if !self.timings.total.is_zero() {
+            out.push_str(&format!(
+                "║  Time:        {:<35}║\n",
+                self.timings.describe()
+            ));
+        }
+        if self.hooks_run > 0 {
+            let hooks_str = format!(
+                "{} run, {} failed",
+                self.hooks_run,
+                self.hook_failures.len()
+            );
+            out.push_str(&format!("║  Hooks:       {:<35}║\n", hooks_str));
+        }
         out.push_str("╚══════════════════════════════════════════════════╝\n");
 
+        for failure in &self.hook_failures {
+            out.push_str(&format!("hook failed: {}\n", failure));
+        }
+
         out
     }
 }
@@ -371,6 +1152,15 @@ impl std::fmt::Display for GenerationSummary {
     }
 }
 
+/// `1.2 s` above a second, `340 ms` below.
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() >= 1 {
+        format!("{:.1} s", duration.as_secs_f64())
+    } else {
+        format!("{} ms", duration.as_millis())
+    }
+}
+
 /// Produce a [`GenerationSummary`] from a [`GeneratedProject`].
 pub fn summarize(project: &GeneratedProject) -> GenerationSummary {
     GenerationSummary::from_project(project)
@@ -383,8 +1173,13 @@ pub fn summarize(project: &GeneratedProject) -> GenerationSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::WarningSeverity;
     use imortal_core::DataType;
-    use imortal_ir::{AuthConfig, EndpointGroup, Entity, Field, OperationType, ProjectGraph};
+    use imortal_ir::templates::ProjectTemplate;
+    use imortal_ir::{
+        AuthConfig, EndpointGroup, Entity, Field, FieldSet, OperationType, ProjectGraph,
+        Relationship,
+    };
     use uuid::Uuid;
 
     /// Helper: empty project.
@@ -492,7 +1287,7 @@ mod tests {
         let has_empty_warning = output
             .warnings
             .iter()
-            .any(|w| w.contains("No entities defined"));
+            .any(|w| w.message.contains("No entities defined"));
         assert!(
             has_empty_warning,
             "Should warn about no entities: {:?}",
@@ -500,6 +1295,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_warns_about_unprotected_sensitive_fields() {
+        let mut project = full_project();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields.push(Field::new("api_key", DataType::String));
+
+        let output = Generator::with_defaults().generate(&project).unwrap();
+
+        assert!(
+            output.warnings.iter().any(
+                |w| w.message.contains("User.api_key") && w.message.contains("looks sensitive")
+            ),
+            "Should flag api_key: {:?}",
+            output.warnings
+        );
+    }
+
+    #[test]
+    fn test_generate_warnings_link_to_sources() {
+        let mut project = full_project();
+        let user = project.entities.values_mut().next().unwrap();
+        let user_id = user.id;
+        let field = Field::new("api_key", DataType::String);
+        let field_id = field.id;
+        user.fields.push(field);
+        user.fields
+            .push(Field::new("displayName", DataType::String));
+
+        let output = Generator::with_defaults().generate(&project).unwrap();
+
+        let sensitive = output
+            .warnings
+            .iter()
+            .find(|w| w.field_id == Some(field_id))
+            .expect("sensitive field warning");
+        assert_eq!(sensitive.code, WarningCode::SensitiveFieldExposed);
+        assert_eq!(sensitive.entity_id, Some(user_id));
+        assert_eq!(sensitive.severity, WarningSeverity::Warning);
+        assert!(sensitive.suggestion.is_some());
+
+        let renamed = output
+            .warnings
+            .iter()
+            .find(|w| w.code == WarningCode::RenamedIdentifier)
+            .expect("rename note");
+        assert_eq!(renamed.severity, WarningSeverity::Info);
+    }
+
     // ── Full project generation ──────────────────────────────────────────
 
     #[test]
@@ -614,6 +1457,96 @@ mod tests {
         assert!(!paths.iter().any(|p| p.starts_with("src/auth/")));
     }
 
+    // ── Showcase templates ───────────────────────────────────────────────
+
+    /// The project templates offered by `imortal new` generate code that
+    /// compiles; these are the mistakes `cargo check` caught in them.
+    #[test]
+    fn test_generate_project_templates() {
+        for template in ProjectTemplate::all() {
+            let project = template.build("showcase");
+            let output = Generator::with_defaults().generate(&project).unwrap();
+            let file = |path: &str| {
+                output
+                    .files
+                    .iter()
+                    .find(|f| f.path.to_string_lossy() == path)
+                    .map(|f| f.content.as_str())
+            };
+
+            for f in &output.files {
+                let path = f.path.to_string_lossy();
+                let content = &f.content;
+                // Nullable fields are `Option` once, in the model and the DTOs
+                assert!(!content.contains("Option<Option<Option<"), "{}", path);
+                if content.contains(" Uuid::new_v4()") || content.contains("(Uuid::new_v4()") {
+                    assert!(content.contains("use uuid::Uuid;"), "{}", path);
+                }
+
+                // Every relation leads to an entity that relates back
+                if let Some(module) = path
+                    .strip_prefix("src/models/")
+                    .and_then(|p| p.strip_suffix(".rs"))
+                {
+                    for line in content.lines() {
+                        let Some(target) = ["has_many = \"super::", "belongs_to = \"super::"]
+                            .iter()
+                            .find_map(|attr| line.split_once(attr))
+                            .and_then(|(_, rest)| rest.split_once("::Entity"))
+                            .map(|(target, _)| target)
+                        else {
+                            continue;
+                        };
+                        let back = format!("impl Related<super::{}::Entity> for Entity", module);
+                        assert!(
+                            file(&format!("src/models/{}.rs", target))
+                                .unwrap()
+                                .contains(&back),
+                            "{} template: {} relates to {}, but not back",
+                            template,
+                            module,
+                            target
+                        );
+                    }
+                }
+            }
+
+            // Every route names a handler that exists
+            if let Some(api) = file("src/routes/api.rs") {
+                for call in api
+                    .split('(')
+                    .filter_map(|s| s.split_once(')').map(|(call, _)| call))
+                {
+                    let Some((module, handler)) = call.split_once("::") else {
+                        continue;
+                    };
+                    let ident = |s: &str| s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if !ident(module) || !ident(handler) {
+                        continue;
+                    }
+                    let handlers = file(&format!("src/handlers/{}.rs", module)).unwrap();
+                    assert!(
+                        handlers.contains(&format!("pub async fn {}(", handler)),
+                        "{} template: {}::{} is routed but not generated",
+                        template,
+                        module,
+                        handler
+                    );
+                }
+            }
+
+            // `require_auth` runs as a route layer, without the router's state
+            if let Some(middleware) = file("src/auth/middleware.rs") {
+                assert!(middleware.contains("Extension(state): Extension<AppState>"));
+                assert!(
+                    file("src/routes/mod.rs")
+                        .unwrap()
+                        .contains(".layer(Extension(state.clone()))")
+                );
+            }
+        }
+    }
+
     // ── Warnings ─────────────────────────────────────────────────────────
 
     #[test]
@@ -633,7 +1566,7 @@ mod tests {
         let has_warning = output
             .warnings
             .iter()
-            .any(|w| w.contains("Secret") && w.contains("no endpoint group"));
+            .any(|w| w.message.contains("Secret") && w.message.contains("no endpoint group"));
         assert!(
             has_warning,
             "Should warn about entity without endpoints: {:?}",
@@ -652,7 +1585,7 @@ mod tests {
         let has_warning = output
             .warnings
             .iter()
-            .any(|w| w.contains("no endpoints require authentication"));
+            .any(|w| w.message.contains("no endpoints require authentication"));
         assert!(
             has_warning,
             "Should warn about auth enabled but no secured endpoints: {:?}",
@@ -660,37 +1593,676 @@ mod tests {
         );
     }
 
-    // ── Configuration flags ──────────────────────────────────────────────
-
     #[test]
-    fn test_generate_without_tests() {
-        let project = full_project();
-        let config = GeneratorConfig::new().without_tests();
-        let output = Generator::new(config).generate(&project).unwrap();
-
-        let paths: Vec<String> = output
-            .files
-            .iter()
-            .map(|f| f.path.to_string_lossy().to_string())
-            .collect();
+    fn test_generate_warns_renamed_field() {
+        let mut project = full_project();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields
+            .push(Field::new("displayName", DataType::String));
 
+        let output = Generator::with_defaults().generate(&project).unwrap();
         assert!(
-            !paths.iter().any(|p| p.starts_with("tests/")),
-            "Should not generate test files"
+            output.warnings.iter().any(|w| w
+                .message
+                .contains("'User.displayName' will be generated as 'display_name'")),
+            "Should warn about the renamed field: {:?}",
+            output.warnings
         );
     }
 
     #[test]
-    fn test_generate_without_migrations() {
-        let project = full_project();
-        let config = GeneratorConfig::new().without_migrations();
-        let output = Generator::new(config).generate(&project).unwrap();
-
-        let paths: Vec<String> = output
-            .files
-            .iter()
-            .map(|f| f.path.to_string_lossy().to_string())
-            .collect();
+    fn test_generate_warns_array_without_native_support() {
+        let mut project = full_project();
+        project.config.database = DatabaseType::SQLite;
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields.push(Field::new(
+            "tags",
+            DataType::Array(Box::new(DataType::String)),
+        ));
+
+        let output = Generator::with_defaults().generate(&project).unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("'User.tags' is an array")),
+            "Should warn about the array column: {:?}",
+            output.warnings
+        );
+    }
+
+    #[test]
+    fn test_generate_warns_decimal_on_sqlite() {
+        let mut project = full_project();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields.push(Field::new("balance", DataType::money()));
+
+        let warns = |project: &ProjectGraph| {
+            Generator::with_defaults()
+                .generate(project)
+                .unwrap()
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("'User.balance' is a Decimal(12,2)"))
+        };
+        assert!(!warns(&project));
+        project.config.database = DatabaseType::SQLite;
+        assert!(warns(&project));
+    }
+
+    #[test]
+    fn test_generate_warns_schema_on_sqlite() {
+        let mut project = full_project();
+        project.config.database = DatabaseType::SQLite;
+        let user = project.entities.values_mut().next().unwrap();
+        user.config.schema = Some("auth".to_string());
+
+        let output = Generator::with_defaults().generate(&project).unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("'User' is placed in schema 'auth'")),
+            "Should warn about the ignored schema: {:?}",
+            output.warnings
+        );
+    }
+
+    #[test]
+    fn test_generate_diesel_project() {
+        let project = full_project();
+        let config = GeneratorConfig::new().with_orm(imortal_ir::Orm::Diesel);
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert!(paths.contains(&"src/schema.rs".to_string()));
+        assert!(paths.contains(&"diesel.toml".to_string()));
+        assert!(paths.iter().any(|p| p.ends_with("_create_users/up.sql")));
+
+        let cargo = output
+            .files
+            .iter()
+            .find(|f| f.path.ends_with("Cargo.toml"))
+            .unwrap();
+        assert!(cargo.content.contains("diesel = {"));
+        assert!(!cargo.content.contains("sea-orm"));
+
+        // Lists are sortable by default, which Diesel leaves out
+        let list = output
+            .warnings
+            .iter()
+            .find(|w| w.code == WarningCode::UnsupportedByOrm)
+            .expect("should report list sorting as unsupported");
+        assert_eq!(list.severity, WarningSeverity::Info);
+    }
+
+    #[test]
+    fn test_generate_actix_project() {
+        let project = full_project();
+        let config = GeneratorConfig::new().with_framework(imortal_ir::WebFramework::ActixWeb);
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let file = |path: &str| {
+            &output
+                .files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap_or_else(|| panic!("{} not generated", path))
+                .content
+        };
+
+        let cargo = file("Cargo.toml");
+        assert!(cargo.contains("actix-web = \"4.9\""));
+        assert!(!cargo.contains("axum"));
+        assert!(!cargo.contains("tower"));
+
+        let main = file("src/main.rs");
+        assert!(main.contains(
+            "HttpServer::new(move || App::new().configure(configure_app(state.clone())))"
+        ));
+        assert!(!main.contains("axum::serve"));
+
+        assert!(file("src/error.rs").contains("impl ResponseError for AppError"));
+        assert!(file("src/routes/api.rs").contains("fn user_routes() -> Scope {"));
+
+        let handlers = file("src/handlers/user.rs");
+        assert!(handlers.contains("    state: Data<AppState>,\n"));
+        assert!(handlers.contains("    let id = id.into_inner();\n"));
+        assert!(handlers.contains("Ok(HttpResponse::Created().json(UserResponse::from(model)))"));
+        assert!(!handlers.contains("axum"));
+
+        let readme = file("README.md");
+        assert!(readme.contains("| Framework      | Actix Web        |"));
+    }
+
+    #[test]
+    fn test_generate_warns_diesel_unsupported_features() {
+        let mut project = full_project();
+        project.config.database = DatabaseType::SQLite;
+        project.config.report_delete_blockers = true;
+        project.config.background_jobs = true;
+
+        let output = Generator::new(GeneratorConfig::new().with_orm(imortal_ir::Orm::Diesel))
+            .generate(&project)
+            .unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("delete blocker reports")),
+            "Should warn about delete blockers: {:?}",
+            output.warnings
+        );
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("The project uses background jobs")),
+            "Should warn about background jobs: {:?}",
+            output.warnings
+        );
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::UnsupportedColumnType
+                    && w.message.contains("Diesel only maps UUIDs on PostgreSQL")),
+            "Should warn about UUID columns on SQLite: {:?}",
+            output.warnings
+        );
+    }
+
+    #[test]
+    fn test_generate_notes_diesel_polymorphic_helpers() {
+        let mut project = full_project();
+        let user = project.entities.keys().copied().next().unwrap();
+        let note = project.add_entity(Entity::new("Note"));
+        project
+            .create_relationship_with_fk(
+                Relationship::polymorphic(note, user, "notable").with_name("NoteUser"),
+            )
+            .unwrap();
+
+        let output = Generator::new(GeneratorConfig::new().with_orm(imortal_ir::Orm::Diesel))
+            .generate(&project)
+            .unwrap();
+        let warning = output
+            .warnings
+            .iter()
+            .find(|w| {
+                w.message
+                    .contains("Relationship 'NoteUser' uses polymorphic association helpers")
+            })
+            .expect("should note the missing helpers");
+        assert_eq!(warning.severity, WarningSeverity::Info);
+    }
+
+    #[test]
+    fn test_generate_notes_diesel_scoped_unique_checks() {
+        let mut project = full_project();
+        let user = project.entities.keys().copied().next().unwrap();
+        let mut team = Entity::new("Team");
+        team.fields.push(Field::new("slug", DataType::String));
+        let team = project.add_entity(team);
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(user, team))
+            .unwrap();
+        let team = project.get_entity_mut(team).unwrap();
+        team.fields
+            .iter_mut()
+            .find(|f| f.name == "slug")
+            .unwrap()
+            .unique_scope = Some(rel);
+
+        let output = Generator::new(GeneratorConfig::new().with_orm(imortal_ir::Orm::Diesel))
+            .generate(&project)
+            .unwrap();
+        let warning = output
+            .warnings
+            .iter()
+            .find(|w| {
+                w.message
+                    .contains("Field 'Team.slug' uses per-parent uniqueness checks")
+            })
+            .expect("should note the missing checks");
+        assert_eq!(warning.severity, WarningSeverity::Info);
+    }
+
+    #[test]
+    fn test_generate_warns_diesel_association_endpoints() {
+        let mut project = full_project();
+        let user = project.entities.keys().copied().next().unwrap();
+        let group = project.add_entity(Entity::new("Group"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(user, group, "user_groups"))
+            .unwrap();
+        project.promote_junction(rel).unwrap();
+
+        let output = Generator::new(GeneratorConfig::new().with_orm(imortal_ir::Orm::Diesel))
+            .generate(&project)
+            .unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::UnsupportedByOrm
+                    && w.message
+                        .contains("Relationship 'UserGroups' uses association endpoints")),
+            "Should warn about association endpoints: {:?}",
+            output.warnings
+        );
+        assert!(
+            !output
+                .files
+                .iter()
+                .any(|f| f.path.ends_with("user_links.rs"))
+        );
+        // The junction entity itself is still generated
+        assert!(
+            output
+                .files
+                .iter()
+                .any(|f| f.path.to_string_lossy().contains("_create_user_groups/"))
+        );
+
+        let output = Generator::new(GeneratorConfig::new())
+            .generate(&project)
+            .unwrap();
+        assert!(
+            output
+                .files
+                .iter()
+                .any(|f| f.path.ends_with("user_links.rs"))
+        );
+    }
+
+    #[test]
+    fn test_generate_graphql_only_project() {
+        let project = auth_project();
+        let config = GeneratorConfig::new().with_api_style(imortal_ir::ApiStyle::Graphql);
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        for path in [
+            "src/graphql/mod.rs",
+            "src/graphql/loaders.rs",
+            "src/graphql/user.rs",
+            "src/graphql/http.rs",
+        ] {
+            assert!(paths.iter().any(|p| p == path), "{} not generated", path);
+        }
+        assert!(!paths.iter().any(|p| p.starts_with("src/handlers/")));
+        assert!(!paths.iter().any(|p| p == "src/routes/api.rs"));
+        assert!(!paths.iter().any(|p| p == "openapi.yaml"));
+
+        let file = |path: &str| {
+            &output
+                .files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap()
+                .content
+        };
+        let lib = file("src/lib.rs");
+        assert!(lib.contains("pub mod graphql;"));
+        assert!(!lib.contains("pub mod handlers;"));
+        assert!(file("Cargo.toml").contains("async-graphql = {"));
+        assert!(file("src/error.rs").contains("impl async_graphql::ErrorExtensions for AppError"));
+        assert!(file("README.md").contains("| API            | GraphQL        |"));
+    }
+
+    #[test]
+    fn test_generate_graphql_realtime_project() {
+        let mut project = auth_project();
+        let config = GeneratorConfig::new()
+            .with_api_style(imortal_ir::ApiStyle::Graphql)
+            .with_framework(imortal_ir::WebFramework::ActixWeb);
+        let output = Generator::new(config.clone()).generate(&project).unwrap();
+        assert!(!output.files.iter().any(|f| f.path.ends_with("realtime.rs")));
+
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime = true;
+        }
+        // REST clients get a WebSocket per entity instead
+        let rest = Generator::new(GeneratorConfig::new())
+            .generate(&project)
+            .unwrap();
+        assert!(!rest.warnings.iter().any(|w| w.message.contains("realtime")));
+        let rest_file = |path: &str| {
+            &rest
+                .files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap_or_else(|| panic!("{} not generated", path))
+                .content
+        };
+        assert!(rest_file("src/realtime.rs").contains("pub fn websocket("));
+        assert!(rest_file("src/handlers/user.rs").contains("realtime::websocket(ws, \"User\")"));
+        assert!(
+            rest_file("src/routes/api.rs").contains(".route(\"/ws\", get(user::user_changes))")
+        );
+        assert!(rest_file("Cargo.toml").contains("features = [\"macros\", \"ws\"]"));
+
+        let output = Generator::new(config).generate(&project).unwrap();
+        assert!(
+            !output
+                .warnings
+                .iter()
+                .any(|w| w.message.contains("realtime"))
+        );
+        let file = |path: &str| {
+            &output
+                .files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap_or_else(|| panic!("{} not generated", path))
+                .content
+        };
+        assert!(file("src/realtime.rs").contains("pub fn change_stream("));
+        assert!(file("src/lib.rs").contains("pub mod realtime;"));
+        assert!(file("Cargo.toml").contains("futures-util = \"0.3\""));
+        assert!(file("src/graphql/user.rs").contains("async fn user_changed("));
+        assert!(file("src/graphql/http.rs").contains(
+            ".on_connection_init(move |init| connection_data(init, claims, jwt_secret))"
+        ));
+    }
+
+    #[test]
+    fn test_generate_rest_and_graphql_project() {
+        let project = full_project();
+        let config = GeneratorConfig::new()
+            .with_api_style(imortal_ir::ApiStyle::Both)
+            .with_framework(imortal_ir::WebFramework::ActixWeb);
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert!(paths.iter().any(|p| p == "src/handlers/user.rs"));
+        assert!(paths.iter().any(|p| p == "src/graphql/user.rs"));
+        assert!(paths.iter().any(|p| p == "openapi.yaml"));
+
+        let routes = &output
+            .files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/mod.rs")
+            .unwrap()
+            .content;
+        assert!(routes.contains(".configure(crate::graphql::http::configure(schema))"));
+    }
+
+    #[test]
+    fn test_generate_graphql_falls_back_to_rest() {
+        let project = full_project();
+        let output = Generator::new(
+            GeneratorConfig::new()
+                .with_orm(imortal_ir::Orm::Diesel)
+                .with_api_style(imortal_ir::ApiStyle::Graphql),
+        )
+        .generate(&project)
+        .unwrap();
+
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::UnsupportedByOrm
+                    && w.message.contains("the GraphQL API")),
+            "Should warn about GraphQL on Diesel: {:?}",
+            output.warnings
+        );
+        assert!(
+            output
+                .files
+                .iter()
+                .any(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+        );
+        assert!(
+            !output
+                .files
+                .iter()
+                .any(|f| f.path.starts_with("src/graphql"))
+        );
+    }
+
+    #[test]
+    fn test_generate_warns_unindexed_nested_fk() {
+        let mut project = full_project();
+        let user_id = project.entities.values().next().unwrap().id;
+        let post_id = project.add_entity(Entity::new("Post"));
+        let (_, fk_id) = project
+            .create_relationship_with_fk(imortal_ir::Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        let post = project.get_entity_mut(post_id).unwrap();
+        post.get_field_mut(fk_id.unwrap()).unwrap().indexed = false;
+        let unindexed = |output: &GeneratedProject| {
+            output
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::UnindexedNestedForeignKey)
+        };
+
+        // Indexed anyway under the default policy
+        let output = Generator::with_defaults().generate(&project).unwrap();
+        assert!(!unindexed(&output), "{:?}", output.warnings);
+
+        project.config.index_foreign_keys = false;
+        let output = Generator::with_defaults().generate(&project).unwrap();
+        assert!(
+            output.warnings.iter().any(|w| w
+                .message
+                .contains("'Post.user_id' is used by nested endpoints")),
+            "Should warn about the unindexed foreign key: {:?}",
+            output.warnings
+        );
+        assert!(unindexed(&output));
+    }
+
+    // ── Strict mode ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_strict_mode_fails_on_warnings() {
+        let project = empty_project();
+        let config = GeneratorConfig::new().fail_on_warnings();
+
+        let err = Generator::new(config).generate(&project).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("strict mode"), "{}", message);
+        assert!(message.contains("No entities defined"), "{}", message);
+    }
+
+    /// A project strict mode accepts
+    fn strict_clean_project() -> ProjectGraph {
+        let mut project = auth_project();
+        for entity in project.entities.values_mut() {
+            for field in &mut entity.fields {
+                if field.looks_sensitive() {
+                    field.apply_secret_recommendations();
+                }
+            }
+        }
+        project
+    }
+
+    /// `project` without the last field of its first entity
+    fn drop_last_field(project: &ProjectGraph) -> ProjectGraph {
+        let mut project = project.clone();
+        let entity = project.entities.values_mut().next().unwrap();
+        entity.fields.pop().unwrap();
+        project
+    }
+
+    #[test]
+    fn test_strict_mode_passes_clean_project() {
+        let project = strict_clean_project();
+        let config = GeneratorConfig::new().fail_on_warnings();
+
+        let result = Generator::new(config).generate(&project);
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_destructive_migration() {
+        let baseline = strict_clean_project();
+        let project = drop_last_field(&baseline);
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new()
+            .with_output_dir(dir.path())
+            .with_baseline(baseline)
+            .fail_on_warnings();
+
+        let message = Generator::new(config)
+            .generate_and_write(&project)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("strict mode"), "{}", message);
+        assert!(
+            message.contains("existing data there will be lost"),
+            "{}",
+            message
+        );
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_staged_migration() {
+        let mut baseline = strict_clean_project();
+        baseline.config.safe_migrations = true;
+        let project = drop_last_field(&baseline);
+        let config = GeneratorConfig::new()
+            .with_baseline(baseline.clone())
+            .fail_on_warnings();
+
+        let message = Generator::new(config)
+            .generate(&project)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("is staged"), "{}", message);
+
+        // Without strict mode the staged migration is only reported
+        let config = GeneratorConfig::new().with_baseline(baseline);
+        let output = Generator::new(config).generate(&project).unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .any(|w| w.code == WarningCode::StagedMigration)
+        );
+    }
+
+    #[test]
+    fn test_strict_mode_fails_on_edited_file() {
+        let project = strict_clean_project();
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new().with_output_dir(dir.path());
+        let output = Generator::new(config.clone())
+            .generate_and_write(&project)
+            .unwrap();
+
+        let path = dir.path().join(&output.files[0].path);
+        std::fs::write(&path, "// edited by hand\n").unwrap();
+        let message = Generator::new(config.fail_on_warnings())
+            .generate_and_write(&project)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            message.contains("was edited since it was generated"),
+            "{}",
+            message
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "// edited by hand\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_refuses_incompatible_options() {
+        let mut project = full_project();
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+
+        // The override is what gets checked, not the saved API style
+        let err =
+            Generator::new(GeneratorConfig::new().with_api_style(imortal_ir::ApiStyle::Graphql))
+                .generate(&project)
+                .unwrap_err()
+                .to_string();
+        assert!(
+            err.contains("incompatible options: 1 problem(s)"),
+            "{}",
+            err
+        );
+        assert!(err.contains("[config.api_style]"), "{}", err);
+        assert!(err.contains("help: Set the API style to"), "{}", err);
+
+        assert!(
+            Generator::new(GeneratorConfig::new().with_api_style(imortal_ir::ApiStyle::Both))
+                .generate(&project)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_generate_refuses_fullstack_workspace_member() {
+        let mut project = full_project();
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+
+        let err = Generator::new(GeneratorConfig::new().as_workspace_member())
+            .generate(&project)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Cargo workspace of its own"), "{}", err);
+    }
+
+    #[test]
+    fn test_strict_report_lists_every_issue() {
+        let report = strict_report(&["first".to_string(), "second".to_string()]);
+        assert!(report.contains("2 warning(s)"));
+        assert!(report.contains("  1. first"));
+        assert!(report.contains("  2. second"));
+    }
+
+    // ── Configuration flags ──────────────────────────────────────────────
+
+    #[test]
+    fn test_generate_without_tests() {
+        let project = full_project();
+        let config = GeneratorConfig::new().without_tests();
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+
+        assert!(
+            !paths.iter().any(|p| p.starts_with("tests/")),
+            "Should not generate test files"
+        );
+    }
+
+    #[test]
+    fn test_generate_without_migrations() {
+        let project = full_project();
+        let config = GeneratorConfig::new().without_migrations();
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
 
         assert!(
             !paths.iter().any(|p| p.starts_with("migrations/")),
@@ -698,6 +2270,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_with_docker() {
+        let project = full_project();
+        let paths = |config: GeneratorConfig| -> Vec<String> {
+            Generator::new(config)
+                .generate(&project)
+                .unwrap()
+                .files
+                .iter()
+                .map(|f| f.path.to_string_lossy().to_string())
+                .collect()
+        };
+
+        let without = paths(GeneratorConfig::new());
+        assert!(!without.iter().any(|p| p == "Dockerfile"));
+
+        let with = paths(GeneratorConfig::new().with_docker());
+        assert!(with.iter().any(|p| p == "Dockerfile"));
+        assert!(with.iter().any(|p| p == "docker-compose.yml"));
+        assert!(with.iter().any(|p| p == ".dockerignore"));
+    }
+
+    #[test]
+    fn test_generate_with_load_tests() {
+        let mut project = full_project();
+        let paths = |project: &ProjectGraph, config: GeneratorConfig| -> Vec<String> {
+            Generator::new(config)
+                .generate(project)
+                .unwrap()
+                .files
+                .iter()
+                .map(|f| f.path.to_string_lossy().to_string())
+                .collect()
+        };
+
+        let without = paths(&project, GeneratorConfig::new());
+        assert!(!without.iter().any(|p| p == "loadtest/k6.js"));
+
+        let with = paths(&project, GeneratorConfig::new().with_load_tests());
+        assert!(with.iter().any(|p| p == "loadtest/k6.js"));
+
+        // Lives with the backend it calls
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+        let fullstack = paths(&project, GeneratorConfig::new().with_load_tests());
+        assert!(fullstack.iter().any(|p| p == "backend/loadtest/k6.js"));
+    }
+
+    #[test]
+    fn test_generate_with_client_sdk() {
+        let mut project = full_project();
+        let paths = |project: &ProjectGraph, config: GeneratorConfig| -> Vec<String> {
+            Generator::new(config)
+                .generate(project)
+                .unwrap()
+                .files
+                .iter()
+                .map(|f| f.path.to_string_lossy().to_string())
+                .collect()
+        };
+
+        let without = paths(&project, GeneratorConfig::new());
+        assert!(!without.iter().any(|p| p.starts_with("client/")));
+
+        let with = paths(&project, GeneratorConfig::new().with_client_sdk());
+        assert!(with.iter().any(|p| p == "client/Cargo.toml"));
+        assert!(with.iter().any(|p| p == "client/src/client.rs"));
+        let readme = Generator::new(GeneratorConfig::new().with_client_sdk())
+            .generate(&project)
+            .unwrap()
+            .files
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "README.md")
+            .unwrap();
+        assert!(readme.content.contains("## Client SDK"));
+
+        // A crate of its own next to the workspace members
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+        let fullstack = paths(&project, GeneratorConfig::new().with_client_sdk());
+        assert!(fullstack.iter().any(|p| p == "client/Cargo.toml"));
+        assert!(!fullstack.iter().any(|p| p.starts_with("backend/client/")));
+    }
+
+    #[test]
+    fn test_generate_with_typescript_client() {
+        let mut project = full_project();
+        let has_ts_client = |project: &ProjectGraph| {
+            Generator::new(GeneratorConfig::new())
+                .generate(project)
+                .unwrap()
+                .files
+                .iter()
+                .any(|f| f.path.to_string_lossy() == "frontend/ts-client/client.ts")
+        };
+
+        assert!(!has_ts_client(&project));
+
+        project.config.typescript_client = true;
+        assert!(has_ts_client(&project));
+        let readme = Generator::new(GeneratorConfig::new())
+            .generate(&project)
+            .unwrap()
+            .files
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "README.md")
+            .unwrap();
+        assert!(readme.content.contains("## TypeScript Client"));
+
+        // Next to the Dioxus frontend rather than under backend/
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+        assert!(has_ts_client(&project));
+    }
+
+    #[test]
+    fn test_generate_with_seeds() {
+        let project = full_project();
+        let output = Generator::new(GeneratorConfig::new().with_seeds())
+            .generate(&project)
+            .unwrap();
+        let file = |path: &str| {
+            output
+                .files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .map(|f| f.content.as_str())
+        };
+
+        assert!(file("src/bin/seed.rs").is_some());
+        assert!(file("seeds/001_users.sql").is_some());
+        assert!(
+            file("Cargo.toml")
+                .unwrap()
+                .contains("default-run = \"my_app\"")
+        );
+        assert!(file("README.md").unwrap().contains("## Seed Data"));
+    }
+
     #[test]
     fn test_generate_without_docs() {
         let project = full_project();
@@ -789,6 +2497,444 @@ mod tests {
         // Test Display trait implementation
         let formatted = format!("{}", summary);
         assert!(formatted.contains("Code Generation Complete"));
+        assert!(!formatted.contains("Hooks:"));
+    }
+
+    #[test]
+    fn test_generation_summary_reports_hook_failures() {
+        let mut output = GeneratedProject::new("test");
+        output.hook_outcomes = vec![
+            crate::HookOutcome {
+                hook: "rustfmt".to_string(),
+                file: None,
+                status: crate::HookStatus::Succeeded,
+                stdout: String::new(),
+                stderr: String::new(),
+                duration: std::time::Duration::ZERO,
+            },
+            crate::HookOutcome {
+                hook: "sqlfluff fix".to_string(),
+                file: Some("migrations/001.sql".into()),
+                status: crate::HookStatus::Failed(Some(1)),
+                stdout: String::new(),
+                stderr: "L010: keywords must be upper case\n".to_string(),
+                duration: std::time::Duration::ZERO,
+            },
+        ];
+
+        let summary = GenerationSummary::from_project(&output);
+        let display = summary.display();
+
+        assert_eq!(summary.hooks_run, 2);
+        assert_eq!(summary.hook_failures.len(), 1);
+        assert!(display.contains("1 failed"));
+        assert!(display.contains("sqlfluff fix (migrations/001.sql): exit code 1"));
+        assert!(display.contains("L010: keywords must be upper case"));
+    }
+
+    #[test]
+    fn test_generation_summary_reports_time() {
+        let mut output = GeneratedProject::new("test");
+        let summary = GenerationSummary::from_project(&output);
+        assert!(!summary.display().contains("Time:"));
+
+        output.timings = GenerationTimings {
+            stages: Vec::new(),
+            formatting: Some(Duration::from_millis(120)),
+            total: Duration::from_millis(1500),
+        };
+        let display = GenerationSummary::from_project(&output).display();
+        assert!(display.contains("Time:        1.5 s (rustfmt 120 ms)"));
+    }
+
+    // ── Stages and formatting ────────────────────────────────────────────
+
+    #[test]
+    fn test_run_stages_keeps_stage_order() {
+        let (files, timings) = run_stages([
+            (
+                "slow",
+                Box::new(|| {
+                    std::thread::sleep(Duration::from_millis(50));
+                    vec![GeneratedFile::rust("slow.rs", "")]
+                }),
+            ),
+            ("empty", Box::new(Vec::new)),
+            (
+                "fast",
+                Box::new(|| {
+                    vec![
+                        GeneratedFile::rust("a.rs", ""),
+                        GeneratedFile::rust("b.rs", ""),
+                    ]
+                }),
+            ),
+        ]);
+
+        let [slow, empty, fast] = files;
+        assert_eq!(slow[0].path, std::path::PathBuf::from("slow.rs"));
+        assert!(empty.is_empty());
+        assert_eq!(fast.len(), 2);
+
+        let names: Vec<_> = timings.iter().map(|t| (t.stage, t.files)).collect();
+        assert_eq!(names, [("slow", 1), ("empty", 0), ("fast", 2)]);
+        assert!(timings[0].duration >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_generate_reports_stage_timings() {
+        let config = GeneratorConfig::new().without_formatting();
+        let output = Generator::new(config).generate(&full_project()).unwrap();
+        let timings = &output.timings;
+
+        assert_eq!(timings.stages[0].stage, "rust");
+        assert_eq!(timings.stages[1].stage, "migrations");
+        assert_eq!(timings.stages.last().unwrap().stage, "docker");
+        let staged: usize = timings.stages.iter().map(|s| s.files).sum();
+        assert_eq!(staged, output.file_count());
+        assert!(timings.formatting.is_none());
+        assert!(timings.total >= timings.slowest_stage().unwrap().duration);
+    }
+
+    #[test]
+    fn test_generate_formats_rust_files() {
+        if std::process::Command::new("rustfmt")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+        let mut project = auth_project();
+        project.config.project_type = imortal_ir::ProjectType::Fullstack;
+        let config = GeneratorConfig::new().with_client_sdk().with_seeds();
+
+        let output = Generator::new(config.clone()).generate(&project).unwrap();
+
+        // Every generated Rust file parses
+        let unformatted: Vec<_> = output
+            .warnings
+            .iter()
+            .filter(|w| w.code == WarningCode::Unformatted)
+            .map(|w| w.message.as_str())
+            .collect();
+        assert!(unformatted.is_empty(), "{unformatted:#?}");
+        assert!(output.timings.formatting.is_some());
+
+        let unformatted = Generator::new(config.without_formatting())
+            .generate(&project)
+            .unwrap();
+        assert_eq!(unformatted.file_count(), output.file_count());
+        assert!(
+            output
+                .files
+                .iter()
+                .zip(&unformatted.files)
+                .all(|(a, b)| a.path == b.path)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_generate_and_write_runs_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new()
+            .with_output_dir(dir.path())
+            .with_hook(crate::PostHook::once("touch hooked"))
+            .with_option("hook.toml", "test -s");
+
+        let output = Generator::new(config)
+            .generate_and_write(&full_project())
+            .unwrap();
+
+        assert!(dir.path().join("hooked").exists());
+        assert!(output.hook_outcomes.len() >= 2);
+        assert!(output.hook_outcomes.iter().all(|o| o.succeeded()));
+    }
+
+    // ── Dry run ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_plan_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let output_dir = dir.path().join("out");
+        let config = GeneratorConfig::new().with_output_dir(&output_dir);
+
+        let plan = Generator::new(config).plan(&full_project()).unwrap();
+
+        assert!(!output_dir.exists());
+        assert_eq!(plan.manifest.len(), plan.project.file_count());
+        assert!(
+            plan.manifest
+                .iter()
+                .all(|e| e.status == crate::FileStatus::Added && !e.conflict)
+        );
+        let main = plan
+            .manifest
+            .iter()
+            .find(|e| e.path == std::path::Path::new("src/main.rs"))
+            .unwrap();
+        assert!(main.size > 0);
+        assert_eq!(main.file_type, FileType::Rust);
+    }
+
+    #[test]
+    fn test_plan_detects_conflicts_unless_overwriting() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new().with_output_dir(dir.path());
+        let project = full_project();
+        Generator::new(config.clone())
+            .generate_and_write(&project)
+            .unwrap();
+        std::fs::write(dir.path().join("src/main.rs"), "// edited\n").unwrap();
+
+        let plan = Generator::new(config.clone()).plan(&project).unwrap();
+        let conflicts: Vec<_> = plan.conflicts().map(|e| e.path.clone()).collect();
+        assert_eq!(conflicts, [std::path::PathBuf::from("src/main.rs")]);
+        assert!(plan.summary().ends_with("; 1 conflict"));
+        assert_eq!(plan.preview.count(crate::FileStatus::Changed), 1);
+
+        let plan = Generator::new(config.allow_overwrite())
+            .plan(&project)
+            .unwrap();
+        assert!(!plan.has_conflicts());
+        assert_eq!(plan.write(&HashSet::new()).unwrap(), 1);
+        assert!(
+            !std::fs::read_to_string(dir.path().join("src/main.rs"))
+                .unwrap()
+                .contains("// edited")
+        );
+    }
+
+    #[test]
+    fn test_generate_and_write_keeps_edited_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new().with_output_dir(dir.path());
+        let project = full_project();
+        Generator::new(config.clone())
+            .generate_and_write(&project)
+            .unwrap();
+        assert!(dir.path().join(crate::MANIFEST_FILE).exists());
+
+        let main = dir.path().join("src/main.rs");
+        let model = dir.path().join("src/models/user.rs");
+        let old_model = std::fs::read_to_string(&model).unwrap();
+        std::fs::write(&main, "// edited\n").unwrap();
+
+        // Output changes everywhere; only the edited file is left alone
+        let mut config = config;
+        config.generate_docs = false;
+        for _ in 0..2 {
+            let output = Generator::new(config.clone())
+                .generate_and_write(&project)
+                .unwrap();
+            let edited: Vec<_> = output
+                .warnings
+                .iter()
+                .filter(|w| w.code == WarningCode::UserModified)
+                .map(|w| w.message.as_str())
+                .collect();
+            assert_eq!(
+                edited,
+                ["'src/main.rs' was edited since it was generated and was not overwritten."]
+            );
+            assert_eq!(std::fs::read_to_string(&main).unwrap(), "// edited\n");
+            assert_ne!(std::fs::read_to_string(&model).unwrap(), old_model);
+        }
+
+        let output = Generator::new(config.allow_overwrite())
+            .generate_and_write(&project)
+            .unwrap();
+        assert!(
+            output
+                .warnings
+                .iter()
+                .all(|w| w.code != WarningCode::UserModified)
+        );
+        assert_ne!(std::fs::read_to_string(&main).unwrap(), "// edited\n");
+    }
+
+    // ── Template overrides ───────────────────────────────────────────────
+
+    #[test]
+    fn test_templates_dir_overrides_builtin_templates() {
+        let templates = tempfile::tempdir().unwrap();
+        let deploy = templates.path().join("deploy");
+        std::fs::create_dir_all(&deploy).unwrap();
+        std::fs::write(
+            deploy.join("Dockerfile.jinja"),
+            "FROM scratch\nCOPY {{ package }} /\nEXPOSE {{ port }}\n",
+        )
+        .unwrap();
+        std::fs::write(deploy.join("Dockerfil.jinja"), "typo\n").unwrap();
+
+        let config = GeneratorConfig::new()
+            .with_docker()
+            .with_templates_dir(templates.path());
+        let output = Generator::new(config).generate(&full_project()).unwrap();
+
+        let dockerfile = output
+            .files
+            .iter()
+            .find(|f| f.path.to_str() == Some("Dockerfile"))
+            .unwrap();
+        assert_eq!(
+            dockerfile.content,
+            "FROM scratch\nCOPY my_app /\nEXPOSE 8080\n"
+        );
+        // Templates it does not override keep their built-in version
+        assert!(
+            output
+                .files
+                .iter()
+                .any(|f| f.path.to_str() == Some("docker-compose.yml")
+                    && f.content.contains("services:"))
+        );
+
+        let unused: Vec<_> = output
+            .warnings
+            .iter()
+            .filter(|w| w.code == WarningCode::UnusedTemplate)
+            .collect();
+        assert_eq!(unused.len(), 1);
+        assert!(unused[0].message.contains("'deploy/Dockerfil.jinja'"));
+    }
+
+    #[test]
+    fn test_templates_dir_overrides_source_templates() {
+        let templates = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(templates.path().join("rust")).unwrap();
+        std::fs::create_dir_all(templates.path().join("migrations")).unwrap();
+        std::fs::write(
+            templates.path().join("rust/model.rs.jinja"),
+            crate::templates::builtin_source("rust/model.rs.jinja")
+                .unwrap()
+                .replace(
+                    "impl ActiveModelBehavior for ActiveModel {}",
+                    "// Rows of `{{ table }}`\nimpl ActiveModelBehavior for ActiveModel {}",
+                ),
+        )
+        .unwrap();
+        std::fs::write(
+            templates.path().join("migrations/migration.sql.jinja"),
+            crate::templates::builtin_source("migrations/migration.sql.jinja")
+                .unwrap()
+                .replace(
+                    "-- Generated by",
+                    "-- Reviewed by the DBA team\n-- Generated by",
+                ),
+        )
+        .unwrap();
+
+        let project = full_project();
+        let builtin = Generator::new(GeneratorConfig::new())
+            .generate(&project)
+            .unwrap();
+        let config = GeneratorConfig::new().with_templates_dir(templates.path());
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let file = |output: &GeneratedProject, path: &str| {
+            output
+                .files
+                .iter()
+                .find(|f| f.path.to_str() == Some(path))
+                .unwrap()
+                .content
+                .clone()
+        };
+        assert!(file(&output, "src/models/user.rs").contains("// Rows of `users`\n"));
+        assert!(!file(&builtin, "src/models/user.rs").contains("// Rows of"));
+        let migrations: Vec<_> = output
+            .files
+            .iter()
+            .filter(|f| f.file_type == FileType::Sql)
+            .collect();
+        assert!(!migrations.is_empty());
+        assert!(
+            migrations
+                .iter()
+                .all(|f| f.content.contains("-- Reviewed by the DBA team\n"))
+        );
+        // The other templates keep their built-in version
+        for path in [
+            "src/handlers/user.rs",
+            "src/routes/mod.rs",
+            "src/routes/api.rs",
+        ] {
+            assert_eq!(file(&output, path), file(&builtin, path), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_broken_template_fails_generation() {
+        let templates = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(templates.path().join("scaffold")).unwrap();
+        std::fs::write(
+            templates.path().join("scaffold/env.example.jinja"),
+            "PORT={{ server_port }}\n",
+        )
+        .unwrap();
+
+        let config = GeneratorConfig::new().with_templates_dir(templates.path());
+        let error = Generator::new(config)
+            .generate(&full_project())
+            .unwrap_err();
+        assert!(
+            matches!(&error, EngineError::TemplateRender { template, .. }
+                if template.ends_with("env.example.jinja")),
+            "{error}"
+        );
+
+        let config = GeneratorConfig::new().with_templates_dir(templates.path().join("missing"));
+        assert!(Generator::new(config).generate(&full_project()).is_err());
+    }
+
+    // ── Protected regions ────────────────────────────────────────────────
+
+    #[test]
+    fn test_regeneration_keeps_protected_regions() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GeneratorConfig::new().with_output_dir(dir.path());
+        let generator = Generator::new(config);
+        let project = full_project();
+        generator.generate_and_write(&project).unwrap();
+
+        let model = dir.path().join("src/models/user.rs");
+        let content = std::fs::read_to_string(&model).unwrap();
+        let custom = "fn shout(name: &str) -> String {\n    name.to_uppercase()\n}\n";
+        let content = content.replace(
+            "survives regeneration\n",
+            &format!("survives regeneration\n{custom}"),
+        );
+        std::fs::write(
+            &model,
+            format!("{content}// <immortal:keep helpers>\nconst X: u8 = 1;\n// </immortal:keep>\n"),
+        )
+        .unwrap();
+
+        let output = generator.generate_and_write(&project).unwrap();
+
+        let content = std::fs::read_to_string(&model).unwrap();
+        assert!(content.contains(custom));
+        assert!(
+            content
+                .ends_with("// <immortal:keep helpers>\nconst X: u8 = 1;\n// </immortal:keep>\n")
+        );
+        let relocated: Vec<_> = output
+            .warnings
+            .iter()
+            .filter(|w| w.code == WarningCode::RelocatedRegion)
+            .collect();
+        assert_eq!(relocated.len(), 1);
+        assert!(
+            relocated[0]
+                .message
+                .contains("'helpers' in 'src/models/user.rs'")
+        );
+
+        // Nothing left to write
+        let plan = generator.plan(&project).unwrap();
+        assert!(!plan.preview.has_changes(), "{}", plan.summary());
     }
 
     // ── Multiple entities ────────────────────────────────────────────────
@@ -850,7 +2996,7 @@ mod tests {
         assert!(
             paths
                 .iter()
-                .filter(|p| p.starts_with("migrations/"))
+                .filter(|p| p.starts_with("migrations/") && p.ends_with(".sql"))
                 .count()
                 == 3,
             "Should have 3 migration files"
@@ -860,7 +3006,7 @@ mod tests {
         let has_warning = output
             .warnings
             .iter()
-            .any(|w| w.contains("Category") && w.contains("no endpoint group"));
+            .any(|w| w.message.contains("Category") && w.message.contains("no endpoint group"));
         assert!(has_warning, "Should warn about Category");
     }
 
@@ -970,7 +3116,7 @@ mod tests {
             .find(|f| f.path.to_string_lossy() == "src/routes/api.rs")
             .expect("Should have src/routes/api.rs");
 
-        assert!(api_routes.content.contains("/api/users"));
+        assert!(api_routes.content.contains("constants::USER_PATH"));
         assert!(api_routes.content.contains("user::create_user"));
         assert!(api_routes.content.contains("user::list_users"));
         assert!(api_routes.content.contains("user::get_user"));
@@ -1075,4 +3221,110 @@ mod tests {
 
         assert!(env.content.contains("sqlite://"));
     }
+
+    #[test]
+    fn test_generate_incremental_migration_from_baseline() {
+        let baseline = full_project();
+        let output = Generator::with_defaults().generate(&baseline).unwrap();
+        let snapshot = output
+            .files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == migrations::SCHEMA_SNAPSHOT_FILE)
+            .expect("schema snapshot is written with the migrations");
+        let restored =
+            imortal_ir::serialization::load_project_from_string(&snapshot.content).unwrap();
+
+        let mut project = baseline.clone();
+        let user = project
+            .entities
+            .values_mut()
+            .find(|e| e.name == "User")
+            .unwrap();
+        user.fields.push(Field::new("bio", DataType::Text));
+
+        let config = GeneratorConfig::default().with_baseline(restored);
+        let output = Generator::new(config).generate(&project).unwrap();
+        let sql: Vec<_> = output
+            .files
+            .iter()
+            .filter(|f| f.path.to_string_lossy().ends_with(".sql"))
+            .collect();
+
+        assert_eq!(sql.len(), 1, "only the alter migration is emitted");
+        assert!(sql[0].path.to_string_lossy().ends_with("_alter_schema.sql"));
+        assert!(
+            sql[0]
+                .content
+                .contains("ALTER TABLE \"users\" ADD COLUMN \"bio\" TEXT;")
+        );
+        assert!(
+            output
+                .files
+                .iter()
+                .any(|f| f.path.to_string_lossy() == migrations::SCHEMA_SNAPSHOT_FILE)
+        );
+    }
+
+    #[test]
+    fn test_generate_expands_field_sets() {
+        let mut project = full_project();
+        let auditable = FieldSet::auditable();
+        let set_id = auditable.id;
+        project.add_field_set(auditable);
+        let user = project
+            .entities
+            .values_mut()
+            .find(|e| e.name == "User")
+            .unwrap();
+        user.include_field_set(set_id);
+
+        let output = Generator::with_defaults().generate(&project).unwrap();
+        let sql = output
+            .files
+            .iter()
+            .find(|f| f.path.to_string_lossy().ends_with(".sql"))
+            .unwrap();
+        assert!(sql.content.contains("\"created_by\" UUID"));
+
+        // A field added to the set reaches every entity including it
+        let mut edited = project.clone();
+        let set = edited.field_sets.get_mut(&set_id).unwrap();
+        set.fields.push(Field::new("deleted_by", DataType::Uuid));
+
+        let config = GeneratorConfig::default().with_baseline(project);
+        let output = Generator::new(config).generate(&edited).unwrap();
+        let sql = output
+            .files
+            .iter()
+            .find(|f| f.path.to_string_lossy().ends_with("_alter_schema.sql"))
+            .unwrap();
+        assert!(sql.content.contains("ADD COLUMN \"deleted_by\""));
+        assert!(!sql.content.contains("created_by"));
+    }
+
+    #[test]
+    fn test_incremental_migration_warns_about_dropped_columns() {
+        let baseline = full_project();
+        let mut project = baseline.clone();
+        let user = project
+            .entities
+            .values_mut()
+            .find(|e| e.name == "User")
+            .unwrap();
+        let dropped = user.fields.pop().unwrap();
+
+        let config = GeneratorConfig::default().with_baseline(baseline);
+        let output = Generator::new(config).generate(&project).unwrap();
+
+        let warning = output
+            .warnings
+            .iter()
+            .find(|w| w.code == WarningCode::DestructiveMigration)
+            .expect("dropping a column is flagged");
+        assert!(
+            warning
+                .message
+                .contains(&format!("column users.{}", dropped.name))
+        );
+    }
 }
@@ -0,0 +1,506 @@
+//! # Post-Generation Hooks
+//!
+//! Commands run over the generated output once it has been written to disk,
+//! for organisation-specific post-processing (`sqlfluff fix`, licence header
+//! scripts, …) without forking the generator.
+//!
+//! A hook either runs **once** per generation, in the output directory, or
+//! **per file** of one [`FileType`]. Per-file hooks receive the file's path
+//! relative to the output directory: wherever `{file}` appears in the
+//! arguments, or appended as the last argument otherwise.
+//!
+//! Every run is bounded by the hook's timeout; a hook that overruns is
+//! killed. Exit status and captured output are returned as
+//! [`HookOutcome`]s and reported in the generation summary. A failing hook
+//! never fails generation — the files are already written.
+//!
+//! ## Declaring Hooks
+//!
+//! ```rust,ignore
+//! use imortal_codegen::{FileType, GeneratorConfig, PostHook};
+//!
+//! let config = GeneratorConfig::new()
+//!     .with_hook(PostHook::per_file(FileType::Sql, "sqlfluff fix --dialect postgres"))
+//!     .with_hook(PostHook::once("./scripts/add-headers.sh"));
+//! ```
+//!
+//! or through options, which is how front-ends without typed access pass
+//! them: `hook` runs once, `hook.<ext>` runs per file with that extension
+//! (`hook.sql`, `hook.rs`, …) and `hook_timeout_secs` sets their timeout.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::{FileType, GeneratedFile, GeneratorConfig};
+
+/// Timeout for hooks that do not set their own.
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Placeholder replaced by the file path in per-file hook arguments.
+pub const FILE_PLACEHOLDER: &str = "{file}";
+
+// ============================================================================
+// PostHook
+// ============================================================================
+
+/// What a hook runs over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookTarget {
+    /// Once per generation, in the output directory
+    Once,
+    /// Once for every generated file of this type
+    Files(FileType),
+}
+
+/// A command run after the generated files are written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostHook {
+    /// Name shown in the summary (the command line by default)
+    pub name: String,
+
+    /// Program to run
+    pub program: String,
+
+    /// Arguments, possibly containing [`FILE_PLACEHOLDER`]
+    pub args: Vec<String>,
+
+    /// What the hook runs over
+    pub target: HookTarget,
+
+    /// How long one run may take before it is killed
+    pub timeout: Duration,
+}
+
+impl PostHook {
+    /// A hook run once per generation.
+    ///
+    /// The command line is split on whitespace; no shell is involved, so
+    /// wrap it in `sh -c` to use pipes or globs.
+    pub fn once(command: &str) -> Self {
+        Self::new(command, HookTarget::Once)
+    }
+
+    /// A hook run once for every generated file of `file_type`.
+    pub fn per_file(file_type: FileType, command: &str) -> Self {
+        Self::new(command, HookTarget::Files(file_type))
+    }
+
+    fn new(command: &str, target: HookTarget) -> Self {
+        let mut parts = command.split_whitespace().map(str::to_string);
+        Self {
+            name: command.trim().to_string(),
+            program: parts.next().unwrap_or_default(),
+            args: parts.collect(),
+            target,
+            timeout: DEFAULT_HOOK_TIMEOUT,
+        }
+    }
+
+    /// Set the name shown in the summary
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the per-run timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Arguments for one run, with the file path filled in.
+    fn args_for(&self, file: Option<&Path>) -> Vec<String> {
+        let Some(file) = file else {
+            return self.args.clone();
+        };
+
+        let path = file.to_string_lossy();
+        if self.args.iter().any(|a| a.contains(FILE_PLACEHOLDER)) {
+            self.args
+                .iter()
+                .map(|a| a.replace(FILE_PLACEHOLDER, &path))
+                .collect()
+        } else {
+            let mut args = self.args.clone();
+            args.push(path.into_owned());
+            args
+        }
+    }
+}
+
+// ============================================================================
+// HookOutcome
+// ============================================================================
+
+/// How a single hook run ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookStatus {
+    /// Exited with status 0
+    Succeeded,
+    /// Exited with a non-zero status (`None` if killed by a signal)
+    Failed(Option<i32>),
+    /// Killed after exceeding its timeout
+    TimedOut,
+    /// Could not be started
+    NotStarted(String),
+}
+
+/// The result of one hook run
+#[derive(Debug, Clone)]
+pub struct HookOutcome {
+    /// Hook name
+    pub hook: String,
+
+    /// File the hook ran on (per-file hooks only)
+    pub file: Option<PathBuf>,
+
+    /// How the run ended
+    pub status: HookStatus,
+
+    /// Captured standard output
+    pub stdout: String,
+
+    /// Captured standard error
+    pub stderr: String,
+
+    /// Wall-clock time taken
+    pub duration: Duration,
+}
+
+impl HookOutcome {
+    /// Whether the run exited successfully
+    pub fn succeeded(&self) -> bool {
+        self.status == HookStatus::Succeeded
+    }
+
+    /// One-line description, e.g. "sqlfluff fix (migrations/001.sql): exit code 1"
+    pub fn describe(&self) -> String {
+        let target = match &self.file {
+            Some(file) => format!("{} ({})", self.hook, file.display()),
+            None => self.hook.clone(),
+        };
+        let status = match &self.status {
+            HookStatus::Succeeded => "ok".to_string(),
+            HookStatus::Failed(Some(code)) => format!("exit code {}", code),
+            HookStatus::Failed(None) => "terminated by signal".to_string(),
+            HookStatus::TimedOut => format!("timed out after {:.1}s", self.duration.as_secs_f64()),
+            HookStatus::NotStarted(reason) => format!("could not start: {}", reason),
+        };
+        format!("{}: {}", target, status)
+    }
+
+    /// Output worth showing for a failed run: stderr, or stdout if stderr
+    /// is empty, trimmed to its last `max_lines` lines.
+    pub fn output_tail(&self, max_lines: usize) -> Vec<&str> {
+        let output = if self.stderr.trim().is_empty() {
+            &self.stdout
+        } else {
+            &self.stderr
+        };
+        let lines: Vec<&str> = output.lines().filter(|l| !l.trim().is_empty()).collect();
+        lines[lines.len().saturating_sub(max_lines)..].to_vec()
+    }
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// All hooks for a run: those added with
+/// [`GeneratorConfig::with_hook`], then those declared in options.
+pub fn configured_hooks(config: &GeneratorConfig) -> Vec<PostHook> {
+    let mut hooks = config.hooks.clone();
+    hooks.extend(hooks_from_options(&config.options));
+    hooks
+}
+
+/// Hooks declared as `hook` / `hook.<ext>` options, in key order.
+///
+/// Keys with an unknown extension are skipped with a log message.
+pub fn hooks_from_options(options: &HashMap<String, String>) -> Vec<PostHook> {
+    let timeout = options
+        .get("hook_timeout_secs")
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_HOOK_TIMEOUT);
+
+    let mut keys: Vec<&String> = options.keys().collect();
+    keys.sort();
+
+    let mut hooks = Vec::new();
+    for key in keys {
+        let command = &options[key];
+        if command.trim().is_empty() {
+            continue;
+        }
+
+        let hook = if key == "hook" {
+            PostHook::once(command)
+        } else if let Some(ext) = key.strip_prefix("hook.") {
+            match FileType::from_extension(ext) {
+                Some(file_type) => PostHook::per_file(file_type, command),
+                None => {
+                    tracing::warn!(option = %key, "unknown file extension for hook, skipping");
+                    continue;
+                }
+            }
+        } else {
+            continue;
+        };
+        hooks.push(hook.with_timeout(timeout));
+    }
+    hooks
+}
+
+// ============================================================================
+// Execution
+// ============================================================================
+
+/// Run every hook over the files written to `base_dir`.
+///
+/// Hooks run in order, each to completion (or timeout) before the next.
+pub fn run_hooks(hooks: &[PostHook], base_dir: &Path, files: &[GeneratedFile]) -> Vec<HookOutcome> {
+    let mut outcomes = Vec::new();
+
+    for hook in hooks {
+        match hook.target {
+            HookTarget::Once => outcomes.push(run_hook(hook, base_dir, None)),
+            HookTarget::Files(file_type) => {
+                for file in files.iter().filter(|f| f.file_type == file_type) {
+                    outcomes.push(run_hook(hook, base_dir, Some(&file.path)));
+                }
+            }
+        }
+    }
+
+    let failed = outcomes.iter().filter(|o| !o.succeeded()).count();
+    tracing::info!(
+        runs = outcomes.len(),
+        failed,
+        "post-generation hooks complete"
+    );
+
+    outcomes
+}
+
+/// Run one hook, capturing its output and killing it on timeout.
+fn run_hook(hook: &PostHook, base_dir: &Path, file: Option<&Path>) -> HookOutcome {
+    let started = Instant::now();
+    let outcome = |status, stdout, stderr| HookOutcome {
+        hook: hook.name.clone(),
+        file: file.map(Path::to_path_buf),
+        status,
+        stdout,
+        stderr,
+        duration: started.elapsed(),
+    };
+
+    let child = Command::new(&hook.program)
+        .args(hook.args_for(file))
+        .current_dir(base_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            return outcome(
+                HookStatus::NotStarted(e.to_string()),
+                String::new(),
+                String::new(),
+            );
+        }
+    };
+
+    // Drain the pipes on their own threads so a chatty hook cannot block
+    let stdout = child.stdout.take().map(capture);
+    let stderr = child.stderr.take().map(capture);
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => break HookStatus::Succeeded,
+            Ok(Some(status)) => break HookStatus::Failed(status.code()),
+            Ok(None) if started.elapsed() >= hook.timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break HookStatus::TimedOut;
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+            Err(e) => break HookStatus::NotStarted(e.to_string()),
+        }
+    };
+
+    let collect = |handle: Option<std::thread::JoinHandle<String>>| {
+        handle.and_then(|h| h.join().ok()).unwrap_or_default()
+    };
+    outcome(status, collect(stdout), collect(stderr))
+}
+
+fn capture(mut pipe: impl Read + Send + 'static) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_hook_parses_command_line() {
+        let hook = PostHook::per_file(FileType::Sql, "sqlfluff fix --dialect postgres");
+
+        assert_eq!(hook.name, "sqlfluff fix --dialect postgres");
+        assert_eq!(hook.program, "sqlfluff");
+        assert_eq!(hook.args, vec!["fix", "--dialect", "postgres"]);
+        assert_eq!(hook.target, HookTarget::Files(FileType::Sql));
+        assert_eq!(hook.timeout, DEFAULT_HOOK_TIMEOUT);
+    }
+
+    #[test]
+    fn test_file_path_is_appended_or_substituted() {
+        let file = Path::new("migrations/001.sql");
+
+        let appended = PostHook::per_file(FileType::Sql, "sqlfluff fix");
+        assert_eq!(
+            appended.args_for(Some(file)),
+            vec!["fix", "migrations/001.sql"]
+        );
+
+        let substituted = PostHook::per_file(FileType::Sql, "fmt --in={file} --quiet");
+        assert_eq!(
+            substituted.args_for(Some(file)),
+            vec!["--in=migrations/001.sql", "--quiet"]
+        );
+
+        assert_eq!(appended.args_for(None), vec!["fix"]);
+    }
+
+    #[test]
+    fn test_hooks_from_options() {
+        let options = HashMap::from([
+            ("hook".to_string(), "./post.sh".to_string()),
+            ("hook.sql".to_string(), "sqlfluff fix".to_string()),
+            ("hook.nope".to_string(), "ignored".to_string()),
+            ("hook_timeout_secs".to_string(), "5".to_string()),
+            ("docker".to_string(), "true".to_string()),
+        ]);
+
+        let hooks = hooks_from_options(&options);
+
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[0].target, HookTarget::Once);
+        assert_eq!(hooks[0].program, "./post.sh");
+        assert_eq!(hooks[1].target, HookTarget::Files(FileType::Sql));
+        assert!(hooks.iter().all(|h| h.timeout == Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_configured_hooks_puts_explicit_hooks_first() {
+        let config = GeneratorConfig::new()
+            .with_option("hook", "second")
+            .with_hook(PostHook::once("first"));
+
+        let names: Vec<String> = configured_hooks(&config)
+            .into_iter()
+            .map(|h| h.name)
+            .collect();
+        assert_eq!(names, vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_missing_program_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let outcomes = run_hooks(
+            &[PostHook::once("imortal-no-such-program")],
+            dir.path(),
+            &[],
+        );
+
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(outcomes[0].status, HookStatus::NotStarted(_)));
+        assert!(outcomes[0].describe().contains("could not start"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_once_hook_captures_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let outcomes = run_hooks(&[PostHook::once("echo formatted")], dir.path(), &[]);
+
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].succeeded());
+        assert_eq!(outcomes[0].stdout.trim(), "formatted");
+        assert!(outcomes[0].file.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_per_file_hook_runs_on_matching_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![
+            GeneratedFile::sql("migrations/001.sql", "SELECT 1;"),
+            GeneratedFile::sql("migrations/002.sql", "SELECT 2;"),
+            GeneratedFile::rust("src/main.rs", "fn main() {}"),
+        ];
+        for file in &files {
+            let path = dir.path().join(&file.path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, &file.content).unwrap();
+        }
+
+        let outcomes = run_hooks(
+            &[PostHook::per_file(FileType::Sql, "test -f {file}")],
+            dir.path(),
+            &files,
+        );
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(HookOutcome::succeeded));
+        assert_eq!(
+            outcomes[1].file.as_deref(),
+            Some(Path::new("migrations/002.sql"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failing_hook_reports_exit_code_and_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let hook = PostHook {
+            name: "lint".to_string(),
+            program: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo 'bad line' >&2; exit 3".to_string()],
+            target: HookTarget::Once,
+            timeout: DEFAULT_HOOK_TIMEOUT,
+        };
+
+        let outcomes = run_hooks(&[hook], dir.path(), &[]);
+
+        assert_eq!(outcomes[0].status, HookStatus::Failed(Some(3)));
+        assert_eq!(outcomes[0].output_tail(5), vec!["bad line"]);
+        assert_eq!(outcomes[0].describe(), "lint: exit code 3");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_is_killed_after_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let hook = PostHook::once("sleep 10").with_timeout(Duration::from_millis(100));
+
+        let outcomes = run_hooks(&[hook], dir.path(), &[]);
+
+        assert_eq!(outcomes[0].status, HookStatus::TimedOut);
+        assert!(outcomes[0].duration < Duration::from_secs(5));
+    }
+}
@@ -7,34 +7,77 @@
 //!
 //! ## Features
 //!
-//! - **Model Generation**: SeaORM entity generation from IR entities
+//! - **Model Generation**: SeaORM or Diesel models generated from IR entities
 //! - **Handler Generation**: Axum request handlers for CRUD operations
 //! - **Router Generation**: API route configuration
 //! - **Migration Generation**: SQL migrations for database schema
 //! - **Auth Generation**: JWT authentication middleware and handlers
 //! - **Frontend Generation**: Dioxus components for fullstack projects
+//! - **OpenAPI Generation**: OpenAPI 3.1 spec describing the generated API
+//! - **Schema Export**: DBML, Mermaid `erDiagram` and PlantUML versions of the schema
+//! - **Deployment Generation**: Dockerfile, docker-compose and .dockerignore
+//! - **Seed Generation**: fake records per entity and a `seed` binary loading them
+//! - **Load Test Generation**: a k6 script calling every endpoint, ramped below its rate limits
+//! - **Post-Generation Hooks**: per-file-type or one-off commands run over the output
+//! - **Output Check**: compares a fresh generation with committed output (for CI)
+//! - **Formatting**: Rust output run through rustfmt, one invocation per source tree
+//! - **File Templates**: deployment and scaffolding files rendered from overridable templates
+//! - **Protected Regions**: code between `<immortal:keep>` markers survives regeneration
+//! - **Output Manifest**: checksums of the written files, so user edits are not overwritten
+//! - **Dry Run**: a manifest of the files a generation would write, with conflicts
+//! - **Output Preview**: per-file diff against the output directory before writing
+//! - **Workspace Members**: generates a member crate into an existing Cargo workspace
 //!
 
 // ============================================================================
 // Modules
 // ============================================================================
 
+pub mod check;
 pub mod context;
+pub mod deploy;
+pub mod export;
+pub mod format;
 pub mod frontend;
 pub mod generator;
+pub mod hooks;
+pub mod loadtest;
+pub mod manifest;
 pub mod migrations;
+pub mod openapi;
+pub mod plan;
+pub mod preview;
+pub mod regions;
 pub mod rust;
+pub mod sdk;
+pub mod seeds;
+pub mod templates;
+pub mod typescript;
+pub mod warnings;
+pub mod workspace;
 
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use context::{EntityInfo, GenerationContext};
-pub use generator::{GenerationSummary, Generator, generate, generate_to_dir, summarize};
+pub use check::{OutputCheck, check_output};
+pub use context::{DeleteEffect, EntityInfo, GenerationContext, ResolvedAggregate};
+pub use export::{SchemaFormat, export_schema};
+pub use generator::{
+    GenerationSummary, GenerationTimings, Generator, StageTiming, compatibility_report, generate,
+    generate_to_dir, strict_report, summarize,
+};
+pub use hooks::{HookOutcome, HookStatus, HookTarget, PostHook};
+pub use manifest::{MANIFEST_FILE, Manifest};
+pub use plan::{GenerationPlan, ManifestEntry};
+pub use preview::{DiffLine, FilePreview, FileStatus, OutputPreview, preview_output};
+pub use templates::Templates;
+pub use warnings::{GenerationWarning, WarningCode, WarningSeverity};
+pub use workspace::WorkspaceMember;
 
 use imortal_core::{EngineError, EngineResult};
-use imortal_ir::ProjectGraph;
-use std::collections::HashMap;
+use imortal_ir::{ApiStyle, Orm, ProjectGraph, WebFramework};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 // ============================================================================
@@ -59,9 +102,47 @@ pub struct GeneratorConfig {
     /// Whether to format generated code with rustfmt
     pub format_code: bool,
 
-    /// Whether to overwrite existing files
+    /// Whether to overwrite files edited since they were generated
     pub overwrite: bool,
 
+    /// Treat generation warnings as errors (for CI gates)
+    pub strict: bool,
+
+    /// Write `openapi.json` next to `openapi.yaml`
+    pub openapi_json: bool,
+
+    /// Generate a Dockerfile, docker-compose.yml and .dockerignore
+    pub generate_docker: bool,
+
+    /// Generate seed SQL files and a `seed` binary applying them
+    pub generate_seeds: bool,
+
+    /// Generate a k6 load test script for the REST endpoints
+    pub generate_load_tests: bool,
+
+    /// Generate a standalone Rust client crate for the REST API
+    pub client_sdk: bool,
+
+    /// Generate a member crate for an existing Cargo workspace rather than a
+    /// standalone project (see [`WorkspaceMember`] for registering it)
+    pub workspace_member: bool,
+
+    /// ORM to generate for, overriding the project's own choice
+    pub orm: Option<Orm>,
+
+    /// Web framework to generate for, overriding the project's own choice
+    pub framework: Option<WebFramework>,
+
+    /// API style to generate, overriding the project's own choice
+    pub api_style: Option<ApiStyle>,
+
+    /// Schema the database was last migrated to; when set, migrations only
+    /// contain the changes since then
+    pub baseline: Option<ProjectGraph>,
+
+    /// Commands run over the output after it is written
+    pub hooks: Vec<PostHook>,
+
     /// Custom options
     pub options: HashMap<String, String>,
 }
@@ -75,6 +156,18 @@ impl Default for GeneratorConfig {
             generate_migrations: true,
             format_code: true,
             overwrite: false,
+            strict: false,
+            openapi_json: false,
+            generate_docker: false,
+            generate_seeds: false,
+            generate_load_tests: false,
+            client_sdk: false,
+            workspace_member: false,
+            orm: None,
+            framework: None,
+            api_style: None,
+            baseline: None,
+            hooks: Vec::new(),
             options: HashMap::new(),
         }
     }
@@ -110,12 +203,97 @@ impl GeneratorConfig {
         self
     }
 
+    /// Leave generated Rust files unformatted instead of running rustfmt
+    pub fn without_formatting(mut self) -> Self {
+        self.format_code = false;
+        self
+    }
+
     /// Allow overwriting existing files
     pub fn allow_overwrite(mut self) -> Self {
         self.overwrite = true;
         self
     }
 
+    /// Fail generation when any warning is produced
+    pub fn fail_on_warnings(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Also write the OpenAPI spec as JSON
+    pub fn with_openapi_json(mut self) -> Self {
+        self.openapi_json = true;
+        self
+    }
+
+    /// Generate Docker deployment files
+    pub fn with_docker(mut self) -> Self {
+        self.generate_docker = true;
+        self
+    }
+
+    /// Generate seed data
+    pub fn with_seeds(mut self) -> Self {
+        self.generate_seeds = true;
+        self
+    }
+
+    /// Generate a k6 load test script
+    pub fn with_load_tests(mut self) -> Self {
+        self.generate_load_tests = true;
+        self
+    }
+
+    /// Generate a typed Rust client crate
+    pub fn with_client_sdk(mut self) -> Self {
+        self.client_sdk = true;
+        self
+    }
+
+    /// Generate a member crate for an existing Cargo workspace
+    pub fn as_workspace_member(mut self) -> Self {
+        self.workspace_member = true;
+        self
+    }
+
+    /// Generate for the given ORM regardless of the project config
+    pub fn with_orm(mut self, orm: Orm) -> Self {
+        self.orm = Some(orm);
+        self
+    }
+
+    /// Generate for the given web framework regardless of the project config
+    pub fn with_framework(mut self, framework: WebFramework) -> Self {
+        self.framework = Some(framework);
+        self
+    }
+
+    /// Generate REST, GraphQL or both regardless of the project config
+    pub fn with_api_style(mut self, api_style: ApiStyle) -> Self {
+        self.api_style = Some(api_style);
+        self
+    }
+
+    /// Generate an incremental migration against a previous schema
+    pub fn with_baseline(mut self, project: ProjectGraph) -> Self {
+        self.baseline = Some(project);
+        self
+    }
+
+    /// Run a command after the output is written
+    pub fn with_hook(mut self, hook: PostHook) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Render file templates overridden by the `.jinja` files in `dir`
+    /// (see [`templates`])
+    pub fn with_templates_dir(self, dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref().to_string_lossy().into_owned();
+        self.with_option(templates::TEMPLATES_DIR_OPTION, dir)
+    }
+
     /// Set a custom option
     pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.options.insert(key.into(), value.into());
@@ -179,6 +357,8 @@ pub enum FileType {
     Toml,
     Markdown,
     Env,
+    Yaml,
+    Json,
     Other,
 }
 
@@ -191,9 +371,25 @@ impl FileType {
             FileType::Toml => "toml",
             FileType::Markdown => "md",
             FileType::Env => "env",
+            FileType::Yaml => "yaml",
+            FileType::Json => "json",
             FileType::Other => "txt",
         }
     }
+
+    /// The file type with this extension, if any
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "rs" => Some(FileType::Rust),
+            "sql" => Some(FileType::Sql),
+            "toml" => Some(FileType::Toml),
+            "md" => Some(FileType::Markdown),
+            "env" => Some(FileType::Env),
+            "yaml" | "yml" => Some(FileType::Yaml),
+            "json" => Some(FileType::Json),
+            _ => None,
+        }
+    }
 }
 
 // ============================================================================
@@ -210,7 +406,13 @@ pub struct GeneratedProject {
     pub files: Vec<GeneratedFile>,
 
     /// Warnings generated during code generation
-    pub warnings: Vec<String>,
+    pub warnings: Vec<GenerationWarning>,
+
+    /// Results of the post-generation hooks, once they have run
+    pub hook_outcomes: Vec<HookOutcome>,
+
+    /// Where the generation time went
+    pub timings: GenerationTimings,
 }
 
 impl GeneratedProject {
@@ -220,6 +422,8 @@ impl GeneratedProject {
             name: name.into(),
             files: Vec::new(),
             warnings: Vec::new(),
+            hook_outcomes: Vec::new(),
+            timings: GenerationTimings::default(),
         }
     }
 
@@ -229,7 +433,7 @@ impl GeneratedProject {
     }
 
     /// Add a warning
-    pub fn add_warning(&mut self, warning: impl Into<String>) {
+    pub fn add_warning(&mut self, warning: impl Into<GenerationWarning>) {
         self.warnings.push(warning.into());
     }
 
@@ -253,9 +457,18 @@ impl GeneratedProject {
 
     /// Write all files to disk
     pub fn write_to_disk(&self, base_dir: impl AsRef<Path>) -> EngineResult<()> {
+        self.write_to_disk_except(base_dir, &HashSet::new())
+    }
+
+    /// Write all files to disk except those whose path is in `excluded`
+    pub fn write_to_disk_except(
+        &self,
+        base_dir: impl AsRef<Path>,
+        excluded: &HashSet<PathBuf>,
+    ) -> EngineResult<()> {
         let base_dir = base_dir.as_ref();
 
-        for file in &self.files {
+        for file in self.files.iter().filter(|f| !excluded.contains(&f.path)) {
             let full_path = base_dir.join(&file.path);
 
             // Create parent directories
@@ -336,7 +549,17 @@ mod tests {
         let config = GeneratorConfig::default();
         assert!(config.generate_tests);
         assert!(config.generate_docs);
+        assert!(config.format_code);
         assert!(!config.overwrite);
+        assert!(!config.strict);
+        assert!(!config.generate_docker);
+        assert!(!config.generate_load_tests);
+        assert!(!config.client_sdk);
+        assert!(!config.workspace_member);
+        assert!(config.orm.is_none());
+        assert!(config.framework.is_none());
+        assert!(config.api_style.is_none());
+        assert!(config.baseline.is_none());
     }
 
     #[test]
@@ -344,11 +567,35 @@ mod tests {
         let config = GeneratorConfig::new()
             .with_output_dir("/tmp/output")
             .without_tests()
-            .allow_overwrite();
+            .without_formatting()
+            .allow_overwrite()
+            .fail_on_warnings()
+            .with_openapi_json()
+            .with_docker()
+            .with_load_tests()
+            .with_client_sdk()
+            .as_workspace_member()
+            .with_orm(Orm::Diesel)
+            .with_framework(WebFramework::ActixWeb)
+            .with_api_style(ApiStyle::Both)
+            .with_hook(PostHook::once("./post.sh"))
+            .with_baseline(ProjectGraph::new("previous"));
 
         assert_eq!(config.output_dir, PathBuf::from("/tmp/output"));
         assert!(!config.generate_tests);
+        assert!(!config.format_code);
         assert!(config.overwrite);
+        assert!(config.strict);
+        assert!(config.openapi_json);
+        assert!(config.generate_docker);
+        assert!(config.generate_load_tests);
+        assert!(config.client_sdk);
+        assert!(config.workspace_member);
+        assert_eq!(config.orm, Some(Orm::Diesel));
+        assert_eq!(config.framework, Some(WebFramework::ActixWeb));
+        assert_eq!(config.api_style, Some(ApiStyle::Both));
+        assert_eq!(config.hooks.len(), 1);
+        assert_eq!(config.baseline.unwrap().meta.name, "previous");
     }
 
     #[test]
@@ -358,6 +605,13 @@ mod tests {
         assert_eq!(file.file_type, FileType::Rust);
     }
 
+    #[test]
+    fn test_file_type_from_extension() {
+        assert_eq!(FileType::from_extension("sql"), Some(FileType::Sql));
+        assert_eq!(FileType::from_extension("yml"), Some(FileType::Yaml));
+        assert_eq!(FileType::from_extension("txt"), None);
+    }
+
     #[test]
     fn test_generated_project() {
         let mut project = GeneratedProject::new("test");
@@ -0,0 +1,879 @@
+//! # Load Test Generator
+//!
+//! Generates a [k6](https://k6.io) script exercising every enabled REST
+//! operation, so a team can baseline the generated API's latency as soon as
+//! it runs.
+//!
+//! ## Generated Files
+//!
+//! - `loadtest/k6.js` — one scenario per entity with endpoints. Each
+//!   iteration lists the records, creates one with a sample payload, reads,
+//!   updates and deletes it, for whichever of those operations are enabled.
+//!
+//! ## Script
+//!
+//! - **Targets**: `BASE_URL` (default: the configured host and port) plus each
+//!   entity's public path, with the version header when the project versions
+//!   its API through one
+//! - **Auth**: secured operations send `Authorization: Bearer $AUTH_TOKEN`
+//! - **Payloads**: built from each field's example, validations and type;
+//!   unique columns get a fresh value per request, and foreign keys point at
+//!   records `setup()` creates first (or ids passed as `-e USER_ID=…`)
+//! - **Ramp profile**: each scenario ramps up to 80% of the tightest rate
+//!   limit among its operations, or [`DEFAULT_RATE`] iterations a minute
+//!   without one, holds, then ramps down; `-e PROFILE=smoke` runs a single
+//!   iteration per scenario instead
+//! - **Thresholds**: under 1% failed requests and a p95 latency under
+//!   `P95_MS` (500 ms by default); requests are tagged `METHOD /path`, so
+//!   k6's summary breaks latency down per endpoint
+//!
+//! ## Usage
+//!
+//! The script is only generated when `ctx.generate_load_tests()` returns
+//! `true` — set with [`GeneratorConfig::with_load_tests`] or
+//! `with_option("load_tests", "true")`.
+//!
+//! ```bash
+//! k6 run loadtest/k6.js
+//! BASE_URL=https://staging.example.com AUTH_TOKEN=… k6 run loadtest/k6.js
+//! ```
+//!
+//! [`GeneratorConfig::with_load_tests`]: crate::GeneratorConfig::with_load_tests
+
+use imortal_core::{DataType, Validation};
+use imortal_ir::{CrudOperation, EndpointGroup, Field, OperationType};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::models::dto_field_name;
+use crate::{FileType, GeneratedFile};
+
+/// Path of the generated script
+pub const SCRIPT_PATH: &str = "loadtest/k6.js";
+
+/// Iterations per minute a scenario ramps up to when none of its operations
+/// is rate limited
+pub const DEFAULT_RATE: u64 = 600;
+
+/// Share of a rate limit the ramp targets, so the run measures latency
+/// rather than `429`s
+const RATE_LIMIT_HEADROOM: f64 = 0.8;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `loadtest/k6.js`.
+///
+/// Returns an empty `Vec` if load test generation is disabled or no entity
+/// has enabled REST endpoints.
+pub fn generate_load_tests(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.generate_load_tests() || !ctx.rest_enabled() {
+        return Vec::new();
+    }
+
+    let targets: Vec<Target> = ctx
+        .entities()
+        .iter()
+        .filter_map(|entity| Target::new(EntityInfo::new(entity, ctx)))
+        .collect();
+    if targets.is_empty() {
+        return Vec::new();
+    }
+
+    vec![GeneratedFile::new(
+        SCRIPT_PATH,
+        render_script(&targets, ctx),
+        FileType::Other,
+    )]
+}
+
+// ============================================================================
+// Targets
+// ============================================================================
+
+/// An entity with enabled endpoints, as the script calls it.
+struct Target<'a> {
+    info: EntityInfo<'a>,
+    endpoint: &'a EndpointGroup,
+    operations: Vec<&'a CrudOperation>,
+    /// Scenario and exported function name (`users`)
+    scenario: String,
+    /// Key of the entity's record in `setup()`'s ids (`user`)
+    key: String,
+}
+
+impl<'a> Target<'a> {
+    fn new(info: EntityInfo<'a>) -> Option<Self> {
+        let endpoint = info.endpoint().filter(|ep| ep.enabled)?;
+        let mut operations = endpoint.enabled_operations();
+        if operations.is_empty() {
+            return None;
+        }
+        // The order an iteration calls them in
+        operations.sort_by_key(|op| match op.operation_type {
+            OperationType::ReadAll => 0,
+            OperationType::Create => 1,
+            OperationType::Read => 2,
+            OperationType::Update => 3,
+            OperationType::Delete => 4,
+        });
+
+        let key = info.snake_name();
+        Some(Self {
+            scenario: GenerationContext::pluralize(&key),
+            key,
+            info,
+            endpoint,
+            operations,
+        })
+    }
+
+    fn operation(&self, op_type: OperationType) -> Option<&'a CrudOperation> {
+        self.operations
+            .iter()
+            .copied()
+            .find(|op| op.operation_type == op_type)
+    }
+
+    /// Environment variable holding an existing record's id (`USER_ID`).
+    fn id_variable(&self) -> String {
+        format!("{}_ID", self.key.to_uppercase())
+    }
+
+    /// Name of the JS function building a create (or update) payload.
+    fn payload_fn(&self, op_type: OperationType) -> String {
+        let verb = if op_type == OperationType::Update {
+            "update"
+        } else {
+            "create"
+        };
+        format!("{}{}", verb, self.info.pascal_name())
+    }
+
+    /// Arguments after the body of a `call(…)`: whether the operation needs
+    /// the token, the expected status, and the version header's value.
+    fn call_tail(&self, op: &CrudOperation, ctx: &GenerationContext) -> String {
+        let secured = ctx.auth_enabled()
+            && self
+                .endpoint
+                .effective_security(op.operation_type)
+                .auth_required;
+        let mut tail = format!("{}, {}", secured, op.success_status);
+        if ctx.api_versioning().header_name().is_some()
+            && let Some(version) = self.info.api_version()
+        {
+            tail.push_str(&format!(", {}", js_string(version)));
+        }
+        tail
+    }
+
+    /// Response field holding the primary key.
+    fn id_key(&self) -> String {
+        self.info
+            .pk()
+            .map(|pk| GenerationContext::snake(&pk.name))
+            .unwrap_or_else(|| "id".to_string())
+    }
+
+    /// Iterations per minute the scenario ramps up to, and the limit it was
+    /// derived from.
+    fn rate(&self) -> (u64, Option<String>) {
+        let tightest = self
+            .operations
+            .iter()
+            .filter_map(|op| Some((op, op.rate_limit.as_ref()?)))
+            .filter(|(_, limit)| limit.requests > 0 && limit.window_seconds > 0)
+            .min_by(|(_, a), (_, b)| a.requests_per_second().total_cmp(&b.requests_per_second()));
+
+        match tightest {
+            Some((op, limit)) => {
+                let per_minute = limit.requests_per_second() * 60.0 * RATE_LIMIT_HEADROOM;
+                let note = format!(
+                    "80% of {} requests / {}s on {}",
+                    limit.requests,
+                    limit.window_seconds,
+                    self.request_name(op)
+                );
+                ((per_minute.floor() as u64).max(1), Some(note))
+            }
+            None => (DEFAULT_RATE, None),
+        }
+    }
+
+    /// Tag of an operation's requests (`GET /api/users/{id}`).
+    fn request_name(&self, op: &CrudOperation) -> String {
+        let base = self.info.base_path();
+        if op.operation_type.is_single() {
+            format!("{} {}/{{id}}", op.http_method(), base)
+        } else {
+            format!("{} {}", op.http_method(), base)
+        }
+    }
+}
+
+// ============================================================================
+// Script
+// ============================================================================
+
+fn render_script(targets: &[Target], ctx: &GenerationContext) -> String {
+    let mut script = format!(
+        "\
+// Load test for the {name} API
+//
+// Generated by Immortal Engine v2.0
+//
+//   k6 run loadtest/k6.js
+//   BASE_URL=https://staging.example.com AUTH_TOKEN=… k6 run loadtest/k6.js
+//   k6 run -e PROFILE=smoke loadtest/k6.js   # one pass over every endpoint
+//
+// Every entity runs in its own scenario. An iteration calls each enabled
+// operation once, so arrival rates are iterations (not requests) per minute.
+// Records the scenarios reference are created in setup(); pass an existing
+// id instead with -e <ENTITY>_ID=….
+
+import http from 'k6/http';
+import {{ check }} from 'k6';
+
+const BASE_URL = (__ENV.BASE_URL || 'http://{host}:{port}').replace(/\\/$/, '');
+const AUTH_TOKEN = __ENV.AUTH_TOKEN || '';
+const SMOKE = __ENV.PROFILE === 'smoke';
+const P95_MS = Number(__ENV.P95_MS || 500);
+",
+        name = ctx.package_name(),
+        host = client_host(ctx.server_host()),
+        port = ctx.server_port(),
+    );
+    if let Some(header) = ctx.api_versioning().header_name() {
+        script.push_str(&format!("const VERSION_HEADER = {};\n", js_string(header)));
+    }
+
+    // Ramp profile
+    script.push_str(
+        "
+// Ramp up to `target` iterations a minute, hold, then ramp down
+function ramp(target) {
+  if (SMOKE) {
+    return { executor: 'per-vu-iterations', vus: 1, iterations: 1 };
+  }
+  return {
+    executor: 'ramping-arrival-rate',
+    startRate: 0,
+    timeUnit: '1m',
+    preAllocatedVUs: 10,
+    maxVUs: 100,
+    stages: [
+      { duration: '30s', target: Math.max(1, Math.round(target / 4)) },
+      { duration: '1m', target },
+      { duration: '2m', target },
+      { duration: '30s', target: 0 },
+    ],
+  };
+}
+
+export const options = {
+  scenarios: {
+",
+    );
+    for target in targets {
+        let (rate, note) = target.rate();
+        let note = note.map(|n| format!(" // {}", n)).unwrap_or_default();
+        script.push_str(&format!(
+            "    {scenario}: {{ ...ramp({rate}), exec: '{scenario}' }},{note}\n",
+            scenario = target.scenario,
+        ));
+    }
+    script.push_str(
+        "  },
+  thresholds: {
+    http_req_failed: ['rate<0.01'],
+    http_req_duration: [`p(95)<${P95_MS}`],
+  },
+};
+",
+    );
+
+    script.push_str(&render_helpers(ctx));
+
+    // Payloads
+    script.push_str(
+        "
+// ============================================================================
+// Payloads
+// ============================================================================
+",
+    );
+    for target in targets {
+        for op_type in [OperationType::Create, OperationType::Update] {
+            if target.operation(op_type).is_some() {
+                script.push_str(&render_payload(target, op_type, ctx));
+            }
+        }
+    }
+
+    script.push_str(&render_setup(targets, ctx));
+    for target in targets {
+        script.push_str(&render_scenario(target, ctx));
+    }
+
+    script
+}
+
+/// Request helpers shared by every scenario.
+fn render_helpers(ctx: &GenerationContext) -> String {
+    let version_header = if ctx.api_versioning().header_name().is_some() {
+        "  if (version) headers[VERSION_HEADER] = version;\n"
+    } else {
+        ""
+    };
+    format!(
+        "
+// ============================================================================
+// Helpers
+// ============================================================================
+
+function params(name, secured, version) {{
+  const headers = {{ 'Content-Type': 'application/json' }};
+  if (secured && AUTH_TOKEN) headers.Authorization = `Bearer ${{AUTH_TOKEN}}`;
+{version_header}  return {{ headers, tags: {{ name }} }};
+}}
+
+// Send a request tagged `name` and check it answered `status`
+function call(method, name, path, body, secured, status, version) {{
+  const payload = body === null ? null : JSON.stringify(body);
+  const res = http.request(method, `${{BASE_URL}}${{path}}`, payload, params(name, secured, version));
+  check(res, {{ [`${{name}} → ${{status}}`]: (r) => r.status === status }});
+  return res;
+}}
+
+// Distinct per VU and iteration, for unique columns
+function unique() {{
+  return `${{__VU}}-${{__ITER}}-${{Math.floor(Math.random() * 1e9)}}`;
+}}
+
+// Pad or trim to a length validation, keeping the distinct tail
+function text(value, min, max) {{
+  while (value.length < min) value += 'x';
+  return max && value.length > max ? value.slice(value.length - max) : value;
+}}
+
+function uuid() {{
+  return 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, (c) => {{
+    const r = (Math.random() * 16) | 0;
+    return (c === 'x' ? r : (r & 0x3) | 0x8).toString(16);
+  }});
+}}
+"
+    )
+}
+
+/// `createUser(ids)` / `updateUser(ids)`: a payload for the operation.
+fn render_payload(target: &Target, op_type: OperationType, ctx: &GenerationContext) -> String {
+    let info = &target.info;
+    let fields = if op_type == OperationType::Update {
+        info.update_fields()
+    } else {
+        info.create_fields()
+    };
+
+    let mut body = String::new();
+    for field in fields {
+        let name = if op_type == OperationType::Update {
+            GenerationContext::snake(&field.name)
+        } else {
+            dto_field_name(field, ctx)
+        };
+        body.push_str(&format!(
+            "    {}: {},\n",
+            name,
+            payload_value(field, info, ctx)
+        ));
+    }
+
+    // `n` tells unique values of one payload apart
+    let unique = if body.contains("${n}") {
+        "  const n = unique();\n"
+    } else {
+        ""
+    };
+    format!(
+        "
+function {function}(ids) {{
+{unique}  return {{
+{body}  }};
+}}
+",
+        function = target.payload_fn(op_type),
+    )
+}
+
+/// `setup()` creates a record per entity for others to reference, parents
+/// first; `teardown()` deletes them again, children first.
+fn render_setup(targets: &[Target], ctx: &GenerationContext) -> String {
+    let mut setup = String::from(
+        "
+// ============================================================================
+// Lifecycle
+// ============================================================================
+
+export function setup() {
+  const ids = {};
+  let res;
+",
+    );
+    let mut teardown = String::new();
+
+    for target in targets {
+        let variable = target.id_variable();
+        let Some(create) = target.operation(OperationType::Create) else {
+            setup.push_str(&format!("  ids.{} = __ENV.{};\n", target.key, variable));
+            continue;
+        };
+        setup.push_str(&format!(
+            "  ids.{key} = __ENV.{variable};
+  if (!ids.{key}) {{
+    res = call('POST', '{name}', '{base}', {payload}(ids), {tail});
+    ids.{key} = res.status === {status} ? res.json('{id}') : undefined;
+  }}
+",
+            key = target.key,
+            name = target.request_name(create),
+            base = target.info.base_path(),
+            payload = target.payload_fn(OperationType::Create),
+            tail = target.call_tail(create, ctx),
+            status = create.success_status,
+            id = target.id_key(),
+        ));
+
+        if let Some(delete) = target.operation(OperationType::Delete) {
+            teardown.insert_str(
+                0,
+                &format!(
+                    "  if (!__ENV.{variable} && data.ids.{key}) {{
+    call('DELETE', '{name}', `{base}/${{data.ids.{key}}}`, null, {tail});
+  }}
+",
+                    key = target.key,
+                    name = target.request_name(delete),
+                    base = target.info.base_path(),
+                    tail = target.call_tail(delete, ctx),
+                ),
+            );
+        }
+    }
+    setup.push_str("  return { ids };\n}\n");
+
+    if !teardown.is_empty() {
+        setup.push_str(&format!(
+            "\nexport function teardown(data) {{\n{}}}\n",
+            teardown
+        ));
+    }
+    setup
+}
+
+/// The exported function a scenario runs each iteration.
+fn render_scenario(target: &Target, ctx: &GenerationContext) -> String {
+    let base = target.info.base_path();
+    let mut body = String::new();
+
+    if let Some(list) = target.operation(OperationType::ReadAll) {
+        body.push_str(&format!(
+            "  call('GET', '{}', '{}', null, {});\n",
+            target.request_name(list),
+            base,
+            target.call_tail(list, ctx)
+        ));
+    }
+
+    let created = target.operation(OperationType::Create);
+    let singles: Vec<&CrudOperation> = target
+        .operations
+        .iter()
+        .copied()
+        .filter(|op| op.operation_type.is_single())
+        // Only the record this iteration created is deleted
+        .filter(|op| op.operation_type != OperationType::Delete || created.is_some())
+        .collect();
+
+    match created {
+        Some(create) => {
+            body.push_str(&format!(
+                "
+  const res = call('POST', '{name}', '{base}', {payload}(data.ids), {tail});
+  const id = res.status === {status} ? res.json('{id}') : undefined;
+",
+                name = target.request_name(create),
+                payload = target.payload_fn(OperationType::Create),
+                tail = target.call_tail(create, ctx),
+                status = create.success_status,
+                id = target.id_key(),
+            ));
+        }
+        None if !singles.is_empty() => {
+            body.push_str(&format!("\n  const id = data.ids.{};\n", target.key));
+        }
+        None => {}
+    }
+
+    if !singles.is_empty() {
+        body.push_str("  if (id === undefined) return;\n\n");
+        for op in singles {
+            let payload = if op.operation_type == OperationType::Update {
+                format!("{}(data.ids)", target.payload_fn(OperationType::Update))
+            } else {
+                "null".to_string()
+            };
+            body.push_str(&format!(
+                "  call('{}', '{}', `{}/${{id}}`, {}, {});\n",
+                op.http_method(),
+                target.request_name(op),
+                base,
+                payload,
+                target.call_tail(op, ctx)
+            ));
+        }
+    }
+
+    format!(
+        "
+export function {}(data) {{
+{}}}
+",
+        target.scenario, body
+    )
+}
+
+// ============================================================================
+// Sample values
+// ============================================================================
+
+/// JS expression for a field's value in a payload: foreign keys reference
+/// `ids`, everything else follows the field's type and validations.
+fn payload_value(field: &Field, info: &EntityInfo, ctx: &GenerationContext) -> String {
+    if let Some(fk) = field
+        .foreign_key_ref
+        .as_ref()
+        .filter(|_| field.is_foreign_key)
+    {
+        // Self-references start a new tree
+        if fk.entity_id == info.entity.id {
+            return "null".to_string();
+        }
+        return ctx
+            .entity_by_id(fk.entity_id)
+            .map(|parent| format!("ids.{}", GenerationContext::snake(&parent.name)))
+            .unwrap_or_else(|| "null".to_string());
+    }
+
+    if ctx.is_password_field(field) {
+        let secret = if field.unique {
+            "`LoadTest-${n}`".to_string()
+        } else {
+            js_string("LoadTest-Passw0rd!")
+        };
+        return fit_text(secret, field);
+    }
+    sample_value(&field.data_type, field)
+}
+
+/// JS expression for a value of type `dt`.
+fn sample_value(dt: &DataType, field: &Field) -> String {
+    match dt {
+        DataType::Optional(inner) => sample_value(inner, field),
+        DataType::Array(inner) => format!("[{}]", sample_value(inner, field)),
+        DataType::String | DataType::Text => text_value(dt, field),
+        DataType::Int32 | DataType::Int64 => {
+            let (lo, hi) = bounds(field);
+            if field.unique {
+                let hi = hi.unwrap_or(i32::MAX as f64);
+                let lo = lo.unwrap_or(1.0);
+                format!(
+                    "{} + Math.floor(Math.random() * {})",
+                    lo.ceil() as i64,
+                    (hi.floor() - lo.ceil()).max(0.0) as i64 + 1
+                )
+            } else {
+                example(field, |raw| raw.parse::<i64>().ok().map(|v| v.to_string()))
+                    .unwrap_or_else(|| (clamp(1.0, lo, hi).ceil() as i64).to_string())
+            }
+        }
+        DataType::Float32 | DataType::Float64 => {
+            example(field, |raw| raw.parse::<f64>().ok().map(|v| v.to_string())).unwrap_or_else(
+                || {
+                    let (lo, hi) = bounds(field);
+                    clamp(1.5, lo, hi).to_string()
+                },
+            )
+        }
+        // rust_decimal deserializes from a string
+        DataType::Decimal { scale, .. } => example(field, |raw| Some(js_string(raw)))
+            .unwrap_or_else(|| {
+                let fraction = "0".repeat(*scale as usize);
+                let point = if fraction.is_empty() { "" } else { "." };
+                js_string(&format!("10{}{}", point, fraction))
+            }),
+        DataType::Bool => example(field, |raw| raw.parse::<bool>().ok().map(|v| v.to_string()))
+            .unwrap_or_else(|| "true".to_string()),
+        DataType::Uuid | DataType::Reference { .. } => "uuid()".to_string(),
+        DataType::DateTime => "new Date().toISOString()".to_string(),
+        DataType::Date => "new Date().toISOString().slice(0, 10)".to_string(),
+        DataType::Time => {
+            example(field, |raw| Some(js_string(raw))).unwrap_or_else(|| js_string("12:00:00"))
+        }
+        DataType::Bytes => "[]".to_string(),
+        DataType::Json => "{}".to_string(),
+        DataType::Enum { variants, .. } => variants
+            .first()
+            .map(|v| js_string(v))
+            .unwrap_or_else(|| "null".to_string()),
+    }
+}
+
+/// A string satisfying the field's validations, distinct per request when
+/// the column is unique.
+fn text_value(dt: &DataType, field: &Field) -> String {
+    let validations = &field.validations;
+    if let Some(values) = validations.iter().find_map(|v| match v {
+        Validation::OneOf(values) if !values.is_empty() => Some(values),
+        _ => None,
+    }) {
+        return js_string(&values[0]);
+    }
+    if !field.unique
+        && let Some(example) = example(field, |raw| Some(js_string(raw)))
+    {
+        return example;
+    }
+
+    let name = GenerationContext::snake(&field.name);
+    let value = if validations.contains(&Validation::Email) {
+        "`loadtest-${n}@example.com`".to_string()
+    } else if validations.contains(&Validation::Url) {
+        "`https://example.com/${n}`".to_string()
+    } else if validations.contains(&Validation::Uuid) {
+        return "uuid()".to_string();
+    } else if validations.contains(&Validation::Phone) {
+        return js_string("+15550100123");
+    } else if field.unique {
+        format!("`{}-${{n}}`", name)
+    } else if matches!(dt, DataType::Text) {
+        js_string("Sample text written by the load test.")
+    } else {
+        js_string(&format!("sample {}", name.replace('_', " ")))
+    };
+    fit_text(value, field)
+}
+
+/// Wrap a string expression in `text(…)` when the field limits its length.
+fn fit_text(value: String, field: &Field) -> String {
+    let min = field.validations.iter().find_map(|v| match v {
+        Validation::MinLength(n) => Some(*n),
+        _ => None,
+    });
+    let max = field.validations.iter().find_map(|v| match v {
+        Validation::MaxLength(n) => Some(*n),
+        _ => None,
+    });
+    match (min, max) {
+        (None, None) => value,
+        (min, max) => format!(
+            "text({}, {}, {})",
+            value,
+            min.unwrap_or(0),
+            max.map(|m| m.to_string())
+                .unwrap_or_else(|| "null".to_string())
+        ),
+    }
+}
+
+/// The field's example as a JS literal, if it has one `convert` accepts.
+fn example(field: &Field, convert: impl Fn(&str) -> Option<String>) -> Option<String> {
+    field
+        .example
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .and_then(convert)
+}
+
+/// `Min` and `Max` validations of the field.
+fn bounds(field: &Field) -> (Option<f64>, Option<f64>) {
+    let min = field.validations.iter().find_map(|v| match v {
+        Validation::Min(n) => Some(*n),
+        _ => None,
+    });
+    let max = field.validations.iter().find_map(|v| match v {
+        Validation::Max(n) => Some(*n),
+        _ => None,
+    });
+    (min, max)
+}
+
+fn clamp(value: f64, lo: Option<f64>, hi: Option<f64>) -> f64 {
+    let value = lo.map_or(value, |lo| value.max(lo));
+    hi.map_or(value, |hi| value.min(hi))
+}
+
+/// Host clients reach the server on: a wildcard bind address means local.
+pub(crate) fn client_host(host: &str) -> &str {
+    match host {
+        "0.0.0.0" | "::" | "[::]" | "" => "127.0.0.1",
+        host => host,
+    }
+}
+
+/// A single-quoted JS string literal.
+pub(crate) fn js_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => out.push_str("\\'"),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_ir::{ApiVersioning, Entity, ProjectGraph};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        let mut user = Entity::new("User");
+        user.add_field(
+            Field::new("email", DataType::String)
+                .required()
+                .unique()
+                .with_validation(Validation::Email),
+        );
+        user.add_field(
+            Field::new("name", DataType::String)
+                .required()
+                .with_validation(Validation::MaxLength(8)),
+        );
+        user.add_field(Field::new("password_hash", DataType::String).required());
+        user.add_field(
+            Field::new("age", DataType::Int32)
+                .with_validation(Validation::Min(21.0))
+                .with_validation(Validation::Max(30.0)),
+        );
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let mut post = Entity::new("Post");
+        post.add_field(Field::new("title", DataType::String).required());
+        post.add_field(
+            Field::new("status", DataType::String)
+                .with_validation(Validation::OneOf(vec!["draft".into(), "live".into()])),
+        );
+        let mut author = Field::foreign_key("user_id", "User", "id").required();
+        if let Some(fk) = &mut author.foreign_key_ref {
+            fk.entity_id = user_id;
+        }
+        post.add_field(author);
+        let post_id = post.id;
+        project.add_entity(post);
+
+        let mut users = EndpointGroup::new(user_id, "User");
+        if let Some(create) = users.get_operation_mut(OperationType::Create) {
+            create.rate_limit = Some(imortal_ir::RateLimit::new(10, 60));
+        }
+        project.add_endpoint(users);
+        project.add_endpoint(EndpointGroup::new(post_id, "Post").secured());
+        project
+    }
+
+    fn script(project: &ProjectGraph) -> String {
+        let ctx =
+            GenerationContext::from_project(project, GeneratorConfig::new().with_load_tests());
+        let files = generate_load_tests(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_string_lossy(), SCRIPT_PATH);
+        files[0].content.clone()
+    }
+
+    #[test]
+    fn test_load_tests_disabled_by_default() {
+        let ctx = GenerationContext::from_project_default(&blog());
+        assert!(generate_load_tests(&ctx).is_empty());
+
+        // Nothing to call without endpoints
+        let mut project = ProjectGraph::new("empty");
+        project.add_entity(Entity::new("User"));
+        let ctx =
+            GenerationContext::from_project(&project, GeneratorConfig::new().with_load_tests());
+        assert!(generate_load_tests(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_scenarios_ramp_below_rate_limits() {
+        let script = script(&blog());
+        // 10 requests a minute, at 80%
+        assert!(script.contains(
+            "users: { ...ramp(8), exec: 'users' }, // 80% of 10 requests / 60s on POST /api/users"
+        ));
+        assert!(script.contains(&format!(
+            "posts: {{ ...ramp({}), exec: 'posts' }},\n",
+            DEFAULT_RATE
+        )));
+        assert!(script.contains("export function users(data)"));
+        assert!(script.contains("http_req_duration: [`p(95)<${P95_MS}`]"));
+    }
+
+    #[test]
+    fn test_operations_and_auth() {
+        let script = script(&blog());
+        assert!(script.contains("call('GET', 'GET /api/users', '/api/users', null, false, 200);"));
+        assert!(script.contains(
+            "call('PUT', 'PUT /api/posts/{id}', `/api/posts/${id}`, updatePost(data.ids), true, 200);"
+        ));
+        assert!(script.contains(
+            "call('DELETE', 'DELETE /api/users/{id}', `/api/users/${id}`, null, false, 204);"
+        ));
+        // Parents are created first and deleted last
+        let setup_user = script.find("ids.user = __ENV.USER_ID;").unwrap();
+        let setup_post = script.find("ids.post = __ENV.POST_ID;").unwrap();
+        assert!(setup_user < setup_post);
+        let teardown = &script[script.find("export function teardown").unwrap()..];
+        assert!(teardown.find("data.ids.post").unwrap() < teardown.find("data.ids.user").unwrap());
+    }
+
+    #[test]
+    fn test_payloads_follow_fields() {
+        let script = script(&blog());
+        assert!(script.contains("email: `loadtest-${n}@example.com`,"));
+        assert!(script.contains("name: text('sample name', 0, 8),"));
+        assert!(script.contains("password: 'LoadTest-Passw0rd!',"));
+        assert!(script.contains("age: 21,"));
+        assert!(script.contains("status: 'draft',"));
+        assert!(script.contains("user_id: ids.user,"));
+        // Foreign keys aren't updatable
+        let update = &script[script.find("function updatePost").unwrap()..];
+        let update = &update[..update.find("}\n").unwrap()];
+        assert!(!update.contains("user_id"));
+    }
+
+    #[test]
+    fn test_version_header() {
+        let mut project = blog();
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let script = script(&project);
+        assert!(script.contains("const VERSION_HEADER = 'X-API-Version';"));
+        assert!(script.contains("if (version) headers[VERSION_HEADER] = version;"));
+        assert!(
+            script.contains("call('GET', 'GET /api/users', '/api/users', null, false, 200, 'v1');")
+        );
+    }
+}
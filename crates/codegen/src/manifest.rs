@@ -0,0 +1,259 @@
+//! # Output Manifest
+//!
+//! `.immortal-manifest`, at the root of the output directory, records a
+//! checksum of every file as it was last written by the generator. On the
+//! next run it tells files the user edited apart from files that are merely
+//! out of date:
+//!
+//! - a file whose checksum still matches its entry is untouched and is
+//!   replaced freely;
+//! - a file that no longer matches was **edited** since it was generated.
+//!   Unless [`GeneratorConfig::overwrite`](crate::GeneratorConfig::overwrite)
+//!   is set, it is left as it is and reported.
+//!
+//! Files without an entry (for instance the whole output of a generator
+//! version that predates the manifest) count as generated when they carry
+//! the auto-generated header, and as the user's own otherwise.
+//!
+//! Checksums skip protected regions (see [`regions`](crate::regions)) and
+//! ignore line endings, so code kept in a region is never an edit. They are
+//! 64-bit FNV-1a hashes: enough to notice a change, not meant to resist
+//! tampering.
+//!
+//! The manifest is plain text, one `checksum  path` line per file sorted by
+//! path, so it can be committed and reviewed with the output.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use imortal_core::{EngineError, EngineResult};
+
+use crate::check::{GENERATED_MARKER, normalize_newlines};
+use crate::regions::strip_regions;
+
+/// File name of the manifest, at the root of the output directory
+pub const MANIFEST_FILE: &str = ".immortal-manifest";
+
+/// First line of the manifest
+const MANIFEST_HEADER: &str =
+    "# Checksums of the files Immortal Engine wrote here, used to detect local edits";
+
+// ============================================================================
+// Checksums
+// ============================================================================
+
+/// Checksum of a file's generator-owned content, as 16 hex digits.
+pub fn checksum(content: &str) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let owned = normalize_newlines(strip_regions(content).as_bytes());
+    let hash = owned.iter().fold(OFFSET, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+// ============================================================================
+// Manifest
+// ============================================================================
+
+/// Checksums of the files last written to an output directory
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<PathBuf, String>,
+}
+
+impl Manifest {
+    /// Read the manifest of `dir`; empty if there is none or it is unreadable.
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(dir.join(MANIFEST_FILE))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parse manifest content, skipping comments and malformed lines.
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split_once("  "))
+            .map(|(sum, path)| (PathBuf::from(path), sum.to_string()))
+            .collect();
+        Self { entries }
+    }
+
+    /// The manifest as written to disk.
+    pub fn render(&self) -> String {
+        let mut out = String::from(MANIFEST_HEADER);
+        out.push('\n');
+        for (path, sum) in &self.entries {
+            out.push_str(&format!("{}  {}\n", sum, path.to_string_lossy()));
+        }
+        out
+    }
+
+    /// Write the manifest into `dir`, creating it if needed.
+    pub fn save(&self, dir: &Path) -> EngineResult<()> {
+        std::fs::create_dir_all(dir).map_err(|e| EngineError::DirectoryCreate {
+            path: dir.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        let path = dir.join(MANIFEST_FILE);
+        std::fs::write(&path, self.render()).map_err(|e| EngineError::FileWrite {
+            path,
+            message: e.to_string(),
+        })
+    }
+
+    /// Number of recorded files
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no file is recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Recorded checksum of `path`
+    pub fn get(&self, path: &Path) -> Option<&str> {
+        self.entries.get(path).map(String::as_str)
+    }
+
+    /// Record `content` as the generated version of `path`.
+    pub fn insert(&mut self, path: impl Into<PathBuf>, content: &str) {
+        self.entries.insert(path.into(), checksum(content));
+    }
+
+    /// Whether `on_disk`, the current content of `path`, was edited since the
+    /// generator wrote it.
+    pub fn is_edited(&self, path: &Path, on_disk: &str) -> bool {
+        match self.get(path) {
+            Some(sum) => checksum(on_disk) != sum,
+            None => !on_disk.contains(GENERATED_MARKER),
+        }
+    }
+
+    /// Update the manifest of `dir` after writing into it.
+    ///
+    /// `generated` are the paths of the generation; those in `skipped` were
+    /// not written and keep their previous entry. Everything else is
+    /// recorded from its content on disk, after any hook rewrote it. Entries
+    /// of files no longer generated are kept while the file exists.
+    pub fn record<'a>(
+        dir: &Path,
+        generated: impl IntoIterator<Item = &'a Path>,
+        skipped: &HashSet<PathBuf>,
+    ) -> EngineResult<Self> {
+        let previous = Self::load(dir);
+        let mut manifest = Self::default();
+
+        for path in generated {
+            if skipped.contains(path) {
+                if let Some(sum) = previous.get(path) {
+                    manifest.entries.insert(path.to_path_buf(), sum.to_string());
+                }
+            } else if let Ok(content) = std::fs::read_to_string(dir.join(path)) {
+                manifest.insert(path, &content);
+            }
+        }
+        for (path, sum) in previous.entries {
+            if !manifest.entries.contains_key(&path) && dir.join(&path).is_file() {
+                manifest.entries.entry(path).or_insert(sum);
+            }
+        }
+
+        manifest.save(dir)?;
+        Ok(manifest)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_ignores_regions_and_line_endings() {
+        let sum = checksum("fn a() {}\n");
+        assert_eq!(sum.len(), 16);
+        assert_eq!(sum, checksum("fn a() {}\r\n"));
+        assert_eq!(
+            sum,
+            checksum("fn a() {}\n// <immortal:keep x>\nfn mine() {}\n// </immortal:keep>\n")
+        );
+        assert_ne!(sum, checksum("fn b() {}\n"));
+        // FNV-1a of the empty input is its offset basis
+        assert_eq!(checksum(""), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn test_render_and_parse_round_trip() {
+        let mut manifest = Manifest::default();
+        manifest.insert("src/main.rs", "fn main() {}\n");
+        manifest.insert("Cargo.toml", "[package]\n");
+
+        let rendered = manifest.render();
+        assert!(rendered.starts_with("# Checksums"));
+        assert!(!rendered.contains(GENERATED_MARKER));
+        let lines: Vec<&str> = rendered.lines().skip(1).collect();
+        assert!(lines[0].ends_with("  Cargo.toml"));
+        assert!(lines[1].ends_with("  src/main.rs"));
+
+        assert_eq!(Manifest::parse(&rendered), manifest);
+        assert_eq!(manifest.len(), 2);
+    }
+
+    #[test]
+    fn test_is_edited() {
+        let mut manifest = Manifest::default();
+        manifest.insert("src/main.rs", "fn main() {}\n");
+
+        let path = Path::new("src/main.rs");
+        assert!(!manifest.is_edited(path, "fn main() {}\r\n"));
+        assert!(manifest.is_edited(path, "fn main() { edited() }\n"));
+
+        // Untracked files are the generator's only if they say so
+        let path = Path::new("src/lib.rs");
+        assert!(!manifest.is_edited(path, "//! Auto-generated by Immortal Engine v2.0.\n"));
+        assert!(manifest.is_edited(path, "// mine\n"));
+    }
+
+    #[test]
+    fn test_record_keeps_skipped_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |path: &str, content: &str| {
+            let full = dir.path().join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        };
+        write("a.rs", "a v1\n");
+        write("b.rs", "b v1\n");
+        write("gone.rs", "gone\n");
+        let paths = [Path::new("a.rs"), Path::new("b.rs"), Path::new("gone.rs")];
+        Manifest::record(dir.path(), paths, &HashSet::new()).unwrap();
+
+        // b.rs was edited and skipped; gone.rs is deleted
+        write("a.rs", "a v2\n");
+        write("b.rs", "b edited\n");
+        std::fs::remove_file(dir.path().join("gone.rs")).unwrap();
+        let skipped = HashSet::from([PathBuf::from("b.rs")]);
+        let manifest =
+            Manifest::record(dir.path(), [Path::new("a.rs"), Path::new("b.rs")], &skipped).unwrap();
+
+        assert_eq!(
+            manifest.get(Path::new("a.rs")),
+            Some(checksum("a v2\n").as_str())
+        );
+        assert_eq!(
+            manifest.get(Path::new("b.rs")),
+            Some(checksum("b v1\n").as_str())
+        );
+        assert_eq!(manifest.get(Path::new("gone.rs")), None);
+        assert_eq!(Manifest::load(dir.path()), manifest);
+    }
+}
@@ -0,0 +1,1431 @@
+//! # Incremental Migrations
+//!
+//! Compares the project against a *baseline* — the schema the database was
+//! last migrated to, normally the [`SCHEMA_SNAPSHOT_FILE`](super::SCHEMA_SNAPSHOT_FILE)
+//! written next to the migrations on every run — and emits one migration
+//! with the statements that take the database from the baseline to the
+//! current project, instead of recreating every table.
+//!
+//! Tables and columns are matched by entity and field ID first, then by
+//! name, so renaming a field becomes `RENAME COLUMN` rather than a drop and
+//! an add.
+//!
+//! | Change                          | Statement                                             |
+//! |---------------------------------|-------------------------------------------------------|
+//! | New / removed entity            | `CREATE TABLE` / `DROP TABLE`                         |
+//! | Renamed table or schema         | `ALTER TABLE … RENAME TO` / `SET SCHEMA` (`RENAME TABLE` on MySQL) |
+//! | New / removed field             | `ADD COLUMN` / `DROP COLUMN`                          |
+//! | Renamed field                   | `RENAME COLUMN`                                       |
+//! | Type, nullability or default    | `ALTER COLUMN` (PostgreSQL), `MODIFY COLUMN` (MySQL)  |
+//! | Foreign keys and indexes        | drop what went away, create what is new               |
+//! | New / removed enum type         | `CREATE TYPE` / `DROP TYPE` (PostgreSQL)              |
+//! | New enum variant                | `ALTER TYPE … ADD VALUE` (PostgreSQL)                 |
+//! | New generated column            | `ADD COLUMN … GENERATED ALWAYS AS (…) STORED`         |
+//! | Tablespace (PostgreSQL)         | `ALTER TABLE … SET TABLESPACE`                        |
+//! | Engine or charset (MySQL)       | `ALTER TABLE … ENGINE=` / `CONVERT TO CHARACTER SET`  |
+//!
+//! SQLite cannot alter a column or add a constraint to an existing table.
+//! Those changes, like primary key and uniqueness changes, changed
+//! generation expressions and removed enum variants on any database,
+//! are written as `-- MANUAL:` comments and reported in
+//! [`SchemaMigration::manual`], typically to be handled by rebuilding the
+//! table.
+//!
+//! ## Safe migrations
+//!
+//! With the project's `safe_migrations` flag, destructive changes are staged
+//! so the version still running during a deploy keeps working:
+//!
+//! | Change                  | This migration                               | Deferred                   |
+//! |-------------------------|----------------------------------------------|----------------------------|
+//! | Narrowing type change   | add `{column}_new`, backfill, swap the names | drop `{column}_old`        |
+//! | Removed field           | drop `NOT NULL` so new inserts succeed       | `DROP COLUMN`              |
+//! | Removed entity or enum  | nothing                                      | `DROP TABLE` / `DROP TYPE` |
+//!
+//! Deferred statements are written commented out in a `DEFERRED` section of
+//! the migration file and listed in [`SchemaMigration::deferred`], to go
+//! into a later migration once no deployed version uses the old schema.
+//! Widening type changes (`VARCHAR(50)` → `VARCHAR(100)`, `INTEGER` →
+//! `BIGINT`) lose no data and stay a single `ALTER COLUMN`.
+
+use imortal_ir::DatabaseType;
+use uuid::Uuid;
+
+use super::sql::{
+    ColumnDef, TableDef, TableOptions, create_enum_type, create_table_statements,
+    database_display_name, quote_identifier, quote_table,
+};
+use crate::context::{EntityInfo, GenerationContext};
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// SchemaMigration
+// ============================================================================
+
+/// The statements that migrate a database from a baseline schema to the
+/// current project.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaMigration {
+    /// SQL statements and `-- MANUAL:` notes, in execution order
+    pub statements: Vec<String>,
+    /// What the migration drops, e.g. `column posts.summary`
+    pub destructive: Vec<String>,
+    /// Changes the target database cannot make in place, for the developer
+    /// to handle by hand
+    pub manual: Vec<String>,
+    /// Destructive changes split over several migrations (safe migrations),
+    /// e.g. `column posts.views INTEGER → SMALLINT`
+    pub staged: Vec<String>,
+    /// Statements that finish the staged changes, for a later migration
+    pub deferred: Vec<String>,
+}
+
+impl SchemaMigration {
+    /// Whether the baseline already matches the project
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty() && self.deferred.is_empty()
+    }
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Work out the migration from `baseline` (the schema the database is at)
+/// to `ctx` (the current project).
+pub fn plan_migration(baseline: &GenerationContext, ctx: &GenerationContext) -> SchemaMigration {
+    let db = ctx.database();
+
+    let old_tables: Vec<TableDef> = baseline
+        .entities()
+        .iter()
+        .map(|e| TableDef::of(&EntityInfo::new(e, baseline), baseline))
+        .collect();
+    let new_tables: Vec<TableDef> = ctx
+        .entities()
+        .iter()
+        .map(|e| TableDef::of(&EntityInfo::new(e, ctx), ctx))
+        .collect();
+
+    let pairing = pair_up(
+        &baseline
+            .entities()
+            .iter()
+            .zip(&old_tables)
+            .map(|(e, t)| (Some(e.id), t.name.clone()))
+            .collect::<Vec<_>>(),
+        &ctx.entities()
+            .iter()
+            .zip(&new_tables)
+            .map(|(e, t)| (Some(e.id), t.name.clone()))
+            .collect::<Vec<_>>(),
+    );
+
+    let mut plan = Plan {
+        safe: ctx.safe_migrations(),
+        ..Plan::default()
+    };
+    let enum_pairing = pair_up(
+        &baseline
+            .enums()
+            .iter()
+            .map(|e| (Some(e.id), e.name.clone()))
+            .collect::<Vec<_>>(),
+        &ctx.enums()
+            .iter()
+            .map(|e| (Some(e.id), e.name.clone()))
+            .collect::<Vec<_>>(),
+    );
+    if db == DatabaseType::PostgreSQL {
+        alter_enum_types(baseline, ctx, &enum_pairing, &mut plan);
+    }
+
+    // Renames come first so new tables can reference the new names
+    for &(old, new) in &pairing.matched {
+        if old_tables[old].qualified(db) != new_tables[new].qualified(db) {
+            plan.renames
+                .extend(rename_table(&old_tables[old], &new_tables[new], db));
+        }
+    }
+
+    // New tables, in dependency order
+    for &new in &pairing.added {
+        let info = EntityInfo::new(&ctx.entities()[new], ctx);
+        plan.creates
+            .push(create_table_statements(&info, ctx).trim_end().to_string());
+    }
+
+    for &(old, new) in &pairing.matched {
+        alter_table(&old_tables[old], &new_tables[new], db, &mut plan);
+    }
+
+    // Removed tables, children before the tables they reference
+    for &old in pairing.removed.iter().rev() {
+        let table = &old_tables[old];
+        plan.drop(
+            format!("DROP TABLE IF EXISTS {};", table.qualified(db)),
+            format!("table {}", table.name),
+        );
+    }
+
+    // Removed enum types, once no table uses them
+    if db == DatabaseType::PostgreSQL {
+        for &old in enum_pairing.removed.iter().rev() {
+            let name = GenerationContext::enum_type_name(&baseline.enums()[old].name);
+            plan.drop(
+                format!("DROP TYPE IF EXISTS {};", name),
+                format!("enum type {}", name),
+            );
+        }
+    }
+
+    plan.finish()
+}
+
+/// Render a planned migration as a migration file, or `None` when there is
+/// nothing to migrate.
+pub fn generate_alter_migration(
+    migration: &SchemaMigration,
+    ctx: &GenerationContext,
+) -> Option<GeneratedFile> {
+    if migration.is_empty() {
+        return None;
+    }
+
+    let mut out = String::with_capacity(2048);
+
+    out.push_str("-- Migration: Update schema\n");
+    out.push_str(&format!(
+        "-- Changes since the schema in {}\n",
+        super::SCHEMA_SNAPSHOT_FILE
+    ));
+    out.push_str(&format!(
+        "-- Database: {}\n",
+        database_display_name(ctx.database())
+    ));
+    out.push_str("-- Generated by Immortal Engine v2.0\n");
+    if !migration.destructive.is_empty() {
+        out.push_str("--\n-- Review before applying. This migration drops:\n");
+        for item in &migration.destructive {
+            out.push_str(&format!("--   - {}\n", item));
+        }
+    }
+    if !migration.manual.is_empty() {
+        out.push_str("--\n-- Some changes need manual SQL; see the MANUAL notes below.\n");
+    }
+    if !migration.staged.is_empty() {
+        out.push_str(
+            "--\n-- Safe migrations: these changes are staged so running code keeps working:\n",
+        );
+        for item in &migration.staged {
+            out.push_str(&format!("--   - {}\n", item));
+        }
+        out.push_str(
+            "-- Finish them with the DEFERRED statements in a later migration, once\n\
+             -- every deployed version uses the new schema.\n",
+        );
+    }
+    out.push('\n');
+
+    out.push_str("-- ============================================================\n");
+    out.push_str("-- UP\n");
+    out.push_str("-- ============================================================\n\n");
+
+    for statement in &migration.statements {
+        out.push_str(statement);
+        out.push('\n');
+        // Multi-statement blocks (new tables) get some air around them
+        if statement.contains('\n') {
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+
+    if !migration.deferred.is_empty() {
+        out.push_str("-- ============================================================\n");
+        out.push_str("-- DEFERRED (move to a later migration)\n");
+        out.push_str("-- ============================================================\n\n");
+        for statement in &migration.deferred {
+            out.push_str(&format!("-- {}\n", statement));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("-- ============================================================\n");
+    out.push_str("-- DOWN\n");
+    out.push_str("-- ============================================================\n\n");
+    out.push_str("-- Revert by hand; dropped columns and tables cannot be restored.\n");
+
+    Some(GeneratedFile::new(
+        format!("migrations/{}", ctx.alter_migration_filename()),
+        out,
+        FileType::Sql,
+    ))
+}
+
+// ============================================================================
+// Planning
+// ============================================================================
+
+/// Statements grouped by phase, joined in order by [`Plan::finish`].
+#[derive(Default)]
+struct Plan {
+    /// Stage destructive changes instead of making them now
+    safe: bool,
+    renames: Vec<String>,
+    creates: Vec<String>,
+    alters: Vec<String>,
+    drops: Vec<String>,
+    deferred: Vec<String>,
+    destructive: Vec<String>,
+    manual: Vec<String>,
+    staged: Vec<String>,
+}
+
+impl Plan {
+    fn manual(&mut self, note: String) {
+        self.alters.push(format!("-- MANUAL: {}", note));
+        self.manual.push(note);
+    }
+
+    /// Drop a table or type now, or defer the drop in safe mode.
+    fn drop(&mut self, statement: String, what: String) {
+        if self.safe {
+            self.deferred.push(statement);
+            self.staged.push(format!("{} dropped", what));
+        } else {
+            self.drops.push(statement);
+            self.destructive.push(what);
+        }
+    }
+
+    fn finish(self) -> SchemaMigration {
+        let mut statements = self.renames;
+        statements.extend(self.creates);
+        statements.extend(self.alters);
+        statements.extend(self.drops);
+        SchemaMigration {
+            statements,
+            destructive: self.destructive,
+            manual: self.manual,
+            staged: self.staged,
+            deferred: self.deferred,
+        }
+    }
+}
+
+/// Indexes into the old and new lists, paired by ID and then by name.
+#[derive(Debug, Default)]
+struct Pairing {
+    matched: Vec<(usize, usize)>,
+    removed: Vec<usize>,
+    added: Vec<usize>,
+}
+
+/// Pair `(id, name)` items from two versions. Items without an ID (implied
+/// columns) can only be paired by name.
+fn pair_up(old: &[(Option<Uuid>, String)], new: &[(Option<Uuid>, String)]) -> Pairing {
+    let mut used = vec![false; old.len()];
+    let mut pairs: Vec<Option<usize>> = vec![None; new.len()];
+
+    for (n, (id, _)) in new.iter().enumerate() {
+        if id.is_none() {
+            continue;
+        }
+        if let Some(o) = old.iter().position(|(old_id, _)| old_id == id) {
+            used[o] = true;
+            pairs[n] = Some(o);
+        }
+    }
+    for (n, (_, name)) in new.iter().enumerate() {
+        if pairs[n].is_some() {
+            continue;
+        }
+        if let Some(o) = (0..old.len()).find(|&o| !used[o] && old[o].1 == *name) {
+            used[o] = true;
+            pairs[n] = Some(o);
+        }
+    }
+
+    let mut pairing = Pairing::default();
+    for (n, pair) in pairs.into_iter().enumerate() {
+        match pair {
+            Some(o) => pairing.matched.push((o, n)),
+            None => pairing.added.push(n),
+        }
+    }
+    pairing.removed = (0..old.len()).filter(|&o| !used[o]).collect();
+    pairing
+}
+
+/// Create, rename and extend PostgreSQL enum types. Variants can be added
+/// but not removed in place.
+fn alter_enum_types(
+    baseline: &GenerationContext,
+    ctx: &GenerationContext,
+    pairing: &Pairing,
+    plan: &mut Plan,
+) {
+    for &(old, new) in &pairing.matched {
+        let (before, after) = (&baseline.enums()[old], &ctx.enums()[new]);
+        let old_name = GenerationContext::enum_type_name(&before.name);
+        let name = GenerationContext::enum_type_name(&after.name);
+        if old_name != name {
+            plan.renames
+                .push(format!("ALTER TYPE {} RENAME TO {};", old_name, name));
+        }
+
+        for variant in &after.variants {
+            if !before.variants.contains(variant) {
+                plan.creates.push(format!(
+                    "ALTER TYPE {} ADD VALUE IF NOT EXISTS '{}';",
+                    name, variant
+                ));
+            }
+        }
+        for variant in &before.variants {
+            if !after.variants.contains(variant) {
+                plan.manual(format!(
+                    "PostgreSQL cannot remove '{}' from enum type {}; recreate the type by hand",
+                    variant, name
+                ));
+            }
+        }
+    }
+
+    for &new in &pairing.added {
+        plan.creates.push(create_enum_type(&ctx.enums()[new]));
+    }
+}
+
+/// Move a table to another tablespace, or change its MySQL engine or
+/// character set. Options that were removed have no "default" to return to
+/// and are noted for a manual change.
+fn alter_table_options(
+    old: &TableOptions,
+    new: &TableOptions,
+    table: &str,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    if old.tablespace != new.tablespace {
+        let target = new.tablespace.as_deref().unwrap_or("pg_default");
+        plan.alters.push(format!(
+            "ALTER TABLE {} SET TABLESPACE {};",
+            table,
+            quote_identifier(target, db)
+        ));
+    }
+
+    if old.engine != new.engine {
+        match &new.engine {
+            Some(engine) => plan
+                .alters
+                .push(format!("ALTER TABLE {} ENGINE={};", table, engine)),
+            None => plan.manual(format!(
+                "{} no longer sets a storage engine; it keeps {} until changed by hand",
+                table,
+                old.engine.as_deref().unwrap_or_default()
+            )),
+        }
+    }
+
+    if (&old.charset, &old.collation) != (&new.charset, &new.collation) {
+        match (&new.charset, &new.collation) {
+            (Some(charset), collation) => plan.alters.push(format!(
+                "ALTER TABLE {} CONVERT TO CHARACTER SET {}{};",
+                table,
+                charset,
+                collation
+                    .as_ref()
+                    .map(|c| format!(" COLLATE {}", c))
+                    .unwrap_or_default()
+            )),
+            (None, Some(collation)) => plan
+                .alters
+                .push(format!("ALTER TABLE {} COLLATE={};", table, collation)),
+            (None, None) => plan.manual(format!(
+                "{} no longer sets a character set; its columns keep the current one",
+                table
+            )),
+        }
+    }
+}
+
+fn rename_table(old: &TableDef, new: &TableDef, db: DatabaseType) -> Vec<String> {
+    match db {
+        DatabaseType::MySQL => vec![format!(
+            "RENAME TABLE {} TO {};",
+            old.qualified(db),
+            new.qualified(db)
+        )],
+        DatabaseType::PostgreSQL => {
+            let mut out = Vec::new();
+            let mut current = old.qualified(db);
+            if old.schema != new.schema {
+                let target = match &new.schema {
+                    Some(schema) => {
+                        out.push(format!(
+                            "CREATE SCHEMA IF NOT EXISTS {};",
+                            quote_identifier(schema, db)
+                        ));
+                        schema.as_str()
+                    }
+                    None => "public",
+                };
+                out.push(format!(
+                    "ALTER TABLE {} SET SCHEMA {};",
+                    current,
+                    quote_identifier(target, db)
+                ));
+                current = quote_table(&old.name, new.schema.as_deref(), db);
+            }
+            if old.name != new.name {
+                out.push(format!(
+                    "ALTER TABLE {} RENAME TO {};",
+                    current,
+                    quote_identifier(&new.name, db)
+                ));
+            }
+            out
+        }
+        DatabaseType::SQLite => vec![format!(
+            "ALTER TABLE {} RENAME TO {};",
+            old.qualified(db),
+            quote_identifier(&new.name, db)
+        )],
+    }
+}
+
+/// Column, foreign key and index changes for a table present in both
+/// versions. `new` is the table's name after any rename.
+fn alter_table(old: &TableDef, new: &TableDef, db: DatabaseType, plan: &mut Plan) {
+    let table = new.qualified(db);
+
+    alter_table_options(&old.options, &new.options, &table, db, plan);
+
+    // ── Foreign keys and indexes that went away ──────────────────────────
+    let dropped_fks: Vec<_> = old
+        .foreign_keys
+        .iter()
+        .filter(|fk| !new.foreign_keys.contains(fk))
+        .collect();
+    for fk in &dropped_fks {
+        match db {
+            DatabaseType::PostgreSQL => plan.alters.push(format!(
+                "ALTER TABLE {} DROP CONSTRAINT IF EXISTS {};",
+                table,
+                quote_identifier(&fk.name, db)
+            )),
+            DatabaseType::MySQL => plan.alters.push(format!(
+                "ALTER TABLE {} DROP FOREIGN KEY {};",
+                table,
+                quote_identifier(&fk.name, db)
+            )),
+            DatabaseType::SQLite => plan.manual(format!(
+                "SQLite cannot drop foreign key {} from {}; rebuild the table",
+                fk.name, new.name
+            )),
+        }
+    }
+
+    for index in old.indexes.iter().filter(|i| !new.indexes.contains(i)) {
+        plan.alters.push(match db {
+            // Indexes follow their table into a new schema
+            DatabaseType::PostgreSQL => format!(
+                "DROP INDEX IF EXISTS {};",
+                quote_table(&index.name, new.schema.as_deref(), db)
+            ),
+            DatabaseType::MySQL => format!(
+                "DROP INDEX {} ON {};",
+                quote_identifier(&index.name, db),
+                table
+            ),
+            DatabaseType::SQLite => {
+                format!(
+                    "DROP INDEX IF EXISTS {};",
+                    quote_identifier(&index.name, db)
+                )
+            }
+        });
+    }
+
+    // ── Columns ──────────────────────────────────────────────────────────
+    let keys = |columns: &[ColumnDef]| -> Vec<(Option<Uuid>, String)> {
+        columns
+            .iter()
+            .map(|c| (c.field_id, c.name.clone()))
+            .collect()
+    };
+    let pairing = pair_up(&keys(&old.columns), &keys(&new.columns));
+
+    for &(o, n) in &pairing.matched {
+        let (before, after) = (&old.columns[o], &new.columns[n]);
+        if before.name != after.name {
+            plan.alters.push(format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                table,
+                quote_identifier(&before.name, db),
+                quote_identifier(&after.name, db)
+            ));
+        }
+    }
+
+    for &n in &pairing.added {
+        add_column(&new.name, &table, &new.columns[n], db, plan);
+    }
+
+    for &(o, n) in &pairing.matched {
+        let (before, after) = (&old.columns[o], &new.columns[n]);
+        if plan.safe
+            && !before.primary_key
+            && !after.primary_key
+            && before.generated.is_none()
+            && after.generated.is_none()
+            && before.sql_type != after.sql_type
+            && !widens(&before.sql_type, &after.sql_type)
+        {
+            stage_type_change(new, &table, before, after, db, plan);
+        } else {
+            alter_column(&new.name, &table, before, after, db, plan);
+        }
+    }
+
+    for &o in &pairing.removed {
+        let column = &old.columns[o];
+        let drop = format!(
+            "ALTER TABLE {} DROP COLUMN {};",
+            table,
+            quote_identifier(&column.name, db)
+        );
+        if plan.safe {
+            stage_column_drop(&new.name, &table, column, db, plan);
+            plan.deferred.push(drop);
+        } else {
+            plan.alters.push(drop);
+            plan.destructive
+                .push(format!("column {}.{}", new.name, column.name));
+        }
+    }
+
+    // ── New foreign keys and indexes ─────────────────────────────────────
+    for fk in new
+        .foreign_keys
+        .iter()
+        .filter(|fk| !old.foreign_keys.contains(fk))
+    {
+        match db {
+            DatabaseType::PostgreSQL | DatabaseType::MySQL => plan.alters.push(format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} {};",
+                table,
+                quote_identifier(&fk.name, db),
+                fk.clause
+            )),
+            DatabaseType::SQLite => plan.manual(format!(
+                "SQLite cannot add foreign key {} ({}) to an existing table; rebuild the table to enforce it",
+                fk.name, fk.clause
+            )),
+        }
+    }
+
+    for index in new.indexes.iter().filter(|i| !old.indexes.contains(i)) {
+        plan.alters.push(index.sql.clone());
+    }
+}
+
+fn add_column(
+    table_name: &str,
+    table: &str,
+    column: &ColumnDef,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    if column.primary_key {
+        plan.manual(format!(
+            "add primary key column {}.{} by hand",
+            table_name, column.name
+        ));
+        return;
+    }
+    // SQLite can only add virtual generated columns to an existing table
+    if db == DatabaseType::SQLite && column.generated.is_some() {
+        plan.manual(format!(
+            "SQLite cannot add generated column {}.{} to an existing table; rebuild the table",
+            table_name, column.name
+        ));
+        return;
+    }
+    if column.not_null && column.default.is_none() && column.generated.is_none() {
+        plan.manual(format!(
+            "{}.{} is NOT NULL without a default; existing rows need a value (backfill, or add it nullable first)",
+            table_name, column.name
+        ));
+    }
+
+    // SQLite refuses UNIQUE in ADD COLUMN, but a unique index does the same job
+    if db == DatabaseType::SQLite && column.unique {
+        let plain = ColumnDef {
+            unique: false,
+            ..column.clone()
+        };
+        plan.alters.push(format!(
+            "ALTER TABLE {} ADD COLUMN {};",
+            table,
+            plain.render(db)
+        ));
+        plan.alters.push(format!(
+            "CREATE UNIQUE INDEX IF NOT EXISTS {} ON {} ({});",
+            quote_identifier(&format!("uq_{}_{}", table_name, column.name), db),
+            table,
+            quote_identifier(&column.name, db)
+        ));
+        return;
+    }
+
+    plan.alters.push(format!(
+        "ALTER TABLE {} ADD COLUMN {};",
+        table,
+        column.render(db)
+    ));
+}
+
+fn alter_column(
+    table_name: &str,
+    table: &str,
+    before: &ColumnDef,
+    after: &ColumnDef,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    let column = quote_identifier(&after.name, db);
+    let qualified = format!("{}.{}", table_name, after.name);
+
+    if before.primary_key != after.primary_key {
+        plan.manual(format!("change the primary key of {} by hand", table_name));
+    }
+    if before.unique != after.unique {
+        let change = if after.unique { "add" } else { "drop" };
+        plan.manual(format!(
+            "{} the unique constraint on {} by hand",
+            change, qualified
+        ));
+    }
+
+    // A generation expression can't be altered in place on every
+    // supported database (PostgreSQL only gained SET EXPRESSION in 17)
+    if before.generated != after.generated {
+        let change = match (&before.generated, &after.generated) {
+            (None, Some(_)) => "make it a generated column",
+            (Some(_), None) => "make it a plain column",
+            _ => "change its expression",
+        };
+        plan.manual(format!(
+            "{} is computed differently now; drop and re-add the column to {}",
+            qualified, change
+        ));
+        return;
+    }
+
+    let type_changed = before.sql_type != after.sql_type;
+    let null_changed = before.not_null != after.not_null;
+    let default_changed = before.default != after.default;
+    if !(type_changed || null_changed || default_changed) {
+        return;
+    }
+
+    match db {
+        DatabaseType::PostgreSQL => {
+            if type_changed {
+                plan.alters.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{};",
+                    table, column, after.sql_type, column, after.sql_type
+                ));
+            }
+            if null_changed {
+                let action = if after.not_null { "SET" } else { "DROP" };
+                plan.alters.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} {} NOT NULL;",
+                    table, column, action
+                ));
+            }
+            if default_changed {
+                plan.alters.push(match &after.default {
+                    Some(default) => format!(
+                        "ALTER TABLE {} ALTER COLUMN {} SET {};",
+                        table, column, default
+                    ),
+                    None => format!(
+                        "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                        table, column
+                    ),
+                });
+            }
+        }
+        // MODIFY restates the whole column; uniqueness is left alone
+        DatabaseType::MySQL => {
+            let restated = ColumnDef {
+                unique: false,
+                primary_key: false,
+                ..after.clone()
+            };
+            plan.alters.push(format!(
+                "ALTER TABLE {} MODIFY COLUMN {};",
+                table,
+                restated.render(db)
+            ));
+        }
+        DatabaseType::SQLite => {
+            let mut changes = Vec::new();
+            if type_changed {
+                changes.push(format!("type {} → {}", before.sql_type, after.sql_type));
+            }
+            if null_changed {
+                changes.push(if after.not_null {
+                    "now NOT NULL".to_string()
+                } else {
+                    "now nullable".to_string()
+                });
+            }
+            if default_changed {
+                changes.push("default changed".to_string());
+            }
+            plan.manual(format!(
+                "SQLite cannot alter column {} in place ({}); rebuild the table",
+                qualified,
+                changes.join(", ")
+            ));
+        }
+    }
+}
+
+// ============================================================================
+// Safe migrations
+// ============================================================================
+
+/// Change a column's type without breaking running code: add the column
+/// with the new type, backfill it, swap the names, and leave the old column
+/// (`{column}_old`) for a deferred drop.
+fn stage_type_change(
+    new: &TableDef,
+    table: &str,
+    before: &ColumnDef,
+    after: &ColumnDef,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    let qualified = format!("{}.{}", new.name, after.name);
+    let column = quote_identifier(&after.name, db);
+    let new_column = quote_identifier(&format!("{}_new", after.name), db);
+    let old_column = quote_identifier(&format!("{}_old", after.name), db);
+    let change = format!("{} {} → {}", qualified, before.sql_type, after.sql_type);
+    plan.staged.push(format!("column {}", change));
+
+    // 1. Expand: the new column is nullable until every row has a value
+    plan.alters.push(format!(
+        "-- STAGED 1/3: {}: add the column with the new type",
+        change
+    ));
+    let added = ColumnDef {
+        name: format!("{}_new", after.name),
+        not_null: false,
+        primary_key: false,
+        unique: false,
+        ..after.clone()
+    };
+    plan.alters.push(format!(
+        "ALTER TABLE {} ADD COLUMN {};",
+        table,
+        added.render(db)
+    ));
+
+    // 2. Backfill; MySQL's CAST only knows a few target types, so it relies
+    // on assignment conversion instead
+    plan.alters.push(format!(
+        "-- STAGED 2/3: {}: copy the values over (batch this on large tables)",
+        qualified
+    ));
+    let value = match db {
+        DatabaseType::MySQL => column.clone(),
+        _ => format!("CAST({} AS {})", column, after.sql_type),
+    };
+    plan.alters
+        .push(format!("UPDATE {} SET {} = {};", table, new_column, value));
+
+    // 3. Swap the names; the old column must accept the rows new code writes
+    plan.alters.push(format!(
+        "-- STAGED 3/3: {}: swap the columns, keeping the old one as {}_old",
+        qualified, after.name
+    ));
+    plan.alters.push(format!(
+        "ALTER TABLE {} RENAME COLUMN {} TO {};",
+        table, column, old_column
+    ));
+    plan.alters.push(format!(
+        "ALTER TABLE {} RENAME COLUMN {} TO {};",
+        table, new_column, column
+    ));
+    if before.not_null {
+        let old = ColumnDef {
+            name: format!("{}_old", after.name),
+            ..before.clone()
+        };
+        relax_not_null(&new.name, table, &old, db, plan);
+    }
+    if after.not_null {
+        match db {
+            DatabaseType::PostgreSQL => plan.alters.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET NOT NULL;",
+                table, column
+            )),
+            DatabaseType::MySQL => {
+                let restated = ColumnDef {
+                    unique: false,
+                    primary_key: false,
+                    ..after.clone()
+                };
+                plan.alters.push(format!(
+                    "ALTER TABLE {} MODIFY COLUMN {};",
+                    table,
+                    restated.render(db)
+                ));
+            }
+            DatabaseType::SQLite => plan.manual(format!(
+                "SQLite cannot make {} NOT NULL after the swap; rebuild the table",
+                qualified
+            )),
+        }
+    }
+
+    // Indexes and constraints stay with the renamed old column
+    let constrained = after.unique
+        || new.indexes.iter().any(|index| {
+            index
+                .sql
+                .split_once(" ON ")
+                .and_then(|(_, target)| target.split_once('('))
+                .is_some_and(|(_, columns)| columns.contains(&column))
+        })
+        || new
+            .foreign_keys
+            .iter()
+            .any(|fk| fk.clause.starts_with(&format!("FOREIGN KEY ({})", column)));
+    if constrained {
+        plan.manual(format!(
+            "indexes and constraints on {} stay with {}_old after the swap; recreate them on the new column",
+            qualified, after.name
+        ));
+    }
+
+    plan.deferred
+        .push(format!("ALTER TABLE {} DROP COLUMN {};", table, old_column));
+}
+
+/// Keep a removed column until a later migration, relaxing `NOT NULL` so
+/// code that no longer writes it can still insert rows.
+fn stage_column_drop(
+    table_name: &str,
+    table: &str,
+    column: &ColumnDef,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    plan.staged
+        .push(format!("column {}.{} dropped", table_name, column.name));
+    plan.alters.push(format!(
+        "-- STAGED: {}.{} is no longer used; it is dropped by a DEFERRED statement",
+        table_name, column.name
+    ));
+    if column.not_null && column.default.is_none() && column.generated.is_none() {
+        relax_not_null(table_name, table, column, db, plan);
+    }
+}
+
+/// `DROP NOT NULL` on a column that stays around for a deferred drop.
+fn relax_not_null(
+    table_name: &str,
+    table: &str,
+    column: &ColumnDef,
+    db: DatabaseType,
+    plan: &mut Plan,
+) {
+    match db {
+        DatabaseType::PostgreSQL => plan.alters.push(format!(
+            "ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL;",
+            table,
+            quote_identifier(&column.name, db)
+        )),
+        DatabaseType::MySQL => {
+            let restated = ColumnDef {
+                not_null: false,
+                unique: false,
+                primary_key: false,
+                ..column.clone()
+            };
+            plan.alters.push(format!(
+                "ALTER TABLE {} MODIFY COLUMN {};",
+                table,
+                restated.render(db)
+            ));
+        }
+        DatabaseType::SQLite => plan.manual(format!(
+            "SQLite cannot drop NOT NULL from {}.{}; inserts that leave it out fail until it is dropped",
+            table_name, column.name
+        )),
+    }
+}
+
+/// Whether a type change only widens the column, so every existing value
+/// fits the new type unchanged.
+fn widens(before: &str, after: &str) -> bool {
+    // `NAME(a,b)` → ("NAME", [a, b])
+    fn parse(sql_type: &str) -> (&str, Vec<u32>) {
+        match sql_type.split_once('(') {
+            Some((name, args)) => (
+                name.trim(),
+                args.trim_end_matches(')')
+                    .split(',')
+                    .filter_map(|a| a.trim().parse().ok())
+                    .collect(),
+            ),
+            None => (sql_type.trim(), Vec::new()),
+        }
+    }
+
+    let (from, from_args) = parse(before);
+    let (to, to_args) = parse(after);
+    match (from, to) {
+        ("VARCHAR", "VARCHAR") => match (from_args.first(), to_args.first()) {
+            (Some(a), Some(b)) => b >= a,
+            _ => false,
+        },
+        ("VARCHAR", "TEXT" | "LONGTEXT") | ("TEXT", "LONGTEXT") => true,
+        ("SMALLINT", "INTEGER" | "INT" | "BIGINT") | ("INTEGER" | "INT", "BIGINT") => true,
+        ("REAL", "DOUBLE PRECISION") | ("FLOAT", "DOUBLE") => true,
+        ("NUMERIC", "NUMERIC") | ("DECIMAL", "DECIMAL") => match (&from_args[..], &to_args[..]) {
+            // Both the integer digits and the scale must fit
+            ([p1, s1], [p2, s2]) => s2 >= s1 && p2.saturating_sub(*s2) >= p1.saturating_sub(*s1),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{Entity, EntityConfig, EntityIndex, EnumDef, Field, ProjectGraph};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        let mut post = Entity::new("Post");
+        let mut title = Field::new("title", DataType::String);
+        title.required = true;
+        post.fields.push(title);
+        post.fields.push(Field::new("summary", DataType::Text));
+        project.add_entity(post);
+        project
+    }
+
+    fn plan(old: &ProjectGraph, new: &ProjectGraph) -> SchemaMigration {
+        plan_migration(
+            &GenerationContext::from_project_default(old),
+            &GenerationContext::from_project_default(new),
+        )
+    }
+
+    fn post_mut(project: &mut ProjectGraph) -> &mut Entity {
+        project
+            .entities
+            .values_mut()
+            .find(|e| e.name == "Post")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unchanged_project_needs_no_migration() {
+        let project = blog();
+        let migration = plan(&project, &project);
+        assert!(migration.is_empty());
+
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_alter_migration(&migration, &ctx).is_none());
+    }
+
+    #[test]
+    fn test_add_drop_and_rename_columns() {
+        let old = blog();
+        let mut new = old.clone();
+        let post = post_mut(&mut new);
+        post.fields.retain(|f| f.name != "summary");
+        let title = post.fields.iter_mut().find(|f| f.name == "title").unwrap();
+        title.name = "headline".to_string();
+        title.column_name = "headline".to_string();
+        let mut views = Field::new("views", DataType::Int32);
+        views.indexed = true;
+        post.fields.push(views);
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            vec![
+                "ALTER TABLE \"posts\" RENAME COLUMN \"title\" TO \"headline\";",
+                "ALTER TABLE \"posts\" ADD COLUMN \"views\" INTEGER;",
+                "ALTER TABLE \"posts\" DROP COLUMN \"summary\";",
+                "CREATE INDEX IF NOT EXISTS \"idx_posts_views\" ON \"posts\" (\"views\");",
+            ]
+        );
+        assert_eq!(migration.destructive, vec!["column posts.summary"]);
+        assert!(migration.manual.is_empty());
+    }
+
+    #[test]
+    fn test_change_column_type_per_database() {
+        let old = blog();
+        let mut new = old.clone();
+        let title = post_mut(&mut new)
+            .fields
+            .iter_mut()
+            .find(|f| f.name == "title")
+            .unwrap();
+        title.data_type = DataType::Text;
+        title.required = false;
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            vec![
+                "ALTER TABLE \"posts\" ALTER COLUMN \"title\" TYPE TEXT USING \"title\"::TEXT;",
+                "ALTER TABLE \"posts\" ALTER COLUMN \"title\" DROP NOT NULL;",
+            ]
+        );
+
+        let (mut old_mysql, mut new_mysql) = (old.clone(), new.clone());
+        old_mysql.config.database = DatabaseType::MySQL;
+        new_mysql.config.database = DatabaseType::MySQL;
+        assert_eq!(
+            plan(&old_mysql, &new_mysql).statements,
+            vec!["ALTER TABLE `posts` MODIFY COLUMN `title` LONGTEXT;"]
+        );
+
+        let (mut old_sqlite, mut new_sqlite) = (old, new);
+        old_sqlite.config.database = DatabaseType::SQLite;
+        new_sqlite.config.database = DatabaseType::SQLite;
+        let migration = plan(&old_sqlite, &new_sqlite);
+        assert_eq!(migration.manual.len(), 1);
+        assert!(migration.manual[0].contains("SQLite cannot alter column posts.title"));
+        assert!(migration.statements[0].starts_with("-- MANUAL: "));
+    }
+
+    #[test]
+    fn test_new_and_removed_tables() {
+        let old = blog();
+        let mut new = old.clone();
+        let post_id = post_mut(&mut new).id;
+        new.remove_entity(post_id);
+        new.add_entity(Entity::new("Tag"));
+
+        let migration = plan(&old, &new);
+        assert!(migration.statements[0].contains("CREATE TABLE IF NOT EXISTS \"tags\""));
+        assert_eq!(
+            migration.statements.last().unwrap(),
+            "DROP TABLE IF EXISTS \"posts\";"
+        );
+        assert_eq!(migration.destructive, vec!["table posts"]);
+    }
+
+    #[test]
+    fn test_renamed_table_and_soft_delete() {
+        let old = blog();
+        let mut new = old.clone();
+        let post = post_mut(&mut new);
+        post.table_name = "articles".to_string();
+        post.config.soft_delete = true;
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            vec![
+                "ALTER TABLE \"posts\" RENAME TO \"articles\";",
+                "ALTER TABLE \"articles\" ADD COLUMN \"deleted_at\" TIMESTAMP WITH TIME ZONE DEFAULT NULL;",
+                "CREATE INDEX IF NOT EXISTS \"idx_articles_deleted_at\" ON \"articles\" (\"deleted_at\");",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_types() {
+        let mut old = blog();
+        let status = EnumDef::new("PostStatus").with_variants(["draft", "published"]);
+        post_mut(&mut old)
+            .fields
+            .push(Field::new("status", status.data_type()));
+        old.add_enum(status.clone());
+
+        let mut new = old.clone();
+        new.update_enum(
+            EnumDef {
+                name: "ArticleStatus".to_string(),
+                ..status.clone()
+            }
+            .with_variant("archived"),
+        );
+        let role = EnumDef::new("Role").with_variant("admin");
+        new.add_enum(role.clone());
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements[..3],
+            [
+                "ALTER TYPE post_status RENAME TO article_status;".to_string(),
+                "ALTER TYPE article_status ADD VALUE IF NOT EXISTS 'archived';".to_string(),
+                create_enum_type(&role),
+            ]
+        );
+
+        // Dropped variants need a hand-written migration; dropped types go last
+        let mut newer = new.clone();
+        newer.update_enum(EnumDef {
+            variants: vec!["draft".to_string()],
+            ..new.get_enum(status.id).unwrap().clone()
+        });
+        newer.remove_enum(role.id);
+        let migration = plan(&new, &newer);
+        assert!(migration.manual.iter().any(|m| m.contains("'published'")));
+        assert_eq!(
+            migration.statements.last().unwrap(),
+            "DROP TYPE IF EXISTS role;"
+        );
+        assert_eq!(migration.destructive, vec!["enum type role"]);
+    }
+
+    #[test]
+    fn test_computed_columns() {
+        use imortal_ir::ComputedField;
+
+        let old = blog();
+        let mut new = old.clone();
+        let post = post_mut(&mut new);
+        post.fields.push(
+            Field::new("teaser", DataType::Text)
+                .with_computed(ComputedField::generated("title || summary")),
+        );
+        post.fields.push(
+            Field::new("shout", DataType::Text).with_computed(ComputedField::getter("title")),
+        );
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            [
+                "ALTER TABLE \"posts\" ADD COLUMN \"teaser\" TEXT GENERATED ALWAYS AS (\"title\" || \"summary\") STORED;"
+            ]
+        );
+        assert!(migration.manual.is_empty());
+
+        let mut newer = new.clone();
+        let teaser = post_mut(&mut newer)
+            .fields
+            .iter_mut()
+            .find(|f| f.name == "teaser")
+            .unwrap();
+        teaser.computed = Some(ComputedField::generated("summary"));
+        let migration = plan(&new, &newer);
+        assert_eq!(migration.manual.len(), 1);
+        assert!(migration.manual[0].contains("posts.teaser"));
+    }
+
+    #[test]
+    fn test_composite_constraints() {
+        let old = blog();
+        let mut new = old.clone();
+        post_mut(&mut new).config = EntityConfig::new()
+            .with_unique_together(["title", "summary"])
+            .with_index(EntityIndex::new(["summary", "created_at"]));
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            vec![
+                "CREATE UNIQUE INDEX IF NOT EXISTS \"uq_posts_title_summary\" ON \"posts\" (\"title\", \"summary\");",
+                "CREATE INDEX IF NOT EXISTS \"idx_posts_summary_created_at\" ON \"posts\" (\"summary\", \"created_at\");",
+            ]
+        );
+
+        // Dropping a field takes its constraints with it
+        let mut dropped = new.clone();
+        let summary = post_mut(&mut dropped)
+            .get_field_by_name("summary")
+            .unwrap()
+            .id;
+        post_mut(&mut dropped).remove_field(summary);
+        let migration = plan(&new, &dropped);
+        assert_eq!(
+            migration.statements,
+            vec![
+                "DROP INDEX IF EXISTS \"uq_posts_title_summary\";",
+                "DROP INDEX IF EXISTS \"idx_posts_summary_created_at\";",
+                "ALTER TABLE \"posts\" DROP COLUMN \"summary\";",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_options() {
+        let old = blog();
+        let mut new = old.clone();
+        post_mut(&mut new).config.tablespace = Some("fast".to_string());
+        assert_eq!(
+            plan(&old, &new).statements,
+            vec!["ALTER TABLE \"posts\" SET TABLESPACE \"fast\";"]
+        );
+        assert_eq!(
+            plan(&new, &old).statements,
+            vec!["ALTER TABLE \"posts\" SET TABLESPACE \"pg_default\";"]
+        );
+
+        // MySQL ignores the tablespace and honours its own options
+        let (mut old_mysql, mut new_mysql) = (old.clone(), new.clone());
+        old_mysql.config.database = DatabaseType::MySQL;
+        new_mysql.config.database = DatabaseType::MySQL;
+        assert!(plan(&old_mysql, &new_mysql).is_empty());
+
+        let config = &mut post_mut(&mut new_mysql).config;
+        config.engine = Some("InnoDB".to_string());
+        config.charset = Some("utf8mb4".to_string());
+        config.collation = Some("utf8mb4_unicode_ci".to_string());
+        assert_eq!(
+            plan(&old_mysql, &new_mysql).statements,
+            vec![
+                "ALTER TABLE `posts` ENGINE=InnoDB;",
+                "ALTER TABLE `posts` CONVERT TO CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;",
+            ]
+        );
+        let migration = plan(&new_mysql, &old_mysql);
+        assert_eq!(migration.manual.len(), 2);
+        assert!(migration.manual[0].contains("keeps InnoDB"));
+    }
+
+    #[test]
+    fn test_safe_migrations() {
+        let mut old = blog();
+        old.config.safe_migrations = true;
+        let mut views = Field::new("views", DataType::Int64);
+        views.required = true;
+        views.indexed = true;
+        post_mut(&mut old).fields.push(views);
+        let mut new = old.clone();
+        let post = post_mut(&mut new);
+        post.fields.retain(|f| f.name != "summary");
+        for field in post.fields.iter_mut() {
+            match field.name.as_str() {
+                "title" => field.data_type = DataType::Text,
+                "views" => field.data_type = DataType::Int32,
+                _ => {}
+            }
+        }
+
+        let migration = plan(&old, &new);
+        assert_eq!(
+            migration.statements,
+            vec![
+                // Widening stays in place
+                "ALTER TABLE \"posts\" ALTER COLUMN \"title\" TYPE TEXT USING \"title\"::TEXT;",
+                "-- STAGED 1/3: posts.views BIGINT → INTEGER: add the column with the new type",
+                "ALTER TABLE \"posts\" ADD COLUMN \"views_new\" INTEGER;",
+                "-- STAGED 2/3: posts.views: copy the values over (batch this on large tables)",
+                "UPDATE \"posts\" SET \"views_new\" = CAST(\"views\" AS INTEGER);",
+                "-- STAGED 3/3: posts.views: swap the columns, keeping the old one as views_old",
+                "ALTER TABLE \"posts\" RENAME COLUMN \"views\" TO \"views_old\";",
+                "ALTER TABLE \"posts\" RENAME COLUMN \"views_new\" TO \"views\";",
+                "ALTER TABLE \"posts\" ALTER COLUMN \"views_old\" DROP NOT NULL;",
+                "ALTER TABLE \"posts\" ALTER COLUMN \"views\" SET NOT NULL;",
+                "-- MANUAL: indexes and constraints on posts.views stay with views_old after the swap; recreate them on the new column",
+                "-- STAGED: posts.summary is no longer used; it is dropped by a DEFERRED statement",
+            ]
+        );
+        assert_eq!(
+            migration.deferred,
+            vec![
+                "ALTER TABLE \"posts\" DROP COLUMN \"views_old\";",
+                "ALTER TABLE \"posts\" DROP COLUMN \"summary\";",
+            ]
+        );
+        assert_eq!(
+            migration.staged,
+            vec![
+                "column posts.views BIGINT → INTEGER",
+                "column posts.summary dropped"
+            ]
+        );
+        assert!(migration.destructive.is_empty());
+
+        // Removed tables wait too; MySQL relaxes NOT NULL with MODIFY
+        let (mut old_mysql, mut new_mysql) = (old.clone(), old);
+        old_mysql.config.database = DatabaseType::MySQL;
+        new_mysql.config.database = DatabaseType::MySQL;
+        let post = post_mut(&mut new_mysql);
+        post.fields.retain(|f| f.name != "views");
+        let mut comment = Entity::new("Comment");
+        comment.fields.push(Field::new("body", DataType::Text));
+        old_mysql.add_entity(comment);
+        let migration = plan(&old_mysql, &new_mysql);
+        assert!(
+            migration
+                .statements
+                .contains(&"ALTER TABLE `posts` MODIFY COLUMN `views` BIGINT;".to_string())
+        );
+        assert_eq!(
+            migration.deferred,
+            vec![
+                "ALTER TABLE `posts` DROP COLUMN `views`;",
+                "DROP TABLE IF EXISTS `comments`;",
+            ]
+        );
+
+        let ctx = GenerationContext::from_project_default(&new_mysql);
+        let file = generate_alter_migration(&migration, &ctx).unwrap();
+        assert!(file.content.contains("--   - table comments dropped"));
+        assert!(file.content.contains(
+            "-- DEFERRED (move to a later migration)\n-- ============================================================\n\n-- ALTER TABLE `posts` DROP COLUMN `views`;"
+        ));
+    }
+
+    #[test]
+    fn test_widening_type_changes() {
+        assert!(widens("VARCHAR(50)", "VARCHAR(255)"));
+        assert!(widens("VARCHAR(255)", "TEXT"));
+        assert!(widens("INT", "BIGINT"));
+        assert!(widens("NUMERIC(10,2)", "NUMERIC(12,2)"));
+        assert!(!widens("VARCHAR(255)", "VARCHAR(50)"));
+        assert!(!widens("BIGINT", "INTEGER"));
+        assert!(!widens("NUMERIC(10,2)", "NUMERIC(10,4)"));
+        assert!(!widens("TEXT", "UUID"));
+    }
+
+    #[test]
+    fn test_alter_migration_file() {
+        let old = blog();
+        let mut new = old.clone();
+        post_mut(&mut new).fields.retain(|f| f.name != "summary");
+
+        let ctx = GenerationContext::from_project_default(&new);
+        let migration = plan(&old, &new);
+        let file = generate_alter_migration(&migration, &ctx).unwrap();
+
+        let path = file.path.to_string_lossy();
+        assert!(path.starts_with("migrations/"));
+        assert!(path.ends_with("_alter_schema.sql"));
+        assert!(file.content.contains(
+            "-- Review before applying. This migration drops:\n--   - column posts.summary"
+        ));
+        assert!(file.content.contains("-- UP"));
+        assert!(
+            file.content
+                .contains("ALTER TABLE \"posts\" DROP COLUMN \"summary\";")
+        );
+    }
+}
@@ -21,7 +21,34 @@
 //! - Timestamp columns (`created_at`, `updated_at`)
 //! - Soft-delete support (`deleted_at`)
 //! - `IF NOT EXISTS` for idempotent migrations
+//!
+//! ## Incremental Migrations
+//!
+//! Every run also writes [`SCHEMA_SNAPSHOT_FILE`], a copy of the project the
+//! migrations were generated from. When a later run is given that snapshot
+//! as its baseline (see [`GeneratorConfig::with_baseline`]), it emits a
+//! single `{timestamp}_alter_schema.sql` with only the changes since then —
+//! see [`alter`].
+//!
+//! [`GeneratorConfig::with_baseline`]: crate::GeneratorConfig::with_baseline
 
+pub mod alter;
 pub mod sql;
 
+use std::path::PathBuf;
+
+use imortal_ir::{ProjectGraph, ProjectType};
+
+pub use alter::{SchemaMigration, generate_alter_migration, plan_migration};
 pub use sql::generate_migrations;
+
+/// Snapshot of the schema the migrations describe, relative to the backend root
+pub const SCHEMA_SNAPSHOT_FILE: &str = "migrations/schema.ieng.lock";
+
+/// Where the schema snapshot lives relative to the output directory.
+pub fn schema_snapshot_path(project: &ProjectGraph) -> PathBuf {
+    match project.config.project_type {
+        ProjectType::Fullstack => PathBuf::from("backend").join(SCHEMA_SNAPSHOT_FILE),
+        _ => PathBuf::from(SCHEMA_SNAPSHOT_FILE),
+    }
+}
@@ -5,6 +5,12 @@
 //!
 //! Each entity produces one migration file. Files are ordered so that
 //! referenced (parent) tables are created before referencing (child) tables.
+//! On PostgreSQL, enum types get their own migration
+//! (`{date}000000_create_enum_types.sql`), run before every table.
+//!
+//! Table migrations are rendered from overridable templates, with the
+//! variables of [`MigrationContext`] and [`CreateTableContext`]; see
+//! [`templates`](crate::templates).
 //!
 //! ## Supported Databases
 //!
@@ -12,8 +18,10 @@
 //! - **MySQL**: Full support with appropriate type mappings
 //! - **SQLite**: Simplified types (TEXT, INTEGER, REAL, BLOB)
 
-use imortal_core::{DataType, IdType, ReferentialAction};
-use imortal_ir::DatabaseType;
+use imortal_core::{DataType, ReferentialAction};
+use imortal_ir::{ComputedToken, DatabaseType, Entity, EntityConfig, EnumDef, Field, IndexType};
+use serde::Serialize;
+use uuid::Uuid;
 
 use crate::context::{EntityInfo, GenerationContext};
 use crate::{FileType, GeneratedFile};
@@ -33,6 +41,15 @@ pub fn generate_migrations(ctx: &GenerationContext) -> Vec<GeneratedFile> {
         return files;
     }
 
+    if let Some(content) = generate_create_enum_types(ctx) {
+        let filename = ctx.migration_filename(0, ENUM_TYPES_MIGRATION);
+        files.push(GeneratedFile::new(
+            format!("migrations/{}", filename),
+            content,
+            FileType::Sql,
+        ));
+    }
+
     for (index, entity) in ctx.entities().iter().enumerate() {
         let info = EntityInfo::new(entity, ctx);
         let table = info.table_name();
@@ -43,106 +60,242 @@ pub fn generate_migrations(ctx: &GenerationContext) -> Vec<GeneratedFile> {
         files.push(GeneratedFile::new(path, content, FileType::Sql));
     }
 
+    for (index, (table, statements)) in auth_tables(ctx).into_iter().enumerate() {
+        let filename = ctx.migration_filename(ctx.entities().len() + 1 + index, table);
+        files.push(GeneratedFile::new(
+            format!("migrations/{}", filename),
+            generate_auth_table_migration(table, &statements, ctx),
+            FileType::Sql,
+        ));
+    }
+
     files
 }
 
-// ============================================================================
-// CREATE TABLE generation
-// ============================================================================
+/// Name of the migration creating the PostgreSQL enum types
+pub(crate) const ENUM_TYPES_MIGRATION: &str = "enum_types";
 
-/// Generate the full SQL content for one entity's migration file.
-fn generate_create_table(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let db = ctx.database();
-    let table = info.table_name();
-    let entity = info.entity;
+/// Table holding the refresh tokens of auth sessions
+pub(crate) const REFRESH_TOKENS_TABLE: &str = "refresh_tokens";
 
-    let mut out = String::with_capacity(2048);
+/// Table holding OAuth2 sign-ins in progress
+pub(crate) const OAUTH_STATES_TABLE: &str = "oauth_states";
 
-    // Header comment
-    out.push_str(&format!("-- Migration: Create table `{}`\n", table));
-    out.push_str(&format!("-- Entity: {}\n", info.pascal_name()));
-    if let Some(desc) = &entity.description {
-        out.push_str(&format!("-- Description: {}\n", desc));
-    }
-    out.push_str(&format!("-- Database: {}\n", database_display_name(db)));
-    out.push_str("-- Generated by Immortal Engine v2.0\n");
-    out.push_str("\n");
+/// Table linking identity provider accounts to users
+pub(crate) const OAUTH_ACCOUNTS_TABLE: &str = "oauth_accounts";
+
+// ============================================================================
+// Enum types
+// ============================================================================
 
-    // ── UP migration ─────────────────────────────────────────────────────
+/// The migration creating the project's enum types, or `None` when there
+/// are none or the database keeps enums in the column (MySQL `ENUM`,
+/// SQLite `TEXT`).
+fn generate_create_enum_types(ctx: &GenerationContext) -> Option<String> {
+    let statements = create_enum_type_statements(ctx)?;
+
+    let mut out = String::with_capacity(1024);
+    out.push_str("-- Migration: Create enum types\n");
+    out.push_str(&format!(
+        "-- Database: {}\n",
+        database_display_name(ctx.database())
+    ));
+    out.push_str("-- Generated by Immortal Engine v2.0\n\n");
 
     out.push_str("-- ============================================================\n");
     out.push_str("-- UP\n");
     out.push_str("-- ============================================================\n\n");
+    out.push_str(&statements);
 
-    // CREATE TABLE
-    out.push_str(&format!(
-        "CREATE TABLE IF NOT EXISTS {} (\n",
-        quote_identifier(&table, db)
-    ));
+    out.push_str("-- ============================================================\n");
+    out.push_str("-- DOWN\n");
+    out.push_str("-- ============================================================\n\n");
+    out.push_str(&drop_enum_type_statements(ctx).replace("DROP", "-- DROP"));
 
-    let mut column_defs: Vec<String> = Vec::new();
-    let mut constraints: Vec<String> = Vec::new();
+    Some(out)
+}
 
-    // ── columns ──────────────────────────────────────────────────────────
+/// `CREATE TYPE` for every enum, each skipped when the type exists.
+/// `None` unless the database is PostgreSQL and the project has enums.
+pub(crate) fn create_enum_type_statements(ctx: &GenerationContext) -> Option<String> {
+    if ctx.database() != DatabaseType::PostgreSQL || ctx.enums().is_empty() {
+        return None;
+    }
 
-    for field in &entity.fields {
-        let col_name = if field.column_name.is_empty() {
-            GenerationContext::snake(&field.name)
-        } else {
-            field.column_name.clone()
-        };
+    let mut out = String::with_capacity(512);
+    for def in ctx.enums() {
+        out.push_str(&create_enum_type(def));
+        out.push_str("\n\n");
+    }
+    Some(out)
+}
 
-        let mut parts: Vec<String> = Vec::new();
+/// `DROP TYPE` for every enum, in reverse order.
+pub(crate) fn drop_enum_type_statements(ctx: &GenerationContext) -> String {
+    ctx.enums()
+        .iter()
+        .rev()
+        .map(|def| {
+            format!(
+                "DROP TYPE IF EXISTS {};\n",
+                GenerationContext::enum_type_name(&def.name)
+            )
+        })
+        .collect()
+}
 
-        // Column name
-        parts.push(format!("    {}", quote_identifier(&col_name, db)));
+/// PostgreSQL `CREATE TYPE … AS ENUM`, which does nothing when the type
+/// already exists (there is no `IF NOT EXISTS` for types).
+pub(crate) fn create_enum_type(def: &EnumDef) -> String {
+    let labels: Vec<String> = def
+        .variants
+        .iter()
+        .map(|v| format!("'{}'", v.replace('\'', "''")))
+        .collect();
+    format!(
+        "DO $$ BEGIN\n    CREATE TYPE {} AS ENUM ({});\nEXCEPTION\n    WHEN duplicate_object THEN NULL;\nEND $$;",
+        GenerationContext::enum_type_name(&def.name),
+        labels.join(", ")
+    )
+}
 
-        // Column type
-        if field.is_primary_key {
-            parts.push(GenerationContext::pk_sql_type(info.id_type(), db));
-        } else {
-            let is_nullable = matches!(field.data_type, DataType::Optional(_));
-            let sql_ty = GenerationContext::sql_type(&field.data_type, db);
-            parts.push(sql_ty);
-
-            // NOT NULL (skip for optional types and for primary keys handled above)
-            if field.required && !is_nullable {
-                parts.push("NOT NULL".to_string());
-            }
-        }
+// ============================================================================
+// Table description
+// ============================================================================
 
-        // PRIMARY KEY (inline for simplicity)
-        if field.is_primary_key {
+/// A column as it appears in the generated `CREATE TABLE`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ColumnDef {
+    /// The field the column comes from; `None` for the implied timestamp
+    /// and soft-delete columns
+    pub field_id: Option<Uuid>,
+    pub name: String,
+    pub sql_type: String,
+    pub not_null: bool,
+    pub primary_key: bool,
+    pub unique: bool,
+    /// Full `DEFAULT …` clause
+    pub default: Option<String>,
+    /// Full `GENERATED ALWAYS AS (…) STORED` clause of a computed column
+    pub generated: Option<String>,
+}
+
+impl ColumnDef {
+    /// The column definition:
+    /// `"name" TYPE [GENERATED …] [NOT NULL] [PRIMARY KEY] [UNIQUE] [DEFAULT …]`.
+    pub(crate) fn render(&self, db: DatabaseType) -> String {
+        let mut parts = vec![quote_identifier(&self.name, db), self.sql_type.clone()];
+        if let Some(generated) = &self.generated {
+            parts.push(generated.clone());
+        }
+        if self.not_null {
+            parts.push("NOT NULL".to_string());
+        }
+        if self.primary_key {
             parts.push("PRIMARY KEY".to_string());
         }
-
-        // UNIQUE
-        if field.unique && !field.is_primary_key {
+        if self.unique {
             parts.push("UNIQUE".to_string());
         }
+        if let Some(default) = &self.default {
+            parts.push(default.clone());
+        }
+        parts.join(" ")
+    }
 
-        // DEFAULT
-        if let Some(default_clause) = GenerationContext::sql_default(field, db) {
-            if !default_clause.is_empty() {
-                parts.push(default_clause);
-            }
+    /// An implied `TIMESTAMP`-typed column (`created_at`, `deleted_at`, …).
+    fn implied(name: &str, not_null: bool, default: &str, db: DatabaseType) -> Self {
+        Self {
+            field_id: None,
+            name: name.to_string(),
+            sql_type: GenerationContext::sql_type(&DataType::DateTime, db),
+            not_null,
+            primary_key: false,
+            unique: false,
+            default: Some(default.to_string()),
+            generated: None,
         }
+    }
+}
+
+/// An out-of-line foreign key constraint.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ForeignKeyDef {
+    /// Constraint name (`fk_{table}_{column}`)
+    pub name: String,
+    /// `FOREIGN KEY (…) REFERENCES … ON DELETE … ON UPDATE …`
+    pub clause: String,
+}
+
+/// A `CREATE [UNIQUE] INDEX` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IndexDef {
+    pub name: String,
+    /// The full statement, including the trailing `;`
+    pub sql: String,
+}
+
+/// Everything the migrations create for one entity's table.
+#[derive(Debug, Clone)]
+pub(crate) struct TableDef {
+    pub name: String,
+    pub schema: Option<String>,
+    pub options: TableOptions,
+    pub columns: Vec<ColumnDef>,
+    pub foreign_keys: Vec<ForeignKeyDef>,
+    pub indexes: Vec<IndexDef>,
+}
+
+impl TableDef {
+    /// Describe an entity's table as the target database sees it.
+    pub(crate) fn of(info: &EntityInfo, ctx: &GenerationContext) -> Self {
+        let db = ctx.database();
+        let table = info.table_name();
+        let entity = info.entity;
+
+        let mut columns = Vec::new();
+        let mut foreign_keys = Vec::new();
 
-        column_defs.push(parts.join(" "));
+        // Computed getters live on the model, not in the table
+        for field in entity.fields.iter().filter(|f| f.is_column()) {
+            let col_name = column_name(field);
 
-        // ── foreign key constraint (out-of-line) ─────────────────────────
-        if field.is_foreign_key {
+            let (sql_type, not_null) = if field.is_primary_key {
+                (GenerationContext::pk_sql_type(info.id_type(), db), false)
+            } else {
+                // NOT NULL is skipped for optional types
+                let is_nullable = matches!(field.data_type, DataType::Optional(_));
+                (
+                    GenerationContext::sql_type(&field.data_type, db),
+                    field.required && !is_nullable,
+                )
+            };
+
+            columns.push(ColumnDef {
+                field_id: Some(field.id),
+                name: col_name.clone(),
+                sql_type,
+                not_null,
+                primary_key: field.is_primary_key,
+                unique: field.unique && !field.is_primary_key,
+                default: GenerationContext::sql_default(field, db)
+                    .filter(|d| !d.is_empty() && !field.is_computed()),
+                generated: generated_clause(field, entity, db),
+            });
+
+            if !field.is_foreign_key {
+                continue;
+            }
             if let Some(fk) = &field.foreign_key_ref {
-                let ref_entity = ctx.entity_by_id(fk.entity_id);
-                let ref_table = ref_entity
+                let ref_table = ctx
+                    .entity_by_id(fk.entity_id)
                     .map(|e| {
-                        if e.table_name.is_empty() {
-                            GenerationContext::table_name(&e.name)
-                        } else {
-                            e.table_name.clone()
-                        }
+                        let ref_info = EntityInfo::new(e, ctx);
+                        quote_table(&ref_info.table_name(), ref_info.schema(), db)
                     })
-                    .unwrap_or_else(|| GenerationContext::table_name(&fk.entity_name));
+                    .unwrap_or_else(|| {
+                        quote_identifier(&GenerationContext::table_name(&fk.entity_name), db)
+                    });
 
                 let ref_col = if fk.field_name.is_empty() {
                     "id".to_string()
@@ -150,120 +303,423 @@ fn generate_create_table(info: &EntityInfo, ctx: &GenerationContext) -> String {
                     fk.field_name.clone()
                 };
 
-                let on_delete = referential_action_sql(&fk.on_delete);
-                let on_update = referential_action_sql(&fk.on_update);
-
-                let constraint_name = format!("fk_{}_{}", table, col_name);
+                foreign_keys.push(ForeignKeyDef {
+                    name: format!("fk_{}_{}", table, col_name),
+                    clause: format!(
+                        "FOREIGN KEY ({}) REFERENCES {} ({}) ON DELETE {} ON UPDATE {}",
+                        quote_identifier(&col_name, db),
+                        ref_table,
+                        quote_identifier(&ref_col, db),
+                        referential_action_sql(&fk.on_delete),
+                        referential_action_sql(&fk.on_update),
+                    ),
+                });
+            }
+        }
 
-                constraints.push(format!(
-                    "    CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({}) ON DELETE {} ON UPDATE {}",
-                    quote_identifier(&constraint_name, db),
-                    quote_identifier(&col_name, db),
-                    quote_identifier(&ref_table, db),
-                    quote_identifier(&ref_col, db),
-                    on_delete,
-                    on_update,
-                ));
+        // Timestamp columns (if enabled and not already present)
+        let has_column = |name: &str| {
+            entity
+                .fields
+                .iter()
+                .any(|f| f.name == name || f.column_name == name)
+        };
+        if info.has_timestamps() {
+            for name in ["created_at", "updated_at"] {
+                if !has_column(name) {
+                    columns.push(ColumnDef::implied(
+                        name,
+                        true,
+                        "DEFAULT CURRENT_TIMESTAMP",
+                        db,
+                    ));
+                }
             }
         }
+
+        // Soft-delete column
+        if info.has_soft_delete() && !has_column("deleted_at") {
+            columns.push(ColumnDef::implied("deleted_at", false, "DEFAULT NULL", db));
+        }
+
+        Self {
+            name: table,
+            schema: info.schema().map(str::to_string),
+            options: TableOptions::of(&entity.config, db),
+            columns,
+            foreign_keys,
+            indexes: table_indexes(info, ctx),
+        }
     }
 
-    // ── timestamp columns (if enabled and not already present) ───────────
-    if info.has_timestamps() {
-        let has_created = entity
-            .fields
-            .iter()
-            .any(|f| f.name == "created_at" || f.column_name == "created_at");
-        let has_updated = entity
-            .fields
-            .iter()
-            .any(|f| f.name == "updated_at" || f.column_name == "updated_at");
-
-        if !has_created {
-            column_defs.push(format!(
-                "    {} {} NOT NULL DEFAULT CURRENT_TIMESTAMP",
-                quote_identifier("created_at", db),
-                GenerationContext::sql_type(&DataType::DateTime, db),
-            ));
+    /// The quoted, schema-qualified table name.
+    pub(crate) fn qualified(&self, db: DatabaseType) -> String {
+        quote_table(&self.name, self.schema.as_deref(), db)
+    }
+}
+
+/// Storage options of a table, kept only where the target database
+/// honours them (validation warns about the others)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct TableOptions {
+    /// PostgreSQL tablespace
+    pub tablespace: Option<String>,
+    /// MySQL storage engine
+    pub engine: Option<String>,
+    /// MySQL default character set
+    pub charset: Option<String>,
+    /// MySQL default collation
+    pub collation: Option<String>,
+}
+
+impl TableOptions {
+    fn of(config: &EntityConfig, db: DatabaseType) -> Self {
+        let keep = |value: Option<&str>, target: DatabaseType| {
+            value.filter(|_| db == target).map(str::to_string)
+        };
+        Self {
+            tablespace: keep(config.tablespace_name(), DatabaseType::PostgreSQL),
+            engine: keep(config.engine_name(), DatabaseType::MySQL),
+            charset: keep(config.charset_name(), DatabaseType::MySQL),
+            collation: keep(config.collation_name(), DatabaseType::MySQL),
+        }
+    }
+
+    /// The options as they follow the column list of `CREATE TABLE`,
+    /// each with a leading space (empty when none are set)
+    pub(crate) fn render(&self, db: DatabaseType) -> String {
+        let mut out = String::new();
+        if let Some(tablespace) = &self.tablespace {
+            out.push_str(&format!(" TABLESPACE {}", quote_identifier(tablespace, db)));
+        }
+        if let Some(engine) = &self.engine {
+            out.push_str(&format!(" ENGINE={}", engine));
         }
-        if !has_updated {
-            column_defs.push(format!(
-                "    {} {} NOT NULL DEFAULT CURRENT_TIMESTAMP",
-                quote_identifier("updated_at", db),
-                GenerationContext::sql_type(&DataType::DateTime, db),
-            ));
+        if let Some(charset) = &self.charset {
+            out.push_str(&format!(" DEFAULT CHARSET={}", charset));
         }
+        if let Some(collation) = &self.collation {
+            out.push_str(&format!(" COLLATE={}", collation));
+        }
+        out
     }
+}
 
-    // ── soft-delete column ───────────────────────────────────────────────
-    if info.has_soft_delete() {
-        let has_deleted = entity
-            .fields
+/// `GENERATED ALWAYS AS (…) STORED` for a computed field the database
+/// stores. Field names become quoted column names; `||` becomes `CONCAT`
+/// on MySQL, and on PostgreSQL non-text operands are cast so the
+/// expression stays immutable.
+fn generated_clause(field: &Field, entity: &Entity, db: DatabaseType) -> Option<String> {
+    let computed = field.computed.as_ref().filter(|c| c.is_stored())?;
+    let tokens = computed.tokens().ok()?;
+    let concat = computed.is_concat();
+
+    let operand = |token: &ComputedToken| -> String {
+        match token {
+            ComputedToken::Field(name) => {
+                let source = entity.fields.iter().find(|f| &f.name == name);
+                let column = quote_identifier(&source.map(column_name).unwrap_or(name.clone()), db);
+                let text = source.is_some_and(|f| {
+                    matches!(
+                        &f.data_type,
+                        DataType::String | DataType::Text
+                    ) || matches!(
+                        &f.data_type,
+                        DataType::Optional(inner) if matches!(**inner, DataType::String | DataType::Text)
+                    )
+                });
+                if concat && db == DatabaseType::PostgreSQL && !text {
+                    format!("CAST({} AS TEXT)", column)
+                } else {
+                    column
+                }
+            }
+            ComputedToken::Text(text) => format!("'{}'", text.replace('\'', "''")),
+            ComputedToken::Number(number) => number.clone(),
+            ComputedToken::Op(op) => op.symbol().to_string(),
+            ComputedToken::LParen => "(".to_string(),
+            ComputedToken::RParen => ")".to_string(),
+        }
+    };
+
+    let expression = if concat && db == DatabaseType::MySQL {
+        let operands: Vec<String> = tokens
             .iter()
-            .any(|f| f.name == "deleted_at" || f.column_name == "deleted_at");
-        if !has_deleted {
-            column_defs.push(format!(
-                "    {} {} DEFAULT NULL",
-                quote_identifier("deleted_at", db),
-                GenerationContext::sql_type(&DataType::DateTime, db),
-            ));
+            .filter(|t| !matches!(t, ComputedToken::Op(_)))
+            .map(operand)
+            .collect();
+        format!("CONCAT({})", operands.join(", "))
+    } else {
+        let mut out = String::new();
+        for token in &tokens {
+            if !out.is_empty() && !out.ends_with('(') && *token != ComputedToken::RParen {
+                out.push(' ');
+            }
+            out.push_str(&operand(token));
         }
+        out
+    };
+
+    Some(format!("GENERATED ALWAYS AS ({}) STORED", expression))
+}
+
+/// Column name of a field: its explicit column name, or its snake_case name.
+fn column_name(field: &Field) -> String {
+    if field.column_name.is_empty() {
+        GenerationContext::snake(&field.name)
+    } else {
+        field.column_name.clone()
     }
+}
 
-    // Combine columns and constraints
-    let mut all_lines: Vec<String> = column_defs;
-    all_lines.extend(constraints);
+// ============================================================================
+// CREATE TABLE generation
+// ============================================================================
 
-    out.push_str(&all_lines.join(",\n"));
-    out.push('\n');
-    out.push_str(");\n\n");
+/// Generate the full SQL content for one entity's migration file.
+fn generate_create_table(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let db = ctx.database();
+    let table = info.table_name();
+    let context = MigrationContext {
+        qualified: quote_table(&table, info.schema(), db),
+        entity: Some(info.pascal_name()),
+        description: info.entity.description.clone(),
+        database_name: database_display_name(db),
+        statements: create_table_statements(info, ctx),
+        table,
+    };
+    ctx.render("migrations/migration.sql.jinja", context)
+}
 
-    // ── indexes ──────────────────────────────────────────────────────────
+/// The statements that create one entity's table: its schema, the
+/// `CREATE TABLE` itself, indexes and (PostgreSQL) comments.
+pub(crate) fn create_table_statements(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    ctx.render(
+        "migrations/create_table.sql.jinja",
+        CreateTableContext::new(info, ctx),
+    )
+}
 
-    let indexes = generate_indexes(info, ctx);
-    if !indexes.is_empty() {
-        out.push_str(&indexes);
-        out.push('\n');
-    }
+// ============================================================================
+// Template context
+// ============================================================================
 
-    // ── comments (PostgreSQL only) ───────────────────────────────────────
+/// Variables of the `migrations/migration.sql.jinja` template, which renders
+/// the migration creating one table (see [`templates`](crate::templates)).
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationContext {
+    /// Table name
+    pub table: String,
+    /// Quoted table name, schema-qualified if the table has a schema
+    pub qualified: String,
+    /// Entity the table stores, in PascalCase (none for the auth tables)
+    pub entity: Option<String>,
+    /// The entity's description
+    pub description: Option<String>,
+    /// Database product name, e.g. `PostgreSQL`
+    pub database_name: &'static str,
+    /// The statements creating the table, ending in a blank line
+    pub statements: String,
+}
 
-    if db == DatabaseType::PostgreSQL {
-        if let Some(desc) = &entity.description {
-            out.push_str(&format!(
-                "COMMENT ON TABLE {} IS '{}';\n",
-                quote_identifier(&table, db),
-                desc.replace('\'', "''")
-            ));
-        }
-        for field in &entity.fields {
-            if let Some(desc) = &field.description {
-                let col_name = if field.column_name.is_empty() {
-                    GenerationContext::snake(&field.name)
-                } else {
-                    field.column_name.clone()
-                };
-                out.push_str(&format!(
-                    "COMMENT ON COLUMN {}.{} IS '{}';\n",
-                    quote_identifier(&table, db),
-                    quote_identifier(&col_name, db),
-                    desc.replace('\'', "''")
-                ));
-            }
+/// Variables of the `migrations/create_table.sql.jinja` template, which
+/// renders the statements creating one entity's table, in its migration and
+/// wherever the table is created whole (see [`templates`](crate::templates)).
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateTableContext {
+    /// `postgresql`, `mysql` or `sqlite`
+    pub database: DatabaseType,
+    /// Quoted table name, schema-qualified if the table has a schema
+    pub table: String,
+    /// Quoted schema to create first, if the table is outside the default
+    /// one
+    pub schema: Option<String>,
+    /// Column definitions, then the foreign key constraints
+    pub definitions: Vec<String>,
+    /// Table options following the closing parenthesis (e.g. MySQL's
+    /// `ENGINE=InnoDB`), with their leading space
+    pub options: String,
+    /// `CREATE INDEX` statements
+    pub indexes: Vec<String>,
+    /// The entity's description, with `'` doubled
+    pub comment: Option<String>,
+    /// Descriptions of the columns that have one
+    pub column_comments: Vec<ColumnComment>,
+}
+
+/// The description of one column, for `COMMENT ON COLUMN`
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnComment {
+    /// Quoted column name
+    pub name: String,
+    /// The field's description, with `'` doubled
+    pub comment: String,
+}
+
+impl CreateTableContext {
+    /// The variables for `info`'s table.
+    pub fn new(info: &EntityInfo, ctx: &GenerationContext) -> Self {
+        let db = ctx.database();
+        let def = TableDef::of(info, ctx);
+        let entity = info.entity;
+
+        // Columns, then out-of-line constraints
+        let mut definitions: Vec<String> = def.columns.iter().map(|c| c.render(db)).collect();
+        definitions.extend(def.foreign_keys.iter().map(|fk| {
+            format!(
+                "CONSTRAINT {} {}",
+                quote_identifier(&fk.name, db),
+                fk.clause
+            )
+        }));
+
+        Self {
+            database: db,
+            table: def.qualified(db),
+            schema: def.schema.as_deref().map(|s| quote_identifier(s, db)),
+            definitions,
+            options: def.options.render(db),
+            indexes: def.indexes.iter().map(|index| index.sql.clone()).collect(),
+            comment: entity.description.as_ref().map(|d| d.replace('\'', "''")),
+            column_comments: entity
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    Some(ColumnComment {
+                        name: quote_identifier(&column_name(field), db),
+                        comment: field.description.as_ref()?.replace('\'', "''"),
+                    })
+                })
+                .collect(),
         }
-        out.push('\n');
     }
+}
 
-    // ── DOWN migration ───────────────────────────────────────────────────
+// ============================================================================
+// Auth tables
+// ============================================================================
 
-    out.push_str("-- ============================================================\n");
-    out.push_str("-- DOWN\n");
-    out.push_str("-- ============================================================\n\n");
+/// The tables auth keeps besides the entities', each with the statements
+/// creating it, in migration order: refresh tokens, then the OAuth2 states
+/// and linked accounts.
+pub(crate) fn auth_tables(ctx: &GenerationContext) -> Vec<(&'static str, String)> {
+    let mut tables = Vec::new();
+    if ctx.refresh_tokens() {
+        tables.push((REFRESH_TOKENS_TABLE, refresh_tokens_statements(ctx)));
+    }
+    if !ctx.oauth_providers().is_empty() {
+        tables.push((OAUTH_STATES_TABLE, oauth_states_statements(ctx)));
+        tables.push((OAUTH_ACCOUNTS_TABLE, oauth_accounts_statements(ctx)));
+    }
+    tables
+}
 
-    out.push_str(&format!(
-        "-- DROP TABLE IF EXISTS {};\n",
-        quote_identifier(&table, db),
-    ));
+/// The migration creating one of the [`auth_tables`].
+fn generate_auth_table_migration(table: &str, statements: &str, ctx: &GenerationContext) -> String {
+    let db = ctx.database();
+    let context = MigrationContext {
+        table: table.to_string(),
+        qualified: quote_identifier(table, db),
+        entity: None,
+        description: None,
+        database_name: database_display_name(db),
+        statements: statements.to_string(),
+    };
+    ctx.render("migrations/migration.sql.jinja", context)
+}
+
+/// The statements creating the refresh token table: one row per token
+/// issued, stored as a SHA-256 hash. Rotating a token revokes its row and
+/// adds one to the same `session_id`; times are UNIX seconds.
+pub(crate) fn refresh_tokens_statements(ctx: &GenerationContext) -> String {
+    auth_table_statements(
+        REFRESH_TOKENS_TABLE,
+        &[
+            ("token_hash", "VARCHAR(64) NOT NULL"),
+            ("session_id", "VARCHAR(36) NOT NULL"),
+            ("user_id", "VARCHAR(255) NOT NULL"),
+            ("identifier", "VARCHAR(255) NOT NULL"),
+            ("roles", "TEXT NOT NULL"),
+            ("expires_at", "BIGINT NOT NULL"),
+            ("revoked_at", "BIGINT"),
+            ("created_at", "BIGINT NOT NULL"),
+        ],
+        &["token_hash"],
+        &["session_id", "user_id"],
+        ctx,
+    )
+}
+
+/// The statements creating the OAuth2 state table: one row per sign-in
+/// started, keyed by the `state` sent to the provider, holding the PKCE
+/// verifier until the callback takes it.
+pub(crate) fn oauth_states_statements(ctx: &GenerationContext) -> String {
+    auth_table_statements(
+        OAUTH_STATES_TABLE,
+        &[
+            ("state", "VARCHAR(64) NOT NULL"),
+            ("provider", "VARCHAR(64) NOT NULL"),
+            ("code_verifier", "VARCHAR(128) NOT NULL"),
+            ("expires_at", "BIGINT NOT NULL"),
+        ],
+        &["state"],
+        &[],
+        ctx,
+    )
+}
+
+/// The statements creating the linked account table: the user each
+/// provider account signs in as, keyed by the provider's subject.
+pub(crate) fn oauth_accounts_statements(ctx: &GenerationContext) -> String {
+    auth_table_statements(
+        OAUTH_ACCOUNTS_TABLE,
+        &[
+            ("provider", "VARCHAR(64) NOT NULL"),
+            ("subject", "VARCHAR(255) NOT NULL"),
+            ("user_id", "VARCHAR(255) NOT NULL"),
+            ("email", "VARCHAR(255)"),
+            ("created_at", "BIGINT NOT NULL"),
+        ],
+        &["provider", "subject"],
+        &["user_id"],
+        ctx,
+    )
+}
+
+/// `CREATE TABLE` for an auth table, then an index per `indexed` column.
+fn auth_table_statements(
+    table: &str,
+    columns: &[(&str, &str)],
+    primary_key: &[&str],
+    indexed: &[&str],
+    ctx: &GenerationContext,
+) -> String {
+    let db = ctx.database();
+    let quoted = quote_identifier(table, db);
+    let column = |name: &str| quote_identifier(name, db);
+
+    let mut lines: Vec<String> = columns
+        .iter()
+        .map(|(name, definition)| format!("    {} {}", column(name), definition))
+        .collect();
+    let key: Vec<String> = primary_key.iter().map(|name| column(name)).collect();
+    lines.push(format!("    PRIMARY KEY ({})", key.join(", ")));
+
+    let mut out = format!("CREATE TABLE IF NOT EXISTS {} (\n", quoted);
+    out.push_str(&lines.join(",\n"));
+    out.push_str("\n);\n\n");
+
+    for name in indexed {
+        out.push_str(&format!(
+            "CREATE INDEX IF NOT EXISTS {} ON {} ({});\n",
+            column(&format!("idx_{}_{}", table, name)),
+            quoted,
+            column(name)
+        ));
+    }
+    if !indexed.is_empty() {
+        out.push('\n');
+    }
 
     out
 }
@@ -272,62 +728,124 @@ fn generate_create_table(info: &EntityInfo, ctx: &GenerationContext) -> String {
 // Index generation
 // ============================================================================
 
-/// Generate CREATE INDEX statements for fields marked as `indexed` and for
-/// foreign key columns.
-fn generate_indexes(info: &EntityInfo, ctx: &GenerationContext) -> String {
+/// CREATE INDEX statements for fields marked as `indexed`, for every
+/// foreign key column when the project's "always index foreign keys" policy
+/// is on, and for the entity's composite unique constraints and indexes.
+fn table_indexes(info: &EntityInfo, ctx: &GenerationContext) -> Vec<IndexDef> {
     let db = ctx.database();
     let table = info.table_name();
+    let qualified = quote_table(&table, info.schema(), db);
     let entity = info.entity;
 
-    let mut out = String::new();
+    let mut indexes = Vec::new();
 
-    for field in &entity.fields {
+    for field in entity.fields.iter().filter(|f| f.is_column()) {
         // Skip primary keys (already indexed) and non-indexed fields
         if field.is_primary_key {
             continue;
         }
 
-        let col_name = if field.column_name.is_empty() {
-            GenerationContext::snake(&field.name)
-        } else {
-            field.column_name.clone()
-        };
-
-        let needs_index = field.indexed || field.is_foreign_key;
+        let col_name = column_name(field);
+        let needs_index = field.indexed || (field.is_foreign_key && ctx.index_foreign_keys());
 
         if needs_index {
-            let index_name = format!("idx_{}_{}", table, col_name);
-
-            if field.unique {
-                out.push_str(&format!(
-                    "CREATE UNIQUE INDEX IF NOT EXISTS {} ON {} ({});\n",
-                    quote_identifier(&index_name, db),
-                    quote_identifier(&table, db),
-                    quote_identifier(&col_name, db),
-                ));
-            } else {
-                out.push_str(&format!(
-                    "CREATE INDEX IF NOT EXISTS {} ON {} ({});\n",
-                    quote_identifier(&index_name, db),
-                    quote_identifier(&table, db),
+            let name = format!("idx_{}_{}", table, col_name);
+            let unique = if field.unique { "UNIQUE " } else { "" };
+            indexes.push(IndexDef {
+                sql: format!(
+                    "CREATE {}INDEX IF NOT EXISTS {} ON {} ({});",
+                    unique,
+                    quote_identifier(&name, db),
+                    qualified,
                     quote_identifier(&col_name, db),
-                ));
-            }
+                ),
+                name,
+            });
         }
     }
 
+    // Entity-level unique constraints (including fields unique per parent)
+    // and indexes. Uniqueness is enforced with a unique index, which every
+    // database can add to an existing table.
+    let implied = entity.config.implied_columns();
+    let columns_of = |fields: &[String]| -> Option<Vec<String>> {
+        fields
+            .iter()
+            .map(|name| match entity.get_field_by_name(name) {
+                Some(field) => field.is_column().then(|| column_name(field)),
+                None => implied.contains(&name.as_str()).then(|| name.clone()),
+            })
+            .collect()
+    };
+    let column_list = |columns: &[String]| -> String {
+        columns
+            .iter()
+            .map(|c| quote_identifier(c, db))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    for fields in &info.unique_together() {
+        let Some(columns) = columns_of(fields).filter(|c| !c.is_empty()) else {
+            continue;
+        };
+        let name = format!("uq_{}_{}", table, columns.join("_"));
+        indexes.push(IndexDef {
+            sql: format!(
+                "CREATE UNIQUE INDEX IF NOT EXISTS {} ON {} ({});",
+                quote_identifier(&name, db),
+                qualified,
+                column_list(&columns),
+            ),
+            name,
+        });
+    }
+
+    for index in &entity.config.indexes {
+        let Some(columns) = columns_of(&index.fields).filter(|c| !c.is_empty()) else {
+            continue;
+        };
+        let name = format!("idx_{}_{}", table, columns.join("_"));
+        // Unsupported types fall back to the default (validation warns)
+        let method = Some(index.index_type)
+            .filter(|t| *t != IndexType::BTree && t.supported_by(db))
+            .map(|t| t.sql_name().to_string());
+        let sql = match (db, method) {
+            (DatabaseType::PostgreSQL, Some(method)) => format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} USING {} ({});",
+                quote_identifier(&name, db),
+                qualified,
+                method,
+                column_list(&columns),
+            ),
+            (_, method) => format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} ({}){};",
+                quote_identifier(&name, db),
+                qualified,
+                column_list(&columns),
+                method
+                    .map(|m| format!(" USING {}", m.to_uppercase()))
+                    .unwrap_or_default(),
+            ),
+        };
+        indexes.push(IndexDef { sql, name });
+    }
+
     // Soft-delete index (if enabled)
     if info.has_soft_delete() {
-        let index_name = format!("idx_{}_deleted_at", table);
-        out.push_str(&format!(
-            "CREATE INDEX IF NOT EXISTS {} ON {} ({});\n",
-            quote_identifier(&index_name, db),
-            quote_identifier(&table, db),
-            quote_identifier("deleted_at", db),
-        ));
+        let name = format!("idx_{}_deleted_at", table);
+        indexes.push(IndexDef {
+            sql: format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} ({});",
+                quote_identifier(&name, db),
+                qualified,
+                quote_identifier("deleted_at", db),
+            ),
+            name,
+        });
     }
 
-    out
+    indexes
 }
 
 // ============================================================================
@@ -335,15 +853,27 @@ fn generate_indexes(info: &EntityInfo, ctx: &GenerationContext) -> String {
 // ============================================================================
 
 /// Quote an identifier according to the target database dialect.
-fn quote_identifier(name: &str, db: DatabaseType) -> String {
+pub(crate) fn quote_identifier(name: &str, db: DatabaseType) -> String {
     match db {
         DatabaseType::PostgreSQL | DatabaseType::SQLite => format!("\"{}\"", name),
         DatabaseType::MySQL => format!("`{}`", name),
     }
 }
 
+/// Quote a table name, qualified by its schema when one is set.
+pub(crate) fn quote_table(table: &str, schema: Option<&str>, db: DatabaseType) -> String {
+    match schema {
+        Some(schema) => format!(
+            "{}.{}",
+            quote_identifier(schema, db),
+            quote_identifier(table, db)
+        ),
+        None => quote_identifier(table, db),
+    }
+}
+
 /// Map a `ReferentialAction` to its SQL keyword.
-fn referential_action_sql(action: &ReferentialAction) -> &'static str {
+pub(crate) fn referential_action_sql(action: &ReferentialAction) -> &'static str {
     match action {
         ReferentialAction::Cascade => "CASCADE",
         ReferentialAction::SetNull => "SET NULL",
@@ -354,7 +884,7 @@ fn referential_action_sql(action: &ReferentialAction) -> &'static str {
 }
 
 /// Human-readable database name for migration header comments.
-fn database_display_name(db: DatabaseType) -> &'static str {
+pub(crate) fn database_display_name(db: DatabaseType) -> &'static str {
     match db {
         DatabaseType::PostgreSQL => "PostgreSQL",
         DatabaseType::MySQL => "MySQL",
@@ -370,7 +900,10 @@ fn database_display_name(db: DatabaseType) -> &'static str {
 mod tests {
     use super::*;
     use imortal_core::{DataType, IdType, ReferentialAction};
-    use imortal_ir::{DatabaseType, Entity, EntityConfig, Field, ForeignKeyRef, ProjectGraph};
+    use imortal_ir::{
+        AuthConfig, DatabaseType, Entity, EntityConfig, EntityIndex, Field, ForeignKeyRef,
+        OAuthProvider, ProjectGraph,
+    };
     use uuid::Uuid;
 
     /// Helper: create a basic entity with some fields.
@@ -455,6 +988,61 @@ mod tests {
         assert!(content.contains("\"updated_at\""));
     }
 
+    #[test]
+    fn test_refresh_tokens_migration() {
+        let mut project = ProjectGraph::new("blog");
+        project.add_entity(make_user_entity());
+        project.config.auth = AuthConfig::jwt();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(generate_migrations(&ctx).len(), 1);
+
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_migrations(&ctx);
+        assert_eq!(files.len(), 2);
+        assert!(
+            files[1]
+                .path
+                .to_string_lossy()
+                .contains("_create_refresh_tokens.sql")
+        );
+
+        let content = &files[1].content;
+        assert!(content.contains("CREATE TABLE IF NOT EXISTS \"refresh_tokens\""));
+        assert!(content.contains("\"token_hash\" VARCHAR(64) NOT NULL,"));
+        assert!(content.contains("PRIMARY KEY (\"token_hash\")"));
+        assert!(content.contains("\"revoked_at\" BIGINT,"));
+        assert!(content.contains(
+            "CREATE INDEX IF NOT EXISTS \"idx_refresh_tokens_session_id\" ON \"refresh_tokens\" (\"session_id\");"
+        ));
+        assert!(content.contains("-- DROP TABLE IF EXISTS \"refresh_tokens\";"));
+    }
+
+    #[test]
+    fn test_oauth_migrations() {
+        let mut project = ProjectGraph::new("blog");
+        project.add_entity(make_user_entity());
+        project.config.auth = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::google())
+            .with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_migrations(&ctx);
+        let names: Vec<String> = files
+            .iter()
+            .map(|f| f.path.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names.len(), 4, "{:?}", names);
+        assert!(names[1].ends_with("_create_refresh_tokens.sql"));
+        assert!(names[2].ends_with("_create_oauth_states.sql"));
+        assert!(names[3].ends_with("_create_oauth_accounts.sql"));
+
+        let accounts = &files[3].content;
+        assert!(accounts.contains("\"email\" VARCHAR(255),"));
+        assert!(accounts.contains("PRIMARY KEY (\"provider\", \"subject\")"));
+        assert!(accounts.contains("\"idx_oauth_accounts_user_id\""));
+        assert!(!files[2].content.contains("CREATE INDEX"));
+    }
+
     #[test]
     fn test_generate_migrations_with_fk() {
         let mut project = ProjectGraph::new("blog");
@@ -484,6 +1072,204 @@ mod tests {
         assert!(content.contains("idx_posts_user_id"));
     }
 
+    #[test]
+    fn test_fk_index_policy() {
+        let mut project = ProjectGraph::new("blog");
+        let user = make_user_entity();
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let mut post = make_post_entity(user_id);
+        post.fields.iter_mut().for_each(|f| f.indexed = false);
+        project.add_entity(post);
+
+        let posts_sql = |project: &ProjectGraph| {
+            let ctx = GenerationContext::from_project_default(project);
+            generate_migrations(&ctx)
+                .into_iter()
+                .find(|f| f.path.to_string_lossy().contains("posts"))
+                .unwrap()
+                .content
+        };
+
+        // Policy on (default): indexed even though the field opts out
+        assert!(posts_sql(&project).contains("idx_posts_user_id"));
+
+        project.config.index_foreign_keys = false;
+        assert!(!posts_sql(&project).contains("idx_posts_user_id"));
+    }
+
+    #[test]
+    fn test_composite_constraints() {
+        let mut project = ProjectGraph::new("blog");
+        let user = make_user_entity();
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let mut post = make_post_entity(user_id);
+        post.config = post
+            .config
+            .with_unique_together(["user_id", "title"])
+            .with_index(EntityIndex::new(["user_id", "created_at"]))
+            .with_index(EntityIndex::new(["user_id", "published_at"]))
+            .with_index(EntityIndex::new(["content"]).with_type(IndexType::Gin))
+            .with_index(EntityIndex::new(["title"]).with_type(IndexType::Hash));
+        project.add_entity(post);
+
+        let posts_sql = |project: &ProjectGraph| {
+            let ctx = GenerationContext::from_project_default(project);
+            generate_migrations(&ctx)
+                .into_iter()
+                .find(|f| f.path.to_string_lossy().contains("posts"))
+                .unwrap()
+                .content
+        };
+
+        let sql = posts_sql(&project);
+        assert!(sql.contains(
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"uq_posts_user_id_title\" ON \"posts\" (\"user_id\", \"title\");"
+        ));
+        // Implied timestamp columns can be indexed; unknown fields can't
+        assert!(sql.contains("ON \"posts\" (\"user_id\", \"created_at\");"));
+        assert!(!sql.contains("published_at"));
+        assert!(sql.contains(
+            "CREATE INDEX IF NOT EXISTS \"idx_posts_content\" ON \"posts\" USING gin (\"content\");"
+        ));
+        assert!(sql.contains("USING hash (\"title\");"));
+
+        project.config.database = DatabaseType::MySQL;
+        let sql = posts_sql(&project);
+        assert!(sql.contains("ON `posts` (`title`) USING HASH;"));
+        // MySQL has no GIN indexes: a plain one instead
+        assert!(
+            sql.contains("CREATE INDEX IF NOT EXISTS `idx_posts_content` ON `posts` (`content`);")
+        );
+
+        project.config.database = DatabaseType::SQLite;
+        let sql = posts_sql(&project);
+        assert!(sql.contains("ON \"posts\" (\"title\");"));
+        assert!(!sql.contains("USING"));
+    }
+
+    #[test]
+    fn test_unique_per_parent_index() {
+        let mut project = ProjectGraph::new("blog");
+        let user = make_user_entity();
+        let user_id = user.id;
+        project.add_entity(user);
+        let post = make_post_entity(user_id);
+        let post_id = post.id;
+        project.add_entity(post);
+        let rel =
+            imortal_ir::Relationship::one_to_many(user_id, post_id).with_from_field("user_id");
+        let rel_id = rel.id;
+        project.add_relationship(rel);
+        let post = project.get_entity_mut(post_id).unwrap();
+        post.fields
+            .iter_mut()
+            .find(|f| f.name == "title")
+            .unwrap()
+            .unique_scope = Some(rel_id);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let sql = generate_migrations(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy().contains("posts"))
+            .unwrap()
+            .content;
+        assert!(sql.contains(
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"uq_posts_user_id_title\" ON \"posts\" (\"user_id\", \"title\");"
+        ));
+    }
+
+    #[test]
+    fn test_generate_migrations_with_schema() {
+        let mut project = ProjectGraph::new("blog");
+        let mut user = make_user_entity();
+        user.config.schema = Some("auth".to_string());
+        user.table_name = "accounts".to_string();
+        let user_id = user.id;
+        project.add_entity(user);
+        project.add_entity(make_post_entity(user_id));
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_migrations(&ctx);
+
+        let users = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("accounts"))
+            .unwrap();
+        assert!(
+            users
+                .content
+                .contains("CREATE SCHEMA IF NOT EXISTS \"auth\";")
+        );
+        assert!(
+            users
+                .content
+                .contains("CREATE TABLE IF NOT EXISTS \"auth\".\"accounts\" (")
+        );
+        assert!(
+            users
+                .content
+                .contains("ON \"auth\".\"accounts\" (\"email\")")
+        );
+
+        // References into another schema are qualified too
+        let posts = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("posts"))
+            .unwrap();
+        assert!(
+            posts
+                .content
+                .contains("REFERENCES \"auth\".\"accounts\" (\"id\")")
+        );
+        assert!(!posts.content.contains("CREATE SCHEMA"));
+
+        // SQLite has no schemas, so the qualifier is dropped
+        project.config.database = DatabaseType::SQLite;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_migrations(&ctx);
+        assert!(files.iter().all(|f| !f.content.contains("\"auth\"")));
+    }
+
+    #[test]
+    fn test_generate_migrations_with_table_options() {
+        let mut project = ProjectGraph::new("shop");
+        let mut user = make_user_entity();
+        user.config = user
+            .config
+            .clone()
+            .with_tablespace("fast")
+            .with_engine("InnoDB")
+            .with_charset("utf8mb4", Some("utf8mb4_unicode_ci"));
+        project.add_entity(user);
+
+        let create_table = |project: &ProjectGraph| {
+            let ctx = GenerationContext::from_project_default(project);
+            let files = generate_migrations(&ctx);
+            let content = &files
+                .iter()
+                .find(|f| f.path.to_string_lossy().contains("users"))
+                .unwrap()
+                .content;
+            let start = content.find("CREATE TABLE").unwrap();
+            let end = start + content[start..].find(";\n").unwrap();
+            content[start..end].lines().last().unwrap().to_string()
+        };
+
+        // Each database gets only the options it understands
+        assert_eq!(create_table(&project), ") TABLESPACE \"fast\"");
+        project.config.database = DatabaseType::MySQL;
+        assert_eq!(
+            create_table(&project),
+            ") ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci"
+        );
+        project.config.database = DatabaseType::SQLite;
+        assert_eq!(create_table(&project), ")");
+    }
+
     #[test]
     fn test_generate_migrations_mysql() {
         let mut project = ProjectGraph::new("shop");
@@ -649,6 +1435,7 @@ mod tests {
             ("count", DataType::Int32),
             ("big_count", DataType::Int64),
             ("score", DataType::Float64),
+            ("price", DataType::decimal(10, 2)),
             ("active", DataType::Bool),
             ("data", DataType::Json),
             ("content", DataType::Text),
@@ -671,6 +1458,7 @@ mod tests {
         assert!(content.contains("INTEGER")); // Int32
         assert!(content.contains("BIGINT")); // Int64
         assert!(content.contains("DOUBLE PRECISION")); // Float64 (PostgreSQL)
+        assert!(content.contains("NUMERIC(10,2)")); // Decimal (PostgreSQL)
         assert!(content.contains("BOOLEAN")); // Bool
         assert!(content.contains("JSONB")); // Json (PostgreSQL)
         assert!(content.contains("TEXT")); // Text
@@ -705,4 +1493,50 @@ mod tests {
             bio_line
         );
     }
+
+    #[test]
+    fn test_computed_columns() {
+        use imortal_ir::ComputedField;
+
+        let mut person = Entity::new("Person");
+        person
+            .fields
+            .push(Field::new("first_name", DataType::String).required());
+        person.fields.push(Field::new("age", DataType::Int32));
+        person.fields.push(
+            Field::new("label", DataType::String)
+                .with_computed(ComputedField::generated("first_name || ' (' || age || ')'")),
+        );
+        person.fields.push(
+            Field::new("months", DataType::Int32).with_computed(ComputedField::getter("age * 12")),
+        );
+
+        let column = |db: DatabaseType| {
+            let mut project = ProjectGraph::new("people");
+            project.config.database = db;
+            project.add_entity(person.clone());
+            let ctx = GenerationContext::from_project_default(&project);
+            let content = generate_migrations(&ctx).remove(0).content;
+            assert!(!content.contains("months"), "{content}");
+            content
+                .lines()
+                .find(|l| l.contains("label"))
+                .unwrap()
+                .trim()
+                .to_string()
+        };
+
+        assert_eq!(
+            column(DatabaseType::PostgreSQL),
+            "\"label\" VARCHAR(255) GENERATED ALWAYS AS (\"first_name\" || ' (' || CAST(\"age\" AS TEXT) || ')') STORED,"
+        );
+        assert!(
+            column(DatabaseType::MySQL)
+                .contains("GENERATED ALWAYS AS (CONCAT(`first_name`, ' (', `age`, ')')) STORED")
+        );
+        assert!(
+            column(DatabaseType::SQLite)
+                .contains("GENERATED ALWAYS AS (\"first_name\" || ' (' || \"age\" || ')') STORED")
+        );
+    }
 }
@@ -0,0 +1,2283 @@
+//! # OpenAPI Specification Generator
+//!
+//! Generates an OpenAPI 3.1 document describing the REST API of the
+//! generated project, so clients can be generated from it and the API can be
+//! browsed in any OpenAPI viewer without building the server first.
+//!
+//! ## Generated Files
+//!
+//! - `openapi.yaml` — always, when OpenAPI is enabled for the project
+//! - `openapi.json` — the same document, when [`GeneratorConfig::openapi_json`]
+//!   is set
+//!
+//! ## What the Spec Covers
+//!
+//! The document mirrors what the other generators emit rather than the raw
+//! IR, so it stays accurate:
+//!
+//! - one path per enabled, documented CRUD operation, at the same paths the
+//!   router mounts (`{base}` and `{base}/{id}`)
+//! - `Create…Dto`, `Update…Dto`, `…Response` and `…ListItem` schemas with
+//!   the same fields, renames and optionality as the DTO structs
+//! - list responses in the project's pagination envelope
+//! - the link routes of promoted many-to-many junctions
+//!   (`{base}/{id}/{accessor}`), with their `…LinkDto` payloads
+//! - the upload routes of `File`/`Image` fields (`{base}/{id}/{field}`),
+//!   taking a `multipart/form-data` file
+//! - `ETag` / `Last-Modified` headers, their conditional request headers and
+//!   `304 Not Modified` on read operations that send them
+//! - custom query endpoints with their query-string parameters and rows
+//! - a bearer JWT security scheme on operations that require auth
+//!
+//! Documents are built as a [`Node`] tree, which keeps keys in insertion
+//! order and renders to both YAML and JSON.
+//!
+//! [`GeneratorConfig::openapi_json`]: crate::GeneratorConfig::openapi_json
+
+use std::fmt::Write;
+
+use imortal_core::{DataType, IdType, Validation};
+use imortal_ir::{
+    AggregateKind, CrudOperation, ETagSource, EndpointGroup, Field, HttpCaching, OperationType,
+    PaginationStyle, WidgetType,
+};
+
+use crate::context::{Association, EntityInfo, GenerationContext};
+use crate::rust::handlers::{
+    ListFilterKind, delete_effects_summary, link_associations, list_filters, list_options,
+    list_sort_columns, upload_handler_name,
+};
+use crate::rust::models::soft_delete_column;
+use crate::rust::queries::{query_handler_name, query_shape};
+use crate::{FileType, GeneratedFile};
+
+/// OpenAPI version the generated documents declare
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Name of the security scheme secured operations refer to
+const BEARER_AUTH: &str = "bearerAuth";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `openapi.yaml` (and `openapi.json` if requested). Returns no
+/// files when OpenAPI is disabled for the project.
+pub fn generate_openapi(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.openapi_enabled() {
+        return Vec::new();
+    }
+
+    let document = openapi_document(ctx);
+    let mut files = vec![GeneratedFile::new(
+        "openapi.yaml",
+        document.to_yaml(),
+        FileType::Yaml,
+    )];
+    if ctx.generator_config.openapi_json {
+        files.push(GeneratedFile::new(
+            "openapi.json",
+            document.to_json(),
+            FileType::Json,
+        ));
+    }
+    files
+}
+
+/// Build the OpenAPI document for a project.
+pub fn openapi_document(ctx: &GenerationContext) -> Node {
+    let mut paths = Node::map();
+    let mut schemas = Node::map();
+    let mut tags = Vec::new();
+
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        let Some(endpoint) = info.endpoint().filter(|ep| ep.enabled) else {
+            continue;
+        };
+        let operations: Vec<_> = endpoint
+            .enabled_operations()
+            .into_iter()
+            .filter(|op| op.documented)
+            .collect();
+        if operations.is_empty() {
+            continue;
+        }
+
+        let tag = endpoint_tag(&info, endpoint);
+        if !tags
+            .iter()
+            .any(|t: &Node| t.get("name") == Some(&Node::from(tag.as_str())))
+        {
+            let mut node = Node::map().with("name", tag.as_str());
+            if let Some(description) = &endpoint.description {
+                node = node.with("description", description.as_str());
+            }
+            tags.push(node);
+        }
+
+        entity_schemas(&info, ctx, &mut schemas);
+
+        let base = info.base_path();
+        let item_path = format!("{}/{{id}}", base);
+        for op in operations {
+            let path = if op.operation_type.is_single() {
+                &item_path
+            } else {
+                &base
+            };
+            let operation = entity_operation(&info, endpoint, op, &tag, ctx);
+            paths
+                .entry(path)
+                .set(&op.http_method().to_lowercase(), operation);
+
+            if op.operation_type == OperationType::Delete && info.has_soft_delete() {
+                paths
+                    .entry(&format!("{}/restore", item_path))
+                    .set("post", restore_operation(&info, endpoint, op, &tag, ctx));
+            }
+
+            if op.operation_type == OperationType::Update {
+                for field in info.upload_fields() {
+                    paths
+                        .entry(&format!(
+                            "{}/{}",
+                            item_path,
+                            GenerationContext::snake(&field.name)
+                        ))
+                        .set(
+                            "post",
+                            upload_operation(&info, field, endpoint, op, &tag, ctx),
+                        );
+                }
+                for association in link_associations(&info) {
+                    let junction = EntityInfo::new(association.junction, ctx);
+                    entity_schemas(&junction, ctx, &mut schemas);
+                    let (properties, required) =
+                        create_properties(&association.fields(ctx), junction.id_type());
+                    schemas.set(
+                        &association.dto_name(),
+                        object_schema(properties, required).with(
+                            "description",
+                            format!(
+                                "Payload linking a {} to one of its {}.",
+                                info.pascal_name(),
+                                association.accessor
+                            ),
+                        ),
+                    );
+                    for (path, method, operation) in
+                        link_operations(&info, &association, endpoint, op, &tag, ctx)
+                    {
+                        paths.entry(&path).set(method, operation);
+                    }
+                }
+            }
+        }
+    }
+
+    let queries: Vec<_> = ctx
+        .queries()
+        .iter()
+        .filter_map(|q| Some((q, query_shape(q, ctx)?)))
+        .collect();
+    if !queries.is_empty() {
+        tags.push(Node::map().with("name", "queries"));
+    }
+    for (query, (params, columns)) in queries {
+        let row_name = format!("{}Row", GenerationContext::pascal(&query.name));
+
+        let mut properties = Node::map();
+        let mut required = Vec::new();
+        for column in &columns {
+            let schema = field_schema(column.field, column.entity.config.id_type);
+            let schema = if column.nullable {
+                nullable(schema)
+            } else {
+                schema
+            };
+            properties = properties.with(&column.name, schema);
+            required.push(Node::from(column.name.as_str()));
+        }
+        schemas.set(&row_name, object_schema(properties, required));
+
+        let mut parameters: Vec<Node> = params
+            .iter()
+            .map(|p| {
+                let schema = match p.field {
+                    Some(field) => data_type_schema(&base_type(&field.data_type)),
+                    None => Node::map().with("type", "string"),
+                };
+                query_parameter(&p.name, schema, None)
+            })
+            .collect();
+        let limit_description = match query.limit {
+            Some(max) => format!("Maximum number of rows to return (at most {})", max),
+            None => "Maximum number of rows to return".to_string(),
+        };
+        parameters.push(query_parameter(
+            "limit",
+            integer_schema("int64").with("minimum", 0),
+            Some(&limit_description),
+        ));
+
+        let description = query
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Custom query over {}.", query.entity_name));
+        let mut operation = Node::map()
+            .with("tags", vec![Node::from("queries")])
+            .with("summary", description.as_str())
+            .with("operationId", query_handler_name(query))
+            .with("parameters", parameters);
+        let mut responses = Node::map().with(
+            "200",
+            json_response(
+                &description,
+                Node::map()
+                    .with("type", "array")
+                    .with("items", schema_ref(&row_name)),
+            ),
+        );
+        if ctx.auth_enabled() && query.security.auth_required {
+            operation = operation.with("security", bearer_security());
+            responses.set("401", error_response("Missing or invalid token"));
+        }
+        operation = operation.with("responses", responses);
+
+        paths.entry(&query.path).set("get", operation);
+    }
+
+    schemas.set("ProblemDetails", error_schema());
+
+    let mut components = Node::map().with("schemas", schemas);
+    if ctx.auth_enabled() {
+        components = components.with(
+            "securitySchemes",
+            Node::map().with(
+                BEARER_AUTH,
+                Node::map()
+                    .with("type", "http")
+                    .with("scheme", "bearer")
+                    .with("bearerFormat", "JWT"),
+            ),
+        );
+    }
+
+    let mut info = Node::map()
+        .with("title", ctx.meta.name.as_str())
+        .with("version", ctx.meta.version.as_str());
+    if let Some(description) = &ctx.meta.description {
+        info = info.with("description", description.as_str());
+    }
+    let authors = ctx.meta.authors();
+    let repository = non_blank(&ctx.meta.repository);
+    if !authors.is_empty() || repository.is_some() {
+        let mut contact = Node::map();
+        if !authors.is_empty() {
+            contact.set("name", authors.join(", "));
+        }
+        if let Some(repository) = repository {
+            contact.set("url", repository);
+        }
+        info = info.with("contact", contact);
+    }
+    if let Some(license) = non_blank(&ctx.meta.license) {
+        // Project licenses are SPDX expressions, which 3.1 takes as-is
+        info = info.with(
+            "license",
+            Node::map()
+                .with("name", license)
+                .with("identifier", license),
+        );
+    }
+
+    let host = match ctx.server_host() {
+        "0.0.0.0" | "::" => "localhost",
+        host => host,
+    };
+
+    Node::map()
+        .with("openapi", OPENAPI_VERSION)
+        .with("info", info)
+        .with(
+            "servers",
+            vec![Node::map().with("url", format!("http://{}:{}", host, ctx.server_port()))],
+        )
+        .with("tags", tags)
+        .with("paths", paths)
+        .with("components", components)
+}
+
+// ============================================================================
+// Entity operations
+// ============================================================================
+
+/// Tag grouping an entity's operations: the endpoint's first tag, or the
+/// entity name.
+fn endpoint_tag(info: &EntityInfo, endpoint: &EndpointGroup) -> String {
+    endpoint
+        .tags
+        .first()
+        .cloned()
+        .unwrap_or_else(|| info.pascal_name())
+}
+
+fn entity_operation(
+    info: &EntityInfo,
+    endpoint: &EndpointGroup,
+    op: &CrudOperation,
+    tag: &str,
+    ctx: &GenerationContext,
+) -> Node {
+    let pascal = info.pascal_name();
+    let name = &info.entity.name;
+    let response = schema_ref(&GenerationContext::response_dto_name(name));
+    let caching = info.caching(op.operation_type);
+
+    let summary = match op.operation_type {
+        OperationType::Create => format!("Create a {}", pascal),
+        OperationType::Read => format!("Get a {} by ID", pascal),
+        OperationType::ReadAll => format!("List {}", info.plural_name()),
+        OperationType::Update => format!("Update a {}", pascal),
+        OperationType::Delete => format!("Delete a {}", pascal),
+    };
+
+    let mut operation = Node::map()
+        .with("tags", vec![Node::from(tag)])
+        .with("summary", summary)
+        .with("operationId", op.default_operation_id(name));
+    if let Some(description) = &op.description {
+        operation = operation.with("description", description.as_str());
+    }
+
+    let mut parameters = Vec::new();
+    if op.operation_type.is_single() {
+        parameters.push(
+            Node::map()
+                .with("name", "id")
+                .with("in", "path")
+                .with("required", true)
+                .with("description", format!("{} ID", pascal))
+                .with("schema", id_schema(info.id_type())),
+        );
+    }
+    if op.operation_type == OperationType::ReadAll {
+        parameters.extend(list_parameters(info, ctx));
+    }
+    if info.has_soft_delete()
+        && matches!(
+            op.operation_type,
+            OperationType::Read | OperationType::ReadAll
+        )
+    {
+        parameters.push(query_parameter(
+            "include_deleted",
+            Node::map().with("type", "boolean").with("default", false),
+            Some("Include soft-deleted records"),
+        ));
+    }
+    if let Some(caching) = caching {
+        parameters.extend(conditional_parameters(caching));
+    }
+    parameters.extend(version_header_parameter(info, ctx));
+    if !parameters.is_empty() {
+        operation = operation.with("parameters", parameters);
+    }
+
+    let body = match op.operation_type {
+        OperationType::Create => Some(GenerationContext::create_dto_name(name)),
+        OperationType::Update => Some(GenerationContext::update_dto_name(name)),
+        _ => None,
+    };
+    if let Some(body) = body {
+        operation = operation.with(
+            "requestBody",
+            Node::map().with("required", true).with(
+                "content",
+                Node::map().with(
+                    "application/json",
+                    Node::map().with("schema", schema_ref(&body)),
+                ),
+            ),
+        );
+    }
+
+    let status = op.success_status.to_string();
+    let mut responses = match op.operation_type {
+        OperationType::Create => Node::map().with(
+            &status,
+            json_response(&format!("{} created", pascal), response),
+        ),
+        OperationType::Read | OperationType::Update => Node::map().with(
+            &status,
+            with_cache_headers(json_response(&pascal, response), caching),
+        ),
+        OperationType::ReadAll => Node::map().with(
+            &status,
+            with_cache_headers(
+                json_response(
+                    &format!("A page of {}", info.plural_name()),
+                    list_schema(info, ctx),
+                ),
+                caching,
+            ),
+        ),
+        OperationType::Delete => Node::map().with(
+            &status,
+            Node::map().with("description", delete_effects_summary(info)),
+        ),
+    };
+
+    if let Some(caching) = caching {
+        responses.set("304", not_modified_response(caching));
+    }
+    if matches!(
+        op.operation_type,
+        OperationType::Create | OperationType::Update
+    ) {
+        responses.set("422", error_response("Validation failed"));
+    }
+    if op.operation_type == OperationType::ReadAll {
+        let cursor = ctx.pagination() == PaginationStyle::Cursor;
+        let sorted = !list_sort_columns(info, ctx).is_empty();
+        match (cursor, sorted) {
+            (true, _) => responses.set("400", error_response("Invalid cursor")),
+            (false, true) => responses.set("400", error_response("Unknown sort field")),
+            (false, false) => {}
+        }
+    }
+    if op.operation_type.is_single() {
+        responses.set("404", error_response("Not found"));
+    }
+    let scoped = info.scoped_uniques();
+    if matches!(
+        op.operation_type,
+        OperationType::Create | OperationType::Update
+    ) && !scoped.is_empty()
+    {
+        let taken: Vec<String> = scoped
+            .iter()
+            .map(|s| s.conflict_message(info.entity))
+            .collect();
+        let description = taken.join("; ");
+        let mut chars = description.chars();
+        let description = chars
+            .next()
+            .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+            .unwrap_or_default();
+        responses.set("409", error_response(&description));
+    }
+    if op.operation_type == OperationType::Delete
+        && ctx.report_delete_blockers()
+        && !info.has_soft_delete()
+        && !info.delete_blockers().is_empty()
+    {
+        responses.set(
+            "409",
+            error_response("Other records still reference it; the body lists them"),
+        );
+    }
+
+    secured(operation, responses, endpoint, op, ctx)
+}
+
+/// `POST {base}/{id}/restore` of a soft-deleting entity, secured like its
+/// delete operation.
+fn restore_operation(
+    info: &EntityInfo,
+    endpoint: &EndpointGroup,
+    delete: &CrudOperation,
+    tag: &str,
+    ctx: &GenerationContext,
+) -> Node {
+    let pascal = info.pascal_name();
+    let operation = Node::map()
+        .with("tags", vec![Node::from(tag)])
+        .with("summary", format!("Restore a soft-deleted {}", pascal))
+        .with("operationId", format!("restore_{}", info.snake_name()))
+        .with(
+            "parameters",
+            std::iter::once(
+                Node::map()
+                    .with("name", "id")
+                    .with("in", "path")
+                    .with("required", true)
+                    .with("description", format!("{} ID", pascal))
+                    .with("schema", id_schema(info.id_type())),
+            )
+            .chain(version_header_parameter(info, ctx))
+            .collect::<Vec<_>>(),
+        );
+    let responses = Node::map()
+        .with(
+            "200",
+            json_response(
+                &format!("{} restored", pascal),
+                schema_ref(&GenerationContext::response_dto_name(&info.entity.name)),
+            ),
+        )
+        .with("404", error_response("Not found"));
+
+    secured(operation, responses, endpoint, delete, ctx)
+}
+
+/// `POST {base}/{id}/{field}` of an upload field, secured like the update
+/// operation.
+fn upload_operation(
+    info: &EntityInfo,
+    field: &Field,
+    endpoint: &EndpointGroup,
+    update: &CrudOperation,
+    tag: &str,
+    ctx: &GenerationContext,
+) -> Node {
+    let pascal = info.pascal_name();
+    let file = Node::map().with("type", "string").with("format", "binary");
+    let file = if field.ui_hints.widget == Some(WidgetType::Image) {
+        file.with("contentMediaType", "image/*")
+    } else {
+        file
+    };
+    let operation = Node::map()
+        .with("tags", vec![Node::from(tag)])
+        .with("summary", format!("Upload the {}'s {}", pascal, field.name))
+        .with("operationId", upload_handler_name(info, field))
+        .with(
+            "parameters",
+            std::iter::once(
+                Node::map()
+                    .with("name", "id")
+                    .with("in", "path")
+                    .with("required", true)
+                    .with("description", format!("{} ID", pascal))
+                    .with("schema", id_schema(info.id_type())),
+            )
+            .chain(version_header_parameter(info, ctx))
+            .collect::<Vec<_>>(),
+        )
+        .with(
+            "requestBody",
+            Node::map().with("required", true).with(
+                "content",
+                Node::map().with(
+                    "multipart/form-data",
+                    Node::map().with(
+                        "schema",
+                        Node::map()
+                            .with("type", "object")
+                            .with("properties", Node::map().with("file", file))
+                            .with("required", vec![Node::from("file")]),
+                    ),
+                ),
+            ),
+        );
+    let responses = Node::map()
+        .with(
+            "200",
+            json_response(
+                &format!("{} with the stored file's URL", pascal),
+                schema_ref(&GenerationContext::response_dto_name(&info.entity.name)),
+            ),
+        )
+        .with("400", error_response("Missing, oversized or rejected file"))
+        .with("404", error_response("Not found"));
+
+    secured(operation, responses, endpoint, update, ctx)
+}
+
+/// The list, link and unlink operations of an association, as
+/// `(path, method, operation)`, secured like the owner's update operation.
+fn link_operations(
+    info: &EntityInfo,
+    association: &Association,
+    endpoint: &EndpointGroup,
+    update: &CrudOperation,
+    tag: &str,
+    ctx: &GenerationContext,
+) -> [(String, &'static str, Node); 3] {
+    let pascal = info.pascal_name();
+    let accessor = &association.accessor;
+    let other = association.other_param();
+    let collection = format!("{}/{{id}}/{}", info.base_path(), accessor);
+    let item = format!("{}/{{{}}}", collection, other);
+    let junction = schema_ref(&GenerationContext::response_dto_name(
+        &association.junction.name,
+    ));
+    let suffix = association.handler_suffix();
+
+    let id_parameter = Node::map()
+        .with("name", "id")
+        .with("in", "path")
+        .with("required", true)
+        .with("description", format!("{} ID", pascal))
+        .with("schema", id_schema(info.id_type()));
+    let parameters = |extra: Option<Node>| {
+        std::iter::once(id_parameter.clone())
+            .chain(extra)
+            .chain(version_header_parameter(info, ctx))
+            .collect::<Vec<_>>()
+    };
+    let operation = |summary: String, id: String, extra: Option<Node>| {
+        Node::map()
+            .with("tags", vec![Node::from(tag)])
+            .with("summary", summary)
+            .with("operationId", id)
+            .with("parameters", parameters(extra))
+    };
+
+    let list = secured(
+        operation(
+            format!("List the {} linked to a {}", accessor, pascal),
+            format!("list_{}", suffix),
+            None,
+        ),
+        Node::map()
+            .with(
+                "200",
+                json_response(
+                    &format!("Links of the {}", pascal),
+                    Node::map()
+                        .with("type", "array")
+                        .with("items", junction.clone()),
+                ),
+            )
+            .with("404", error_response("Not found")),
+        endpoint,
+        update,
+        ctx,
+    );
+
+    let link = secured(
+        operation(
+            format!("Link a {} to one of its {}", pascal, accessor),
+            format!("link_{}", suffix),
+            None,
+        )
+        .with(
+            "requestBody",
+            Node::map().with("required", true).with(
+                "content",
+                Node::map().with(
+                    "application/json",
+                    Node::map().with("schema", schema_ref(&association.dto_name())),
+                ),
+            ),
+        ),
+        Node::map()
+            .with("201", json_response("Link created", junction))
+            .with("404", error_response("Not found"))
+            .with("422", error_response("Validation failed")),
+        endpoint,
+        update,
+        ctx,
+    );
+
+    let other_info = EntityInfo::new(association.other, ctx);
+    let unlink = secured(
+        operation(
+            format!("Unlink a {} from one of its {}", pascal, accessor),
+            format!("unlink_{}", suffix),
+            Some(
+                Node::map()
+                    .with("name", other.as_str())
+                    .with("in", "path")
+                    .with("required", true)
+                    .with("description", format!("{} ID", other_info.pascal_name()))
+                    .with("schema", id_schema(other_info.id_type())),
+            ),
+        ),
+        Node::map()
+            .with(
+                "204",
+                Node::map().with("description", format!("{} unlinked", pascal)),
+            )
+            .with("404", error_response("Not linked")),
+        endpoint,
+        update,
+        ctx,
+    );
+
+    [
+        (collection.clone(), "get", list),
+        (collection, "post", link),
+        (item, "delete", unlink),
+    ]
+}
+
+/// The version header selecting an entity's operations, under header
+/// versioning.
+fn version_header_parameter(info: &EntityInfo, ctx: &GenerationContext) -> Option<Node> {
+    let versioning = ctx.api_versioning();
+    let header = versioning.header_name()?;
+    let version = info.api_version()?;
+    Some(
+        Node::map()
+            .with("name", header)
+            .with("in", "header")
+            .with("required", false)
+            .with(
+                "description",
+                format!("API version; these operations are served for `{}`", version),
+            )
+            .with(
+                "schema",
+                Node::map()
+                    .with("type", "string")
+                    .with("default", versioning.default_version().unwrap_or_default()),
+            ),
+    )
+}
+
+/// `If-None-Match` / `If-Modified-Since` of a read operation sending
+/// caching headers.
+fn conditional_parameters(caching: &HttpCaching) -> Vec<Node> {
+    let header = |name: &str, description: &str| {
+        Node::map()
+            .with("name", name)
+            .with("in", "header")
+            .with("required", false)
+            .with("description", description)
+            .with("schema", Node::map().with("type", "string"))
+    };
+    let mut parameters = Vec::new();
+    if caching.etag.is_some() {
+        parameters.push(header(
+            "If-None-Match",
+            "ETags of a cached copy; answered with 304 while one still matches",
+        ));
+    }
+    if caching.last_modified {
+        parameters.push(header(
+            "If-Modified-Since",
+            "HTTP date of a cached copy; answered with 304 if nothing changed since (ignored when If-None-Match is sent)",
+        ));
+    }
+    parameters
+}
+
+/// `response` with the `ETag` / `Last-Modified` headers `caching` sends.
+fn with_cache_headers(response: Node, caching: Option<&HttpCaching>) -> Node {
+    match caching {
+        Some(caching) => response.with("headers", cache_headers(caching)),
+        None => response,
+    }
+}
+
+/// The `304 Not Modified` answer to a conditional read.
+fn not_modified_response(caching: &HttpCaching) -> Node {
+    Node::map()
+        .with(
+            "description",
+            "Not modified; the cached copy is still current",
+        )
+        .with("headers", cache_headers(caching))
+}
+
+fn cache_headers(caching: &HttpCaching) -> Node {
+    let mut headers = Node::map();
+    if let Some(source) = caching.etag {
+        let description = match source {
+            ETagSource::UpdatedAt => "Weak entity tag from the key and updated_at of each record",
+            ETagSource::ContentHash => "Entity tag hashing the response body",
+        };
+        headers.set(
+            "ETag",
+            Node::map()
+                .with("description", description)
+                .with("schema", Node::map().with("type", "string")),
+        );
+    }
+    if caching.last_modified {
+        headers.set(
+            "Last-Modified",
+            Node::map()
+                .with("description", "Newest updated_at of the returned records")
+                .with("schema", Node::map().with("type", "string")),
+        );
+    }
+    headers
+}
+
+/// Attach `responses` to `operation`, with the bearer requirement and
+/// 401/403 answers when the operation needs authentication.
+fn secured(
+    mut operation: Node,
+    mut responses: Node,
+    endpoint: &EndpointGroup,
+    op: &CrudOperation,
+    ctx: &GenerationContext,
+) -> Node {
+    let security = endpoint.effective_security(op.operation_type);
+    if ctx.auth_enabled() && security.auth_required {
+        operation = operation.with("security", bearer_security());
+        responses.set("401", error_response("Missing or invalid token"));
+        if !security.roles.is_empty() {
+            responses.set(
+                "403",
+                error_response(&format!("Requires role: {}", security.roles.join(", "))),
+            );
+        }
+    }
+
+    operation.with("responses", responses)
+}
+
+/// Query-string parameters of list operations: pagination for the project's
+/// style, then the endpoint's sort key and filters.
+fn list_parameters(info: &EntityInfo, ctx: &GenerationContext) -> Vec<Node> {
+    let options = list_options(info);
+    let page_size = integer_schema("int64")
+        .with("minimum", 1)
+        .with("maximum", options.max_page_size as i64)
+        .with("default", options.default_page_size as i64);
+
+    let mut parameters = match ctx.pagination() {
+        PaginationStyle::Offset => vec![
+            query_parameter(
+                "page",
+                integer_schema("int64")
+                    .with("minimum", 1)
+                    .with("default", 1),
+                Some("Page number (1-based)"),
+            ),
+            query_parameter("per_page", page_size, Some("Items per page")),
+        ],
+        PaginationStyle::Cursor => vec![
+            query_parameter(
+                "cursor",
+                Node::map().with("type", "string"),
+                Some("Cursor from a previous page's `next_cursor`. Omit for the first page."),
+            ),
+            query_parameter("limit", page_size, Some("Items per page")),
+        ],
+        PaginationStyle::None => Vec::new(),
+    };
+
+    let sort_columns = list_sort_columns(info, ctx);
+    if !sort_columns.is_empty() {
+        let fields: Vec<&str> = sort_columns.iter().map(|c| c.name.as_str()).collect();
+        parameters.push(query_parameter(
+            "sort",
+            Node::map().with("type", "string"),
+            Some(&format!(
+                "Comma-separated fields to sort by, descending with a leading `-`. One of: {}",
+                fields.join(", ")
+            )),
+        ));
+    }
+
+    let plural = info.plural_name();
+    for filter in list_filters(info) {
+        let name = &filter.column.name;
+        let (schema, description) = match filter.kind {
+            ListFilterKind::Contains => (
+                Node::map().with("type", "string"),
+                format!("Only {} whose `{}` contains this", plural, name),
+            ),
+            kind => {
+                let comparison = match kind {
+                    ListFilterKind::Min => "is at least",
+                    ListFilterKind::Max => "is at most",
+                    _ => "equals",
+                };
+                (
+                    data_type_schema(&filter.column.data_type),
+                    format!("Only {} whose `{}` {} this", plural, name, comparison),
+                )
+            }
+        };
+        parameters.push(query_parameter(&filter.param, schema, Some(&description)));
+    }
+
+    parameters
+}
+
+/// Properties of a create payload: plain-text secrets go under the name
+/// without `_hash`, and only required fields are mandatory.
+fn create_properties(fields: &[&Field], id_type: IdType) -> (Node, Vec<Node>) {
+    let mut properties = Node::map();
+    let mut required = Vec::new();
+    for field in fields {
+        let rust_name = GenerationContext::snake(&field.name);
+        let is_password_field =
+            field.secret || field.name.contains("password") || field.name.contains("secret");
+        let dto_name = if is_password_field && rust_name.ends_with("_hash") {
+            rust_name.trim_end_matches("_hash").to_string()
+        } else {
+            rust_name
+        };
+
+        let mut schema = input_schema(field, id_type);
+        if is_password_field {
+            schema = schema.with("format", "password").without("examples");
+        }
+        if field.required {
+            required.push(Node::from(dto_name.as_str()));
+        } else {
+            schema = nullable(schema);
+        }
+        properties = properties.with(&dto_name, schema);
+    }
+    (properties, required)
+}
+
+/// Response schema of a list operation: the item type in the project's
+/// pagination envelope.
+fn list_schema(info: &EntityInfo, ctx: &GenerationContext) -> Node {
+    let item = if info.related_aggregates().is_empty() {
+        GenerationContext::response_dto_name(&info.entity.name)
+    } else {
+        GenerationContext::list_item_dto_name(&info.entity.name)
+    };
+    match ctx.pagination() {
+        PaginationStyle::None => Node::map()
+            .with("type", "array")
+            .with("items", schema_ref(&item)),
+        PaginationStyle::Offset | PaginationStyle::Cursor => {
+            schema_ref(&format!("{}Page", info.pascal_name()))
+        }
+    }
+}
+
+// ============================================================================
+// Entity schemas
+// ============================================================================
+
+/// Add the DTO schemas of an entity: create, update, response, and the list
+/// item and page envelope where the list operation uses them.
+fn entity_schemas(info: &EntityInfo, ctx: &GenerationContext, schemas: &mut Node) {
+    let name = &info.entity.name;
+    let id_type = info.id_type();
+
+    // Create, with any children embedded as arrays
+    let (mut properties, required) = create_properties(&info.create_fields(), id_type);
+    for nested in info.nested_children() {
+        properties = properties.with(
+            &nested.payload_field(),
+            Node::map()
+                .with("type", "array")
+                .with("items", schema_ref(&nested.dto_name())),
+        );
+    }
+    schemas.set(
+        &GenerationContext::create_dto_name(name),
+        object_schema(properties, required).with(
+            "description",
+            format!("Payload for creating a new {}.", info.pascal_name()),
+        ),
+    );
+
+    // One embedded child, per parent accepting it
+    for nested in info.nested_parents() {
+        let (properties, required) = create_properties(&nested.fields(ctx), id_type);
+        schemas.set(
+            &nested.dto_name(),
+            object_schema(properties, required).with(
+                "description",
+                format!(
+                    "Payload for a {} created together with its {}.",
+                    info.pascal_name(),
+                    GenerationContext::pascal(&nested.parent.name)
+                ),
+            ),
+        );
+    }
+
+    // Update: every field optional
+    let mut properties = Node::map();
+    for field in info.update_fields() {
+        properties = properties.with(
+            &GenerationContext::snake(&field.name),
+            nullable(input_schema(field, id_type)),
+        );
+    }
+    schemas.set(
+        &GenerationContext::update_dto_name(name),
+        object_schema(properties, Vec::new()).with(
+            "description",
+            format!(
+                "Payload for updating an existing {}. All fields are optional.",
+                info.pascal_name()
+            ),
+        ),
+    );
+
+    // Response: optionality follows the data type, as in the struct
+    let mut properties = Node::map();
+    let mut required = Vec::new();
+    let fields = info.response_fields();
+    for field in &fields {
+        let field_name = GenerationContext::snake(&field.name);
+        properties = properties.with(&field_name, field_schema(field, id_type));
+        required.push(Node::from(field_name.as_str()));
+    }
+    if info.has_timestamps() {
+        for timestamp in ["created_at", "updated_at"] {
+            if !fields.iter().any(|f| f.name == timestamp) {
+                properties = properties.with(timestamp, data_type_schema(&DataType::DateTime));
+                required.push(Node::from(timestamp));
+            }
+        }
+    }
+    if soft_delete_column(info) {
+        properties = properties.with(
+            "deleted_at",
+            nullable(data_type_schema(&DataType::DateTime)),
+        );
+        required.push(Node::from("deleted_at"));
+    }
+    let response_name = GenerationContext::response_dto_name(name);
+    schemas.set(
+        &response_name,
+        object_schema(properties, required).with(
+            "description",
+            format!(
+                "Response representation of a {}. Excludes secret and hidden fields.",
+                info.pascal_name()
+            ),
+        ),
+    );
+
+    // List item: the response flattened together with related aggregates
+    let aggregates = info.related_aggregates();
+    let item_name = if aggregates.is_empty() {
+        response_name
+    } else {
+        let mut properties = Node::map();
+        let mut required = Vec::new();
+        for resolved in &aggregates {
+            let field = GenerationContext::snake(&resolved.aggregate.field);
+            let related =
+                GenerationContext::pluralize(&GenerationContext::snake(&resolved.related.name));
+            let schema = match resolved.aggregate.kind {
+                AggregateKind::Count => integer_schema("int64")
+                    .with("minimum", 0)
+                    .with("description", format!("Number of related {}.", related)),
+                AggregateKind::Exists => Node::map().with("type", "boolean").with(
+                    "description",
+                    format!("Whether any related {} exist.", related),
+                ),
+            };
+            properties = properties.with(&field, schema);
+            required.push(Node::from(field.as_str()));
+        }
+        let item_name = GenerationContext::list_item_dto_name(name);
+        schemas.set(
+            &item_name,
+            Node::map().with(
+                "allOf",
+                vec![
+                    schema_ref(&response_name),
+                    object_schema(properties, required),
+                ],
+            ),
+        );
+        item_name
+    };
+
+    // Page envelope, named after the entity since OpenAPI has no generics
+    let items = Node::map()
+        .with("type", "array")
+        .with("items", schema_ref(&item_name));
+    let page = match ctx.pagination() {
+        PaginationStyle::Offset => Some(object_schema(
+            Node::map()
+                .with("items", items)
+                .with("total", integer_schema("int64").with("minimum", 0))
+                .with("page", integer_schema("int64").with("minimum", 1))
+                .with("per_page", integer_schema("int64").with("minimum", 1))
+                .with("total_pages", integer_schema("int64").with("minimum", 0)),
+            ["items", "total", "page", "per_page", "total_pages"]
+                .into_iter()
+                .map(Node::from)
+                .collect(),
+        )),
+        PaginationStyle::Cursor => Some(object_schema(
+            Node::map()
+                .with("items", items)
+                .with("next_cursor", nullable(Node::map().with("type", "string")))
+                .with("has_more", Node::map().with("type", "boolean")),
+            ["items", "next_cursor", "has_more"]
+                .into_iter()
+                .map(Node::from)
+                .collect(),
+        )),
+        PaginationStyle::None => None,
+    };
+    if let Some(page) = page {
+        schemas.set(&format!("{}Page", info.pascal_name()), page);
+    }
+}
+
+/// Schema of a field as clients send it, with its validations as JSON
+/// Schema constraints.
+fn input_schema(field: &Field, id_type: IdType) -> Node {
+    let mut schema = field_schema(field, id_type);
+    // Validator has no decimal support, so bounds on decimals aren't enforced
+    let numeric = !field.data_type.is_decimal();
+
+    for validation in &field.validations {
+        schema = match validation {
+            Validation::MinLength(n) => schema.with("minLength", *n as i64),
+            Validation::MaxLength(n) => schema.with("maxLength", *n as i64),
+            Validation::Min(n) if numeric => schema.with("minimum", *n),
+            Validation::Max(n) if numeric => schema.with("maximum", *n),
+            Validation::Pattern { regex, .. } => schema.with("pattern", regex.as_str()),
+            Validation::Email => schema.with("format", "email"),
+            Validation::Url => schema.with("format", "uri"),
+            Validation::Uuid => schema.with("format", "uuid"),
+            Validation::OneOf(values) => schema.with(
+                "enum",
+                values
+                    .iter()
+                    .map(|v| Node::from(v.as_str()))
+                    .collect::<Vec<_>>(),
+            ),
+            _ => schema,
+        };
+    }
+    schema
+}
+
+/// Schema of a field's value, with its description and example.
+fn field_schema(field: &Field, id_type: IdType) -> Node {
+    let mut schema = if field.is_primary_key {
+        id_schema(id_type)
+    } else {
+        data_type_schema(&field.data_type)
+    };
+
+    if let Some(description) = field
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        schema = schema.with("description", description);
+    }
+    if field
+        .example
+        .as_deref()
+        .is_some_and(|e| !e.trim().is_empty())
+    {
+        schema = schema.with("examples", vec![example_value(field)]);
+    }
+    schema
+}
+
+/// JSON Schema for a data type, following its serde representation in the
+/// generated code.
+fn data_type_schema(dt: &DataType) -> Node {
+    let typed = |ty: &str| Node::map().with("type", ty);
+    let string_format = |format: &str| typed("string").with("format", format);
+
+    match dt {
+        DataType::String | DataType::Text => typed("string"),
+        DataType::Int32 => integer_schema("int32"),
+        DataType::Int64 => integer_schema("int64"),
+        DataType::Float32 => typed("number").with("format", "float"),
+        DataType::Float64 => typed("number").with("format", "double"),
+        // rust_decimal serializes as a string to keep every digit
+        DataType::Decimal { precision, scale } => string_format("decimal").with(
+            "description",
+            format!(
+                "Decimal with up to {} digits, {} after the point",
+                precision, scale
+            ),
+        ),
+        DataType::Bool => typed("boolean"),
+        DataType::Uuid | DataType::Reference { .. } => string_format("uuid"),
+        DataType::DateTime => string_format("date-time"),
+        DataType::Date => string_format("date"),
+        DataType::Time => string_format("time"),
+        DataType::Bytes => typed("array").with("items", integer_schema("uint8")),
+        DataType::Json => Node::map(),
+        DataType::Optional(inner) => nullable(data_type_schema(inner)),
+        DataType::Array(inner) => typed("array").with("items", data_type_schema(inner)),
+        DataType::Enum { variants, .. } => typed("string").with(
+            "enum",
+            variants
+                .iter()
+                .map(|v| Node::from(v.as_str()))
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
+
+/// Schema of a primary key (also used for `{id}` path parameters).
+fn id_schema(id_type: IdType) -> Node {
+    match id_type {
+        IdType::Uuid => Node::map().with("type", "string").with("format", "uuid"),
+        IdType::Serial => integer_schema("int32"),
+        IdType::Cuid | IdType::Ulid => Node::map().with("type", "string"),
+    }
+}
+
+/// A field's example in its JSON type (see [`Field::example_json`]).
+fn example_value(field: &Field) -> Node {
+    serde_json::from_str::<serde_json::Value>(&field.example_json())
+        .map(Node::from)
+        .unwrap_or_else(|_| Node::from(field.example_or_suggested()))
+}
+
+/// A trimmed metadata value, unless it is missing or blank.
+fn non_blank(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// The type inside `Optional`, which query parameters never need.
+fn base_type(dt: &DataType) -> DataType {
+    match dt {
+        DataType::Optional(inner) => inner.as_ref().clone(),
+        other => other.clone(),
+    }
+}
+
+// ============================================================================
+// Schema helpers
+// ============================================================================
+
+fn integer_schema(format: &str) -> Node {
+    Node::map().with("type", "integer").with("format", format)
+}
+
+fn object_schema(properties: Node, required: Vec<Node>) -> Node {
+    let mut schema = Node::map().with("type", "object");
+    if !required.is_empty() {
+        schema = schema.with("required", required);
+    }
+    schema.with("properties", properties)
+}
+
+/// Allow `null` as well: OpenAPI 3.1 drops `nullable` in favour of JSON
+/// Schema type lists.
+fn nullable(schema: Node) -> Node {
+    match schema.get("type") {
+        Some(Node::Str(ty)) => {
+            let types = vec![Node::from(ty.as_str()), Node::from("null")];
+            schema.with("type", types)
+        }
+        Some(_) => schema,
+        None if schema.get("$ref").is_some() => {
+            Node::map().with("oneOf", vec![schema, Node::map().with("type", "null")])
+        }
+        // An empty schema already accepts null
+        None => schema,
+    }
+}
+
+fn schema_ref(name: &str) -> Node {
+    Node::map().with("$ref", format!("#/components/schemas/{}", name))
+}
+
+fn query_parameter(name: &str, schema: Node, description: Option<&str>) -> Node {
+    let mut parameter = Node::map().with("name", name).with("in", "query");
+    if let Some(description) = description {
+        parameter = parameter.with("description", description);
+    }
+    parameter.with("required", false).with("schema", schema)
+}
+
+fn json_response(description: &str, schema: Node) -> Node {
+    Node::map().with("description", description).with(
+        "content",
+        Node::map().with("application/json", Node::map().with("schema", schema)),
+    )
+}
+
+/// An error answered with RFC 7807 problem details.
+fn error_response(description: &str) -> Node {
+    Node::map().with("description", description).with(
+        "content",
+        Node::map().with(
+            "application/problem+json",
+            Node::map().with("schema", schema_ref("ProblemDetails")),
+        ),
+    )
+}
+
+fn error_schema() -> Node {
+    object_schema(
+        Node::map()
+            .with(
+                "type",
+                Node::map()
+                    .with("type", "string")
+                    .with("description", "Problem type URI; always `about:blank`"),
+            )
+            .with(
+                "title",
+                Node::map()
+                    .with("type", "string")
+                    .with("description", "Status text, e.g. `Not Found`"),
+            )
+            .with("status", integer_schema("int32"))
+            .with(
+                "detail",
+                Node::map()
+                    .with("type", "string")
+                    .with("description", "Human-readable error description"),
+            )
+            .with(
+                "code",
+                Node::map().with("type", "string").with(
+                    "description",
+                    "Machine-readable error code, e.g. `not_found`",
+                ),
+            )
+            .with(
+                "fields",
+                Node::map()
+                    .with("type", "array")
+                    .with(
+                        "description",
+                        "Failed rules of a `validation_error`, in the `Accept-Language` of the request",
+                    )
+                    .with(
+                        "items",
+                        object_schema(
+                            Node::map()
+                                .with("field", Node::map().with("type", "string"))
+                                .with(
+                                    "code",
+                                    Node::map().with("type", "string").with(
+                                        "description",
+                                        "Message key, e.g. `validation.length.max`",
+                                    ),
+                                )
+                                .with("message", Node::map().with("type", "string")),
+                            vec![Node::from("field"), Node::from("code"), Node::from("message")],
+                        ),
+                    ),
+            ),
+        vec![
+            Node::from("type"),
+            Node::from("title"),
+            Node::from("status"),
+            Node::from("detail"),
+            Node::from("code"),
+        ],
+    )
+    .with(
+        "description",
+        "RFC 7807 problem details returned for all error responses.",
+    )
+}
+
+fn bearer_security() -> Vec<Node> {
+    vec![Node::map().with(BEARER_AUTH, Vec::<Node>::new())]
+}
+
+// ============================================================================
+// Node
+// ============================================================================
+
+/// A JSON-compatible value whose maps keep their keys in insertion order,
+/// so the spec reads top-down (`openapi`, `info`, `paths`, …) whichever way
+/// it is rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    List(Vec<Node>),
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    /// An empty map
+    pub fn map() -> Self {
+        Node::Map(Vec::new())
+    }
+
+    /// Set a key (replacing any existing value) and return the map
+    pub fn with(mut self, key: &str, value: impl Into<Node>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Remove a key and return the map
+    pub fn without(mut self, key: &str) -> Self {
+        if let Node::Map(entries) = &mut self {
+            entries.retain(|(k, _)| k != key);
+        }
+        self
+    }
+
+    /// Set a key, replacing any existing value. Does nothing on non-maps.
+    pub fn set(&mut self, key: &str, value: impl Into<Node>) {
+        let value = value.into();
+        if let Node::Map(entries) = self {
+            match entries.iter_mut().find(|(k, _)| k == key) {
+                Some((_, existing)) => *existing = value,
+                None => entries.push((key.to_string(), value)),
+            }
+        }
+    }
+
+    /// Get the value at a key
+    pub fn get(&self, key: &str) -> Option<&Node> {
+        match self {
+            Node::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Get the map at a key, inserting an empty one if it is missing
+    pub fn entry(&mut self, key: &str) -> &mut Node {
+        if self.get(key).is_none() {
+            self.set(key, Node::map());
+        }
+        match self {
+            Node::Map(entries) => {
+                let index = entries.iter().position(|(k, _)| k == key).unwrap();
+                &mut entries[index].1
+            }
+            _ => unreachable!("entry() is only called on maps"),
+        }
+    }
+
+    /// Render as pretty-printed JSON with two-space indentation
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    /// Render as block-style YAML
+    pub fn to_yaml(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Node::Map(entries) if !entries.is_empty() => write_yaml_map(&mut out, entries, 0),
+            Node::List(items) if !items.is_empty() => write_yaml_list(&mut out, items, 0),
+            scalar => {
+                out.push_str(&scalar.yaml_inline());
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    fn write_json(&self, out: &mut String, indent: usize) {
+        let pad = "  ".repeat(indent + 1);
+        let close = "  ".repeat(indent);
+        match self {
+            Node::List(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&pad);
+                    item.write_json(out, indent + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                let _ = write!(out, "{}]", close);
+            }
+            Node::Map(entries) if !entries.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    let _ = write!(out, "{}{}: ", pad, json_string(key));
+                    value.write_json(out, indent + 1);
+                    out.push_str(if i + 1 < entries.len() { ",\n" } else { "\n" });
+                }
+                let _ = write!(out, "{}}}", close);
+            }
+            scalar => out.push_str(&scalar.json_scalar()),
+        }
+    }
+
+    fn json_scalar(&self) -> String {
+        match self {
+            Node::Null => "null".to_string(),
+            Node::Bool(b) => b.to_string(),
+            Node::Int(n) => n.to_string(),
+            Node::Float(f) if f.is_finite() => f.to_string(),
+            Node::Float(_) => "null".to_string(),
+            Node::Str(s) => json_string(s),
+            Node::List(_) => "[]".to_string(),
+            Node::Map(_) => "{}".to_string(),
+        }
+    }
+
+    /// A value that fits on the line of its key or list dash
+    fn yaml_inline(&self) -> String {
+        match self {
+            Node::Str(s) => yaml_string(s),
+            Node::Float(f) if f.is_finite() => f.to_string(),
+            Node::Float(_) => "null".to_string(),
+            other => other.json_scalar(),
+        }
+    }
+
+    fn is_yaml_block(&self) -> bool {
+        match self {
+            Node::List(items) => !items.is_empty(),
+            Node::Map(entries) => !entries.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+fn write_yaml_map(out: &mut String, entries: &[(String, Node)], indent: usize) {
+    for (i, (key, value)) in entries.iter().enumerate() {
+        // The first key of a map inside a list shares the dash's line
+        if i > 0 || !out.ends_with("- ") {
+            out.push_str(&" ".repeat(indent));
+        }
+        out.push_str(&yaml_string(key));
+        out.push(':');
+        write_yaml_value(out, value, indent);
+    }
+}
+
+fn write_yaml_list(out: &mut String, items: &[Node], indent: usize) {
+    for item in items {
+        out.push_str(&" ".repeat(indent));
+        out.push_str("- ");
+        match item {
+            Node::Map(entries) if !entries.is_empty() => write_yaml_map(out, entries, indent + 2),
+            Node::List(inner) if !inner.is_empty() => {
+                out.push('\n');
+                write_yaml_list(out, inner, indent + 2);
+            }
+            scalar => {
+                out.push_str(&scalar.yaml_inline());
+                out.push('\n');
+            }
+        }
+    }
+}
+
+/// Write the value of a map entry, just after its `key:`
+fn write_yaml_value(out: &mut String, value: &Node, indent: usize) {
+    if !value.is_yaml_block() {
+        out.push(' ');
+        out.push_str(&value.yaml_inline());
+        out.push('\n');
+        return;
+    }
+
+    out.push('\n');
+    match value {
+        Node::Map(entries) => write_yaml_map(out, entries, indent + 2),
+        Node::List(items) => write_yaml_list(out, items, indent + 2),
+        _ => unreachable!(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("\"{}\"", s))
+}
+
+/// A YAML scalar for a string: plain when that can't be misread as another
+/// type or as YAML syntax, otherwise double-quoted (JSON escaping is valid
+/// YAML).
+fn yaml_string(s: &str) -> String {
+    let reserved = matches!(
+        s.to_ascii_lowercase().as_str(),
+        "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "y" | "n" | "~"
+    );
+    let plain = !reserved
+        && s.chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '_')
+        && !s.ends_with(' ')
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && s.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(
+                    c,
+                    ' ' | '_' | '-' | '.' | '/' | ',' | '(' | ')' | '$' | '`' | '\''
+                )
+        });
+
+    if plain { s.to_string() } else { json_string(s) }
+}
+
+impl From<&str> for Node {
+    fn from(s: &str) -> Self {
+        Node::Str(s.to_string())
+    }
+}
+
+impl From<String> for Node {
+    fn from(s: String) -> Self {
+        Node::Str(s)
+    }
+}
+
+impl From<bool> for Node {
+    fn from(b: bool) -> Self {
+        Node::Bool(b)
+    }
+}
+
+impl From<i64> for Node {
+    fn from(n: i64) -> Self {
+        Node::Int(n)
+    }
+}
+
+impl From<i32> for Node {
+    fn from(n: i32) -> Self {
+        Node::Int(n as i64)
+    }
+}
+
+impl From<f64> for Node {
+    fn from(f: f64) -> Self {
+        Node::Float(f)
+    }
+}
+
+impl From<Vec<Node>> for Node {
+    fn from(items: Vec<Node>) -> Self {
+        Node::List(items)
+    }
+}
+
+impl From<serde_json::Value> for Node {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Node::Null,
+            serde_json::Value::Bool(b) => Node::Bool(b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Node::Int(i),
+                None => Node::Float(n.as_f64().unwrap_or_default()),
+            },
+            serde_json::Value::String(s) => Node::Str(s),
+            serde_json::Value::Array(items) => {
+                Node::List(items.into_iter().map(Node::from).collect())
+            }
+            serde_json::Value::Object(entries) => Node::Map(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, Node::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{
+        ApiVersioning, AuthConfig, EndpointSecurity, Entity, ListOptions, ProjectGraph,
+        Relationship,
+    };
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+
+        let mut user = Entity::new("User");
+        user.config.timestamps = true;
+        let mut email = Field::new("email", DataType::String);
+        email.required = true;
+        email.validations.push(Validation::Email);
+        email.validations.push(Validation::MaxLength(255));
+        email.example = Some("ada@example.com".to_string());
+        user.fields.push(email);
+        let mut password = Field::new("password_hash", DataType::String);
+        password.required = true;
+        password.secret = true;
+        user.fields.push(password);
+        user.fields.push(Field::new(
+            "bio",
+            DataType::Optional(Box::new(DataType::Text)),
+        ));
+        let user_id = user.id;
+        project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+
+        project
+    }
+
+    fn spec(project: &ProjectGraph) -> Node {
+        openapi_document(&GenerationContext::from_project_default(project))
+    }
+
+    #[test]
+    fn test_document_skeleton() {
+        let doc = spec(&blog());
+        assert_eq!(doc.get("openapi"), Some(&Node::from("3.1.0")));
+        assert_eq!(
+            doc.get("info").and_then(|i| i.get("title")),
+            Some(&Node::from("blog"))
+        );
+
+        let paths = doc.get("paths").unwrap();
+        let list = paths.get("/api/users").unwrap();
+        assert!(list.get("get").is_some() && list.get("post").is_some());
+        let item = paths.get("/api/users/{id}").unwrap();
+        for method in ["get", "put", "delete"] {
+            assert!(item.get(method).is_some(), "missing {}", method);
+        }
+        assert_eq!(
+            item.get("delete").unwrap().get("operationId"),
+            Some(&Node::from("delete_user"))
+        );
+    }
+
+    #[test]
+    fn test_list_parameters() {
+        let mut project = blog();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint
+            .get_operation_mut(OperationType::ReadAll)
+            .unwrap()
+            .list = ListOptions::default().with_page_sizes(10, 30);
+
+        let doc = spec(&project);
+        let list = doc.get("paths").unwrap().get("/api/users").unwrap();
+        let Some(Node::List(parameters)) = list.get("get").unwrap().get("parameters") else {
+            panic!("list has no parameters");
+        };
+        let parameter = |name: &str| {
+            parameters
+                .iter()
+                .find(|p| p.get("name") == Some(&Node::from(name)))
+                .unwrap_or_else(|| panic!("missing {}", name))
+                .get("schema")
+                .unwrap()
+        };
+
+        assert_eq!(parameter("per_page").get("maximum"), Some(&Node::Int(30)));
+        assert_eq!(parameter("per_page").get("default"), Some(&Node::Int(10)));
+        assert_eq!(parameter("sort").get("type"), Some(&Node::from("string")));
+        assert_eq!(parameter("email").get("type"), Some(&Node::from("string")));
+        assert_eq!(
+            parameter("created_at_min").get("format"),
+            Some(&Node::from("date-time"))
+        );
+        // Secret fields can't be filtered on
+        assert!(
+            !parameters
+                .iter()
+                .any(|p| p.get("name") == Some(&Node::from("password_hash")))
+        );
+    }
+
+    #[test]
+    fn test_info_from_project_metadata() {
+        let mut project = blog();
+        let info = spec(&project).get("info").unwrap().clone();
+        assert!(info.get("contact").is_none() && info.get("license").is_none());
+
+        project.meta.author = Some("Jane Doe, John Doe".to_string());
+        project.meta.repository = Some("https://github.com/example/blog".to_string());
+        project.meta.license = Some("MIT".to_string());
+        let info = spec(&project).get("info").unwrap().clone();
+        assert_eq!(
+            info.get("contact"),
+            Some(
+                &Node::map()
+                    .with("name", "Jane Doe, John Doe")
+                    .with("url", "https://github.com/example/blog")
+            )
+        );
+        assert_eq!(
+            info.get("license").and_then(|l| l.get("identifier")),
+            Some(&Node::from("MIT"))
+        );
+    }
+
+    #[test]
+    fn test_entity_schemas_match_dtos() {
+        let doc = spec(&blog());
+        let schemas = doc.get("components").unwrap().get("schemas").unwrap();
+
+        let create = schemas.get("CreateUserDto").unwrap();
+        let properties = create.get("properties").unwrap();
+        let email = properties.get("email").unwrap();
+        assert_eq!(email.get("format"), Some(&Node::from("email")));
+        assert_eq!(email.get("maxLength"), Some(&Node::Int(255)));
+        // Secrets are sent in plain text under the name without `_hash`
+        assert!(properties.get("password_hash").is_none());
+        assert_eq!(
+            properties.get("password").unwrap().get("format"),
+            Some(&Node::from("password"))
+        );
+        assert_eq!(
+            create.get("required"),
+            Some(&Node::from(vec![
+                Node::from("email"),
+                Node::from("password")
+            ]))
+        );
+
+        let response = schemas
+            .get("UserResponse")
+            .unwrap()
+            .get("properties")
+            .unwrap();
+        assert!(response.get("password_hash").is_none());
+        assert!(response.get("created_at").is_some());
+        assert_eq!(
+            response.get("bio").unwrap().get("type"),
+            Some(&Node::from(vec![Node::from("string"), Node::from("null")]))
+        );
+
+        assert!(schemas.get("UserPage").is_some());
+        let problem = schemas.get("ProblemDetails").unwrap();
+        assert_eq!(
+            problem.get("required"),
+            Some(&Node::from(vec![
+                Node::from("type"),
+                Node::from("title"),
+                Node::from("status"),
+                Node::from("detail"),
+                Node::from("code"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_examples_keep_their_json_type() {
+        let mut project = blog();
+        let user = project.entities.values_mut().next().unwrap();
+        let mut age = Field::new("age", DataType::Int32);
+        age.example = Some("42".to_string());
+        user.fields.push(age);
+        let mut settings = Field::new("settings", DataType::Json);
+        settings.example = Some(r#"{"theme": "dark"}"#.to_string());
+        user.fields.push(settings);
+
+        let doc = spec(&project);
+        let properties = doc
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(|s| s.get("UserResponse"))
+            .and_then(|u| u.get("properties"))
+            .unwrap();
+        let example = |name: &str| properties.get(name).unwrap().get("examples").cloned();
+        assert_eq!(
+            example("email"),
+            Some(Node::from(vec![Node::from("ada@example.com")]))
+        );
+        assert_eq!(example("age"), Some(Node::from(vec![Node::Int(42)])));
+        assert_eq!(
+            example("settings"),
+            Some(Node::from(vec![Node::map().with("theme", "dark")]))
+        );
+        assert_eq!(example("bio"), None);
+    }
+
+    #[test]
+    fn test_secured_operations() {
+        let mut project = blog();
+        project.config.auth = AuthConfig::jwt();
+        let user_id = project.entities.keys().copied().next().unwrap();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.set_operation_security(
+            OperationType::Delete,
+            EndpointSecurity::with_roles(vec!["admin".into()]),
+        );
+        assert_eq!(endpoint.entity_id, user_id);
+
+        let doc = spec(&project);
+        let schemes = doc
+            .get("components")
+            .unwrap()
+            .get("securitySchemes")
+            .unwrap();
+        assert_eq!(
+            schemes.get("bearerAuth").unwrap().get("scheme"),
+            Some(&Node::from("bearer"))
+        );
+
+        let item = doc.get("paths").unwrap().get("/api/users/{id}").unwrap();
+        let delete = item.get("delete").unwrap();
+        assert!(delete.get("security").is_some());
+        assert!(delete.get("responses").unwrap().get("403").is_some());
+        assert!(item.get("get").unwrap().get("security").is_none());
+    }
+
+    #[test]
+    fn test_soft_delete_operations() {
+        let mut project = blog();
+        project
+            .entities
+            .values_mut()
+            .next()
+            .unwrap()
+            .config
+            .soft_delete = true;
+        let doc = spec(&project);
+        let paths = doc.get("paths").unwrap();
+
+        for (path, method) in [("/api/users", "get"), ("/api/users/{id}", "get")] {
+            let Some(Node::List(parameters)) = paths
+                .get(path)
+                .unwrap()
+                .get(method)
+                .unwrap()
+                .get("parameters")
+            else {
+                panic!("{} has no parameters", path);
+            };
+            assert!(
+                parameters
+                    .iter()
+                    .any(|p| p.get("name") == Some(&Node::from("include_deleted")))
+            );
+        }
+
+        let restore = paths
+            .get("/api/users/{id}/restore")
+            .and_then(|p| p.get("post"))
+            .expect("restore path");
+        assert_eq!(
+            restore.get("operationId"),
+            Some(&Node::from("restore_user"))
+        );
+
+        let response = doc
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(|s| s.get("UserResponse"))
+            .unwrap();
+        assert!(
+            response
+                .get("properties")
+                .and_then(|p| p.get("deleted_at"))
+                .is_some()
+        );
+
+        let doc = spec(&blog());
+        assert!(
+            doc.get("paths")
+                .unwrap()
+                .get("/api/users/{id}/restore")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_conditional_read_operations() {
+        let mut project = blog();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint
+                .get_operation_mut(OperationType::Read)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::UpdatedAt).with_last_modified();
+            endpoint
+                .get_operation_mut(OperationType::ReadAll)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::ContentHash);
+        }
+        let doc = spec(&project);
+        let operation = |path: &str, method: &str| {
+            doc.get("paths")
+                .and_then(|p| p.get(path))
+                .and_then(|p| p.get(method))
+                .cloned()
+                .unwrap()
+        };
+        let parameter_names = |op: &Node| -> Vec<Node> {
+            match op.get("parameters") {
+                Some(Node::List(items)) => items
+                    .iter()
+                    .filter_map(|p| p.get("name").cloned())
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+
+        let get = operation("/api/users/{id}", "get");
+        let names = parameter_names(&get);
+        assert!(names.contains(&Node::from("If-None-Match")));
+        assert!(names.contains(&Node::from("If-Modified-Since")));
+        let headers = get
+            .get("responses")
+            .and_then(|r| r.get("200"))
+            .and_then(|r| r.get("headers"))
+            .unwrap();
+        assert!(headers.get("ETag").is_some());
+        assert!(headers.get("Last-Modified").is_some());
+        assert!(get.get("responses").and_then(|r| r.get("304")).is_some());
+
+        let list = operation("/api/users", "get");
+        let names = parameter_names(&list);
+        assert!(names.contains(&Node::from("If-None-Match")));
+        assert!(!names.contains(&Node::from("If-Modified-Since")));
+        assert!(list.get("responses").and_then(|r| r.get("304")).is_some());
+
+        // Writes are left alone
+        let update = operation("/api/users/{id}", "put");
+        assert!(update.get("responses").and_then(|r| r.get("304")).is_none());
+    }
+
+    #[test]
+    fn test_upload_operations() {
+        let mut project = blog();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields
+            .push(Field::new("avatar", DataType::String).with_widget(WidgetType::Image));
+        let doc = spec(&project);
+
+        let upload = doc
+            .get("paths")
+            .and_then(|p| p.get("/api/users/{id}/avatar"))
+            .and_then(|p| p.get("post"))
+            .expect("upload path");
+        assert_eq!(
+            upload.get("operationId"),
+            Some(&Node::from("upload_user_avatar"))
+        );
+        let file = upload
+            .get("requestBody")
+            .and_then(|b| b.get("content"))
+            .and_then(|c| c.get("multipart/form-data"))
+            .and_then(|m| m.get("schema"))
+            .and_then(|s| s.get("properties"))
+            .and_then(|p| p.get("file"))
+            .unwrap();
+        assert_eq!(file.get("format"), Some(&Node::from("binary")));
+        assert_eq!(file.get("contentMediaType"), Some(&Node::from("image/*")));
+
+        // Set through the upload route only
+        let create = doc
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(|s| s.get("CreateUserDto"))
+            .unwrap();
+        assert!(
+            create
+                .get("properties")
+                .and_then(|p| p.get("avatar"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_scoped_unique_conflicts() {
+        let mut project = blog();
+        let user = project.entities.keys().copied().next().unwrap();
+        let mut team = Entity::new("Team");
+        team.fields.push(Field::new("slug", DataType::String));
+        let team = project.add_entity(team);
+        project.add_endpoint(EndpointGroup::new(team, "Team"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(user, team))
+            .unwrap();
+        let team = project.get_entity_mut(team).unwrap();
+        team.fields
+            .iter_mut()
+            .find(|f| f.name == "slug")
+            .unwrap()
+            .unique_scope = Some(rel);
+
+        let doc = spec(&project);
+        let paths = doc.get("paths").unwrap();
+        for (path, method) in [("/api/teams", "post"), ("/api/teams/{id}", "put")] {
+            let conflict = paths
+                .get(path)
+                .and_then(|p| p.get(method))
+                .and_then(|o| o.get("responses"))
+                .and_then(|r| r.get("409"))
+                .unwrap_or_else(|| panic!("{} {} has no 409", method, path));
+            assert_eq!(
+                conflict.get("description"),
+                Some(&Node::from(
+                    "A team with this slug already exists in this user"
+                ))
+            );
+        }
+        assert!(
+            paths
+                .get("/api/users")
+                .and_then(|p| p.get("post"))
+                .and_then(|o| o.get("responses"))
+                .and_then(|r| r.get("409"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_association_operations() {
+        let mut project = ProjectGraph::new("blog");
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        project.add_endpoint(EndpointGroup::new(post, "Post"));
+        project.add_endpoint(EndpointGroup::new(tag, "Tag"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(post, tag, "post_tags"))
+            .unwrap();
+        project.promote_junction(rel).unwrap();
+        let doc = spec(&project);
+        let paths = doc.get("paths").unwrap();
+
+        let operation = |path: &str, method: &str| {
+            paths
+                .get(path)
+                .and_then(|p| p.get(method))
+                .unwrap_or_else(|| panic!("{} {} missing", method, path))
+                .get("operationId")
+                .cloned()
+        };
+        assert_eq!(
+            operation("/api/posts/{id}/tags", "get"),
+            Some(Node::from("list_post_tags"))
+        );
+        assert_eq!(
+            operation("/api/posts/{id}/tags", "post"),
+            Some(Node::from("link_post_tags"))
+        );
+        assert_eq!(
+            operation("/api/posts/{id}/tags/{tag_id}", "delete"),
+            Some(Node::from("unlink_post_tags"))
+        );
+        assert!(paths.get("/api/tags/{id}/posts/{post_id}").is_some());
+
+        let schemas = doc
+            .get("components")
+            .and_then(|c| c.get("schemas"))
+            .unwrap();
+        assert!(schemas.get("PostTagResponse").is_some());
+        let link = schemas.get("PostTagsLinkDto").expect("link DTO schema");
+        let properties = link.get("properties").unwrap();
+        assert!(properties.get("tag_id").is_some());
+        assert!(properties.get("post_id").is_none());
+    }
+
+    #[test]
+    fn test_api_versioning_paths() {
+        let mut project = blog();
+        project.config.api_versioning = ApiVersioning::path_prefix("v1");
+        let doc = spec(&project);
+        let paths = doc.get("paths").unwrap();
+        assert!(paths.get("/api/v1/users").is_some());
+        assert!(paths.get("/api/users").is_none());
+
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let doc = spec(&project);
+        let Some(Node::List(parameters)) = doc
+            .get("paths")
+            .and_then(|p| p.get("/api/users/{id}"))
+            .and_then(|p| p.get("get"))
+            .and_then(|op| op.get("parameters"))
+        else {
+            panic!("/api/users/{{id}} has no parameters");
+        };
+        let header = parameters
+            .iter()
+            .find(|p| p.get("name") == Some(&Node::from("X-API-Version")))
+            .expect("version header parameter");
+        assert_eq!(header.get("in"), Some(&Node::from("header")));
+    }
+
+    #[test]
+    fn test_yaml_and_json_rendering() {
+        let node = Node::map()
+            .with("openapi", "3.1.0")
+            .with("title", "Blog: the API")
+            .with("empty", Node::map())
+            .with(
+                "list",
+                vec![
+                    Node::map().with("name", "a").with("required", true),
+                    Node::from("null"),
+                ],
+            )
+            .with("nested", Node::map().with("size", 2).with("x", 1.5));
+
+        assert_eq!(
+            node.to_yaml(),
+            "openapi: \"3.1.0\"\n\
+             title: \"Blog: the API\"\n\
+             empty: {}\n\
+             list:\n  \
+               - name: a\n    \
+                 required: true\n  \
+               - \"null\"\n\
+             nested:\n  \
+               size: 2\n  \
+               x: 1.5\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&node.to_json()).unwrap();
+        assert_eq!(json["list"][0]["required"], true);
+        assert_eq!(json["nested"]["x"], 1.5);
+    }
+
+    #[test]
+    fn test_generated_spec_imports_back() {
+        let mut project = blog();
+        project.config.auth = AuthConfig::jwt();
+        let mut post = Entity::new("Post");
+        let mut title = Field::new("title", DataType::String);
+        title.required = true;
+        post.fields.push(title);
+        let mut author = Field::foreign_key("user_id", "User", "id");
+        author.required = true;
+        post.fields.push(author);
+        let post_id = post.id;
+        project.add_entity(post);
+        project.add_endpoint(EndpointGroup::new(post_id, "Post").secured());
+
+        let api = imortal_ir::import_openapi(&spec(&project).to_yaml()).unwrap();
+        assert!(api.warnings.is_empty(), "{:?}", api.warnings);
+        let imported = api.into_project("blog");
+        assert!(imported.config.auth.enabled);
+
+        for entity in project.entities.values() {
+            let copy = imported.get_entity_by_name(&entity.name).unwrap();
+            for field in &entity.fields {
+                let imported = copy.get_field_by_name(&field.name).unwrap();
+                assert_eq!(imported.required, field.required, "{}", field.name);
+                assert_eq!(imported.secret, field.secret, "{}", field.name);
+            }
+        }
+        let user = imported.get_entity_by_name("User").unwrap();
+        assert!(user.config.timestamps);
+        assert!(
+            user.get_field_by_name("email")
+                .unwrap()
+                .validations
+                .contains(&Validation::Email)
+        );
+        let post = imported.get_entity_by_name("Post").unwrap();
+        assert!(post.get_field_by_name("user_id").unwrap().is_foreign_key);
+        assert_eq!(imported.relationship_count(), 1);
+
+        let mut paths: Vec<(&str, bool)> = imported
+            .endpoints()
+            .map(|e| (e.base_path.as_str(), e.requires_auth()))
+            .collect();
+        paths.sort();
+        assert_eq!(paths, [("/api/posts", true), ("/api/users", false)]);
+        assert!(
+            imported
+                .endpoints()
+                .all(|e| e.enabled_operations().len() == 5)
+        );
+    }
+
+    #[test]
+    fn test_generate_openapi_files() {
+        let project = blog();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_openapi(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str(), Some("openapi.yaml"));
+        assert!(files[0].content.starts_with("openapi: \"3.1.0\"\n"));
+
+        let config = crate::GeneratorConfig::default().with_openapi_json();
+        let ctx = GenerationContext::from_project(&project, config);
+        let files = generate_openapi(&ctx);
+        assert_eq!(files.len(), 2);
+        assert!(serde_json::from_str::<serde_json::Value>(&files[1].content).is_ok());
+
+        let mut project = blog();
+        project.config.openapi_enabled = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_openapi(&ctx).is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+//! # Dry Run
+//!
+//! [`Generator::plan`](crate::Generator::plan) runs the generators without
+//! touching the output directory and returns a [`GenerationPlan`]: a
+//! manifest of every file that would be written or deleted, its size, and
+//! whether it conflicts with what is on disk.
+//!
+//! A file **conflicts** when writing it would replace or delete an existing
+//! file the user edited since it was generated — it is
+//! [`Changed`](FileStatus::Changed) or [`Removed`](FileStatus::Removed) and
+//! no longer matches its checksum in the [output manifest](crate::manifest)
+//! — and the generator was not configured to
+//! [overwrite](crate::GeneratorConfig::allow_overwrite). Added and unchanged
+//! files never conflict, nor do files that are merely out of date.
+//!
+//! The plan keeps the generated files, so it can be written afterwards
+//! without generating again. Writing it updates the output manifest.
+//! Post-generation hooks only run through
+//! [`Generator::generate_and_write`](crate::Generator::generate_and_write).
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use imortal_core::EngineResult;
+
+use crate::manifest::Manifest;
+use crate::preview::{FileStatus, OutputPreview, preview_output};
+use crate::{FileType, GeneratedProject};
+
+// ============================================================================
+// ManifestEntry
+// ============================================================================
+
+/// One file of a [`GenerationPlan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// Path relative to the output directory
+    pub path: PathBuf,
+
+    /// File type, guessed from the extension for removed files
+    pub file_type: FileType,
+
+    /// Bytes that would be written, 0 for removed files
+    pub size: usize,
+
+    /// How the file relates to the output directory
+    pub status: FileStatus,
+
+    /// Writing the file would replace or delete one the user edited,
+    /// without `overwrite` set
+    pub conflict: bool,
+}
+
+// ============================================================================
+// GenerationPlan
+// ============================================================================
+
+/// What a generation would do to its output directory
+#[derive(Debug, Clone)]
+pub struct GenerationPlan {
+    /// Directory the files would be written to
+    pub output_dir: PathBuf,
+
+    /// Whether existing files may be replaced
+    pub overwrite: bool,
+
+    /// Every file, sorted by path
+    pub manifest: Vec<ManifestEntry>,
+
+    /// Comparison with the directory, with contents for diffing
+    pub preview: OutputPreview,
+
+    /// The generated files and warnings
+    pub project: GeneratedProject,
+}
+
+impl GenerationPlan {
+    /// Compare a generated project with `output_dir`.
+    pub fn new(project: GeneratedProject, output_dir: &Path, overwrite: bool) -> Self {
+        let preview = preview_output(&project, output_dir);
+        let checksums = Manifest::load(output_dir);
+        let manifest = preview
+            .files
+            .iter()
+            .map(|file| ManifestEntry {
+                path: file.path.clone(),
+                file_type: file.file_type,
+                size: file.content.len(),
+                status: file.status,
+                conflict: !overwrite
+                    && matches!(file.status, FileStatus::Changed | FileStatus::Removed)
+                    && file
+                        .existing
+                        .as_deref()
+                        .is_some_and(|existing| checksums.is_edited(&file.path, existing)),
+            })
+            .collect();
+
+        Self {
+            output_dir: output_dir.to_path_buf(),
+            overwrite,
+            manifest,
+            preview,
+            project,
+        }
+    }
+
+    /// Entries that conflict with files on disk
+    pub fn conflicts(&self) -> impl Iterator<Item = &ManifestEntry> {
+        self.manifest.iter().filter(|entry| entry.conflict)
+    }
+
+    /// Whether any entry conflicts
+    pub fn has_conflicts(&self) -> bool {
+        self.conflicts().next().is_some()
+    }
+
+    /// Paths of the conflicting entries, the set to exclude from
+    /// [`write`](Self::write) to leave them alone
+    pub fn conflicting_paths(&self) -> HashSet<PathBuf> {
+        self.conflicts().map(|entry| entry.path.clone()).collect()
+    }
+
+    /// Total bytes of the files that would be written
+    pub fn total_bytes(&self) -> usize {
+        self.manifest
+            .iter()
+            .filter(|entry| entry.status.is_change())
+            .map(|entry| entry.size)
+            .sum()
+    }
+
+    /// One-line summary, e.g. `3 added, 1 changed, 40 unchanged; 1 conflict`
+    pub fn summary(&self) -> String {
+        let mut summary = self.preview.summary();
+        match self.conflicts().count() {
+            0 => {}
+            1 => summary.push_str("; 1 conflict"),
+            n => summary.push_str(&format!("; {} conflicts", n)),
+        }
+        summary
+    }
+
+    /// Write the plan, skipping unchanged files and any path in `excluded`.
+    /// Removed files are deleted. The output manifest then records the
+    /// written files; excluded ones keep their previous checksum.
+    ///
+    /// Conflicting files are written like any other; exclude
+    /// [`conflicting_paths`](Self::conflicting_paths) to leave them alone.
+    ///
+    /// Returns the number of files written or deleted.
+    pub fn write(&self, excluded: &HashSet<PathBuf>) -> EngineResult<usize> {
+        let written = self
+            .preview
+            .write(&self.project, &self.output_dir, excluded)?;
+        let generated = self.project.files.iter().map(|f| f.path.as_path());
+        Manifest::record(&self.output_dir, generated, excluded)?;
+        Ok(written)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratedFile;
+    use crate::manifest::MANIFEST_FILE;
+
+    fn project() -> GeneratedProject {
+        let mut project = GeneratedProject::new("app");
+        project.add_file(GeneratedFile::rust("src/main.rs", "fn main() {}\n"));
+        project.add_file(GeneratedFile::rust("src/lib.rs", "pub mod a;\n"));
+        project.add_file(GeneratedFile::toml("Cargo.toml", "[package]\n"));
+        project
+    }
+
+    fn output_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "// hand-written\n").unwrap();
+        std::fs::write(
+            dir.path().join("src/old.rs"),
+            "// Auto-generated by Immortal Engine\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_manifest_lists_sizes_and_statuses() {
+        let dir = output_dir();
+        let plan = GenerationPlan::new(project(), dir.path(), true);
+
+        let entries: Vec<_> = plan
+            .manifest
+            .iter()
+            .map(|e| (e.path.to_str().unwrap(), e.status, e.size))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("Cargo.toml", FileStatus::Added, 10),
+                ("src/lib.rs", FileStatus::Changed, 11),
+                ("src/main.rs", FileStatus::Unchanged, 13),
+                ("src/old.rs", FileStatus::Removed, 0),
+            ]
+        );
+        assert_eq!(plan.total_bytes(), 21);
+        assert!(!plan.has_conflicts());
+    }
+
+    #[test]
+    fn test_conflicts_without_overwrite() {
+        let dir = output_dir();
+        let plan = GenerationPlan::new(project(), dir.path(), false);
+
+        // src/old.rs carries the generated header, so it is only out of date
+        let conflicts: Vec<_> = plan.conflicts().map(|e| e.path.clone()).collect();
+        assert_eq!(conflicts, [PathBuf::from("src/lib.rs")]);
+        assert_eq!(
+            plan.summary(),
+            "1 added, 1 changed, 1 removed, 1 unchanged; 1 conflict"
+        );
+    }
+
+    #[test]
+    fn test_conflicts_follow_the_output_manifest() {
+        let dir = output_dir();
+        GenerationPlan::new(project(), dir.path(), true)
+            .write(&HashSet::new())
+            .unwrap();
+        assert!(dir.path().join(MANIFEST_FILE).exists());
+
+        // Regenerating over untouched output replaces it freely
+        let mut next = project();
+        next.files[0].content = "fn main() { run() }\n".to_string();
+        let plan = GenerationPlan::new(next.clone(), dir.path(), false);
+        assert_eq!(plan.preview.count(FileStatus::Changed), 1);
+        assert!(!plan.has_conflicts());
+
+        // An edit made since is protected
+        std::fs::write(dir.path().join("src/main.rs"), "fn main() { mine() }\n").unwrap();
+        let plan = GenerationPlan::new(next, dir.path(), false);
+        assert_eq!(
+            plan.conflicting_paths(),
+            HashSet::from([PathBuf::from("src/main.rs")])
+        );
+    }
+
+    #[test]
+    fn test_write_can_leave_conflicts_alone() {
+        let dir = output_dir();
+        let plan = GenerationPlan::new(project(), dir.path(), false);
+
+        let written = plan.write(&plan.conflicting_paths()).unwrap();
+        assert_eq!(written, 2);
+        assert!(dir.path().join("Cargo.toml").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("src/lib.rs")).unwrap(),
+            "// hand-written\n"
+        );
+        assert!(!dir.path().join("src/old.rs").exists());
+
+        let manifest = Manifest::load(dir.path());
+        assert!(manifest.get(Path::new("Cargo.toml")).is_some());
+        assert!(manifest.get(Path::new("src/lib.rs")).is_none());
+    }
+
+    #[test]
+    fn test_missing_output_dir_has_no_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = GenerationPlan::new(project(), &dir.path().join("new"), false);
+        assert!(!plan.has_conflicts());
+        assert!(plan.manifest.iter().all(|e| e.status == FileStatus::Added));
+        assert_eq!(plan.summary(), "3 added");
+    }
+}
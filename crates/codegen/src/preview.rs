@@ -0,0 +1,535 @@
+//! # Output Preview
+//!
+//! Compares a generation held in memory with the output directory it is
+//! about to be written to, so the files can be reviewed before anything on
+//! disk changes.
+//!
+//! Every generated file is **added**, **changed** or **unchanged**. Files
+//! in the directory that carry the auto-generated header but are no longer
+//! produced are **removed** — writing the preview deletes them. Files can
+//! be excluded from the write one by one, which leaves their copy on disk
+//! as it is.
+//!
+//! Migrations are matched across dates and line endings are normalised,
+//! the same way as [`check_output`](crate::check_output).
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use imortal_core::{EngineError, EngineResult};
+
+use crate::check::{GENERATED_MARKER, committed_files, normalize_newlines, undated_migration};
+use crate::{FileType, GeneratedProject};
+
+/// Largest `old lines × new lines` product diffed line by line; bigger
+/// files are shown as fully replaced
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+// ============================================================================
+// FileStatus
+// ============================================================================
+
+/// How a file in the preview relates to the output directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileStatus {
+    /// Generated, with no copy on disk
+    Added,
+    /// Generated, with a different copy on disk
+    Changed,
+    /// Generated, with an identical copy on disk
+    Unchanged,
+    /// On disk, marked as generated, but no longer produced
+    Removed,
+}
+
+impl FileStatus {
+    /// Short label for display
+    pub fn label(&self) -> &'static str {
+        match self {
+            FileStatus::Added => "added",
+            FileStatus::Changed => "changed",
+            FileStatus::Unchanged => "unchanged",
+            FileStatus::Removed => "removed",
+        }
+    }
+
+    /// Whether writing the preview touches this file
+    pub fn is_change(&self) -> bool {
+        !matches!(self, FileStatus::Unchanged)
+    }
+}
+
+// ============================================================================
+// FilePreview
+// ============================================================================
+
+/// One file of an [`OutputPreview`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePreview {
+    /// Path relative to the output directory
+    pub path: PathBuf,
+
+    /// How the file relates to the directory
+    pub status: FileStatus,
+
+    /// File type, guessed from the extension for removed files
+    pub file_type: FileType,
+
+    /// Generated content, empty for removed files
+    pub content: String,
+
+    /// Content currently on disk, if any
+    pub existing: Option<String>,
+}
+
+impl FilePreview {
+    /// Line diff from the copy on disk to the generated content
+    pub fn diff(&self) -> Vec<DiffLine> {
+        diff_lines(self.existing.as_deref().unwrap_or_default(), &self.content)
+    }
+
+    /// Number of added and removed lines
+    pub fn line_counts(&self) -> (usize, usize) {
+        let diff = self.diff();
+        let added = diff
+            .iter()
+            .filter(|l| matches!(l, DiffLine::Added(_)))
+            .count();
+        let removed = diff
+            .iter()
+            .filter(|l| matches!(l, DiffLine::Removed(_)))
+            .count();
+        (added, removed)
+    }
+}
+
+// ============================================================================
+// OutputPreview
+// ============================================================================
+
+/// Every file a generation would add, change or remove in a directory
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputPreview {
+    /// Files sorted by path
+    pub files: Vec<FilePreview>,
+}
+
+impl OutputPreview {
+    /// Number of files with the given status
+    pub fn count(&self, status: FileStatus) -> usize {
+        self.files.iter().filter(|f| f.status == status).count()
+    }
+
+    /// Whether writing would change anything on disk
+    pub fn has_changes(&self) -> bool {
+        self.files.iter().any(|f| f.status.is_change())
+    }
+
+    /// Look up a file by path
+    pub fn file(&self, path: &Path) -> Option<&FilePreview> {
+        self.files.iter().find(|f| f.path == path)
+    }
+
+    /// One-line summary, e.g. `3 added, 1 changed, 40 unchanged`
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = [
+            FileStatus::Added,
+            FileStatus::Changed,
+            FileStatus::Removed,
+            FileStatus::Unchanged,
+        ]
+        .into_iter()
+        .map(|status| (self.count(status), status.label()))
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{} {}", count, label))
+        .collect();
+
+        if parts.is_empty() {
+            "no files".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    /// Write the preview into `dir`, skipping unchanged files and any path
+    /// in `excluded`. Removed files are deleted.
+    ///
+    /// Returns the number of files written or deleted.
+    pub fn write(
+        &self,
+        project: &GeneratedProject,
+        dir: &Path,
+        excluded: &HashSet<PathBuf>,
+    ) -> EngineResult<usize> {
+        let written: HashSet<&Path> = self
+            .files
+            .iter()
+            .filter(|f| f.status.is_change() && !excluded.contains(&f.path))
+            .map(|f| f.path.as_path())
+            .collect();
+
+        let skipped: HashSet<PathBuf> = project
+            .files
+            .iter()
+            .filter(|f| !written.contains(f.path.as_path()))
+            .map(|f| f.path.clone())
+            .collect();
+        project.write_to_disk_except(dir, &skipped)?;
+
+        for file in &self.files {
+            if file.status == FileStatus::Removed && written.contains(file.path.as_path()) {
+                let path = dir.join(&file.path);
+                std::fs::remove_file(&path).map_err(|e| EngineError::FileWrite {
+                    path: path.clone(),
+                    message: e.to_string(),
+                })?;
+            }
+        }
+
+        Ok(written.len())
+    }
+}
+
+/// Compare the generated `project` with the contents of `dir`.
+///
+/// A missing `dir` counts as empty, so every file is added.
+pub fn preview_output(project: &GeneratedProject, dir: &Path) -> OutputPreview {
+    let existing = if dir.is_dir() {
+        committed_files(dir)
+    } else {
+        HashSet::new()
+    };
+    let by_migration_key: HashMap<PathBuf, &PathBuf> = existing
+        .iter()
+        .filter_map(|path| undated_migration(path).map(|key| (key, path)))
+        .collect();
+
+    let mut files = Vec::new();
+    let mut seen: HashSet<&Path> = HashSet::new();
+
+    for file in &project.files {
+        let counterpart = if existing.contains(&file.path) {
+            Some(&file.path)
+        } else {
+            undated_migration(&file.path).and_then(|key| by_migration_key.get(&key).copied())
+        };
+        let on_disk = counterpart.and_then(|path| {
+            seen.insert(path);
+            std::fs::read(dir.join(path)).ok()
+        });
+
+        let status = match &on_disk {
+            None => FileStatus::Added,
+            Some(bytes)
+                if normalize_newlines(bytes) == normalize_newlines(file.content.as_bytes()) =>
+            {
+                FileStatus::Unchanged
+            }
+            Some(_) => FileStatus::Changed,
+        };
+        files.push(FilePreview {
+            path: file.path.clone(),
+            status,
+            file_type: file.file_type,
+            content: file.content.clone(),
+            existing: on_disk.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+        });
+    }
+
+    for path in &existing {
+        if seen.contains(path.as_path()) {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(dir.join(path)) else {
+            continue;
+        };
+        if !content.contains(GENERATED_MARKER) {
+            continue;
+        }
+        let file_type = path
+            .extension()
+            .and_then(|ext| FileType::from_extension(&ext.to_string_lossy()))
+            .unwrap_or(FileType::Other);
+        files.push(FilePreview {
+            path: path.clone(),
+            status: FileStatus::Removed,
+            file_type,
+            content: String::new(),
+            existing: Some(content),
+        });
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    OutputPreview { files }
+}
+
+// ============================================================================
+// Line Diff
+// ============================================================================
+
+/// One line of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// Present on both sides
+    Context(String),
+    /// Only in the generated content
+    Added(String),
+    /// Only in the copy on disk
+    Removed(String),
+    /// A run of unchanged lines left out by [`compact_diff`]
+    Skipped(usize),
+}
+
+/// Line diff from `old` to `new`, based on their longest common
+/// subsequence. Line endings are ignored.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut diff: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|l| DiffLine::Context(l.to_string()))
+        .collect();
+
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_DIFF_CELLS {
+        diff.extend(old_mid.iter().map(|l| DiffLine::Removed(l.to_string())));
+        diff.extend(new_mid.iter().map(|l| DiffLine::Added(l.to_string())));
+    } else {
+        diff.extend(lcs_diff(old_mid, new_mid));
+    }
+
+    diff.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|l| DiffLine::Context(l.to_string())),
+    );
+    diff
+}
+
+/// Keep only `context` unchanged lines around each change, replacing the
+/// rest with [`DiffLine::Skipped`]
+pub fn compact_diff(diff: Vec<DiffLine>, context: usize) -> Vec<DiffLine> {
+    let changed: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !matches!(l, DiffLine::Context(_)))
+        .map(|(i, _)| i)
+        .collect();
+    let near_change = |i: usize| {
+        let at = changed.partition_point(|&c| c + context < i);
+        changed.get(at).is_some_and(|&c| c <= i + context)
+    };
+
+    let mut out = Vec::new();
+    let mut skipped = 0;
+    for (i, line) in diff.into_iter().enumerate() {
+        if matches!(line, DiffLine::Context(_)) && !near_change(i) {
+            skipped += 1;
+            continue;
+        }
+        if skipped > 0 {
+            out.push(DiffLine::Skipped(skipped));
+            skipped = 0;
+        }
+        out.push(line);
+    }
+    if skipped > 0 {
+        out.push(DiffLine::Skipped(skipped));
+    }
+    out
+}
+
+fn lcs_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    // lengths[i][j]: longest common subsequence of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(DiffLine::Context(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            diff.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    diff.extend(new[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+    diff
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratedFile;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let full = dir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(full, content).unwrap();
+    }
+
+    fn project(files: &[(&str, &str)]) -> GeneratedProject {
+        let mut project = GeneratedProject::new("app");
+        for (path, content) in files {
+            project.add_file(GeneratedFile::rust(*path, *content));
+        }
+        project
+    }
+
+    #[test]
+    fn test_preview_statuses() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "src/main.rs", "fn main() {}\r\n");
+        write(dir.path(), "src/lib.rs", "pub mod old;\n");
+        write(
+            dir.path(),
+            "src/models/tag.rs",
+            "//! Auto-generated by Immortal Engine\n",
+        );
+        write(dir.path(), "src/custom.rs", "// hand-written\n");
+
+        let generated = project(&[
+            ("src/main.rs", "fn main() {}\n"),
+            ("src/lib.rs", "pub mod new;\n"),
+            ("src/config.rs", "\n"),
+        ]);
+        let preview = preview_output(&generated, dir.path());
+
+        let statuses: Vec<(&str, FileStatus)> = preview
+            .files
+            .iter()
+            .map(|f| (f.path.to_str().unwrap(), f.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("src/config.rs", FileStatus::Added),
+                ("src/lib.rs", FileStatus::Changed),
+                ("src/main.rs", FileStatus::Unchanged),
+                ("src/models/tag.rs", FileStatus::Removed),
+            ]
+        );
+        assert_eq!(
+            preview.summary(),
+            "1 added, 1 changed, 1 removed, 1 unchanged"
+        );
+        assert_eq!(
+            preview.file(Path::new("src/lib.rs")).unwrap().line_counts(),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_preview_missing_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let generated = project(&[("Cargo.toml", "[package]\n")]);
+
+        let preview = preview_output(&generated, &dir.path().join("nope"));
+        assert_eq!(preview.count(FileStatus::Added), 1);
+        assert!(preview.has_changes());
+        assert_eq!(preview.files[0].existing, None);
+    }
+
+    #[test]
+    fn test_write_skips_excluded_and_deletes_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "src/lib.rs", "pub mod old;\n");
+        write(
+            dir.path(),
+            "src/keep.rs",
+            "// Auto-generated by Immortal Engine\n",
+        );
+        write(
+            dir.path(),
+            "src/gone.rs",
+            "// Auto-generated by Immortal Engine\n",
+        );
+
+        let generated = project(&[("src/lib.rs", "pub mod new;\n"), ("src/main.rs", "")]);
+        let preview = preview_output(&generated, dir.path());
+        let excluded: HashSet<PathBuf> =
+            [PathBuf::from("src/lib.rs"), PathBuf::from("src/keep.rs")].into();
+
+        let touched = preview.write(&generated, dir.path(), &excluded).unwrap();
+        assert_eq!(touched, 2);
+        assert!(dir.path().join("src/main.rs").exists());
+        assert!(!dir.path().join("src/gone.rs").exists());
+        assert!(dir.path().join("src/keep.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("src/lib.rs")).unwrap(),
+            "pub mod old;\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc\nd\n", "a\nc\nx\nd\n");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Context("a".into()),
+                DiffLine::Removed("b".into()),
+                DiffLine::Context("c".into()),
+                DiffLine::Added("x".into()),
+                DiffLine::Context("d".into()),
+            ]
+        );
+        assert_eq!(diff_lines("", "one\n"), vec![DiffLine::Added("one".into())]);
+        assert!(
+            diff_lines("same\r\n", "same\n")
+                .iter()
+                .all(|l| matches!(l, DiffLine::Context(_)))
+        );
+    }
+
+    #[test]
+    fn test_compact_diff() {
+        let old: String = (0..20).map(|i| format!("line {}\n", i)).collect();
+        let new = old.replace("line 10\n", "changed\n");
+
+        let compact = compact_diff(diff_lines(&old, &new), 2);
+        assert_eq!(
+            compact,
+            vec![
+                DiffLine::Skipped(8),
+                DiffLine::Context("line 8".into()),
+                DiffLine::Context("line 9".into()),
+                DiffLine::Removed("line 10".into()),
+                DiffLine::Added("changed".into()),
+                DiffLine::Context("line 11".into()),
+                DiffLine::Context("line 12".into()),
+                DiffLine::Skipped(7),
+            ]
+        );
+    }
+}
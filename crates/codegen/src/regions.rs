@@ -0,0 +1,437 @@
+//! # Protected Regions
+//!
+//! Lets hand-written code live inside generated files. Everything between a
+//! pair of markers survives regeneration:
+//!
+//! ```rust,ignore
+//! // <immortal:keep custom> code between these markers survives regeneration
+//! impl User {
+//!     pub fn display_name(&self) -> String { /* … */ }
+//! }
+//! // </immortal:keep>
+//! ```
+//!
+//! Before the output is returned, each generated file is compared with its
+//! copy in the output directory. The body of every region found on disk
+//! replaces the body of the region with the same name in the new output.
+//! Any comment syntax works — only the `<immortal:keep name>` and
+//! `</immortal:keep>` tags are looked for, one per line. Unnamed regions are
+//! matched in order.
+//!
+//! A region the new output has no place for is appended to the end of the
+//! file, markers included, and reported so it can be moved back. Code is
+//! never dropped.
+//!
+//! Per-entity model and handler files end with an empty `custom` region
+//! (see [`keep_region`]); regions can be added anywhere else by hand.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::GeneratedFile;
+
+/// Opening tag, followed by an optional name and `>`
+pub const KEEP_OPEN: &str = "<immortal:keep";
+
+/// Closing tag
+pub const KEEP_CLOSE: &str = "</immortal:keep>";
+
+/// Region at the end of per-entity model and handler files
+pub const CUSTOM_REGION: &str = "custom";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// An empty region called `name`, commented with `comment` (`//`, `#`, `--`).
+pub fn keep_region(name: &str, comment: &str) -> String {
+    format!(
+        "{comment} {KEEP_OPEN} {name}> code between these markers survives regeneration\n\
+         {comment} {KEEP_CLOSE}\n"
+    )
+}
+
+/// Regions carried over by [`preserve_regions`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegionReport {
+    /// Number of regions whose body was carried over
+    pub kept: usize,
+    /// Regions with no counterpart in the new output, appended to the end
+    /// of their file: the file and the region name
+    pub relocated: Vec<(PathBuf, String)>,
+}
+
+/// Carry the protected regions of the files in `dir` over into `files`.
+pub fn preserve_regions(files: &mut [GeneratedFile], dir: &Path) -> RegionReport {
+    let mut report = RegionReport::default();
+    if !dir.is_dir() {
+        return report;
+    }
+
+    for file in files {
+        let Ok(existing) = std::fs::read_to_string(dir.join(&file.path)) else {
+            continue;
+        };
+        if !existing.contains(KEEP_OPEN) {
+            continue;
+        }
+        let merged = merge_regions(&existing, &file.content);
+        file.content = merged.content;
+        report.kept += merged.kept;
+        report.relocated.extend(
+            merged
+                .relocated
+                .into_iter()
+                .map(|name| (file.path.clone(), name)),
+        );
+    }
+    report
+}
+
+/// Result of [`merge_regions`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedRegions {
+    /// The generated content with the existing region bodies
+    pub content: String,
+    /// Number of regions carried over
+    pub kept: usize,
+    /// Names of the regions appended to the end (`""` for unnamed ones)
+    pub relocated: Vec<String>,
+}
+
+/// Put the region bodies of `existing` into `generated`.
+pub fn merge_regions(existing: &str, generated: &str) -> MergedRegions {
+    let lines: Vec<&str> = existing.split_inclusive('\n').collect();
+    let mut regions: Vec<(RegionKey, Region)> = parse_regions(&lines);
+    let mut positions: HashMap<RegionKey, usize> = regions
+        .iter()
+        .enumerate()
+        .map(|(i, (key, _))| (key.clone(), i))
+        .collect();
+    let mut used = vec![false; regions.len()];
+
+    let new_lines: Vec<&str> = generated.split_inclusive('\n').collect();
+    let new_regions = parse_regions(&new_lines);
+
+    let mut content = String::with_capacity(generated.len());
+    let mut kept = 0;
+    let mut next_line = 0;
+    for (key, region) in &new_regions {
+        let Some(i) = positions.remove(key) else {
+            continue;
+        };
+        let (_, old) = &regions[i];
+        for line in &new_lines[next_line..=region.open] {
+            content.push_str(line);
+        }
+        for line in &lines[old.open + 1..old.close] {
+            content.push_str(line);
+        }
+        next_line = region.close;
+        used[i] = true;
+        kept += 1;
+    }
+    for line in &new_lines[next_line..] {
+        content.push_str(line);
+    }
+
+    let mut relocated = Vec::new();
+    for (i, (key, region)) in regions.drain(..).enumerate() {
+        if used[i] {
+            continue;
+        }
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
+        for line in &lines[region.open..=region.close] {
+            content.push_str(line);
+        }
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        relocated.push(key.0);
+    }
+
+    MergedRegions {
+        content,
+        kept,
+        relocated,
+    }
+}
+
+/// `content` without its protected regions, markers included — what is
+/// left is the part regeneration owns.
+pub fn strip_regions(content: &str) -> String {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let mut stripped = String::with_capacity(content.len());
+    let mut next_line = 0;
+    for (_, region) in parse_regions(&lines) {
+        for line in &lines[next_line..region.open] {
+            stripped.push_str(line);
+        }
+        next_line = region.close + 1;
+    }
+    for line in &lines[next_line..] {
+        stripped.push_str(line);
+    }
+    stripped
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// A region's name and how many regions of that name came before it
+type RegionKey = (String, usize);
+
+/// Line indices of a region's markers
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    open: usize,
+    close: usize,
+}
+
+/// Closed regions in order; an unclosed marker and anything nested inside a
+/// region are not regions.
+fn parse_regions(lines: &[&str]) -> Vec<(RegionKey, Region)> {
+    let mut regions = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut open: Option<(String, usize)> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        match &open {
+            None => {
+                if let Some(name) = open_tag(line) {
+                    open = Some((name, i));
+                }
+            }
+            Some(_) if line.contains(KEEP_CLOSE) => {
+                let (name, start) = open.take().expect("inside a region");
+                let count = seen.entry(name.clone()).or_default();
+                regions.push((
+                    (name, *count),
+                    Region {
+                        open: start,
+                        close: i,
+                    },
+                ));
+                *count += 1;
+            }
+            Some(_) => {}
+        }
+    }
+    regions
+}
+
+/// The region name if `line` opens one
+fn open_tag(line: &str) -> Option<String> {
+    let rest = &line[line.find(KEEP_OPEN)? + KEEP_OPEN.len()..];
+    if !rest.starts_with([' ', '>']) {
+        return None;
+    }
+    let end = rest.find('>')?;
+    Some(rest[..end].trim().to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENERATED: &str = "\
+fn generated() {}
+
+// <immortal:keep custom> code between these markers survives regeneration
+// </immortal:keep>
+";
+
+    #[test]
+    fn test_keep_region() {
+        assert_eq!(
+            keep_region("custom", "//"),
+            "// <immortal:keep custom> code between these markers survives regeneration\n\
+             // </immortal:keep>\n"
+        );
+        assert_eq!(
+            keep_region("indexes", "--"),
+            "-- <immortal:keep indexes> code between these markers survives regeneration\n\
+             -- </immortal:keep>\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_keeps_region_body() {
+        let existing = "\
+fn old_generated() {}
+
+// <immortal:keep custom> code between these markers survives regeneration
+fn mine() {}
+// </immortal:keep>
+";
+        let merged = merge_regions(existing, GENERATED);
+        assert_eq!(
+            merged.content,
+            "\
+fn generated() {}
+
+// <immortal:keep custom> code between these markers survives regeneration
+fn mine() {}
+// </immortal:keep>
+"
+        );
+        assert_eq!(merged.kept, 1);
+        assert!(merged.relocated.is_empty());
+    }
+
+    #[test]
+    fn test_merge_matches_by_name_and_order() {
+        let existing = "\
+# <immortal:keep b>
+b = 2
+# </immortal:keep>
+# <immortal:keep>
+first
+# </immortal:keep>
+# <immortal:keep a>
+a = 1
+# </immortal:keep>
+# <immortal:keep>
+second
+# </immortal:keep>
+";
+        let generated = "\
+[x]
+# <immortal:keep a>
+# </immortal:keep>
+# <immortal:keep>
+# </immortal:keep>
+[y]
+# <immortal:keep b>
+# </immortal:keep>
+# <immortal:keep>
+# </immortal:keep>
+";
+        let merged = merge_regions(existing, generated);
+        assert_eq!(
+            merged.content,
+            "\
+[x]
+# <immortal:keep a>
+a = 1
+# </immortal:keep>
+# <immortal:keep>
+first
+# </immortal:keep>
+[y]
+# <immortal:keep b>
+b = 2
+# </immortal:keep>
+# <immortal:keep>
+second
+# </immortal:keep>
+"
+        );
+        assert_eq!(merged.kept, 4);
+    }
+
+    #[test]
+    fn test_merge_relocates_regions_without_a_place() {
+        let existing = "\
+// <immortal:keep helpers>
+fn helper() {}
+// </immortal:keep>
+";
+        let merged = merge_regions(existing, "fn generated() {}");
+        assert_eq!(
+            merged.content,
+            "\
+fn generated() {}
+
+// <immortal:keep helpers>
+fn helper() {}
+// </immortal:keep>
+"
+        );
+        assert_eq!(merged.kept, 0);
+        assert_eq!(merged.relocated, ["helpers"]);
+    }
+
+    #[test]
+    fn test_merge_ignores_unclosed_and_lookalike_tags() {
+        let existing = "\
+// <immortal:keeper custom>
+lost
+// </immortal:keep>
+// <immortal:keep custom>
+never closed
+";
+        let merged = merge_regions(existing, GENERATED);
+        assert_eq!(merged.content, GENERATED);
+        assert_eq!(merged.kept, 0);
+        assert!(merged.relocated.is_empty());
+    }
+
+    #[test]
+    fn test_merge_handles_crlf() {
+        let existing = "// <immortal:keep custom>\r\nfn mine() {}\r\n// </immortal:keep>\r\n";
+        let merged = merge_regions(existing, GENERATED);
+        assert!(
+            merged
+                .content
+                .contains("regeneration\nfn mine() {}\r\n// </immortal:keep>\n")
+        );
+    }
+
+    #[test]
+    fn test_strip_regions() {
+        let content = "\
+a
+// <immortal:keep custom>
+mine
+// </immortal:keep>
+b
+// <immortal:keep open>
+c
+";
+        assert_eq!(strip_regions(content), "a\nb\n// <immortal:keep open>\nc\n");
+        assert_eq!(strip_regions(GENERATED), "fn generated() {}\n\n");
+    }
+
+    #[test]
+    fn test_preserve_regions_reads_the_output_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/user.rs"),
+            "// <immortal:keep custom>\nfn mine() {}\n// </immortal:keep>\n\
+             // <immortal:keep old>\nfn older() {}\n// </immortal:keep>\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("src/post.rs"), "fn edited() {}\n").unwrap();
+
+        let mut files = vec![
+            GeneratedFile::rust("src/user.rs", GENERATED),
+            GeneratedFile::rust("src/post.rs", GENERATED),
+            GeneratedFile::rust("src/tag.rs", GENERATED),
+        ];
+        let report = preserve_regions(&mut files, dir.path());
+
+        assert!(files[0].content.contains("fn mine() {}"));
+        assert!(
+            files[0]
+                .content
+                .ends_with("fn older() {}\n// </immortal:keep>\n")
+        );
+        assert_eq!(files[1].content, GENERATED);
+        assert_eq!(files[2].content, GENERATED);
+        assert_eq!(report.kept, 1);
+        assert_eq!(
+            report.relocated,
+            [(PathBuf::from("src/user.rs"), "old".to_string())]
+        );
+    }
+}
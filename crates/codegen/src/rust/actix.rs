@@ -0,0 +1,2200 @@
+//! # Actix Web Backend
+//!
+//! [`ActixBackend`] generates the HTTP layer for Actix Web 4 instead of Axum.
+//! Handlers, models and the data layer are shared with the Axum output; only
+//! the pieces that touch the framework differ:
+//!
+//! - handlers take `state: Data<AppState>` and `id: Path<…>` and answer
+//!   `HttpResponse::Created()` / `HttpResponse::NoContent()`
+//! - `src/routes/mod.rs` exposes `configure_app(state)` for
+//!   `App::configure`, `src/routes/api.rs` mounts one `web::scope` per entity
+//! - middleware are `middleware::from_fn` functions over `ServiceRequest`
+//! - `AppError` implements `ResponseError`
+//!
+//! ## Router Structure
+//!
+//! ```text
+//! App::new().configure(configure_app(state))
+//!   app_data(state), app_data(JsonConfig)
+//!   route("/healthz"), route("/readyz") // if health checks enabled
+//!   scope("")
+//!     .configure(api::configure)   // scope("/api/users"), scope("/api/posts"), …
+//!     .wrap(Logger)                // or the OpenTelemetry `http_span`
+//!     .wrap(Cors)                  // if CORS enabled
+//!     .wrap(PrometheusMetrics)     // if metrics enabled; answers `/metrics`
+//! ```
+//!
+//! Secured operations wrap their own route with `from_fn(require_auth)`, so
+//! public and secured operations can share a path; those naming roles add a
+//! `RequireRoles` inside it. Actix has no per-request
+//! timeout middleware, so unlike the Axum router there is no request timeout.
+
+use imortal_ir::{
+    AuthStrategy, CrudOperation, EndpointSecurity, OperationType, RealtimeTransport, UploadStorage,
+    WebFramework,
+};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::constants::{cors_route_origins, path_const};
+use crate::rust::framework::{Extractor, FrameworkBackend};
+use crate::rust::handlers::{
+    crud_handler_name, link_associations, links_module, live_handler_name, upload_handler_name,
+};
+use crate::rust::queries::query_handler_name;
+use crate::rust::rate_limit::{limiter_name, query_limiter_name};
+use crate::rust::routes::{cors_origin_allowed, has_enabled_handlers};
+use crate::rust::{auth, doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+/// Actix Web 4: `HttpServer`/`App` with scope-based route tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActixBackend;
+
+impl FrameworkBackend for ActixBackend {
+    fn framework(&self) -> WebFramework {
+        WebFramework::ActixWeb
+    }
+
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String {
+        let mut out = String::from("actix-web = \"4.9\"\n");
+        if ctx.graphql_enabled() {
+            out.push_str("async-graphql-actix-web = \"7\"\n");
+        }
+        if ctx.config.cors_enabled {
+            out.push_str("actix-cors = \"0.7\"\n");
+        }
+        if ctx.uses_realtime_transport(RealtimeTransport::WebSocket) {
+            out.push_str("actix-ws = \"0.3\"\n");
+        }
+        if ctx.metrics() {
+            out.push_str("actix-web-prom = \"0.9\"\n");
+        }
+        if ctx.uploads_enabled() {
+            out.push_str("actix-multipart = \"0.7\"\n");
+            if ctx.uploads().storage == UploadStorage::Local {
+                out.push_str("actix-files = \"0.6\"\n");
+            }
+            // `read_upload` walks the multipart stream; the realtime
+            // section adds the crate already
+            if !ctx.realtime_enabled() {
+                out.push_str("futures-util = \"0.3\"\n");
+            }
+        }
+        out
+    }
+
+    fn auth_dependency(&self, _strategy: AuthStrategy) -> Option<&'static str> {
+        // Bearer headers are parsed by hand and cookies are built in.
+        None
+    }
+
+    fn utoipa_feature(&self) -> &'static str {
+        "actix_extras"
+    }
+
+    fn swagger_ui_feature(&self) -> &'static str {
+        "actix-web"
+    }
+
+    fn handler_imports(&self, extractors: &[Extractor], responses: bool) -> String {
+        let mut names = vec!["Json"];
+        for extractor in extractors {
+            names.push(match extractor {
+                Extractor::State => "Data",
+                Extractor::Path => "Path",
+                Extractor::Query => "Query",
+            });
+        }
+        names.sort_unstable();
+
+        let mut out = format!("use actix_web::web::{{{}}};\n", names.join(", "));
+        if responses {
+            out.push_str("use actix_web::HttpResponse;\n");
+        }
+        out
+    }
+
+    fn state_param(&self) -> &'static str {
+        "state: Data<AppState>"
+    }
+
+    fn path_param(&self, ty: &str) -> String {
+        format!("id: Path<{}>", ty)
+    }
+
+    fn path_binding(&self) -> &'static str {
+        "    let id = id.into_inner();\n"
+    }
+
+    fn path_pair_param(&self, ty: &str, _second: &str, second_ty: &str) -> String {
+        format!("path: Path<({}, {})>", ty, second_ty)
+    }
+
+    fn path_pair_binding(&self, second: &str) -> String {
+        format!("    let (id, {}) = path.into_inner();\n", second)
+    }
+
+    fn created_type(&self, _body: &str) -> String {
+        "HttpResponse".to_string()
+    }
+
+    fn created(&self, value: &str) -> String {
+        format!("HttpResponse::Created().json({})", value)
+    }
+
+    fn no_content_type(&self) -> &'static str {
+        "HttpResponse"
+    }
+
+    fn no_content(&self) -> &'static str {
+        "HttpResponse::NoContent().finish()"
+    }
+
+    fn live_handler(&self, fn_name: &str, entity: &str, transport: RealtimeTransport) -> String {
+        match transport {
+            RealtimeTransport::WebSocket => format!(
+                "\
+pub async fn {fn_name}(
+    req: actix_web::HttpRequest,
+    body: actix_web::web::Payload,
+) -> Result<actix_web::HttpResponse, actix_web::Error> {{
+    realtime::websocket(&req, body, \"{entity}\")
+}}
+"
+            ),
+            RealtimeTransport::Sse => format!(
+                "pub async fn {fn_name}() -> actix_web::HttpResponse {{\n    realtime::sse(\"{entity}\")\n}}\n"
+            ),
+        }
+    }
+
+    fn upload_param(&self) -> &'static str {
+        "multipart: actix_multipart::Multipart"
+    }
+
+    fn headers_param(&self) -> &'static str {
+        "request: actix_web::HttpRequest"
+    }
+
+    fn headers_arg(&self) -> &'static str {
+        "&request"
+    }
+
+    fn cached_response_type(&self) -> &'static str {
+        "actix_web::HttpResponse"
+    }
+
+    fn generate_routes(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        let mut files = vec![generate_routes_mod(ctx)];
+        if ctx.rest_enabled() {
+            files.push(generate_api_routes(ctx));
+        }
+        files
+    }
+
+    fn generate_middleware(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        vec![GeneratedFile::new(
+            "src/middleware.rs",
+            build_middleware(ctx),
+            FileType::Rust,
+        )]
+    }
+
+    fn generate_auth_middleware(&self, ctx: &GenerationContext) -> GeneratedFile {
+        GeneratedFile::new(
+            "src/auth/middleware.rs",
+            build_auth_middleware(ctx),
+            FileType::Rust,
+        )
+    }
+
+    fn generate_graphql_http(&self, ctx: &GenerationContext) -> GeneratedFile {
+        GeneratedFile::new(
+            "src/graphql/http.rs",
+            build_graphql_http(ctx),
+            FileType::Rust,
+        )
+    }
+
+    fn realtime_streams(&self, ctx: &GenerationContext) -> String {
+        build_realtime_streams(ctx)
+    }
+
+    // Actix doesn't cap multipart payloads, so the limit is checked while
+    // the file streams in
+    fn upload_reader(&self) -> &'static str {
+        "\
+/// Read the first file part of a multipart body, giving up as soon as it
+/// outgrows `MAX_UPLOAD_BYTES`.
+pub async fn read_upload(mut multipart: actix_multipart::Multipart) -> Result<Upload, AppError> {
+    use futures_util::TryStreamExt;
+
+    while let Some(mut field) = multipart
+        .try_next()
+        .await
+        .map_err(|e| AppError::BadRequest(e.to_string()))?
+    {
+        let Some(file_name) = field
+            .content_disposition()
+            .and_then(|cd| cd.get_filename())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_string());
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field
+            .try_next()
+            .await
+            .map_err(|e| AppError::BadRequest(e.to_string()))?
+        {
+            if bytes.len() + chunk.len() > MAX_UPLOAD_BYTES {
+                return Err(too_large());
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        return Ok(Upload {
+            file_name: Some(file_name),
+            content_type,
+            bytes,
+        });
+    }
+    Err(AppError::BadRequest(
+        \"Expected a file part in the multipart body\".to_string(),
+    ))
+}
+"
+    }
+
+    fn cache_imports(&self) -> &'static str {
+        "use actix_web::{HttpRequest, HttpResponse};\n"
+    }
+
+    fn cache_responder(&self) -> &'static str {
+        "\
+impl Validators {
+    /// `body` as JSON with the validator headers, or `304 Not Modified`
+    /// without a body if the request's conditional headers still match.
+    pub fn respond<T: Serialize>(self, request: &HttpRequest, body: T) -> Result<HttpResponse, AppError> {
+        let header = |name: &str| request.headers().get(name).and_then(|v| v.to_str().ok());
+        let (validators, not_modified) = self.check(
+            &body,
+            header(\"if-none-match\"),
+            header(\"if-modified-since\"),
+        )?;
+
+        let mut response = if not_modified {
+            HttpResponse::NotModified()
+        } else {
+            HttpResponse::Ok()
+        };
+        for (name, value) in validators {
+            response.insert_header((name, value));
+        }
+        Ok(if not_modified {
+            response.finish()
+        } else {
+            response.json(body)
+        })
+    }
+}
+"
+    }
+
+    fn health_imports(&self) -> &'static str {
+        "use actix_web::{web, HttpResponse};\nuse serde_json::json;\n\n"
+    }
+
+    fn health_handlers(&self) -> &'static str {
+        "\
+/// Liveness probe: the process is up and answering requests.
+pub async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().json(json!({ \"status\": \"ok\" }))
+}
+
+/// Readiness probe: `200` while the database answers, `503` otherwise, so
+/// load balancers stop sending traffic to an instance that cannot serve it.
+pub async fn readyz(state: web::Data<AppState>) -> HttpResponse {
+    match check_database(&state).await {
+        Ok(()) => HttpResponse::Ok().json(json!({ \"status\": \"ready\" })),
+        Err(e) => {
+            tracing::warn!(error = %e, \"readiness check failed\");
+            HttpResponse::ServiceUnavailable().json(json!({ \"status\": \"unavailable\" }))
+        }
+    }
+}
+"
+    }
+
+    fn session_imports(&self) -> &'static str {
+        "use actix_web::{web, HttpResponse};\n"
+    }
+
+    fn session_handlers(&self) -> &'static str {
+        "\
+/// `POST /api/auth/refresh`: trade a refresh token for a new pair.
+pub async fn refresh_session(
+    state: web::Data<AppState>,
+    body: web::Json<RefreshRequest>,
+) -> Result<HttpResponse, AppError> {
+    Ok(HttpResponse::Ok().json(refresh(&state, &body.refresh_token).await?))
+}
+
+/// `POST /api/auth/logout`: end the caller's session.
+pub async fn logout(
+    state: web::Data<AppState>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    if let Some(session_id) = &claims.sid {
+        end(&state, session_id).await?;
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// `POST /api/auth/logout-all`: end every session of the caller.
+pub async fn logout_all(
+    state: web::Data<AppState>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    end_all(&state, &claims.sub).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+"
+    }
+
+    fn rate_limit_imports(&self, auth: bool) -> String {
+        format!(
+            "\
+use actix_web::body::MessageBody;
+use actix_web::dev::{{ServiceRequest, ServiceResponse}};
+use actix_web::error::InternalError;
+use actix_web::http::header::{{HeaderValue, RETRY_AFTER}};
+use actix_web::http::StatusCode;
+use actix_web::middleware::Next;
+use actix_web::{{Error, {}ResponseError}};
+",
+            if auth { "HttpMessage, " } else { "" }
+        )
+    }
+
+    fn rate_limit_middleware(&self, auth: bool) -> String {
+        let user = if auth {
+            "        KeyBy::User => match request.extensions().get::<Claims>() {
+            Some(claims) => format!(\"user:{}\", claims.sub),
+            None => ip(),
+        },
+"
+        } else {
+            ""
+        };
+        format!(
+            "\
+/// Middleware counting each request against `limiter`; over the quota it
+/// answers with a `Retry-After` header instead of running the handler.
+/// Wrap a route in it inside `require_auth` (the last `wrap` runs first),
+/// so per-user quotas see the caller:
+///
+/// ```rust,ignore
+/// web::post()
+///     .to(handler)
+///     .wrap(from_fn(|request, next| rate_limit::enforce(&rate_limit::USER_CREATE, request, next)))
+///     .wrap(from_fn(require_auth))
+/// ```
+pub async fn enforce(
+    limiter: &'static Limiter,
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {{
+    let key = client_key(limiter.quota.key_by, &request);
+    if let Err(retry_after) = limiter.check(key) {{
+        let error = limiter.exceeded();
+        let mut response = error.error_response();
+        *response.status_mut() = limiter.status();
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        return Err(InternalError::from_response(error, response).into());
+    }}
+    next.call(request).await
+}}
+
+/// The key a request is counted under. The client IP is the peer address;
+/// behind a reverse proxy, read the proxy's forwarded header here instead.
+fn client_key(key_by: KeyBy, request: &ServiceRequest) -> String {{
+    let ip = || {{
+        request
+            .peer_addr()
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_default()
+    }};
+    match key_by {{
+        KeyBy::Ip => ip(),
+{user}        KeyBy::Global => String::new(),
+    }}
+}}
+"
+        )
+    }
+
+    fn oauth_imports(&self) -> &'static str {
+        "use actix_web::http::header::LOCATION;\nuse actix_web::{web, HttpResponse};\n"
+    }
+
+    fn oauth_handlers(&self) -> &'static str {
+        "\
+/// `GET /api/auth/oauth/{provider}/authorize`: send the browser to the
+/// provider's sign-in page.
+pub async fn oauth_authorize(
+    state: web::Data<AppState>,
+    provider: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let url = authorize_url(&state, &provider).await?;
+    Ok(HttpResponse::Found().insert_header((LOCATION, url)).finish())
+}
+
+/// `GET /api/auth/oauth/{provider}/callback`: where the provider sends the
+/// browser back; answers the signed-in tokens.
+pub async fn oauth_callback(
+    state: web::Data<AppState>,
+    provider: web::Path<String>,
+    callback: web::Query<Callback>,
+) -> Result<HttpResponse, AppError> {
+    Ok(HttpResponse::Ok().json(complete(&state, &provider, callback.into_inner()).await?))
+}
+"
+    }
+
+    fn error_imports(&self) -> &'static str {
+        "use actix_web::{http::StatusCode, HttpResponse, ResponseError};\n"
+    }
+
+    fn error_response_open(&self) -> &'static str {
+        "\
+// ============================================================================
+// ResponseError — convert AppError into an HTTP response
+// ============================================================================
+
+impl ResponseError for AppError {
+    fn error_response(&self) -> HttpResponse {
+"
+    }
+
+    fn error_response_close(&self) -> &'static str {
+        "        HttpResponse::build(status)\n            .content_type(PROBLEM_JSON)\n            .json(body)\n"
+    }
+
+    fn app_builder(&self) -> &'static str {
+        "configure_app"
+    }
+
+    fn server_imports(&self) -> &'static str {
+        "use actix_web::{web, App, HttpServer};\n"
+    }
+
+    fn serve(&self) -> &'static str {
+        "\
+    // ── 6. Build application ─────────────────────────────────────────
+    let state = web::Data::new(state);
+
+    // ── 7. Start server ──────────────────────────────────────────────
+    let bind_addr = config.bind_address();
+    tracing::info!(address = %bind_addr, \"starting HTTP server\");
+
+    let server = HttpServer::new(move || App::new().configure(configure_app(state.clone())))
+        .bind(&bind_addr)
+        .with_context(|| format!(\"failed to bind to {}\", bind_addr))?;
+
+    tracing::info!(
+        address = %bind_addr,
+        \"server is ready — listening for connections\",
+    );
+
+    server.run().await.context(\"server error\")?;
+"
+    }
+
+    fn test_serve(&self) -> &'static str {
+        "\
+        let state = web::Data::new(AppState::new(db, config));
+        let server = HttpServer::new(move || App::new().configure(configure_app(state.clone())))
+            .workers(1)
+            .listen(listener)
+            .expect(\"failed to listen on random port\")
+            .run();
+
+        // Start server in background
+        tokio::spawn(server);
+"
+    }
+}
+
+// ============================================================================
+// routes/mod.rs
+// ============================================================================
+
+fn generate_routes_mod(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = String::with_capacity(2048);
+
+    content.push_str(&file_header(
+        "Route definitions — top-level application assembly.",
+    ));
+
+    if ctx.rest_enabled() {
+        content.push_str("pub mod api;\n\n");
+    }
+
+    if ctx.config.cors_enabled {
+        content.push_str("use actix_cors::Cors;\n");
+    }
+    content.push_str("use actix_web::error::JsonPayloadError;\n");
+    if ctx.telemetry() {
+        content.push_str("use actix_web::middleware::from_fn;\n");
+    } else {
+        content.push_str("use actix_web::middleware::{from_fn, Logger};\n");
+    }
+    content.push_str("use actix_web::{web, HttpRequest};\n");
+    if ctx.metrics() {
+        content.push_str("use actix_web_prom::{PrometheusMetrics, PrometheusMetricsBuilder};\n");
+        content.push_str("use std::sync::OnceLock;\n");
+    }
+    content.push('\n');
+
+    let oauth = !ctx.oauth_providers().is_empty();
+    if ctx.refresh_tokens() {
+        content.push_str("use crate::auth::middleware::require_auth;\n");
+    }
+    if oauth {
+        content.push_str("use crate::auth::oauth;\n");
+    }
+    if ctx.refresh_tokens() {
+        content.push_str("use crate::auth::sessions;\n");
+    }
+    if ctx.config.cors_enabled || ctx.auth_routes() {
+        content.push_str("use crate::constants;\n");
+    }
+    content.push_str("use crate::error::AppError;\n");
+    if ctx.health_checks() {
+        content.push_str("use crate::health;\n");
+    }
+    if ctx.telemetry() {
+        content.push_str("use crate::middleware::{http_span, locale};\n");
+    } else {
+        content.push_str("use crate::middleware::locale;\n");
+    }
+    content.push_str("use crate::state::AppState;\n\n");
+
+    content.push_str(&doc_comment(
+        Some(
+            "Register the application state, middleware and all routes.\n\n\
+             Called once per worker: `App::new().configure(configure_app(state.clone()))`.",
+        ),
+        ctx,
+    ));
+    content.push_str(
+        "pub fn configure_app(state: web::Data<AppState>) -> impl FnOnce(&mut web::ServiceConfig) {\n",
+    );
+    content.push_str("    move |cfg| {\n");
+
+    let route_cors = !cors_route_origins(ctx).is_empty();
+    if ctx.config.cors_enabled {
+        content.push_str(
+            "        let cors = Cors::default().allow_any_header();\n\
+             \x20       // An empty `CORS_ALLOWED_METHODS` allows any method\n\
+             \x20       let cors = if constants::CORS_ALLOWED_METHODS.is_empty() {\n\
+             \x20           cors.allow_any_method()\n\
+             \x20       } else {\n\
+             \x20           cors.allowed_methods(constants::CORS_ALLOWED_METHODS.iter().copied())\n\
+             \x20       };\n\
+             \x20       let cors = if constants::CORS_ALLOW_CREDENTIALS {\n\
+             \x20           cors.supports_credentials()\n\
+             \x20       } else {\n\
+             \x20           cors\n\
+             \x20       };\n",
+        );
+        if route_cors {
+            content.push_str(
+                "        // Routes under a `CORS_ROUTE_ORIGINS` path allow their own origins\n\
+                 \x20       let cors = cors.allowed_origin_fn(|origin, request| {\n\
+                 \x20           cors_origin_allowed(origin.as_bytes(), request.uri.path())\n\
+                 \x20       });\n\n",
+            );
+        } else {
+            content.push_str(
+                "        // An empty `CORS_ALLOWED_ORIGINS` allows any origin\n\
+                 \x20       let cors = if constants::CORS_ALLOWED_ORIGINS.is_empty() {\n\
+                 \x20           cors.allow_any_origin()\n\
+                 \x20       } else {\n\
+                 \x20           constants::CORS_ALLOWED_ORIGINS\n\
+                 \x20               .iter()\n\
+                 \x20               .fold(cors, |cors, origin| cors.allowed_origin(origin))\n\
+                 \x20       };\n\n",
+            );
+        }
+    }
+
+    // Built per worker; the change feed behind subscriptions is process-wide.
+    if ctx.graphql_enabled() {
+        content.push_str(
+            "        let schema = crate::graphql::build_schema(state.get_ref().clone());\n\n",
+        );
+    }
+
+    let serves_uploads = ctx.uploads_enabled() && ctx.uploads().storage == UploadStorage::Local;
+    if serves_uploads {
+        content.push_str(
+            "        let uploads = actix_files::Files::new(\"/uploads\", &state.config.upload_dir);\n\n",
+        );
+    }
+
+    content.push_str("        cfg.app_data(state)\n");
+    content
+        .push_str("            .app_data(web::JsonConfig::default().error_handler(json_error))\n");
+    // Ahead of the catch-all scope, which would answer 404 for them
+    if ctx.health_checks() {
+        content.push_str("            .route(\"/healthz\", web::get().to(health::healthz))\n");
+        content.push_str("            .route(\"/readyz\", web::get().to(health::readyz))\n");
+    }
+    if serves_uploads {
+        content.push_str("            .service(uploads)\n");
+    }
+    content.push_str("            .service(\n");
+    content.push_str("                web::scope(\"\")\n");
+    // Logging out needs the access token; refreshing and signing in are open
+    if ctx.auth_routes() {
+        let mut routes = Vec::new();
+        if ctx.refresh_tokens() {
+            routes.extend([
+                ".route(\"/refresh\", web::post().to(sessions::refresh_session))",
+                ".route(\"/logout\", web::post().to(sessions::logout).wrap(from_fn(require_auth)))",
+                ".route(\"/logout-all\", web::post().to(sessions::logout_all).wrap(from_fn(require_auth)))",
+            ]);
+        }
+        if oauth {
+            routes.extend([
+                ".route(\"/oauth/{provider}/authorize\", web::get().to(oauth::oauth_authorize))",
+                ".route(\"/oauth/{provider}/callback\", web::get().to(oauth::oauth_callback))",
+            ]);
+        }
+        content.push_str(
+            "                    .service(\n                        web::scope(constants::AUTH_PATH)",
+        );
+        for route in routes {
+            content.push_str("\n                            ");
+            content.push_str(route);
+        }
+        content.push_str(",\n                    )\n");
+    }
+    if ctx.rest_enabled() {
+        content.push_str("                    .configure(api::configure)\n");
+    }
+    if ctx.graphql_enabled() {
+        content
+            .push_str("                    .configure(crate::graphql::http::configure(schema))\n");
+    }
+
+    // `wrap` runs last-registered first, so the locale scope sits inside
+    // the logger and covers the handler's error response.
+    content.push_str("                    .wrap(from_fn(locale))\n");
+
+    // With telemetry the OpenTelemetry HTTP span replaces the access log.
+    if ctx.telemetry() {
+        content.push_str("                    .wrap(from_fn(http_span))");
+    } else {
+        content.push_str("                    .wrap(Logger::default())");
+    }
+    if ctx.config.cors_enabled {
+        content.push_str("\n                    .wrap(cors)");
+    }
+    if ctx.metrics() {
+        content.push_str("\n                    .wrap(metrics())");
+    }
+    content.push_str(",\n            );\n");
+    content.push_str("    }\n");
+    content.push_str("}\n\n");
+
+    if route_cors {
+        content.push_str(&cors_origin_allowed(ctx));
+        content.push('\n');
+    }
+
+    if ctx.metrics() {
+        content.push_str(&doc_comment(
+            Some(
+                "Prometheus request metrics; the middleware itself answers `GET /metrics`.\n\n\
+                 Built once and shared by every worker, so all of them count into one\n\
+                 registry.",
+            ),
+            ctx,
+        ));
+        content.push_str(
+            "\
+fn metrics() -> PrometheusMetrics {
+    static METRICS: OnceLock<PrometheusMetrics> = OnceLock::new();
+    METRICS
+        .get_or_init(|| {
+            PrometheusMetricsBuilder::new(\"api\")
+                .endpoint(\"/metrics\")
+                .build()
+                .expect(\"failed to register Prometheus metrics\")
+        })
+        .clone()
+}
+
+",
+        );
+    }
+
+    content.push_str(&doc_comment(
+        Some(
+            "Answer rejected JSON bodies like the handlers' own errors: malformed\n\
+             JSON is a 400, a body that does not fit the DTO a 422.",
+        ),
+        ctx,
+    ));
+    content.push_str(
+        "\
+fn json_error(err: JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    match err {
+        JsonPayloadError::Deserialize(e) if e.is_data() => AppError::Validation(e.to_string()).into(),
+        other => AppError::BadRequest(other.to_string()).into(),
+    }
+}
+",
+    );
+
+    GeneratedFile::new("src/routes/mod.rs", content, FileType::Rust)
+}
+
+// ============================================================================
+// routes/api.rs
+// ============================================================================
+
+fn generate_api_routes(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = String::with_capacity(4096);
+
+    content.push_str(&file_header(
+        "API route definitions — entity endpoint groups.",
+    ));
+
+    let version_header = ctx.api_versioning().header_name();
+    if version_header.is_some() {
+        content.push_str("use actix_web::guard::{self, Guard};\n");
+    }
+    content.push_str("use actix_web::{web, Scope};\n");
+    if ctx.auth_enabled() || ctx.rate_limiting_enabled() {
+        content.push_str("use actix_web::middleware::from_fn;\n");
+    }
+    content.push('\n');
+
+    let routes_entities = ctx
+        .entities()
+        .iter()
+        .any(|entity| has_enabled_handlers(&EntityInfo::new(entity, ctx)));
+    if routes_entities {
+        content.push_str("use crate::constants;\n");
+    }
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        if has_enabled_handlers(&info) {
+            content.push_str(&format!("use crate::handlers::{};\n", info.module_name()));
+        }
+        if let Some(links) = links_module(&info) {
+            content.push_str(&format!("use crate::handlers::{};\n", links));
+        }
+    }
+    if !ctx.queries().is_empty() {
+        content.push_str("use crate::handlers::queries;\n");
+    }
+    if ctx.auth_enabled() {
+        content.push_str("use crate::auth::middleware::require_auth;\n");
+    }
+    if ctx.rate_limiting_enabled() {
+        content.push_str("use crate::rate_limit;\n");
+    }
+    if auth::routes_check_roles(ctx) {
+        content.push_str("use crate::auth::middleware::RequireRoles;\n");
+        if ctx.role_enum() {
+            content.push_str("use crate::auth::roles::Role;\n");
+        }
+    }
+    content.push('\n');
+
+    content.push_str(&doc_comment(
+        Some("Assemble all API routes.\n\nEach entity's routes are mounted under its configured base path."),
+        ctx,
+    ));
+    content.push_str("pub fn configure(cfg: &mut web::ServiceConfig) {\n");
+
+    let scopes: Vec<String> = ctx
+        .entities()
+        .iter()
+        .map(|entity| EntityInfo::new(entity, ctx))
+        .filter(has_enabled_handlers)
+        .map(|info| format!("{}_routes()", info.snake_name()))
+        .collect();
+    if !scopes.is_empty() {
+        content.push_str("    cfg");
+        for scope in &scopes {
+            content.push_str(&format!(".service({})", scope));
+        }
+        content.push_str(";\n");
+    }
+    if !ctx.queries().is_empty() {
+        content.push_str("    query_routes(cfg);\n");
+    }
+    content.push_str("}\n\n");
+
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        if !has_enabled_handlers(&info) {
+            continue;
+        }
+        content.push_str(&generate_entity_routes(&info, ctx));
+        content.push('\n');
+    }
+
+    if !ctx.queries().is_empty() {
+        content.push_str(&generate_query_routes(ctx));
+        content.push('\n');
+    }
+
+    if let (Some(header), Some(default_version)) =
+        (version_header, ctx.api_versioning().default_version())
+    {
+        content.push_str(&version_guard(header, default_version, ctx));
+    }
+
+    GeneratedFile::new("src/routes/api.rs", content, FileType::Rust)
+}
+
+/// `fn {entity}_routes() -> Scope`: one route per enabled operation, with
+/// secured operations wrapped in `require_auth` individually.
+fn generate_entity_routes(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let Some(endpoint) = info.endpoint().filter(|ep| ep.enabled) else {
+        return String::new();
+    };
+    let module = info.module_name();
+
+    let mut out = doc_comment(
+        Some(&format!("Routes for {} endpoints.", info.pascal_name())),
+        ctx,
+    );
+    out.push_str(&format!("fn {}_routes() -> Scope {{\n", info.snake_name()));
+    out.push_str(&format!(
+        "    web::scope(constants::{})\n",
+        path_const(info)
+    ));
+    // Header versioning mounts every version at the public path and lets a
+    // guard pick the scope; otherwise the version is in the path
+    if let Some(version) = info.api_version()
+        && ctx.api_versioning().header_name().is_some()
+    {
+        out.push_str(&format!("        .guard(version_guard(\"{}\"))\n", version));
+    }
+
+    // Ahead of `/{id}`, which would otherwise match it
+    if let Some(transport) = info.live_transport() {
+        out.push_str(&format!(
+            "        .route(\"/{}\", web::get().to({}::{}){})\n",
+            transport.path(),
+            module,
+            live_handler_name(info),
+            if info.live_auth_required() {
+                ".wrap(from_fn(require_auth))"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    for op in endpoint.enabled_operations() {
+        let security = op.security.as_ref().unwrap_or(&endpoint.global_security);
+        let limiter = ctx
+            .rate_limit(op.rate_limit.as_ref())
+            .map(|_| limiter_name(info, op));
+        let wrap = format!("{}{}", rate_limit_wrap(limiter), auth_wrap(ctx, security));
+        out.push_str(&route_line(op, &module, &wrap));
+        if op.operation_type == OperationType::Delete && info.has_soft_delete() {
+            out.push_str(&format!(
+                "        .route(\"/{{id}}/restore\", web::post().to({}::restore_{}){})\n",
+                module,
+                info.snake_name(),
+                wrap
+            ));
+        }
+        if op.operation_type == OperationType::Update {
+            for field in info.upload_fields() {
+                out.push_str(&format!(
+                    "        .route(\"/{{id}}/{}\", web::post().to({}::{}){})\n",
+                    GenerationContext::snake(&field.name),
+                    module,
+                    upload_handler_name(info, field),
+                    wrap
+                ));
+            }
+        }
+        if let Some(links) =
+            links_module(info).filter(|_| op.operation_type == OperationType::Update)
+        {
+            for association in link_associations(info) {
+                let suffix = association.handler_suffix();
+                let collection = format!("/{{id}}/{}", association.accessor);
+                let item = format!("{}/{{{}}}", collection, association.other_param());
+                for (path, method, handler) in [
+                    (&collection, "get", "list"),
+                    (&collection, "post", "link"),
+                    (&item, "delete", "unlink"),
+                ] {
+                    out.push_str(&format!(
+                        "        .route(\"{path}\", web::{method}().to({links}::{handler}_{suffix}){wrap})\n"
+                    ));
+                }
+            }
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// `fn version_guard(version)`: matches requests whose version header names
+/// `version`, with requests lacking the header taken as the default version.
+fn version_guard(header: &str, default_version: &str, ctx: &GenerationContext) -> String {
+    let mut out = doc_comment(
+        Some(&format!(
+            "Match requests whose `{}` header names `version`; requests\n\
+             without the header are served the default version, `{}`.",
+            header, default_version
+        )),
+        ctx,
+    );
+    out.push_str(&format!(
+        "\
+fn version_guard(version: &'static str) -> impl Guard {{
+    guard::fn_guard(move |ctx| {{
+        ctx.head()
+            .headers()
+            .get(\"{}\")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or(\"{}\")
+            == version
+    }})
+}}
+",
+        header.to_ascii_lowercase(),
+        default_version
+    ));
+    out
+}
+
+/// The middleware a route with `security` is wrapped in: `require_auth`
+/// when it is secured, with `RequireRoles` inside it when it names roles.
+fn auth_wrap(ctx: &GenerationContext, security: &EndpointSecurity) -> String {
+    if !ctx.auth_enabled() || !security.auth_required {
+        return String::new();
+    }
+    match auth::required_roles(ctx, security) {
+        // The last `wrap` runs first
+        Some(roles) => format!(".wrap({roles}).wrap(from_fn(require_auth))"),
+        None => ".wrap(from_fn(require_auth))".to_string(),
+    }
+}
+
+/// The middleware a route counted against the `limiter` static is wrapped
+/// in. It goes before [`auth_wrap`] so it runs inside `require_auth` and
+/// per-user quotas see the caller.
+fn rate_limit_wrap(limiter: Option<String>) -> String {
+    limiter
+        .map(|name| {
+            format!(
+                ".wrap(from_fn(|request, next| rate_limit::enforce(&rate_limit::{name}, request, next)))"
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// A `.route(…)` line for one CRUD operation, wrapped in `wrap`.
+fn route_line(op: &CrudOperation, handler_module: &str, wrap: &str) -> String {
+    let (path, method) = match op.operation_type {
+        OperationType::Create => ("", "post"),
+        OperationType::ReadAll => ("", "get"),
+        OperationType::Read => ("/{id}", "get"),
+        OperationType::Update => ("/{id}", "put"),
+        OperationType::Delete => ("/{id}", "delete"),
+    };
+
+    format!(
+        "        .route(\"{}\", web::{}().to({}::{}){})\n",
+        path,
+        method,
+        handler_module,
+        crud_handler_name(op, handler_module),
+        wrap
+    )
+}
+
+/// `fn query_routes(cfg)`: custom query endpoints, mounted at their full path.
+fn generate_query_routes(ctx: &GenerationContext) -> String {
+    let mut out = doc_comment(Some("Routes for custom query endpoints."), ctx);
+    out.push_str("fn query_routes(cfg: &mut web::ServiceConfig) {\n");
+
+    for query in ctx.queries() {
+        let limiter = ctx.rate_limit(None).map(|_| query_limiter_name(query));
+        let wrap = format!(
+            "{}{}",
+            rate_limit_wrap(limiter),
+            auth_wrap(ctx, &query.security)
+        );
+        out.push_str(&format!(
+            "    cfg.route(\"{}\", web::get().to(queries::{}){});\n",
+            query.path,
+            query_handler_name(query),
+            wrap
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// middleware.rs
+// ============================================================================
+
+fn build_middleware(ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(4096);
+
+    out.push_str(&file_header("Custom middleware for request processing."));
+
+    out.push_str(
+        "\
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{self, HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::Error;
+",
+    );
+    if ctx.telemetry() {
+        out.push_str(
+            "\
+use opentelemetry::propagation::Extractor;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+",
+        );
+    }
+    out.push_str(
+        "\
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::i18n;
+
+",
+    );
+
+    if ctx.generate_docs() {
+        out.push_str(
+            "\
+/// Middleware that logs every incoming request and its response status.
+///
+/// Emits a structured `tracing` event at the `info` level with the method,
+/// path, status code and latency in milliseconds. Apply it with
+/// `.wrap(from_fn(request_logger))`.
+",
+        );
+    }
+    out.push_str(
+        "\
+pub async fn request_logger(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let method = request.method().clone();
+    let path = request.path().to_string();
+    let start = Instant::now();
+
+    tracing::debug!(
+        method = %method,
+        path = %path,
+        \"request started\",
+    );
+
+    let response = next.call(request).await?;
+
+    let latency = start.elapsed();
+    let status = response.status().as_u16();
+
+    tracing::info!(
+        method = %method,
+        path = %path,
+        status = status,
+        latency_ms = latency.as_millis() as u64,
+        \"request completed\",
+    );
+
+    Ok(response)
+}
+
+",
+    );
+
+    if ctx.generate_docs() {
+        out.push_str(
+            "\
+/// Middleware that injects a unique `X-Request-Id` header into every response.
+///
+/// If the incoming request already carries an `X-Request-Id` header, it is
+/// preserved. Otherwise a new UUID v4 is generated.
+",
+        );
+    }
+    out.push_str(
+        "\
+pub async fn request_id(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    // Reuse existing request ID or generate a new one
+    let request_id = request
+        .headers()
+        .get(\"x-request-id\")
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let mut response = next.call(request).await?;
+
+    // Attach the request ID to the response
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response
+            .headers_mut()
+            .insert(HeaderName::from_static(\"x-request-id\"), value);
+    }
+
+    Ok(response)
+}
+
+",
+    );
+
+    if ctx.generate_docs() {
+        out.push_str(crate::rust::middleware::LOCALE_DOC);
+    }
+    out.push_str(
+        "\
+pub async fn locale(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let locale = i18n::negotiate(
+        request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok()),
+    );
+
+    let mut response = i18n::scope(locale, next.call(request)).await?;
+
+    if response.status().is_client_error() || response.status().is_server_error() {
+        if let Ok(value) = HeaderValue::from_str(locale) {
+            response.headers_mut().insert(header::CONTENT_LANGUAGE, value);
+        }
+    }
+
+    Ok(response)
+}
+
+",
+    );
+
+    if ctx.telemetry() {
+        if ctx.generate_docs() {
+            out.push_str(
+                "\
+/// Middleware that wraps each request in an OpenTelemetry HTTP server span.
+///
+/// The span is named `{method} {route}` and carries the semantic-convention
+/// fields `http.request.method`, `http.route`, `url.path` and
+/// `http.response.status_code`; 5xx responses mark it as an error. A W3C
+/// `traceparent` header makes it part of the caller's trace.
+///
+/// `require_auth` records `enduser.id` and `enduser.role` on this span for
+/// authenticated routes.
+",
+            );
+        }
+        out.push_str(
+            "\
+pub async fn http_span(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let method = request.method().clone();
+    let route = request
+        .match_pattern()
+        .unwrap_or_else(|| request.path().to_string());
+
+    let span = tracing::info_span!(
+        \"http_request\",
+        otel.name = %format!(\"{} {}\", method, route),
+        otel.kind = \"server\",
+        otel.status_code = tracing::field::Empty,
+        http.request.method = %method,
+        http.route = %route,
+        url.path = %request.path(),
+        http.response.status_code = tracing::field::Empty,
+        enduser.id = tracing::field::Empty,
+        enduser.role = tracing::field::Empty,
+    );
+
+    // Join the caller's trace when the request carries one
+    let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent);
+
+    let response = next.call(request).instrument(span.clone()).await?;
+
+    let status = response.status();
+    span.record(\"http.response.status_code\", status.as_u16());
+    if status.is_server_error() {
+        span.record(\"otel.status_code\", \"ERROR\");
+    }
+
+    Ok(response)
+}
+
+/// Reads propagation headers from an Actix header map.
+struct HeaderExtractor<'a>(&'a actix_web::http::header::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+",
+        );
+    }
+
+    if ctx.generate_docs() {
+        out.push_str(
+            "\
+/// Maximum allowed request body size in bytes.
+///
+/// Apply it to JSON bodies with
+/// `web::JsonConfig::default().limit(MAX_BODY_SIZE)`.
+",
+        );
+    }
+    out.push_str(
+        "\
+pub const MAX_BODY_SIZE: usize = 10 * 1024 * 1024; // 10 MiB
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_body_size() {
+        assert_eq!(MAX_BODY_SIZE, 10 * 1024 * 1024);
+    }
+}
+",
+    );
+
+    out
+}
+
+// ============================================================================
+// auth/middleware.rs
+// ============================================================================
+
+fn build_auth_middleware(ctx: &GenerationContext) -> String {
+    let mut content = String::with_capacity(4096);
+
+    content.push_str(&file_header(
+        "Authentication middleware for Actix Web routes.",
+    ));
+
+    content.push_str(
+        "\
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpMessage, HttpRequest};
+
+use crate::auth::jwt::{verify_token, Claims};
+use crate::auth::roles::Role;
+",
+    );
+    if ctx.refresh_tokens() {
+        content.push_str("use crate::auth::sessions;\n");
+    }
+    content.push_str(
+        "\
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Middleware that requires a valid JWT in the `Authorization: Bearer <token>`
+/// header. Apply it to a route with `.wrap(from_fn(require_auth))`.
+///
+/// On success the decoded [`Claims`] are inserted into the request's
+/// extensions, where handlers read them through [`AuthUser`] or
+/// [`extract_claims`].
+///
+/// On failure — no token, one that does not verify, or one whose session
+/// has ended — the request is answered with `401 Unauthorized`.
+pub async fn require_auth(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let Some(token) = bearer_token(&request) else {
+        return Err(unauthorized(\"Missing authentication token\"));
+    };
+
+    let state = request
+        .app_data::<web::Data<AppState>>()
+        .expect(\"AppState not registered — was the app built with configure_app?\");
+
+    let claims = verify_token(&token, &state.config.jwt_secret).map_err(|e| {
+        tracing::warn!(\"JWT verification failed: {}\", e);
+        unauthorized(\"Invalid or expired authentication token\")
+    })?;
+
+",
+    );
+
+    if ctx.refresh_tokens() {
+        content.push_str(
+            "\
+    // Tokens of a session that has ended are no longer accepted
+    let ended = match &claims.sid {
+        Some(session_id) => !sessions::is_active(state, session_id).await?,
+        None => false,
+    };
+    if ended {
+        return Err(unauthorized(\"Session has ended\"));
+    }
+
+",
+        );
+    }
+
+    if ctx.telemetry() {
+        content.push_str(
+            "\
+    let span = tracing::Span::current();
+    span.record(\"enduser.id\", claims.sub.as_str());
+    span.record(\"enduser.role\", claims.roles.join(\",\").as_str());
+
+",
+        );
+    }
+
+    content.push_str(
+        "\
+    // Make claims available to handlers
+    request.extensions_mut().insert(claims);
+
+    next.call(request).await
+}
+
+/// The token from an `Authorization: Bearer <token>` header.
+fn bearer_token(request: &ServiceRequest) -> Option<String> {
+    let header = request.headers().get(AUTHORIZATION)?.to_str().ok()?;
+    header.strip_prefix(\"Bearer \").map(str::to_string)
+}
+
+/// The `401 Unauthorized` error returned by [`require_auth`].
+fn unauthorized(message: &str) -> Error {
+    AppError::unauthorized(message).into()
+}
+
+/// Extract the authenticated user's [`Claims`] from a request's extensions.
+///
+/// # Panics
+///
+/// Panics if called on a request that has not passed through `require_auth`.
+pub fn extract_claims(request: &HttpRequest) -> Claims {
+    request
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .expect(\"Claims not found — did the request pass through require_auth?\")
+}
+
+/// Check whether the authenticated user has *any* of the required roles.
+///
+/// Returns `Ok(())` if the user has at least one matching role, or a
+/// `403 Forbidden` error otherwise.
+///
+/// ```rust,ignore
+/// pub async fn admin_only_handler(user: AuthUser) -> Result<HttpResponse, AppError> {
+///     check_roles(&user, &[\"admin\"])?;
+///     // … handler logic …
+/// }
+/// ```
+pub fn check_roles(claims: &Claims, required_roles: &[&str]) -> Result<(), AppError> {
+    if required_roles.is_empty() {
+        return Ok(());
+    }
+
+    if claims.has_any_role(required_roles) {
+        Ok(())
+    } else {
+        tracing::warn!(
+            \"Access denied for user '{}': required roles {:?}, has {:?}\",
+            claims.sub,
+            required_roles,
+            claims.roles,
+        );
+        Err(AppError::forbidden(\"Insufficient permissions\"))
+    }
+}
+
+/// The roles a route accepts: callers holding none of them are answered
+/// with `403 Forbidden`.
+///
+/// It reads the [`Claims`] stored by [`require_auth`], so wrap it inside
+/// that middleware (the last `wrap` runs first):
+///
+/// ```rust,ignore
+/// web::post()
+///     .to(handler)
+///     .wrap(RequireRoles(&[Role::Admin]))
+///     .wrap(from_fn(require_auth))
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RequireRoles(pub &'static [Role]);
+
+impl<S, B> Transform<S, ServiceRequest> for RequireRoles
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequireRolesMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireRolesMiddleware {
+            service,
+            roles: self.0,
+        }))
+    }
+}
+
+/// The service [`RequireRoles`] wraps a route in.
+pub struct RequireRolesMiddleware<S> {
+    service: S,
+    roles: &'static [Role],
+}
+
+impl<S, B> Service<ServiceRequest> for RequireRolesMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, request: ServiceRequest) -> Self::Future {
+        let allowed = match request.extensions().get::<Claims>() {
+            Some(claims) => {
+                let required: Vec<&str> = self.roles.iter().map(|role| role.as_ref()).collect();
+                check_roles(claims, &required).map_err(Error::from)
+            }
+            None => Err(unauthorized(\"Missing authentication token\")),
+        };
+        match allowed {
+            Ok(()) => Box::pin(self.service.call(request)),
+            Err(e) => Box::pin(ready(Err(e))),
+        }
+    }
+}
+
+/// Extractor for the [`Claims`] `require_auth` stored on the request.
+///
+/// ```rust,ignore
+/// pub async fn my_handler(user: AuthUser) -> HttpResponse {
+///     HttpResponse::Ok().body(format!(\"Hello, {}!\", user.sub))
+/// }
+/// ```
+pub type AuthUser = web::ReqData<Claims>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::jwt::Claims;
+
+    #[test]
+    fn test_check_roles_passes_with_matching_role() {
+        let claims = Claims::new(\"u1\", \"a@b.com\", vec![\"admin\".into()], 1);
+        let result = check_roles(&claims, &[\"admin\"]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_roles_passes_with_any_matching_role() {
+        let claims = Claims::new(\"u1\", \"a@b.com\", vec![\"editor\".into()], 1);
+        let result = check_roles(&claims, &[\"admin\", \"editor\"]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_roles_fails_without_matching_role() {
+        let claims = Claims::new(\"u1\", \"a@b.com\", vec![\"viewer\".into()], 1);
+        let result = check_roles(&claims, &[\"admin\"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_roles_passes_with_empty_required() {
+        let claims = Claims::new(\"u1\", \"a@b.com\", vec![], 1);
+        let result = check_roles(&claims, &[]);
+        assert!(result.is_ok());
+    }
+}
+",
+    );
+
+    content
+}
+
+// ============================================================================
+// realtime.rs — REST change streams
+// ============================================================================
+
+fn build_realtime_streams(ctx: &GenerationContext) -> String {
+    let mut out = String::new();
+
+    if ctx.uses_realtime_transport(RealtimeTransport::WebSocket) {
+        out.push_str(&doc_comment(
+            Some(
+                "Upgrade to a WebSocket that sends each change to `entity` as a JSON\n\
+                 text message until the client disconnects.",
+            ),
+            ctx,
+        ));
+        out.push_str(
+            "\
+pub fn websocket(
+    req: &actix_web::HttpRequest,
+    body: actix_web::web::Payload,
+    entity: &'static str,
+) -> Result<actix_web::HttpResponse, actix_web::Error> {
+    use actix_ws::Message;
+    use futures_util::StreamExt;
+
+    let (response, mut session, mut messages) = actix_ws::handle(req, body)?;
+    actix_web::rt::spawn(async move {
+        let mut changes = std::pin::pin!(change_stream(entity));
+        loop {
+            tokio::select! {
+                change = changes.next() => {
+                    let Some(change) = change else { break };
+                    let Ok(text) = serde_json::to_string(&change) else { continue };
+                    if session.text(text).await.is_err() {
+                        return;
+                    }
+                }
+                // Clients only listen; pings are answered, anything but a
+                // close ignored
+                message = messages.next() => match message {
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            return;
+                        }
+                    }
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        let _ = session.close(None).await;
+    });
+    Ok(response)
+}
+
+",
+        );
+    }
+
+    if ctx.uses_realtime_transport(RealtimeTransport::Sse) {
+        out.push_str(&doc_comment(
+            Some("A `text/event-stream` with a `change` event, as JSON, for each change\nto `entity`."),
+            ctx,
+        ));
+        out.push_str(
+            "\
+pub fn sse(entity: &'static str) -> actix_web::HttpResponse {
+    use futures_util::StreamExt;
+
+    let events = change_stream(entity).map(|change| {
+        let data = serde_json::to_string(&change).unwrap_or_default();
+        Ok::<_, std::convert::Infallible>(actix_web::web::Bytes::from(format!(
+            \"event: change\\ndata: {data}\\n\\n\"
+        )))
+    });
+    actix_web::HttpResponse::Ok()
+        .content_type(\"text/event-stream\")
+        .insert_header((\"Cache-Control\", \"no-cache\"))
+        .streaming(events)
+}
+
+",
+        );
+    }
+
+    out
+}
+
+// ============================================================================
+// graphql/http.rs
+// ============================================================================
+
+fn build_graphql_http(ctx: &GenerationContext) -> String {
+    let auth = ctx.auth_enabled();
+    let mut content = String::with_capacity(2048);
+
+    content.push_str(&file_header(
+        "GraphQL over HTTP and WebSocket for Actix Web.",
+    ));
+
+    if auth {
+        content.push_str(
+            "use actix_web::http::header::AUTHORIZATION;
+",
+        );
+    }
+    content.push_str(
+        "use actix_web::{web, HttpRequest, HttpResponse};
+",
+    );
+    content.push_str(
+        "use async_graphql::http::GraphiQLSource;
+",
+    );
+    content.push_str(
+        "use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse, GraphQLSubscription};
+
+",
+    );
+    if auth {
+        content.push_str(
+            "use crate::auth::jwt::{verify_token, Claims};
+",
+        );
+    }
+    if auth {
+        content.push_str("use crate::graphql::{connection_data, AppSchema};\n");
+    } else {
+        content.push_str("use crate::graphql::AppSchema;\n");
+    }
+    if auth {
+        content.push_str(
+            "use crate::state::AppState;
+",
+        );
+    }
+    content.push('\n');
+
+    content.push_str(&doc_comment(
+        Some(
+            "Serve `schema`: GraphiQL on `GET /graphql`, queries and mutations on\n\
+             `POST /graphql` and subscriptions on the `/graphql/ws` WebSocket.",
+        ),
+        ctx,
+    ));
+    content.push_str(
+        "\
+pub fn configure(schema: AppSchema) -> impl FnOnce(&mut web::ServiceConfig) {
+    move |cfg| {
+        cfg.app_data(web::Data::new(schema))
+            .service(
+                web::resource(\"/graphql\")
+                    .route(web::get().to(graphiql))
+                    .route(web::post().to(graphql)),
+            )
+            .service(web::resource(\"/graphql/ws\").route(web::get().to(graphql_ws)));
+    }
+}
+
+async fn graphiql() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(\"text/html; charset=utf-8\")
+        .body(
+            GraphiQLSource::build()
+                .endpoint(\"/graphql\")
+                .subscription_endpoint(\"/graphql/ws\")
+                .finish(),
+        )
+}
+
+",
+    );
+
+    if auth {
+        content.push_str(
+            "\
+async fn graphql(
+    schema: web::Data<AppSchema>,
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let mut request = request.into_inner();
+    if let Some(claims) = bearer_claims(&req, &state) {
+        request = request.data(claims);
+    }
+    schema.execute(request).await.into()
+}
+
+async fn graphql_ws(
+    schema: web::Data<AppSchema>,
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<HttpResponse> {
+    let claims = bearer_claims(&req, &state);
+    let jwt_secret = state.config.jwt_secret.clone();
+    GraphQLSubscription::new(AppSchema::clone(&schema))
+        .on_connection_init(move |init| connection_data(init, claims, jwt_secret))
+        .start(&req, payload)
+}
+
+/// Claims of a valid `Authorization: Bearer <token>` header. Requests
+/// without one run anonymously; resolvers that need a caller refuse them.
+fn bearer_claims(req: &HttpRequest, state: &AppState) -> Option<Claims> {
+    let header = req.headers().get(AUTHORIZATION)?.to_str().ok()?;
+    let token = header.strip_prefix(\"Bearer \")?;
+    verify_token(token, &state.config.jwt_secret)
+        .map_err(|e| tracing::warn!(\"JWT verification failed: {}\", e))
+        .ok()
+}
+",
+        );
+    } else {
+        content.push_str(
+            "\
+async fn graphql(schema: web::Data<AppSchema>, request: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(request.into_inner()).await.into()
+}
+
+async fn graphql_ws(
+    schema: web::Data<AppSchema>,
+    req: HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<HttpResponse> {
+    GraphQLSubscription::new(AppSchema::clone(&schema)).start(&req, payload)
+}
+",
+        );
+    }
+
+    content
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{
+        ApiVersioning, AuthConfig, EndpointGroup, EndpointSecurity, Entity, Field, OAuthProvider,
+        ObservabilityConfig, ProjectGraph, RateLimit, Relationship,
+    };
+
+    fn setup_project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("actix_api");
+        project.config.framework = WebFramework::ActixWeb;
+
+        let mut user = Entity::new("User");
+        user.config.timestamps = true;
+        let user_id = user.id;
+        let mut email = Field::new("email", DataType::String);
+        email.required = true;
+        user.fields.push(email);
+        project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+
+        project
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .unwrap_or_else(|| panic!("{} not generated", path))
+            .content
+    }
+
+    #[test]
+    fn test_routes_mod_configures_app() {
+        let project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+
+        assert!(content.contains(
+            "pub fn configure_app(state: web::Data<AppState>) -> impl FnOnce(&mut web::ServiceConfig) {"
+        ));
+        assert!(content.contains("cfg.app_data(state)"));
+        assert!(
+            content.contains(".app_data(web::JsonConfig::default().error_handler(json_error))")
+        );
+        assert!(content.contains(".configure(api::configure)"));
+        assert!(
+            content
+                .contains(".wrap(from_fn(locale))\n                    .wrap(Logger::default())")
+        );
+        assert!(content.contains(".wrap(cors)"));
+        assert!(content.contains("Cors::default()"));
+        assert!(content.contains("if e.is_data() => AppError::Validation(e.to_string()).into()"));
+        assert!(!content.contains("axum"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_cors_policy() {
+        let mut project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+        assert!(
+            content
+                .contains("cors.allowed_methods(constants::CORS_ALLOWED_METHODS.iter().copied())")
+        );
+        assert!(content.contains("cors.supports_credentials()"));
+        assert!(content.contains("cors.allow_any_origin()"));
+
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security =
+            EndpointSecurity::open().add_cors_origin("https://admin.example.com");
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+        assert!(content.contains(
+            "let cors = cors.allowed_origin_fn(|origin, request| {\n            cors_origin_allowed(origin.as_bytes(), request.uri.path())\n        });"
+        ));
+        assert!(content.contains("fn cors_origin_allowed(origin: &[u8], path: &str) -> bool {"));
+        assert!(!content.contains("allow_any_origin"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_telemetry_and_without_cors() {
+        let mut project = setup_project();
+        project.config.telemetry = true;
+        project.config.cors_enabled = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+
+        assert!(content.contains(".wrap(from_fn(http_span)),"));
+        assert!(!content.contains("Logger"));
+        assert!(!content.contains("Cors"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_observability() {
+        let mut project = setup_project();
+        project.config.observability = ObservabilityConfig::all();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+
+        assert!(content.contains("use crate::health;"));
+        assert!(content.contains(
+            ".route(\"/healthz\", web::get().to(health::healthz))\n            .route(\"/readyz\", web::get().to(health::readyz))\n            .service(\n"
+        ));
+        assert!(content.contains(".wrap(cors)\n                    .wrap(metrics()),"));
+        assert!(content.contains("static METRICS: OnceLock<PrometheusMetrics> = OnceLock::new();"));
+        assert!(content.contains(".endpoint(\"/metrics\")"));
+        assert!(
+            ActixBackend
+                .cargo_dependencies(&ctx)
+                .contains("actix-web-prom = \"0.9\"")
+        );
+    }
+
+    #[test]
+    fn test_api_routes_scope_per_entity() {
+        let mut project = setup_project();
+        project.config.auth.enabled = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("pub fn configure(cfg: &mut web::ServiceConfig) {"));
+        assert!(content.contains("    cfg.service(user_routes());"));
+        assert!(content.contains("fn user_routes() -> Scope {"));
+        assert!(content.contains("web::scope(constants::USER_PATH)"));
+        assert!(content.contains(".route(\"\", web::post().to(user::create_user))"));
+        assert!(content.contains(".route(\"\", web::get().to(user::list_users))"));
+        assert!(content.contains(".route(\"/{id}\", web::get().to(user::get_user))"));
+        assert!(content.contains(".route(\"/{id}\", web::put().to(user::update_user))"));
+        assert!(content.contains(".route(\"/{id}\", web::delete().to(user::delete_user))"));
+        assert!(!content.contains("require_auth"));
+    }
+
+    #[test]
+    fn test_api_routes_header_versioning_guards_scopes() {
+        let mut project = setup_project();
+        project.config.auth.enabled = false;
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let mut account = Entity::new("Account");
+        let account_id = account.id;
+        account.fields.push(Field::new("email", DataType::String));
+        project.add_entity(account);
+        let mut v2 = EndpointGroup::new(account_id, "Account").with_base_path("/api/users");
+        v2.api_version = Some("v2".to_string());
+        project.add_endpoint(v2);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("use actix_web::guard::{self, Guard};"));
+        assert!(
+            content.contains(
+                "web::scope(constants::USER_PATH)\n        .guard(version_guard(\"v1\"))"
+            )
+        );
+        assert!(content.contains(
+            "web::scope(constants::ACCOUNT_PATH)\n        .guard(version_guard(\"v2\"))"
+        ));
+        assert!(content.contains("fn version_guard(version: &'static str) -> impl Guard {"));
+        assert!(content.contains(".get(\"x-api-version\")"));
+        assert!(content.contains(".unwrap_or(\"v1\")"));
+    }
+
+    #[test]
+    fn test_api_routes_wrap_secured_operations() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig {
+            enabled: true,
+            ..AuthConfig::default()
+        };
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security = EndpointSecurity::authenticated();
+        endpoint.set_operation_security(OperationType::ReadAll, EndpointSecurity::open());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("use crate::auth::middleware::require_auth;"));
+        assert!(content.contains(".route(\"\", web::get().to(user::list_users))\n"));
+        assert!(content.contains(
+            ".route(\"\", web::post().to(user::create_user).wrap(from_fn(require_auth)))"
+        ));
+    }
+
+    #[test]
+    fn test_api_routes_require_roles() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::jwt();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security = EndpointSecurity::authenticated();
+        endpoint.set_operation_security(
+            OperationType::Delete,
+            EndpointSecurity::with_roles(vec!["admin".into()]),
+        );
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("use crate::auth::middleware::RequireRoles;"));
+        assert!(content.contains("use crate::auth::roles::Role;"));
+        assert!(content.contains(
+            ".route(\"/{id}\", web::delete().to(user::delete_user).wrap(RequireRoles(&[Role::Admin])).wrap(from_fn(require_auth)))"
+        ));
+        assert!(content.contains(
+            ".route(\"/{id}\", web::get().to(user::get_user).wrap(from_fn(require_auth)))"
+        ));
+
+        let middleware = ActixBackend.generate_auth_middleware(&ctx).content;
+        assert!(middleware.contains("pub struct RequireRoles(pub &'static [Role]);"));
+        assert!(middleware.contains("impl<S, B> Transform<S, ServiceRequest> for RequireRoles"));
+        assert!(middleware.contains("check_roles(claims, &required).map_err(Error::from)"));
+    }
+
+    #[test]
+    fn test_api_routes_rate_limited_inside_auth() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::jwt();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security = EndpointSecurity::authenticated();
+        endpoint
+            .get_operation_mut(OperationType::Create)
+            .unwrap()
+            .rate_limit = Some(RateLimit::strict());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("use crate::rate_limit;"));
+        assert!(content.contains(
+            ".route(\"\", web::post().to(user::create_user).wrap(from_fn(|request, next| rate_limit::enforce(&rate_limit::USER_CREATE, request, next))).wrap(from_fn(require_auth)))"
+        ));
+        assert!(content.contains(
+            ".route(\"/{id}\", web::get().to(user::get_user).wrap(from_fn(require_auth)))"
+        ));
+    }
+
+    #[test]
+    fn test_api_routes_stream_changes_ahead_of_id() {
+        let mut project = setup_project();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime = true;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = file(&ActixBackend.generate_routes(&ctx), "src/routes/api.rs").to_string();
+
+        let live = content
+            .find(".route(\"/ws\", web::get().to(user::user_changes))\n")
+            .expect("stream route");
+        assert!(live < content.find("\"/{id}\"").unwrap());
+        assert!(
+            ActixBackend
+                .cargo_dependencies(&ctx)
+                .contains("actix-ws = \"0.3\"")
+        );
+    }
+
+    #[test]
+    fn test_api_routes_link_promoted_junctions() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig {
+            enabled: true,
+            ..AuthConfig::default()
+        };
+        let user = project.entities.keys().copied().next().unwrap();
+        let team = project.add_entity(Entity::new("Team"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(user, team, "user_teams"))
+            .unwrap();
+        project.promote_junction(rel).unwrap();
+        project
+            .endpoints
+            .values_mut()
+            .next()
+            .unwrap()
+            .set_operation_security(OperationType::Update, EndpointSecurity::authenticated());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/api.rs");
+
+        assert!(content.contains("use crate::handlers::user_links;"));
+        assert!(content.contains(
+            ".route(\"/{id}/teams\", web::get().to(user_links::list_user_teams).wrap(from_fn(require_auth)))"
+        ));
+        assert!(content.contains(
+            ".route(\"/{id}/teams\", web::post().to(user_links::link_user_teams).wrap(from_fn(require_auth)))"
+        ));
+        assert!(content.contains(
+            ".route(\"/{id}/teams/{team_id}\", web::delete().to(user_links::unlink_user_teams).wrap(from_fn(require_auth)))"
+        ));
+
+        // Actix binds both path segments as one tuple
+        let handlers = crate::rust::handlers::generate_handlers(&ctx);
+        let links = file(&handlers, "src/handlers/user_links.rs");
+        assert!(links.contains("path: Path<(uuid::Uuid, uuid::Uuid)>,"));
+        assert!(links.contains("    let (id, team_id) = path.into_inner();\n"));
+    }
+
+    #[test]
+    fn test_middleware_uses_service_requests() {
+        let mut project = setup_project();
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = build_middleware(&ctx);
+
+        assert!(content.contains("pub async fn request_logger("));
+        assert!(content.contains("pub async fn request_id("));
+        assert!(content.contains("pub async fn locale("));
+        assert!(content.contains("pub async fn http_span("));
+        assert!(content.contains("next: Next<impl MessageBody>,"));
+        assert!(content.contains(".match_pattern()"));
+        assert!(content.contains("impl Extractor for HeaderExtractor<'_> {"));
+        assert!(!content.contains("opentelemetry_http"));
+    }
+
+    #[test]
+    fn test_auth_middleware_reads_bearer_header() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig {
+            enabled: true,
+            ..AuthConfig::default()
+        };
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = build_auth_middleware(&ctx);
+
+        assert!(content.contains("pub async fn require_auth("));
+        assert!(content.contains("header.strip_prefix(\"Bearer \")"));
+        assert!(content.contains(".app_data::<web::Data<AppState>>()"));
+        assert!(content.contains("request.extensions_mut().insert(claims);"));
+        assert!(content.contains("pub type AuthUser = web::ReqData<Claims>;"));
+        assert!(!content.contains("axum"));
+    }
+
+    #[test]
+    fn test_refresh_tokens_mount_session_routes() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let middleware = build_auth_middleware(&ctx);
+        assert!(middleware.contains("use crate::auth::sessions;"));
+        assert!(
+            middleware
+                .contains("Some(session_id) => !sessions::is_active(state, session_id).await?,")
+        );
+
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+        assert!(content.contains("use crate::auth::sessions;"));
+        assert!(content.contains(
+            "web::scope(\"\")\n                    .service(\n                        web::scope(constants::AUTH_PATH)"
+        ));
+        assert!(content.contains(
+            ".route(\"/logout-all\", web::post().to(sessions::logout_all).wrap(from_fn(require_auth))),"
+        ));
+    }
+
+    #[test]
+    fn test_oauth_mounts_sign_in_routes() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::oauth2().with_oauth_provider(OAuthProvider::github());
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let files = ActixBackend.generate_routes(&ctx);
+        let content = file(&files, "src/routes/mod.rs");
+        assert!(content.contains("use crate::auth::oauth;"));
+        assert!(!content.contains("use crate::auth::sessions;"));
+        assert!(content.contains(
+            "web::scope(constants::AUTH_PATH)\n                            .route(\"/oauth/{provider}/authorize\", web::get().to(oauth::oauth_authorize))\n                            .route(\"/oauth/{provider}/callback\", web::get().to(oauth::oauth_callback)),\n                    )"
+        ));
+    }
+}
@@ -7,13 +7,33 @@
 //!
 //! - `src/auth/mod.rs` — module declarations and re-exports
 //! - `src/auth/jwt.rs` — `Claims` struct, `create_token`, `verify_token`
-//! - `src/auth/middleware.rs` — Axum `require_auth` middleware and role checks
+//! - `src/auth/roles.rs` — the `Role` type routes are guarded by
+//! - `src/auth/middleware.rs` — `require_auth` middleware and role checks, for
+//!   Axum here or Actix Web in [`super::actix`]
+//! - `src/auth/sessions.rs` — refresh tokens and revocable sessions, when
+//!   [`AuthConfig::refresh_tokens`](imortal_ir::AuthConfig::refresh_tokens)
+//!   is set (see [`super::sessions`])
 //!
 //! ## Usage
 //!
 //! The generated auth module is only produced when `ctx.auth_enabled()` is
 //! `true`. The router generator (`routes.rs`) will wrap secured routes with
-//! the `require_auth` middleware layer.
+//! the `require_auth` middleware layer, and hand it the `AppState` as a
+//! request extension.
+//!
+//! ## Roles
+//!
+//! Routes whose [`EndpointSecurity::roles`] name any role also get a
+//! `RequireRoles` check, answering `403 Forbidden` to callers whose token's
+//! `roles` claim holds none of them. What a role is depends on the auth
+//! config:
+//!
+//! | `available_roles` | `Role` | Routes check |
+//! |-------------------|--------|--------------|
+//! | declared | an enum of every role the project names | `RequireRoles(&[Role::Admin])` |
+//! | empty | `&'static str`: roles are data, e.g. rows of the app's own roles table | `RequireRoles(&["admin"])` |
+//!
+//! [`EndpointSecurity::roles`]: imortal_ir::EndpointSecurity::roles
 //!
 //! ## Token Flow
 //!
@@ -23,18 +43,23 @@
 //!          → Handler reads Claims from extensions
 //! ```
 
-use imortal_ir::AuthStrategy;
+use imortal_ir::{AuthStrategy, EndpointSecurity};
 
 use crate::context::GenerationContext;
 use crate::rust::file_header;
+use crate::rust::oauth::generate_oauth;
+use crate::rust::sessions::generate_sessions;
 use crate::{FileType, GeneratedFile};
 
+/// Where the session and OAuth2 sign-in routes are mounted.
+pub(crate) const AUTH_PATH: &str = "/api/auth";
+
 // ============================================================================
 // Public API
 // ============================================================================
 
 /// Generate all authentication files (`src/auth/mod.rs`, `src/auth/jwt.rs`,
-/// `src/auth/middleware.rs`).
+/// `src/auth/middleware.rs`, and `src/auth/sessions.rs` with refresh tokens).
 ///
 /// Returns an empty `Vec` if authentication is not enabled.
 pub fn generate_auth(ctx: &GenerationContext) -> Vec<GeneratedFile> {
@@ -42,11 +67,58 @@ pub fn generate_auth(ctx: &GenerationContext) -> Vec<GeneratedFile> {
         return Vec::new();
     }
 
-    vec![
+    let mut files = vec![
         generate_auth_mod(ctx),
         generate_jwt(ctx),
-        generate_auth_middleware(ctx),
-    ]
+        generate_roles(ctx),
+        ctx.framework_backend().generate_auth_middleware(ctx),
+    ];
+    files.extend(generate_sessions(ctx));
+    files.extend(generate_oauth(ctx));
+    files
+}
+
+/// The `RequireRoles(&[…])` value guarding a route with `security`, when
+/// auth is on, the route requires it and it names any role.
+pub(crate) fn required_roles(
+    ctx: &GenerationContext,
+    security: &EndpointSecurity,
+) -> Option<String> {
+    if !ctx.auth_enabled() || !security.auth_required || security.roles.is_empty() {
+        return None;
+    }
+    let roles: Vec<String> = security
+        .roles
+        .iter()
+        .map(|role| {
+            if ctx.role_enum() {
+                format!("Role::{}", GenerationContext::pascal(role))
+            } else {
+                format!("{:?}", role)
+            }
+        })
+        .collect();
+    Some(format!("RequireRoles(&[{}])", roles.join(", ")))
+}
+
+/// Whether any generated route checks roles, so the router imports
+/// `RequireRoles` (and `Role`, if it is an enum).
+pub(crate) fn routes_check_roles(ctx: &GenerationContext) -> bool {
+    let endpoint_roles = ctx.entities().iter().any(|entity| {
+        ctx.endpoint_for_entity(entity.id)
+            .filter(|ep| ep.enabled)
+            .is_some_and(|ep| {
+                ep.enabled_operations().iter().any(|op| {
+                    let security = op.security.as_ref().unwrap_or(&ep.global_security);
+                    required_roles(ctx, security).is_some()
+                })
+            })
+    });
+    endpoint_roles
+        || ctx
+            .queries()
+            .iter()
+            .any(|q| required_roles(ctx, &q.security).is_some())
 }
 
 // ============================================================================
@@ -59,11 +131,20 @@ fn generate_auth_mod(ctx: &GenerationContext) -> GeneratedFile {
     content.push_str(&file_header("Authentication module."));
 
     content.push_str("pub mod jwt;\n");
-    content.push_str("pub mod middleware;\n\n");
+    content.push_str("pub mod middleware;\n");
+    if !ctx.oauth_providers().is_empty() {
+        content.push_str("pub mod oauth;\n");
+    }
+    content.push_str("pub mod roles;\n");
+    if ctx.refresh_tokens() {
+        content.push_str("pub mod sessions;\n");
+    }
+    content.push('\n');
 
     content.push_str("// Re-exports for convenience\n");
     content.push_str("pub use jwt::{Claims, create_token, verify_token};\n");
-    content.push_str("pub use middleware::require_auth;\n");
+    content.push_str("pub use middleware::{RequireRoles, require_auth};\n");
+    content.push_str("pub use roles::Role;\n");
 
     GeneratedFile::new("src/auth/mod.rs", content, FileType::Rust)
 }
@@ -87,11 +168,41 @@ use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation}
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::constants::{PASSWORD_HASH_COST, TOKEN_EXPIRY_HOURS};
+
 ",
     );
 
     // ── Claims ───────────────────────────────────────────────────────────
-    content.push_str(
+    let auth = ctx.auth_config();
+    let ident = GenerationContext::snake(auth.identifier_claim());
+    let (ident_doc, ident_arg_doc) = match &auth.principal {
+        Some(principal) => (
+            format!(
+                "The `{}.{}` the user signs in with.",
+                principal.entity, principal.identifier_field
+            ),
+            format!("user's `{}`", principal.identifier_field),
+        ),
+        None => (
+            "User email address.".to_string(),
+            "user's email address".to_string(),
+        ),
+    };
+    // With refresh tokens, access tokens name the session they belong to
+    let (sid_field, sid_init) = if ctx.refresh_tokens() {
+        (
+            "\n    /// Session the token was issued for; `require_auth` rejects it once\n    \
+             /// the session has ended.\n    \
+             #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    \
+             pub sid: Option<String>,\n",
+            "\n            sid: None,",
+        )
+    } else {
+        ("", "")
+    };
+
+    content.push_str(&format!(
         "\
 /// JWT claims payload.
 ///
@@ -100,12 +211,12 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// `require_auth` middleware so that handlers can access the authenticated
 /// user's identity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Claims {
+pub struct Claims {{
     /// Subject — typically the user's primary key (UUID or ID).
     pub sub: String,
 
-    /// User email address.
-    pub email: String,
+    /// {ident_doc}
+    pub {ident}: String,
 
     /// Roles assigned to the user (e.g. `[\"admin\", \"editor\"]`).
     pub roles: Vec<String>,
@@ -115,60 +226,61 @@ pub struct Claims {
 
     /// Issued-at time (seconds since UNIX epoch).
     pub iat: u64,
-}
+{sid_field}}}
 
-impl Claims {
+impl Claims {{
     /// Create a new set of claims.
     ///
     /// # Arguments
     ///
     /// * `user_id`      — unique identifier for the user (UUID string)
-    /// * `email`        — user's email address
+    /// * `{ident}`{pad} — {ident_arg_doc}
     /// * `roles`        — list of role strings
 ",
-    );
+        pad = " ".repeat(12usize.saturating_sub(ident.len())),
+    ));
 
     content.push_str(&format!(
         "    /// * `expiry_hours` — token lifetime in hours (default: {})\n",
         expiry_hours,
     ));
 
-    content.push_str(
+    content.push_str(&format!(
         "\
     pub fn new(
         user_id: impl Into<String>,
-        email: impl Into<String>,
+        {ident}: impl Into<String>,
         roles: Vec<String>,
         expiry_hours: u64,
-    ) -> Self {
+    ) -> Self {{
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect(\"system clock before UNIX epoch\")
             .as_secs();
 
-        Self {
+        Self {{
             sub: user_id.into(),
-            email: email.into(),
+            {ident}: {ident}.into(),
             roles,
             iat: now,
-            exp: now + (expiry_hours * 3600),
-        }
-    }
+            exp: now + (expiry_hours * 3600),{sid_init}
+        }}
+    }}
 
 ",
-    );
+    ));
 
     content.push_str(&format!(
         "\
-    /// Create claims with the default expiry ({expiry_hours} hours).
+    /// Create claims with the default expiry (`TOKEN_EXPIRY_HOURS`, {expiry_hours} hours).
     pub fn with_default_expiry(
         user_id: impl Into<String>,
-        email: impl Into<String>,
+        {ident}: impl Into<String>,
         roles: Vec<String>,
     ) -> Self {{
-        Self::new(user_id, email, roles, {expiry_hours})
+        Self::new(user_id, {ident}, roles, TOKEN_EXPIRY_HOURS)
     }}
-
+{with_session}
     /// Check whether this token has a specific role.
     pub fn has_role(&self, role: &str) -> bool {{
         self.roles.iter().any(|r| r == role)
@@ -190,6 +302,17 @@ impl Claims {
 }}
 
 ",
+        with_session = if ctx.refresh_tokens() {
+            "
+    /// Tie the token to a session, see `crate::auth::sessions`.
+    pub fn with_session(mut self, session_id: impl Into<String>) -> Self {
+        self.sid = Some(session_id.into());
+        self
+    }
+"
+        } else {
+            ""
+        },
     ));
 
     // ── create_token ─────────────────────────────────────────────────────
@@ -263,7 +386,7 @@ pub fn verify_token(
 ///
 /// Returns an error if bcrypt hashing fails.
 pub fn hash_password(password: &str) -> Result<String, bcrypt::BcryptError> {
-    bcrypt::hash(password, bcrypt::DEFAULT_COST)
+    bcrypt::hash(password, PASSWORD_HASH_COST)
 }
 
 /// Verify a plain-text password against a bcrypt hash.
@@ -295,7 +418,13 @@ mod tests {
 
         let decoded = verify_token(&token, TEST_SECRET).expect(\"should verify token\");
         assert_eq!(decoded.sub, \"user-123\");
-        assert_eq!(decoded.email, \"test@example.com\");
+",
+    );
+    content.push_str(&format!(
+        "        assert_eq!(decoded.{ident}, \"test@example.com\");\n"
+    ));
+    content.push_str(
+        "\
         assert_eq!(decoded.roles, vec![\"admin\"]);
     }
 
@@ -353,11 +482,133 @@ mod tests {
     GeneratedFile::new("src/auth/jwt.rs", content, FileType::Rust)
 }
 
+// ============================================================================
+// auth/roles.rs — Role
+// ============================================================================
+
+fn generate_roles(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = String::with_capacity(2048);
+
+    content.push_str(&file_header("Roles routes are guarded by."));
+
+    if !ctx.role_enum() {
+        content.push_str(
+            "\
+/// A role, by the name tokens carry it under in their `roles` claim.
+///
+/// The auth config declares no roles, so roles are data — managed by the
+/// application (e.g. rows of its own roles table) rather than fixed here.
+pub type Role = &'static str;
+",
+        );
+        return GeneratedFile::new("src/auth/roles.rs", content, FileType::Rust);
+    }
+
+    let roles = ctx.roles();
+    let variants: Vec<String> = roles.iter().map(|r| GenerationContext::pascal(r)).collect();
+
+    content.push_str("use serde::{Deserialize, Serialize};\n\n");
+    content.push_str(
+        "\
+/// A role users can hold, carried in the `roles` claim of their token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Role {
+",
+    );
+    for (role, variant) in roles.iter().zip(&variants) {
+        content.push_str(&format!(
+            "    #[serde(rename = \"{role}\")]\n    {variant},\n"
+        ));
+    }
+    content.push_str("}\n\n");
+
+    let all: Vec<String> = variants.iter().map(|v| format!("Role::{v}")).collect();
+    content.push_str(&format!(
+        "\
+impl Role {{
+    /// Every role, in declaration order.
+    pub const ALL: [Role; {count}] = [{all}];
+
+    /// The role's name in tokens.
+    pub const fn as_str(self) -> &'static str {{
+        match self {{
+",
+        count = all.len(),
+        all = all.join(", "),
+    ));
+    for (role, variant) in roles.iter().zip(&variants) {
+        content.push_str(&format!("            Role::{variant} => \"{role}\",\n"));
+    }
+    content.push_str(
+        "\
+        }
+    }
+}
+
+impl AsRef<str> for Role {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.as_str() == s)
+            .ok_or_else(|| format!(\"unknown role '{s}'\"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_names_round_trip() {
+        for role in Role::ALL {
+            assert_eq!(role.as_str().parse::<Role>(), Ok(role));
+        }
+        assert!(\"no-such-role\".parse::<Role>().is_err());
+    }
+}
+",
+    );
+
+    GeneratedFile::new("src/auth/roles.rs", content, FileType::Rust)
+}
+
 // ============================================================================
 // auth/middleware.rs — require_auth, require_roles
 // ============================================================================
 
-fn generate_auth_middleware(ctx: &GenerationContext) -> GeneratedFile {
+/// The Axum `require_auth` step rejecting tokens whose session has ended.
+const REQUIRE_LIVE_SESSION: &str = "\
+    // Tokens of a session that has ended are no longer accepted
+    let ended = match &claims.sid {
+        Some(session_id) => !sessions::is_active(&state, session_id)
+            .await
+            .map_err(IntoResponse::into_response)?,
+        None => false,
+    };
+    if ended {
+        return Err(unauthorized(\"Session has ended\"));
+    }
+
+";
+
+/// The Axum `src/auth/middleware.rs`; see [`FrameworkBackend::generate_auth_middleware`].
+///
+/// [`FrameworkBackend::generate_auth_middleware`]: crate::rust::framework::FrameworkBackend::generate_auth_middleware
+pub(crate) fn generate_auth_middleware(ctx: &GenerationContext) -> GeneratedFile {
     let mut content = String::with_capacity(4096);
 
     content.push_str(&file_header("Authentication middleware for Axum routes."));
@@ -367,22 +618,24 @@ fn generate_auth_middleware(ctx: &GenerationContext) -> GeneratedFile {
         "\
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
     middleware::Next,
     response::{IntoResponse, Response},
-    Json,
+    Extension,
 };
 use axum_extra::{
     headers::{authorization::Bearer, Authorization},
     TypedHeader,
 };
-use serde_json::json;
 
 use crate::auth::jwt::{verify_token, Claims};
-use crate::state::AppState;
-
+use crate::auth::roles::Role;
+use crate::error::AppError;
 ",
     );
+    if ctx.refresh_tokens() {
+        content.push_str("use crate::auth::sessions;\n");
+    }
+    content.push_str("use crate::state::AppState;\n\n");
 
     // ── require_auth ─────────────────────────────────────────────────────
     content.push_str(
@@ -397,28 +650,48 @@ use crate::state::AppState;
 /// let claims = request.extensions().get::<Claims>().unwrap();
 /// ```
 ///
-/// On failure a `401 Unauthorized` JSON response is returned.
+/// On failure — no token, one that does not verify, or one whose session
+/// has ended — a `401 Unauthorized` JSON response is returned.
+///
+/// Route layers are added before the router has its state, so the state
+/// comes from the `Extension` layer `create_router` adds.
 pub async fn require_auth(
-    State(state): State<AppState>,
-    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Extension(state): Extension<AppState>,
+    auth: Option<TypedHeader<Authorization<Bearer>>>,
     mut request: Request,
     next: Next,
 ) -> Result<Response, Response> {
+    let Some(TypedHeader(auth)) = auth else {
+        return Err(unauthorized(\"Missing authentication token\"));
+    };
     let token = auth.token();
 
     let claims = verify_token(token, &state.config.jwt_secret)
         .map_err(|e| {
             tracing::warn!(\"JWT verification failed: {}\", e);
-            (
-                StatusCode::UNAUTHORIZED,
-                Json(json!({
-                    \"error\": \"unauthorized\",
-                    \"message\": \"Invalid or expired authentication token\"
-                })),
-            )
-                .into_response()
+            unauthorized(\"Invalid or expired authentication token\")
         })?;
 
+",
+    );
+    if ctx.refresh_tokens() {
+        content.push_str(REQUIRE_LIVE_SESSION);
+    }
+
+    // With telemetry, tag the request's HTTP span with the caller.
+    if ctx.telemetry() {
+        content.push_str(
+            "\
+    let span = tracing::Span::current();
+    span.record(\"enduser.id\", claims.sub.as_str());
+    span.record(\"enduser.role\", claims.roles.join(\",\").as_str());
+
+",
+        );
+    }
+
+    content.push_str(
+        "\
     // Make claims available to handlers
     request.extensions_mut().insert(claims);
 
@@ -431,6 +704,11 @@ pub async fn require_auth(
     // ── require_roles ────────────────────────────────────────────────────
     content.push_str(
         "\
+/// The `401 Unauthorized` problem details sent by [`require_auth`].
+fn unauthorized(message: &str) -> Response {
+    AppError::unauthorized(message).into_response()
+}
+
 /// Extract the authenticated user's [`Claims`] from a request's extensions.
 ///
 /// This is a convenience function for handlers that need to inspect the
@@ -475,38 +753,50 @@ pub fn check_roles(claims: &Claims, required_roles: &[&str]) -> Result<(), Respo
             required_roles,
             claims.roles,
         );
-        Err((
-            StatusCode::FORBIDDEN,
-            Json(json!({
-                \"error\": \"forbidden\",
-                \"message\": \"Insufficient permissions\"
-            })),
-        )
-            .into_response())
+        Err(AppError::forbidden(\"Insufficient permissions\").into_response())
     }
 }
 
 ",
     );
 
-    // ── Optional: role-checking middleware factory ────────────────────────
+    // ── require_roles ────────────────────────────────────────────────────
     content.push_str(
         "\
-/// Create a middleware layer that requires the authenticated user to have
-/// at least one of the specified roles.
+/// The roles a route accepts: callers must hold at least one of them.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireRoles(pub &'static [Role]);
+
+/// Middleware answering `403 Forbidden` to callers that hold none of the
+/// route's [`RequireRoles`].
 ///
-/// This is intended to be used as a route layer **after** `require_auth`:
+/// It reads the [`Claims`] stored by [`require_auth`], so it goes on the
+/// method router, inside the router's `require_auth` layer:
 ///
 /// ```rust,ignore
 /// Router::new()
-///     .route(\"/admin\", get(admin_handler))
+///     .route(
+///         \"/\",
+///         post(handler).route_layer(middleware::from_fn_with_state(
+///             RequireRoles(&[Role::Admin]),
+///             require_roles,
+///         )),
+///     )
 ///     .route_layer(middleware::from_fn(require_auth))
-///     // Note: role checking is typically done inside the handler
-///     // using check_roles() for more flexibility.
 /// ```
-///
-/// For fine-grained per-handler role checks, prefer calling
-/// [`check_roles`] directly inside the handler function.
+pub async fn require_roles(
+    State(RequireRoles(roles)): State<RequireRoles>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let Some(claims) = request.extensions().get::<Claims>() else {
+        return Err(unauthorized(\"Missing authentication token\"));
+    };
+    let required: Vec<&str> = roles.iter().map(|role| role.as_ref()).collect();
+    check_roles(claims, &required)?;
+
+    Ok(next.run(request).await)
+}
 
 ",
     );
@@ -524,7 +814,7 @@ pub fn check_roles(claims: &Claims, required_roles: &[&str]) -> Result<(), Respo
 /// pub async fn my_handler(
 ///     Extension(claims): Extension<Claims>,
 /// ) -> impl IntoResponse {
-///     format!(\"Hello, {}!\", claims.email)
+///     format!(\"Hello, {}!\", claims.sub)
 /// }
 /// ```
 ///
@@ -583,7 +873,7 @@ mod tests {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use imortal_ir::{AuthConfig, ProjectGraph};
+    use imortal_ir::{AuthConfig, AuthPrincipal, OAuthProvider, ProjectGraph};
 
     #[test]
     fn test_generate_auth_disabled() {
@@ -604,7 +894,7 @@ mod tests {
         let ctx = GenerationContext::from_project_default(&project);
         let files = generate_auth(&ctx);
 
-        assert_eq!(files.len(), 3);
+        assert_eq!(files.len(), 4);
 
         let paths: Vec<String> = files
             .iter()
@@ -613,6 +903,7 @@ mod tests {
 
         assert!(paths.contains(&"src/auth/mod.rs".to_string()));
         assert!(paths.contains(&"src/auth/jwt.rs".to_string()));
+        assert!(paths.contains(&"src/auth/roles.rs".to_string()));
         assert!(paths.contains(&"src/auth/middleware.rs".to_string()));
     }
 
@@ -633,7 +924,75 @@ mod tests {
         assert!(content.contains("pub mod jwt;"));
         assert!(content.contains("pub mod middleware;"));
         assert!(content.contains("pub use jwt::{Claims, create_token, verify_token};"));
-        assert!(content.contains("pub use middleware::require_auth;"));
+        assert!(content.contains("pub use middleware::{RequireRoles, require_auth};"));
+        assert!(content.contains("pub use roles::Role;"));
+    }
+
+    #[test]
+    fn test_roles_enum_from_declared_roles() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        project.config.auth.available_roles = vec!["user".into(), "super-admin".into()];
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_auth(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/auth/roles.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("pub enum Role {"));
+        assert!(content.contains("    #[serde(rename = \"super-admin\")]\n    SuperAdmin,"));
+        assert!(content.contains("pub const ALL: [Role; 2] = [Role::User, Role::SuperAdmin];"));
+        assert!(content.contains("Role::SuperAdmin => \"super-admin\","));
+        assert!(content.contains("impl std::str::FromStr for Role {"));
+    }
+
+    #[test]
+    fn test_roles_alias_without_declared_roles() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        project.config.auth.available_roles.clear();
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_auth(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/auth/roles.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("pub type Role = &'static str;"));
+        assert!(!content.contains("enum"));
+    }
+
+    #[test]
+    fn test_required_roles() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let admin = EndpointSecurity::with_roles(vec!["admin".into(), "editor".into()]);
+        assert_eq!(
+            required_roles(&ctx, &admin).as_deref(),
+            Some("RequireRoles(&[Role::Admin, Role::Editor])")
+        );
+        assert_eq!(
+            required_roles(&ctx, &EndpointSecurity::authenticated()),
+            None
+        );
+
+        project.config.auth.available_roles.clear();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(
+            required_roles(&ctx, &admin).as_deref(),
+            Some("RequireRoles(&[\"admin\", \"editor\"])")
+        );
+
+        project.config.auth.enabled = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(required_roles(&ctx, &admin), None);
     }
 
     #[test]
@@ -739,13 +1098,99 @@ mod tests {
         let content = &mw_file.content;
 
         assert!(content.contains("pub async fn require_auth("));
-        assert!(content.contains("State(state): State<AppState>"));
-        assert!(content.contains("TypedHeader(auth): TypedHeader<Authorization<Bearer>>"));
+        assert!(content.contains("Extension(state): Extension<AppState>"));
+        assert!(content.contains("auth: Option<TypedHeader<Authorization<Bearer>>>"));
+        assert!(content.contains("Missing authentication token"));
         assert!(content.contains("verify_token(token, &state.config.jwt_secret)"));
         assert!(content.contains("request.extensions_mut().insert(claims)"));
         assert!(content.contains("next.run(request).await"));
     }
 
+    #[test]
+    fn test_require_auth_records_enduser_with_telemetry() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        let middleware = |project: &ProjectGraph| {
+            generate_auth(&GenerationContext::from_project_default(project))
+                .into_iter()
+                .find(|f| f.path.to_string_lossy() == "src/auth/middleware.rs")
+                .unwrap()
+                .content
+        };
+        assert!(!middleware(&project).contains("enduser.role"));
+
+        project.config.telemetry = true;
+        let content = middleware(&project);
+        assert!(content.contains("span.record(\"enduser.id\", claims.sub.as_str());"));
+        assert!(
+            content.contains("span.record(\"enduser.role\", claims.roles.join(\",\").as_str());")
+        );
+        assert!(content.contains("request.extensions_mut().insert(claims)"));
+    }
+
+    #[test]
+    fn test_refresh_tokens_make_sessions_revocable() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        let file = |files: &[GeneratedFile], path: &str| {
+            files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .map(|f| f.content.clone())
+        };
+
+        let files = generate_auth(&GenerationContext::from_project_default(&project));
+        assert!(file(&files, "src/auth/sessions.rs").is_none());
+        assert!(!file(&files, "src/auth/jwt.rs").unwrap().contains("pub sid"));
+        assert!(
+            !file(&files, "src/auth/middleware.rs")
+                .unwrap()
+                .contains("sessions::is_active")
+        );
+
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        let files = generate_auth(&GenerationContext::from_project_default(&project));
+        assert!(file(&files, "src/auth/sessions.rs").is_some());
+        assert!(
+            file(&files, "src/auth/mod.rs")
+                .unwrap()
+                .contains("pub mod sessions;")
+        );
+
+        let jwt = file(&files, "src/auth/jwt.rs").unwrap();
+        assert!(jwt.contains("pub sid: Option<String>,"));
+        assert!(jwt.contains("sid: None,"));
+        assert!(
+            jwt.contains("pub fn with_session(mut self, session_id: impl Into<String>) -> Self")
+        );
+
+        let middleware = file(&files, "src/auth/middleware.rs").unwrap();
+        assert!(middleware.contains("use crate::auth::sessions;"));
+        assert!(
+            middleware.contains("Some(session_id) => !sessions::is_active(&state, session_id)")
+        );
+        assert!(middleware.contains("return Err(unauthorized(\"Session has ended\"));"));
+    }
+
+    #[test]
+    fn test_generate_auth_with_oauth() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::oauth2().with_oauth_provider(OAuthProvider::google());
+        let files = generate_auth(&GenerationContext::from_project_default(&project));
+        let paths: Vec<&str> = files.iter().map(|f| f.path.to_str().unwrap()).collect();
+        assert!(paths.contains(&"src/auth/oauth.rs"));
+        assert!(!paths.contains(&"src/auth/sessions.rs"));
+        let module = files
+            .iter()
+            .find(|f| f.path.ends_with("auth/mod.rs"))
+            .unwrap();
+        assert!(
+            module
+                .content
+                .contains("pub mod middleware;\npub mod oauth;\npub mod roles;")
+        );
+    }
+
     #[test]
     fn test_middleware_contains_check_roles() {
         let mut project = ProjectGraph::new("test");
@@ -762,8 +1207,14 @@ mod tests {
         let content = &mw_file.content;
 
         assert!(content.contains("pub fn check_roles("));
-        assert!(content.contains("StatusCode::FORBIDDEN"));
-        assert!(content.contains("Insufficient permissions"));
+        assert!(
+            content
+                .contains("Err(AppError::forbidden(\"Insufficient permissions\").into_response())")
+        );
+        assert!(content.contains("pub struct RequireRoles(pub &'static [Role]);"));
+        assert!(content.contains(
+            "pub async fn require_roles(\n    State(RequireRoles(roles)): State<RequireRoles>,"
+        ));
     }
 
     #[test]
@@ -800,12 +1251,11 @@ mod tests {
 
         let content = &mw_file.content;
 
-        // Should return structured JSON error responses, not bare status codes
-        assert!(content.contains("json!({"));
-        assert!(content.contains("\"error\""));
-        assert!(content.contains("\"message\""));
-        assert!(content.contains("StatusCode::UNAUTHORIZED"));
-        assert!(content.contains("StatusCode::FORBIDDEN"));
+        // Should answer with AppError's problem details, not ad-hoc bodies
+        assert!(content.contains("use crate::error::AppError;"));
+        assert!(content.contains("    AppError::unauthorized(message).into_response()\n"));
+        assert!(content.contains("AppError::forbidden(\"Insufficient permissions\")"));
+        assert!(!content.contains("json!({"));
     }
 
     #[test]
@@ -829,6 +1279,34 @@ mod tests {
         assert!(content.contains("48"));
     }
 
+    #[test]
+    fn test_jwt_follows_principal_mapping() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt()
+            .with_principal(AuthPrincipal::new("Account").with_identifier("username"))
+            .with_password_hash_cost(10);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_auth(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/auth/jwt.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains(
+            "/// The `Account.username` the user signs in with.\n    pub username: String,"
+        ));
+        assert!(content.contains("        username: impl Into<String>,"));
+        assert!(content.contains("username: username.into(),"));
+        assert!(content.contains("assert_eq!(decoded.username, "));
+        assert!(!content.contains("email"));
+        assert!(
+            content.contains("use crate::constants::{PASSWORD_HASH_COST, TOKEN_EXPIRY_HOURS};")
+        );
+        assert!(content.contains("bcrypt::hash(password, PASSWORD_HASH_COST)"));
+    }
+
     #[test]
     fn test_jwt_file_has_tests() {
         let mut project = ProjectGraph::new("test");
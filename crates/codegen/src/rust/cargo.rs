@@ -4,16 +4,26 @@
 //!
 //! The dependencies included vary based on the project configuration:
 //!
-//! - **Always**: axum, sea-orm, serde, tokio, tracing, uuid, etc.
-//! - **Auth enabled**: jsonwebtoken, axum-extra (typed headers), bcrypt
+//! - **Always**: the web framework (axum + tower-http, or actix-web), the ORM,
+//!   serde, tokio, tracing, uuid, etc.
+//! - **Auth enabled**: jsonwebtoken, bcrypt, and axum-extra (typed headers) on Axum
+//! - **Refresh tokens**: sha2, to store refresh tokens hashed
+//! - **OAuth2 sign-in**: reqwest to call the identity providers, sha2 and
+//!   base64 for PKCE
 //! - **PostgreSQL**: sqlx with `postgres` feature
 //! - **MySQL**: sqlx with `mysql` feature
 //! - **SQLite**: sqlx with `sqlite` feature
 //! - **OpenAPI**: utoipa, utoipa-swagger-ui
-//! - **CORS**: tower-http with `cors` feature
+//! - **Telemetry**: opentelemetry (SDK, OTLP exporter, HTTP propagation), tracing-opentelemetry
+//! - **CORS**: tower-http with `cors` feature, or actix-cors
 //! - **Validator**: validator crate for DTO validation
+//! - **Decimal fields**: rust_decimal (and utoipa's `decimal` feature)
+//! - **GraphQL**: async-graphql plus its Axum or Actix Web integration
+//! - **File uploads**: axum's `multipart` feature (and tower-http `fs` for
+//!   local storage), or actix-multipart (and actix-files); rust-s3 for S3
+//! - **Metrics**: axum-prometheus, or actix-web-prom
 
-use imortal_ir::{AuthStrategy, DatabaseType};
+use imortal_ir::{AuthStrategy, ProjectMeta, UploadStorage, WebFramework};
 
 use crate::context::GenerationContext;
 use crate::{FileType, GeneratedFile};
@@ -35,7 +45,6 @@ pub fn generate_cargo_toml(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 fn build_cargo_toml(ctx: &GenerationContext) -> String {
     let pkg = ctx.package_name();
     let edition = ctx.rust_edition();
-    let db = ctx.database();
     let auth_enabled = ctx.auth_enabled();
     let openapi = ctx.openapi_enabled();
 
@@ -53,17 +62,17 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     // ── [package] ────────────────────────────────────────────────────────
     out.push_str("[package]\n");
     out.push_str(&format!("name = \"{}\"\n", pkg));
-    out.push_str("version = \"0.1.0\"\n");
+    out.push_str(&format!(
+        "version = \"{}\"\n",
+        escape_toml_string(&ctx.meta.version)
+    ));
     out.push_str(&format!("edition = \"{}\"\n", edition));
-
-    if let Some(desc) = &ctx.meta.description {
-        out.push_str(&format!("description = \"{}\"\n", escape_toml_string(desc)));
-    }
-    if let Some(author) = &ctx.meta.author {
-        out.push_str(&format!("authors = [\"{}\"]\n", escape_toml_string(author)));
-    }
-
+    out.push_str(&package_metadata(&ctx.meta));
     out.push_str("publish = false\n");
+    if ctx.generate_seeds() {
+        // `src/bin/seed.rs` makes the server one of two binaries
+        out.push_str(&format!("default-run = \"{}\"\n", pkg));
+    }
     out.push('\n');
 
     // ── [dependencies] ───────────────────────────────────────────────────
@@ -71,18 +80,7 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
 
     // -- Web framework --
     out.push_str("# Web framework\n");
-    out.push_str("axum = { version = \"0.8\", features = [\"macros\"] }\n");
-
-    let mut tower_http_features = vec!["\"trace\""];
-    if ctx.config.cors_enabled {
-        tower_http_features.push("\"cors\"");
-    }
-    tower_http_features.push("\"timeout\"");
-    out.push_str(&format!(
-        "tower-http = {{ version = \"0.6\", features = [{}] }}\n",
-        tower_http_features.join(", ")
-    ));
-    out.push_str("tower = \"0.5\"\n");
+    out.push_str(&ctx.framework_backend().cargo_dependencies(ctx));
     out.push('\n');
 
     // -- Async runtime --
@@ -93,15 +91,7 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     // -- ORM & Database --
     out.push_str("# ORM & Database\n");
 
-    let sea_orm_db_feature = match db {
-        DatabaseType::PostgreSQL => "sqlx-postgres",
-        DatabaseType::MySQL => "sqlx-mysql",
-        DatabaseType::SQLite => "sqlx-sqlite",
-    };
-    out.push_str(&format!(
-        "sea-orm = {{ version = \"1.1\", features = [\"runtime-tokio-rustls\", \"{}\", \"macros\"] }}\n",
-        sea_orm_db_feature
-    ));
+    out.push_str(&ctx.orm_backend().cargo_dependencies(ctx));
     out.push('\n');
 
     // -- Serialization --
@@ -113,6 +103,9 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     // -- Validation --
     out.push_str("# Validation\n");
     out.push_str("validator = { version = \"0.19\", features = [\"derive\"] }\n");
+    if ctx.uses_pattern_validation() {
+        out.push_str("regex = \"1\"\n");
+    }
     out.push('\n');
 
     // -- Identifiers --
@@ -125,6 +118,44 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     out.push_str("chrono = { version = \"0.4\", features = [\"serde\"] }\n");
     out.push('\n');
 
+    // -- Exact decimals (conditional) --
+    if ctx.uses_decimal() {
+        out.push_str("# Exact decimals\n");
+        out.push_str(&ctx.orm_backend().rust_decimal_dependency(ctx));
+        out.push('\n');
+    }
+
+    // -- GraphQL (conditional) --
+    if ctx.graphql_enabled() {
+        let decimal = if ctx.uses_decimal() {
+            ", \"decimal\""
+        } else {
+            ""
+        };
+        out.push_str("# GraphQL\n");
+        out.push_str(&format!(
+            "async-graphql = {{ version = \"7\", features = [\"chrono\", \"uuid\", \"dataloader\"{}] }}\n",
+            decimal
+        ));
+        out.push('\n');
+    }
+
+    // -- Realtime change feed (conditional) --
+    if ctx.realtime_enabled() {
+        out.push_str("# Realtime\n");
+        out.push_str("futures-util = \"0.3\"\n");
+        out.push('\n');
+    }
+
+    // -- S3 upload storage (conditional) --
+    if ctx.uploads_enabled() && ctx.uploads().storage == UploadStorage::S3 {
+        out.push_str("# File uploads\n");
+        out.push_str(
+            "rust-s3 = { version = \"0.35\", default-features = false, features = [\"tokio-rustls-tls\"] }\n",
+        );
+        out.push('\n');
+    }
+
     // -- Error handling --
     out.push_str("# Error handling\n");
     out.push_str("thiserror = \"2\"\n");
@@ -137,6 +168,20 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     out.push_str("tracing-subscriber = { version = \"0.3\", features = [\"env-filter\"] }\n");
     out.push('\n');
 
+    // -- Telemetry (conditional) --
+    if ctx.telemetry() {
+        out.push_str("# Telemetry\n");
+        out.push_str("opentelemetry = \"0.27\"\n");
+        out.push_str("opentelemetry_sdk = { version = \"0.27\", features = [\"rt-tokio\"] }\n");
+        out.push_str("opentelemetry-otlp = { version = \"0.27\", features = [\"grpc-tonic\"] }\n");
+        // Actix's header map is adapted by hand in `middleware.rs`.
+        if ctx.framework() == WebFramework::Axum {
+            out.push_str("opentelemetry-http = \"0.27\"\n");
+        }
+        out.push_str("tracing-opentelemetry = \"0.28\"\n");
+        out.push('\n');
+    }
+
     // -- Configuration --
     out.push_str("# Configuration\n");
     out.push_str("dotenvy = \"0.15\"\n");
@@ -146,27 +191,45 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     if auth_enabled {
         out.push_str("# Authentication\n");
         match ctx.auth_strategy() {
-            AuthStrategy::Jwt | AuthStrategy::ApiKey => {
+            AuthStrategy::Jwt | AuthStrategy::ApiKey | AuthStrategy::OAuth2 => {
                 out.push_str("jsonwebtoken = \"9\"\n");
-                out.push_str(
-                    "axum-extra = { version = \"0.10\", features = [\"typed-header\"] }\n",
-                );
-                out.push_str("bcrypt = \"0.16\"\n");
             }
-            AuthStrategy::Session => {
-                out.push_str("axum-extra = { version = \"0.10\", features = [\"cookie\"] }\n");
-                out.push_str("bcrypt = \"0.16\"\n");
-            }
-            AuthStrategy::None => {}
+            AuthStrategy::Session | AuthStrategy::None => {}
+        }
+        if let Some(dep) = ctx.framework_backend().auth_dependency(ctx.auth_strategy()) {
+            out.push_str(dep);
+        }
+        if ctx.auth_strategy() != AuthStrategy::None {
+            out.push_str("bcrypt = \"0.16\"\n");
+        }
+        if !ctx.oauth_providers().is_empty() {
+            out.push_str("base64 = \"0.22\"\n");
+            out.push_str("reqwest = { version = \"0.12\", features = [\"json\"] }\n");
+        }
+        if ctx.refresh_tokens() || !ctx.oauth_providers().is_empty() {
+            out.push_str("sha2 = \"0.10\"\n");
         }
         out.push('\n');
     }
 
     // -- OpenAPI (conditional) --
     if openapi {
+        let framework = ctx.framework_backend();
         out.push_str("# OpenAPI / Swagger\n");
-        out.push_str("utoipa = { version = \"5\", features = [\"axum_extras\"] }\n");
-        out.push_str("utoipa-swagger-ui = { version = \"8\", features = [\"axum\"] }\n");
+        let decimal = if ctx.uses_decimal() {
+            ", \"decimal\""
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "utoipa = {{ version = \"5\", features = [\"{}\", \"uuid\", \"chrono\"{}] }}\n",
+            framework.utoipa_feature(),
+            decimal
+        ));
+        out.push_str(&format!(
+            "utoipa-swagger-ui = {{ version = \"8\", features = [\"{}\"] }}\n",
+            framework.swagger_ui_feature()
+        ));
         out.push('\n');
     }
 
@@ -181,6 +244,10 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
     }
 
     // ── [profile.release] ────────────────────────────────────────────────
+    // Cargo only reads profiles from the workspace root
+    if ctx.workspace_member() {
+        return out;
+    }
     out.push_str("[profile.release]\n");
     out.push_str("opt-level = 3\n");
     out.push_str("lto = true\n");
@@ -194,6 +261,53 @@ fn build_cargo_toml(ctx: &GenerationContext) -> String {
 // Helpers
 // ============================================================================
 
+/// `rust_decimal` serializes as a string, so amounts survive JSON round trips.
+pub(crate) const RUST_DECIMAL_DEP: &str =
+    "rust_decimal = { version = \"1\", features = [\"serde\"] }\n";
+
+/// The descriptive `[package]` keys set in the project metadata, one per
+/// line. Blank values are left out rather than written as empty strings.
+pub(crate) fn package_metadata(meta: &ProjectMeta) -> String {
+    let mut out = String::new();
+    let quoted_list = |items: &[&str]| {
+        items
+            .iter()
+            .map(|item| format!("\"{}\"", escape_toml_string(item)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    if let Some(desc) = meta.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out.push_str(&format!("description = \"{}\"\n", escape_toml_string(desc)));
+    }
+    let authors = meta.authors();
+    if !authors.is_empty() {
+        out.push_str(&format!("authors = [{}]\n", quoted_list(&authors)));
+    }
+    if let Some(license) = meta.license.as_deref().filter(|l| !l.trim().is_empty()) {
+        out.push_str(&format!(
+            "license = \"{}\"\n",
+            escape_toml_string(license.trim())
+        ));
+    }
+    if let Some(repo) = meta.repository.as_deref().filter(|r| !r.trim().is_empty()) {
+        out.push_str(&format!(
+            "repository = \"{}\"\n",
+            escape_toml_string(repo.trim())
+        ));
+    }
+    let keywords: Vec<&str> = meta
+        .keywords
+        .iter()
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty())
+        .collect();
+    if !keywords.is_empty() {
+        out.push_str(&format!("keywords = [{}]\n", quoted_list(&keywords)));
+    }
+    out
+}
+
 /// Escape a string for TOML double-quoted values.
 fn escape_toml_string(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -210,7 +324,7 @@ fn escape_toml_string(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use imortal_ir::{AuthConfig, AuthStrategy, DatabaseType, ProjectGraph};
+    use imortal_ir::{AuthConfig, AuthStrategy, DatabaseType, OAuthProvider, ProjectGraph};
 
     #[test]
     fn test_generate_cargo_toml_basic() {
@@ -275,6 +389,51 @@ mod tests {
         assert!(content.contains("sqlx-sqlite"));
     }
 
+    #[test]
+    fn test_generate_cargo_toml_decimal() {
+        let mut project = ProjectGraph::new("shop");
+        project.config.openapi_enabled = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(!content.contains("rust_decimal"));
+        assert!(!content.contains("\"decimal\""));
+
+        let mut product = imortal_ir::Entity::new("Product");
+        product.fields.push(imortal_ir::Field::new(
+            "price",
+            imortal_core::DataType::money(),
+        ));
+        project.add_entity(product);
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("rust_decimal = { version = \"1\", features = [\"serde\"] }"));
+        assert!(content.contains("\"chrono\", \"decimal\"]"));
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_regex_for_patterns() {
+        let mut project = ProjectGraph::new("crm");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!generate_cargo_toml(&ctx)[0].content.contains("regex"));
+
+        let mut lead = imortal_ir::Entity::new("Lead");
+        lead.fields.push(
+            imortal_ir::Field::new("code", imortal_core::DataType::String).with_validation(
+                imortal_core::Validation::Pattern {
+                    regex: "^L-\\d+$".to_string(),
+                    message: String::new(),
+                },
+            ),
+        );
+        project.add_entity(lead);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(
+            generate_cargo_toml(&ctx)[0]
+                .content
+                .contains("regex = \"1\"\n")
+        );
+    }
+
     #[test]
     fn test_generate_cargo_toml_with_auth() {
         let mut project = ProjectGraph::new("secure_api");
@@ -287,6 +446,21 @@ mod tests {
         assert!(content.contains("jsonwebtoken"));
         assert!(content.contains("axum-extra"));
         assert!(content.contains("bcrypt"));
+        assert!(!content.contains("sha2"));
+
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("sha2 = \"0.10\""));
+        assert!(!content.contains("base64"));
+
+        project.config.auth = AuthConfig::oauth2().with_oauth_provider(OAuthProvider::google());
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("jsonwebtoken"));
+        assert!(content.contains("base64 = \"0.22\""));
+        assert!(content.contains("reqwest = { version = \"0.12\", features = [\"json\"] }"));
+        assert!(content.contains("sha2 = \"0.10\""));
     }
 
     #[test]
@@ -327,6 +501,25 @@ mod tests {
         assert!(!content.contains("utoipa"));
     }
 
+    #[test]
+    fn test_generate_cargo_toml_with_telemetry() {
+        let mut project = ProjectGraph::new("traced_api");
+        let plain = generate_cargo_toml(&GenerationContext::from_project_default(&project));
+        assert!(!plain[0].content.contains("opentelemetry"));
+
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+
+        assert!(
+            content
+                .contains("opentelemetry_sdk = { version = \"0.27\", features = [\"rt-tokio\"] }")
+        );
+        assert!(content.contains("opentelemetry-otlp"));
+        assert!(content.contains("opentelemetry-http"));
+        assert!(content.contains("tracing-opentelemetry = \"0.28\""));
+    }
+
     #[test]
     fn test_generate_cargo_toml_with_cors() {
         let mut project = ProjectGraph::new("cors_api");
@@ -372,6 +565,13 @@ mod tests {
         assert!(content.contains("[profile.release]"));
         assert!(content.contains("lto = true"));
         assert!(content.contains("strip = true"));
+
+        // A workspace member leaves profiles to the workspace root
+        let config = crate::GeneratorConfig::new().as_workspace_member();
+        let ctx = GenerationContext::from_project(&project, config);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(!content.contains("[profile.release]"));
+        assert!(content.contains("[dependencies]"));
     }
 
     #[test]
@@ -390,6 +590,67 @@ mod tests {
         assert!(!content.contains("jsonwebtoken"));
     }
 
+    #[test]
+    fn test_generate_cargo_toml_graphql() {
+        let mut project = ProjectGraph::new("graph_api");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(!content.contains("async-graphql"));
+
+        project.config.api_style = imortal_ir::ApiStyle::Graphql;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("async-graphql = { version = \"7\""));
+        assert!(content.contains("async-graphql-axum = \"7\""));
+        assert!(content.contains("\"ws\""));
+        // GraphQL-only projects have no REST routes to document
+        assert!(!content.contains("utoipa"));
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_uploads() {
+        use imortal_core::DataType;
+        use imortal_ir::{EndpointGroup, Entity, Field, UploadConfig, WidgetType};
+
+        let mut project = ProjectGraph::new("upload_api");
+        let mut user = Entity::new("User");
+        user.fields
+            .push(Field::new("avatar", DataType::String).with_widget(WidgetType::Image));
+        let user = project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user, "User"));
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(
+            content
+                .contains("axum = { version = \"0.8\", features = [\"macros\", \"multipart\"] }")
+        );
+        assert!(content.contains("\"timeout\", \"fs\"]"));
+        assert!(!content.contains("rust-s3"));
+
+        project.config.uploads = UploadConfig::s3();
+        project.config.framework = WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("actix-multipart = \"0.7\"\n"));
+        assert!(content.contains("futures-util = \"0.3\"\n"));
+        assert!(!content.contains("actix-files"));
+        assert!(content.contains("# File uploads\nrust-s3 = { version = \"0.35\""));
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_seeds_keep_server_default() {
+        let mut project = ProjectGraph::new("seeded_api");
+        project.config.package_name = "seeded_api".to_string();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!generate_cargo_toml(&ctx)[0].content.contains("default-run"));
+
+        let ctx =
+            GenerationContext::from_project(&project, crate::GeneratorConfig::new().with_seeds());
+        let content = &generate_cargo_toml(&ctx)[0].content;
+        assert!(content.contains("default-run = \"seeded_api\"\n"));
+    }
+
     #[test]
     fn test_escape_toml_string() {
         assert_eq!(escape_toml_string("hello"), "hello");
@@ -410,5 +671,25 @@ mod tests {
 
         assert!(content.contains("description = \"A great API\""));
         assert!(content.contains("authors = [\"Jane Doe\"]"));
+        assert!(!content.contains("license ="));
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_with_publishing_metadata() {
+        let mut project = ProjectGraph::new("meta_api");
+        project.meta.version = "2.1.0".to_string();
+        project.meta.author = Some("Jane Doe, John \"JD\" Doe".to_string());
+        project.meta.license = Some("MIT OR Apache-2.0".to_string());
+        project.meta.repository = Some("https://github.com/example/meta_api".to_string());
+        project.meta.keywords = vec!["api".to_string(), " ".to_string(), "rest".to_string()];
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_cargo_toml(&ctx)[0].content;
+
+        assert!(content.contains("version = \"2.1.0\"\n"));
+        assert!(content.contains("authors = [\"Jane Doe\", \"John \\\"JD\\\" Doe\"]\n"));
+        assert!(content.contains("license = \"MIT OR Apache-2.0\"\n"));
+        assert!(content.contains("repository = \"https://github.com/example/meta_api\"\n"));
+        assert!(content.contains("keywords = [\"api\", \"rest\"]\n"));
     }
 }
@@ -0,0 +1,279 @@
+//! # Computed Field Getters
+//!
+//! Computed fields stored as model getters have no column; their value is
+//! calculated from the other fields on every read. This module renders those
+//! getters as an `impl Model` block shared by the SeaORM and Diesel models.
+//!
+//! The getter returns `Option<T>` when the field is nullable. A nullable
+//! operand then makes the whole value `None`, matching SQL, where `NULL`
+//! propagates through both `||` and arithmetic. A non-nullable getter falls
+//! back to the operand's default instead.
+
+use imortal_core::DataType;
+use imortal_ir::{ComputedToken, Entity, Field};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::models::data_type_to_rust;
+
+/// Fields of an entity computed by a model getter, in field order.
+pub(crate) fn getter_fields(entity: &Entity) -> Vec<&Field> {
+    entity
+        .fields
+        .iter()
+        .filter(|f| f.computed.as_ref().is_some_and(|c| !c.is_stored()))
+        .collect()
+}
+
+/// Rust return type of a getter.
+pub(crate) fn getter_type(field: &Field, nullable: bool) -> String {
+    let base = data_type_to_rust(inner_type(&field.data_type));
+    if nullable {
+        format!("Option<{}>", base)
+    } else {
+        base
+    }
+}
+
+/// `impl Model { … }` with one method per getter field; empty when the
+/// entity has none. `nullable` tells whether a field is an `Option` on the
+/// model, which differs between ORMs.
+pub(crate) fn generate_getters(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    nullable: impl Fn(&Field) -> bool,
+) -> String {
+    let getters = getter_fields(info.entity);
+    if getters.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from("impl Model {\n");
+    for (i, field) in getters.iter().enumerate() {
+        let Some(body) = getter_body(field, info.entity, &nullable) else {
+            continue;
+        };
+        let expression = field.computed.as_ref().map(|c| c.expression.as_str());
+
+        if i > 0 {
+            out.push('\n');
+        }
+        if ctx.generate_docs() {
+            match &field.description {
+                Some(desc) => out.push_str(&format!("    /// {}\n", desc)),
+                None => out.push_str(&format!(
+                    "    /// Computed as `{}`.\n",
+                    expression.unwrap_or_default()
+                )),
+            }
+        }
+        out.push_str(&format!(
+            "    pub fn {}(&self) -> {} {{\n",
+            GenerationContext::snake(&field.name),
+            getter_type(field, nullable(field))
+        ));
+        out.push_str(&format!("        {}\n", body));
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n\n");
+    out
+}
+
+/// The getter's expression in Rust, or `None` when the expression does not
+/// parse (validation reports that).
+fn getter_body(
+    field: &Field,
+    entity: &Entity,
+    nullable: &impl Fn(&Field) -> bool,
+) -> Option<String> {
+    let computed = field.computed.as_ref()?;
+    let tokens = computed.tokens().ok()?;
+    let returns_option = nullable(field);
+    let source = |name: &str| entity.fields.iter().find(|f| f.name == name);
+
+    // A single field: a copy of it
+    if let [ComputedToken::Field(name)] = tokens.as_slice() {
+        let from = source(name)?;
+        let mut value = format!("self.{}", GenerationContext::snake(name));
+        if !is_copy(&from.data_type) {
+            value.push_str(".clone()");
+        }
+        return Some(match (nullable(from), returns_option) {
+            (true, false) => format!("{}.unwrap_or_default()", value),
+            (false, true) => format!("Some({})", value),
+            _ => value,
+        });
+    }
+
+    let body = if computed.is_concat() {
+        // Text literals go into the format string, fields become arguments
+        let mut template = String::new();
+        let mut args = Vec::new();
+        for token in &tokens {
+            match token {
+                ComputedToken::Field(name) => {
+                    let ident = GenerationContext::snake(name);
+                    template.push_str("{}");
+                    args.push(
+                        match (source(name).is_some_and(&nullable), returns_option) {
+                            (true, true) => format!("self.{}.as_ref()?", ident),
+                            (true, false) => format!(
+                                "self.{}.as_ref().map(ToString::to_string).unwrap_or_default()",
+                                ident
+                            ),
+                            (false, _) => format!("self.{}", ident),
+                        },
+                    );
+                }
+                ComputedToken::Text(text) => {
+                    template.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                }
+                ComputedToken::Number(number) => template.push_str(number),
+                _ => {}
+            }
+        }
+        format!("format!({:?}, {})", template, args.join(", "))
+    } else {
+        let result = inner_type(&field.data_type);
+        let mut out = String::new();
+        for token in &tokens {
+            let part = match token {
+                ComputedToken::Field(name) => {
+                    let ident = GenerationContext::snake(name);
+                    match (source(name).is_some_and(&nullable), returns_option) {
+                        (true, true) => format!("self.{}?", ident),
+                        (true, false) => format!("self.{}.unwrap_or_default()", ident),
+                        (false, _) => format!("self.{}", ident),
+                    }
+                }
+                ComputedToken::Number(number) => number_literal(number, result),
+                ComputedToken::Op(op) => op.symbol().to_string(),
+                ComputedToken::LParen => "(".to_string(),
+                ComputedToken::RParen => ")".to_string(),
+                ComputedToken::Text(_) => return None,
+            };
+            if !out.is_empty() && !out.ends_with('(') && *token != ComputedToken::RParen {
+                out.push(' ');
+            }
+            out.push_str(&part);
+        }
+        out
+    };
+
+    Some(if returns_option {
+        format!("Some({})", body)
+    } else {
+        body
+    })
+}
+
+/// A number literal typed like the getter's result.
+fn number_literal(number: &str, result: &DataType) -> String {
+    match result {
+        DataType::Float32 | DataType::Float64 if !number.contains('.') => {
+            format!("{}.0", number)
+        }
+        DataType::Decimal { .. } => {
+            let scale = number.split_once('.').map_or(0, |(_, frac)| frac.len());
+            format!("Decimal::new({}, {})", number.replace('.', ""), scale)
+        }
+        _ => number.to_string(),
+    }
+}
+
+fn inner_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Optional(inner) => inner.as_ref(),
+        other => other,
+    }
+}
+
+/// Whether values of the type are `Copy` in generated code.
+pub(crate) fn is_copy(data_type: &DataType) -> bool {
+    matches!(
+        inner_type(data_type),
+        DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal { .. }
+            | DataType::Bool
+            | DataType::Uuid
+            | DataType::DateTime
+            | DataType::Date
+            | DataType::Time
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{ComputedField, ProjectGraph};
+
+    fn render(entity: Entity) -> String {
+        let mut project = ProjectGraph::new("shop");
+        project.add_entity(entity);
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(&ctx.entities()[0], &ctx);
+        generate_getters(&info, &ctx, |f| {
+            matches!(f.data_type, DataType::Optional(_))
+        })
+    }
+
+    #[test]
+    fn test_concat_getter() {
+        let mut entity = Entity::new("Person");
+        entity.add_field(Field::new("first_name", DataType::String));
+        entity.add_field(Field::new(
+            "nickname",
+            DataType::Optional(Box::new(DataType::String)),
+        ));
+        entity.add_field(
+            Field::new("display_name", DataType::String)
+                .with_computed(ComputedField::getter("first_name || ' {' || nickname")),
+        );
+
+        let out = render(entity);
+        assert!(out.contains("pub fn display_name(&self) -> String {"));
+        assert!(out.contains(
+            "format!(\"{} {{{}\", self.first_name, self.nickname.as_ref().map(ToString::to_string).unwrap_or_default())"
+        ));
+    }
+
+    #[test]
+    fn test_arithmetic_getter() {
+        let money = DataType::Decimal {
+            precision: 10,
+            scale: 2,
+        };
+        let mut entity = Entity::new("Line");
+        entity.add_field(Field::new("unit_price", money.clone()));
+        entity.add_field(Field::new(
+            "discount",
+            DataType::Optional(Box::new(money.clone())),
+        ));
+        entity.add_field(
+            Field::new("total", DataType::Optional(Box::new(money)))
+                .with_computed(ComputedField::getter("(unit_price - discount) * 1.5")),
+        );
+        entity.add_field(
+            Field::new(
+                "price_copy",
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+            )
+            .with_computed(ComputedField::generated("unit_price")),
+        );
+
+        let out = render(entity);
+        assert!(out.contains("pub fn total(&self) -> Option<Decimal> {"));
+        assert!(out.contains("Some((self.unit_price - self.discount?) * Decimal::new(15, 1))"));
+        // Generated columns are read from the model like any other column
+        assert!(!out.contains("fn price_copy"));
+    }
+}
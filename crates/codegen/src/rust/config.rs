@@ -18,6 +18,8 @@
 //! }
 //! ```
 
+use imortal_ir::{OAuthProviderKind, UploadStorage};
+
 use crate::context::GenerationContext;
 use crate::rust::file_header;
 use crate::{FileType, GeneratedFile};
@@ -41,6 +43,10 @@ fn build_config(ctx: &GenerationContext) -> String {
     let port = ctx.server_port();
     let auth_enabled = ctx.auth_enabled();
     let expiry_hours = ctx.auth_config().token_expiry_hours;
+    let read_replicas = ctx.read_replicas();
+    let background_jobs = ctx.background_jobs();
+    let scheduled_jobs = crate::rust::jobs::has_scheduled_jobs(ctx);
+    let uploads = ctx.uploads_enabled().then_some(ctx.uploads().storage);
 
     let mut out = String::with_capacity(4096);
 
@@ -50,6 +56,9 @@ fn build_config(ctx: &GenerationContext) -> String {
 
     // ── Imports ──────────────────────────────────────────────────────────
     out.push_str("use std::env;\n\n");
+    if auth_enabled {
+        out.push_str("use crate::constants::TOKEN_EXPIRY_HOURS;\n\n");
+    }
 
     // ── Config struct ────────────────────────────────────────────────────
     out.push_str(
@@ -82,6 +91,17 @@ pub struct Config {
 ",
     );
 
+    if read_replicas {
+        out.push_str(
+            "\
+    /// Read-replica connection URL used by list and get handlers.
+    ///
+    /// Falls back to `database_url` when `DATABASE_READ_URL` is unset.
+    pub database_read_url: String,
+",
+        );
+    }
+
     if auth_enabled {
         out.push_str(
             "\
@@ -97,6 +117,88 @@ pub struct Config {
         );
     }
 
+    let oauth = ctx.oauth_providers();
+    if !oauth.is_empty() {
+        out.push_str(
+            "\
+    // ── OAuth2 sign-in ───────────────────────────────────────────────
+    /// Public base URL of this API, which identity providers redirect back to.
+    pub oauth_redirect_base_url: String,
+",
+        );
+        for provider in oauth {
+            let name = &provider.name;
+            out.push_str(&format!(
+                "\n    /// OAuth2 client credentials registered with {}.\n    \
+                 pub {name}_client_id: String,\n    \
+                 pub {name}_client_secret: String,\n",
+                provider.kind.display_name()
+            ));
+            if provider.kind == OAuthProviderKind::Oidc {
+                out.push_str(&format!(
+                    "\n    /// Issuer whose discovery document names the `{name}` endpoints.\n    \
+                     pub {name}_issuer_url: String,\n"
+                ));
+            }
+        }
+    }
+
+    if background_jobs {
+        out.push_str(
+            "\
+    // ── Background jobs ──────────────────────────────────────────────
+    /// Number of jobs run at the same time.
+    pub job_workers: usize,
+",
+        );
+    }
+
+    if scheduled_jobs {
+        out.push_str(
+            "\
+    /// Seconds between runs of the scheduled jobs.
+    pub job_interval_secs: u64,
+
+    /// Days a soft-deleted row is kept before it is purged.
+    pub soft_delete_retention_days: i64,
+",
+        );
+    }
+
+    match uploads {
+        Some(UploadStorage::Local) => out.push_str(
+            "\
+    // ── File uploads ─────────────────────────────────────────────────
+    /// Directory uploaded files are written to, served under `/uploads`.
+    pub upload_dir: String,
+
+    /// Prefix of the URLs stored for uploaded files.
+    pub upload_base_url: String,
+",
+        ),
+        Some(UploadStorage::S3) => out.push_str(
+            "\
+    // ── File uploads ─────────────────────────────────────────────────
+    /// Bucket uploaded files are put in.
+    pub s3_bucket: String,
+
+    /// Region of the bucket.
+    pub s3_region: String,
+
+    /// S3 API endpoint (AWS, MinIO, R2, ...).
+    pub s3_endpoint: String,
+
+    pub s3_access_key: String,
+
+    pub s3_secret_key: String,
+
+    /// Prefix of the URLs stored for uploaded files.
+    pub s3_public_url: String,
+",
+        ),
+        None => {}
+    }
+
     out.push_str("}\n\n");
 
     // ── Config::from_env ─────────────────────────────────────────────────
@@ -113,7 +215,7 @@ pub struct Config {
     /// ## Required Variables
     ///
     /// - `DATABASE_URL`
-    ///
+{s3_required}    ///
     /// ## Optional Variables (with defaults)
     ///
     /// - `SERVER_HOST` (default: `{host}`)
@@ -121,18 +223,72 @@ pub struct Config {
     /// - `RUST_LOG` (default: `info`)
     /// - `DATABASE_MAX_CONNECTIONS` (default: `10`)
     /// - `DATABASE_MIN_CONNECTIONS` (default: `1`)
-"
+",
+        s3_required = if uploads == Some(UploadStorage::S3) {
+            "    /// - `S3_BUCKET`, `S3_ENDPOINT`, `S3_ACCESS_KEY`, `S3_SECRET_KEY`\n"
+        } else {
+            ""
+        },
     ));
 
+    if read_replicas {
+        out.push_str("    /// - `DATABASE_READ_URL` (default: the value of `DATABASE_URL`)\n");
+    }
+
     if auth_enabled {
         out.push_str(&format!(
             "\
     /// - `JWT_SECRET` (**required** when auth is enabled)
-    /// - `JWT_EXPIRY_HOURS` (default: `{expiry_hours}`)
+    /// - `JWT_EXPIRY_HOURS` (default: `TOKEN_EXPIRY_HOURS`, {expiry_hours})
 ",
         ));
     }
 
+    if !oauth.is_empty() {
+        let credentials: Vec<String> = oauth
+            .iter()
+            .map(|provider| {
+                let prefix = provider.name.to_uppercase();
+                format!("`{prefix}_CLIENT_ID`, `{prefix}_CLIENT_SECRET`")
+            })
+            .collect();
+        out.push_str(&format!(
+            "    /// - {} (**required** for OAuth2 sign-in)\n",
+            credentials.join(", ")
+        ));
+        out.push_str(&format!(
+            "    /// - `OAUTH_REDIRECT_BASE_URL` (default: `http://{host}:{port}`)\n"
+        ));
+        for provider in oauth.iter().filter(|p| p.kind == OAuthProviderKind::Oidc) {
+            out.push_str(&format!(
+                "    /// - `{}_ISSUER_URL` (default: `{}`)\n",
+                provider.name.to_uppercase(),
+                provider.issuer_url.as_deref().unwrap_or_default()
+            ));
+        }
+    }
+
+    if background_jobs {
+        out.push_str("    /// - `JOB_WORKERS` (default: `4`)\n");
+    }
+
+    if scheduled_jobs {
+        out.push_str("    /// - `JOB_INTERVAL_SECS` (default: `3600`)\n");
+        out.push_str("    /// - `SOFT_DELETE_RETENTION_DAYS` (default: `30`)\n");
+    }
+
+    match uploads {
+        Some(UploadStorage::Local) => {
+            out.push_str("    /// - `UPLOAD_DIR` (default: `uploads`)\n");
+            out.push_str("    /// - `UPLOAD_BASE_URL` (default: `/uploads`)\n");
+        }
+        Some(UploadStorage::S3) => {
+            out.push_str("    /// - `S3_REGION` (default: `us-east-1`)\n");
+            out.push_str("    /// - `S3_PUBLIC_URL` (default: `{S3_ENDPOINT}/{S3_BUCKET}`)\n");
+        }
+        None => {}
+    }
+
     out.push_str("    pub fn from_env() -> Self {\n");
 
     out.push_str(&format!(
@@ -164,19 +320,124 @@ pub struct Config {
 "
     ));
 
+    if read_replicas {
+        out.push_str(
+            "\
+        let database_read_url = env::var(\"DATABASE_READ_URL\")
+            .unwrap_or_else(|_| database_url.clone());
+
+",
+        );
+    }
+
     if auth_enabled {
-        out.push_str(&format!(
+        out.push_str(
             "\
         let jwt_secret = env::var(\"JWT_SECRET\")
             .expect(\"JWT_SECRET environment variable is required for authentication\");
 
         let jwt_expiry_hours = env::var(\"JWT_EXPIRY_HOURS\")
-            .unwrap_or_else(|_| \"{expiry_hours}\".to_string())
-            .parse::<u64>()
-            .expect(\"JWT_EXPIRY_HOURS must be a valid u64\");
+            .map(|hours| hours.parse::<u64>().expect(\"JWT_EXPIRY_HOURS must be a valid u64\"))
+            .unwrap_or(TOKEN_EXPIRY_HOURS);
+
+",
+        );
+    }
+
+    if !oauth.is_empty() {
+        out.push_str(&format!(
+            "\
+        let oauth_redirect_base_url = env::var(\"OAUTH_REDIRECT_BASE_URL\")
+            .unwrap_or_else(|_| \"http://{host}:{port}\".to_string());
 
 "
         ));
+        for provider in oauth {
+            let name = &provider.name;
+            let prefix = name.to_uppercase();
+            for (field, var) in [
+                ("client_id", "CLIENT_ID"),
+                ("client_secret", "CLIENT_SECRET"),
+            ] {
+                out.push_str(&format!(
+                    "        let {name}_{field} = env::var(\"{prefix}_{var}\")\n            \
+                     .expect(\"{prefix}_{var} environment variable is required for OAuth2 sign-in\");\n\n"
+                ));
+            }
+            if provider.kind == OAuthProviderKind::Oidc {
+                out.push_str(&format!(
+                    "        let {name}_issuer_url = env::var(\"{prefix}_ISSUER_URL\")\n            \
+                     .unwrap_or_else(|_| \"{}\".to_string());\n\n",
+                    provider.issuer_url.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+    }
+
+    if background_jobs {
+        out.push_str(
+            "\
+        let job_workers = env::var(\"JOB_WORKERS\")
+            .unwrap_or_else(|_| \"4\".to_string())
+            .parse::<usize>()
+            .expect(\"JOB_WORKERS must be a valid usize\");
+
+",
+        );
+    }
+
+    if scheduled_jobs {
+        out.push_str(
+            "\
+        let job_interval_secs = env::var(\"JOB_INTERVAL_SECS\")
+            .unwrap_or_else(|_| \"3600\".to_string())
+            .parse::<u64>()
+            .expect(\"JOB_INTERVAL_SECS must be a valid u64\");
+
+        let soft_delete_retention_days = env::var(\"SOFT_DELETE_RETENTION_DAYS\")
+            .unwrap_or_else(|_| \"30\".to_string())
+            .parse::<i64>()
+            .expect(\"SOFT_DELETE_RETENTION_DAYS must be a valid i64\");
+
+",
+        );
+    }
+
+    match uploads {
+        Some(UploadStorage::Local) => out.push_str(
+            "\
+        let upload_dir = env::var(\"UPLOAD_DIR\")
+            .unwrap_or_else(|_| \"uploads\".to_string());
+
+        let upload_base_url = env::var(\"UPLOAD_BASE_URL\")
+            .unwrap_or_else(|_| \"/uploads\".to_string());
+
+",
+        ),
+        Some(UploadStorage::S3) => out.push_str(
+            "\
+        let s3_bucket = env::var(\"S3_BUCKET\")
+            .expect(\"S3_BUCKET environment variable is required for uploads\");
+
+        let s3_region = env::var(\"S3_REGION\")
+            .unwrap_or_else(|_| \"us-east-1\".to_string());
+
+        let s3_endpoint = env::var(\"S3_ENDPOINT\")
+            .expect(\"S3_ENDPOINT environment variable is required for uploads\");
+
+        let s3_access_key = env::var(\"S3_ACCESS_KEY\")
+            .expect(\"S3_ACCESS_KEY environment variable is required for uploads\");
+
+        let s3_secret_key = env::var(\"S3_SECRET_KEY\")
+            .expect(\"S3_SECRET_KEY environment variable is required for uploads\");
+
+        let s3_public_url = env::var(\"S3_PUBLIC_URL\").unwrap_or_else(|_| {
+            format!(\"{}/{}\", s3_endpoint.trim_end_matches('/'), s3_bucket)
+        });
+
+",
+        ),
+        None => {}
     }
 
     // Construct Self
@@ -188,11 +449,55 @@ pub struct Config {
     out.push_str("            database_max_connections,\n");
     out.push_str("            database_min_connections,\n");
 
+    if read_replicas {
+        out.push_str("            database_read_url,\n");
+    }
+
     if auth_enabled {
         out.push_str("            jwt_secret,\n");
         out.push_str("            jwt_expiry_hours,\n");
     }
 
+    if !oauth.is_empty() {
+        out.push_str("            oauth_redirect_base_url,\n");
+        for provider in oauth {
+            out.push_str(&format!("            {}_client_id,\n", provider.name));
+            out.push_str(&format!("            {}_client_secret,\n", provider.name));
+            if provider.kind == OAuthProviderKind::Oidc {
+                out.push_str(&format!("            {}_issuer_url,\n", provider.name));
+            }
+        }
+    }
+
+    if background_jobs {
+        out.push_str("            job_workers,\n");
+    }
+
+    if scheduled_jobs {
+        out.push_str("            job_interval_secs,\n");
+        out.push_str("            soft_delete_retention_days,\n");
+    }
+
+    match uploads {
+        Some(UploadStorage::Local) => {
+            out.push_str("            upload_dir,\n");
+            out.push_str("            upload_base_url,\n");
+        }
+        Some(UploadStorage::S3) => {
+            for field in [
+                "s3_bucket",
+                "s3_region",
+                "s3_endpoint",
+                "s3_access_key",
+                "s3_secret_key",
+                "s3_public_url",
+            ] {
+                out.push_str(&format!("            {},\n", field));
+            }
+        }
+        None => {}
+    }
+
     out.push_str("        }\n");
     out.push_str("    }\n\n");
 
@@ -206,20 +511,8 @@ pub struct Config {
 ",
     );
 
-    // ── database_connect_options helper ──────────────────────────────────
-    out.push_str(
-        "\
-\n    /// Build SeaORM `ConnectOptions` from the loaded configuration.
-    pub fn database_connect_options(&self) -> sea_orm::ConnectOptions {
-        let mut opt = sea_orm::ConnectOptions::new(&self.database_url);
-        opt.max_connections(self.database_max_connections)
-            .min_connections(self.database_min_connections)
-            .sqlx_logging(true)
-            .sqlx_logging_level(tracing::log::LevelFilter::Debug);
-        opt
-    }
-",
-    );
+    // ── Connection pool helpers ──────────────────────────────────────────
+    out.push_str(&ctx.orm_backend().config_methods(ctx));
 
     out.push_str("}\n\n");
 
@@ -247,7 +540,7 @@ impl Default for Config {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use imortal_ir::{AuthConfig, ProjectGraph};
+    use imortal_ir::{AuthConfig, OAuthProvider, ProjectGraph};
 
     #[test]
     fn test_generate_config_produces_one_file() {
@@ -333,6 +626,98 @@ mod tests {
         assert!(!content.contains("jwt_expiry_hours"));
     }
 
+    #[test]
+    fn test_config_with_read_replicas() {
+        let mut project = ProjectGraph::new("test");
+        let files = generate_config(&GenerationContext::from_project_default(&project));
+        assert!(!files[0].content.contains("DATABASE_READ_URL"));
+
+        project.config.read_replicas = true;
+        let files = generate_config(&GenerationContext::from_project_default(&project));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub database_read_url: String"));
+        assert!(content.contains("env::var(\"DATABASE_READ_URL\")"));
+        assert!(content.contains(".unwrap_or_else(|_| database_url.clone())"));
+        assert!(content.contains("database_read_url,"));
+        assert!(content.contains("pub fn database_read_connect_options("));
+    }
+
+    #[test]
+    fn test_config_with_background_jobs() {
+        let mut project = ProjectGraph::new("test");
+        project.config.background_jobs = true;
+        let files = generate_config(&GenerationContext::from_project_default(&project));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub job_workers: usize"));
+        assert!(content.contains("env::var(\"JOB_WORKERS\")"));
+        assert!(!content.contains("job_interval_secs"));
+
+        let mut post = imortal_ir::Entity::new("Post");
+        post.config.soft_delete = true;
+        project.add_entity(post);
+        let files = generate_config(&GenerationContext::from_project_default(&project));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub job_interval_secs: u64"));
+        assert!(content.contains("pub soft_delete_retention_days: i64"));
+        assert!(content.contains(".parse::<i64>()"));
+        assert!(content.contains("            soft_delete_retention_days,\n"));
+    }
+
+    #[test]
+    fn test_config_with_uploads() {
+        use imortal_core::DataType;
+        use imortal_ir::{EndpointGroup, Entity, Field, UploadConfig, WidgetType};
+
+        let mut project = ProjectGraph::new("test");
+        let mut user = Entity::new("User");
+        user.fields
+            .push(Field::new("resume", DataType::String).with_widget(WidgetType::File));
+        let user = project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user, "User"));
+
+        let content =
+            &generate_config(&GenerationContext::from_project_default(&project))[0].content;
+        assert!(content.contains("pub upload_dir: String"));
+        assert!(content.contains("env::var(\"UPLOAD_BASE_URL\")"));
+        assert!(content.contains("            upload_base_url,\n"));
+        assert!(!content.contains("s3_bucket"));
+
+        project.config.uploads = UploadConfig::s3();
+        let content =
+            &generate_config(&GenerationContext::from_project_default(&project))[0].content;
+        assert!(
+            content.contains(
+                "    /// - `S3_BUCKET`, `S3_ENDPOINT`, `S3_ACCESS_KEY`, `S3_SECRET_KEY`\n"
+            )
+        );
+        assert!(content.contains("pub s3_public_url: String"));
+        assert!(content.contains("            s3_secret_key,\n"));
+        assert!(!content.contains("upload_dir"));
+    }
+
+    #[test]
+    fn test_config_with_oauth_providers() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::github())
+            .with_oauth_provider(OAuthProvider::oidc("okta", "https://example.okta.com"));
+        let content =
+            &generate_config(&GenerationContext::from_project_default(&project))[0].content;
+
+        assert!(content.contains("pub oauth_redirect_base_url: String,"));
+        assert!(content.contains("pub github_client_secret: String,"));
+        assert!(content.contains(
+            "let github_client_id = env::var(\"GITHUB_CLIENT_ID\")\n            .expect(\"GITHUB_CLIENT_ID environment variable is required for OAuth2 sign-in\");"
+        ));
+        assert!(content.contains("pub okta_issuer_url: String,"));
+        assert!(content.contains(".unwrap_or_else(|_| \"https://example.okta.com\".to_string());"));
+        assert!(content.contains("            okta_issuer_url,\n"));
+        assert!(!content.contains("github_issuer_url"));
+    }
+
     #[test]
     fn test_config_uses_project_host_port() {
         let mut project = ProjectGraph::new("test");
@@ -0,0 +1,495 @@
+//! # Constants Generator
+//!
+//! Generates `src/constants.rs`, one place for the values a user is most
+//! likely to tune after generation. Every other generated file refers to
+//! these constants instead of repeating the literal:
+//!
+//! | Constant | Emitted | Used by |
+//! |----------|---------|---------|
+//! | `REQUEST_TIMEOUT_SECS` | with Axum | the router's timeout layer |
+//! | `{ENTITY}_PATH` | per entity with REST routes | the router, to mount the entity's routes |
+//! | `{ENTITY}_DEFAULT_PAGE_SIZE`, `{ENTITY}_MAX_PAGE_SIZE` | per entity with a list operation | REST list handlers and GraphQL list queries |
+//! | `TOKEN_EXPIRY_HOURS`, `PASSWORD_HASH_COST` | with auth | `config.rs` (when `JWT_EXPIRY_HOURS` is unset) and `auth/jwt.rs` |
+//! | `AUTH_PATH` | with refresh tokens or OAuth2 | the router, to mount the session and sign-in routes |
+//! | `REFRESH_TOKEN_EXPIRY_DAYS` | with refresh tokens | `auth/sessions.rs` |
+//! | `OAUTH_STATE_TTL_SECS` | with OAuth2 | `auth/oauth.rs` |
+//! | `{ROUTE}_RATE_LIMIT`, `{ROUTE}_RATE_WINDOW_SECS` | per rate limited route | the route's limiter in `rate_limit.rs` |
+//! | `CORS_ALLOWED_ORIGINS`, `CORS_ALLOWED_METHODS`, `CORS_ALLOW_CREDENTIALS` | with CORS | the CORS layer; empty lists allow any origin or method |
+//! | `CORS_ROUTE_ORIGINS` | with CORS, when an endpoint group lists its own origins | the CORS layer, for the group's routes |
+//!
+//! Values come from the IR: page sizes from each list operation's
+//! [`ListOptions`](imortal_ir::ListOptions), auth values from
+//! [`AuthConfig`](imortal_ir::AuthConfig), quotas from each route's
+//! [`RateLimit`](imortal_ir::RateLimit) or the project default.
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::auth::AUTH_PATH;
+use crate::rust::rate_limit::{quota_consts, route_limits};
+use crate::rust::routes::has_enabled_handlers;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+use imortal_ir::{OperationType, WebFramework};
+
+/// Seconds a request may run before the router answers `408`.
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Seconds an OAuth2 sign-in may take from authorize to callback.
+const OAUTH_STATE_TTL_SECS: i64 = 600;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/constants.rs`.
+pub fn generate_constants(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    vec![GeneratedFile::new(
+        "src/constants.rs",
+        build_constants(ctx),
+        FileType::Rust,
+    )]
+}
+
+/// Name of the constant holding the path an entity's routes are mounted at.
+pub(crate) fn path_const(info: &EntityInfo) -> String {
+    format!("{}_PATH", info.snake_name().to_uppercase())
+}
+
+/// The routes allowing origins of their own instead of the project's, as
+/// the constant holding their mount path and the origins: each endpoint
+/// group with CORS enabled whose origins differ from the project's.
+pub(crate) fn cors_route_origins(ctx: &GenerationContext) -> Vec<(String, &[String])> {
+    if !ctx.config.cors_enabled || !ctx.rest_enabled() {
+        return Vec::new();
+    }
+    ctx.entities()
+        .iter()
+        .map(|entity| EntityInfo::new(entity, ctx))
+        .filter(has_enabled_handlers)
+        .filter_map(|info| {
+            let security = &info.endpoint()?.global_security;
+            let own = security.cors_enabled
+                && !security.cors_origins.is_empty()
+                && security.cors_origins != ctx.config.cors.allowed_origins;
+            own.then(|| (path_const(&info), security.cors_origins.as_slice()))
+        })
+        .collect()
+}
+
+/// Names of an entity's default and maximum page size constants.
+pub(crate) fn page_size_consts(info: &EntityInfo) -> (String, String) {
+    let prefix = info.snake_name().to_uppercase();
+    (
+        format!("{prefix}_DEFAULT_PAGE_SIZE"),
+        format!("{prefix}_MAX_PAGE_SIZE"),
+    )
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_constants(ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(2048);
+
+    out.push_str(&file_header(
+        "Tunable constants — timeouts, route paths, page sizes and auth defaults.",
+    ));
+
+    // Actix has no per-request timeout middleware to feed
+    if ctx.framework() == WebFramework::Axum {
+        out.push_str(&doc_comment(
+            Some("Seconds a request may run before it is answered with `408 Request Timeout`."),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const REQUEST_TIMEOUT_SECS: u64 = {REQUEST_TIMEOUT_SECS};\n"
+        ));
+    }
+
+    let entities: Vec<EntityInfo> = ctx
+        .entities()
+        .iter()
+        .map(|entity| EntityInfo::new(entity, ctx))
+        .collect();
+
+    let routed: Vec<&EntityInfo> = entities
+        .iter()
+        .filter(|info| ctx.rest_enabled() && has_enabled_handlers(info))
+        .collect();
+    if !routed.is_empty() {
+        out.push_str(&section("Route paths"));
+        for info in routed {
+            out.push_str(&doc_comment(
+                Some(&format!(
+                    "Path the `{}` routes are mounted at.",
+                    info.pascal_name()
+                )),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const {}: &str = \"{}\";\n",
+                path_const(info),
+                mount_path(info, ctx)
+            ));
+        }
+    }
+
+    let listed: Vec<&EntityInfo> = entities.iter().filter(|info| lists(info)).collect();
+    if !listed.is_empty() {
+        out.push_str(&section("Pagination"));
+        for info in listed {
+            let options = crate::rust::handlers::list_options(info);
+            let (default_const, max_const) = page_size_consts(info);
+            out.push_str(&doc_comment(
+                Some(&format!(
+                    "Page size of a `{}` list when the request names none.",
+                    info.pascal_name()
+                )),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const {default_const}: u64 = {};\n",
+                options.default_page_size
+            ));
+            out.push_str(&doc_comment(
+                Some(&format!(
+                    "Largest page of `{}`s a list request can ask for.",
+                    info.pascal_name()
+                )),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const {max_const}: u64 = {};\n",
+                options.max_page_size
+            ));
+        }
+    }
+
+    if ctx.auth_enabled() {
+        let auth = ctx.auth_config();
+        out.push_str(&section("Authentication"));
+        out.push_str(&doc_comment(
+            Some("Token lifetime in hours when `JWT_EXPIRY_HOURS` is unset."),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const TOKEN_EXPIRY_HOURS: u64 = {};\n",
+            auth.token_expiry_hours
+        ));
+        out.push_str(&doc_comment(
+            Some("bcrypt cost factor; each step doubles the work per hash."),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const PASSWORD_HASH_COST: u32 = {};\n",
+            auth.password_hash_cost
+        ));
+        if ctx.auth_routes() {
+            out.push_str(&doc_comment(
+                Some("Path the session and sign-in routes are mounted at."),
+                ctx,
+            ));
+            out.push_str(&format!("pub const AUTH_PATH: &str = \"{AUTH_PATH}\";\n"));
+        }
+        if ctx.refresh_tokens() {
+            out.push_str(&doc_comment(
+                Some("Days a refresh token stays valid; every refresh issues a new one."),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const REFRESH_TOKEN_EXPIRY_DAYS: i64 = {};\n",
+                auth.refresh_token_expiry_days
+            ));
+        }
+        if !ctx.oauth_providers().is_empty() {
+            out.push_str(&doc_comment(
+                Some("Seconds a user has to finish signing in with an identity provider."),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const OAUTH_STATE_TTL_SECS: i64 = {OAUTH_STATE_TTL_SECS};\n"
+            ));
+        }
+    }
+
+    let limits = route_limits(ctx);
+    if !limits.is_empty() {
+        out.push_str(&section("Rate limits"));
+        for route in limits {
+            let (requests_const, window_const) = quota_consts(&route.name);
+            out.push_str(&doc_comment(
+                Some(&format!(
+                    "Requests a client may make to {} per window.",
+                    route.serves
+                )),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const {requests_const}: u32 = {};\n",
+                route.limit.requests
+            ));
+            out.push_str(&doc_comment(
+                Some(&format!(
+                    "Length of a {} rate limit window in seconds.",
+                    route.serves
+                )),
+                ctx,
+            ));
+            out.push_str(&format!(
+                "pub const {window_const}: u64 = {};\n",
+                route.limit.window_seconds
+            ));
+        }
+    }
+
+    if ctx.config.cors_enabled {
+        let cors = &ctx.config.cors;
+        out.push_str(&section("CORS"));
+        out.push_str(&doc_comment(
+            Some(
+                "Origins allowed to call the API from a browser, e.g. `\"https://app.example.com\"`.\n\n\
+                 Empty allows any origin.",
+            ),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const CORS_ALLOWED_ORIGINS: &[&str] = {};\n",
+            str_slice(&cors.allowed_origins)
+        ));
+        out.push_str(&doc_comment(
+            Some("Methods cross-origin requests may use, e.g. `\"GET\"`. Empty allows any method."),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const CORS_ALLOWED_METHODS: &[&str] = {};\n",
+            str_slice(&cors.allowed_methods)
+        ));
+        out.push_str(&doc_comment(
+            Some(
+                "Whether browsers may send cookies and `Authorization` headers cross-origin.\n\n\
+                 Needs `CORS_ALLOWED_ORIGINS` to name the origins.",
+            ),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "pub const CORS_ALLOW_CREDENTIALS: bool = {};\n",
+            cors.allow_credentials
+        ));
+
+        let routes = cors_route_origins(ctx);
+        if !routes.is_empty() {
+            out.push_str(&doc_comment(
+                Some(
+                    "Routes allowing origins of their own instead of `CORS_ALLOWED_ORIGINS`,\n\
+                     by the path they are mounted at.",
+                ),
+                ctx,
+            ));
+            out.push_str("pub const CORS_ROUTE_ORIGINS: &[(&str, &[&str])] = &[\n");
+            for (path, origins) in routes {
+                out.push_str(&format!("    ({path}, {}),\n", str_slice(origins)));
+            }
+            out.push_str("];\n");
+        }
+    }
+
+    out
+}
+
+/// `strings` as a `&[&str]` literal.
+fn str_slice(strings: &[String]) -> String {
+    let items: Vec<String> = strings.iter().map(|s| format!("{s:?}")).collect();
+    format!("&[{}]", items.join(", "))
+}
+
+fn section(title: &str) -> String {
+    format!(
+        "\n// ============================================================================\n\
+         // {title}\n\
+         // ============================================================================\n\n"
+    )
+}
+
+/// Whether the entity has a list operation, REST or GraphQL.
+fn lists(info: &EntityInfo) -> bool {
+    info.endpoint()
+        .and_then(|ep| ep.get_operation(OperationType::ReadAll))
+        .is_some_and(|op| op.enabled)
+}
+
+/// The path the router mounts the entity's routes at. Actix mounts every
+/// version of a header-versioned API at the public path and picks one with
+/// a guard; otherwise the version, if any, is part of the path.
+fn mount_path(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let header_versioned =
+        ctx.api_versioning().header_name().is_some() && info.api_version().is_some();
+    if ctx.framework() == WebFramework::ActixWeb && header_versioned {
+        info.base_path()
+    } else {
+        info.route_path()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{
+        ApiVersioning, AuthConfig, CorsConfig, CrudOperation, DataType, EndpointGroup,
+        EndpointSecurity, Entity, Field, ListOptions, OAuthProvider, ProjectGraph,
+    };
+
+    fn project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        let mut user = Entity::new("User");
+        user.add_field(Field::new("email", DataType::String));
+        let user_id = user.id;
+        project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        project
+    }
+
+    fn constants(project: &ProjectGraph) -> String {
+        let ctx = GenerationContext::from_project_default(project);
+        generate_constants(&ctx).remove(0).content
+    }
+
+    #[test]
+    fn test_constants_file() {
+        let project = project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_constants(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str().unwrap(), "src/constants.rs");
+        assert!(
+            files[0]
+                .content
+                .contains("pub const REQUEST_TIMEOUT_SECS: u64 = 30;")
+        );
+    }
+
+    #[test]
+    fn test_route_path_and_page_sizes() {
+        let mut project = project();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.operations = vec![
+            CrudOperation::new(OperationType::ReadAll)
+                .with_list_options(ListOptions::default().with_page_sizes(25, 50)),
+        ];
+        let content = constants(&project);
+        assert!(content.contains("pub const USER_PATH: &str = \"/api/users\";"));
+        assert!(content.contains("pub const USER_DEFAULT_PAGE_SIZE: u64 = 25;"));
+        assert!(content.contains("pub const USER_MAX_PAGE_SIZE: u64 = 50;"));
+    }
+
+    #[test]
+    fn test_no_page_sizes_without_list() {
+        let mut project = project();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.operations = vec![CrudOperation::new(OperationType::Read)];
+        let content = constants(&project);
+        assert!(content.contains("USER_PATH"));
+        assert!(!content.contains("PAGE_SIZE"));
+    }
+
+    #[test]
+    fn test_auth_and_cors_constants() {
+        let mut project = project();
+        project.config.auth = AuthConfig::default().with_password_hash_cost(12);
+        project.config.auth.token_expiry_hours = 48;
+        project.config.cors_enabled = true;
+        let content = constants(&project);
+        assert!(content.contains("pub const TOKEN_EXPIRY_HOURS: u64 = 48;"));
+        assert!(content.contains("pub const PASSWORD_HASH_COST: u32 = 12;"));
+        assert!(content.contains("pub const CORS_ALLOWED_ORIGINS: &[&str] = &[];"));
+        assert!(!content.contains("REFRESH_TOKEN_EXPIRY_DAYS"));
+
+        project.config.auth.refresh_tokens = true;
+        project.config.auth.refresh_token_expiry_days = 14;
+        let content = constants(&project);
+        assert!(content.contains("pub const AUTH_PATH: &str = \"/api/auth\";"));
+        assert!(content.contains("pub const REFRESH_TOKEN_EXPIRY_DAYS: i64 = 14;"));
+        assert!(!content.contains("OAUTH_STATE_TTL_SECS"));
+
+        project.config.auth = AuthConfig::oauth2().with_oauth_provider(OAuthProvider::github());
+        let content = constants(&project);
+        assert!(content.contains("pub const AUTH_PATH: &str = \"/api/auth\";"));
+        assert!(content.contains("pub const OAUTH_STATE_TTL_SECS: i64 = 600;"));
+        assert!(!content.contains("REFRESH_TOKEN_EXPIRY_DAYS"));
+
+        project.config.auth.enabled = false;
+        project.config.cors_enabled = false;
+        let content = constants(&project);
+        assert!(!content.contains("TOKEN_EXPIRY_HOURS"));
+        assert!(!content.contains("CORS_ALLOWED_ORIGINS"));
+    }
+
+    #[test]
+    fn test_cors_policy_constants() {
+        let mut project = project();
+        project.config.cors = CorsConfig::default()
+            .with_origin("https://app.example.com")
+            .with_methods(&["GET", "POST"])
+            .with_credentials();
+        let content = constants(&project);
+        assert!(
+            content.contains(
+                "pub const CORS_ALLOWED_ORIGINS: &[&str] = &[\"https://app.example.com\"];"
+            )
+        );
+        assert!(
+            content.contains("pub const CORS_ALLOWED_METHODS: &[&str] = &[\"GET\", \"POST\"];")
+        );
+        assert!(content.contains("pub const CORS_ALLOW_CREDENTIALS: bool = true;"));
+        assert!(!content.contains("CORS_ROUTE_ORIGINS"));
+
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security = EndpointSecurity::open()
+            .add_cors_origin("https://admin.example.com")
+            .add_cors_origin("http://localhost:3000");
+        let content = constants(&project);
+        assert!(content.contains(
+            "pub const CORS_ROUTE_ORIGINS: &[(&str, &[&str])] = &[\n    (USER_PATH, &[\"https://admin.example.com\", \"http://localhost:3000\"]),\n];"
+        ));
+
+        // Origins matching the project's need no override
+        project.config.cors.allowed_origins = vec![
+            "https://admin.example.com".into(),
+            "http://localhost:3000".into(),
+        ];
+        assert!(!constants(&project).contains("CORS_ROUTE_ORIGINS"));
+    }
+
+    #[test]
+    fn test_rate_limit_constants() {
+        let mut project = project();
+        assert!(!constants(&project).contains("Rate limits"));
+
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.operations = vec![
+            CrudOperation::new(OperationType::Create).with_rate_limit(5, 300),
+            CrudOperation::new(OperationType::Read),
+        ];
+        let content = constants(&project);
+        assert!(content.contains(
+            "/// Requests a client may make to Create `User` per window.\npub const USER_CREATE_RATE_LIMIT: u32 = 5;"
+        ));
+        assert!(content.contains("pub const USER_CREATE_RATE_WINDOW_SECS: u64 = 300;"));
+        assert!(!content.contains("USER_READ_RATE_LIMIT"));
+    }
+
+    #[test]
+    fn test_actix_header_versioning_mounts_public_path() {
+        let mut project = project();
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let axum = constants(&project);
+        assert!(axum.contains("pub const USER_PATH: &str = \"/api/v1/users\";"));
+
+        project.config.framework = WebFramework::ActixWeb;
+        let actix = constants(&project);
+        assert!(actix.contains("pub const USER_PATH: &str = \"/api/users\";"));
+    }
+}
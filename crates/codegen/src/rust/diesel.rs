@@ -0,0 +1,2446 @@
+//! # Diesel Backend
+//!
+//! [`OrmBackend`] implementation generating a Diesel data layer:
+//!
+//! - `src/schema.rs` — `diesel::table!` definitions mirroring the
+//!   migrations, `joinable!` for each foreign key
+//! - `src/models/{entity}.rs` — a `Queryable`/`Selectable` model, the
+//!   `Insertable` and `AsChangeset` rows the handlers write, and the DTOs
+//! - `src/handlers/{entity}.rs` — CRUD handlers
+//! - `migrations/{version}_create_{table}/up.sql` + `down.sql`, the layout
+//!   `diesel migration run` expects
+//! - `diesel.toml` pointing the Diesel CLI at `src/schema.rs`
+//!
+//! Diesel is synchronous: handlers pass their query to `state::run`, which
+//! checks a connection out of the r2d2 pool on Tokio's blocking thread pool.
+//!
+//! Custom queries, nested create, related aggregates, list sorting/filtering
+//! and delete blocker reports are not generated for Diesel (see
+//! [`OrmFeature`]); the generator warns when a project uses them.
+
+use imortal_core::DataType;
+use imortal_ir::{DatabaseType, EnumDef, Field, OperationType, Orm, PaginationStyle};
+use uuid::Uuid;
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::migrations::SchemaMigration;
+use crate::migrations::sql::{
+    ENUM_TYPES_MIGRATION, TableDef, auth_tables, create_enum_type_statements,
+    create_table_statements, database_display_name, drop_enum_type_statements, quote_identifier,
+};
+use crate::rust::constants::page_size_consts;
+use crate::rust::enums::{self, EnumMapping};
+use crate::rust::framework::Extractor;
+use crate::rust::handlers::{
+    ReadResponse, delete_effects_doc, delete_openapi_attr, generate_handlers_mod,
+    generate_live_handler, handler_span, http_cache_import, list_default_sort, publish_line,
+    realtime_import, restores, store_upload, upload_handler_doc, upload_handler_name,
+};
+use crate::rust::http_cache::{model_version, model_versions};
+use crate::rust::models::{
+    data_type_to_rust, dto_field_name, field_rust_type, generate_create_dto, generate_update_dto,
+    generate_validation_helpers, list_response_type,
+};
+use crate::rust::orm::{OrmBackend, OrmFeature};
+use crate::rust::{computed, doc_comment, file_header};
+use crate::{FileType, GeneratedFile, migrations};
+
+// ============================================================================
+// Backend
+// ============================================================================
+
+/// Diesel: `table!` schema, derive-based models and an r2d2 pool.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DieselBackend;
+
+impl OrmBackend for DieselBackend {
+    fn orm(&self) -> Orm {
+        Orm::Diesel
+    }
+
+    fn supports(&self, feature: OrmFeature) -> bool {
+        match feature {
+            OrmFeature::CustomQueries
+            | OrmFeature::NestedCreate
+            | OrmFeature::RelatedAggregates
+            | OrmFeature::ListQueryParams
+            | OrmFeature::DeleteBlockers
+            | OrmFeature::GraphQl
+            | OrmFeature::Associations
+            | OrmFeature::Polymorphic
+            | OrmFeature::BackgroundJobs
+            | OrmFeature::ScopedUniqueChecks => false,
+        }
+    }
+
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String {
+        let backend_features = match ctx.database() {
+            DatabaseType::PostgreSQL => "\"postgres\"",
+            DatabaseType::MySQL => "\"mysql\"",
+            DatabaseType::SQLite => "\"sqlite\", \"returning_clauses_for_sqlite_3_35\"",
+        };
+        let mut out = format!(
+            "diesel = {{ version = \"2.2\", features = [{}, \"r2d2\", \"chrono\", \"uuid\", \"serde_json\"] }}\n",
+            backend_features
+        );
+        if ctx.database() == DatabaseType::SQLite {
+            // Build SQLite from source so no system library is needed
+            out.push_str("libsqlite3-sys = { version = \"0.30\", features = [\"bundled\"] }\n");
+        }
+        out
+    }
+
+    fn rust_decimal_dependency(&self, ctx: &GenerationContext) -> String {
+        let feature = match ctx.database() {
+            DatabaseType::PostgreSQL => ", \"db-diesel2-postgres\"",
+            DatabaseType::MySQL => ", \"db-diesel2-mysql\"",
+            DatabaseType::SQLite => "",
+        };
+        format!(
+            "rust_decimal = {{ version = \"1\", features = [\"serde\"{}] }}\n",
+            feature
+        )
+    }
+
+    fn lib_modules(&self) -> &'static [&'static str] {
+        &["pub mod schema;"]
+    }
+
+    fn generate_state(&self, ctx: &GenerationContext) -> GeneratedFile {
+        GeneratedFile::new("src/state.rs", build_state(ctx), FileType::Rust)
+    }
+
+    fn config_methods(&self, ctx: &GenerationContext) -> String {
+        let mut out = String::from(
+            "\
+\n    /// Build the Diesel connection pool for the primary database.
+    pub fn database_pool(&self) -> Result<crate::state::DbPool, diesel::r2d2::PoolError> {
+        diesel::r2d2::Pool::builder()
+            .max_size(self.database_max_connections)
+            .min_idle(Some(self.database_min_connections))
+            .build(diesel::r2d2::ConnectionManager::new(&self.database_url))
+    }
+",
+        );
+
+        if ctx.read_replicas() {
+            out.push_str(
+                "\
+\n    /// Build the Diesel connection pool for the read replica.
+    pub fn database_read_pool(&self) -> Result<crate::state::DbPool, diesel::r2d2::PoolError> {
+        diesel::r2d2::Pool::builder()
+            .max_size(self.database_max_connections)
+            .min_idle(Some(self.database_min_connections))
+            .build(diesel::r2d2::ConnectionManager::new(&self.database_read_url))
+    }
+",
+            );
+        }
+
+        out
+    }
+
+    fn main_imports(&self) -> &'static str {
+        ""
+    }
+
+    fn connect_expr(&self, read_replica: bool) -> &'static str {
+        if read_replica {
+            "config.database_read_pool()"
+        } else {
+            "config.database_pool()"
+        }
+    }
+
+    fn test_connect_expr(&self) -> &'static str {
+        "config.database_pool()"
+    }
+
+    fn log_filter(&self) -> &'static str {
+        "info,tower_http=debug"
+    }
+
+    fn database_check(&self, read_replica: bool) -> String {
+        let replica = if read_replica {
+            "    run(&state.db_read, ping).await?;\n"
+        } else {
+            ""
+        };
+        format!(
+            "\
+/// Ping the database; the instance is not ready while it is unreachable.
+async fn check_database(state: &AppState) -> Result<(), AppError> {{
+    use crate::state::run;
+
+    run(&state.db, ping).await?;
+{replica}    Ok(())
+}}
+
+fn ping(conn: &mut crate::state::DbConnection) -> Result<(), AppError> {{
+    use diesel::RunQueryDsl;
+
+    diesel::sql_query(\"SELECT 1\").execute(conn)?;
+    Ok(())
+}}
+"
+        )
+    }
+
+    fn store_imports(&self) -> &'static str {
+        "use diesel::sql_types::{BigInt, Nullable, Text};\nuse diesel::{OptionalExtension, QueryableByName, RunQueryDsl};\n"
+    }
+
+    fn session_store(&self) -> &'static str {
+        "\
+async fn insert_token(
+    state: &AppState,
+    token_hash: String,
+    token: RefreshToken,
+    now: i64,
+) -> Result<(), AppError> {
+    run(&state.db, move |conn| {
+        diesel::sql_query(INSERT_SQL)
+            .bind::<Text, _>(token_hash)
+            .bind::<Text, _>(token.session_id)
+            .bind::<Text, _>(token.user_id)
+            .bind::<Text, _>(token.identifier)
+            .bind::<Text, _>(token.roles)
+            .bind::<BigInt, _>(token.expires_at)
+            .bind::<BigInt, _>(now)
+            .execute(conn)?;
+        Ok(())
+    })
+    .await
+}
+
+async fn find_token(state: &AppState, token_hash: String) -> Result<Option<RefreshToken>, AppError> {
+    #[derive(QueryableByName)]
+    struct Row {
+        #[diesel(sql_type = Text)]
+        session_id: String,
+        #[diesel(sql_type = Text)]
+        user_id: String,
+        #[diesel(sql_type = Text)]
+        identifier: String,
+        #[diesel(sql_type = Text)]
+        roles: String,
+        #[diesel(sql_type = BigInt)]
+        expires_at: i64,
+        #[diesel(sql_type = Nullable<BigInt>)]
+        revoked_at: Option<i64>,
+    }
+
+    run(&state.db, move |conn| {
+        let row = diesel::sql_query(FIND_SQL)
+            .bind::<Text, _>(token_hash)
+            .get_result::<Row>(conn)
+            .optional()?;
+        Ok(row.map(|row| RefreshToken {
+            session_id: row.session_id,
+            user_id: row.user_id,
+            identifier: row.identifier,
+            roles: row.roles,
+            expires_at: row.expires_at,
+            revoked_at: row.revoked_at,
+        }))
+    })
+    .await
+}
+
+/// Run one of the `REVOKE_…_SQL` statements, answering the rows revoked.
+async fn revoke(state: &AppState, sql: &'static str, key: String, now: i64) -> Result<u64, AppError> {
+    run(&state.db, move |conn| {
+        let revoked = diesel::sql_query(sql)
+            .bind::<BigInt, _>(now)
+            .bind::<Text, _>(key)
+            .execute(conn)?;
+        Ok(revoked as u64)
+    })
+    .await
+}
+
+async fn count_live(state: &AppState, session_id: String, now: i64) -> Result<i64, AppError> {
+    #[derive(QueryableByName)]
+    struct Live {
+        #[diesel(sql_type = BigInt)]
+        live: i64,
+    }
+
+    run(&state.db, move |conn| {
+        let row = diesel::sql_query(LIVE_SQL)
+            .bind::<Text, _>(session_id)
+            .bind::<BigInt, _>(now)
+            .get_result::<Live>(conn)?;
+        Ok(row.live)
+    })
+    .await
+}
+"
+    }
+
+    fn oauth_store(&self) -> &'static str {
+        "\
+/// Store a started sign-in, dropping the ones that expired unfinished.
+async fn insert_state(
+    state: &AppState,
+    csrf: String,
+    pending: PendingState,
+    now: i64,
+) -> Result<(), AppError> {
+    run(&state.db, move |conn| {
+        diesel::sql_query(PURGE_STATES_SQL)
+            .bind::<BigInt, _>(now)
+            .execute(conn)?;
+        diesel::sql_query(INSERT_STATE_SQL)
+            .bind::<Text, _>(csrf)
+            .bind::<Text, _>(pending.provider)
+            .bind::<Text, _>(pending.code_verifier)
+            .bind::<BigInt, _>(pending.expires_at)
+            .execute(conn)?;
+        Ok(())
+    })
+    .await
+}
+
+/// Look up and delete a started sign-in, so each state is used once.
+async fn take_state(state: &AppState, csrf: String) -> Result<Option<PendingState>, AppError> {
+    #[derive(QueryableByName)]
+    struct Row {
+        #[diesel(sql_type = Text)]
+        provider: String,
+        #[diesel(sql_type = Text)]
+        code_verifier: String,
+        #[diesel(sql_type = BigInt)]
+        expires_at: i64,
+    }
+
+    run(&state.db, move |conn| {
+        let row = diesel::sql_query(FIND_STATE_SQL)
+            .bind::<Text, _>(csrf.clone())
+            .get_result::<Row>(conn)
+            .optional()?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        // A concurrent callback with the same state may have taken it first
+        let deleted = diesel::sql_query(DELETE_STATE_SQL)
+            .bind::<Text, _>(csrf)
+            .execute(conn)?;
+        if deleted == 0 {
+            return Ok(None);
+        }
+        Ok(Some(PendingState {
+            provider: row.provider,
+            code_verifier: row.code_verifier,
+            expires_at: row.expires_at,
+        }))
+    })
+    .await
+}
+
+async fn find_account(state: &AppState, provider: String, subject: String) -> Result<Option<String>, AppError> {
+    #[derive(QueryableByName)]
+    struct Account {
+        #[diesel(sql_type = Text)]
+        user_id: String,
+    }
+
+    run(&state.db, move |conn| {
+        let account = diesel::sql_query(FIND_ACCOUNT_SQL)
+            .bind::<Text, _>(provider)
+            .bind::<Text, _>(subject)
+            .get_result::<Account>(conn)
+            .optional()?;
+        Ok(account.map(|account| account.user_id))
+    })
+    .await
+}
+
+async fn insert_account(state: &AppState, profile: &Profile, user_id: String, now: i64) -> Result<(), AppError> {
+    let (provider, subject, email) =
+        (profile.provider.clone(), profile.subject.clone(), profile.email.clone());
+    run(&state.db, move |conn| {
+        diesel::sql_query(INSERT_ACCOUNT_SQL)
+            .bind::<Text, _>(provider)
+            .bind::<Text, _>(subject)
+            .bind::<Text, _>(user_id)
+            .bind::<Nullable<Text>, _>(email)
+            .bind::<BigInt, _>(now)
+            .execute(conn)?;
+        Ok(())
+    })
+    .await
+}
+"
+    }
+
+    fn error_conversion(&self) -> &'static str {
+        "\
+impl From<diesel::result::Error> for AppError {
+    fn from(err: diesel::result::Error) -> Self {
+        use diesel::result::{DatabaseErrorKind, Error};
+
+        match err {
+            Error::NotFound => AppError::NotFound,
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => AppError::Conflict(
+                \"A record with the given unique field(s) already exists\".to_string(),
+            ),
+            Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                AppError::BadRequest(\"Referenced record does not exist\".to_string())
+            }
+            other => AppError::Database(other.to_string()),
+        }
+    }
+}
+"
+    }
+
+    fn error_conversion_tests(&self) -> &'static str {
+        "
+    #[test]
+    fn test_from_diesel_not_found() {
+        let app_err = AppError::from(diesel::result::Error::NotFound);
+        assert!(matches!(app_err, AppError::NotFound));
+    }
+
+    #[test]
+    fn test_from_diesel_unique_violation() {
+        let db_err = diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            Box::new(\"duplicate key value violates unique constraint\".to_string()),
+        );
+        let app_err = AppError::from(db_err);
+        assert!(matches!(app_err, AppError::Conflict(_)));
+    }
+
+    #[test]
+    fn test_from_diesel_generic() {
+        let app_err = AppError::from(diesel::result::Error::RollbackTransaction);
+        assert!(matches!(app_err, AppError::Database(_)));
+    }
+"
+    }
+
+    fn scaffolding(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        vec![
+            generate_schema(ctx),
+            GeneratedFile::new("diesel.toml", DIESEL_TOML.to_string(), FileType::Toml),
+        ]
+    }
+
+    fn generate_models(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        let mut files = vec![generate_models_mod(ctx)];
+        files.extend(enums::generate_enums(ctx));
+        for entity in ctx.entities() {
+            let info = EntityInfo::new(entity, ctx);
+            files.push(generate_entity_model(&info, ctx));
+        }
+        files
+    }
+
+    fn enum_module_imports(&self, ctx: &GenerationContext) -> String {
+        match ctx.database() {
+            DatabaseType::PostgreSQL => {
+                "\
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Text;
+"
+            }
+            DatabaseType::MySQL | DatabaseType::SQLite => {
+                "\
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Text;
+"
+            }
+        }
+        .to_string()
+    }
+
+    fn enum_mapping(&self, def: &EnumDef, ctx: &GenerationContext) -> EnumMapping {
+        let name = GenerationContext::pascal(&def.name);
+        let derives = vec![
+            "diesel::AsExpression".to_string(),
+            "diesel::FromSqlRow".to_string(),
+        ];
+
+        // PostgreSQL columns use the enum type the migrations create,
+        // declared in `schema.rs`; it is sent and received as text
+        if ctx.database() == DatabaseType::PostgreSQL {
+            let sql_type = format!("crate::schema::sql_types::{}", name);
+            return EnumMapping {
+                derives,
+                attributes: vec![format!("#[diesel(sql_type = {})]", sql_type)],
+                variant_attribute: None,
+                impls: format!(
+                    r#"impl ToSql<{sql_type}, Pg> for {name} {{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {{
+        <str as ToSql<Text, Pg>>::to_sql(self.as_str(), out)
+    }}
+}}
+
+impl FromSql<{sql_type}, Pg> for {name} {{
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {{
+        let value = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        Ok(value.parse()?)
+    }}
+}}
+"#
+                ),
+            };
+        }
+
+        EnumMapping {
+            derives,
+            attributes: vec!["#[diesel(sql_type = Text)]".to_string()],
+            variant_attribute: None,
+            impls: format!(
+                r#"impl<DB> ToSql<Text, DB> for {name}
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {{
+        <str as ToSql<Text, DB>>::to_sql(self.as_str(), out)
+    }}
+}}
+
+impl<DB> FromSql<Text, DB> for {name}
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {{
+        let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(value.parse()?)
+    }}
+}}
+"#
+            ),
+        }
+    }
+
+    fn generate_handlers(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        let mut files = vec![generate_handlers_mod(ctx)];
+        for entity in ctx.entities() {
+            let info = EntityInfo::new(entity, ctx);
+            let has_handlers = info
+                .endpoint()
+                .is_some_and(|ep| ep.enabled && !ep.enabled_operations().is_empty());
+            if has_handlers {
+                files.push(generate_entity_handlers(&info, ctx));
+            }
+        }
+        files
+    }
+
+    fn generate_migrations(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        let mut files = Vec::new();
+
+        if !ctx.generate_migrations() {
+            return files;
+        }
+
+        if let Some(statements) = create_enum_type_statements(ctx) {
+            let filename = ctx.migration_filename(0, ENUM_TYPES_MIGRATION);
+            let dir = format!("migrations/{}", filename.trim_end_matches(".sql"));
+            let mut up = migration_header("Create enum types", ctx);
+            up.push_str(&statements);
+            files.push(GeneratedFile::new(
+                format!("{}/up.sql", dir),
+                up,
+                FileType::Sql,
+            ));
+            files.push(GeneratedFile::new(
+                format!("{}/down.sql", dir),
+                drop_enum_type_statements(ctx),
+                FileType::Sql,
+            ));
+        }
+
+        for (index, entity) in ctx.entities().iter().enumerate() {
+            let info = EntityInfo::new(entity, ctx);
+            let table = info.table_name();
+            let filename = ctx.migration_filename(index + 1, &table);
+            let dir = format!("migrations/{}", filename.trim_end_matches(".sql"));
+
+            let mut up = migration_header(&format!("Create table `{}`", table), ctx);
+            up.push_str(&create_table_statements(&info, ctx));
+
+            let down = format!(
+                "DROP TABLE IF EXISTS {};\n",
+                TableDef::of(&info, ctx).qualified(ctx.database())
+            );
+
+            files.push(GeneratedFile::new(
+                format!("{}/up.sql", dir),
+                up,
+                FileType::Sql,
+            ));
+            files.push(GeneratedFile::new(
+                format!("{}/down.sql", dir),
+                down,
+                FileType::Sql,
+            ));
+        }
+
+        for (index, (table, statements)) in auth_tables(ctx).into_iter().enumerate() {
+            let filename = ctx.migration_filename(ctx.entities().len() + 1 + index, table);
+            let dir = format!("migrations/{}", filename.trim_end_matches(".sql"));
+            let mut up = migration_header(&format!("Create table `{}`", table), ctx);
+            up.push_str(&statements);
+            files.push(GeneratedFile::new(
+                format!("{}/up.sql", dir),
+                up,
+                FileType::Sql,
+            ));
+            files.push(GeneratedFile::new(
+                format!("{}/down.sql", dir),
+                format!(
+                    "DROP TABLE IF EXISTS {};\n",
+                    quote_identifier(table, ctx.database())
+                ),
+                FileType::Sql,
+            ));
+        }
+
+        files
+    }
+
+    fn generate_alter_migration(
+        &self,
+        plan: &SchemaMigration,
+        ctx: &GenerationContext,
+    ) -> Vec<GeneratedFile> {
+        let Some(file) = migrations::generate_alter_migration(plan, ctx) else {
+            return Vec::new();
+        };
+
+        // Same statements, in a migration directory instead of a flat file
+        let dir = format!(
+            "migrations/{}",
+            ctx.alter_migration_filename().trim_end_matches(".sql")
+        );
+        vec![
+            GeneratedFile::new(format!("{}/up.sql", dir), file.content, FileType::Sql),
+            GeneratedFile::new(
+                format!("{}/down.sql", dir),
+                "-- Revert by hand; dropped columns and tables cannot be restored.\n".to_string(),
+                FileType::Sql,
+            ),
+        ]
+    }
+
+    fn readme_migrate_step(&self) -> &'static str {
+        "diesel migration run"
+    }
+
+    fn seed_apply(&self) -> &'static str {
+        r#"/// Run the seed files in one transaction. Diesel is synchronous, which is
+/// fine here: nothing else runs while seeding.
+async fn apply(config: &Config, files: &[PathBuf]) -> anyhow::Result<()> {
+    use diesel::Connection;
+    use diesel::connection::SimpleConnection;
+
+    let pool = config
+        .database_pool()
+        .context("failed to connect to database")?;
+    let mut conn = pool.get()?;
+
+    conn.transaction(|conn| {
+        for path in files {
+            let sql = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            conn.batch_execute(&sql)
+                .with_context(|| format!("failed to apply {}", path.display()))?;
+            println!("  applied {}", path.display());
+        }
+        anyhow::Ok(())
+    })
+}
+"#
+    }
+}
+
+/// Diesel CLI configuration: `diesel print-schema` writes to the schema
+/// module the generated code uses.
+const DIESEL_TOML: &str = "\
+# Diesel CLI configuration — generated by Immortal Engine v2.0.
+# See https://diesel.rs/guides/configuring-diesel-cli
+
+[print_schema]
+file = \"src/schema.rs\"
+
+[migrations_directory]
+dir = \"migrations\"
+";
+
+/// Comment block at the top of each `up.sql`.
+fn migration_header(title: &str, ctx: &GenerationContext) -> String {
+    format!(
+        "-- Migration: {}\n-- Database: {}\n-- Generated by Immortal Engine v2.0\n\n",
+        title,
+        database_display_name(ctx.database())
+    )
+}
+
+// ============================================================================
+// Columns
+// ============================================================================
+
+/// A column of an entity's table as Diesel sees it.
+struct DieselColumn<'a> {
+    /// The field the column comes from; `None` for timestamps and `deleted_at`
+    field: Option<&'a Field>,
+    /// Name in the Diesel DSL and on the model struct
+    ident: String,
+    /// Name in the database
+    name: String,
+    /// `table!` type, including `Nullable<…>`
+    sql_type: String,
+    /// Model field type, including `Option<…>`
+    rust_type: String,
+    primary_key: bool,
+}
+
+/// The columns of an entity's table, in migration order.
+fn table_columns<'a>(info: &EntityInfo<'a>, ctx: &GenerationContext) -> Vec<DieselColumn<'a>> {
+    let db = ctx.database();
+    let fields: Vec<(Uuid, &'a Field)> = info.entity.fields.iter().map(|f| (f.id, f)).collect();
+
+    TableDef::of(info, ctx)
+        .columns
+        .into_iter()
+        .map(|column| {
+            let field = column
+                .field_id
+                .and_then(|id| fields.iter().find(|(fid, _)| *fid == id).map(|(_, f)| *f));
+
+            let (ident, sql_type, rust_type) = match field {
+                Some(field) if field.is_primary_key => {
+                    let rust_type = field_rust_type(field, info);
+                    let sql_type = match rust_type.as_str() {
+                        "Uuid" => "Uuid",
+                        "i32" => "Integer",
+                        "i64" => "BigInt",
+                        _ => "Text",
+                    };
+                    (
+                        GenerationContext::snake(&field.name),
+                        sql_type.to_string(),
+                        rust_type,
+                    )
+                }
+                Some(field) => {
+                    let inner = match &field.data_type {
+                        DataType::Optional(inner) => inner.as_ref(),
+                        other => other,
+                    };
+                    let mut sql_type = diesel_sql_type(inner, db);
+                    let mut rust_type = data_type_to_rust(inner);
+                    if !column.not_null {
+                        sql_type = format!("Nullable<{}>", sql_type);
+                        rust_type = format!("Option<{}>", rust_type);
+                    }
+                    (GenerationContext::snake(&field.name), sql_type, rust_type)
+                }
+                None => {
+                    let sql_type = diesel_sql_type(&DataType::DateTime, db);
+                    if column.not_null {
+                        (column.name.clone(), sql_type, "DateTime<Utc>".to_string())
+                    } else {
+                        (
+                            column.name.clone(),
+                            format!("Nullable<{}>", sql_type),
+                            "Option<DateTime<Utc>>".to_string(),
+                        )
+                    }
+                }
+            };
+
+            DieselColumn {
+                field,
+                ident,
+                name: column.name,
+                sql_type,
+                rust_type,
+                primary_key: column.primary_key,
+            }
+        })
+        .collect()
+}
+
+/// Whether a field is an `Option` on the model: a nullable column, or a
+/// getter that is not required.
+fn nullable(field: &Field, columns: &[DieselColumn]) -> bool {
+    match columns
+        .iter()
+        .find(|c| c.field.is_some_and(|f| f.id == field.id))
+    {
+        Some(column) => column.rust_type.starts_with("Option<"),
+        None => !field.required || matches!(field.data_type, DataType::Optional(_)),
+    }
+}
+
+/// Diesel SQL type for a (non-optional) data type.
+pub(crate) fn diesel_sql_type(dt: &DataType, db: DatabaseType) -> String {
+    match dt {
+        DataType::String | DataType::Text => "Text".into(),
+        // The PostgreSQL enum type, declared in `schema.rs`
+        DataType::Enum { name, .. } if db == DatabaseType::PostgreSQL => {
+            GenerationContext::pascal(name)
+        }
+        DataType::Enum { .. } => "Text".into(),
+        DataType::Int32 => "Integer".into(),
+        DataType::Int64 => "BigInt".into(),
+        DataType::Float32 => "Float".into(),
+        DataType::Float64 => "Double".into(),
+        DataType::Decimal { .. } => "Numeric".into(),
+        DataType::Bool => "Bool".into(),
+        DataType::Uuid | DataType::Reference { .. } => "Uuid".into(),
+        DataType::DateTime => match db {
+            DatabaseType::PostgreSQL => "Timestamptz".into(),
+            DatabaseType::MySQL => "Datetime".into(),
+            DatabaseType::SQLite => "TimestamptzSqlite".into(),
+        },
+        DataType::Date => "Date".into(),
+        DataType::Time => "Time".into(),
+        DataType::Bytes => "Binary".into(),
+        DataType::Json => match db {
+            DatabaseType::PostgreSQL => "Jsonb".into(),
+            _ => "Json".into(),
+        },
+        DataType::Array(inner) => format!("Array<{}>", diesel_sql_type(inner, db)),
+        DataType::Optional(inner) => format!("Nullable<{}>", diesel_sql_type(inner, db)),
+    }
+}
+
+/// Why Diesel cannot map a field's type on `db`, if it cannot.
+pub(crate) fn unsupported_column_reason(dt: &DataType, db: DatabaseType) -> Option<&'static str> {
+    match dt {
+        DataType::Optional(inner) | DataType::Array(inner) if db == DatabaseType::PostgreSQL => {
+            unsupported_column_reason(inner, db)
+        }
+        DataType::Optional(inner) => unsupported_column_reason(inner, db),
+        DataType::Array(_) => Some("Diesel only maps arrays on PostgreSQL"),
+        DataType::Uuid | DataType::Reference { .. } if db != DatabaseType::PostgreSQL => {
+            Some("Diesel only maps UUIDs on PostgreSQL")
+        }
+        DataType::DateTime if db == DatabaseType::MySQL => {
+            Some("Diesel reads MySQL DATETIME columns as chrono::NaiveDateTime, not DateTime<Utc>")
+        }
+        DataType::Decimal { .. } if db == DatabaseType::SQLite => {
+            Some("Diesel has no decimal mapping for SQLite")
+        }
+        _ => None,
+    }
+}
+
+// ============================================================================
+// src/schema.rs
+// ============================================================================
+
+fn generate_schema(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = file_header("Diesel table definitions, mirroring the migrations.");
+    content
+        .push_str("// After hand-written migrations, regenerate with `diesel print-schema`.\n\n");
+
+    // PostgreSQL enum types, as `diesel print-schema` declares them
+    let pg_enums = ctx.database() == DatabaseType::PostgreSQL && !ctx.enums().is_empty();
+    if pg_enums {
+        content.push_str("pub mod sql_types {\n");
+        for (i, def) in ctx.enums().iter().enumerate() {
+            if i > 0 {
+                content.push('\n');
+            }
+            content.push_str(
+                "    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]\n",
+            );
+            content.push_str(&format!(
+                "    #[diesel(postgres_type(name = \"{}\"))]\n",
+                GenerationContext::enum_type_name(&def.name)
+            ));
+            content.push_str(&format!(
+                "    pub struct {};\n",
+                GenerationContext::pascal(&def.name)
+            ));
+        }
+        content.push_str("}\n\n");
+    }
+
+    let mut tables = Vec::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        let table = info.table_name();
+        let columns = table_columns(&info, ctx);
+        let pk = info
+            .pk()
+            .map(|f| GenerationContext::snake(&f.name))
+            .unwrap_or_else(|| "id".to_string());
+        let qualified = match info.schema() {
+            Some(schema) => format!("{}.{}", schema, table),
+            None => table.clone(),
+        };
+
+        content.push_str("diesel::table! {\n");
+        if let Some(desc) = entity
+            .description
+            .as_deref()
+            .filter(|_| ctx.generate_docs())
+        {
+            content.push_str(&format!("    /// {}\n", desc));
+        }
+        if pg_enums {
+            let used = enums::enum_imports(
+                ctx,
+                "super::sql_types",
+                columns.iter().map(|c| c.sql_type.as_str()),
+            );
+            if !used.is_empty() {
+                content.push_str("    use diesel::sql_types::*;\n");
+                content.push_str(&format!("    {}\n", used));
+            }
+        }
+        content.push_str(&format!("    {} ({}) {{\n", qualified, pk));
+        for column in columns {
+            if column.ident != column.name {
+                content.push_str(&format!("        #[sql_name = \"{}\"]\n", column.name));
+            }
+            content.push_str(&format!(
+                "        {} -> {},\n",
+                column.ident, column.sql_type
+            ));
+        }
+        content.push_str("    }\n}\n\n");
+        tables.push(table);
+    }
+
+    // One joinable! per child/parent pair; Diesel rejects duplicates and
+    // self-joins.
+    let mut joins: Vec<(String, String)> = Vec::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        for field in entity.fields.iter().filter(|f| f.is_foreign_key) {
+            let Some(parent) = field
+                .foreign_key_ref
+                .as_ref()
+                .and_then(|fk| ctx.entity_by_id(fk.entity_id))
+            else {
+                continue;
+            };
+            if parent.id == entity.id {
+                continue;
+            }
+            let pair = (info.table_name(), EntityInfo::new(parent, ctx).table_name());
+            if joins.contains(&pair) {
+                continue;
+            }
+            content.push_str(&format!(
+                "diesel::joinable!({} -> {} ({}));\n",
+                pair.0,
+                pair.1,
+                GenerationContext::snake(&field.name)
+            ));
+            joins.push(pair);
+        }
+    }
+    if !joins.is_empty() {
+        content.push('\n');
+    }
+
+    if tables.len() > 1 {
+        content.push_str("diesel::allow_tables_to_appear_in_same_query!(\n");
+        for table in &tables {
+            content.push_str(&format!("    {},\n", table));
+        }
+        content.push_str(");\n");
+    }
+
+    GeneratedFile::new("src/schema.rs", content, FileType::Rust)
+}
+
+// ============================================================================
+// src/models/
+// ============================================================================
+
+/// Rust type of the entity's primary key.
+fn key_type(info: &EntityInfo) -> &'static str {
+    GenerationContext::pk_rust_type(info.id_type())
+}
+
+/// Whether a column is one of the entity's `created_at`/`updated_at`
+/// timestamps, which the handlers fill in.
+fn is_timestamp(info: &EntityInfo, column: &str) -> bool {
+    info.has_timestamps() && matches!(column, "created_at" | "updated_at")
+}
+
+/// Name of the `Insertable` row (e.g. `NewPost`).
+fn insertable_name(info: &EntityInfo) -> String {
+    format!("New{}", info.pascal_name())
+}
+
+/// Name of the `AsChangeset` row (e.g. `PostChanges`).
+fn changeset_name(info: &EntityInfo) -> String {
+    format!("{}Changes", info.pascal_name())
+}
+
+fn generate_models_mod(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = file_header("Model definitions (Diesel models and DTOs).");
+
+    if !ctx.enums().is_empty() {
+        content.push_str("pub mod enums;\n");
+    }
+    for entity in ctx.entities() {
+        content.push_str(&format!(
+            "pub mod {};\n",
+            GenerationContext::module_name(&entity.name)
+        ));
+    }
+
+    if !ctx.entities().is_empty() {
+        content.push('\n');
+        content.push_str("// Re-exports for convenience\n");
+        content.push_str(&enums::enum_reexport(ctx));
+
+        for entity in ctx.entities() {
+            let info = EntityInfo::new(entity, ctx);
+            content.push_str(&format!(
+                "pub use {}::{{Model as {}Model, {}, {}, {}, {}, {}}};\n",
+                info.module_name(),
+                info.pascal_name(),
+                insertable_name(&info),
+                changeset_name(&info),
+                GenerationContext::create_dto_name(&entity.name),
+                GenerationContext::update_dto_name(&entity.name),
+                GenerationContext::response_dto_name(&entity.name),
+            ));
+        }
+    }
+
+    GeneratedFile::new("src/models/mod.rs", content, FileType::Rust)
+}
+
+fn generate_entity_model(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFile {
+    let table = info.table_name();
+    let columns = table_columns(info, ctx);
+    let backend = match ctx.database() {
+        DatabaseType::PostgreSQL => "diesel::pg::Pg",
+        DatabaseType::MySQL => "diesel::mysql::Mysql",
+        DatabaseType::SQLite => "diesel::sqlite::Sqlite",
+    };
+
+    let mut out = file_header(&format!(
+        "{} model — Diesel model and DTOs.",
+        info.pascal_name()
+    ));
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    let types: String = columns.iter().map(|c| c.rust_type.as_str()).collect();
+    out.push_str("use diesel::prelude::*;\n");
+    out.push_str("use serde::{Deserialize, Serialize};\n");
+    out.push_str("use validator::Validate;\n");
+    if types.contains("Uuid") {
+        out.push_str("use uuid::Uuid;\n");
+    }
+    if types.contains("DateTime<Utc>") {
+        out.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    if types.contains("Decimal") {
+        out.push_str("use rust_decimal::Decimal;\n");
+    }
+    out.push('\n');
+    out.push_str(&enums::enum_imports(
+        ctx,
+        "super::enums",
+        columns.iter().map(|c| c.rust_type.as_str()),
+    ));
+    out.push_str(&format!("use crate::schema::{};\n\n", table));
+
+    // ── Model ────────────────────────────────────────────────────────────
+    out.push_str(&doc_comment(info.entity.description.as_deref(), ctx));
+    out.push_str(
+        "#[derive(Clone, Debug, PartialEq, Queryable, Selectable, Identifiable, Serialize, Deserialize)]\n",
+    );
+    out.push_str(&format!("#[diesel(table_name = {})]\n", table));
+    if let Some(pk) = columns.iter().find(|c| c.primary_key)
+        && pk.ident != "id"
+    {
+        out.push_str(&format!("#[diesel(primary_key({}))]\n", pk.ident));
+    }
+    out.push_str(&format!("#[diesel(check_for_backend({}))]\n", backend));
+    out.push_str("pub struct Model {\n");
+    for column in &columns {
+        if let Some(desc) = column.field.and_then(|f| f.description.as_deref()) {
+            out.push_str(&format!("    /// {}\n", desc));
+        }
+        if column.field.is_some_and(|f| f.secret) {
+            out.push_str("    #[serde(skip_serializing)]\n");
+        }
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            column.ident, column.rust_type
+        ));
+    }
+    out.push_str("}\n\n");
+
+    // Computed field getters
+    out.push_str(&computed::generate_getters(info, ctx, |f| {
+        nullable(f, &columns)
+    }));
+
+    // ── Insertable ───────────────────────────────────────────────────────
+    let create_fields = info.create_fields();
+    out.push_str(&doc_comment(
+        Some("Row inserted by the create handler."),
+        ctx,
+    ));
+    out.push_str("#[derive(Debug, Insertable)]\n");
+    out.push_str(&format!("#[diesel(table_name = {})]\n", table));
+    out.push_str(&format!("pub struct {} {{\n", insertable_name(info)));
+    for column in &columns {
+        let inserted = match column.field {
+            // UUID keys are generated by the handler, others by the database
+            _ if column.primary_key => column.rust_type == "Uuid",
+            _ if is_timestamp(info, &column.name) => true,
+            Some(field) => create_fields.iter().any(|f| f.id == field.id),
+            None => false,
+        };
+        if inserted {
+            out.push_str(&format!(
+                "    pub {}: {},\n",
+                column.ident, column.rust_type
+            ));
+        }
+    }
+    out.push_str("}\n\n");
+
+    // ── Changeset ────────────────────────────────────────────────────────
+    let update_fields = info.update_fields();
+    out.push_str(&doc_comment(
+        Some("Columns written by the update handler; `None` leaves a column unchanged."),
+        ctx,
+    ));
+    out.push_str("#[derive(Debug, Default, PartialEq, AsChangeset)]\n");
+    out.push_str(&format!("#[diesel(table_name = {})]\n", table));
+    out.push_str(&format!("pub struct {} {{\n", changeset_name(info)));
+    for column in &columns {
+        let changed = match column.field {
+            _ if is_timestamp(info, &column.name) => column.name == "updated_at",
+            Some(field) => update_fields.iter().any(|f| f.id == field.id),
+            None => column.name == "deleted_at",
+        };
+        if changed {
+            out.push_str(&format!(
+                "    pub {}: Option<{}>,\n",
+                column.ident, column.rust_type
+            ));
+        }
+    }
+    out.push_str("}\n\n");
+
+    // ── DTOs ─────────────────────────────────────────────────────────────
+    out.push_str(
+        "// ============================================================================\n",
+    );
+    out.push_str("// DTOs (Data Transfer Objects)\n");
+    out.push_str(
+        "// ============================================================================\n\n",
+    );
+    out.push_str(&generate_validation_helpers(info));
+    out.push_str(&generate_create_dto(info, ctx));
+    out.push('\n');
+    out.push_str(&generate_update_dto(info, ctx));
+    out.push('\n');
+    out.push_str(&generate_response_dto(info, ctx, &columns));
+
+    GeneratedFile::new(
+        format!("src/models/{}.rs", info.module_name()),
+        out,
+        FileType::Rust,
+    )
+}
+
+/// The response DTO and its `From<Model>`, typed like the Diesel model so
+/// nullable columns stay `Option`s.
+fn generate_response_dto(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    columns: &[DieselColumn],
+) -> String {
+    let name = GenerationContext::response_dto_name(&info.entity.name);
+    let exposed: Vec<&DieselColumn> = columns
+        .iter()
+        .filter(|c| c.field.is_none_or(|f| f.in_response_dto()))
+        .collect();
+    let getters: Vec<&Field> = computed::getter_fields(info.entity)
+        .into_iter()
+        .filter(|f| f.in_response_dto())
+        .collect();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Response representation of a {}. Excludes secret and hidden fields.",
+            info.pascal_name()
+        )),
+        ctx,
+    );
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", name));
+    for column in &exposed {
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            column.ident, column.rust_type
+        ));
+    }
+    for field in &getters {
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            GenerationContext::snake(&field.name),
+            computed::getter_type(field, nullable(field, columns))
+        ));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(&format!("impl From<Model> for {} {{\n", name));
+    out.push_str("    fn from(model: Model) -> Self {\n");
+    // Getters read the model, so they run before its fields are moved out
+    for field in &getters {
+        out.push_str(&format!(
+            "        let {0} = model.{0}();\n",
+            GenerationContext::snake(&field.name)
+        ));
+    }
+    out.push_str("        Self {\n");
+    for column in &exposed {
+        out.push_str(&format!("            {0}: model.{0},\n", column.ident));
+    }
+    for field in &getters {
+        out.push_str(&format!(
+            "            {},\n",
+            GenerationContext::snake(&field.name)
+        ));
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// src/handlers/
+// ============================================================================
+
+fn generate_entity_handlers(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFile {
+    let ops: Vec<OperationType> = info
+        .endpoint()
+        .map(|ep| {
+            ep.enabled_operations()
+                .iter()
+                .map(|op| op.operation_type)
+                .collect()
+        })
+        .unwrap_or_default();
+    let has = |op: OperationType| ops.contains(&op);
+
+    let mut out = file_header(&format!(
+        "Request handlers for {} endpoints.",
+        info.pascal_name()
+    ));
+    out.push_str(&generate_handler_imports(info, ctx, &ops));
+    out.push('\n');
+
+    if ctx.database() == DatabaseType::MySQL
+        && has(OperationType::Create)
+        && key_type(info) != "Uuid"
+    {
+        out.push_str(
+            "diesel::define_sql_function! {\n    /// Id of the last row inserted on this connection.\n    fn last_insert_id() -> diesel::sql_types::Unsigned<diesel::sql_types::BigInt>;\n}\n\n",
+        );
+    }
+
+    if has(OperationType::ReadAll) {
+        out.push_str(&generate_list_handler(info, ctx));
+        out.push('\n');
+    }
+    if has(OperationType::Read) {
+        out.push_str(&generate_get_handler(info, ctx));
+        out.push('\n');
+    }
+    if has(OperationType::Create) {
+        out.push_str(&generate_create_handler(info, ctx));
+        out.push('\n');
+    }
+    if has(OperationType::Update) {
+        out.push_str(&generate_update_handler(info, ctx));
+        out.push('\n');
+    }
+    if has(OperationType::Delete) {
+        out.push_str(&generate_delete_handler(info, ctx));
+        out.push('\n');
+    }
+    if restores(info, &ops) {
+        out.push_str(&generate_restore_handler(info, ctx));
+        out.push('\n');
+    }
+    if info.live_transport().is_some() {
+        out.push_str(&generate_live_handler(info, ctx));
+        out.push('\n');
+    }
+    for field in info.upload_fields() {
+        out.push_str(&generate_upload_handler(info, field, ctx));
+        out.push('\n');
+    }
+
+    GeneratedFile::new(
+        format!("src/handlers/{}.rs", info.module_name()),
+        out,
+        FileType::Rust,
+    )
+}
+
+fn generate_handler_imports(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    ops: &[OperationType],
+) -> String {
+    let has = |op: OperationType| ops.contains(&op);
+    let mut out = String::with_capacity(512);
+
+    let mut extractors = vec![Extractor::State];
+    if has(OperationType::Read) || has(OperationType::Update) || has(OperationType::Delete) {
+        extractors.push(Extractor::Path);
+    }
+    let scoped_reads =
+        info.has_soft_delete() && (has(OperationType::ReadAll) || has(OperationType::Read));
+    if scoped_reads || (has(OperationType::ReadAll) && ctx.pagination() != PaginationStyle::None) {
+        extractors.push(Extractor::Query);
+    }
+    out.push_str(&ctx.framework_backend().handler_imports(
+        &extractors,
+        has(OperationType::Create) || has(OperationType::Delete),
+    ));
+    out.push_str("use diesel::prelude::*;\n");
+
+    let uuid_path = key_type(info) == "Uuid";
+    if uuid_path
+        && (has(OperationType::Create)
+            || has(OperationType::Read)
+            || has(OperationType::Update)
+            || has(OperationType::Delete))
+    {
+        out.push_str("use uuid::Uuid;\n");
+    }
+
+    let writes = has(OperationType::Create) || has(OperationType::Update);
+    if writes {
+        out.push_str("use validator::Validate;\n");
+    }
+    let hashes = ctx.auth_enabled()
+        && ((has(OperationType::Create)
+            && info
+                .create_fields()
+                .iter()
+                .any(|f| ctx.is_password_field(f)))
+            || (has(OperationType::Update)
+                && info
+                    .update_fields()
+                    .iter()
+                    .any(|f| ctx.is_password_field(f))));
+    if hashes {
+        out.push_str("use crate::auth::jwt::hash_password;\n");
+    }
+    out.push('\n');
+
+    if has(OperationType::ReadAll) && ctx.pagination() != PaginationStyle::None {
+        let (default_size, max_size) = page_size_consts(info);
+        out.push_str(&format!(
+            "use crate::constants::{{{default_size}, {max_size}}};\n"
+        ));
+    }
+    out.push_str("use crate::error::AppError;\n");
+    let mut models = vec!["Model".to_string()];
+    if has(OperationType::Create) {
+        models.push(insertable_name(info));
+        models.push(GenerationContext::create_dto_name(&info.entity.name));
+    }
+    if has(OperationType::Update) {
+        models.push(changeset_name(info));
+        models.push(GenerationContext::update_dto_name(&info.entity.name));
+    }
+    if has(OperationType::ReadAll)
+        || has(OperationType::Read)
+        || has(OperationType::Create)
+        || has(OperationType::Update)
+        || restores(info, ops)
+    {
+        models.push(GenerationContext::response_dto_name(&info.entity.name));
+    }
+    out.push_str(&format!(
+        "use crate::models::{}::{{{}}};\n",
+        info.module_name(),
+        models.join(", ")
+    ));
+    out.push_str(&format!("use crate::schema::{};\n", info.table_name()));
+    out.push_str("use crate::state::{AppState, run};\n");
+
+    if has(OperationType::ReadAll) {
+        match ctx.pagination() {
+            PaginationStyle::Offset => {
+                out.push_str("use crate::handlers::{PaginatedResponse, PaginationParams};\n")
+            }
+            PaginationStyle::Cursor => {
+                out.push_str("use crate::handlers::{CursorPage, PaginationParams};\n")
+            }
+            PaginationStyle::None => {}
+        }
+    }
+    if scoped_reads {
+        out.push_str("use crate::handlers::SoftDeleteParams;\n");
+    }
+    out.push_str(&realtime_import(info, ops));
+    out.push_str(&http_cache_import(info, ops));
+    if !info.upload_fields().is_empty() {
+        out.push_str("use crate::storage;\n");
+    }
+
+    out
+}
+
+/// `.filter(…)` leaving out soft-deleted rows unless `include_deleted`,
+/// or nothing for entities without soft delete.
+fn scope_filter(info: &EntityInfo) -> String {
+    if !info.has_soft_delete() {
+        return String::new();
+    }
+    format!(
+        "\n            .filter({}::deleted_at.is_null().or(include_deleted.into_sql::<diesel::sql_types::Bool>()))",
+        info.table_name()
+    )
+}
+
+/// The `Query<SoftDeleteParams>` argument and the binding of its flag, for
+/// handlers reading through [`scope_filter`].
+fn scope_param(info: &EntityInfo) -> (&'static str, &'static str) {
+    if info.has_soft_delete() {
+        (
+            "\n    Query(scope): Query<SoftDeleteParams>,",
+            "    let include_deleted = scope.include_deleted.unwrap_or(false);\n",
+        )
+    } else {
+        ("", "")
+    }
+}
+
+/// The primary-key column's DSL name.
+fn pk_ident(info: &EntityInfo) -> String {
+    info.pk()
+        .map(|f| GenerationContext::snake(&f.name))
+        .unwrap_or_else(|| "id".to_string())
+}
+
+/// `.order_by(…)` / `.then_order_by(…)` calls for the endpoint's default
+/// sort, or nothing.
+fn default_order_chain(info: &EntityInfo) -> String {
+    let table = info.table_name();
+    list_default_sort(info)
+        .iter()
+        .enumerate()
+        .map(|(i, (column, descending))| {
+            let method = if i == 0 { "order_by" } else { "then_order_by" };
+            let direction = if *descending { "desc" } else { "asc" };
+            format!(
+                "\n            .{method}({table}::{}.{direction}())",
+                column.name
+            )
+        })
+        .collect()
+}
+
+fn generate_list_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("list_{}", info.plural_name());
+    let table = info.table_name();
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let list_type = list_response_type(ctx, &response_dto);
+    let read_db = ctx.read_db();
+    let (default_size, max_size) = page_size_consts(info);
+    let order = default_order_chain(info);
+    let state_param = ctx.framework_backend().state_param();
+    let scope = scope_filter(info);
+    let (scope_arg, include_deleted) = scope_param(info);
+    let response = ReadResponse::new(
+        info,
+        ctx,
+        OperationType::ReadAll,
+        &list_type,
+        &model_versions(info, "models"),
+    );
+    let scope_arg = format!("{}{}", scope_arg, response.param);
+    let return_type = &response.return_type;
+
+    let summary = match ctx.pagination() {
+        PaginationStyle::Offset => "with pagination",
+        PaginationStyle::Cursor => "with cursor pagination",
+        PaginationStyle::None => "without pagination",
+    };
+    let mut out = doc_comment(
+        Some(&format!(
+            "List all {}s {}.\n\nGET {}",
+            info.snake_name(),
+            summary,
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::ReadAll, &fn_name));
+
+    let into_items = format!(
+        "{}    let items: Vec<{response_dto}> = models.into_iter().map({response_dto}::from).collect();",
+        response.validators
+    );
+
+    match ctx.pagination() {
+        PaginationStyle::Offset => out.push_str(&format!(
+            r#"pub async fn {fn_name}(
+    {state_param},
+    Query(params): Query<PaginationParams>,{scope_arg}
+) -> Result<{return_type}, AppError> {{
+{include_deleted}    let page = params.page.unwrap_or(1).max(1);
+    let per_page = params.per_page.unwrap_or({default_size}).min({max_size}).max(1);
+
+    let (models, total) = run(&{read_db}, move |conn| {{
+        let total: i64 = {table}::table{scope}.count().get_result(conn)?;
+        let models: Vec<Model> = {table}::table{scope}{order}
+            .limit(per_page as i64)
+            .offset(((page - 1) * per_page) as i64)
+            .select(Model::as_select())
+            .load(conn)?;
+        Ok((models, total as u64))
+    }})
+    .await?;
+
+{into_items}
+    {answer}
+}}
+"#,
+            answer = response.answer("PaginatedResponse::new(items, total, page, per_page)"),
+        )),
+        PaginationStyle::Cursor => {
+            let pk = pk_ident(info);
+            let pk_type = key_type(info);
+            out.push_str(&format!(
+                r#"pub async fn {fn_name}(
+    {state_param},
+    Query(params): Query<PaginationParams>,{scope_arg}
+) -> Result<{return_type}, AppError> {{
+{include_deleted}    let limit = params.limit.unwrap_or({default_size}).min({max_size}).max(1);
+    let after = params
+        .cursor
+        .as_deref()
+        .map(|cursor| cursor.parse::<{pk_type}>())
+        .transpose()
+        .map_err(|_| AppError::bad_request("Invalid cursor"))?;
+
+    // Fetch one extra row to learn whether another page follows.
+    let mut models = run(&{read_db}, move |conn| {{
+        let mut query = {table}::table{scope}
+            .order({table}::{pk}.asc())
+            .limit(limit as i64 + 1)
+            .into_boxed();
+        if let Some(after) = after {{
+            query = query.filter({table}::{pk}.gt(after));
+        }}
+        let models: Vec<Model> = query.select(Model::as_select()).load(conn)?;
+        Ok(models)
+    }})
+    .await?;
+
+    let next_cursor = if models.len() as u64 > limit {{
+        models.truncate(limit as usize);
+        models.last().map(|m| m.{pk}.to_string())
+    }} else {{
+        None
+    }};
+
+{into_items}
+    {answer}
+}}
+"#,
+                answer = response.answer("CursorPage::new(items, next_cursor)"),
+            ));
+        }
+        PaginationStyle::None => out.push_str(&format!(
+            r#"pub async fn {fn_name}(
+    {state_param},{scope_arg}
+) -> Result<{return_type}, AppError> {{
+{include_deleted}    let models = run(&{read_db}, move |conn| {{
+        let models: Vec<Model> = {table}::table{scope}{order}
+            .select(Model::as_select())
+            .load(conn)?;
+        Ok(models)
+    }})
+    .await?;
+
+{into_items}
+    {answer}
+}}
+"#,
+            answer = response.answer("items"),
+        )),
+    }
+
+    out
+}
+
+fn generate_get_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("get_{}", info.snake_name());
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(key_type(info));
+    let path_binding = framework.path_binding();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Get a single {} by ID.\n\nGET {}/:id",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Read, &fn_name));
+    let scope = scope_filter(info);
+    let (scope_arg, include_deleted) = scope_param(info);
+    let response = ReadResponse::new(
+        info,
+        ctx,
+        OperationType::Read,
+        &response_dto,
+        &model_version(info, "item"),
+    );
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},{scope_arg}{headers_param}
+) -> Result<{return_type}, AppError> {{
+{path_binding}{include_deleted}    let item = run(&{read_db}, move |conn| {{
+        let item: Model = {table}::table
+            .find(id){scope}
+            .select(Model::as_select())
+            .first(conn)?;
+        Ok(item)
+    }})
+    .await?;
+
+{validators}    {answer}
+}}
+"#,
+        read_db = ctx.read_db(),
+        table = info.table_name(),
+        headers_param = response.param,
+        return_type = response.return_type,
+        validators = response.validators,
+        answer = response.answer(&format!("{response_dto}::from(item)")),
+    ));
+    out
+}
+
+/// Statements inside the blocking closure that write `row` and return the
+/// stored `Model`. MySQL has no `RETURNING`, so the row is read back.
+fn write_returning(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    write: &str,
+    lookup: &str,
+) -> String {
+    let table = info.table_name();
+    if ctx.database() == DatabaseType::MySQL {
+        format!(
+            "        {write}
+            .execute(conn)?;{lookup}
+        let model: Model = {table}::table
+            .find(id)
+            .select(Model::as_select())
+            .first(conn)?;
+        Ok(model)"
+        )
+    } else {
+        format!(
+            "        let model: Model = {write}
+            .returning(Model::as_returning())
+            .get_result(conn)?;
+        Ok(model)"
+        )
+    }
+}
+
+fn generate_create_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("create_{}", info.snake_name());
+    let create_dto = GenerationContext::create_dto_name(&info.entity.name);
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let table = info.table_name();
+    let pk = pk_ident(info);
+    let uuid_key = key_type(info) == "Uuid";
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let created_type = framework.created_type(&response_dto);
+    let created = framework.created(&format!("{response_dto}::from(model)"));
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Create a new {}.\n\nPOST {}",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Create, &fn_name));
+
+    // Build the row from the payload, hashing secrets
+    let mut row = String::new();
+    if uuid_key {
+        row.push_str(&format!("        {pk}: Uuid::new_v4(),\n"));
+    }
+    for field in info.create_fields() {
+        let name = GenerationContext::snake(&field.name);
+        let source = format!("payload.{}", dto_field_name(field, ctx));
+        if ctx.is_password_field(field) && ctx.auth_enabled() {
+            row.push_str(&format!(
+                "        {name}: hash_password(&{source}).map_err(|e| AppError::internal(format!(\"Password hashing failed: {{}}\", e)))?,\n"
+            ));
+        } else {
+            row.push_str(&format!("        {name}: {source},\n"));
+        }
+    }
+    if info.has_timestamps() {
+        for timestamp in ["created_at", "updated_at"] {
+            if !info.create_fields().iter().any(|f| f.name == timestamp) {
+                row.push_str(&format!("        {timestamp}: chrono::Utc::now(),\n"));
+            }
+        }
+    }
+
+    // MySQL has no RETURNING: read the row back by its key
+    let lookup = if uuid_key {
+        format!("\n        let id = row.{pk};")
+    } else {
+        "\n        let id = diesel::select(last_insert_id()).get_result::<u64>(conn)? as i32;"
+            .to_string()
+    };
+    let body = write_returning(
+        info,
+        ctx,
+        &format!("diesel::insert_into({table}::table)\n            .values(&row)"),
+        &lookup,
+    );
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    Json(payload): Json<{create_dto}>,
+) -> Result<{created_type}, AppError> {{
+    payload.validate().map_err(AppError::from)?;
+
+    let row = {insertable} {{
+{row}    }};
+
+    let model = run(&state.db, move |conn| {{
+{body}
+    }})
+    .await?;
+{publish}
+    Ok({created})
+}}
+"#,
+        insertable = insertable_name(info),
+        publish = publish_line(info, "Created", &format!("&model.{pk}")),
+    ));
+    out
+}
+
+fn generate_update_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("update_{}", info.snake_name());
+    let update_dto = GenerationContext::update_dto_name(&info.entity.name);
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let changeset = changeset_name(info);
+    let table = info.table_name();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(key_type(info));
+    let path_binding = framework.path_binding();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Update an existing {} by ID.\n\nPUT {}/:id",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
+
+    let mut changes = String::new();
+    for field in info.update_fields() {
+        let name = GenerationContext::snake(&field.name);
+        if ctx.is_password_field(field) && ctx.auth_enabled() {
+            changes.push_str(&format!(
+                "        {name}: payload\n            .{name}\n            .map(|val| hash_password(&val))\n            .transpose()\n            .map_err(|e| AppError::internal(format!(\"Password hashing failed: {{}}\", e)))?,\n"
+            ));
+        } else {
+            changes.push_str(&format!("        {name}: payload.{name},\n"));
+        }
+    }
+    if info.has_timestamps() {
+        changes.push_str("        updated_at: Some(chrono::Utc::now()),\n");
+    }
+    changes.push_str("        ..Default::default()\n");
+
+    // Soft-deleted rows count as missing
+    let mut empty_check = if info.has_soft_delete() {
+        format!(
+            "        {table}::table\n            .find(id)\n            .filter({table}::deleted_at.is_null())\n            .select({table}::{pk})\n            .first::<{key}>(conn)?;\n",
+            pk = pk_ident(info),
+            key = key_type(info),
+        )
+    } else {
+        String::new()
+    };
+
+    // Diesel refuses an empty changeset; without timestamps the payload
+    // may change nothing
+    if !info.has_timestamps() {
+        empty_check.push_str(&format!(
+            r#"        if changes == {changeset}::default() {{
+            let model: Model = {table}::table
+                .find(id)
+                .select(Model::as_select())
+                .first(conn)?;
+            return Ok(model);
+        }}
+"#
+        ));
+    }
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+    Json(payload): Json<{update_dto}>,
+) -> Result<Json<{response_dto}>, AppError> {{
+{path_binding}    payload.validate().map_err(AppError::from)?;
+
+    let changes = {changeset} {{
+{changes}    }};
+
+    let updated = run(&state.db, move |conn| {{
+{empty_check}{write}
+    }})
+    .await?;
+{publish}
+    Ok(Json({response_dto}::from(updated)))
+}}
+"#,
+        publish = publish_line(info, "Updated", "id"),
+        write = write_returning(
+            info,
+            ctx,
+            &format!("diesel::update({table}::table.find(id))\n            .set(&changes)"),
+            "",
+        ),
+    ));
+    out
+}
+
+fn generate_delete_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("delete_{}", info.snake_name());
+    let table = info.table_name();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(key_type(info));
+    let path_binding = framework.path_binding();
+    let no_content_type = framework.no_content_type();
+    let no_content = framework.no_content();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Delete a {} by ID.\n\nDELETE {}/:id{}",
+            info.snake_name(),
+            info.base_path(),
+            delete_effects_doc(info)
+                .map(|e| format!("\n\n{}", e))
+                .unwrap_or_default()
+        )),
+        ctx,
+    );
+    out.push_str(&delete_openapi_attr(info, ctx));
+    out.push_str(&handler_span(info, ctx, OperationType::Delete, &fn_name));
+
+    let statement = if info.has_soft_delete() {
+        // Soft delete: set deleted_at instead of removing the row
+        format!(
+            "diesel::update({table}::table.find(id).filter({table}::deleted_at.is_null()))\n            .set({table}::deleted_at.eq(Some(chrono::Utc::now())))"
+        )
+    } else {
+        format!("diesel::delete({table}::table.find(id))")
+    };
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+) -> Result<{no_content_type}, AppError> {{
+{path_binding}    let affected = run(&state.db, move |conn| {{
+        let affected = {statement}
+            .execute(conn)?;
+        Ok(affected)
+    }})
+    .await?;
+
+    if affected == 0 {{
+        return Err(AppError::NotFound);
+    }}
+{publish}
+    Ok({no_content})
+}}
+"#,
+        publish = publish_line(info, "Deleted", "id"),
+    ));
+    out
+}
+
+/// `restore_{entity}`: clears `deleted_at`, answering with the record.
+fn generate_restore_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("restore_{}", info.snake_name());
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let table = info.table_name();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(key_type(info));
+    let path_binding = framework.path_binding();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Restore a soft-deleted {}. Restoring a record that is not deleted changes nothing.\n\nPOST {}/:id/restore",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Delete, &fn_name));
+
+    let mut assignments = vec![format!(
+        "{table}::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)"
+    )];
+    if info.has_timestamps() {
+        assignments.push(format!("{table}::updated_at.eq(chrono::Utc::now())"));
+    }
+    let assignments = if assignments.len() == 1 {
+        assignments.remove(0)
+    } else {
+        format!("({})", assignments.join(", "))
+    };
+    let write = write_returning(
+        info,
+        ctx,
+        &format!("diesel::update({table}::table.find(id))\n            .set({assignments})"),
+        "",
+    );
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+) -> Result<Json<{response_dto}>, AppError> {{
+{path_binding}    let restored = run(&state.db, move |conn| {{
+{write}
+    }})
+    .await?;
+{publish}
+    Ok(Json({response_dto}::from(restored)))
+}}
+"#,
+        publish = publish_line(info, "Updated", "id"),
+    ));
+    out
+}
+
+/// Generate `upload_{entity}_{field}`. The record is looked up before the
+/// file is stored, so a missing one leaves nothing behind in storage.
+fn generate_upload_handler(info: &EntityInfo, field: &Field, ctx: &GenerationContext) -> String {
+    let fn_name = upload_handler_name(info, field);
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let table = info.table_name();
+    let column = GenerationContext::snake(&field.name);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(key_type(info));
+    let path_binding = framework.path_binding();
+    let upload_param = framework.upload_param();
+    let active = if info.has_soft_delete() {
+        format!("\n            .filter({table}::deleted_at.is_null())")
+    } else {
+        String::new()
+    };
+
+    let mut out = upload_handler_doc(info, field, ctx);
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
+
+    let assignments = if info.has_timestamps() {
+        format!("({table}::{column}.eq(url), {table}::updated_at.eq(chrono::Utc::now()))")
+    } else {
+        format!("{table}::{column}.eq(url)")
+    };
+    let write = write_returning(
+        info,
+        ctx,
+        &format!("diesel::update({table}::table.find(id))\n            .set({assignments})"),
+        "",
+    );
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+    {upload_param},
+) -> Result<Json<{response_dto}>, AppError> {{
+{path_binding}    run(&state.db, move |conn| {{
+        {table}::table
+            .find(id){active}
+            .select({table}::{pk})
+            .first::<{key}>(conn)?;
+        Ok(())
+    }})
+    .await?;
+
+{store}
+    let updated = run(&state.db, move |conn| {{
+{write}
+    }})
+    .await?;
+{publish}
+    Ok(Json({response_dto}::from(updated)))
+}}
+"#,
+        pk = pk_ident(info),
+        key = key_type(info),
+        store = store_upload(info, field),
+        publish = publish_line(info, "Updated", "id"),
+    ));
+    out
+}
+
+// ============================================================================
+// src/state.rs
+// ============================================================================
+
+fn build_state(ctx: &GenerationContext) -> String {
+    let connection = match ctx.database() {
+        DatabaseType::PostgreSQL => "diesel::PgConnection",
+        DatabaseType::MySQL => "diesel::MysqlConnection",
+        DatabaseType::SQLite => "diesel::SqliteConnection",
+    };
+
+    let mut content = String::with_capacity(2048);
+
+    if ctx.generate_docs() {
+        content.push_str("//! Application state shared across all handlers.\n\n");
+    }
+
+    content.push_str("use diesel::r2d2::{ConnectionManager, Pool};\n\n");
+    content.push_str("use crate::config::Config;\n");
+    content.push_str("use crate::error::AppError;\n\n");
+
+    content.push_str(&format!(
+        "\
+/// Diesel connection for the configured database.
+pub type DbConnection = {connection};
+
+/// Pool of Diesel connections.
+pub type DbPool = Pool<ConnectionManager<DbConnection>>;
+
+"
+    ));
+
+    if ctx.read_replicas() {
+        content.push_str(
+            "\
+/// Shared application state, handed to every request handler.
+///
+/// Holds two connection pools: `db` points at the primary and takes every
+/// write, `db_read` points at a read replica and serves list/get requests.
+/// Replicas lag behind the primary, so code that must observe its own
+/// writes should read through `db`.
+#[derive(Clone)]
+pub struct AppState {
+    /// Diesel connection pool for the primary (writes).
+    pub db: DbPool,
+    /// Diesel connection pool for the read replica.
+    pub db_read: DbPool,
+    /// Application configuration.
+    pub config: Config,
+}
+
+impl AppState {
+    /// Create a new `AppState`.
+    pub fn new(db: DbPool, db_read: DbPool, config: Config) -> Self {
+        Self {
+            db,
+            db_read,
+            config,
+        }
+    }
+}
+",
+        );
+    } else {
+        content.push_str(
+            "\
+/// Shared application state, handed to every request handler.
+#[derive(Clone)]
+pub struct AppState {
+    /// Diesel connection pool.
+    pub db: DbPool,
+    /// Application configuration.
+    pub config: Config,
+}
+
+impl AppState {
+    /// Create a new `AppState`.
+    pub fn new(db: DbPool, config: Config) -> Self {
+        Self { db, config }
+    }
+}
+",
+        );
+    }
+
+    content.push_str(
+        "
+/// Run a Diesel query with a connection from `pool`.
+///
+/// Diesel is synchronous, so the query runs on Tokio's blocking thread pool
+/// instead of stalling the async executor.
+pub async fn run<T, F>(pool: &DbPool, query: F) -> Result<T, AppError>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut DbConnection) -> Result<T, AppError> + Send + 'static,
+{
+    let pool = pool.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut conn = pool.get().map_err(|e| AppError::Database(e.to_string()))?;
+        query(&mut conn)
+    })
+    .await
+    .map_err(|e| AppError::internal(format!(\"Database task failed: {}\", e)))?
+}
+",
+    );
+
+    content
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_ir::{CrudOperation, ETagSource, EndpointGroup, Entity, HttpCaching, ProjectGraph};
+
+    fn blog_project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        project.config.orm = Orm::Diesel;
+
+        let mut user = Entity::new("User");
+        user.fields
+            .push(Field::new("email", DataType::String).required());
+        user.fields.push(Field::new("nickname", DataType::String));
+        let mut password = Field::new("password_hash", DataType::String).required();
+        password.secret = true;
+        user.fields.push(password);
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let mut post = Entity::new("Post");
+        post.fields
+            .push(Field::new("title", DataType::String).required());
+        let mut author = Field::new("author_id", DataType::Uuid).required();
+        author.is_foreign_key = true;
+        author.foreign_key_ref =
+            Some(imortal_ir::ForeignKeyRef::new("User").with_entity_id(user_id));
+        post.fields.push(author);
+        let post_id = post.id;
+        project.add_entity(post);
+
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        project.add_endpoint(EndpointGroup::new(post_id, "Post"));
+        project
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .unwrap_or_else(|| panic!("missing {path}"))
+            .content
+    }
+
+    #[test]
+    fn test_schema_declares_tables_and_joins() {
+        let project = blog_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let schema = &DieselBackend.scaffolding(&ctx)[0].content;
+
+        assert!(schema.contains("diesel::table! {"));
+        assert!(schema.contains("    users (id) {"));
+        assert!(schema.contains("        email -> Text,"));
+        assert!(schema.contains("        nickname -> Nullable<Text>,"));
+        assert!(schema.contains("        created_at -> Timestamptz,"));
+        assert!(schema.contains("diesel::joinable!(posts -> users (author_id));"));
+        assert!(schema.contains("diesel::allow_tables_to_appear_in_same_query!("));
+    }
+
+    #[test]
+    fn test_models_use_diesel_derives() {
+        let project = blog_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_models(&ctx);
+        let user = file(&files, "src/models/user.rs");
+
+        assert!(user.contains("Queryable, Selectable, Identifiable"));
+        assert!(user.contains("#[diesel(table_name = users)]"));
+        assert!(user.contains("#[diesel(check_for_backend(diesel::pg::Pg))]"));
+        assert!(user.contains("pub nickname: Option<String>,"));
+        assert!(user.contains("pub struct NewUser {"));
+        assert!(user.contains("#[derive(Debug, Default, PartialEq, AsChangeset)]"));
+        assert!(user.contains("pub struct UserChanges {"));
+        assert!(user.contains("pub struct CreateUser"));
+        assert!(!user.contains("sea_orm"));
+
+        // Secrets never reach the response DTO
+        let response = user.split("pub struct UserResponse").nth(1).unwrap();
+        assert!(!response.contains("password_hash"));
+
+        let module = file(&files, "src/models/mod.rs");
+        assert!(module.contains("pub use user::{Model as UserModel, NewUser, UserChanges,"));
+    }
+
+    #[test]
+    fn test_handlers_run_queries_on_the_pool() {
+        let project = blog_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_handlers(&ctx);
+        let posts = file(&files, "src/handlers/post.rs");
+
+        assert!(posts.contains("use crate::state::{AppState, run};"));
+        assert!(posts.contains("pub async fn list_posts("));
+        assert!(posts.contains("posts::table.count().get_result(conn)?"));
+        assert!(posts.contains("pub async fn create_post("));
+        assert!(posts.contains(".returning(Model::as_returning())"));
+        assert!(posts.contains("pub async fn delete_post("));
+        assert!(posts.contains("diesel::delete(posts::table.find(id))"));
+        assert!(!posts.contains("sea_orm"));
+    }
+
+    #[test]
+    fn test_soft_delete_scopes_and_restore() {
+        let mut project = blog_project();
+        for entity in project.entities.values_mut() {
+            entity.config.soft_delete = true;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_handlers(&ctx);
+        let posts = file(&files, "src/handlers/post.rs");
+
+        assert!(posts.contains("use crate::handlers::SoftDeleteParams;"));
+        assert!(posts.contains("let include_deleted = scope.include_deleted.unwrap_or(false);"));
+        assert!(posts.contains(
+            ".filter(posts::deleted_at.is_null().or(include_deleted.into_sql::<diesel::sql_types::Bool>()))"
+        ));
+        assert!(
+            posts.contains(
+                "diesel::update(posts::table.find(id).filter(posts::deleted_at.is_null()))"
+            )
+        );
+        assert!(posts.contains("pub async fn restore_post("));
+        assert!(posts.contains("posts::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>)"));
+
+        let models = DieselBackend.generate_models(&ctx);
+        assert!(
+            file(&models, "src/models/post.rs")
+                .contains("            deleted_at: model.deleted_at,\n")
+        );
+    }
+
+    #[test]
+    fn test_conditional_reads() {
+        let mut project = blog_project();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint
+                .get_operation_mut(OperationType::Read)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::UpdatedAt).with_last_modified();
+            endpoint
+                .get_operation_mut(OperationType::ReadAll)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::ContentHash);
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_handlers(&ctx);
+        let posts = file(&files, "src/handlers/post.rs");
+
+        assert!(posts.contains("use crate::http_cache;\n"));
+        assert!(posts.contains(
+            "    headers: axum::http::HeaderMap,\n) -> Result<axum::response::Response, AppError> {"
+        ));
+        assert!(posts.contains(
+            "        .versions(std::iter::once((item.id, item.updated_at)))\n        .last_modified(std::iter::once((item.id, item.updated_at)));\n"
+        ));
+        assert!(posts.contains("    validators.respond(&headers, PostResponse::from(item))\n"));
+        assert!(
+            posts.contains(
+                "let validators = http_cache::Validators::new()\n        .hash_body();\n"
+            )
+        );
+        assert!(posts.contains(
+            "    validators.respond(&headers, PaginatedResponse::new(items, total, page, per_page))\n"
+        ));
+    }
+
+    #[test]
+    fn test_mysql_reads_inserted_rows_back() {
+        let mut project = blog_project();
+        project.config.database = DatabaseType::MySQL;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_handlers(&ctx);
+        let posts = file(&files, "src/handlers/post.rs");
+
+        assert!(!posts.contains("returning("));
+        assert!(posts.contains("let id = row.id;"));
+        assert!(!posts.contains("last_insert_id"));
+        assert!(posts.contains(".execute(conn)?;"));
+    }
+
+    #[test]
+    fn test_migrations_use_diesel_layout() {
+        let project = blog_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_migrations(&ctx);
+
+        assert_eq!(files.len(), 4);
+        let up = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().ends_with("_create_users/up.sql"))
+            .unwrap();
+        assert!(up.content.contains("CREATE TABLE IF NOT EXISTS"));
+        let down = files
+            .iter()
+            .find(|f| f.path.to_string_lossy().ends_with("_create_users/down.sql"))
+            .unwrap();
+        assert_eq!(down.content, "DROP TABLE IF EXISTS \"users\";\n");
+
+        let ctx =
+            GenerationContext::from_project(&project, GeneratorConfig::new().without_migrations());
+        assert!(DieselBackend.generate_migrations(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_refresh_tokens_migration() {
+        let mut project = blog_project();
+        project.config.auth = imortal_ir::AuthConfig::jwt().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = DieselBackend.generate_migrations(&ctx);
+
+        let up = files
+            .iter()
+            .find(|f| {
+                f.path
+                    .to_string_lossy()
+                    .ends_with("_create_refresh_tokens/up.sql")
+            })
+            .unwrap();
+        assert!(
+            up.content
+                .contains("CREATE TABLE IF NOT EXISTS \"refresh_tokens\"")
+        );
+        let down = files
+            .iter()
+            .find(|f| {
+                f.path
+                    .to_string_lossy()
+                    .ends_with("_create_refresh_tokens/down.sql")
+            })
+            .unwrap();
+        assert_eq!(down.content, "DROP TABLE IF EXISTS \"refresh_tokens\";\n");
+    }
+
+    #[test]
+    fn test_state_exposes_pool_and_runner() {
+        let mut project = blog_project();
+        project.config.read_replicas = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let state = DieselBackend.generate_state(&ctx).content;
+
+        assert!(state.contains("pub type DbConnection = diesel::PgConnection;"));
+        assert!(state.contains("pub db_read: DbPool,"));
+        assert!(state.contains("tokio::task::spawn_blocking"));
+    }
+
+    #[test]
+    fn test_unsupported_features_are_dropped() {
+        let mut project = blog_project();
+        let post_id = project
+            .entities
+            .values()
+            .find(|e| e.name == "Post")
+            .unwrap()
+            .id;
+        let endpoint = project
+            .endpoints
+            .values_mut()
+            .find(|ep| ep.entity_id == post_id)
+            .unwrap();
+        if let Some(op) = endpoint
+            .operations
+            .iter_mut()
+            .find(|op: &&mut CrudOperation| op.operation_type == OperationType::ReadAll)
+        {
+            op.list.filtering = true;
+            op.list.sorting = true;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let post = ctx.entities().iter().find(|e| e.name == "Post").unwrap();
+        let info = EntityInfo::new(post, &ctx);
+
+        assert!(crate::rust::handlers::list_filters(&info).is_empty());
+        assert!(crate::rust::handlers::list_sort_columns(&info, &ctx).is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_column_reasons() {
+        assert!(unsupported_column_reason(&DataType::Uuid, DatabaseType::PostgreSQL).is_none());
+        assert!(unsupported_column_reason(&DataType::Uuid, DatabaseType::SQLite).is_some());
+        assert!(
+            unsupported_column_reason(
+                &DataType::Optional(Box::new(DataType::DateTime)),
+                DatabaseType::MySQL
+            )
+            .is_some()
+        );
+        assert!(
+            unsupported_column_reason(
+                &DataType::Array(Box::new(DataType::Int32)),
+                DatabaseType::PostgreSQL
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! # Enum Generator
+//!
+//! Generates `src/models/enums.rs`: one Rust enum per enum type in
+//! [`GenerationContext::enums`] — the project's enum types plus inline enums
+//! declared by fields.
+//!
+//! Every enum serializes as its variant values (`"draft"`), lists them in
+//! `ALL`, and implements `Display` and `FromStr`. The ORM backend adds the
+//! database mapping (see [`OrmBackend::enum_mapping`]):
+//!
+//! - **SeaORM**: `DeriveActiveEnum`, stored in the PostgreSQL enum type
+//!   created by the migrations, or in a text column elsewhere
+//! - **Diesel**: `ToSql`/`FromSql` for the PostgreSQL enum type declared in
+//!   `src/schema.rs`, or for `Text` elsewhere
+//!
+//! Enums also derive `utoipa::ToSchema` when OpenAPI is enabled and
+//! `async_graphql::Enum` when the API serves GraphQL.
+//!
+//! [`OrmBackend::enum_mapping`]: crate::rust::orm::OrmBackend::enum_mapping
+
+use imortal_ir::EnumDef;
+
+use crate::context::GenerationContext;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// EnumMapping
+// ============================================================================
+
+/// The ORM-specific parts of a generated enum.
+#[derive(Debug, Clone, Default)]
+pub struct EnumMapping {
+    /// Extra derives, e.g. `DeriveActiveEnum`
+    pub derives: Vec<String>,
+    /// Attributes on the enum, e.g. `#[sea_orm(…)]`
+    pub attributes: Vec<String>,
+    /// Attribute on each variant, given the variant's value
+    pub variant_attribute: Option<fn(&str) -> String>,
+    /// Items following the enum, e.g. `impl ToSql<…>`
+    pub impls: String,
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/models/enums.rs`, or nothing when the project has no enums.
+pub fn generate_enums(ctx: &GenerationContext) -> Option<GeneratedFile> {
+    if ctx.enums().is_empty() {
+        return None;
+    }
+
+    let orm = ctx.orm_backend();
+    let mut content = file_header("Enum types shared by the models.");
+    content.push_str(&orm.enum_module_imports(ctx));
+    content.push_str("use serde::{Deserialize, Serialize};\n\n");
+
+    for def in ctx.enums() {
+        let mut mapping = orm.enum_mapping(def, ctx);
+        if ctx.openapi_enabled() {
+            mapping.derives.push("utoipa::ToSchema".to_string());
+        }
+        if ctx.graphql_enabled() {
+            mapping.derives.push("async_graphql::Enum".to_string());
+        }
+        content.push_str(&enum_definition(def, &mapping, ctx));
+        content.push('\n');
+    }
+
+    Some(GeneratedFile::new(
+        "src/models/enums.rs",
+        content.trim_end().to_string() + "\n",
+        FileType::Rust,
+    ))
+}
+
+/// The enum, its inherent impl and its `Display`/`FromStr` impls, followed
+/// by the mapping's impls. Also used for the frontend's shared crate, with
+/// an empty mapping.
+pub(crate) fn enum_definition(
+    def: &EnumDef,
+    mapping: &EnumMapping,
+    ctx: &GenerationContext,
+) -> String {
+    let name = GenerationContext::pascal(&def.name);
+    let variants: Vec<(String, &str)> = def
+        .variants
+        .iter()
+        .map(|v| (variant_ident(v), v.as_str()))
+        .collect();
+
+    let mut derives: Vec<String> = [
+        "Debug",
+        "Clone",
+        "Copy",
+        "PartialEq",
+        "Eq",
+        "Hash",
+        "Serialize",
+        "Deserialize",
+    ]
+    .iter()
+    .map(|d| d.to_string())
+    .collect();
+    derives.extend(mapping.derives.iter().cloned());
+
+    let mut out = String::with_capacity(1024);
+    let description = def
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("Values of the `{}` enum.", name));
+    out.push_str(&doc_comment(Some(&description), ctx));
+    out.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+    for attribute in &mapping.attributes {
+        out.push_str(attribute);
+        out.push('\n');
+    }
+    out.push_str(&format!("pub enum {} {{\n", name));
+    for (ident, value) in &variants {
+        if let Some(attribute) = mapping.variant_attribute {
+            out.push_str(&format!("    {}\n", attribute(value)));
+        }
+        if ident != value {
+            out.push_str(&format!("    #[serde(rename = \"{}\")]\n", value));
+        }
+        out.push_str(&format!("    {},\n", ident));
+    }
+    out.push_str("}\n\n");
+
+    // ── Inherent impl ────────────────────────────────────────────────────
+    let all: Vec<String> = variants
+        .iter()
+        .map(|(ident, _)| format!("Self::{}", ident))
+        .collect();
+    out.push_str(&format!("impl {} {{\n", name));
+    out.push_str("    /// Every variant, in declaration order.\n");
+    out.push_str(&format!(
+        "    pub const ALL: [Self; {}] = [{}];\n\n",
+        all.len(),
+        all.join(", ")
+    ));
+    out.push_str("    /// The value stored in the database and sent over the wire.\n");
+    out.push_str("    pub fn as_str(&self) -> &'static str {\n        match self {\n");
+    for (ident, value) in &variants {
+        out.push_str(&format!("            Self::{} => \"{}\",\n", ident, value));
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    // ── Display / FromStr ────────────────────────────────────────────────
+    out.push_str(&format!(
+        r#"impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+
+impl std::str::FromStr for {name} {{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        Self::ALL
+            .into_iter()
+            .find(|v| v.as_str() == s)
+            .ok_or_else(|| format!("unknown {name} value: {{}}", s))
+    }}
+}}
+"#
+    ));
+
+    if !mapping.impls.is_empty() {
+        out.push('\n');
+        out.push_str(&mapping.impls);
+    }
+
+    out
+}
+
+/// `use` line for the enums appearing in `types`, from `module`, e.g.
+/// `use super::enums::{PostStatus, Role};`. Empty when none appear.
+pub(crate) fn enum_imports<'t>(
+    ctx: &GenerationContext,
+    module: &str,
+    types: impl IntoIterator<Item = &'t str>,
+) -> String {
+    let idents: Vec<&str> = types
+        .into_iter()
+        .flat_map(|ty| ty.split(|c: char| !(c.is_alphanumeric() || c == '_')))
+        .collect();
+    let used: Vec<String> = ctx
+        .enums()
+        .iter()
+        .map(|def| GenerationContext::pascal(&def.name))
+        .filter(|name| idents.contains(&name.as_str()))
+        .collect();
+
+    match used.as_slice() {
+        [] => String::new(),
+        [one] => format!("use {}::{};\n", module, one),
+        many => format!("use {}::{{{}}};\n", module, many.join(", ")),
+    }
+}
+
+/// `pub use enums::{…};` re-exporting every enum from `models/mod.rs`.
+/// Empty when the project has no enums.
+pub(crate) fn enum_reexport(ctx: &GenerationContext) -> String {
+    let names: Vec<String> = ctx
+        .enums()
+        .iter()
+        .map(|def| GenerationContext::pascal(&def.name))
+        .collect();
+    match names.as_slice() {
+        [] => String::new(),
+        [one] => format!("pub use enums::{};\n", one),
+        many => format!("pub use enums::{{{}}};\n", many.join(", ")),
+    }
+}
+
+/// Rust identifier of a variant: `in_review` → `InReview`.
+pub(crate) fn variant_ident(value: &str) -> String {
+    GenerationContext::pascal(value)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{ApiStyle, DatabaseType, Entity, Field, Orm, ProjectConfig, ProjectGraph};
+
+    fn project(config: ProjectConfig) -> ProjectGraph {
+        let mut project = ProjectGraph::with_config("blog", config);
+        let status = EnumDef::new("PostStatus")
+            .with_description("Where a post is in its life")
+            .with_variants(["draft", "in-review", "Published"]);
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("status", status.data_type()));
+        project.add_entity(post);
+        project.add_enum(status);
+        project
+    }
+
+    fn enums_file(project: &ProjectGraph) -> String {
+        generate_enums(&GenerationContext::from_project_default(project))
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn test_no_enums_no_file() {
+        let ctx = GenerationContext::from_project_default(&ProjectGraph::new("empty"));
+        assert!(generate_enums(&ctx).is_none());
+    }
+
+    #[test]
+    fn test_sea_orm_postgres_enum() {
+        let content = enums_file(&project(ProjectConfig::default()));
+
+        assert!(content.contains("/// Where a post is in its life\n"));
+        assert!(content.contains("EnumIter, DeriveActiveEnum, utoipa::ToSchema)]"));
+        assert!(content.contains(
+            "#[sea_orm(rs_type = \"String\", db_type = \"Enum\", enum_name = \"post_status\")]"
+        ));
+        assert!(content.contains(
+            "    #[sea_orm(string_value = \"in-review\")]\n    #[serde(rename = \"in-review\")]\n    InReview,\n"
+        ));
+        // No rename when the value is already the identifier
+        assert!(content.contains("    #[sea_orm(string_value = \"Published\")]\n    Published,\n"));
+        assert!(content.contains(
+            "pub const ALL: [Self; 3] = [Self::Draft, Self::InReview, Self::Published];"
+        ));
+        assert!(content.contains("Self::InReview => \"in-review\","));
+        assert!(content.contains("impl std::str::FromStr for PostStatus"));
+        assert!(!content.contains("async_graphql"));
+    }
+
+    #[test]
+    fn test_sea_orm_text_column_elsewhere() {
+        let config = ProjectConfig::default().with_database(DatabaseType::SQLite);
+        let content = enums_file(&project(config));
+        assert!(content.contains("#[sea_orm(rs_type = \"String\", db_type = \"Text\")]"));
+    }
+
+    #[test]
+    fn test_diesel_enum() {
+        let content = enums_file(&project(ProjectConfig::default().with_orm(Orm::Diesel)));
+        assert!(content.contains("#[diesel(sql_type = crate::schema::sql_types::PostStatus)]"));
+        assert!(
+            content.contains("impl ToSql<crate::schema::sql_types::PostStatus, Pg> for PostStatus")
+        );
+        assert!(
+            content
+                .contains("impl FromSql<crate::schema::sql_types::PostStatus, Pg> for PostStatus")
+        );
+
+        let config = ProjectConfig::default()
+            .with_orm(Orm::Diesel)
+            .with_database(DatabaseType::MySQL);
+        let content = enums_file(&project(config));
+        assert!(content.contains("#[diesel(sql_type = Text)]"));
+        assert!(content.contains("impl<DB> ToSql<Text, DB> for PostStatus"));
+    }
+
+    #[test]
+    fn test_graphql_derive() {
+        let config = ProjectConfig::default().with_api_style(ApiStyle::Both);
+        let content = enums_file(&project(config));
+        assert!(content.contains("utoipa::ToSchema, async_graphql::Enum)]"));
+    }
+
+    #[test]
+    fn test_enum_imports() {
+        let ctx = GenerationContext::from_project_default(&project(ProjectConfig::default()));
+        assert_eq!(
+            enum_imports(&ctx, "super::enums", ["Option<PostStatus>", "String"]),
+            "use super::enums::PostStatus;\n"
+        );
+        assert_eq!(enum_imports(&ctx, "super::enums", ["PostStatusCode"]), "");
+    }
+}
@@ -2,9 +2,9 @@
 //!
 //! Generates `src/error.rs` for the generated project. The error module
 //! defines an `AppError` enum that serves as the unified error type across
-//! all Axum handlers. It implements `IntoResponse` so that errors are
-//! automatically converted into structured JSON responses with appropriate
-//! HTTP status codes.
+//! all handlers. It implements `IntoResponse` (Axum) or `ResponseError`
+//! (Actix Web) so that errors are automatically converted into RFC 7807
+//! `application/problem+json` responses with appropriate HTTP status codes.
 //!
 //! ## Generated Error Variants
 //!
@@ -12,31 +12,45 @@
 //! |------------------|-------------|------------------------------------|
 //! | `NotFound`       | 404         | Entity not found by ID             |
 //! | `BadRequest`     | 400         | Malformed request body / params    |
-//! | `Validation`     | 422         | `validator` crate validation fails |
+//! | `Validation`     | 422         | Hand-written validation failure    |
+//! | `InvalidFields`  | 422         | `validator` crate validation fails |
 //! | `Unauthorized`   | 401         | Missing or invalid auth token      |
 //! | `Forbidden`      | 403         | Insufficient roles / permissions   |
 //! | `Conflict`       | 409         | Unique constraint violation        |
-//! | `Database`       | 500         | SeaORM / SQLx errors               |
+//! | `DeleteBlocked`  | 409         | Restricting references (opt-in)    |
+//! | `RateLimited`    | 429         | Route quota used up (rate limits)  |
+//! | `Database`       | 500         | ORM / driver errors                |
 //! | `Internal`       | 500         | Catch-all for unexpected errors    |
 //!
 //! ## `From` Implementations
 //!
-//! - `From<sea_orm::DbErr>` — maps database errors, with special handling
-//!   for unique-constraint violations (→ `Conflict`)
-//! - `From<validator::ValidationErrors>` — maps validation failures
+//! - `From<sea_orm::DbErr>` / `From<diesel::result::Error>` — maps database
+//!   errors, with special handling for unique-constraint violations
+//!   (→ `Conflict`)
+//! - `From<validator::ValidationErrors>` — maps validation failures to
+//!   `InvalidFields`, keeping each rule's message key and parameters
 //! - `From<std::io::Error>` — maps I/O errors
 //! - `From<anyhow::Error>` — maps generic errors
 //!
-//! ## `IntoResponse` Implementation
+//! ## Response Conversion
 //!
-//! Every variant is serialised as:
+//! Every variant is serialised as RFC 7807 problem details, with the
+//! machine-readable error code as the `code` extension member:
 //!
 //! ```json
 //! {
-//!   "error": "not_found",
-//!   "message": "The requested resource was not found"
+//!   "type": "about:blank",
+//!   "title": "Not Found",
+//!   "status": 404,
+//!   "detail": "The requested resource was not found",
+//!   "code": "not_found"
 //! }
 //! ```
+//!
+//! `InvalidFields` adds a `fields` list whose messages come from the
+//! `locales/` catalog of the request's `Accept-Language` (see
+//! [`crate::rust::i18n`]). The auth middleware answers with the same
+//! body, through `AppError`.
 
 use crate::context::GenerationContext;
 use crate::rust::file_header;
@@ -58,6 +72,8 @@ pub fn generate_error(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 
 fn build_error(ctx: &GenerationContext) -> String {
     let auth_enabled = ctx.auth_enabled();
+    let delete_blockers = ctx.report_delete_blockers();
+    let rate_limited = ctx.rate_limiting_enabled();
 
     let mut out = String::with_capacity(8192);
 
@@ -66,17 +82,18 @@ fn build_error(ctx: &GenerationContext) -> String {
     ));
 
     // ── Imports ──────────────────────────────────────────────────────────
+    let framework = ctx.framework_backend();
+    out.push_str(framework.error_imports());
     out.push_str(
         "\
-use axum::{
-    http::StatusCode,
-    response::{IntoResponse, Response},
-    Json,
-};
 use serde::Serialize;
 use serde_json::json;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::i18n;
+
 ",
     );
 
@@ -89,8 +106,8 @@ use thiserror::Error;
 
 /// Unified application error type.
 ///
-/// Every handler returns `Result<T, AppError>`. The `IntoResponse`
-/// implementation converts each variant into a JSON response with the
+/// Every handler returns `Result<T, AppError>`. The response conversion
+/// below turns each variant into a problem details response with the
 /// appropriate HTTP status code.
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -102,10 +119,15 @@ pub enum AppError {
     #[error(\"Bad request: {0}\")]
     BadRequest(String),
 
-    /// One or more fields failed validation (422).
+    /// Hand-written validation failed (422).
     #[error(\"Validation error: {0}\")]
     Validation(String),
 
+    /// One or more fields failed their `#[validate]` rules (422). The
+    /// messages are localized when the response is built.
+    #[error(\"Validation error: {}\", summary(.0, i18n::DEFAULT_LOCALE))]
+    InvalidFields(Vec<FieldError>),
+
 ",
     );
 
@@ -130,6 +152,34 @@ pub enum AppError {
     #[error(\"Conflict: {0}\")]
     Conflict(String),
 
+",
+    );
+
+    if delete_blockers {
+        out.push_str(
+            "\
+    /// A delete was refused because other records still reference the row (409).
+    #[error(\"Cannot delete: {} kind(s) of record still reference it\", .0.len())]
+    DeleteBlocked(Vec<BlockingRecords>),
+
+",
+        );
+    }
+
+    if rate_limited {
+        out.push_str(
+            "\
+    /// The caller used up a route's request quota (429, or the route's
+    /// configured status).
+    #[error(\"{0}\")]
+    RateLimited(String),
+
+",
+        );
+    }
+
+    out.push_str(
+        "\
     /// A database error occurred (500).
     #[error(\"Database error: {0}\")]
     Database(String),
@@ -139,18 +189,17 @@ pub enum AppError {
     Internal(String),
 }
 
+/// Media type of every error response (RFC 7807 problem details).
+pub const PROBLEM_JSON: &str = \"application/problem+json\";
+
 ",
     );
 
-    // ── IntoResponse ─────────────────────────────────────────────────────
+    // ── Response conversion ──────────────────────────────────────────────
+    out.push_str(framework.error_response_open());
     out.push_str(
         "\
-// ============================================================================
-// IntoResponse — convert AppError into an HTTP response
-// ============================================================================
-
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
+        let locale = i18n::current_locale();
         let (status, error_code, message) = match &self {
             AppError::NotFound => (
                 StatusCode::NOT_FOUND,
@@ -167,6 +216,11 @@ impl IntoResponse for AppError {
                 \"validation_error\",
                 self.to_string(),
             ),
+            AppError::InvalidFields(errors) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                \"validation_error\",
+                summary(errors, locale),
+            ),
 ",
     );
 
@@ -194,6 +248,35 @@ impl IntoResponse for AppError {
                 \"conflict\",
                 self.to_string(),
             ),
+",
+    );
+
+    if delete_blockers {
+        out.push_str(
+            "\
+            AppError::DeleteBlocked(_) => (
+                StatusCode::CONFLICT,
+                \"delete_blocked\",
+                self.to_string(),
+            ),
+",
+        );
+    }
+
+    if rate_limited {
+        out.push_str(
+            "\
+            AppError::RateLimited(_) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                \"rate_limited\",
+                self.to_string(),
+            ),
+",
+        );
+    }
+
+    out.push_str(
+        "\
             AppError::Database(msg) => {
                 // Log the full database error but return a generic message
                 tracing::error!(\"Database error: {}\", msg);
@@ -213,77 +296,171 @@ impl IntoResponse for AppError {
             }
         };
 
-        let body = json!({
-            \"error\": error_code,
-            \"message\": message,
+",
+    );
+
+    out.push_str(
+        "\
+        // `about:blank`: the status code says what went wrong, `code` which rule
+        let mut body = json!({
+            \"type\": \"about:blank\",
+            \"title\": status.canonical_reason().unwrap_or(\"Error\"),
+            \"status\": status.as_u16(),
+            \"detail\": message,
+            \"code\": error_code,
         });
+        if let AppError::InvalidFields(errors) = &self {
+            body[\"fields\"] = field_messages(errors, locale);
+        }
+",
+    );
+
+    if delete_blockers {
+        out.push_str(
+            "\
+        if let AppError::DeleteBlocked(blocking) = &self {
+            body[\"blocking\"] = json!(blocking);
+        }
+",
+        );
+    }
+
+    out.push('\n');
+    out.push_str(framework.error_response_close());
+    out.push_str("    }\n}\n\n");
+
+    if delete_blockers {
+        out.push_str(
+            "\
+/// Records of one kind that prevent a delete (part of a `delete_blocked` response).
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockingRecords {
+    /// Entity holding the reference.
+    pub entity: &'static str,
+    /// Foreign key field that references the row being deleted.
+    pub field: &'static str,
+    /// How many records reference it.
+    pub count: u64,
+}
+
+",
+        );
+    }
+
+    // ── FieldError (localized validation failures) ───────────────────────
+    out.push_str(
+        "\
+/// One failed validation rule (part of a `validation_error` response).
+#[derive(Debug, Clone)]
+pub struct FieldError {
+    /// Field path, e.g. `title` or `posts[0].title` for nested payloads.
+    pub field: String,
+    /// Message key of the rule in the `locales/` catalogs, e.g.
+    /// `validation.length.max`.
+    pub code: String,
+    /// The rule's parameters (`min`, `max`, …), filled into the message.
+    pub params: HashMap<Cow<'static, str>, serde_json::Value>,
+    /// Message a custom validator attached, used when no catalog has `code`.
+    pub fallback: Option<String>,
+}
 
-        (status, Json(body)).into_response()
+impl FieldError {
+    /// The message for this failure in `locale`.
+    pub fn message(&self, locale: &str) -> String {
+        i18n::translate(locale, &self.code, &self.params)
+            .or_else(|| self.fallback.clone())
+            .or_else(|| i18n::translate(locale, \"validation.invalid\", &self.params))
+            .unwrap_or_else(|| self.code.clone())
     }
 }
 
+/// `field: message` for every failure, joined with `; `.
+fn summary(errors: &[FieldError], locale: &str) -> String {
+    errors
+        .iter()
+        .map(|e| format!(\"{}: {}\", e.field, e.message(locale)))
+        .collect::<Vec<_>>()
+        .join(\"; \")
+}
+
+/// The `fields` list of a `validation_error` body.
+fn field_messages(errors: &[FieldError], locale: &str) -> serde_json::Value {
+    errors
+        .iter()
+        .map(|e| {
+            json!({
+                \"field\": e.field,
+                \"code\": e.code,
+                \"message\": e.message(locale),
+            })
+        })
+        .collect()
+}
+
 ",
     );
 
-    // ── From<sea_orm::DbErr> ─────────────────────────────────────────────
+    // ── From<ORM error> ──────────────────────────────────────────────────
     out.push_str(
         "\
 // ============================================================================
 // From implementations
 // ============================================================================
 
-impl From<sea_orm::DbErr> for AppError {
-    fn from(err: sea_orm::DbErr) -> Self {
-        let msg = err.to_string();
-
-        // Detect unique constraint violations for a friendlier error
-        if msg.contains(\"duplicate key\")
-            || msg.contains(\"UNIQUE constraint failed\")
-            || msg.contains(\"Duplicate entry\")
-        {
-            return AppError::Conflict(
-                \"A record with the given unique field(s) already exists\".to_string(),
-            );
-        }
-
-        // Detect foreign key violations
-        if msg.contains(\"foreign key constraint\")
-            || msg.contains(\"FOREIGN KEY constraint failed\")
-        {
-            return AppError::BadRequest(
-                \"Referenced record does not exist\".to_string(),
-            );
-        }
-
-        AppError::Database(msg)
-    }
-}
-
 ",
     );
+    out.push_str(ctx.orm_backend().error_conversion());
+    out.push('\n');
 
     // ── From<validator::ValidationErrors> ─────────────────────────────────
     out.push_str(
         "\
 impl From<validator::ValidationErrors> for AppError {
     fn from(err: validator::ValidationErrors) -> Self {
-        // Build a human-readable summary of all validation failures
-        let mut messages: Vec<String> = Vec::new();
-
-        for (field, errors) in err.field_errors() {
-            for error in errors {
-                let msg = error
-                    .message
-                    .as_ref()
-                    .map(|m| m.to_string())
-                    .unwrap_or_else(|| {
-                        format!(\"Validation failed on field '{}': {:?}\", field, error.code)
+        let mut errors = Vec::new();
+        collect_field_errors(\"\", &err, &mut errors);
+        // validator keeps its errors in a HashMap; sort for stable output
+        errors.sort_by(|a, b| a.field.cmp(&b.field));
+        AppError::InvalidFields(errors)
+    }
+}
+
+/// Flatten validator's error tree into `field`, `parent.field` and
+/// `list[0].field` paths.
+fn collect_field_errors(
+    prefix: &str,
+    errors: &validator::ValidationErrors,
+    out: &mut Vec<FieldError>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!(\"{}.{}\", prefix, field)
+        };
+        match kind {
+            validator::ValidationErrorsKind::Field(failures) => {
+                for failure in failures {
+                    let mut params = failure.params.clone();
+                    // Never echo submitted values (passwords included) back
+                    params.remove(\"value\");
+                    out.push(FieldError {
+                        field: path.clone(),
+                        code: failure.code.to_string(),
+                        params,
+                        fallback: failure.message.as_ref().map(|m| m.to_string()),
                     });
-                messages.push(msg);
+                }
+            }
+            validator::ValidationErrorsKind::Struct(inner) => {
+                collect_field_errors(&path, inner, out);
+            }
+            validator::ValidationErrorsKind::List(items) => {
+                for (index, inner) in items {
+                    collect_field_errors(&format!(\"{}[{}]\", path, index), inner, out);
+                }
             }
         }
-
-        AppError::Validation(messages.join(\"; \"))
     }
 }
 
@@ -314,6 +491,11 @@ impl From<anyhow::Error> for AppError {
 ",
     );
 
+    // ── GraphQL error extensions (conditional) ───────────────────────────
+    if ctx.graphql_enabled() {
+        out.push_str(&graphql_error_extensions(ctx));
+    }
+
     // ── ErrorResponse (typed response structure) ─────────────────────────
     out.push_str(
         "\
@@ -321,18 +503,28 @@ impl From<anyhow::Error> for AppError {
 // Typed error response (for documentation / OpenAPI)
 // ============================================================================
 
-/// JSON structure returned for all error responses.
+/// RFC 7807 problem details returned for all error responses.
 ///
 /// This struct exists primarily for OpenAPI schema generation (via `utoipa`).
-/// The actual serialisation is handled inline by the `IntoResponse`
+/// The actual serialisation is handled inline by the response conversion
 /// implementation above.
 #[derive(Debug, Clone, Serialize)]
-pub struct ErrorResponse {
-    /// Machine-readable error code (e.g. `\"not_found\"`, `\"validation_error\"`).
-    pub error: String,
+pub struct ProblemDetails {
+    /// Problem type URI; always `about:blank`, so `title` is the status text.
+    #[serde(rename = \"type\")]
+    pub problem_type: String,
+
+    /// Short summary of the status, e.g. `\"Not Found\"`.
+    pub title: String,
+
+    /// HTTP status code, repeated from the response.
+    pub status: u16,
+
+    /// Human-readable explanation of this occurrence.
+    pub detail: String,
 
-    /// Human-readable error description.
-    pub message: String,
+    /// Machine-readable error code (e.g. `\"not_found\"`, `\"validation_error\"`).
+    pub code: String,
 }
 
 ",
@@ -426,23 +618,28 @@ mod tests {
         let err = AppError::internal(\"something broke\");
         assert_eq!(err.to_string(), \"Internal error: something broke\");
     }
-
-    #[test]
-    fn test_from_db_err_duplicate_key() {
-        let db_err = sea_orm::DbErr::Query(sea_orm::RuntimeErr::Internal(
-            \"duplicate key value violates unique constraint\".to_string(),
-        ));
-        let app_err = AppError::from(db_err);
-        assert!(matches!(app_err, AppError::Conflict(_)));
-    }
-
+",
+    );
+    out.push_str(ctx.orm_backend().error_conversion_tests());
+    out.push_str(
+        "
     #[test]
-    fn test_from_db_err_generic() {
-        let db_err = sea_orm::DbErr::Conn(sea_orm::RuntimeErr::Internal(
-            \"connection refused\".to_string(),
-        ));
-        let app_err = AppError::from(db_err);
-        assert!(matches!(app_err, AppError::Database(_)));
+    fn test_from_validation_errors_uses_catalog() {
+        let mut failure = validator::ValidationError::new(\"validation.length.max\");
+        failure.add_param(Cow::from(\"max\"), &5);
+        failure.add_param(Cow::from(\"value\"), &\"far too long\");
+        let mut errors = validator::ValidationErrors::new();
+        errors.add(\"title\", failure);
+
+        let app_err = AppError::from(errors);
+        let AppError::InvalidFields(fields) = &app_err else {
+            panic!(\"expected InvalidFields, got {:?}\", app_err);
+        };
+        assert!(!fields[0].params.contains_key(\"value\"));
+        assert_eq!(
+            app_err.to_string(),
+            \"Validation error: title: must be at most 5 characters long\"
+        );
     }
 
     #[test]
@@ -482,6 +679,85 @@ mod tests {
     out
 }
 
+/// `impl ErrorExtensions for AppError`: the GraphQL counterpart of the
+/// response conversion, carrying the same `code` under `extensions` and
+/// hiding database and internal details the same way.
+fn graphql_error_extensions(ctx: &GenerationContext) -> String {
+    let mut out = String::from(
+        "\
+// ============================================================================
+// GraphQL errors
+// ============================================================================
+
+/// GraphQL resolvers report an `AppError` as a message plus its error code
+/// in `extensions.code`, e.g. `{ \"message\": \"…\", \"extensions\": { \"code\": \"not_found\" } }`.
+impl async_graphql::ErrorExtensions for AppError {
+    fn extend(&self) -> async_graphql::Error {
+        let (code, message) = match self {
+            AppError::NotFound => (\"not_found\", self.to_string()),
+            AppError::BadRequest(_) => (\"bad_request\", self.to_string()),
+            AppError::Validation(_) => (\"validation_error\", self.to_string()),
+            AppError::InvalidFields(errors) => {
+                (\"validation_error\", summary(errors, i18n::current_locale()))
+            }
+",
+    );
+    if ctx.auth_enabled() {
+        out.push_str(
+            "\
+            AppError::Unauthorized(_) => (\"unauthorized\", self.to_string()),
+            AppError::Forbidden(_) => (\"forbidden\", self.to_string()),
+",
+        );
+    }
+    out.push_str("            AppError::Conflict(_) => (\"conflict\", self.to_string()),\n");
+    if ctx.report_delete_blockers() {
+        out.push_str(
+            "            AppError::DeleteBlocked(_) => (\"delete_blocked\", self.to_string()),\n",
+        );
+    }
+    if ctx.rate_limiting_enabled() {
+        out.push_str(
+            "            AppError::RateLimited(_) => (\"rate_limited\", self.to_string()),\n",
+        );
+    }
+    out.push_str(
+        "\
+            AppError::Database(msg) => {
+                tracing::error!(\"Database error: {}\", msg);
+                (\"database_error\", \"An internal database error occurred\".to_string())
+            }
+            AppError::Internal(msg) => {
+                tracing::error!(\"Internal error: {}\", msg);
+                (\"internal_error\", \"An unexpected error occurred\".to_string())
+            }
+        };
+
+        async_graphql::Error::new(message).extend_with(|_, extensions| {
+            extensions.set(\"code\", code);
+            if let AppError::InvalidFields(errors) = self {
+                let fields = field_messages(errors, i18n::current_locale());
+                if let Ok(value) = async_graphql::Value::from_json(fields) {
+                    extensions.set(\"fields\", value);
+                }
+            }
+",
+    );
+    if ctx.report_delete_blockers() {
+        out.push_str(
+            "\
+            if let AppError::DeleteBlocked(blocking) = self {
+                if let Ok(value) = async_graphql::Value::from_json(json!(blocking)) {
+                    extensions.set(\"blocking\", value);
+                }
+            }
+",
+        );
+    }
+    out.push_str("        })\n    }\n}\n\n");
+    out
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -565,9 +841,11 @@ mod tests {
         let files = generate_error(&ctx);
         let content = &files[0].content;
 
-        assert!(content.contains("pub struct ErrorResponse"));
-        assert!(content.contains("pub error: String"));
-        assert!(content.contains("pub message: String"));
+        assert!(content.contains("pub struct ProblemDetails"));
+        assert!(content.contains("    #[serde(rename = \"type\")]\n    pub problem_type: String,"));
+        assert!(content.contains("pub status: u16"));
+        assert!(content.contains("pub detail: String"));
+        assert!(content.contains("pub code: String"));
     }
 
     #[test]
@@ -602,6 +880,23 @@ mod tests {
         assert!(!content.contains("StatusCode::FORBIDDEN"));
     }
 
+    #[test]
+    fn test_error_rate_limited_only_with_rate_limits() {
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!generate_error(&ctx)[0].content.contains("RateLimited"));
+
+        let user = project.add_entity(imortal_ir::Entity::new("User"));
+        project.add_endpoint(imortal_ir::EndpointGroup::new(user, "User"));
+        project.config.rate_limit = Some(imortal_ir::RateLimit::permissive());
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(content.contains("    RateLimited(String),"));
+        assert!(
+            content.contains("StatusCode::TOO_MANY_REQUESTS,\n                \"rate_limited\",")
+        );
+    }
+
     #[test]
     fn test_error_db_duplicate_key_detection() {
         let project = ProjectGraph::new("test");
@@ -648,10 +943,25 @@ mod tests {
         let files = generate_error(&ctx);
         let content = &files[0].content;
 
-        // Responses should be structured JSON with error and message fields
+        // Responses should be RFC 7807 problem details
         assert!(content.contains("json!({"));
-        assert!(content.contains("\"error\": error_code"));
-        assert!(content.contains("\"message\": message"));
+        assert!(content.contains("\"type\": \"about:blank\""));
+        assert!(content.contains("\"title\": status.canonical_reason().unwrap_or(\"Error\")"));
+        assert!(content.contains("\"status\": status.as_u16()"));
+        assert!(content.contains("\"detail\": message"));
+        assert!(content.contains("\"code\": error_code"));
+        assert!(content.contains("pub const PROBLEM_JSON: &str = \"application/problem+json\";"));
+        assert!(content.contains(
+            "(status, [(header::CONTENT_TYPE, PROBLEM_JSON)], Json(body)).into_response()"
+        ));
+
+        let mut project = ProjectGraph::new("test");
+        project.config.framework = imortal_ir::WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(content.contains(
+            "HttpResponse::build(status)\n            .content_type(PROBLEM_JSON)\n            .json(body)"
+        ));
     }
 
     #[test]
@@ -661,9 +971,14 @@ mod tests {
         let files = generate_error(&ctx);
         let content = &files[0].content;
 
-        // The From<ValidationErrors> impl should iterate field errors
-        assert!(content.contains("field_errors()"));
-        assert!(content.contains("messages.join"));
+        // The From<ValidationErrors> impl keeps each failure's key and
+        // parameters, nested payloads included, for localization
+        assert!(content.contains("AppError::InvalidFields(errors)"));
+        assert!(content.contains("fn collect_field_errors("));
+        assert!(content.contains("validator::ValidationErrorsKind::List(items)"));
+        assert!(content.contains("params.remove(\"value\");"));
+        assert!(content.contains("body[\"fields\"] = field_messages(errors, locale);"));
+        assert!(content.contains("let locale = i18n::current_locale();"));
     }
 
     #[test]
@@ -707,6 +1022,36 @@ mod tests {
         assert!(content.contains("fn test_from_io_error"));
     }
 
+    #[test]
+    fn test_error_delete_blocked_is_opt_in() {
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(!content.contains("DeleteBlocked"));
+        assert!(!content.contains("BlockingRecords"));
+
+        project.config.report_delete_blockers = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(content.contains("DeleteBlocked(Vec<BlockingRecords>)"));
+        assert!(content.contains("\"delete_blocked\""));
+        assert!(content.contains("pub struct BlockingRecords"));
+    }
+
+    #[test]
+    fn test_error_graphql_extensions() {
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(!content.contains("ErrorExtensions"));
+
+        project.config.api_style = imortal_ir::ApiStyle::Both;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_error(&ctx)[0].content;
+        assert!(content.contains("impl async_graphql::ErrorExtensions for AppError"));
+        assert!(content.contains("extensions.set(\"code\", code);"));
+    }
+
     #[test]
     fn test_error_with_auth_has_auth_tests() {
         let mut project = ProjectGraph::new("test");
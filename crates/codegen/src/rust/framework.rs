@@ -0,0 +1,754 @@
+//! # Web Framework Backends
+//!
+//! The HTTP layer of the generated project — handler signatures, the router,
+//! middleware, the error-to-response conversion and the server start-up — is
+//! written for one web framework. Each framework implements
+//! [`FrameworkBackend`]; the handler generators ask the backend selected by
+//! [`GenerationContext::framework`] for extractor parameters and response
+//! expressions, so the handler bodies themselves are shared.
+//!
+//! | Backend | Router | Handler state |
+//! |---------|--------|---------------|
+//! | [`AxumBackend`] | `create_router(state) -> Router` | `State(state): State<AppState>` |
+//! | [`ActixBackend`] | `configure_app(state)` for `App::configure` | `state: Data<AppState>` |
+
+use imortal_ir::{AuthStrategy, RealtimeTransport, UploadStorage, WebFramework};
+
+use crate::GeneratedFile;
+use crate::context::GenerationContext;
+use crate::rust::{auth, middleware, routes};
+
+pub use crate::rust::actix::ActixBackend;
+
+// ============================================================================
+// Extractors
+// ============================================================================
+
+/// A request extractor a handler module uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Extractor {
+    /// Shared application state
+    State,
+    /// Path parameters (`/{id}`)
+    Path,
+    /// Query-string parameters
+    Query,
+}
+
+// ============================================================================
+// Backend trait
+// ============================================================================
+
+/// The framework-specific parts of the generated Rust project.
+pub trait FrameworkBackend: Sync {
+    /// The web framework this backend generates for.
+    fn framework(&self) -> WebFramework;
+
+    /// `[dependencies]` lines for the framework and its middleware crates.
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String;
+
+    /// Extra dependency line the auth middleware needs for `strategy`.
+    fn auth_dependency(&self, strategy: AuthStrategy) -> Option<&'static str>;
+
+    /// `utoipa` feature integrating with the framework.
+    fn utoipa_feature(&self) -> &'static str;
+
+    /// `utoipa-swagger-ui` feature serving the UI from the framework.
+    fn swagger_ui_feature(&self) -> &'static str;
+
+    // ── Handlers ─────────────────────────────────────────────────────────
+
+    /// `use` lines for a handler module. `responses` is set when the module
+    /// builds `201 Created` or `204 No Content` responses.
+    fn handler_imports(&self, extractors: &[Extractor], responses: bool) -> String;
+
+    /// The handler parameter binding `state`.
+    fn state_param(&self) -> &'static str;
+
+    /// The handler parameter binding the `id` path segment.
+    fn path_param(&self, ty: &str) -> String;
+
+    /// Statement(s) opening a handler body that takes [`Self::path_param`],
+    /// leaving `id` as the plain key.
+    fn path_binding(&self) -> &'static str;
+
+    /// The handler parameter binding two path segments, `id` and `second`
+    /// (e.g. `/:id/tags/:tag_id`).
+    fn path_pair_param(&self, ty: &str, second: &str, second_ty: &str) -> String;
+
+    /// Statement(s) opening a handler body that takes
+    /// [`Self::path_pair_param`], leaving `id` and `second` as plain keys.
+    fn path_pair_binding(&self, second: &str) -> String;
+
+    /// Return type (inside `Result<…, AppError>`) of a create handler.
+    fn created_type(&self, body: &str) -> String;
+
+    /// Expression for a `201 Created` response with `value` as JSON body.
+    fn created(&self, value: &str) -> String;
+
+    /// Return type (inside `Result<…, AppError>`) of a delete handler.
+    fn no_content_type(&self) -> &'static str;
+
+    /// Expression for a `204 No Content` response.
+    fn no_content(&self) -> &'static str;
+
+    /// Handler `fn_name`, streaming `entity`'s changes over `transport`
+    /// through the functions of [`Self::realtime_streams`].
+    fn live_handler(&self, fn_name: &str, entity: &str, transport: RealtimeTransport) -> String;
+
+    /// The handler parameter binding a `multipart/form-data` body as
+    /// `multipart`, for `storage::read_upload`.
+    fn upload_param(&self) -> &'static str;
+
+    /// The handler parameter giving access to the request headers, for
+    /// `http_cache::Validators::respond`.
+    fn headers_param(&self) -> &'static str;
+
+    /// The argument passing [`Self::headers_param`] on to
+    /// `http_cache::Validators::respond`.
+    fn headers_arg(&self) -> &'static str;
+
+    /// Return type (inside `Result<…, AppError>`) of a handler answering
+    /// through `http_cache::Validators::respond`.
+    fn cached_response_type(&self) -> &'static str;
+
+    // ── Project files ────────────────────────────────────────────────────
+
+    /// `src/routes/`: the router and per-entity route groups.
+    fn generate_routes(&self, ctx: &GenerationContext) -> Vec<GeneratedFile>;
+
+    /// `src/middleware.rs`.
+    fn generate_middleware(&self, ctx: &GenerationContext) -> Vec<GeneratedFile>;
+
+    /// `src/auth/middleware.rs`: `require_auth` and the role helpers.
+    fn generate_auth_middleware(&self, ctx: &GenerationContext) -> GeneratedFile;
+
+    /// `src/graphql/http.rs`: serves the GraphQL schema, its GraphiQL page
+    /// and the subscription WebSocket.
+    fn generate_graphql_http(&self, ctx: &GenerationContext) -> GeneratedFile;
+
+    /// Functions of `src/realtime.rs` serving the change feed over each
+    /// REST transport in use: `websocket` and `sse`.
+    fn realtime_streams(&self, ctx: &GenerationContext) -> String;
+
+    /// `read_upload` of `src/storage.rs`: the first file part of a multipart
+    /// body, refused past `MAX_UPLOAD_BYTES`.
+    fn upload_reader(&self) -> &'static str;
+
+    /// Framework `use` lines `src/http_cache.rs` needs for
+    /// [`Self::cache_responder`].
+    fn cache_imports(&self) -> &'static str;
+
+    /// `Validators::respond` of `src/http_cache.rs`: the JSON body or
+    /// `304 Not Modified`, with the validator headers.
+    fn cache_responder(&self) -> &'static str;
+
+    /// `use` lines `src/health.rs` needs for [`Self::health_handlers`].
+    fn health_imports(&self) -> &'static str;
+
+    /// `healthz` and `readyz` of `src/health.rs`; `readyz` answers 503 when
+    /// `check_database` fails.
+    fn health_handlers(&self) -> &'static str;
+
+    /// `use` lines `src/auth/sessions.rs` needs for [`Self::session_handlers`].
+    fn session_imports(&self) -> &'static str;
+
+    /// `refresh_session`, `logout` and `logout_all` of `src/auth/sessions.rs`;
+    /// the logout handlers run behind `require_auth`.
+    fn session_handlers(&self) -> &'static str;
+
+    /// Framework `use` lines `src/rate_limit.rs` needs, `StatusCode`
+    /// among them; with `auth` also what reading `Claims` takes.
+    fn rate_limit_imports(&self, auth: bool) -> String;
+
+    /// `enforce`, the middleware counting requests against a `Limiter`,
+    /// and the `client_key` it counts them under; with `auth` a signed-in
+    /// caller is keyed by user.
+    fn rate_limit_middleware(&self, auth: bool) -> String;
+
+    /// `use` lines `src/auth/oauth.rs` needs for [`Self::oauth_handlers`].
+    fn oauth_imports(&self) -> &'static str;
+
+    /// `oauth_authorize`, redirecting to the provider, and `oauth_callback`,
+    /// answering the signed-in tokens, of `src/auth/oauth.rs`.
+    fn oauth_handlers(&self) -> &'static str;
+
+    /// `use` lines `src/error.rs` needs for its response conversion.
+    fn error_imports(&self) -> &'static str;
+
+    /// Section banner, `impl` line and method signature of the conversion
+    /// from `AppError` to a response.
+    fn error_response_open(&self) -> &'static str;
+
+    /// Final expression of the conversion, turning `status` and `body` into
+    /// the response.
+    fn error_response_close(&self) -> &'static str;
+
+    /// Name of the `routes` function `main.rs` and the tests build the
+    /// application with.
+    fn app_builder(&self) -> &'static str;
+
+    /// Framework `use` lines `main.rs` and the integration tests need to
+    /// serve the application.
+    fn server_imports(&self) -> &'static str;
+
+    /// Steps 6 and 7 of `main`: build the application from `state` and
+    /// serve it on `bind_addr`.
+    fn serve(&self) -> &'static str;
+
+    /// Test-server start-up from `state` values (`db`, `config`) and a
+    /// bound `std::net::TcpListener` named `listener`.
+    fn test_serve(&self) -> &'static str;
+}
+
+/// The backend for `framework`.
+pub fn backend(framework: WebFramework) -> &'static dyn FrameworkBackend {
+    match framework {
+        WebFramework::Axum => &AxumBackend,
+        WebFramework::ActixWeb => &ActixBackend,
+    }
+}
+
+// ============================================================================
+// Axum
+// ============================================================================
+
+/// Axum: extractor-based handlers behind tower layers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AxumBackend;
+
+impl FrameworkBackend for AxumBackend {
+    fn framework(&self) -> WebFramework {
+        WebFramework::Axum
+    }
+
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String {
+        let mut axum_features = vec!["\"macros\""];
+        if ctx.uploads_enabled() {
+            axum_features.push("\"multipart\"");
+        }
+        // The GraphQL subscription endpoint is a WebSocket, as are the
+        // realtime routes by default
+        if ctx.graphql_enabled() || ctx.uses_realtime_transport(RealtimeTransport::WebSocket) {
+            axum_features.push("\"ws\"");
+        }
+        let mut out = format!(
+            "axum = {{ version = \"0.8\", features = [{}] }}\n",
+            axum_features.join(", ")
+        );
+        if ctx.graphql_enabled() {
+            out.push_str("async-graphql-axum = \"7\"\n");
+        }
+        if ctx.metrics() {
+            out.push_str("axum-prometheus = \"0.8\"\n");
+        }
+
+        let mut tower_http_features = vec!["\"trace\""];
+        if ctx.config.cors_enabled {
+            tower_http_features.push("\"cors\"");
+        }
+        tower_http_features.push("\"timeout\"");
+        // Local uploads are served back from `UPLOAD_DIR`
+        if ctx.uploads_enabled() && ctx.uploads().storage == UploadStorage::Local {
+            tower_http_features.push("\"fs\"");
+        }
+        out.push_str(&format!(
+            "tower-http = {{ version = \"0.6\", features = [{}] }}\n",
+            tower_http_features.join(", ")
+        ));
+        // `ServiceExt::oneshot` re-dispatches header-versioned requests
+        if ctx.rest_enabled() && ctx.api_versioning().header_name().is_some() {
+            out.push_str("tower = { version = \"0.5\", features = [\"util\"] }\n");
+        } else {
+            out.push_str("tower = \"0.5\"\n");
+        }
+        out
+    }
+
+    fn auth_dependency(&self, strategy: AuthStrategy) -> Option<&'static str> {
+        match strategy {
+            AuthStrategy::Jwt | AuthStrategy::ApiKey | AuthStrategy::OAuth2 => {
+                Some("axum-extra = { version = \"0.10\", features = [\"typed-header\"] }\n")
+            }
+            AuthStrategy::Session => {
+                Some("axum-extra = { version = \"0.10\", features = [\"cookie\"] }\n")
+            }
+            AuthStrategy::None => None,
+        }
+    }
+
+    fn utoipa_feature(&self) -> &'static str {
+        "axum_extras"
+    }
+
+    fn swagger_ui_feature(&self) -> &'static str {
+        "axum"
+    }
+
+    fn handler_imports(&self, extractors: &[Extractor], responses: bool) -> String {
+        let names: Vec<&str> = extractors
+            .iter()
+            .map(|e| match e {
+                Extractor::State => "State",
+                Extractor::Path => "Path",
+                Extractor::Query => "Query",
+            })
+            .collect();
+
+        let mut out = format!("use axum::extract::{{{}}};\n", names.join(", "));
+        if responses {
+            out.push_str("use axum::http::StatusCode;\n");
+        }
+        out.push_str("use axum::Json;\n");
+        out
+    }
+
+    fn state_param(&self) -> &'static str {
+        "State(state): State<AppState>"
+    }
+
+    fn path_param(&self, ty: &str) -> String {
+        format!("Path(id): Path<{}>", ty)
+    }
+
+    fn path_binding(&self) -> &'static str {
+        ""
+    }
+
+    fn path_pair_param(&self, ty: &str, second: &str, second_ty: &str) -> String {
+        format!("Path((id, {})): Path<({}, {})>", second, ty, second_ty)
+    }
+
+    fn path_pair_binding(&self, _second: &str) -> String {
+        String::new()
+    }
+
+    fn created_type(&self, body: &str) -> String {
+        format!("(StatusCode, Json<{}>)", body)
+    }
+
+    fn created(&self, value: &str) -> String {
+        format!("(StatusCode::CREATED, Json({}))", value)
+    }
+
+    fn no_content_type(&self) -> &'static str {
+        "StatusCode"
+    }
+
+    fn no_content(&self) -> &'static str {
+        "StatusCode::NO_CONTENT"
+    }
+
+    fn live_handler(&self, fn_name: &str, entity: &str, transport: RealtimeTransport) -> String {
+        match transport {
+            RealtimeTransport::WebSocket => format!(
+                "pub async fn {fn_name}(ws: axum::extract::WebSocketUpgrade) -> axum::response::Response {{\n    realtime::websocket(ws, \"{entity}\")\n}}\n"
+            ),
+            RealtimeTransport::Sse => format!(
+                "pub async fn {fn_name}() -> impl axum::response::IntoResponse {{\n    realtime::sse(\"{entity}\")\n}}\n"
+            ),
+        }
+    }
+
+    fn upload_param(&self) -> &'static str {
+        "multipart: axum::extract::Multipart"
+    }
+
+    fn headers_param(&self) -> &'static str {
+        "headers: axum::http::HeaderMap"
+    }
+
+    fn headers_arg(&self) -> &'static str {
+        "&headers"
+    }
+
+    fn cached_response_type(&self) -> &'static str {
+        "axum::response::Response"
+    }
+
+    fn generate_routes(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        routes::generate_routes(ctx)
+    }
+
+    fn generate_middleware(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        middleware::generate_middleware(ctx)
+    }
+
+    fn generate_auth_middleware(&self, ctx: &GenerationContext) -> GeneratedFile {
+        auth::generate_auth_middleware(ctx)
+    }
+
+    fn generate_graphql_http(&self, ctx: &GenerationContext) -> GeneratedFile {
+        routes::generate_graphql_http(ctx)
+    }
+
+    fn realtime_streams(&self, ctx: &GenerationContext) -> String {
+        routes::realtime_streams(ctx)
+    }
+
+    fn upload_reader(&self) -> &'static str {
+        "\
+/// Body limit of the upload routes: the file plus room for the multipart
+/// framing around it.
+pub const MAX_BODY_BYTES: usize = MAX_UPLOAD_BYTES + 64 * 1024;
+
+/// Read the first file part of a multipart body.
+pub async fn read_upload(mut multipart: axum::extract::Multipart) -> Result<Upload, AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(e.to_string()))?
+    {
+        if field.file_name().is_none() {
+            continue;
+        }
+        let file_name = field.file_name().map(str::to_string);
+        let content_type = field.content_type().map(str::to_string);
+        let bytes = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        if bytes.len() > MAX_UPLOAD_BYTES {
+            return Err(too_large());
+        }
+        return Ok(Upload {
+            file_name,
+            content_type,
+            bytes: bytes.to_vec(),
+        });
+    }
+    Err(AppError::BadRequest(
+        \"Expected a file part in the multipart body\".to_string(),
+    ))
+}
+"
+    }
+
+    fn cache_imports(&self) -> &'static str {
+        "use axum::Json;\nuse axum::http::{HeaderMap, HeaderValue, StatusCode};\nuse axum::response::{IntoResponse, Response};\n"
+    }
+
+    fn cache_responder(&self) -> &'static str {
+        "\
+impl Validators {
+    /// `body` as JSON with the validator headers, or `304 Not Modified`
+    /// without a body if the request's conditional headers still match.
+    pub fn respond<T: Serialize>(self, headers: &HeaderMap, body: T) -> Result<Response, AppError> {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let (validators, not_modified) = self.check(
+            &body,
+            header(\"if-none-match\"),
+            header(\"if-modified-since\"),
+        )?;
+
+        let mut response = if not_modified {
+            StatusCode::NOT_MODIFIED.into_response()
+        } else {
+            Json(body).into_response()
+        };
+        for (name, value) in validators {
+            if let Ok(value) = HeaderValue::from_str(&value) {
+                response.headers_mut().insert(name, value);
+            }
+        }
+        Ok(response)
+    }
+}
+"
+    }
+
+    fn health_imports(&self) -> &'static str {
+        "use axum::Json;\nuse axum::extract::State;\nuse axum::http::StatusCode;\nuse serde_json::{json, Value};\n\n"
+    }
+
+    fn health_handlers(&self) -> &'static str {
+        "\
+/// Liveness probe: the process is up and answering requests.
+pub async fn healthz() -> Json<Value> {
+    Json(json!({ \"status\": \"ok\" }))
+}
+
+/// Readiness probe: `200` while the database answers, `503` otherwise, so
+/// load balancers stop sending traffic to an instance that cannot serve it.
+pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    match check_database(&state).await {
+        Ok(()) => (StatusCode::OK, Json(json!({ \"status\": \"ready\" }))),
+        Err(e) => {
+            tracing::warn!(error = %e, \"readiness check failed\");
+            (StatusCode::SERVICE_UNAVAILABLE, Json(json!({ \"status\": \"unavailable\" })))
+        }
+    }
+}
+"
+    }
+
+    fn session_imports(&self) -> &'static str {
+        "use axum::extract::State;\nuse axum::http::StatusCode;\nuse axum::{Extension, Json};\n"
+    }
+
+    fn session_handlers(&self) -> &'static str {
+        "\
+/// `POST /api/auth/refresh`: trade a refresh token for a new pair.
+pub async fn refresh_session(
+    State(state): State<AppState>,
+    Json(body): Json<RefreshRequest>,
+) -> Result<Json<TokenPair>, AppError> {
+    Ok(Json(refresh(&state, &body.refresh_token).await?))
+}
+
+/// `POST /api/auth/logout`: end the caller's session.
+pub async fn logout(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<StatusCode, AppError> {
+    if let Some(session_id) = &claims.sid {
+        end(&state, session_id).await?;
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /api/auth/logout-all`: end every session of the caller.
+pub async fn logout_all(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<StatusCode, AppError> {
+    end_all(&state, &claims.sub).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+"
+    }
+
+    fn rate_limit_imports(&self, _auth: bool) -> String {
+        "\
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::net::SocketAddr;
+"
+        .to_string()
+    }
+
+    fn rate_limit_middleware(&self, auth: bool) -> String {
+        let user = if auth {
+            "        KeyBy::User => match request.extensions().get::<Claims>() {
+            Some(claims) => format!(\"user:{}\", claims.sub),
+            None => ip(),
+        },
+"
+        } else {
+            ""
+        };
+        format!(
+            "\
+/// Middleware counting each request against `limiter`; over the quota it
+/// answers with a `Retry-After` header instead of running the handler.
+///
+/// ```rust,ignore
+/// .route(\"/\", post(handler).route_layer(middleware::from_fn_with_state(&rate_limit::USER_CREATE, rate_limit::enforce)))
+/// ```
+pub async fn enforce(
+    State(limiter): State<&'static Limiter>,
+    request: Request,
+    next: Next,
+) -> Response {{
+    let key = client_key(limiter.quota.key_by, &request);
+    match limiter.check(key) {{
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {{
+            let mut response = limiter.exceeded().into_response();
+            *response.status_mut() = limiter.status();
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }}
+    }}
+}}
+
+/// The key a request is counted under. The client IP is the peer address;
+/// behind a reverse proxy, read the proxy's forwarded header here instead.
+fn client_key(key_by: KeyBy, request: &Request) -> String {{
+    let ip = || {{
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+            .unwrap_or_default()
+    }};
+    match key_by {{
+        KeyBy::Ip => ip(),
+{user}        KeyBy::Global => String::new(),
+    }}
+}}
+"
+        )
+    }
+
+    fn oauth_imports(&self) -> &'static str {
+        "use axum::extract::{Path, Query, State};\nuse axum::response::Redirect;\nuse axum::Json;\n"
+    }
+
+    fn oauth_handlers(&self) -> &'static str {
+        "\
+/// `GET /api/auth/oauth/{provider}/authorize`: send the browser to the
+/// provider's sign-in page.
+pub async fn oauth_authorize(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+) -> Result<Redirect, AppError> {
+    Ok(Redirect::to(&authorize_url(&state, &provider).await?))
+}
+
+/// `GET /api/auth/oauth/{provider}/callback`: where the provider sends the
+/// browser back; answers the signed-in tokens.
+pub async fn oauth_callback(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    Query(callback): Query<Callback>,
+) -> Result<Json<SignIn>, AppError> {
+    Ok(Json(complete(&state, &provider, callback).await?))
+}
+"
+    }
+
+    fn error_imports(&self) -> &'static str {
+        "\
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+"
+    }
+
+    fn error_response_open(&self) -> &'static str {
+        "\
+// ============================================================================
+// IntoResponse — convert AppError into an HTTP response
+// ============================================================================
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+"
+    }
+
+    fn error_response_close(&self) -> &'static str {
+        "        (status, [(header::CONTENT_TYPE, PROBLEM_JSON)], Json(body)).into_response()\n"
+    }
+
+    fn app_builder(&self) -> &'static str {
+        "create_router"
+    }
+
+    fn server_imports(&self) -> &'static str {
+        ""
+    }
+
+    fn serve(&self) -> &'static str {
+        "\
+    // ── 6. Build router ──────────────────────────────────────────────
+    let router = create_router(state);
+
+    // ── 7. Start server ──────────────────────────────────────────────
+    let bind_addr = config.bind_address();
+    tracing::info!(address = %bind_addr, \"starting HTTP server\");
+
+    let listener = tokio::net::TcpListener::bind(&bind_addr)
+        .await
+        .with_context(|| format!(\"failed to bind to {}\", bind_addr))?;
+
+    tracing::info!(
+        address = %bind_addr,
+        \"server is ready — listening for connections\",
+    );
+
+    // Connect info carries the peer address rate limits key clients by
+    axum::serve(listener, router.into_make_service_with_connect_info::<std::net::SocketAddr>())
+        .await
+        .context(\"server error\")?;
+"
+    }
+
+    fn test_serve(&self) -> &'static str {
+        "\
+        let state = AppState::new(db, config);
+        let router = create_router(state);
+
+        // Convert std TcpListener to tokio TcpListener
+        listener.set_nonblocking(true).unwrap();
+        let tokio_listener = tokio::net::TcpListener::from_std(listener).unwrap();
+
+        // Start server in background
+        tokio::spawn(async move {
+            axum::serve(tokio_listener, router.into_make_service_with_connect_info::<std::net::SocketAddr>())
+                .await
+                .expect(\"server error\");
+        });
+"
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_lookup() {
+        assert_eq!(backend(WebFramework::Axum).framework(), WebFramework::Axum);
+        assert_eq!(
+            backend(WebFramework::ActixWeb).framework(),
+            WebFramework::ActixWeb
+        );
+    }
+
+    #[test]
+    fn test_axum_handler_fragments() {
+        let axum = AxumBackend;
+        assert_eq!(
+            axum.handler_imports(&[Extractor::State, Extractor::Path], true),
+            "use axum::extract::{State, Path};\nuse axum::http::StatusCode;\nuse axum::Json;\n"
+        );
+        assert_eq!(axum.path_param("Uuid"), "Path(id): Path<Uuid>");
+        assert_eq!(axum.path_binding(), "");
+        assert_eq!(
+            axum.path_pair_param("Uuid", "tag_id", "i64"),
+            "Path((id, tag_id)): Path<(Uuid, i64)>"
+        );
+        assert_eq!(
+            axum.created("UserResponse::from(model)"),
+            "(StatusCode::CREATED, Json(UserResponse::from(model)))"
+        );
+        assert_eq!(
+            axum.live_handler("watch_posts", "Post", RealtimeTransport::Sse),
+            "pub async fn watch_posts() -> impl axum::response::IntoResponse {\n    realtime::sse(\"Post\")\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_actix_handler_fragments() {
+        let actix = ActixBackend;
+        assert_eq!(
+            actix.handler_imports(&[Extractor::State, Extractor::Path], true),
+            "use actix_web::web::{Data, Json, Path};\nuse actix_web::HttpResponse;\n"
+        );
+        assert_eq!(actix.state_param(), "state: Data<AppState>");
+        assert_eq!(actix.path_param("i64"), "id: Path<i64>");
+        assert_eq!(actix.path_binding(), "    let id = id.into_inner();\n");
+        assert_eq!(
+            actix.path_pair_param("Uuid", "tag_id", "i64"),
+            "path: Path<(Uuid, i64)>"
+        );
+        assert_eq!(
+            actix.path_pair_binding("tag_id"),
+            "    let (id, tag_id) = path.into_inner();\n"
+        );
+        assert_eq!(actix.created_type("UserResponse"), "HttpResponse");
+        assert_eq!(actix.no_content(), "HttpResponse::NoContent().finish()");
+    }
+}
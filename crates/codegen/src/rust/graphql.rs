@@ -0,0 +1,1742 @@
+//! # GraphQL Generator (async-graphql)
+//!
+//! Generates an `async-graphql` schema over the same SeaORM models and
+//! DTOs the REST handlers use, for projects whose [`ApiStyle`] serves
+//! GraphQL. The schema can replace the REST routes or be mounted next to
+//! them.
+//!
+//! ## Generated Files
+//!
+//! - `src/graphql/mod.rs` — the merged `QueryRoot` / `MutationRoot` /
+//!   `SubscriptionRoot`, `build_schema`, the auth guard and the token check
+//!   on subscription connections
+//! - `src/graphql/loaders.rs` — `DataLoader`s batching relationship lookups
+//! - `src/graphql/{entity}.rs` — object type, inputs, queries, mutations and,
+//!   for realtime entities, a change subscription
+//! - `src/graphql/http.rs` — the framework glue serving `/graphql`
+//!   (GraphiQL on `GET`, queries on `POST`) and `/graphql/ws`
+//!
+//! ## Operations
+//!
+//! Each enabled CRUD operation of an entity's endpoint group becomes a
+//! field, guarded by the operation's security when auth is enabled:
+//!
+//! | Operation | Field |
+//! |-----------|-------|
+//! | ReadAll   | `posts(offset, limit): [Post!]!` |
+//! | Read      | `post(id): Post` |
+//! | Create    | `createPost(input): Post!` |
+//! | Update    | `updatePost(id, input): Post!` |
+//! | Delete    | `deletePost(id): Boolean!` |
+//!
+//! Entities whose endpoint group is marked realtime also get a `postChanged`
+//! subscription, fed by their mutations through the `src/realtime.rs` change
+//! feed. The subscription is guarded like reading the list, and a bearer
+//! token sent on connecting is verified before any subscription starts.
+//!
+//! ## Relationships
+//!
+//! Foreign keys become object fields in both directions — `post.author`
+//! and `user.posts` — resolved through `DataLoader`s, so a list of posts
+//! with their authors costs two queries rather than one per post.
+//!
+//! [`ApiStyle`]: imortal_ir::ApiStyle
+
+use imortal_core::DataType;
+use imortal_ir::{Entity, Field, OperationType};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::constants::page_size_consts;
+use crate::rust::handlers::{
+    active_model_sets, delete_blocker_check, find_existing, update_field_sets,
+};
+use crate::rust::models::{dto_field_name, field_rust_type, field_rust_type_dto};
+use crate::rust::routes::has_enabled_handlers;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+/// Largest page the list queries return, whatever `limit` asks for.
+const MAX_PAGE_SIZE: u64 = 100;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate the `src/graphql/` module.
+pub fn generate_graphql(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    let schema = SchemaEntities::new(ctx);
+
+    let mut files = vec![
+        generate_graphql_mod(&schema, ctx),
+        generate_loaders(&schema, ctx),
+    ];
+    for info in &schema.entities {
+        files.push(generate_entity_graphql(info, &schema, ctx));
+    }
+    files.push(ctx.framework_backend().generate_graphql_http(ctx));
+    files
+}
+
+// ============================================================================
+// Schema model
+// ============================================================================
+
+/// The entities exposed in the schema: those with enabled endpoints.
+struct SchemaEntities<'a> {
+    entities: Vec<EntityInfo<'a>>,
+}
+
+/// A foreign key on an entity, seen from the entity it points at or from.
+struct Reference<'a> {
+    /// Entity holding the foreign key
+    child: &'a Entity,
+    /// The foreign key field
+    fk_field: &'a Field,
+    /// Entity the key points at
+    parent: &'a Entity,
+}
+
+impl<'a> Reference<'a> {
+    fn optional(&self) -> bool {
+        matches!(self.fk_field.data_type, DataType::Optional(_))
+    }
+
+    /// `author` for `author_id`; falls back to the parent's name when the
+    /// key has no `_id` suffix to strip.
+    fn parent_field_name(&self) -> String {
+        let fk = GenerationContext::snake(&self.fk_field.name);
+        match fk.strip_suffix("_id") {
+            Some(stem) if !stem.is_empty() => stem.to_string(),
+            _ => format!("{}_{}", fk, GenerationContext::snake(&self.parent.name)),
+        }
+    }
+
+    /// `posts`, or `posts_by_author` when the child points at the parent
+    /// through more than one key.
+    fn children_field_name(&self, ambiguous: bool) -> String {
+        let plural = GenerationContext::pluralize(&GenerationContext::snake(&self.child.name));
+        if ambiguous {
+            format!("{}_by_{}", plural, self.parent_field_name())
+        } else {
+            plural
+        }
+    }
+
+    /// The accessor name the relationship over this key gives one end
+    /// (`author` on the child, `posts` on the parent), when one covers it.
+    fn relation_accessor(&self, ctx: &GenerationContext, on_child: bool) -> Option<String> {
+        let entity = if on_child { self.child } else { self.parent };
+        EntityInfo::new(entity, ctx)
+            .relation_accessors()
+            .into_iter()
+            .filter(|a| on_child || a.many)
+            .find(|a| {
+                a.holds_fk == on_child && a.fk_field.is_some_and(|f| f.id == self.fk_field.id)
+            })
+            .map(|a| a.name)
+    }
+
+    /// `PostsByAuthorIdLoader`
+    fn children_loader(&self) -> String {
+        format!(
+            "{}By{}Loader",
+            GenerationContext::pascal(&GenerationContext::pluralize(&self.child.name)),
+            GenerationContext::pascal(&self.fk_field.name),
+        )
+    }
+}
+
+/// `UserLoader`: the by-primary-key loader of an entity.
+fn pk_loader(entity: &Entity) -> String {
+    format!("{}Loader", GenerationContext::pascal(&entity.name))
+}
+
+impl<'a> SchemaEntities<'a> {
+    fn new(ctx: &'a GenerationContext) -> Self {
+        let entities = ctx
+            .entities()
+            .iter()
+            .map(|e| EntityInfo::new(e, ctx))
+            .filter(has_enabled_handlers)
+            .collect();
+        Self { entities }
+    }
+
+    fn contains(&self, entity: &Entity) -> bool {
+        self.entities.iter().any(|i| i.entity.id == entity.id)
+    }
+
+    /// Every foreign key between two schema entities.
+    fn references(&self) -> Vec<Reference<'a>> {
+        let mut refs = Vec::new();
+        for info in &self.entities {
+            for field in &info.entity.fields {
+                let Some(fk) = field
+                    .foreign_key_ref
+                    .as_ref()
+                    .filter(|_| field.is_foreign_key)
+                else {
+                    continue;
+                };
+                let Some(parent) = info.ctx.entity_by_id(fk.entity_id) else {
+                    continue;
+                };
+                if self.contains(parent) {
+                    refs.push(Reference {
+                        child: info.entity,
+                        fk_field: field,
+                        parent,
+                    });
+                }
+            }
+        }
+        refs
+    }
+
+    /// Keys on `entity` pointing at other schema entities (`post.author`).
+    /// Only keys the response exposes can be followed.
+    fn parents_of(&self, info: &EntityInfo<'a>) -> Vec<Reference<'a>> {
+        let exposed = info.response_fields();
+        self.references()
+            .into_iter()
+            .filter(|r| r.child.id == info.entity.id)
+            .filter(|r| exposed.iter().any(|f| f.id == r.fk_field.id))
+            .collect()
+    }
+
+    /// Keys on other schema entities pointing at `entity` (`user.posts`).
+    fn children_of(&self, info: &EntityInfo<'a>) -> Vec<Reference<'a>> {
+        self.references()
+            .into_iter()
+            .filter(|r| r.parent.id == info.entity.id)
+            .collect()
+    }
+
+    fn info(&self, entity: &Entity) -> &EntityInfo<'a> {
+        self.entities
+            .iter()
+            .find(|i| i.entity.id == entity.id)
+            .expect("reference to an entity outside the schema")
+    }
+}
+
+/// Enabled operations of an entity's endpoint group.
+fn operations(info: &EntityInfo) -> Vec<OperationType> {
+    info.endpoint()
+        .map(|ep| {
+            ep.enabled_operations()
+                .iter()
+                .map(|op| op.operation_type)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn has_mutations(info: &EntityInfo) -> bool {
+    operations(info).iter().any(|op| {
+        matches!(
+            op,
+            OperationType::Create | OperationType::Update | OperationType::Delete
+        )
+    })
+}
+
+/// Whether the entity has a change subscription: it pushes its changes to
+/// live subscribers and has mutations to push them.
+fn is_live(info: &EntityInfo) -> bool {
+    info.is_realtime() && has_mutations(info)
+}
+
+/// `publish_change(…);` after a mutation of a live entity, or nothing.
+fn publish_line(info: &EntityInfo, kind: &str, id: &str) -> String {
+    if !is_live(info) {
+        return String::new();
+    }
+    format!(
+        "        publish_change(\"{}\", ChangeKind::{}, {});\n",
+        info.pascal_name(),
+        kind,
+        id
+    )
+}
+
+/// `require_auth(ctx, &[…])?;` for an operation that needs it, or nothing.
+fn auth_guard(info: &EntityInfo, ctx: &GenerationContext, op: OperationType) -> String {
+    if !ctx.auth_enabled() {
+        return String::new();
+    }
+    let Some(security) = info.endpoint().map(|ep| ep.effective_security(op)) else {
+        return String::new();
+    };
+    if !security.auth_required {
+        return String::new();
+    }
+    let roles: Vec<String> = security.roles.iter().map(|r| format!("{:?}", r)).collect();
+    format!("        require_auth(ctx, &[{}])?;\n", roles.join(", "))
+}
+
+/// Name of the list query: `posts`, or `news_list` when the plural is the
+/// singular.
+fn list_field_name(info: &EntityInfo) -> String {
+    let plural = info.plural_name();
+    if plural == info.snake_name() {
+        format!("{}_list", plural)
+    } else {
+        plural
+    }
+}
+
+/// Fields of the object type, in response order: the response DTO's
+/// fields plus the timestamps it adds.
+fn object_fields(info: &EntityInfo) -> Vec<(String, String)> {
+    let fields = info.response_fields();
+    let mut out: Vec<(String, String)> = fields
+        .iter()
+        .map(|f| (GenerationContext::snake(&f.name), field_rust_type(f, info)))
+        .collect();
+    if info.has_timestamps() {
+        for ts in ["created_at", "updated_at"] {
+            if !fields.iter().any(|f| f.name == ts) {
+                out.push((ts.to_string(), "DateTime<Utc>".to_string()));
+            }
+        }
+    }
+    out
+}
+
+/// `use` lines for the scalar types appearing in `types`.
+fn scalar_imports<'t>(types: impl IntoIterator<Item = &'t str>) -> String {
+    let (mut uuid, mut chrono, mut decimal) = (false, false, false);
+    for ty in types {
+        uuid |= ty.contains("Uuid");
+        chrono |= ty.contains("DateTime<Utc>");
+        decimal |= ty.contains("Decimal");
+    }
+    let mut out = String::new();
+    if chrono {
+        out.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    if decimal {
+        out.push_str("use rust_decimal::Decimal;\n");
+    }
+    if uuid {
+        out.push_str("use uuid::Uuid;\n");
+    }
+    out
+}
+
+// ============================================================================
+// graphql/mod.rs
+// ============================================================================
+
+fn generate_graphql_mod(schema: &SchemaEntities, ctx: &GenerationContext) -> GeneratedFile {
+    let mutable: Vec<&EntityInfo> = schema
+        .entities
+        .iter()
+        .filter(|i| has_mutations(i))
+        .collect();
+    let live: Vec<&EntityInfo> = mutable.iter().copied().filter(|i| is_live(i)).collect();
+    let has_mutations = !mutable.is_empty();
+    let has_subscriptions = !live.is_empty();
+
+    let mut content = String::with_capacity(4096);
+    content.push_str(&file_header(
+        "GraphQL schema — root types, schema assembly and the auth guard.",
+    ));
+
+    content.push_str("pub mod http;\npub mod loaders;\n");
+    for info in &schema.entities {
+        content.push_str(&format!("pub mod {};\n", info.module_name()));
+    }
+    content.push('\n');
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    content.push_str("use async_graphql::dataloader::DataLoader;\n");
+    let mut graphql_imports = vec!["MergedObject", "Object", "Schema"];
+    if ctx.auth_enabled() {
+        graphql_imports.extend(["Context", "Data", "ErrorExtensions"]);
+    }
+    if !has_mutations {
+        graphql_imports.push("EmptyMutation");
+    }
+    if has_subscriptions {
+        graphql_imports.push("MergedSubscription");
+    } else {
+        graphql_imports.push("EmptySubscription");
+    }
+    graphql_imports.sort_unstable();
+    content.push_str(&format!(
+        "use async_graphql::{{{}}};\n",
+        graphql_imports.join(", ")
+    ));
+    content.push('\n');
+    if ctx.auth_enabled() {
+        content.push_str("use crate::auth::jwt::{verify_token, Claims};\n");
+        content.push_str("use crate::error::AppError;\n");
+    }
+    content.push_str("use crate::state::AppState;\n\n");
+
+    // ── Root types ───────────────────────────────────────────────────────
+    content.push_str(
+        "// ============================================================================\n\
+         // Schema\n\
+         // ============================================================================\n\n",
+    );
+
+    let mutation_root = if has_mutations {
+        "MutationRoot"
+    } else {
+        "EmptyMutation"
+    };
+    let subscription_root = if has_subscriptions {
+        "SubscriptionRoot"
+    } else {
+        "EmptySubscription"
+    };
+
+    content.push_str(&doc_comment(Some("The schema served at `/graphql`."), ctx));
+    content.push_str(&format!(
+        "pub type AppSchema = Schema<QueryRoot, {}, {}>;\n\n",
+        mutation_root, subscription_root
+    ));
+
+    let mut queries = vec!["MetaQuery".to_string()];
+    queries.extend(
+        schema
+            .entities
+            .iter()
+            .map(|i| format!("{}::{}Query", i.module_name(), i.pascal_name())),
+    );
+    content.push_str(&doc_comment(
+        Some("Every entity's queries, merged into one `Query` type."),
+        ctx,
+    ));
+    content.push_str("#[derive(MergedObject, Default)]\n");
+    content.push_str(&format!(
+        "pub struct QueryRoot({});\n\n",
+        queries.join(", ")
+    ));
+
+    if has_mutations {
+        let mutations: Vec<String> = mutable
+            .iter()
+            .map(|i| format!("{}::{}Mutation", i.module_name(), i.pascal_name()))
+            .collect();
+        content.push_str(&doc_comment(
+            Some("Every entity's mutations, merged into one `Mutation` type."),
+            ctx,
+        ));
+        content.push_str("#[derive(MergedObject, Default)]\n");
+        content.push_str(&format!(
+            "pub struct MutationRoot({});\n\n",
+            mutations.join(", ")
+        ));
+    }
+
+    if has_subscriptions {
+        let subscriptions: Vec<String> = live
+            .iter()
+            .map(|i| format!("{}::{}Subscription", i.module_name(), i.pascal_name()))
+            .collect();
+        content.push_str(&doc_comment(
+            Some("Every entity's change feed, merged into one `Subscription` type."),
+            ctx,
+        ));
+        content.push_str("#[derive(MergedSubscription, Default)]\n");
+        content.push_str(&format!(
+            "pub struct SubscriptionRoot({});\n\n",
+            subscriptions.join(", ")
+        ));
+    }
+
+    content.push_str(&doc_comment(Some("Queries about the API itself."), ctx));
+    content.push_str(
+        "\
+#[derive(Default)]
+pub struct MetaQuery;
+
+#[Object]
+impl MetaQuery {
+    /// Version of the running server.
+    async fn api_version(&self) -> &'static str {
+        env!(\"CARGO_PKG_VERSION\")
+    }
+}
+
+",
+    );
+
+    // ── build_schema ─────────────────────────────────────────────────────
+    content.push_str(&doc_comment(
+        Some(
+            "Build the schema, with the dataloaders and application state every\n\
+             resolver reads from its context.",
+        ),
+        ctx,
+    ));
+    content.push_str("pub fn build_schema(state: AppState) -> AppSchema {\n");
+    let roots = [
+        "QueryRoot::default()",
+        if has_mutations {
+            "MutationRoot::default()"
+        } else {
+            "EmptyMutation"
+        },
+        if has_subscriptions {
+            "SubscriptionRoot::default()"
+        } else {
+            "EmptySubscription"
+        },
+    ];
+    content.push_str(&format!(
+        "    Schema::build(\n        {},\n    )\n",
+        roots.join(",\n        ")
+    ));
+    for loader in loader_names(schema) {
+        content.push_str(&format!(
+            "    .data(DataLoader::new(\n        loaders::{}::new({}.clone()),\n        tokio::spawn,\n    ))\n",
+            loader,
+            ctx.read_db(),
+        ));
+    }
+    content.push_str("    .data(state)\n");
+    content.push_str("    .limit_depth(10)\n");
+    content.push_str("    .finish()\n");
+    content.push_str("}\n");
+
+    // ── Auth guard ───────────────────────────────────────────────────────
+    if ctx.auth_enabled() {
+        content.push('\n');
+        content.push_str(&doc_comment(
+            Some(
+                "Claims of the caller, as put in the request data by the HTTP layer.\n\n\
+                 Fails with `unauthorized` without a valid bearer token, and with\n\
+                 `forbidden` when `roles` is non-empty and the caller holds none of them.",
+            ),
+            ctx,
+        ));
+        content.push_str(
+            "\
+pub fn require_auth<'a>(
+    ctx: &Context<'a>,
+    roles: &[&str],
+) -> async_graphql::Result<&'a Claims> {
+    let claims = ctx
+        .data_opt::<Claims>()
+        .ok_or_else(|| AppError::unauthorized(\"Missing authentication token\").extend())?;
+    if !roles.is_empty() && !claims.has_any_role(roles) {
+        return Err(AppError::forbidden(format!(\"Requires one of the roles {:?}\", roles)).extend());
+    }
+    Ok(claims)
+}
+
+",
+        );
+        content.push_str(&doc_comment(
+            Some(
+                "Request data for a subscription connection, checked when it opens.\n\n\
+                 Browsers cannot set headers on a WebSocket, so the caller's bearer token\n\
+                 is read from the `Authorization` (or `authToken`) entry of the\n\
+                 `connection_init` payload, falling back to `header_claims` from the\n\
+                 upgrade request. A token that does not verify refuses the connection.",
+            ),
+            ctx,
+        ));
+        content.push_str(
+            "\
+pub async fn connection_data(
+    payload: serde_json::Value,
+    header_claims: Option<Claims>,
+    jwt_secret: String,
+) -> async_graphql::Result<Data> {
+    let token = [\"Authorization\", \"authToken\"]
+        .into_iter()
+        .find_map(|key| payload.get(key)?.as_str());
+    let claims = match token {
+        Some(token) => {
+            let token = token.strip_prefix(\"Bearer \").unwrap_or(token);
+            let claims = verify_token(token, &jwt_secret).map_err(|e| {
+                tracing::warn!(\"JWT verification failed: {}\", e);
+                AppError::unauthorized(\"Invalid authentication token\").extend()
+            })?;
+            Some(claims)
+        }
+        None => header_claims,
+    };
+    let mut data = Data::default();
+    if let Some(claims) = claims {
+        data.insert(claims);
+    }
+    Ok(data)
+}
+",
+        );
+    }
+
+    GeneratedFile::new("src/graphql/mod.rs", content, FileType::Rust)
+}
+
+/// Loaders registered with the schema: one by primary key for every
+/// entity something points at, one per one-to-many key.
+fn loader_names(schema: &SchemaEntities) -> Vec<String> {
+    let refs = schema.references();
+    let mut names: Vec<String> = Vec::new();
+    for info in &schema.entities {
+        if refs.iter().any(|r| r.parent.id == info.entity.id) {
+            names.push(pk_loader(info.entity));
+        }
+    }
+    names.extend(refs.iter().map(Reference::children_loader));
+    names
+}
+
+// ============================================================================
+// graphql/loaders.rs
+// ============================================================================
+
+fn generate_loaders(schema: &SchemaEntities, ctx: &GenerationContext) -> GeneratedFile {
+    let refs = schema.references();
+    let parents: Vec<&EntityInfo> = schema
+        .entities
+        .iter()
+        .filter(|i| refs.iter().any(|r| r.parent.id == i.entity.id))
+        .collect();
+
+    let mut content = String::with_capacity(4096);
+    content.push_str(&file_header(
+        "GraphQL dataloaders — batched relationship lookups.",
+    ));
+
+    if parents.is_empty() {
+        content.push_str("// No relationships between exposed entities.\n");
+        return GeneratedFile::new("src/graphql/loaders.rs", content, FileType::Rust);
+    }
+
+    content.push_str("use std::collections::HashMap;\nuse std::sync::Arc;\n\n");
+    content.push_str("use async_graphql::dataloader::Loader;\n");
+    content.push_str(
+        "use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};\n",
+    );
+    // Key types are fully qualified, so no scalar imports are needed
+    content.push('\n');
+    content.push_str("use crate::error::AppError;\n");
+
+    let mut modules: Vec<String> = parents.iter().map(|i| i.module_name()).collect();
+    modules.extend(
+        refs.iter()
+            .map(|r| GenerationContext::module_name(&r.child.name)),
+    );
+    modules.sort();
+    modules.dedup();
+    content.push_str(&format!(
+        "use crate::models::{{{}}};\n\n",
+        modules.join(", ")
+    ));
+
+    for info in &parents {
+        content.push_str(&generate_pk_loader(info, ctx));
+        content.push('\n');
+    }
+    for reference in &refs {
+        content.push_str(&generate_children_loader(
+            reference,
+            schema.info(reference.child),
+            schema.info(reference.parent),
+            ctx,
+        ));
+        content.push('\n');
+    }
+
+    GeneratedFile::new("src/graphql/loaders.rs", content, FileType::Rust)
+}
+
+/// Struct and constructor shared by every loader.
+fn loader_struct(name: &str, doc: &str, ctx: &GenerationContext) -> String {
+    let mut out = doc_comment(Some(doc), ctx);
+    out.push_str(&format!(
+        "\
+pub struct {name} {{
+    db: DatabaseConnection,
+}}
+
+impl {name} {{
+    pub fn new(db: DatabaseConnection) -> Self {{
+        Self {{ db }}
+    }}
+}}
+
+"
+    ));
+    out
+}
+
+fn generate_pk_loader(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let name = pk_loader(info.entity);
+    let pascal = info.pascal_name();
+    let module = info.module_name();
+    let key = info.pk_rust_type();
+    let pk = info
+        .pk()
+        .map(|f| f.name.clone())
+        .unwrap_or_else(|| "id".to_string());
+    let pk_snake = GenerationContext::snake(&pk);
+    let pk_column = GenerationContext::pascal(&pk);
+
+    let mut out = loader_struct(
+        &name,
+        &format!(
+            "Loads `{}`s by primary key, one query for every lookup in a request.",
+            pascal
+        ),
+        ctx,
+    );
+    out.push_str(&format!(
+        "\
+impl Loader<{key}> for {name} {{
+    type Value = super::{module}::{pascal};
+    type Error = Arc<AppError>;
+
+    async fn load(&self, keys: &[{key}]) -> Result<HashMap<{key}, Self::Value>, Self::Error> {{
+        let rows = {module}::Entity::find()
+            .filter({module}::Column::{pk_column}.is_in(keys.iter().copied()))
+            .all(&self.db)
+            .await
+            .map_err(|e| Arc::new(AppError::from(e)))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.{pk_snake}, Self::Value::from(row)))
+            .collect())
+    }}
+}}
+"
+    ));
+    out
+}
+
+fn generate_children_loader(
+    reference: &Reference,
+    child: &EntityInfo,
+    parent: &EntityInfo,
+    ctx: &GenerationContext,
+) -> String {
+    let name = reference.children_loader();
+    let key = parent.pk_rust_type();
+    let child_module = child.module_name();
+    let child_pascal = child.pascal_name();
+    let fk = GenerationContext::snake(&reference.fk_field.name);
+    let fk_column = GenerationContext::pascal(&reference.fk_field.name);
+    let child_pk = GenerationContext::pascal(
+        &child
+            .pk()
+            .map(|f| f.name.clone())
+            .unwrap_or_else(|| "id".to_string()),
+    );
+
+    let group = if reference.optional() {
+        format!(
+            "            if let Some(key) = row.{fk} {{\n                grouped.entry(key).or_default().push(super::{child_module}::{child_pascal}::from(row));\n            }}\n"
+        )
+    } else {
+        format!(
+            "            grouped\n                .entry(row.{fk})\n                .or_default()\n                .push(super::{child_module}::{child_pascal}::from(row));\n"
+        )
+    };
+
+    // Soft-deleted children are left out, as in the list query
+    let find = if child.has_soft_delete() {
+        "find_active"
+    } else {
+        "find"
+    };
+
+    let mut out = loader_struct(
+        &name,
+        &format!(
+            "Loads the `{}`s of each `{}` through `{}`, one query per request.",
+            child_pascal,
+            parent.pascal_name(),
+            fk,
+        ),
+        ctx,
+    );
+    out.push_str(&format!(
+        "\
+impl Loader<{key}> for {name} {{
+    type Value = Vec<super::{child_module}::{child_pascal}>;
+    type Error = Arc<AppError>;
+
+    async fn load(&self, keys: &[{key}]) -> Result<HashMap<{key}, Self::Value>, Self::Error> {{
+        let rows = {child_module}::Entity::{find}()
+            .filter({child_module}::Column::{fk_column}.is_in(keys.iter().copied()))
+            .order_by_asc({child_module}::Column::{child_pk})
+            .all(&self.db)
+            .await
+            .map_err(|e| Arc::new(AppError::from(e)))?;
+
+        let mut grouped: HashMap<{key}, Self::Value> = HashMap::new();
+        for row in rows {{
+{group}        }}
+        Ok(grouped)
+    }}
+}}
+"
+    ));
+    out
+}
+
+// ============================================================================
+// graphql/{entity}.rs
+// ============================================================================
+
+fn generate_entity_graphql(
+    info: &EntityInfo,
+    schema: &SchemaEntities,
+    ctx: &GenerationContext,
+) -> GeneratedFile {
+    let ops = operations(info);
+    let parents = schema.parents_of(info);
+    let children = schema.children_of(info);
+    let has_relations = !parents.is_empty() || !children.is_empty();
+    let creates = ops.contains(&OperationType::Create);
+    let updates = ops.contains(&OperationType::Update);
+    let deletes = ops.contains(&OperationType::Delete);
+    let mutates = creates || updates || deletes;
+    let live = is_live(info);
+    let blockers = if deletes {
+        info.delete_blockers()
+    } else {
+        Vec::new()
+    };
+
+    let mut content = String::with_capacity(8192);
+    content.push_str(&file_header(&format!(
+        "GraphQL type, queries and mutations for {}.",
+        info.pascal_name()
+    )));
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    if live {
+        content.push_str("use async_graphql::futures_util::Stream;\n");
+    }
+    if has_relations {
+        content.push_str("use async_graphql::dataloader::DataLoader;\n");
+    }
+    let mut graphql_imports = vec!["Context", "ErrorExtensions", "Object", "SimpleObject"];
+    if has_relations {
+        graphql_imports.push("ComplexObject");
+    }
+    if creates || updates {
+        graphql_imports.push("InputObject");
+    }
+    if live {
+        graphql_imports.push("Subscription");
+    }
+    graphql_imports.sort_unstable();
+    content.push_str(&format!(
+        "use async_graphql::{{{}}};\n",
+        graphql_imports.join(", ")
+    ));
+
+    let mut sea_imports = vec!["EntityTrait"];
+    if creates || updates || (deletes && info.has_soft_delete()) {
+        sea_imports.extend(["ActiveModelTrait", "Set"]);
+    }
+    if updates || (deletes && info.has_soft_delete()) {
+        sea_imports.push("IntoActiveModel");
+    }
+    if !blockers.is_empty() {
+        sea_imports.extend(["ColumnTrait", "PaginatorTrait", "QueryFilter"]);
+    }
+    if ops.contains(&OperationType::ReadAll) {
+        sea_imports.extend(["QueryOrder", "QuerySelect"]);
+    }
+    sea_imports.sort_unstable();
+    content.push_str(&format!("use sea_orm::{{{}}};\n", sea_imports.join(", ")));
+
+    let object = object_fields(info);
+    let create_fields = info.create_fields();
+    let update_fields = info.update_fields();
+    let mut types: Vec<String> = object.iter().map(|(_, ty)| ty.clone()).collect();
+    types.push(info.pk_rust_type());
+    if creates {
+        types.extend(create_fields.iter().map(|f| field_rust_type_dto(f, false)));
+    }
+    if updates {
+        types.extend(update_fields.iter().map(|f| field_rust_type_dto(f, false)));
+    }
+    content.push_str(&scalar_imports(types.iter().map(String::as_str)));
+    if creates || updates {
+        content.push_str("use validator::Validate;\n");
+    }
+    let hashes = ctx.auth_enabled()
+        && ((creates && create_fields.iter().any(|f| ctx.is_password_field(f)))
+            || (updates && update_fields.iter().any(|f| ctx.is_password_field(f))));
+    if hashes {
+        content.push_str("use crate::auth::jwt::hash_password;\n");
+    }
+    content.push('\n');
+
+    if blockers.is_empty() {
+        content.push_str("use crate::error::AppError;\n");
+    } else {
+        content.push_str("use crate::error::{AppError, BlockingRecords};\n");
+    }
+    let mut local = Vec::new();
+    if has_relations {
+        local.push("loaders".to_string());
+    }
+    if ctx.auth_enabled() && uses_auth_guard(info, &parents, &children, schema, ctx) {
+        local.push("require_auth".to_string());
+    }
+    if !local.is_empty() {
+        content.push_str(&format!("use crate::graphql::{{{}}};\n", local.join(", ")));
+    }
+
+    let module = info.module_name();
+    let mut model_imports = vec!["self".to_string()];
+    if creates {
+        model_imports.push(GenerationContext::create_dto_name(&info.entity.name));
+    }
+    model_imports.push(GenerationContext::response_dto_name(&info.entity.name));
+    if updates {
+        model_imports.push(GenerationContext::update_dto_name(&info.entity.name));
+    }
+    content.push_str(&format!(
+        "use crate::models::{}::{{{}}};\n",
+        module,
+        model_imports.join(", ")
+    ));
+    let mut blocker_modules: Vec<String> = blockers
+        .iter()
+        .map(|b| GenerationContext::module_name(&b.child.name))
+        .filter(|m| *m != module)
+        .collect();
+    blocker_modules.sort();
+    blocker_modules.dedup();
+    for m in blocker_modules {
+        content.push_str(&format!("use crate::models::{};\n", m));
+    }
+    if live {
+        content.push_str(
+            "use crate::realtime::{change_stream, publish_change, ChangeKind, EntityChange};\n",
+        );
+    }
+    content.push_str("use crate::state::AppState;\n\n");
+
+    // ── Object type ──────────────────────────────────────────────────────
+    content.push_str(&generate_object(info, &object, has_relations, ctx));
+    content.push('\n');
+    if has_relations {
+        content.push_str(&generate_relations(info, &parents, &children, schema, ctx));
+        content.push('\n');
+    }
+
+    // ── Inputs ───────────────────────────────────────────────────────────
+    if creates {
+        content.push_str(&generate_create_input(info, ctx));
+        content.push('\n');
+    }
+    if updates {
+        content.push_str(&generate_update_input(info, ctx));
+        content.push('\n');
+    }
+
+    // ── Resolvers ────────────────────────────────────────────────────────
+    content.push_str(&generate_query(info, &ops, ctx));
+    content.push('\n');
+    if mutates {
+        content.push_str(&generate_mutation(info, &ops, ctx));
+        content.push('\n');
+        if live {
+            content.push_str(&generate_subscription(info, &ops, ctx));
+            content.push('\n');
+        }
+        content.push_str(&generate_mutation_bodies(info, &ops, ctx));
+    }
+
+    GeneratedFile::new(
+        format!("src/graphql/{}.rs", module),
+        content,
+        FileType::Rust,
+    )
+}
+
+/// Whether any resolver in the entity's file checks the caller.
+fn uses_auth_guard(
+    info: &EntityInfo,
+    parents: &[Reference],
+    children: &[Reference],
+    schema: &SchemaEntities,
+    ctx: &GenerationContext,
+) -> bool {
+    let own = operations(info)
+        .into_iter()
+        .chain(subscription_security_op(info).filter(|_| is_live(info)))
+        .any(|op| !auth_guard(info, ctx, op).is_empty());
+    let parent_reads = parents
+        .iter()
+        .any(|r| !auth_guard(schema.info(r.parent), ctx, OperationType::Read).is_empty());
+    let child_reads = children
+        .iter()
+        .any(|r| !auth_guard(schema.info(r.child), ctx, OperationType::ReadAll).is_empty());
+    own || parent_reads || child_reads
+}
+
+/// Operation whose security guards the change subscription: reading the
+/// list if it is exposed, otherwise reading one record.
+fn subscription_security_op(info: &EntityInfo) -> Option<OperationType> {
+    let ops = operations(info);
+    [OperationType::ReadAll, OperationType::Read]
+        .into_iter()
+        .find(|op| ops.contains(op))
+}
+
+fn generate_object(
+    info: &EntityInfo,
+    fields: &[(String, String)],
+    complex: bool,
+    ctx: &GenerationContext,
+) -> String {
+    let pascal = info.pascal_name();
+    let module = info.module_name();
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+
+    let mut out = doc_comment(
+        Some(&format!("A {}, as returned by the GraphQL API.", pascal)),
+        ctx,
+    );
+    out.push_str("#[derive(SimpleObject, Debug, Clone)]\n");
+    if complex {
+        out.push_str("#[graphql(complex)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", pascal));
+    for (name, ty) in fields {
+        out.push_str(&format!("    pub {}: {},\n", name, ty));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(&format!(
+        "impl From<{module}::Model> for {pascal} {{\n    fn from(model: {module}::Model) -> Self {{\n        let response = {response_dto}::from(model);\n        Self {{\n"
+    ));
+    for (name, _) in fields {
+        out.push_str(&format!("            {name}: response.{name},\n"));
+    }
+    out.push_str("        }\n    }\n}\n");
+    out
+}
+
+fn generate_relations(
+    info: &EntityInfo,
+    parents: &[Reference],
+    children: &[Reference],
+    schema: &SchemaEntities,
+    ctx: &GenerationContext,
+) -> String {
+    let mut out = String::from("#[ComplexObject]\n");
+    out.push_str(&format!("impl {} {{\n", info.pascal_name()));
+
+    let mut first = true;
+    for reference in parents {
+        if !first {
+            out.push('\n');
+        }
+        first = false;
+
+        let parent = schema.info(reference.parent);
+        let fk = GenerationContext::snake(&reference.fk_field.name);
+        let ty = format!("super::{}::{}", parent.module_name(), parent.pascal_name());
+        out.push_str(&indent_doc(
+            &format!("The `{}` `{}` points at.", parent.pascal_name(), fk),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "    async fn {}(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<{}>> {{\n",
+            reference
+                .relation_accessor(ctx, true)
+                .unwrap_or_else(|| reference.parent_field_name()),
+            ty,
+        ));
+        out.push_str(&auth_guard(parent, ctx, OperationType::Read));
+        let loader = format!(
+            "ctx.data::<DataLoader<loaders::{}>>()?",
+            pk_loader(reference.parent)
+        );
+        if reference.optional() {
+            out.push_str(&format!(
+                "        let Some(key) = self.{fk} else {{\n            return Ok(None);\n        }};\n        {loader}\n            .load_one(key)\n            .await\n            .map_err(|e| e.extend())\n"
+            ));
+        } else {
+            out.push_str(&format!(
+                "        {loader}\n            .load_one(self.{fk})\n            .await\n            .map_err(|e| e.extend())\n"
+            ));
+        }
+        out.push_str("    }\n");
+    }
+
+    let pk = GenerationContext::snake(
+        &info
+            .pk()
+            .map(|f| f.name.clone())
+            .unwrap_or_else(|| "id".to_string()),
+    );
+    for reference in children {
+        if !first {
+            out.push('\n');
+        }
+        first = false;
+
+        let child = schema.info(reference.child);
+        let ambiguous = children
+            .iter()
+            .filter(|r| r.child.id == reference.child.id)
+            .count()
+            > 1;
+        out.push_str(&indent_doc(
+            &format!(
+                "The `{}`s whose `{}` points here.",
+                child.pascal_name(),
+                GenerationContext::snake(&reference.fk_field.name)
+            ),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "    async fn {}(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<super::{}::{}>> {{\n",
+            reference
+                .relation_accessor(ctx, false)
+                .unwrap_or_else(|| reference.children_field_name(ambiguous)),
+            child.module_name(),
+            child.pascal_name(),
+        ));
+        out.push_str(&auth_guard(child, ctx, OperationType::ReadAll));
+        out.push_str(&format!(
+            "        let children = ctx\n            .data::<DataLoader<loaders::{}>>()?\n            .load_one(self.{pk})\n            .await\n            .map_err(|e| e.extend())?;\n        Ok(children.unwrap_or_default())\n",
+            reference.children_loader(),
+        ));
+        out.push_str("    }\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// A doc comment indented for an `impl` block member.
+fn indent_doc(text: &str, ctx: &GenerationContext) -> String {
+    doc_comment(Some(text), ctx)
+        .lines()
+        .map(|line| format!("    {}\n", line.trim_end()))
+        .collect()
+}
+
+fn generate_create_input(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let name = format!("Create{}Input", info.pascal_name());
+    let dto = GenerationContext::create_dto_name(&info.entity.name);
+    let fields: Vec<(String, String)> = info
+        .create_fields()
+        .iter()
+        .map(|f| (dto_field_name(f, ctx), field_rust_type_dto(f, false)))
+        .collect();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Input of `create{}`; validated like the REST create payload.",
+            info.pascal_name()
+        )),
+        ctx,
+    );
+    out.push_str("#[derive(InputObject)]\n");
+    out.push_str(&format!("pub struct {} {{\n", name));
+    for (field, ty) in &fields {
+        out.push_str(&format!("    pub {}: {},\n", field, ty));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(&format!(
+        "impl From<{name}> for {dto} {{\n    fn from(input: {name}) -> Self {{\n        Self {{\n"
+    ));
+    for (field, _) in &fields {
+        out.push_str(&format!("            {field}: input.{field},\n"));
+    }
+    // Nested children are a REST payload feature
+    for nested in info.nested_children() {
+        out.push_str(&format!(
+            "            {}: Vec::new(),\n",
+            nested.payload_field()
+        ));
+    }
+    out.push_str("        }\n    }\n}\n");
+    out
+}
+
+fn generate_update_input(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let name = format!("Update{}Input", info.pascal_name());
+    let dto = GenerationContext::update_dto_name(&info.entity.name);
+    let fields: Vec<(String, String)> = info
+        .update_fields()
+        .iter()
+        .map(|f| {
+            (
+                GenerationContext::snake(&f.name),
+                format!("Option<{}>", field_rust_type_dto(f, false)),
+            )
+        })
+        .collect();
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Input of `update{}`; only the fields given are changed.",
+            info.pascal_name()
+        )),
+        ctx,
+    );
+    out.push_str("#[derive(InputObject)]\n");
+    out.push_str(&format!("pub struct {} {{\n", name));
+    for (field, ty) in &fields {
+        out.push_str(&format!("    pub {}: {},\n", field, ty));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(&format!(
+        "impl From<{name}> for {dto} {{\n    fn from(input: {name}) -> Self {{\n        Self {{\n"
+    ));
+    for (field, _) in &fields {
+        out.push_str(&format!("            {field}: input.{field},\n"));
+    }
+    out.push_str("        }\n    }\n}\n");
+    out
+}
+
+fn generate_query(info: &EntityInfo, ops: &[OperationType], ctx: &GenerationContext) -> String {
+    let pascal = info.pascal_name();
+    let module = info.module_name();
+    let pk_type = info.pk_rust_type();
+    let read_db = ctx.read_db();
+
+    let mut out = doc_comment(Some(&format!("Queries for `{}`s.", pascal)), ctx);
+    out.push_str(&format!(
+        "#[derive(Default)]\npub struct {pascal}Query;\n\n#[Object]\nimpl {pascal}Query {{\n"
+    ));
+
+    let mut first = true;
+    if ops.contains(&OperationType::ReadAll) {
+        first = false;
+        let options = crate::rust::handlers::list_options(info);
+        let max = if options.max_page_size > 0 {
+            options.max_page_size
+        } else {
+            MAX_PAGE_SIZE
+        };
+        let default = options.default_page_size.clamp(1, max);
+        // The generated constants, unless the configured sizes need clamping
+        let (default_attr, cap) =
+            if max == options.max_page_size && default == options.default_page_size {
+                let (default_const, max_const) = page_size_consts(info);
+                (
+                    format!("default_with = \"crate::constants::{default_const}\""),
+                    format!("crate::constants::{max_const}"),
+                )
+            } else {
+                (format!("default = {default}"), max.to_string())
+            };
+        let pk_column = GenerationContext::pascal(
+            &info
+                .pk()
+                .map(|f| f.name.clone())
+                .unwrap_or_else(|| "id".to_string()),
+        );
+        out.push_str(&indent_doc(
+            &format!(
+                "{}s in primary-key order. `limit` is capped at {}.",
+                pascal, max
+            ),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "    async fn {list}(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 0)] offset: u64,
+        #[graphql({default_attr})] limit: u64,
+    ) -> async_graphql::Result<Vec<{pascal}>> {{
+{guard}        let state = ctx.data::<AppState>()?;
+        let rows = {module}::Entity::{find}()
+            .order_by_asc({module}::Column::{pk_column})
+            .offset(offset)
+            .limit(limit.min({cap}))
+            .all(&{read_db})
+            .await
+            .map_err(|e| AppError::from(e).extend())?;
+        Ok(rows.into_iter().map({pascal}::from).collect())
+    }}
+",
+            list = list_field_name(info),
+            guard = auth_guard(info, ctx, OperationType::ReadAll),
+            find = if info.has_soft_delete() {
+                "find_active"
+            } else {
+                "find"
+            },
+        ));
+    }
+
+    if ops.contains(&OperationType::Read) {
+        if !first {
+            out.push('\n');
+        }
+        out.push_str(&indent_doc(
+            &format!("The {} with this ID, if there is one.", info.snake_name()),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "    async fn {snake}(&self, ctx: &Context<'_>, id: {pk_type}) -> async_graphql::Result<Option<{pascal}>> {{
+{guard}        let state = ctx.data::<AppState>()?;
+        let row = {module}::Entity::{find}(id)
+            .one(&{read_db})
+            .await
+            .map_err(|e| AppError::from(e).extend())?;
+        Ok(row.map({pascal}::from))
+    }}
+",
+            snake = info.snake_name(),
+            guard = auth_guard(info, ctx, OperationType::Read),
+            find = find_existing(info),
+        ));
+    } else if first {
+        // An object type needs at least one field
+        out.push_str(&format!(
+            "    /// `{pascal}` is only reachable through mutations.\n    async fn {snake}_readable(&self) -> bool {{\n        false\n    }}\n",
+            snake = info.snake_name(),
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn generate_mutation(info: &EntityInfo, ops: &[OperationType], ctx: &GenerationContext) -> String {
+    let pascal = info.pascal_name();
+    let snake = info.snake_name();
+    let pk_type = info.pk_rust_type();
+    let pk = GenerationContext::snake(
+        &info
+            .pk()
+            .map(|f| f.name.clone())
+            .unwrap_or_else(|| "id".to_string()),
+    );
+
+    let mut out = doc_comment(Some(&format!("Mutations of `{}`s.", pascal)), ctx);
+    out.push_str(&format!(
+        "#[derive(Default)]\npub struct {pascal}Mutation;\n\n#[Object]\nimpl {pascal}Mutation {{\n"
+    ));
+
+    let mut resolvers = Vec::new();
+    if ops.contains(&OperationType::Create) {
+        resolvers.push(format!(
+            "{doc}    async fn create_{snake}(&self, ctx: &Context<'_>, input: Create{pascal}Input) -> async_graphql::Result<{pascal}> {{
+{guard}        let state = ctx.data::<AppState>()?;
+        let created = create(state, input.into()).await.map_err(|e| e.extend())?;
+{publish}        Ok(created)
+    }}
+",
+            doc = indent_doc(&format!("Create a {}.", snake), ctx),
+            publish = publish_line(info, "Created", &format!("&created.{}", pk)),
+            guard = auth_guard(info, ctx, OperationType::Create),
+        ));
+    }
+    if ops.contains(&OperationType::Update) {
+        resolvers.push(format!(
+            "{doc}    async fn update_{snake}(
+        &self,
+        ctx: &Context<'_>,
+        id: {pk_type},
+        input: Update{pascal}Input,
+    ) -> async_graphql::Result<{pascal}> {{
+{guard}        let state = ctx.data::<AppState>()?;
+        let updated = update(state, id, input.into()).await.map_err(|e| e.extend())?;
+{publish}        Ok(updated)
+    }}
+",
+            doc = indent_doc(&format!("Update the {} with this ID.", snake), ctx),
+            publish = publish_line(info, "Updated", "id"),
+            guard = auth_guard(info, ctx, OperationType::Update),
+        ));
+    }
+    if ops.contains(&OperationType::Delete) {
+        let doc = match crate::rust::handlers::delete_effects_doc(info) {
+            Some(effects) => format!("Delete the {} with this ID.\n\n{}", snake, effects),
+            None => format!("Delete the {} with this ID.", snake),
+        };
+        resolvers.push(format!(
+            "{doc}    async fn delete_{snake}(&self, ctx: &Context<'_>, id: {pk_type}) -> async_graphql::Result<bool> {{
+{guard}        let state = ctx.data::<AppState>()?;
+        delete(state, id).await.map_err(|e| e.extend())?;
+{publish}        Ok(true)
+    }}
+",
+            doc = indent_doc(&doc, ctx),
+            publish = publish_line(info, "Deleted", "id"),
+            guard = auth_guard(info, ctx, OperationType::Delete),
+        ));
+    }
+    out.push_str(&resolvers.join("\n"));
+    out.push_str("}\n");
+    out
+}
+
+fn generate_subscription(
+    info: &EntityInfo,
+    _ops: &[OperationType],
+    ctx: &GenerationContext,
+) -> String {
+    let pascal = info.pascal_name();
+    let guard = subscription_security_op(info)
+        .map(|op| auth_guard(info, ctx, op))
+        .unwrap_or_default();
+    let ctx_param = if guard.is_empty() {
+        String::new()
+    } else {
+        ", ctx: &Context<'_>".to_string()
+    };
+
+    let mut out = doc_comment(
+        Some(&format!("Change notifications for `{}`s.", pascal)),
+        ctx,
+    );
+    out.push_str(&format!(
+        "#[derive(Default)]\npub struct {pascal}Subscription;\n\n#[Subscription]\nimpl {pascal}Subscription {{\n"
+    ));
+    out.push_str(&indent_doc(
+        &format!(
+            "Every {} created, updated or deleted after subscribing.",
+            info.snake_name()
+        ),
+        ctx,
+    ));
+    out.push_str(&format!(
+        "    async fn {snake}_changed(&self{ctx_param}) -> async_graphql::Result<impl Stream<Item = EntityChange>> {{
+{guard}        Ok(change_stream(\"{pascal}\"))
+    }}
+}}
+",
+        snake = info.snake_name(),
+    ));
+    out
+}
+
+/// The mutations' database work, written against `AppError` like the REST
+/// handlers so the same snippets apply.
+fn generate_mutation_bodies(
+    info: &EntityInfo,
+    ops: &[OperationType],
+    ctx: &GenerationContext,
+) -> String {
+    let pascal = info.pascal_name();
+    let module = info.module_name();
+    let pk_type = info.pk_rust_type();
+
+    let mut out = String::from(
+        "// ============================================================================\n\
+         // Database operations\n\
+         // ============================================================================\n",
+    );
+
+    if ops.contains(&OperationType::Create) {
+        out.push_str(&format!(
+            "\nasync fn create(state: &AppState, payload: {dto}) -> Result<{pascal}, AppError> {{
+    payload.validate().map_err(AppError::from)?;
+
+    let model = {module}::ActiveModel {{
+{sets}        ..Default::default()
+    }}
+    .insert(&state.db)
+    .await
+    .map_err(AppError::from)?;
+
+    Ok({pascal}::from(model))
+}}
+",
+            dto = GenerationContext::create_dto_name(&info.entity.name),
+            sets = active_model_sets(info, ctx, &info.create_fields(), "payload", 8),
+        ));
+    }
+
+    if ops.contains(&OperationType::Update) {
+        out.push_str(&format!(
+            "\nasync fn update(state: &AppState, id: {pk_type}, payload: {dto}) -> Result<{pascal}, AppError> {{
+    payload.validate().map_err(AppError::from)?;
+
+    let existing = {module}::Entity::{find}(id)
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+    let mut active: {module}::ActiveModel = existing.into_active_model();
+
+{sets}
+    let updated = active.update(&state.db).await.map_err(AppError::from)?;
+
+    Ok({pascal}::from(updated))
+}}
+",
+            dto = GenerationContext::update_dto_name(&info.entity.name),
+            sets = update_field_sets(info, ctx),
+            find = find_existing(info),
+        ));
+    }
+
+    if ops.contains(&OperationType::Delete) {
+        if info.has_soft_delete() {
+            out.push_str(&format!(
+                "\nasync fn delete(state: &AppState, id: {pk_type}) -> Result<(), AppError> {{
+    let existing = {module}::Entity::find_active_by_id(id)
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+    let mut active: {module}::ActiveModel = existing.into_active_model();
+    active.deleted_at = Set(Some(chrono::Utc::now()));
+    active.update(&state.db).await.map_err(AppError::from)?;
+
+    Ok(())
+}}
+"
+            ));
+        } else {
+            out.push_str(&format!(
+                "\nasync fn delete(state: &AppState, id: {pk_type}) -> Result<(), AppError> {{
+{blocker_check}    let result = {module}::Entity::delete_by_id(id)
+        .exec(&state.db)
+        .await
+        .map_err(AppError::from)?;
+
+    if result.rows_affected == 0 {{
+        return Err(AppError::NotFound);
+    }}
+
+    Ok(())
+}}
+",
+                blocker_check = delete_blocker_check(&info.delete_blockers()),
+            ));
+        }
+    }
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_ir::{ApiStyle, EndpointGroup, EndpointSecurity, ProjectGraph, Relationship};
+
+    fn blog(auth: bool) -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        project.config.api_style = ApiStyle::Graphql;
+        project.config.auth.enabled = auth;
+
+        let mut user = Entity::new("User");
+        user.fields.push(Field::new("email", DataType::String));
+        let user_id = project.add_entity(user);
+
+        let mut post = Entity::new("Post");
+        post.config.timestamps = true;
+        post.fields.push(Field::new("title", DataType::String));
+        let post_id = project.add_entity(post);
+
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        let mut post_ep = EndpointGroup::new(post_id, "Post").realtime();
+        if auth {
+            post_ep.global_security = EndpointSecurity::authenticated();
+        }
+        project.add_endpoint(post_ep);
+
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        project
+    }
+
+    fn generate(project: &ProjectGraph) -> Vec<GeneratedFile> {
+        let ctx = GenerationContext::from_project(project, GeneratorConfig::default());
+        generate_graphql(&ctx)
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .unwrap_or_else(|| panic!("{} not generated", path))
+            .content
+    }
+
+    #[test]
+    fn test_generates_module_per_entity() {
+        let files = generate(&blog(false));
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(module.contains("pub mod user;"));
+        assert!(module.contains("pub mod post;"));
+        assert!(module.contains("MetaQuery, "));
+        assert!(module.contains("user::UserQuery"));
+        assert!(module.contains("#[derive(MergedSubscription, Default)]"));
+        assert!(module.contains("pub fn build_schema(state: AppState) -> AppSchema"));
+        assert!(file(&files, "src/graphql/http.rs").contains("graphql_routes"));
+    }
+
+    #[test]
+    fn test_entity_crud_resolvers() {
+        let files = generate(&blog(false));
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(post.contains("pub struct Post {"));
+        assert!(post.contains("async fn posts("));
+        assert!(post.contains("async fn post(&self, ctx: &Context<'_>, id: uuid::Uuid)"));
+        assert!(post.contains("async fn create_post("));
+        assert!(post.contains("async fn update_post("));
+        assert!(post.contains("async fn delete_post("));
+        assert!(post.contains("async fn post_changed(&self)"));
+        assert!(post.contains("pub struct CreatePostInput {"));
+        assert!(post.contains("impl From<CreatePostInput> for CreatePostDto"));
+        assert!(post.contains("payload.validate().map_err(AppError::from)?;"));
+        assert!(post.contains("publish_change(\"Post\", ChangeKind::Created"));
+    }
+
+    #[test]
+    fn test_subscriptions_only_for_realtime_entities() {
+        let files = generate(&blog(false));
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(module.contains("pub struct SubscriptionRoot(post::PostSubscription);"));
+        assert!(!module.contains("broadcast"));
+
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(post.contains(
+            "use crate::realtime::{change_stream, publish_change, ChangeKind, EntityChange};"
+        ));
+        assert!(post.contains("Ok(change_stream(\"Post\"))"));
+
+        // Users change, but nobody subscribes to them
+        let user = file(&files, "src/graphql/user.rs");
+        assert!(user.contains("async fn create_user("));
+        assert!(!user.contains("UserSubscription"));
+        assert!(!user.contains("publish_change"));
+        assert!(!user.contains("crate::realtime"));
+
+        let mut project = blog(false);
+        for ep in project.endpoints.values_mut() {
+            ep.realtime = false;
+        }
+        let files = generate(&project);
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(
+            module.contains(
+                "pub type AppSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;"
+            )
+        );
+        assert!(module.contains("        MutationRoot::default(),\n        EmptySubscription,\n"));
+        assert!(!file(&files, "src/graphql/post.rs").contains("Subscription"));
+    }
+
+    #[test]
+    fn test_subscription_connections_verify_tokens() {
+        let files = generate(&blog(true));
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(module.contains("pub async fn connection_data("));
+        assert!(module.contains("[\"Authorization\", \"authToken\"]"));
+        assert!(
+            module.contains("AppError::unauthorized(\"Invalid authentication token\").extend()")
+        );
+
+        let http = file(&files, "src/graphql/http.rs");
+        assert!(http.contains("use crate::graphql::{connection_data, AppSchema};"));
+        assert!(http.contains(
+            ".on_connection_init(move |init| connection_data(init, claims, jwt_secret))"
+        ));
+        assert!(!http.contains("with_data"));
+
+        let public = generate(&blog(false));
+        assert!(!file(&public, "src/graphql/mod.rs").contains("connection_data"));
+        assert!(!file(&public, "src/graphql/http.rs").contains("on_connection_init"));
+    }
+
+    #[test]
+    fn test_relationships_use_dataloaders() {
+        let files = generate(&blog(false));
+        let loaders = file(&files, "src/graphql/loaders.rs");
+        assert!(loaders.contains("impl Loader<uuid::Uuid> for UserLoader"));
+        assert!(loaders.contains("impl Loader<uuid::Uuid> for PostsByUserIdLoader"));
+        assert!(loaders.contains(".filter(post::Column::UserId.is_in(keys.iter().copied()))"));
+
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(post.contains("#[graphql(complex)]"));
+        assert!(post.contains("async fn user(&self, ctx: &Context<'_>)"));
+        assert!(post.contains(".load_one(self.user_id)"));
+
+        let user = file(&files, "src/graphql/user.rs");
+        assert!(user.contains("async fn posts(&self, ctx: &Context<'_>)"));
+        assert!(user.contains("DataLoader<loaders::PostsByUserIdLoader>"));
+
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(module.contains("loaders::UserLoader::new(state.db.clone())"));
+        assert!(module.contains("loaders::PostsByUserIdLoader::new(state.db.clone())"));
+    }
+
+    #[test]
+    fn test_relationship_fields_follow_accessor_names() {
+        let mut project = blog(false);
+        for rel in project.relationships.values_mut() {
+            rel.accessor_name = Some("author".to_string());
+            rel.inverse_name = Some("articles".to_string());
+        }
+        let files = generate(&project);
+
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(post.contains("async fn author(&self, ctx: &Context<'_>)"));
+        assert!(post.contains(".load_one(self.user_id)"));
+        let user = file(&files, "src/graphql/user.rs");
+        assert!(user.contains("async fn articles(&self, ctx: &Context<'_>)"));
+        assert!(!user.contains("async fn posts(&self, ctx: &Context<'_>)"));
+    }
+
+    #[test]
+    fn test_secured_operations_require_auth() {
+        let files = generate(&blog(true));
+        assert!(file(&files, "src/graphql/mod.rs").contains("pub fn require_auth<'a>("));
+
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(post.contains("require_auth(ctx, &[])?;"));
+        assert!(post.contains("async fn post_changed(&self, ctx: &Context<'_>)"));
+
+        // Users are public, but listing a user's posts reads posts
+        let user = file(&files, "src/graphql/user.rs");
+        assert_eq!(user.matches("require_auth(ctx, &[])?;").count(), 1);
+
+        let public = generate(&blog(false));
+        assert!(!file(&public, "src/graphql/mod.rs").contains("require_auth"));
+    }
+
+    #[test]
+    fn test_read_only_entities_have_no_mutations() {
+        let mut project = blog(false);
+        for ep in project.endpoints.values_mut() {
+            ep.operations
+                .retain(|op| op.operation_type == OperationType::Read);
+        }
+        let files = generate(&project);
+        let module = file(&files, "src/graphql/mod.rs");
+        assert!(
+            module.contains(
+                "pub type AppSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;"
+            )
+        );
+        assert!(!module.contains("ChangeKind"));
+
+        let post = file(&files, "src/graphql/post.rs");
+        assert!(!post.contains("PostMutation"));
+        assert!(!post.contains("async fn posts("));
+    }
+}
@@ -1,28 +1,110 @@
-//! # Handler Generator (Axum)
+//! # Handler Generator (SeaORM)
 //!
-//! Generates Axum request handler functions for every entity that has
-//! configured endpoints. Each enabled CRUD operation produces a dedicated
-//! async handler function.
+//! Generates request handler functions for every entity that has configured
+//! endpoints. Each enabled CRUD operation produces a dedicated async handler
+//! function.
 //!
 //! ## Generated Files
 //!
 //! - `src/handlers/mod.rs` — module declarations, pagination types
 //! - `src/handlers/{entity}.rs` — CRUD handlers per entity
 //!
+//! Each entity's file is rendered from an overridable template with the
+//! variables of [`HandlersContext`]; see [`templates`](crate::templates).
+//!
 //! ## Handler Signatures
 //!
 //! | Operation | Signature |
 //! |-----------|-----------|
-//! | List      | `async fn list_{entities}(State, Query<PaginationParams>) -> Result<Json<PaginatedResponse<…>>, AppError>` |
+//! | List      | `async fn list_{entities}(State, Query<PaginationParams>, Query<{Entity}ListParams>) -> Result<Json<PaginatedResponse<…>>, AppError>` (envelope depends on the project's `PaginationStyle`) |
 //! | Get       | `async fn get_{entity}(State, Path<PK>) -> Result<Json<Response>, AppError>` |
 //! | Create    | `async fn create_{entity}(State, Json<CreateDto>) -> Result<(StatusCode, Json<Response>), AppError>` |
 //! | Update    | `async fn update_{entity}(State, Path<PK>, Json<UpdateDto>) -> Result<Json<Response>, AppError>` |
 //! | Delete    | `async fn delete_{entity}(State, Path<PK>) -> Result<StatusCode, AppError>` |
-
-use imortal_ir::OperationType;
-
-use crate::context::{EntityInfo, GenerationContext};
-use crate::rust::models::generate_pagination_types;
+//!
+//! The signatures above are the Axum ones; with Actix Web the state is a
+//! `Data<AppState>`, and create/delete return `HttpResponse` (see
+//! [`FrameworkBackend`](crate::rust::framework::FrameworkBackend)).
+//!
+//! When an endpoint requests related counts or existence flags, list handlers
+//! return `{Entity}ListItem`s and load the aggregates for the whole page with
+//! one grouped query per aggregate.
+//!
+//! ## Sorting and Filtering
+//!
+//! List handlers also read a `{Entity}ListParams` struct following the
+//! operation's [`ListOptions`]: `?sort=-created_at,name` over the comparable
+//! response fields (not with cursor pagination, which is always in key
+//! order), and per-field filters such as `?email_contains=` or
+//! `?price_min=`. Page sizes come from the same options, through the
+//! `{ENTITY}_DEFAULT_PAGE_SIZE` and `{ENTITY}_MAX_PAGE_SIZE` constants.
+//!
+//! ## Soft Delete
+//!
+//! Entities with `soft_delete` keep deleted rows, with `deleted_at` set.
+//! Their handlers read through the model's `find_active` scopes, so list,
+//! get, update and delete treat those rows as gone; list and get take
+//! `?include_deleted=true` (`SoftDeleteParams`) to see them anyway, and
+//! `POST {base}/:id/restore` clears `deleted_at` again.
+//!
+//! ## Nested Create
+//!
+//! For each one-to-many relationship marked `nested_create`, the parent's
+//! create handler accepts the children inline (e.g. `items` in
+//! `POST /api/orders`), validates them with the parent payload and inserts
+//! parent and children in one transaction, setting each child's foreign key
+//! to the new parent.
+//!
+//! ## Associations
+//!
+//! A many-to-many relationship whose junction was promoted to an entity is
+//! managed from each side's routes, in `src/handlers/{entity}_links.rs`:
+//! `GET {base}/:id/{accessor}` lists the junction rows, `POST` inserts one
+//! (the junction's own fields included, e.g. `role`) and
+//! `DELETE {base}/:id/{accessor}/:{other}_id` removes it. The routes ride on
+//! the entity's update operation and share its security.
+//!
+//! ## Realtime
+//!
+//! Handlers of an entity whose endpoint group is marked realtime publish
+//! each committed create, update, delete and restore to the `src/realtime.rs`
+//! change feed, and `{entity}_changes` streams the feed to REST clients over
+//! the group's transport: `GET {base}/ws` (WebSocket) or `GET {base}/events`
+//! (server-sent events).
+//!
+//! ## Uploads
+//!
+//! Each `File`/`Image` field gets `upload_{entity}_{field}` at
+//! `POST {base}/:id/{field}`, riding on the update operation's security. It
+//! reads the first file part of a `multipart/form-data` body, stores it
+//! through `src/storage.rs` and saves the returned URL in the field; image
+//! fields refuse files not sent as `image/*`. The DTOs leave these fields
+//! out.
+//!
+//! ## Telemetry
+//!
+//! With telemetry enabled every handler gets a `#[tracing::instrument]` span
+//! named after the function, carrying `app.entity`, `app.operation`,
+//! `app.auth_required` (and the required roles and record id where they
+//! apply). It nests under the HTTP span from the middleware module, which
+//! holds the semantic-convention request fields and the caller's role.
+
+use imortal_core::DataType;
+use imortal_ir::{
+    AggregateKind, Field, ListOptions, OperationType, PaginationStyle, RealtimeTransport,
+    WidgetType,
+};
+use serde::Serialize;
+
+use crate::context::{Association, DeleteEffect, EntityInfo, GenerationContext};
+use crate::rust::computed::is_copy;
+use crate::rust::constants::page_size_consts;
+use crate::rust::framework::Extractor;
+use crate::rust::http_cache::{self, model_version, model_versions};
+use crate::rust::models::{
+    converts_create, converts_update, generate_pagination_types, list_response_type,
+};
+use crate::rust::orm::OrmFeature;
 use crate::rust::{doc_comment, file_header};
 use crate::{FileType, GeneratedFile};
 
@@ -50,6 +132,9 @@ pub fn generate_handlers(ctx: &GenerationContext) -> Vec<GeneratedFile> {
         };
 
         files.push(generate_entity_handlers(&info, ctx));
+        if let Some(links) = generate_link_handlers(&info, ctx) {
+            files.push(links);
+        }
     }
 
     files
@@ -59,12 +144,19 @@ pub fn generate_handlers(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 // handlers/mod.rs
 // ============================================================================
 
-fn generate_handlers_mod(ctx: &GenerationContext) -> GeneratedFile {
+pub(crate) fn generate_handlers_mod(ctx: &GenerationContext) -> GeneratedFile {
     let mut content = String::with_capacity(2048);
 
     content.push_str(&file_header("Request handlers for all API endpoints."));
 
-    content.push_str("use serde::{Deserialize, Serialize};\n\n");
+    let pagination_types = generate_pagination_types(ctx);
+    let soft_delete_params = generate_soft_delete_params(ctx);
+    if !pagination_types.is_empty() {
+        content.push_str("use serde::{Deserialize, Serialize};\n\n");
+    } else if !soft_delete_params.is_empty() {
+        content.push_str("use serde::Deserialize;\n\n");
+    }
+    let shared_types = pagination_types + &soft_delete_params;
 
     // Module declarations
     for entity in ctx.entities() {
@@ -77,77 +169,166 @@ fn generate_handlers_mod(ctx: &GenerationContext) -> GeneratedFile {
             let module = GenerationContext::module_name(&entity.name);
             content.push_str(&format!("pub mod {};\n", module));
         }
+        if let Some(links) = links_module(&info) {
+            content.push_str(&format!("pub mod {};\n", links));
+        }
     }
 
-    content.push('\n');
+    if !ctx.queries().is_empty() {
+        content.push_str("pub mod queries;\n");
+    }
 
-    // Pagination types (shared across all handlers)
-    content.push_str(
-        "// ============================================================================\n",
-    );
-    content.push_str("// Shared Types\n");
-    content.push_str(
-        "// ============================================================================\n",
-    );
-    content.push_str(&generate_pagination_types());
+    // Pagination and soft-delete types (shared across all handlers)
+    if !shared_types.is_empty() {
+        content.push('\n');
+        content.push_str(
+            "// ============================================================================\n",
+        );
+        content.push_str("// Shared Types\n");
+        content.push_str(
+            "// ============================================================================\n",
+        );
+        content.push_str(&shared_types);
+    }
 
     GeneratedFile::new("src/handlers/mod.rs", content, FileType::Rust)
 }
 
+/// `SoftDeleteParams`, the `?include_deleted=` flag of list and get
+/// endpoints, when a soft-deleting entity has either.
+fn generate_soft_delete_params(ctx: &GenerationContext) -> String {
+    let needed = ctx.entities().iter().any(|entity| {
+        let info = EntityInfo::new(entity, ctx);
+        info.has_soft_delete()
+            && info.endpoint().is_some_and(|ep| {
+                ep.enabled
+                    && ep.enabled_operations().iter().any(|op| {
+                        matches!(
+                            op.operation_type,
+                            OperationType::Read | OperationType::ReadAll
+                        )
+                    })
+            })
+    });
+    if !needed {
+        return String::new();
+    }
+
+    let derive = if ctx.openapi_enabled() {
+        "#[derive(Debug, Clone, Default, Deserialize, utoipa::IntoParams)]"
+    } else {
+        "#[derive(Debug, Clone, Default, Deserialize)]"
+    };
+    format!(
+        r#"
+/// Query parameters of list and get endpoints over soft-deleted records.
+{derive}
+pub struct SoftDeleteParams {{
+    /// Include soft-deleted records. Defaults to false.
+    pub include_deleted: Option<bool>,
+}}
+"#
+    )
+}
+
 // ============================================================================
 // Per-entity handler file
 // ============================================================================
 
 fn generate_entity_handlers(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFile {
-    let module = info.module_name();
-    let path = format!("src/handlers/{}.rs", module);
-
-    let endpoint = info.endpoint().unwrap(); // caller guarantees this exists
-    let enabled_ops: Vec<OperationType> = endpoint
-        .enabled_operations()
-        .iter()
-        .map(|op| op.operation_type)
-        .collect();
-
-    let mut content = String::with_capacity(4096);
-
-    // File header
-    content.push_str(&file_header(&format!(
-        "Request handlers for {} endpoints.",
-        info.pascal_name()
-    )));
-
-    // Imports
-    content.push_str(&generate_handler_imports(info, ctx, &enabled_ops));
-    content.push('\n');
-
-    // Individual handlers
-    if enabled_ops.contains(&OperationType::ReadAll) {
-        content.push_str(&generate_list_handler(info, ctx));
-        content.push('\n');
-    }
-
-    if enabled_ops.contains(&OperationType::Read) {
-        content.push_str(&generate_get_handler(info, ctx));
-        content.push('\n');
-    }
+    let path = format!("src/handlers/{}.rs", info.module_name());
+    let content = ctx.render("rust/handlers.rs.jinja", HandlersContext::new(info, ctx));
+    GeneratedFile::new(path, content, FileType::Rust)
+}
 
-    if enabled_ops.contains(&OperationType::Create) {
-        content.push_str(&generate_create_handler(info, ctx));
-        content.push('\n');
-    }
+/// Variables of the `rust/handlers.rs.jinja` template, which renders one
+/// entity's `src/handlers/{entity}.rs` (see [`templates`](crate::templates)).
+///
+/// Each handler is passed as rendered Rust, empty when its operation is not
+/// enabled.
+#[derive(Debug, Clone, Serialize)]
+pub struct HandlersContext {
+    /// Entity name in PascalCase
+    pub name: String,
+    /// `use` lines, one per line of Rust (groups are separated by empty
+    /// ones)
+    pub imports: Vec<String>,
+    /// `{Entity}ListParams`, the sorting and filtering query of the list
+    /// handler
+    pub list_params: String,
+    /// `list_{entities}`
+    pub list: String,
+    /// Functions applying the list parameters to the query
+    pub list_helpers: String,
+    /// Loader of the related counts and existence flags of a listed page
+    pub aggregates: String,
+    /// `get_{entity}`
+    pub get: String,
+    /// `create_{entity}`
+    pub create: String,
+    /// `update_{entity}`
+    pub update: String,
+    /// `delete_{entity}`
+    pub delete: String,
+    /// `restore_{entity}`, for a soft-deleting entity
+    pub restore: String,
+    /// `{entity}_changes`, streaming the realtime change feed
+    pub live: String,
+    /// `upload_{entity}_{field}`, one per file or image field
+    pub uploads: Vec<String>,
+}
 
-    if enabled_ops.contains(&OperationType::Update) {
-        content.push_str(&generate_update_handler(info, ctx));
-        content.push('\n');
-    }
+impl HandlersContext {
+    /// The variables for `info`'s handler file; `info` must have an
+    /// endpoint.
+    pub fn new(info: &EntityInfo, ctx: &GenerationContext) -> Self {
+        let endpoint = info.endpoint().unwrap(); // caller guarantees this exists
+        let enabled_ops: Vec<OperationType> = endpoint
+            .enabled_operations()
+            .iter()
+            .map(|op| op.operation_type)
+            .collect();
+        let section =
+            |op: OperationType, generate: fn(&EntityInfo, &GenerationContext) -> String| {
+                if enabled_ops.contains(&op) {
+                    generate(info, ctx)
+                } else {
+                    String::new()
+                }
+            };
 
-    if enabled_ops.contains(&OperationType::Delete) {
-        content.push_str(&generate_delete_handler(info, ctx));
-        content.push('\n');
+        Self {
+            name: info.pascal_name(),
+            imports: generate_handler_imports(info, ctx, &enabled_ops)
+                .trim_end()
+                .lines()
+                .map(str::to_string)
+                .collect(),
+            list_params: section(OperationType::ReadAll, generate_list_params),
+            list: section(OperationType::ReadAll, generate_list_handler),
+            list_helpers: section(OperationType::ReadAll, generate_list_helpers),
+            aggregates: section(OperationType::ReadAll, generate_aggregates_loader),
+            get: section(OperationType::Read, generate_get_handler),
+            create: section(OperationType::Create, generate_create_handler),
+            update: section(OperationType::Update, generate_update_handler),
+            delete: section(OperationType::Delete, generate_delete_handler),
+            restore: if restores(info, &enabled_ops) {
+                generate_restore_handler(info, ctx)
+            } else {
+                String::new()
+            },
+            live: if info.live_transport().is_some() {
+                generate_live_handler(info, ctx)
+            } else {
+                String::new()
+            },
+            uploads: info
+                .upload_fields()
+                .into_iter()
+                .map(|field| generate_upload_handler(info, field, ctx))
+                .collect(),
+        }
     }
-
-    GeneratedFile::new(path, content, FileType::Rust)
 }
 
 // ============================================================================
@@ -164,58 +345,136 @@ fn generate_handler_imports(
     let update_dto = GenerationContext::update_dto_name(&info.entity.name);
     let response_dto = GenerationContext::response_dto_name(&info.entity.name);
 
+    // Children inserted by the create handler
+    let nested = if ops.contains(&OperationType::Create) {
+        info.nested_children()
+    } else {
+        Vec::new()
+    };
+
     // Check if this entity has any password/secret fields that need hashing
     let has_secret_fields = info
         .entity
         .fields
         .iter()
-        .any(|f| f.secret || f.name.contains("password"));
+        .chain(nested.iter().flat_map(|n| n.child.fields.iter()))
+        .any(|f| ctx.is_password_field(f));
+
+    let lists_aggregates =
+        ops.contains(&OperationType::ReadAll) && !info.related_aggregates().is_empty();
 
     let mut out = String::with_capacity(1024);
 
-    // axum imports
-    let mut axum_extracts = vec!["State"];
+    if lists_aggregates {
+        let uses_sets = info
+            .related_aggregates()
+            .iter()
+            .any(|r| r.aggregate.kind == AggregateKind::Exists);
+        if uses_sets {
+            out.push_str("use std::collections::{HashMap, HashSet};\n\n");
+        } else {
+            out.push_str("use std::collections::HashMap;\n\n");
+        }
+    }
+
+    // Framework imports
+    let mut extractors = vec![Extractor::State];
     if ops.contains(&OperationType::Read)
         || ops.contains(&OperationType::Update)
         || ops.contains(&OperationType::Delete)
     {
-        axum_extracts.push("Path");
+        extractors.push(Extractor::Path);
     }
-    if ops.contains(&OperationType::ReadAll) {
-        axum_extracts.push("Query");
+    let lists = ops.contains(&OperationType::ReadAll);
+    let list_filtered = lists && !list_filters(info).is_empty();
+    let list_sorted = lists && !list_sort_columns(info, ctx).is_empty();
+    let list_ordered =
+        lists && ctx.pagination() != PaginationStyle::Cursor && !list_default_sort(info).is_empty();
+    let scoped_reads = info.has_soft_delete() && (lists || ops.contains(&OperationType::Read));
+    if scoped_reads
+        || (lists && (ctx.pagination() != PaginationStyle::None || list_filtered || list_sorted))
+    {
+        extractors.push(Extractor::Query);
     }
 
-    out.push_str(&format!(
-        "use axum::extract::{{{}}};\n",
-        axum_extracts.join(", ")
+    out.push_str(&ctx.framework_backend().handler_imports(
+        &extractors,
+        ops.contains(&OperationType::Create) || ops.contains(&OperationType::Delete),
     ));
 
-    if ops.contains(&OperationType::Create) {
-        out.push_str("use axum::http::StatusCode;\n");
-    } else if ops.contains(&OperationType::Delete) {
-        out.push_str("use axum::http::StatusCode;\n");
-    }
-
-    out.push_str("use axum::Json;\n");
-
     // SeaORM imports
     let mut sea_imports = vec!["EntityTrait"];
-    if ops.contains(&OperationType::Create) || ops.contains(&OperationType::Update) {
+    if ops.contains(&OperationType::Create)
+        || ops.contains(&OperationType::Update)
+        || restores(info, ops)
+    {
         sea_imports.push("ActiveModelTrait");
-        sea_imports.push("Set");
+        // Payloads converting through the model file need no `Set` here
+        if (ops.contains(&OperationType::Create)
+            && (!nested.is_empty() || !converts_create(info, ctx)))
+            || (ops.contains(&OperationType::Update) && !converts_update(info, ctx))
+            || restores(info, ops)
+            || !info.upload_fields().is_empty()
+        {
+            sea_imports.push("Set");
+        }
     }
-    if ops.contains(&OperationType::ReadAll) {
+    let delete_blockers = if ops.contains(&OperationType::Delete) {
+        info.delete_blockers()
+    } else {
+        Vec::new()
+    };
+    if (ops.contains(&OperationType::ReadAll) && ctx.pagination() == PaginationStyle::Offset)
+        || !delete_blockers.is_empty()
+    {
         sea_imports.push("PaginatorTrait");
     }
-    if ops.contains(&OperationType::Update) {
+    if ops.contains(&OperationType::Update) || restores(info, ops) {
         sea_imports.push("IntoActiveModel");
     }
+    if lists_aggregates {
+        sea_imports.extend([
+            "ColumnTrait",
+            "DatabaseConnection",
+            "QueryFilter",
+            "QuerySelect",
+        ]);
+    } else if !delete_blockers.is_empty()
+        || list_filtered
+        || [OperationType::Create, OperationType::Update]
+            .into_iter()
+            .any(|op| ops.contains(&op) && !checked_scopes(info, op).is_empty())
+    {
+        sea_imports.extend(["ColumnTrait", "QueryFilter"]);
+    }
+    if list_sorted || list_ordered {
+        sea_imports.push("QueryOrder");
+    }
+    if list_sorted {
+        sea_imports.push("Order");
+    }
+    if list_filtered || list_sorted {
+        sea_imports.push("Select");
+    }
+    if !nested.is_empty() {
+        sea_imports.push("TransactionTrait");
+    }
 
     out.push_str(&format!("use sea_orm::{{{}}};\n", sea_imports.join(", ")));
+    if lists_aggregates {
+        out.push_str("use sea_orm::sea_query::Expr;\n");
+    }
 
-    // uuid (for path parameters)
-    let pk_type = info.pk_rust_type();
-    if pk_type == "Uuid" {
+    // uuid (for keys minted by `active_model_sets`)
+    let builds_row =
+        ops.contains(&OperationType::Create) && (!nested.is_empty() || !converts_create(info, ctx));
+    if (builds_row && info.pk_rust_type().contains("Uuid"))
+        || nested.iter().any(|n| {
+            EntityInfo::new(n.child, ctx)
+                .pk_rust_type()
+                .contains("Uuid")
+        })
+    {
         out.push_str("use uuid::Uuid;\n");
     }
 
@@ -235,7 +494,17 @@ fn generate_handler_imports(
     out.push('\n');
 
     // Local imports
-    out.push_str("use crate::error::AppError;\n");
+    if ops.contains(&OperationType::ReadAll) && ctx.pagination() != PaginationStyle::None {
+        let (default_size, max_size) = page_size_consts(info);
+        out.push_str(&format!(
+            "use crate::constants::{{{default_size}, {max_size}}};\n"
+        ));
+    }
+    if delete_blockers.is_empty() {
+        out.push_str("use crate::error::AppError;\n");
+    } else {
+        out.push_str("use crate::error::{AppError, BlockingRecords};\n");
+    }
     out.push_str("use crate::state::AppState;\n");
 
     // Model imports
@@ -247,6 +516,9 @@ fn generate_handler_imports(
         model_imports.push(update_dto.clone());
     }
     model_imports.push(response_dto.clone());
+    if lists_aggregates {
+        model_imports.push(GenerationContext::list_item_dto_name(&info.entity.name));
+    }
 
     out.push_str(&format!(
         "use crate::models::{}::{{{}}};\n",
@@ -254,153 +526,1019 @@ fn generate_handler_imports(
         model_imports.join(", "),
     ));
 
+    let mut related_modules: Vec<String> = Vec::new();
+    if lists_aggregates {
+        related_modules.extend(
+            info.related_aggregates()
+                .iter()
+                .map(|r| GenerationContext::module_name(&r.related.name)),
+        );
+    }
+    related_modules.extend(
+        delete_blockers
+            .iter()
+            .map(|b| GenerationContext::module_name(&b.child.name))
+            .filter(|m| *m != module),
+    );
+    related_modules.extend(
+        nested
+            .iter()
+            .map(|n| GenerationContext::module_name(&n.child.name)),
+    );
+    related_modules.sort();
+    related_modules.dedup();
+    for related in related_modules {
+        out.push_str(&format!("use crate::models::{};\n", related));
+    }
+
     // Pagination types (for list handler)
     if ops.contains(&OperationType::ReadAll) {
-        out.push_str("use crate::handlers::{PaginationParams, PaginatedResponse};\n");
+        match ctx.pagination() {
+            PaginationStyle::Offset => {
+                out.push_str("use crate::handlers::{PaginationParams, PaginatedResponse};\n")
+            }
+            PaginationStyle::Cursor => {
+                out.push_str("use crate::handlers::{CursorPage, PaginationParams};\n")
+            }
+            PaginationStyle::None => {}
+        }
+    }
+    if scoped_reads {
+        out.push_str("use crate::handlers::SoftDeleteParams;\n");
+    }
+    out.push_str(&realtime_import(info, ops));
+    out.push_str(&http_cache_import(info, ops));
+    if !info.upload_fields().is_empty() {
+        out.push_str("use crate::storage;\n");
     }
 
     out.push('\n');
     out
 }
 
+/// `use crate::realtime…;` for a handler module publishing changes or
+/// streaming them, or nothing.
+pub(crate) fn realtime_import(info: &EntityInfo, ops: &[OperationType]) -> String {
+    let publishes = info.is_realtime()
+        && [
+            OperationType::Create,
+            OperationType::Update,
+            OperationType::Delete,
+        ]
+        .iter()
+        .any(|op| ops.contains(op));
+    match (publishes, info.live_transport().is_some()) {
+        (true, true) => "use crate::realtime::{self, publish_change, ChangeKind};\n".to_string(),
+        (true, false) => "use crate::realtime::{publish_change, ChangeKind};\n".to_string(),
+        (false, true) => "use crate::realtime;\n".to_string(),
+        (false, false) => String::new(),
+    }
+}
+
 // ============================================================================
 // List handler (ReadAll)
 // ============================================================================
 
+/// `use crate::http_cache;` if one of `ops` sends caching headers.
+pub(crate) fn http_cache_import(info: &EntityInfo, ops: &[OperationType]) -> String {
+    if ops.iter().any(|op| info.caching(*op).is_some()) {
+        "use crate::http_cache;\n".to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// How a read handler answers: with plain JSON, or through
+/// `http_cache::Validators` when its operation sends caching headers.
+pub(crate) struct ReadResponse {
+    /// Extra handler parameter, as `"\n    headers: …,"`
+    pub param: String,
+    /// Return type inside `Result<…, AppError>`
+    pub return_type: String,
+    /// Statement building `validators` from the fetched rows, if cached
+    pub validators: String,
+    headers_arg: Option<&'static str>,
+}
+
+impl ReadResponse {
+    /// The response of `op`, whose plain body is `Json<{body_type}>`.
+    /// `rows` is an expression of the `(key, updated_at)` pairs of the rows
+    /// answered with.
+    pub(crate) fn new(
+        info: &EntityInfo,
+        ctx: &GenerationContext,
+        op: OperationType,
+        body_type: &str,
+        rows: &str,
+    ) -> Self {
+        match info.caching(op) {
+            Some(caching) => {
+                let framework = ctx.framework_backend();
+                Self {
+                    param: format!("\n    {},", framework.headers_param()),
+                    return_type: framework.cached_response_type().to_string(),
+                    validators: http_cache::validators_statement(caching, rows),
+                    headers_arg: Some(framework.headers_arg()),
+                }
+            }
+            None => Self {
+                param: String::new(),
+                return_type: format!("Json<{}>", body_type),
+                validators: String::new(),
+                headers_arg: None,
+            },
+        }
+    }
+
+    /// Whether the handler answers through `http_cache::Validators`.
+    pub(crate) fn is_cached(&self) -> bool {
+        self.headers_arg.is_some()
+    }
+
+    /// The handler's final expression, answering with `body`.
+    pub(crate) fn answer(&self, body: &str) -> String {
+        match self.headers_arg {
+            Some(headers) => format!("validators.respond({}, {})", headers, body),
+            None => format!("Ok(Json({}))", body),
+        }
+    }
+}
+
 fn generate_list_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
     let fn_name = format!("list_{}", info.plural_name());
+    let state_param = ctx.framework_backend().state_param();
     let response_dto = GenerationContext::response_dto_name(&info.entity.name);
-    let pascal = info.pascal_name();
+    let with_aggregates = !info.related_aggregates().is_empty();
+    let item_dto = if with_aggregates {
+        GenerationContext::list_item_dto_name(&info.entity.name)
+    } else {
+        response_dto.clone()
+    };
+    let list_type = list_response_type(ctx, &item_dto);
+    let module = info.module_name();
+    let read_db = ctx.read_db();
+    let response = ReadResponse::new(
+        info,
+        ctx,
+        OperationType::ReadAll,
+        &list_type,
+        &model_versions(info, "models"),
+    );
+    let return_type = &response.return_type;
+    let validators = &response.validators;
+
+    // Turn the fetched models into response items, via one grouped query per
+    // related aggregate when the endpoint asks for them. Cached lists keep
+    // the models around long enough to take their validators.
+    let collect_items = |fetch: &str| {
+        if with_aggregates {
+            format!(
+                "    let models = {fetch}\n        .await\n        .map_err(AppError::from)?;\n{validators}    let items = with_related_aggregates(&{read_db}, models).await?;\n"
+            )
+        } else if response.is_cached() {
+            format!(
+                "    let models = {fetch}\n        .await\n        .map_err(AppError::from)?;\n{validators}    let items: Vec<{response_dto}> = models.into_iter().map({response_dto}::from).collect();\n"
+            )
+        } else {
+            format!(
+                "    let items: Vec<{response_dto}> = {fetch}\n        .await\n        .map_err(AppError::from)?\n        .into_iter()\n        .map({response_dto}::from)\n        .collect();\n"
+            )
+        }
+    };
 
     let mut out = String::with_capacity(1024);
 
+    let summary = match ctx.pagination() {
+        PaginationStyle::Offset => "with pagination",
+        PaginationStyle::Cursor => "with cursor pagination",
+        PaginationStyle::None => "without pagination",
+    };
     out.push_str(&doc_comment(
         Some(&format!(
-            "List all {}s with pagination.\n\nGET {}",
+            "List all {}s {}.\n\nGET {}",
             info.snake_name(),
+            summary,
             info.base_path()
         )),
         ctx,
     ));
 
-    out.push_str(&format!(
-        r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-) -> Result<Json<PaginatedResponse<{response_dto}>>, AppError> {{
-    let page = params.page.unwrap_or(1).max(1);
-    let per_page = params.per_page.unwrap_or(20).min(100).max(1);
+    let (default_size, max_size) = page_size_consts(info);
+    let filters = list_filters(info);
+    let sorting = !list_sort_columns(info, ctx).is_empty();
+    let has_params = !filters.is_empty() || sorting;
+    let params_name = list_params_name(info);
+    let mut params_arg = if has_params {
+        format!("\n    Query(filters): Query<{params_name}>,")
+    } else {
+        String::new()
+    };
+
+    // Soft-deleted rows are left out unless the request asks for them
+    let (scope, mut select) = if info.has_soft_delete() {
+        params_arg.push_str("\n    Query(scope): Query<SoftDeleteParams>,");
+        (
+            format!(
+                "    let base = if scope.include_deleted.unwrap_or(false) {{\n        {module}::Entity::find()\n    }} else {{\n        {module}::Entity::find_active()\n    }};\n"
+            ),
+            "base".to_string(),
+        )
+    } else {
+        (String::new(), format!("{module}::Entity::find()"))
+    };
+    params_arg.push_str(&response.param);
+
+    // The base select, sorted then filtered. Sorting borrows `filters.sort`
+    // and must come first, since filtering consumes `filters`.
+    if sorting {
+        select = format!(
+            "sort_{}({select}, filters.sort.as_deref())?",
+            info.plural_name()
+        );
+    } else if ctx.pagination() != PaginationStyle::Cursor {
+        select.push_str(&default_order_chain(info));
+    }
+    if !filters.is_empty() {
+        select = format!("filter_{}({select}, filters)", info.plural_name());
+    }
+
+    out.push_str(&handler_span(info, ctx, OperationType::ReadAll, &fn_name));
+
+    match ctx.pagination() {
+        PaginationStyle::Offset => out.push_str(&format!(
+            r#"pub async fn {fn_name}(
+    {state_param},
+    Query(params): Query<PaginationParams>,{params_arg}
+) -> Result<{return_type}, AppError> {{
+{scope}    let page = params.page.unwrap_or(1).max(1);
+    let per_page = params.per_page.unwrap_or({default_size}).min({max_size}).max(1);
 
-    let paginator = {module}::Entity::find()
-        .paginate(&state.db, per_page);
+    let paginator = {select}
+        .paginate(&{read_db}, per_page);
 
     let total = paginator.num_items().await.map_err(AppError::from)?;
 
-    let items: Vec<{response_dto}> = paginator
-        .fetch_page(page - 1)
+{items}
+    {answer}
+}}
+"#,
+            items = collect_items("paginator\n        .fetch_page(page - 1)"),
+            answer = response.answer("PaginatedResponse::new(items, total, page, per_page)"),
+        )),
+        PaginationStyle::Cursor => {
+            let pk_column = info
+                .pk()
+                .map(|f| GenerationContext::pascal(&f.name))
+                .unwrap_or_else(|| "Id".to_string());
+            let pk_field = info
+                .pk()
+                .map(|f| GenerationContext::snake(&f.name))
+                .unwrap_or_else(|| "id".to_string());
+            let pk_type = info.pk_rust_type();
+            let items = if with_aggregates {
+                format!(
+                    "{validators}    let items = with_related_aggregates(&{read_db}, models).await?;"
+                )
+            } else if response.is_cached() {
+                format!(
+                    "{validators}    let items: Vec<{response_dto}> = models.into_iter().map({response_dto}::from).collect();"
+                )
+            } else {
+                format!(
+                    "    let items: Vec<{response_dto}> = models.into_iter().map({response_dto}::from).collect();"
+                )
+            };
+
+            out.push_str(&format!(
+                r#"pub async fn {fn_name}(
+    {state_param},
+    Query(params): Query<PaginationParams>,{params_arg}
+) -> Result<{return_type}, AppError> {{
+{scope}    let limit = params.limit.unwrap_or({default_size}).min({max_size}).max(1);
+
+    let mut cursor = {select}.cursor_by({module}::Column::{pk_column});
+    if let Some(after) = params.cursor.as_deref() {{
+        let after: {pk_type} = after
+            .parse()
+            .map_err(|_| AppError::bad_request("Invalid cursor"))?;
+        cursor.after(after);
+    }}
+
+    // Fetch one extra row to learn whether another page follows.
+    let mut models = cursor
+        .first(limit + 1)
+        .all(&{read_db})
         .await
-        .map_err(AppError::from)?
-        .into_iter()
-        .map({response_dto}::from)
-        .collect();
+        .map_err(AppError::from)?;
+
+    let next_cursor = if models.len() as u64 > limit {{
+        models.truncate(limit as usize);
+        models.last().map(|m| m.{pk_field}.to_string())
+    }} else {{
+        None
+    }};
+
+{items}
 
-    Ok(Json(PaginatedResponse::new(items, total, page, per_page)))
+    {answer}
 }}
 "#,
-        module = info.module_name(),
-    ));
+                answer = response.answer("CursorPage::new(items, next_cursor)"),
+            ));
+        }
+        PaginationStyle::None => out.push_str(&format!(
+            r#"pub async fn {fn_name}(
+    {state_param},{params_arg}
+) -> Result<{return_type}, AppError> {{
+{scope}{items}
+    {answer}
+}}
+"#,
+            items = collect_items(&format!("{select}\n        .all(&{read_db})")),
+            answer = response.answer("items"),
+        )),
+    }
 
     out
 }
 
 // ============================================================================
-// Get handler (Read)
+// List sorting and filtering
 // ============================================================================
 
-fn generate_get_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let fn_name = format!("get_{}", info.snake_name());
-    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
-    let pk_type = info.pk_rust_type();
+/// A column a list endpoint can sort or filter by.
+#[derive(Debug, Clone)]
+pub(crate) struct ListColumn {
+    /// Name in the API (the snake_case field name)
+    pub name: String,
+    /// SeaORM `Column` variant
+    pub variant: String,
+    /// Value type, without `Optional`
+    pub data_type: DataType,
+    /// Whether this is the primary key
+    pub is_primary_key: bool,
+}
 
-    let mut out = String::with_capacity(512);
+/// How a list filter compares its parameter with the column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListFilterKind {
+    /// `?{field}=` — equal
+    Eq,
+    /// `?{field}_contains=` — substring match (`LIKE '%…%'`)
+    Contains,
+    /// `?{field}_min=` — greater than or equal
+    Min,
+    /// `?{field}_max=` — less than or equal
+    Max,
+}
 
-    out.push_str(&doc_comment(
-        Some(&format!(
-            "Get a single {} by ID.\n\nGET {}/:id",
-            info.snake_name(),
-            info.base_path()
-        )),
-        ctx,
-    ));
+/// A query-string filter of a list endpoint.
+#[derive(Debug, Clone)]
+pub(crate) struct ListFilter {
+    pub param: String,
+    pub kind: ListFilterKind,
+    pub column: ListColumn,
+}
 
-    out.push_str(&format!(
-        r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
-    Path(id): Path<{pk_type}>,
-) -> Result<Json<{response_dto}>, AppError> {{
-    let item = {module}::Entity::find_by_id(id)
-        .one(&state.db)
-        .await
-        .map_err(AppError::from)?
-        .ok_or(AppError::NotFound)?;
+/// Parameters that pagination and the soft-delete scope already use, so no
+/// filter may take them.
+const RESERVED_LIST_PARAMS: &[&str] = &[
+    "page",
+    "per_page",
+    "cursor",
+    "limit",
+    "sort",
+    "include_deleted",
+];
+
+/// The list options of an entity's ReadAll operation.
+pub(crate) fn list_options(info: &EntityInfo) -> ListOptions {
+    info.endpoint()
+        .and_then(|ep| ep.get_operation(OperationType::ReadAll))
+        .map(|op| op.list.clone())
+        .unwrap_or_default()
+}
 
-    Ok(Json({response_dto}::from(item)))
-}}
-"#,
-        module = info.module_name(),
-    ));
+/// Name of the generated query-string struct for sorting and filters.
+pub(crate) fn list_params_name(info: &EntityInfo) -> String {
+    format!("{}ListParams", info.pascal_name())
+}
 
-    out
+/// Columns of the list response with a type that can be compared: every
+/// non-secret column except JSON, bytes and arrays, plus the timestamps.
+/// Computed getters have no column to query, so they are left out.
+fn list_columns(info: &EntityInfo) -> Vec<ListColumn> {
+    let mut columns: Vec<ListColumn> = info
+        .response_fields()
+        .into_iter()
+        .filter(|field| field.is_column())
+        .filter_map(|field| {
+            let data_type = match &field.data_type {
+                DataType::Optional(inner) => inner.as_ref().clone(),
+                other => other.clone(),
+            };
+            if matches!(
+                data_type,
+                DataType::Json | DataType::Bytes | DataType::Array(_) | DataType::Optional(_)
+            ) {
+                return None;
+            }
+            Some(ListColumn {
+                name: GenerationContext::snake(&field.name),
+                variant: GenerationContext::pascal(&field.name),
+                data_type,
+                is_primary_key: field.is_primary_key,
+            })
+        })
+        .collect();
+
+    if info.has_timestamps() {
+        for timestamp in ["created_at", "updated_at"] {
+            if !columns.iter().any(|c| c.name == timestamp) {
+                columns.push(ListColumn {
+                    name: timestamp.to_string(),
+                    variant: GenerationContext::pascal(timestamp),
+                    data_type: DataType::DateTime,
+                    is_primary_key: false,
+                });
+            }
+        }
+    }
+
+    columns
 }
 
-// ============================================================================
-// Create handler
-// ============================================================================
+/// Columns clients may sort by. Empty when sorting is off, for cursor
+/// pagination, whose cursor only works in primary-key order, and when the
+/// ORM backend cannot generate sorting.
+pub(crate) fn list_sort_columns(info: &EntityInfo, ctx: &GenerationContext) -> Vec<ListColumn> {
+    if !list_options(info).sorting
+        || ctx.pagination() == PaginationStyle::Cursor
+        || !ctx.orm_supports(OrmFeature::ListQueryParams)
+    {
+        return Vec::new();
+    }
+    list_columns(info)
+        .into_iter()
+        .filter(|c| !matches!(c.data_type, DataType::Enum { .. }))
+        .collect()
+}
 
-fn generate_create_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let fn_name = format!("create_{}", info.snake_name());
-    let create_dto = GenerationContext::create_dto_name(&info.entity.name);
-    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
-    let pk_type = info.pk_rust_type();
+/// The endpoint's default sort resolved against the list columns; keys
+/// naming unknown fields are dropped.
+pub(crate) fn list_default_sort(info: &EntityInfo) -> Vec<(ListColumn, bool)> {
+    let columns = list_columns(info);
+    list_options(info)
+        .default_sort_keys()
+        .into_iter()
+        .filter_map(|(field, descending)| {
+            let column = columns.iter().find(|c| c.name == field)?;
+            Some((column.clone(), descending))
+        })
+        .collect()
+}
 
-    let create_fields = info.create_fields();
+/// Filter parameters generated for a list endpoint, from each column's type.
+/// Empty when the ORM backend cannot generate filters.
+pub(crate) fn list_filters(info: &EntityInfo) -> Vec<ListFilter> {
+    if !list_options(info).filtering || !info.ctx.orm_supports(OrmFeature::ListQueryParams) {
+        return Vec::new();
+    }
 
-    let mut out = String::with_capacity(2048);
+    let mut filters: Vec<ListFilter> = Vec::new();
+    for column in list_columns(info) {
+        if column.is_primary_key {
+            continue;
+        }
+        let kinds: &[ListFilterKind] = match column.data_type {
+            DataType::String | DataType::Text => &[ListFilterKind::Eq, ListFilterKind::Contains],
+            DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal { .. }
+            | DataType::DateTime
+            | DataType::Date
+            | DataType::Time => &[ListFilterKind::Eq, ListFilterKind::Min, ListFilterKind::Max],
+            DataType::Bool | DataType::Uuid | DataType::Reference { .. } => &[ListFilterKind::Eq],
+            _ => &[],
+        };
+        for &kind in kinds {
+            let param = match kind {
+                ListFilterKind::Eq => column.name.clone(),
+                ListFilterKind::Contains => format!("{}_contains", column.name),
+                ListFilterKind::Min => format!("{}_min", column.name),
+                ListFilterKind::Max => format!("{}_max", column.name),
+            };
+            if RESERVED_LIST_PARAMS.contains(&param.as_str())
+                || filters.iter().any(|f| f.param == param)
+            {
+                continue;
+            }
+            filters.push(ListFilter {
+                param,
+                kind,
+                column: column.clone(),
+            });
+        }
+    }
+    filters
+}
+
+/// Rust type of a filter parameter. Paths are fully qualified so the
+/// handler file needs no extra imports.
+fn list_param_rust_type(dt: &DataType) -> &'static str {
+    match dt {
+        DataType::Int32 => "i32",
+        DataType::Int64 => "i64",
+        DataType::Float32 => "f32",
+        DataType::Float64 => "f64",
+        DataType::Decimal { .. } => "rust_decimal::Decimal",
+        DataType::Bool => "bool",
+        DataType::Uuid | DataType::Reference { .. } => "uuid::Uuid",
+        DataType::DateTime => "chrono::DateTime<chrono::Utc>",
+        DataType::Date => "chrono::NaiveDate",
+        DataType::Time => "chrono::NaiveTime",
+        _ => "String",
+    }
+}
+
+/// `.order_by_…(…)` calls for the endpoint's default sort, or nothing.
+fn default_order_chain(info: &EntityInfo) -> String {
+    let module = info.module_name();
+    list_default_sort(info)
+        .iter()
+        .map(|(column, descending)| {
+            let method = if *descending {
+                "order_by_desc"
+            } else {
+                "order_by_asc"
+            };
+            format!("\n        .{method}({module}::Column::{})", column.variant)
+        })
+        .collect()
+}
+
+/// Generate the `{Entity}ListParams` query-string struct with the sort key
+/// and filters. Returns an empty string when the list takes neither.
+fn generate_list_params(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let filters = list_filters(info);
+    let sorting = !list_sort_columns(info, ctx).is_empty();
+    if filters.is_empty() && !sorting {
+        return String::new();
+    }
 
+    let plural = info.plural_name();
+    let mut out = String::with_capacity(1024);
     out.push_str(&doc_comment(
         Some(&format!(
-            "Create a new {}.\n\nPOST {}",
-            info.snake_name(),
+            "Sorting and filters for `GET {}`.\n\nPagination parameters are read separately, into `PaginationParams`.",
             info.base_path()
         )),
         ctx,
     ));
-
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Default, serde::Deserialize, utoipa::IntoParams)]\n");
+        out.push_str("#[into_params(parameter_in = Query)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Default, serde::Deserialize)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", list_params_name(info)));
+
+    if sorting {
+        out.push_str(
+            "    /// Comma-separated fields to sort by, descending with a leading `-`\n    /// (e.g. `-created_at,name`)\n",
+        );
+        out.push_str("    pub sort: Option<String>,\n");
+    }
+    for filter in &filters {
+        let name = &filter.column.name;
+        let doc = match filter.kind {
+            ListFilterKind::Eq => format!("Only {plural} whose `{name}` equals this"),
+            ListFilterKind::Contains => format!("Only {plural} whose `{name}` contains this"),
+            ListFilterKind::Min => format!("Only {plural} whose `{name}` is at least this"),
+            ListFilterKind::Max => format!("Only {plural} whose `{name}` is at most this"),
+        };
+        out.push_str(&format!(
+            "    /// {doc}\n    pub {}: Option<{}>,\n",
+            filter.param,
+            list_param_rust_type(&filter.column.data_type)
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Generate `filter_{entities}` (applies the query-string filters) and
+/// `sort_{entities}` (orders by `?sort=`), for whichever the list uses.
+fn generate_list_helpers(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let module = info.module_name();
+    let plural = info.plural_name();
+    let params_name = list_params_name(info);
+    let mut out = String::new();
+
+    let filters = list_filters(info);
+    if !filters.is_empty() {
+        out.push('\n');
+        out.push_str(&doc_comment(
+            Some(&format!(
+                "Narrow a {} query to the requested filters.",
+                info.snake_name()
+            )),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "fn filter_{plural}(\n    mut select: Select<{module}::Entity>,\n    filters: {params_name},\n) -> Select<{module}::Entity> {{\n"
+        ));
+        for filter in &filters {
+            let condition = match filter.kind {
+                ListFilterKind::Eq => "eq(value)",
+                ListFilterKind::Contains => "contains(value)",
+                ListFilterKind::Min => "gte(value)",
+                ListFilterKind::Max => "lte(value)",
+            };
+            out.push_str(&format!(
+                "    if let Some(value) = filters.{} {{\n        select = select.filter({module}::Column::{}.{condition});\n    }}\n",
+                filter.param, filter.column.variant
+            ));
+        }
+        out.push_str("    select\n}\n");
+    }
+
+    let columns = list_sort_columns(info, ctx);
+    if !columns.is_empty() {
+        let fallback = match default_order_chain(info) {
+            chain if chain.is_empty() => "select".to_string(),
+            chain => format!("select{}", chain.replace("\n        ", "\n            ")),
+        };
+        let tie_break = match info.pk() {
+            Some(pk) => format!(
+                "\n\n    // Break ties by ID so rows don't move between pages\n    Ok(select.order_by_asc({module}::Column::{}))",
+                GenerationContext::pascal(&pk.name)
+            ),
+            None => "\n\n    Ok(select)".to_string(),
+        };
+
+        out.push('\n');
+        out.push_str(&doc_comment(
+            Some(&format!(
+                "Order a {} query by `?sort=`, falling back to the default order.\n\nKeys are comma-separated field names, descending with a leading `-`.",
+                info.snake_name()
+            )),
+            ctx,
+        ));
+        out.push_str(&format!(
+            "fn sort_{plural}(\n    mut select: Select<{module}::Entity>,\n    sort: Option<&str>,\n) -> Result<Select<{module}::Entity>, AppError> {{\n    let Some(sort) = sort.filter(|s| !s.trim().is_empty()) else {{\n        return Ok({fallback});\n    }};\n\n    for key in sort.split(',').map(str::trim).filter(|k| !k.is_empty()) {{\n        let (field, order) = match key.strip_prefix('-') {{\n            Some(field) => (field, Order::Desc),\n            None => (key.trim_start_matches('+'), Order::Asc),\n        }};\n        let column = match field {{\n"
+        ));
+        for column in &columns {
+            out.push_str(&format!(
+                "            \"{}\" => {module}::Column::{},\n",
+                column.name, column.variant
+            ));
+        }
+        out.push_str(&format!(
+            "            _ => return Err(AppError::bad_request(format!(\"Cannot sort by '{{}}'\", field))),\n        }};\n        select = select.order_by(column, order);\n    }}{tie_break}\n}}\n"
+        ));
+    }
+
+    out
+}
+
+/// Generate `with_related_aggregates`, which attaches the endpoint's related
+/// counts / existence flags to a page of models. Each aggregate is one
+/// `GROUP BY` (count) or `DISTINCT` (exists) query over the whole page, so the
+/// cost does not grow with the page size.
+///
+/// Returns an empty string when the endpoint requests no aggregates.
+fn generate_aggregates_loader(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let aggregates = info.related_aggregates();
+    if aggregates.is_empty() {
+        return String::new();
+    }
+
+    let module = info.module_name();
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let item_dto = GenerationContext::list_item_dto_name(&info.entity.name);
+    let pk_type = info.pk_rust_type();
+    let pk_field = info
+        .pk()
+        .map(|f| GenerationContext::snake(&f.name))
+        .unwrap_or_else(|| "id".to_string());
+    let copy_pk = if pk_type == "String" { ".clone()" } else { "" };
+
+    let mut out = String::with_capacity(2048);
+
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Attach related-row aggregates to a page of {}s.\n\nRuns one grouped query per aggregate for the whole page rather than one per row.",
+            info.snake_name()
+        )),
+        ctx,
+    ));
+    out.push_str(&format!(
+        "async fn with_related_aggregates(\n    db: &DatabaseConnection,\n    models: Vec<{module}::Model>,\n) -> Result<Vec<{item_dto}>, AppError> {{\n"
+    ));
+    out.push_str(&format!(
+        "    let ids: Vec<{pk_type}> = models.iter().map(|m| m.{pk_field}{copy_pk}).collect();\n\n"
+    ));
+
+    let mut assignments = Vec::new();
+    for resolved in &aggregates {
+        let related = GenerationContext::module_name(&resolved.related.name);
+        let fk = GenerationContext::pascal(&resolved.fk_field);
+        let name = GenerationContext::snake(&resolved.aggregate.field);
+
+        match resolved.aggregate.kind {
+            AggregateKind::Count => {
+                out.push_str(&format!(
+                    r#"    let {name}: HashMap<{pk_type}, i64> = {related}::Entity::find()
+        .select_only()
+        .column({related}::Column::{fk})
+        .column_as(Expr::col({related}::Column::{fk}).count(), "count")
+        .filter({related}::Column::{fk}.is_in(ids.clone()))
+        .group_by({related}::Column::{fk})
+        .into_tuple::<(Option<{pk_type}>, i64)>()
+        .all(db)
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .filter_map(|(id, count)| Some((id?, count)))
+        .collect();
+
+"#
+                ));
+                assignments.push(format!(
+                    "                {name}: {name}.get(&id).copied().unwrap_or(0) as u64,\n"
+                ));
+            }
+            AggregateKind::Exists => {
+                out.push_str(&format!(
+                    r#"    let {name}: HashSet<{pk_type}> = {related}::Entity::find()
+        .select_only()
+        .column({related}::Column::{fk})
+        .distinct()
+        .filter({related}::Column::{fk}.is_in(ids.clone()))
+        .into_tuple::<Option<{pk_type}>>()
+        .all(db)
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .flatten()
+        .collect();
+
+"#
+                ));
+                assignments.push(format!("                {name}: {name}.contains(&id),\n"));
+            }
+        }
+    }
+
+    out.push_str("    Ok(models\n        .into_iter()\n        .map(|model| {\n");
+    out.push_str(&format!(
+        "            let id = model.{pk_field}{copy_pk};\n"
+    ));
+    out.push_str(&format!("            {item_dto} {{\n"));
+    for assignment in &assignments {
+        out.push_str(assignment);
+    }
+    out.push_str(&format!(
+        "                {}: {response_dto}::from(model),\n",
+        info.snake_name()
+    ));
+    out.push_str("            }\n        })\n        .collect())\n}\n");
+
+    out
+}
+
+// ============================================================================
+// Get handler (Read)
+// ============================================================================
+
+fn generate_get_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("get_{}", info.snake_name());
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let pk_type = info.pk_rust_type();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&pk_type);
+    let path_binding = framework.path_binding();
+    let read_db = ctx.read_db();
+
+    let mut out = String::with_capacity(512);
+
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Get a single {} by ID.\n\nGET {}/:id",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    ));
+    out.push_str(&handler_span(info, ctx, OperationType::Read, &fn_name));
+
+    let module = info.module_name();
+    let (scope_param, select) = if info.has_soft_delete() {
+        (
+            "\n    Query(scope): Query<SoftDeleteParams>,",
+            format!(
+                "let select = if scope.include_deleted.unwrap_or(false) {{\n        {module}::Entity::find_by_id(id)\n    }} else {{\n        {module}::Entity::find_active_by_id(id)\n    }};\n    let item = select"
+            ),
+        )
+    } else {
+        ("", format!("let item = {module}::Entity::find_by_id(id)"))
+    };
+    let response = ReadResponse::new(
+        info,
+        ctx,
+        OperationType::Read,
+        &response_dto,
+        &model_version(info, "item"),
+    );
+
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},{scope_param}{headers_param}
+) -> Result<{return_type}, AppError> {{
+{path_binding}    {select}
+        .one(&{read_db})
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+{validators}    {answer}
+}}
+"#,
+        headers_param = response.param,
+        return_type = response.return_type,
+        validators = response.validators,
+        answer = response.answer(&format!("{response_dto}::from(item)")),
+    ));
+
+    out
+}
+
+// ============================================================================
+// Create handler
+// ============================================================================
+
+fn generate_create_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("create_{}", info.snake_name());
+    let create_dto = GenerationContext::create_dto_name(&info.entity.name);
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let nested = info.nested_children();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let created_type = framework.created_type(&response_dto);
+
+    let mut out = String::with_capacity(2048);
+
+    let mut doc = format!(
+        "Create a new {}.\n\nPOST {}",
+        info.snake_name(),
+        info.base_path()
+    );
+    if !nested.is_empty() {
+        let fields: Vec<String> = nested
+            .iter()
+            .map(|n| format!("`{}`", n.payload_field()))
+            .collect();
+        doc.push_str(&format!(
+            "\n\nEmbedded {} are inserted in the same transaction; if any fails, nothing is created.",
+            fields.join(", ")
+        ));
+    }
+    out.push_str(&doc_comment(Some(&doc), ctx));
+    out.push_str(&handler_span(info, ctx, OperationType::Create, &fn_name));
+
     out.push_str(&format!(
         r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
+    {state_param},
     Json(payload): Json<{create_dto}>,
-) -> Result<(StatusCode, Json<{response_dto}>), AppError> {{
+) -> Result<{created_type}, AppError> {{
     payload.validate().map_err(AppError::from)?;
+"#,
+    ));
+    out.push_str(&unique_scope_checks(info, OperationType::Create));
+
+    let db = if nested.is_empty() {
+        "&state.db"
+    } else {
+        out.push_str("\n    let txn = state.db.begin().await.map_err(AppError::from)?;\n");
+        "&txn"
+    };
 
-    let model = {module}::ActiveModel {{
+    if nested.is_empty() && converts_create(info, ctx) {
+        out.push_str(&format!(
+            r#"
+    let model = {}::ActiveModel::from(payload)
+        .insert({db})
+        .await
+        .map_err(AppError::from)?;
 "#,
-        module = info.module_name(),
+            info.module_name()
+        ));
+    } else {
+        out.push_str(&format!(
+            "\n    let model = {}::ActiveModel {{\n",
+            info.module_name()
+        ));
+        out.push_str(&active_model_sets(
+            info,
+            ctx,
+            &info.create_fields(),
+            "payload",
+            8,
+        ));
+        out.push_str(&format!(
+            r#"        ..Default::default()
+    }}
+    .insert({db})
+    .await
+    .map_err(AppError::from)?;
+"#,
+        ));
+    }
+
+    // Embedded children, pointed at the new row
+    for child in &nested {
+        let child_info = EntityInfo::new(child.child, ctx);
+        let fk = GenerationContext::snake(&child.fk_field.name);
+        let fk_value = if matches!(child.fk_field.data_type, DataType::Optional(_)) {
+            "Some(model.id)"
+        } else {
+            "model.id"
+        };
+
+        out.push_str(&format!(
+            "\n    for item in payload.{} {{\n        {}::ActiveModel {{\n",
+            child.payload_field(),
+            child_info.module_name(),
+        ));
+        out.push_str(&active_model_sets(
+            &child_info,
+            ctx,
+            &child.fields(ctx),
+            "item",
+            12,
+        ));
+        out.push_str(&format!(
+            r#"            {fk}: Set({fk_value}),
+            ..Default::default()
+        }}
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+    }}
+"#,
+        ));
+    }
+
+    if !nested.is_empty() {
+        out.push_str("\n    txn.commit().await.map_err(AppError::from)?;\n");
+    }
+    out.push_str(&publish_line(
+        info,
+        "Created",
+        &format!(
+            "&model.{}",
+            info.pk()
+                .map(|f| GenerationContext::snake(&f.name))
+                .unwrap_or_else(|| "id".to_string())
+        ),
+    ));
+
+    out.push_str(&format!(
+        "\n    Ok({})\n}}\n",
+        framework.created(&format!("{response_dto}::from(model)"))
     ));
 
+    out
+}
+
+/// `field: Set(…)` lines for inserting a new row from a create payload
+/// bound to `source`: the primary key (for UUID keys), each field (hashing
+/// secrets), timestamps and the soft-delete marker.
+pub(crate) fn active_model_sets(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    fields: &[&imortal_ir::Field],
+    source: &str,
+    indent: usize,
+) -> String {
+    let pad = " ".repeat(indent);
+    let mut out = String::new();
+
     // Primary key assignment
-    if pk_type.contains("Uuid") {
-        out.push_str("        id: Set(Uuid::new_v4()),\n");
+    if info.pk_rust_type().contains("Uuid") {
+        out.push_str(&format!("{pad}id: Set(Uuid::new_v4()),\n"));
     }
     // else Serial — the DB auto-increments
 
     // Set fields from payload — hash password/secret fields
-    for field in &create_fields {
+    for field in fields {
         let name = GenerationContext::snake(&field.name);
-        let is_password_field =
-            field.secret || field.name.contains("password") || field.name.contains("secret");
-
-        if is_password_field && ctx.auth_enabled() {
+        if ctx.is_password_field(field) && ctx.auth_enabled() {
             // Hash the password before storing
             // The DTO field is named without the _hash suffix (e.g. "password"),
             // but the model field may be "password_hash". We accept the DTO field
@@ -412,41 +1550,28 @@ fn generate_create_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
                 name.clone()
             };
             out.push_str(&format!(
-                "        {name}: Set(hash_password(&payload.{dto_field}).map_err(|e| AppError::internal(format!(\"Password hashing failed: {{}}\", e)))?),\n"
+                "{pad}{name}: Set(hash_password(&{source}.{dto_field}).map_err(|e| AppError::internal(format!(\"Password hashing failed: {{}}\", e)))?),\n"
             ));
         } else {
-            out.push_str(&format!("        {name}: Set(payload.{name}),\n"));
+            out.push_str(&format!("{pad}{name}: Set({source}.{name}),\n"));
         }
     }
 
     // Timestamps
     if info.has_timestamps() {
-        if !create_fields.iter().any(|f| f.name == "created_at") {
-            out.push_str("        created_at: Set(chrono::Utc::now()),\n");
+        if !fields.iter().any(|f| f.name == "created_at") {
+            out.push_str(&format!("{pad}created_at: Set(chrono::Utc::now()),\n"));
         }
-        if !create_fields.iter().any(|f| f.name == "updated_at") {
-            out.push_str("        updated_at: Set(chrono::Utc::now()),\n");
+        if !fields.iter().any(|f| f.name == "updated_at") {
+            out.push_str(&format!("{pad}updated_at: Set(chrono::Utc::now()),\n"));
         }
     }
 
     // Soft-delete default
     if info.has_soft_delete() {
-        out.push_str("        deleted_at: Set(None),\n");
+        out.push_str(&format!("{pad}deleted_at: Set(None),\n"));
     }
 
-    // Close ActiveModel and insert
-    out.push_str(&format!(
-        r#"        ..Default::default()
-    }}
-    .insert(&state.db)
-    .await
-    .map_err(AppError::from)?;
-
-    Ok((StatusCode::CREATED, Json({response_dto}::from(model))))
-}}
-"#,
-    ));
-
     out
 }
 
@@ -459,8 +1584,10 @@ fn generate_update_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
     let update_dto = GenerationContext::update_dto_name(&info.entity.name);
     let response_dto = GenerationContext::response_dto_name(&info.entity.name);
     let pk_type = info.pk_rust_type();
-
-    let update_fields = info.update_fields();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&pk_type);
+    let path_binding = framework.path_binding();
 
     let mut out = String::with_capacity(2048);
 
@@ -472,35 +1599,143 @@ fn generate_update_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
         )),
         ctx,
     ));
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
 
     out.push_str(&format!(
         r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
-    Path(id): Path<{pk_type}>,
+    {state_param},
+    {path_param},
     Json(payload): Json<{update_dto}>,
 ) -> Result<Json<{response_dto}>, AppError> {{
-    payload.validate().map_err(AppError::from)?;
+{path_binding}    payload.validate().map_err(AppError::from)?;
 
     // Find existing record
-    let existing = {module}::Entity::find_by_id(id)
+    let existing = {module}::Entity::{find}(id)
         .one(&state.db)
         .await
         .map_err(AppError::from)?
         .ok_or(AppError::NotFound)?;
+"#,
+        module = info.module_name(),
+        find = find_existing(info),
+    ));
+    out.push_str(&unique_scope_checks(info, OperationType::Update));
+    out.push_str(&format!(
+        "\n    let mut active: {}::ActiveModel = existing.into_active_model();\n\n",
+        info.module_name()
+    ));
 
-    let mut active: {module}::ActiveModel = existing.into_active_model();
+    if converts_update(info, ctx) {
+        out.push_str("    payload.apply(&mut active);\n");
+    } else {
+        out.push_str(&update_field_sets(info, ctx));
+    }
 
+    // Save and return
+    out.push_str(&format!(
+        r#"
+    let updated = active.update(&state.db).await.map_err(AppError::from)?;
+{publish}
+    Ok(Json({response_dto}::from(updated)))
+}}
 "#,
-        module = info.module_name(),
+        publish = publish_line(info, "Updated", "id"),
     ));
 
-    // Apply optional field updates — hash password/secret fields
-    for field in &update_fields {
-        let name = GenerationContext::snake(&field.name);
-        let is_password_field =
-            field.secret || field.name.contains("password") || field.name.contains("secret");
+    out
+}
+
+/// Fields unique per parent the create or update handler checks before
+/// writing: create needs the value and its parent key in the payload,
+/// update only checks a changed value (the key itself can't change).
+fn checked_scopes<'a>(
+    info: &EntityInfo<'a>,
+    op: OperationType,
+) -> Vec<crate::context::ScopedUnique<'a>> {
+    let writable = match op {
+        OperationType::Create => info.create_fields(),
+        _ => info.update_fields(),
+    };
+    info.scoped_uniques()
+        .into_iter()
+        .filter(|s| {
+            writable.iter().any(|f| f.id == s.field.id)
+                && (op != OperationType::Create || writable.iter().any(|f| f.id == s.key.id))
+        })
+        .collect()
+}
+
+/// Lookups refusing a value already taken among the rows sharing its
+/// parent, so the client gets a 409 naming the field rather than the
+/// unique index's error. Soft-deleted rows count: the index covers them.
+fn unique_scope_checks(info: &EntityInfo, op: OperationType) -> String {
+    let module = info.module_name();
+    let mut out = String::new();
+
+    for scoped in checked_scopes(info, op) {
+        let field = GenerationContext::snake(&scoped.field.name);
+        let key = GenerationContext::snake(&scoped.key.name);
+        let filters = |pad: &str, key_value: &str, value: &str, exclude: bool| {
+            let mut filters = format!(
+                "{pad}    .filter({module}::Column::{}.eq({key_value}))\n{pad}    .filter({module}::Column::{}.eq({value}))\n",
+                GenerationContext::pascal(&scoped.key.name),
+                GenerationContext::pascal(&scoped.field.name),
+            );
+            if exclude {
+                filters.push_str(&format!("{pad}    .filter({module}::Column::Id.ne(id))\n"));
+            }
+            filters
+        };
+        let conflict = scoped.conflict_message(info.entity);
+
+        out.push_str(&format!(
+            "\n    // `{field}` is unique per {}\n",
+            scoped.parent_name.replace('_', " ")
+        ));
+        if op == OperationType::Create {
+            let value = if is_copy(&scoped.field.data_type) {
+                format!("payload.{field}")
+            } else {
+                format!("payload.{field}.clone()")
+            };
+            out.push_str(&format!(
+                "    let taken = {module}::Entity::find()\n{}        .one(&state.db)\n        .await\n        .map_err(AppError::from)?\n        .is_some();\n    if taken {{\n        return Err(AppError::conflict({conflict:?}));\n    }}\n",
+                filters("    ", &format!("payload.{key}"), &value, false),
+            ));
+        } else {
+            let value = if is_copy(&scoped.field.data_type) {
+                format!("*{field}")
+            } else {
+                format!("{field}.clone()")
+            };
+            out.push_str(&format!(
+                "    if let Some({field}) = &payload.{field} {{\n        let taken = {module}::Entity::find()\n{}            .one(&state.db)\n            .await\n            .map_err(AppError::from)?\n            .is_some();\n        if taken {{\n            return Err(AppError::conflict({conflict:?}));\n        }}\n    }}\n",
+                filters("        ", &format!("existing.{key}"), &value, true),
+            ));
+        }
+    }
+
+    out
+}
+
+/// The entity method loading a record by ID when soft-deleted records
+/// count as missing.
+pub(crate) fn find_existing(info: &EntityInfo) -> &'static str {
+    if info.has_soft_delete() {
+        "find_active_by_id"
+    } else {
+        "find_by_id"
+    }
+}
 
-        if is_password_field && ctx.auth_enabled() {
+/// Statements copying each present field of an update `payload` onto the
+/// `active` model (hashing secrets), then bumping `updated_at`.
+pub(crate) fn update_field_sets(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let mut out = String::new();
+
+    for field in &info.update_fields() {
+        let name = GenerationContext::snake(&field.name);
+        if ctx.is_password_field(field) && ctx.auth_enabled() {
             out.push_str(&format!(
                 "    if let Some(val) = payload.{name} {{\n        active.{name} = Set(hash_password(&val).map_err(|e| AppError::internal(format!(\"Password hashing failed: {{}}\", e)))?);\n    }}\n",
             ));
@@ -516,16 +1751,6 @@ fn generate_update_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
         out.push_str("\n    active.updated_at = Set(chrono::Utc::now());\n");
     }
 
-    // Save and return
-    out.push_str(&format!(
-        r#"
-    let updated = active.update(&state.db).await.map_err(AppError::from)?;
-
-    Ok(Json({response_dto}::from(updated)))
-}}
-"#,
-    ));
-
     out
 }
 
@@ -536,26 +1761,41 @@ fn generate_update_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
 fn generate_delete_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
     let fn_name = format!("delete_{}", info.snake_name());
     let pk_type = info.pk_rust_type();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&pk_type);
+    let path_binding = framework.path_binding();
+    let no_content_type = framework.no_content_type();
+    let no_content = framework.no_content();
+    let effects = delete_effects_doc(info);
+    let blockers = info.delete_blockers();
 
     let mut out = String::with_capacity(1024);
 
     out.push_str(&doc_comment(
         Some(&format!(
-            "Delete a {} by ID.\n\nDELETE {}/:id",
+            "Delete a {} by ID.\n\nDELETE {}/:id{}",
             info.snake_name(),
-            info.base_path()
+            info.base_path(),
+            effects
+                .as_deref()
+                .map(|e| format!("\n\n{}", e))
+                .unwrap_or_default()
         )),
         ctx,
     ));
 
+    out.push_str(&delete_openapi_attr(info, ctx));
+    out.push_str(&handler_span(info, ctx, OperationType::Delete, &fn_name));
+
     if info.has_soft_delete() {
         // Soft delete: set deleted_at instead of actual deletion
         out.push_str(&format!(
             r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
-    Path(id): Path<{pk_type}>,
-) -> Result<StatusCode, AppError> {{
-    let existing = {module}::Entity::find_by_id(id)
+    {state_param},
+    {path_param},
+) -> Result<{no_content_type}, AppError> {{
+{path_binding}    let existing = {module}::Entity::find_active_by_id(id)
         .one(&state.db)
         .await
         .map_err(AppError::from)?
@@ -564,36 +1804,760 @@ fn generate_delete_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
     let mut active: {module}::ActiveModel = existing.into_active_model();
     active.deleted_at = Set(Some(chrono::Utc::now()));
     active.update(&state.db).await.map_err(AppError::from)?;
-
-    Ok(StatusCode::NO_CONTENT)
+{publish}
+    Ok({no_content})
 }}
 "#,
             module = info.module_name(),
+            publish = publish_line(info, "Deleted", "id"),
         ));
     } else {
         // Hard delete
         out.push_str(&format!(
             r#"pub async fn {fn_name}(
-    State(state): State<AppState>,
-    Path(id): Path<{pk_type}>,
-) -> Result<StatusCode, AppError> {{
-    let result = {module}::Entity::delete_by_id(id)
+    {state_param},
+    {path_param},
+) -> Result<{no_content_type}, AppError> {{
+{path_binding}{blocker_check}    let result = {module}::Entity::delete_by_id(id)
         .exec(&state.db)
         .await
         .map_err(AppError::from)?;
 
-    if result.rows_affected == 0 {{
-        return Err(AppError::NotFound);
-    }}
+    if result.rows_affected == 0 {{
+        return Err(AppError::NotFound);
+    }}
+{publish}
+    Ok({no_content})
+}}
+"#,
+            module = info.module_name(),
+            blocker_check = delete_blocker_check(&blockers),
+            publish = publish_line(info, "Deleted", "id"),
+        ));
+    }
+
+    out
+}
+
+/// Whether the entity gets a `restore` handler: it soft-deletes and exposes
+/// delete.
+pub(crate) fn restores(info: &EntityInfo, ops: &[OperationType]) -> bool {
+    info.has_soft_delete() && ops.contains(&OperationType::Delete)
+}
+
+/// Generate `restore_{entity}`, which clears `deleted_at` on a soft-deleted
+/// record.
+fn generate_restore_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let fn_name = format!("restore_{}", info.snake_name());
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&info.pk_rust_type());
+    let path_binding = framework.path_binding();
+    let touch = if info.has_timestamps() {
+        "    active.updated_at = Set(chrono::Utc::now());\n"
+    } else {
+        ""
+    };
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Restore a soft-deleted {}. Restoring a record that is not deleted changes nothing.\n\nPOST {}/:id/restore",
+            info.snake_name(),
+            info.base_path()
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Delete, &fn_name));
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+) -> Result<Json<{response_dto}>, AppError> {{
+{path_binding}    let existing = {module}::Entity::find_by_id(id)
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+    let mut active: {module}::ActiveModel = existing.into_active_model();
+    active.deleted_at = Set(None);
+{touch}    let restored = active.update(&state.db).await.map_err(AppError::from)?;
+{publish}
+    Ok(Json({response_dto}::from(restored)))
+}}
+"#,
+        module = info.module_name(),
+        publish = publish_line(info, "Updated", "id"),
+    ));
+    out
+}
+
+/// `publish_change(…);` after a committed write to a realtime entity, or
+/// nothing.
+pub(crate) fn publish_line(info: &EntityInfo, kind: &str, id: &str) -> String {
+    if !info.is_realtime() {
+        return String::new();
+    }
+    format!(
+        "    publish_change(\"{}\", ChangeKind::{}, {});\n",
+        info.pascal_name(),
+        kind,
+        id
+    )
+}
+
+/// Name of the handler a CRUD operation routes to in `module`: the
+/// operation's custom handler, or the one generated here (`list_categories`,
+/// pluralized like the table name).
+pub(crate) fn crud_handler_name(op: &imortal_ir::CrudOperation, module: &str) -> String {
+    match op.operation_type {
+        OperationType::ReadAll if op.custom_handler.is_none() => {
+            format!("list_{}", GenerationContext::pluralize(module))
+        }
+        _ => op.handler_name(module),
+    }
+}
+
+/// Name of the handler streaming an entity's changes, e.g. `post_changes`.
+pub(crate) fn live_handler_name(info: &EntityInfo) -> String {
+    format!("{}_changes", info.snake_name())
+}
+
+/// Generate `{entity}_changes`, streaming the entity's changes over its
+/// endpoint group's realtime transport.
+pub(crate) fn generate_live_handler(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let transport = info
+        .live_transport()
+        .expect("caller checks the entity streams its changes");
+    let (what, method) = match transport {
+        RealtimeTransport::WebSocket => ("a WebSocket", "GET (WebSocket upgrade)"),
+        RealtimeTransport::Sse => ("server-sent events", "GET"),
+    };
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Stream every {} created, updated or deleted from now on, as {}.\n\n{} {}/{}",
+            info.snake_name(),
+            what,
+            method,
+            info.base_path(),
+            transport.path()
+        )),
+        ctx,
+    );
+    out.push_str(&ctx.framework_backend().live_handler(
+        &live_handler_name(info),
+        &info.pascal_name(),
+        transport,
+    ));
+    out
+}
+
+/// Name of the handler storing a file for an upload field, e.g.
+/// `upload_user_avatar`.
+pub(crate) fn upload_handler_name(info: &EntityInfo, field: &Field) -> String {
+    format!(
+        "upload_{}_{}",
+        info.snake_name(),
+        GenerationContext::snake(&field.name)
+    )
+}
+
+/// Doc comment of an upload handler, shared by both ORM backends.
+pub(crate) fn upload_handler_doc(
+    info: &EntityInfo,
+    field: &Field,
+    ctx: &GenerationContext,
+) -> String {
+    let kind = if field.ui_hints.widget == Some(WidgetType::Image) {
+        "an image"
+    } else {
+        "a file"
+    };
+    doc_comment(
+        Some(&format!(
+            "Upload {} for a {}'s `{}`, replacing the stored URL.\n\nPOST {}/:id/{} (multipart/form-data)",
+            kind,
+            info.snake_name(),
+            GenerationContext::snake(&field.name),
+            info.base_path(),
+            GenerationContext::snake(&field.name)
+        )),
+        ctx,
+    )
+}
+
+/// Statements reading the upload into `upload` and storing it, leaving its
+/// URL in `url`. Files go under `{table}/{id}/{field}-…`.
+pub(crate) fn store_upload(info: &EntityInfo, field: &Field) -> String {
+    let image_check = if field.ui_hints.widget == Some(WidgetType::Image) {
+        "    upload.require_image()?;\n"
+    } else {
+        ""
+    };
+    format!(
+        "    let upload = storage::read_upload(multipart).await?;\n{image_check}    let key = upload.key(&format!(\"{}/{{}}/{}\", id));\n    let url = storage::store(&state.config, &key, upload).await?;\n",
+        info.table_name(),
+        GenerationContext::snake(&field.name)
+    )
+}
+
+/// Generate `upload_{entity}_{field}`, storing a multipart file and
+/// setting the field to its URL.
+fn generate_upload_handler(info: &EntityInfo, field: &Field, ctx: &GenerationContext) -> String {
+    let fn_name = upload_handler_name(info, field);
+    let response_dto = GenerationContext::response_dto_name(&info.entity.name);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&info.pk_rust_type());
+    let path_binding = framework.path_binding();
+    let upload_param = framework.upload_param();
+    let url = if field.required { "url" } else { "Some(url)" };
+    let touch = if info.has_timestamps() {
+        "    active.updated_at = Set(chrono::Utc::now());\n"
+    } else {
+        ""
+    };
+
+    let mut out = upload_handler_doc(info, field, ctx);
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+    {upload_param},
+) -> Result<Json<{response_dto}>, AppError> {{
+{path_binding}    let existing = {module}::Entity::{find}(id)
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+{store}
+    let mut active: {module}::ActiveModel = existing.into_active_model();
+    active.{column} = Set({url});
+{touch}    let updated = active.update(&state.db).await.map_err(AppError::from)?;
+{publish}
+    Ok(Json({response_dto}::from(updated)))
+}}
+"#,
+        module = info.module_name(),
+        find = find_existing(info),
+        store = store_upload(info, field),
+        column = GenerationContext::snake(&field.name),
+        publish = publish_line(info, "Updated", "id"),
+    ));
+    out
+}
+
+/// `#[utoipa::path]` attribute for the delete handler, or nothing without
+/// OpenAPI.
+pub(crate) fn delete_openapi_attr(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    if !ctx.openapi_enabled() {
+        return String::new();
+    }
+
+    let pk_type = info.pk_rust_type();
+    let blockers = info.delete_blockers();
+    let summary = delete_effects_summary(info);
+    let conflict = if blockers.is_empty() {
+        String::new()
+    } else {
+        "\n        (status = 409, description = \"Other records still reference it; the body lists them\"),"
+            .to_string()
+    };
+    format!(
+        "#[utoipa::path(\n    delete,\n    path = \"{}/{{id}}\",\n    params((\"id\" = {}, Path, description = \"{} ID\")),\n    responses(\n        (status = 204, description = {:?}),\n        (status = 404, description = \"Not found\"),{}\n    ),\n    tag = \"{}\"\n)]\n",
+        info.base_path(),
+        pk_type,
+        info.pascal_name(),
+        summary,
+        conflict,
+        info.pascal_name()
+    )
+}
+
+/// Docs paragraph describing what a delete does to referencing rows, or
+/// `None` when nothing references the entity.
+pub(crate) fn delete_effects_doc(info: &EntityInfo) -> Option<String> {
+    if info.has_soft_delete() {
+        return Some(
+            "Soft delete: the row is only marked deleted, so rows referencing it are left untouched."
+                .to_string(),
+        );
+    }
+
+    let effects = info.delete_effects();
+    if effects.is_empty() {
+        return None;
+    }
+
+    let mut doc = String::from("Deleting a record:");
+    for effect in &effects {
+        doc.push_str(&format!("\n- {}", effect.describe()));
+    }
+    if !info.delete_blockers().is_empty() {
+        doc.push_str("\n\nResponds 409 Conflict, listing the blocking records, when a restricting reference exists.");
+    }
+    Some(doc)
+}
+
+/// One-line version of the delete effects for the OpenAPI 204 response.
+pub(crate) fn delete_effects_summary(info: &EntityInfo) -> String {
+    if info.has_soft_delete() {
+        return "Marked deleted; referencing rows are untouched".to_string();
+    }
+    let effects = info.delete_effects();
+    if effects.is_empty() {
+        return "Deleted".to_string();
+    }
+    let described: Vec<String> = effects.iter().map(|e| e.describe()).collect();
+    format!("Deleted. Deleting a record {}", described.join("; "))
+}
+
+/// Count referencing rows for each `RESTRICT` foreign key and answer 409
+/// before attempting the delete.
+/// Count the rows behind each restricting foreign key and return
+/// `AppError::DeleteBlocked` before deleting when any exist.
+pub(crate) fn delete_blocker_check(blockers: &[DeleteEffect]) -> String {
+    if blockers.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from(
+        "    // Restricting foreign keys would reject the delete; report what blocks it.\n    let mut blocking = Vec::new();\n",
+    );
+    for blocker in blockers {
+        let module = GenerationContext::module_name(&blocker.child.name);
+        let column = GenerationContext::pascal(&blocker.fk_field.name);
+        out.push_str(&format!(
+            r#"    let count = {module}::Entity::find()
+        .filter({module}::Column::{column}.eq(id))
+        .count(&state.db)
+        .await
+        .map_err(AppError::from)?;
+    if count > 0 {{
+        blocking.push(BlockingRecords {{
+            entity: "{entity}",
+            field: "{field}",
+            count,
+        }});
+    }}
+"#,
+            entity = blocker.child.name,
+            field = blocker.fk_field.name,
+        ));
+    }
+    out.push_str(
+        "    if !blocking.is_empty() {\n        return Err(AppError::DeleteBlocked(blocking));\n    }\n\n",
+    );
+    out
+}
+
+// ============================================================================
+// Association handlers
+// ============================================================================
+
+/// Associations an entity serves link routes for: they ride on its update
+/// operation, so it needs one.
+pub(crate) fn link_associations<'a>(info: &EntityInfo<'a>) -> Vec<Association<'a>> {
+    let updates = info.endpoint().is_some_and(|ep| {
+        ep.enabled
+            && ep
+                .enabled_operations()
+                .iter()
+                .any(|op| op.operation_type == OperationType::Update)
+    });
+    if updates {
+        info.associations()
+    } else {
+        Vec::new()
+    }
+}
+
+/// The handler module with an entity's link handlers (`{entity}_links`),
+/// if it serves any.
+pub(crate) fn links_module(info: &EntityInfo) -> Option<String> {
+    if link_associations(info).is_empty() {
+        None
+    } else {
+        Some(format!("{}_links", info.module_name()))
+    }
+}
+
+/// Generate `src/handlers/{entity}_links.rs`: list, link and unlink
+/// handlers for each association the entity serves.
+fn generate_link_handlers(info: &EntityInfo, ctx: &GenerationContext) -> Option<GeneratedFile> {
+    let module = links_module(info)?;
+    let associations = link_associations(info);
+    let framework = ctx.framework_backend();
+
+    let mut content = String::with_capacity(4096);
+    content.push_str(&file_header(&format!(
+        "Request handlers linking {} rows to others through junction entities.",
+        info.pascal_name()
+    )));
+
+    // Imports
+    let junctions: Vec<EntityInfo> = associations
+        .iter()
+        .map(|a| EntityInfo::new(a.junction, ctx))
+        .collect();
+    content.push_str(&framework.handler_imports(&[Extractor::State, Extractor::Path], true));
+    let mut sea_imports = vec![
+        "ActiveModelTrait",
+        "ColumnTrait",
+        "EntityTrait",
+        "QueryFilter",
+        "Set",
+    ];
+    if junctions.iter().any(|j| j.has_soft_delete()) {
+        sea_imports.push("IntoActiveModel");
+    }
+    content.push_str(&format!("use sea_orm::{{{}}};\n", sea_imports.join(", ")));
+    // Junction primary keys are minted by `active_model_sets`
+    if junctions.iter().any(|j| j.pk_rust_type().contains("Uuid")) {
+        content.push_str("use uuid::Uuid;\n");
+    }
+    content.push_str("use validator::Validate;\n");
+    let hashes = ctx.auth_enabled()
+        && associations
+            .iter()
+            .any(|a| a.fields(ctx).iter().any(|f| ctx.is_password_field(f)));
+    if hashes {
+        content.push_str("use crate::auth::jwt::hash_password;\n");
+    }
+    content.push('\n');
+    content.push_str("use crate::error::AppError;\n");
+    content.push_str(&format!("use crate::models::{};\n", info.module_name()));
+    let mut junction_imports: Vec<(String, Vec<String>)> = Vec::new();
+    for association in &associations {
+        let junction = GenerationContext::module_name(&association.junction.name);
+        let names = [
+            association.dto_name(),
+            GenerationContext::response_dto_name(&association.junction.name),
+        ];
+        match junction_imports.iter_mut().find(|(m, _)| *m == junction) {
+            Some((_, imports)) => imports.extend(names),
+            None => junction_imports.push((junction, names.to_vec())),
+        }
+    }
+    junction_imports.sort();
+    for (junction, mut imports) in junction_imports {
+        imports.sort();
+        imports.dedup();
+        content.push_str(&format!(
+            "use crate::models::{}::{{self, {}}};\n",
+            junction,
+            imports.join(", ")
+        ));
+    }
+    content.push_str("use crate::state::AppState;\n");
+
+    for association in &associations {
+        content.push('\n');
+        content.push_str(&generate_list_links_handler(info, association, ctx));
+        content.push('\n');
+        content.push_str(&generate_link_handler(info, association, ctx));
+        content.push('\n');
+        content.push_str(&generate_unlink_handler(info, association, ctx));
+    }
+
+    Some(GeneratedFile::new(
+        format!("src/handlers/{}.rs", module),
+        content,
+        FileType::Rust,
+    ))
+}
+
+/// Statement answering 404 unless the owner row `id` exists.
+fn owner_check(info: &EntityInfo) -> String {
+    format!(
+        r#"    {module}::Entity::{find}(id)
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+"#,
+        module = info.module_name(),
+        find = find_existing(info),
+    )
+}
+
+/// `list_{entity}_{accessor}`: the junction rows of one owner row.
+fn generate_list_links_handler(
+    info: &EntityInfo,
+    association: &Association,
+    ctx: &GenerationContext,
+) -> String {
+    let fn_name = format!("list_{}", association.handler_suffix());
+    let junction = EntityInfo::new(association.junction, ctx);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&info.pk_rust_type());
+    let path_binding = framework.path_binding();
+    let response_dto = GenerationContext::response_dto_name(&association.junction.name);
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "List the {} linked to a {}, as `{}` rows.\n\nGET {}/:id/{}",
+            association.accessor,
+            info.snake_name(),
+            junction.pascal_name(),
+            info.base_path(),
+            association.accessor,
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+) -> Result<Json<Vec<{response_dto}>>, AppError> {{
+{path_binding}{owner_check}
+    let links = {junction}::Entity::{find}()
+        .filter({junction}::Column::{owner_key}.eq(id))
+        .all(&state.db)
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(Json(links.into_iter().map({response_dto}::from).collect()))
+}}
+"#,
+        owner_check = owner_check(info),
+        junction = junction.module_name(),
+        find = if junction.has_soft_delete() {
+            "find_active"
+        } else {
+            "find"
+        },
+        owner_key = GenerationContext::pascal(&association.owner_key.name),
+    ));
+    out
+}
+
+/// `link_{entity}_{accessor}`: insert a junction row pointing at the owner
+/// row from the path.
+fn generate_link_handler(
+    info: &EntityInfo,
+    association: &Association,
+    ctx: &GenerationContext,
+) -> String {
+    let fn_name = format!("link_{}", association.handler_suffix());
+    let junction = EntityInfo::new(association.junction, ctx);
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_param(&info.pk_rust_type());
+    let path_binding = framework.path_binding();
+    let response_dto = GenerationContext::response_dto_name(&association.junction.name);
+    let created_type = framework.created_type(&response_dto);
+    let owner_value = if matches!(association.owner_key.data_type, DataType::Optional(_)) {
+        "Some(id)"
+    } else {
+        "id"
+    };
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Link a {} to one of its {}. `{}` is set from the path.\n\nPOST {}/:id/{}",
+            info.snake_name(),
+            association.accessor,
+            GenerationContext::snake(&association.owner_key.name),
+            info.base_path(),
+            association.accessor,
+        )),
+        ctx,
+    );
+    out.push_str(&handler_span(info, ctx, OperationType::Update, &fn_name));
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+    Json(payload): Json<{dto}>,
+) -> Result<{created_type}, AppError> {{
+{path_binding}    payload.validate().map_err(AppError::from)?;
+{owner_check}
+    let link = {junction}::ActiveModel {{
+"#,
+        dto = association.dto_name(),
+        owner_check = owner_check(info),
+        junction = junction.module_name(),
+    ));
+    out.push_str(&active_model_sets(
+        &junction,
+        ctx,
+        &association.fields(ctx),
+        "payload",
+        8,
+    ));
+    out.push_str(&format!(
+        r#"        {owner_key}: Set({owner_value}),
+        ..Default::default()
+    }}
+    .insert(&state.db)
+    .await
+    .map_err(AppError::from)?;
+
+    Ok({created})
+}}
+"#,
+        owner_key = GenerationContext::snake(&association.owner_key.name),
+        created = framework.created(&format!("{response_dto}::from(link)")),
+    ));
+    out
+}
+
+/// `unlink_{entity}_{accessor}`: remove the junction row between the two
+/// rows in the path.
+fn generate_unlink_handler(
+    info: &EntityInfo,
+    association: &Association,
+    ctx: &GenerationContext,
+) -> String {
+    let fn_name = format!("unlink_{}", association.handler_suffix());
+    let junction = EntityInfo::new(association.junction, ctx);
+    let other = association.other_param();
+    let framework = ctx.framework_backend();
+    let state_param = framework.state_param();
+    let path_param = framework.path_pair_param(
+        &info.pk_rust_type(),
+        &other,
+        &EntityInfo::new(association.other, ctx).pk_rust_type(),
+    );
+    let path_binding = framework.path_pair_binding(&other);
+    let no_content_type = framework.no_content_type();
+    let no_content = framework.no_content();
+    let module = junction.module_name();
+    let owner_key = GenerationContext::pascal(&association.owner_key.name);
+    let other_key = GenerationContext::pascal(&association.other_key.name);
+
+    let mut out = doc_comment(
+        Some(&format!(
+            "Unlink a {} from one of its {}.\n\nDELETE {}/:id/{}/:{}",
+            info.snake_name(),
+            association.accessor,
+            info.base_path(),
+            association.accessor,
+            other,
+        )),
+        ctx,
+    );
+    // Frameworks extracting the pair as a whole only bind `id` in the body
+    out.push_str(&instrument(
+        info,
+        ctx,
+        OperationType::Update,
+        &fn_name,
+        path_binding.is_empty(),
+    ));
+    out.push_str(&format!(
+        r#"pub async fn {fn_name}(
+    {state_param},
+    {path_param},
+) -> Result<{no_content_type}, AppError> {{
+{path_binding}"#
+    ));
+    if junction.has_soft_delete() {
+        out.push_str(&format!(
+            r#"    let link = {module}::Entity::find_active()
+        .filter({module}::Column::{owner_key}.eq(id))
+        .filter({module}::Column::{other_key}.eq({other}))
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?
+        .ok_or(AppError::NotFound)?;
+
+    let mut active: {module}::ActiveModel = link.into_active_model();
+    active.deleted_at = Set(Some(chrono::Utc::now()));
+    active.update(&state.db).await.map_err(AppError::from)?;
+"#
+        ));
+    } else {
+        out.push_str(&format!(
+            r#"    let result = {module}::Entity::delete_many()
+        .filter({module}::Column::{owner_key}.eq(id))
+        .filter({module}::Column::{other_key}.eq({other}))
+        .exec(&state.db)
+        .await
+        .map_err(AppError::from)?;
+
+    if result.rows_affected == 0 {{
+        return Err(AppError::NotFound);
+    }}
+"#
+        ));
+    }
+    out.push_str(&format!("\n    Ok({no_content})\n}}\n"));
+    out
+}
+
+// ============================================================================
+// Telemetry
+// ============================================================================
 
-    Ok(StatusCode::NO_CONTENT)
-}}
-"#,
-            module = info.module_name(),
+/// `#[tracing::instrument]` attribute for a CRUD handler when telemetry is
+/// enabled, empty otherwise.
+///
+/// The span carries the entity, operation and the route's auth requirement
+/// as typed fields (plus the record id for single-item operations), and is a
+/// child of the request's HTTP span.
+pub(crate) fn handler_span(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    operation: OperationType,
+    fn_name: &str,
+) -> String {
+    let record_id = matches!(
+        operation,
+        OperationType::Read | OperationType::Update | OperationType::Delete
+    );
+    instrument(info, ctx, operation, fn_name, record_id)
+}
+
+/// [`handler_span`] for a handler that may not bind `id` by itself.
+fn instrument(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    operation: OperationType,
+    fn_name: &str,
+    record_id: bool,
+) -> String {
+    if !ctx.telemetry() {
+        return String::new();
+    }
+
+    let security = info.endpoint().map(|ep| ep.effective_security(operation));
+    let auth_required = ctx.auth_enabled() && security.as_ref().is_some_and(|s| s.auth_required);
+
+    let mut fields = vec![
+        format!("app.entity = {:?}", info.pascal_name()),
+        format!(
+            "app.operation = {:?}",
+            operation.display_name().to_lowercase()
+        ),
+        format!("app.auth_required = {}", auth_required),
+    ];
+    if let Some(security) = security.filter(|s| auth_required && !s.roles.is_empty()) {
+        fields.push(format!(
+            "app.required_roles = {:?}",
+            security.roles.join(",")
         ));
     }
+    if record_id {
+        fields.push("app.record_id = %id".to_string());
+    }
+    fields.push(format!("code.function = {:?}", fn_name));
 
-    out
+    format!(
+        "#[tracing::instrument(\n    name = {:?},\n    skip_all,\n    fields(\n{}    )\n)]\n",
+        fn_name,
+        fields
+            .iter()
+            .map(|f| format!("        {},\n", f))
+            .collect::<String>()
+    )
 }
 
 // ============================================================================
@@ -604,7 +2568,10 @@ fn generate_delete_handler(info: &EntityInfo, ctx: &GenerationContext) -> String
 mod tests {
     use super::*;
     use imortal_core::DataType;
-    use imortal_ir::{CrudOperation, EndpointGroup, Entity, Field, OperationType, ProjectGraph};
+    use imortal_ir::{
+        AuthConfig, AuthPrincipal, CrudOperation, ETagSource, EndpointGroup, Entity, Field,
+        HttpCaching, OperationType, ProjectGraph, Relationship, WebFramework,
+    };
     use uuid::Uuid;
 
     /// Create a User entity with an endpoint group.
@@ -761,6 +2728,203 @@ mod tests {
         assert!(content.contains("fetch_page"));
     }
 
+    #[test]
+    fn test_list_handler_cursor_pagination() {
+        let mut project = setup_project();
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let mod_file = files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/mod.rs")
+            .unwrap();
+        assert!(
+            mod_file
+                .content
+                .contains("pub struct CursorPage<T: Serialize>")
+        );
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains("use crate::handlers::{CursorPage, PaginationParams};"));
+        assert!(content.contains("Result<Json<CursorPage<UserResponse>>, AppError>"));
+        assert!(content.contains("cursor_by(user::Column::Id)"));
+        assert!(content.contains(".first(limit + 1)"));
+        assert!(!content.contains("PaginatorTrait"));
+    }
+
+    /// Turn off sorting and filtering on the project's first endpoint.
+    fn without_list_params(project: &mut ProjectGraph) {
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        let op = endpoint.get_operation_mut(OperationType::ReadAll).unwrap();
+        op.list = ListOptions::default().without_sorting().without_filtering();
+    }
+
+    #[test]
+    fn test_list_handler_bare_array() {
+        let mut project = setup_project();
+        project.config.pagination = PaginationStyle::None;
+        without_list_params(&mut project);
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let mod_file = files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/mod.rs")
+            .unwrap();
+        assert!(!mod_file.content.contains("PaginationParams"));
+        assert!(!mod_file.content.contains("use serde"));
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains("Result<Json<Vec<UserResponse>>, AppError>"));
+        assert!(!content.contains("Query"));
+        assert!(!content.contains("PaginationParams"));
+    }
+
+    #[test]
+    fn test_list_handler_sorting_and_filters() {
+        let mut project = setup_project();
+        let user = project.entities.values_mut().next().unwrap();
+        let mut age = Field::new("age", DataType::Optional(Box::new(DataType::Int32)));
+        age.required = false;
+        user.fields.push(age);
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        let op = endpoint.get_operation_mut(OperationType::ReadAll).unwrap();
+        op.list = ListOptions::default()
+            .with_page_sizes(25, 50)
+            .with_default_sort("-created_at,unknown");
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_handlers(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("pub struct UserListParams {"));
+        assert!(content.contains("    pub sort: Option<String>,\n"));
+        assert!(content.contains("    pub email_contains: Option<String>,\n"));
+        assert!(content.contains("    pub age_min: Option<i32>,\n"));
+        assert!(
+            content.contains("    pub created_at_max: Option<chrono::DateTime<chrono::Utc>>,\n")
+        );
+        // The primary key has its own endpoint
+        assert!(!content.contains("    pub id: Option"));
+
+        assert!(content.contains("    Query(filters): Query<UserListParams>,\n"));
+        assert!(
+            content.contains("use crate::constants::{USER_DEFAULT_PAGE_SIZE, USER_MAX_PAGE_SIZE};")
+        );
+        assert!(
+            content.contains(".unwrap_or(USER_DEFAULT_PAGE_SIZE).min(USER_MAX_PAGE_SIZE).max(1)")
+        );
+        assert!(content.contains(
+            "let paginator = filter_users(sort_users(user::Entity::find(), filters.sort.as_deref())?, filters)"
+        ));
+        assert!(content.contains("select = select.filter(user::Column::Email.contains(value));"));
+        assert!(content.contains("select = select.filter(user::Column::Age.gte(value));"));
+        assert!(content.contains("            \"created_at\" => user::Column::CreatedAt,\n"));
+        // Unknown default sort keys are dropped
+        assert!(
+            content.contains(
+                "return Ok(select\n            .order_by_desc(user::Column::CreatedAt));"
+            )
+        );
+        assert!(content.contains("Ok(select.order_by_asc(user::Column::Id))"));
+        assert!(content.contains("use sea_orm::{EntityTrait, ActiveModelTrait, PaginatorTrait, IntoActiveModel, ColumnTrait, QueryFilter, QueryOrder, Order, Select};"));
+    }
+
+    #[test]
+    fn test_list_handler_cursor_filters_without_sorting() {
+        let mut project = setup_project();
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_handlers(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        // The cursor only works in primary-key order
+        assert!(!content.contains("pub sort:"));
+        assert!(!content.contains("fn sort_users"));
+        assert!(content.contains("let mut cursor = filter_users(user::Entity::find(), filters).cursor_by(user::Column::Id);"));
+    }
+
+    /// User 1:N Post, with the user list asking for post counts and flags.
+    fn setup_aggregates_project() -> ProjectGraph {
+        let mut project = setup_project();
+        let user_id = project.entities.values().next().unwrap().id;
+
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("title", DataType::String));
+        let post_id = post.id;
+        project.add_entity(post);
+
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        *endpoint = endpoint
+            .clone()
+            .with_related_count(rel_id, "posts_count")
+            .with_related_exists(rel_id, "has_posts");
+
+        project
+    }
+
+    #[test]
+    fn test_list_handler_related_aggregates() {
+        let project = setup_aggregates_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("Result<Json<PaginatedResponse<UserListItem>>, AppError>"));
+        assert!(content.contains("let items = with_related_aggregates(&state.db, models).await?;"));
+        assert!(content.contains("use crate::models::post;"));
+        assert!(content.contains("use std::collections::{HashMap, HashSet};"));
+
+        // One grouped query per aggregate, never per row
+        assert!(content.contains(".group_by(post::Column::UserId)"));
+        assert!(content.contains(".filter(post::Column::UserId.is_in(ids.clone()))"));
+        assert!(
+            content.contains("posts_count: posts_count.get(&id).copied().unwrap_or(0) as u64,")
+        );
+        assert!(content.contains("has_posts: has_posts.contains(&id),"));
+        assert_eq!(content.matches(".all(db)").count(), 2);
+    }
+
+    #[test]
+    fn test_list_handler_without_aggregates_unchanged() {
+        let project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+        assert!(content.contains(".map(UserResponse::from)"));
+        assert!(!content.contains("with_related_aggregates"));
+        assert!(!content.contains("HashMap"));
+    }
+
     #[test]
     fn test_get_handler_returns_response_dto() {
         let project = setup_project();
@@ -793,16 +2957,183 @@ mod tests {
         assert!(content.contains("CreateUserDto"));
         assert!(content.contains("payload.validate()"));
         assert!(
-            content.contains("Uuid::new_v4()"),
-            "Create handler should generate UUID PK assignment. Content:\n{}",
+            content.contains(
+                "let model = user::ActiveModel::from(payload)\n        .insert(&state.db)"
+            ),
+            "Create handler should insert the converted payload. Content:\n{}",
             content
         );
-        assert!(content.contains("Set(payload.email)"));
-        assert!(content.contains("Set(payload.name)"));
         assert!(content.contains(".insert(&state.db)"));
         assert!(content.contains("StatusCode::CREATED"));
     }
 
+    #[test]
+    fn test_create_handler_hashes_principal_password_field() {
+        let mut project = setup_project();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields
+            .push(Field::new("digest", DataType::String).required());
+        project.config.auth = AuthConfig::jwt()
+            .with_principal(AuthPrincipal::new("User").with_password_field("digest"));
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap()
+            .content;
+
+        assert!(content.contains("use crate::auth::jwt::hash_password;"));
+        assert!(content.contains("digest: Set(hash_password(&payload.digest)"));
+        assert!(content.contains("Set(payload.email)"));
+    }
+
+    /// User → Post, with posts created inline in `POST /api/users`.
+    fn setup_nested_project() -> ProjectGraph {
+        let mut project = setup_project();
+        let user_id = project.entities.values().next().unwrap().id;
+
+        let mut post = Entity::new("Post");
+        let mut title = Field::new("title", DataType::String);
+        title.required = true;
+        post.fields.push(title);
+        let post_id = post.id;
+        project.add_entity(post);
+        project.add_endpoint(EndpointGroup::new(post_id, "Post"));
+
+        project
+            .create_relationship_with_fk(
+                Relationship::one_to_many(user_id, post_id).with_nested_create(),
+            )
+            .unwrap();
+
+        project
+    }
+
+    #[test]
+    fn test_create_handler_inserts_nested_children_in_transaction() {
+        let project = setup_nested_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("TransactionTrait"));
+        assert!(content.contains("use crate::models::post;"));
+        assert!(content.contains("let txn = state.db.begin().await.map_err(AppError::from)?;"));
+        assert!(content.contains(".insert(&txn)"));
+        assert!(!content.contains(".insert(&state.db)"));
+        assert!(content.contains("for item in payload.posts {"));
+        assert!(content.contains("post::ActiveModel {"));
+        assert!(content.contains("title: Set(item.title),"));
+        assert!(content.contains("user_id: Set(model.id),"));
+        assert!(!content.contains("Set(item.user_id)"));
+        assert!(content.contains("txn.commit().await.map_err(AppError::from)?;"));
+
+        // The children's own create handler is unaffected
+        let post_handlers = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/post.rs")
+            .unwrap()
+            .content;
+        assert!(post_handlers.contains(".insert(&state.db)"));
+        assert!(!post_handlers.contains("TransactionTrait"));
+    }
+
+    /// Post and Tag, many-to-many through a promoted `PostTag` with a
+    /// `role` of its own; only Post has endpoints.
+    fn setup_junction_project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("test_api");
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        project.add_endpoint(EndpointGroup::new(post, "Post"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(post, tag, "post_tags"))
+            .unwrap();
+        let junction = project.promote_junction(rel).unwrap();
+        let mut role = Field::new("role", DataType::String);
+        role.required = true;
+        project.get_entity_mut(junction).unwrap().add_field(role);
+        project
+    }
+
+    #[test]
+    fn test_link_handlers_for_promoted_junction() {
+        let project = setup_junction_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        assert!(
+            files[0]
+                .content
+                .contains("pub mod post;\npub mod post_links;\n")
+        );
+        let links = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/post_links.rs")
+            .unwrap()
+            .content;
+        assert!(links.contains("use uuid::Uuid;"));
+        assert!(
+            links
+                .contains("use crate::models::post_tag::{self, PostTagResponse, PostTagsLinkDto};")
+        );
+
+        assert!(links.contains("pub async fn list_post_tags("));
+        assert!(links.contains("post::Entity::find_by_id(id)"));
+        assert!(links.contains(".filter(post_tag::Column::PostId.eq(id))"));
+
+        assert!(links.contains("pub async fn link_post_tags("));
+        assert!(links.contains("Json(payload): Json<PostTagsLinkDto>,"));
+        assert!(links.contains("tag_id: Set(payload.tag_id),"));
+        assert!(links.contains("role: Set(payload.role),"));
+        assert!(links.contains("post_id: Set(id),"));
+        assert!(!links.contains("Set(payload.post_id)"));
+
+        assert!(links.contains("pub async fn unlink_post_tags("));
+        assert!(links.contains("Path((id, tag_id)): Path<(uuid::Uuid, uuid::Uuid)>,"));
+        assert!(links.contains(".filter(post_tag::Column::TagId.eq(tag_id))"));
+        assert!(links.contains("post_tag::Entity::delete_many()"));
+
+        // Tag has no endpoints, and Post none without update
+        assert!(
+            !files
+                .iter()
+                .any(|f| f.path.to_string_lossy().contains("tag_links"))
+        );
+        let mut read_only = setup_junction_project();
+        for endpoint in read_only.endpoints.values_mut() {
+            *endpoint = EndpointGroup::new(endpoint.entity_id, &endpoint.entity_name)
+                .with_operations(&[OperationType::Read]);
+        }
+        let ctx = GenerationContext::from_project_default(&read_only);
+        assert!(
+            !generate_handlers(&ctx)
+                .iter()
+                .any(|f| f.path.to_string_lossy().contains("_links"))
+        );
+    }
+
+    #[test]
+    fn test_create_handler_without_nested_children_has_no_transaction() {
+        let project = setup_aggregates_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+        assert!(!content.contains("txn"));
+        assert!(!content.contains("for item in payload."));
+    }
+
     #[test]
     fn test_create_handler_with_timestamps() {
         let project = setup_project();
@@ -814,8 +3145,19 @@ mod tests {
             .find(|f| f.path.to_string_lossy().contains("user.rs"))
             .unwrap();
 
+        // The payload conversion stamps the row
         let content = &user_file.content;
-        assert!(content.contains("chrono::Utc::now()"));
+        assert!(content.contains("ActiveModel::from(payload)"));
+        let models = crate::rust::models::generate_models(&ctx);
+        let model = models
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap();
+        assert!(
+            model
+                .content
+                .contains("created_at: Set(chrono::Utc::now()),")
+        );
     }
 
     #[test]
@@ -832,8 +3174,172 @@ mod tests {
         let content = &user_file.content;
         assert!(content.contains("UpdateUserDto"));
         assert!(content.contains("into_active_model()"));
-        assert!(content.contains("if let Some(val)"));
+        assert!(content.contains("    payload.apply(&mut active);\n"));
+        assert!(!content.contains("Set("));
+        assert!(content.contains(".update(&state.db)"));
+    }
+
+    #[test]
+    fn test_create_and_update_check_unique_scope() {
+        let mut project = setup_project();
+        let user_id = *project.entities.keys().next().unwrap();
+        let mut team = Entity::new("Team");
+        team.fields
+            .push(Field::new("slug", DataType::String).required());
+        let team_id = project.add_entity(team);
+        project.add_endpoint(EndpointGroup::new(team_id, "Team"));
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, team_id))
+            .unwrap();
+        let team = project.get_entity_mut(team_id).unwrap();
+        team.fields
+            .iter_mut()
+            .find(|f| f.name == "slug")
+            .unwrap()
+            .unique_scope = Some(rel_id);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/team.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("ColumnTrait"));
+        assert!(content.contains(
+            "    // `slug` is unique per user
+    let taken = team::Entity::find()
+        .filter(team::Column::UserId.eq(payload.user_id))
+        .filter(team::Column::Slug.eq(payload.slug.clone()))
+        .one(&state.db)"
+        ));
+        assert!(content.contains(
+            "    if let Some(slug) = &payload.slug {
+        let taken = team::Entity::find()
+            .filter(team::Column::UserId.eq(existing.user_id))
+            .filter(team::Column::Slug.eq(slug.clone()))
+            .filter(team::Column::Id.ne(id))"
+        ));
+        assert!(content.contains(
+            "return Err(AppError::conflict(\"a team with this slug already exists in this user\"));"
+        ));
+
+        // Unscoped entities are untouched
+        let user = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+        assert!(!user.contains("let taken"));
+    }
+
+    #[test]
+    fn test_handler_spans_with_telemetry() {
+        let mut project = setup_project();
+        project.config.telemetry = true;
+        project.config.auth = imortal_ir::AuthConfig::jwt();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.global_security =
+                imortal_ir::EndpointSecurity::with_roles(vec!["admin".into(), "editor".into()]);
+            endpoint
+                .operations
+                .retain(|op| op.operation_type != OperationType::Update);
+            endpoint.operations.push(
+                CrudOperation::new(OperationType::Update)
+                    .with_security(imortal_ir::EndpointSecurity::authenticated()),
+            );
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains(
+            "#[tracing::instrument(\n    name = \"list_users\",\n    skip_all,\n    fields(\n        app.entity = \"User\",\n        app.operation = \"list\",\n        app.auth_required = true,\n        app.required_roles = \"admin,editor\",\n        code.function = \"list_users\",\n    )\n)]\npub async fn list_users("
+        ));
+        assert!(content.contains("app.operation = \"read\",\n        app.auth_required = true,\n        app.required_roles = \"admin,editor\",\n        app.record_id = %id,"));
+        assert!(content.contains("app.operation = \"update\",\n        app.auth_required = true,\n        app.record_id = %id,"));
+        assert_eq!(content.matches("#[tracing::instrument(").count(), 5);
+
+        project.config.telemetry = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+        assert!(
+            files
+                .iter()
+                .all(|f| !f.content.contains("tracing::instrument"))
+        );
+    }
+
+    #[test]
+    fn test_read_replica_routes_reads_only() {
+        let mut project = setup_project();
+        project.config.read_replicas = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy().contains("user.rs"))
+            .unwrap()
+            .content;
+        assert!(content.contains(".paginate(&state.db_read, per_page)"));
+        assert!(content.contains(".one(&state.db_read)"));
+        assert!(content.contains(".insert(&state.db)"));
         assert!(content.contains(".update(&state.db)"));
+        assert!(content.contains(".exec(&state.db)"));
+    }
+
+    #[test]
+    fn test_delete_handler_documents_effects() {
+        let project = setup_aggregates_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        assert!(
+            content.contains(
+                "/// - is refused while any `Post` references it via `user_id` (RESTRICT)"
+            )
+        );
+        assert!(content.contains("#[utoipa::path(\n    delete,\n    path = \"/api/users/{id}\""));
+        // Reporting blockers is opt-in
+        assert!(!content.contains("status = 409"));
+        assert!(!content.contains("DeleteBlocked"));
+    }
+
+    #[test]
+    fn test_delete_handler_reports_blockers() {
+        let mut project = setup_aggregates_project();
+        project.config.report_delete_blockers = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("use crate::error::{AppError, BlockingRecords};"));
+        assert!(content.contains("use crate::models::post;"));
+        assert!(content.contains(".filter(post::Column::UserId.eq(id))"));
+        assert!(content.contains("return Err(AppError::DeleteBlocked(blocking));"));
+        assert!(content.contains("status = 409"));
+
+        // The check runs before the delete is attempted
+        let check = content.find("DeleteBlocked").unwrap();
+        let delete = content.find("delete_by_id").unwrap();
+        assert!(check < delete);
     }
 
     #[test]
@@ -887,6 +3393,163 @@ mod tests {
         assert!(content.contains("Set(Some(chrono::Utc::now()))"));
     }
 
+    #[test]
+    fn test_soft_delete_scopes_and_restore() {
+        let mut project = setup_project();
+        project
+            .entities
+            .values_mut()
+            .next()
+            .unwrap()
+            .config
+            .soft_delete = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+
+        let handlers = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/handlers/user.rs")
+            .unwrap()
+            .content;
+        assert!(handlers.contains("use crate::handlers::SoftDeleteParams;"));
+        assert!(handlers.contains("Query(scope): Query<SoftDeleteParams>,"));
+        assert!(handlers.contains("user::Entity::find_active()"));
+        assert!(handlers.contains("user::Entity::find_active_by_id(id)"));
+        // get, update and delete all skip soft-deleted rows
+        assert_eq!(
+            handlers
+                .matches("user::Entity::find_active_by_id(id)")
+                .count(),
+            3
+        );
+        assert!(handlers.contains("pub async fn restore_user("));
+        assert!(handlers.contains("active.deleted_at = Set(None);"));
+
+        let shared = &files[0].content;
+        assert!(shared.contains("pub struct SoftDeleteParams {"));
+        assert!(shared.contains("pub include_deleted: Option<bool>,"));
+
+        // Without soft delete nothing changes
+        let ctx_plain = GenerationContext::from_project_default(&setup_project());
+        let files = generate_handlers(&ctx_plain);
+        assert!(!files[0].content.contains("SoftDeleteParams"));
+        assert!(!files[1].content.contains("restore_user"));
+        assert!(!files[1].content.contains("find_active"));
+    }
+
+    #[test]
+    fn test_realtime_handlers_publish_and_stream() {
+        let mut project = setup_project();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime = true;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_handlers(&ctx);
+        let handlers = &files[1].content;
+
+        assert!(handlers.contains("use crate::realtime::{self, publish_change, ChangeKind};"));
+        assert!(handlers.contains(
+            "    publish_change(\"User\", ChangeKind::Created, &model.id);\n\n    Ok((StatusCode::CREATED"
+        ));
+        assert!(handlers.contains("publish_change(\"User\", ChangeKind::Updated, id);"));
+        assert!(handlers.contains("publish_change(\"User\", ChangeKind::Deleted, id);"));
+        assert!(handlers.contains("GET (WebSocket upgrade) /api/users/ws"));
+        assert!(handlers.contains(
+            "pub async fn user_changes(ws: axum::extract::WebSocketUpgrade) -> axum::response::Response {\n    realtime::websocket(ws, \"User\")\n}"
+        ));
+
+        // Read-only groups stream but publish nothing
+        let mut project = setup_read_only_project();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime = true;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let handlers = &generate_handlers(&ctx)[1].content;
+        assert!(handlers.contains("use crate::realtime;\n"));
+        assert!(!handlers.contains("publish_change"));
+        assert!(handlers.contains("pub async fn item_changes("));
+
+        // Not realtime: neither
+        let ctx = GenerationContext::from_project_default(&setup_project());
+        let handlers = &generate_handlers(&ctx)[1].content;
+        assert!(!handlers.contains("realtime"));
+        assert!(!handlers.contains("publish_change"));
+    }
+
+    #[test]
+    fn test_conditional_read_handlers() {
+        let mut project = setup_project();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint
+                .get_operation_mut(OperationType::Read)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::ContentHash);
+            endpoint
+                .get_operation_mut(OperationType::ReadAll)
+                .unwrap()
+                .caching = HttpCaching::etag(ETagSource::UpdatedAt).with_last_modified();
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let handlers = &generate_handlers(&ctx)[1].content;
+
+        assert!(handlers.contains("use crate::http_cache;\n"));
+        assert!(handlers.contains(
+            "    headers: axum::http::HeaderMap,\n) -> Result<axum::response::Response, AppError> {"
+        ));
+        assert!(handlers.contains(
+            "    let validators = http_cache::Validators::new()\n        .hash_body();\n    validators.respond(&headers, UserResponse::from(item))\n"
+        ));
+        // Lists take their validators from the fetched models
+        assert!(handlers.contains(
+            "    let models = paginator\n        .fetch_page(page - 1)\n        .await\n        .map_err(AppError::from)?;\n    let validators = http_cache::Validators::new()\n        .versions(models.iter().map(|m| (m.id, m.updated_at)))\n"
+        ));
+        assert!(handlers.contains(
+            "    validators.respond(&headers, PaginatedResponse::new(items, total, page, per_page))\n"
+        ));
+
+        // Actix reads the headers off the request
+        project.config.framework = WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let handlers = &generate_handlers(&ctx)[1].content;
+        assert!(handlers.contains(
+            "    request: actix_web::HttpRequest,\n) -> Result<actix_web::HttpResponse, AppError> {"
+        ));
+        assert!(handlers.contains("validators.respond(&request, UserResponse::from(item))"));
+
+        // Without caching the handlers answer with plain JSON
+        let ctx = GenerationContext::from_project_default(&setup_project());
+        let handlers = &generate_handlers(&ctx)[1].content;
+        assert!(!handlers.contains("http_cache"));
+        assert!(handlers.contains("    Ok(Json(UserResponse::from(item)))\n"));
+    }
+
+    #[test]
+    fn test_upload_handlers_store_and_set_url() {
+        let mut project = setup_project();
+        for entity in project.entities.values_mut() {
+            entity
+                .fields
+                .push(Field::new("avatar", DataType::String).with_widget(WidgetType::Image));
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let handlers = &generate_handlers(&ctx)[1].content;
+
+        assert!(handlers.contains("use crate::storage;\n"));
+        assert!(handlers.contains("POST /api/users/:id/avatar (multipart/form-data)"));
+        assert!(handlers.contains("pub async fn upload_user_avatar("));
+        assert!(handlers.contains("    multipart: axum::extract::Multipart,\n"));
+        assert!(handlers.contains("    upload.require_image()?;\n"));
+        assert!(handlers.contains("let key = upload.key(&format!(\"users/{}/avatar\", id));"));
+        assert!(handlers.contains("    active.avatar = Set(Some(url));\n"));
+        assert!(handlers.contains("    active.updated_at = Set(chrono::Utc::now());\n"));
+
+        // Without upload fields there is no storage to reach for
+        let ctx = GenerationContext::from_project_default(&setup_project());
+        let handlers = &generate_handlers(&ctx)[1].content;
+        assert!(!handlers.contains("storage"));
+        assert!(!handlers.contains("upload_"));
+    }
+
     #[test]
     fn test_read_only_handlers() {
         let project = setup_read_only_project();
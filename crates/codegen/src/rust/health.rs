@@ -0,0 +1,120 @@
+//! # Health Check Generator
+//!
+//! Generates `src/health.rs` for projects with health checks enabled
+//! ([`ObservabilityConfig::health_checks`]). The router mounts both probes
+//! at the root, outside the API routes, so they never need auth or an API
+//! version:
+//!
+//! | Route | Answers | Use |
+//! |-------|---------|-----|
+//! | `GET /healthz` | always `200` | liveness: restart the process when it stops answering |
+//! | `GET /readyz`  | `200`, or `503` while the database is unreachable | readiness: route traffic only to ready instances |
+//!
+//! The database ping comes from
+//! [`OrmBackend::database_check`](crate::rust::orm::OrmBackend::database_check),
+//! the handlers from
+//! [`FrameworkBackend::health_handlers`](crate::rust::framework::FrameworkBackend::health_handlers).
+//!
+//! [`ObservabilityConfig::health_checks`]: imortal_ir::ObservabilityConfig::health_checks
+
+use crate::context::GenerationContext;
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/health.rs`, if the project serves health checks.
+pub fn generate_health(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.health_checks() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/health.rs",
+        build_health(ctx),
+        FileType::Rust,
+    )]
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_health(ctx: &GenerationContext) -> String {
+    let framework = ctx.framework_backend();
+    let mut out = String::with_capacity(2048);
+
+    out.push_str(&file_header(
+        "Health checks — liveness and readiness probes.",
+    ));
+    out.push_str(framework.health_imports());
+    out.push_str("use crate::error::AppError;\n");
+    out.push_str("use crate::state::AppState;\n\n");
+
+    out.push_str(framework.health_handlers());
+    out.push('\n');
+    out.push_str(&ctx.orm_backend().database_check(ctx.read_replicas()));
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{ObservabilityConfig, Orm, ProjectGraph, WebFramework};
+
+    fn project(observability: ObservabilityConfig) -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        project.config.observability = observability;
+        project
+    }
+
+    #[test]
+    fn test_health_module() {
+        let project = project(ObservabilityConfig::default().with_health_checks());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_health(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str(), Some("src/health.rs"));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub async fn healthz() -> Json<Value>"));
+        assert!(content.contains(
+            "pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<Value>)"
+        ));
+        assert!(content.contains("StatusCode::SERVICE_UNAVAILABLE"));
+        assert!(content.contains("state.db.ping().await?;"));
+        assert!(!content.contains("db_read"));
+    }
+
+    #[test]
+    fn test_health_module_variants() {
+        let mut project = project(ObservabilityConfig::default().with_health_checks());
+        project.config.framework = WebFramework::ActixWeb;
+        project.config.orm = Orm::Diesel;
+        project.config.read_replicas = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_health(&ctx)[0].content;
+
+        assert!(
+            content.contains("pub async fn readyz(state: web::Data<AppState>) -> HttpResponse")
+        );
+        assert!(content.contains("HttpResponse::ServiceUnavailable()"));
+        assert!(content.contains("run(&state.db, ping).await?;"));
+        assert!(content.contains("run(&state.db_read, ping).await?;"));
+        assert!(content.contains("diesel::sql_query(\"SELECT 1\").execute(conn)?;"));
+    }
+
+    #[test]
+    fn test_no_health_module_without_health_checks() {
+        let ctx = GenerationContext::from_project_default(&project(
+            ObservabilityConfig::default().with_metrics(),
+        ));
+        assert!(generate_health(&ctx).is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+//! # HTTP Cache Generator
+//!
+//! Generates `src/http_cache.rs` for projects where some read operation
+//! sends caching headers ([`HttpCaching`]). A handler builds `Validators`
+//! from what it fetched and answers through `Validators::respond`, which
+//! sends the JSON body with the headers, or `304 Not Modified` when the
+//! request's `If-None-Match` / `If-Modified-Since` still matches.
+//!
+//! | Option | Header | Derived from |
+//! |--------|--------|--------------|
+//! | ETag from `updated_at` | `ETag: W/"…"` | key and `updated_at` of each returned row |
+//! | ETag from content hash | `ETag: "…"` | FNV-1a hash of the serialized body |
+//! | Last-Modified | `Last-Modified` | newest `updated_at` of the returned rows |
+//!
+//! Nothing is cached on the server: the body is still built for every
+//! request, the client's cache just doesn't download it again.
+//!
+//! [`HttpCaching`]: imortal_ir::HttpCaching
+
+use imortal_ir::{ETagSource, HttpCaching};
+
+use crate::FileType;
+use crate::GeneratedFile;
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::file_header;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/http_cache.rs`, if any read handler sends caching headers.
+pub fn generate_http_cache(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.http_caching_enabled() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/http_cache.rs",
+        build_http_cache(ctx),
+        FileType::Rust,
+    )]
+}
+
+/// Statement building `validators` from `rows`, an expression of
+/// `(key, updated_at)` pairs evaluated once per validator derived from it.
+pub(crate) fn validators_statement(caching: &HttpCaching, rows: &str) -> String {
+    let mut chain = String::from("http_cache::Validators::new()");
+    match caching.etag {
+        Some(ETagSource::UpdatedAt) => chain.push_str(&format!("\n        .versions({rows})")),
+        Some(ETagSource::ContentHash) => chain.push_str("\n        .hash_body()"),
+        None => {}
+    }
+    if caching.last_modified {
+        chain.push_str(&format!("\n        .last_modified({rows})"));
+    }
+    format!("    let validators = {chain};\n")
+}
+
+/// `(key, updated_at)` pairs of the models in `models` (a slice or `Vec`).
+pub(crate) fn model_versions(info: &EntityInfo, models: &str) -> String {
+    let pk = info
+        .pk()
+        .map(|f| GenerationContext::snake(&f.name))
+        .unwrap_or_else(|| "id".to_string());
+    format!("{models}.iter().map(|m| (m.{pk}, m.updated_at))")
+}
+
+/// `(key, updated_at)` pair of the single model `model`.
+pub(crate) fn model_version(info: &EntityInfo, model: &str) -> String {
+    let pk = info
+        .pk()
+        .map(|f| GenerationContext::snake(&f.name))
+        .unwrap_or_else(|| "id".to_string());
+    format!("std::iter::once(({model}.{pk}, {model}.updated_at))")
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_http_cache(ctx: &GenerationContext) -> String {
+    let framework = ctx.framework_backend();
+    let mut out = String::with_capacity(4096);
+
+    out.push_str(&file_header(
+        "HTTP caching — ETag / Last-Modified validators and conditional GETs.",
+    ));
+    out.push_str(framework.cache_imports());
+    out.push_str("use chrono::{DateTime, Utc};\n");
+    out.push_str("use serde::Serialize;\n\n");
+    out.push_str("use crate::error::AppError;\n\n");
+
+    out.push_str(
+        "\
+/// Validators of a read response: an ETag and/or a Last-Modified time.
+#[derive(Debug, Default)]
+pub struct Validators {
+    etag: Option<String>,
+    hash_body: bool,
+    last_modified: Option<DateTime<Utc>>,
+}
+
+impl Validators {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A weak ETag over the key and `updated_at` of each returned row.
+    pub fn versions<K: std::fmt::Display>(
+        mut self,
+        rows: impl IntoIterator<Item = (K, DateTime<Utc>)>,
+    ) -> Self {
+        let mut hash = Fnv1a::new();
+        for (key, updated_at) in rows {
+            hash.write(key.to_string().as_bytes());
+            hash.write(&[0]);
+            hash.write(&updated_at.timestamp_micros().to_le_bytes());
+        }
+        self.etag = Some(format!(\"W/\\\"{:016x}\\\"\", hash.finish()));
+        self
+    }
+
+    /// A strong ETag hashing the serialized response body.
+    pub fn hash_body(mut self) -> Self {
+        self.hash_body = true;
+        self
+    }
+
+    /// Last-Modified at the newest `updated_at` of the returned rows; none
+    /// for an empty list.
+    pub fn last_modified<K>(mut self, rows: impl IntoIterator<Item = (K, DateTime<Utc>)>) -> Self {
+        self.last_modified = rows.into_iter().map(|(_, updated_at)| updated_at).max();
+        self
+    }
+
+    /// The validator headers for `body`, and whether the request's
+    /// conditional headers still match them. `If-None-Match` takes
+    /// precedence over `If-Modified-Since` (RFC 9110, section 13.2.2).
+    fn check<T: Serialize>(
+        &self,
+        body: &T,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>,
+    ) -> Result<(Vec<(&'static str, String)>, bool), AppError> {
+        let etag = if self.hash_body {
+            let bytes = serde_json::to_vec(body).map_err(|e| AppError::internal(e.to_string()))?;
+            let mut hash = Fnv1a::new();
+            hash.write(&bytes);
+            Some(format!(\"\\\"{:016x}\\\"\", hash.finish()))
+        } else {
+            self.etag.clone()
+        };
+
+        let not_modified = match (if_none_match, &etag) {
+            (Some(candidates), Some(etag)) => candidates
+                .split(',')
+                .map(str::trim)
+                .any(|tag| tag == \"*\" || opaque_tag(tag) == opaque_tag(etag)),
+            (Some(_), None) => false,
+            (None, _) => match (self.last_modified, if_modified_since) {
+                (Some(modified), Some(since)) => DateTime::parse_from_rfc2822(since)
+                    .is_ok_and(|since| modified.timestamp() <= since.timestamp()),
+                _ => false,
+            },
+        };
+
+        let mut headers = Vec::new();
+        if let Some(etag) = etag {
+            headers.push((\"etag\", etag));
+        }
+        if let Some(modified) = self.last_modified {
+            headers.push((
+                \"last-modified\",
+                modified.format(\"%a, %d %b %Y %H:%M:%S GMT\").to_string(),
+            ));
+        }
+        Ok((headers, not_modified))
+    }
+}
+
+",
+    );
+    out.push_str(framework.cache_responder());
+    out.push_str(
+        "
+/// An entity tag without its weak marker, for the weak comparison
+/// `If-None-Match` uses.
+fn opaque_tag(tag: &str) -> &str {
+    tag.strip_prefix(\"W/\").unwrap_or(tag)
+}
+
+/// 64-bit FNV-1a: stable across builds and restarts, unlike
+/// `std::hash::DefaultHasher`, so ETags survive a redeploy.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+",
+    );
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{EndpointGroup, Entity, OperationType, ProjectGraph, WebFramework};
+
+    fn cached_project(caching: HttpCaching) -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        let id = project.add_entity(Entity::new("Post"));
+        let mut group = EndpointGroup::new(id, "Post");
+        group
+            .get_operation_mut(OperationType::Read)
+            .unwrap()
+            .caching = caching;
+        project.add_endpoint(group);
+        project
+    }
+
+    #[test]
+    fn test_http_cache_module() {
+        let project = cached_project(HttpCaching::etag(ETagSource::ContentHash));
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_http_cache(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str(), Some("src/http_cache.rs"));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub struct Validators {"));
+        assert!(content.contains("pub fn respond<T: Serialize>(self, headers: &HeaderMap, body: T) -> Result<Response, AppError>"));
+        assert!(content.contains("StatusCode::NOT_MODIFIED.into_response()"));
+        assert!(
+            content.contains(".any(|tag| tag == \"*\" || opaque_tag(tag) == opaque_tag(etag))")
+        );
+        assert!(content.contains("format!(\"W/\\\"{:016x}\\\"\", hash.finish())"));
+
+        let mut project = project;
+        project.config.framework = WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_http_cache(&ctx)[0].content;
+        assert!(content.contains("use actix_web::{HttpRequest, HttpResponse};"));
+        assert!(content.contains("HttpResponse::NotModified()"));
+        assert!(content.contains("response.insert_header((name, value));"));
+    }
+
+    #[test]
+    fn test_no_http_cache_without_caching() {
+        let ctx = GenerationContext::from_project_default(&cached_project(HttpCaching::default()));
+        assert!(generate_http_cache(&ctx).is_empty());
+
+        // Headers from updated_at need timestamps
+        let mut project = cached_project(HttpCaching::last_modified());
+        for entity in project.entities.values_mut() {
+            entity.config.timestamps = false;
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_http_cache(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_validators_statement() {
+        let caching = HttpCaching::etag(ETagSource::UpdatedAt).with_last_modified();
+        assert_eq!(
+            validators_statement(&caching, "rows"),
+            "    let validators = http_cache::Validators::new()\n        .versions(rows)\n        .last_modified(rows);\n"
+        );
+        assert_eq!(
+            validators_statement(&HttpCaching::etag(ETagSource::ContentHash), "rows"),
+            "    let validators = http_cache::Validators::new()\n        .hash_body();\n"
+        );
+    }
+}
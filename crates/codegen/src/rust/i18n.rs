@@ -0,0 +1,409 @@
+//! # Localization Generator
+//!
+//! Generates the message catalogs of the generated project and the module
+//! that serves them:
+//!
+//! - `locales/en.json` — the default English catalog, one template per
+//!   validation key plus the messages written on pattern rules
+//! - `src/i18n.rs` — `Accept-Language` negotiation, the per-request locale
+//!   and catalog lookup with `{param}` interpolation
+//!
+//! DTO `#[validate]` attributes carry their catalog key as the validator
+//! `code` (see [`validation_key`]), so `AppError` can render a failed rule
+//! in whichever language the request asked for. Other languages are plain
+//! `locales/{lang}.json` files with the same keys, read at start-up.
+
+use std::collections::BTreeMap;
+
+use imortal_core::Validation;
+use imortal_ir::Field;
+
+use crate::context::GenerationContext;
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile};
+
+/// Key of a combined `length(min, max)` rule.
+pub(crate) const LENGTH_BETWEEN_KEY: &str = "validation.length.between";
+
+/// Key used when a rule's own key has no message in any catalog.
+const FALLBACK_KEY: &str = "validation.invalid";
+
+/// English templates of the built-in keys. `{min}`, `{max}` and friends
+/// are the parameters `validator` records on the failed rule.
+const ENGLISH: &[(&str, &str)] = &[
+    (FALLBACK_KEY, "is invalid"),
+    (
+        "validation.length.min",
+        "must be at least {min} characters long",
+    ),
+    (
+        "validation.length.max",
+        "must be at most {max} characters long",
+    ),
+    (
+        LENGTH_BETWEEN_KEY,
+        "must be between {min} and {max} characters long",
+    ),
+    ("validation.range.min", "must be at least {min}"),
+    ("validation.range.max", "must be at most {max}"),
+    ("validation.email", "must be a valid email address"),
+    ("validation.url", "must be a valid URL"),
+    ("validation.phone", "must be a valid phone number"),
+    ("validation.pattern", "has an invalid format"),
+    ("validation.one_of", "is not one of the allowed values"),
+];
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/i18n.rs` and `locales/en.json`.
+pub fn generate_i18n(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    vec![
+        GeneratedFile::new("src/i18n.rs", build_i18n(ctx), FileType::Rust),
+        GeneratedFile::new("locales/en.json", english_catalog(ctx), FileType::Json),
+    ]
+}
+
+/// Catalog key of `validation` on `entity.field`, or `None` for rules that
+/// don't become a `#[validate]` attribute.
+///
+/// A pattern rule with its own message gets a key of its own, so the
+/// message can be translated like the built-in ones.
+pub(crate) fn validation_key(
+    entity: &str,
+    field: &Field,
+    validation: &Validation,
+) -> Option<String> {
+    let key = match validation {
+        Validation::Required | Validation::Uuid => return None,
+        Validation::MinLength(_) => "validation.length.min".to_string(),
+        Validation::MaxLength(_) => "validation.length.max".to_string(),
+        Validation::Min(_) => "validation.range.min".to_string(),
+        Validation::Max(_) => "validation.range.max".to_string(),
+        Validation::Pattern { message, .. } if !message.trim().is_empty() => format!(
+            "validation.{}.{}.pattern",
+            GenerationContext::snake(entity),
+            GenerationContext::snake(&field.name),
+        ),
+        Validation::Pattern { .. } => "validation.pattern".to_string(),
+        Validation::Email => "validation.email".to_string(),
+        Validation::Url => "validation.url".to_string(),
+        Validation::Phone => "validation.phone".to_string(),
+        Validation::OneOf(_) => "validation.one_of".to_string(),
+        Validation::Custom { name, .. } => {
+            format!("validation.custom.{}", GenerationContext::snake(name))
+        }
+    };
+    Some(key)
+}
+
+// ============================================================================
+// locales/en.json
+// ============================================================================
+
+/// The built-in templates plus every pattern message and custom rule in
+/// the project, sorted by key.
+fn english_catalog(ctx: &GenerationContext) -> String {
+    let mut catalog: BTreeMap<String, String> = ENGLISH
+        .iter()
+        .map(|(key, message)| (key.to_string(), message.to_string()))
+        .collect();
+
+    for entity in ctx.entities() {
+        for field in &entity.fields {
+            for validation in &field.validations {
+                let Some(key) = validation_key(&entity.name, field, validation) else {
+                    continue;
+                };
+                match validation {
+                    Validation::Pattern { message, .. } if !message.trim().is_empty() => {
+                        catalog.insert(key, message.trim().to_string());
+                    }
+                    Validation::Custom { name, .. } => {
+                        catalog
+                            .entry(key)
+                            .or_insert_with(|| format!("failed the {} check", name));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let mut out = serde_json::to_string_pretty(&catalog).unwrap_or_else(|_| "{}".to_string());
+    out.push('\n');
+    out
+}
+
+// ============================================================================
+// src/i18n.rs
+// ============================================================================
+
+fn build_i18n(ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(6144);
+
+    out.push_str(&file_header(
+        "Localized messages — `Accept-Language` negotiation and catalog lookup.",
+    ));
+
+    if ctx.generate_docs() {
+        out.push_str(
+            "\
+//! Catalogs are flat JSON maps from a message key to a template, one file
+//! per language in `locales/` (or `LOCALES_DIR`), read once at start-up.
+//! `en.json` is also compiled in, and any key a language lacks falls back
+//! to it. Templates refer to the failed rule's parameters as `{min}`,
+//! `{max}` and so on.
+
+",
+        );
+    }
+
+    out.push_str(
+        "\
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+/// Locale used when the request names none the server has a catalog for.
+pub const DEFAULT_LOCALE: &str = \"en\";
+
+/// The English catalog, compiled in so the server works without `locales/`.
+const DEFAULT_CATALOG: &str = include_str!(\"../locales/en.json\");
+
+type Catalog = HashMap<String, String>;
+
+tokio::task_local! {
+    static LOCALE: &'static str;
+}
+
+/// Every catalog, keyed by lower-case language tag.
+fn catalogs() -> &'static HashMap<String, Catalog> {
+    static CATALOGS: OnceLock<HashMap<String, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        let mut catalogs = HashMap::new();
+        catalogs.insert(
+            DEFAULT_LOCALE.to_string(),
+            serde_json::from_str::<Catalog>(DEFAULT_CATALOG).unwrap_or_default(),
+        );
+
+        let dir = std::env::var(\"LOCALES_DIR\").unwrap_or_else(|_| \"locales\".to_string());
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return catalogs;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_none_or(|ext| ext != \"json\") {
+                continue;
+            }
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| serde_json::from_str::<Catalog>(&text).map_err(|e| e.to_string()));
+            match parsed {
+                Ok(catalog) => catalogs
+                    .entry(locale.to_lowercase())
+                    .or_default()
+                    .extend(catalog),
+                Err(err) => tracing::warn!(\"Skipping locale file {}: {}\", path.display(), err),
+            }
+        }
+        catalogs
+    })
+}
+
+/// Pick the catalog that best matches an `Accept-Language` header such as
+/// `fr-CH, fr;q=0.9, en;q=0.8`.
+///
+/// Languages are tried in quality order, each as given and then by its
+/// primary subtag (`fr-CH` → `fr`); without a match the result is
+/// [`DEFAULT_LOCALE`].
+pub fn negotiate(accept_language: Option<&str>) -> &'static str {
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix(\"q=\"))
+                .and_then(|q| q.trim().parse().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && tag != \"*\" && quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+    // Stable, so equally preferred languages keep the header's order
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let catalogs = catalogs();
+    for (tag, _) in ranges {
+        let tag = tag.to_lowercase();
+        let primary = tag.split('-').next().unwrap_or_default();
+        for candidate in [tag.as_str(), primary] {
+            if let Some((locale, _)) = catalogs.get_key_value(candidate) {
+                return locale;
+            }
+        }
+    }
+    DEFAULT_LOCALE
+}
+
+/// Run `future` with `locale` as the current locale. The `locale`
+/// middleware wraps every request in this.
+pub async fn scope<F: Future>(locale: &'static str, future: F) -> F::Output {
+    LOCALE.scope(locale, future).await
+}
+
+/// The locale of the request being handled, or [`DEFAULT_LOCALE`] outside
+/// of one.
+pub fn current_locale() -> &'static str {
+    LOCALE.try_with(|locale| *locale).unwrap_or(DEFAULT_LOCALE)
+}
+
+/// The message for `key` in `locale` (falling back to the default
+/// catalog), with each `{name}` replaced by `params[name]`. `None` when no
+/// catalog has the key.
+pub fn translate(
+    locale: &str,
+    key: &str,
+    params: &HashMap<Cow<'static, str>, Value>,
+) -> Option<String> {
+    let catalogs = catalogs();
+    let template = catalogs
+        .get(locale)
+        .and_then(|catalog| catalog.get(key))
+        .or_else(|| catalogs.get(DEFAULT_LOCALE)?.get(key))?;
+
+    let mut message = template.clone();
+    for (name, value) in params {
+        let value = match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        message = message.replace(&format!(\"{{{}}}\", name), &value);
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_falls_back_to_default() {
+        assert_eq!(negotiate(None), DEFAULT_LOCALE);
+        assert_eq!(negotiate(Some(\"\")), DEFAULT_LOCALE);
+        assert_eq!(negotiate(Some(\"xx-YY, *\")), DEFAULT_LOCALE);
+    }
+
+    #[test]
+    fn test_negotiate_matches_primary_subtag() {
+        assert_eq!(negotiate(Some(\"xx;q=0.9, en-GB;q=0.8\")), \"en\");
+    }
+
+    #[test]
+    fn test_translate_interpolates_params() {
+        let mut params = HashMap::new();
+        params.insert(Cow::from(\"max\"), Value::from(100));
+        assert_eq!(
+            translate(DEFAULT_LOCALE, \"validation.length.max\", &params).as_deref(),
+            Some(\"must be at most 100 characters long\"),
+        );
+        assert_eq!(translate(DEFAULT_LOCALE, \"no.such.key\", &params), None);
+    }
+
+    #[test]
+    fn test_current_locale_outside_a_request() {
+        assert_eq!(current_locale(), DEFAULT_LOCALE);
+    }
+}
+",
+    );
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{Entity, ProjectGraph};
+
+    fn catalog_for(project: &ProjectGraph) -> serde_json::Map<String, serde_json::Value> {
+        let ctx = GenerationContext::from_project_default(project);
+        let files = generate_i18n(&ctx);
+        let json = files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "locales/en.json")
+            .expect("locales/en.json not generated");
+        serde_json::from_str(&json.content).unwrap()
+    }
+
+    #[test]
+    fn test_generate_i18n_files() {
+        let project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_i18n(&ctx);
+
+        let paths: Vec<String> = files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(paths, vec!["src/i18n.rs", "locales/en.json"]);
+
+        let module = &files[0].content;
+        assert!(module.contains("include_str!(\"../locales/en.json\")"));
+        assert!(module.contains("pub fn negotiate(accept_language: Option<&str>) -> &'static str"));
+        assert!(module.contains("pub fn current_locale() -> &'static str"));
+        assert!(module.contains("pub fn translate("));
+    }
+
+    #[test]
+    fn test_english_catalog_has_every_builtin_key() {
+        let catalog = catalog_for(&ProjectGraph::new("test"));
+        for (key, message) in ENGLISH {
+            assert_eq!(catalog.get(*key).and_then(|v| v.as_str()), Some(*message));
+        }
+    }
+
+    #[test]
+    fn test_pattern_message_gets_its_own_key() {
+        let mut project = ProjectGraph::new("test");
+        let mut user = Entity::new("User");
+        let mut handle = Field::new("handle", DataType::String);
+        handle.validations.push(Validation::Pattern {
+            regex: "^[a-z]+$".into(),
+            message: "may only contain lower-case letters".into(),
+        });
+        let key = validation_key("User", &handle, &handle.validations[0]).unwrap();
+        assert_eq!(key, "validation.user.handle.pattern");
+        user.fields.push(handle);
+        project.add_entity(user);
+
+        let catalog = catalog_for(&project);
+        assert_eq!(
+            catalog.get(&key).and_then(|v| v.as_str()),
+            Some("may only contain lower-case letters")
+        );
+    }
+
+    #[test]
+    fn test_validation_key_skips_type_checked_rules() {
+        let field = Field::new("id", DataType::Uuid);
+        assert_eq!(validation_key("User", &field, &Validation::Uuid), None);
+        assert_eq!(validation_key("User", &field, &Validation::Required), None);
+        assert_eq!(
+            validation_key("User", &field, &Validation::Email).as_deref(),
+            Some("validation.email")
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! # Background Jobs Generator
+//!
+//! Generates `src/jobs.rs` when the project enables background jobs: a
+//! process-wide queue drained by a bounded pool of tokio worker tasks, and
+//! a schedule that enqueues maintenance jobs every `JOB_INTERVAL_SECS`.
+//!
+//! Each entity with soft delete gets a `PurgeDeletedPosts`-style job that
+//! permanently removes rows soft-deleted longer ago than
+//! `SOFT_DELETE_RETENTION_DAYS`; other work is added as further `Job`
+//! variants. `main.rs` starts the workers once the database is connected,
+//! and any code can queue work with `jobs::enqueue`.
+//!
+//! Jobs live in memory, so queued jobs that haven't run are lost on
+//! shutdown — scheduled jobs simply run again on the next start.
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+/// Jobs that may wait for a worker before `enqueue` refuses more.
+const QUEUE_CAPACITY: usize = 1024;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/jobs.rs`, if the project runs background jobs.
+pub fn generate_jobs(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.background_jobs() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/jobs.rs",
+        build_jobs(ctx),
+        FileType::Rust,
+    )]
+}
+
+/// Whether any entity gets a scheduled purge job, which is what the
+/// `JOB_INTERVAL_SECS` and `SOFT_DELETE_RETENTION_DAYS` settings are for.
+pub fn has_scheduled_jobs(ctx: &GenerationContext) -> bool {
+    ctx.background_jobs() && !purge_jobs(ctx).is_empty()
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+/// A scheduled purge of one soft-deleting entity's old rows.
+struct PurgeJob {
+    /// `Job` variant, e.g. `PurgeDeletedPosts`.
+    variant: String,
+    /// Name used in logs, e.g. `purge_deleted_posts`.
+    name: String,
+    /// Model module, e.g. `post`.
+    module: String,
+    /// Plural shown in the doc comment, e.g. `posts`.
+    plural: String,
+}
+
+fn purge_jobs(ctx: &GenerationContext) -> Vec<PurgeJob> {
+    ctx.entities()
+        .iter()
+        .map(|entity| EntityInfo::new(entity, ctx))
+        .filter(|info| info.has_soft_delete())
+        .map(|info| {
+            let plural = info.plural_name();
+            PurgeJob {
+                variant: format!("PurgeDeleted{}", GenerationContext::pascal(&plural)),
+                name: format!("purge_deleted_{}", plural),
+                module: info.module_name(),
+                plural: plural.replace('_', " "),
+            }
+        })
+        .collect()
+}
+
+fn build_jobs(ctx: &GenerationContext) -> String {
+    let jobs = purge_jobs(ctx);
+    let scheduled = !jobs.is_empty();
+    let mut out = String::with_capacity(4096);
+
+    out.push_str(&file_header(
+        "Background jobs — a queue drained by worker tasks, and scheduled maintenance.",
+    ));
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    out.push_str("use std::sync::{Arc, OnceLock};\n");
+    if scheduled {
+        out.push_str("use std::time::Duration;\n\n");
+        out.push_str(
+            "use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};\n",
+        );
+    } else {
+        out.push_str("\nuse sea_orm::{DatabaseConnection, DbErr};\n");
+    }
+    out.push_str("use tokio::sync::{mpsc, Semaphore};\n\n");
+    out.push_str("use crate::config::Config;\n\n");
+
+    out.push_str(&format!(
+        "/// Jobs that may wait for a worker before `enqueue` refuses more.\nconst QUEUE_CAPACITY: usize = {};\n\n",
+        QUEUE_CAPACITY
+    ));
+
+    // ── Job enum ─────────────────────────────────────────────────────────
+    let job_doc = if scheduled {
+        "A unit of background work."
+    } else {
+        "A unit of background work. Add a variant per kind of job, with its\nwork in [`Job::run`]."
+    };
+    out.push_str(&doc_comment(Some(job_doc), ctx));
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub enum Job {\n");
+    for job in &jobs {
+        out.push_str(&format!(
+            "    /// Permanently delete {} soft-deleted more than the retention period ago.\n    {},\n",
+            job.plural, job.variant
+        ));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl Job {\n");
+    if scheduled {
+        out.push_str("    /// Jobs enqueued every `JOB_INTERVAL_SECS`.\n");
+        out.push_str(&format!(
+            "    pub const SCHEDULED: &'static [Job] = &[{}];\n\n",
+            jobs.iter()
+                .map(|j| format!("Job::{}", j.variant))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    out.push_str("    /// Name used in logs.\n    pub fn name(&self) -> &'static str {\n        match *self {\n");
+    for job in &jobs {
+        out.push_str(&format!(
+            "            Job::{} => \"{}\",\n",
+            job.variant, job.name
+        ));
+    }
+    out.push_str("        }\n    }\n\n");
+
+    let (db, config) = if scheduled {
+        ("db", "config")
+    } else {
+        ("_db", "_config")
+    };
+    out.push_str(&format!(
+        "    /// Do the work, returning how many rows it touched.\n    async fn run(self, {}: &DatabaseConnection, {}: &Config) -> Result<u64, DbErr> {{\n        match self {{\n",
+        db, config
+    ));
+    for job in &jobs {
+        out.push_str(&format!(
+            "\
+            Job::{variant} => {{
+                let cutoff = chrono::Utc::now()
+                    - chrono::Duration::days(config.soft_delete_retention_days);
+                let result = crate::models::{module}::Entity::delete_many()
+                    .filter(crate::models::{module}::Column::DeletedAt.lt(cutoff))
+                    .exec(db)
+                    .await?;
+                Ok(result.rows_affected)
+            }}
+",
+            variant = job.variant,
+            module = job.module,
+        ));
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    // ── Queue ────────────────────────────────────────────────────────────
+    out.push_str(&doc_comment(Some("Why a job could not be queued."), ctx));
+    out.push_str(
+        "\
+#[derive(Debug, thiserror::Error)]
+pub enum EnqueueError {
+    #[error(\"background jobs have not been started\")]
+    NotStarted,
+    #[error(\"the job queue is full\")]
+    Full,
+}
+
+/// Process-wide, so any code can queue work without threading a handle.
+static QUEUE: OnceLock<mpsc::Sender<Job>> = OnceLock::new();
+
+",
+    );
+    out.push_str(&doc_comment(
+        Some("Queue a job for the workers. Fails rather than waits when the queue is full."),
+        ctx,
+    ));
+    out.push_str(
+        "\
+pub fn enqueue(job: Job) -> Result<(), EnqueueError> {
+    let queue = QUEUE.get().ok_or(EnqueueError::NotStarted)?;
+    queue.try_send(job).map_err(|_| EnqueueError::Full)
+}
+
+",
+    );
+
+    // ── Workers and schedule ─────────────────────────────────────────────
+    let start_doc = if scheduled {
+        "Start the workers (`JOB_WORKERS` jobs at a time) and the schedule.\nCall once, from `main`."
+    } else {
+        "Start the workers (`JOB_WORKERS` jobs at a time). Call once, from `main`."
+    };
+    out.push_str(&doc_comment(Some(start_doc), ctx));
+    out.push_str(
+        "\
+pub fn start(db: DatabaseConnection, config: &Config) {
+    let (tx, mut rx) = mpsc::channel::<Job>(QUEUE_CAPACITY);
+    if QUEUE.set(tx).is_err() {
+        tracing::warn!(\"background jobs already started\");
+        return;
+    }
+
+    let workers = Arc::new(Semaphore::new(config.job_workers.max(1)));
+    let worker_config = config.clone();
+    tokio::spawn(async move {
+        while let Some(job) = rx.recv().await {
+            let Ok(permit) = workers.clone().acquire_owned().await else {
+                break;
+            };
+            let db = db.clone();
+            let config = worker_config.clone();
+            tokio::spawn(async move {
+                let _permit = permit;
+                match job.run(&db, &config).await {
+                    Ok(rows) => tracing::info!(job = job.name(), rows, \"job finished\"),
+                    Err(e) => tracing::error!(job = job.name(), error = %e, \"job failed\"),
+                }
+            });
+        }
+    });
+",
+    );
+    if scheduled {
+        out.push_str(
+            "
+    let interval = Duration::from_secs(config.job_interval_secs.max(1));
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            for job in Job::SCHEDULED {
+                if let Err(e) = enqueue(*job) {
+                    tracing::warn!(job = job.name(), error = %e, \"could not schedule job\");
+                }
+            }
+        }
+    });
+",
+        );
+    }
+    out.push_str("}\n");
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{Entity, ProjectGraph};
+
+    fn blog(soft_delete: bool) -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        project.config.background_jobs = true;
+        let mut post = Entity::new("BlogPost");
+        post.config.soft_delete = soft_delete;
+        project.add_entity(post);
+        project.add_entity(Entity::new("User"));
+        project
+    }
+
+    #[test]
+    fn test_purge_job_per_soft_deleting_entity() {
+        let project = blog(true);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(has_scheduled_jobs(&ctx));
+        let files = generate_jobs(&ctx);
+        assert_eq!(files.len(), 1);
+        let content = &files[0].content;
+
+        assert!(content.contains(
+            "    /// Permanently delete blog posts soft-deleted more than the retention period ago.\n    PurgeDeletedBlogPosts,\n"
+        ));
+        assert!(!content.contains("PurgeDeletedUsers"));
+        assert!(
+            content
+                .contains("pub const SCHEDULED: &'static [Job] = &[Job::PurgeDeletedBlogPosts];")
+        );
+        assert!(content.contains("Job::PurgeDeletedBlogPosts => \"purge_deleted_blog_posts\","));
+        assert!(
+            content.contains(".filter(crate::models::blog_post::Column::DeletedAt.lt(cutoff))")
+        );
+        assert!(content.contains("Semaphore::new(config.job_workers.max(1))"));
+        assert!(content.contains("Duration::from_secs(config.job_interval_secs.max(1))"));
+        assert!(content.contains("pub fn enqueue(job: Job) -> Result<(), EnqueueError> {"));
+    }
+
+    #[test]
+    fn test_queue_without_scheduled_jobs() {
+        let project = blog(false);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!has_scheduled_jobs(&ctx));
+        let content = &generate_jobs(&ctx)[0].content;
+
+        assert!(content.contains("pub enum Job {\n}"));
+        assert!(content.contains("async fn run(self, _db: &DatabaseConnection, _config: &Config)"));
+        assert!(!content.contains("SCHEDULED"));
+        assert!(!content.contains("job_interval_secs"));
+        assert!(content.contains("pub fn start(db: DatabaseConnection, config: &Config) {"));
+    }
+
+    #[test]
+    fn test_no_jobs_unless_enabled() {
+        let mut project = blog(true);
+        project.config.background_jobs = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_jobs(&ctx).is_empty());
+    }
+}
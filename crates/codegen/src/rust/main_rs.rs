@@ -6,10 +6,12 @@
 //! 1. Loads environment variables from `.env` via `dotenvy`
 //! 2. Initialises structured logging with `tracing_subscriber`
 //! 3. Reads configuration from environment (`Config::from_env()`)
-//! 4. Connects to the database via SeaORM
+//! 4. Connects to the database through the selected ORM backend
 //! 5. Builds the application state (`AppState`)
-//! 6. Assembles the Axum router (with middleware, CORS, auth layers)
-//! 7. Binds a TCP listener and starts the server
+//! 6. Assembles the application (with middleware, CORS, auth layers) for the
+//!    selected web framework
+//! 7. Binds a TCP listener and starts the server (`axum::serve` or
+//!    Actix's `HttpServer`)
 //!
 //! ## Generated Code Structure
 //!
@@ -57,13 +59,68 @@ fn build_main(ctx: &GenerationContext) -> String {
         imortal_ir::DatabaseType::SQLite => "SQLite",
     };
 
+    // With read replicas a second pool serves list/get handlers.
+    let orm = ctx.orm_backend();
+    let framework = ctx.framework_backend();
+    let (read_connect, new_state) = if ctx.read_replicas() {
+        (
+            format!(
+                r#"
+    tracing::info!("connecting to read replica…");
+    let db_read = {}
+        .context("failed to connect to read replica")?;
+    tracing::info!("read replica connection established");
+"#,
+                orm.connect_expr(true)
+            ),
+            "AppState::new(db, db_read, config.clone())",
+        )
+    } else {
+        (String::new(), "AppState::new(db, config.clone())")
+    };
+
+    // Background jobs share the primary pool and start before the server
+    // accepts requests, so handlers can enqueue straight away.
+    let start_jobs = if ctx.background_jobs() {
+        r#"
+    tracing::info!(workers = config.job_workers, "starting background jobs");
+    jobs::start(db.clone(), &config);
+"#
+    } else {
+        ""
+    };
+
+    // With telemetry the tracer provider is kept so buffered spans can be
+    // flushed on the way out.
+    let (init, shutdown) = if ctx.telemetry() {
+        (
+            "let tracer_provider = init_tracing()?;",
+            r#"
+    // Flush spans still buffered in the batch exporter
+    tracer_provider
+        .shutdown()
+        .context("failed to flush traces")?;
+"#,
+        )
+    } else {
+        ("init_tracing();", "")
+    };
+
     let mut out = String::with_capacity(4096);
 
     out.push_str(&file_header(&format!("{} — application entry point.", pkg)));
 
     // ── Imports ──────────────────────────────────────────────────────────
     out.push_str("use anyhow::Context;\n");
-    out.push_str("use sea_orm::Database;\n");
+    out.push_str(orm.main_imports());
+    out.push_str(framework.server_imports());
+    if ctx.telemetry() {
+        out.push_str("use opentelemetry::KeyValue;\n");
+        out.push_str("use opentelemetry::trace::TracerProvider as _;\n");
+        out.push_str(
+            "use opentelemetry_sdk::{propagation::TraceContextPropagator, runtime, trace::TracerProvider, Resource};\n",
+        );
+    }
     out.push_str(
         "use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};\n",
     );
@@ -72,7 +129,14 @@ fn build_main(ctx: &GenerationContext) -> String {
     // Import from the crate itself (the generated lib.rs)
     let crate_ident = pkg.replace('-', "_");
     out.push_str(&format!("use {}::config::Config;\n", crate_ident));
-    out.push_str(&format!("use {}::routes::create_router;\n", crate_ident));
+    out.push_str(&format!(
+        "use {}::routes::{};\n",
+        crate_ident,
+        framework.app_builder()
+    ));
+    if ctx.background_jobs() {
+        out.push_str(&format!("use {}::jobs;\n", crate_ident));
+    }
     out.push_str(&format!("use {}::state::AppState;\n", crate_ident));
     out.push_str("\n");
 
@@ -87,7 +151,7 @@ async fn main() -> anyhow::Result<()> {{
     dotenvy::dotenv().ok();
 
     // ── 2. Initialise structured logging ─────────────────────────────
-    init_tracing();
+    {init}
 
     tracing::info!(
         name = "{}",
@@ -105,45 +169,40 @@ async fn main() -> anyhow::Result<()> {{
 
     // ── 4. Connect to database ───────────────────────────────────────
     tracing::info!("connecting to {} database…");
-    let db = Database::connect(config.database_connect_options())
-        .await
+    let db = {connect}
         .context("failed to connect to database")?;
     tracing::info!("database connection established");
-
+{read_connect}{start_jobs}
     // ── 5. Build application state ───────────────────────────────────
-    let state = AppState::new(db, config.clone());
-
-    // ── 6. Build router ──────────────────────────────────────────────
-    let router = create_router(state);
-
-    // ── 7. Start server ──────────────────────────────────────────────
-    let bind_addr = config.bind_address();
-    tracing::info!(address = %bind_addr, "starting HTTP server");
-
-    let listener = tokio::net::TcpListener::bind(&bind_addr)
-        .await
-        .with_context(|| format!("failed to bind to {{}}", bind_addr))?;
-
-    tracing::info!(
-        address = %bind_addr,
-        "server is ready — listening for connections",
-    );
-
-    axum::serve(listener, router)
-        .await
-        .context("server error")?;
+    let state = {new_state};
 
+{serve}{shutdown}
     Ok(())
 }}
 "#,
-        pkg, db_name, host, port, pkg, db_name,
+        pkg,
+        db_name,
+        host,
+        port,
+        pkg,
+        db_name,
+        connect = orm.connect_expr(false),
+        read_connect = read_connect,
+        start_jobs = start_jobs,
+        new_state = new_state,
+        init = init,
+        serve = framework.serve(),
+        shutdown = shutdown,
     ));
 
     out.push('\n');
 
     // ── init_tracing helper ──────────────────────────────────────────────
-    out.push_str(
-        r#"/// Initialise the `tracing` subscriber with an env-filter.
+    if ctx.telemetry() {
+        out.push_str(&INIT_TRACING_OTEL.replace("{log_filter}", orm.log_filter()));
+    } else {
+        out.push_str(
+            &r#"/// Initialise the `tracing` subscriber with an env-filter.
 ///
 /// The log level is controlled by the `RUST_LOG` environment variable.
 /// If not set it defaults to `info` for application logs and `warn` for
@@ -158,7 +217,7 @@ async fn main() -> anyhow::Result<()> {{
 fn init_tracing() {
     let filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| {
-            EnvFilter::new("info,tower_http=debug,sea_orm=info")
+            EnvFilter::new("{log_filter}")
         });
 
     tracing_subscriber::registry()
@@ -170,8 +229,10 @@ fn init_tracing() {
             .with_line_number(false))
         .init();
 }
-"#,
-    );
+"#
+            .replace("{log_filter}", orm.log_filter()),
+        );
+    }
 
     out.push('\n');
 
@@ -198,6 +259,59 @@ fn print_banner() {{
     out
 }
 
+/// `init_tracing` for projects with telemetry: the usual fmt layer plus an
+/// OpenTelemetry layer exporting spans over OTLP/gRPC.
+const INIT_TRACING_OTEL: &str = r#"/// Initialise the `tracing` subscriber with an env-filter and an
+/// OpenTelemetry layer that exports spans over OTLP.
+///
+/// The exporter follows the standard OpenTelemetry environment variables,
+/// so traces can go straight to Jaeger, Tempo or a collector:
+///
+/// ```bash
+/// OTEL_EXPORTER_OTLP_ENDPOINT=http://localhost:4317 \
+/// OTEL_SERVICE_NAME=my-api \
+/// cargo run
+/// ```
+///
+/// Incoming W3C `traceparent` headers are honoured, so spans join the
+/// caller's trace.
+fn init_tracing() -> anyhow::Result<TracerProvider> {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| {
+            EnvFilter::new("{log_filter}")
+        });
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .build()
+        .context("failed to build the OTLP span exporter")?;
+    let service_name = std::env::var("OTEL_SERVICE_NAME")
+        .unwrap_or_else(|_| env!("CARGO_PKG_NAME").to_string());
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new([
+            KeyValue::new("service.name", service_name),
+            KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+        ]))
+        .build();
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_thread_ids(false)
+            .with_file(false)
+            .with_line_number(false))
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .init();
+
+    Ok(provider)
+}
+"#;
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -274,6 +388,54 @@ mod tests {
         assert!(content.contains("AppState::new(db, config.clone())"));
     }
 
+    #[test]
+    fn test_main_connects_read_replica() {
+        let mut project = ProjectGraph::new("test");
+        project.config.read_replicas = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_main(&ctx);
+        let content = &files[0].content;
+
+        assert!(content.contains("Database::connect(config.database_read_connect_options())"));
+        assert!(content.contains("AppState::new(db, db_read, config.clone())"));
+    }
+
+    #[test]
+    fn test_main_starts_background_jobs() {
+        let mut project = ProjectGraph::new("test");
+        let content = &generate_main(&GenerationContext::from_project_default(&project))[0].content;
+        assert!(!content.contains("jobs::start"));
+
+        project.config.background_jobs = true;
+        let content = &generate_main(&GenerationContext::from_project_default(&project))[0].content;
+        assert!(content.contains("use my_app::jobs;\n"));
+        assert!(content.contains(
+            "    jobs::start(db.clone(), &config);\n\n    // ── 5. Build application state"
+        ));
+    }
+
+    #[test]
+    fn test_main_exports_traces_with_telemetry() {
+        let mut project = ProjectGraph::new("test");
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_main(&ctx);
+        let content = &files[0].content;
+
+        assert!(content.contains("let tracer_provider = init_tracing()?;"));
+        assert!(content.contains("fn init_tracing() -> anyhow::Result<TracerProvider>"));
+        assert!(content.contains("opentelemetry_otlp::SpanExporter::builder()"));
+        assert!(content.contains("set_text_map_propagator(TraceContextPropagator::new())"));
+        assert!(content.contains(".with(tracing_opentelemetry::layer().with_tracer(tracer))"));
+        assert!(content.contains("tracer_provider\n        .shutdown()"));
+
+        let plain = generate_main(&GenerationContext::from_project_default(
+            &ProjectGraph::new("test"),
+        ));
+        assert!(plain[0].content.contains("    init_tracing();"));
+        assert!(!plain[0].content.contains("opentelemetry"));
+    }
+
     #[test]
     fn test_main_creates_router() {
         let project = ProjectGraph::new("test");
@@ -302,7 +464,9 @@ mod tests {
         let files = generate_main(&ctx);
         let content = &files[0].content;
 
-        assert!(content.contains("axum::serve(listener, router)"));
+        assert!(content.contains(
+            "axum::serve(listener, router.into_make_service_with_connect_info::<std::net::SocketAddr>())"
+        ));
     }
 
     #[test]
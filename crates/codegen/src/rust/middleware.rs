@@ -5,6 +5,9 @@
 //!
 //! - **Request logging**: structured tracing of method, path, status, and latency
 //! - **Request ID**: injects a unique `X-Request-Id` header into every response
+//! - **Locale**: negotiates `Accept-Language` so error messages are localized
+//! - **HTTP spans** (telemetry only): an OpenTelemetry server span per routed
+//!   request, following the HTTP semantic conventions
 //!
 //! These middleware are applied globally via the router in `routes/mod.rs`.
 //!
@@ -24,7 +27,8 @@ use crate::{FileType, GeneratedFile};
 /// Generate the middleware module for the generated project.
 ///
 /// Produces a single file `src/middleware.rs` containing request logging
-/// and request-ID middleware.
+/// and request-ID middleware, plus the HTTP span middleware when telemetry
+/// is enabled.
 pub fn generate_middleware(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     let content = build_middleware(ctx);
     vec![GeneratedFile::new(
@@ -38,23 +42,67 @@ pub fn generate_middleware(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 // Builder
 // ============================================================================
 
+/// Doc comment of the `locale` middleware, shared with the Actix Web
+/// version.
+pub(crate) const LOCALE_DOC: &str = "\
+/// Middleware that picks the language of error messages from the request's
+/// `Accept-Language` header.
+///
+/// Errors built while the request is handled use the negotiated locale (see
+/// `crate::i18n`); error responses name it in `Content-Language`.
+";
+
 fn build_middleware(ctx: &GenerationContext) -> String {
     let mut out = String::with_capacity(4096);
 
     out.push_str(&file_header("Custom middleware for request processing."));
 
     // ── Imports ──────────────────────────────────────────────────────────
-    out.push_str(
+    let extract = if ctx.telemetry() {
+        "extract::{MatchedPath, Request}"
+    } else {
+        "extract::Request"
+    };
+    out.push_str(&format!(
         "\
-use axum::{
-    extract::Request,
-    http::HeaderValue,
+use axum::{{
+    {extract},
+    http::{{header, HeaderValue}},
     middleware::Next,
     response::Response,
-};
+}};
+"
+    ));
+    let version_header = ctx
+        .api_versioning()
+        .header_name()
+        .filter(|_| ctx.rest_enabled());
+    if version_header.is_some() {
+        out.push_str(
+            "\
+use axum::http::{StatusCode, Uri};
+use axum::response::IntoResponse;
+use axum::Router;
+use tower::ServiceExt;
+",
+        );
+    }
+    if ctx.telemetry() {
+        out.push_str(
+            "\
+use opentelemetry_http::HeaderExtractor;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+",
+        );
+    }
+    out.push_str(
+        "\
 use std::time::Instant;
 use uuid::Uuid;
 
+use crate::i18n;
+
 ",
     );
 
@@ -157,6 +205,112 @@ pub async fn request_id(
 ",
     );
 
+    // ── Locale middleware ─────────────────────────────────────────────────
+    if ctx.generate_docs() {
+        out.push_str(LOCALE_DOC);
+    }
+    out.push_str(
+        "\
+pub async fn locale(
+    request: Request,
+    next: Next,
+) -> Response {
+    let locale = i18n::negotiate(
+        request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok()),
+    );
+
+    let mut response = i18n::scope(locale, next.run(request)).await;
+
+    if response.status().is_client_error() || response.status().is_server_error() {
+        if let Ok(value) = HeaderValue::from_str(locale) {
+            response.headers_mut().insert(header::CONTENT_LANGUAGE, value);
+        }
+    }
+
+    response
+}
+
+",
+    );
+
+    // ── API version header ────────────────────────────────────────────────
+    if let Some(header) = version_header {
+        out.push_str(&api_version_fallback(
+            header,
+            ctx.api_versioning().default_version().unwrap_or_default(),
+            ctx,
+        ));
+    }
+
+    // ── HTTP server spans (telemetry) ─────────────────────────────────────
+    if ctx.telemetry() {
+        if ctx.generate_docs() {
+            out.push_str(
+                "\
+/// Middleware that wraps each routed request in an OpenTelemetry HTTP
+/// server span.
+///
+/// The span is named `{method} {route}` and carries the semantic-convention
+/// fields `http.request.method`, `http.route`, `url.path` and
+/// `http.response.status_code`; 5xx responses mark it as an error. A W3C
+/// `traceparent` header makes it part of the caller's trace.
+///
+/// `require_auth` records `enduser.id` and `enduser.role` on this span for
+/// authenticated routes.
+",
+            );
+        }
+
+        out.push_str(
+            "\
+pub async fn http_span(
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+
+    let span = tracing::info_span!(
+        \"http_request\",
+        otel.name = %format!(\"{} {}\", method, route),
+        otel.kind = \"server\",
+        otel.status_code = tracing::field::Empty,
+        http.request.method = %method,
+        http.route = %route,
+        url.path = %request.uri().path(),
+        http.response.status_code = tracing::field::Empty,
+        enduser.id = tracing::field::Empty,
+        enduser.role = tracing::field::Empty,
+    );
+
+    // Join the caller's trace when the request carries one
+    let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent);
+
+    let response = next.run(request).instrument(span.clone()).await;
+
+    let status = response.status();
+    span.record(\"http.response.status_code\", status.as_u16());
+    if status.is_server_error() {
+        span.record(\"otel.status_code\", \"ERROR\");
+    }
+
+    response
+}
+
+",
+        );
+    }
+
     // ── Request body size limit (optional helper) ────────────────────────
     if ctx.generate_docs() {
         out.push_str(
@@ -201,6 +355,74 @@ mod tests {
     out
 }
 
+/// The router fallback behind header versioning: an `/api` request that
+/// matched no route is re-dispatched on its versioned path.
+fn api_version_fallback(header: &str, default_version: &str, ctx: &GenerationContext) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "\
+/// Header naming the API version a request wants.
+pub const API_VERSION_HEADER: &str = \"{}\";
+
+/// Version served to requests without the header.
+pub const DEFAULT_API_VERSION: &str = \"{}\";
+
+",
+        header.to_ascii_lowercase(),
+        default_version
+    ));
+
+    if ctx.generate_docs() {
+        out.push_str(&format!(
+            "\
+/// Router fallback that serves an `/api` request from the versioned routes,
+/// for the version in its `{header}` header (`{default_version}` when absent):
+/// `GET /api/users` with `{header}: v2` is handled as `GET /api/v2/users`.
+///
+/// Unknown versions and paths outside `/api` answer 404.
+"
+        ));
+    }
+
+    out.push_str(
+        "\
+pub async fn api_version(versioned: Router, mut request: Request) -> Response {
+    let version = request
+        .headers()
+        .get(API_VERSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or(DEFAULT_API_VERSION);
+    let valid = !version.is_empty()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+
+    let uri = request
+        .uri()
+        .path()
+        .strip_prefix(\"/api\")
+        .filter(|rest| valid && (rest.is_empty() || rest.starts_with('/')))
+        .map(|rest| match request.uri().query() {
+            Some(query) => format!(\"/api/{}{}?{}\", version, rest, query),
+            None => format!(\"/api/{}{}\", version, rest),
+        })
+        .and_then(|uri| uri.parse::<Uri>().ok());
+    let Some(uri) = uri else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    *request.uri_mut() = uri;
+    match versioned.oneshot(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
+",
+    );
+    out
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -260,6 +482,17 @@ mod tests {
         assert!(content.contains("10 * 1024 * 1024"));
     }
 
+    #[test]
+    fn test_middleware_has_locale() {
+        let project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_middleware(&ctx)[0].content;
+
+        assert!(content.contains("pub async fn locale("));
+        assert!(content.contains("i18n::scope(locale, next.run(request)).await"));
+        assert!(content.contains("header::CONTENT_LANGUAGE"));
+    }
+
     #[test]
     fn test_middleware_has_imports() {
         let project = ProjectGraph::new("test");
@@ -276,6 +509,47 @@ mod tests {
         assert!(content.contains("HeaderValue"));
     }
 
+    #[test]
+    fn test_middleware_http_span_with_telemetry() {
+        let project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_middleware(&ctx)[0].content;
+        assert!(!content.contains("http_span"));
+        assert!(!content.contains("MatchedPath"));
+
+        let mut project = ProjectGraph::new("test");
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_middleware(&ctx)[0].content;
+
+        assert!(content.contains("extract::{MatchedPath, Request},"));
+        assert!(content.contains("pub async fn http_span("));
+        assert!(content.contains("otel.kind = \"server\""));
+        assert!(content.contains("http.request.method = %method"));
+        assert!(content.contains("http.route = %route"));
+        assert!(content.contains("enduser.role = tracing::field::Empty"));
+        assert!(content.contains("span.set_parent(parent);"));
+        assert!(content.contains("span.record(\"http.response.status_code\", status.as_u16());"));
+    }
+
+    #[test]
+    fn test_middleware_api_version_fallback() {
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!build_middleware(&ctx).contains("api_version"));
+
+        project.config.api_versioning = imortal_ir::ApiVersioning::header("v1");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = build_middleware(&ctx);
+        assert!(content.contains("pub const API_VERSION_HEADER: &str = \"x-api-version\";"));
+        assert!(content.contains("pub const DEFAULT_API_VERSION: &str = \"v1\";"));
+        assert!(
+            content.contains("pub async fn api_version(versioned: Router, mut request: Request)")
+        );
+        assert!(content.contains("versioned.oneshot(request).await"));
+        assert!(content.contains("use tower::ServiceExt;"));
+    }
+
     #[test]
     fn test_middleware_has_file_header() {
         let project = ProjectGraph::new("test");
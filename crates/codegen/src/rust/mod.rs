@@ -12,13 +12,22 @@
 //! ```text
 //! generate_rust_project
 //!   ├── cargo::generate_cargo_toml
-//!   ├── models::generate_models
-//!   ├── handlers::generate_handlers
-//!   ├── routes::generate_routes
+//!   ├── OrmBackend::generate_models    (SeaORM or Diesel, see [`orm`])
+//!   ├── OrmBackend::generate_handlers  (+ queries, if custom queries defined; REST only)
+//!   ├── graphql::generate_graphql      (if the API style serves GraphQL)
+//!   ├── realtime::generate_realtime    (if an entity pushes changes to subscribers)
+//!   ├── jobs::generate_jobs            (if background jobs enabled)
+//!   ├── storage::generate_storage      (if an entity has File/Image fields)
+//!   ├── http_cache::generate_http_cache (if a read operation sends ETag/Last-Modified)
+//!   ├── rate_limit::generate_rate_limit (if a route or the project sets a rate limit)
+//!   ├── health::generate_health        (if health checks enabled)
+//!   ├── FrameworkBackend::generate_routes      (Axum or Actix Web, see [`framework`])
 //!   ├── auth::generate_auth           (if auth enabled)
-//!   ├── middleware::generate_middleware
+//!   ├── FrameworkBackend::generate_middleware
 //!   ├── config::generate_config
+//!   ├── constants::generate_constants (timeouts, route paths, page sizes, auth defaults)
 //!   ├── error::generate_error
+//!   ├── i18n::generate_i18n           (message catalogs + locale negotiation)
 //!   ├── main_rs::generate_main
 //!   └── tests::generate_tests         (if tests enabled)
 //! ```
@@ -31,14 +40,19 @@
 //! ├── .env.example
 //! ├── .gitignore
 //! ├── README.md
+//! ├── locales/
+//! │   └── en.json       (default message catalog)
 //! ├── src/
 //! │   ├── main.rs
 //! │   ├── lib.rs
 //! │   ├── config.rs
+//! │   ├── constants.rs
 //! │   ├── error.rs
+//! │   ├── i18n.rs
 //! │   ├── state.rs
 //! │   ├── models/
 //! │   │   ├── mod.rs
+//! │   │   ├── enums.rs     (if the project has enum types)
 //! │   │   └── {entity}.rs  (one per entity)
 //! │   ├── handlers/
 //! │   │   ├── mod.rs
@@ -49,24 +63,51 @@
 //! │   └── auth/             (if auth enabled)
 //! │       ├── mod.rs
 //! │       ├── jwt.rs
-//! │       └── middleware.rs
+//! │       ├── roles.rs
+//! │       ├── middleware.rs
+//! │       ├── oauth.rs      (if OAuth2 providers configured)
+//! │       └── sessions.rs   (if refresh tokens enabled)
 //! └── tests/
 //!     └── api_tests.rs
 //! ```
 
+pub mod actix;
 pub mod auth;
 pub mod cargo;
+pub mod computed;
 pub mod config;
+pub mod constants;
+pub mod diesel;
+pub mod enums;
 pub mod error;
+pub mod framework;
+pub mod graphql;
 pub mod handlers;
+pub mod health;
+pub mod http_cache;
+pub mod i18n;
+pub mod jobs;
 pub mod main_rs;
 pub mod middleware;
 pub mod models;
+pub mod oauth;
+pub mod orm;
+pub mod queries;
+pub mod rate_limit;
+pub mod realtime;
 pub mod routes;
+pub mod sessions;
+pub mod storage;
 pub mod test_gen;
 
-use crate::context::GenerationContext;
+use crate::context::{EntityInfo, GenerationContext};
+use crate::regions;
 use crate::{FileType, GeneratedFile};
+use imortal_ir::{
+    ApiStyle, ETagSource, OAuthProvider, OperationType, RealtimeTransport, UploadStorage,
+    WidgetType,
+};
+use serde::Serialize;
 
 // ============================================================================
 // Orchestrator
@@ -88,6 +129,7 @@ use crate::{FileType, GeneratedFile};
 /// A `Vec<GeneratedFile>` containing every file that should be written to
 /// disk for the generated Rust project.
 pub fn generate_rust_project(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    let orm = ctx.orm_backend();
     let mut files: Vec<GeneratedFile> = Vec::new();
 
     // ── Project scaffolding ──────────────────────────────────────────────
@@ -98,18 +140,50 @@ pub fn generate_rust_project(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 
     // ── Core source modules ──────────────────────────────────────────────
     files.extend(config::generate_config(ctx));
+    files.extend(constants::generate_constants(ctx));
     files.extend(error::generate_error(ctx));
-    files.extend(generate_state(ctx));
+    files.extend(i18n::generate_i18n(ctx));
+    files.push(orm.generate_state(ctx));
     files.extend(generate_lib_rs(ctx));
+    files.extend(orm.scaffolding(ctx));
 
-    // ── Models (SeaORM entities + DTOs) ──────────────────────────────────
-    files.extend(models::generate_models(ctx));
+    // ── Models (ORM entities + DTOs) ─────────────────────────────────────
+    files.extend(orm.generate_models(ctx).into_iter().map(with_custom_region));
 
-    // ── Handlers (Axum request handlers) ─────────────────────────────────
-    files.extend(handlers::generate_handlers(ctx));
+    // ── Handlers (request handlers + custom queries) ─────────────────────
+    if ctx.rest_enabled() {
+        files.extend(
+            orm.generate_handlers(ctx)
+                .into_iter()
+                .map(with_custom_region),
+        );
+    }
+
+    // ── GraphQL schema (async-graphql) ───────────────────────────────────
+    if ctx.graphql_enabled() {
+        files.extend(graphql::generate_graphql(ctx));
+    }
+
+    // ── Realtime change feed ─────────────────────────────────────────────
+    files.extend(realtime::generate_realtime(ctx));
+
+    // ── Background jobs ──────────────────────────────────────────────────
+    files.extend(jobs::generate_jobs(ctx));
+
+    // ── Upload storage ───────────────────────────────────────────────────
+    files.extend(storage::generate_storage(ctx));
+
+    // ── Conditional GETs ─────────────────────────────────────────────────
+    files.extend(http_cache::generate_http_cache(ctx));
+
+    // ── Rate limiting ────────────────────────────────────────────────────
+    files.extend(rate_limit::generate_rate_limit(ctx));
+
+    // ── Health checks ────────────────────────────────────────────────────
+    files.extend(health::generate_health(ctx));
 
-    // ── Routes (Axum router) ─────────────────────────────────────────────
-    files.extend(routes::generate_routes(ctx));
+    // ── Routes (Axum router / Actix route tables) ────────────────────────
+    files.extend(ctx.framework_backend().generate_routes(ctx));
 
     // ── Authentication (JWT / middleware) ─────────────────────────────────
     if ctx.auth_enabled() {
@@ -117,7 +191,7 @@ pub fn generate_rust_project(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     }
 
     // ── Generic middleware ────────────────────────────────────────────────
-    files.extend(middleware::generate_middleware(ctx));
+    files.extend(ctx.framework_backend().generate_middleware(ctx));
 
     // ── main.rs (entry point) ────────────────────────────────────────────
     files.extend(main_rs::generate_main(ctx));
@@ -130,6 +204,19 @@ pub fn generate_rust_project(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     files
 }
 
+/// End a per-entity module (not a `mod.rs`) with an empty protected region
+/// for hand-written items.
+fn with_custom_region(mut file: GeneratedFile) -> GeneratedFile {
+    if file.file_type == FileType::Rust && !file.path.ends_with("mod.rs") {
+        let end = file.content.trim_end_matches('\n').len();
+        file.content.truncate(end);
+        file.content.push_str("\n\n");
+        file.content
+            .push_str(&regions::keep_region(regions::CUSTOM_REGION, "//"));
+    }
+    file
+}
+
 // ============================================================================
 // Inline small generators (files that don't warrant their own module)
 // ============================================================================
@@ -138,12 +225,38 @@ pub fn generate_rust_project(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 fn generate_lib_rs(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     let mut modules = vec![
         "pub mod config;",
+        "pub mod constants;",
         "pub mod error;",
+        "pub mod i18n;",
         "pub mod state;",
         "pub mod models;",
-        "pub mod handlers;",
-        "pub mod routes;",
     ];
+    if ctx.rest_enabled() {
+        modules.push("pub mod handlers;");
+    }
+    if ctx.graphql_enabled() {
+        modules.push("pub mod graphql;");
+    }
+    if ctx.realtime_enabled() {
+        modules.push("pub mod realtime;");
+    }
+    if ctx.background_jobs() {
+        modules.push("pub mod jobs;");
+    }
+    if ctx.uploads_enabled() {
+        modules.push("pub mod storage;");
+    }
+    if ctx.http_caching_enabled() {
+        modules.push("pub mod http_cache;");
+    }
+    if ctx.rate_limiting_enabled() {
+        modules.push("pub mod rate_limit;");
+    }
+    if ctx.health_checks() {
+        modules.push("pub mod health;");
+    }
+    modules.push("pub mod routes;");
+    modules.extend(ctx.orm_backend().lib_modules());
 
     if ctx.auth_enabled() {
         modules.push("pub mod auth;");
@@ -168,126 +281,281 @@ fn generate_lib_rs(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     vec![GeneratedFile::new("src/lib.rs", content, FileType::Rust)]
 }
 
-/// Generate `src/state.rs` — the shared application state (database pool, config).
-fn generate_state(ctx: &GenerationContext) -> Vec<GeneratedFile> {
-    let mut content = String::with_capacity(1024);
+/// Variables of the `scaffold/` templates (see [`templates`](crate::templates))
+#[derive(Debug, Clone, Serialize)]
+pub struct ScaffoldContext {
+    /// Crate name
+    pub package: String,
+    /// Address the server binds to
+    pub host: String,
+    /// Port the server listens on
+    pub port: u16,
+    /// Example connection URL for the project's database
+    pub database_url: String,
+    /// Whether list/get requests can go to a read replica
+    pub read_replicas: bool,
+    /// Whether authentication is enabled
+    pub auth: bool,
+    /// Lifetime of issued tokens
+    pub token_expiry_hours: u32,
+    /// OAuth providers, as in the project file (`kind`, `name`,
+    /// `issuer_url`, `scopes`)
+    pub oauth_providers: Vec<OAuthProvider>,
+    /// Whether OpenTelemetry export is enabled
+    pub telemetry: bool,
+    /// Whether background jobs are enabled
+    pub background_jobs: bool,
+    /// Whether any job runs on a schedule
+    pub scheduled_jobs: bool,
+    /// Whether any entity has upload fields
+    pub uploads: bool,
+    /// `local` or `s3`
+    pub upload_storage: UploadStorage,
+}
 
-    if ctx.generate_docs() {
-        content.push_str("//! Application state shared across all handlers.\n\n");
+impl ScaffoldContext {
+    /// The variables for `ctx`.
+    pub fn new(ctx: &GenerationContext) -> Self {
+        Self {
+            package: ctx.package_name().to_string(),
+            host: ctx.server_host().to_string(),
+            port: ctx.server_port(),
+            database_url: ctx.example_database_url(),
+            read_replicas: ctx.read_replicas(),
+            auth: ctx.auth_enabled(),
+            token_expiry_hours: ctx.auth_config().token_expiry_hours,
+            oauth_providers: ctx.oauth_providers().to_vec(),
+            telemetry: ctx.telemetry(),
+            background_jobs: ctx.background_jobs(),
+            scheduled_jobs: ctx.background_jobs() && jobs::has_scheduled_jobs(ctx),
+            uploads: ctx.uploads_enabled(),
+            upload_storage: ctx.uploads().storage,
+        }
     }
+}
 
-    content.push_str("use sea_orm::DatabaseConnection;\n");
-    content.push_str("use crate::config::Config;\n\n");
+/// Generate `.env.example` with sensible defaults.
+fn generate_dotenv(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    let content = ctx.render("scaffold/env.example.jinja", ScaffoldContext::new(ctx));
+    vec![GeneratedFile::new(".env.example", content, FileType::Env)]
+}
 
-    content.push_str(
-        "\
-/// Shared application state available in every Axum handler via `State<AppState>`.
-#[derive(Clone)]
-pub struct AppState {
-    /// SeaORM database connection pool.
-    pub db: DatabaseConnection,
-    /// Application configuration.
-    pub config: Config,
+/// Generate `.gitignore` for a Rust project.
+fn generate_gitignore(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    let content = ctx.render("scaffold/gitignore.jinja", ScaffoldContext::new(ctx));
+    vec![GeneratedFile::new(".gitignore", content, FileType::Other)]
 }
 
-impl AppState {
-    /// Create a new `AppState`.
-    pub fn new(db: DatabaseConnection, config: Config) -> Self {
-        Self { db, config }
+/// README section listing what deleting each entity does to the records
+/// that reference it (empty when there are no foreign keys).
+fn readme_delete_section(ctx: &GenerationContext) -> String {
+    let mut body = String::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        if info.has_soft_delete() {
+            continue;
+        }
+        let effects = info.delete_effects();
+        if effects.is_empty() {
+            continue;
+        }
+        body.push_str(&format!(
+            "\n### {}\n\nDeleting a `{}`:\n\n",
+            entity.name, entity.name
+        ));
+        for effect in &effects {
+            body.push_str(&format!("- {}\n", effect.describe()));
+        }
     }
+
+    if body.is_empty() {
+        return String::new();
+    }
+
+    let blocked_note = if ctx.report_delete_blockers() {
+        "Refused deletes answer `409 Conflict` with a `blocking` list of the referencing records.\n"
+    } else {
+        "Refused deletes surface as database errors.\n"
+    };
+    format!(
+        "## Delete Behaviour\n\nDerived from each foreign key's `ON DELETE` action. {blocked_note}{body}"
+    )
 }
-",
-    );
 
-    vec![GeneratedFile::new("src/state.rs", content, FileType::Rust)]
+/// README section listing the REST routes streaming record changes (empty
+/// when no entity streams them).
+fn readme_realtime_section(ctx: &GenerationContext) -> String {
+    let mut streams = String::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        let Some(transport) = info.live_transport() else {
+            continue;
+        };
+        let what = match transport {
+            RealtimeTransport::WebSocket => "WebSocket",
+            RealtimeTransport::Sse => "server-sent events",
+        };
+        streams.push_str(&format!(
+            "- `{}` — {} at `GET {}/{}`\n",
+            entity.name,
+            what,
+            info.base_path(),
+            transport.path()
+        ));
+    }
+    if streams.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "\
+## Realtime
+
+Every create, update, delete and restore of these entities is pushed to
+open streams as JSON, e.g. `{{\"entity\":\"Post\",\"kind\":\"created\",\"id\":\"…\"}}`:
+
+{streams}
+A stream shares the authentication of the entity's list (else get) endpoint. Browsers
+can't send an `Authorization` header when opening a `WebSocket` or an
+`EventSource`, so open secured streams from a client that can. A client
+that falls far behind skips the changes it missed.
+
+"
+    )
 }
 
-/// Generate `.env.example` with sensible defaults.
-fn generate_dotenv(ctx: &GenerationContext) -> Vec<GeneratedFile> {
-    let db_url = ctx.example_database_url();
-    let host = ctx.server_host();
-    let port = ctx.server_port();
+/// README section listing the read routes that answer conditional requests
+/// (empty when none send caching headers).
+fn readme_caching_section(ctx: &GenerationContext) -> String {
+    let mut routes = String::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        for (op, path) in [
+            (OperationType::ReadAll, info.base_path()),
+            (OperationType::Read, format!("{}/:id", info.base_path())),
+        ] {
+            let Some(caching) = info.caching(op) else {
+                continue;
+            };
+            let mut headers = Vec::new();
+            match caching.etag {
+                Some(ETagSource::UpdatedAt) => headers.push("`ETag` (from `updated_at`)"),
+                Some(ETagSource::ContentHash) => headers.push("`ETag` (body hash)"),
+                None => {}
+            }
+            if caching.last_modified {
+                headers.push("`Last-Modified`");
+            }
+            routes.push_str(&format!("- `GET {}` — {}\n", path, headers.join(", ")));
+        }
+    }
+    if routes.is_empty() {
+        return String::new();
+    }
 
-    let mut content = String::with_capacity(512);
+    format!(
+        "\
+## Conditional Requests
 
-    content.push_str(
-        "# =============================================================================\n",
-    );
-    content.push_str(&format!(
-        "# {} — Environment Variables\n",
-        ctx.package_name()
-    ));
-    content.push_str(
-        "# =============================================================================\n",
-    );
-    content.push_str("# Copy this file to `.env` and fill in the values.\n\n");
+These routes send caching headers and answer `304 Not Modified`, with no
+body, while the client's `If-None-Match` (or `If-Modified-Since`) still
+matches:
 
-    content.push_str(
-        "# ── Server ────────────────────────────────────────────────────────────────────\n",
-    );
-    content.push_str(&format!("SERVER_HOST={}\n", host));
-    content.push_str(&format!("SERVER_PORT={}\n", port));
-    content.push_str("RUST_LOG=info\n\n");
+{routes}
+ETags from `updated_at` only notice changes made through the API, which
+keeps `updated_at` current; lists cover the rows of the returned page.
 
-    content.push_str(
-        "# ── Database ──────────────────────────────────────────────────────────────────\n",
-    );
-    content.push_str(&format!("DATABASE_URL={}\n", db_url));
-    content.push_str("DATABASE_MAX_CONNECTIONS=10\n");
-    content.push_str("DATABASE_MIN_CONNECTIONS=1\n\n");
+"
+    )
+}
 
-    if ctx.auth_enabled() {
-        content.push_str(
-            "# ── Authentication ────────────────────────────────────────────────────────────\n",
+/// README section listing the health and metrics routes (empty when the
+/// project serves neither).
+fn readme_observability_section(ctx: &GenerationContext) -> String {
+    let mut routes = String::new();
+    if ctx.health_checks() {
+        routes.push_str("- `GET /healthz` — liveness: `200` while the process is up\n");
+        routes.push_str(
+            "- `GET /readyz` — readiness: `200` while the database answers, `503` otherwise\n",
+        );
+    }
+    if ctx.metrics() {
+        routes.push_str(
+            "- `GET /metrics` — request counts and latencies in the Prometheus text format\n",
         );
-        content.push_str("JWT_SECRET=change-me-to-a-long-random-string\n");
-        content.push_str(&format!(
-            "JWT_EXPIRY_HOURS={}\n\n",
-            ctx.auth_config().token_expiry_hours
-        ));
+    }
+    if routes.is_empty() {
+        return String::new();
     }
 
-    vec![GeneratedFile::new(".env.example", content, FileType::Env)]
+    format!(
+        "\
+## Health & Metrics
+
+Served at the root, without authentication:
+
+{routes}
+Point orchestrator probes and your Prometheus scrape config at these paths.
+
+"
+    )
 }
 
-/// Generate `.gitignore` for a Rust project.
-fn generate_gitignore(_ctx: &GenerationContext) -> Vec<GeneratedFile> {
-    let content = "\
-# Rust
-/target/
-**/*.rs.bk
-Cargo.lock
-
-# Environment
-.env
-.env.local
-.env.*.local
-
-# IDE
-.idea/
-.vscode/
-*.swp
-*.swo
-*~
-
-# OS
-.DS_Store
-Thumbs.db
-
-# Logs
-*.log
-";
-
-    vec![GeneratedFile::new(
-        ".gitignore",
-        content.to_string(),
-        FileType::Other,
-    )]
+/// README section listing the upload routes (empty when there are none).
+fn readme_uploads_section(ctx: &GenerationContext) -> String {
+    let mut routes = String::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        for field in info.upload_fields() {
+            let images = if field.ui_hints.widget == Some(WidgetType::Image) {
+                " (images only)"
+            } else {
+                ""
+            };
+            routes.push_str(&format!(
+                "- `POST {}/:id/{}` — `{}.{}`{}\n",
+                info.base_path(),
+                GenerationContext::snake(&field.name),
+                entity.name,
+                field.name,
+                images
+            ));
+        }
+    }
+    if routes.is_empty() {
+        return String::new();
+    }
+
+    let storage = match ctx.uploads().storage {
+        UploadStorage::Local => {
+            "Files are written to `UPLOAD_DIR` and served from `/uploads`;\n\
+             `UPLOAD_BASE_URL` prefixes the stored URLs."
+        }
+        UploadStorage::S3 => {
+            "Files are put in the `S3_BUCKET` bucket at `S3_ENDPOINT` with path-style\n\
+             addressing, so MinIO and R2 work too; `S3_PUBLIC_URL` prefixes the stored\n\
+             URLs."
+        }
+    };
+
+    format!(
+        "\
+## File Uploads
+
+Send a `multipart/form-data` body with one file part to an upload route.
+The file is stored and the record comes back with the field set to its URL:
+
+{routes}
+Uploads are limited to {max} MB and share the authentication of the
+entity's update endpoint. {storage}
+
+",
+        max = ctx.uploads().max_size_mb,
+    )
 }
 
 /// Generate `README.md` with project overview and setup instructions.
 fn generate_readme(ctx: &GenerationContext) -> Vec<GeneratedFile> {
-    let pkg = ctx.package_name();
     let db_name = match ctx.database() {
         imortal_ir::DatabaseType::PostgreSQL => "PostgreSQL",
         imortal_ir::DatabaseType::MySQL => "MySQL",
@@ -314,18 +582,252 @@ Protected endpoints require a valid `Authorization: Bearer <token>` header.
         String::new()
     };
 
+    let replica_section = if ctx.read_replicas() {
+        "\
+## Read Replicas
+
+Reads and writes use separate connection pools:
+
+- `DATABASE_URL` — the primary; every create, update and delete goes here
+- `DATABASE_READ_URL` — a read replica used by list and get endpoints
+  (falls back to `DATABASE_URL` when unset)
+
+### Consistency caveats
+
+Replication is asynchronous, so the replica can lag behind the primary:
+
+- A record read straight after it was written may be missing or stale.
+  Create and update responses are built from the primary, so use them
+  rather than re-fetching when you need the new state.
+- Two list requests can disagree if they hit replicas at different points.
+- Custom code that needs read-your-writes should query `state.db`
+  instead of `state.db_read`.
+"
+    } else {
+        ""
+    };
+
+    let telemetry_section = if ctx.telemetry() {
+        "\
+## Tracing
+
+The server exports OpenTelemetry traces over OTLP/gRPC to
+`OTEL_EXPORTER_OTLP_ENDPOINT` (Jaeger, Tempo or a collector), under the
+service name in `OTEL_SERVICE_NAME`. Each request gets:
+
+- an HTTP server span named `METHOD /route`, with `http.request.method`,
+  `http.route`, `url.path`, `http.response.status_code` and, on
+  authenticated routes, `enduser.id` and `enduser.role`
+- a child span per handler with `app.entity`, `app.operation`,
+  `app.auth_required` and, for single-record operations, `app.record_id`
+
+Incoming `traceparent` headers are honoured, so calls from other traced
+services show up in the same trace.
+"
+    } else {
+        ""
+    };
+
+    let jobs_section = if jobs::has_scheduled_jobs(ctx) {
+        "\
+## Background Jobs
+
+`src/jobs.rs` runs a job queue on `JOB_WORKERS` tokio tasks. Queue work from
+anywhere with `jobs::enqueue(Job::…)`; add a `Job` variant for each new kind
+of job.
+
+Every `JOB_INTERVAL_SECS` the server purges rows soft-deleted more than
+`SOFT_DELETE_RETENTION_DAYS` ago, one job per soft-deleting entity. Queued
+jobs live in memory and are dropped on shutdown.
+"
+    } else if ctx.background_jobs() {
+        "\
+## Background Jobs
+
+`src/jobs.rs` runs a job queue on `JOB_WORKERS` tokio tasks. Add a `Job`
+variant for each kind of job and queue it from anywhere with
+`jobs::enqueue(Job::…)`. Queued jobs live in memory and are dropped on
+shutdown.
+"
+    } else {
+        ""
+    };
+
+    let seed_section = if ctx.generate_seeds() {
+        format!(
+            "\
+## Seed Data
+
+`seeds/` holds fake records for every entity, one SQL file per table in
+foreign-key order. Load them into a freshly migrated database with:
+
+```bash
+cargo run --bin seed
+```
+
+The files run in one transaction. Seeded accounts log in with the password
+`{}`. Record counts come from each entity's seed count in the project.
+
+",
+            crate::seeds::SEED_PASSWORD
+        )
+    } else {
+        String::new()
+    };
+
+    let load_test_section = if ctx.generate_load_tests() {
+        format!(
+            "\
+## Load Tests
+
+`{script}` is a [k6](https://k6.io) script calling every endpoint, one scenario per
+entity. Each scenario ramps up to 80% of its tightest rate limit, holds, then
+ramps down; p95 latency over `P95_MS` (500 ms) fails the run.
+
+```bash
+k6 run {script}                   # against http://{host}:{port}
+BASE_URL=https://staging.example.com AUTH_TOKEN=… k6 run {script}
+k6 run -e PROFILE=smoke {script}  # one pass over every endpoint
+```
+
+",
+            script = crate::loadtest::SCRIPT_PATH,
+            host = crate::loadtest::client_host(ctx.server_host()),
+            port = ctx.server_port()
+        )
+    } else {
+        String::new()
+    };
+
+    let sdk_section = if crate::sdk::generates_sdk(ctx) {
+        format!(
+            "\
+## Client SDK
+
+`{dir}/` at the project root is a standalone `{package}` crate for calling this
+API from Rust. `ApiClient` has one async method per endpoint, taking and
+returning the same request and response types; failed calls return
+`ClientError::Api` with the status, code and detail of the problem details.
+
+```rust
+let client = {krate}::ApiClient::new()
+    .with_base_url(\"http://{host}:{port}\")
+    .with_token(token);
+```
+
+",
+            dir = crate::sdk::SDK_DIR,
+            package = crate::sdk::sdk_package_name(ctx),
+            krate = crate::sdk::sdk_crate_name(ctx),
+            host = crate::loadtest::client_host(ctx.server_host()),
+            port = ctx.server_port(),
+        )
+    } else {
+        String::new()
+    };
+
+    let ts_client_section = if crate::typescript::generates_ts_client(ctx) {
+        format!(
+            "\
+## TypeScript Client
+
+`{dir}/` holds TypeScript interfaces for every request and response body and
+a `fetch`-based client with a class per endpoint group. Errors are thrown as
+`ApiError`, carrying the problem details.
+
+```ts
+import {{ ApiClient }} from './{dir}';
+
+const api = new ApiClient({{ baseUrl: 'http://{host}:{port}', token }});
+```
+
+",
+            dir = crate::typescript::TS_CLIENT_DIR,
+            host = crate::loadtest::client_host(ctx.server_host()),
+            port = ctx.server_port(),
+        )
+    } else {
+        String::new()
+    };
+
+    let delete_section = readme_delete_section(ctx);
+    let graphql_section = if ctx.graphql_enabled() {
+        "\
+## GraphQL
+
+`POST /graphql` serves queries and mutations for every entity with enabled
+endpoints; open `/graphql` in a browser for GraphiQL. Entities marked
+realtime stream their record changes over the WebSocket at `/graphql/ws`;
+send the bearer token as `Authorization` in the `connection_init` payload.
+Relations are resolved through batched dataloaders, and queries are limited
+to a depth of 10.
+
+"
+    } else {
+        ""
+    };
+    let realtime_section = readme_realtime_section(ctx);
+    let uploads_section = readme_uploads_section(ctx);
+    let caching_section = readme_caching_section(ctx);
+    let observability_section = readme_observability_section(ctx);
+    let api_name = match (ctx.rest_enabled(), ctx.graphql_enabled()) {
+        (true, true) => ApiStyle::Both,
+        (false, true) => ApiStyle::Graphql,
+        _ => ApiStyle::Rest,
+    }
+    .display_name();
+
+    let meta = &ctx.meta;
+    let description = match meta.description.as_deref().map(str::trim) {
+        Some(desc) if !desc.is_empty() => format!("{}\n\n", desc),
+        _ => String::new(),
+    };
+
+    let mut meta_rows = format!("| Version        | {}       |\n", meta.version);
+    let authors = meta.authors();
+    if !authors.is_empty() {
+        meta_rows.push_str(&format!(
+            "| Authors        | {}       |\n",
+            authors.join(", ")
+        ));
+    }
+    let license = meta
+        .license
+        .as_deref()
+        .map(str::trim)
+        .filter(|l| !l.is_empty());
+    if let Some(license) = license {
+        meta_rows.push_str(&format!("| License        | {}       |\n", license));
+    }
+    if let Some(repo) = meta
+        .repository
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+    {
+        meta_rows.push_str(&format!("| Repository     | <{}>     |\n", repo));
+    }
+
+    let license_section = match license {
+        Some(license) => format!("Licensed under `{}`.", license),
+        None => {
+            "This project was generated with Immortal Engine. Use it however you like.".to_string()
+        }
+    };
+
     let content = format!(
         "\
-# {pkg}
+# {title}
 
-> Auto-generated by **Immortal Engine v2.0**
+{description}> Auto-generated by **Immortal Engine v2.0**
 
 ## Overview
 
 | Property       | Value       |
 |----------------|-------------|
-| Framework      | Axum        |
-| ORM            | SeaORM      |
+{meta_rows}| Framework      | {framework_name}        |
+| API            | {api_name}        |
+| ORM            | {orm_name}      |
 | Database       | {db_name}   |
 | Entities       | {entity_count}          |
 | Endpoint Groups| {endpoint_count}          |
@@ -340,7 +842,7 @@ cp .env.example .env
 $EDITOR .env
 
 # 3. Run database migrations
-# (Apply the SQL files in the migrations/ directory)
+{migrate_step}
 
 # 4. Build and run
 cargo run
@@ -348,7 +850,33 @@ cargo run
 
 The server will start on `http://{host}:{port}`.
 
-{auth_section}
+{auth_section}{graphql_section}{realtime_section}{uploads_section}{caching_section}{replica_section}{observability_section}{telemetry_section}{jobs_section}{seed_section}{load_test_section}{sdk_section}{ts_client_section}{delete_section}
+## Errors
+
+Errors are RFC 7807 problem details (`application/problem+json`) with the
+machine-readable error code in `code`. Validation errors are rendered in
+the language the client asks for with `Accept-Language`; the response
+names it in `Content-Language`. Each rule has a message key (e.g.
+`validation.length.max`) and the `validation_error` body lists every
+failed field:
+
+```json
+{{
+  \"type\": \"about:blank\",
+  \"title\": \"Unprocessable Entity\",
+  \"status\": 422,
+  \"detail\": \"title: must be at most 200 characters long\",
+  \"code\": \"validation_error\",
+  \"fields\": [{{ \"field\": \"title\", \"code\": \"validation.length.max\", \"message\": \"must be at most 200 characters long\" }}]
+}}
+```
+
+`locales/en.json` is the default catalog and is regenerated with the
+project. To add a language, copy it to `locales/<lang>.json` (e.g.
+`fr.json`, `pt-br.json`) and translate the values; `{{min}}`-style
+placeholders are filled in from the failed rule. Catalogs are read from
+`LOCALES_DIR` at start-up.
+
 ## Project Structure
 
 ```
@@ -356,20 +884,41 @@ src/
 ├── main.rs          # Entry point, server setup
 ├── lib.rs           # Module declarations
 ├── config.rs        # Configuration from environment
+├── constants.rs     # Timeouts, route paths, page sizes and auth defaults
 ├── error.rs         # Application error types
+├── i18n.rs          # Accept-Language negotiation and message catalogs
 ├── state.rs         # Shared application state
-├── models/          # SeaORM entity definitions & DTOs
-├── handlers/        # Axum request handlers
+├── models/          # {orm_name} models & DTOs
+├── handlers/        # {framework_name} request handlers
 ├── routes/          # Route definitions
 └── middleware/       # Custom middleware
 ```
 
+## Custom Code
+
+Generated files are rewritten when the project is regenerated, except
+between protected region markers. Each model and handler file ends with
+an empty `custom` region for your own items, and regions can be added
+anywhere else:
+
+```rust
+// <immortal:keep custom> code between these markers survives regeneration
+pub fn normalize_email(email: &str) -> String {{
+    email.trim().to_lowercase()
+}}
+// </immortal:keep>
+```
+
 ## License
 
-This project was generated with Immortal Engine. Use it however you like.
+{license_section}
 ",
+        title = meta.name,
         host = ctx.server_host(),
         port = ctx.server_port(),
+        orm_name = ctx.orm().display_name(),
+        framework_name = ctx.framework().display_name(),
+        migrate_step = ctx.orm_backend().readme_migrate_step(),
     );
 
     vec![GeneratedFile::new("README.md", content, FileType::Markdown)]
@@ -419,7 +968,8 @@ pub fn file_header(description: &str) -> String {
 //! {}
 //!
 //! Auto-generated by Immortal Engine v2.0.
-//! DO NOT EDIT — changes will be overwritten on next generation.
+//! DO NOT EDIT — changes will be overwritten on next generation, except
+//! inside protected `immortal:keep` regions.
 
 ",
         description
@@ -462,6 +1012,14 @@ mod tests {
             paths.iter().any(|p| p == ".gitignore"),
             "Missing .gitignore"
         );
+        assert!(
+            paths.iter().any(|p| p == "src/i18n.rs"),
+            "Missing src/i18n.rs"
+        );
+        assert!(
+            paths.iter().any(|p| p == "locales/en.json"),
+            "Missing locales/en.json"
+        );
         assert!(paths.iter().any(|p| p == "README.md"), "Missing README.md");
         assert!(
             paths.iter().any(|p| p == "src/main.rs"),
@@ -475,6 +1033,10 @@ mod tests {
             paths.iter().any(|p| p == "src/config.rs"),
             "Missing src/config.rs"
         );
+        assert!(
+            paths.iter().any(|p| p == "src/constants.rs"),
+            "Missing src/constants.rs"
+        );
         assert!(
             paths.iter().any(|p| p == "src/error.rs"),
             "Missing src/error.rs"
@@ -485,6 +1047,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_entity_modules_end_with_custom_region() {
+        let mut project = ProjectGraph::new("blog");
+        let user = imortal_ir::Entity::new("User");
+        let user_id = user.id;
+        project.add_entity(user);
+        project.add_endpoint(imortal_ir::EndpointGroup::new(user_id, "User"));
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_rust_project(&ctx);
+        let content = |path: &str| {
+            &files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap_or_else(|| panic!("missing {path}"))
+                .content
+        };
+
+        let region = regions::keep_region(regions::CUSTOM_REGION, "//");
+        for path in ["src/models/user.rs", "src/handlers/user.rs"] {
+            let content = content(path);
+            assert!(content.ends_with(&format!("}}\n\n{region}")), "{path}");
+        }
+        assert!(!content("src/models/mod.rs").contains(regions::KEEP_OPEN));
+        assert!(!content("src/main.rs").contains(regions::KEEP_OPEN));
+    }
+
     #[test]
     fn test_generate_lib_rs_without_auth() {
         let mut project = ProjectGraph::new("test");
@@ -518,7 +1106,7 @@ mod tests {
     fn test_generate_state() {
         let project = ProjectGraph::new("test");
         let ctx = GenerationContext::from_project_default(&project);
-        let files = generate_state(&ctx);
+        let files = vec![ctx.orm_backend().generate_state(&ctx)];
 
         assert_eq!(files.len(), 1);
         let content = &files[0].content;
@@ -527,6 +1115,56 @@ mod tests {
         assert!(content.contains("Config"));
     }
 
+    #[test]
+    fn test_generate_state_with_read_replicas() {
+        let mut project = ProjectGraph::new("test");
+        project.config.read_replicas = true;
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let state = &ctx.orm_backend().generate_state(&ctx).content;
+        assert!(state.contains("pub db_read: DatabaseConnection"));
+
+        let dotenv = &generate_dotenv(&ctx)[0].content;
+        assert!(dotenv.contains("DATABASE_READ_URL="));
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Read Replicas"));
+        assert!(readme.contains("Consistency caveats"));
+    }
+
+    #[test]
+    fn test_generate_readme_delete_behaviour() {
+        use imortal_ir::{Entity, Relationship};
+
+        let mut project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(
+            !generate_readme(&ctx)[0]
+                .content
+                .contains("## Delete Behaviour")
+        );
+
+        let user = Entity::new("User");
+        let uid = user.id;
+        project.add_entity(user);
+        let post = Entity::new("Post");
+        let pid = post.id;
+        project.add_entity(post);
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(uid, pid))
+            .unwrap();
+        project.config.report_delete_blockers = true;
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Delete Behaviour"));
+        assert!(readme.contains("### User"));
+        assert!(
+            readme.contains("- is refused while any `Post` references it via `user_id` (RESTRICT)")
+        );
+        assert!(readme.contains("`409 Conflict`"));
+    }
+
     #[test]
     fn test_generate_dotenv() {
         let project = ProjectGraph::new("myapp");
@@ -552,6 +1190,168 @@ mod tests {
         assert!(content.contains("JWT_EXPIRY_HOURS="));
     }
 
+    #[test]
+    fn test_generate_dotenv_with_oauth() {
+        let mut project = ProjectGraph::new("myapp");
+        project.config.auth = imortal_ir::AuthConfig::oauth2()
+            .with_oauth_provider(imortal_ir::OAuthProvider::google())
+            .with_oauth_provider(imortal_ir::OAuthProvider::oidc(
+                "okta",
+                "https://example.okta.com",
+            ));
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let content = &generate_dotenv(&ctx)[0].content;
+        assert!(content.contains("OAUTH_REDIRECT_BASE_URL=http://"));
+        assert!(content.contains("GOOGLE_CLIENT_ID=\nGOOGLE_CLIENT_SECRET=\n"));
+        assert!(content.contains("OKTA_ISSUER_URL=https://example.okta.com\n"));
+    }
+
+    #[test]
+    fn test_generate_telemetry_env_and_readme() {
+        let mut project = ProjectGraph::new("myapp");
+        project.config.package_name = "myapp".to_string();
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let env = &generate_dotenv(&ctx)[0].content;
+        assert!(env.contains("OTEL_EXPORTER_OTLP_ENDPOINT=http://localhost:4317"));
+        assert!(env.contains("OTEL_SERVICE_NAME=myapp"));
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Tracing"));
+        assert!(readme.contains("`enduser.role`"));
+    }
+
+    #[test]
+    fn test_generate_background_jobs_env_and_readme() {
+        use imortal_ir::Entity;
+
+        let mut project = ProjectGraph::new("myapp");
+        project.config.background_jobs = true;
+        let mut post = Entity::new("Post");
+        post.config.soft_delete = true;
+        project.add_entity(post);
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let env = &generate_dotenv(&ctx)[0].content;
+        assert!(
+            env.contains("JOB_WORKERS=4\nJOB_INTERVAL_SECS=3600\nSOFT_DELETE_RETENTION_DAYS=30\n")
+        );
+
+        let lib = &generate_lib_rs(&ctx)[0].content;
+        assert!(lib.contains("pub mod jobs;"));
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Background Jobs"));
+        assert!(readme.contains("`SOFT_DELETE_RETENTION_DAYS`"));
+    }
+
+    #[test]
+    fn test_generate_readme_lists_realtime_streams() {
+        use imortal_ir::{EndpointGroup, Entity};
+
+        let mut project = ProjectGraph::new("myapp");
+        let post = project.add_entity(Entity::new("Post"));
+        let comment = project.add_entity(Entity::new("Comment"));
+        project.add_endpoint(EndpointGroup::new(post, "Post").realtime());
+        project.add_endpoint(
+            EndpointGroup::new(comment, "Comment").realtime_over(RealtimeTransport::Sse),
+        );
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Realtime"));
+        assert!(readme.contains("- `Post` — WebSocket at `GET /api/posts/ws`\n"));
+        assert!(
+            readme.contains("- `Comment` — server-sent events at `GET /api/comments/events`\n")
+        );
+
+        let lib = &generate_lib_rs(&ctx)[0].content;
+        assert!(lib.contains("pub mod realtime;"));
+    }
+
+    #[test]
+    fn test_generate_uploads_env_and_readme() {
+        use imortal_core::DataType;
+        use imortal_ir::{EndpointGroup, Entity, Field, UploadConfig};
+
+        let mut project = ProjectGraph::new("myapp");
+        let mut user = Entity::new("User");
+        user.fields
+            .push(Field::new("avatar", DataType::String).with_widget(WidgetType::Image));
+        let user = project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user, "User"));
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let env = &generate_dotenv(&ctx)[0].content;
+        assert!(env.contains("UPLOAD_DIR=uploads\nUPLOAD_BASE_URL=/uploads\n"));
+
+        let lib = &generate_lib_rs(&ctx)[0].content;
+        assert!(lib.contains("pub mod storage;"));
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## File Uploads"));
+        assert!(readme.contains("- `POST /api/users/:id/avatar` — `User.avatar` (images only)\n"));
+        assert!(readme.contains("limited to 10 MB"));
+
+        project.config.uploads = UploadConfig::s3();
+        let ctx = GenerationContext::from_project_default(&project);
+        let env = &generate_dotenv(&ctx)[0].content;
+        assert!(env.contains("S3_BUCKET=my-app-uploads\n"));
+        assert!(!env.contains("UPLOAD_DIR"));
+        assert!(generate_readme(&ctx)[0].content.contains("`S3_PUBLIC_URL`"));
+    }
+
+    #[test]
+    fn test_generate_caching_readme() {
+        use imortal_ir::{EndpointGroup, Entity, HttpCaching};
+
+        let mut project = ProjectGraph::new("myapp");
+        let post = project.add_entity(Entity::new("Post"));
+        let mut group = EndpointGroup::new(post, "Post");
+        group
+            .get_operation_mut(OperationType::Read)
+            .unwrap()
+            .caching = HttpCaching::etag(ETagSource::UpdatedAt).with_last_modified();
+        project.add_endpoint(group);
+        let ctx = GenerationContext::from_project_default(&project);
+
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Conditional Requests"));
+        assert!(
+            readme
+                .contains("- `GET /api/posts/:id` — `ETag` (from `updated_at`), `Last-Modified`\n")
+        );
+        assert!(!readme.contains("- `GET /api/posts` —"));
+        assert!(
+            generate_lib_rs(&ctx)[0]
+                .content
+                .contains("pub mod http_cache;")
+        );
+    }
+
+    #[test]
+    fn test_generate_observability_readme() {
+        use imortal_ir::ObservabilityConfig;
+
+        let mut project = ProjectGraph::new("myapp");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(
+            !generate_readme(&ctx)[0]
+                .content
+                .contains("## Health & Metrics")
+        );
+
+        project.config.observability = ObservabilityConfig::all();
+        let ctx = GenerationContext::from_project_default(&project);
+        let readme = &generate_readme(&ctx)[0].content;
+        assert!(readme.contains("## Health & Metrics"));
+        assert!(readme.contains("- `GET /readyz` — readiness"));
+        assert!(readme.contains("- `GET /metrics` — request counts"));
+        assert!(generate_lib_rs(&ctx)[0].content.contains("pub mod health;"));
+    }
+
     #[test]
     fn test_generate_gitignore() {
         let project = ProjectGraph::new("test");
@@ -577,6 +1377,26 @@ mod tests {
         assert!(content.contains("Axum"));
         assert!(content.contains("SeaORM"));
         assert!(content.contains("Quick Start"));
+        assert!(content.contains("## Custom Code"));
+        assert!(content.contains("// <immortal:keep custom>"));
+        assert!(content.contains("| Version        | 0.1.0"));
+        assert!(content.contains("Use it however you like."));
+    }
+
+    #[test]
+    fn test_generate_readme_metadata() {
+        let mut project = ProjectGraph::new("Blog API");
+        project.meta.description = Some("Posts and comments".to_string());
+        project.meta.author = Some("Jane Doe, John Doe".to_string());
+        project.meta.license = Some("Apache-2.0".to_string());
+        project.meta.repository = Some("https://github.com/example/blog".to_string());
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_readme(&ctx)[0].content;
+
+        assert!(content.starts_with("# Blog API\n\nPosts and comments\n\n> Auto-generated"));
+        assert!(content.contains("| Authors        | Jane Doe, John Doe"));
+        assert!(content.contains("| Repository     | <https://github.com/example/blog>"));
+        assert!(content.contains("## License\n\nLicensed under `Apache-2.0`."));
     }
 
     #[test]
@@ -11,12 +11,32 @@
 //! - `src/models/user.rs` — SeaORM entity with:
 //!   - `Model` struct (`DeriveEntityModel`)
 //!   - `Relation` enum (`DeriveRelation`)
-//!   - `Related<…>` implementations
+//!   - `Related<…>` implementations, through the junction entity for a
+//!     many-to-many relationship
 //!   - `ActiveModelBehavior` implementation
-//!   - `CreateUserDto` — fields for creation, with `validator` derives
+//!   - `impl Model` with an async accessor per foreign-key relationship end,
+//!     named after the relationship's accessor names (`post.author(&db)`,
+//!     `user.posts(&db)`)
+//!   - `impl Model` with a getter per computed field not stored as a column
+//!   - per polymorphic association (e.g. `commentable`): `CommentableType`
+//!     naming the target entities, `Commentable` holding the row, and
+//!     `Entity::find_by_commentable`; each target gets an accessor for the
+//!     rows pointing at it (`post.comments(&db)`)
+//!   - `Entity::UNIQUE_TOGETHER` / `Entity::INDEXES` for composite constraints
+//!   - `CreateUserDto` — fields for creation, with `validator` derives, plus
+//!     a `Vec` of embedded children per one-to-many relationship marked for
+//!     nested creation
+//!   - `CreatePostInUserDto` — one such embedded child, without its FK
 //!   - `UpdateUserDto` — optional fields for partial update
-//!   - `UserResponse` — safe output DTO (excludes secrets)
+//!   - `impl From<CreateUserDto> for ActiveModel` and `UpdateUserDto::apply`,
+//!     which the handlers write through (only when no field needs hashing)
+//!   - `UserResponse` — safe output DTO (excludes secret and hidden fields)
 //!   - `impl From<Model> for UserResponse`
+//!   - `UserListItem` — list item with related counts / existence flags
+//!     (only when the endpoint requests them)
+//!
+//! Each entity's file is rendered from an overridable template with the
+//! variables of [`ModelContext`]; see [`templates`](crate::templates).
 //!
 //! ## Type Mapping
 //!
@@ -24,10 +44,19 @@
 //! attributes, and `validator` annotations based on the field's configuration.
 
 use imortal_core::{DataType, IdType, RelationType, Validation};
-use imortal_ir::Entity;
-
-use crate::context::{EntityInfo, GenerationContext};
-use crate::rust::{doc_comment, file_header};
+use imortal_ir::relationship_helpers::{
+    determine_fk_entity, get_fk_field_to_entity, junction_keys,
+};
+use imortal_ir::{AggregateKind, Entity, PaginationStyle};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::context::{
+    Association, EntityInfo, GenerationContext, NestedCreate, PolymorphicAssociation,
+};
+use crate::rust::handlers::{active_model_sets, link_associations};
+use crate::rust::i18n::{LENGTH_BETWEEN_KEY, validation_key};
+use crate::rust::{computed, doc_comment, enums, file_header};
 use crate::{FileType, GeneratedFile};
 
 // ============================================================================
@@ -41,6 +70,9 @@ pub fn generate_models(ctx: &GenerationContext) -> Vec<GeneratedFile> {
     // models/mod.rs
     files.push(generate_models_mod(ctx));
 
+    // models/enums.rs
+    files.extend(enums::generate_enums(ctx));
+
     // Per-entity files
     for entity in ctx.entities() {
         let info = EntityInfo::new(entity, ctx);
@@ -61,6 +93,9 @@ fn generate_models_mod(ctx: &GenerationContext) -> GeneratedFile {
         "Model definitions (SeaORM entities and DTOs).",
     ));
 
+    if !ctx.enums().is_empty() {
+        content.push_str("pub mod enums;\n");
+    }
     for entity in ctx.entities() {
         let module = GenerationContext::module_name(&entity.name);
         content.push_str(&format!("pub mod {};\n", module));
@@ -69,6 +104,7 @@ fn generate_models_mod(ctx: &GenerationContext) -> GeneratedFile {
     if !ctx.entities().is_empty() {
         content.push('\n');
         content.push_str("// Re-exports for convenience\n");
+        content.push_str(&enums::enum_reexport(ctx));
 
         for entity in ctx.entities() {
             let module = GenerationContext::module_name(&entity.name);
@@ -77,8 +113,14 @@ fn generate_models_mod(ctx: &GenerationContext) -> GeneratedFile {
             let update_dto = GenerationContext::update_dto_name(&entity.name);
             let response_dto = GenerationContext::response_dto_name(&entity.name);
 
+            let list_item = if EntityInfo::new(entity, ctx).related_aggregates().is_empty() {
+                String::new()
+            } else {
+                format!(", {}", GenerationContext::list_item_dto_name(&entity.name))
+            };
+
             content.push_str(&format!(
-                "pub use {module}::{{Model as {pascal}Model, {create_dto}, {update_dto}, {response_dto}}};\n"
+                "pub use {module}::{{Model as {pascal}Model, {create_dto}, {update_dto}, {response_dto}{list_item}}};\n"
             ));
         }
     }
@@ -91,185 +133,347 @@ fn generate_models_mod(ctx: &GenerationContext) -> GeneratedFile {
 // ============================================================================
 
 fn generate_entity_model(info: &EntityInfo, ctx: &GenerationContext) -> GeneratedFile {
-    let module = info.module_name();
-    let path = format!("src/models/{}.rs", module);
-
-    let mut content = String::with_capacity(4096);
-
-    // File header
-    content.push_str(&file_header(&format!(
-        "{} model — SeaORM entity and DTOs.",
-        info.pascal_name()
-    )));
-
-    // Imports
-    content.push_str(&generate_imports(info, ctx));
-    content.push('\n');
-
-    // SeaORM Model
-    content.push_str(&generate_model_struct(info, ctx));
-    content.push('\n');
-
-    // Relation enum
-    content.push_str(&generate_relation_enum(info, ctx));
-    content.push('\n');
-
-    // Related implementations
-    content.push_str(&generate_related_impls(info, ctx));
-
-    // ActiveModelBehavior
-    content.push_str("impl ActiveModelBehavior for ActiveModel {}\n\n");
-
-    // Separator
-    content.push_str(
-        "// ============================================================================\n",
-    );
-    content.push_str("// DTOs (Data Transfer Objects)\n");
-    content.push_str(
-        "// ============================================================================\n\n",
-    );
-
-    // Create DTO
-    content.push_str(&generate_create_dto(info, ctx));
-    content.push('\n');
+    let path = format!("src/models/{}.rs", info.module_name());
+    let content = ctx.render("rust/model.rs.jinja", ModelContext::new(info, ctx));
+    GeneratedFile::new(path, content, FileType::Rust)
+}
 
-    // Update DTO
-    content.push_str(&generate_update_dto(info, ctx));
-    content.push('\n');
+// ============================================================================
+// Template context
+// ============================================================================
 
-    // Response DTO
-    content.push_str(&generate_response_dto(info, ctx));
-    content.push('\n');
+/// Variables of the `rust/model.rs.jinja` template, which renders one
+/// entity's `src/models/{entity}.rs` (see [`templates`](crate::templates)).
+///
+/// The SeaORM entity is described field by field; the `impl` blocks and DTOs
+/// below it are passed as rendered Rust, each empty when the entity has none.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelContext {
+    /// Entity name in PascalCase
+    pub name: String,
+    /// `use` lines, one per item
+    pub imports: Vec<String>,
+    /// Doc comment lines of the `Model` struct, each ending in a newline
+    /// (empty without a description or with docs off)
+    pub doc: String,
+    /// Table name
+    pub table: String,
+    /// Database schema of the table, if not the default one
+    pub schema: Option<String>,
+    /// Columns of the `Model` struct, timestamps and `deleted_at` included
+    pub columns: Vec<ModelColumn>,
+    /// Variants of the `Relation` enum
+    pub relations: Vec<RelationVariant>,
+    /// `Related` impls, one per entity reachable from this one
+    pub related: Vec<RelatedImpl>,
+    /// `find_active` / `find_deleted` scopes of a soft-deleting entity
+    pub scopes: String,
+    /// Async accessors for the rows at the other end of each relationship
+    pub accessors: String,
+    /// Types and lookups of the polymorphic associations
+    pub polymorphic: String,
+    /// Getters of the computed fields not stored as columns
+    pub computed: String,
+    /// `Entity::UNIQUE_TOGETHER` and `Entity::INDEXES`
+    pub constraints: String,
+    /// Checks backing the DTOs' `#[validate]` attributes
+    pub validation: String,
+    /// `Create{Entity}Dto`
+    pub create_dto: String,
+    /// Payloads creating this entity inside a parent's create request
+    pub nested_create_dtos: Vec<String>,
+    /// Payloads linking rows through this entity, when it is a junction
+    pub link_dtos: Vec<String>,
+    /// `Update{Entity}Dto`
+    pub update_dto: String,
+    /// Conversions of the DTOs into an `ActiveModel`
+    pub conversions: String,
+    /// `{Entity}Response`
+    pub response_dto: String,
+    /// `impl From<Model> for {Entity}Response`
+    pub from_model: String,
+    /// `{Entity}ListItem`, with related counts and existence flags
+    pub list_item: String,
+}
 
-    // From<Model> for Response
-    content.push_str(&generate_from_model(info, ctx));
+/// One column of a `Model` struct
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelColumn {
+    /// Field name in snake_case
+    pub name: String,
+    /// Rust type
+    pub rust_type: String,
+    /// The field's description
+    pub doc: Option<String>,
+    /// Arguments of the `#[sea_orm(...)]` attribute
+    pub attrs: Vec<String>,
+    /// Whether the field is a secret kept out of serialized output
+    pub skip_serializing: bool,
+    /// Whether the column is the primary key
+    pub primary_key: bool,
+}
 
-    // Pagination params (only in first entity file to avoid duplication;
-    // ideally this goes into a shared module, but we keep it simple here)
-    // We skip it here and put it in the handlers or a shared module.
+/// One `impl Related<super::{module}::Entity> for Entity`
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedImpl {
+    /// Module of the related entity
+    pub module: String,
+    /// Relation returned by `to()`, e.g. `Relation::Author`
+    pub to: String,
+    /// Relation reversed by `via()`, for a many-to-many relationship
+    /// through its junction entity
+    pub via: Option<String>,
+}
 
-    GeneratedFile::new(path, content, FileType::Rust)
+impl ModelContext {
+    /// The variables for `info`'s model file.
+    pub fn new(info: &EntityInfo, ctx: &GenerationContext) -> Self {
+        Self {
+            name: info.pascal_name(),
+            imports: model_imports(info, ctx),
+            doc: doc_comment(info.entity.description.as_deref(), ctx),
+            table: info.table_name(),
+            schema: info.schema().map(str::to_string),
+            columns: model_columns(info),
+            relations: relation_variants(info, ctx),
+            related: related_impls(info, ctx),
+            scopes: generate_soft_delete_scopes(info, ctx),
+            accessors: generate_relation_accessors(info, ctx),
+            polymorphic: info
+                .polymorphic_associations()
+                .iter()
+                .map(|association| generate_polymorphic_types(association, ctx))
+                .collect(),
+            computed: computed::generate_getters(info, ctx, |f| {
+                matches!(f.data_type, DataType::Optional(_))
+            }),
+            constraints: generate_table_constraints(info, ctx),
+            validation: generate_validation_helpers(info),
+            create_dto: generate_create_dto(info, ctx),
+            nested_create_dtos: info
+                .nested_parents()
+                .iter()
+                .map(|nested| generate_nested_create_dto(nested, ctx))
+                .collect(),
+            link_dtos: ctx
+                .entities()
+                .iter()
+                .flat_map(|owner| link_associations(&EntityInfo::new(owner, ctx)))
+                .filter(|a| a.junction.id == info.entity.id)
+                .map(|association| generate_link_dto(&association, ctx))
+                .collect(),
+            update_dto: generate_update_dto(info, ctx),
+            conversions: generate_active_model_conversions(info, ctx),
+            response_dto: generate_response_dto(info, ctx),
+            from_model: generate_from_model(info, ctx),
+            list_item: generate_list_item_dto(info, ctx),
+        }
+    }
 }
 
 // ============================================================================
 // Imports
 // ============================================================================
 
-fn generate_imports(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let mut out = String::with_capacity(512);
-
-    out.push_str("use sea_orm::entity::prelude::*;\n");
-    out.push_str("use serde::{Deserialize, Serialize};\n");
-    out.push_str("use validator::Validate;\n");
+fn model_imports(info: &EntityInfo, ctx: &GenerationContext) -> Vec<String> {
+    let mut imports = vec![
+        "use sea_orm::entity::prelude::*;".to_string(),
+        "use serde::{Deserialize, Serialize};".to_string(),
+        "use validator::Validate;".to_string(),
+    ];
+    if converts_create(info, ctx) || converts_update(info, ctx) {
+        imports.push("use sea_orm::Set;".to_string());
+    }
 
     // Check if we need uuid
     let needs_uuid = info
         .entity
         .fields
         .iter()
-        .any(|f| matches!(f.data_type, DataType::Uuid) || f.is_primary_key);
+        .any(|f| matches!(column_type(&f.data_type), DataType::Uuid) || f.is_primary_key);
     if needs_uuid {
-        out.push_str("use uuid::Uuid;\n");
+        imports.push("use uuid::Uuid;".to_string());
     }
 
     // Check if we need chrono
     let needs_chrono = info.entity.fields.iter().any(|f| {
         matches!(
-            f.data_type,
+            column_type(&f.data_type),
             DataType::DateTime | DataType::Date | DataType::Time
         )
-    }) || info.has_timestamps();
+    }) || info.has_timestamps()
+        || info.has_soft_delete();
     if needs_chrono {
-        out.push_str("use chrono::{DateTime, Utc};\n");
+        imports.push("use chrono::{DateTime, Utc};".to_string());
     }
 
-    out.push('\n');
-    out
+    let types: Vec<String> = info
+        .entity
+        .fields
+        .iter()
+        .map(|f| data_type_to_rust(&f.data_type))
+        .collect();
+    imports.extend(
+        enums::enum_imports(ctx, "super::enums", types.iter().map(String::as_str))
+            .lines()
+            .map(str::to_string),
+    );
+
+    imports
+}
+
+/// The column's own type, without the `Optional` wrapper
+fn column_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Optional(inner) => inner.as_ref(),
+        other => other,
+    }
 }
 
 // ============================================================================
 // SeaORM Model struct
 // ============================================================================
 
-fn generate_model_struct(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let table = info.table_name();
-    let mut out = String::with_capacity(2048);
-
-    // Doc comment
-    out.push_str(&doc_comment(info.entity.description.as_deref(), ctx));
-
-    // Derive block
-    out.push_str("#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]\n");
-    out.push_str(&format!("#[sea_orm(table_name = \"{}\")]\n", table));
-    out.push_str("pub struct Model {\n");
+fn model_columns(info: &EntityInfo) -> Vec<ModelColumn> {
+    // Computed getters are methods, not columns
+    let mut columns: Vec<ModelColumn> = info
+        .entity
+        .fields
+        .iter()
+        .filter(|f| f.is_column())
+        .map(|field| {
+            let col_name = if field.column_name.is_empty() {
+                GenerationContext::snake(&field.name)
+            } else {
+                field.column_name.clone()
+            };
+            let rust_name = GenerationContext::snake(&field.name);
+
+            // SeaORM column attributes
+            let mut attrs = Vec::new();
+
+            if field.is_primary_key {
+                match info.id_type() {
+                    IdType::Serial => attrs.push("primary_key".to_string()),
+                    _ => attrs.push("primary_key, auto_increment = false".to_string()),
+                }
+            }
 
-    for field in &info.entity.fields {
-        let col_name = if field.column_name.is_empty() {
-            GenerationContext::snake(&field.name)
-        } else {
-            field.column_name.clone()
-        };
-        let rust_name = GenerationContext::snake(&field.name);
-        let rust_type = field_rust_type(field, info);
+            if col_name != rust_name {
+                attrs.push(format!("column_name = \"{}\"", col_name));
+            }
 
-        // SeaORM column attributes
-        let mut attrs = Vec::new();
+            if field.unique && !field.is_primary_key {
+                attrs.push("unique".to_string());
+            }
 
-        if field.is_primary_key {
-            match info.id_type() {
-                IdType::Serial => attrs.push("primary_key".to_string()),
-                _ => attrs.push("primary_key, auto_increment = false".to_string()),
+            // SeaORM would otherwise create the column without precision/scale
+            if let DataType::Decimal { .. } = field.data_type {
+                attrs.push(format!(
+                    "column_type = \"{}\"",
+                    field.data_type.to_sea_orm_type()
+                ));
             }
-        }
 
-        if col_name != rust_name {
-            attrs.push(format!("column_name = \"{}\"", col_name));
-        }
+            ModelColumn {
+                rust_type: field_rust_type(field, info),
+                name: rust_name,
+                doc: field.description.clone(),
+                attrs,
+                skip_serializing: field.secret,
+                primary_key: field.is_primary_key,
+            }
+        })
+        .collect();
+
+    let implied = |name: &str, rust_type: &str| ModelColumn {
+        name: name.to_string(),
+        rust_type: rust_type.to_string(),
+        doc: None,
+        attrs: Vec::new(),
+        skip_serializing: false,
+        primary_key: false,
+    };
+    let declared = |name: &str| info.entity.fields.iter().any(|f| f.name == name);
 
-        if field.unique && !field.is_primary_key {
-            attrs.push("unique".to_string());
+    // Add timestamp fields if enabled and not already present
+    if info.has_timestamps() {
+        if !declared("created_at") {
+            columns.push(implied("created_at", "DateTimeUtc"));
         }
-
-        // Field-level doc comment
-        if let Some(desc) = &field.description {
-            out.push_str(&format!("    /// {}\n", desc));
+        if !declared("updated_at") {
+            columns.push(implied("updated_at", "DateTimeUtc"));
         }
+    }
 
-        // SeaORM attribute
-        if !attrs.is_empty() {
-            out.push_str(&format!("    #[sea_orm({})]\n", attrs.join(", ")));
-        }
+    // Soft-delete field
+    if info.has_soft_delete() && !declared("deleted_at") {
+        columns.push(implied("deleted_at", "Option<DateTimeUtc>"));
+    }
 
-        // Skip serializing secrets
-        if field.secret {
-            out.push_str("    #[serde(skip_serializing)]\n");
-        }
+    columns
+}
 
-        out.push_str(&format!("    pub {}: {},\n", rust_name, rust_type));
+// ============================================================================
+// Composite constraints
+// ============================================================================
 
-        // Blank line between fields for readability
-        if field.is_primary_key {
-            out.push('\n');
-        }
+/// `Entity::UNIQUE_TOGETHER` and `Entity::INDEXES`: the entity-level
+/// constraints as `Column` lists, since SeaORM's attributes only describe
+/// single columns. Empty when the entity has neither.
+fn generate_table_constraints(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let config = &info.entity.config;
+    let implied = config.implied_columns();
+    let columns = |fields: &[String]| -> Option<String> {
+        let variants = fields
+            .iter()
+            .map(|name| {
+                let known = match info.entity.get_field_by_name(name) {
+                    Some(field) => field.is_column(),
+                    None => implied.contains(&name.as_str()),
+                };
+                known.then(|| format!("Column::{}", GenerationContext::pascal(name)))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        (!variants.is_empty()).then(|| format!("&[{}]", variants.join(", ")))
+    };
+
+    let unique: Vec<String> = info
+        .unique_together()
+        .iter()
+        .filter_map(|fields| columns(fields))
+        .collect();
+    let indexes: Vec<String> = config
+        .indexes
+        .iter()
+        .filter_map(|index| columns(&index.fields))
+        .collect();
+    if unique.is_empty() && indexes.is_empty() {
+        return String::new();
     }
 
-    // Add timestamp fields if enabled and not already present
-    if info.has_timestamps() {
-        if !info.entity.fields.iter().any(|f| f.name == "created_at") {
-            out.push_str("    pub created_at: DateTimeUtc,\n");
-        }
-        if !info.entity.fields.iter().any(|f| f.name == "updated_at") {
-            out.push_str("    pub updated_at: DateTimeUtc,\n");
+    let mut out = String::from("impl Entity {\n");
+    if !unique.is_empty() {
+        if ctx.generate_docs() {
+            out.push_str("    /// Column sets whose combined values are unique.\n");
         }
+        out.push_str(&format!(
+            "    pub const UNIQUE_TOGETHER: &[&[Column]] = &[{}];\n",
+            unique.join(", ")
+        ));
     }
-
-    // Soft-delete field
-    if info.has_soft_delete() {
-        if !info.entity.fields.iter().any(|f| f.name == "deleted_at") {
-            out.push_str("    pub deleted_at: Option<DateTimeUtc>,\n");
+    if !indexes.is_empty() {
+        if !unique.is_empty() {
+            out.push('\n');
+        }
+        if ctx.generate_docs() {
+            out.push_str("    /// Indexed column lists, in index column order.\n");
         }
+        out.push_str(&format!(
+            "    pub const INDEXES: &[&[Column]] = &[{}];\n",
+            indexes.join(", ")
+        ));
     }
-
     out.push_str("}\n\n");
     out
 }
@@ -278,18 +482,49 @@ fn generate_model_struct(info: &EntityInfo, ctx: &GenerationContext) -> String {
 // Relation enum
 // ============================================================================
 
-fn generate_relation_enum(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let mut out = String::with_capacity(1024);
+/// One variant of an entity's `Relation` enum.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationVariant {
+    /// Variant name
+    pub name: String,
+    /// `belongs_to`, `has_one` or `has_many`
+    pub kind: &'static str,
+    /// Entity the variant leads to.
+    #[serde(skip)]
+    pub target_id: Uuid,
+    /// Module of that entity
+    pub target_module: String,
+    /// The FK column on this entity, for `belongs_to` variants.
+    pub from_column: Option<String>,
+}
+
+/// The `Relation` variants of `info`: `belongs_to` on the side holding the
+/// FK, `has_many` / `has_one` on the other. Many-to-many and polymorphic
+/// relationships have no FK of their own and get no variant.
+fn relation_variants(info: &EntityInfo, ctx: &GenerationContext) -> Vec<RelationVariant> {
+    let mut variants = Vec::new();
 
     let outgoing = info.outgoing_relationships();
     let incoming = info.incoming_relationships();
 
-    out.push_str("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
-    out.push_str("pub enum Relation {\n");
+    // Outgoing relationships are named after the relationship, incoming
+    // ones after the entity they come from
+    let ends = outgoing
+        .iter()
+        .map(|rel| (*rel, rel.to_entity_id, true))
+        .chain(
+            incoming
+                .iter()
+                // A self-reference is already covered by its outgoing end
+                .filter(|rel| rel.from_entity_id != info.entity.id)
+                .map(|rel| (*rel, rel.from_entity_id, false)),
+        );
 
-    // Outgoing relationships (has_many, has_one)
-    for rel in &outgoing {
-        let target_entity = ctx.entity_by_id(rel.to_entity_id);
+    for (rel, target_id, is_outgoing) in ends {
+        let Some(holder) = determine_fk_entity(rel) else {
+            continue;
+        };
+        let target_entity = ctx.entity_by_id(target_id);
         let target_module = target_entity
             .map(|e| GenerationContext::module_name(&e.name))
             .unwrap_or_else(|| "unknown".to_string());
@@ -297,161 +532,247 @@ fn generate_relation_enum(info: &EntityInfo, ctx: &GenerationContext) -> String
             .map(|e| GenerationContext::pascal(&e.name))
             .unwrap_or_else(|| "Unknown".to_string());
 
-        let variant_name = GenerationContext::pascal(&rel.name);
-        let variant_name = if variant_name.is_empty() {
-            target_pascal.clone()
+        let name = if is_outgoing {
+            GenerationContext::pascal(&rel.name)
         } else {
-            variant_name
+            String::new()
         };
+        let name = if name.is_empty() { target_pascal } else { name };
+
+        if holder == info.entity.id {
+            // The FK is on this entity → belongs_to
+            let fk_field = info
+                .entity
+                .fields
+                .iter()
+                .find(|f| !rel.from_field.is_empty() && f.name == rel.from_field)
+                .or_else(|| get_fk_field_to_entity(info.entity, target_id));
+            let from_column = fk_field
+                .map(|f| GenerationContext::pascal(&f.name))
+                .unwrap_or_else(|| format!("{}Id", GenerationContext::pascal(&target_module)));
+
+            variants.push(RelationVariant {
+                name,
+                kind: "belongs_to",
+                target_id,
+                target_module,
+                from_column: Some(from_column),
+            });
+        } else {
+            let kind = if matches!(rel.relation_type, RelationType::OneToOne) {
+                "has_one"
+            } else {
+                "has_many"
+            };
+            variants.push(RelationVariant {
+                name,
+                kind,
+                target_id,
+                target_module,
+                from_column: None,
+            });
+        }
+    }
 
-        let relation_attr = match &rel.relation_type {
-            RelationType::OneToOne => {
-                format!("#[sea_orm(has_one = \"super::{}::Entity\")]", target_module)
-            }
-            RelationType::OneToMany => {
-                format!(
-                    "#[sea_orm(has_many = \"super::{}::Entity\")]",
-                    target_module
-                )
-            }
-            _ => {
-                // ManyToOne and ManyToMany are handled differently
-                // ManyToOne is represented as a belongs_to on the other side
-                format!(
-                    "#[sea_orm(has_many = \"super::{}::Entity\")]",
-                    target_module
-                )
-            }
-        };
+    variants
+}
 
-        out.push_str(&format!("    {}\n", relation_attr));
-        out.push_str(&format!("    {},\n", variant_name));
-    }
+// ============================================================================
+// Related<…> implementations
+// ============================================================================
 
-    // Incoming relationships (belongs_to)
-    for rel in &incoming {
-        let source_entity = ctx.entity_by_id(rel.from_entity_id);
-        let source_module = source_entity
-            .map(|e| GenerationContext::module_name(&e.name))
-            .unwrap_or_else(|| "unknown".to_string());
+/// One `Related` impl per entity this one reaches: directly through a
+/// `Relation` variant, or through the junction entity of a many-to-many
+/// relationship. SeaORM allows one impl per target, so the first wins.
+fn related_impls(info: &EntityInfo, ctx: &GenerationContext) -> Vec<RelatedImpl> {
+    let mut impls = Vec::new();
+    let mut targets: Vec<Uuid> = Vec::new();
 
-        // Skip if already covered by outgoing
-        let is_already_covered = outgoing
-            .iter()
-            .any(|r| r.from_entity_id == rel.from_entity_id);
-        if is_already_covered {
+    for variant in relation_variants(info, ctx) {
+        if targets.contains(&variant.target_id) {
             continue;
         }
+        targets.push(variant.target_id);
 
-        // For ManyToOne, the current entity has an FK field → belongs_to
-        if matches!(rel.relation_type, RelationType::OneToMany) {
-            // Find the FK field on this entity
-            let fk_field = info.entity.fields.iter().find(|f| {
-                f.is_foreign_key
-                    && f.foreign_key_ref
-                        .as_ref()
-                        .map(|fk| fk.entity_id == rel.from_entity_id)
-                        .unwrap_or(false)
-            });
-
-            let from_col = fk_field
-                .map(|f| GenerationContext::pascal(&f.name))
-                .unwrap_or_else(|| format!("{}Id", GenerationContext::pascal(&source_module)));
-
-            let variant_name = source_entity
-                .map(|e| GenerationContext::pascal(&e.name))
-                .unwrap_or_else(|| "Unknown".to_string());
+        impls.push(RelatedImpl {
+            module: variant.target_module,
+            to: format!("Relation::{}", variant.name),
+            via: None,
+        });
+    }
 
-            out.push_str(&format!(
-                "    #[sea_orm(\n        belongs_to = \"super::{}::Entity\",\n        from = \"Column::{}\",\n        to = \"super::{}::Column::Id\"\n    )]\n",
-                source_module, from_col, source_module,
-            ));
-            out.push_str(&format!("    {},\n", variant_name));
+    let many_to_many = info
+        .outgoing_relationships()
+        .into_iter()
+        .chain(info.incoming_relationships())
+        .filter(|rel| rel.is_many_to_many());
+    for rel in many_to_many {
+        let other_id = if rel.from_entity_id == info.entity.id {
+            rel.to_entity_id
+        } else {
+            rel.from_entity_id
+        };
+        if targets.contains(&other_id) {
+            continue;
         }
+        let Some(junction) = rel.junction_entity_id.and_then(|id| ctx.entity_by_id(id)) else {
+            continue;
+        };
+        let Some((from_key, to_key)) = junction_keys(junction, rel) else {
+            continue;
+        };
+        let Some(other) = ctx.entity_by_id(other_id) else {
+            continue;
+        };
+        let (own_key, other_key) = if rel.from_entity_id == info.entity.id {
+            (from_key, to_key)
+        } else {
+            (to_key, from_key)
+        };
+
+        // The junction's variants for the keys to either side
+        let junction_variants = relation_variants(&EntityInfo::new(junction, ctx), ctx);
+        let variant_for = |key: &imortal_ir::Field| {
+            let column = GenerationContext::pascal(&key.name);
+            junction_variants
+                .iter()
+                .find(|v| v.from_column.as_deref() == Some(column.as_str()))
+                .map(|v| v.name.clone())
+        };
+        let (Some(to), Some(via)) = (variant_for(other_key), variant_for(own_key)) else {
+            continue;
+        };
+        targets.push(other_id);
+
+        let junction_module = GenerationContext::module_name(&junction.name);
+        impls.push(RelatedImpl {
+            module: GenerationContext::module_name(&other.name),
+            to: format!("super::{}::Relation::{}", junction_module, to),
+            via: Some(format!("super::{}::Relation::{}", junction_module, via)),
+        });
     }
 
-    out.push_str("}\n\n");
-    out
+    impls
 }
 
 // ============================================================================
-// Related<…> implementations
+// Create DTO
 // ============================================================================
 
-fn generate_related_impls(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let mut out = String::new();
+pub(crate) fn generate_create_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let name = GenerationContext::create_dto_name(&info.entity.name);
+    let fields = info.create_fields();
 
-    let outgoing = info.outgoing_relationships();
+    let mut out = String::with_capacity(1024);
 
-    for rel in &outgoing {
-        let target_entity = ctx.entity_by_id(rel.to_entity_id);
-        let target_module = target_entity
-            .map(|e| GenerationContext::module_name(&e.name))
-            .unwrap_or_else(|| "unknown".to_string());
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Payload for creating a new {}.",
+            info.pascal_name()
+        )),
+        ctx,
+    ));
 
-        let variant_name = GenerationContext::pascal(&rel.name);
-        let variant_name = if variant_name.is_empty() {
-            target_entity
-                .map(|e| GenerationContext::pascal(&e.name))
-                .unwrap_or_else(|| "Unknown".to_string())
-        } else {
-            variant_name
-        };
+    out.push_str("#[derive(Debug, Clone, Deserialize, Validate)]\n");
+    out.push_str(&format!("pub struct {} {{\n", name));
+    out.push_str(&create_dto_fields(&info.entity.name, &fields, ctx));
 
+    // Children created in the same request
+    for nested in info.nested_children() {
         out.push_str(&format!(
-            "impl Related<super::{}::Entity> for Entity {{\n",
-            target_module
+            "    /// `{}` rows created with this {} in the same transaction.\n",
+            GenerationContext::pascal(&nested.child.name),
+            info.snake_name(),
         ));
+        out.push_str("    #[validate(nested)]\n");
+        out.push_str("    #[serde(default)]\n");
         out.push_str(&format!(
-            "    fn to() -> RelationDef {{\n        Relation::{}.def()\n    }}\n",
-            variant_name
+            "    pub {}: Vec<super::{}::{}>,\n",
+            nested.payload_field(),
+            GenerationContext::module_name(&nested.child.name),
+            nested.dto_name(),
         ));
-        out.push_str("}\n\n");
     }
 
+    out.push_str("}\n");
     out
 }
 
-// ============================================================================
-// Create DTO
-// ============================================================================
+/// Payload for one child embedded in its parent's create request: the
+/// child's create fields without the FK, which the handler fills in.
+fn generate_nested_create_dto(nested: &NestedCreate, ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(1024);
 
-fn generate_create_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let name = GenerationContext::create_dto_name(&info.entity.name);
-    let fields = info.create_fields();
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Payload for a {} created together with its {}. `{}` is set from the new {}.",
+            GenerationContext::pascal(&nested.child.name),
+            GenerationContext::pascal(&nested.parent.name),
+            GenerationContext::snake(&nested.fk_field.name),
+            GenerationContext::snake(&nested.parent.name),
+        )),
+        ctx,
+    ));
 
+    out.push_str("#[derive(Debug, Clone, Deserialize, Validate)]\n");
+    out.push_str(&format!("pub struct {} {{\n", nested.dto_name()));
+    out.push_str(&create_dto_fields(
+        &nested.child.name,
+        &nested.fields(ctx),
+        ctx,
+    ));
+    out.push_str("}\n");
+    out
+}
+
+/// Payload linking an owner row to another through this junction entity:
+/// the junction's create fields without the key to the owner, which the
+/// handler fills in from the path.
+fn generate_link_dto(association: &Association, ctx: &GenerationContext) -> String {
     let mut out = String::with_capacity(1024);
 
     out.push_str(&doc_comment(
         Some(&format!(
-            "Payload for creating a new {}.",
-            info.pascal_name()
+            "Payload linking a {} to one of its {}. `{}` is set from the path.",
+            GenerationContext::pascal(&association.owner.name),
+            association.accessor,
+            GenerationContext::snake(&association.owner_key.name),
         )),
         ctx,
     ));
 
     out.push_str("#[derive(Debug, Clone, Deserialize, Validate)]\n");
-    out.push_str(&format!("pub struct {} {{\n", name));
+    out.push_str(&format!("pub struct {} {{\n", association.dto_name()));
+    out.push_str(&create_dto_fields(
+        &association.junction.name,
+        &association.fields(ctx),
+        ctx,
+    ));
+    out.push_str("}\n");
+    out
+}
 
-    for field in &fields {
+/// Field lines shared by create payloads.
+fn create_dto_fields(
+    entity: &str,
+    fields: &[&imortal_ir::Field],
+    ctx: &GenerationContext,
+) -> String {
+    let mut out = String::new();
+
+    for field in fields {
         let rust_name = GenerationContext::snake(&field.name);
         let rust_type = field_rust_type_dto(field, false);
 
         // For password/secret fields: the DTO accepts plain text from the user
         // (e.g. "password" instead of "password_hash"). The handler is
         // responsible for hashing before storage.
-        let is_password_field =
-            field.secret || field.name.contains("password") || field.name.contains("secret");
+        let is_password_field = ctx.is_password_field(field);
 
-        let dto_field_name = if is_password_field && rust_name.ends_with("_hash") {
-            // password_hash → password (user sends plain text, handler hashes)
-            rust_name.trim_end_matches("_hash").to_string()
-        } else {
-            rust_name.clone()
-        };
+        let dto_field_name = dto_field_name(field, ctx);
 
         // Validation attributes
-        let validators = generate_validator_attrs(field);
+        let validators = generate_validator_attrs(entity, field);
         for attr in &validators {
             out.push_str(&format!("    {}\n", attr));
         }
@@ -462,10 +783,15 @@ fn generate_create_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
             ));
         }
 
+        if let Some(example) = field.example.as_deref().filter(|e| !e.trim().is_empty()) {
+            if ctx.generate_docs() && !is_password_field {
+                out.push_str(&format!("    /// Example: `{}`\n", example));
+            }
+        }
+
         out.push_str(&format!("    pub {}: {},\n", dto_field_name, rust_type));
     }
 
-    out.push_str("}\n");
     out
 }
 
@@ -473,7 +799,7 @@ fn generate_create_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
 // Update DTO
 // ============================================================================
 
-fn generate_update_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
+pub(crate) fn generate_update_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
     let name = GenerationContext::update_dto_name(&info.entity.name);
     let fields = info.update_fields();
 
@@ -498,7 +824,7 @@ fn generate_update_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
         let rust_type = format!("Option<{}>", inner_type);
 
         // Validation (skip_if = None is implicit for Option fields in validator)
-        let validators = generate_validator_attrs(field);
+        let validators = generate_validator_attrs(&info.entity.name, field);
         for attr in &validators {
             out.push_str(&format!("    {}\n", attr));
         }
@@ -510,6 +836,489 @@ fn generate_update_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
     out
 }
 
+// ============================================================================
+// DTO → ActiveModel
+// ============================================================================
+
+/// Whether `CreateXDto` converts straight into an `ActiveModel`: not when
+/// a field must be hashed first, which can fail.
+pub(crate) fn converts_create(info: &EntityInfo, ctx: &GenerationContext) -> bool {
+    !info
+        .create_fields()
+        .iter()
+        .any(|f| ctx.is_password_field(f))
+}
+
+/// Whether `UpdateXDto::apply` is generated; see [`converts_create`].
+pub(crate) fn converts_update(info: &EntityInfo, ctx: &GenerationContext) -> bool {
+    !info
+        .update_fields()
+        .iter()
+        .any(|f| ctx.is_password_field(f))
+}
+
+/// `impl From<CreateXDto> for ActiveModel` minting the key and timestamps,
+/// and `UpdateXDto::apply` copying the present fields onto a loaded row.
+fn generate_active_model_conversions(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let mut out = String::new();
+
+    if converts_create(info, ctx) {
+        out.push_str(&format!(
+            "impl From<{}> for ActiveModel {{\n    fn from(dto: {}) -> Self {{\n        Self {{\n",
+            GenerationContext::create_dto_name(&info.entity.name),
+            GenerationContext::create_dto_name(&info.entity.name),
+        ));
+        out.push_str(&active_model_sets(
+            info,
+            ctx,
+            &info.create_fields(),
+            "dto",
+            12,
+        ));
+        out.push_str("            ..Default::default()\n        }\n    }\n}\n");
+    }
+
+    if converts_update(info, ctx) {
+        let fields = info.update_fields();
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "impl {} {{\n",
+            GenerationContext::update_dto_name(&info.entity.name)
+        ));
+        out.push_str(&format!(
+            "    /// Copy the fields present in this payload onto `active`{}.\n",
+            if info.has_timestamps() {
+                " and bump `updated_at`"
+            } else {
+                ""
+            }
+        ));
+        let param = if fields.is_empty() && !info.has_timestamps() {
+            "_active"
+        } else {
+            "active"
+        };
+        out.push_str(&format!(
+            "    pub fn apply(self, {}: &mut ActiveModel) {{\n",
+            param
+        ));
+        for field in &fields {
+            out.push_str(&format!(
+                "        if let Some(val) = self.{name} {{\n            active.{name} = Set(val);\n        }}\n",
+                name = GenerationContext::snake(&field.name)
+            ));
+        }
+        if info.has_timestamps() {
+            out.push_str("        active.updated_at = Set(chrono::Utc::now());\n");
+        }
+        out.push_str("    }\n}\n");
+    }
+
+    out
+}
+
+// ============================================================================
+// Soft-delete scopes
+// ============================================================================
+
+/// Whether the generator adds the `deleted_at` column itself, rather than
+/// the entity declaring it as a field.
+pub(crate) fn soft_delete_column(info: &EntityInfo) -> bool {
+    info.has_soft_delete() && !info.entity.fields.iter().any(|f| f.name == "deleted_at")
+}
+
+/// `find_active`, `find_active_by_id` and `find_deleted` on the entity of a
+/// soft-deleting model, the selects its handlers read through.
+fn generate_soft_delete_scopes(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    if !info.has_soft_delete() {
+        return String::new();
+    }
+
+    let scopes = [
+        (
+            "Rows that are not soft-deleted.",
+            "find_active()".to_string(),
+            "Self::find().filter(Column::DeletedAt.is_null())",
+        ),
+        (
+            "The row with this ID, unless it is soft-deleted.",
+            format!("find_active_by_id(id: {})", info.pk_rust_type()),
+            "Self::find_by_id(id).filter(Column::DeletedAt.is_null())",
+        ),
+        (
+            "Soft-deleted rows only.",
+            "find_deleted()".to_string(),
+            "Self::find().filter(Column::DeletedAt.is_not_null())",
+        ),
+    ];
+
+    let mut out = String::from("impl Entity {\n");
+    for (i, (doc, signature, body)) in scopes.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        if ctx.generate_docs() {
+            out.push_str(&format!("    /// {}\n", doc));
+        }
+        out.push_str(&format!("    pub fn {} -> Select<Entity> {{\n", signature));
+        out.push_str(&format!("        {}\n", body));
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n\n");
+    out
+}
+
+// ============================================================================
+// Relation accessors
+// ============================================================================
+
+/// `impl Model` with one accessor per relationship end on the entity: the
+/// row its foreign key points at (`author`), or the rows pointing at it
+/// (`posts`). Many-to-many ends have no key on either entity and are left
+/// out, as are accessors that would shadow a field.
+fn generate_relation_accessors(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let pk = info
+        .pk()
+        .map(|f| GenerationContext::snake(&f.name))
+        .unwrap_or_else(|| "id".to_string());
+    let clone_pk = if info.pk_rust_type() == "String" {
+        ".clone()"
+    } else {
+        ""
+    };
+
+    let mut methods = Vec::new();
+    for accessor in info.relation_accessors() {
+        let Some(fk_field) = accessor.fk_field else {
+            continue;
+        };
+        if info.entity.fields.iter().any(|f| f.name == accessor.name) {
+            continue;
+        }
+        let target = EntityInfo::new(accessor.target, ctx);
+        let module = target.module_name();
+        let fk = GenerationContext::snake(&fk_field.name);
+        let soft_delete = target.has_soft_delete();
+
+        let mut out = String::new();
+        if accessor.holds_fk {
+            if ctx.generate_docs() {
+                out.push_str(&format!(
+                    "    /// The `{}` this {}'s `{}` points at.\n",
+                    target.pascal_name(),
+                    info.snake_name(),
+                    fk
+                ));
+            }
+            out.push_str(&format!(
+                "    pub async fn {}<C: ConnectionTrait>(\n        &self,\n        db: &C,\n    ) -> Result<Option<super::{}::Model>, DbErr> {{\n",
+                accessor.name, module
+            ));
+            let clone = if target.pk_rust_type() == "String" {
+                ".clone()"
+            } else {
+                ""
+            };
+            let key = if matches!(fk_field.data_type, DataType::Optional(_)) {
+                out.push_str(&format!(
+                    "        let Some(id) = self.{}{} else {{\n            return Ok(None);\n        }};\n",
+                    fk, clone
+                ));
+                "id".to_string()
+            } else {
+                format!("self.{}{}", fk, clone)
+            };
+            let find = if soft_delete {
+                "find_active_by_id"
+            } else {
+                "find_by_id"
+            };
+            out.push_str(&format!(
+                "        super::{}::Entity::{}({}).one(db).await\n",
+                module, find, key
+            ));
+        } else {
+            let (returns, fetch) = if accessor.many {
+                (format!("Vec<super::{}::Model>", module), "all")
+            } else {
+                (format!("Option<super::{}::Model>", module), "one")
+            };
+            if ctx.generate_docs() {
+                out.push_str(&format!(
+                    "    /// {} `{}` whose `{}` points at this {}.\n",
+                    if accessor.many { "Every" } else { "The" },
+                    target.pascal_name(),
+                    fk,
+                    info.snake_name()
+                ));
+            }
+            out.push_str(&format!(
+                "    pub async fn {}<C: ConnectionTrait>(\n        &self,\n        db: &C,\n    ) -> Result<{}, DbErr> {{\n",
+                accessor.name, returns
+            ));
+            out.push_str(&format!(
+                "        super::{module}::Entity::{}()\n            .filter(super::{module}::Column::{}.eq(self.{}{}))\n            .{}(db)\n            .await\n",
+                if soft_delete { "find_active" } else { "find" },
+                GenerationContext::pascal(&fk_field.name),
+                pk,
+                clone_pk,
+                fetch,
+            ));
+        }
+        out.push_str("    }\n");
+        methods.push(out);
+    }
+
+    for association in info.polymorphic_associations() {
+        if !info
+            .entity
+            .fields
+            .iter()
+            .any(|f| f.name == association.name)
+        {
+            methods.push(polymorphic_accessor(&association, ctx));
+        }
+    }
+    for inverse in info.polymorphic_inverses() {
+        if info.entity.fields.iter().any(|f| f.name == inverse.name) {
+            continue;
+        }
+        let association = &inverse.association;
+        let holder = EntityInfo::new(association.holder, ctx);
+        let module = holder.module_name();
+        let mut out = String::new();
+        if ctx.generate_docs() {
+            out.push_str(&format!(
+                "    /// Every `{}` whose `{}` points at this {}.\n",
+                holder.pascal_name(),
+                association.name,
+                info.snake_name()
+            ));
+        }
+        out.push_str(&format!(
+            "    pub async fn {}<C: ConnectionTrait>(\n        &self,\n        db: &C,\n    ) -> Result<Vec<super::{}::Model>, DbErr> {{\n",
+            inverse.name, module
+        ));
+        out.push_str(&format!(
+            "        super::{module}::Entity::find_by_{}(super::{module}::{}::{}, self.{}{})\n            .all(db)\n            .await\n",
+            association.name,
+            association.type_name(),
+            info.pascal_name(),
+            pk,
+            clone_pk,
+        ));
+        out.push_str("    }\n");
+        methods.push(out);
+    }
+
+    if methods.is_empty() {
+        return String::new();
+    }
+    format!("impl Model {{\n{}}}\n\n", methods.join("\n"))
+}
+
+/// The holder's accessor for the row its polymorphic pair points at, e.g.
+/// `comment.commentable(&db)`. A discriminator naming no known entity is
+/// reported as an error rather than read as a missing row.
+fn polymorphic_accessor(association: &PolymorphicAssociation, ctx: &GenerationContext) -> String {
+    let discriminator = GenerationContext::snake(&association.discriminator.name);
+    let key = GenerationContext::snake(&association.key.name);
+    let type_name = association.type_name();
+    let enum_name = association.enum_name();
+
+    let mut out = String::new();
+    if ctx.generate_docs() {
+        out.push_str(&format!(
+            "    /// The row `{}` and `{}` point at.\n",
+            discriminator, key
+        ));
+    }
+    out.push_str(&format!(
+        "    pub async fn {}<C: ConnectionTrait>(\n        &self,\n        db: &C,\n    ) -> Result<Option<{}>, DbErr> {{\n",
+        association.name, enum_name
+    ));
+    if matches!(association.discriminator.data_type, DataType::Optional(_)) {
+        out.push_str(&format!(
+            "        let Some(kind) = self.{}.as_deref() else {{\n            return Ok(None);\n        }};\n",
+            discriminator
+        ));
+        out.push_str(&format!(
+            "        let kind: {} = kind.parse().map_err(DbErr::Custom)?;\n",
+            type_name
+        ));
+    } else {
+        out.push_str(&format!(
+            "        let kind: {} = self.{}.parse().map_err(DbErr::Custom)?;\n",
+            type_name, discriminator
+        ));
+    }
+    let clone = if key_rust_type(association) == "String" {
+        ".clone()"
+    } else {
+        ""
+    };
+    if matches!(association.key.data_type, DataType::Optional(_)) {
+        out.push_str(&format!(
+            "        let Some(id) = self.{}{} else {{\n            return Ok(None);\n        }};\n",
+            key, clone
+        ));
+    } else {
+        out.push_str(&format!("        let id = self.{}{};\n", key, clone));
+    }
+    out.push_str("        Ok(match kind {\n");
+    for target in &association.targets {
+        let target = EntityInfo::new(target, ctx);
+        let find = if target.has_soft_delete() {
+            "find_active_by_id"
+        } else {
+            "find_by_id"
+        };
+        out.push_str(&format!(
+            "            {}::{variant} => super::{}::Entity::{}(id)\n                .one(db)\n                .await?\n                .map({}::{variant}),\n",
+            type_name,
+            target.module_name(),
+            find,
+            enum_name,
+            variant = target.pascal_name(),
+        ));
+    }
+    out.push_str("        })\n");
+    out.push_str("    }\n");
+    out
+}
+
+/// Rust type of a polymorphic key's value, without the `Option` of a
+/// nullable key.
+fn key_rust_type(association: &PolymorphicAssociation) -> String {
+    match &association.key.data_type {
+        DataType::Optional(inner) => data_type_to_rust(inner),
+        other => data_type_to_rust(other),
+    }
+}
+
+/// `CommentableType` and `Commentable` for a polymorphic association held
+/// by the entity, and `Entity::find_by_commentable` selecting the rows
+/// that point at one target row.
+fn generate_polymorphic_types(
+    association: &PolymorphicAssociation,
+    ctx: &GenerationContext,
+) -> String {
+    let holder = EntityInfo::new(association.holder, ctx);
+    let discriminator = GenerationContext::snake(&association.discriminator.name);
+    let key = GenerationContext::snake(&association.key.name);
+    let type_name = association.type_name();
+    let enum_name = association.enum_name();
+    let targets: Vec<(String, String, String)> = association
+        .targets
+        .iter()
+        .map(|t| {
+            let info = EntityInfo::new(t, ctx);
+            (
+                info.pascal_name(),
+                info.module_name(),
+                PolymorphicAssociation::type_value(t),
+            )
+        })
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Entities a {}'s `{}` may name.",
+            holder.snake_name(),
+            discriminator
+        )),
+        ctx,
+    ));
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]\n");
+    out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+    out.push_str(&format!("pub enum {} {{\n", type_name));
+    for (variant, _, _) in &targets {
+        out.push_str(&format!("    {},\n", variant));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(&format!("impl {} {{\n", type_name));
+    if ctx.generate_docs() {
+        out.push_str(&format!(
+            "    /// The value stored in `{}`.\n",
+            discriminator
+        ));
+    }
+    out.push_str("    pub fn as_str(&self) -> &'static str {\n        match self {\n");
+    for (variant, _, value) in &targets {
+        out.push_str(&format!(
+            "            Self::{} => \"{}\",\n",
+            variant, value
+        ));
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    out.push_str(&format!("impl std::str::FromStr for {} {{\n", type_name));
+    out.push_str("    type Err = String;\n\n");
+    out.push_str("    fn from_str(s: &str) -> Result<Self, Self::Err> {\n        match s {\n");
+    for (variant, _, value) in &targets {
+        out.push_str(&format!(
+            "            \"{}\" => Ok(Self::{}),\n",
+            value, variant
+        ));
+    }
+    out.push_str(&format!(
+        "            other => Err(format!(\"unknown {} '{{}}'\", other)),\n",
+        discriminator
+    ));
+    out.push_str("        }\n    }\n}\n\n");
+
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "The row a {}'s `{}` and `{}` point at.",
+            holder.snake_name(),
+            discriminator,
+            key
+        )),
+        ctx,
+    ));
+    out.push_str("#[derive(Debug, Clone, PartialEq, Serialize)]\n");
+    out.push_str("#[serde(tag = \"type\", content = \"row\", rename_all = \"snake_case\")]\n");
+    out.push_str(&format!("pub enum {} {{\n", enum_name));
+    for (variant, module, _) in &targets {
+        out.push_str(&format!("    {}(super::{}::Model),\n", variant, module));
+    }
+    out.push_str("}\n\n");
+
+    let key_type = key_rust_type(association);
+    out.push_str("impl Entity {\n");
+    if ctx.generate_docs() {
+        let rows = if holder.has_soft_delete() {
+            "Rows, not soft-deleted,"
+        } else {
+            "Rows"
+        };
+        out.push_str(&format!(
+            "    /// {} whose `{}` points at the `kind` row with this ID.\n",
+            rows, association.name
+        ));
+    }
+    out.push_str(&format!(
+        "    pub fn find_by_{}(kind: {}, id: {}) -> Select<Entity> {{\n",
+        association.name, type_name, key_type
+    ));
+    out.push_str(&format!(
+        "        Self::{}()\n            .filter(Column::{}.eq(kind.as_str()))\n            .filter(Column::{}.eq(id))\n",
+        if holder.has_soft_delete() {
+            "find_active"
+        } else {
+            "find"
+        },
+        GenerationContext::pascal(&association.discriminator.name),
+        GenerationContext::pascal(&association.key.name),
+    ));
+    out.push_str("    }\n}\n\n");
+    out
+}
+
 // ============================================================================
 // Response DTO
 // ============================================================================
@@ -522,13 +1331,17 @@ fn generate_response_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
 
     out.push_str(&doc_comment(
         Some(&format!(
-            "Response representation of a {}. Excludes sensitive fields.",
+            "Response representation of a {}. Excludes secret and hidden fields.",
             info.pascal_name()
         )),
         ctx,
     ));
 
-    out.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    }
     out.push_str(&format!("pub struct {} {{\n", name));
 
     for field in &fields {
@@ -547,6 +1360,9 @@ fn generate_response_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
             out.push_str("    pub updated_at: DateTime<Utc>,\n");
         }
     }
+    if soft_delete_column(info) {
+        out.push_str("    pub deleted_at: Option<DateTime<Utc>>,\n");
+    }
 
     out.push_str("}\n");
     out
@@ -564,14 +1380,26 @@ fn generate_from_model(info: &EntityInfo, ctx: &GenerationContext) -> String {
 
     out.push_str(&format!("impl From<Model> for {} {{\n", response_name));
     out.push_str("    fn from(model: Model) -> Self {\n");
+
+    // Getters read the model, so they run before its fields are moved out
+    for field in fields.iter().filter(|f| !f.is_column()) {
+        out.push_str(&format!(
+            "        let {name} = model.{name}();\n",
+            name = GenerationContext::snake(&field.name)
+        ));
+    }
     out.push_str("        Self {\n");
 
     for field in &fields {
         let rust_name = GenerationContext::snake(&field.name);
-        out.push_str(&format!(
-            "            {name}: model.{name},\n",
-            name = rust_name
-        ));
+        if field.is_column() {
+            out.push_str(&format!(
+                "            {name}: model.{name},\n",
+                name = rust_name
+            ));
+        } else {
+            out.push_str(&format!("            {},\n", rust_name));
+        }
     }
 
     // Timestamps
@@ -583,6 +1411,9 @@ fn generate_from_model(info: &EntityInfo, ctx: &GenerationContext) -> String {
             out.push_str("            updated_at: model.updated_at,\n");
         }
     }
+    if soft_delete_column(info) {
+        out.push_str("            deleted_at: model.deleted_at,\n");
+    }
 
     out.push_str("        }\n");
     out.push_str("    }\n");
@@ -591,54 +1422,112 @@ fn generate_from_model(info: &EntityInfo, ctx: &GenerationContext) -> String {
     out
 }
 
+// ============================================================================
+// List item DTO (related aggregates)
+// ============================================================================
+
+/// Generate `{Entity}ListItem`: the response DTO flattened together with the
+/// endpoint's related counts and existence flags. Empty when the endpoint
+/// requests none.
+fn generate_list_item_dto(info: &EntityInfo, ctx: &GenerationContext) -> String {
+    let aggregates = info.related_aggregates();
+    if aggregates.is_empty() {
+        return String::new();
+    }
+
+    let name = GenerationContext::list_item_dto_name(&info.entity.name);
+    let response_name = GenerationContext::response_dto_name(&info.entity.name);
+    let mut out = String::with_capacity(512);
+
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "A {} as returned by list endpoints, with related-row aggregates.",
+            info.pascal_name()
+        )),
+        ctx,
+    ));
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", name));
+    out.push_str("    #[serde(flatten)]\n");
+    out.push_str(&format!(
+        "    pub {}: {},\n",
+        info.snake_name(),
+        response_name
+    ));
+
+    for resolved in &aggregates {
+        let field = GenerationContext::snake(&resolved.aggregate.field);
+        let related =
+            GenerationContext::pluralize(&GenerationContext::snake(&resolved.related.name));
+        let (doc, rust_type) = match resolved.aggregate.kind {
+            AggregateKind::Count => (format!("Number of related {}.", related), "u64"),
+            AggregateKind::Exists => (format!("Whether any related {} exist.", related), "bool"),
+        };
+        if ctx.generate_docs() {
+            out.push_str(&format!("    /// {}\n", doc));
+        }
+        out.push_str(&format!("    pub {}: {},\n", field, rust_type));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
 // ============================================================================
 // Validator attribute generation
 // ============================================================================
 
 /// Generate `#[validate(…)]` attributes for a field based on its validations.
-fn generate_validator_attrs(field: &imortal_ir::Field) -> Vec<String> {
+///
+/// Each rule's `code` is its message key in the `locales/` catalogs, so
+/// `AppError` can report it in the request's language.
+fn generate_validator_attrs(entity: &str, field: &imortal_ir::Field) -> Vec<String> {
     let mut attrs = Vec::new();
+    let decimal = field.data_type.is_decimal();
 
-    for validation in &field.validations {
+    for (index, validation) in field.validations.iter().enumerate() {
+        let Some(code) = validation_key(entity, field, validation) else {
+            continue; // handled by the type system
+        };
         let attr = match validation {
-            Validation::Required => None, // handled by type being non-Option
-            Validation::MinLength(n) => Some(format!("#[validate(length(min = {}))]", n)),
-            Validation::MaxLength(n) => Some(format!("#[validate(length(max = {}))]", n)),
-            Validation::Min(n) => Some(format!("#[validate(range(min = {}))]", n)),
-            Validation::Max(n) => Some(format!("#[validate(range(max = {}))]", n)),
-            Validation::Pattern { regex: _, message } => {
-                if message.is_empty() {
-                    Some(format!(
-                        "#[validate(regex(path = \"RE_{}\"))]",
-                        GenerationContext::snake(&field.name).to_uppercase(),
-                    ))
-                } else {
-                    Some(format!(
-                        "#[validate(regex(path = \"RE_{}\", message = \"{}\"))]",
-                        GenerationContext::snake(&field.name).to_uppercase(),
-                        message,
-                    ))
-                }
-            }
-            Validation::Email => Some("#[validate(email)]".to_string()),
-            Validation::Url => Some("#[validate(url)]".to_string()),
-            Validation::Uuid => None, // UUID validation is handled by the type system
-            Validation::Phone => Some("#[validate(phone)]".to_string()),
-            Validation::OneOf(values) => {
-                // validator doesn't have a built-in OneOf; use custom
-                Some(format!(
-                    "#[validate(custom(function = \"validate_one_of\"))] // allowed: {:?}",
-                    values,
-                ))
+            Validation::Required | Validation::Uuid => continue,
+            Validation::MinLength(n) => {
+                format!("#[validate(length(min = {}, code = \"{}\"))]", n, code)
             }
-            Validation::Custom { name, .. } => {
-                Some(format!("#[validate(custom(function = \"{}\"))]", name))
+            Validation::MaxLength(n) => {
+                format!("#[validate(length(max = {}, code = \"{}\"))]", n, code)
             }
+            // validator's `range` only accepts primitive numbers
+            Validation::Min(n) if decimal => format!(
+                "// min = {} is not checked here: validator has no Decimal support",
+                n
+            ),
+            Validation::Max(n) if decimal => format!(
+                "// max = {} is not checked here: validator has no Decimal support",
+                n
+            ),
+            Validation::Min(n) => format!("#[validate(range(min = {}, code = \"{}\"))]", n, code),
+            Validation::Max(n) => format!("#[validate(range(max = {}, code = \"{}\"))]", n, code),
+            // A pattern's own message lives in the catalog under its key
+            Validation::Pattern { .. } => format!(
+                "#[validate(regex(path = *{}, code = \"{}\"))]",
+                helper_name(field, index),
+                code,
+            ),
+            Validation::Email => format!("#[validate(email(code = \"{}\"))]", code),
+            Validation::Url => format!("#[validate(url(code = \"{}\"))]", code),
+            // validator has no built-in for these; see `generate_validation_helpers`
+            Validation::Phone | Validation::OneOf(_) | Validation::Custom { .. } => format!(
+                "#[validate(custom(function = \"{}\", code = \"{}\"))]",
+                helper_name(field, index),
+                code
+            ),
         };
-
-        if let Some(a) = attr {
-            attrs.push(a);
-        }
+        attrs.push(attr);
     }
 
     // Combine multiple length validations into one if both min and max exist
@@ -662,20 +1551,123 @@ fn generate_validator_attrs(field: &imortal_ir::Field) -> Vec<String> {
         // Remove individual length attrs and add combined
         attrs.retain(|a| !a.contains("length("));
         attrs.push(format!(
-            "#[validate(length(min = {}, max = {}))]",
-            min_val, max_val
+            "#[validate(length(min = {}, max = {}, code = \"{}\"))]",
+            min_val, max_val, LENGTH_BETWEEN_KEY
         ));
     }
 
     attrs
 }
 
+/// Name of the item checking the `index`-th rule of `field`: a `RE_*`
+/// static for a pattern, a `validate_*` function for the rules validator
+/// has no built-in for. A field's second rule of a kind gets a `_2` suffix.
+fn helper_name(field: &imortal_ir::Field, index: usize) -> String {
+    let validation = &field.validations[index];
+    let nth = field.validations[..index]
+        .iter()
+        .filter(|v| std::mem::discriminant(*v) == std::mem::discriminant(validation))
+        .count();
+    let suffix = if nth == 0 {
+        String::new()
+    } else {
+        format!("_{}", nth + 1)
+    };
+    let field_name = GenerationContext::snake(&field.name);
+
+    match validation {
+        Validation::Pattern { .. } => format!("RE_{}{}", field_name.to_uppercase(), suffix),
+        Validation::Phone => "validate_phone".to_string(),
+        Validation::OneOf(_) => format!("validate_{}_one_of{}", field_name, suffix),
+        // Without an expression the function is the user's to write
+        Validation::Custom { name, expression } if expression.trim().is_empty() => {
+            GenerationContext::snake(name)
+        }
+        Validation::Custom { name, .. } => {
+            format!(
+                "validate_{}_{}{}",
+                field_name,
+                GenerationContext::snake(name),
+                suffix
+            )
+        }
+        _ => String::new(),
+    }
+}
+
+/// Items behind the `#[validate]` attributes validator can't check on its
+/// own: a compiled `RE_*` regex per pattern rule, and a function per phone,
+/// allowed-values or custom-expression rule on the entity's payload fields.
+pub(crate) fn generate_validation_helpers(info: &EntityInfo) -> String {
+    let create = info.create_fields();
+    let update = info.update_fields();
+    let mut out = String::new();
+    let mut phone = false;
+
+    let payload_fields = info
+        .entity
+        .fields
+        .iter()
+        .filter(|f| create.iter().chain(&update).any(|p| p.id == f.id));
+    for field in payload_fields {
+        let inner = match &field.data_type {
+            DataType::Optional(inner) => data_type_to_rust(inner),
+            other => data_type_to_rust(other),
+        };
+        // validator hands primitive numbers over by value, the rest by reference
+        let (param, as_str) = match inner.as_str() {
+            "String" => ("&str".to_string(), "value"),
+            "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" => {
+                (inner, "value.to_string().as_str()")
+            }
+            _ => (format!("&{}", inner), "value.to_string().as_str()"),
+        };
+
+        for (index, validation) in field.validations.iter().enumerate() {
+            let name = helper_name(field, index);
+            let code = validation_key(&info.entity.name, field, validation).unwrap_or_default();
+            match validation {
+                Validation::Pattern { regex, .. } => out.push_str(&format!(
+                    "static {name}: std::sync::LazyLock<regex::Regex> =\n    std::sync::LazyLock::new(|| regex::Regex::new({regex:?}).expect(\"valid `{field}` pattern\"));\n\n",
+                    field = GenerationContext::snake(&field.name),
+                )),
+                Validation::Phone => phone = true,
+                Validation::OneOf(values) => out.push_str(&format!(
+                    "/// `{field}` must be one of {allowed}.\nfn {name}(value: {param}) -> Result<(), validator::ValidationError> {{\n    const ALLOWED: &[&str] = &{values:?};\n    if ALLOWED.contains(&{as_str}) {{\n        Ok(())\n    }} else {{\n        Err(validator::ValidationError::new({code:?}))\n    }}\n}}\n\n",
+                    field = GenerationContext::snake(&field.name),
+                    allowed = values
+                        .iter()
+                        .map(|v| format!("`{}`", v))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )),
+                Validation::Custom { expression, .. } if !expression.trim().is_empty() => {
+                    out.push_str(&format!(
+                        "/// `{field}` must satisfy `{expression}`.\nfn {name}(value: {param}) -> Result<(), validator::ValidationError> {{\n    if {expression} {{\n        Ok(())\n    }} else {{\n        Err(validator::ValidationError::new({code:?}))\n    }}\n}}\n\n",
+                        field = GenerationContext::snake(&field.name),
+                        expression = expression.trim(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if phone {
+        out.push_str(
+            "/// A phone number: an optional leading `+`, then 7 to 15 digits, which\n/// spaces, dashes, dots and parentheses may separate.\nfn validate_phone(value: &str) -> Result<(), validator::ValidationError> {\n    let number = value.strip_prefix('+').unwrap_or(value);\n    let digits = number.chars().filter(char::is_ascii_digit).count();\n    if number.chars().all(|c| c.is_ascii_digit() || \" -.()\".contains(c))\n        && (7..=15).contains(&digits)\n    {\n        Ok(())\n    } else {\n        Err(validator::ValidationError::new(\"validation.phone\"))\n    }\n}\n\n",
+        );
+    }
+
+    out
+}
+
 // ============================================================================
 // Rust type helpers
 // ============================================================================
 
 /// Get the Rust type for a field as it appears in the SeaORM `Model` struct.
-fn field_rust_type(field: &imortal_ir::Field, info: &EntityInfo) -> String {
+pub(crate) fn field_rust_type(field: &imortal_ir::Field, info: &EntityInfo) -> String {
     if field.is_primary_key {
         return GenerationContext::pk_rust_type(info.id_type()).to_string();
     }
@@ -683,10 +1675,22 @@ fn field_rust_type(field: &imortal_ir::Field, info: &EntityInfo) -> String {
     data_type_to_rust(&field.data_type)
 }
 
+/// Create-DTO field holding a column's value: password columns are sent in
+/// plain text, so `password_hash` becomes `password`.
+pub(crate) fn dto_field_name(field: &imortal_ir::Field, ctx: &GenerationContext) -> String {
+    let name = GenerationContext::snake(&field.name);
+    if ctx.is_password_field(field) && name.ends_with("_hash") {
+        name.trim_end_matches("_hash").to_string()
+    } else {
+        name
+    }
+}
+
 /// Get the Rust type for a DTO field.
 /// - For create DTOs: required fields are non-Option, optional are Option
+///   (an `Optional` type already is, so it is not wrapped twice)
 /// - For update DTOs: all fields are wrapped in Option by the caller
-fn field_rust_type_dto(field: &imortal_ir::Field, _is_update: bool) -> String {
+pub(crate) fn field_rust_type_dto(field: &imortal_ir::Field, _is_update: bool) -> String {
     if field.is_primary_key {
         // PKs are usually not in DTOs, but if they are, use the raw type
         return data_type_to_rust(&field.data_type);
@@ -695,7 +1699,7 @@ fn field_rust_type_dto(field: &imortal_ir::Field, _is_update: bool) -> String {
     let base = data_type_to_rust(&field.data_type);
 
     // If the field is optional (not required), wrap in Option
-    if !field.required {
+    if !field.required && !matches!(field.data_type, DataType::Optional(_)) {
         format!("Option<{}>", base)
     } else {
         base
@@ -703,13 +1707,14 @@ fn field_rust_type_dto(field: &imortal_ir::Field, _is_update: bool) -> String {
 }
 
 /// Map a `DataType` to a Rust type string for generated code.
-fn data_type_to_rust(dt: &DataType) -> String {
+pub(crate) fn data_type_to_rust(dt: &DataType) -> String {
     match dt {
         DataType::String | DataType::Text => "String".to_string(),
         DataType::Int32 => "i32".to_string(),
         DataType::Int64 => "i64".to_string(),
         DataType::Float32 => "f32".to_string(),
         DataType::Float64 => "f64".to_string(),
+        DataType::Decimal { .. } => "Decimal".to_string(),
         DataType::Bool => "bool".to_string(),
         DataType::Uuid => "Uuid".to_string(),
         DataType::DateTime => "DateTime<Utc>".to_string(),
@@ -720,7 +1725,7 @@ fn data_type_to_rust(dt: &DataType) -> String {
         DataType::Optional(inner) => format!("Option<{}>", data_type_to_rust(inner)),
         DataType::Array(inner) => format!("Vec<{}>", data_type_to_rust(inner)),
         DataType::Reference { .. } => "Uuid".to_string(),
-        DataType::Enum { name, .. } => name.clone(),
+        DataType::Enum { name, .. } => GenerationContext::pascal(name),
     }
 }
 
@@ -728,22 +1733,50 @@ fn data_type_to_rust(dt: &DataType) -> String {
 // Pagination helper struct (generated once, used by handlers)
 // ============================================================================
 
-/// Generate a shared pagination types module.
+/// Type returned by list handlers for the project's pagination style.
+pub fn list_response_type(ctx: &GenerationContext, item: &str) -> String {
+    match ctx.pagination() {
+        PaginationStyle::Offset => format!("PaginatedResponse<{}>", item),
+        PaginationStyle::Cursor => format!("CursorPage<{}>", item),
+        PaginationStyle::None => format!("Vec<{}>", item),
+    }
+}
+
+/// Generate the shared pagination types for the project's pagination style.
 /// This is called from the handlers module but defined here for proximity.
-pub fn generate_pagination_types() -> String {
-    r#"
+///
+/// Bare-array projects need no shared types and get an empty string.
+pub fn generate_pagination_types(ctx: &GenerationContext) -> String {
+    let (params_derive, envelope_derive) = if ctx.openapi_enabled() {
+        (
+            "#[derive(Debug, Clone, Deserialize, utoipa::IntoParams)]",
+            "#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]",
+        )
+    } else {
+        (
+            "#[derive(Debug, Clone, Deserialize)]",
+            "#[derive(Debug, Clone, Serialize)]",
+        )
+    };
+
+    match ctx.pagination() {
+        PaginationStyle::Offset => format!(
+            r#"
 /// Query parameters for paginated list endpoints.
-#[derive(Debug, Clone, Deserialize)]
-pub struct PaginationParams {
+{params_derive}
+pub struct PaginationParams {{
     /// Page number (1-based). Defaults to 1.
     pub page: Option<u64>,
-    /// Items per page. Defaults to 20, max 100.
+    /// Items per page. Defaults to 20, max 100, unless the endpoint sets its own.
     pub per_page: Option<u64>,
-}
+}}
 
 /// Paginated response wrapper.
-#[derive(Debug, Clone, Serialize)]
-pub struct PaginatedResponse<T: Serialize> {
+///
+/// Page-number pagination lets clients jump to any page and show totals,
+/// at the cost of a `COUNT(*)` per request and slower deep pages.
+{envelope_derive}
+pub struct PaginatedResponse<T: Serialize> {{
     /// The items in this page.
     pub items: Vec<T>,
     /// Total number of items across all pages.
@@ -754,27 +1787,66 @@ pub struct PaginatedResponse<T: Serialize> {
     pub per_page: u64,
     /// Total number of pages.
     pub total_pages: u64,
-}
+}}
 
-impl<T: Serialize> PaginatedResponse<T> {
+impl<T: Serialize> PaginatedResponse<T> {{
     /// Create a new paginated response.
-    pub fn new(items: Vec<T>, total: u64, page: u64, per_page: u64) -> Self {
-        let total_pages = if per_page > 0 {
+    pub fn new(items: Vec<T>, total: u64, page: u64, per_page: u64) -> Self {{
+        let total_pages = if per_page > 0 {{
             (total + per_page - 1) / per_page
-        } else {
+        }} else {{
             0
-        };
-        Self {
+        }};
+        Self {{
             items,
             total,
             page,
             per_page,
             total_pages,
-        }
-    }
-}
+        }}
+    }}
+}}
+"#
+        ),
+        PaginationStyle::Cursor => format!(
+            r#"
+/// Query parameters for cursor-paginated list endpoints.
+{params_derive}
+pub struct PaginationParams {{
+    /// Opaque cursor from a previous page's `next_cursor`. Omit for the first page.
+    pub cursor: Option<String>,
+    /// Items per page. Defaults to 20, max 100, unless the endpoint sets its own.
+    pub limit: Option<u64>,
+}}
+
+/// Cursor-paginated response wrapper.
+///
+/// Cursor pagination costs the same at any depth and does not skip or repeat
+/// rows when new ones are inserted, but offers no totals or random access.
+{envelope_derive}
+pub struct CursorPage<T: Serialize> {{
+    /// The items in this page.
+    pub items: Vec<T>,
+    /// Cursor for the next page, or `None` on the last page.
+    pub next_cursor: Option<String>,
+    /// Whether more items follow this page.
+    pub has_more: bool,
+}}
+
+impl<T: Serialize> CursorPage<T> {{
+    /// Create a new cursor page.
+    pub fn new(items: Vec<T>, next_cursor: Option<String>) -> Self {{
+        Self {{
+            items,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }}
+    }}
+}}
 "#
-    .to_string()
+        ),
+        PaginationStyle::None => String::new(),
+    }
 }
 
 // ============================================================================
@@ -785,9 +1857,19 @@ impl<T: Serialize> PaginatedResponse<T> {
 mod tests {
     use super::*;
     use imortal_core::DataType;
-    use imortal_ir::{Entity, EntityConfig, Field, ProjectGraph};
+    use imortal_ir::{Entity, EntityConfig, EntityIndex, Field, ProjectGraph};
     use uuid::Uuid;
 
+    /// The `Model` struct of `info`'s model file.
+    fn generate_model_struct(info: &EntityInfo, ctx: &GenerationContext) -> String {
+        let content = generate_entity_model(info, ctx).content;
+        let start = content
+            .find("#[derive(Clone, Debug, PartialEq, DeriveEntityModel")
+            .unwrap();
+        let end = start + content[start..].find("\n}\n").unwrap() + 3;
+        content[start..end].to_string()
+    }
+
     /// Create a simple User entity for testing.
     fn make_user_entity() -> Entity {
         let mut entity = Entity::new("User");
@@ -859,6 +1941,43 @@ mod tests {
         assert!(paths.iter().any(|p| p.contains("post.rs")));
     }
 
+    #[test]
+    fn test_junction_model_has_link_dto() {
+        let mut project = ProjectGraph::new("blog");
+        let user = project.add_entity(make_user_entity());
+        let post = project.add_entity(make_post_entity());
+        project.add_endpoint(imortal_ir::EndpointGroup::new(user, "User"));
+        let (rel, _) = project
+            .create_relationship_with_fk(imortal_ir::Relationship::many_to_many(
+                user,
+                post,
+                "bookmarks",
+            ))
+            .unwrap();
+        let junction = project.promote_junction(rel).unwrap();
+        let mut note = Field::new("note", DataType::Text);
+        note.required = false;
+        project.get_entity_mut(junction).unwrap().add_field(note);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_models(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/models/user_post.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("pub struct UserPostsLinkDto {"));
+        let dto = &content[content.find("pub struct UserPostsLinkDto").unwrap()..];
+        let dto = &dto[..dto.find("\n}").unwrap()];
+        assert!(dto.contains("pub post_id: Uuid,"));
+        assert!(dto.contains("pub note: Option<String>,"));
+        assert!(!dto.contains("user_id"));
+
+        // Post has no endpoints, so no DTO for its side
+        assert!(!content.contains("PostUsersLinkDto"));
+    }
+
     #[test]
     fn test_model_struct_contains_sea_orm_attrs() {
         let mut project = ProjectGraph::new("test");
@@ -880,6 +1999,37 @@ mod tests {
         assert!(model.contains("serde(skip_serializing)"));
     }
 
+    #[test]
+    fn test_model_struct_decimal_column() {
+        let mut project = ProjectGraph::new("shop");
+        let mut product = Entity::new("Product");
+        let mut price = Field::new("price", DataType::decimal(10, 2));
+        price.required = true;
+        product.fields.push(price);
+        project.add_entity(product);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entities().first().unwrap(), &ctx);
+        let model = generate_model_struct(&info, &ctx);
+
+        assert!(model.contains("#[sea_orm(column_type = \"Decimal(Some((10, 2)))\")]"));
+        assert!(model.contains("pub price: Decimal,"));
+    }
+
+    #[test]
+    fn test_model_struct_schema_name() {
+        let mut project = ProjectGraph::new("test");
+        let mut user = make_user_entity();
+        user.config.schema = Some("auth".to_string());
+        project.add_entity(user);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let entity = ctx.entities().first().unwrap();
+        let model = generate_model_struct(&EntityInfo::new(entity, &ctx), &ctx);
+
+        assert!(model.contains("#[sea_orm(schema_name = \"auth\", table_name = \"users\")]"));
+    }
+
     #[test]
     fn test_model_struct_timestamps() {
         let mut project = ProjectGraph::new("test");
@@ -919,6 +2069,41 @@ mod tests {
         assert!(model.contains("Option<DateTimeUtc>"));
     }
 
+    #[test]
+    fn test_soft_delete_scopes_and_response() {
+        let mut project = ProjectGraph::new("test");
+        let mut entity = make_post_entity();
+        entity.config.soft_delete = true;
+        entity.config.timestamps = false;
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(&ctx.entities()[0], &ctx);
+        let content = generate_entity_model(&info, &ctx).content;
+
+        assert!(content.contains("impl Entity {"));
+        assert!(content.contains("pub fn find_active() -> Select<Entity> {"));
+        assert!(content.contains("Self::find().filter(Column::DeletedAt.is_null())"));
+        assert!(content.contains("pub fn find_active_by_id(id: uuid::Uuid) -> Select<Entity> {"));
+        assert!(content.contains("Self::find().filter(Column::DeletedAt.is_not_null())"));
+        assert!(content.contains("use chrono::{DateTime, Utc};"));
+        assert!(content.contains("    pub deleted_at: Option<DateTime<Utc>>,\n"));
+        assert!(content.contains("            deleted_at: model.deleted_at,\n"));
+
+        project
+            .entities
+            .values_mut()
+            .next()
+            .unwrap()
+            .config
+            .soft_delete = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(&ctx.entities()[0], &ctx);
+        let content = generate_entity_model(&info, &ctx).content;
+        assert!(!content.contains("find_active"));
+        assert!(!content.contains("deleted_at"));
+    }
+
     #[test]
     fn test_create_dto() {
         let mut project = ProjectGraph::new("test");
@@ -947,8 +2132,205 @@ mod tests {
             dto.contains("Plain-text value"),
             "Should have doc comment explaining hashing"
         );
-        assert!(dto.contains("#[validate(email)]"));
-        assert!(dto.contains("#[validate(length(min = 1, max = 100))]"));
+        assert!(dto.contains("#[validate(email(code = \"validation.email\"))]"));
+        assert!(dto.contains(
+            "#[validate(length(min = 1, max = 100, code = \"validation.length.between\"))]"
+        ));
+    }
+
+    #[test]
+    fn test_relation_accessors_use_relationship_names() {
+        let mut project = ProjectGraph::new("test");
+        let user = make_user_entity();
+        let post = make_post_entity();
+        let (user_id, post_id) = (user.id, post.id);
+        project.add_entity(user);
+        project.add_entity(post);
+        project
+            .create_relationship_with_fk(
+                imortal_ir::Relationship::one_to_many(user_id, post_id)
+                    .with_accessor("author")
+                    .with_inverse("articles")
+                    .with_nested_create(),
+            )
+            .unwrap();
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_models(&ctx);
+        let file = |path: &str| {
+            &files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap()
+                .content
+        };
+
+        let user_model = file("src/models/user.rs");
+        assert!(user_model.contains("/// Every `Post` whose `user_id` points at this user."));
+        assert!(user_model.contains("pub async fn articles<C: ConnectionTrait>("));
+        assert!(user_model.contains(") -> Result<Vec<super::post::Model>, DbErr> {"));
+        assert!(user_model.contains(".filter(super::post::Column::UserId.eq(self.id))"));
+        assert!(user_model.contains("pub articles: Vec<super::post::CreatePostInUserDto>,"));
+
+        let post_model = file("src/models/post.rs");
+        assert!(post_model.contains("/// The `User` this post's `user_id` points at."));
+        assert!(post_model.contains("pub async fn author<C: ConnectionTrait>("));
+        assert!(post_model.contains("super::user::Entity::find_by_id(self.user_id).one(db).await"));
+    }
+
+    #[test]
+    fn test_relation_accessors_handle_optional_keys_and_soft_deleted_rows() {
+        let mut project = ProjectGraph::new("test");
+        let mut user = make_user_entity();
+        user.config.soft_delete = true;
+        let post = make_post_entity();
+        let (user_id, post_id) = (user.id, post.id);
+        project.add_entity(user);
+        project.add_entity(post);
+        project
+            .create_relationship_with_fk(
+                imortal_ir::Relationship::one_to_many(user_id, post_id).optional(),
+            )
+            .unwrap();
+        let post = project.entities.get_mut(&post_id).unwrap();
+        let fk = post
+            .fields
+            .iter_mut()
+            .find(|f| f.name == "user_id")
+            .unwrap();
+        fk.data_type = DataType::Optional(Box::new(DataType::Uuid));
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let post_model = generate_models(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "src/models/post.rs")
+            .unwrap()
+            .content;
+        assert!(post_model.contains(
+            "        let Some(id) = self.user_id else {\n            return Ok(None);\n        };\n        super::user::Entity::find_active_by_id(id).one(db).await\n"
+        ));
+    }
+
+    #[test]
+    fn test_polymorphic_association_helpers() {
+        let mut project = ProjectGraph::new("test");
+        let comment_id = project.add_entity(Entity::new("Comment"));
+        let post_id = project.add_entity(make_post_entity());
+        let mut photo = Entity::new("Photo");
+        photo.config.soft_delete = true;
+        let photo_id = project.add_entity(photo);
+        for target in [post_id, photo_id] {
+            project
+                .create_relationship_with_fk(imortal_ir::Relationship::polymorphic(
+                    comment_id,
+                    target,
+                    "commentable",
+                ))
+                .unwrap();
+        }
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_models(&ctx);
+        let model = |path: &str| {
+            files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap()
+                .content
+                .clone()
+        };
+
+        let comment = model("src/models/comment.rs");
+        assert!(comment.contains("pub enum CommentableType {\n    Photo,\n    Post,\n}"));
+        assert!(comment.contains("            Self::Photo => \"photo\",\n"));
+        assert!(comment.contains("            \"post\" => Ok(Self::Post),\n"));
+        assert!(comment.contains(
+            "pub enum Commentable {\n    Photo(super::photo::Model),\n    Post(super::post::Model),\n}"
+        ));
+        assert!(comment.contains("    ) -> Result<Option<Commentable>, DbErr> {\n"));
+        assert!(comment.contains(
+            "let kind: CommentableType = self.commentable_type.parse().map_err(DbErr::Custom)?;"
+        ));
+        // Soft-deleted targets read as missing
+        assert!(
+            comment
+                .contains("CommentableType::Photo => super::photo::Entity::find_active_by_id(id)")
+        );
+        assert!(comment.contains("CommentableType::Post => super::post::Entity::find_by_id(id)"));
+        assert!(comment.contains(
+            "pub fn find_by_commentable(kind: CommentableType, id: Uuid) -> Select<Entity> {"
+        ));
+        assert!(comment.contains(".filter(Column::CommentableType.eq(kind.as_str()))"));
+        // No foreign key, so no SeaORM relation
+        assert!(!comment.contains("belongs_to"));
+
+        let post = model("src/models/post.rs");
+        assert!(post.contains("pub async fn comments<C: ConnectionTrait>("));
+        assert!(post.contains(
+            "super::comment::Entity::find_by_commentable(super::comment::CommentableType::Post, self.id)"
+        ));
+    }
+
+    #[test]
+    fn test_create_dto_embeds_nested_children() {
+        let mut project = ProjectGraph::new("test");
+        let user = make_user_entity();
+        let post = make_post_entity();
+        let (user_id, post_id) = (user.id, post.id);
+        project.add_entity(user);
+        project.add_entity(post);
+        project
+            .create_relationship_with_fk(
+                imortal_ir::Relationship::one_to_many(user_id, post_id).with_nested_create(),
+            )
+            .unwrap();
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_models(&ctx);
+        let file = |path: &str| {
+            &files
+                .iter()
+                .find(|f| f.path.to_string_lossy() == path)
+                .unwrap()
+                .content
+        };
+
+        // The parent's payload holds a validated list of children
+        let user_model = file("src/models/user.rs");
+        assert!(user_model.contains(
+            "    #[validate(nested)]\n    #[serde(default)]\n    pub posts: Vec<super::post::CreatePostInUserDto>,\n"
+        ));
+
+        // The child payload is the child's create payload without the FK
+        let post_model = file("src/models/post.rs");
+        let start = post_model.find("pub struct CreatePostInUserDto").unwrap();
+        let nested_dto = &post_model[start..];
+        let nested_dto = &nested_dto[..nested_dto.find("}\n").unwrap()];
+        assert!(nested_dto.contains("pub title: String"));
+        assert!(!nested_dto.contains("user_id"));
+        assert!(post_model.contains("`user_id` is set from the new user."));
+        let start = post_model.find("pub struct CreatePostDto").unwrap();
+        assert!(post_model[start..].contains("pub user_id: Uuid"));
+    }
+
+    #[test]
+    fn test_create_dto_documents_examples() {
+        let mut project = ProjectGraph::new("test");
+        let mut entity = Entity::new("Book");
+        entity.config.timestamps = false;
+        entity.fields.push(
+            Field::new("title", DataType::String)
+                .required()
+                .with_example("Dune"),
+        );
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let e = ctx.entities().first().unwrap();
+        let info = EntityInfo::new(e, &ctx);
+
+        let dto = generate_create_dto(&info, &ctx);
+        assert!(dto.contains("/// Example: `Dune`"));
     }
 
     #[test]
@@ -1006,6 +2388,68 @@ mod tests {
         assert!(!from_impl.contains("model.password_hash"));
     }
 
+    #[test]
+    fn test_response_dto_skips_hidden_fields() {
+        let mut entity = make_post_entity();
+        entity
+            .fields
+            .push(Field::new("moderation_notes", DataType::Text).hidden());
+        let mut project = ProjectGraph::new("test");
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entities().first().unwrap(), &ctx);
+
+        let dto = generate_response_dto(&info, &ctx);
+        assert!(dto.contains("pub id: Uuid"));
+        assert!(dto.contains("pub title: String"));
+        assert!(!dto.contains("moderation_notes"));
+        assert!(!generate_from_model(&info, &ctx).contains("moderation_notes"));
+
+        // Still writable: hidden only keeps it out of responses
+        assert!(generate_create_dto(&info, &ctx).contains("pub moderation_notes:"));
+    }
+
+    #[test]
+    fn test_active_model_conversions() {
+        let mut post = make_post_entity();
+        post.fields
+            .push(Field::new("slug", DataType::String).readonly());
+        let mut project = ProjectGraph::new("test");
+        project.add_entity(post);
+        project.add_entity(make_user_entity());
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let post = ctx.entity_by_name("Post").unwrap();
+        let info = EntityInfo::new(post, &ctx);
+
+        let conversions = generate_active_model_conversions(&info, &ctx);
+        assert!(conversions.contains("impl From<CreatePostDto> for ActiveModel {\n    fn from(dto: CreatePostDto) -> Self {\n"));
+        assert!(conversions.contains("            id: Set(Uuid::new_v4()),\n"));
+        assert!(conversions.contains("            title: Set(dto.title),\n"));
+        assert!(conversions.contains("            created_at: Set(chrono::Utc::now()),\n"));
+        assert!(conversions.contains("impl UpdatePostDto {\n"));
+        assert!(conversions.contains("    pub fn apply(self, active: &mut ActiveModel) {\n"));
+        assert!(conversions.contains(
+            "        if let Some(val) = self.content {\n            active.content = Set(val);\n        }\n"
+        ));
+        assert!(conversions.contains("        active.updated_at = Set(chrono::Utc::now());\n"));
+        // Readonly fields are never written from a payload
+        assert!(!conversions.contains("slug"));
+
+        let file = generate_entity_model(&info, &ctx);
+        assert!(file.content.contains("use sea_orm::Set;\n"));
+
+        // Password hashing can fail, so the handler keeps writing those
+        let user = ctx.entity_by_name("User").unwrap();
+        let info = EntityInfo::new(user, &ctx);
+        assert!(!converts_create(&info, &ctx));
+        assert!(converts_update(&info, &ctx));
+        let conversions = generate_active_model_conversions(&info, &ctx);
+        assert!(!conversions.contains("impl From<CreateUserDto>"));
+        assert!(conversions.contains("impl UpdateUserDto {"));
+    }
+
     #[test]
     fn test_models_mod_rs() {
         let mut project = ProjectGraph::new("test");
@@ -1024,6 +2468,43 @@ mod tests {
         assert!(content.contains("PostResponse"));
     }
 
+    #[test]
+    fn test_list_item_dto_with_related_aggregates() {
+        let mut project = ProjectGraph::new("test");
+        project.config.openapi_enabled = true;
+        let user_id = project.add_entity(make_user_entity());
+        let post_id = project.add_entity(Entity::new("Post"));
+        let (rel_id, _) = project
+            .create_relationship_with_fk(imortal_ir::Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        project.add_endpoint(
+            imortal_ir::EndpointGroup::new(user_id, "User")
+                .with_related_count(rel_id, "posts_count")
+                .with_related_exists(rel_id, "has_posts"),
+        );
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let entity = ctx.entity_by_name("User").unwrap();
+        let info = EntityInfo::new(entity, &ctx);
+
+        let dto = generate_list_item_dto(&info, &ctx);
+        assert!(dto.contains("utoipa::ToSchema"));
+        assert!(dto.contains("pub struct UserListItem"));
+        assert!(dto.contains("#[serde(flatten)]\n    pub user: UserResponse,"));
+        assert!(dto.contains("pub posts_count: u64,"));
+        assert!(dto.contains("pub has_posts: bool,"));
+        assert!(generate_response_dto(&info, &ctx).contains("utoipa::ToSchema"));
+        assert!(
+            generate_models_mod(&ctx)
+                .content
+                .contains("UserResponse, UserListItem}")
+        );
+
+        // Post has no endpoint, so no list item
+        let post = EntityInfo::new(ctx.entity_by_name("Post").unwrap(), &ctx);
+        assert!(generate_list_item_dto(&post, &ctx).is_empty());
+    }
+
     #[test]
     fn test_data_type_to_rust() {
         assert_eq!(data_type_to_rust(&DataType::String), "String");
@@ -1057,7 +2538,7 @@ mod tests {
         let mut field = Field::new("email", DataType::String);
         field.validations.push(Validation::Email);
 
-        let attrs = generate_validator_attrs(&field);
+        let attrs = generate_validator_attrs("User", &field);
         assert_eq!(attrs.len(), 1);
         assert!(attrs[0].contains("email"));
     }
@@ -1068,9 +2549,12 @@ mod tests {
         field.validations.push(Validation::MinLength(1));
         field.validations.push(Validation::MaxLength(100));
 
-        let attrs = generate_validator_attrs(&field);
+        let attrs = generate_validator_attrs("User", &field);
         assert_eq!(attrs.len(), 1);
-        assert!(attrs[0].contains("length(min = 1, max = 100)"));
+        assert_eq!(
+            attrs[0],
+            "#[validate(length(min = 1, max = 100, code = \"validation.length.between\"))]"
+        );
     }
 
     #[test]
@@ -1078,7 +2562,7 @@ mod tests {
         let mut field = Field::new("website", DataType::String);
         field.validations.push(Validation::Url);
 
-        let attrs = generate_validator_attrs(&field);
+        let attrs = generate_validator_attrs("User", &field);
         assert_eq!(attrs.len(), 1);
         assert!(attrs[0].contains("url"));
     }
@@ -1089,18 +2573,166 @@ mod tests {
         field.validations.push(Validation::Min(0.0));
         field.validations.push(Validation::Max(150.0));
 
-        let attrs = generate_validator_attrs(&field);
+        let attrs = generate_validator_attrs("User", &field);
         assert_eq!(attrs.len(), 2);
-        assert!(attrs.iter().any(|a| a.contains("range(min = 0)")));
-        assert!(attrs.iter().any(|a| a.contains("range(max = 150)")));
+        assert!(
+            attrs
+                .iter()
+                .any(|a| a.contains("range(min = 0, code = \"validation.range.min\")"))
+        );
+        assert!(
+            attrs
+                .iter()
+                .any(|a| a.contains("range(max = 150, code = \"validation.range.max\")"))
+        );
+    }
+
+    #[test]
+    fn test_validator_attrs_decimal_range_not_emitted() {
+        let mut field = Field::new("price", DataType::money());
+        field.validations.push(Validation::Min(0.0));
+
+        let attrs = generate_validator_attrs("User", &field);
+        assert_eq!(attrs.len(), 1);
+        assert!(attrs[0].starts_with("// min = 0 is not checked"));
+    }
+
+    #[test]
+    fn test_validation_helpers() {
+        let mut entity = Entity::new("Contact");
+        entity.fields.push(
+            Field::new("code", DataType::String)
+                .with_validation(Validation::Pattern {
+                    regex: r"^[A-Z]{3}\d+$".to_string(),
+                    message: String::new(),
+                })
+                .with_validation(Validation::Pattern {
+                    regex: "^[^\"]+$".to_string(),
+                    message: String::new(),
+                }),
+        );
+        entity
+            .fields
+            .push(Field::new("phone", DataType::String).with_validation(Validation::Phone));
+        entity.fields.push(
+            Field::new("status", DataType::String)
+                .with_validation(Validation::OneOf(vec!["new".into(), "won".into()])),
+        );
+        entity.fields.push(
+            Field::new("seats", DataType::Int32)
+                .with_validation(Validation::OneOf(vec!["2".into(), "4".into()]))
+                .with_validation(Validation::Custom {
+                    name: "Even".to_string(),
+                    expression: "value % 2 == 0".to_string(),
+                }),
+        );
+        entity
+            .fields
+            .push(
+                Field::new("slug", DataType::String).with_validation(Validation::Custom {
+                    name: "check_slug".to_string(),
+                    expression: String::new(),
+                }),
+            );
+        let mut project = ProjectGraph::new("test");
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entities().first().unwrap(), &ctx);
+        let helpers = generate_validation_helpers(&info);
+
+        // Patterns compile once, numbered per field
+        assert!(helpers.contains("static RE_CODE: std::sync::LazyLock<regex::Regex> =\n    std::sync::LazyLock::new(|| regex::Regex::new(\"^[A-Z]{3}\\\\d+$\")"));
+        assert!(helpers.contains("static RE_CODE_2: std::sync::LazyLock<regex::Regex> ="));
+        assert!(helpers.contains("regex::Regex::new(\"^[^\\\"]+$\")"));
+        let code = &info.entity.fields[1];
+        assert_eq!(
+            generate_validator_attrs("Contact", code),
+            vec![
+                "#[validate(regex(path = *RE_CODE, code = \"validation.pattern\"))]",
+                "#[validate(regex(path = *RE_CODE_2, code = \"validation.pattern\"))]",
+            ]
+        );
+
+        // One phone check per file, reached through `custom`
+        assert_eq!(helpers.matches("fn validate_phone(value: &str)").count(), 1);
+        let dto = generate_create_dto(&info, &ctx);
+        assert!(
+            dto.contains(
+                "    #[validate(custom(function = \"validate_phone\", code = \"validation.phone\"))]\n    pub phone: Option<String>,\n"
+            )
+        );
+
+        // Allowed values compare as strings
+        assert!(helpers.contains(
+            "fn validate_status_one_of(value: &str) -> Result<(), validator::ValidationError> {\n    const ALLOWED: &[&str] = &[\"new\", \"won\"];\n    if ALLOWED.contains(&value) {"
+        ));
+        assert!(helpers.contains("fn validate_seats_one_of(value: i32)"));
+        assert!(helpers.contains("if ALLOWED.contains(&value.to_string().as_str()) {"));
+
+        // Custom expressions become the function body
+        assert!(helpers.contains(
+            "fn validate_seats_even(value: i32) -> Result<(), validator::ValidationError> {\n    if value % 2 == 0 {\n        Ok(())\n    } else {\n        Err(validator::ValidationError::new(\"validation.custom.even\"))\n    }\n}\n"
+        ));
+        // ... while one without an expression is left to the user
+        assert!(!helpers.contains("check_slug"));
+        let slug = info.entity.fields.last().unwrap();
+        assert_eq!(
+            generate_validator_attrs("Contact", slug),
+            vec![
+                "#[validate(custom(function = \"check_slug\", code = \"validation.custom.check_slug\"))]"
+            ]
+        );
+
+        // Fields no payload carries need no helpers
+        let mut project = ProjectGraph::new("test");
+        let mut entity = Entity::new("Contact");
+        entity.fields.push(
+            Field::new("phone", DataType::String)
+                .readonly()
+                .with_validation(Validation::Phone),
+        );
+        project.add_entity(entity);
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(ctx.entities().first().unwrap(), &ctx);
+        assert!(generate_validation_helpers(&info).is_empty());
     }
 
     #[test]
     fn test_pagination_types() {
-        let content = generate_pagination_types();
+        let project = ProjectGraph::new("test");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_pagination_types(&ctx);
         assert!(content.contains("PaginationParams"));
         assert!(content.contains("PaginatedResponse"));
         assert!(content.contains("total_pages"));
+        assert_eq!(
+            list_response_type(&ctx, "UserResponse"),
+            "PaginatedResponse<UserResponse>"
+        );
+    }
+
+    #[test]
+    fn test_pagination_types_follow_style() {
+        let mut project = ProjectGraph::new("test");
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_pagination_types(&ctx);
+        assert!(content.contains("pub struct CursorPage<T: Serialize>"));
+        assert!(content.contains("pub next_cursor: Option<String>"));
+        assert!(!content.contains("total_pages"));
+        assert_eq!(
+            list_response_type(&ctx, "UserResponse"),
+            "CursorPage<UserResponse>"
+        );
+
+        project.config.pagination = PaginationStyle::None;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_pagination_types(&ctx).is_empty());
+        assert_eq!(
+            list_response_type(&ctx, "UserResponse"),
+            "Vec<UserResponse>"
+        );
     }
 
     #[test]
@@ -1122,6 +2754,37 @@ mod tests {
         assert!(model.contains("unique"));
     }
 
+    #[test]
+    fn test_table_constraints() {
+        let mut project = ProjectGraph::new("test");
+        let mut entity = Entity::new("Post");
+        entity.fields.push(Field::new("author_id", DataType::Uuid));
+        entity.fields.push(Field::new("slug", DataType::String));
+        entity.config = EntityConfig::new()
+            .with_unique_together(["author_id", "slug"])
+            .with_unique_together(["author_id", "missing"])
+            .with_index(EntityIndex::new(["author_id", "created_at"]));
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(&ctx.entities()[0], &ctx);
+        let out = generate_table_constraints(&info, &ctx);
+        assert!(out.contains(
+            "pub const UNIQUE_TOGETHER: &[&[Column]] = &[&[Column::AuthorId, Column::Slug]];"
+        ));
+        assert!(out.contains(
+            "pub const INDEXES: &[&[Column]] = &[&[Column::AuthorId, Column::CreatedAt]];"
+        ));
+
+        let mut plain = Entity::new("Tag");
+        plain.fields.push(Field::new("name", DataType::String));
+        let mut project = ProjectGraph::new("test");
+        project.add_entity(plain);
+        let ctx = GenerationContext::from_project_default(&project);
+        let info = EntityInfo::new(&ctx.entities()[0], &ctx);
+        assert!(generate_table_constraints(&info, &ctx).is_empty());
+    }
+
     #[test]
     fn test_optional_field_in_dto() {
         let mut project = ProjectGraph::new("test");
@@ -0,0 +1,640 @@
+//! # OAuth2 Sign-in Generator
+//!
+//! Generates `src/auth/oauth.rs` for projects using the OAuth2 strategy
+//! ([`AuthConfig::oauth_providers`]). Each configured provider signs users
+//! in through the authorization code flow with PKCE:
+//!
+//! | Route | Does |
+//! |-------|------|
+//! | `GET /api/auth/oauth/{provider}/authorize` | stores a CSRF state and PKCE verifier, redirects to the provider |
+//! | `GET /api/auth/oauth/{provider}/callback` | takes the state, trades the code for a token, signs the linked user in |
+//!
+//! Started sign-ins live in the `oauth_states` table and are used once; the
+//! provider account each user signs in with is linked in `oauth_accounts`
+//! (see [`oauth_accounts_statements`]). Google and GitHub endpoints are
+//! built in, a generic OpenID Connect provider finds its endpoints in the
+//! issuer's discovery document. With refresh tokens a sign-in starts a
+//! session; otherwise it answers a bare access token.
+//!
+//! The SQL is shared; running it comes from
+//! [`OrmBackend::oauth_store`](crate::rust::orm::OrmBackend::oauth_store)
+//! and the handlers from
+//! [`FrameworkBackend::oauth_handlers`](crate::rust::framework::FrameworkBackend::oauth_handlers).
+//!
+//! [`AuthConfig::oauth_providers`]: imortal_ir::AuthConfig::oauth_providers
+//! [`oauth_accounts_statements`]: crate::migrations::sql::oauth_accounts_statements
+
+use imortal_ir::{DatabaseType, OAuthProvider, OAuthProviderKind, Orm};
+
+use crate::context::GenerationContext;
+use crate::migrations::sql::{OAUTH_ACCOUNTS_TABLE, OAUTH_STATES_TABLE};
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/auth/oauth.rs`, if the project signs in with OAuth2
+/// providers.
+pub fn generate_oauth(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if ctx.oauth_providers().is_empty() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/auth/oauth.rs",
+        build_oauth(ctx),
+        FileType::Rust,
+    )]
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_oauth(ctx: &GenerationContext) -> String {
+    let framework = ctx.framework_backend();
+    let providers = ctx.oauth_providers();
+    let discovery = providers.iter().any(|p| p.kind == OAuthProviderKind::Oidc);
+    let mut out = String::with_capacity(12288);
+
+    out.push_str(&file_header(
+        "OAuth2 sign-in — authorization code flow with PKCE and linked accounts.",
+    ));
+    out.push_str(framework.oauth_imports());
+    out.push_str(ctx.orm_backend().store_imports());
+    out.push_str(
+        "\
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+",
+    );
+    if ctx.refresh_tokens() {
+        out.push_str(
+            "use crate::auth::jwt::Claims;\nuse crate::auth::sessions::{self, TokenPair};\n",
+        );
+    } else {
+        out.push_str("use crate::auth::jwt::{create_token, Claims};\n");
+    }
+    out.push_str(
+        "\
+use crate::constants::{AUTH_PATH, OAUTH_STATE_TTL_SECS};
+use crate::error::AppError;
+use crate::state::AppState;
+",
+    );
+    if ctx.orm() == Orm::Diesel {
+        out.push_str("use crate::state::run;\n");
+    }
+    out.push('\n');
+
+    // ── Types ────────────────────────────────────────────────────────────
+    if ctx.refresh_tokens() {
+        out.push_str(
+            "\
+/// What a completed sign-in answers: the first token pair of a new session.
+pub type SignIn = TokenPair;
+",
+        );
+    } else {
+        out.push_str(
+            "\
+/// What a completed sign-in answers.
+#[derive(Debug, Serialize)]
+pub struct SignIn {
+    pub access_token: String,
+    pub token_type: &'static str,
+    /// Access token lifetime in seconds.
+    pub expires_in: u64,
+}
+",
+        );
+    }
+    out.push_str(
+        "
+/// Query string the provider redirects back with: a code on success, an
+/// error when the user declined.
+#[derive(Debug, Deserialize)]
+pub struct Callback {
+    pub code: Option<String>,
+    pub state: String,
+    pub error: Option<String>,
+}
+
+/// The provider account that signed in.
+#[derive(Debug, Serialize)]
+pub struct Profile {
+    pub provider: String,
+    /// The provider's stable id for the account.
+    pub subject: String,
+    /// The account's email, if the provider shares it. Not necessarily
+    /// verified.
+    pub email: Option<String>,
+}
+
+/// A sign-in started by [`authorize_url`], waiting for its callback.
+struct PendingState {
+    provider: String,
+    code_verifier: String,
+    expires_at: i64,
+}
+
+/// Endpoints and credentials of a provider.
+struct Provider {
+    authorize_url: String,
+    token_url: String,
+    userinfo_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: &'static str,
+}
+
+",
+    );
+
+    // ── Flow ─────────────────────────────────────────────────────────────
+    out.push_str(
+        "\
+/// Where to send the browser to sign in with the `name` provider. Stores
+/// the CSRF state and PKCE verifier the callback checks.
+pub async fn authorize_url(state: &AppState, name: &str) -> Result<String, AppError> {
+    let provider = provider(state, name).await?;
+    let csrf = Uuid::new_v4().simple().to_string();
+    let code_verifier = format!(\"{}{}\", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+    let now = now();
+    let pending = PendingState {
+        provider: name.to_string(),
+        code_verifier,
+        expires_at: now + OAUTH_STATE_TTL_SECS,
+    };
+    insert_state(state, csrf.clone(), pending, now).await?;
+
+    let redirect_uri = redirect_uri(state, name);
+    let url = reqwest::Url::parse_with_params(
+        &provider.authorize_url,
+        [
+            (\"response_type\", \"code\"),
+            (\"client_id\", provider.client_id.as_str()),
+            (\"redirect_uri\", redirect_uri.as_str()),
+            (\"scope\", provider.scope),
+            (\"state\", csrf.as_str()),
+            (\"code_challenge\", challenge.as_str()),
+            (\"code_challenge_method\", \"S256\"),
+        ],
+    )
+    .map_err(|e| AppError::internal(format!(\"Invalid authorize URL: {}\", e)))?;
+    Ok(url.into())
+}
+
+/// Finish signing in with the `name` provider: check the state, trade the
+/// code for the provider's token, and sign in the user the account is
+/// linked to, linking it on its first sign-in.
+pub async fn complete(state: &AppState, name: &str, callback: Callback) -> Result<SignIn, AppError> {
+    let Some(code) = callback.code else {
+        let reason = callback.error.unwrap_or_else(|| \"no code\".to_string());
+        return Err(AppError::unauthorized(format!(\"Sign-in was not completed: {}\", reason)));
+    };
+    let Some(pending) = take_state(state, callback.state).await? else {
+        return Err(AppError::unauthorized(\"Unknown or reused sign-in state\"));
+    };
+    if pending.provider != name {
+        return Err(AppError::unauthorized(\"Sign-in state belongs to another provider\"));
+    }
+    if pending.expires_at <= now() {
+        return Err(AppError::unauthorized(\"Sign-in state has expired\"));
+    }
+
+    let provider = provider(state, name).await?;
+    let access_token = exchange(state, name, &provider, code, pending.code_verifier).await?;
+    let profile = fetch_profile(name, &provider, &access_token).await?;
+
+    let linked = find_account(state, profile.provider.clone(), profile.subject.clone()).await?;
+    let user_id = match linked {
+        Some(user_id) => user_id,
+        None => {
+            let user_id = resolve_user(state, &profile).await?;
+            insert_account(state, &profile, user_id.clone(), now()).await?;
+            user_id
+        }
+    };
+
+    let identifier = profile.email.unwrap_or(profile.subject);
+    let claims = Claims::new(user_id, identifier, Vec::new(), state.config.jwt_expiry_hours);
+    sign_in(state, &claims).await
+}
+
+/// The user a provider account signs in as, on its first sign-in. Each
+/// new account becomes a new user; to link it to an existing user instead,
+/// look them up here — by an email the provider has verified only, or
+/// anyone could take over that user by registering the email elsewhere.
+async fn resolve_user(_state: &AppState, _profile: &Profile) -> Result<String, AppError> {
+    Ok(Uuid::new_v4().to_string())
+}
+
+",
+    );
+    if ctx.refresh_tokens() {
+        out.push_str(
+            "\
+/// Start a session for the signed-in user.
+async fn sign_in(state: &AppState, claims: &Claims) -> Result<SignIn, AppError> {
+    sessions::start(state, claims).await
+}
+",
+        );
+    } else {
+        out.push_str(
+            "\
+/// Sign an access token for the signed-in user.
+async fn sign_in(state: &AppState, claims: &Claims) -> Result<SignIn, AppError> {
+    let access_token = create_token(claims, &state.config.jwt_secret)
+        .map_err(|e| AppError::internal(format!(\"Failed to sign token: {}\", e)))?;
+    Ok(SignIn {
+        access_token,
+        token_type: \"Bearer\",
+        expires_in: state.config.jwt_expiry_hours * 3600,
+    })
+}
+",
+        );
+    }
+
+    // ── Providers ────────────────────────────────────────────────────────
+    out.push_str(
+        "
+// ============================================================================
+// Providers
+// ============================================================================
+
+",
+    );
+    out.push_str(&provider_fn(providers));
+    if discovery {
+        out.push_str(
+            "
+/// The endpoints an OpenID Connect issuer publishes.
+#[derive(Deserialize)]
+struct Discovery {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+}
+
+async fn discover(issuer_url: &str) -> Result<Discovery, AppError> {
+    let url = format!(\"{}/.well-known/openid-configuration\", issuer_url.trim_end_matches('/'));
+    let response = client().get(url).send().await.map_err(upstream)?;
+    response.error_for_status().map_err(upstream)?.json().await.map_err(upstream)
+}
+",
+        );
+    }
+    out.push_str(
+        "
+/// Trade the authorization code for the provider's access token.
+async fn exchange(
+    state: &AppState,
+    name: &str,
+    provider: &Provider,
+    code: String,
+    code_verifier: String,
+) -> Result<String, AppError> {
+    #[derive(Deserialize)]
+    struct TokenResponse {
+        access_token: String,
+    }
+
+    let redirect_uri = redirect_uri(state, name);
+    let form = [
+        (\"grant_type\", \"authorization_code\"),
+        (\"code\", code.as_str()),
+        (\"redirect_uri\", redirect_uri.as_str()),
+        (\"client_id\", provider.client_id.as_str()),
+        (\"client_secret\", provider.client_secret.as_str()),
+        (\"code_verifier\", code_verifier.as_str()),
+    ];
+    // GitHub answers form-encoded unless asked for JSON
+    let response = client()
+        .post(&provider.token_url)
+        .header(reqwest::header::ACCEPT, \"application/json\")
+        .form(&form)
+        .send()
+        .await
+        .map_err(upstream)?;
+    let token: TokenResponse = response.error_for_status().map_err(upstream)?.json().await.map_err(upstream)?;
+    Ok(token.access_token)
+}
+
+/// Ask the provider which account signed in.
+async fn fetch_profile(name: &str, provider: &Provider, access_token: &str) -> Result<Profile, AppError> {
+    let response = client()
+        .get(&provider.userinfo_url)
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(upstream)?;
+    let info: Value = response.error_for_status().map_err(upstream)?.json().await.map_err(upstream)?;
+
+    // OpenID Connect names the account `sub`, GitHub a numeric `id`
+    let subject = match (&info[\"sub\"], &info[\"id\"]) {
+        (Value::String(sub), _) => sub.clone(),
+        (_, Value::Number(id)) => id.to_string(),
+        _ => return Err(AppError::internal(\"OAuth2 provider answered no account id\")),
+    };
+    Ok(Profile {
+        provider: name.to_string(),
+        subject,
+        email: info[\"email\"].as_str().map(str::to_string),
+    })
+}
+
+/// The callback URL registered with every provider.
+fn redirect_uri(state: &AppState, name: &str) -> String {
+    format!(\"{}{}/oauth/{}/callback\", state.config.oauth_redirect_base_url, AUTH_PATH, name)
+}
+
+/// Shared HTTP client. GitHub's API rejects requests without a User-Agent.
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .user_agent(concat!(env!(\"CARGO_PKG_NAME\"), \"/\", env!(\"CARGO_PKG_VERSION\")))
+            .build()
+            .expect(\"failed to build the OAuth2 HTTP client\")
+    })
+}
+
+/// A provider that fails to answer is our error, not the caller's.
+fn upstream(err: reqwest::Error) -> AppError {
+    AppError::internal(format!(\"OAuth2 provider request failed: {}\", err))
+}
+
+/// Seconds since the UNIX epoch.
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect(\"system clock before UNIX epoch\")
+        .as_secs() as i64
+}
+
+",
+    );
+
+    // ── Store ────────────────────────────────────────────────────────────
+    out.push_str(
+        "\
+// ============================================================================
+// Store
+// ============================================================================
+
+",
+    );
+    out.push_str(&oauth_sql(ctx.database()));
+    out.push('\n');
+    out.push_str(ctx.orm_backend().oauth_store());
+
+    // ── Handlers ─────────────────────────────────────────────────────────
+    out.push_str(
+        "
+// ============================================================================
+// Handlers
+// ============================================================================
+
+",
+    );
+    out.push_str(framework.oauth_handlers());
+
+    out
+}
+
+/// `async fn provider(state, name)`: the endpoints and credentials of each
+/// configured provider, `404` for any other name.
+fn provider_fn(providers: &[OAuthProvider]) -> String {
+    let mut out = String::from(
+        "\
+async fn provider(state: &AppState, name: &str) -> Result<Provider, AppError> {
+    let config = &state.config;
+    match name {
+",
+    );
+    for provider in providers {
+        let name = &provider.name;
+        let fields = format!(
+            "                client_id: config.{name}_client_id.clone(),\n\
+             \x20               client_secret: config.{name}_client_secret.clone(),\n\
+             \x20               scope: {:?},\n",
+            provider.scope()
+        );
+        let (authorize, token, userinfo) = match provider.kind {
+            OAuthProviderKind::Google => (
+                "\"https://accounts.google.com/o/oauth2/v2/auth\".to_string()",
+                "\"https://oauth2.googleapis.com/token\".to_string()",
+                "\"https://openidconnect.googleapis.com/v1/userinfo\".to_string()",
+            ),
+            OAuthProviderKind::GitHub => (
+                "\"https://github.com/login/oauth/authorize\".to_string()",
+                "\"https://github.com/login/oauth/access_token\".to_string()",
+                "\"https://api.github.com/user\".to_string()",
+            ),
+            OAuthProviderKind::Oidc => (
+                "discovery.authorization_endpoint",
+                "discovery.token_endpoint",
+                "discovery.userinfo_endpoint",
+            ),
+        };
+        out.push_str(&format!("        \"{name}\" => {{\n"));
+        if provider.kind == OAuthProviderKind::Oidc {
+            out.push_str(&format!(
+                "            let discovery = discover(&config.{name}_issuer_url).await?;\n"
+            ));
+        }
+        out.push_str(&format!(
+            "            Ok(Provider {{\n\
+             \x20               authorize_url: {authorize},\n\
+             \x20               token_url: {token},\n\
+             \x20               userinfo_url: {userinfo},\n\
+             {fields}\
+             \x20           }})\n\
+             \x20       }}\n"
+        ));
+    }
+    out.push_str("        _ => Err(AppError::NotFound),\n    }\n}\n");
+    out
+}
+
+/// The `const …_SQL` statements the store runs, with the database's
+/// placeholders.
+fn oauth_sql(db: DatabaseType) -> String {
+    let p = |n: usize| match db {
+        DatabaseType::PostgreSQL => format!("${n}"),
+        DatabaseType::MySQL | DatabaseType::SQLite => "?".to_string(),
+    };
+    let values = |count: usize| (1..=count).map(p).collect::<Vec<_>>().join(", ");
+    let states = OAUTH_STATES_TABLE;
+    let accounts = OAUTH_ACCOUNTS_TABLE;
+
+    [
+        (
+            "INSERT_STATE_SQL",
+            format!(
+                "INSERT INTO {states} (state, provider, code_verifier, expires_at) VALUES ({})",
+                values(4)
+            ),
+        ),
+        (
+            "FIND_STATE_SQL",
+            format!(
+                "SELECT provider, code_verifier, expires_at FROM {states} WHERE state = {}",
+                p(1)
+            ),
+        ),
+        (
+            "DELETE_STATE_SQL",
+            format!("DELETE FROM {states} WHERE state = {}", p(1)),
+        ),
+        (
+            "PURGE_STATES_SQL",
+            format!("DELETE FROM {states} WHERE expires_at <= {}", p(1)),
+        ),
+        (
+            "FIND_ACCOUNT_SQL",
+            format!(
+                "SELECT user_id FROM {accounts} WHERE provider = {} AND subject = {}",
+                p(1),
+                p(2)
+            ),
+        ),
+        (
+            "INSERT_ACCOUNT_SQL",
+            format!(
+                "INSERT INTO {accounts} (provider, subject, user_id, email, created_at) VALUES ({})",
+                values(5)
+            ),
+        ),
+    ]
+    .iter()
+    .map(|(name, sql)| format!("const {name}: &str = \"{sql}\";\n"))
+    .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{AuthConfig, ProjectGraph, WebFramework};
+
+    fn project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        project.config.auth = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::google())
+            .with_oauth_provider(OAuthProvider::github())
+            .with_oauth_provider(OAuthProvider::oidc("okta", "https://example.okta.com"));
+        project
+    }
+
+    fn oauth(project: &ProjectGraph) -> String {
+        let ctx = GenerationContext::from_project_default(project);
+        generate_oauth(&ctx).remove(0).content
+    }
+
+    #[test]
+    fn test_oauth_only_with_providers() {
+        let mut project = ProjectGraph::new("app");
+        project.config.auth = AuthConfig::jwt().with_oauth_provider(OAuthProvider::google());
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_oauth(&ctx).is_empty());
+
+        project.config.auth = AuthConfig::oauth2();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_oauth(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_oauth_flow_checks_state_and_pkce() {
+        let content = oauth(&project());
+        assert!(content.contains("pub async fn authorize_url(state: &AppState, name: &str)"));
+        assert!(content.contains("(\"code_challenge_method\", \"S256\"),"));
+        assert!(
+            content.contains("let Some(pending) = take_state(state, callback.state).await? else {")
+        );
+        assert!(content.contains("if pending.expires_at <= now() {"));
+        assert!(content.contains("(\"code_verifier\", code_verifier.as_str()),"));
+        assert!(
+            content.contains("insert_account(state, &profile, user_id.clone(), now()).await?;")
+        );
+    }
+
+    #[test]
+    fn test_oauth_provider_endpoints() {
+        let content = oauth(&project());
+        assert!(content.contains("        \"google\" => {\n            Ok(Provider {\n                authorize_url: \"https://accounts.google.com/o/oauth2/v2/auth\".to_string(),"));
+        assert!(content.contains("userinfo_url: \"https://api.github.com/user\".to_string(),"));
+        assert!(content.contains("scope: \"read:user user:email\","));
+        assert!(content.contains("let discovery = discover(&config.okta_issuer_url).await?;"));
+        assert!(content.contains("client_secret: config.okta_client_secret.clone(),"));
+        assert!(content.contains("_ => Err(AppError::NotFound),"));
+        assert!(content.contains("struct Discovery {"));
+
+        let mut project = ProjectGraph::new("app");
+        project.config.auth = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::google().with_scopes(&["openid", "email"]));
+        let content = oauth(&project);
+        assert!(content.contains("scope: \"openid email\","));
+        assert!(!content.contains("discover("));
+    }
+
+    #[test]
+    fn test_oauth_sign_in_with_or_without_sessions() {
+        let mut project = project();
+        let content = oauth(&project);
+        assert!(content.contains("pub struct SignIn {"));
+        assert!(
+            content.contains("let access_token = create_token(claims, &state.config.jwt_secret)")
+        );
+
+        project.config.auth = project.config.auth.clone().with_refresh_tokens();
+        let content = oauth(&project);
+        assert!(content.contains("pub type SignIn = TokenPair;"));
+        assert!(content.contains("sessions::start(state, claims).await"));
+        assert!(!content.contains("create_token"));
+    }
+
+    #[test]
+    fn test_oauth_sql_placeholders() {
+        let postgres = oauth_sql(DatabaseType::PostgreSQL);
+        assert!(postgres.contains(
+            "const FIND_ACCOUNT_SQL: &str = \"SELECT user_id FROM oauth_accounts WHERE provider = $1 AND subject = $2\";"
+        ));
+        assert!(postgres.contains("VALUES ($1, $2, $3, $4, $5)"));
+
+        let mysql = oauth_sql(DatabaseType::MySQL);
+        assert!(mysql.contains("DELETE FROM oauth_states WHERE state = ?\";"));
+    }
+
+    #[test]
+    fn test_oauth_orm_and_framework() {
+        let content = oauth(&project());
+        assert!(content.contains("use sea_orm::{ConnectionTrait, Statement};"));
+        assert!(content.contains("pub async fn oauth_callback("));
+        assert!(content.contains("Query(callback): Query<Callback>,"));
+
+        let mut project = project();
+        project.config.orm = Orm::Diesel;
+        project.config.framework = WebFramework::ActixWeb;
+        let content = oauth(&project);
+        assert!(content.contains("use crate::state::run;"));
+        assert!(content.contains(".bind::<Nullable<Text>, _>(email)"));
+        assert!(content.contains("HttpResponse::Found().insert_header((LOCATION, url))"));
+    }
+}
@@ -0,0 +1,639 @@
+//! # ORM Backends
+//!
+//! The data layer of the generated project — models, handlers, migrations,
+//! the connection pool and the database error conversion — is written for
+//! one ORM. Each ORM implements [`OrmBackend`]; the other generators ask the
+//! backend selected by [`GenerationContext::orm`] for the ORM-specific
+//! pieces and stay ORM-agnostic themselves.
+//!
+//! | Backend | Models | Migrations |
+//! |---------|--------|------------|
+//! | [`SeaOrmBackend`] | `DeriveEntityModel` entities | `migrations/{date}{n}_create_{table}.sql` |
+//! | [`DieselBackend`] | `schema.rs` + derive-based models | `migrations/{timestamp}_create_{table}/up.sql` + `down.sql` |
+//!
+//! Not every backend can generate every feature of the project model; see
+//! [`OrmFeature`]. Features a backend lacks are left out of the generated
+//! code (and the OpenAPI spec) and reported as generation warnings.
+
+use imortal_ir::{DatabaseType, EnumDef, Orm};
+
+use crate::GeneratedFile;
+use crate::context::GenerationContext;
+use crate::migrations::{self, SchemaMigration};
+use crate::rust::cargo::RUST_DECIMAL_DEP;
+use crate::rust::enums::EnumMapping;
+use crate::rust::{handlers, models, queries};
+
+pub use crate::rust::diesel::DieselBackend;
+
+// ============================================================================
+// Features
+// ============================================================================
+
+/// A generator feature that depends on ORM support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrmFeature {
+    /// Query-builder endpoints (`handlers/queries.rs`)
+    CustomQueries,
+    /// Children created inside their parent's create request
+    NestedCreate,
+    /// Related counts / existence flags in list responses
+    RelatedAggregates,
+    /// `?sort=` and per-field filter parameters on list endpoints
+    ListQueryParams,
+    /// 409 responses listing the rows that block a delete
+    DeleteBlockers,
+    /// The async-graphql schema (`src/graphql/`)
+    GraphQl,
+    /// Nested routes managing the links of a promoted many-to-many junction
+    Associations,
+    /// Target enums and lookups for polymorphic associations
+    Polymorphic,
+    /// The job queue and scheduled jobs (`src/jobs.rs`)
+    BackgroundJobs,
+    /// Create/update lookups naming a field already taken within its parent
+    ScopedUniqueChecks,
+}
+
+impl OrmFeature {
+    /// Human-readable name used in warnings.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            OrmFeature::CustomQueries => "custom queries",
+            OrmFeature::NestedCreate => "nested create",
+            OrmFeature::RelatedAggregates => "related aggregates",
+            OrmFeature::ListQueryParams => "list sorting and filtering",
+            OrmFeature::DeleteBlockers => "delete blocker reports",
+            OrmFeature::GraphQl => "the GraphQL API",
+            OrmFeature::Associations => "association endpoints",
+            OrmFeature::Polymorphic => "polymorphic association helpers",
+            OrmFeature::BackgroundJobs => "background jobs",
+            OrmFeature::ScopedUniqueChecks => "per-parent uniqueness checks",
+        }
+    }
+}
+
+// ============================================================================
+// Backend trait
+// ============================================================================
+
+/// The ORM-specific parts of the generated Rust project.
+pub trait OrmBackend: Sync {
+    /// The ORM this backend generates for.
+    fn orm(&self) -> Orm;
+
+    /// Whether the backend can generate `feature`.
+    fn supports(&self, feature: OrmFeature) -> bool;
+
+    /// `[dependencies]` lines for the ORM and database driver.
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String;
+
+    /// The `rust_decimal` dependency line, with whatever integration
+    /// features the ORM needs.
+    fn rust_decimal_dependency(&self, _ctx: &GenerationContext) -> String {
+        RUST_DECIMAL_DEP.to_string()
+    }
+
+    /// Extra `pub mod` lines for `src/lib.rs`.
+    fn lib_modules(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// `src/state.rs`: the connection pool type(s) and `AppState`.
+    fn generate_state(&self, ctx: &GenerationContext) -> GeneratedFile;
+
+    /// `impl Config` methods building the connection pool(s).
+    fn config_methods(&self, ctx: &GenerationContext) -> String;
+
+    /// `use` lines `main.rs` needs to connect.
+    fn main_imports(&self) -> &'static str;
+
+    /// Expression in `main` evaluating to a `Result` with the pool, before
+    /// `.context(…)?` is applied.
+    fn connect_expr(&self, read_replica: bool) -> &'static str;
+
+    /// Expression in the generated integration tests evaluating to a
+    /// `Result` with the pool, before `.expect(…)` is applied.
+    fn test_connect_expr(&self) -> &'static str;
+
+    /// Default `EnvFilter` directives when `RUST_LOG` is unset.
+    fn log_filter(&self) -> &'static str;
+
+    /// `async fn check_database(state)` of `src/health.rs`: pings the
+    /// primary pool, and the read replica's if there is one.
+    fn database_check(&self, read_replica: bool) -> String;
+
+    /// `use` lines the raw SQL stores of `src/auth/` need:
+    /// [`Self::session_store`] and [`Self::oauth_store`].
+    fn store_imports(&self) -> &'static str;
+
+    /// The refresh token store of `src/auth/sessions.rs`: `insert_token`,
+    /// `find_token`, `revoke` and `count_live`, running its `…_SQL`
+    /// statements on the primary pool.
+    fn session_store(&self) -> &'static str;
+
+    /// The sign-in store of `src/auth/oauth.rs`: `insert_state`,
+    /// `take_state`, `find_account` and `insert_account`, running its
+    /// `…_SQL` statements on the primary pool.
+    fn oauth_store(&self) -> &'static str;
+
+    /// `impl From<…> for AppError` for the ORM's error type.
+    fn error_conversion(&self) -> &'static str;
+
+    /// Unit tests for [`OrmBackend::error_conversion`], indented to sit
+    /// inside the generated `mod tests`.
+    fn error_conversion_tests(&self) -> &'static str;
+
+    /// Files other generators don't cover (e.g. `src/schema.rs`).
+    fn scaffolding(&self, _ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        Vec::new()
+    }
+
+    /// `src/models/`: entities/models and DTOs.
+    fn generate_models(&self, ctx: &GenerationContext) -> Vec<GeneratedFile>;
+
+    /// `use` lines `src/models/enums.rs` needs for the enum mappings.
+    fn enum_module_imports(&self, ctx: &GenerationContext) -> String;
+
+    /// How an enum type is stored: the derives, attributes and impls its
+    /// Rust enum gets.
+    fn enum_mapping(&self, def: &EnumDef, ctx: &GenerationContext) -> EnumMapping;
+
+    /// `src/handlers/`: CRUD handlers and any query endpoints.
+    fn generate_handlers(&self, ctx: &GenerationContext) -> Vec<GeneratedFile>;
+
+    /// One `CREATE TABLE` migration per entity.
+    fn generate_migrations(&self, ctx: &GenerationContext) -> Vec<GeneratedFile>;
+
+    /// The incremental migration for changes since a baseline schema.
+    fn generate_alter_migration(
+        &self,
+        plan: &SchemaMigration,
+        ctx: &GenerationContext,
+    ) -> Vec<GeneratedFile>;
+
+    /// README "run the migrations" step.
+    fn readme_migrate_step(&self) -> &'static str;
+
+    /// The seed binary's `apply(config, files)`, running every seed file in
+    /// one transaction.
+    fn seed_apply(&self) -> &'static str;
+}
+
+/// The backend for `orm`.
+pub fn backend(orm: Orm) -> &'static dyn OrmBackend {
+    match orm {
+        Orm::SeaOrm => &SeaOrmBackend,
+        Orm::Diesel => &DieselBackend,
+    }
+}
+
+// ============================================================================
+// SeaORM
+// ============================================================================
+
+/// SeaORM: async entities queried straight from the handlers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeaOrmBackend;
+
+impl OrmBackend for SeaOrmBackend {
+    fn orm(&self) -> Orm {
+        Orm::SeaOrm
+    }
+
+    fn supports(&self, _feature: OrmFeature) -> bool {
+        true
+    }
+
+    fn cargo_dependencies(&self, ctx: &GenerationContext) -> String {
+        let db_feature = match ctx.database() {
+            DatabaseType::PostgreSQL => "sqlx-postgres",
+            DatabaseType::MySQL => "sqlx-mysql",
+            DatabaseType::SQLite => "sqlx-sqlite",
+        };
+        format!(
+            "sea-orm = {{ version = \"1.1\", features = [\"runtime-tokio-rustls\", \"{}\", \"macros\"] }}\n",
+            db_feature
+        )
+    }
+
+    fn generate_state(&self, ctx: &GenerationContext) -> GeneratedFile {
+        let mut content = String::with_capacity(1024);
+
+        if ctx.generate_docs() {
+            content.push_str("//! Application state shared across all handlers.\n\n");
+        }
+
+        content.push_str("use sea_orm::DatabaseConnection;\n");
+        content.push_str("use crate::config::Config;\n\n");
+
+        if ctx.read_replicas() {
+            content.push_str(
+                "\
+/// Shared application state, handed to every request handler.
+///
+/// Holds two connection pools: `db` points at the primary and takes every
+/// write, `db_read` points at a read replica and serves list/get requests.
+/// Replicas lag behind the primary, so code that must observe its own
+/// writes should read through `db`.
+#[derive(Clone)]
+pub struct AppState {
+    /// SeaORM connection pool for the primary (writes).
+    pub db: DatabaseConnection,
+    /// SeaORM connection pool for the read replica.
+    pub db_read: DatabaseConnection,
+    /// Application configuration.
+    pub config: Config,
+}
+
+impl AppState {
+    /// Create a new `AppState`.
+    pub fn new(db: DatabaseConnection, db_read: DatabaseConnection, config: Config) -> Self {
+        Self {
+            db,
+            db_read,
+            config,
+        }
+    }
+}
+",
+            );
+        } else {
+            content.push_str(
+                "\
+/// Shared application state, handed to every request handler.
+#[derive(Clone)]
+pub struct AppState {
+    /// SeaORM database connection pool.
+    pub db: DatabaseConnection,
+    /// Application configuration.
+    pub config: Config,
+}
+
+impl AppState {
+    /// Create a new `AppState`.
+    pub fn new(db: DatabaseConnection, config: Config) -> Self {
+        Self { db, config }
+    }
+}
+",
+            );
+        }
+
+        GeneratedFile::new("src/state.rs", content, crate::FileType::Rust)
+    }
+
+    fn config_methods(&self, ctx: &GenerationContext) -> String {
+        let mut out = String::from(
+            "\
+\n    /// Build SeaORM `ConnectOptions` from the loaded configuration.
+    pub fn database_connect_options(&self) -> sea_orm::ConnectOptions {
+        let mut opt = sea_orm::ConnectOptions::new(&self.database_url);
+        opt.max_connections(self.database_max_connections)
+            .min_connections(self.database_min_connections)
+            .sqlx_logging(true)
+            .sqlx_logging_level(tracing::log::LevelFilter::Debug);
+        opt
+    }
+",
+        );
+
+        if ctx.read_replicas() {
+            out.push_str(
+                "\
+\n    /// Build SeaORM `ConnectOptions` for the read-replica connection.
+    pub fn database_read_connect_options(&self) -> sea_orm::ConnectOptions {
+        let mut opt = sea_orm::ConnectOptions::new(&self.database_read_url);
+        opt.max_connections(self.database_max_connections)
+            .min_connections(self.database_min_connections)
+            .sqlx_logging(true)
+            .sqlx_logging_level(tracing::log::LevelFilter::Debug);
+        opt
+    }
+",
+            );
+        }
+
+        out
+    }
+
+    fn main_imports(&self) -> &'static str {
+        "use sea_orm::Database;\n"
+    }
+
+    fn connect_expr(&self, read_replica: bool) -> &'static str {
+        if read_replica {
+            "Database::connect(config.database_read_connect_options())\n        .await"
+        } else {
+            "Database::connect(config.database_connect_options())\n        .await"
+        }
+    }
+
+    fn test_connect_expr(&self) -> &'static str {
+        "sea_orm::Database::connect(config.database_connect_options())\n            .await"
+    }
+
+    fn log_filter(&self) -> &'static str {
+        "info,tower_http=debug,sea_orm=info"
+    }
+
+    fn database_check(&self, read_replica: bool) -> String {
+        let replica = if read_replica {
+            "    state.db_read.ping().await?;\n"
+        } else {
+            ""
+        };
+        format!(
+            "\
+/// Ping the database; the instance is not ready while it is unreachable.
+async fn check_database(state: &AppState) -> Result<(), AppError> {{
+    state.db.ping().await?;
+{replica}    Ok(())
+}}
+"
+        )
+    }
+
+    fn store_imports(&self) -> &'static str {
+        "use sea_orm::{ConnectionTrait, Statement};\n"
+    }
+
+    fn session_store(&self) -> &'static str {
+        "\
+async fn insert_token(
+    state: &AppState,
+    token_hash: String,
+    token: RefreshToken,
+    now: i64,
+) -> Result<(), AppError> {
+    let values = [
+        token_hash.into(),
+        token.session_id.into(),
+        token.user_id.into(),
+        token.identifier.into(),
+        token.roles.into(),
+        token.expires_at.into(),
+        now.into(),
+    ];
+    state.db.execute(statement(state, INSERT_SQL, values)).await?;
+    Ok(())
+}
+
+async fn find_token(state: &AppState, token_hash: String) -> Result<Option<RefreshToken>, AppError> {
+    let statement = statement(state, FIND_SQL, [token_hash.into()]);
+    let Some(row) = state.db.query_one(statement).await? else {
+        return Ok(None);
+    };
+    Ok(Some(RefreshToken {
+        session_id: row.try_get(\"\", \"session_id\")?,
+        user_id: row.try_get(\"\", \"user_id\")?,
+        identifier: row.try_get(\"\", \"identifier\")?,
+        roles: row.try_get(\"\", \"roles\")?,
+        expires_at: row.try_get(\"\", \"expires_at\")?,
+        revoked_at: row.try_get(\"\", \"revoked_at\")?,
+    }))
+}
+
+/// Run one of the `REVOKE_…_SQL` statements, answering the rows revoked.
+async fn revoke(state: &AppState, sql: &str, key: String, now: i64) -> Result<u64, AppError> {
+    let result = state.db.execute(statement(state, sql, [now.into(), key.into()])).await?;
+    Ok(result.rows_affected())
+}
+
+async fn count_live(state: &AppState, session_id: String, now: i64) -> Result<i64, AppError> {
+    let statement = statement(state, LIVE_SQL, [session_id.into(), now.into()]);
+    match state.db.query_one(statement).await? {
+        Some(row) => Ok(row.try_get(\"\", \"live\")?),
+        None => Ok(0),
+    }
+}
+
+fn statement<const N: usize>(state: &AppState, sql: &str, values: [sea_orm::Value; N]) -> Statement {
+    Statement::from_sql_and_values(state.db.get_database_backend(), sql, values)
+}
+"
+    }
+
+    fn oauth_store(&self) -> &'static str {
+        "\
+/// Store a started sign-in, dropping the ones that expired unfinished.
+async fn insert_state(
+    state: &AppState,
+    csrf: String,
+    pending: PendingState,
+    now: i64,
+) -> Result<(), AppError> {
+    state.db.execute(statement(state, PURGE_STATES_SQL, [now.into()])).await?;
+    let values = [
+        csrf.into(),
+        pending.provider.into(),
+        pending.code_verifier.into(),
+        pending.expires_at.into(),
+    ];
+    state.db.execute(statement(state, INSERT_STATE_SQL, values)).await?;
+    Ok(())
+}
+
+/// Look up and delete a started sign-in, so each state is used once.
+async fn take_state(state: &AppState, csrf: String) -> Result<Option<PendingState>, AppError> {
+    let find = statement(state, FIND_STATE_SQL, [csrf.clone().into()]);
+    let Some(row) = state.db.query_one(find).await? else {
+        return Ok(None);
+    };
+    // A concurrent callback with the same state may have taken it first
+    let deleted = state.db.execute(statement(state, DELETE_STATE_SQL, [csrf.into()])).await?;
+    if deleted.rows_affected() == 0 {
+        return Ok(None);
+    }
+    Ok(Some(PendingState {
+        provider: row.try_get(\"\", \"provider\")?,
+        code_verifier: row.try_get(\"\", \"code_verifier\")?,
+        expires_at: row.try_get(\"\", \"expires_at\")?,
+    }))
+}
+
+async fn find_account(state: &AppState, provider: String, subject: String) -> Result<Option<String>, AppError> {
+    let statement = statement(state, FIND_ACCOUNT_SQL, [provider.into(), subject.into()]);
+    match state.db.query_one(statement).await? {
+        Some(row) => Ok(Some(row.try_get(\"\", \"user_id\")?)),
+        None => Ok(None),
+    }
+}
+
+async fn insert_account(state: &AppState, profile: &Profile, user_id: String, now: i64) -> Result<(), AppError> {
+    let values = [
+        profile.provider.clone().into(),
+        profile.subject.clone().into(),
+        user_id.into(),
+        profile.email.clone().into(),
+        now.into(),
+    ];
+    state.db.execute(statement(state, INSERT_ACCOUNT_SQL, values)).await?;
+    Ok(())
+}
+
+fn statement<const N: usize>(state: &AppState, sql: &str, values: [sea_orm::Value; N]) -> Statement {
+    Statement::from_sql_and_values(state.db.get_database_backend(), sql, values)
+}
+"
+    }
+
+    fn error_conversion(&self) -> &'static str {
+        "\
+impl From<sea_orm::DbErr> for AppError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        let msg = err.to_string();
+
+        // Detect unique constraint violations for a friendlier error
+        if msg.contains(\"duplicate key\")
+            || msg.contains(\"UNIQUE constraint failed\")
+            || msg.contains(\"Duplicate entry\")
+        {
+            return AppError::Conflict(
+                \"A record with the given unique field(s) already exists\".to_string(),
+            );
+        }
+
+        // Detect foreign key violations
+        if msg.contains(\"foreign key constraint\")
+            || msg.contains(\"FOREIGN KEY constraint failed\")
+        {
+            return AppError::BadRequest(
+                \"Referenced record does not exist\".to_string(),
+            );
+        }
+
+        AppError::Database(msg)
+    }
+}
+"
+    }
+
+    fn error_conversion_tests(&self) -> &'static str {
+        "
+    #[test]
+    fn test_from_db_err_duplicate_key() {
+        let db_err = sea_orm::DbErr::Query(sea_orm::RuntimeErr::Internal(
+            \"duplicate key value violates unique constraint\".to_string(),
+        ));
+        let app_err = AppError::from(db_err);
+        assert!(matches!(app_err, AppError::Conflict(_)));
+    }
+
+    #[test]
+    fn test_from_db_err_generic() {
+        let db_err = sea_orm::DbErr::Conn(sea_orm::RuntimeErr::Internal(
+            \"connection refused\".to_string(),
+        ));
+        let app_err = AppError::from(db_err);
+        assert!(matches!(app_err, AppError::Database(_)));
+    }
+"
+    }
+
+    fn generate_models(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        models::generate_models(ctx)
+    }
+
+    fn enum_module_imports(&self, _ctx: &GenerationContext) -> String {
+        "use sea_orm::entity::prelude::*;\n".to_string()
+    }
+
+    fn enum_mapping(&self, def: &EnumDef, ctx: &GenerationContext) -> EnumMapping {
+        // PostgreSQL columns use the enum type the migrations create
+        let storage = match ctx.database() {
+            DatabaseType::PostgreSQL => format!(
+                "db_type = \"Enum\", enum_name = \"{}\"",
+                GenerationContext::enum_type_name(&def.name)
+            ),
+            DatabaseType::MySQL | DatabaseType::SQLite => "db_type = \"Text\"".to_string(),
+        };
+        EnumMapping {
+            derives: vec!["EnumIter".to_string(), "DeriveActiveEnum".to_string()],
+            attributes: vec![format!("#[sea_orm(rs_type = \"String\", {})]", storage)],
+            variant_attribute: Some(|value| format!("#[sea_orm(string_value = \"{}\")]", value)),
+            impls: String::new(),
+        }
+    }
+
+    fn generate_handlers(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        let mut files = handlers::generate_handlers(ctx);
+        files.extend(queries::generate_queries(ctx));
+        files
+    }
+
+    fn generate_migrations(&self, ctx: &GenerationContext) -> Vec<GeneratedFile> {
+        migrations::generate_migrations(ctx)
+    }
+
+    fn generate_alter_migration(
+        &self,
+        plan: &SchemaMigration,
+        ctx: &GenerationContext,
+    ) -> Vec<GeneratedFile> {
+        migrations::generate_alter_migration(plan, ctx)
+            .into_iter()
+            .collect()
+    }
+
+    fn readme_migrate_step(&self) -> &'static str {
+        "# (Apply the SQL files in the migrations/ directory)"
+    }
+
+    fn seed_apply(&self) -> &'static str {
+        r#"/// Run the seed files in one transaction.
+async fn apply(config: &Config, files: &[PathBuf]) -> anyhow::Result<()> {
+    use sea_orm::{ConnectionTrait, Database, TransactionTrait};
+
+    let db = Database::connect(config.database_connect_options())
+        .await
+        .context("failed to connect to database")?;
+
+    let txn = db.begin().await?;
+    for path in files {
+        let sql = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        txn.execute_unprepared(&sql)
+            .await
+            .with_context(|| format!("failed to apply {}", path.display()))?;
+        println!("  applied {}", path.display());
+    }
+    txn.commit().await?;
+    Ok(())
+}
+"#
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_lookup() {
+        assert_eq!(backend(Orm::SeaOrm).orm(), Orm::SeaOrm);
+        assert_eq!(backend(Orm::Diesel).orm(), Orm::Diesel);
+    }
+
+    #[test]
+    fn test_sea_orm_supports_everything() {
+        for feature in [
+            OrmFeature::CustomQueries,
+            OrmFeature::NestedCreate,
+            OrmFeature::RelatedAggregates,
+            OrmFeature::ListQueryParams,
+            OrmFeature::DeleteBlockers,
+            OrmFeature::GraphQl,
+            OrmFeature::Associations,
+            OrmFeature::Polymorphic,
+            OrmFeature::BackgroundJobs,
+            OrmFeature::ScopedUniqueChecks,
+        ] {
+            assert!(SeaOrmBackend.supports(feature), "{:?}", feature);
+        }
+    }
+}
@@ -0,0 +1,847 @@
+//! # Custom Query Generator
+//!
+//! Generates read-only `GET` endpoints for the custom queries defined with
+//! the visual query builder. Each [`QueryDefinition`] produces:
+//!
+//! - a `{Query}Params` struct for optional query-string filters and `limit`
+//! - a `{Query}Row` struct (`FromQueryResult`) holding the projected columns
+//! - an async handler that builds the SeaORM select with joins, filters,
+//!   ordering and limit
+//!
+//! ## Generated Files
+//!
+//! - `src/handlers/queries.rs` — all custom query handlers (only when the
+//!   project defines at least one enabled query)
+//!
+//! ## Field References
+//!
+//! Filters, sort keys and projections refer to `field` (root entity) or
+//! `Entity.field` (joined entity). Columns from joined entities are exposed
+//! as `{entity}_{field}` in the row struct. References that cannot be
+//! resolved are skipped; the IR `QueriesRule` reports them before generation.
+
+use imortal_core::DataType;
+use imortal_ir::{
+    Entity, Field, FilterOperator, FilterValue, QueryDefinition, Relationship, SortDirection,
+};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::framework::Extractor;
+use crate::rust::models::field_rust_type;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/handlers/queries.rs` if the project has enabled queries.
+pub fn generate_queries(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    let resolved: Vec<ResolvedQuery> = ctx
+        .queries()
+        .iter()
+        .filter_map(|q| ResolvedQuery::new(q, ctx))
+        .collect();
+
+    if resolved.is_empty() {
+        return Vec::new();
+    }
+
+    let mut content = String::with_capacity(4096);
+
+    content.push_str(&file_header(
+        "Custom query endpoints built with the query builder.",
+    ));
+    content.push_str(&generate_imports(&resolved, ctx));
+
+    for query in &resolved {
+        content.push_str(
+            "// ============================================================================\n",
+        );
+        content.push_str(&format!("// {}\n", query.def.name));
+        content.push_str(
+            "// ============================================================================\n\n",
+        );
+        content.push_str(&generate_params_struct(query, ctx));
+        content.push('\n');
+        content.push_str(&generate_row_struct(query, ctx));
+        content.push('\n');
+        content.push_str(&generate_query_handler(query, ctx));
+        content.push('\n');
+    }
+
+    vec![GeneratedFile::new(
+        "src/handlers/queries.rs",
+        content,
+        FileType::Rust,
+    )]
+}
+
+/// Name of the handler function generated for a query.
+pub fn query_handler_name(query: &QueryDefinition) -> String {
+    GenerationContext::snake(&query.name)
+}
+
+// ============================================================================
+// Resolution
+// ============================================================================
+
+/// A query with its entity, join and field references resolved against
+/// the generation context.
+struct ResolvedQuery<'a> {
+    def: &'a QueryDefinition,
+    root: &'a Entity,
+    joins: Vec<ResolvedJoin<'a>>,
+    columns: Vec<ResolvedColumn<'a>>,
+}
+
+struct ResolvedJoin<'a> {
+    entity: &'a Entity,
+    /// SeaORM `RelationDef` expression, e.g. `user::Relation::Posts.def()`
+    relation_expr: String,
+    optional: bool,
+}
+
+struct ResolvedColumn<'a> {
+    entity: &'a Entity,
+    field: &'a Field,
+    /// Column path, e.g. `post::Column::Title`
+    column: String,
+    /// Name in the row struct / SQL alias
+    alias: String,
+    /// Rust type in the row struct
+    rust_type: String,
+}
+
+impl<'a> ResolvedQuery<'a> {
+    fn new(def: &'a QueryDefinition, ctx: &'a GenerationContext) -> Option<Self> {
+        let root = ctx.entity_by_id(def.entity_id)?;
+
+        let joins = def
+            .joins
+            .iter()
+            .filter_map(|join| {
+                let rel = ctx
+                    .relationships()
+                    .iter()
+                    .find(|r| r.id == join.relationship_id)?;
+                let (entity, relation_expr) = join_relation(root, rel, ctx)?;
+                Some(ResolvedJoin {
+                    entity,
+                    relation_expr,
+                    optional: join.optional,
+                })
+            })
+            .collect();
+
+        let mut query = Self {
+            def,
+            root,
+            joins,
+            columns: Vec::new(),
+        };
+
+        query.columns = if def.projection.is_empty() {
+            ctx.response_fields(root)
+                .into_iter()
+                .filter(|field| field.is_column())
+                .map(|field| query.column_for(None, field, ctx))
+                .collect()
+        } else {
+            def.projection
+                .iter()
+                .filter_map(|field_ref| query.resolve(field_ref, ctx))
+                .collect()
+        };
+
+        Some(query)
+    }
+
+    /// Resolve a `field` / `Entity.field` reference.
+    fn resolve(&self, field_ref: &str, ctx: &'a GenerationContext) -> Option<ResolvedColumn<'a>> {
+        let (entity_name, field_name) = imortal_ir::query::split_field_ref(field_ref);
+
+        let join = match entity_name {
+            Some(name) if name != self.root.name => {
+                Some(self.joins.iter().find(|j| j.entity.name == name)?)
+            }
+            _ => None,
+        };
+
+        let entity = join.map(|j| j.entity).unwrap_or(self.root);
+        // Model getters have no column to select or filter on
+        let field = entity
+            .get_field_by_name(field_name)
+            .filter(|field| field.is_column())?;
+        Some(self.column_for(join, field, ctx))
+    }
+
+    fn column_for(
+        &self,
+        join: Option<&ResolvedJoin<'a>>,
+        field: &'a Field,
+        ctx: &'a GenerationContext,
+    ) -> ResolvedColumn<'a> {
+        let entity = join.map(|j| j.entity).unwrap_or(self.root);
+        let module = GenerationContext::module_name(&entity.name);
+        let field_snake = GenerationContext::snake(&field.name);
+
+        let alias = match join {
+            Some(_) => format!("{}_{}", GenerationContext::snake(&entity.name), field_snake),
+            None => field_snake,
+        };
+
+        let mut rust_type = field_rust_type(field, &EntityInfo::new(entity, ctx));
+        if join.is_some_and(|j| j.optional) && !rust_type.starts_with("Option<") {
+            rust_type = format!("Option<{}>", rust_type);
+        }
+
+        ResolvedColumn {
+            entity,
+            field,
+            column: format!(
+                "{}::Column::{}",
+                module,
+                GenerationContext::pascal(&field.name)
+            ),
+            alias,
+            rust_type,
+        }
+    }
+
+    fn pascal_name(&self) -> String {
+        GenerationContext::pascal(&self.def.name)
+    }
+
+    fn params_name(&self) -> String {
+        format!("{}Params", self.pascal_name())
+    }
+
+    fn row_name(&self) -> String {
+        format!("{}Row", self.pascal_name())
+    }
+
+    /// Query-string parameters in declaration order (deduplicated by name).
+    fn params(&self, ctx: &'a GenerationContext) -> Vec<(String, String)> {
+        self.param_columns(ctx)
+            .into_iter()
+            .map(|(name, operator, column)| {
+                let ty = match operator {
+                    FilterOperator::Contains => "String".to_string(),
+                    _ => param_rust_type(&column.rust_type),
+                };
+                (name, ty)
+            })
+            .collect()
+    }
+
+    /// Parameter filters with the column each compares against, in
+    /// declaration order (deduplicated by parameter name).
+    fn param_columns(
+        &self,
+        ctx: &'a GenerationContext,
+    ) -> Vec<(String, FilterOperator, ResolvedColumn<'a>)> {
+        let mut params: Vec<(String, FilterOperator, ResolvedColumn<'a>)> = Vec::new();
+
+        for filter in &self.def.filters {
+            let FilterValue::Param(name) = &filter.value else {
+                continue;
+            };
+            let name = GenerationContext::snake(name);
+            if params.iter().any(|(n, _, _)| *n == name) {
+                continue;
+            }
+            let Some(column) = self.resolve(&filter.field, ctx) else {
+                continue;
+            };
+            params.push((name, filter.operator, column));
+        }
+
+        params
+    }
+}
+
+// ============================================================================
+// Client-facing shape (used by the OpenAPI spec)
+// ============================================================================
+
+/// A query-string parameter of a query endpoint.
+pub(crate) struct QueryParam<'a> {
+    pub name: String,
+    /// Field the parameter is compared against; `None` for `contains`
+    /// filters, which always take a string
+    pub field: Option<&'a Field>,
+}
+
+/// A column of the rows a query endpoint returns.
+pub(crate) struct QueryColumn<'a> {
+    pub name: String,
+    pub entity: &'a Entity,
+    pub field: &'a Field,
+    /// Whether the value can be null (optional field or optional join)
+    pub nullable: bool,
+}
+
+/// The parameters and row columns of a query as API clients see them, or
+/// `None` when its root entity no longer exists.
+pub(crate) fn query_shape<'a>(
+    def: &'a QueryDefinition,
+    ctx: &'a GenerationContext,
+) -> Option<(Vec<QueryParam<'a>>, Vec<QueryColumn<'a>>)> {
+    let query = ResolvedQuery::new(def, ctx)?;
+
+    let params = query
+        .param_columns(ctx)
+        .into_iter()
+        .map(|(name, operator, column)| QueryParam {
+            name,
+            field: (operator != FilterOperator::Contains).then_some(column.field),
+        })
+        .collect();
+
+    let columns = query
+        .columns
+        .into_iter()
+        .map(|column| QueryColumn {
+            nullable: column.rust_type.starts_with("Option<"),
+            name: column.alias,
+            entity: column.entity,
+            field: column.field,
+        })
+        .collect();
+
+    Some((params, columns))
+}
+
+/// Build the `RelationDef` expression for joining `rel` from `root`.
+///
+/// SeaORM only declares `Relation` variants on the source side of an
+/// outgoing relationship, so joins where the root is the target use the
+/// source entity's variant reversed.
+fn join_relation<'a>(
+    root: &Entity,
+    rel: &Relationship,
+    ctx: &'a GenerationContext,
+) -> Option<(&'a Entity, String)> {
+    let variant = |target: &Entity| {
+        let name = GenerationContext::pascal(&rel.name);
+        if name.is_empty() {
+            GenerationContext::pascal(&target.name)
+        } else {
+            name
+        }
+    };
+
+    if rel.is_polymorphic() {
+        // No SeaORM relation: the key points at several tables
+        None
+    } else if rel.from_entity_id == root.id {
+        let target = ctx.entity_by_id(rel.to_entity_id)?;
+        Some((
+            target,
+            format!(
+                "{}::Relation::{}.def()",
+                GenerationContext::module_name(&root.name),
+                variant(target)
+            ),
+        ))
+    } else if rel.to_entity_id == root.id {
+        let source = ctx.entity_by_id(rel.from_entity_id)?;
+        Some((
+            source,
+            format!(
+                "{}::Relation::{}.def().rev()",
+                GenerationContext::module_name(&source.name),
+                variant(root)
+            ),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Type of a query-string parameter compared against a column.
+fn param_rust_type(column_type: &str) -> String {
+    column_type
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(column_type)
+        .to_string()
+}
+
+/// Render a literal filter value as a Rust expression for the column type.
+fn literal_expr(data_type: &DataType, raw: &str) -> String {
+    let base = match data_type {
+        DataType::Optional(inner) => inner.as_ref(),
+        other => other,
+    };
+
+    match base {
+        DataType::Int32 | DataType::Int64 if raw.parse::<i64>().is_ok() => raw.to_string(),
+        DataType::Float32 | DataType::Float64 if raw.parse::<f64>().is_ok() => {
+            if raw.contains('.') {
+                raw.to_string()
+            } else {
+                format!("{}.0", raw)
+            }
+        }
+        DataType::Decimal { .. } if raw.parse::<f64>().is_ok() => decimal_literal(raw),
+        DataType::Bool if raw == "true" || raw == "false" => raw.to_string(),
+        DataType::Uuid | DataType::Reference { .. } if uuid::Uuid::parse_str(raw).is_ok() => {
+            format!("uuid::uuid!(\"{}\")", raw)
+        }
+        _ => format!("{:?}", raw),
+    }
+}
+
+/// Render a plain decimal string as `Decimal::new(mantissa, scale)`, falling
+/// back to parsing at runtime for anything that isn't `[-]digits[.digits]`.
+fn decimal_literal(raw: &str) -> String {
+    let (int, frac) = raw.split_once('.').unwrap_or((raw, ""));
+    let digits = int.trim_start_matches('-');
+    let plain = !digits.is_empty()
+        && digits
+            .chars()
+            .chain(frac.chars())
+            .all(|c| c.is_ascii_digit())
+        && frac.len() <= DataType::MAX_DECIMAL_PRECISION as usize;
+
+    match format!("{}{}", int, frac).parse::<i64>() {
+        Ok(mantissa) if plain => {
+            format!("rust_decimal::Decimal::new({}, {})", mantissa, frac.len())
+        }
+        _ => format!(
+            "{:?}.parse::<rust_decimal::Decimal>().expect(\"valid decimal\")",
+            raw
+        ),
+    }
+}
+
+// ============================================================================
+// Imports
+// ============================================================================
+
+fn generate_imports(queries: &[ResolvedQuery], ctx: &GenerationContext) -> String {
+    let has_filters = queries.iter().any(|q| !q.def.filters.is_empty());
+    let has_joins = queries.iter().any(|q| !q.joins.is_empty());
+    let has_sort = queries.iter().any(|q| !q.def.sort.is_empty());
+
+    let types: Vec<&str> = queries
+        .iter()
+        .flat_map(|q| q.columns.iter().map(|c| c.rust_type.as_str()))
+        .collect();
+
+    let mut out = String::with_capacity(1024);
+
+    out.push_str(
+        &ctx.framework_backend()
+            .handler_imports(&[Extractor::Query, Extractor::State], false),
+    );
+
+    let mut sea_imports = vec!["EntityTrait", "FromQueryResult", "QuerySelect"];
+    if has_filters {
+        sea_imports.push("ColumnTrait");
+        sea_imports.push("QueryFilter");
+    }
+    if has_joins {
+        sea_imports.push("JoinType");
+        sea_imports.push("RelationTrait");
+    }
+    if has_sort {
+        sea_imports.push("QueryOrder");
+    }
+    sea_imports.sort_unstable();
+    out.push_str(&format!("use sea_orm::{{{}}};\n", sea_imports.join(", ")));
+
+    out.push_str("use serde::{Deserialize, Serialize};\n");
+    if ctx.openapi_enabled() {
+        out.push_str("use utoipa::{IntoParams, ToSchema};\n");
+    }
+    if types.iter().any(|t| t.contains("DateTime<Utc>")) {
+        out.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    if types.iter().any(|t| t.contains("Uuid")) {
+        out.push_str("use uuid::Uuid;\n");
+    }
+    if types.iter().any(|t| t.contains("Decimal")) {
+        out.push_str("use rust_decimal::Decimal;\n");
+    }
+
+    out.push('\n');
+    out.push_str("use crate::error::AppError;\n");
+    out.push_str("use crate::state::AppState;\n");
+
+    let mut modules: Vec<String> = queries
+        .iter()
+        .flat_map(|q| std::iter::once(q.root).chain(q.joins.iter().map(|j| j.entity)))
+        .map(|e| GenerationContext::module_name(&e.name))
+        .collect();
+    modules.sort();
+    modules.dedup();
+    out.push_str(&format!("use crate::models::{{{}}};\n", modules.join(", ")));
+
+    out.push('\n');
+    out
+}
+
+// ============================================================================
+// DTOs
+// ============================================================================
+
+fn generate_params_struct(query: &ResolvedQuery, ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(512);
+
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Query-string parameters for `GET {}`.",
+            query.def.path
+        )),
+        ctx,
+    ));
+
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Default, Deserialize, IntoParams)]\n");
+        out.push_str("#[into_params(parameter_in = Query)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Default, Deserialize)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", query.params_name()));
+
+    for (name, ty) in query.params(ctx) {
+        out.push_str(&format!("    pub {}: Option<{}>,\n", name, ty));
+    }
+
+    match query.def.limit {
+        Some(max) => out.push_str(&format!(
+            "    /// Maximum number of rows to return (at most {})\n",
+            max
+        )),
+        None => out.push_str("    /// Maximum number of rows to return\n"),
+    }
+    out.push_str("    pub limit: Option<u64>,\n");
+    out.push_str("}\n");
+
+    out
+}
+
+fn generate_row_struct(query: &ResolvedQuery, ctx: &GenerationContext) -> String {
+    let mut out = String::with_capacity(512);
+
+    out.push_str(&doc_comment(
+        Some(&format!("Row returned by `GET {}`.", query.def.path)),
+        ctx,
+    ));
+
+    if ctx.openapi_enabled() {
+        out.push_str("#[derive(Debug, Clone, Serialize, FromQueryResult, ToSchema)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, Serialize, FromQueryResult)]\n");
+    }
+    out.push_str(&format!("pub struct {} {{\n", query.row_name()));
+
+    for column in &query.columns {
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            column.alias, column.rust_type
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// Handler
+// ============================================================================
+
+fn generate_query_handler(query: &ResolvedQuery, ctx: &GenerationContext) -> String {
+    let fn_name = query_handler_name(query.def);
+    let params_name = query.params_name();
+    let row_name = query.row_name();
+    let root_module = GenerationContext::module_name(&query.root.name);
+
+    let mut out = String::with_capacity(2048);
+
+    let description = query
+        .def
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("Custom query over {}.", query.root.name));
+
+    out.push_str(&doc_comment(
+        Some(&format!("{}\n\nGET {}", description, query.def.path)),
+        ctx,
+    ));
+
+    if ctx.openapi_enabled() {
+        out.push_str(&format!(
+            "#[utoipa::path(\n    get,\n    path = \"{}\",\n    params({}),\n    responses(\n        (status = 200, description = {:?}, body = [{}])\n    ),\n    tag = \"queries\"\n)]\n",
+            query.def.path, params_name, description, row_name
+        ));
+    }
+
+    out.push_str(&format!(
+        "pub async fn {fn_name}(\n    {state_param},\n    Query(params): Query<{params_name}>,\n) -> Result<Json<Vec<{row_name}>>, AppError> {{\n",
+        state_param = ctx.framework_backend().state_param(),
+    ));
+
+    // Base select with projection and joins
+    let param_filters: Vec<_> = query
+        .def
+        .filters
+        .iter()
+        .filter(|f| matches!(f.value, FilterValue::Param(_)))
+        .collect();
+    let binding = if param_filters.is_empty() {
+        "let select"
+    } else {
+        "let mut select"
+    };
+
+    out.push_str(&format!(
+        "    {} = {}::Entity::find()\n        .select_only()\n",
+        binding, root_module
+    ));
+    for column in &query.columns {
+        out.push_str(&format!(
+            "        .column_as({}, \"{}\")\n",
+            column.column, column.alias
+        ));
+    }
+    for join in &query.joins {
+        let join_type = if join.optional {
+            "LeftJoin"
+        } else {
+            "InnerJoin"
+        };
+        out.push_str(&format!(
+            "        .join(JoinType::{}, {})\n",
+            join_type, join.relation_expr
+        ));
+    }
+
+    // Fixed filters
+    for filter in &query.def.filters {
+        let Some(column) = query.resolve(&filter.field, ctx) else {
+            continue;
+        };
+        let method = filter.operator.sea_orm_method();
+        match &filter.value {
+            FilterValue::Param(_) => {}
+            _ if !filter.operator.takes_value() => {
+                out.push_str(&format!(
+                    "        .filter({}.{}())\n",
+                    column.column, method
+                ));
+            }
+            FilterValue::Literal(raw) => {
+                out.push_str(&format!(
+                    "        .filter({}.{}({}))\n",
+                    column.column,
+                    method,
+                    literal_expr(&column.field.data_type, raw)
+                ));
+            }
+            FilterValue::None => {}
+        }
+    }
+    out.push_str("        ;\n\n");
+
+    // Optional filters from query-string parameters
+    for filter in &param_filters {
+        let (FilterValue::Param(name), Some(column)) =
+            (&filter.value, query.resolve(&filter.field, ctx))
+        else {
+            continue;
+        };
+        out.push_str(&format!(
+            "    if let Some(value) = params.{} {{\n        select = select.filter({}.{}(value));\n    }}\n",
+            GenerationContext::snake(name),
+            column.column,
+            filter.operator.sea_orm_method(),
+        ));
+    }
+    if !param_filters.is_empty() {
+        out.push('\n');
+    }
+
+    // Ordering, limit and execution
+    out.push_str("    let rows = select\n");
+    for sort in &query.def.sort {
+        let Some(column) = query.resolve(&sort.field, ctx) else {
+            continue;
+        };
+        let method = match sort.direction {
+            SortDirection::Asc => "order_by_asc",
+            SortDirection::Desc => "order_by_desc",
+        };
+        out.push_str(&format!("        .{}({})\n", method, column.column));
+    }
+    match query.def.limit {
+        Some(max) => out.push_str(&format!(
+            "        .limit(params.limit.unwrap_or({max}).min({max}))\n"
+        )),
+        None => out.push_str("        .limit(params.limit)\n"),
+    }
+    out.push_str(&format!(
+        "        .into_model::<{}>()\n        .all(&{})\n        .await\n        .map_err(AppError::from)?;\n\n",
+        row_name,
+        ctx.read_db()
+    ));
+
+    out.push_str("    Ok(Json(rows))\n}\n");
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::RelationType;
+    use imortal_ir::{ProjectGraph, QueryFilter, QueryJoin};
+
+    fn setup_project() -> (ProjectGraph, uuid::Uuid) {
+        let mut project = ProjectGraph::new("blog");
+
+        let mut user = Entity::new("User");
+        user.add_field(Field::new("email", DataType::String).required());
+        user.add_field(Field::new("is_active", DataType::Bool));
+        user.add_field(Field::new("password_hash", DataType::String).secret());
+        let user_id = user.id;
+
+        let mut post = Entity::new("Post");
+        post.add_field(Field::new("title", DataType::String).required());
+        post.add_field(Field::new("views", DataType::Int32));
+        let post_id = post.id;
+
+        project.add_entity(user);
+        project.add_entity(post);
+
+        let rel = Relationship::new(user_id, post_id, RelationType::OneToMany).with_name("posts");
+        let rel_id = rel.id;
+        project.relationships.insert(rel_id, rel);
+
+        let query = QueryDefinition::new("popular_posts", post_id, "Post")
+            .with_description("Most viewed posts with their authors.")
+            .with_join(QueryJoin::new(rel_id, "User"))
+            .with_filter(QueryFilter::literal("views", FilterOperator::Gte, "100"))
+            .with_filter(QueryFilter::param(
+                "User.email",
+                FilterOperator::Eq,
+                "author",
+            ))
+            .with_sort("views", SortDirection::Desc)
+            .select("title")
+            .select("views")
+            .select("User.email")
+            .with_limit(25);
+        project.add_query(query);
+
+        (project, user_id)
+    }
+
+    fn generated(project: &ProjectGraph) -> String {
+        let ctx = GenerationContext::from_project_default(project);
+        let files = generate_queries(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str().unwrap(), "src/handlers/queries.rs");
+        files[0].content.clone()
+    }
+
+    #[test]
+    fn test_no_queries_no_file() {
+        let project = ProjectGraph::new("empty");
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_queries(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_query_dtos() {
+        let (project, _) = setup_project();
+        let content = generated(&project);
+
+        assert!(content.contains("pub struct PopularPostsParams {"));
+        assert!(content.contains("pub author: Option<String>,"));
+        assert!(content.contains("pub limit: Option<u64>,"));
+        assert!(content.contains("pub struct PopularPostsRow {"));
+        assert!(content.contains("pub title: String,"));
+        assert!(content.contains("pub views: i32,"));
+        assert!(content.contains("pub user_email: String,"));
+        assert!(content.contains("FromQueryResult"));
+    }
+
+    #[test]
+    fn test_query_handler() {
+        let (project, _) = setup_project();
+        let content = generated(&project);
+
+        assert!(content.contains("pub async fn popular_posts("));
+        assert!(content.contains("post::Entity::find()"));
+        assert!(content.contains(".column_as(user::Column::Email, \"user_email\")"));
+        // Post is the target of User.posts, so the join is reversed
+        assert!(content.contains(".join(JoinType::InnerJoin, user::Relation::Posts.def().rev())"));
+        assert!(content.contains(".filter(post::Column::Views.gte(100))"));
+        assert!(content.contains("if let Some(value) = params.author {"));
+        assert!(content.contains("select.filter(user::Column::Email.eq(value))"));
+        assert!(content.contains(".order_by_desc(post::Column::Views)"));
+        assert!(content.contains(".limit(params.limit.unwrap_or(25).min(25))"));
+        assert!(content.contains(".into_model::<PopularPostsRow>()"));
+        assert!(content.contains("use crate::models::{post, user};"));
+    }
+
+    #[test]
+    fn test_default_projection_skips_secrets() {
+        let (mut project, user_id) = setup_project();
+        project.add_query(
+            QueryDefinition::new("active_users", user_id, "User").with_filter(
+                QueryFilter::literal("is_active", FilterOperator::Eq, "true"),
+            ),
+        );
+
+        let content = generated(&project);
+        assert!(content.contains("pub struct ActiveUsersRow {"));
+        assert!(content.contains(".filter(user::Column::IsActive.eq(true))"));
+        assert!(!content.contains("password_hash"));
+        // No parameter filters → no mutable binding
+        assert!(content.contains("let select = user::Entity::find()"));
+    }
+
+    #[test]
+    fn test_openapi_annotations() {
+        let (mut project, _) = setup_project();
+        project.config.openapi_enabled = true;
+        let content = generated(&project);
+
+        assert!(content.contains("#[utoipa::path("));
+        assert!(content.contains("path = \"/api/reports/popular_posts\""));
+        assert!(content.contains("body = [PopularPostsRow]"));
+        assert!(content.contains("IntoParams"));
+        assert!(content.contains("ToSchema"));
+    }
+
+    #[test]
+    fn test_literal_expr() {
+        assert_eq!(literal_expr(&DataType::Int32, "42"), "42");
+        assert_eq!(literal_expr(&DataType::Float64, "1"), "1.0");
+        assert_eq!(
+            literal_expr(&DataType::money(), "19.99"),
+            "rust_decimal::Decimal::new(1999, 2)"
+        );
+        assert_eq!(
+            literal_expr(&DataType::money(), "-5"),
+            "rust_decimal::Decimal::new(-5, 0)"
+        );
+        assert!(
+            literal_expr(&DataType::money(), "1e3").contains(".parse::<rust_decimal::Decimal>()")
+        );
+        assert_eq!(literal_expr(&DataType::Bool, "true"), "true");
+        assert_eq!(literal_expr(&DataType::String, "active"), "\"active\"");
+        assert_eq!(
+            literal_expr(&DataType::Optional(Box::new(DataType::Int64)), "7"),
+            "7"
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! # Rate Limit Generator
+//!
+//! Generates `src/rate_limit.rs` for projects where some REST route is rate
+//! limited: an operation's own [`RateLimit`], or the project default
+//! ([`ProjectConfig::rate_limit`]) for every operation and custom query
+//! without one. Each limited route gets a `Limiter` static counting
+//! requests in fixed windows; over the quota the route answers `429` (or
+//! the configured status) with a `Retry-After` header.
+//!
+//! | `RateLimit` | Counted per |
+//! |-------------|-------------|
+//! | `key: "ip"` | client IP address |
+//! | `key: "user"` / `"user_id"`, or `per_user` | signed-in user, else client IP |
+//! | `key: "global"`, or not `per_user` | route, for every client together |
+//!
+//! The quotas are constants (`{ROUTE}_RATE_LIMIT`,
+//! `{ROUTE}_RATE_WINDOW_SECS`) in `src/constants.rs`. Counts live in the
+//! server's memory, so each replica enforces its own quota.
+//!
+//! The middleware comes from
+//! [`FrameworkBackend::rate_limit_middleware`](crate::rust::framework::FrameworkBackend::rate_limit_middleware);
+//! the routers wrap limited routes inside `require_auth`, so per-user
+//! quotas see the caller.
+//!
+//! [`RateLimit`]: imortal_ir::RateLimit
+//! [`ProjectConfig::rate_limit`]: imortal_ir::ProjectConfig::rate_limit
+
+use imortal_ir::{CrudOperation, QueryDefinition, RateLimit};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::queries::query_handler_name;
+use crate::rust::routes::has_enabled_handlers;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/rate_limit.rs`, if any REST route is rate limited.
+pub fn generate_rate_limit(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.rate_limiting_enabled() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/rate_limit.rs",
+        build_rate_limit(ctx),
+        FileType::Rust,
+    )]
+}
+
+/// A rate limited route: the name of its `Limiter` static, what it serves
+/// and its limit.
+pub(crate) struct RouteLimit<'a> {
+    pub name: String,
+    pub serves: String,
+    pub limit: &'a RateLimit,
+}
+
+/// Every rate limited route, in router order.
+pub(crate) fn route_limits(ctx: &GenerationContext) -> Vec<RouteLimit<'_>> {
+    if !ctx.rest_enabled() {
+        return Vec::new();
+    }
+    let mut limits = Vec::new();
+    for entity in ctx.entities() {
+        let info = EntityInfo::new(entity, ctx);
+        let Some(endpoint) = info.endpoint().filter(|_| has_enabled_handlers(&info)) else {
+            continue;
+        };
+        for op in endpoint.enabled_operations() {
+            if let Some(limit) = ctx.rate_limit(op.rate_limit.as_ref()) {
+                limits.push(RouteLimit {
+                    name: limiter_name(&info, op),
+                    serves: format!(
+                        "{} `{}`",
+                        op.operation_type.display_name(),
+                        info.pascal_name()
+                    ),
+                    limit,
+                });
+            }
+        }
+    }
+    for query in ctx.queries() {
+        if let Some(limit) = ctx.rate_limit(None) {
+            limits.push(RouteLimit {
+                name: query_limiter_name(query),
+                serves: format!("Query `{}`", query.name),
+                limit,
+            });
+        }
+    }
+    limits
+}
+
+/// Name of the `Limiter` static of an entity operation, e.g. `USER_CREATE`.
+/// Routes riding on the operation (restore, links, uploads) share it.
+pub(crate) fn limiter_name(info: &EntityInfo, op: &CrudOperation) -> String {
+    format!("{}_{}", info.snake_name(), op.operation_type.display_name()).to_uppercase()
+}
+
+/// Name of the `Limiter` static of a custom query, e.g. `SEARCH_USERS`.
+pub(crate) fn query_limiter_name(query: &QueryDefinition) -> String {
+    query_handler_name(query).to_uppercase()
+}
+
+/// Names of a route's quota constants in `src/constants.rs`.
+pub(crate) fn quota_consts(name: &str) -> (String, String) {
+    (
+        format!("{name}_RATE_LIMIT"),
+        format!("{name}_RATE_WINDOW_SECS"),
+    )
+}
+
+/// Who `limit` counts requests for, as a `KeyBy` variant. Without auth
+/// there is no user to count for, so the client IP stands in.
+fn key_by(limit: &RateLimit, ctx: &GenerationContext) -> &'static str {
+    let per_user = match limit.key.as_deref() {
+        Some("ip") => return "Ip",
+        Some("global") => return "Global",
+        Some("user" | "user_id") => true,
+        _ => limit.per_user,
+    };
+    match (per_user, ctx.auth_enabled()) {
+        (true, true) => "User",
+        (true, false) => "Ip",
+        (false, _) => "Global",
+    }
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_rate_limit(ctx: &GenerationContext) -> String {
+    let framework = ctx.framework_backend();
+    let auth = ctx.auth_enabled();
+    let mut out = String::with_capacity(8192);
+
+    out.push_str(&file_header(
+        "Rate limiting — fixed-window request quotas per route, by client IP or user.",
+    ));
+    out.push_str(&framework.rate_limit_imports(auth));
+    out.push_str(
+        "\
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+",
+    );
+    if auth {
+        out.push_str("use crate::auth::jwt::Claims;\n");
+    }
+    out.push_str(
+        "\
+use crate::constants;
+use crate::error::AppError;
+
+/// Keys a limiter holds before it forgets the ones whose window has passed.
+const PRUNE_AT: usize = 10_000;
+
+/// Who a quota counts requests for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyBy {
+    /// Each client IP address.
+    Ip,
+",
+    );
+    if auth {
+        out.push_str(
+            "    /// Each signed-in user; anonymous requests count per client IP.\n    User,\n",
+        );
+    }
+    out.push_str(
+        "\
+    /// Every client together.
+    Global,
+}
+
+/// How many requests a route accepts per window, and from whom.
+#[derive(Debug, Clone, Copy)]
+pub struct Quota {
+    pub requests: u32,
+    pub window_secs: u64,
+    pub key_by: KeyBy,
+    /// Status answered over the quota, normally `429 Too Many Requests`.
+    pub status: u16,
+    pub message: &'static str,
+}
+
+/// A route's limiter: its quota and the current window of each key.
+pub struct Limiter {
+    quota: Quota,
+    windows: Mutex<BTreeMap<String, Window>>,
+}
+
+/// Requests counted for one key since `started`.
+struct Window {
+    started: Instant,
+    count: u32,
+}
+
+impl Limiter {
+    pub const fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            windows: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Count a request from `key`. Once the key has used its window up,
+    /// answers the seconds until the next window opens instead.
+    pub fn check(&self, key: String) -> Result<(), u64> {
+        let length = Duration::from_secs(self.quota.window_secs);
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+        if windows.len() >= PRUNE_AT {
+            windows.retain(|_, window| now.duration_since(window.started) < length);
+        }
+
+        let window = windows.entry(key).or_insert(Window { started: now, count: 0 });
+        if now.duration_since(window.started) >= length {
+            *window = Window { started: now, count: 0 };
+        }
+        if window.count >= self.quota.requests {
+            let remaining = length.saturating_sub(now.duration_since(window.started));
+            return Err(remaining.as_secs_f64().ceil() as u64);
+        }
+        window.count += 1;
+        Ok(())
+    }
+
+    /// The error answered over the quota.
+    fn exceeded(&self) -> AppError {
+        AppError::RateLimited(self.quota.message.to_string())
+    }
+
+    /// The status answered over the quota.
+    fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.quota.status).unwrap_or(StatusCode::TOO_MANY_REQUESTS)
+    }
+}
+
+",
+    );
+    out.push_str(&framework.rate_limit_middleware(auth));
+
+    out.push_str(
+        "
+// ============================================================================
+// Limiters
+// ============================================================================
+",
+    );
+    for route in route_limits(ctx) {
+        let (requests, window) = quota_consts(&route.name);
+        let message = route
+            .limit
+            .exceeded_message
+            .as_deref()
+            .unwrap_or("Rate limit exceeded, try again later");
+        out.push('\n');
+        out.push_str(&doc_comment(Some(&format!("{}.", route.serves)), ctx));
+        out.push_str(&format!(
+            "\
+pub static {name}: Limiter = Limiter::new(Quota {{
+    requests: constants::{requests},
+    window_secs: constants::{window},
+    key_by: KeyBy::{key_by},
+    status: {status},
+    message: {message:?},
+}});
+",
+            name = route.name,
+            key_by = key_by(route.limit, ctx),
+            status = route.limit.exceeded_status,
+        ));
+    }
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{AuthConfig, EndpointGroup, Entity, Field, OperationType, ProjectGraph};
+    use imortal_ir::{QueryDefinition, WebFramework};
+
+    fn project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        project.config.auth.enabled = false;
+        let mut user = Entity::new("User");
+        user.fields.push(Field::new("email", DataType::String));
+        let mut endpoint = EndpointGroup::new(user.id, "User");
+        endpoint
+            .get_operation_mut(OperationType::Create)
+            .unwrap()
+            .rate_limit = Some(RateLimit::strict());
+        project.add_entity(user);
+        project.add_endpoint(endpoint);
+        project
+    }
+
+    fn rate_limit(project: &ProjectGraph) -> Option<String> {
+        let ctx = GenerationContext::from_project_default(project);
+        generate_rate_limit(&ctx).pop().map(|file| file.content)
+    }
+
+    #[test]
+    fn test_rate_limit_only_when_a_route_is_limited() {
+        let mut project = project();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint
+            .get_operation_mut(OperationType::Create)
+            .unwrap()
+            .rate_limit = None;
+        assert!(rate_limit(&project).is_none());
+
+        project.config.rate_limit = Some(RateLimit::permissive());
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains("pub static USER_LIST: Limiter"));
+        assert!(content.contains("pub static USER_DELETE: Limiter"));
+    }
+
+    #[test]
+    fn test_operation_limit_overrides_default() {
+        let mut project = project();
+        project.config.rate_limit = Some(RateLimit::permissive().global());
+        let ctx = GenerationContext::from_project_default(&project);
+        let limits = route_limits(&ctx);
+        let create = limits.iter().find(|r| r.name == "USER_CREATE").unwrap();
+        assert_eq!(create.limit.requests, 10);
+        let read = limits.iter().find(|r| r.name == "USER_READ").unwrap();
+        assert_eq!(read.limit.requests, 100);
+
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains(
+            "pub static USER_CREATE: Limiter = Limiter::new(Quota {\n    requests: constants::USER_CREATE_RATE_LIMIT,\n    window_secs: constants::USER_CREATE_RATE_WINDOW_SECS,\n    key_by: KeyBy::Ip,\n    status: 429,"
+        ));
+        assert!(content.contains("    key_by: KeyBy::Global,\n"));
+    }
+
+    #[test]
+    fn test_rate_limit_keys() {
+        let mut project = project();
+        project.config.auth = AuthConfig::jwt();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert_eq!(key_by(&RateLimit::strict(), &ctx), "User");
+        assert_eq!(key_by(&RateLimit::strict().with_key("ip"), &ctx), "Ip");
+        assert_eq!(key_by(&RateLimit::strict().global(), &ctx), "Global");
+        assert_eq!(
+            key_by(&RateLimit::strict().global().with_key("user_id"), &ctx),
+            "User"
+        );
+
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains("use crate::auth::jwt::Claims;"));
+        assert!(content.contains("    User,\n"));
+        assert!(content.contains("KeyBy::User => match request.extensions().get::<Claims>() {"));
+    }
+
+    #[test]
+    fn test_rate_limit_message_and_queries() {
+        let mut project = project();
+        project.config.rate_limit = Some(RateLimit::permissive().with_message("Slow down"));
+        let entity_id = project.entities.keys().next().copied().unwrap();
+        project.add_query(QueryDefinition::new("search_users", entity_id, "User"));
+
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains("/// Query `search_users`.\npub static SEARCH_USERS: Limiter"));
+        assert!(content.contains("message: \"Slow down\","));
+        assert!(content.contains("message: \"Rate limit exceeded, try again later\","));
+    }
+
+    #[test]
+    fn test_rate_limit_framework_middleware() {
+        let mut project = project();
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains("State(limiter): State<&'static Limiter>,"));
+        assert!(content.contains("ConnectInfo<SocketAddr>"));
+        assert!(!content.contains("Claims"));
+
+        project.config.framework = WebFramework::ActixWeb;
+        let content = rate_limit(&project).unwrap();
+        assert!(content.contains("next: Next<impl MessageBody>,"));
+        assert!(content.contains("InternalError::from_response(error, response)"));
+        assert!(content.contains(".peer_addr()"));
+    }
+}
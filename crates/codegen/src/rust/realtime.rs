@@ -0,0 +1,238 @@
+//! # Realtime Change Feed Generator
+//!
+//! Generates `src/realtime.rs`: an in-process broadcast channel carrying
+//! entity change events from the code that makes the changes to live
+//! subscribers. Entities opt in through their endpoint group's `realtime`
+//! flag.
+//!
+//! REST handlers and GraphQL mutations of an opted-in entity publish to the
+//! feed. REST clients read it from the entity's `GET {base}/ws` WebSocket or
+//! `GET {base}/events` server-sent events, as the endpoint group's
+//! `realtime_transport` picks; the framework backend writes the `websocket`
+//! and `sse` functions those routes call. GraphQL clients read it through
+//! the entity's `postChanged` subscription. The feed itself knows nothing
+//! about GraphQL beyond deriving the output types.
+
+use crate::context::GenerationContext;
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+/// Changes a subscriber may fall behind by before it starts skipping some.
+const CHANNEL_CAPACITY: usize = 256;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/realtime.rs`, if any entity pushes its changes.
+pub fn generate_realtime(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.realtime_enabled() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/realtime.rs",
+        build_realtime(ctx),
+        FileType::Rust,
+    )]
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_realtime(ctx: &GenerationContext) -> String {
+    let graphql = ctx.graphql_enabled();
+    let mut out = String::with_capacity(2048);
+
+    out.push_str(&file_header(
+        "Entity change feed — pushes create/update/delete events to live subscribers.",
+    ));
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    out.push_str("use std::sync::OnceLock;\n\n");
+    out.push_str("use futures_util::stream::{self, Stream};\n");
+    out.push_str("use serde::Serialize;\n");
+    out.push_str("use tokio::sync::broadcast;\n\n");
+
+    // ── Event types ──────────────────────────────────────────────────────
+    let (kind_derive, change_derive) = if graphql {
+        ("async_graphql::Enum, ", "async_graphql::SimpleObject, ")
+    } else {
+        ("", "")
+    };
+    out.push_str(&doc_comment(Some("What happened to a record."), ctx));
+    out.push_str(&format!(
+        "\
+#[derive({kind_derive}Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = \"snake_case\")]
+pub enum ChangeKind {{
+    Created,
+    Updated,
+    Deleted,
+}}
+
+"
+    ));
+    out.push_str(&doc_comment(
+        Some("A change pushed to subscribers once it is committed."),
+        ctx,
+    ));
+    out.push_str(&format!(
+        "\
+#[derive({change_derive}Debug, Clone, Serialize)]
+pub struct EntityChange {{
+    /// Entity name, e.g. `\"Post\"`.
+    pub entity: String,
+    pub kind: ChangeKind,
+    /// Primary key of the changed record.
+    pub id: String,
+}}
+
+"
+    ));
+
+    // ── Channel ──────────────────────────────────────────────────────────
+    out.push_str(&format!(
+        "\
+/// Process-wide, so subscribers see changes made on any worker.
+static CHANGES: OnceLock<broadcast::Sender<EntityChange>> = OnceLock::new();
+
+fn changes() -> &'static broadcast::Sender<EntityChange> {{
+    CHANGES.get_or_init(|| broadcast::channel({CHANNEL_CAPACITY}).0)
+}}
+
+"
+    ));
+    out.push_str(&doc_comment(
+        Some("Tell subscribers about a change. A feed nobody listens to drops it."),
+        ctx,
+    ));
+    out.push_str(
+        "\
+pub fn publish_change(entity: &str, kind: ChangeKind, id: impl ToString) {
+    let _ = changes().send(EntityChange {
+        entity: entity.to_string(),
+        kind,
+        id: id.to_string(),
+    });
+}
+
+",
+    );
+    out.push_str(&doc_comment(
+        Some(
+            "Changes to `entity` from now on. A subscriber that falls more than the\n\
+             channel's capacity behind skips the changes it missed.",
+        ),
+        ctx,
+    ));
+    out.push_str(
+        "\
+pub fn change_stream(entity: &'static str) -> impl Stream<Item = EntityChange> {
+    stream::unfold(changes().subscribe(), move |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(change) if change.entity == entity => return Some((change, rx)),
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+",
+    );
+
+    let streams = ctx.framework_backend().realtime_streams(ctx);
+    if !streams.is_empty() {
+        out.push('\n');
+        out.push_str(streams.trim_end());
+        out.push('\n');
+    }
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{
+        ApiStyle, EndpointGroup, Entity, ProjectGraph, RealtimeTransport, WebFramework,
+    };
+
+    fn blog(api_style: ApiStyle, realtime: bool) -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        project.config.api_style = api_style;
+        let post = project.add_entity(Entity::new("Post"));
+        let mut endpoint = EndpointGroup::new(post, "Post");
+        endpoint.realtime = realtime;
+        project.add_endpoint(endpoint);
+        project
+    }
+
+    fn with_transport(mut project: ProjectGraph, transport: RealtimeTransport) -> ProjectGraph {
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime_transport = transport;
+        }
+        project
+    }
+
+    #[test]
+    fn test_change_feed_for_graphql_subscriptions() {
+        let project = blog(ApiStyle::Graphql, true);
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_realtime(&ctx);
+        assert_eq!(files.len(), 1);
+        let content = &files[0].content;
+
+        assert!(content.contains("#[derive(async_graphql::Enum, Debug, Clone, Copy"));
+        assert!(
+            content.contains("#[derive(async_graphql::SimpleObject, Debug, Clone, Serialize)]")
+        );
+        assert!(content.contains("broadcast::channel(256)"));
+        assert!(content.contains("pub fn publish_change(entity: &str, kind: ChangeKind"));
+        assert!(content.contains("pub fn change_stream(entity: &'static str)"));
+        // No REST routes to serve it over
+        assert!(!content.contains("pub fn websocket("));
+        assert!(!content.contains("pub fn sse("));
+    }
+
+    #[test]
+    fn test_change_feed_streams_for_rest() {
+        let project = blog(ApiStyle::Rest, true);
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_realtime(&ctx)[0].content;
+        assert!(content.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]"));
+        assert!(content.contains(
+            "pub fn websocket(ws: axum::extract::WebSocketUpgrade, entity: &'static str)"
+        ));
+        assert!(!content.contains("pub fn sse("));
+
+        let project = with_transport(blog(ApiStyle::Rest, true), RealtimeTransport::Sse);
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_realtime(&ctx)[0].content;
+        assert!(content.contains("Sse::new(events).keep_alive(KeepAlive::default())"));
+        assert!(!content.contains("pub fn websocket("));
+
+        let mut project = blog(ApiStyle::Rest, true);
+        project.config.framework = WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_realtime(&ctx)[0].content;
+        assert!(content.contains("actix_ws::handle(req, body)?"));
+        assert!(content.contains("session.pong(&bytes)"));
+    }
+
+    #[test]
+    fn test_no_change_feed_without_realtime_entities() {
+        let project = blog(ApiStyle::Graphql, false);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_realtime(&ctx).is_empty());
+
+        let project = blog(ApiStyle::Rest, false);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_realtime(&ctx).is_empty());
+    }
+}
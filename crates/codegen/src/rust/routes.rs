@@ -7,16 +7,24 @@
 //!
 //! - `src/routes/mod.rs` — module declarations and the top-level `create_router` fn
 //! - `src/routes/api.rs` — per-entity route groups, nested under their base paths
+//!   (`constants::{ENTITY}_PATH`)
+//!
+//! Both are rendered from overridable templates, with the variables of
+//! [`RoutesContext`] and [`ApiRoutesContext`]; see
+//! [`templates`](crate::templates).
 //!
 //! ## Router Structure
 //!
 //! ```text
 //! Router::new()
-//!   .nest("/api/users",   user_routes())
-//!   .nest("/api/posts",   post_routes())
+//!   .route("/healthz", …)      // if health checks enabled, also /readyz
+//!   .route("/metrics", …)      // if metrics enabled
+//!   .nest(constants::USER_PATH, user_routes())   // "/api/users"
+//!   .nest(constants::POST_PATH, post_routes())   // "/api/posts"
 //!   …
-//!   .layer(TraceLayer)
+//!   .layer(TraceLayer)        // or the OpenTelemetry `http_span` route layer
 //!   .layer(CorsLayer)          // if CORS enabled
+//!   .layer(PrometheusMetricLayer) // if metrics enabled
 //!   .with_state(app_state)
 //! ```
 //!
@@ -29,23 +37,50 @@
 //! | Read      | `GET    /:id`            | operation enabled      |
 //! | Update    | `PUT    /:id`            | operation enabled      |
 //! | Delete    | `DELETE /:id`            | operation enabled      |
+//! | Restore   | `POST   /:id/restore`    | Delete enabled, entity soft-deletes |
+//! | Links     | `GET/POST /:id/{accessor}`, `DELETE /:id/{accessor}/:{other}_id` | Update enabled, promoted many-to-many junction |
+//! | Changes   | `GET    /ws` or `/events` | group marked realtime (security of list, else get) |
+//! | Upload    | `POST   /:id/{field}`    | Update enabled, `File`/`Image` field |
 //!
 //! When authentication is enabled, secured routes are wrapped with the
-//! `require_auth` middleware layer.
+//! `require_auth` middleware layer, and those whose security names roles get
+//! a `require_roles` layer on their method router. Upload routes raise the request body
+//! limit to `storage::MAX_BODY_BYTES`; with local storage the router also
+//! serves the stored files under `/uploads`.
+//!
+//! ## API Versioning
+//!
+//! Versioned groups are nested under `/api/{version}/…`: the group's own
+//! `api_version`, else the project default, so several versions of one
+//! resource can be served side by side. With header versioning the public
+//! paths stay unversioned; a router fallback (`middleware::api_version`)
+//! re-dispatches `/api/…` requests to the version named in the header.
 
-use imortal_ir::OperationType;
+use imortal_ir::{OperationType, RealtimeTransport, UploadStorage};
+use serde::Serialize;
 
 use crate::context::{EntityInfo, GenerationContext};
-use crate::rust::{doc_comment, file_header};
+use crate::rust::constants::{cors_route_origins, path_const};
+use crate::rust::handlers::{
+    crud_handler_name, link_associations, links_module, live_handler_name, upload_handler_name,
+};
+use crate::rust::queries::query_handler_name;
+use crate::rust::rate_limit::{limiter_name, query_limiter_name};
+use crate::rust::{auth, doc_comment, file_header};
 use crate::{FileType, GeneratedFile};
 
 // ============================================================================
 // Public API
 // ============================================================================
 
-/// Generate all route files (`src/routes/mod.rs` and `src/routes/api.rs`).
+/// Generate all route files (`src/routes/mod.rs` and, unless the project
+/// serves GraphQL only, `src/routes/api.rs`).
 pub fn generate_routes(ctx: &GenerationContext) -> Vec<GeneratedFile> {
-    vec![generate_routes_mod(ctx), generate_api_routes(ctx)]
+    let mut files = vec![generate_routes_mod(ctx)];
+    if ctx.rest_enabled() {
+        files.push(generate_api_routes(ctx));
+    }
+    files
 }
 
 // ============================================================================
@@ -53,225 +88,503 @@ pub fn generate_routes(ctx: &GenerationContext) -> Vec<GeneratedFile> {
 // ============================================================================
 
 fn generate_routes_mod(ctx: &GenerationContext) -> GeneratedFile {
-    let mut content = String::with_capacity(2048);
+    let content = ctx.render("rust/routes.rs.jinja", RoutesContext::new(ctx));
+    GeneratedFile::new("src/routes/mod.rs", content, FileType::Rust)
+}
 
-    content.push_str(&file_header(
-        "Route definitions — top-level router assembly.",
-    ));
+/// Variables of the `rust/routes.rs.jinja` template, which renders
+/// `src/routes/mod.rs` (see [`templates`](crate::templates)).
+#[derive(Debug, Clone, Serialize)]
+pub struct RoutesContext {
+    /// Whether doc comments are generated
+    pub docs: bool,
+    /// Whether the REST routes of `api.rs` are served
+    pub rest: bool,
+    /// Whether GraphQL is served
+    pub graphql: bool,
+    /// Whether the API version can be named in a request header
+    pub header_versioning: bool,
+    /// Whether authentication is enabled
+    pub auth: bool,
+    /// Whether the auth routes (sessions, OAuth2 sign-in) are served
+    pub auth_routes: bool,
+    /// Whether refresh tokens are issued
+    pub refresh_tokens: bool,
+    /// Whether any OAuth provider is configured
+    pub oauth: bool,
+    /// Whether `/healthz` and `/readyz` are served
+    pub health_checks: bool,
+    /// Whether `/metrics` is served
+    pub metrics: bool,
+    /// Whether telemetry is enabled
+    pub telemetry: bool,
+    /// Whether CORS is enabled
+    pub cors: bool,
+    /// Whether some routes allow their own CORS origins
+    pub route_cors: bool,
+    /// Whether uploaded files are served from local storage
+    pub serves_uploads: bool,
+    /// Items imported from `axum::routing`
+    pub routing: Vec<&'static str>,
+    /// Items imported from `crate::middleware`
+    pub middleware: Vec<&'static str>,
+    /// `fn cors_origin_allowed`, when `route_cors` is set
+    pub cors_origin_allowed: String,
+}
+
+impl RoutesContext {
+    /// The variables for `ctx`.
+    pub fn new(ctx: &GenerationContext) -> Self {
+        let header_versioning = ctx.rest_enabled() && ctx.api_versioning().header_name().is_some();
+        let oauth = !ctx.oauth_providers().is_empty();
+        let route_cors = !cors_route_origins(ctx).is_empty();
+
+        let mut routing = Vec::new();
+        if ctx.health_checks() || ctx.metrics() || oauth {
+            routing.push("get");
+        }
+        if ctx.refresh_tokens() {
+            routing.push("post");
+        }
+
+        let mut middleware = Vec::new();
+        if header_versioning {
+            middleware.push("api_version");
+        }
+        if ctx.telemetry() {
+            middleware.push("http_span");
+        }
+        middleware.push("locale");
+
+        Self {
+            docs: ctx.generate_docs(),
+            rest: ctx.rest_enabled(),
+            graphql: ctx.graphql_enabled(),
+            header_versioning,
+            auth: ctx.auth_enabled(),
+            auth_routes: ctx.auth_routes(),
+            refresh_tokens: ctx.refresh_tokens(),
+            oauth,
+            health_checks: ctx.health_checks(),
+            metrics: ctx.metrics(),
+            telemetry: ctx.telemetry(),
+            cors: ctx.config.cors_enabled,
+            route_cors,
+            serves_uploads: ctx.uploads_enabled() && ctx.uploads().storage == UploadStorage::Local,
+            routing,
+            middleware,
+            cors_origin_allowed: if route_cors {
+                cors_origin_allowed(ctx)
+            } else {
+                String::new()
+            },
+        }
+    }
+}
+
+// ============================================================================
+// graphql/http.rs
+// ============================================================================
 
-    // Imports
-    content.push_str("pub mod api;\n\n");
+/// Generate `src/graphql/http.rs`: the Axum routes serving the schema.
+pub(crate) fn generate_graphql_http(ctx: &GenerationContext) -> GeneratedFile {
+    let auth = ctx.auth_enabled();
+    let mut content = String::with_capacity(2048);
 
-    content.push_str("use axum::Router;\n");
+    content.push_str(&file_header("GraphQL over HTTP and WebSocket for Axum."));
 
-    if ctx.config.cors_enabled {
-        content.push_str("use tower_http::cors::{Any, CorsLayer};\n");
+    content.push_str("use async_graphql::http::{GraphiQLSource, ALL_WEBSOCKET_PROTOCOLS};\n");
+    content.push_str(
+        "use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};\n",
+    );
+    if auth {
+        content.push_str("use axum::extract::{State, WebSocketUpgrade};\n");
+        content.push_str("use axum::http::{header::AUTHORIZATION, HeaderMap};\n");
+    } else {
+        content.push_str("use axum::extract::WebSocketUpgrade;\n");
+    }
+    content.push_str("use axum::response::{Html, IntoResponse, Response};\n");
+    content.push_str("use axum::routing::get;\n");
+    content.push_str("use axum::{Extension, Router};\n\n");
+    if auth {
+        content.push_str("use crate::auth::jwt::{verify_token, Claims};\n");
+    }
+    if auth {
+        content.push_str("use crate::graphql::{connection_data, AppSchema};\n");
+    } else {
+        content.push_str("use crate::graphql::AppSchema;\n");
     }
-    content.push_str("use tower_http::trace::TraceLayer;\n");
-    content.push_str("use std::time::Duration;\n");
-    content.push_str("use tower_http::timeout::TimeoutLayer;\n");
-    content.push('\n');
     content.push_str("use crate::state::AppState;\n\n");
 
-    // create_router function
     content.push_str(&doc_comment(
-        Some("Build the complete application router with all routes, middleware, and state."),
+        Some(
+            "Routes serving `schema`: GraphiQL on `GET /graphql`, queries and\n\
+             mutations on `POST /graphql` and subscriptions on the `/graphql/ws`\n\
+             WebSocket.",
+        ),
         ctx,
     ));
+    content.push_str(
+        "\
+pub fn graphql_routes(schema: AppSchema) -> Router<AppState> {
+    Router::new()
+        .route(\"/graphql\", get(graphiql).post(graphql))
+        .route(\"/graphql/ws\", get(graphql_ws))
+        .layer(Extension(schema))
+}
 
-    content.push_str("pub fn create_router(state: AppState) -> Router {\n");
+async fn graphiql() -> impl IntoResponse {
+    Html(
+        GraphiQLSource::build()
+            .endpoint(\"/graphql\")
+            .subscription_endpoint(\"/graphql/ws\")
+            .finish(),
+    )
+}
+
+",
+    );
 
-    // CORS layer
-    if ctx.config.cors_enabled {
+    if auth {
         content.push_str(
-            "    let cors = CorsLayer::new()\n\
-             \x20       .allow_origin(Any)\n\
-             \x20       .allow_methods(Any)\n\
-             \x20       .allow_headers(Any);\n\n",
-        );
+            "\
+async fn graphql(
+    State(state): State<AppState>,
+    Extension(schema): Extension<AppSchema>,
+    headers: HeaderMap,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let mut request = request.into_inner();
+    if let Some(claims) = bearer_claims(&headers, &state) {
+        request = request.data(claims);
     }
+    schema.execute(request).await.into()
+}
 
-    content.push_str("    let api_routes = api::api_routes();\n\n");
-
-    content.push_str("    Router::new()\n");
-    content.push_str("        .nest(\"/\", api_routes)\n");
+async fn graphql_ws(
+    State(state): State<AppState>,
+    Extension(schema): Extension<AppSchema>,
+    headers: HeaderMap,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let claims = bearer_claims(&headers, &state);
+    let jwt_secret = state.config.jwt_secret.clone();
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            GraphQLWebSocket::new(stream, schema, protocol)
+                .on_connection_init(move |init| connection_data(init, claims, jwt_secret))
+                .serve()
+        })
+}
 
-    // Layers
-    content.push_str("        .layer(TraceLayer::new_for_http())\n");
-    content.push_str("        .layer(TimeoutLayer::new(Duration::from_secs(30)))\n");
+/// Claims of a valid `Authorization: Bearer <token>` header. Requests
+/// without one run anonymously; resolvers that need a caller refuse them.
+fn bearer_claims(headers: &HeaderMap, state: &AppState) -> Option<Claims> {
+    let header = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let token = header.strip_prefix(\"Bearer \")?;
+    verify_token(token, &state.config.jwt_secret)
+        .map_err(|e| tracing::warn!(\"JWT verification failed: {}\", e))
+        .ok()
+}
+",
+        );
+    } else {
+        content.push_str(
+            "\
+async fn graphql(Extension(schema): Extension<AppSchema>, request: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(request.into_inner()).await.into()
+}
 
-    if ctx.config.cors_enabled {
-        content.push_str("        .layer(cors)\n");
+async fn graphql_ws(
+    Extension(schema): Extension<AppSchema>,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| GraphQLWebSocket::new(stream, schema, protocol).serve())
+}
+",
+        );
     }
 
-    content.push_str("        .with_state(state)\n");
-    content.push_str("}\n");
-
-    GeneratedFile::new("src/routes/mod.rs", content, FileType::Rust)
+    GeneratedFile::new("src/graphql/http.rs", content, FileType::Rust)
 }
 
 // ============================================================================
-// routes/api.rs — per-entity route groups
+// realtime.rs — REST change streams
 // ============================================================================
 
-fn generate_api_routes(ctx: &GenerationContext) -> GeneratedFile {
-    let mut content = String::with_capacity(4096);
-
-    content.push_str(&file_header(
-        "API route definitions — entity endpoint groups.",
-    ));
-
-    // ── Imports ──────────────────────────────────────────────────────────
-
-    content.push_str("use axum::{\n");
-    content.push_str("    Router,\n");
-    content.push_str("    routing::{get, post, put, delete},\n");
-    content.push_str("};\n");
+/// The Axum `websocket` and `sse` functions of `src/realtime.rs`, for the
+/// transports some entity streams its changes over.
+pub(crate) fn realtime_streams(ctx: &GenerationContext) -> String {
+    let mut out = String::new();
+
+    if ctx.uses_realtime_transport(RealtimeTransport::WebSocket) {
+        out.push_str(&doc_comment(
+            Some(
+                "Upgrade to a WebSocket that sends each change to `entity` as a JSON\n\
+                 text message until the client disconnects.",
+            ),
+            ctx,
+        ));
+        out.push_str(
+            "\
+pub fn websocket(ws: axum::extract::WebSocketUpgrade, entity: &'static str) -> axum::response::Response {
+    use axum::extract::ws::Message;
+    use futures_util::StreamExt;
+
+    ws.on_upgrade(move |mut socket| async move {
+        let mut changes = std::pin::pin!(change_stream(entity));
+        loop {
+            tokio::select! {
+                change = changes.next() => {
+                    let Some(change) = change else { break };
+                    let Ok(text) = serde_json::to_string(&change) else { continue };
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        break;
+                    }
+                }
+                // Clients only listen; anything but a close is ignored
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+    })
+}
 
-    if ctx.auth_enabled() {
-        content.push_str("use axum::middleware;\n");
+",
+        );
     }
 
-    content.push('\n');
-    content.push_str("use crate::state::AppState;\n");
+    if ctx.uses_realtime_transport(RealtimeTransport::Sse) {
+        out.push_str(&doc_comment(
+            Some(
+                "A `text/event-stream` with a `change` event, as JSON, for each change\n\
+                 to `entity`. Keep-alive comments stop proxies closing it when idle.",
+            ),
+            ctx,
+        ));
+        out.push_str(
+            "\
+pub fn sse(entity: &'static str) -> impl axum::response::IntoResponse {
+    use axum::response::sse::{Event, KeepAlive, Sse};
+    use futures_util::StreamExt;
+
+    let events = change_stream(entity).map(|change| Event::default().event(\"change\").json_data(change));
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
 
-    // Import each entity's handler module
-    for entity in ctx.entities() {
-        let info = EntityInfo::new(entity, ctx);
-        if has_enabled_handlers(&info) {
-            let module = info.module_name();
-            content.push_str(&format!("use crate::handlers::{};\n", module,));
-        }
+",
+        );
     }
 
-    if ctx.auth_enabled() {
-        content.push_str("use crate::auth::middleware::require_auth;\n");
-    }
+    out
+}
 
-    content.push('\n');
+// ============================================================================
+// routes/api.rs — per-entity route groups
+// ============================================================================
 
-    // ── Top-level api_routes function ────────────────────────────────────
+fn generate_api_routes(ctx: &GenerationContext) -> GeneratedFile {
+    let content = ctx.render("rust/api.rs.jinja", ApiRoutesContext::new(ctx));
+    GeneratedFile::new("src/routes/api.rs", content, FileType::Rust)
+}
 
-    content.push_str(&doc_comment(
-        Some("Assemble all API routes.\n\nEach entity's routes are nested under its configured base path."),
-        ctx,
-    ));
+/// Variables of the `rust/api.rs.jinja` template, which renders
+/// `src/routes/api.rs` (see [`templates`](crate::templates)).
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiRoutesContext {
+    /// Whether doc comments are generated
+    pub docs: bool,
+    /// Whether authentication is enabled
+    pub auth: bool,
+    /// Whether some route requires roles
+    pub check_roles: bool,
+    /// Whether roles are a `Role` enum rather than strings
+    pub role_enum: bool,
+    /// Whether rate limiting is enabled
+    pub rate_limiting: bool,
+    /// Whether uploads are enabled
+    pub uploads: bool,
+    /// Handler modules the routes call into, in entity order
+    pub handler_modules: Vec<String>,
+    /// One route group per entity with enabled operations
+    pub groups: Vec<RouteGroup>,
+    /// `fn query_routes`, for the custom query endpoints (empty without
+    /// any)
+    pub query_routes: String,
+}
 
-    content.push_str("pub fn api_routes() -> Router<AppState> {\n");
-    content.push_str("    Router::new()\n");
+/// The routes of one entity, nested under its base path
+#[derive(Debug, Clone, Serialize)]
+pub struct RouteGroup {
+    /// Entity name in PascalCase
+    pub name: String,
+    /// Name of the function building the group's router
+    pub function: String,
+    /// Constant in `constants.rs` holding the base path
+    pub path_const: String,
+    /// `.route(…)` lines open to anyone, each ending in a newline
+    pub public: String,
+    /// `.route(…)` lines behind `require_auth`, each ending in a newline
+    pub secured: String,
+}
 
-    for entity in ctx.entities() {
-        let info = EntityInfo::new(entity, ctx);
-        if !has_enabled_handlers(&info) {
-            continue;
+impl ApiRoutesContext {
+    /// The variables for `ctx`.
+    pub fn new(ctx: &GenerationContext) -> Self {
+        let mut handler_modules = Vec::new();
+        let mut groups = Vec::new();
+        for entity in ctx.entities() {
+            let info = EntityInfo::new(entity, ctx);
+            if has_enabled_handlers(&info) {
+                handler_modules.push(info.module_name());
+                groups.extend(RouteGroup::new(&info, ctx));
+            }
+            if let Some(links) = links_module(&info) {
+                handler_modules.push(links);
+            }
         }
 
-        let base_path = info.base_path();
-        let fn_name = format!("{}_routes", info.snake_name());
-
-        content.push_str(&format!(
-            "        .nest(\"{}\", {}())\n",
-            base_path, fn_name,
-        ));
+        Self {
+            docs: ctx.generate_docs(),
+            auth: ctx.auth_enabled(),
+            check_roles: auth::routes_check_roles(ctx),
+            role_enum: ctx.role_enum(),
+            rate_limiting: ctx.rate_limiting_enabled(),
+            uploads: ctx.uploads_enabled(),
+            handler_modules,
+            groups,
+            query_routes: if ctx.queries().is_empty() {
+                String::new()
+            } else {
+                generate_query_routes(ctx)
+            },
+        }
     }
+}
 
-    content.push_str("}\n\n");
-
-    // ── Per-entity route functions ───────────────────────────────────────
+// ============================================================================
+// Per-entity route group
+// ============================================================================
 
-    for entity in ctx.entities() {
-        let info = EntityInfo::new(entity, ctx);
-        if !has_enabled_handlers(&info) {
-            continue;
+impl RouteGroup {
+    /// The routes of `info`, respecting which CRUD operations are enabled
+    /// and whether individual operations require authentication. `None`
+    /// when its endpoint group is disabled.
+    pub fn new(info: &EntityInfo, ctx: &GenerationContext) -> Option<Self> {
+        let endpoint = info.endpoint().filter(|ep| ep.enabled)?;
+        let module = info.module_name();
+
+        // Determine which operations are enabled and whether they need auth.
+        let enabled_ops = endpoint.enabled_operations();
+
+        // We split into "public" routes and "secured" routes so we can layer
+        // the auth middleware only on the secured ones.
+        let auth_enabled = ctx.auth_enabled();
+
+        // Collect operations grouped by whether they require auth
+        let mut public_ops: Vec<&imortal_ir::CrudOperation> = Vec::new();
+        let mut secured_ops: Vec<&imortal_ir::CrudOperation> = Vec::new();
+
+        for op in &enabled_ops {
+            let effective_security = op.security.as_ref().unwrap_or(&endpoint.global_security);
+
+            if auth_enabled && effective_security.auth_required {
+                secured_ops.push(op);
+            } else {
+                public_ops.push(op);
+            }
         }
 
-        content.push_str(&generate_entity_routes(&info, ctx));
-        content.push('\n');
+        let lines = |ops: &[&imortal_ir::CrudOperation], secured: bool| {
+            let mut lines = String::new();
+            for op in ops {
+                let mut op_lines = route_line(op, &module, "        ");
+                op_lines.push_str(&restore_route_line(info, op, "        "));
+                op_lines.push_str(&link_route_lines(info, op, "        "));
+                op_lines.push_str(&upload_route_lines(info, op, "        "));
+                if ctx.rate_limit(op.rate_limit.as_ref()).is_some() {
+                    op_lines = with_rate_limit(&op_lines, &limiter_name(info, op));
+                }
+                let security = op.security.as_ref().unwrap_or(&endpoint.global_security);
+                match auth::required_roles(ctx, security) {
+                    Some(roles) => lines.push_str(&with_required_roles(&op_lines, &roles)),
+                    None => lines.push_str(&op_lines),
+                }
+            }
+            if info.live_auth_required() == secured {
+                lines.push_str(&live_route_line(info, "        "));
+            }
+            lines
+        };
+
+        Some(Self {
+            name: info.pascal_name(),
+            function: format!("{}_routes", info.snake_name()),
+            path_const: path_const(info),
+            public: lines(&public_ops, false),
+            secured: lines(&secured_ops, true),
+        })
     }
-
-    GeneratedFile::new("src/routes/api.rs", content, FileType::Rust)
 }
 
-// ============================================================================
-// Per-entity route builder function
-// ============================================================================
-
-/// Generate the `fn {entity}_routes() -> Router<AppState>` function for one
-/// entity, respecting which CRUD operations are enabled and whether
-/// individual operations require authentication.
-fn generate_entity_routes(info: &EntityInfo, ctx: &GenerationContext) -> String {
-    let endpoint = match info.endpoint() {
-        Some(ep) if ep.enabled => ep,
-        _ => return String::new(),
+/// Generate `fn query_routes() -> Router<AppState>` for the custom query
+/// endpoints. Queries are mounted at their full path rather than nested.
+fn generate_query_routes(ctx: &GenerationContext) -> String {
+    let auth_enabled = ctx.auth_enabled();
+    let (secured, public): (Vec<_>, Vec<_>) = ctx
+        .queries()
+        .iter()
+        .partition(|q| auth_enabled && q.security.auth_required);
+
+    let route = |q: &&imortal_ir::QueryDefinition| {
+        let line = format!(
+            "        .route(\"{}\", get(queries::{}))\n",
+            q.path,
+            query_handler_name(q)
+        );
+        let line = match ctx.rate_limit(None) {
+            Some(_) => with_rate_limit(&line, &query_limiter_name(q)),
+            None => line,
+        };
+        match auth::required_roles(ctx, &q.security) {
+            Some(roles) => with_required_roles(&line, &roles),
+            None => line,
+        }
     };
 
-    let fn_name = format!("{}_routes", info.snake_name());
-    let module = info.module_name();
-    let pascal = info.pascal_name();
-
-    let mut out = String::with_capacity(1024);
+    let mut out = String::with_capacity(512);
 
     out.push_str(&doc_comment(
-        Some(&format!("Routes for {} endpoints.", pascal)),
+        Some("Routes for custom query endpoints."),
         ctx,
     ));
+    out.push_str("fn query_routes() -> Router<AppState> {\n");
 
-    out.push_str(&format!("fn {}() -> Router<AppState> {{\n", fn_name,));
-
-    // Determine which operations are enabled and whether they need auth.
-    let enabled_ops = endpoint.enabled_operations();
-
-    // We split into "public" routes and "secured" routes so we can layer
-    // the auth middleware only on the secured ones.
-    let auth_enabled = ctx.auth_enabled();
-
-    // Collect operations grouped by whether they require auth
-    let mut public_ops: Vec<&imortal_ir::CrudOperation> = Vec::new();
-    let mut secured_ops: Vec<&imortal_ir::CrudOperation> = Vec::new();
-
-    for op in &enabled_ops {
-        let effective_security = op.security.as_ref().unwrap_or(&endpoint.global_security);
-
-        if auth_enabled && effective_security.auth_required {
-            secured_ops.push(op);
-        } else {
-            public_ops.push(op);
-        }
-    }
-
-    let has_public = !public_ops.is_empty();
-    let has_secured = !secured_ops.is_empty();
-
-    // Build the public router
-    if has_public && has_secured {
-        // Need two routers merged
+    if !public.is_empty() && !secured.is_empty() {
         out.push_str("    let public = Router::new()\n");
-        for op in &public_ops {
-            out.push_str(&route_line(op, &module, "        "));
-        }
+        out.extend(public.iter().map(route));
         out.push_str("    ;\n\n");
 
         out.push_str("    let secured = Router::new()\n");
-        for op in &secured_ops {
-            out.push_str(&route_line(op, &module, "        "));
-        }
+        out.extend(secured.iter().map(route));
         out.push_str("        .route_layer(middleware::from_fn(require_auth))\n");
         out.push_str("    ;\n\n");
 
         out.push_str("    public.merge(secured)\n");
-    } else if has_secured {
-        // All routes are secured
+    } else if !secured.is_empty() {
         out.push_str("    Router::new()\n");
-        for op in &secured_ops {
-            out.push_str(&route_line(op, &module, "        "));
-        }
+        out.extend(secured.iter().map(route));
         out.push_str("        .route_layer(middleware::from_fn(require_auth))\n");
     } else {
-        // All routes are public
         out.push_str("    Router::new()\n");
-        for op in &public_ops {
-            out.push_str(&route_line(op, &module, "        "));
-        }
+        out.extend(public.iter().map(route));
     }
 
     out.push_str("}\n");
@@ -290,7 +603,7 @@ fn generate_entity_routes(info: &EntityInfo, ctx: &GenerationContext) -> String
 /// Operations that share the same path pattern are combined into a single
 /// `.route()` call using method chaining (e.g. `get(…).post(…)`).
 fn route_line(op: &imortal_ir::CrudOperation, handler_module: &str, indent: &str) -> String {
-    let handler_name = op.handler_name(handler_module);
+    let handler_name = crud_handler_name(op, handler_module);
     let handler_ref = format!("{}::{}", handler_module, handler_name);
 
     let (path, method_fn) = match op.operation_type {
@@ -304,13 +617,138 @@ fn route_line(op: &imortal_ir::CrudOperation, handler_module: &str, indent: &str
     format!("{indent}.route(\"{path}\", {method_fn}({handler_ref}))\n",)
 }
 
+/// The `restore` route riding on a soft-deleting entity's Delete operation,
+/// so it shares that operation's security group; empty otherwise.
+fn restore_route_line(info: &EntityInfo, op: &imortal_ir::CrudOperation, indent: &str) -> String {
+    if op.operation_type != OperationType::Delete || !info.has_soft_delete() {
+        return String::new();
+    }
+    format!(
+        "{indent}.route(\"/:id/restore\", post({}::restore_{}))\n",
+        info.module_name(),
+        info.snake_name()
+    )
+}
+
+/// The route streaming a realtime entity's changes, e.g.
+/// `.route("/ws", get(post::watch_posts))`; empty otherwise.
+fn live_route_line(info: &EntityInfo, indent: &str) -> String {
+    let Some(transport) = info.live_transport() else {
+        return String::new();
+    };
+    format!(
+        "{indent}.route(\"/{}\", get({}::{}))\n",
+        transport.path(),
+        info.module_name(),
+        live_handler_name(info)
+    )
+}
+
+/// The association routes riding on an entity's Update operation, so they
+/// share that operation's security group; empty otherwise.
+fn link_route_lines(info: &EntityInfo, op: &imortal_ir::CrudOperation, indent: &str) -> String {
+    let Some(module) = links_module(info).filter(|_| op.operation_type == OperationType::Update)
+    else {
+        return String::new();
+    };
+    link_associations(info)
+        .iter()
+        .map(|a| {
+            let suffix = a.handler_suffix();
+            format!(
+                "{indent}.route(\"/:id/{accessor}\", get({module}::list_{suffix}).post({module}::link_{suffix}))\n\
+                 {indent}.route(\"/:id/{accessor}/:{other}\", delete({module}::unlink_{suffix}))\n",
+                accessor = a.accessor,
+                other = a.other_param(),
+            )
+        })
+        .collect()
+}
+
+/// The upload routes riding on an entity's Update operation, so they share
+/// that operation's security group; empty otherwise.
+fn upload_route_lines(info: &EntityInfo, op: &imortal_ir::CrudOperation, indent: &str) -> String {
+    if op.operation_type != OperationType::Update {
+        return String::new();
+    }
+    info.upload_fields()
+        .into_iter()
+        .map(|field| {
+            format!(
+                "{indent}.route(\"/:id/{}\", post({}::{}).layer(DefaultBodyLimit::max(storage::MAX_BODY_BYTES)))\n",
+                GenerationContext::snake(&field.name),
+                info.module_name(),
+                upload_handler_name(info, field)
+            )
+        })
+        .collect()
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/// `.route(…)` lines with each method router guarded by `roles`, a
+/// `RequireRoles(…)` value; the router's `require_auth` layer runs first.
+fn with_required_roles(lines: &str, roles: &str) -> String {
+    lines
+        .lines()
+        .map(|line| {
+            let route = line.strip_suffix(')').unwrap_or(line);
+            format!(
+                "{route}.route_layer(middleware::from_fn_with_state({roles}, require_roles)))\n"
+            )
+        })
+        .collect()
+}
+
+/// `fn cors_origin_allowed(origin, path)`, picking the origins a route
+/// allows from `CORS_ROUTE_ORIGINS` (see
+/// [`cors_route_origins`]). Framework-agnostic; both routers call it from
+/// their CORS origin predicate.
+pub(crate) fn cors_origin_allowed(ctx: &GenerationContext) -> String {
+    let mut out = doc_comment(
+        Some(
+            "Whether `origin` may call the route at `path`: routes under a\n\
+             `CORS_ROUTE_ORIGINS` path allow their own origins, the rest\n\
+             `CORS_ALLOWED_ORIGINS`, where empty allows any origin.",
+        ),
+        ctx,
+    );
+    out.push_str(
+        "\
+fn cors_origin_allowed(origin: &[u8], path: &str) -> bool {
+    let allowed = constants::CORS_ROUTE_ORIGINS
+        .iter()
+        .find(|(prefix, _)| {
+            path.strip_prefix(*prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+        .map_or(constants::CORS_ALLOWED_ORIGINS, |(_, origins)| *origins);
+    allowed.is_empty() || allowed.iter().any(|allowed| allowed.as_bytes() == origin)
+}
+",
+    );
+    out
+}
+
+/// `.route(…)` lines with each method router counted against the `limiter`
+/// static of `rate_limit.rs`, inside the router's `require_auth` layer.
+fn with_rate_limit(lines: &str, limiter: &str) -> String {
+    lines
+        .lines()
+        .map(|line| {
+            let route = line.strip_suffix(')').unwrap_or(line);
+            format!(
+                "{route}.route_layer(middleware::from_fn_with_state(&rate_limit::{limiter}, rate_limit::enforce)))\n"
+            )
+        })
+        .collect()
+}
+
 /// Check whether an entity has at least one enabled handler that should
 /// appear in the router.
-fn has_enabled_handlers(info: &EntityInfo) -> bool {
+pub(crate) fn has_enabled_handlers(info: &EntityInfo) -> bool {
     info.endpoint()
         .map(|ep| ep.enabled && !ep.enabled_operations().is_empty())
         .unwrap_or(false)
@@ -323,10 +761,12 @@ fn has_enabled_handlers(info: &EntityInfo) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rust::constants;
     use imortal_core::DataType;
     use imortal_ir::{
-        AuthConfig, CrudOperation, EndpointGroup, EndpointSecurity, Entity, Field, OperationType,
-        ProjectGraph,
+        ApiVersioning, AuthConfig, CorsConfig, CrudOperation, EndpointGroup, EndpointSecurity,
+        Entity, Field, OAuthProvider, ObservabilityConfig, OperationType, ProjectGraph, RateLimit,
+        Relationship,
     };
     use uuid::Uuid;
 
@@ -415,6 +855,53 @@ mod tests {
         assert!(content.contains(".with_state(state)"));
         assert!(content.contains("TraceLayer"));
         assert!(content.contains("TimeoutLayer"));
+        assert!(content.contains(".layer(middleware::from_fn(locale))"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_telemetry() {
+        let mut project = setup_full_project();
+        project.config.telemetry = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_routes(&ctx);
+
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/mod.rs")
+            .unwrap()
+            .content;
+        assert!(content.contains("use axum::{Router, middleware};"));
+        assert!(content.contains("use crate::middleware::{http_span, locale};"));
+        assert!(content.contains(".route_layer(middleware::from_fn(http_span))"));
+        assert!(!content.contains("TraceLayer"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_observability() {
+        let mut project = setup_full_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_routes(&ctx)[0].content;
+        assert!(!content.contains("/healthz"));
+        assert!(!content.contains("Prometheus"));
+
+        project.config.observability = ObservabilityConfig::all();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_routes(&ctx)[0].content;
+        assert!(content.contains("use axum::routing::get;"));
+        assert!(content.contains("use crate::health;"));
+        assert!(
+            content
+                .contains("let (prometheus_layer, metric_handle) = PrometheusMetricLayer::pair();")
+        );
+        assert!(content.contains(
+            "Router::new()\n        .route(\"/healthz\", get(health::healthz))\n        .route(\"/readyz\", get(health::readyz))\n"
+        ));
+        assert!(
+            content.contains(
+                ".route(\"/metrics\", get(move || async move { metric_handle.render() }))"
+            )
+        );
+        assert!(content.contains(".layer(prometheus_layer)\n        .with_state(state)"));
     }
 
     #[test]
@@ -433,6 +920,71 @@ mod tests {
         let content = &mod_file.content;
         assert!(content.contains("CorsLayer"));
         assert!(content.contains(".layer(cors)"));
+        assert!(content.contains(".allow_credentials(constants::CORS_ALLOW_CREDENTIALS);"));
+        assert!(content.contains("AllowOrigin::any()"));
+        assert!(!content.contains("fn cors_origin_allowed"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_route_cors_origins() {
+        let mut project = setup_full_project();
+        project.config.cors = CorsConfig::default().with_origin("https://app.example.com");
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security =
+            EndpointSecurity::open().add_cors_origin("https://admin.example.com");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx).remove(0).content;
+
+        assert!(content.contains("use axum::http::request::Parts;"));
+        assert!(content.contains(
+            "let origins = AllowOrigin::predicate(|origin: &HeaderValue, request: &Parts| {\n        cors_origin_allowed(origin.as_bytes(), request.uri.path())\n    });"
+        ));
+        assert!(content.contains("fn cors_origin_allowed(origin: &[u8], path: &str) -> bool {"));
+        assert!(!content.contains("AllowOrigin::any()"));
+    }
+
+    #[test]
+    fn test_routes_mod_with_refresh_tokens() {
+        let mut project = setup_full_project();
+        project.config.auth = AuthConfig::jwt();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx).remove(0).content;
+        assert!(!content.contains("auth_routes"));
+        // The state reaches `require_auth` as a request extension
+        assert!(content.contains("use axum::Extension;\n"));
+        assert!(content.contains("        .layer(Extension(state.clone()))\n"));
+
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx).remove(0).content;
+        assert!(content.contains("use axum::routing::post;"));
+        assert!(content.contains("use crate::auth::sessions;"));
+        assert!(content.contains(".nest(constants::AUTH_PATH, auth_routes())"));
+        assert!(content.contains(
+            ".route(\"/logout-all\", post(sessions::logout_all))\n        .route_layer(middleware::from_fn(require_auth))\n        .route(\"/refresh\", post(sessions::refresh_session))"
+        ));
+    }
+
+    #[test]
+    fn test_routes_mod_with_oauth() {
+        let mut project = setup_full_project();
+        project.config.auth = AuthConfig::oauth2().with_oauth_provider(OAuthProvider::google());
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx).remove(0).content;
+        assert!(content.contains("use crate::auth::oauth;"));
+        assert!(!content.contains("use crate::auth::sessions;"));
+        assert!(!content.contains("route_layer(middleware::from_fn(require_auth))"));
+        assert!(content.contains(
+            "Router::new()\n        .route(\"/oauth/:provider/authorize\", get(oauth::oauth_authorize))\n        .route(\"/oauth/:provider/callback\", get(oauth::oauth_callback))\n}"
+        ));
+
+        project.config.auth = project.config.auth.clone().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx).remove(0).content;
+        assert!(content.contains("use axum::routing::{get, post};"));
+        assert!(content.contains(
+            ".route(\"/refresh\", post(sessions::refresh_session))\n        .route(\"/oauth/:provider/authorize\""
+        ));
     }
 
     #[test]
@@ -465,9 +1017,8 @@ mod tests {
 
         let content = &api_file.content;
         assert!(content.contains("pub fn api_routes()"));
-        assert!(content.contains(".nest("));
-        assert!(content.contains("/api/users"));
-        assert!(content.contains("user_routes()"));
+        assert!(content.contains("use crate::constants;"));
+        assert!(content.contains(".nest(constants::USER_PATH, user_routes())"));
     }
 
     #[test]
@@ -482,10 +1033,8 @@ mod tests {
             .unwrap();
 
         let content = &api_file.content;
-        assert!(content.contains("/api/users"));
-        assert!(content.contains("/api/posts"));
-        assert!(content.contains("user_routes()"));
-        assert!(content.contains("post_routes()"));
+        assert!(content.contains(".nest(constants::USER_PATH, user_routes())"));
+        assert!(content.contains(".nest(constants::POST_PATH, post_routes())"));
     }
 
     #[test]
@@ -509,6 +1058,83 @@ mod tests {
         assert!(content.contains("delete(user::delete_user)"));
     }
 
+    #[test]
+    fn test_entity_routes_restore_soft_deleted() {
+        let mut project = setup_full_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let api = generate_api_routes(&ctx).content;
+        assert!(!api.contains("restore"));
+
+        project
+            .entities
+            .values_mut()
+            .next()
+            .unwrap()
+            .config
+            .soft_delete = true;
+        let ctx = GenerationContext::from_project_default(&project);
+        let api = generate_api_routes(&ctx).content;
+        assert!(api.contains(".route(\"/:id/restore\", post(user::restore_user))"));
+    }
+
+    #[test]
+    fn test_entity_routes_link_promoted_junctions() {
+        let mut project = setup_multi_entity_project();
+        let id = |project: &ProjectGraph, name: &str| project.get_entity_by_name(name).unwrap().id;
+        let (user, post) = (id(&project, "User"), id(&project, "Post"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(user, post, "user_posts"))
+            .unwrap();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!generate_api_routes(&ctx).content.contains("_links"));
+
+        project.promote_junction(rel).unwrap();
+        let ctx = GenerationContext::from_project_default(&project);
+        let api = generate_api_routes(&ctx).content;
+        assert!(api.contains("use crate::handlers::user_links;"));
+        assert!(api.contains(
+            ".route(\"/:id/posts\", get(user_links::list_user_posts).post(user_links::link_user_posts))"
+        ));
+        assert!(
+            api.contains(".route(\"/:id/posts/:post_id\", delete(user_links::unlink_user_posts))")
+        );
+        assert!(
+            api.contains(".route(\"/:id/users/:user_id\", delete(post_links::unlink_post_users))")
+        );
+    }
+
+    #[test]
+    fn test_versioned_groups_coexist() {
+        let mut project = setup_multi_entity_project();
+        for endpoint in project.endpoints.values_mut() {
+            if endpoint.entity_name == "Post" {
+                endpoint.api_version = Some("v2".to_string());
+            }
+        }
+        project.config.api_versioning = ApiVersioning::path_prefix("v1");
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let api = generate_api_routes(&ctx).content;
+        assert!(api.contains(".nest(constants::USER_PATH, user_routes())"));
+        assert!(api.contains(".nest(constants::POST_PATH, post_routes())"));
+        let constants = &constants::generate_constants(&ctx)[0].content;
+        assert!(constants.contains("pub const USER_PATH: &str = \"/api/v1/users\";"));
+        assert!(constants.contains("pub const POST_PATH: &str = \"/api/v2/posts\";"));
+        let router = generate_routes_mod(&ctx).content;
+        assert!(!router.contains("fallback"));
+
+        project.config.api_versioning = ApiVersioning::header("v1");
+        let ctx = GenerationContext::from_project_default(&project);
+        let constants = &constants::generate_constants(&ctx)[0].content;
+        assert!(constants.contains("pub const POST_PATH: &str = \"/api/v2/posts\";"));
+        let router = generate_routes_mod(&ctx).content;
+        assert!(router.contains("use crate::middleware::{api_version, locale};"));
+        assert!(router.contains("let versioned = api_routes.clone().with_state(state.clone());"));
+        assert!(router.contains(
+            ".fallback(move |request: Request| api_version(versioned.clone(), request))"
+        ));
+    }
+
     #[test]
     fn test_entity_routes_read_only() {
         let mut project = ProjectGraph::new("ro_api");
@@ -580,6 +1206,71 @@ mod tests {
         assert!(content.contains("route_layer(middleware::from_fn(require_auth))"));
     }
 
+    #[test]
+    fn test_entity_routes_rate_limited() {
+        let mut project = setup_full_project();
+        project.config.rate_limit = Some(RateLimit::permissive());
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint
+            .get_operation_mut(OperationType::Create)
+            .unwrap()
+            .rate_limit = Some(RateLimit::strict());
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_routes(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/api.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("use axum::middleware;"));
+        assert!(content.contains("use crate::rate_limit;"));
+        assert!(content.contains(
+            ".route(\"/\", post(user::create_user).route_layer(middleware::from_fn_with_state(&rate_limit::USER_CREATE, rate_limit::enforce)))"
+        ));
+        assert!(content.contains(
+            ".route(\"/:id\", get(user::get_user).route_layer(middleware::from_fn_with_state(&rate_limit::USER_READ, rate_limit::enforce)))"
+        ));
+    }
+
+    #[test]
+    fn test_entity_routes_require_roles() {
+        let mut project = setup_full_project();
+        project.config.auth = AuthConfig::jwt();
+        let endpoint = project.endpoints.values_mut().next().unwrap();
+        endpoint.global_security = EndpointSecurity::authenticated();
+        endpoint.set_operation_security(
+            OperationType::Create,
+            EndpointSecurity::with_roles(vec!["admin".into(), "editor".into()]),
+        );
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_routes(&ctx);
+        let content = &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/api.rs")
+            .unwrap()
+            .content;
+
+        assert!(content.contains("use crate::auth::middleware::{RequireRoles, require_roles};"));
+        assert!(content.contains("use crate::auth::roles::Role;"));
+        assert!(content.contains(
+            ".route(\"/\", post(user::create_user).route_layer(middleware::from_fn_with_state(RequireRoles(&[Role::Admin, Role::Editor]), require_roles)))"
+        ));
+        assert!(content.contains(".route(\"/:id\", get(user::get_user))\n"));
+
+        // Without declared roles, routes name them as strings
+        project.config.auth.available_roles.clear();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = generate_routes(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/api.rs")
+            .unwrap()
+            .content;
+        assert!(content.contains("RequireRoles(&[\"admin\", \"editor\"])"));
+        assert!(!content.contains("use crate::auth::roles::Role;"));
+    }
+
     #[test]
     fn test_entity_routes_mixed_security() {
         let mut project = ProjectGraph::new("mixed_api");
@@ -615,6 +1306,38 @@ mod tests {
         assert!(content.contains("public.merge(secured)"));
     }
 
+    #[test]
+    fn test_entity_routes_stream_changes_with_list_security() {
+        let mut project = ProjectGraph::new("live_api");
+        project.config.auth = AuthConfig::jwt();
+        let post = project.add_entity(Entity::new("Post"));
+
+        // The public list takes the stream with it
+        let mut endpoint = EndpointGroup::new(post, "Post").realtime();
+        endpoint.global_security.auth_required = true;
+        endpoint.set_operation_security(OperationType::ReadAll, EndpointSecurity::open());
+        project.add_endpoint(endpoint);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_api_routes(&ctx).content;
+        let (public, secured) = content.split_once("let secured").unwrap();
+        assert!(public.contains(".route(\"/ws\", get(post::post_changes))"));
+        assert!(!secured.contains("post_changes"));
+
+        // Server-sent events, secured with the rest
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.realtime_transport = RealtimeTransport::Sse;
+            endpoint
+                .set_operation_security(OperationType::ReadAll, EndpointSecurity::authenticated());
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_api_routes(&ctx).content;
+        assert!(!content.contains("let public"));
+        assert!(content.contains(
+            "        .route(\"/events\", get(post::post_changes))\n        .route_layer(middleware::from_fn(require_auth))"
+        ));
+    }
+
     #[test]
     fn test_no_routes_for_entity_without_endpoint() {
         let mut project = ProjectGraph::new("no_ep_api");
@@ -696,6 +1419,10 @@ mod tests {
         let delete_line = route_line(&delete_op, "user", "        ");
         assert!(delete_line.contains("route(\"/:id\""));
         assert!(delete_line.contains("delete(user::delete_user)"));
+
+        // Lists route to the handler named after the table
+        let read_all_line = route_line(&read_all_op, "category", "        ");
+        assert!(read_all_line.contains("get(category::list_categories)"));
     }
 
     #[test]
@@ -731,4 +1458,40 @@ mod tests {
 
         assert!(content.contains("routing::{get, post, put, delete}"));
     }
+
+    #[test]
+    fn test_query_routes() {
+        let mut project = setup_full_project();
+        project.config.auth = AuthConfig::jwt();
+        let user_id = *project.entities.keys().next().unwrap();
+
+        project.add_query(imortal_ir::QueryDefinition::new(
+            "user_directory",
+            user_id,
+            "User",
+        ));
+        project.add_query(
+            imortal_ir::QueryDefinition::new("user_audit", user_id, "User")
+                .with_security(EndpointSecurity::authenticated()),
+        );
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_routes(&ctx);
+
+        let api_file = files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/routes/api.rs")
+            .unwrap();
+
+        let content = &api_file.content;
+
+        assert!(content.contains("use crate::handlers::queries;"));
+        assert!(content.contains(".merge(query_routes())"));
+        assert!(
+            content
+                .contains(".route(\"/api/reports/user_directory\", get(queries::user_directory))")
+        );
+        assert!(content.contains("let secured = Router::new()"));
+        assert!(content.contains(".route(\"/api/reports/user_audit\", get(queries::user_audit))"));
+    }
 }
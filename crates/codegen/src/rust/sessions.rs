@@ -0,0 +1,385 @@
+//! # Sessions Generator (refresh tokens)
+//!
+//! Generates `src/auth/sessions.rs` for projects that issue refresh tokens
+//! ([`AuthConfig::refresh_tokens`]). A session starts at sign-in and lives
+//! as a chain of refresh tokens in the `refresh_tokens` table (see
+//! [`refresh_tokens_statements`]):
+//!
+//! | Route | Auth | Does |
+//! |-------|------|------|
+//! | `POST /api/auth/refresh` | refresh token in the body | revokes the token and answers a new access/refresh pair |
+//! | `POST /api/auth/logout` | bearer token | ends the caller's session |
+//! | `POST /api/auth/logout-all` | bearer token | ends every session of the caller |
+//!
+//! Access tokens carry their session in a `sid` claim, and `require_auth`
+//! rejects those whose session has ended. Presenting a refresh token that
+//! was already rotated means it leaked, so the whole session is revoked.
+//!
+//! The SQL is shared; running it comes from
+//! [`OrmBackend::session_store`](crate::rust::orm::OrmBackend::session_store)
+//! and the handlers from
+//! [`FrameworkBackend::session_handlers`](crate::rust::framework::FrameworkBackend::session_handlers).
+//!
+//! [`AuthConfig::refresh_tokens`]: imortal_ir::AuthConfig::refresh_tokens
+//! [`refresh_tokens_statements`]: crate::migrations::sql::refresh_tokens_statements
+
+use imortal_ir::{DatabaseType, Orm};
+
+use crate::context::GenerationContext;
+use crate::migrations::sql::REFRESH_TOKENS_TABLE;
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/auth/sessions.rs`, if the project issues refresh tokens.
+pub fn generate_sessions(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.refresh_tokens() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/auth/sessions.rs",
+        build_sessions(ctx),
+        FileType::Rust,
+    )]
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_sessions(ctx: &GenerationContext) -> String {
+    let framework = ctx.framework_backend();
+    let ident = GenerationContext::snake(ctx.auth_config().identifier_claim());
+    let mut out = String::with_capacity(8192);
+
+    out.push_str(&file_header(
+        "Sessions — rotating refresh tokens, revocation and the session endpoints.",
+    ));
+    out.push_str(framework.session_imports());
+    out.push_str(ctx.orm_backend().store_imports());
+    out.push_str(
+        "\
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+use crate::auth::jwt::{create_token, Claims};
+use crate::constants::REFRESH_TOKEN_EXPIRY_DAYS;
+use crate::error::AppError;
+use crate::state::AppState;
+",
+    );
+    if ctx.orm() == Orm::Diesel {
+        out.push_str("use crate::state::run;\n");
+    }
+    out.push('\n');
+
+    out.push_str(&format!(
+        "\
+/// Access and refresh token, answered at sign-in and on every refresh.
+#[derive(Debug, Serialize)]
+pub struct TokenPair {{
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: &'static str,
+    /// Access token lifetime in seconds.
+    pub expires_in: u64,
+}}
+
+/// Body of `POST /api/auth/refresh`.
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {{
+    pub refresh_token: String,
+}}
+
+/// A stored refresh token, with the claims its session was started with.
+struct RefreshToken {{
+    session_id: String,
+    user_id: String,
+    identifier: String,
+    /// Comma-separated role names.
+    roles: String,
+    expires_at: i64,
+    revoked_at: Option<i64>,
+}}
+
+/// Start a session for a user who just signed in, answering its first
+/// token pair:
+///
+/// ```rust,ignore
+/// let claims = Claims::with_default_expiry(user.id.to_string(), user.{ident}, roles);
+/// let tokens = sessions::start(&state, &claims).await?;
+/// ```
+pub async fn start(state: &AppState, claims: &Claims) -> Result<TokenPair, AppError> {{
+    let session_id = Uuid::new_v4().to_string();
+    issue(state, session_id, &claims.sub, &claims.{ident}, claims.roles.clone()).await
+}}
+
+/// Trade a refresh token for a new pair. The token is revoked; presenting
+/// it again means it leaked, and the whole session is revoked.
+pub async fn refresh(state: &AppState, refresh_token: &str) -> Result<TokenPair, AppError> {{
+    let token_hash = hash_token(refresh_token);
+    let Some(token) = find_token(state, token_hash.clone()).await? else {{
+        return Err(AppError::unauthorized(\"Invalid refresh token\"));
+    }};
+
+    let now = now();
+    if token.revoked_at.is_some() {{
+        tracing::warn!(\"Refresh token reused, revoking session {{}}\", token.session_id);
+        end(state, &token.session_id).await?;
+        return Err(AppError::unauthorized(\"Refresh token has been revoked\"));
+    }}
+    if token.expires_at <= now {{
+        return Err(AppError::unauthorized(\"Refresh token has expired\"));
+    }}
+    // A concurrent refresh with the same token may have revoked it first
+    if revoke(state, REVOKE_TOKEN_SQL, token_hash, now).await? == 0 {{
+        return Err(AppError::unauthorized(\"Refresh token has been revoked\"));
+    }}
+
+    let roles = token
+        .roles
+        .split(',')
+        .filter(|role| !role.is_empty())
+        .map(str::to_string)
+        .collect();
+    issue(state, token.session_id, &token.user_id, &token.identifier, roles).await
+}}
+
+/// End a session: its refresh tokens stop working, and so do the access
+/// tokens issued for it.
+pub async fn end(state: &AppState, session_id: &str) -> Result<(), AppError> {{
+    revoke(state, REVOKE_SESSION_SQL, session_id.to_string(), now()).await?;
+    Ok(())
+}}
+
+/// End every session of a user, e.g. after a password change.
+pub async fn end_all(state: &AppState, user_id: &str) -> Result<(), AppError> {{
+    revoke(state, REVOKE_USER_SQL, user_id.to_string(), now()).await?;
+    Ok(())
+}}
+
+/// Whether a session is live: it holds a refresh token that is neither
+/// revoked nor expired.
+pub async fn is_active(state: &AppState, session_id: &str) -> Result<bool, AppError> {{
+    Ok(count_live(state, session_id.to_string(), now()).await? > 0)
+}}
+
+/// Sign a new access token for the session and store a new refresh token.
+async fn issue(
+    state: &AppState,
+    session_id: String,
+    user_id: &str,
+    identifier: &str,
+    roles: Vec<String>,
+) -> Result<TokenPair, AppError> {{
+    let expiry_hours = state.config.jwt_expiry_hours;
+    let claims = Claims::new(user_id, identifier, roles, expiry_hours).with_session(&session_id);
+    let access_token = create_token(&claims, &state.config.jwt_secret)
+        .map_err(|e| AppError::internal(format!(\"Failed to sign token: {{}}\", e)))?;
+
+    let refresh_token = format!(\"{{}}{{}}\", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let now = now();
+    let token = RefreshToken {{
+        session_id,
+        user_id: user_id.to_string(),
+        identifier: identifier.to_string(),
+        roles: claims.roles.join(\",\"),
+        expires_at: now + REFRESH_TOKEN_EXPIRY_DAYS * 86_400,
+        revoked_at: None,
+    }};
+    insert_token(state, hash_token(&refresh_token), token, now).await?;
+
+    Ok(TokenPair {{
+        access_token,
+        refresh_token,
+        token_type: \"Bearer\",
+        expires_in: expiry_hours * 3600,
+    }})
+}}
+
+/// Refresh tokens are stored as their SHA-256, so a leaked table holds no
+/// usable token.
+fn hash_token(token: &str) -> String {{
+    format!(\"{{:x}}\", Sha256::digest(token.as_bytes()))
+}}
+
+/// Seconds since the UNIX epoch.
+fn now() -> i64 {{
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect(\"system clock before UNIX epoch\")
+        .as_secs() as i64
+}}
+
+"
+    ));
+
+    // ── Store ────────────────────────────────────────────────────────────
+    out.push_str(
+        "\
+// ============================================================================
+// Store
+// ============================================================================
+
+",
+    );
+    out.push_str(&session_sql(ctx.database()));
+    out.push('\n');
+    out.push_str(ctx.orm_backend().session_store());
+
+    // ── Handlers ─────────────────────────────────────────────────────────
+    out.push_str(
+        "
+// ============================================================================
+// Handlers
+// ============================================================================
+
+",
+    );
+    out.push_str(framework.session_handlers());
+
+    out
+}
+
+/// The `const …_SQL` statements the store runs, with the database's
+/// placeholders.
+fn session_sql(db: DatabaseType) -> String {
+    let p = |n: usize| match db {
+        DatabaseType::PostgreSQL => format!("${n}"),
+        DatabaseType::MySQL | DatabaseType::SQLite => "?".to_string(),
+    };
+    let table = REFRESH_TOKENS_TABLE;
+    let revoke = |column: &str| {
+        format!(
+            "UPDATE {table} SET revoked_at = {} WHERE {column} = {} AND revoked_at IS NULL",
+            p(1),
+            p(2)
+        )
+    };
+    let values: Vec<String> = (1..=7).map(p).collect();
+
+    [
+        (
+            "INSERT_SQL",
+            format!(
+                "INSERT INTO {table} (token_hash, session_id, user_id, identifier, roles, expires_at, created_at) VALUES ({})",
+                values.join(", ")
+            ),
+        ),
+        (
+            "FIND_SQL",
+            format!(
+                "SELECT session_id, user_id, identifier, roles, expires_at, revoked_at FROM {table} WHERE token_hash = {}",
+                p(1)
+            ),
+        ),
+        ("REVOKE_TOKEN_SQL", revoke("token_hash")),
+        ("REVOKE_SESSION_SQL", revoke("session_id")),
+        ("REVOKE_USER_SQL", revoke("user_id")),
+        (
+            "LIVE_SQL",
+            format!(
+                "SELECT COUNT(*) AS live FROM {table} WHERE session_id = {} AND revoked_at IS NULL AND expires_at > {}",
+                p(1),
+                p(2)
+            ),
+        ),
+    ]
+    .iter()
+    .map(|(name, sql)| format!("const {name}: &str = \"{sql}\";\n"))
+    .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{AuthConfig, AuthPrincipal, ProjectGraph, WebFramework};
+
+    fn project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        project.config.auth = AuthConfig::jwt().with_refresh_tokens();
+        project
+    }
+
+    fn sessions(project: &ProjectGraph) -> String {
+        let ctx = GenerationContext::from_project_default(project);
+        generate_sessions(&ctx).remove(0).content
+    }
+
+    #[test]
+    fn test_sessions_only_with_refresh_tokens() {
+        let mut project = ProjectGraph::new("app");
+        project.config.auth = AuthConfig::jwt();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_sessions(&ctx).is_empty());
+
+        project.config.auth = AuthConfig::none().with_refresh_tokens();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_sessions(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_sessions_rotate_and_revoke() {
+        let content = sessions(&project());
+        assert!(content.contains("pub async fn start(state: &AppState, claims: &Claims)"));
+        assert!(content.contains("issue(state, session_id, &claims.sub, &claims.email,"));
+        assert!(content.contains("pub async fn refresh(state: &AppState, refresh_token: &str)"));
+        assert!(content.contains("end(state, &token.session_id).await?;"));
+        assert!(
+            content.contains("if revoke(state, REVOKE_TOKEN_SQL, token_hash, now).await? == 0")
+        );
+        assert!(content.contains(".with_session(&session_id);"));
+        assert!(content.contains("Sha256::digest(token.as_bytes())"));
+        assert!(content.contains("pub async fn is_active("));
+    }
+
+    #[test]
+    fn test_sessions_follow_principal_identifier() {
+        let mut project = project();
+        project.config.auth = project
+            .config
+            .auth
+            .clone()
+            .with_principal(AuthPrincipal::new("Account").with_identifier("username"));
+        let content = sessions(&project);
+        assert!(content.contains("&claims.username,"));
+    }
+
+    #[test]
+    fn test_session_sql_placeholders() {
+        let postgres = session_sql(DatabaseType::PostgreSQL);
+        assert!(postgres.contains(
+            "const REVOKE_SESSION_SQL: &str = \"UPDATE refresh_tokens SET revoked_at = $1 WHERE session_id = $2 AND revoked_at IS NULL\";"
+        ));
+        assert!(postgres.contains("VALUES ($1, $2, $3, $4, $5, $6, $7)"));
+
+        let sqlite = session_sql(DatabaseType::SQLite);
+        assert!(sqlite.contains("WHERE token_hash = ?\";"));
+        assert!(sqlite.contains("expires_at > ?\";"));
+    }
+
+    #[test]
+    fn test_sessions_orm_and_framework() {
+        let content = sessions(&project());
+        assert!(content.contains("use sea_orm::{ConnectionTrait, Statement};"));
+        assert!(content.contains("pub async fn refresh_session("));
+        assert!(content.contains("Extension(claims): Extension<Claims>"));
+
+        let mut project = project();
+        project.config.orm = Orm::Diesel;
+        project.config.framework = WebFramework::ActixWeb;
+        let content = sessions(&project);
+        assert!(content.contains("run(&state.db, move |conn| {"));
+        assert!(content.contains("#[derive(QueryableByName)]"));
+        assert!(content.contains("claims: web::ReqData<Claims>"));
+    }
+}
@@ -0,0 +1,279 @@
+//! # Upload Storage Generator
+//!
+//! Generates `src/storage.rs` for projects with upload fields: fields whose
+//! UI widget is `File` or `Image`. Each such field gets a
+//! `POST {base}/:id/{field}` route taking a `multipart/form-data` body; the
+//! handler reads the first file part with `read_upload`, stores it with
+//! `store` and writes the returned URL to the field's column.
+//!
+//! Where files go follows the project's [`UploadConfig`]:
+//!
+//! | Storage | Written to | URL |
+//! |---------|------------|-----|
+//! | Local   | `UPLOAD_DIR`, served by the app under `/uploads` | `UPLOAD_BASE_URL/{key}` |
+//! | S3      | `S3_BUCKET` at `S3_ENDPOINT` (path-style, so MinIO and R2 work too) | `S3_PUBLIC_URL/{key}` |
+//!
+//! Reading the multipart body is framework-specific and comes from
+//! [`FrameworkBackend::upload_reader`](crate::rust::framework::FrameworkBackend::upload_reader).
+//!
+//! [`UploadConfig`]: imortal_ir::UploadConfig
+
+use imortal_ir::{UploadStorage, WidgetType};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::rust::{doc_comment, file_header};
+use crate::{FileType, GeneratedFile};
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `src/storage.rs`, if any entity has an upload route.
+pub fn generate_storage(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.uploads_enabled() {
+        return Vec::new();
+    }
+    vec![GeneratedFile::new(
+        "src/storage.rs",
+        build_storage(ctx),
+        FileType::Rust,
+    )]
+}
+
+/// Whether some upload field only takes images.
+pub(crate) fn accepts_images(ctx: &GenerationContext) -> bool {
+    ctx.entities().iter().any(|entity| {
+        EntityInfo::new(entity, ctx)
+            .upload_fields()
+            .iter()
+            .any(|f| f.ui_hints.widget == Some(WidgetType::Image))
+    })
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+fn build_storage(ctx: &GenerationContext) -> String {
+    let uploads = ctx.uploads();
+    let mut out = String::with_capacity(4096);
+
+    out.push_str(&file_header(
+        "File storage — reads multipart uploads and stores them on disk or in S3.",
+    ));
+
+    // ── Imports ──────────────────────────────────────────────────────────
+    if uploads.storage == UploadStorage::S3 {
+        out.push_str("use s3::creds::Credentials;\n");
+        out.push_str("use s3::{Bucket, Region};\n\n");
+    }
+    out.push_str("use crate::config::Config;\n");
+    out.push_str("use crate::error::AppError;\n\n");
+
+    // ── Upload ───────────────────────────────────────────────────────────
+    out.push_str(&doc_comment(
+        Some(&format!(
+            "Largest accepted file, in bytes ({} MB).",
+            uploads.max_size_mb
+        )),
+        ctx,
+    ));
+    out.push_str(&format!(
+        "pub const MAX_UPLOAD_BYTES: usize = {};\n\n",
+        uploads.max_size_bytes()
+    ));
+
+    out.push_str(&doc_comment(
+        Some("A file read from a multipart body."),
+        ctx,
+    ));
+    out.push_str(
+        "\
+pub struct Upload {
+    /// File name the client sent, if any.
+    pub file_name: Option<String>,
+    /// Content type the client sent, if any.
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+impl Upload {
+",
+    );
+    if accepts_images(ctx) {
+        out.push_str(
+            "    /// Reject anything the client didn't send as an image.
+    pub fn require_image(&self) -> Result<(), AppError> {
+        match self.content_type.as_deref() {
+            Some(content_type) if content_type.starts_with(\"image/\") => Ok(()),
+            _ => Err(AppError::BadRequest(\"Expected an image file\".to_string())),
+        }
+    }
+
+",
+        );
+    }
+    out.push_str(
+        "    /// A fresh storage key under `prefix`, keeping the file's extension so
+    /// the stored file is served with the right type.
+    pub fn key(&self, prefix: &str) -> String {
+        let extension = self
+            .file_name
+            .as_deref()
+            .and_then(|name| std::path::Path::new(name).extension())
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.chars().all(|c| c.is_ascii_alphanumeric()));
+        match extension {
+            Some(ext) => format!(\"{}-{}.{}\", prefix, uuid::Uuid::new_v4(), ext.to_ascii_lowercase()),
+            None => format!(\"{}-{}\", prefix, uuid::Uuid::new_v4()),
+        }
+    }
+}
+
+fn too_large() -> AppError {
+    AppError::BadRequest(format!(
+        \"File is larger than {} MB\",
+        MAX_UPLOAD_BYTES / (1024 * 1024)
+    ))
+}
+
+fn storage_error(e: impl std::fmt::Display) -> AppError {
+    AppError::internal(format!(\"Failed to store upload: {}\", e))
+}
+
+",
+    );
+
+    out.push_str(ctx.framework_backend().upload_reader());
+    out.push('\n');
+
+    // ── Store ────────────────────────────────────────────────────────────
+    match uploads.storage {
+        UploadStorage::Local => out.push_str(
+            "\
+/// Write `upload` to `UPLOAD_DIR` under `key` and return its public URL.
+pub async fn store(config: &Config, key: &str, upload: Upload) -> Result<String, AppError> {
+    let path = std::path::Path::new(&config.upload_dir).join(key);
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await.map_err(storage_error)?;
+    }
+    tokio::fs::write(&path, &upload.bytes)
+        .await
+        .map_err(storage_error)?;
+
+    Ok(format!(\"{}/{}\", config.upload_base_url.trim_end_matches('/'), key))
+}
+",
+        ),
+        UploadStorage::S3 => out.push_str(
+            "\
+/// Put `upload` in the `S3_BUCKET` bucket under `key` and return its public
+/// URL.
+pub async fn store(config: &Config, key: &str, upload: Upload) -> Result<String, AppError> {
+    let region = Region::Custom {
+        region: config.s3_region.clone(),
+        endpoint: config.s3_endpoint.clone(),
+    };
+    let credentials = Credentials::new(
+        Some(&config.s3_access_key),
+        Some(&config.s3_secret_key),
+        None,
+        None,
+        None,
+    )
+    .map_err(storage_error)?;
+    let bucket = Bucket::new(&config.s3_bucket, region, credentials)
+        .map_err(storage_error)?
+        .with_path_style();
+
+    let content_type = upload
+        .content_type
+        .as_deref()
+        .unwrap_or(\"application/octet-stream\");
+    bucket
+        .put_object_with_content_type(key, &upload.bytes, content_type)
+        .await
+        .map_err(storage_error)?;
+
+    Ok(format!(\"{}/{}\", config.s3_public_url.trim_end_matches('/'), key))
+}
+",
+        ),
+    }
+
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{
+        CrudOperation, EndpointGroup, Entity, Field, OperationType, ProjectGraph, UploadConfig,
+        WebFramework,
+    };
+
+    fn profiles(widget: WidgetType) -> ProjectGraph {
+        let mut project = ProjectGraph::new("app");
+        let mut profile = Entity::new("Profile");
+        profile
+            .fields
+            .push(Field::new("avatar", DataType::String).with_widget(widget));
+        let id = project.add_entity(profile);
+        project.add_endpoint(EndpointGroup::new(id, "Profile"));
+        project
+    }
+
+    #[test]
+    fn test_local_storage() {
+        let project = profiles(WidgetType::Image);
+        let ctx = GenerationContext::from_project_default(&project);
+        let files = generate_storage(&ctx);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.to_str(), Some("src/storage.rs"));
+        let content = &files[0].content;
+
+        assert!(content.contains("pub const MAX_UPLOAD_BYTES: usize = 10485760;"));
+        assert!(content.contains("pub fn require_image(&self)"));
+        assert!(
+            content.contains("pub async fn read_upload(mut multipart: axum::extract::Multipart)")
+        );
+        assert!(content.contains("Path::new(&config.upload_dir).join(key)"));
+        assert!(!content.contains("s3::"));
+    }
+
+    #[test]
+    fn test_s3_storage_with_actix() {
+        let mut project = profiles(WidgetType::File);
+        project.config.uploads = UploadConfig::s3().with_max_size_mb(2);
+        project.config.framework = WebFramework::ActixWeb;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_storage(&ctx)[0].content;
+
+        assert!(content.contains("pub const MAX_UPLOAD_BYTES: usize = 2097152;"));
+        assert!(!content.contains("require_image"));
+        assert!(content.contains("mut multipart: actix_multipart::Multipart"));
+        assert!(content.contains("Bucket::new(&config.s3_bucket, region, credentials)"));
+        assert!(content.contains(".with_path_style()"));
+        assert!(content.contains("put_object_with_content_type(key, &upload.bytes, content_type)"));
+    }
+
+    #[test]
+    fn test_no_storage_without_upload_routes() {
+        let project = profiles(WidgetType::Text);
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_storage(&ctx).is_empty());
+
+        // The routes ride on update
+        let mut project = profiles(WidgetType::File);
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.operations = vec![CrudOperation::new(OperationType::Read)];
+        }
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_storage(&ctx).is_empty());
+    }
+}
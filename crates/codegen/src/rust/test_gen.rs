@@ -10,8 +10,12 @@
 //!
 //! - Health check / server startup test
 //! - CRUD operation tests (create, read, list, update, delete)
-//! - Validation failure tests (if validator is used)
+//! - Validation failure tests: an empty body, plus one request per field
+//!   validation rule with a value that breaks it (422)
 //! - Not-found tests (404 for missing resources)
+//! - Auth-required tests for operations behind `require_auth` (401 without
+//!   a token or with an invalid one); the other tests send a bearer token
+//!   carrying the operation's roles to these operations
 //!
 //! ## Test Infrastructure
 //!
@@ -34,7 +38,7 @@
 //! cargo test -- --nocapture
 //! ```
 
-use imortal_ir::OperationType;
+use imortal_ir::{OperationType, PaginationStyle};
 
 use crate::context::{EntityInfo, GenerationContext};
 use crate::rust::file_header;
@@ -79,8 +83,19 @@ fn generate_api_tests(ctx: &GenerationContext) -> GeneratedFile {
     content.push_str(&generate_health_check_test(ctx));
     content.push('\n');
 
+    // ── GraphQL endpoint test ────────────────────────────────────────────
+    if ctx.graphql_enabled() {
+        content.push_str(&generate_graphql_test());
+        content.push('\n');
+    }
+
     // ── Per-entity tests ─────────────────────────────────────────────────
-    for entity in ctx.entities() {
+    let entities = if ctx.rest_enabled() {
+        ctx.entities()
+    } else {
+        &[]
+    };
+    for entity in entities {
         let info = EntityInfo::new(entity, ctx);
 
         // Only generate tests for entities with enabled endpoints
@@ -116,11 +131,16 @@ fn generate_test_imports(ctx: &GenerationContext) -> String {
     out.push_str("use reqwest::StatusCode;\n");
     out.push_str("use serde_json::{json, Value};\n");
     out.push_str("use std::net::TcpListener;\n");
+    out.push_str(ctx.framework_backend().server_imports());
     out.push('\n');
 
     // Import from the generated crate
     out.push_str(&format!("use {}::config::Config;\n", crate_ident));
-    out.push_str(&format!("use {}::routes::create_router;\n", crate_ident));
+    out.push_str(&format!(
+        "use {}::routes::{};\n",
+        crate_ident,
+        ctx.framework_backend().app_builder()
+    ));
     out.push_str(&format!("use {}::state::AppState;\n", crate_ident));
     out.push('\n');
 
@@ -135,7 +155,7 @@ fn generate_test_helpers(ctx: &GenerationContext) -> String {
     let mut out = String::with_capacity(4096);
 
     out.push_str(
-        "\
+        &"\
 // ============================================================================
 // Test Helpers
 // ============================================================================
@@ -152,7 +172,7 @@ struct TestServer {
 impl TestServer {
     /// Start a test server on a random port.
     ///
-    /// This spins up a full Axum server in a background tokio task and
+    /// This spins up a full {framework} server in a background tokio task and
     /// returns a `TestServer` handle with the base URL for making requests.
     async fn start() -> Self {
         // Load .env for test database URL
@@ -167,24 +187,10 @@ impl TestServer {
         let base_url = format!(\"http://127.0.0.1:{}\", port);
 
         // Connect to database
-        let db = sea_orm::Database::connect(config.database_connect_options())
-            .await
+        let db = {connect}
             .expect(\"failed to connect to test database\");
 
-        let state = AppState::new(db, config);
-        let router = create_router(state);
-
-        // Convert std TcpListener to tokio TcpListener
-        listener.set_nonblocking(true).unwrap();
-        let tokio_listener = tokio::net::TcpListener::from_std(listener).unwrap();
-
-        // Start server in background
-        tokio::spawn(async move {
-            axum::serve(tokio_listener, router)
-                .await
-                .expect(\"server error\");
-        });
-
+{serve}
         // Give the server a moment to start
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
@@ -217,7 +223,10 @@ fn test_client() -> reqwest::Client {
         .expect(\"failed to create HTTP client\")
 }
 
-",
+"
+        .replace("{connect}", ctx.orm_backend().test_connect_expr())
+        .replace("{serve}", ctx.framework_backend().test_serve())
+        .replace("{framework}", ctx.framework().display_name()),
     );
 
     // Add auth helper if auth is enabled
@@ -290,9 +299,84 @@ async fn test_server_starts() {
 ",
     );
 
+    if ctx.health_checks() {
+        out.push_str(
+            "\
+/// The test database is reachable, so the instance reports itself ready.
+#[tokio::test]
+async fn test_health_probes() {
+    let server = TestServer::start().await;
+    let client = test_client();
+
+    for path in [\"/healthz\", \"/readyz\"] {
+        let response = client
+            .get(&server.url(path))
+            .send()
+            .await
+            .expect(\"request failed\");
+        assert_eq!(response.status(), StatusCode::OK, \"{} failed\", path);
+    }
+}
+
+",
+        );
+    }
+
+    if ctx.metrics() {
+        out.push_str(
+            "\
+/// Metrics are exported in the Prometheus text format.
+#[tokio::test]
+async fn test_metrics_endpoint() {
+    let server = TestServer::start().await;
+    let client = test_client();
+
+    let response = client
+        .get(&server.url(\"/metrics\"))
+        .send()
+        .await
+        .expect(\"request failed\");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+",
+        );
+    }
+
     out
 }
 
+// ============================================================================
+// GraphQL endpoint test
+// ============================================================================
+
+fn generate_graphql_test() -> String {
+    "\
+// ============================================================================
+// GraphQL
+// ============================================================================
+
+/// Verify that the GraphQL endpoint executes a query.
+#[tokio::test]
+async fn test_graphql_answers_queries() {
+    let server = TestServer::start().await;
+    let client = test_client();
+
+    let response = client
+        .post(&server.url(\"/graphql\"))
+        .json(&json!({ \"query\": \"{ apiVersion }\" }))
+        .send()
+        .await
+        .expect(\"request failed\");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.expect(\"invalid JSON\");
+    assert!(body[\"data\"][\"apiVersion\"].is_string(), \"unexpected body: {}\", body);
+}
+"
+    .to_string()
+}
+
 // ============================================================================
 // Per-entity test module
 // ============================================================================
@@ -326,9 +410,40 @@ mod {} {{
         pascal, pascal, module_name,
     ));
 
+    // ── Header versioning: requests name this group's version ───────────
+    if let (Some(header), Some(version)) = (ctx.api_versioning().header_name(), info.api_version())
+    {
+        out.push_str(&format!(
+            "\
+    /// Test client sending `{header}: {version}` with every request.
+    fn test_client() -> reqwest::Client {{
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            \"{header_lower}\",
+            reqwest::header::HeaderValue::from_static(\"{version}\"),
+        );
+        reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .default_headers(headers)
+            .build()
+            .expect(\"failed to create HTTP client\")
+    }}
+
+",
+            header_lower = header.to_ascii_lowercase(),
+        ));
+    }
+
     // ── Build a sample JSON payload for creating an entity ────────────────
     let sample_json = build_sample_create_json(info, ctx);
 
+    // ── Bearer tokens for operations behind the auth middleware ──────────
+    let auth_list = bearer_auth_line(info, ctx, OperationType::ReadAll, 12);
+    let auth_create = bearer_auth_line(info, ctx, OperationType::Create, 12);
+    let auth_read = bearer_auth_line(info, ctx, OperationType::Read, 12);
+    let auth_update = bearer_auth_line(info, ctx, OperationType::Update, 12);
+    let auth_delete = bearer_auth_line(info, ctx, OperationType::Delete, 12);
+
     // ── List test (ReadAll) ──────────────────────────────────────────────
     if enabled_ops.contains(&OperationType::ReadAll) {
         out.push_str(&format!(
@@ -343,7 +458,7 @@ mod {} {{
 
         let response = client
             .get(&server.url(\"{base_path}\"))
-            .send()
+{auth_list}            .send()
             .await
             .expect(\"request failed\");
 
@@ -354,19 +469,18 @@ mod {} {{
         );
 
         let body: Value = response.json().await.expect(\"invalid JSON\");
-        assert!(body.get(\"items\").is_some(), \"Response should have 'items' field\");
-        assert!(body.get(\"total\").is_some(), \"Response should have 'total' field\");
-        assert!(body.get(\"page\").is_some(), \"Response should have 'page' field\");
-    }}
+{envelope_checks}    }}
 
 ",
             plural = info.plural_name(),
             base_path = base_path,
+            envelope_checks = list_envelope_checks(ctx),
         ));
 
         // Pagination test
-        out.push_str(&format!(
-            "\
+        match ctx.pagination() {
+            PaginationStyle::Offset => out.push_str(&format!(
+                "\
     /// Test list endpoint with pagination parameters.
     ///
     /// GET {base_path}?page=1&per_page=5
@@ -377,7 +491,7 @@ mod {} {{
 
         let response = client
             .get(&server.url(\"{base_path}?page=1&per_page=5\"))
-            .send()
+{auth_list}            .send()
             .await
             .expect(\"request failed\");
 
@@ -389,9 +503,38 @@ mod {} {{
     }}
 
 ",
-            plural = info.plural_name(),
-            base_path = base_path,
-        ));
+                plural = info.plural_name(),
+                base_path = base_path,
+            )),
+            PaginationStyle::Cursor => out.push_str(&format!(
+                "\
+    /// Test list endpoint with a cursor page size.
+    ///
+    /// GET {base_path}?limit=5
+    #[tokio::test]
+    async fn test_list_{plural}_with_pagination() {{
+        let server = TestServer::start().await;
+        let client = test_client();
+
+        let response = client
+            .get(&server.url(\"{base_path}?limit=5\"))
+{auth_list}            .send()
+            .await
+            .expect(\"request failed\");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = response.json().await.expect(\"invalid JSON\");
+        let count = body[\"items\"].as_array().map(|a| a.len()).unwrap_or(0);
+        assert!(count <= 5, \"limit should cap the page size\");
+    }}
+
+",
+                plural = info.plural_name(),
+                base_path = base_path,
+            )),
+            PaginationStyle::None => {}
+        }
     }
 
     // ── Create test ──────────────────────────────────────────────────────
@@ -410,7 +553,7 @@ mod {} {{
 
         let response = client
             .post(&server.url(\"{base_path}\"))
-            .json(&payload)
+{auth_create}            .json(&payload)
             .send()
             .await
             .expect(\"request failed\");
@@ -444,7 +587,7 @@ mod {} {{
 
         let response = client
             .post(&server.url(\"{base_path}\"))
-            .json(&json!({{}}))
+{auth_create}            .json(&json!({{}}))
             .send()
             .await
             .expect(\"request failed\");
@@ -462,6 +605,57 @@ mod {} {{
             snake = info.snake_name(),
             base_path = base_path,
         ));
+
+        // Per-field validation failures
+        let cases = invalid_field_cases(info);
+        if !cases.is_empty() {
+            let auth_create_case = bearer_auth_line(info, ctx, OperationType::Create, 16);
+            let case_lines: String = cases
+                .iter()
+                .map(|(field, value)| format!("            (\"{}\", {}),\n", field, value))
+                .collect();
+
+            out.push_str(&format!(
+                "\
+    /// Test that each field validation rule rejects an out-of-range value.
+    ///
+    /// POST {base_path} with one invalid field at a time
+    #[tokio::test]
+    async fn test_create_{snake}_rejects_invalid_fields() {{
+        let server = TestServer::start().await;
+        let client = test_client();
+
+        let valid = {sample_json};
+
+        let cases: Vec<(&str, Value)> = vec![
+{case_lines}        ];
+
+        for (field, value) in cases {{
+            let mut payload = valid.clone();
+            payload[field] = value;
+
+            let response = client
+                .post(&server.url(\"{base_path}\"))
+{auth_create_case}                .json(&payload)
+                .send()
+                .await
+                .expect(\"request failed\");
+
+            assert_eq!(
+                response.status(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                \"Invalid '{{}}' should fail validation\",
+                field,
+            );
+        }}
+    }}
+
+",
+                snake = info.snake_name(),
+                base_path = base_path,
+                sample_json = sample_json,
+            ));
+        }
     }
 
     // ── Get test (Read) ──────────────────────────────────────────────────
@@ -483,7 +677,7 @@ mod {} {{
 
         let response = client
             .get(&server.url(&format!(\"{base_path}/{{}}\", fake_id)))
-            .send()
+{auth_read}            .send()
             .await
             .expect(\"request failed\");
 
@@ -493,16 +687,71 @@ mod {} {{
             \"Non-existent resource should return 404\"
         );
 
+        assert_eq!(
+            response.headers()[\"content-type\"],
+            \"application/problem+json\",
+            \"Errors should be RFC 7807 problem details\"
+        );
         let body: Value = response.json().await.expect(\"invalid JSON\");
-        assert!(
-            body.get(\"error\").is_some(),
-            \"Error response should have an 'error' field\"
+        assert_eq!(body[\"status\"], 404);
+        assert_eq!(body[\"code\"], \"not_found\");
+    }}
+
+",
+            snake = info.snake_name(),
+            base_path = base_path,
+        ));
+    }
+
+    // ── Get test (Create + Read) ─────────────────────────────────────────
+    if enabled_ops.contains(&OperationType::Create) && enabled_ops.contains(&OperationType::Read) {
+        out.push_str(&format!(
+            "\
+    /// Test getting an existing {snake} by ID.
+    ///
+    /// POST {base_path} (create) → GET {base_path}/{{id}}
+    #[tokio::test]
+    async fn test_get_{snake}() {{
+        let server = TestServer::start().await;
+        let client = test_client();
+
+        let payload = {sample_json};
+
+        let create_resp = client
+            .post(&server.url(\"{base_path}\"))
+{auth_create}            .json(&payload)
+            .send()
+            .await
+            .expect(\"create request failed\");
+
+        assert_eq!(create_resp.status(), StatusCode::CREATED);
+
+        let created: Value = create_resp.json().await.expect(\"invalid JSON\");
+        let id = created
+            .get(\"id\")
+            .and_then(|v| v.as_str())
+            .expect(\"created resource should have an 'id'\");
+
+        let response = client
+            .get(&server.url(&format!(\"{base_path}/{{}}\", id)))
+{auth_read}            .send()
+            .await
+            .expect(\"get request failed\");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::OK,
+            \"Get endpoint should return 200\"
         );
+
+        let fetched: Value = response.json().await.expect(\"invalid JSON\");
+        assert_eq!(fetched, created, \"Fetched resource should match the created one\");
     }}
 
 ",
             snake = info.snake_name(),
             base_path = base_path,
+            sample_json = sample_json,
         ));
     }
 
@@ -522,7 +771,7 @@ mod {} {{
 
         let response = client
             .delete(&server.url(&format!(\"{base_path}/{{}}\", fake_id)))
-            .send()
+{auth_delete}            .send()
             .await
             .expect(\"request failed\");
 
@@ -563,7 +812,7 @@ mod {} {{
 
         let create_resp = client
             .post(&server.url(\"{base_path}\"))
-            .json(&payload)
+{auth_create}            .json(&payload)
             .send()
             .await
             .expect(\"create request failed\");
@@ -579,7 +828,7 @@ mod {} {{
         // 2. Read
         let get_resp = client
             .get(&server.url(&format!(\"{base_path}/{{}}\", id)))
-            .send()
+{auth_read}            .send()
             .await
             .expect(\"get request failed\");
 
@@ -595,7 +844,7 @@ mod {} {{
         // 3. Delete
         let delete_resp = client
             .delete(&server.url(&format!(\"{base_path}/{{}}\", id)))
-            .send()
+{auth_delete}            .send()
             .await
             .expect(\"delete request failed\");
 
@@ -604,7 +853,7 @@ mod {} {{
         // 4. Verify deleted
         let verify_resp = client
             .get(&server.url(&format!(\"{base_path}/{{}}\", id)))
-            .send()
+{auth_read}            .send()
             .await
             .expect(\"verify request failed\");
 
@@ -613,12 +862,13 @@ mod {} {{
             StatusCode::NOT_FOUND,
             \"Deleted resource should return 404\"
         );
-    }}
+{restore}    }}
 
 ",
             snake = info.snake_name(),
             base_path = base_path,
             sample_json = sample_json,
+            restore = lifecycle_restore(info, &base_path, &auth_delete, &auth_read),
         ));
     }
 
@@ -643,7 +893,7 @@ mod {} {{
 
         let create_resp = client
             .post(&server.url(\"{base_path}\"))
-            .json(&payload)
+{auth_create}            .json(&payload)
             .send()
             .await
             .expect(\"create request failed\");
@@ -661,7 +911,7 @@ mod {} {{
 
         let update_resp = client
             .put(&server.url(&format!(\"{base_path}/{{}}\", id)))
-            .json(&update_payload)
+{auth_update}            .json(&update_payload)
             .send()
             .await
             .expect(\"update request failed\");
@@ -705,7 +955,7 @@ mod {} {{
 
         let response = client
             .put(&server.url(&format!(\"{base_path}/{{}}\", fake_id)))
-            .json(&payload)
+{auth_update}            .json(&payload)
             .send()
             .await
             .expect(\"request failed\");
@@ -723,18 +973,292 @@ mod {} {{
         ));
     }
 
+    // ── Auth-required tests ──────────────────────────────────────────────
+    for &op in enabled_ops {
+        if let Some(roles) = secured_roles(info, ctx, op) {
+            out.push_str(&requires_auth_test(info, op));
+            if !roles.is_empty() {
+                out.push_str(&requires_role_test(info, op, &roles));
+            }
+        }
+    }
+
     // Close module
     out.push_str("}\n");
 
     out
 }
 
+/// Assertions on the list response body for the project's pagination style.
+fn list_envelope_checks(ctx: &GenerationContext) -> &'static str {
+    match ctx.pagination() {
+        PaginationStyle::Offset => {
+            "        assert!(body.get(\"items\").is_some(), \"Response should have 'items' field\");
+        assert!(body.get(\"total\").is_some(), \"Response should have 'total' field\");
+        assert!(body.get(\"page\").is_some(), \"Response should have 'page' field\");
+"
+        }
+        PaginationStyle::Cursor => {
+            "        assert!(body.get(\"items\").is_some(), \"Response should have 'items' field\");
+        assert!(body.get(\"next_cursor\").is_some(), \"Response should have 'next_cursor' field\");
+        assert!(body.get(\"has_more\").is_some(), \"Response should have 'has_more' field\");
+"
+        }
+        PaginationStyle::None => {
+            "        assert!(body.is_array(), \"Response should be a JSON array\");
+"
+        }
+    }
+}
+
+/// Lifecycle steps restoring a soft-deleted resource and reading it again,
+/// or nothing for entities that delete rows.
+fn lifecycle_restore(
+    info: &EntityInfo,
+    base_path: &str,
+    auth_delete: &str,
+    auth_read: &str,
+) -> String {
+    if !info.has_soft_delete() {
+        return String::new();
+    }
+    format!(
+        "
+        // 5. Restore (soft delete keeps the row)
+        let restore_resp = client
+            .post(&server.url(&format!(\"{base_path}/{{}}/restore\", id)))
+{auth_delete}            .send()
+            .await
+            .expect(\"restore request failed\");
+
+        assert_eq!(restore_resp.status(), StatusCode::OK);
+
+        let restored_resp = client
+            .get(&server.url(&format!(\"{base_path}/{{}}\", id)))
+{auth_read}            .send()
+            .await
+            .expect(\"get request failed\");
+
+        assert_eq!(
+            restored_resp.status(),
+            StatusCode::OK,
+            \"Restored resource should be readable again\"
+        );
+"
+    )
+}
+
+// ============================================================================
+// Auth helpers
+// ============================================================================
+
+/// The roles an operation asks for, or `None` if the generated router does
+/// not put it behind `require_auth`.
+fn secured_roles(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    op: OperationType,
+) -> Option<Vec<String>> {
+    if !ctx.auth_enabled() {
+        return None;
+    }
+    let security = info.endpoint()?.effective_security(op);
+    security.auth_required.then_some(security.roles)
+}
+
+/// A `.bearer_auth(…)` line (indented by `indent` spaces) for a request to
+/// `op`, or an empty string for public operations.
+fn bearer_auth_line(
+    info: &EntityInfo,
+    ctx: &GenerationContext,
+    op: OperationType,
+    indent: usize,
+) -> String {
+    let Some(roles) = secured_roles(info, ctx, op) else {
+        return String::new();
+    };
+
+    let token = if roles.is_empty() {
+        "user_token()".to_string()
+    } else {
+        let roles: Vec<String> = roles
+            .iter()
+            .map(|r| format!("\"{}\".to_string()", r))
+            .collect();
+        format!(
+            "create_test_token(\"test-user-id\", vec![{}])",
+            roles.join(", ")
+        )
+    };
+
+    format!("{:indent$}.bearer_auth({})\n", "", token, indent = indent)
+}
+
+/// The request method, test name stem, URL expression and body line of a
+/// request to `op` on a random ID.
+fn op_request(
+    info: &EntityInfo,
+    op: OperationType,
+) -> (&'static str, String, String, &'static str) {
+    let base_path = info.base_path();
+    let (method, test_name) = match op {
+        OperationType::Create => ("post", format!("create_{}", info.snake_name())),
+        OperationType::Read => ("get", format!("get_{}", info.snake_name())),
+        OperationType::ReadAll => ("get", format!("list_{}", info.plural_name())),
+        OperationType::Update => ("put", format!("update_{}", info.snake_name())),
+        OperationType::Delete => ("delete", format!("delete_{}", info.snake_name())),
+    };
+    let url = match op {
+        OperationType::Create | OperationType::ReadAll => format!("\"{}\"", base_path),
+        _ => format!("&format!(\"{}/{{}}\", uuid::Uuid::new_v4())", base_path),
+    };
+    let body = match op {
+        OperationType::Create | OperationType::Update => "            .json(&json!({}))\n",
+        _ => "",
+    };
+    (method, test_name, url, body)
+}
+
+/// A test that `op` answers 403 to a valid token holding none of `roles`.
+fn requires_role_test(info: &EntityInfo, op: OperationType, roles: &[String]) -> String {
+    let (method, test_name, url, body) = op_request(info, op);
+    format!(
+        "\
+    /// Test that {upper} {base_path}{suffix} requires one of the roles {roles}.
+    #[tokio::test]
+    async fn test_{test_name}_requires_role() {{
+        let server = TestServer::start().await;
+        let client = test_client();
+
+        let response = client
+            .{method}(&server.url({url}))
+            .bearer_auth(create_test_token(\"test-user-id\", vec![]))
+{body}            .send()
+            .await
+            .expect(\"request failed\");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::FORBIDDEN,
+            \"Request without a required role should return 403\"
+        );
+    }}
+
+",
+        upper = method.to_uppercase(),
+        base_path = info.base_path(),
+        suffix = match op {
+            OperationType::Create | OperationType::ReadAll => "",
+            _ => "/{id}",
+        },
+        roles = roles.join(", "),
+    )
+}
+
+/// A test that `op` answers 401 both without a token and with a malformed one.
+fn requires_auth_test(info: &EntityInfo, op: OperationType) -> String {
+    let base_path = info.base_path();
+    let (method, test_name, url, body) = op_request(info, op);
+
+    format!(
+        "\
+    /// Test that {upper} {base_path}{suffix} rejects missing and invalid tokens.
+    #[tokio::test]
+    async fn test_{test_name}_requires_auth() {{
+        let server = TestServer::start().await;
+        let client = test_client();
+        let url = server.url({url});
+
+        let response = client
+            .{method}(&url)
+{body}            .send()
+            .await
+            .expect(\"request failed\");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            \"Request without a token should return 401\"
+        );
+
+        let response = client
+            .{method}(&url)
+            .bearer_auth(\"not-a-valid-token\")
+{body}            .send()
+            .await
+            .expect(\"request failed\");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            \"Request with an invalid token should return 401\"
+        );
+    }}
+
+",
+        upper = method.to_uppercase(),
+        suffix = match op {
+            OperationType::Create | OperationType::ReadAll => "",
+            _ => "/{id}",
+        },
+    )
+}
+
 // ============================================================================
 // Sample data helpers
 // ============================================================================
 
-/// Build a sample JSON object for creating an entity, with sensible test values
-/// based on field types.
+/// One invalid `(field, value)` pair per create-field validation rule that
+/// has an obvious counterexample. Values are Rust expressions yielding a
+/// `serde_json::Value`.
+///
+/// Pattern, phone, one-of and custom rules are skipped, as are decimal
+/// ranges, which the generated DTOs do not check.
+fn invalid_field_cases(info: &EntityInfo) -> Vec<(String, String)> {
+    use imortal_core::{DataType, Validation};
+
+    let mut cases = Vec::new();
+
+    for field in info.create_fields() {
+        let name = GenerationContext::snake(&field.name);
+        let mut data_type = &field.data_type;
+        while let DataType::Optional(inner) = data_type {
+            data_type = inner;
+        }
+        let integer = matches!(data_type, DataType::Int32 | DataType::Int64);
+        let float = matches!(data_type, DataType::Float32 | DataType::Float64);
+        let string = matches!(data_type, DataType::String | DataType::Text);
+        let number = |n: f64| {
+            if integer {
+                format!("json!({})", n as i64)
+            } else {
+                format!("json!({:?})", n)
+            }
+        };
+
+        for validation in &field.validations {
+            let value = match validation {
+                Validation::MinLength(n) if string && *n > 0 => {
+                    format!("json!(\"x\".repeat({}))", n - 1)
+                }
+                Validation::MaxLength(n) if string => format!("json!(\"x\".repeat({}))", n + 1),
+                Validation::Email if string => "json!(\"not-an-email\")".to_string(),
+                Validation::Url if string => "json!(\"not a url\")".to_string(),
+                Validation::Min(n) if integer => number(n.ceil() - 1.0),
+                Validation::Max(n) if integer => number(n.floor() + 1.0),
+                Validation::Min(n) if float => number(n - 1.0),
+                Validation::Max(n) if float => number(n + 1.0),
+                _ => continue,
+            };
+            cases.push((name.clone(), value));
+        }
+    }
+
+    cases
+}
+
+/// Build a sample JSON object for creating an entity, using each field's
+/// example value when set and sensible type-based test values otherwise.
 fn build_sample_create_json(info: &EntityInfo, _ctx: &GenerationContext) -> String {
     let fields = info.create_fields();
 
@@ -742,7 +1266,7 @@ fn build_sample_create_json(info: &EntityInfo, _ctx: &GenerationContext) -> Stri
 
     for field in &fields {
         let name = GenerationContext::snake(&field.name);
-        let value = sample_value_for_type(&field.data_type, &name);
+        let value = sample_value_for_field(field, &name);
         entries.push(format!("            \"{}\": {}", name, value));
     }
 
@@ -771,6 +1295,15 @@ fn build_sample_update_json(fields: &[&imortal_ir::Field]) -> String {
     format!("json!({{\n{}\n        }})", entries.join(",\n"),)
 }
 
+/// Sample value for a field: the user-provided example if there is one,
+/// otherwise a type-based test value.
+fn sample_value_for_field(field: &imortal_ir::Field, field_name: &str) -> String {
+    match &field.example {
+        Some(example) if !example.trim().is_empty() => field.example_json(),
+        _ => sample_value_for_type(&field.data_type, field_name),
+    }
+}
+
 /// Generate a sensible sample value for a given data type.
 ///
 /// These are used to build test request payloads.
@@ -794,6 +1327,8 @@ fn sample_value_for_type(dt: &imortal_core::DataType, field_name: &str) -> Strin
         }
         DataType::Int32 | DataType::Int64 => "42".to_string(),
         DataType::Float32 | DataType::Float64 => "3.14".to_string(),
+        // Sent as a string so no precision is lost; fits even NUMERIC(1,1)
+        DataType::Decimal { .. } => "\"0.5\"".to_string(),
         DataType::Bool => "true".to_string(),
         DataType::Uuid => format!(
             "\"{}\"",
@@ -826,9 +1361,10 @@ fn sample_value_for_type(dt: &imortal_core::DataType, field_name: &str) -> Strin
 #[cfg(test)]
 mod tests {
     use super::*;
-    use imortal_core::DataType;
+    use imortal_core::{DataType, Validation};
     use imortal_ir::{
-        AuthConfig, CrudOperation, EndpointGroup, Entity, Field, OperationType, ProjectGraph,
+        ApiVersioning, AuthConfig, CrudOperation, EndpointGroup, EndpointSecurity, Entity, Field,
+        OperationType, ProjectGraph,
     };
     use uuid::Uuid;
 
@@ -888,6 +1424,22 @@ mod tests {
         assert_eq!(files[0].path.to_string_lossy(), "tests/api_tests.rs");
     }
 
+    #[test]
+    fn test_header_versioning_sends_version_header() {
+        let mut project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(!generate_tests(&ctx)[0].content.contains("default_headers"));
+
+        project.config.api_versioning = ApiVersioning::header("v3");
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+        assert!(
+            content.contains("/// Test client sending `X-API-Version: v3` with every request.")
+        );
+        assert!(content.contains("HeaderValue::from_static(\"v3\")"));
+        assert!(content.contains(".default_headers(headers)"));
+    }
+
     #[test]
     fn test_generate_tests_disabled() {
         let project = setup_project();
@@ -933,6 +1485,15 @@ mod tests {
 
         assert!(content.contains("#[tokio::test]"));
         assert!(content.contains("async fn test_server_starts()"));
+        assert!(!content.contains("async fn test_health_probes()"));
+
+        let mut project = project;
+        project.config.observability = imortal_ir::ObservabilityConfig::all();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+        assert!(content.contains("async fn test_health_probes()"));
+        assert!(content.contains("for path in [\"/healthz\", \"/readyz\"] {"));
+        assert!(content.contains("async fn test_metrics_endpoint()"));
     }
 
     #[test]
@@ -974,6 +1535,22 @@ mod tests {
         assert!(content.contains("page=1&per_page=5"));
     }
 
+    #[test]
+    fn test_test_file_list_test_follows_pagination_style() {
+        let mut project = setup_project();
+        project.config.pagination = PaginationStyle::Cursor;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+        assert!(content.contains("?limit=5"));
+        assert!(content.contains("next_cursor"));
+
+        project.config.pagination = PaginationStyle::None;
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+        assert!(content.contains("body.is_array()"));
+        assert!(!content.contains("test_list_users_with_pagination"));
+    }
+
     #[test]
     fn test_test_file_create_validation_error() {
         let project = setup_project();
@@ -1054,6 +1631,122 @@ mod tests {
         assert!(content.contains("JWT_SECRET"));
     }
 
+    #[test]
+    fn test_secured_endpoints_get_auth_tests_and_tokens() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::jwt();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.global_security = EndpointSecurity::authenticated();
+            endpoint.set_operation_security(OperationType::Read, EndpointSecurity::open());
+            endpoint.set_operation_security(
+                OperationType::Delete,
+                EndpointSecurity::with_roles(vec!["admin".to_string()]),
+            );
+        }
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(content.contains("async fn test_create_user_requires_auth()"));
+        assert!(content.contains("async fn test_list_users_requires_auth()"));
+        assert!(content.contains("async fn test_update_user_requires_auth()"));
+        assert!(content.contains("async fn test_delete_user_requires_auth()"));
+        assert!(!content.contains("async fn test_get_user_requires_auth()"));
+        assert!(content.contains("StatusCode::UNAUTHORIZED"));
+        assert!(content.contains(".bearer_auth(\"not-a-valid-token\")"));
+
+        // Happy paths authenticate, with the operation's roles when it has some
+        assert!(content.contains(".bearer_auth(user_token())"));
+        assert!(content.contains(
+            ".bearer_auth(create_test_token(\"test-user-id\", vec![\"admin\".to_string()]))"
+        ));
+
+        // Role-guarded operations refuse a token without the role
+        assert!(content.contains("async fn test_delete_user_requires_role()"));
+        assert!(!content.contains("async fn test_create_user_requires_role()"));
+        assert!(content.contains(".bearer_auth(create_test_token(\"test-user-id\", vec![]))"));
+        assert!(content.contains("StatusCode::FORBIDDEN"));
+    }
+
+    #[test]
+    fn test_open_endpoints_have_no_auth_tests() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::jwt();
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(!content.contains("_requires_auth()"));
+        assert!(!content.contains(".bearer_auth("));
+    }
+
+    #[test]
+    fn test_secured_endpoints_without_auth_config_are_public() {
+        let mut project = setup_project();
+        project.config.auth = AuthConfig::none();
+        for endpoint in project.endpoints.values_mut() {
+            endpoint.global_security = EndpointSecurity::authenticated();
+        }
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(!content.contains("_requires_auth()"));
+        assert!(!content.contains(".bearer_auth("));
+    }
+
+    #[test]
+    fn test_get_happy_path_test() {
+        let project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(content.contains("async fn test_get_user()"));
+        assert!(content.contains("\"Get endpoint should return 200\""));
+    }
+
+    #[test]
+    fn test_invalid_field_tests_follow_validations() {
+        let mut project = setup_project();
+        let user = project.entities.values_mut().next().unwrap();
+        user.fields
+            .iter_mut()
+            .find(|f| f.name == "email")
+            .unwrap()
+            .validations
+            .push(Validation::Email);
+        user.fields
+            .iter_mut()
+            .find(|f| f.name == "name")
+            .unwrap()
+            .validations
+            .extend([Validation::MinLength(2), Validation::MaxLength(50)]);
+        let mut age = Field::new("age", DataType::Int32);
+        age.validations
+            .extend([Validation::Min(0.0), Validation::Max(150.0)]);
+        user.fields.push(age);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(content.contains("async fn test_create_user_rejects_invalid_fields()"));
+        assert!(content.contains("(\"email\", json!(\"not-an-email\")),"));
+        assert!(content.contains("(\"name\", json!(\"x\".repeat(1))),"));
+        assert!(content.contains("(\"name\", json!(\"x\".repeat(51))),"));
+        assert!(content.contains("(\"age\", json!(-1)),"));
+        assert!(content.contains("(\"age\", json!(151)),"));
+        assert!(content.contains("StatusCode::UNPROCESSABLE_ENTITY"));
+    }
+
+    #[test]
+    fn test_no_invalid_field_test_without_validations() {
+        let project = setup_project();
+        let ctx = GenerationContext::from_project_default(&project);
+        let content = &generate_tests(&ctx)[0].content;
+
+        assert!(!content.contains("rejects_invalid_fields"));
+    }
+
     #[test]
     fn test_test_file_without_auth_no_token_helpers() {
         let mut project = setup_project();
@@ -1073,6 +1766,10 @@ mod tests {
         assert!(sample_value_for_type(&DataType::String, "name").contains("test_name"));
         assert_eq!(sample_value_for_type(&DataType::Int32, "count"), "42");
         assert_eq!(sample_value_for_type(&DataType::Float64, "price"), "3.14");
+        assert_eq!(
+            sample_value_for_type(&DataType::money(), "price"),
+            "\"0.5\""
+        );
         assert_eq!(sample_value_for_type(&DataType::Bool, "active"), "true");
         assert!(sample_value_for_type(&DataType::Uuid, "ref_id").contains("00000000"));
         assert!(sample_value_for_type(&DataType::DateTime, "created_at").contains("2026"));
@@ -1116,6 +1813,33 @@ mod tests {
         assert!(json.contains("3.14"));
     }
 
+    #[test]
+    fn test_sample_create_json_uses_field_examples() {
+        let mut project = ProjectGraph::new("test");
+
+        let mut entity = Entity::new("Product");
+        entity.config.timestamps = false;
+        entity.fields.push(
+            Field::new("name", DataType::String)
+                .required()
+                .with_example("Desk Lamp"),
+        );
+        entity.fields.push(
+            Field::new("stock", DataType::Int32)
+                .required()
+                .with_example("12"),
+        );
+        project.add_entity(entity);
+
+        let ctx = GenerationContext::from_project_default(&project);
+        let e = ctx.entities().first().unwrap();
+        let info = EntityInfo::new(e, &ctx);
+
+        let json = build_sample_create_json(&info, &ctx);
+        assert!(json.contains("\"name\": \"Desk Lamp\""));
+        assert!(json.contains("\"stock\": 12"));
+    }
+
     #[test]
     fn test_sample_update_json() {
         let name_field = Field::new("name", DataType::String);
@@ -0,0 +1,333 @@
+//! # Client SDK Generator
+//!
+//! Generates a standalone Rust crate that consumers of the generated REST API
+//! can depend on instead of hand-writing HTTP calls.
+//!
+//! ## Generated Files
+//!
+//! - `client/Cargo.toml` — the `{package}-client` crate, with an empty
+//!   `[workspace]` table so it builds on its own inside a generated workspace
+//! - `client/src/lib.rs` — crate docs and re-exports
+//! - `client/src/types.rs` — the create, update and response DTOs, enums,
+//!   pagination envelope and problem details `ApiError`, the same types the
+//!   fullstack `shared` crate holds
+//! - `client/src/client.rs` — `ApiClient`, a `reqwest` wrapper with one async
+//!   method per enabled operation (`list_users`, `get_user`, `create_user`,
+//!   `update_user`, `delete_user`), and `ClientError`
+//!
+//! Both the types and the client come from the generators behind the
+//! fullstack frontend, so the SDK and the frontend cannot drift apart.
+//!
+//! ## Usage
+//!
+//! The crate is only generated when `ctx.generate_client_sdk()` returns
+//! `true` — set with [`GeneratorConfig::with_client_sdk`] or
+//! `with_option("client_sdk", "true")`.
+//!
+//! ```rust,ignore
+//! use my_app_client::{ApiClient, CreateUserDto};
+//!
+//! let client = ApiClient::new().with_base_url("https://api.example.com");
+//! let user = client.create_user(&CreateUserDto { /* … */ }).await?;
+//! ```
+//!
+//! [`GeneratorConfig::with_client_sdk`]: crate::GeneratorConfig::with_client_sdk
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::frontend::{api_client, shared_types};
+use crate::rust::cargo::{RUST_DECIMAL_DEP, package_metadata};
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile};
+
+/// Directory the SDK crate is written to
+pub const SDK_DIR: &str = "client";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate the `client/` SDK crate.
+///
+/// Returns an empty `Vec` if SDK generation is disabled or no entity has
+/// enabled REST endpoints.
+pub fn generate_client_sdk(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !generates_sdk(ctx) {
+        return Vec::new();
+    }
+
+    vec![
+        generate_cargo_toml(ctx),
+        generate_lib(ctx),
+        generate_types(ctx),
+        generate_client(ctx),
+    ]
+}
+
+/// Whether the SDK is enabled and has any REST operation to call.
+pub fn generates_sdk(ctx: &GenerationContext) -> bool {
+    ctx.generate_client_sdk()
+        && ctx.rest_enabled()
+        && ctx.entities().iter().any(|entity| {
+            EntityInfo::new(entity, ctx)
+                .endpoint()
+                .is_some_and(|ep| ep.enabled)
+        })
+}
+
+/// Name of the SDK package, e.g. `my_app-client`.
+pub fn sdk_package_name(ctx: &GenerationContext) -> String {
+    format!("{}-client", ctx.package_name())
+}
+
+/// Name the SDK is imported under, e.g. `my_app_client`.
+pub fn sdk_crate_name(ctx: &GenerationContext) -> String {
+    sdk_package_name(ctx).replace('-', "_")
+}
+
+// ============================================================================
+// Cargo.toml
+// ============================================================================
+
+fn generate_cargo_toml(ctx: &GenerationContext) -> GeneratedFile {
+    let content = format!(
+        r#"[package]
+name = "{name}"
+version = "{version}"
+edition = "{edition}"
+{metadata}
+# Built on its own, even when generated inside the project's workspace
+[workspace]
+
+[dependencies]
+# HTTP Client
+reqwest = {{ version = "0.12", features = ["json"] }}
+
+# Serialization
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+
+# Identifiers
+uuid = {{ version = "1", features = ["v4", "serde"] }}
+
+# Date/Time
+chrono = {{ version = "0.4", features = ["serde"] }}
+{decimal}
+# Error handling
+thiserror = "2"
+"#,
+        name = sdk_package_name(ctx),
+        version = ctx.meta.version,
+        edition = ctx.rust_edition(),
+        metadata = package_metadata(&ctx.meta),
+        decimal = if ctx.uses_decimal() {
+            RUST_DECIMAL_DEP
+        } else {
+            ""
+        },
+    );
+
+    GeneratedFile::new(format!("{SDK_DIR}/Cargo.toml"), content, FileType::Toml)
+}
+
+// ============================================================================
+// src/lib.rs
+// ============================================================================
+
+fn generate_lib(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = file_header(&format!("Typed client for the {} REST API.", ctx.meta.name));
+
+    content.push_str(&format!(
+        r#"//! ```rust,ignore
+//! use {krate}::ApiClient;
+//!
+//! let client = ApiClient::new()
+//!     .with_base_url("https://api.example.com")
+//!     .with_token(token);
+//! ```
+//!
+//! Failed requests return [`ClientError::Api`] with the status, code and
+//! detail of the server's problem details body.
+
+mod client;
+pub mod types;
+
+pub use client::{{ApiClient, ClientError}};
+pub use types::*;
+"#,
+        krate = sdk_crate_name(ctx),
+    ));
+
+    GeneratedFile::new(format!("{SDK_DIR}/src/lib.rs"), content, FileType::Rust)
+}
+
+// ============================================================================
+// src/types.rs
+// ============================================================================
+
+fn generate_types(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = file_header("Request and response types of the REST API.");
+    content.push_str(&shared_types(ctx));
+
+    GeneratedFile::new(format!("{SDK_DIR}/src/types.rs"), content, FileType::Rust)
+}
+
+// ============================================================================
+// src/client.rs
+// ============================================================================
+
+fn generate_client(ctx: &GenerationContext) -> GeneratedFile {
+    let mut content = file_header("Type-safe HTTP client for the REST API.");
+    content.push_str(&api_client::client_source(ctx, "crate::types"));
+
+    GeneratedFile::new(format!("{SDK_DIR}/src/client.rs"), content, FileType::Rust)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_core::DataType;
+    use imortal_ir::{ApiStyle, EndpointGroup, Entity, Field, OperationType, ProjectGraph};
+
+    fn project() -> ProjectGraph {
+        let mut project = ProjectGraph::new("my_app");
+        project.config.package_name = "my_app".to_string();
+
+        let mut user = Entity::new("User");
+        user.config.timestamps = true;
+        let user_id = user.id;
+        let mut email = Field::new("email", DataType::String);
+        email.required = true;
+        user.fields.push(email);
+        let mut pw = Field::new("password_hash", DataType::String);
+        pw.required = true;
+        pw.secret = true;
+        user.fields.push(pw);
+        project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+
+        let tag = Entity::new("Tag");
+        let tag_id = tag.id;
+        project.add_entity(tag);
+        project.add_endpoint(
+            EndpointGroup::new(tag_id, "Tag")
+                .with_operations(&[OperationType::Read, OperationType::ReadAll]),
+        );
+
+        project
+    }
+
+    fn files(project: &ProjectGraph) -> Vec<GeneratedFile> {
+        let ctx =
+            GenerationContext::from_project(project, GeneratorConfig::new().with_client_sdk());
+        generate_client_sdk(&ctx)
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .unwrap_or_else(|| panic!("missing {path}"))
+            .content
+    }
+
+    #[test]
+    fn test_generate_client_sdk_files() {
+        let files = files(&project());
+        let paths: Vec<String> = files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "client/Cargo.toml",
+                "client/src/lib.rs",
+                "client/src/types.rs",
+                "client/src/client.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sdk_cargo_toml_is_standalone() {
+        let files = files(&project());
+        let cargo = file(&files, "client/Cargo.toml");
+        assert!(cargo.contains("name = \"my_app-client\""));
+        assert!(cargo.contains("\n[workspace]\n"));
+        assert!(cargo.contains("reqwest = { version = \"0.12\", features = [\"json\"] }"));
+        assert!(!cargo.contains(".workspace = true"));
+        assert!(!cargo.contains("rust_decimal"));
+    }
+
+    #[test]
+    fn test_sdk_reuses_dtos() {
+        let files = files(&project());
+        let types = file(&files, "client/src/types.rs");
+        assert!(types.contains("pub struct CreateUserDto {"));
+        assert!(types.contains("pub struct UpdateUserDto {"));
+        assert!(types.contains("pub struct UserResponse {"));
+        assert!(types.contains("pub struct ApiError {"));
+        let response = &types[types.find("pub struct UserResponse").unwrap()..];
+        let response = &response[..response.find('}').unwrap()];
+        assert!(!response.contains("password_hash"));
+
+        let lib = file(&files, "client/src/lib.rs");
+        assert!(lib.contains("pub use client::{ApiClient, ClientError};"));
+        assert!(lib.contains("pub use types::*;"));
+        assert!(lib.contains("use my_app_client::ApiClient;"));
+    }
+
+    #[test]
+    fn test_sdk_client_has_one_method_per_operation() {
+        let files = files(&project());
+        let client = file(&files, "client/src/client.rs");
+        assert!(client.contains("use crate::types::{ApiError, PaginatedResponse};"));
+        assert!(client.contains("use crate::types::{CreateUserDto, UpdateUserDto, UserResponse};"));
+        assert!(!client.contains("use shared::"));
+        for method in [
+            "list_users",
+            "get_user",
+            "create_user",
+            "update_user",
+            "delete_user",
+            "list_tags",
+            "get_tag",
+        ] {
+            assert!(
+                client.contains(&format!("pub async fn {method}(")),
+                "missing {method}"
+            );
+        }
+        assert!(!client.contains("pub async fn create_tag("));
+        assert!(!client.contains("pub async fn delete_tag("));
+    }
+
+    #[test]
+    fn test_sdk_disabled_by_default() {
+        let project = project();
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_client_sdk(&ctx).is_empty());
+
+        let ctx = GenerationContext::from_project(
+            &project,
+            GeneratorConfig::new().with_option("client_sdk", "true"),
+        );
+        assert_eq!(generate_client_sdk(&ctx).len(), 4);
+    }
+
+    #[test]
+    fn test_sdk_needs_rest_endpoints() {
+        let mut project = project();
+        project.config.api_style = ApiStyle::Graphql;
+        assert!(files(&project).is_empty());
+
+        let mut project = self::project();
+        project.endpoints.clear();
+        assert!(files(&project).is_empty());
+    }
+}
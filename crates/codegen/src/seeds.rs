@@ -0,0 +1,1624 @@
+//! # Seed Data Generator
+//!
+//! Generates fake records to fill a freshly migrated database with, so the
+//! generated API has something to serve during development and demos.
+//!
+//! ## Generated Files
+//!
+//! - `seeds/{index}_{table}.sql` — the `INSERT`s for one entity, numbered in
+//!   dependency order (referenced tables first)
+//! - `src/bin/seed.rs` — `cargo run --bin seed` applies every `.sql` file in
+//!   `seeds/` in one transaction
+//!
+//! ## Values
+//!
+//! Records are deterministic: generating the same project twice produces the
+//! same rows. Each value follows its field:
+//!
+//! - the field's example, when it has one and need not be unique
+//! - its validations: length, range, email, URL, phone and one-of
+//! - its name, for realistic values (`email`, `first_name`, `title`, `city`, …)
+//! - its type, for everything else
+//!
+//! Unique columns get distinct values, and foreign keys point at seeded rows
+//! of the table they reference (a row each for one-to-one keys). The auth
+//! principal's password is stored as the bcrypt hash of [`SEED_PASSWORD`].
+//!
+//! Each entity gets [`EntityConfig::seed_count`] records; `0` leaves its table
+//! empty. An entity whose required foreign key references an empty table is
+//! skipped with a warning, as are pattern validations the values can't be
+//! shown to match.
+//!
+//! Seed files are only generated when `ctx.generate_seeds()` returns `true` —
+//! set with [`GeneratorConfig::with_seeds`].
+//!
+//! [`EntityConfig::seed_count`]: imortal_ir::EntityConfig::seed_count
+//! [`GeneratorConfig::with_seeds`]: crate::GeneratorConfig::with_seeds
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use imortal_core::{DataType, IdType, Validation};
+use imortal_ir::{DatabaseType, Field};
+use uuid::Uuid;
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::migrations::sql::{TableDef, database_display_name, quote_identifier};
+use crate::rust::file_header;
+use crate::{FileType, GeneratedFile, GenerationWarning, WarningCode};
+
+/// Password of every seeded account
+pub const SEED_PASSWORD: &str = "password";
+
+/// bcrypt hash (cost 12) of [`SEED_PASSWORD`]
+const SEED_PASSWORD_HASH: &str = "$2b$12$l3x43MPeOXh/4zzT.8JQkedt8plKM1cVIXCsGCVqIVem8WTjM1S.a";
+
+/// Rows per `INSERT` statement
+const ROWS_PER_INSERT: usize = 100;
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate the seed SQL files and the `seed` binary that applies them.
+///
+/// Returns an empty `Vec` if seed generation is disabled.
+pub fn generate_seeds(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.generate_seeds() {
+        return Vec::new();
+    }
+
+    let plan = plan_seeds(ctx);
+    let mut files: Vec<GeneratedFile> = plan
+        .tables
+        .iter()
+        .enumerate()
+        .map(|(index, table)| {
+            GeneratedFile::new(
+                format!("seeds/{:03}_{}.sql", index + 1, table.def.name),
+                render_table(table, ctx),
+                FileType::Sql,
+            )
+        })
+        .collect();
+
+    files.push(GeneratedFile::new(
+        "src/bin/seed.rs",
+        generate_seed_binary(ctx),
+        FileType::Rust,
+    ));
+    files
+}
+
+/// Warnings about entities the seeds skip or only approximate.
+pub fn seed_warnings(ctx: &GenerationContext) -> Vec<GenerationWarning> {
+    if !ctx.generate_seeds() {
+        return Vec::new();
+    }
+    plan_seeds(ctx).warnings
+}
+
+// ============================================================================
+// Planning
+// ============================================================================
+
+/// A dynamically typed value, rendered per database.
+#[derive(Debug, Clone, PartialEq)]
+enum SeedValue {
+    Null,
+    Int(i64),
+    /// A float or decimal, already rounded to its scale
+    Number(String),
+    Bool(bool),
+    Text(String),
+    Uuid(Uuid),
+    DateTime(NaiveDateTime),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Bytes(Vec<u8>),
+    Json(String),
+    Array(Vec<SeedValue>),
+}
+
+/// The rows seeded into one table.
+struct SeedTable<'a> {
+    info: EntityInfo<'a>,
+    def: TableDef,
+    columns: Vec<String>,
+    rows: Vec<Vec<SeedValue>>,
+    /// Auto-increment primary key column, whose sequence must catch up
+    serial_pk: Option<String>,
+    /// Whether the rows include a hashed password
+    has_password: bool,
+}
+
+struct SeedPlan<'a> {
+    tables: Vec<SeedTable<'a>>,
+    warnings: Vec<GenerationWarning>,
+}
+
+/// Seeded values of a table, by column (and field) name.
+type SeededColumns = HashMap<String, Vec<SeedValue>>;
+
+fn plan_seeds(ctx: &GenerationContext) -> SeedPlan<'_> {
+    let mut tables = Vec::new();
+    let mut warnings = Vec::new();
+    let mut seeded: HashMap<Uuid, SeededColumns> = HashMap::new();
+
+    for entity in ctx.entities() {
+        if entity.config.seed_count == 0 {
+            continue;
+        }
+
+        let info = EntityInfo::new(entity, ctx);
+        let def = TableDef::of(&info, ctx);
+        let columns: Vec<(&Field, bool)> = def
+            .columns
+            .iter()
+            // Generated columns are computed by the database
+            .filter(|col| col.generated.is_none())
+            .filter_map(|col| {
+                let field = entity.fields.iter().find(|f| Some(f.id) == col.field_id)?;
+                Some((field, col.not_null))
+            })
+            // Columns the database fills in itself (`created_at`, …)
+            .filter(|(field, _)| {
+                field.is_primary_key || !(field.readonly && field.default_value.is_some())
+            })
+            .collect();
+
+        warnings.extend(pattern_warnings(&info, &columns));
+
+        let count = match row_count(&info, &columns, &seeded) {
+            Ok(count) => count,
+            Err(warning) => {
+                warnings.push(warning);
+                continue;
+            }
+        };
+
+        let mut generator = RowGenerator {
+            ctx,
+            info: &info,
+            table: def.name.clone(),
+            count,
+            rng: SeedRng::new(&def.name),
+        };
+        let rows: Vec<Vec<SeedValue>> = (0..count)
+            .map(|i| {
+                columns
+                    .iter()
+                    .map(|(field, not_null)| generator.value(field, *not_null, i, &seeded))
+                    .collect()
+            })
+            .collect();
+
+        // Remember every column so later tables can reference it
+        let mut values = SeededColumns::new();
+        for (index, (field, _)) in columns.iter().enumerate() {
+            let column: Vec<SeedValue> = rows.iter().map(|row| row[index].clone()).collect();
+            values.insert(field.name.clone(), column.clone());
+            values.insert(column_of(&def, field), column);
+        }
+        seeded.insert(entity.id, values);
+
+        let serial_pk = match info.id_type() {
+            IdType::Serial => info.pk().map(|pk| column_of(&def, pk)),
+            _ => None,
+        };
+        let has_password = columns.iter().any(|(f, _)| is_password(ctx, f));
+
+        tables.push(SeedTable {
+            columns: columns.iter().map(|(f, _)| column_of(&def, f)).collect(),
+            info,
+            def,
+            rows,
+            serial_pk,
+            has_password,
+        });
+    }
+
+    SeedPlan { tables, warnings }
+}
+
+/// Column name of one of the table's fields.
+fn column_of(def: &TableDef, field: &Field) -> String {
+    def.columns
+        .iter()
+        .find(|c| c.field_id == Some(field.id))
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| GenerationContext::snake(&field.name))
+}
+
+/// The type under any `Optional` wrappers.
+fn base_type(dt: &DataType) -> &DataType {
+    match dt {
+        DataType::Optional(inner) => base_type(inner),
+        other => other,
+    }
+}
+
+/// How many rows an entity gets: its seed count, capped by unique columns
+/// with few possible values and by one-to-one parents. Fails when a required
+/// foreign key has nothing to reference.
+fn row_count(
+    info: &EntityInfo,
+    columns: &[(&Field, bool)],
+    seeded: &HashMap<Uuid, SeededColumns>,
+) -> Result<usize, GenerationWarning> {
+    let entity = info.entity;
+    let mut count = entity.config.seed_count as usize;
+
+    for (field, not_null) in columns {
+        if field.is_primary_key {
+            continue;
+        }
+
+        if let Some(fk) = field
+            .foreign_key_ref
+            .as_ref()
+            .filter(|_| field.is_foreign_key)
+        {
+            if fk.entity_id == entity.id {
+                continue;
+            }
+            let parent_rows = seeded
+                .get(&fk.entity_id)
+                .and_then(|values| values.values().next())
+                .map_or(0, Vec::len);
+            if parent_rows == 0 && *not_null {
+                return Err(GenerationWarning::new(
+                    WarningCode::Other,
+                    format!(
+                        "No seed data for '{}': its required field '{}' references '{}', which has no seed records.",
+                        entity.name, field.name, fk.entity_name
+                    ),
+                )
+                .with_field(entity.id, field.id)
+                .with_suggestion(format!(
+                    "Give '{}' a seed count, or make '{}' optional",
+                    fk.entity_name, field.name
+                )));
+            }
+            if field.unique && parent_rows > 0 {
+                count = count.min(parent_rows);
+            }
+            continue;
+        }
+
+        if field.unique
+            && let Some(capacity) = distinct_values(field)
+        {
+            count = count.min(capacity);
+        }
+    }
+
+    Ok(count)
+}
+
+/// How many distinct values a field can take, when that is a small number.
+fn distinct_values(field: &Field) -> Option<usize> {
+    if let Some(values) = one_of(field) {
+        return Some(values.len());
+    }
+    match base_type(&field.data_type) {
+        DataType::Bool => Some(2),
+        DataType::Enum { variants, .. } if !variants.is_empty() => Some(variants.len()),
+        DataType::Int32 | DataType::Int64 => {
+            let (lo, hi) = int_range(field);
+            usize::try_from(hi.abs_diff(lo)).ok()?.checked_add(1)
+        }
+        _ => None,
+    }
+}
+
+/// Pattern-validated fields whose seeds are not taken from an example.
+fn pattern_warnings(info: &EntityInfo, columns: &[(&Field, bool)]) -> Vec<GenerationWarning> {
+    columns
+        .iter()
+        .filter(|(field, _)| {
+            let patterned = field
+                .validations
+                .iter()
+                .any(|v| matches!(v, Validation::Pattern { .. }));
+            patterned && (field.unique || !has_example(field))
+        })
+        .map(|(field, _)| {
+            GenerationWarning::new(
+                WarningCode::Other,
+                format!(
+                    "Seed values for '{}.{}' may not match its pattern.",
+                    info.entity.name, field.name
+                ),
+            )
+            .with_field(info.entity.id, field.id)
+            .with_suggestion("Give the field a (non-unique) example; seeds use it as-is")
+        })
+        .collect()
+}
+
+// ============================================================================
+// Values
+// ============================================================================
+
+/// Generates the rows of one table.
+struct RowGenerator<'a> {
+    ctx: &'a GenerationContext,
+    info: &'a EntityInfo<'a>,
+    table: String,
+    count: usize,
+    rng: SeedRng,
+}
+
+impl RowGenerator<'_> {
+    /// The value of `field` in row `i`.
+    fn value(
+        &mut self,
+        field: &Field,
+        not_null: bool,
+        i: usize,
+        seeded: &HashMap<Uuid, SeededColumns>,
+    ) -> SeedValue {
+        if field.is_primary_key {
+            return key_value(self.info.id_type(), &self.table, i);
+        }
+
+        if let Some(fk) = field
+            .foreign_key_ref
+            .as_ref()
+            .filter(|_| field.is_foreign_key)
+        {
+            // Self-references point at an earlier row; the first row is a root
+            if fk.entity_id == self.info.entity.id {
+                if field.unique {
+                    return key_value(self.info.id_type(), &self.table, i);
+                }
+                if !not_null && (i == 0 || self.rng.below(4) == 0) {
+                    return SeedValue::Null;
+                }
+                let parent = self.rng.below(i.max(1) as u64) as usize;
+                return key_value(self.info.id_type(), &self.table, parent);
+            }
+
+            let parent = seeded.get(&fk.entity_id).and_then(|values| {
+                let column = if fk.field_name.is_empty() {
+                    "id"
+                } else {
+                    &fk.field_name
+                };
+                values.get(column).or_else(|| {
+                    let parent = self.ctx.entity_by_id(fk.entity_id)?;
+                    values.get(&self.ctx.primary_key_field(parent)?.name)
+                })
+            });
+            return match parent {
+                Some(rows) if !rows.is_empty() => {
+                    let row = if field.unique {
+                        i
+                    } else {
+                        self.rng.below(rows.len() as u64) as usize
+                    };
+                    rows[row.min(rows.len() - 1)].clone()
+                }
+                _ => SeedValue::Null,
+            };
+        }
+
+        if !not_null && self.rng.below(5) == 0 {
+            return SeedValue::Null;
+        }
+
+        if is_password(self.ctx, field) {
+            return SeedValue::Text(SEED_PASSWORD_HASH.to_string());
+        }
+        if self.ctx.is_password_field(field) {
+            return SeedValue::Text(format!("{:016x}{:016x}", self.rng.next(), self.rng.next()));
+        }
+
+        if !field.unique
+            && let Some(value) = from_example(field)
+        {
+            return value;
+        }
+
+        self.typed(&field.data_type, field, i)
+    }
+
+    /// A value of type `dt` for `field`, following its name and validations.
+    fn typed(&mut self, dt: &DataType, field: &Field, i: usize) -> SeedValue {
+        match dt {
+            DataType::Optional(inner) => self.typed(inner, field, i),
+            DataType::String | DataType::Text => SeedValue::Text(self.text(dt, field, i)),
+            DataType::Int32 | DataType::Int64 => SeedValue::Int(self.int(field, i)),
+            DataType::Float32 | DataType::Float64 => {
+                let (lo, hi) = int_range(field);
+                SeedValue::Number(self.number(field, lo as f64, hi as f64, 2, i))
+            }
+            DataType::Decimal { precision, scale } => {
+                let (lo, hi) = int_range(field);
+                let bound = 10f64.powi(i32::from(precision.saturating_sub(*scale)))
+                    - 10f64.powi(-i32::from(*scale));
+                let hi = (hi as f64).min(bound);
+                let lo = (lo as f64).max(-bound).min(hi.max(0.0));
+                SeedValue::Number(self.number(field, lo, hi, usize::from(*scale), i))
+            }
+            DataType::Bool => SeedValue::Bool(if field.unique {
+                i % 2 == 1
+            } else {
+                self.rng.below(2) == 1
+            }),
+            DataType::Uuid | DataType::Reference { .. } => {
+                SeedValue::Uuid(seed_uuid(&format!("{}.{}", self.table, field.name), i))
+            }
+            DataType::DateTime => {
+                let base = base_date().and_time(NaiveTime::MIN);
+                let offset = self.rng.below(365 * 24 * 60 * 60) as i64;
+                SeedValue::DateTime(base + Duration::seconds(offset))
+            }
+            DataType::Date => {
+                let name = GenerationContext::snake(&field.name);
+                let (base, days) = if name.contains("birth") || name.contains("dob") {
+                    (
+                        NaiveDate::from_ymd_opt(1950, 1, 1).unwrap_or(base_date()),
+                        55 * 365,
+                    )
+                } else {
+                    (base_date(), 365)
+                };
+                SeedValue::Date(base + Duration::days(self.rng.below(days) as i64))
+            }
+            DataType::Time => {
+                let minutes = self.rng.below(24 * 60) as u32;
+                SeedValue::Time(
+                    NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0).unwrap_or_default(),
+                )
+            }
+            DataType::Bytes => SeedValue::Bytes(self.rng.next().to_be_bytes().to_vec()),
+            DataType::Json => SeedValue::Json(format!("{{\"seed\": {}}}", i + 1)),
+            DataType::Array(inner) => {
+                let len = 1 + self.rng.below(3) as usize;
+                SeedValue::Array(
+                    (0..len)
+                        .map(|k| self.typed(inner, field, i * 3 + k))
+                        .collect(),
+                )
+            }
+            DataType::Enum { variants, .. } => match variants.len() {
+                0 => SeedValue::Text("variant".to_string()),
+                n => {
+                    let index = if field.unique {
+                        i % n
+                    } else {
+                        self.rng.below(n as u64) as usize
+                    };
+                    SeedValue::Text(variants[index].clone())
+                }
+            },
+        }
+    }
+
+    /// A realistic string for the field, within its length limits.
+    fn text(&mut self, dt: &DataType, field: &Field, i: usize) -> String {
+        let n = i + 1;
+        if let Some(values) = one_of(field) {
+            let index = if field.unique {
+                i
+            } else {
+                self.rng.below(values.len() as u64) as usize
+            };
+            return values[index % values.len()].clone();
+        }
+
+        let name = GenerationContext::snake(&field.name);
+        let has = |v: Validation| field.validations.contains(&v);
+        // One person per row, so its name, email and username agree
+        let mut person = SeedRng::new(&format!("{}#person{}", self.table, i));
+        let first = *person.pick(FIRST_NAMES);
+        let last = *person.pick(LAST_NAMES);
+
+        // `distinct`: the value already contains the row number
+        let (value, distinct) = if has(Validation::Email) || name.contains("email") {
+            let local = format!("{}.{}{}", first, last, n).to_lowercase();
+            (format!("{}@example.com", local), true)
+        } else if has(Validation::Url)
+            || name.ends_with("url")
+            || name.contains("website")
+            || name.contains("link")
+        {
+            (format!("https://example.com/{}/{}", self.table, n), true)
+        } else if has(Validation::Phone) || name.contains("phone") || name.contains("mobile") {
+            (format!("+1555{:07}", n), true)
+        } else if name.contains("username") || name.contains("login") || name.contains("handle") {
+            (format!("{}{}{}", first, last, n).to_lowercase(), true)
+        } else if name.contains("first_name") || name.contains("given_name") {
+            (first.to_string(), false)
+        } else if name.contains("last_name")
+            || name.contains("surname")
+            || name.contains("family_name")
+        {
+            (last.to_string(), false)
+        } else if name.contains("author")
+            || name == "full_name"
+            || (name.contains("name") && self.is_person())
+        {
+            (format!("{} {}", first, last), false)
+        } else if name.contains("title") || name.contains("subject") || name.contains("headline") {
+            (capitalize(&self.words(3, 6)), false)
+        } else if name.contains("slug") {
+            (
+                format!("{}-{}", self.words(2, 3).replace(' ', "-"), n),
+                true,
+            )
+        } else if name.contains("city") {
+            (self.rng.pick(CITIES).to_string(), false)
+        } else if name.contains("country") {
+            (self.rng.pick(COUNTRIES).to_string(), false)
+        } else if name.contains("company") || name.contains("organization") {
+            (self.rng.pick(COMPANIES).to_string(), false)
+        } else if name.contains("address") || name.contains("street") {
+            let number = 1 + self.rng.below(9999);
+            (format!("{} {} Street", number, last), false)
+        } else if name.contains("zip") || name.contains("postal") {
+            (format!("{:05}", 10000 + self.rng.below(90000)), false)
+        } else if name.contains("color") || name.contains("colour") {
+            (format!("#{:06x}", self.rng.below(0x100_0000)), false)
+        } else if name.contains("code") || name.contains("sku") {
+            let prefix: String = (0..3)
+                .map(|_| char::from(b'A' + self.rng.below(26) as u8))
+                .collect();
+            (format!("{}-{:04}", prefix, n), true)
+        } else if matches!(dt, DataType::Text) || LONG_FORM.iter().any(|w| name.contains(w)) {
+            (self.paragraph(), false)
+        } else {
+            (capitalize(&self.words(1, 3)), false)
+        };
+
+        let suffix = (field.unique && !distinct).then(|| format!(" {}", n));
+        let value = match &suffix {
+            Some(suffix) => format!("{}{}", value, suffix),
+            None => value,
+        };
+        fit_length(value, field, dt, suffix.as_deref())
+    }
+
+    /// Whether the entity's records are people, so a `name` is a person's.
+    fn is_person(&self) -> bool {
+        let entity = GenerationContext::snake(&self.info.entity.name);
+        PEOPLE.iter().any(|p| entity.contains(p))
+    }
+
+    /// An integer within the field's range; unique fields count up from its start.
+    fn int(&mut self, field: &Field, i: usize) -> i64 {
+        if let Some(values) = one_of(field) {
+            let parsed: Vec<i64> = values.iter().filter_map(|v| v.parse().ok()).collect();
+            if !parsed.is_empty() {
+                let index = if field.unique {
+                    i
+                } else {
+                    self.rng.below(parsed.len() as u64) as usize
+                };
+                return parsed[index % parsed.len()];
+            }
+        }
+
+        let (lo, hi) = int_range(field);
+        if field.unique {
+            lo.saturating_add(i as i64).min(hi)
+        } else {
+            self.rng.range(lo, hi)
+        }
+    }
+
+    /// A number in `lo..=hi` with `scale` decimals, formatted for SQL.
+    fn number(&mut self, field: &Field, lo: f64, hi: f64, scale: usize, i: usize) -> String {
+        let value = if field.unique {
+            let step = ((hi - lo) / self.count.max(1) as f64).max(10f64.powi(-(scale as i32)));
+            lo + step * i as f64
+        } else {
+            lo + self.rng.unit() * (hi - lo)
+        };
+
+        let factor = 10f64.powi(scale as i32);
+        let mut rounded = (value * factor).round() / factor;
+        if rounded < lo {
+            rounded = (lo * factor).ceil() / factor;
+        }
+        if rounded > hi {
+            rounded = (hi * factor).floor() / factor;
+        }
+        format!("{:.*}", scale, rounded)
+    }
+
+    /// `min..=max` lorem words, space-separated.
+    fn words(&mut self, min: u64, max: u64) -> String {
+        let count = min + self.rng.below(max - min + 1);
+        (0..count)
+            .map(|_| *self.rng.pick(WORDS))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Two to four lorem sentences.
+    fn paragraph(&mut self) -> String {
+        let count = 2 + self.rng.below(3);
+        (0..count)
+            .map(|_| format!("{}.", capitalize(&self.words(5, 12))))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Primary key of row `i` of `table`.
+fn key_value(id_type: IdType, table: &str, i: usize) -> SeedValue {
+    match id_type {
+        IdType::Uuid => SeedValue::Uuid(seed_uuid(table, i)),
+        IdType::Serial => SeedValue::Int(i as i64 + 1),
+        IdType::Cuid => {
+            let mut rng = SeedRng::new(&format!("{}#{}", table, i));
+            let body: String = (0..24)
+                .map(|_| char::from_digit(rng.below(36) as u32, 36).unwrap_or('0'))
+                .collect();
+            SeedValue::Text(format!("c{}", body))
+        }
+        IdType::Ulid => {
+            // Timestamps a millisecond apart keep the keys in row order
+            const EPOCH_MS: u128 = 1_735_689_600_000; // 2025-01-01T00:00:00Z
+            const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+            let mut rng = SeedRng::new(&format!("{}#{}", table, i));
+            let random =
+                (u128::from(rng.next()) << 16 | u128::from(rng.next() >> 48)) & ((1 << 80) - 1);
+            let ulid = (EPOCH_MS + i as u128) << 80 | random;
+            let text: String = (0..26)
+                .map(|k| char::from(CROCKFORD[((ulid >> (125 - 5 * k)) & 31) as usize]))
+                .collect();
+            SeedValue::Text(text)
+        }
+    }
+}
+
+/// A UUID derived from `key` and the row number.
+fn seed_uuid(key: &str, i: usize) -> Uuid {
+    let mut rng = SeedRng::new(&format!("{}#{}", key, i));
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&rng.next().to_be_bytes());
+    bytes[8..].copy_from_slice(&rng.next().to_be_bytes());
+    uuid::Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// The principal's password field, or any field named like a password.
+fn is_password(ctx: &GenerationContext, field: &Field) -> bool {
+    field.name.contains("password")
+        || ctx
+            .principal_password_field()
+            .is_some_and(|f| f.id == field.id)
+}
+
+fn has_example(field: &Field) -> bool {
+    field
+        .example
+        .as_deref()
+        .is_some_and(|e| !e.trim().is_empty())
+}
+
+fn one_of(field: &Field) -> Option<&[String]> {
+    field.validations.iter().find_map(|v| match v {
+        Validation::OneOf(values) if !values.is_empty() => Some(values.as_slice()),
+        _ => None,
+    })
+}
+
+/// The field's example (see [`Field::example_json`]) as a seed value;
+/// `None` without an example, for bytes, or when it doesn't fit the type.
+fn from_example(field: &Field) -> Option<SeedValue> {
+    if !has_example(field) {
+        return None;
+    }
+    let example = serde_json::from_str(&field.example_json()).ok()?;
+    from_json(&field.data_type, &example)
+}
+
+/// A JSON value as a seed value of type `dt`, if it is one.
+fn from_json(dt: &DataType, value: &serde_json::Value) -> Option<SeedValue> {
+    use serde_json::Value;
+
+    match (base_type(dt), value) {
+        (DataType::Int32 | DataType::Int64, Value::Number(n)) => n.as_i64().map(SeedValue::Int),
+        (DataType::Float32 | DataType::Float64, Value::Number(n)) => {
+            Some(SeedValue::Number(n.to_string()))
+        }
+        // Decimals are quoted to keep every digit
+        (DataType::Decimal { .. }, Value::String(s)) => {
+            s.parse::<f64>().ok().map(|_| SeedValue::Number(s.clone()))
+        }
+        (DataType::Bool, Value::Bool(b)) => Some(SeedValue::Bool(*b)),
+        (DataType::Uuid, Value::String(s)) => s.parse().ok().map(SeedValue::Uuid),
+        (DataType::Json, value) => Some(SeedValue::Json(value.to_string())),
+        (DataType::Array(inner), Value::Array(items)) => items
+            .iter()
+            .map(|item| from_json(inner, item))
+            .collect::<Option<Vec<_>>>()
+            .map(SeedValue::Array),
+        (
+            DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal { .. }
+            | DataType::Bool
+            | DataType::Uuid
+            | DataType::Bytes
+            | DataType::Array(_),
+            _,
+        ) => None,
+        (_, Value::String(s)) => Some(SeedValue::Text(s.clone())),
+        _ => None,
+    }
+}
+
+/// Bounds for a numeric field: its `Min`/`Max` validations, else a range that
+/// suits its name, within what its type holds.
+fn int_range(field: &Field) -> (i64, i64) {
+    let name = GenerationContext::snake(&field.name);
+    let (default_lo, default_hi) = NUMERIC_RANGES
+        .iter()
+        .find(|(words, _)| words.iter().any(|w| name.contains(w)))
+        .map_or((1, 1000), |(_, range)| *range);
+
+    let min = field.validations.iter().find_map(|v| match v {
+        Validation::Min(n) => Some(n.ceil() as i64),
+        _ => None,
+    });
+    let max = field.validations.iter().find_map(|v| match v {
+        Validation::Max(n) => Some(n.floor() as i64),
+        _ => None,
+    });
+
+    let (lo, hi) = match (min, max) {
+        (Some(lo), Some(hi)) => (lo, hi.max(lo)),
+        (Some(lo), None) => (
+            lo,
+            if default_hi >= lo {
+                default_hi
+            } else {
+                lo + 1000
+            },
+        ),
+        (None, Some(hi)) => (
+            if default_lo <= hi {
+                default_lo
+            } else {
+                hi - 1000
+            },
+            hi,
+        ),
+        (None, None) => (default_lo, default_hi),
+    };
+
+    match base_type(&field.data_type) {
+        DataType::Int32 => {
+            let clamp = |n: i64| n.clamp(i64::from(i32::MIN), i64::from(i32::MAX));
+            (clamp(lo), clamp(hi))
+        }
+        _ => (lo, hi),
+    }
+}
+
+/// Pad or cut `value` to the field's length limits, keeping the `suffix`
+/// that makes unique values distinct.
+fn fit_length(mut value: String, field: &Field, dt: &DataType, suffix: Option<&str>) -> String {
+    let min = field.validations.iter().find_map(|v| match v {
+        Validation::MinLength(n) => Some(*n),
+        _ => None,
+    });
+    let mut max = field.validations.iter().find_map(|v| match v {
+        Validation::MaxLength(n) => Some(*n),
+        _ => None,
+    });
+    if matches!(dt, DataType::String) {
+        // VARCHAR(255)
+        max = Some(max.map_or(255, |m| m.min(255)));
+    }
+
+    if let Some(min) = min {
+        let mut words = WORDS.iter().cycle();
+        while value.chars().count() < min {
+            value.push(' ');
+            value.push_str(words.next().copied().unwrap_or("lorem"));
+        }
+    }
+
+    match max {
+        Some(max) if value.chars().count() > max => {
+            let suffix = suffix.unwrap_or("");
+            let keep = max.saturating_sub(suffix.chars().count());
+            let mut cut: String = value.chars().take(keep).collect();
+            cut.push_str(suffix);
+            cut.chars().take(max).collect()
+        }
+        _ => value,
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn base_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 1).unwrap_or_default()
+}
+
+/// Small deterministic PRNG (SplitMix64), seeded from a string.
+struct SeedRng(u64);
+
+impl SeedRng {
+    fn new(key: &str) -> Self {
+        // FNV-1a: stable across runs and platforms, unlike `DefaultHasher`
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        Self(hash)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n` (`0` when `n` is `0`).
+    fn below(&mut self, n: u64) -> u64 {
+        if n == 0 { 0 } else { self.next() % n }
+    }
+
+    /// Uniform in `lo..=hi`.
+    fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        let span = (i128::from(hi) - i128::from(lo) + 1) as u128;
+        (i128::from(lo) + (u128::from(self.next()) % span) as i128) as i64
+    }
+
+    /// Uniform in `0.0..1.0`.
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bruno", "Chloe", "Daniel", "Elena", "Farid", "Grace", "Hiro", "Ingrid", "Jamal",
+    "Keiko", "Liam", "Maya", "Nikolai", "Olivia", "Pedro", "Quinn", "Rosa", "Samir", "Tara",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Anderson", "Bianchi", "Chen", "Dubois", "Evans", "Fischer", "Garcia", "Haddad", "Ivanova",
+    "Johnson", "Kowalski", "Lopez", "Morales", "Nakamura", "Okafor", "Patel", "Rossi", "Smith",
+    "Tanaka", "Walker",
+];
+
+const CITIES: &[&str] = &[
+    "Amsterdam",
+    "Buenos Aires",
+    "Cape Town",
+    "Denver",
+    "Edinburgh",
+    "Kyoto",
+    "Lisbon",
+    "Melbourne",
+    "Nairobi",
+    "Oslo",
+    "Seoul",
+    "Toronto",
+];
+
+const COUNTRIES: &[&str] = &[
+    "Argentina",
+    "Australia",
+    "Canada",
+    "Germany",
+    "Japan",
+    "Kenya",
+    "Netherlands",
+    "Norway",
+    "Portugal",
+    "South Korea",
+];
+
+const COMPANIES: &[&str] = &[
+    "Acme Corp",
+    "Blue Harbor Labs",
+    "Cedar & Stone",
+    "Driftwood Systems",
+    "Evergreen Foods",
+    "Foxglove Media",
+    "Granite Logistics",
+    "Helix Health",
+    "Ironbark Tools",
+    "Juniper Finance",
+];
+
+const WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "voluptate",
+    "velit",
+    "esse",
+];
+
+/// Entity names whose records are people
+const PEOPLE: &[&str] = &[
+    "user", "person", "people", "customer", "author", "member", "employee", "contact", "profile",
+    "student", "teacher", "owner", "account", "patient", "staff",
+];
+
+/// Field names that hold long-form text
+const LONG_FORM: &[&str] = &[
+    "description",
+    "bio",
+    "summary",
+    "content",
+    "body",
+    "comment",
+    "notes",
+    "message",
+    "text",
+];
+
+/// Value ranges for numeric fields, by words in their name
+const NUMERIC_RANGES: &[(&[&str], (i64, i64))] = &[
+    (&["age"], (18, 80)),
+    (&["year"], (1990, 2026)),
+    (&["rating", "stars", "score"], (1, 5)),
+    (&["percent"], (0, 100)),
+    (&["quantity", "qty", "count", "stock"], (0, 500)),
+    (&["price", "amount", "cost", "total"], (1, 1000)),
+];
+
+// ============================================================================
+// SQL rendering
+// ============================================================================
+
+/// The seed file for one table.
+fn render_table(table: &SeedTable, ctx: &GenerationContext) -> String {
+    let db = ctx.database();
+    let qualified = table.def.qualified(db);
+
+    let mut out = String::with_capacity(256 + table.rows.len() * 128);
+    out.push_str(&format!(
+        "-- Seed data: `{}` ({} records)\n",
+        table.def.name,
+        table.rows.len()
+    ));
+    out.push_str(&format!("-- Entity: {}\n", table.info.pascal_name()));
+    out.push_str(&format!("-- Database: {}\n", database_display_name(db)));
+    if table.has_password {
+        out.push_str(&format!(
+            "-- Every account's password is \"{}\".\n",
+            SEED_PASSWORD
+        ));
+    }
+    out.push_str("-- Generated by Immortal Engine v2.0\n\n");
+
+    let columns = table
+        .columns
+        .iter()
+        .map(|c| quote_identifier(c, db))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    for chunk in table.rows.chunks(ROWS_PER_INSERT) {
+        out.push_str(&format!("INSERT INTO {} ({}) VALUES\n", qualified, columns));
+        let rows: Vec<String> = chunk
+            .iter()
+            .map(|row| {
+                let values: Vec<String> = row.iter().map(|v| render_value(v, db)).collect();
+                format!("    ({})", values.join(", "))
+            })
+            .collect();
+        out.push_str(&rows.join(",\n"));
+        out.push_str(";\n\n");
+    }
+
+    // Explicit keys don't advance PostgreSQL's sequence; move it past them
+    if let (Some(pk), DatabaseType::PostgreSQL) = (&table.serial_pk, db) {
+        out.push_str(&format!(
+            "SELECT setval(pg_get_serial_sequence({}, {}), (SELECT MAX({}) FROM {}));\n",
+            sql_string(&qualified, db),
+            sql_string(pk, db),
+            quote_identifier(pk, db),
+            qualified,
+        ));
+    }
+
+    out
+}
+
+/// A value as a SQL literal for `db`.
+fn render_value(value: &SeedValue, db: DatabaseType) -> String {
+    match value {
+        SeedValue::Null => "NULL".to_string(),
+        SeedValue::Int(n) => n.to_string(),
+        SeedValue::Number(n) => n.clone(),
+        SeedValue::Bool(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+        SeedValue::Text(s) | SeedValue::Json(s) => sql_string(s, db),
+        SeedValue::Uuid(u) => sql_string(&u.to_string(), db),
+        SeedValue::DateTime(_) | SeedValue::Date(_) | SeedValue::Time(_) => {
+            sql_string(&temporal_text(value, db), db)
+        }
+        SeedValue::Bytes(bytes) => match db {
+            DatabaseType::PostgreSQL => format!("decode('{}', 'hex')", hex(bytes)),
+            DatabaseType::MySQL | DatabaseType::SQLite => format!("X'{}'", hex(bytes)),
+        },
+        // PostgreSQL has native arrays; the others store them as JSON
+        SeedValue::Array(items) => match db {
+            DatabaseType::PostgreSQL => {
+                let elements: Vec<String> = items.iter().map(pg_array_element).collect();
+                sql_string(&format!("{{{}}}", elements.join(",")), db)
+            }
+            DatabaseType::MySQL | DatabaseType::SQLite => {
+                let json = serde_json::Value::Array(items.iter().map(json_value).collect());
+                sql_string(&json.to_string(), db)
+            }
+        },
+    }
+}
+
+/// A quoted string literal. MySQL also treats backslashes as escapes.
+fn sql_string(s: &str, db: DatabaseType) -> String {
+    let escaped = s.replace('\'', "''");
+    match db {
+        DatabaseType::MySQL => format!("'{}'", escaped.replace('\\', "\\\\")),
+        DatabaseType::PostgreSQL | DatabaseType::SQLite => format!("'{}'", escaped),
+    }
+}
+
+/// Dates and times as each database (and its Rust driver) reads them back.
+fn temporal_text(value: &SeedValue, db: DatabaseType) -> String {
+    match value {
+        SeedValue::DateTime(dt) => match db {
+            DatabaseType::PostgreSQL => format!("{}+00", dt.format("%Y-%m-%d %H:%M:%S")),
+            DatabaseType::MySQL => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            DatabaseType::SQLite => format!("{}+00:00", dt.format("%Y-%m-%dT%H:%M:%S")),
+        },
+        SeedValue::Date(d) => d.format("%Y-%m-%d").to_string(),
+        SeedValue::Time(t) => t.format("%H:%M:%S").to_string(),
+        _ => String::new(),
+    }
+}
+
+/// An element of a PostgreSQL array literal (`{…}`).
+fn pg_array_element(value: &SeedValue) -> String {
+    let text = match value {
+        SeedValue::Null => return "NULL".to_string(),
+        SeedValue::Int(n) => return n.to_string(),
+        SeedValue::Number(n) => return n.clone(),
+        SeedValue::Bool(b) => return b.to_string(),
+        SeedValue::Text(s) | SeedValue::Json(s) => s.clone(),
+        SeedValue::Uuid(u) => u.to_string(),
+        SeedValue::DateTime(_) | SeedValue::Date(_) | SeedValue::Time(_) => {
+            temporal_text(value, DatabaseType::PostgreSQL)
+        }
+        SeedValue::Bytes(bytes) => format!("\\x{}", hex(bytes)),
+        SeedValue::Array(items) => {
+            let elements: Vec<String> = items.iter().map(pg_array_element).collect();
+            return format!("{{{}}}", elements.join(","));
+        }
+    };
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A value inside a JSON-encoded array.
+fn json_value(value: &SeedValue) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        SeedValue::Null => Value::Null,
+        SeedValue::Int(n) => Value::from(*n),
+        SeedValue::Number(n) => n.parse::<f64>().map_or(Value::Null, Value::from),
+        SeedValue::Bool(b) => Value::Bool(*b),
+        SeedValue::Text(s) => Value::String(s.clone()),
+        SeedValue::Json(s) => serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.clone())),
+        SeedValue::Uuid(u) => Value::String(u.to_string()),
+        SeedValue::DateTime(_) | SeedValue::Date(_) | SeedValue::Time(_) => {
+            Value::String(temporal_text(value, DatabaseType::SQLite))
+        }
+        SeedValue::Bytes(bytes) => Value::Array(bytes.iter().map(|b| Value::from(*b)).collect()),
+        SeedValue::Array(items) => Value::Array(items.iter().map(json_value).collect()),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// ============================================================================
+// Seed binary
+// ============================================================================
+
+/// `src/bin/seed.rs`: applies the `.sql` files in a directory, in name order,
+/// inside one transaction.
+fn generate_seed_binary(ctx: &GenerationContext) -> String {
+    let crate_ident = ctx.package_name().replace('-', "_");
+    let mut out = file_header("Seed the database with the fake records in `seeds/`.");
+    out.push_str(&format!(
+        r#"//! ```bash
+//! cargo run --bin seed              # applies seeds/*.sql
+//! cargo run --bin seed -- fixtures  # or the .sql files in another directory
+//! ```
+//!
+//! Files run in name order inside one transaction, so a seed that fails
+//! leaves the database untouched. The records have fixed keys: seed a freshly
+//! migrated, empty database. Seeded accounts log in with the password
+//! `{password}`.
+
+use std::path::{{Path, PathBuf}};
+
+use anyhow::Context;
+
+use {crate_ident}::config::Config;
+
+/// Directory read when none is given
+const DEFAULT_SEEDS_DIR: &str = "seeds";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {{
+    dotenvy::dotenv().ok();
+
+    let dir = std::env::args()
+        .nth(1)
+        .map_or_else(|| PathBuf::from(DEFAULT_SEEDS_DIR), PathBuf::from);
+    let files = seed_files(&dir)?;
+    if files.is_empty() {{
+        anyhow::bail!("no .sql files in {{}}", dir.display());
+    }}
+
+    let config = Config::from_env();
+    apply(&config, &files).await?;
+
+    println!("Seeded {{}} file(s) from {{}}", files.len(), dir.display());
+    Ok(())
+}}
+
+/// The `.sql` files in `dir`, sorted by name.
+fn seed_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {{
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {{}}", dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {{
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "sql") {{
+            files.push(path);
+        }}
+    }}
+    files.sort();
+    Ok(files)
+}}
+
+{apply}"#,
+        password = SEED_PASSWORD,
+        crate_ident = crate_ident,
+        apply = ctx.orm_backend().seed_apply(),
+    ));
+    out
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorConfig;
+    use imortal_ir::{Entity, EntityConfig, ProjectGraph};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        let mut user = Entity::new("User");
+        user.add_field(
+            Field::new("email", DataType::String)
+                .required()
+                .unique()
+                .with_validation(Validation::Email),
+        );
+        user.add_field(
+            Field::new("name", DataType::String)
+                .required()
+                .with_validation(Validation::MaxLength(8)),
+        );
+        user.add_field(Field::new("password_hash", DataType::String).required());
+        user.add_field(
+            Field::new("age", DataType::Int32)
+                .with_validation(Validation::Min(21.0))
+                .with_validation(Validation::Max(30.0)),
+        );
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let mut post = Entity::with_timestamps("Post");
+        post.add_field(Field::new("title", DataType::String).required());
+        post.add_field(Field::new("body", DataType::Text));
+        post.add_field(
+            Field::new("status", DataType::String)
+                .with_validation(Validation::OneOf(vec!["draft".into(), "live".into()])),
+        );
+        let mut author = Field::foreign_key("user_id", "User", "id").required();
+        if let Some(fk) = &mut author.foreign_key_ref {
+            fk.entity_id = user_id;
+        }
+        post.add_field(author);
+        project.add_entity(post);
+
+        project
+    }
+
+    fn ctx(project: &ProjectGraph) -> GenerationContext {
+        GenerationContext::from_project(project, GeneratorConfig::new().with_seeds())
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == path)
+            .map(|f| f.content.as_str())
+            .unwrap_or_else(|| panic!("missing {}", path))
+    }
+
+    #[test]
+    fn test_seeds_disabled_by_default() {
+        let ctx = GenerationContext::from_project_default(&blog());
+        assert!(generate_seeds(&ctx).is_empty());
+        assert!(seed_warnings(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_seed_files_in_dependency_order() {
+        let project = blog();
+        let files = generate_seeds(&ctx(&project));
+        let paths: Vec<String> = files
+            .iter()
+            .map(|f| f.path.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "seeds/001_users.sql",
+                "seeds/002_posts.sql",
+                "src/bin/seed.rs"
+            ]
+        );
+
+        let users = file(&files, "seeds/001_users.sql");
+        assert!(users.contains("-- Seed data: `users` (10 records)"));
+        assert!(users.contains("INSERT INTO \"users\" (\"id\", \"email\", \"name\""));
+        assert_eq!(users.matches("@example.com'").count(), 10);
+        assert!(users.contains(SEED_PASSWORD_HASH));
+        assert!(users.contains("password is \"password\""));
+    }
+
+    #[test]
+    fn test_seeds_are_deterministic() {
+        let project = blog();
+        let first = generate_seeds(&ctx(&project));
+        let second = generate_seeds(&ctx(&project));
+        assert_eq!(
+            file(&first, "seeds/002_posts.sql"),
+            file(&second, "seeds/002_posts.sql")
+        );
+    }
+
+    #[test]
+    fn test_seed_values_follow_validations() {
+        let project = blog();
+        let ctx = ctx(&project);
+        let plan = plan_seeds(&ctx);
+        let users = &plan.tables[0];
+        let column = |name: &str| users.columns.iter().position(|c| c == name).unwrap();
+
+        let mut emails = std::collections::HashSet::new();
+        for row in &users.rows {
+            let SeedValue::Text(email) = &row[column("email")] else {
+                panic!("email should be text");
+            };
+            assert!(email.contains('@'));
+            assert!(emails.insert(email.clone()), "duplicate {}", email);
+
+            let SeedValue::Text(name) = &row[column("name")] else {
+                panic!("name should be text");
+            };
+            assert!(name.chars().count() <= 8);
+
+            match &row[column("age")] {
+                SeedValue::Int(age) => assert!((21..=30).contains(age)),
+                SeedValue::Null => {}
+                other => panic!("unexpected age {:?}", other),
+            }
+        }
+
+        let posts = &plan.tables[1];
+        let status = posts.columns.iter().position(|c| c == "status").unwrap();
+        for row in &posts.rows {
+            assert!(matches!(&row[status], SeedValue::Null | SeedValue::Text(_)));
+            if let SeedValue::Text(s) = &row[status] {
+                assert!(s == "draft" || s == "live");
+            }
+        }
+    }
+
+    #[test]
+    fn test_foreign_keys_reference_seeded_rows() {
+        let project = blog();
+        let ctx = ctx(&project);
+        let plan = plan_seeds(&ctx);
+        let user_ids: Vec<&SeedValue> = plan.tables[0].rows.iter().map(|r| &r[0]).collect();
+
+        let posts = &plan.tables[1];
+        let user_id = posts.columns.iter().position(|c| c == "user_id").unwrap();
+        for row in &posts.rows {
+            assert!(user_ids.contains(&&row[user_id]));
+        }
+    }
+
+    #[test]
+    fn test_seed_count_per_entity() {
+        let mut project = blog();
+        for entity in project.entities.values_mut() {
+            entity.config = match entity.name.as_str() {
+                "User" => EntityConfig::new().with_seed_count(3),
+                _ => EntityConfig::new().with_seed_count(150),
+            };
+        }
+        let ctx = ctx(&project);
+        let files = generate_seeds(&ctx);
+        assert!(file(&files, "seeds/001_users.sql").contains("(3 records)"));
+
+        // Large tables are split into several INSERTs
+        let posts = file(&files, "seeds/002_posts.sql");
+        assert!(posts.contains("(150 records)"));
+        assert_eq!(posts.matches("INSERT INTO").count(), 2);
+    }
+
+    #[test]
+    fn test_entity_referencing_unseeded_table_is_skipped() {
+        let mut project = blog();
+        for entity in project.entities.values_mut() {
+            if entity.name == "User" {
+                entity.config = EntityConfig::new().with_seed_count(0);
+            }
+        }
+        let ctx = ctx(&project);
+
+        let files = generate_seeds(&ctx);
+        assert_eq!(files.len(), 1, "only the seed binary");
+
+        let warnings = seed_warnings(&ctx);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("No seed data for 'Post'"));
+    }
+
+    #[test]
+    fn test_unique_columns_cap_row_count() {
+        let mut project = ProjectGraph::new("flags");
+        let mut flag = Entity::new("Flag");
+        flag.add_field(Field::new("enabled", DataType::Bool).required().unique());
+        project.add_entity(flag);
+
+        let ctx = ctx(&project);
+        assert_eq!(plan_seeds(&ctx).tables[0].rows.len(), 2);
+    }
+
+    #[test]
+    fn test_serial_keys_reset_postgres_sequence() {
+        let mut project = ProjectGraph::new("shop");
+        let mut product = Entity::new("Product");
+        product.config = EntityConfig::new()
+            .with_id_type(IdType::Serial)
+            .with_seed_count(2);
+        product.add_field(Field::new("price", DataType::decimal(6, 2)).required());
+        project.add_entity(product);
+
+        let files = generate_seeds(&ctx(&project));
+        let sql = file(&files, "seeds/001_products.sql");
+        assert!(sql.contains("    (1, "));
+        assert!(sql.contains("    (2, "));
+        assert!(sql.contains(
+            "SELECT setval(pg_get_serial_sequence('\"products\"', 'id'), (SELECT MAX(\"id\") FROM \"products\"));"
+        ));
+    }
+
+    #[test]
+    fn test_render_values_per_database() {
+        let datetime = base_date().and_hms_opt(8, 30, 0).unwrap();
+        let cases = [
+            (
+                SeedValue::Text("O'Hara".into()),
+                DatabaseType::PostgreSQL,
+                "'O''Hara'",
+            ),
+            (
+                SeedValue::Text("a\\b".into()),
+                DatabaseType::MySQL,
+                "'a\\\\b'",
+            ),
+            (SeedValue::Bool(true), DatabaseType::SQLite, "TRUE"),
+            (
+                SeedValue::DateTime(datetime),
+                DatabaseType::PostgreSQL,
+                "'2025-01-01 08:30:00+00'",
+            ),
+            (
+                SeedValue::DateTime(datetime),
+                DatabaseType::SQLite,
+                "'2025-01-01T08:30:00+00:00'",
+            ),
+            (
+                SeedValue::Bytes(vec![1, 255]),
+                DatabaseType::PostgreSQL,
+                "decode('01ff', 'hex')",
+            ),
+            (
+                SeedValue::Bytes(vec![1, 255]),
+                DatabaseType::MySQL,
+                "X'01ff'",
+            ),
+            (
+                SeedValue::Array(vec![SeedValue::Text("a\"b".into()), SeedValue::Int(2)]),
+                DatabaseType::PostgreSQL,
+                "'{\"a\\\"b\",2}'",
+            ),
+            (
+                SeedValue::Array(vec![SeedValue::Text("x".into()), SeedValue::Int(2)]),
+                DatabaseType::SQLite,
+                "'[\"x\",2]'",
+            ),
+        ];
+        for (value, db, expected) in cases {
+            assert_eq!(
+                render_value(&value, db),
+                expected,
+                "{:?} on {:?}",
+                value,
+                db
+            );
+        }
+    }
+
+    #[test]
+    fn test_key_values() {
+        assert_eq!(key_value(IdType::Serial, "users", 4), SeedValue::Int(5));
+        assert_eq!(
+            key_value(IdType::Uuid, "users", 0),
+            key_value(IdType::Uuid, "users", 0)
+        );
+        assert_ne!(
+            key_value(IdType::Uuid, "users", 0),
+            key_value(IdType::Uuid, "users", 1)
+        );
+
+        let SeedValue::Text(cuid) = key_value(IdType::Cuid, "users", 0) else {
+            panic!("cuid should be text");
+        };
+        assert_eq!(cuid.len(), 25);
+        assert!(cuid.starts_with('c'));
+
+        let ulid = |i| match key_value(IdType::Ulid, "users", i) {
+            SeedValue::Text(s) => s,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(ulid(0).len(), 26);
+        assert!(ulid(0) < ulid(1), "ULIDs sort in row order");
+    }
+
+    #[test]
+    fn test_values_from_examples() {
+        let example =
+            |dt: DataType, raw: &str| from_example(&Field::new("f", dt).with_example(raw));
+        assert_eq!(example(DataType::Int32, " 42 "), Some(SeedValue::Int(42)));
+        assert_eq!(example(DataType::Int32, "many"), None);
+        assert_eq!(
+            example(
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2
+                },
+                "9.99"
+            ),
+            Some(SeedValue::Number("9.99".into()))
+        );
+        assert_eq!(example(DataType::Bool, "true"), Some(SeedValue::Bool(true)));
+        assert_eq!(
+            example(DataType::Json, r#"{"a":1}"#),
+            Some(SeedValue::Json(r#"{"a":1}"#.into()))
+        );
+        assert_eq!(
+            example(DataType::Array(Box::new(DataType::Int32)), "[1, 2]"),
+            Some(SeedValue::Array(vec![SeedValue::Int(1), SeedValue::Int(2)]))
+        );
+        assert_eq!(
+            example(DataType::String, "Ada"),
+            Some(SeedValue::Text("Ada".into()))
+        );
+        assert_eq!(example(DataType::Bytes, "abc"), None);
+        assert_eq!(from_example(&Field::new("f", DataType::String)), None);
+    }
+
+    #[test]
+    fn test_pattern_fields_warn() {
+        let mut project = ProjectGraph::new("codes");
+        let mut coupon = Entity::new("Coupon");
+        coupon.add_field(Field::new("code", DataType::String).with_validation(
+            Validation::Pattern {
+                regex: "^[A-Z]{8}$".into(),
+                message: "eight capitals".into(),
+            },
+        ));
+        project.add_entity(coupon);
+
+        let warnings = seed_warnings(&ctx(&project));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("'Coupon.code'"));
+    }
+
+    #[test]
+    fn test_seed_binary() {
+        let mut project = blog();
+        project.config.package_name = "blog-api".to_string();
+        let files = generate_seeds(&ctx(&project));
+        let bin = file(&files, "src/bin/seed.rs");
+        assert!(bin.contains("use blog_api::config::Config;"));
+        assert!(bin.contains("const DEFAULT_SEEDS_DIR: &str = \"seeds\";"));
+        assert!(bin.contains("txn.execute_unprepared(&sql)"));
+        assert!(bin.contains("txn.commit().await?;"));
+
+        let diesel = GenerationContext::from_project(
+            &project,
+            GeneratorConfig::new()
+                .with_seeds()
+                .with_orm(imortal_ir::Orm::Diesel),
+        );
+        let files = generate_seeds(&diesel);
+        let bin = file(&files, "src/bin/seed.rs");
+        assert!(bin.contains("conn.transaction(|conn| {"));
+        assert!(bin.contains("conn.batch_execute(&sql)"));
+    }
+}
@@ -0,0 +1,499 @@
+//! # File Templates
+//!
+//! Some generated files are rendered from [minijinja] templates rather than
+//! built in code, so a project can change them without forking the
+//! generator. Each template is embedded in the crate and can be replaced by a
+//! file of the same relative path in a templates directory:
+//!
+//! ```text
+//! my_api.ieng
+//! templates/
+//!   deploy/Dockerfile.jinja        ← replaces the built-in Dockerfile
+//! ```
+//!
+//! The directory is the `templates_dir` option of the
+//! [`GeneratorConfig`](crate::GeneratorConfig) (see
+//! [`GeneratorConfig::with_templates_dir`](crate::GeneratorConfig::with_templates_dir)),
+//! or else a `templates/` directory next to the project file the project was
+//! last saved to. Templates it does not override keep their built-in version.
+//!
+//! ## Templates
+//!
+//! | Template                              | Output                        | Variables              |
+//! |---------------------------------------|-------------------------------|------------------------|
+//! | `deploy/Dockerfile.jinja`             | `Dockerfile`                  | [`DeployContext`]      |
+//! | `deploy/docker-compose.yml.jinja`     | `docker-compose.yml`          | [`DeployContext`]      |
+//! | `deploy/dockerignore.jinja`           | `.dockerignore`               | [`DeployContext`]      |
+//! | `scaffold/env.example.jinja`          | `.env.example`                | [`ScaffoldContext`]    |
+//! | `scaffold/gitignore.jinja`            | `.gitignore`                  | [`ScaffoldContext`]    |
+//! | `rust/model.rs.jinja`                 | `src/models/{entity}.rs`      | [`ModelContext`]       |
+//! | `rust/handlers.rs.jinja`              | `src/handlers/{entity}.rs`    | [`HandlersContext`]    |
+//! | `rust/routes.rs.jinja`                | `src/routes/mod.rs`           | [`RoutesContext`]      |
+//! | `rust/api.rs.jinja`                   | `src/routes/api.rs`           | [`ApiRoutesContext`]   |
+//! | `migrations/migration.sql.jinja`      | `migrations/{n}_{table}.sql`  | [`MigrationContext`]   |
+//! | `migrations/create_table.sql.jinja`   | its `CREATE TABLE` statements | [`CreateTableContext`] |
+//!
+//! The built-in sources are the best starting point for an override; copy
+//! them with [`builtin_source`]. The fields of the context structs are the
+//! variables a template can use.
+//!
+//! The Rust templates lay out the SeaORM models and handlers and the Axum
+//! router; Diesel and Actix Web projects build those files in code. Their
+//! contexts describe the file's structure (columns, relations, route
+//! groups, …) and pass the bodies of functions and DTOs as rendered Rust, so
+//! an override can reorder, drop or add to them. `create_table.sql.jinja`
+//! also renders the tables of Diesel's initial migration.
+//!
+//! ## Syntax
+//!
+//! Templates use Jinja2 syntax with `trim_blocks` and `lstrip_blocks` on: a
+//! line holding only a block tag (`{% if %}`, `{% for %}`, …) leaves nothing
+//! in the output. Undefined variables are errors, and nothing is escaped.
+//!
+//! An override that does not parse fails generation before anything is
+//! generated, and one that fails to render fails it afterwards, with
+//! [`EngineError::TemplateRender`] naming the file. Files in the templates
+//! directory that override no template are reported as warnings. [`check`]
+//! lists every override that does not parse, for tools that watch the
+//! directory.
+//!
+//! [`DeployContext`]: crate::deploy::DeployContext
+//! [`ScaffoldContext`]: crate::rust::ScaffoldContext
+//! [`ModelContext`]: crate::rust::models::ModelContext
+//! [`HandlersContext`]: crate::rust::handlers::HandlersContext
+//! [`RoutesContext`]: crate::rust::routes::RoutesContext
+//! [`ApiRoutesContext`]: crate::rust::routes::ApiRoutesContext
+//! [`MigrationContext`]: crate::migrations::sql::MigrationContext
+//! [`CreateTableContext`]: crate::migrations::sql::CreateTableContext
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use imortal_core::{EngineError, EngineResult};
+use minijinja::{AutoEscape, Environment, UndefinedBehavior};
+use serde::Serialize;
+
+/// Generator option naming the templates directory
+pub const TEMPLATES_DIR_OPTION: &str = "templates_dir";
+
+/// Name of the templates directory looked up next to a project file
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// Extension of template files
+const TEMPLATE_EXTENSION: &str = "jinja";
+
+/// Built-in templates: name and source
+const BUILTIN: &[(&str, &str)] = &[
+    (
+        "deploy/Dockerfile.jinja",
+        include_str!("../templates/deploy/Dockerfile.jinja"),
+    ),
+    (
+        "deploy/docker-compose.yml.jinja",
+        include_str!("../templates/deploy/docker-compose.yml.jinja"),
+    ),
+    (
+        "deploy/dockerignore.jinja",
+        include_str!("../templates/deploy/dockerignore.jinja"),
+    ),
+    (
+        "scaffold/env.example.jinja",
+        include_str!("../templates/scaffold/env.example.jinja"),
+    ),
+    (
+        "scaffold/gitignore.jinja",
+        include_str!("../templates/scaffold/gitignore.jinja"),
+    ),
+    (
+        "rust/model.rs.jinja",
+        include_str!("../templates/rust/model.rs.jinja"),
+    ),
+    (
+        "rust/handlers.rs.jinja",
+        include_str!("../templates/rust/handlers.rs.jinja"),
+    ),
+    (
+        "rust/routes.rs.jinja",
+        include_str!("../templates/rust/routes.rs.jinja"),
+    ),
+    (
+        "rust/api.rs.jinja",
+        include_str!("../templates/rust/api.rs.jinja"),
+    ),
+    (
+        "migrations/migration.sql.jinja",
+        include_str!("../templates/migrations/migration.sql.jinja"),
+    ),
+    (
+        "migrations/create_table.sql.jinja",
+        include_str!("../templates/migrations/create_table.sql.jinja"),
+    ),
+];
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Names of the built-in templates
+pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+    BUILTIN.iter().map(|(name, _)| *name)
+}
+
+/// Source of the built-in template `name`
+pub fn builtin_source(name: &str) -> Option<&'static str> {
+    BUILTIN
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, source)| *source)
+}
+
+/// The templates directory looked up for a project saved at `project_file`.
+pub fn project_templates_dir(project_file: &Path) -> PathBuf {
+    project_file
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(TEMPLATES_DIR)
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+/// The templates of one generation: the built-in ones, with any overrides
+/// from a templates directory.
+pub struct Templates {
+    env: Environment<'static>,
+    /// Directory the overrides came from
+    dir: Option<PathBuf>,
+    /// Names of the overridden templates, sorted
+    overridden: Vec<String>,
+    /// Files in the directory that override no template, relative to it
+    unused: Vec<PathBuf>,
+    /// Render errors, reported once generation finishes
+    failures: Mutex<Vec<EngineError>>,
+}
+
+impl std::fmt::Debug for Templates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Templates")
+            .field("dir", &self.dir)
+            .field("overridden", &self.overridden)
+            .field("unused", &self.unused)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Templates {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl Templates {
+    /// The built-in templates only.
+    pub fn builtin() -> Self {
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        env.set_trim_blocks(true);
+        env.set_lstrip_blocks(true);
+        env.set_keep_trailing_newline(true);
+        env.set_auto_escape_callback(|_| AutoEscape::None);
+        for (name, source) in BUILTIN {
+            env.add_template(name, source)
+                .expect("built-in templates parse");
+        }
+
+        Self {
+            env,
+            dir: None,
+            overridden: Vec::new(),
+            unused: Vec::new(),
+            failures: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The built-in templates, overridden by the `.jinja` files in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Fails when `dir` is not a directory, or an override cannot be read or
+    /// does not parse.
+    pub fn load(dir: &Path) -> EngineResult<Self> {
+        let mut templates = Self::builtin();
+        for (name, path) in template_files(dir)? {
+            if builtin_source(&name).is_none() {
+                let relative = path.strip_prefix(dir).unwrap_or(&path);
+                templates.unused.push(relative.to_path_buf());
+                continue;
+            }
+
+            let source = read_template(&path)?;
+            templates
+                .env
+                .add_template_owned(name.clone(), source)
+                .map_err(|e| render_error(&path, &e))?;
+            templates.overridden.push(name);
+        }
+
+        templates.dir = Some(dir.to_path_buf());
+        Ok(templates)
+    }
+
+    /// Directory the overrides were loaded from
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Names of the overridden templates, sorted
+    pub fn overridden(&self) -> &[String] {
+        &self.overridden
+    }
+
+    /// Whether the template `name` is overridden
+    pub fn is_overridden(&self, name: &str) -> bool {
+        self.overridden.iter().any(|o| o == name)
+    }
+
+    /// `.jinja` files of the templates directory that override no template,
+    /// relative to it
+    pub fn unused(&self) -> &[PathBuf] {
+        &self.unused
+    }
+
+    /// Render the template `name` with `context`.
+    ///
+    /// Generators cannot fail, so a render error yields an empty string and
+    /// is kept for [`take_failures`](Self::take_failures).
+    pub fn render<S: Serialize>(&self, name: &str, context: S) -> String {
+        let rendered = self
+            .env
+            .get_template(name)
+            .and_then(|template| template.render(context));
+        match rendered {
+            Ok(content) => content,
+            Err(e) => {
+                let path = match &self.dir {
+                    Some(dir) if self.is_overridden(name) => dir.join(name),
+                    _ => PathBuf::from(name),
+                };
+                self.failures
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .push(render_error(&path, &e));
+                String::new()
+            }
+        }
+    }
+
+    /// Render errors since the last call
+    pub fn take_failures(&self) -> Vec<EngineError> {
+        std::mem::take(
+            &mut *self
+                .failures
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+}
+
+/// Every error [`Templates::load`] could stop at, for all overrides in `dir`
+/// rather than just the first: files that cannot be read or do not parse.
+///
+/// Meant for editors showing the state of a templates directory while it is
+/// being worked on; empty when the directory loads.
+pub fn check(dir: &Path) -> Vec<EngineError> {
+    let files = match template_files(dir) {
+        Ok(files) => files,
+        Err(e) => return vec![e],
+    };
+
+    let mut env = Environment::new();
+    files
+        .into_iter()
+        .filter(|(name, _)| builtin_source(name).is_some())
+        .filter_map(|(name, path)| {
+            let source = match read_template(&path) {
+                Ok(source) => source,
+                Err(e) => return Some(e),
+            };
+            env.add_template_owned(name, source)
+                .err()
+                .map(|e| render_error(&path, &e))
+        })
+        .collect()
+}
+
+/// The `.jinja` files under `dir`, sorted, each with the template name it
+/// would override (its relative path with `/` separators).
+fn template_files(dir: &Path) -> EngineResult<Vec<(String, PathBuf)>> {
+    if !dir.is_dir() {
+        return Err(EngineError::InvalidConfig(format!(
+            "templates directory '{}' does not exist",
+            dir.display()
+        )));
+    }
+
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry.map_err(|e| EngineError::FileRead {
+            path: dir.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        let path = entry.path();
+        if !entry.file_type().is_file()
+            || path.extension().is_none_or(|ext| ext != TEMPLATE_EXTENSION)
+        {
+            continue;
+        }
+        let name = path
+            .strip_prefix(dir)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push((name, path.to_path_buf()));
+    }
+    Ok(files)
+}
+
+fn read_template(path: &Path) -> EngineResult<String> {
+    std::fs::read_to_string(path).map_err(|e| EngineError::FileRead {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// A minijinja error as an [`EngineError`], with its line if known.
+fn render_error(path: &Path, error: &minijinja::Error) -> EngineError {
+    let mut message = error.kind().to_string();
+    if let Some(detail) = error.detail() {
+        message.push_str(": ");
+        message.push_str(detail);
+    }
+    if let Some(line) = error.line() {
+        message.push_str(&format!(" (line {})", line));
+    }
+    EngineError::TemplateRender {
+        template: path.display().to_string(),
+        message,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minijinja::context;
+
+    fn write(dir: &Path, name: &str, source: &str) {
+        let path = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+
+    #[test]
+    fn test_builtin_templates_are_listed() {
+        let templates = Templates::builtin();
+        for name in builtin_names() {
+            assert!(templates.env.get_template(name).is_ok(), "{name}");
+            assert!(name.ends_with(".jinja"));
+        }
+        assert!(builtin_source("deploy/Dockerfile.jinja").is_some());
+        assert!(builtin_source("Dockerfile.jinja").is_none());
+        assert!(templates.overridden().is_empty());
+    }
+
+    #[test]
+    fn test_project_templates_dir() {
+        assert_eq!(
+            project_templates_dir(Path::new("/work/api/my_api.ieng")),
+            PathBuf::from("/work/api/templates")
+        );
+        assert_eq!(
+            project_templates_dir(Path::new("my_api.ieng")),
+            PathBuf::from("templates")
+        );
+    }
+
+    #[test]
+    fn test_overrides_replace_builtins() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "scaffold/gitignore.jinja",
+            "{% if fullstack %}\n/dist/\n{% endif %}\n/target/\n",
+        );
+        write(dir.path(), "scaffold/gitignor.jinja", "typo\n");
+        write(dir.path(), "README.md", "not a template\n");
+
+        let templates = Templates::load(dir.path()).unwrap();
+        assert_eq!(templates.overridden(), ["scaffold/gitignore.jinja"]);
+        assert!(templates.is_overridden("scaffold/gitignore.jinja"));
+        assert_eq!(
+            templates.unused(),
+            [PathBuf::from("scaffold/gitignor.jinja")]
+        );
+        assert_eq!(
+            templates.render("scaffold/gitignore.jinja", context! { fullstack => true }),
+            "/dist/\n/target/\n"
+        );
+        assert!(templates.take_failures().is_empty());
+    }
+
+    #[test]
+    fn test_syntax_errors_fail_loading() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "deploy/Dockerfile.jinja", "FROM {{ image\n");
+
+        let error = Templates::load(dir.path()).unwrap_err().to_string();
+        assert!(error.contains("Dockerfile.jinja"), "{error}");
+        assert!(error.contains("line 1"), "{error}");
+    }
+
+    #[test]
+    fn test_check_lists_every_syntax_error() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "deploy/Dockerfile.jinja", "FROM {{ image\n");
+        write(dir.path(), "deploy/dockerignore.jinja", "target\n");
+        write(dir.path(), "scaffold/gitignore.jinja", "{% if x %}\n");
+        write(dir.path(), "scaffold/notes.jinja", "{{ ignored\n");
+
+        let errors: Vec<String> = check(dir.path()).iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].contains("Dockerfile.jinja"), "{errors:?}");
+        assert!(errors[1].contains("gitignore.jinja"), "{errors:?}");
+
+        write(dir.path(), "deploy/Dockerfile.jinja", "FROM {{ image }}\n");
+        write(dir.path(), "scaffold/gitignore.jinja", "target\n");
+        assert!(check(dir.path()).is_empty());
+        assert_eq!(check(&dir.path().join("missing")).len(), 1);
+    }
+
+    #[test]
+    fn test_render_errors_are_collected() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "deploy/Dockerfile.jinja", "FROM {{ imgae }}\n");
+
+        let templates = Templates::load(dir.path()).unwrap();
+        assert_eq!(
+            templates.render("deploy/Dockerfile.jinja", context! { image => "x" }),
+            ""
+        );
+        let failures = templates.take_failures();
+        assert_eq!(failures.len(), 1);
+        let message = failures[0].to_string();
+        assert!(message.contains("deploy/Dockerfile.jinja"), "{message}");
+        assert!(message.contains("undefined"), "{message}");
+        assert!(templates.take_failures().is_empty());
+    }
+
+    #[test]
+    fn test_missing_dir_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Templates::load(&dir.path().join("nope")).is_err());
+    }
+}
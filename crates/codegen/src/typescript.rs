@@ -0,0 +1,793 @@
+//! # TypeScript Client Generator
+//!
+//! Generates TypeScript types and a `fetch`-based client for teams calling
+//! the generated REST API from a JavaScript frontend.
+//!
+//! ## Generated Files
+//!
+//! - `frontend/ts-client/types.ts` — a string union per enum and an interface
+//!   per create, update and response DTO, plus the list envelope and
+//!   `ProblemDetails`, matching the JSON the backend reads and writes
+//! - `frontend/ts-client/client.ts` — `HttpClient` (base URL, bearer token,
+//!   `ApiError` on failures), one class per endpoint group with a method per
+//!   enabled operation, and an `ApiClient` holding them all
+//! - `frontend/ts-client/index.ts` — re-exports
+//!
+//! ## Usage
+//!
+//! The files are only generated when `ctx.typescript_client()` returns
+//! `true` — set `typescript_client` in the project config.
+//!
+//! ```ts
+//! import { ApiClient } from './ts-client';
+//!
+//! const api = new ApiClient({ baseUrl: 'http://127.0.0.1:8080', token });
+//! const user = await api.users.get(id);
+//! ```
+
+use imortal_core::DataType;
+use imortal_ir::{Field, OperationType, PaginationStyle};
+
+use crate::context::{EntityInfo, GenerationContext};
+use crate::loadtest::{client_host, js_string};
+use crate::rust::models::{dto_field_name, soft_delete_column};
+use crate::{FileType, GeneratedFile};
+
+/// Directory the TypeScript client is written to
+pub const TS_CLIENT_DIR: &str = "frontend/ts-client";
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Generate `frontend/ts-client/`.
+///
+/// Returns an empty `Vec` if the project doesn't ask for a TypeScript client
+/// or no entity has enabled REST endpoints.
+pub fn generate_typescript_client(ctx: &GenerationContext) -> Vec<GeneratedFile> {
+    if !ctx.typescript_client() {
+        return Vec::new();
+    }
+
+    let groups = groups(ctx);
+    if groups.is_empty() {
+        return Vec::new();
+    }
+
+    vec![
+        GeneratedFile::new(
+            format!("{TS_CLIENT_DIR}/types.ts"),
+            render_types(&groups, ctx),
+            FileType::Other,
+        ),
+        GeneratedFile::new(
+            format!("{TS_CLIENT_DIR}/client.ts"),
+            render_client(&groups, ctx),
+            FileType::Other,
+        ),
+        GeneratedFile::new(
+            format!("{TS_CLIENT_DIR}/index.ts"),
+            format!(
+                "{}export * from './types';\nexport * from './client';\n",
+                header(ctx)
+            ),
+            FileType::Other,
+        ),
+    ]
+}
+
+/// Whether `frontend/ts-client/` is generated.
+pub fn generates_ts_client(ctx: &GenerationContext) -> bool {
+    ctx.typescript_client() && !groups(ctx).is_empty()
+}
+
+// ============================================================================
+// Endpoint groups
+// ============================================================================
+
+fn groups(ctx: &GenerationContext) -> Vec<Group<'_>> {
+    ctx.entities()
+        .iter()
+        .filter_map(|entity| Group::new(EntityInfo::new(entity, ctx)))
+        .collect()
+}
+
+/// An entity with enabled endpoints, as the client exposes it.
+struct Group<'a> {
+    info: EntityInfo<'a>,
+    operations: Vec<OperationType>,
+}
+
+impl<'a> Group<'a> {
+    fn new(info: EntityInfo<'a>) -> Option<Self> {
+        let endpoint = info.endpoint().filter(|ep| ep.enabled)?;
+        let operations: Vec<OperationType> = endpoint
+            .enabled_operations()
+            .iter()
+            .map(|op| op.operation_type)
+            .collect();
+        if operations.is_empty() {
+            return None;
+        }
+        Some(Self { info, operations })
+    }
+
+    fn has(&self, operation: OperationType) -> bool {
+        self.operations.contains(&operation)
+    }
+
+    /// Class wrapping the group's operations (`UserApi`)
+    fn class_name(&self) -> String {
+        format!("{}Api", self.info.pascal_name())
+    }
+
+    /// `ApiClient` property holding the group (`users`)
+    fn property(&self) -> String {
+        GenerationContext::camel(&self.info.plural_name())
+    }
+}
+
+// ============================================================================
+// types.ts
+// ============================================================================
+
+fn render_types(groups: &[Group], ctx: &GenerationContext) -> String {
+    let mut out = header(ctx);
+
+    for def in ctx.enums() {
+        let variants: Vec<String> = def.variants.iter().map(|v| js_string(v)).collect();
+        out.push_str(&format!(
+            "export type {} = {};\n\n",
+            GenerationContext::pascal(&def.name),
+            if variants.is_empty() {
+                "never".to_string()
+            } else {
+                variants.join(" | ")
+            }
+        ));
+    }
+
+    for group in groups {
+        let info = &group.info;
+        let name = &info.entity.name;
+        let pascal = info.pascal_name();
+
+        if group.has(OperationType::Create) {
+            out.push_str(&format!("/** Payload for creating a new {pascal}. */\n"));
+            out.push_str(&format!(
+                "export interface {} {{\n",
+                GenerationContext::create_dto_name(name)
+            ));
+            for field in info.create_fields() {
+                out.push_str(&field_doc(field));
+                out.push_str(&format!(
+                    "  {}{}: {};\n",
+                    dto_field_name(field, ctx),
+                    if field.required { "" } else { "?" },
+                    ts_type(&field.data_type, field.required),
+                ));
+            }
+            out.push_str("}\n\n");
+        }
+
+        if group.has(OperationType::Update) {
+            out.push_str(&format!(
+                "/** Payload for updating an existing {pascal}. Absent fields are left unchanged. */\n"
+            ));
+            out.push_str(&format!(
+                "export interface {} {{\n",
+                GenerationContext::update_dto_name(name)
+            ));
+            for field in info.update_fields() {
+                out.push_str(&field_doc(field));
+                out.push_str(&format!(
+                    "  {}?: {};\n",
+                    GenerationContext::snake(&field.name),
+                    ts_type(&field.data_type, true),
+                ));
+            }
+            out.push_str("}\n\n");
+        }
+
+        if group
+            .operations
+            .iter()
+            .any(|op| *op != OperationType::Delete)
+        {
+            let fields = info.response_fields();
+            out.push_str(&format!("/** Response representation of a {pascal}. */\n"));
+            out.push_str(&format!(
+                "export interface {} {{\n",
+                GenerationContext::response_dto_name(name)
+            ));
+            for field in &fields {
+                out.push_str(&field_doc(field));
+                out.push_str(&format!(
+                    "  {}: {};\n",
+                    GenerationContext::snake(&field.name),
+                    ts_type(&field.data_type, field.required || field.is_primary_key),
+                ));
+            }
+            if info.has_timestamps() {
+                for column in ["created_at", "updated_at"] {
+                    if !fields.iter().any(|f| f.name == column) {
+                        out.push_str(&format!("  {column}: string;\n"));
+                    }
+                }
+            }
+            if soft_delete_column(info) {
+                out.push_str("  deleted_at: string | null;\n");
+            }
+            out.push_str("}\n\n");
+        }
+    }
+
+    match ctx.pagination() {
+        PaginationStyle::Offset => out.push_str(
+            "\
+/** A page of a list endpoint. */
+export interface PaginatedResponse<T> {
+  items: T[];
+  total: number;
+  page: number;
+  per_page: number;
+  total_pages: number;
+}
+
+",
+        ),
+        PaginationStyle::Cursor => out.push_str(
+            "\
+/** A cursor page of a list endpoint; pass `next_cursor` for the next one. */
+export interface CursorPage<T> {
+  items: T[];
+  next_cursor: string | null;
+  has_more: boolean;
+}
+
+",
+        ),
+        PaginationStyle::None => {}
+    }
+
+    out.push_str(
+        "\
+/** RFC 7807 problem details, the body of every error response. */
+export interface ProblemDetails {
+  type: string;
+  title: string;
+  status: number;
+  detail: string;
+  /** Machine-readable error code, e.g. `not_found`. */
+  code: string;
+  /** Extension members, e.g. `fields` on validation errors. */
+  [extension: string]: unknown;
+}
+",
+    );
+
+    out
+}
+
+/// `/** description */` above a field that has one.
+fn field_doc(field: &Field) -> String {
+    match field.description.as_deref().map(str::trim) {
+        Some(description) if !description.is_empty() => {
+            format!("  /** {} */\n", description.replace("*/", "*\\/"))
+        }
+        _ => String::new(),
+    }
+}
+
+/// TypeScript type of a value as the backend serializes it. Not-required
+/// values are `null` when unset.
+fn ts_type(data_type: &DataType, required: bool) -> String {
+    let base = match data_type {
+        DataType::String | DataType::Text => "string".to_string(),
+        DataType::Int32 | DataType::Int64 | DataType::Float32 | DataType::Float64 => {
+            "number".to_string()
+        }
+        // rust_decimal serializes as a string so amounts keep their precision
+        DataType::Decimal { .. } => "string".to_string(),
+        DataType::Bool => "boolean".to_string(),
+        DataType::Uuid | DataType::Reference { .. } => "string".to_string(),
+        DataType::DateTime | DataType::Date | DataType::Time => "string".to_string(),
+        DataType::Bytes => "number[]".to_string(),
+        DataType::Json => "unknown".to_string(),
+        DataType::Optional(inner) => format!("{} | null", ts_type(inner, true)),
+        DataType::Array(inner) => match ts_type(inner, true) {
+            inner if inner.contains(' ') => format!("({inner})[]"),
+            inner => format!("{inner}[]"),
+        },
+        DataType::Enum { name, .. } => GenerationContext::pascal(name),
+    };
+
+    if required || base.ends_with(" | null") {
+        base
+    } else {
+        format!("{base} | null")
+    }
+}
+
+// ============================================================================
+// client.ts
+// ============================================================================
+
+fn render_client(groups: &[Group], ctx: &GenerationContext) -> String {
+    let mut out = header(ctx);
+
+    // Imports
+    let mut types = vec!["ProblemDetails".to_string()];
+    match ctx.pagination() {
+        PaginationStyle::Offset => types.push("PaginatedResponse".to_string()),
+        PaginationStyle::Cursor => types.push("CursorPage".to_string()),
+        PaginationStyle::None => {}
+    }
+    for group in groups {
+        let name = &group.info.entity.name;
+        if group.has(OperationType::Create) {
+            types.push(GenerationContext::create_dto_name(name));
+        }
+        if group.has(OperationType::Update) {
+            types.push(GenerationContext::update_dto_name(name));
+        }
+        if group
+            .operations
+            .iter()
+            .any(|op| *op != OperationType::Delete)
+        {
+            types.push(GenerationContext::response_dto_name(name));
+        }
+    }
+    out.push_str(&format!(
+        "import type {{\n{}}} from './types';\n\n",
+        types
+            .iter()
+            .map(|t| format!("  {t},\n"))
+            .collect::<String>()
+    ));
+
+    out.push_str(&format!(
+        "\
+/** Thrown for every non-2xx response, with the problem details body. */
+export class ApiError extends Error {{
+  constructor(
+    readonly status: number,
+    readonly problem: ProblemDetails,
+  ) {{
+    super(problem.detail || problem.title || `Request failed with status ${{status}}`);
+    this.name = 'ApiError';
+  }}
+
+  /** Machine-readable error code, e.g. `not_found`. */
+  get code(): string {{
+    return this.problem.code;
+  }}
+}}
+
+export interface ClientOptions {{
+  /** Base URL of the API (default: `http://{host}:{port}`). */
+  baseUrl?: string;
+  /** Bearer token sent with every request. */
+  token?: string;
+  /** `fetch` to send requests with (default: the global one). */
+  fetch?: typeof fetch;
+}}
+
+/** Sends JSON requests and turns error responses into `ApiError`s. */
+export class HttpClient {{
+  readonly baseUrl: string;
+  token?: string;
+  private readonly send: typeof fetch;
+
+  constructor(options: ClientOptions = {{}}) {{
+    this.baseUrl = (options.baseUrl ?? 'http://{host}:{port}').replace(/\\/$/, '');
+    this.token = options.token;
+    this.send = options.fetch ?? ((input, init) => fetch(input, init));
+  }}
+
+  async request<T>(
+    method: string,
+    path: string,
+    body?: unknown,
+    headers: Record<string, string> = {{}},
+  ): Promise<T> {{
+    const requestHeaders: Record<string, string> = {{ Accept: 'application/json', ...headers }};
+    if (this.token) {{
+      requestHeaders['Authorization'] = `Bearer ${{this.token}}`;
+    }}
+    if (body !== undefined) {{
+      requestHeaders['Content-Type'] = 'application/json';
+    }}
+
+    const response = await this.send(this.baseUrl + path, {{
+      method,
+      headers: requestHeaders,
+      body: body === undefined ? undefined : JSON.stringify(body),
+    }});
+    const text = await response.text();
+
+    if (!response.ok) {{
+      let problem: Partial<ProblemDetails> = {{}};
+      try {{
+        problem = JSON.parse(text) as Partial<ProblemDetails>;
+      }} catch {{
+        // Not a problem details body
+      }}
+      throw new ApiError(response.status, {{
+        type: 'about:blank',
+        title: response.statusText,
+        status: response.status,
+        detail: '',
+        code: 'unknown',
+        ...problem,
+      }});
+    }}
+
+    return (text ? JSON.parse(text) : undefined) as T;
+  }}
+}}
+
+",
+        host = client_host(ctx.server_host()),
+        port = ctx.server_port(),
+    ));
+
+    let version_header = ctx.api_versioning().header_name();
+    for group in groups {
+        out.push_str(&render_group(group, version_header, ctx));
+    }
+
+    // Root client
+    out.push_str(
+        "\
+/** Every endpoint group of the API, sharing one `HttpClient`. */
+export class ApiClient {
+  readonly http: HttpClient;
+",
+    );
+    for group in groups {
+        out.push_str(&format!(
+            "  readonly {}: {};\n",
+            group.property(),
+            group.class_name()
+        ));
+    }
+    out.push_str(
+        "
+  constructor(options: ClientOptions = {}) {
+    this.http = new HttpClient(options);
+",
+    );
+    for group in groups {
+        out.push_str(&format!(
+            "    this.{} = new {}(this.http);\n",
+            group.property(),
+            group.class_name()
+        ));
+    }
+    out.push_str(
+        "  }
+
+  /** Set or clear the bearer token sent with every request. */
+  setToken(token?: string): void {
+    this.http.token = token;
+  }
+}
+",
+    );
+
+    out
+}
+
+/// The class wrapping one entity's operations.
+fn render_group(group: &Group, version_header: Option<&str>, ctx: &GenerationContext) -> String {
+    let info = &group.info;
+    let name = &info.entity.name;
+    let base_path = info.base_path();
+    let create_dto = GenerationContext::create_dto_name(name);
+    let update_dto = GenerationContext::update_dto_name(name);
+    let response_dto = GenerationContext::response_dto_name(name);
+    let item = format!("`{base_path}/${{encodeURIComponent(id)}}`");
+
+    // Header versioning: every request of the group names its version
+    let (headers, body_headers) = match (version_header, info.api_version()) {
+        (Some(header), Some(version)) => (
+            format!(
+                ", undefined, {{ {}: {} }}",
+                js_string(header),
+                js_string(version)
+            ),
+            format!(", {{ {}: {} }}", js_string(header), js_string(version)),
+        ),
+        _ => (String::new(), String::new()),
+    };
+
+    let mut out = format!(
+        "\
+/** `{pascal}` endpoints under `{base_path}`. */
+export class {class} {{
+  constructor(private readonly http: HttpClient) {{}}
+",
+        pascal = info.pascal_name(),
+        class = group.class_name(),
+    );
+
+    if group.has(OperationType::ReadAll) {
+        out.push_str(&match ctx.pagination() {
+            PaginationStyle::Offset => format!(
+                "
+  /** GET {base_path} */
+  list(page = 1, perPage = 20): Promise<PaginatedResponse<{response_dto}>> {{
+    return this.http.request('GET', `{base_path}?page=${{page}}&per_page=${{perPage}}`{headers});
+  }}
+"
+            ),
+            PaginationStyle::Cursor => format!(
+                "
+  /** GET {base_path}; pass the previous page's `next_cursor` for the next one. */
+  list(cursor?: string | null, limit = 20): Promise<CursorPage<{response_dto}>> {{
+    const query = cursor
+      ? `cursor=${{encodeURIComponent(cursor)}}&limit=${{limit}}`
+      : `limit=${{limit}}`;
+    return this.http.request('GET', `{base_path}?${{query}}`{headers});
+  }}
+"
+            ),
+            PaginationStyle::None => format!(
+                "
+  /** GET {base_path} */
+  list(): Promise<{response_dto}[]> {{
+    return this.http.request('GET', '{base_path}'{headers});
+  }}
+"
+            ),
+        });
+    }
+
+    if group.has(OperationType::Read) {
+        out.push_str(&format!(
+            "
+  /** GET {base_path}/{{id}} */
+  get(id: string): Promise<{response_dto}> {{
+    return this.http.request('GET', {item}{headers});
+  }}
+"
+        ));
+    }
+
+    if group.has(OperationType::Create) {
+        out.push_str(&format!(
+            "
+  /** POST {base_path} */
+  create(payload: {create_dto}): Promise<{response_dto}> {{
+    return this.http.request('POST', '{base_path}', payload{body_headers});
+  }}
+"
+        ));
+    }
+
+    if group.has(OperationType::Update) {
+        out.push_str(&format!(
+            "
+  /** PUT {base_path}/{{id}} */
+  update(id: string, payload: {update_dto}): Promise<{response_dto}> {{
+    return this.http.request('PUT', {item}, payload{body_headers});
+  }}
+"
+        ));
+    }
+
+    if group.has(OperationType::Delete) {
+        out.push_str(&format!(
+            "
+  /** DELETE {base_path}/{{id}} */
+  delete(id: string): Promise<void> {{
+    return this.http.request('DELETE', {item}{headers});
+  }}
+"
+        ));
+    }
+
+    out.push_str("}\n\n");
+    out
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Comment heading every generated file.
+fn header(ctx: &GenerationContext) -> String {
+    format!(
+        "\
+// TypeScript client for the {name} API
+//
+// Auto-generated by Immortal Engine v2.0.
+// DO NOT EDIT — changes will be overwritten on next generation.
+
+",
+        name = ctx.package_name(),
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{ApiVersioning, EndpointGroup, Entity, OperationType, ProjectGraph};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        project.config.typescript_client = true;
+
+        let mut user = Entity::new("User");
+        user.config.timestamps = true;
+        user.add_field(Field::new("email", DataType::String).required());
+        let mut nickname = Field::new("nickname", DataType::String);
+        nickname.description = Some("Shown instead of the email".to_string());
+        user.add_field(nickname);
+        let mut password = Field::new("password_hash", DataType::String).required();
+        password.secret = true;
+        user.add_field(password);
+        user.add_field(
+            Field::new(
+                "status",
+                DataType::Enum {
+                    name: "UserStatus".to_string(),
+                    variants: vec!["active".to_string(), "on-hold".to_string()],
+                },
+            )
+            .required(),
+        );
+        user.add_field(Field::new("tags", DataType::Array(Box::new(DataType::String))).required());
+        let user_id = user.id;
+        project.add_entity(user);
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+
+        let tag = Entity::new("BlogTag");
+        let tag_id = tag.id;
+        project.add_entity(tag);
+        project.add_endpoint(
+            EndpointGroup::new(tag_id, "BlogTag")
+                .with_operations(&[OperationType::Read, OperationType::ReadAll]),
+        );
+
+        project
+    }
+
+    fn file(project: &ProjectGraph, name: &str) -> String {
+        let ctx = GenerationContext::from_project_default(project);
+        generate_typescript_client(&ctx)
+            .into_iter()
+            .find(|f| f.path.to_string_lossy() == format!("{TS_CLIENT_DIR}/{name}"))
+            .unwrap_or_else(|| panic!("missing {name}"))
+            .content
+    }
+
+    #[test]
+    fn test_generate_typescript_client_files() {
+        let project = blog();
+        let ctx = GenerationContext::from_project_default(&project);
+        let paths: Vec<String> = generate_typescript_client(&ctx)
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "frontend/ts-client/types.ts",
+                "frontend/ts-client/client.ts",
+                "frontend/ts-client/index.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typescript_client_is_opt_in() {
+        let mut project = blog();
+        project.config.typescript_client = false;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_typescript_client(&ctx).is_empty());
+
+        let mut project = blog();
+        project.config.api_style = imortal_ir::ApiStyle::Graphql;
+        let ctx = GenerationContext::from_project_default(&project);
+        assert!(generate_typescript_client(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_types_match_the_dtos() {
+        let types = file(&blog(), "types.ts");
+
+        assert!(types.contains("export type UserStatus = 'active' | 'on-hold';"));
+        assert!(types.contains(
+            "export interface CreateUserDto {\n  email: string;\n  /** Shown instead of the email */\n  nickname?: string | null;\n  password: string;\n  status: UserStatus;\n  tags: string[];\n}"
+        ));
+        assert!(types.contains("export interface UpdateUserDto {\n  email?: string;\n"));
+        assert!(types.contains("  nickname?: string;\n"));
+
+        let response = &types[types.find("export interface UserResponse").unwrap()..];
+        let response = &response[..response.find('}').unwrap()];
+        assert!(response.contains("  id: string;\n"));
+        assert!(response.contains("  nickname: string | null;\n"));
+        assert!(response.contains("  created_at: string;\n"));
+        assert!(!response.contains("password"));
+
+        // Read-only group: no payload interfaces
+        assert!(types.contains("export interface BlogTagResponse {"));
+        assert!(!types.contains("CreateBlogTagDto"));
+        assert!(types.contains("export interface PaginatedResponse<T> {"));
+        assert!(types.contains("export interface ProblemDetails {"));
+    }
+
+    #[test]
+    fn test_ts_type() {
+        assert_eq!(ts_type(&DataType::Int64, true), "number");
+        assert_eq!(ts_type(&DataType::Bool, false), "boolean | null");
+        assert_eq!(
+            ts_type(
+                &DataType::Decimal {
+                    precision: 10,
+                    scale: 2
+                },
+                true
+            ),
+            "string"
+        );
+        assert_eq!(
+            ts_type(&DataType::Optional(Box::new(DataType::Uuid)), false),
+            "string | null"
+        );
+        assert_eq!(
+            ts_type(
+                &DataType::Array(Box::new(DataType::Optional(Box::new(DataType::Int32)))),
+                true
+            ),
+            "(number | null)[]"
+        );
+    }
+
+    #[test]
+    fn test_client_has_a_class_per_group() {
+        let client = file(&blog(), "client.ts");
+
+        assert!(client.contains("export class UserApi {"));
+        assert!(client.contains(
+            "  list(page = 1, perPage = 20): Promise<PaginatedResponse<UserResponse>> {"
+        ));
+        assert!(client.contains("  get(id: string): Promise<UserResponse> {"));
+        assert!(client.contains("  create(payload: CreateUserDto): Promise<UserResponse> {"));
+        assert!(
+            client
+                .contains("  update(id: string, payload: UpdateUserDto): Promise<UserResponse> {")
+        );
+        assert!(client.contains("  delete(id: string): Promise<void> {"));
+
+        let tags = &client[client.find("export class BlogTagApi").unwrap()..];
+        let tags = &tags[..tags.find("\n}\n").unwrap()];
+        assert!(tags.contains("  get(id: string)"));
+        assert!(!tags.contains("  create("));
+
+        assert!(client.contains("  readonly users: UserApi;\n  readonly blogTags: BlogTagApi;\n"));
+        assert!(client.contains("    this.blogTags = new BlogTagApi(this.http);\n  }\n"));
+        assert!(client.contains("'http://127.0.0.1:8080'"));
+        assert!(client.contains("import type {\n  ProblemDetails,\n  PaginatedResponse,\n"));
+        assert!(!client.contains("CreateBlogTagDto"));
+    }
+
+    #[test]
+    fn test_client_sends_the_version_header() {
+        let mut project = blog();
+        project.config.api_versioning = ApiVersioning::Header {
+            header: "X-Api-Version".to_string(),
+            default_version: "2".to_string(),
+        };
+        let client = file(&project, "client.ts");
+        assert!(client.contains(", undefined, { 'X-Api-Version': '2' });"));
+        assert!(client.contains("payload, { 'X-Api-Version': '2' });"));
+    }
+}
@@ -0,0 +1,323 @@
+//! # Generation Warnings
+//!
+//! Structured warnings produced while generating a project.
+//!
+//! Each warning carries a machine-readable [`WarningCode`], a
+//! [`WarningSeverity`], the IDs of the model elements it concerns and an
+//! optional suggested fix, so front-ends can filter warnings and link back to
+//! the entity, field or endpoint that caused them. The `Display` output is the
+//! plain message, which is what the CLI prints.
+//!
+//! Issues the IR validator already reports about the generated code are not
+//! checked again here; their [`ValidationWarning`]s convert into generation
+//! warnings.
+
+use imortal_ir::validation::{ValidationTarget, ValidationWarning, ValidationWarningCode};
+use uuid::Uuid;
+
+// ============================================================================
+// WarningCode
+// ============================================================================
+
+/// What kind of issue a warning reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningCode {
+    /// An entity has no endpoint group, so no handlers are generated for it
+    EntityWithoutEndpoint,
+    /// An endpoint group points at an entity that does not exist
+    OrphanEndpoint,
+    /// Auth is enabled but no endpoint requires it
+    AuthWithoutSecuredEndpoints,
+    /// A field looks sensitive but would be exposed
+    SensitiveFieldExposed,
+    /// A foreign key used by nested routes opts out of indexing, and the
+    /// project does not index every foreign key
+    UnindexedNestedForeignKey,
+    /// The project has no entities
+    EmptyProject,
+    /// An entity or field name is rewritten to fit Rust conventions
+    RenamedIdentifier,
+    /// A type the target database can only approximate
+    UnsupportedColumnType,
+    /// A schema the target database cannot honour
+    UnsupportedSchema,
+    /// A feature the selected ORM backend cannot generate
+    UnsupportedByOrm,
+    /// An incremental migration drops a table or column
+    DestructiveMigration,
+    /// An incremental migration needs SQL written by hand
+    ManualMigration,
+    /// An incremental migration defers a destructive change (safe migrations)
+    StagedMigration,
+    /// A generated Rust file rustfmt could not parse, left unformatted
+    Unformatted,
+    /// A protected region had no place in the new output and was moved to
+    /// the end of its file
+    RelocatedRegion,
+    /// A file edited since it was generated was not overwritten
+    UserModified,
+    /// A file in the templates directory overrides no template
+    UnusedTemplate,
+    /// Any other warning
+    Other,
+}
+
+impl WarningCode {
+    /// Short human-readable label for filters and badges
+    pub fn label(&self) -> &'static str {
+        match self {
+            WarningCode::EntityWithoutEndpoint => "No endpoint",
+            WarningCode::OrphanEndpoint => "Orphan endpoint",
+            WarningCode::AuthWithoutSecuredEndpoints => "Auth unused",
+            WarningCode::SensitiveFieldExposed => "Sensitive field",
+            WarningCode::UnindexedNestedForeignKey => "Unindexed FK",
+            WarningCode::EmptyProject => "Empty project",
+            WarningCode::RenamedIdentifier => "Renamed",
+            WarningCode::UnsupportedColumnType => "Column type",
+            WarningCode::UnsupportedSchema => "Schema",
+            WarningCode::UnsupportedByOrm => "ORM support",
+            WarningCode::DestructiveMigration => "Data loss",
+            WarningCode::ManualMigration => "Manual SQL",
+            WarningCode::StagedMigration => "Staged",
+            WarningCode::Unformatted => "Unformatted",
+            WarningCode::RelocatedRegion => "Kept region",
+            WarningCode::UserModified => "Edited file",
+            WarningCode::UnusedTemplate => "Unused template",
+            WarningCode::Other => "Other",
+        }
+    }
+}
+
+// ============================================================================
+// WarningSeverity
+// ============================================================================
+
+/// How much attention a warning deserves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WarningSeverity {
+    /// Informational; the output is correct but may surprise
+    Info,
+    /// Likely a modelling mistake or a degraded result
+    Warning,
+}
+
+impl WarningSeverity {
+    /// Display name for this severity
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WarningSeverity::Info => "Info",
+            WarningSeverity::Warning => "Warning",
+        }
+    }
+}
+
+// ============================================================================
+// GenerationWarning
+// ============================================================================
+
+/// A single warning from a generation run
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationWarning {
+    /// Warning code
+    pub code: WarningCode,
+
+    /// Severity
+    pub severity: WarningSeverity,
+
+    /// Human-readable message
+    pub message: String,
+
+    /// Entity the warning concerns (if any)
+    pub entity_id: Option<Uuid>,
+
+    /// Field the warning concerns (if any); always paired with `entity_id`
+    pub field_id: Option<Uuid>,
+
+    /// Endpoint group the warning concerns (if any)
+    pub endpoint_id: Option<Uuid>,
+
+    /// Suggested fix (if any)
+    pub suggestion: Option<String>,
+}
+
+impl GenerationWarning {
+    /// Create a new warning with [`WarningSeverity::Warning`]
+    pub fn new(code: WarningCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            severity: WarningSeverity::Warning,
+            message: message.into(),
+            entity_id: None,
+            field_id: None,
+            endpoint_id: None,
+            suggestion: None,
+        }
+    }
+
+    /// Downgrade to an informational note
+    pub fn info(mut self) -> Self {
+        self.severity = WarningSeverity::Info;
+        self
+    }
+
+    /// Link to an entity
+    pub fn with_entity(mut self, entity_id: Uuid) -> Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    /// Link to a field of an entity
+    pub fn with_field(mut self, entity_id: Uuid, field_id: Uuid) -> Self {
+        self.entity_id = Some(entity_id);
+        self.field_id = Some(field_id);
+        self
+    }
+
+    /// Link to an endpoint group
+    pub fn with_endpoint(mut self, endpoint_id: Uuid) -> Self {
+        self.endpoint_id = Some(endpoint_id);
+        self
+    }
+
+    /// Add a suggested fix
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Whether the warning links back to a model element
+    pub fn has_source(&self) -> bool {
+        self.entity_id.is_some() || self.endpoint_id.is_some()
+    }
+
+    /// Case-insensitive search over the message, suggestion and code label
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+        self.message.to_lowercase().contains(&query)
+            || self.code.label().to_lowercase().contains(&query)
+            || self
+                .suggestion
+                .as_ref()
+                .is_some_and(|s| s.to_lowercase().contains(&query))
+    }
+}
+
+impl std::fmt::Display for GenerationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<String> for GenerationWarning {
+    fn from(message: String) -> Self {
+        Self::new(WarningCode::Other, message)
+    }
+}
+
+impl From<&str> for GenerationWarning {
+    fn from(message: &str) -> Self {
+        Self::new(WarningCode::Other, message)
+    }
+}
+
+impl From<ValidationWarning> for GenerationWarning {
+    fn from(warning: ValidationWarning) -> Self {
+        let code = match warning.code {
+            ValidationWarningCode::SensitiveFieldExposed => WarningCode::SensitiveFieldExposed,
+            ValidationWarningCode::MissingIndex => WarningCode::UnindexedNestedForeignKey,
+            ValidationWarningCode::RenamedIdentifier => WarningCode::RenamedIdentifier,
+            ValidationWarningCode::UnsupportedColumnType => WarningCode::UnsupportedColumnType,
+            _ => WarningCode::Other,
+        };
+
+        let mut converted = Self::new(code, warning.message);
+        // Renames are expected; the output is correct either way
+        if code == WarningCode::RenamedIdentifier {
+            converted = converted.info();
+        }
+        match warning.target {
+            Some(ValidationTarget::Entity(entity_id)) => {
+                converted = converted.with_entity(entity_id)
+            }
+            Some(ValidationTarget::Field {
+                entity_id,
+                field_id,
+            }) => converted = converted.with_field(entity_id, field_id),
+            Some(ValidationTarget::Endpoint(endpoint_id)) => {
+                converted = converted.with_endpoint(endpoint_id)
+            }
+            Some(ValidationTarget::Relationship(_)) | None => {}
+        }
+        converted.suggestion = warning.suggestion;
+        converted
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warning_builder() {
+        let entity_id = Uuid::new_v4();
+        let field_id = Uuid::new_v4();
+        let warning = GenerationWarning::new(WarningCode::SensitiveFieldExposed, "exposed")
+            .with_field(entity_id, field_id)
+            .with_suggestion("Mark it as secret");
+
+        assert_eq!(warning.severity, WarningSeverity::Warning);
+        assert_eq!(warning.entity_id, Some(entity_id));
+        assert_eq!(warning.field_id, Some(field_id));
+        assert!(warning.has_source());
+        assert_eq!(warning.to_string(), "exposed");
+    }
+
+    #[test]
+    fn test_from_validation_warning() {
+        let entity_id = Uuid::new_v4();
+        let field_id = Uuid::new_v4();
+        let warning = GenerationWarning::from(
+            ValidationWarning::new(ValidationWarningCode::SensitiveFieldExposed, "exposed")
+                .with_target(ValidationTarget::Field {
+                    entity_id,
+                    field_id,
+                })
+                .with_suggestion("Mark it as secret"),
+        );
+
+        assert_eq!(warning.code, WarningCode::SensitiveFieldExposed);
+        assert_eq!(warning.severity, WarningSeverity::Warning);
+        assert_eq!(warning.field_id, Some(field_id));
+        assert_eq!(warning.suggestion.as_deref(), Some("Mark it as secret"));
+        assert_eq!(warning.to_string(), "exposed");
+    }
+
+    #[test]
+    fn test_warning_matches() {
+        let warning = GenerationWarning::new(
+            WarningCode::UnindexedNestedForeignKey,
+            "Foreign key 'Post.user_id' is not indexed",
+        )
+        .with_suggestion("Enable indexing");
+
+        assert!(warning.matches(""));
+        assert!(warning.matches("post.USER_ID"));
+        assert!(warning.matches("unindexed fk"));
+        assert!(warning.matches("enable"));
+        assert!(!warning.matches("comment"));
+    }
+
+    #[test]
+    fn test_warning_from_string() {
+        let warning: GenerationWarning = "plain".into();
+        assert_eq!(warning.code, WarningCode::Other);
+        assert!(!warning.has_source());
+    }
+}
@@ -0,0 +1,482 @@
+//! # Workspace Member Export
+//!
+//! Places the generated crate inside an existing Cargo workspace instead of
+//! a standalone project, so teams can adopt it in an established monorepo.
+//!
+//! Generating with [`GeneratorConfig::workspace_member`](crate::GeneratorConfig)
+//! shapes the output for a member — the release profile is left to the
+//! workspace root, where Cargo reads it. Registering the crate is a separate
+//! step around writing the files:
+//!
+//! ```rust,ignore
+//! use imortal_codegen::{Generator, GeneratorConfig, WorkspaceMember};
+//!
+//! let config = GeneratorConfig::new()
+//!     .with_output_dir("services/api")
+//!     .as_workspace_member();
+//! let member = WorkspaceMember::locate("services/api", &project.config.package_name)?;
+//! Generator::new(config).generate_and_write(&project)?;
+//! member.register()?;
+//! ```
+//!
+//! [`WorkspaceMember::locate`] finds the closest `Cargo.toml` with a
+//! `[workspace]` table above the output directory and refuses to go on when
+//! that would clobber an existing crate: a different package already in the
+//! output directory, or another member with the same package name.
+//! [`WorkspaceMember::register`] then appends the directory to
+//! `[workspace.members]`, editing the manifest as text so its comments and
+//! layout survive.
+
+use std::path::{Component, Path, PathBuf};
+
+use imortal_core::{EngineError, EngineResult};
+
+/// Manifest file name
+const MANIFEST: &str = "Cargo.toml";
+
+// ============================================================================
+// WorkspaceMember
+// ============================================================================
+
+/// Where a generated crate sits in an enclosing Cargo workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// Directory holding the workspace's root `Cargo.toml`
+    pub root: PathBuf,
+
+    /// The crate's directory relative to `root`, with `/` separators, as it
+    /// appears in `[workspace.members]`
+    pub path: String,
+}
+
+impl WorkspaceMember {
+    /// Find the workspace enclosing `output_dir` and check that `package`
+    /// can be generated there without clobbering an existing crate.
+    ///
+    /// `output_dir` does not need to exist yet.
+    pub fn locate(output_dir: impl AsRef<Path>, package: &str) -> EngineResult<Self> {
+        let output_dir = absolute(output_dir.as_ref())?;
+
+        let manifest = output_dir.join(MANIFEST);
+        if manifest.is_file() {
+            let existing = read(&manifest)?;
+            if has_workspace_table(&existing) {
+                return Err(EngineError::CodeGeneration(format!(
+                    "{} is a workspace root, not a place for a member crate",
+                    output_dir.display()
+                )));
+            }
+            if let Some(name) = package_name(&existing)
+                && name != package
+            {
+                return Err(EngineError::CodeGeneration(format!(
+                    "{} already holds the crate '{}'",
+                    output_dir.display(),
+                    name
+                )));
+            }
+        }
+
+        let root = output_dir
+            .ancestors()
+            .skip(1)
+            .find(|dir| {
+                std::fs::read_to_string(dir.join(MANIFEST))
+                    .is_ok_and(|content| has_workspace_table(&content))
+            })
+            .ok_or_else(|| {
+                EngineError::CodeGeneration(format!(
+                    "no Cargo workspace encloses {}",
+                    output_dir.display()
+                ))
+            })?
+            .to_path_buf();
+
+        let path = output_dir
+            .strip_prefix(&root)
+            .map_err(|_| EngineError::InvalidOutputPath(output_dir.clone()))?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        // Cargo rejects two members with the same package name
+        let root_manifest = read(&root.join(MANIFEST))?;
+        for other in members(&root_manifest) {
+            if other == path || other.contains(['*', '?', '[']) {
+                continue;
+            }
+            let clash = std::fs::read_to_string(root.join(&other).join(MANIFEST))
+                .is_ok_and(|content| package_name(&content).as_deref() == Some(package));
+            if clash {
+                return Err(EngineError::CodeGeneration(format!(
+                    "workspace member '{}' is already named '{}'",
+                    other, package
+                )));
+            }
+        }
+
+        Ok(Self { root, path })
+    }
+
+    /// Path of the workspace's root manifest
+    pub fn manifest_path(&self) -> PathBuf {
+        self.root.join(MANIFEST)
+    }
+
+    /// Add the crate to `[workspace.members]`. Returns `false` when it was
+    /// already listed.
+    pub fn register(&self) -> EngineResult<bool> {
+        let manifest = self.manifest_path();
+        let content = read(&manifest)?;
+        let Some(updated) = add_member(&content, &self.path) else {
+            return Ok(false);
+        };
+        std::fs::write(&manifest, updated).map_err(|e| EngineError::FileWrite {
+            path: manifest.clone(),
+            message: e.to_string(),
+        })?;
+        Ok(true)
+    }
+}
+
+// ============================================================================
+// Manifest editing
+// ============================================================================
+
+/// `manifest` with `member` appended to `[workspace.members]`, or `None`
+/// when it is listed already. A workspace without a members list gets one.
+pub fn add_member(manifest: &str, member: &str) -> Option<String> {
+    if members(manifest).iter().any(|m| m == member) {
+        return None;
+    }
+    let entry = format!("\"{}\"", member);
+    let lines: Vec<&str> = manifest.lines().collect();
+    let mut out: Vec<String> = Vec::with_capacity(lines.len() + 2);
+
+    let Some(start) = members_line(&lines) else {
+        // No list yet: open one right under the table header
+        for line in &lines {
+            out.push(line.to_string());
+            if line.trim() == "[workspace]" {
+                out.push(format!("members = [{}]", entry));
+            }
+        }
+        return Some(finish(out, manifest));
+    };
+
+    let opening = lines[start];
+    let bracket = opening.find('[')?;
+    if let Some(close) = opening.rfind(']').filter(|&close| close > bracket) {
+        // Inline list: `members = ["a", "b"]`
+        let items = opening[bracket + 1..close].trim_end();
+        let separator = if items.trim().is_empty() {
+            ""
+        } else if items.ends_with(',') {
+            " "
+        } else {
+            ", "
+        };
+        let line = format!(
+            "{}{}{}{}",
+            &opening[..bracket + 1 + items.len()],
+            separator,
+            entry,
+            &opening[close..]
+        );
+        out.extend(lines[..start].iter().map(|l| l.to_string()));
+        out.push(line);
+        out.extend(lines[start + 1..].iter().map(|l| l.to_string()));
+        return Some(finish(out, manifest));
+    }
+
+    // One member per line, closed by a line starting with `]`
+    let end = (start + 1..lines.len()).find(|&i| lines[i].trim_start().starts_with(']'))?;
+    let last_item = (start + 1..end).rev().find(|&i| is_item(lines[i]));
+    let indent = last_item
+        .map(|i| &lines[i][..lines[i].len() - lines[i].trim_start().len()])
+        .unwrap_or("    ");
+
+    for (i, line) in lines.iter().enumerate() {
+        if i == end {
+            out.push(format!("{}{},", indent, entry));
+        }
+        let code = strip_comment(line).trim_end();
+        if Some(i) == last_item && !code.ends_with(',') {
+            out.push(format!("{},{}", code, &line[code.len()..]));
+        } else {
+            out.push(line.to_string());
+        }
+    }
+    Some(finish(out, manifest))
+}
+
+/// Entries of `[workspace.members]`
+pub fn members(manifest: &str) -> Vec<String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let Some(start) = members_line(&lines) else {
+        return Vec::new();
+    };
+
+    let mut list = String::new();
+    for line in &lines[start..] {
+        let line = strip_comment(line);
+        list.push_str(line);
+        list.push('\n');
+        if line.contains(']') {
+            break;
+        }
+    }
+    let Some(open) = list.find('[') else {
+        return Vec::new();
+    };
+    list[open + 1..]
+        .split(',')
+        .map(|item| item.trim().trim_end_matches(']').trim())
+        .filter_map(|item| item.strip_prefix('"')?.strip_suffix('"'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether the manifest declares a workspace
+pub fn has_workspace_table(manifest: &str) -> bool {
+    manifest
+        .lines()
+        .any(|line| strip_comment(line).trim() == "[workspace]")
+}
+
+/// `name` from the manifest's `[package]` table
+pub fn package_name(manifest: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in manifest.lines() {
+        let line = strip_comment(line).trim();
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+        } else if in_package
+            && let Some((key, value)) = line.split_once('=')
+            && key.trim() == "name"
+        {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Index of the `members = [` line inside `[workspace]`
+fn members_line(lines: &[&str]) -> Option<usize> {
+    let mut in_workspace = false;
+    for (i, line) in lines.iter().enumerate() {
+        let line = strip_comment(line).trim();
+        if line.starts_with('[') && !line.starts_with("[\"") {
+            in_workspace = line == "[workspace]";
+        } else if in_workspace
+            && let Some((key, value)) = line.split_once('=')
+            && key.trim() == "members"
+            && value.trim_start().starts_with('[')
+        {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn is_item(line: &str) -> bool {
+    strip_comment(line).trim().starts_with('"')
+}
+
+/// The line without a trailing `#` comment. Member paths don't contain `#`,
+/// so quoting is not tracked.
+fn strip_comment(line: &str) -> &str {
+    line.split_once('#').map_or(line, |(code, _)| code)
+}
+
+/// Join edited lines, keeping the original's trailing newline
+fn finish(lines: Vec<String>, original: &str) -> String {
+    let mut out = lines.join("\n");
+    if original.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+fn read(path: &Path) -> EngineResult<String> {
+    std::fs::read_to_string(path).map_err(|e| EngineError::FileRead {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// `path` made absolute without requiring it to exist: the closest existing
+/// ancestor is canonicalised and the rest appended.
+fn absolute(path: &Path) -> EngineResult<PathBuf> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let mut missing = Vec::new();
+    let mut existing = path.as_path();
+    while !existing.exists() {
+        let Some(parent) = existing.parent() else {
+            return Err(EngineError::InvalidOutputPath(path.clone()));
+        };
+        if let Some(name) = existing.file_name() {
+            missing.push(name.to_os_string());
+        }
+        existing = parent;
+    }
+
+    let mut resolved = existing.canonicalize()?;
+    for name in missing.into_iter().rev() {
+        resolved.push(name);
+    }
+    // `..` after a missing directory can't be canonicalised; refuse it
+    if resolved.components().any(|c| c == Component::ParentDir) {
+        return Err(EngineError::InvalidOutputPath(path));
+    }
+    Ok(resolved)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT: &str = "\
+[workspace]
+resolver = \"2\"
+members = [
+    \"crates/core\", # shared types
+    \"crates/web\"
+]
+
+[profile.release]
+lto = true
+";
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let full = dir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(full, content).unwrap();
+    }
+
+    fn crate_manifest(name: &str) -> String {
+        format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name)
+    }
+
+    #[test]
+    fn test_members() {
+        assert_eq!(members(ROOT), vec!["crates/core", "crates/web"]);
+        assert_eq!(
+            members("[workspace]\nmembers = [\"a\", \"b\"]\n"),
+            vec!["a", "b"]
+        );
+        assert!(members("[package]\nname = \"x\"\n").is_empty());
+    }
+
+    #[test]
+    fn test_add_member_multiline() {
+        let updated = add_member(ROOT, "services/api").unwrap();
+        assert!(updated.contains(
+            "    \"crates/core\", # shared types\n    \"crates/web\",\n    \"services/api\",\n]"
+        ));
+        assert!(updated.ends_with("lto = true\n"));
+        assert_eq!(
+            members(&updated),
+            vec!["crates/core", "crates/web", "services/api"]
+        );
+
+        // Already listed
+        assert!(add_member(&updated, "services/api").is_none());
+    }
+
+    #[test]
+    fn test_add_member_inline_and_missing_list() {
+        assert_eq!(
+            add_member("[workspace]\nmembers = [\"a\"]\n", "b").unwrap(),
+            "[workspace]\nmembers = [\"a\", \"b\"]\n"
+        );
+        assert_eq!(
+            add_member("[workspace]\nmembers = []\n", "b").unwrap(),
+            "[workspace]\nmembers = [\"b\"]\n"
+        );
+        assert_eq!(
+            add_member("[workspace]\nresolver = \"2\"\n", "b").unwrap(),
+            "[workspace]\nmembers = [\"b\"]\nresolver = \"2\"\n"
+        );
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(
+            package_name(&crate_manifest("blog_api")).as_deref(),
+            Some("blog_api")
+        );
+        assert_eq!(package_name("[workspace]\nmembers = []\n"), None);
+    }
+
+    #[test]
+    fn test_locate_and_register() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "Cargo.toml", ROOT);
+        write(
+            dir.path(),
+            "crates/core/Cargo.toml",
+            &crate_manifest("core"),
+        );
+
+        let member = WorkspaceMember::locate(dir.path().join("services/api"), "blog_api").unwrap();
+        assert_eq!(member.root, dir.path().canonicalize().unwrap());
+        assert_eq!(member.path, "services/api");
+
+        assert!(member.register().unwrap());
+        assert!(!member.register().unwrap());
+        let manifest = std::fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+        assert_eq!(members(&manifest).last().unwrap(), "services/api");
+    }
+
+    #[test]
+    fn test_locate_refuses_to_clobber() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "Cargo.toml", ROOT);
+        write(
+            dir.path(),
+            "crates/core/Cargo.toml",
+            &crate_manifest("core"),
+        );
+
+        // A different crate already lives there
+        let err = WorkspaceMember::locate(dir.path().join("crates/core"), "blog_api").unwrap_err();
+        assert!(err.to_string().contains("already holds the crate 'core'"));
+
+        // Regenerating the same crate is fine
+        assert!(WorkspaceMember::locate(dir.path().join("crates/core"), "core").is_ok());
+
+        // Another member has the package name
+        let err = WorkspaceMember::locate(dir.path().join("services/core"), "core").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'crates/core' is already named 'core'")
+        );
+
+        // The workspace root itself
+        let err = WorkspaceMember::locate(dir.path(), "blog_api").unwrap_err();
+        assert!(err.to_string().contains("workspace root"));
+    }
+
+    #[test]
+    fn test_locate_without_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "Cargo.toml", &crate_manifest("standalone"));
+        let err = WorkspaceMember::locate(dir.path().join("api"), "blog_api").unwrap_err();
+        assert!(err.to_string().contains("no Cargo workspace"));
+    }
+}
@@ -123,6 +123,19 @@ pub enum EngineError {
     #[error("Invalid project file format: {0}")]
     InvalidProjectFormat(String),
 
+    /// Project file that could not be parsed, with where it went wrong
+    #[error(
+        "Invalid project file at line {line}, column {column}{}: {message}",
+        .key.as_deref().map(|key| format!(" (`{}`)", key)).unwrap_or_default()
+    )]
+    ProjectParse {
+        line: usize,
+        column: usize,
+        /// Dotted path to the offending key, when it could be pinned down
+        key: Option<String>,
+        message: String,
+    },
+
     /// Schema version mismatch
     #[error("Schema version mismatch: expected {expected}, found {found}")]
     SchemaVersionMismatch { expected: u32, found: u32 },
@@ -349,6 +362,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_project_parse_error() {
+        let err = EngineError::ProjectParse {
+            line: 12,
+            column: 5,
+            key: Some("project.entities.a1.fields[0]".to_string()),
+            message: "missing field `name`".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid project file at line 12, column 5 (`project.entities.a1.fields[0]`): missing field `name`"
+        );
+
+        let err = EngineError::ProjectParse {
+            line: 3,
+            column: 1,
+            key: None,
+            message: "trailing comma".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid project file at line 3, column 1: trailing comma"
+        );
+    }
+
     #[test]
     fn test_io_error_classification() {
         let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
@@ -271,6 +271,9 @@ pub enum DataType {
     Float32,
     /// 64-bit floating point (double precision)
     Float64,
+    /// Exact fixed-point number (NUMERIC/DECIMAL): `precision` total digits,
+    /// `scale` of them after the decimal point
+    Decimal { precision: u8, scale: u8 },
     /// Boolean true/false
     Bool,
     /// UUID (universally unique identifier)
@@ -305,6 +308,29 @@ pub enum DataType {
 }
 
 impl DataType {
+    /// Largest precision supported by `rust_decimal`, which backs decimals in
+    /// the generated code
+    pub const MAX_DECIMAL_PRECISION: u8 = 28;
+
+    /// Create a decimal type with the given precision and scale
+    pub fn decimal(precision: u8, scale: u8) -> Self {
+        DataType::Decimal { precision, scale }
+    }
+
+    /// Decimal suitable for money amounts: `NUMERIC(12,2)`
+    pub fn money() -> Self {
+        DataType::decimal(12, 2)
+    }
+
+    /// Check if this is a decimal, looking through `Optional` and `Array`
+    pub fn is_decimal(&self) -> bool {
+        match self {
+            DataType::Decimal { .. } => true,
+            DataType::Optional(inner) | DataType::Array(inner) => inner.is_decimal(),
+            _ => false,
+        }
+    }
+
     /// Convert to Rust type string
     pub fn to_rust_type(&self) -> String {
         match self {
@@ -313,6 +339,7 @@ impl DataType {
             DataType::Int64 => "i64".to_string(),
             DataType::Float32 => "f32".to_string(),
             DataType::Float64 => "f64".to_string(),
+            DataType::Decimal { .. } => "rust_decimal::Decimal".to_string(),
             DataType::Bool => "bool".to_string(),
             DataType::Uuid => "uuid::Uuid".to_string(),
             DataType::DateTime => "chrono::DateTime<chrono::Utc>".to_string(),
@@ -336,6 +363,9 @@ impl DataType {
             DataType::Int64 => "BigInteger".to_string(),
             DataType::Float32 => "Float".to_string(),
             DataType::Float64 => "Double".to_string(),
+            DataType::Decimal { precision, scale } => {
+                format!("Decimal(Some(({}, {})))", precision, scale)
+            }
             DataType::Bool => "Boolean".to_string(),
             DataType::Uuid => "Uuid".to_string(),
             DataType::DateTime => "TimestampWithTimeZone".to_string(),
@@ -368,6 +398,7 @@ impl DataType {
             DataType::Int64 => "BIGINT".to_string(),
             DataType::Float32 => "REAL".to_string(),
             DataType::Float64 => "DOUBLE PRECISION".to_string(),
+            DataType::Decimal { precision, scale } => format!("NUMERIC({},{})", precision, scale),
             DataType::Bool => "BOOLEAN".to_string(),
             DataType::Uuid => "UUID".to_string(),
             DataType::DateTime => "TIMESTAMP WITH TIME ZONE".to_string(),
@@ -391,6 +422,7 @@ impl DataType {
             DataType::Int64 => "BIGINT".to_string(),
             DataType::Float32 => "FLOAT".to_string(),
             DataType::Float64 => "DOUBLE".to_string(),
+            DataType::Decimal { precision, scale } => format!("DECIMAL({},{})", precision, scale),
             DataType::Bool => "TINYINT(1)".to_string(),
             DataType::Uuid => "CHAR(36)".to_string(),
             DataType::DateTime => "DATETIME".to_string(),
@@ -418,6 +450,8 @@ impl DataType {
             DataType::String | DataType::Text => "TEXT".to_string(),
             DataType::Int32 | DataType::Int64 => "INTEGER".to_string(),
             DataType::Float32 | DataType::Float64 => "REAL".to_string(),
+            // SQLite has no exact decimal storage; NUMERIC affinity is the closest
+            DataType::Decimal { .. } => "NUMERIC".to_string(),
             DataType::Bool => "INTEGER".to_string(),
             DataType::Uuid => "TEXT".to_string(),
             DataType::DateTime | DataType::Date | DataType::Time => "TEXT".to_string(),
@@ -449,6 +483,7 @@ impl DataType {
             DataType::Int64 => "Big Integer".to_string(),
             DataType::Float32 => "Float".to_string(),
             DataType::Float64 => "Double".to_string(),
+            DataType::Decimal { precision, scale } => format!("Decimal({},{})", precision, scale),
             DataType::Bool => "Boolean".to_string(),
             DataType::Uuid => "UUID".to_string(),
             DataType::DateTime => "DateTime".to_string(),
@@ -472,6 +507,7 @@ impl DataType {
             DataType::Int64,
             DataType::Float32,
             DataType::Float64,
+            DataType::money(),
             DataType::Bool,
             DataType::Uuid,
             DataType::DateTime,
@@ -621,6 +657,11 @@ pub enum RelationType {
     ManyToOne,
     /// Many-to-many through junction table
     ManyToMany { junction_table: String },
+    /// Belongs to one of several entities (e.g., a Comment on a Post or a
+    /// Photo): `discriminator` names the target entity, the relationship's
+    /// key field holds its ID. Each possible target is a relationship of
+    /// its own sharing both fields.
+    Polymorphic { discriminator: String },
 }
 
 impl RelationType {
@@ -631,6 +672,7 @@ impl RelationType {
             RelationType::OneToMany => "One to Many",
             RelationType::ManyToOne => "Many to One",
             RelationType::ManyToMany { .. } => "Many to Many",
+            RelationType::Polymorphic { .. } => "Polymorphic",
         }
     }
 
@@ -641,6 +683,7 @@ impl RelationType {
             RelationType::OneToMany => "1 ───< *",
             RelationType::ManyToOne => "* >─── 1",
             RelationType::ManyToMany { .. } => "* >──< *",
+            RelationType::Polymorphic { .. } => "* >┄┄ 1",
         }
     }
 
@@ -658,6 +701,9 @@ impl RelationType {
             RelationType::ManyToMany { junction_table } => RelationType::ManyToMany {
                 junction_table: junction_table.clone(),
             },
+            RelationType::Polymorphic { discriminator } => RelationType::Polymorphic {
+                discriminator: discriminator.clone(),
+            },
         }
     }
 }
@@ -1135,6 +1181,26 @@ mod tests {
         assert_eq!(DataType::Json.to_postgres_type(), "JSONB");
     }
 
+    #[test]
+    fn test_data_type_decimal() {
+        let money = DataType::money();
+        assert_eq!(money, DataType::decimal(12, 2));
+        assert_eq!(money.to_rust_type(), "rust_decimal::Decimal");
+        assert_eq!(money.to_sea_orm_type(), "Decimal(Some((12, 2)))");
+        assert_eq!(money.to_postgres_type(), "NUMERIC(12,2)");
+        assert_eq!(money.to_mysql_type(), "DECIMAL(12,2)");
+        assert_eq!(money.to_sqlite_type(), "NUMERIC");
+        assert_eq!(money.display_name(), "Decimal(12,2)");
+        assert!(DataType::Optional(Box::new(money)).is_decimal());
+        assert!(!DataType::Float64.is_decimal());
+
+        let json = serde_json::to_string(&DataType::decimal(5, 1)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"Decimal","params":{"precision":5,"scale":1}}"#
+        );
+    }
+
     #[test]
     fn test_data_type_display() {
         assert_eq!(DataType::String.display_name(), "String");
@@ -1179,6 +1245,20 @@ mod tests {
         assert!(!RelationType::OneToMany.requires_junction_table());
     }
 
+    #[test]
+    fn test_relation_type_polymorphic() {
+        let poly = RelationType::Polymorphic {
+            discriminator: "commentable_type".to_string(),
+        };
+        assert_eq!(poly.display_name(), "Polymorphic");
+        assert_eq!(poly.inverse(), poly);
+        assert!(!poly.requires_junction_table());
+        assert_eq!(
+            serde_json::to_string(&poly).unwrap(),
+            r#"{"polymorphic":{"discriminator":"commentable_type"}}"#
+        );
+    }
+
     // ReferentialAction tests
     #[test]
     fn test_referential_action() {
@@ -0,0 +1,410 @@
+//! # Compatibility
+//!
+//! Checks that the project's options can be generated together before any
+//! code is written. Each option is fine on its own, but some combinations
+//! would produce output that does not build or migrate:
+//!
+//! - A fullstack project whose API style is GraphQL only — the generated
+//!   frontend's API client only speaks REST
+//! - SQL expression defaults calling a function the target database lacks
+//!   (e.g. `gen_random_uuid()` on MySQL, `now()` on SQLite)
+//! - Literal defaults on MySQL `LONGTEXT`/`JSON`/`BLOB` columns, which MySQL
+//!   rejects
+//!
+//! The generator refuses to run while [`check_compatibility`] reports
+//! anything; the validator, the CLI and the code generation page list the
+//! same problems with a suggested fix for each.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::compatibility::check_compatibility;
+//! use imortal_ir::{ApiStyle, ProjectConfig, ProjectGraph};
+//!
+//! let mut project = ProjectGraph::new("shop");
+//! project.config = ProjectConfig::fullstack().with_api_style(ApiStyle::Graphql);
+//!
+//! let problems = check_compatibility(&project, &project.config);
+//! assert_eq!(problems.len(), 1);
+//! assert_eq!(problems[0].path, "config.api_style");
+//! ```
+
+use imortal_core::{DataType, DatabaseType};
+use uuid::Uuid;
+
+use crate::{ApiStyle, DefaultValue, Entity, Field, ProjectConfig, ProjectGraph, ProjectType};
+
+// ============================================================================
+// Incompatibility
+// ============================================================================
+
+/// One combination of options that cannot be generated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incompatibility {
+    /// What is wrong, naming the options involved
+    pub message: String,
+
+    /// How to resolve it
+    pub suggestion: String,
+
+    /// Path to the offending element (e.g. "config.api_style",
+    /// "entities.User.fields.id")
+    pub path: String,
+
+    /// Entity the problem is on, if it is not project-wide
+    pub entity_id: Option<Uuid>,
+
+    /// Field the problem is on
+    pub field_id: Option<Uuid>,
+}
+
+impl Incompatibility {
+    fn project(
+        path: impl Into<String>,
+        message: impl Into<String>,
+        suggestion: impl Into<String>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            suggestion: suggestion.into(),
+            path: path.into(),
+            entity_id: None,
+            field_id: None,
+        }
+    }
+
+    fn field(
+        entity: &Entity,
+        field: &Field,
+        message: impl Into<String>,
+        suggestion: impl Into<String>,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            suggestion: suggestion.into(),
+            path: format!("entities.{}.fields.{}", entity.name, field.name),
+            entity_id: Some(entity.id),
+            field_id: Some(field.id),
+        }
+    }
+}
+
+impl std::fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.path, self.message)
+    }
+}
+
+// ============================================================================
+// Checks
+// ============================================================================
+
+/// Every problem with generating `project` under `config`.
+///
+/// `config` is passed separately from `project.config` so the generator can
+/// check the options it will actually use, after command-line overrides.
+pub fn check_compatibility(project: &ProjectGraph, config: &ProjectConfig) -> Vec<Incompatibility> {
+    let mut problems = Vec::new();
+    problems.extend(check_frontend_api_style(config));
+
+    let mut entities: Vec<&Entity> = project.entities.values().collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name));
+    for entity in entities {
+        for field in &entity.fields {
+            problems.extend(check_default(entity, field, config.database));
+        }
+    }
+    problems
+}
+
+/// The generated frontend calls the REST routes, so a fullstack project has
+/// to serve them.
+fn check_frontend_api_style(config: &ProjectConfig) -> Option<Incompatibility> {
+    (config.project_type == ProjectType::Fullstack && config.api_style == ApiStyle::Graphql)
+        .then(|| {
+            Incompatibility::project(
+                "config.api_style",
+                "Fullstack projects need REST routes: the generated frontend's API client has no GraphQL mode, but the API style is GraphQL only.",
+                format!(
+                    "Set the API style to {} or {}",
+                    ApiStyle::Both.display_name(),
+                    ApiStyle::Rest.display_name()
+                ),
+            )
+        })
+}
+
+/// A field's `DEFAULT` clause must be something the database accepts.
+fn check_default(entity: &Entity, field: &Field, db: DatabaseType) -> Option<Incompatibility> {
+    match field.default_value.as_ref()? {
+        DefaultValue::Expression(expr) => check_expression_default(entity, field, expr, db),
+        DefaultValue::String(_) | DefaultValue::EmptyArray | DefaultValue::EmptyObject
+            if db == DatabaseType::MySQL && is_mysql_blob_column(&field.data_type) =>
+        {
+            Some(Incompatibility::field(
+                entity,
+                field,
+                format!(
+                    "Field '{}.{}' has a literal default, but MySQL does not allow one on {} columns.",
+                    entity.name,
+                    field.name,
+                    field.data_type.display_name(),
+                ),
+                "Remove the default and set the value in the create handler, or use an SQL expression default in parentheses, e.g. ('[]') (MySQL 8.0.13+)",
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn check_expression_default(
+    entity: &Entity,
+    field: &Field,
+    expr: &str,
+    db: DatabaseType,
+) -> Option<Incompatibility> {
+    let (function, supported) = expression_functions(expr)
+        .into_iter()
+        .find_map(|name| {
+            DIALECT_FUNCTIONS
+                .iter()
+                .find(|(f, dbs)| *f == name && !dbs.contains(&db))
+                .map(|(f, dbs)| (format!("{}()", f), *dbs))
+        })
+        .or_else(|| {
+            (expr.contains("::") && db != DatabaseType::PostgreSQL)
+                .then(|| ("a '::' cast".to_string(), &[DatabaseType::PostgreSQL][..]))
+        })?;
+
+    let suggestion = match function.as_str() {
+        "gen_random_uuid()" | "uuid_generate_v4()" | "uuid()" | "randomblob()" => {
+            "Use the 'Generate UUID' default instead, which picks the right function for each database"
+                .to_string()
+        }
+        "now()" => "Use the 'Current Timestamp' default instead".to_string(),
+        _ => format!(
+            "Rewrite the expression for {}, or switch the database to {}",
+            db.display_name(),
+            supported
+                .iter()
+                .map(|d| d.display_name())
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ),
+    };
+
+    Some(Incompatibility::field(
+        entity,
+        field,
+        format!(
+            "Field '{}.{}' defaults to '{}', which uses {} — not available on {}.",
+            entity.name,
+            field.name,
+            expr,
+            function,
+            db.display_name(),
+        ),
+        suggestion,
+    ))
+}
+
+/// SQL functions that only some databases provide, by lowercase name
+const DIALECT_FUNCTIONS: &[(&str, &[DatabaseType])] = &[
+    ("gen_random_uuid", &[DatabaseType::PostgreSQL]),
+    ("uuid_generate_v4", &[DatabaseType::PostgreSQL]),
+    ("jsonb_build_object", &[DatabaseType::PostgreSQL]),
+    ("jsonb_build_array", &[DatabaseType::PostgreSQL]),
+    ("uuid", &[DatabaseType::MySQL]),
+    ("uuid_to_bin", &[DatabaseType::MySQL]),
+    ("curdate", &[DatabaseType::MySQL]),
+    ("utc_timestamp", &[DatabaseType::MySQL]),
+    ("randomblob", &[DatabaseType::SQLite]),
+    ("julianday", &[DatabaseType::SQLite]),
+    ("strftime", &[DatabaseType::SQLite]),
+    ("now", &[DatabaseType::PostgreSQL, DatabaseType::MySQL]),
+];
+
+/// Lowercase names of the functions an SQL expression calls, skipping
+/// string literals
+fn expression_functions(expr: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut ident = String::new();
+    // Whitespace after an identifier still allows `name (…)`
+    let mut after_space = false;
+    let mut in_string = false;
+    for c in expr.chars() {
+        if in_string {
+            in_string = c != '\'';
+            continue;
+        }
+        match c {
+            '\'' => {
+                in_string = true;
+                ident.clear();
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' => {
+                if after_space {
+                    ident.clear();
+                    after_space = false;
+                }
+                ident.push(c);
+            }
+            c if c.is_whitespace() => after_space = true,
+            c => {
+                if c == '(' && !ident.is_empty() {
+                    names.push(ident.to_ascii_lowercase());
+                }
+                ident.clear();
+                after_space = false;
+            }
+        }
+    }
+    names
+}
+
+/// Columns MySQL stores as `LONGTEXT`, `JSON` or `BLOB`
+fn is_mysql_blob_column(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Text | DataType::Json | DataType::Bytes | DataType::Array(_) => true,
+        DataType::Optional(inner) => is_mysql_blob_column(inner),
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_with_default(
+        db: DatabaseType,
+        data_type: DataType,
+        default: DefaultValue,
+    ) -> ProjectGraph {
+        let mut project = ProjectGraph::new("compat");
+        project.config.database = db;
+        let mut entity = Entity::new("Post");
+        entity
+            .fields
+            .push(Field::new("body", data_type).with_default(default));
+        project.add_entity(entity);
+        project
+    }
+
+    fn problems(project: &ProjectGraph) -> Vec<Incompatibility> {
+        check_compatibility(project, &project.config)
+    }
+
+    #[test]
+    fn test_fullstack_needs_rest_routes() {
+        let mut config = ProjectConfig::fullstack().with_api_style(ApiStyle::Graphql);
+        let project = ProjectGraph::new("app");
+        let found = check_compatibility(&project, &config);
+        assert_eq!(found.len(), 1);
+        assert!(found[0].suggestion.contains(ApiStyle::Both.display_name()));
+        assert!(found[0].entity_id.is_none());
+
+        config.api_style = ApiStyle::Both;
+        assert!(check_compatibility(&project, &config).is_empty());
+
+        let rest_api = ProjectConfig::rest_api().with_api_style(ApiStyle::Graphql);
+        assert!(check_compatibility(&project, &rest_api).is_empty());
+    }
+
+    #[test]
+    fn test_expression_defaults_follow_the_database() {
+        let uuid = DefaultValue::Expression("gen_random_uuid()".to_string());
+        let project = project_with_default(DatabaseType::PostgreSQL, DataType::Uuid, uuid.clone());
+        assert!(problems(&project).is_empty());
+
+        let project = project_with_default(DatabaseType::MySQL, DataType::Uuid, uuid);
+        let found = problems(&project);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].path, "entities.Post.fields.body");
+        assert!(found[0].message.contains("gen_random_uuid()"));
+        assert!(found[0].message.contains("MySQL"));
+        assert!(found[0].suggestion.contains("Generate UUID"));
+        assert!(found[0].field_id.is_some());
+
+        let now = DefaultValue::Expression("NOW()".to_string());
+        assert!(
+            problems(&project_with_default(
+                DatabaseType::MySQL,
+                DataType::DateTime,
+                now.clone()
+            ))
+            .is_empty()
+        );
+        let found = problems(&project_with_default(
+            DatabaseType::SQLite,
+            DataType::DateTime,
+            now,
+        ));
+        assert!(found[0].suggestion.contains("Current Timestamp"));
+
+        let cast = DefaultValue::Expression("'{}'::jsonb".to_string());
+        let found = problems(&project_with_default(
+            DatabaseType::SQLite,
+            DataType::Json,
+            cast,
+        ));
+        assert!(found[0].message.contains("'::' cast"));
+        assert!(found[0].suggestion.contains("PostgreSQL"));
+
+        // Function names inside string literals are not calls
+        let literal = DefaultValue::Expression("'uuid()'".to_string());
+        assert!(
+            problems(&project_with_default(
+                DatabaseType::SQLite,
+                DataType::String,
+                literal
+            ))
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_mysql_rejects_literal_defaults_on_blob_columns() {
+        let found = problems(&project_with_default(
+            DatabaseType::MySQL,
+            DataType::Json,
+            DefaultValue::EmptyObject,
+        ));
+        assert_eq!(found.len(), 1);
+        assert!(found[0].message.contains("literal default"));
+
+        assert!(
+            problems(&project_with_default(
+                DatabaseType::MySQL,
+                DataType::String,
+                DefaultValue::String("draft".to_string()),
+            ))
+            .is_empty()
+        );
+        assert!(
+            problems(&project_with_default(
+                DatabaseType::PostgreSQL,
+                DataType::Json,
+                DefaultValue::EmptyObject,
+            ))
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_expression_functions() {
+        assert_eq!(
+            expression_functions("gen_random_uuid()"),
+            vec!["gen_random_uuid"]
+        );
+        assert_eq!(
+            expression_functions("COALESCE(NOW (), strftime('%s', 'now'))"),
+            vec!["coalesce", "now", "strftime"]
+        );
+        assert!(expression_functions("'now()'").is_empty());
+        assert!(expression_functions("42").is_empty());
+    }
+}
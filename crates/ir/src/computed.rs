@@ -0,0 +1,440 @@
+//! Computed (derived) fields
+//!
+//! A field with a [`ComputedField`] is derived from other fields of the same
+//! entity instead of being written by clients, e.g.
+//! `full_name = first_name || ' ' || last_name` or
+//! `total = quantity * unit_price`. The value is either stored by the
+//! database as a generated column or calculated by a getter on the
+//! generated model.
+//!
+//! Expressions use a small SQL-like language: field names, `'quoted'` text,
+//! numbers, parentheses, and either `||` (concatenation) or `+ - * /`
+//! (arithmetic) — the two kinds of operator can't be mixed in one
+//! expression, which keeps the translation to SQL and Rust unambiguous.
+
+use imortal_core::{DataType, EngineError, EngineResult, Validatable};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// ComputedField
+// ============================================================================
+
+/// How a field's value is derived from the other fields of its entity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputedField {
+    /// Expression over the entity's fields (e.g., `first_name || ' ' || last_name`)
+    pub expression: String,
+
+    /// Where the value is computed
+    #[serde(default)]
+    pub storage: ComputedStorage,
+}
+
+impl ComputedField {
+    /// A generated column computed and stored by the database
+    pub fn generated(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            storage: ComputedStorage::Generated,
+        }
+    }
+
+    /// A getter on the generated model; nothing is stored
+    pub fn getter(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            storage: ComputedStorage::Getter,
+        }
+    }
+
+    /// Whether the value lives in a database column
+    pub fn is_stored(&self) -> bool {
+        self.storage == ComputedStorage::Generated
+    }
+
+    /// Split the expression into tokens
+    pub fn tokens(&self) -> EngineResult<Vec<ComputedToken>> {
+        tokenize(&self.expression)
+    }
+
+    /// Names of the fields the expression reads, in order of first use
+    pub fn references(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for token in self.tokens().unwrap_or_default() {
+            if let ComputedToken::Field(name) = token
+                && !names.contains(&name)
+            {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Whether the expression concatenates text (`||`) rather than doing
+    /// arithmetic
+    pub fn is_concat(&self) -> bool {
+        self.tokens()
+            .unwrap_or_default()
+            .contains(&ComputedToken::Op(ComputedOp::Concat))
+    }
+
+    /// Check the expression fits the type of the field it computes
+    pub fn check_result_type(&self, data_type: &DataType) -> EngineResult<()> {
+        let inner = match data_type {
+            DataType::Optional(inner) => inner.as_ref(),
+            other => other,
+        };
+        if self.is_concat() {
+            if !matches!(inner, DataType::String | DataType::Text) {
+                return Err(EngineError::validation(
+                    "A '||' expression produces text; make the field String or Text",
+                ));
+            }
+        } else if !is_numeric(inner) && !self.is_single_operand() {
+            return Err(EngineError::validation(
+                "An arithmetic expression needs a numeric field type",
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_single_operand(&self) -> bool {
+        self.tokens()
+            .is_ok_and(|tokens| !tokens.iter().any(|t| matches!(t, ComputedToken::Op(_))))
+    }
+}
+
+impl Validatable for ComputedField {
+    fn validate(&self) -> EngineResult<()> {
+        let tokens = self.tokens()?;
+        if tokens.is_empty() {
+            return Err(EngineError::validation("Computed expression is empty"));
+        }
+
+        // Operands and operators must alternate, parentheses must balance
+        let mut depth = 0usize;
+        let mut expect_operand = true;
+        let mut kind: Option<bool> = None;
+        for token in &tokens {
+            match token {
+                ComputedToken::LParen if expect_operand => depth += 1,
+                ComputedToken::RParen if !expect_operand && depth > 0 => depth -= 1,
+                ComputedToken::Field(_) | ComputedToken::Text(_) | ComputedToken::Number(_)
+                    if expect_operand =>
+                {
+                    expect_operand = false
+                }
+                ComputedToken::Op(op) if !expect_operand => {
+                    let concat = *op == ComputedOp::Concat;
+                    if kind.is_some_and(|k| k != concat) {
+                        return Err(EngineError::validation(
+                            "Computed expression mixes '||' with arithmetic operators",
+                        ));
+                    }
+                    kind = Some(concat);
+                    expect_operand = true;
+                }
+                other => {
+                    return Err(EngineError::validation(format!(
+                        "Unexpected '{}' in computed expression '{}'",
+                        other, self.expression
+                    )));
+                }
+            }
+        }
+        if expect_operand || depth > 0 {
+            return Err(EngineError::validation(format!(
+                "Computed expression '{}' is incomplete",
+                self.expression
+            )));
+        }
+        if kind == Some(false) && tokens.iter().any(|t| matches!(t, ComputedToken::Text(_))) {
+            return Err(EngineError::validation(
+                "Text literals can only be joined with '||'",
+            ));
+        }
+        if kind == Some(true) && tokens.contains(&ComputedToken::LParen) {
+            return Err(EngineError::validation(
+                "Parentheses are not needed in a '||' expression",
+            ));
+        }
+        if self.references().is_empty() {
+            return Err(EngineError::validation(
+                "Computed expression must refer to at least one field",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Where a computed field's value is produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComputedStorage {
+    /// `GENERATED ALWAYS AS (…) STORED` column; filterable and sortable
+    #[default]
+    Generated,
+    /// Method on the generated model; not a column, computed on every read
+    Getter,
+}
+
+impl ComputedStorage {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ComputedStorage::Generated => "Generated column",
+            ComputedStorage::Getter => "Model getter",
+        }
+    }
+
+    /// Get all storage options
+    pub fn all() -> &'static [ComputedStorage] {
+        &[ComputedStorage::Generated, ComputedStorage::Getter]
+    }
+}
+
+// ============================================================================
+// Tokens
+// ============================================================================
+
+/// A token of a computed expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComputedToken {
+    /// A field of the entity
+    Field(String),
+    /// A `'quoted'` text literal (unescaped)
+    Text(String),
+    /// A numeric literal, as written
+    Number(String),
+    /// An operator
+    Op(ComputedOp),
+    /// `(`
+    LParen,
+    /// `)`
+    RParen,
+}
+
+impl std::fmt::Display for ComputedToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComputedToken::Field(name) => write!(f, "{}", name),
+            ComputedToken::Text(text) => write!(f, "'{}'", text.replace('\'', "''")),
+            ComputedToken::Number(n) => write!(f, "{}", n),
+            ComputedToken::Op(op) => write!(f, "{}", op.symbol()),
+            ComputedToken::LParen => write!(f, "("),
+            ComputedToken::RParen => write!(f, ")"),
+        }
+    }
+}
+
+/// Operator of a computed expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComputedOp {
+    /// `||`
+    Concat,
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+}
+
+impl ComputedOp {
+    /// The operator as written in SQL and Rust (`||` only in SQL)
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ComputedOp::Concat => "||",
+            ComputedOp::Add => "+",
+            ComputedOp::Sub => "-",
+            ComputedOp::Mul => "*",
+            ComputedOp::Div => "/",
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> EngineResult<Vec<ComputedToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(ComputedToken::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(ComputedToken::RParen);
+            }
+            '+' | '-' | '*' | '/' => {
+                chars.next();
+                tokens.push(ComputedToken::Op(match c {
+                    '+' => ComputedOp::Add,
+                    '-' => ComputedOp::Sub,
+                    '*' => ComputedOp::Mul,
+                    _ => ComputedOp::Div,
+                }));
+            }
+            '|' => {
+                chars.next();
+                if chars.next() != Some('|') {
+                    return Err(EngineError::validation(
+                        "Use '||' to join text in a computed expression",
+                    ));
+                }
+                tokens.push(ComputedToken::Op(ComputedOp::Concat));
+            }
+            '\'' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            text.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(c) => text.push(c),
+                        None => {
+                            return Err(EngineError::validation(
+                                "Unterminated text literal in computed expression",
+                            ));
+                        }
+                    }
+                }
+                tokens.push(ComputedToken::Text(text));
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() || c == '.' {
+                        number.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if number.matches('.').count() > 1 || number.ends_with('.') {
+                    return Err(EngineError::validation(format!(
+                        "Invalid number '{}' in computed expression",
+                        number
+                    )));
+                }
+                tokens.push(ComputedToken::Number(number));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        name.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(ComputedToken::Field(name));
+            }
+            other => {
+                return Err(EngineError::validation(format!(
+                    "Unexpected '{}' in computed expression",
+                    other
+                )));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal { .. }
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_and_references() {
+        let computed = ComputedField::generated("first_name || ' ' || last_name");
+        assert_eq!(
+            computed.tokens().unwrap(),
+            vec![
+                ComputedToken::Field("first_name".into()),
+                ComputedToken::Op(ComputedOp::Concat),
+                ComputedToken::Text(" ".into()),
+                ComputedToken::Op(ComputedOp::Concat),
+                ComputedToken::Field("last_name".into()),
+            ]
+        );
+        assert_eq!(computed.references(), vec!["first_name", "last_name"]);
+        assert!(computed.is_concat());
+        assert!(computed.validate().is_ok());
+
+        let total = ComputedField::getter("(quantity * unit_price) - discount / 2.5");
+        assert!(!total.is_concat());
+        assert!(total.validate().is_ok());
+        assert_eq!(
+            total.references(),
+            vec!["quantity", "unit_price", "discount"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "",
+            "first_name ||",
+            "a || b + c",
+            "(a + b",
+            "a b",
+            "'it''s' || 'x",
+            "a | b",
+            "price * 'x'",
+            "1 + 2",
+            "a; DROP TABLE users",
+        ] {
+            assert!(
+                ComputedField::generated(expression).validate().is_err(),
+                "{expression}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_result_type() {
+        let concat = ComputedField::generated("a || b");
+        assert!(concat.check_result_type(&DataType::String).is_ok());
+        assert!(concat.check_result_type(&DataType::Int32).is_err());
+
+        let sum = ComputedField::generated("a + b");
+        assert!(
+            sum.check_result_type(&DataType::Optional(Box::new(DataType::Float64)))
+                .is_ok()
+        );
+        assert!(sum.check_result_type(&DataType::String).is_err());
+        assert!(
+            ComputedField::generated("a")
+                .check_result_type(&DataType::String)
+                .is_ok()
+        );
+    }
+}
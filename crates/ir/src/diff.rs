@@ -0,0 +1,878 @@
+//! # Project Diff
+//!
+//! Compares two versions of a project and lists what was added, removed or
+//! modified, for reviewing schema changes stored in version control.
+//!
+//! Entities, fields, relationships and endpoints are matched by ID first, so a
+//! rename shows up as a modification rather than a remove/add pair. Anything
+//! left unmatched is then paired by name, which lets two projects that were
+//! created independently (and so share no IDs) still be compared sensibly.
+//!
+//! Only schema and API settings are compared. Canvas layout, selection state
+//! and modification timestamps change on nearly every save and are ignored.
+//! Field sets are compared by their effect: each is expanded into the
+//! entities including it first, so a field added to a set shows up on each
+//! of them.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::diff::{ChangeKind, diff_projects};
+//! use imortal_ir::{DataType, Entity, Field, ProjectGraph};
+//!
+//! let mut old = ProjectGraph::new("blog");
+//! let post = Entity::new("Post");
+//! let post_id = post.id;
+//! old.add_entity(post);
+//!
+//! let mut new = old.clone();
+//! new.get_entity_mut(post_id)
+//!     .unwrap()
+//!     .add_field(Field::new("title", DataType::String));
+//!
+//! let diff = diff_projects(&old, &new);
+//! assert_eq!(diff.count(ChangeKind::Added), 1);
+//! assert_eq!(diff.to_string(), "+ field Post.title\n");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+
+use uuid::Uuid;
+
+use crate::{
+    CrudOperation, EndpointGroup, EndpointSecurity, Entity, Field, ProjectGraph, Relationship,
+};
+
+// ============================================================================
+// Change
+// ============================================================================
+
+/// Whether an item was added, removed or modified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// Present only in the new project
+    Added,
+    /// Present only in the old project
+    Removed,
+    /// Present in both, with different settings
+    Modified,
+}
+
+impl ChangeKind {
+    /// Prefix used in the textual diff
+    pub fn symbol(&self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Modified => '~',
+        }
+    }
+}
+
+/// The kind of model element a change concerns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// An entity
+    Entity,
+    /// A field of an entity
+    Field,
+    /// A relationship between entities
+    Relationship,
+    /// An endpoint group
+    Endpoint,
+}
+
+impl ItemKind {
+    /// Lowercase label used in the textual diff
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemKind::Entity => "entity",
+            ItemKind::Field => "field",
+            ItemKind::Relationship => "relationship",
+            ItemKind::Endpoint => "endpoint",
+        }
+    }
+}
+
+/// A single difference between two projects
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Added, removed or modified
+    pub kind: ChangeKind,
+    /// What was changed
+    pub item: ItemKind,
+    /// Display name (`Entity.field` for fields), as of the new project when
+    /// the item exists there
+    pub name: String,
+    /// For modifications, one `setting: old → new` entry per changed setting
+    pub details: Vec<String>,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, item: ItemKind, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            item,
+            name: name.into(),
+            details: Vec::new(),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.kind.symbol(),
+            self.item.label(),
+            self.name
+        )?;
+        for detail in &self.details {
+            write!(f, "\n    {}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ProjectDiff
+// ============================================================================
+
+/// All differences between two projects, grouped by item kind
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectDiff {
+    /// Changes in report order: entities and their fields, then
+    /// relationships, then endpoints
+    pub changes: Vec<Change>,
+}
+
+impl ProjectDiff {
+    /// Whether the projects are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes of the given kind
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.kind == kind).count()
+    }
+
+    /// Changes concerning the given kind of item
+    pub fn changes_for(&self, item: ItemKind) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(move |c| c.item == item)
+    }
+
+    /// One-line summary, e.g. `2 added, 1 removed, 3 modified`
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "No differences".to_string();
+        }
+        format!(
+            "{} added, {} removed, {} modified",
+            self.count(ChangeKind::Added),
+            self.count(ChangeKind::Removed),
+            self.count(ChangeKind::Modified)
+        )
+    }
+}
+
+impl Display for ProjectDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Diffing
+// ============================================================================
+
+/// Compare two projects
+pub fn diff_projects(old: &ProjectGraph, new: &ProjectGraph) -> ProjectDiff {
+    let (old, new) = (&old.expand_field_sets(), &new.expand_field_sets());
+    let mut changes = Vec::new();
+    diff_entities(old, new, &mut changes);
+    diff_relationships(old, new, &mut changes);
+    diff_endpoints(old, new, &mut changes);
+    ProjectDiff { changes }
+}
+
+/// Items from two versions, paired by ID and then by key.
+struct Matched<'a, T> {
+    pairs: Vec<(&'a T, &'a T)>,
+    removed: Vec<&'a T>,
+    added: Vec<&'a T>,
+}
+
+fn match_items<'a, T>(
+    old: Vec<&'a T>,
+    new: Vec<&'a T>,
+    id: impl Fn(&T) -> Uuid,
+    old_key: impl Fn(&T) -> String,
+    new_key: impl Fn(&T) -> String,
+) -> Matched<'a, T> {
+    let mut pairs = Vec::new();
+    let mut unmatched_new: Vec<&T> = Vec::new();
+    let mut used = HashSet::new();
+
+    for item in &new {
+        match old.iter().position(|o| id(o) == id(item)) {
+            Some(i) => {
+                used.insert(i);
+                pairs.push((old[i], *item));
+            }
+            None => unmatched_new.push(*item),
+        }
+    }
+
+    let mut added = Vec::new();
+    for item in unmatched_new {
+        let key = new_key(item);
+        match (0..old.len()).find(|i| !used.contains(i) && old_key(old[*i]) == key) {
+            Some(i) => {
+                used.insert(i);
+                pairs.push((old[i], item));
+            }
+            None => added.push(item),
+        }
+    }
+
+    let removed = (0..old.len())
+        .filter(|i| !used.contains(i))
+        .map(|i| old[i])
+        .collect();
+
+    Matched {
+        pairs,
+        removed,
+        added,
+    }
+}
+
+/// Collects `setting: old → new` lines for a modified item
+#[derive(Default)]
+struct Details(Vec<String>);
+
+impl Details {
+    fn compare<T: PartialEq + Display + Debug>(&mut self, label: &str, old: T, new: T) {
+        if old == new {
+            return;
+        }
+        let (old_text, new_text) = (old.to_string(), new.to_string());
+        if old_text == new_text {
+            // The display form hides the difference (e.g. enum variants)
+            self.0.push(format!("{}: {:?} → {:?}", label, old, new));
+        } else {
+            self.0
+                .push(format!("{}: {} → {}", label, old_text, new_text));
+        }
+    }
+
+    fn compare_opt<T: PartialEq + Display + Debug>(
+        &mut self,
+        label: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) {
+        self.compare(label, OptDisplay(old), OptDisplay(new));
+    }
+}
+
+/// Displays `none` for a missing value
+#[derive(Debug, PartialEq)]
+struct OptDisplay<T>(Option<T>);
+
+impl<T: Display> Display for OptDisplay<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(value) => write!(f, "{}", value),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+/// `(a, b), (c)` for a list of constraints, or `none`
+fn field_lists(lists: impl Iterator<Item = String>) -> String {
+    let lists: Vec<String> = lists.map(|l| format!("({})", l)).collect();
+    if lists.is_empty() {
+        "none".to_string()
+    } else {
+        lists.join(", ")
+    }
+}
+
+fn entity_name(project: &ProjectGraph, id: Uuid) -> String {
+    project
+        .get_entity(id)
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| "<missing>".to_string())
+}
+
+fn sorted_entities(project: &ProjectGraph) -> Vec<&Entity> {
+    let mut entities: Vec<&Entity> = project.entities.values().collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name));
+    entities
+}
+
+fn diff_entities(old: &ProjectGraph, new: &ProjectGraph, changes: &mut Vec<Change>) {
+    let matched = match_items(
+        sorted_entities(old),
+        sorted_entities(new),
+        |e| e.id,
+        |e| e.name.to_lowercase(),
+        |e| e.name.to_lowercase(),
+    );
+
+    for (before, after) in matched.pairs {
+        let mut details = Details::default();
+        details.compare("name", &before.name, &after.name);
+        details.compare("table", &before.table_name, &after.table_name);
+        details.compare_opt(
+            "description",
+            before.description.as_ref(),
+            after.description.as_ref(),
+        );
+        details.compare(
+            "timestamps",
+            before.config.timestamps,
+            after.config.timestamps,
+        );
+        details.compare(
+            "soft delete",
+            before.config.soft_delete,
+            after.config.soft_delete,
+        );
+        details.compare("id type", before.config.id_type, after.config.id_type);
+        details.compare("auditable", before.config.auditable, after.config.auditable);
+        details.compare(
+            "generate api",
+            before.config.generate_api,
+            after.config.generate_api,
+        );
+        details.compare_opt(
+            "schema",
+            before.config.schema.as_ref(),
+            after.config.schema.as_ref(),
+        );
+        details.compare_opt(
+            "tablespace",
+            before.config.tablespace.as_ref(),
+            after.config.tablespace.as_ref(),
+        );
+        details.compare_opt(
+            "engine",
+            before.config.engine.as_ref(),
+            after.config.engine.as_ref(),
+        );
+        details.compare_opt(
+            "charset",
+            before.config.charset.as_ref(),
+            after.config.charset.as_ref(),
+        );
+        details.compare_opt(
+            "collation",
+            before.config.collation.as_ref(),
+            after.config.collation.as_ref(),
+        );
+        details.compare(
+            "unique together",
+            field_lists(before.config.unique_together.iter().map(|f| f.join(", "))),
+            field_lists(after.config.unique_together.iter().map(|f| f.join(", "))),
+        );
+        details.compare(
+            "indexes",
+            field_lists(before.config.indexes.iter().map(ToString::to_string)),
+            field_lists(after.config.indexes.iter().map(ToString::to_string)),
+        );
+
+        if !details.0.is_empty() {
+            let mut change = Change::new(ChangeKind::Modified, ItemKind::Entity, &after.name);
+            change.details = details.0;
+            changes.push(change);
+        }
+        diff_fields(old, new, before, after, changes);
+    }
+
+    for entity in matched.removed {
+        changes.push(Change::new(
+            ChangeKind::Removed,
+            ItemKind::Entity,
+            &entity.name,
+        ));
+    }
+    for entity in matched.added {
+        changes.push(Change::new(
+            ChangeKind::Added,
+            ItemKind::Entity,
+            &entity.name,
+        ));
+        for field in &entity.fields {
+            changes.push(Change::new(
+                ChangeKind::Added,
+                ItemKind::Field,
+                format!("{}.{}", entity.name, field.name),
+            ));
+        }
+    }
+}
+
+fn diff_fields(
+    old: &ProjectGraph,
+    new: &ProjectGraph,
+    before: &Entity,
+    after: &Entity,
+    changes: &mut Vec<Change>,
+) {
+    let matched = match_items(
+        before.fields.iter().collect(),
+        after.fields.iter().collect(),
+        |f| f.id,
+        |f| f.name.clone(),
+        |f| f.name.clone(),
+    );
+    let qualified = |field: &Field| format!("{}.{}", after.name, field.name);
+
+    for (old_field, new_field) in matched.pairs {
+        let details = field_details(old, new, old_field, new_field);
+        if !details.is_empty() {
+            let mut change =
+                Change::new(ChangeKind::Modified, ItemKind::Field, qualified(new_field));
+            change.details = details;
+            changes.push(change);
+        }
+    }
+    for field in matched.removed {
+        changes.push(Change::new(
+            ChangeKind::Removed,
+            ItemKind::Field,
+            format!("{}.{}", after.name, field.name),
+        ));
+    }
+    for field in matched.added {
+        changes.push(Change::new(
+            ChangeKind::Added,
+            ItemKind::Field,
+            qualified(field),
+        ));
+    }
+}
+
+fn field_details(
+    old: &ProjectGraph,
+    new: &ProjectGraph,
+    before: &Field,
+    after: &Field,
+) -> Vec<String> {
+    let mut details = Details::default();
+    details.compare("name", &before.name, &after.name);
+    details.compare("column", &before.column_name, &after.column_name);
+    details.compare("type", &before.data_type, &after.data_type);
+    details.compare("required", before.required, after.required);
+    details.compare("unique", before.unique, after.unique);
+    details.compare("indexed", before.indexed, after.indexed);
+    details.compare_opt(
+        "default",
+        before.default_value.as_ref(),
+        after.default_value.as_ref(),
+    );
+    details.compare(
+        "validations",
+        join(&before.validations),
+        join(&after.validations),
+    );
+
+    let target = |project: &ProjectGraph, field: &Field| {
+        field.foreign_key_ref.as_ref().map(|fk| {
+            let name = project
+                .get_entity(fk.entity_id)
+                .map(|e| e.name.clone())
+                .unwrap_or_else(|| fk.entity_name.clone());
+            format!("{}.{}", name, fk.field_name)
+        })
+    };
+    details.compare_opt("references", target(old, before), target(new, after));
+    if let (Some(a), Some(b)) = (&before.foreign_key_ref, &after.foreign_key_ref) {
+        details.compare("on delete", a.on_delete, b.on_delete);
+        details.compare("on update", a.on_update, b.on_update);
+    }
+
+    details.compare("hidden", before.hidden, after.hidden);
+    details.compare("readonly", before.readonly, after.readonly);
+    details.compare("secret", before.secret, after.secret);
+    details.compare_opt(
+        "description",
+        before.description.as_ref(),
+        after.description.as_ref(),
+    );
+    details.0
+}
+
+fn join<T: Display>(items: &[T]) -> String {
+    if items.is_empty() {
+        return "none".to_string();
+    }
+    items
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `name (From → To)`, resolving entity names in the given project
+fn relationship_label(project: &ProjectGraph, rel: &Relationship) -> String {
+    format!(
+        "{} ({} → {})",
+        rel.name,
+        entity_name(project, rel.from_entity_id),
+        entity_name(project, rel.to_entity_id)
+    )
+}
+
+fn sorted_relationships(project: &ProjectGraph) -> Vec<&Relationship> {
+    let mut rels: Vec<&Relationship> = project.relationships.values().collect();
+    rels.sort_by_key(|r| relationship_label(project, r));
+    rels
+}
+
+fn diff_relationships(old: &ProjectGraph, new: &ProjectGraph, changes: &mut Vec<Change>) {
+    let matched = match_items(
+        sorted_relationships(old),
+        sorted_relationships(new),
+        |r| r.id,
+        |r| relationship_label(old, r),
+        |r| relationship_label(new, r),
+    );
+
+    for (before, after) in matched.pairs {
+        let mut details = Details::default();
+        details.compare("name", &before.name, &after.name);
+        details.compare(
+            "from",
+            entity_name(old, before.from_entity_id),
+            entity_name(new, after.from_entity_id),
+        );
+        details.compare(
+            "to",
+            entity_name(old, before.to_entity_id),
+            entity_name(new, after.to_entity_id),
+        );
+        details.compare("type", &before.relation_type, &after.relation_type);
+        details.compare("from field", &before.from_field, &after.from_field);
+        details.compare("to field", &before.to_field, &after.to_field);
+        details.compare_opt(
+            "accessor name",
+            before.accessor_name.as_ref(),
+            after.accessor_name.as_ref(),
+        );
+        details.compare_opt(
+            "inverse name",
+            before.inverse_name.as_ref(),
+            after.inverse_name.as_ref(),
+        );
+        details.compare("on delete", before.on_delete, after.on_delete);
+        details.compare("on update", before.on_update, after.on_update);
+        details.compare("required", before.required, after.required);
+
+        if !details.0.is_empty() {
+            let mut change = Change::new(
+                ChangeKind::Modified,
+                ItemKind::Relationship,
+                relationship_label(new, after),
+            );
+            change.details = details.0;
+            changes.push(change);
+        }
+    }
+    for rel in matched.removed {
+        changes.push(Change::new(
+            ChangeKind::Removed,
+            ItemKind::Relationship,
+            relationship_label(old, rel),
+        ));
+    }
+    for rel in matched.added {
+        changes.push(Change::new(
+            ChangeKind::Added,
+            ItemKind::Relationship,
+            relationship_label(new, rel),
+        ));
+    }
+}
+
+/// `Entity (/base/path)`, resolving the entity name in the given project
+fn endpoint_label(project: &ProjectGraph, endpoint: &EndpointGroup) -> String {
+    let name = project
+        .get_entity(endpoint.entity_id)
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| endpoint.entity_name.clone());
+    format!("{} ({})", name, endpoint.base_path)
+}
+
+/// Short description of who may call an endpoint
+fn access(security: &EndpointSecurity) -> String {
+    match (security.auth_required, security.roles.is_empty()) {
+        (false, _) => "public".to_string(),
+        (true, true) => "authenticated".to_string(),
+        (true, false) => format!("roles {}", security.roles.join("|")),
+    }
+}
+
+fn operation_details(before: &CrudOperation, after: &CrudOperation, details: &mut Details) {
+    let op = after.operation_type;
+    details.compare(&format!("{} enabled", op), before.enabled, after.enabled);
+    details.compare(
+        &format!("{} access", op),
+        before
+            .security
+            .as_ref()
+            .map_or("inherited".to_string(), access),
+        after
+            .security
+            .as_ref()
+            .map_or("inherited".to_string(), access),
+    );
+    let limit = |operation: &CrudOperation| {
+        operation
+            .rate_limit
+            .as_ref()
+            .map(|r| format!("{}/{}s", r.requests, r.window_seconds))
+    };
+    details.compare_opt(&format!("{} rate limit", op), limit(before), limit(after));
+}
+
+fn sorted_endpoints(project: &ProjectGraph) -> Vec<&EndpointGroup> {
+    let mut endpoints: Vec<&EndpointGroup> = project.endpoints.values().collect();
+    endpoints.sort_by_key(|e| endpoint_label(project, e));
+    endpoints
+}
+
+fn diff_endpoints(old: &ProjectGraph, new: &ProjectGraph, changes: &mut Vec<Change>) {
+    // Endpoint groups belong to one entity each, so the entity is the key
+    let entity_key = |project: &ProjectGraph, e: &EndpointGroup| {
+        project
+            .get_entity(e.entity_id)
+            .map(|entity| entity.name.to_lowercase())
+            .unwrap_or_else(|| e.entity_name.to_lowercase())
+    };
+    let matched = match_items(
+        sorted_endpoints(old),
+        sorted_endpoints(new),
+        |e| e.id,
+        |e| entity_key(old, e),
+        |e| entity_key(new, e),
+    );
+
+    for (before, after) in matched.pairs {
+        let mut details = Details::default();
+        details.compare("base path", &before.base_path, &after.base_path);
+        details.compare("enabled", before.enabled, after.enabled);
+        details.compare_opt(
+            "version",
+            before.api_version.as_ref(),
+            after.api_version.as_ref(),
+        );
+        details.compare(
+            "access",
+            access(&before.global_security),
+            access(&after.global_security),
+        );
+        for after_op in &after.operations {
+            match before.get_operation(after_op.operation_type) {
+                Some(before_op) => operation_details(before_op, after_op, &mut details),
+                None => details
+                    .0
+                    .push(format!("{}: added", after_op.operation_type)),
+            }
+        }
+        for before_op in &before.operations {
+            if after.get_operation(before_op.operation_type).is_none() {
+                details
+                    .0
+                    .push(format!("{}: removed", before_op.operation_type));
+            }
+        }
+        details.compare(
+            "middleware",
+            join(&before.middleware),
+            join(&after.middleware),
+        );
+
+        if !details.0.is_empty() {
+            let mut change = Change::new(
+                ChangeKind::Modified,
+                ItemKind::Endpoint,
+                endpoint_label(new, after),
+            );
+            change.details = details.0;
+            changes.push(change);
+        }
+    }
+    for endpoint in matched.removed {
+        changes.push(Change::new(
+            ChangeKind::Removed,
+            ItemKind::Endpoint,
+            endpoint_label(old, endpoint),
+        ));
+    }
+    for endpoint in matched.added {
+        changes.push(Change::new(
+            ChangeKind::Added,
+            ItemKind::Endpoint,
+            endpoint_label(new, endpoint),
+        ));
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OperationType, RateLimit};
+    use imortal_core::{DataType, ReferentialAction};
+
+    fn blog() -> (ProjectGraph, Uuid, Uuid) {
+        let mut project = ProjectGraph::new("blog");
+        let mut user = Entity::new("User");
+        user.add_field(Field::new("email", DataType::String).required());
+        let user_id = project.add_entity(user);
+        let post = Entity::new("Post");
+        let post_id = project.add_entity(post);
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        (project, user_id, post_id)
+    }
+
+    #[test]
+    fn test_identical_projects() {
+        let (project, _, _) = blog();
+        let mut moved = project.clone();
+        for entity in moved.entities.values_mut() {
+            entity.translate(50.0, 10.0);
+        }
+
+        let diff = diff_projects(&project, &moved);
+        assert!(diff.is_empty(), "{}", diff);
+        assert_eq!(diff.summary(), "No differences");
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let (old, _, post_id) = blog();
+        let mut new = old.clone();
+        new.remove_entity(post_id);
+        new.add_entity(Entity::new("Tag"));
+
+        let diff = diff_projects(&old, &new);
+        let lines: Vec<String> = diff.changes.iter().map(|c| c.to_string()).collect();
+        assert!(lines.contains(&"- entity Post".to_string()));
+        assert!(lines.contains(&"+ entity Tag".to_string()));
+        assert!(lines.contains(&"+ field Tag.id".to_string()));
+        assert!(
+            diff.changes_for(ItemKind::Relationship)
+                .all(|c| c.kind == ChangeKind::Removed && c.name.ends_with("(User → Post)"))
+        );
+        assert_eq!(diff.count(ChangeKind::Modified), 0);
+    }
+
+    #[test]
+    fn test_modified_field_details() {
+        let (old, user_id, _) = blog();
+        let mut new = old.clone();
+        let email = new
+            .get_entity_mut(user_id)
+            .unwrap()
+            .get_field_by_name_mut("email")
+            .unwrap();
+        email.name = "email_address".to_string();
+        email.unique = true;
+
+        let diff = diff_projects(&old, &new);
+        assert_eq!(diff.changes.len(), 1);
+        let change = &diff.changes[0];
+        assert_eq!(change.kind, ChangeKind::Modified);
+        assert_eq!(change.name, "User.email_address");
+        assert_eq!(
+            change.details,
+            vec!["name: email → email_address", "unique: false → true"]
+        );
+    }
+
+    #[test]
+    fn test_entity_constraint_details() {
+        let (old, _, post_id) = blog();
+        let mut new = old.clone();
+        let config = &mut new.get_entity_mut(post_id).unwrap().config;
+        config
+            .unique_together
+            .push(vec!["user_id".into(), "id".into()]);
+        config
+            .indexes
+            .push(crate::EntityIndex::new(["user_id"]).with_type(crate::IndexType::Hash));
+        config.engine = Some("InnoDB".into());
+
+        let diff = diff_projects(&old, &new);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(
+            diff.changes[0].details,
+            vec![
+                "engine: none → InnoDB",
+                "unique together: none → (user_id, id)",
+                "indexes: none → (user_id using Hash)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relationship_and_endpoint_changes() {
+        let (old, _, _) = blog();
+        let mut new = old.clone();
+        for rel in new.relationships.values_mut() {
+            rel.on_delete = ReferentialAction::Cascade;
+        }
+        let endpoint = new.endpoints.values_mut().next().unwrap();
+        endpoint.disable_operation(OperationType::Delete);
+        endpoint
+            .get_operation_mut(OperationType::Create)
+            .unwrap()
+            .rate_limit = Some(RateLimit::new(10, 60));
+
+        let diff = diff_projects(&old, &new);
+        let rel = diff.changes_for(ItemKind::Relationship).next().unwrap();
+        assert_eq!(rel.details, vec!["on delete: RESTRICT → CASCADE"]);
+
+        let endpoint = diff.changes_for(ItemKind::Endpoint).next().unwrap();
+        assert_eq!(endpoint.name, "User (/api/users)");
+        assert!(
+            endpoint
+                .details
+                .contains(&"Delete enabled: true → false".to_string())
+        );
+        assert!(
+            endpoint
+                .details
+                .contains(&"Create rate limit: none → 10/60s".to_string())
+        );
+    }
+
+    #[test]
+    fn test_matches_by_name_without_shared_ids() {
+        let (old, _, _) = blog();
+        let (mut new, _, _) = blog();
+        let post_id = new.get_entity_by_name("Post").unwrap().id;
+        new.get_entity_mut(post_id)
+            .unwrap()
+            .add_field(Field::new("title", DataType::String));
+
+        let diff = diff_projects(&old, &new);
+        assert_eq!(diff.to_string(), "+ field Post.title\n");
+    }
+}
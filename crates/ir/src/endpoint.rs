@@ -49,6 +49,18 @@ pub struct EndpointGroup {
 
     /// Description for API documentation
     pub description: Option<String>,
+
+    /// Related-row counts and existence flags added to list responses
+    #[serde(default)]
+    pub related_aggregates: Vec<RelatedAggregate>,
+
+    /// Push create/update/delete events to live subscribers
+    #[serde(default)]
+    pub realtime: bool,
+
+    /// How REST clients receive those events
+    #[serde(default)]
+    pub realtime_transport: RealtimeTransport,
 }
 
 impl EndpointGroup {
@@ -70,6 +82,9 @@ impl EndpointGroup {
             middleware: Vec::new(),
             tags: vec![entity_name],
             description: None,
+            related_aggregates: Vec::new(),
+            realtime: false,
+            realtime_transport: RealtimeTransport::default(),
         }
     }
 
@@ -82,6 +97,12 @@ impl EndpointGroup {
     // Builder methods
     // ========================================================================
 
+    /// Derive the base path from a route style
+    pub fn with_route_style(mut self, style: &RouteStyle) -> Self {
+        self.base_path = style.base_path(&self.entity_name);
+        self
+    }
+
     /// Set the base path
     pub fn with_base_path(mut self, path: impl Into<String>) -> Self {
         self.base_path = path.into();
@@ -137,6 +158,33 @@ impl EndpointGroup {
         self
     }
 
+    /// Include the number of related rows in list responses
+    pub fn with_related_count(mut self, relationship_id: Uuid, field: impl Into<String>) -> Self {
+        self.related_aggregates
+            .push(RelatedAggregate::count(relationship_id, field));
+        self
+    }
+
+    /// Include whether any related rows exist in list responses
+    pub fn with_related_exists(mut self, relationship_id: Uuid, field: impl Into<String>) -> Self {
+        self.related_aggregates
+            .push(RelatedAggregate::exists(relationship_id, field));
+        self
+    }
+
+    /// Push change events for the entity to live subscribers
+    pub fn realtime(mut self) -> Self {
+        self.realtime = true;
+        self
+    }
+
+    /// Push change events for the entity to REST clients over `transport`
+    pub fn realtime_over(mut self, transport: RealtimeTransport) -> Self {
+        self.realtime = true;
+        self.realtime_transport = transport;
+        self
+    }
+
     /// Disable the endpoint group
     pub fn disabled(mut self) -> Self {
         self.enabled = false;
@@ -206,14 +254,20 @@ impl EndpointGroup {
 
     /// Get the full base path including API version
     pub fn full_base_path(&self) -> String {
-        match &self.api_version {
-            Some(version) => format!(
-                "/api/{}{}",
-                version,
-                self.base_path.trim_start_matches("/api")
-            ),
-            None => self.base_path.clone(),
-        }
+        self.route_path(&ApiVersioning::None)
+    }
+
+    /// Path the router mounts this group at under a versioning strategy
+    /// (e.g., "/api/v2/users"); groups on different versions of the same
+    /// base path get different route paths
+    pub fn route_path(&self, versioning: &ApiVersioning) -> String {
+        versioning.route_path(&self.base_path, self.api_version.as_deref())
+    }
+
+    /// Path clients call under a versioning strategy; without the version
+    /// segment when the version travels in a header
+    pub fn public_path(&self, versioning: &ApiVersioning) -> String {
+        versioning.public_path(&self.base_path, self.api_version.as_deref())
     }
 
     /// Get the effective security for an operation
@@ -313,6 +367,14 @@ pub struct CrudOperation {
 
     /// Whether to include in API documentation
     pub documented: bool,
+
+    /// Paging, sorting and filtering for list (ReadAll) operations
+    #[serde(default)]
+    pub list: ListOptions,
+
+    /// ETag / Last-Modified headers for read (Read, ReadAll) operations
+    #[serde(default)]
+    pub caching: HttpCaching,
 }
 
 impl CrudOperation {
@@ -337,6 +399,8 @@ impl CrudOperation {
             operation_id: None,
             success_status,
             documented: true,
+            list: ListOptions::default(),
+            caching: HttpCaching::default(),
         }
     }
 
@@ -409,6 +473,18 @@ impl CrudOperation {
         self
     }
 
+    /// Set the list options
+    pub fn with_list_options(mut self, list: ListOptions) -> Self {
+        self.list = list;
+        self
+    }
+
+    /// Set the HTTP caching headers
+    pub fn with_caching(mut self, caching: HttpCaching) -> Self {
+        self.caching = caching;
+        self
+    }
+
     // ========================================================================
     // Utility methods
     // ========================================================================
@@ -467,6 +543,17 @@ impl Validatable for CrudOperation {
             rate_limit.validate()?;
         }
 
+        if self.operation_type == OperationType::ReadAll {
+            self.list.validate()?;
+        }
+
+        if self.caching.is_enabled() && !self.operation_type.is_read() {
+            return Err(EngineError::validation(format!(
+                "Caching headers only apply to read operations, not {}",
+                self.operation_type
+            )));
+        }
+
         Ok(())
     }
 }
@@ -806,6 +893,619 @@ impl Default for RateLimit {
     }
 }
 
+// ============================================================================
+// ListOptions
+// ============================================================================
+
+/// How clients page, sort and filter a list endpoint
+///
+/// Page sizes apply to both offset (`per_page`) and cursor (`limit`)
+/// pagination; the pagination style itself is project-wide. Sorting uses
+/// `?sort=field` or `?sort=-field` (descending), comma-separated for several
+/// keys. Filters are generated per field from its type: equality for every
+/// filterable field, `{field}_contains` for text, and `{field}_min` /
+/// `{field}_max` for numbers and dates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListOptions {
+    /// Page size when the request doesn't give one
+    pub default_page_size: u64,
+
+    /// Largest page size a request may ask for
+    pub max_page_size: u64,
+
+    /// Whether clients may choose the order with `?sort=`
+    pub sorting: bool,
+
+    /// Order used when the request has no `?sort=` (e.g. "-created_at")
+    pub default_sort: Option<String>,
+
+    /// Whether per-field filter parameters are generated
+    pub filtering: bool,
+}
+
+impl ListOptions {
+    /// Set the default and maximum page sizes
+    pub fn with_page_sizes(mut self, default: u64, max: u64) -> Self {
+        self.default_page_size = default;
+        self.max_page_size = max;
+        self
+    }
+
+    /// Set the default sort
+    pub fn with_default_sort(mut self, sort: impl Into<String>) -> Self {
+        self.default_sort = Some(sort.into());
+        self
+    }
+
+    /// Don't let clients choose the order
+    pub fn without_sorting(mut self) -> Self {
+        self.sorting = false;
+        self
+    }
+
+    /// Don't generate filter parameters
+    pub fn without_filtering(mut self) -> Self {
+        self.filtering = false;
+        self
+    }
+
+    /// The default sort as `(field, descending)` keys
+    pub fn default_sort_keys(&self) -> Vec<(&str, bool)> {
+        parse_sort(self.default_sort.as_deref().unwrap_or_default())
+    }
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            default_page_size: 20,
+            max_page_size: 100,
+            sorting: true,
+            default_sort: None,
+            filtering: true,
+        }
+    }
+}
+
+impl Validatable for ListOptions {
+    fn validate(&self) -> EngineResult<()> {
+        if self.default_page_size == 0 || self.max_page_size == 0 {
+            return Err(EngineError::validation(
+                "List page sizes must be greater than 0",
+            ));
+        }
+        if self.default_page_size > self.max_page_size {
+            return Err(EngineError::validation(format!(
+                "Default page size {} exceeds the maximum of {}",
+                self.default_page_size, self.max_page_size
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Split a sort expression like `"-created_at,name"` into
+/// `(field, descending)` keys, skipping empty entries.
+pub fn parse_sort(sort: &str) -> Vec<(&str, bool)> {
+    sort.split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| match key.strip_prefix('-') {
+            Some(field) => (field.trim(), true),
+            None => (key.trim_start_matches('+').trim(), false),
+        })
+        .filter(|(field, _)| !field.is_empty())
+        .collect()
+}
+
+// ============================================================================
+// HttpCaching
+// ============================================================================
+
+/// Conditional-request headers a read endpoint sends
+///
+/// With an ETag or Last-Modified, a request whose `If-None-Match` or
+/// `If-Modified-Since` still matches gets `304 Not Modified` and no body.
+/// Only the client's cache is involved; nothing is cached on the server.
+/// For a list, the validators cover the rows of the returned page.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpCaching {
+    /// How the ETag is derived, if one is sent
+    pub etag: Option<ETagSource>,
+
+    /// Whether `Last-Modified` is sent, from the newest `updated_at`
+    pub last_modified: bool,
+}
+
+impl HttpCaching {
+    /// An ETag derived from `source`
+    pub fn etag(source: ETagSource) -> Self {
+        Self {
+            etag: Some(source),
+            last_modified: false,
+        }
+    }
+
+    /// Only a `Last-Modified` header
+    pub fn last_modified() -> Self {
+        Self {
+            etag: None,
+            last_modified: true,
+        }
+    }
+
+    /// Also send `Last-Modified`
+    pub fn with_last_modified(mut self) -> Self {
+        self.last_modified = true;
+        self
+    }
+
+    /// Whether any caching header is sent
+    pub fn is_enabled(&self) -> bool {
+        self.etag.is_some() || self.last_modified
+    }
+
+    /// Whether the headers are derived from `updated_at`, so the entity
+    /// needs timestamps
+    pub fn uses_updated_at(&self) -> bool {
+        self.last_modified || self.etag == Some(ETagSource::UpdatedAt)
+    }
+}
+
+/// What an ETag is derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ETagSource {
+    /// The key and `updated_at` of each returned row (a weak ETag); cheap,
+    /// but blind to changes that bypass the API
+    UpdatedAt,
+    /// A hash of the serialized response body (a strong ETag)
+    ContentHash,
+}
+
+impl ETagSource {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ETagSource::UpdatedAt => "Updated At",
+            ETagSource::ContentHash => "Content Hash",
+        }
+    }
+
+    /// Get all sources
+    pub fn all() -> &'static [ETagSource] {
+        &[ETagSource::UpdatedAt, ETagSource::ContentHash]
+    }
+}
+
+impl std::fmt::Display for ETagSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+// ============================================================================
+// RelatedAggregate
+// ============================================================================
+
+/// An aggregate over related rows, returned alongside each list item
+///
+/// The relationship must point *at* the endpoint's entity (the related entity
+/// holds the foreign key), e.g. `User ─1:N─▶ Post` gives `User` a
+/// `posts_count`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedAggregate {
+    /// Relationship linking the related rows to this entity
+    pub relationship_id: Uuid,
+
+    /// What to compute
+    pub kind: AggregateKind,
+
+    /// Name of the response field (e.g., "posts_count", "has_posts")
+    pub field: String,
+}
+
+impl RelatedAggregate {
+    /// Count related rows
+    pub fn count(relationship_id: Uuid, field: impl Into<String>) -> Self {
+        Self {
+            relationship_id,
+            kind: AggregateKind::Count,
+            field: field.into(),
+        }
+    }
+
+    /// Flag whether any related row exists
+    pub fn exists(relationship_id: Uuid, field: impl Into<String>) -> Self {
+        Self {
+            relationship_id,
+            kind: AggregateKind::Exists,
+            field: field.into(),
+        }
+    }
+}
+
+/// Kind of related-row aggregate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateKind {
+    /// Number of related rows (`u64`)
+    Count,
+    /// Whether at least one related row exists (`bool`)
+    Exists,
+}
+
+impl AggregateKind {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AggregateKind::Count => "Count",
+            AggregateKind::Exists => "Exists",
+        }
+    }
+
+    /// Suggested response field name for a related collection
+    /// (e.g., "posts" → "posts_count" / "has_posts")
+    pub fn default_field(&self, related: &str) -> String {
+        match self {
+            AggregateKind::Count => format!("{}_count", related),
+            AggregateKind::Exists => format!("has_{}", related),
+        }
+    }
+}
+
+// ============================================================================
+// RouteStyle
+// ============================================================================
+
+/// How resource paths are derived from entity names.
+///
+/// Used for every base path the project computes itself (new endpoint
+/// groups, entities without one, nested previews); a base path typed in by
+/// hand is left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RouteStyle {
+    /// Plural (`/api/users`) or singular (`/api/user`) resource segments
+    #[serde(default)]
+    pub plurality: PathPlurality,
+
+    /// Word separator used in path segments and parameters
+    #[serde(default)]
+    pub case: PathCase,
+
+    /// How a child collection is addressed under its parent
+    #[serde(default)]
+    pub nesting: NestedPathStyle,
+}
+
+impl RouteStyle {
+    /// Set the plurality
+    pub fn with_plurality(mut self, plurality: PathPlurality) -> Self {
+        self.plurality = plurality;
+        self
+    }
+
+    /// Set the case
+    pub fn with_case(mut self, case: PathCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set the nested-path format
+    pub fn with_nesting(mut self, nesting: NestedPathStyle) -> Self {
+        self.nesting = nesting;
+        self
+    }
+
+    /// Resource segment for an entity (e.g., "BlogPost" → "blog-posts")
+    pub fn segment(&self, entity_name: &str) -> String {
+        let snake = match self.plurality {
+            PathPlurality::Plural => to_snake_case_plural(entity_name),
+            PathPlurality::Singular => to_snake_case(entity_name),
+        };
+        self.case.apply(&snake)
+    }
+
+    /// Base path for an entity (e.g., "BlogPost" → "/api/blog_posts")
+    pub fn base_path(&self, entity_name: &str) -> String {
+        format!("/api/{}", self.segment(entity_name))
+    }
+
+    /// Path parameter naming an entity's id (e.g., "BlogPost" → "blog_post_id").
+    /// Kebab case keeps snake_case parameters since `-` is not valid in a
+    /// route parameter name.
+    pub fn id_param(&self, entity_name: &str) -> String {
+        let snake = format!("{}_id", to_snake_case(entity_name));
+        match self.case {
+            PathCase::Camel => PathCase::Camel.apply(&snake),
+            PathCase::Snake | PathCase::Kebab => snake,
+        }
+    }
+
+    /// Path to a parent's child collection, given the parent's base path;
+    /// `tail` is appended to the collection path (e.g., "" or "/count").
+    ///
+    /// Nested: `/api/users/:user_id/posts/count`;
+    /// flat: `/api/posts/count?user_id=:user_id`.
+    pub fn nested_path(
+        &self,
+        parent_base: &str,
+        parent_name: &str,
+        child_name: &str,
+        tail: &str,
+    ) -> String {
+        let param = self.id_param(parent_name);
+        match self.nesting {
+            NestedPathStyle::Nested => format!(
+                "{}/:{}/{}{}",
+                parent_base,
+                param,
+                self.segment(child_name),
+                tail
+            ),
+            NestedPathStyle::Flat => format!(
+                "{}{}?{}=:{}",
+                self.base_path(child_name),
+                tail,
+                param,
+                param
+            ),
+        }
+    }
+}
+
+/// Whether resource segments use the plural or singular entity name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathPlurality {
+    /// `/api/users`
+    #[default]
+    Plural,
+    /// `/api/user`
+    Singular,
+}
+
+impl PathPlurality {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PathPlurality::Plural => "Plural",
+            PathPlurality::Singular => "Singular",
+        }
+    }
+
+    /// Get all plurality options
+    pub fn all() -> &'static [PathPlurality] {
+        &[PathPlurality::Plural, PathPlurality::Singular]
+    }
+}
+
+/// Word separator used in generated path segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathCase {
+    /// `/api/blog_posts`
+    #[default]
+    Snake,
+    /// `/api/blog-posts`
+    Kebab,
+    /// `/api/blogPosts`
+    Camel,
+}
+
+impl PathCase {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PathCase::Snake => "snake_case",
+            PathCase::Kebab => "kebab-case",
+            PathCase::Camel => "camelCase",
+        }
+    }
+
+    /// Get all case options
+    pub fn all() -> &'static [PathCase] {
+        &[PathCase::Snake, PathCase::Kebab, PathCase::Camel]
+    }
+
+    /// Rewrite a snake_case word in this case
+    pub fn apply(&self, snake: &str) -> String {
+        match self {
+            PathCase::Snake => snake.to_string(),
+            PathCase::Kebab => snake.replace('_', "-"),
+            PathCase::Camel => snake
+                .split('_')
+                .filter(|part| !part.is_empty())
+                .enumerate()
+                .map(|(i, part)| {
+                    let mut chars = part.chars();
+                    match chars.next() {
+                        Some(first) if i > 0 => first.to_uppercase().chain(chars).collect(),
+                        _ => part.to_string(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// How a child collection is addressed relative to its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NestedPathStyle {
+    /// `/api/users/:user_id/posts`
+    #[default]
+    Nested,
+    /// `/api/posts?user_id=:user_id`
+    Flat,
+}
+
+impl NestedPathStyle {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            NestedPathStyle::Nested => "Nested",
+            NestedPathStyle::Flat => "Flat + filter",
+        }
+    }
+
+    /// Get all nesting options
+    pub fn all() -> &'static [NestedPathStyle] {
+        &[NestedPathStyle::Nested, NestedPathStyle::Flat]
+    }
+}
+
+/// Stream a realtime endpoint group's change events are served over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RealtimeTransport {
+    /// `GET {base}/ws`, upgraded to a WebSocket
+    #[default]
+    WebSocket,
+    /// `GET {base}/events`, a `text/event-stream`
+    Sse,
+}
+
+impl RealtimeTransport {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RealtimeTransport::WebSocket => "WebSocket",
+            RealtimeTransport::Sse => "Server-Sent Events",
+        }
+    }
+
+    /// Get all transport options
+    pub fn all() -> &'static [RealtimeTransport] {
+        &[RealtimeTransport::WebSocket, RealtimeTransport::Sse]
+    }
+
+    /// Path segment under the group's base path
+    pub fn path(&self) -> &'static str {
+        match self {
+            RealtimeTransport::WebSocket => "ws",
+            RealtimeTransport::Sse => "events",
+        }
+    }
+}
+
+// ============================================================================
+// ApiVersioning
+// ============================================================================
+
+/// How the generated API tells versions apart.
+///
+/// An endpoint group's own `api_version` takes precedence over the project
+/// default, so groups on different versions of the same resource (e.g.
+/// `/api/v1/users` next to `/api/v2/users`) coexist in one router.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum ApiVersioning {
+    /// No project-wide version; a group with its own `api_version` still
+    /// gets it as a path prefix
+    #[default]
+    None,
+    /// Version as a path segment: `/api/v1/users`
+    PathPrefix {
+        /// Version of groups that don't set one
+        default_version: String,
+    },
+    /// Version picked by a request header; paths stay `/api/users`
+    Header {
+        /// Header carrying the version (e.g. `X-API-Version`)
+        header: String,
+        /// Version of groups that don't set one, and of requests without
+        /// the header
+        default_version: String,
+    },
+}
+
+impl ApiVersioning {
+    /// Header used by [`ApiVersioning::header`] strategies created in the UI
+    pub const DEFAULT_HEADER: &'static str = "X-API-Version";
+
+    /// Version in the path, `default_version` for unversioned groups
+    pub fn path_prefix(default_version: impl Into<String>) -> Self {
+        ApiVersioning::PathPrefix {
+            default_version: default_version.into(),
+        }
+    }
+
+    /// Version in the `X-API-Version` header, `default_version` when absent
+    pub fn header(default_version: impl Into<String>) -> Self {
+        ApiVersioning::Header {
+            header: Self::DEFAULT_HEADER.to_string(),
+            default_version: default_version.into(),
+        }
+    }
+
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ApiVersioning::None => "None",
+            ApiVersioning::PathPrefix { .. } => "Path prefix",
+            ApiVersioning::Header { .. } => "Header",
+        }
+    }
+
+    /// Version of groups that don't set their own
+    pub fn default_version(&self) -> Option<&str> {
+        match self {
+            ApiVersioning::None => None,
+            ApiVersioning::PathPrefix { default_version }
+            | ApiVersioning::Header {
+                default_version, ..
+            } => Some(default_version),
+        }
+    }
+
+    /// Request header carrying the version, for the header strategy
+    pub fn header_name(&self) -> Option<&str> {
+        match self {
+            ApiVersioning::Header { header, .. } => Some(header),
+            _ => None,
+        }
+    }
+
+    /// Effective version of a group: its own, else the project default
+    pub fn resolve<'a>(&'a self, group_version: Option<&'a str>) -> Option<&'a str> {
+        group_version
+            .filter(|v| !v.is_empty())
+            .or(self.default_version())
+    }
+
+    /// Path a router mounts a base path at: the version goes after `/api`
+    /// (e.g., "/api/users" + "v2" → "/api/v2/users")
+    pub fn route_path(&self, base_path: &str, group_version: Option<&str>) -> String {
+        match self.resolve(group_version) {
+            Some(version) => format!("/api/{}{}", version, base_path.trim_start_matches("/api")),
+            None => base_path.to_string(),
+        }
+    }
+
+    /// Path clients call: the route path, except that the header strategy
+    /// leaves the version out (e.g., "/api/users")
+    pub fn public_path(&self, base_path: &str, group_version: Option<&str>) -> String {
+        match self {
+            ApiVersioning::Header { .. } => {
+                format!("/api{}", base_path.trim_start_matches("/api"))
+            }
+            _ => self.route_path(base_path, group_version),
+        }
+    }
+}
+
+/// Whether `version` can be used as a path segment and header value
+/// (letters, digits, `.`, `-` and `_`, e.g. "v2" or "2024-01")
+pub fn is_valid_api_version(version: &str) -> bool {
+    !version.is_empty()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -877,11 +1577,35 @@ mod tests {
         let group = EndpointGroup::new(Uuid::new_v4(), "BlogPost")
             .with_version("v1")
             .secured()
+            .realtime()
             .with_description("Blog post endpoints");
 
         assert_eq!(group.api_version, Some("v1".to_string()));
         assert!(group.global_security.auth_required);
+        assert!(group.realtime);
         assert!(group.description.is_some());
+
+        // Older project files have no realtime flag
+        let mut json = serde_json::to_value(&group).unwrap();
+        json.as_object_mut().unwrap().remove("realtime");
+        let restored: EndpointGroup = serde_json::from_value(json).unwrap();
+        assert!(!restored.realtime);
+    }
+
+    #[test]
+    fn test_realtime_transport() {
+        let group = EndpointGroup::new(Uuid::new_v4(), "Post");
+        assert_eq!(group.realtime_transport, RealtimeTransport::WebSocket);
+
+        let group = group.realtime_over(RealtimeTransport::Sse);
+        assert!(group.realtime);
+        assert_eq!(group.realtime_transport.path(), "events");
+
+        // Older project files stream over WebSockets
+        let mut json = serde_json::to_value(&group).unwrap();
+        json.as_object_mut().unwrap().remove("realtime_transport");
+        let restored: EndpointGroup = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.realtime_transport, RealtimeTransport::WebSocket);
     }
 
     #[test]
@@ -893,6 +1617,51 @@ mod tests {
         assert_eq!(group_no_version.full_base_path(), "/api/users");
     }
 
+    #[test]
+    fn test_api_versioning_paths() {
+        let v1 = EndpointGroup::new(Uuid::new_v4(), "User");
+        let v2 = EndpointGroup::new(Uuid::new_v4(), "User").with_version("v2");
+
+        let none = ApiVersioning::None;
+        assert_eq!(v1.route_path(&none), "/api/users");
+        assert_eq!(v2.route_path(&none), "/api/v2/users");
+
+        let path = ApiVersioning::path_prefix("v1");
+        assert_eq!(v1.route_path(&path), "/api/v1/users");
+        assert_eq!(v2.route_path(&path), "/api/v2/users");
+        assert_eq!(v2.public_path(&path), "/api/v2/users");
+
+        let header = ApiVersioning::header("v1");
+        assert_eq!(header.header_name(), Some("X-API-Version"));
+        assert_eq!(v1.route_path(&header), "/api/v1/users");
+        assert_eq!(v2.route_path(&header), "/api/v2/users");
+        assert_eq!(v1.public_path(&header), "/api/users");
+        assert_eq!(v2.public_path(&header), "/api/users");
+
+        let custom = EndpointGroup::new(Uuid::new_v4(), "User").with_base_path("/members");
+        assert_eq!(custom.route_path(&path), "/api/v1/members");
+        assert_eq!(custom.public_path(&header), "/api/members");
+    }
+
+    #[test]
+    fn test_api_versioning_serde() {
+        let json = serde_json::to_string(&ApiVersioning::header("v1")).unwrap();
+        assert_eq!(
+            json,
+            r#"{"strategy":"header","header":"X-API-Version","default_version":"v1"}"#
+        );
+        let back: ApiVersioning = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, ApiVersioning::header("v1"));
+
+        let none: ApiVersioning = serde_json::from_str(r#"{"strategy":"none"}"#).unwrap();
+        assert_eq!(none, ApiVersioning::None);
+
+        assert!(is_valid_api_version("v2"));
+        assert!(is_valid_api_version("2024-01"));
+        assert!(!is_valid_api_version("v2/../admin"));
+        assert!(!is_valid_api_version(""));
+    }
+
     #[test]
     fn test_endpoint_group_with_operations() {
         let group = EndpointGroup::new(Uuid::new_v4(), "User")
@@ -972,6 +1741,84 @@ mod tests {
         assert!((rate.requests_per_second() - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_related_aggregates() {
+        let rel_id = Uuid::new_v4();
+        let group = EndpointGroup::new(Uuid::new_v4(), "User")
+            .with_related_count(rel_id, AggregateKind::Count.default_field("posts"))
+            .with_related_exists(rel_id, AggregateKind::Exists.default_field("posts"));
+
+        assert_eq!(group.related_aggregates.len(), 2);
+        assert_eq!(group.related_aggregates[0].field, "posts_count");
+        assert_eq!(group.related_aggregates[1].kind, AggregateKind::Exists);
+        assert_eq!(group.related_aggregates[1].field, "has_posts");
+
+        // Older project files have no aggregates
+        let mut json = serde_json::to_value(&group).unwrap();
+        json.as_object_mut().unwrap().remove("related_aggregates");
+        let restored: EndpointGroup = serde_json::from_value(json).unwrap();
+        assert!(restored.related_aggregates.is_empty());
+    }
+
+    #[test]
+    fn test_list_options() {
+        let options = ListOptions::default().with_default_sort("-created_at, name,,+title");
+        assert_eq!(
+            options.default_sort_keys(),
+            vec![("created_at", true), ("name", false), ("title", false)]
+        );
+        assert!(options.validate().is_ok());
+        assert!(
+            ListOptions::default()
+                .with_page_sizes(50, 10)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            ListOptions::default()
+                .with_page_sizes(0, 10)
+                .validate()
+                .is_err()
+        );
+
+        let mut group = EndpointGroup::new(Uuid::new_v4(), "User");
+        group
+            .get_operation_mut(OperationType::ReadAll)
+            .unwrap()
+            .list
+            .max_page_size = 0;
+        assert!(group.validate().is_err());
+
+        // Older project files have no list options
+        let op = CrudOperation::new(OperationType::ReadAll);
+        let mut json = serde_json::to_value(&op).unwrap();
+        json.as_object_mut().unwrap().remove("list");
+        let restored: CrudOperation = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.list, ListOptions::default());
+    }
+
+    #[test]
+    fn test_http_caching() {
+        assert!(!HttpCaching::default().is_enabled());
+
+        let caching = HttpCaching::etag(ETagSource::ContentHash);
+        assert!(caching.is_enabled());
+        assert!(!caching.uses_updated_at());
+        assert!(caching.clone().with_last_modified().uses_updated_at());
+        assert!(HttpCaching::etag(ETagSource::UpdatedAt).uses_updated_at());
+
+        let read = CrudOperation::new(OperationType::Read).with_caching(caching.clone());
+        assert!(read.validate().is_ok());
+        let update = CrudOperation::new(OperationType::Update).with_caching(caching);
+        assert!(update.validate().is_err());
+
+        // Older project files have no caching options
+        let mut json = serde_json::to_value(&read).unwrap();
+        json.as_object_mut().unwrap().remove("caching");
+        let restored: CrudOperation = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.caching, HttpCaching::default());
+    }
+
     #[test]
     fn test_endpoint_group_validation() {
         let valid = EndpointGroup::new(Uuid::new_v4(), "User");
@@ -985,4 +1832,33 @@ mod tests {
         no_slash.base_path = "api/users".to_string();
         assert!(no_slash.validate().is_err());
     }
+
+    #[test]
+    fn test_route_style() {
+        let default = RouteStyle::default();
+        assert_eq!(default.base_path("BlogPost"), "/api/blog_posts");
+        assert_eq!(
+            default.nested_path("/api/users", "User", "BlogPost", ""),
+            "/api/users/:user_id/blog_posts"
+        );
+
+        let kebab = default.with_case(PathCase::Kebab);
+        assert_eq!(kebab.base_path("BlogPost"), "/api/blog-posts");
+        assert_eq!(kebab.id_param("BlogPost"), "blog_post_id");
+
+        let camel = default
+            .with_case(PathCase::Camel)
+            .with_plurality(PathPlurality::Singular);
+        assert_eq!(camel.base_path("BlogPost"), "/api/blogPost");
+        assert_eq!(camel.id_param("BlogPost"), "blogPostId");
+
+        let flat = default.with_nesting(NestedPathStyle::Flat);
+        assert_eq!(
+            flat.nested_path("/api/users", "User", "Post", "/count"),
+            "/api/posts/count?user_id=:user_id"
+        );
+
+        let group = EndpointGroup::new(Uuid::new_v4(), "Category").with_route_style(&camel);
+        assert_eq!(group.base_path, "/api/category");
+    }
 }
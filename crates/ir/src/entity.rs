@@ -5,7 +5,9 @@
 
 use crate::field::Field;
 use chrono::{DateTime, Utc};
-use imortal_core::{DataType, EngineError, EngineResult, IdType, Position, Size, Validatable};
+use imortal_core::{
+    DataType, DatabaseType, EngineError, EngineResult, IdType, Position, Size, Validatable,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -31,6 +33,11 @@ pub struct Entity {
     /// Fields (columns) in this entity
     pub fields: Vec<Field>,
 
+    /// IDs of the project field sets this entity includes, in order; their
+    /// fields follow the entity's own when generating
+    #[serde(default)]
+    pub field_sets: Vec<Uuid>,
+
     /// Position on the canvas
     pub position: Position,
 
@@ -71,6 +78,7 @@ impl Entity {
             table_name,
             description: None,
             fields: Vec::new(),
+            field_sets: Vec::new(),
             position: Position::zero(),
             size: Size::default_entity(),
             config: EntityConfig::default(),
@@ -177,17 +185,70 @@ impl Entity {
         self.touch();
     }
 
+    /// Add copies of fields (typically from another entity), returning the
+    /// new field IDs.
+    ///
+    /// Copies get fresh IDs and go after the existing fields. A name already
+    /// in use gets a numeric suffix, a copied primary key is demoted if this
+    /// entity already has one, and scoped uniqueness is dropped since it
+    /// refers to the source entity's relationships.
+    pub fn paste_fields(&mut self, fields: &[Field]) -> Vec<Uuid> {
+        let mut ids = Vec::with_capacity(fields.len());
+        for field in fields {
+            let mut copy = field.clone();
+            copy.id = Uuid::new_v4();
+            copy.unique_scope = None;
+            copy.display_order = self.fields.len() as i32;
+            if copy.is_primary_key && self.primary_key().is_some() {
+                copy.is_primary_key = false;
+            }
+            if self.has_field(&copy.name) {
+                let n = (2..)
+                    .find(|n| !self.has_field(&format!("{}_{n}", field.name)))
+                    .unwrap_or_default();
+                copy.name = format!("{}_{n}", field.name);
+                copy.column_name = format!("{}_{n}", field.column_name);
+            }
+            ids.push(copy.id);
+            self.add_field(copy);
+        }
+        ids
+    }
+
     /// Add a field using builder pattern
     pub fn with_field(mut self, field: Field) -> Self {
         self.add_field(field);
         self
     }
 
+    /// Include a project field set; returns false if it already was
+    pub fn include_field_set(&mut self, set_id: Uuid) -> bool {
+        if self.field_sets.contains(&set_id) {
+            return false;
+        }
+        self.field_sets.push(set_id);
+        self.touch();
+        true
+    }
+
+    /// Stop including a field set; returns false if it wasn't included
+    pub fn exclude_field_set(&mut self, set_id: Uuid) -> bool {
+        let before = self.field_sets.len();
+        self.field_sets.retain(|id| *id != set_id);
+        if self.field_sets.len() == before {
+            return false;
+        }
+        self.touch();
+        true
+    }
+
     /// Remove a field by ID
     pub fn remove_field(&mut self, field_id: Uuid) -> Option<Field> {
         if let Some(pos) = self.fields.iter().position(|f| f.id == field_id) {
             self.touch();
-            Some(self.fields.remove(pos))
+            let field = self.fields.remove(pos);
+            self.config.remove_constraint_field(&field.name);
+            Some(field)
         } else {
             None
         }
@@ -276,7 +337,7 @@ impl Entity {
         self.fields.iter().filter(|f| f.in_update_dto()).collect()
     }
 
-    /// Get fields for response DTO (excludes secrets)
+    /// Get fields for response DTO (excludes secrets and hidden fields)
     pub fn response_dto_fields(&self) -> Vec<&Field> {
         self.fields.iter().filter(|f| f.in_response_dto()).collect()
     }
@@ -396,9 +457,11 @@ impl Entity {
         new_entity.created_at = Utc::now();
         new_entity.modified_at = Utc::now();
 
-        // Regenerate field IDs
+        // Regenerate field IDs; relationships aren't copied, so neither is
+        // uniqueness scoped to one
         for field in &mut new_entity.fields {
             field.id = Uuid::new_v4();
+            field.unique_scope = None;
         }
 
         new_entity
@@ -539,10 +602,52 @@ pub struct EntityConfig {
     pub model_attributes: Vec<String>,
 
     /// Custom table options (e.g., PostgreSQL schema)
+    #[serde(serialize_with = "crate::serialization::sorted_map")]
     pub table_options: std::collections::HashMap<String, String>,
+
+    /// Database schema/namespace the table lives in (PostgreSQL `schema.table`,
+    /// MySQL database qualifier). `None` uses the connection's default.
+    #[serde(default)]
+    pub schema: Option<String>,
+
+    /// PostgreSQL tablespace the table is stored in. `None` uses the
+    /// database's default.
+    #[serde(default)]
+    pub tablespace: Option<String>,
+
+    /// MySQL storage engine (e.g. `InnoDB`). `None` uses the server's default.
+    #[serde(default)]
+    pub engine: Option<String>,
+
+    /// MySQL default character set (e.g. `utf8mb4`)
+    #[serde(default)]
+    pub charset: Option<String>,
+
+    /// MySQL default collation (e.g. `utf8mb4_unicode_ci`)
+    #[serde(default)]
+    pub collation: Option<String>,
+
+    /// Number of fake records the generated seed files insert (0 = none)
+    #[serde(default = "default_seed_count")]
+    pub seed_count: u32,
+
+    /// Field sets whose combined values must be unique (e.g. `[user_id, slug]`)
+    #[serde(default)]
+    pub unique_together: Vec<Vec<String>>,
+
+    /// Indexes over one or more fields, beyond each field's own `indexed` flag
+    #[serde(default)]
+    pub indexes: Vec<EntityIndex>,
+}
+
+fn default_seed_count() -> u32 {
+    EntityConfig::DEFAULT_SEED_COUNT
 }
 
 impl EntityConfig {
+    /// Seed records generated per entity unless configured otherwise
+    pub const DEFAULT_SEED_COUNT: u32 = 10;
+
     /// Create a new config with default values
     pub fn new() -> Self {
         Self::default()
@@ -589,6 +694,133 @@ impl EntityConfig {
         self.table_options.insert(key.into(), value.into());
         self
     }
+
+    /// Place the table in a database schema/namespace
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Store the table in a PostgreSQL tablespace
+    pub fn with_tablespace(mut self, tablespace: impl Into<String>) -> Self {
+        self.tablespace = Some(tablespace.into());
+        self
+    }
+
+    /// Use a MySQL storage engine
+    pub fn with_engine(mut self, engine: impl Into<String>) -> Self {
+        self.engine = Some(engine.into());
+        self
+    }
+
+    /// Set the MySQL default character set and, optionally, collation
+    pub fn with_charset(mut self, charset: impl Into<String>, collation: Option<&str>) -> Self {
+        self.charset = Some(charset.into());
+        self.collation = collation.map(str::to_string);
+        self
+    }
+
+    /// Set how many seed records to generate
+    pub fn with_seed_count(mut self, count: u32) -> Self {
+        self.seed_count = count;
+        self
+    }
+
+    /// Columns the generated table gets on top of the entity's fields
+    pub fn implied_columns(&self) -> Vec<&'static str> {
+        let mut columns = Vec::new();
+        if self.timestamps {
+            columns.extend(["created_at", "updated_at"]);
+        }
+        if self.soft_delete {
+            columns.push("deleted_at");
+        }
+        columns
+    }
+
+    /// Require a set of fields to be unique together
+    pub fn with_unique_together<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.unique_together
+            .push(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Add an index
+    pub fn with_index(mut self, index: EntityIndex) -> Self {
+        self.indexes.push(index);
+        self
+    }
+
+    /// Point unique constraints and indexes at a renamed field
+    pub fn rename_constraint_field(&mut self, old: &str, new: &str) {
+        let lists = self
+            .unique_together
+            .iter_mut()
+            .chain(self.indexes.iter_mut().map(|i| &mut i.fields));
+        for name in lists.flatten().filter(|name| *name == old) {
+            *name = new.to_string();
+        }
+    }
+
+    /// Drop the unique constraints and indexes that include a field
+    pub fn remove_constraint_field(&mut self, name: &str) {
+        self.unique_together
+            .retain(|fields| !fields.iter().any(|f| f == name));
+        self.indexes
+            .retain(|index| !index.fields.iter().any(|f| f == name));
+    }
+
+    /// The configured schema, if set and non-blank
+    pub fn schema_name(&self) -> Option<&str> {
+        non_blank(&self.schema)
+    }
+
+    /// The configured PostgreSQL tablespace, if set and non-blank
+    pub fn tablespace_name(&self) -> Option<&str> {
+        non_blank(&self.tablespace)
+    }
+
+    /// The configured MySQL storage engine, if set and non-blank
+    pub fn engine_name(&self) -> Option<&str> {
+        non_blank(&self.engine)
+    }
+
+    /// The configured MySQL character set, if set and non-blank
+    pub fn charset_name(&self) -> Option<&str> {
+        non_blank(&self.charset)
+    }
+
+    /// The configured MySQL collation, if set and non-blank
+    pub fn collation_name(&self) -> Option<&str> {
+        non_blank(&self.collation)
+    }
+
+    /// The database-specific table options that are set, as
+    /// (config key, database the option applies to, value)
+    pub fn table_settings(&self) -> Vec<(&'static str, DatabaseType, &str)> {
+        [
+            (
+                "tablespace",
+                DatabaseType::PostgreSQL,
+                self.tablespace_name(),
+            ),
+            ("engine", DatabaseType::MySQL, self.engine_name()),
+            ("charset", DatabaseType::MySQL, self.charset_name()),
+            ("collation", DatabaseType::MySQL, self.collation_name()),
+        ]
+        .into_iter()
+        .filter_map(|(key, db, value)| value.map(|v| (key, db, v)))
+        .collect()
+    }
+}
+
+/// A trimmed optional setting, or `None` when it is blank
+fn non_blank(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|s| !s.is_empty())
 }
 
 impl Default for EntityConfig {
@@ -601,10 +833,119 @@ impl Default for EntityConfig {
             generate_api: true,
             model_attributes: Vec::new(),
             table_options: std::collections::HashMap::new(),
+            schema: None,
+            tablespace: None,
+            engine: None,
+            charset: None,
+            collation: None,
+            seed_count: Self::DEFAULT_SEED_COUNT,
+            unique_together: Vec::new(),
+            indexes: Vec::new(),
         }
     }
 }
 
+// ============================================================================
+// EntityIndex
+// ============================================================================
+
+/// An index over one or more fields of an entity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityIndex {
+    /// Field names, in index column order
+    pub fields: Vec<String>,
+
+    /// Index access method
+    #[serde(default)]
+    pub index_type: IndexType,
+}
+
+impl EntityIndex {
+    /// Create a B-tree index over the given fields
+    pub fn new<I, S>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+            index_type: IndexType::default(),
+        }
+    }
+
+    /// Set the index type
+    pub fn with_type(mut self, index_type: IndexType) -> Self {
+        self.index_type = index_type;
+        self
+    }
+}
+
+impl std::fmt::Display for EntityIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.fields.join(", "))?;
+        if self.index_type != IndexType::BTree {
+            write!(f, " using {}", self.index_type.display_name())?;
+        }
+        Ok(())
+    }
+}
+
+/// Index access method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexType {
+    /// Ordered index for equality, range and sort; every database's default
+    #[default]
+    BTree,
+    /// Equality-only hash index
+    Hash,
+    /// Inverted index for JSON, arrays and full-text search (PostgreSQL)
+    Gin,
+    /// Generalized search tree for geometric and range data (PostgreSQL)
+    Gist,
+}
+
+impl IndexType {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            IndexType::BTree => "B-tree",
+            IndexType::Hash => "Hash",
+            IndexType::Gin => "GIN",
+            IndexType::Gist => "GiST",
+        }
+    }
+
+    /// Name of the access method in SQL (`USING …`)
+    pub fn sql_name(&self) -> &'static str {
+        match self {
+            IndexType::BTree => "btree",
+            IndexType::Hash => "hash",
+            IndexType::Gin => "gin",
+            IndexType::Gist => "gist",
+        }
+    }
+
+    /// Whether the database can build this kind of index
+    pub fn supported_by(&self, db: DatabaseType) -> bool {
+        match db {
+            DatabaseType::PostgreSQL => true,
+            DatabaseType::MySQL => matches!(self, IndexType::BTree | IndexType::Hash),
+            DatabaseType::SQLite => *self == IndexType::BTree,
+        }
+    }
+
+    /// Get all index types
+    pub fn all() -> &'static [IndexType] {
+        &[
+            IndexType::BTree,
+            IndexType::Hash,
+            IndexType::Gin,
+            IndexType::Gist,
+        ]
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -767,6 +1108,28 @@ mod tests {
         assert_ne!(original.position, copy.position);
     }
 
+    #[test]
+    fn test_entity_paste_fields() {
+        let source = Entity::with_timestamps("Post")
+            .with_field(Field::new("title", DataType::String).required());
+        let mut target = Entity::new("Comment").with_field(Field::new("title", DataType::String));
+
+        let copied: Vec<Field> = source.fields.clone();
+        let ids = target.paste_fields(&copied);
+
+        assert_eq!(ids.len(), copied.len());
+        assert!(ids.iter().all(|id| source.get_field(*id).is_none()));
+        assert!(target.has_field("created_at"));
+        assert!(target.has_field("updated_at"));
+
+        // Clashing names are suffixed and only one primary key survives
+        let title = target.get_field_by_name("title_2").unwrap();
+        assert_eq!(title.column_name, "title_2");
+        assert!(title.required);
+        assert!(target.has_field("id_2"));
+        assert_eq!(target.fields.iter().filter(|f| f.is_primary_key).count(), 1);
+    }
+
     #[test]
     fn test_entity_dto_fields() {
         let entity = Entity::with_timestamps("User")
@@ -828,4 +1191,95 @@ mod tests {
         assert!(config.auditable);
         assert_eq!(config.id_type, IdType::Uuid);
     }
+
+    #[test]
+    fn test_entity_config_schema() {
+        assert_eq!(EntityConfig::new().schema_name(), None);
+        assert_eq!(
+            EntityConfig::new().with_schema("billing").schema_name(),
+            Some("billing")
+        );
+        assert_eq!(EntityConfig::new().with_schema("  ").schema_name(), None);
+
+        // Configs saved before schemas existed still load
+        let mut json = serde_json::to_value(EntityConfig::new()).unwrap();
+        json.as_object_mut().unwrap().remove("schema");
+        let config: EntityConfig = serde_json::from_value(json).unwrap();
+        assert!(config.schema.is_none());
+    }
+
+    #[test]
+    fn test_entity_config_table_settings() {
+        assert!(EntityConfig::new().table_settings().is_empty());
+
+        let config = EntityConfig::new()
+            .with_tablespace(" fast ")
+            .with_engine("InnoDB")
+            .with_charset("utf8mb4", Some(""));
+        assert_eq!(config.tablespace_name(), Some("fast"));
+        assert_eq!(config.collation_name(), None);
+        assert_eq!(
+            config.table_settings(),
+            vec![
+                ("tablespace", DatabaseType::PostgreSQL, "fast"),
+                ("engine", DatabaseType::MySQL, "InnoDB"),
+                ("charset", DatabaseType::MySQL, "utf8mb4"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entity_config_seed_count() {
+        assert_eq!(
+            EntityConfig::new().seed_count,
+            EntityConfig::DEFAULT_SEED_COUNT
+        );
+        assert_eq!(EntityConfig::new().with_seed_count(0).seed_count, 0);
+
+        // Configs saved before seed counts existed get the default
+        let mut json = serde_json::to_value(EntityConfig::new().with_seed_count(3)).unwrap();
+        json.as_object_mut().unwrap().remove("seed_count");
+        let config: EntityConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.seed_count, EntityConfig::DEFAULT_SEED_COUNT);
+    }
+
+    #[test]
+    fn test_entity_config_constraints() {
+        let mut entity = Entity::new("Post");
+        entity.add_field(Field::new("user_id", DataType::Uuid));
+        entity.add_field(Field::new("slug", DataType::String));
+        entity.add_field(Field::new("tags", DataType::Json));
+        entity.config = EntityConfig::new()
+            .with_unique_together(["user_id", "slug"])
+            .with_index(EntityIndex::new(["tags"]).with_type(IndexType::Gin));
+
+        entity.config.rename_constraint_field("slug", "handle");
+        assert_eq!(
+            entity.config.unique_together,
+            vec![vec!["user_id", "handle"]]
+        );
+
+        // Removing a field drops every constraint that includes it
+        let tags = entity.get_field_by_name("tags").unwrap().id;
+        entity.remove_field(tags);
+        assert!(entity.config.indexes.is_empty());
+        assert_eq!(entity.config.unique_together.len(), 1);
+
+        assert_eq!(
+            EntityConfig::new().with_soft_delete().implied_columns(),
+            ["created_at", "updated_at", "deleted_at"]
+        );
+
+        assert!(IndexType::Gin.supported_by(DatabaseType::PostgreSQL));
+        assert!(!IndexType::Gin.supported_by(DatabaseType::MySQL));
+        assert!(!IndexType::Hash.supported_by(DatabaseType::SQLite));
+
+        // Configs saved before constraints existed still load
+        let mut json = serde_json::to_value(EntityConfig::new()).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("unique_together");
+        object.remove("indexes");
+        let config: EntityConfig = serde_json::from_value(json).unwrap();
+        assert!(config.unique_together.is_empty() && config.indexes.is_empty());
+    }
 }
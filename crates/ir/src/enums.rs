@@ -0,0 +1,255 @@
+//! Project-level enum types
+//!
+//! An `EnumDef` names a set of variants once so several fields can share
+//! it. Fields refer to an enum through `DataType::Enum`, which carries the
+//! enum's name and a copy of its variants;
+//! [`ProjectGraph::update_enum`](crate::ProjectGraph::update_enum) keeps
+//! those copies in step when the definition changes.
+//!
+//! Variants are stored as they appear on the wire and in the database
+//! (`"draft"`, `"in_review"`); generators derive identifiers from them.
+
+use imortal_core::{DataType, EngineError, EngineResult, Validatable};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// ============================================================================
+// EnumDef
+// ============================================================================
+
+/// A named enum type shared by fields across the project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnumDef {
+    /// Unique identifier
+    pub id: Uuid,
+
+    /// Type name (PascalCase, e.g., "PostStatus")
+    pub name: String,
+
+    /// Human-readable description
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Variant values, in declaration order
+    pub variants: Vec<String>,
+}
+
+impl EnumDef {
+    /// Create an enum with no variants
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            description: None,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Build the definition for an inline `DataType::Enum`, looking through
+    /// `Optional` and `Array`
+    pub fn from_data_type(data_type: &DataType) -> Option<Self> {
+        let (name, variants) = enum_of(data_type)?;
+        Some(Self::new(name).with_variants(variants.iter().cloned()))
+    }
+
+    // ========================================================================
+    // Builder methods
+    // ========================================================================
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a variant
+    pub fn with_variant(mut self, variant: impl Into<String>) -> Self {
+        self.variants.push(variant.into());
+        self
+    }
+
+    /// Add several variants
+    pub fn with_variants<I, S>(mut self, variants: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.variants.extend(variants.into_iter().map(Into::into));
+        self
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// The data type a field of this enum has
+    pub fn data_type(&self) -> DataType {
+        DataType::Enum {
+            name: self.name.clone(),
+            variants: self.variants.clone(),
+        }
+    }
+
+    /// Whether a data type refers to this enum, looking through `Optional`
+    /// and `Array`
+    pub fn is_type_of(&self, data_type: &DataType) -> bool {
+        enum_of(data_type).is_some_and(|(name, _)| name == self.name)
+    }
+
+    /// Whether a data type refers to this enum with the same variants
+    pub fn matches(&self, data_type: &DataType) -> bool {
+        enum_of(data_type).is_some_and(|(name, variants)| {
+            name == self.name && variants == self.variants.as_slice()
+        })
+    }
+}
+
+impl Validatable for EnumDef {
+    fn validate(&self) -> EngineResult<()> {
+        if !is_valid_identifier(&self.name) {
+            return Err(EngineError::Validation(format!(
+                "Enum name '{}' is not a valid identifier",
+                self.name
+            )));
+        }
+        if !self.name.starts_with(|c: char| c.is_uppercase()) {
+            return Err(EngineError::Validation(format!(
+                "Enum name '{}' should start with an uppercase letter",
+                self.name
+            )));
+        }
+        if self.variants.is_empty() {
+            return Err(EngineError::Validation(format!(
+                "Enum '{}' has no variants",
+                self.name
+            )));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for variant in &self.variants {
+            if !is_valid_variant(variant) {
+                return Err(EngineError::Validation(format!(
+                    "Enum '{}' has an invalid variant '{}'; use letters, digits, '_' and '-', starting with a letter",
+                    self.name, variant
+                )));
+            }
+            if !seen.insert(variant_key(variant)) {
+                return Err(EngineError::Validation(format!(
+                    "Enum '{}' has duplicate variant '{}'",
+                    self.name, variant
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// The enum name and variants a data type refers to, looking through
+/// `Optional` and `Array`
+pub fn enum_of(data_type: &DataType) -> Option<(&str, &[String])> {
+    match data_type {
+        DataType::Enum { name, variants } => Some((name, variants)),
+        DataType::Optional(inner) | DataType::Array(inner) => enum_of(inner),
+        _ => None,
+    }
+}
+
+/// Replace the enum a data type refers to, keeping any `Optional` / `Array`
+/// wrapping. Returns whether anything changed.
+pub(crate) fn retype_enum(data_type: &mut DataType, old_name: &str, new: &EnumDef) -> bool {
+    match data_type {
+        DataType::Enum { name, variants } if name == old_name => {
+            if *name == new.name && *variants == new.variants {
+                return false;
+            }
+            *name = new.name.clone();
+            *variants = new.variants.clone();
+            true
+        }
+        DataType::Optional(inner) | DataType::Array(inner) => retype_enum(inner, old_name, new),
+        _ => false,
+    }
+}
+
+/// Variants that would become the same identifier (`in_review`,
+/// `in-review`, `InReview`) count as duplicates
+fn variant_key(variant: &str) -> String {
+    variant
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_valid_variant(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn is_valid_identifier(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_alphabetic()) && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status() -> EnumDef {
+        EnumDef::new("PostStatus").with_variants(["draft", "published"])
+    }
+
+    #[test]
+    fn test_data_type_round_trip() {
+        let def = status();
+        let data_type = DataType::Optional(Box::new(def.data_type()));
+        assert!(def.is_type_of(&data_type));
+        assert!(def.matches(&data_type));
+
+        let inline = EnumDef::from_data_type(&data_type).unwrap();
+        assert_eq!(inline.name, "PostStatus");
+        assert_eq!(inline.variants, def.variants);
+        assert!(EnumDef::from_data_type(&DataType::String).is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(status().validate().is_ok());
+        assert!(EnumDef::new("PostStatus").validate().is_err());
+        assert!(
+            EnumDef::new("post_status")
+                .with_variant("a")
+                .validate()
+                .is_err()
+        );
+        assert!(status().with_variant("1st").validate().is_err());
+        assert!(status().with_variant("Draft").validate().is_err());
+        assert!(
+            EnumDef::new("Stage")
+                .with_variants(["in_review", "in-review"])
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_retype_enum_keeps_wrapping() {
+        let old = status();
+        let renamed = EnumDef::new("ArticleStatus").with_variants(["draft", "archived"]);
+        let mut data_type = DataType::Array(Box::new(old.data_type()));
+
+        assert!(retype_enum(&mut data_type, "PostStatus", &renamed));
+        assert_eq!(data_type, DataType::Array(Box::new(renamed.data_type())));
+        assert!(!retype_enum(&mut data_type, "ArticleStatus", &renamed));
+        assert!(!retype_enum(&mut DataType::Text, "PostStatus", &renamed));
+    }
+}
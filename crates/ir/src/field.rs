@@ -9,6 +9,8 @@ use imortal_core::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::computed::ComputedField;
+
 // ============================================================================
 // Field
 // ============================================================================
@@ -34,6 +36,12 @@ pub struct Field {
     /// Whether the field must be unique
     pub unique: bool,
 
+    /// Relationship the field is unique within (e.g. `slug` unique per
+    /// organization): uniqueness then holds per value of that
+    /// relationship's foreign key on this entity, not across the table
+    #[serde(default)]
+    pub unique_scope: Option<Uuid>,
+
     /// Whether to create an index on this field
     pub indexed: bool,
 
@@ -69,6 +77,14 @@ pub struct Field {
 
     /// Whether this field is a secret (passwords, API keys)
     pub secret: bool,
+
+    /// Example value shown in docs and used for generated sample data
+    #[serde(default)]
+    pub example: Option<String>,
+
+    /// Expression this field is derived from, if it is computed
+    #[serde(default)]
+    pub computed: Option<ComputedField>,
 }
 
 impl Field {
@@ -84,6 +100,7 @@ impl Field {
             data_type,
             required: false,
             unique: false,
+            unique_scope: None,
             indexed: false,
             default_value: None,
             validations: Vec::new(),
@@ -96,6 +113,8 @@ impl Field {
             hidden: false,
             readonly: false,
             secret: false,
+            example: None,
+            computed: None,
         }
     }
 
@@ -170,6 +189,13 @@ impl Field {
         self
     }
 
+    /// Make the field unique within the parent of a relationship, e.g.
+    /// `slug` unique per `organization_id`
+    pub fn unique_within(mut self, relationship_id: Uuid) -> Self {
+        self.unique_scope = Some(relationship_id);
+        self
+    }
+
     /// Mark the field as indexed
     pub fn indexed(mut self) -> Self {
         self.indexed = true;
@@ -213,6 +239,12 @@ impl Field {
         self
     }
 
+    /// Set the UI widget
+    pub fn with_widget(mut self, widget: WidgetType) -> Self {
+        self.ui_hints.widget = Some(widget);
+        self
+    }
+
     /// Set the display order
     pub fn with_order(mut self, order: i32) -> Self {
         self.display_order = order;
@@ -237,12 +269,25 @@ impl Field {
         self
     }
 
+    /// Derive the field from other fields; computed fields are read-only
+    pub fn with_computed(mut self, computed: ComputedField) -> Self {
+        self.computed = Some(computed);
+        self.readonly = true;
+        self
+    }
+
     /// Set the column name (if different from field name)
     pub fn with_column_name(mut self, column_name: impl Into<String>) -> Self {
         self.column_name = column_name.into();
         self
     }
 
+    /// Set the example value
+    pub fn with_example(mut self, example: impl Into<String>) -> Self {
+        self.example = Some(example.into());
+        self
+    }
+
     // ========================================================================
     // Utility methods
     // ========================================================================
@@ -266,6 +311,23 @@ impl Field {
         self.is_foreign_key
     }
 
+    /// Check if this field is derived from other fields
+    pub fn is_computed(&self) -> bool {
+        self.computed.is_some()
+    }
+
+    /// Check if this field is stored in a database column (everything but
+    /// computed getters)
+    pub fn is_column(&self) -> bool {
+        self.computed.as_ref().is_none_or(ComputedField::is_stored)
+    }
+
+    /// Check if this field holds the URL of an uploaded file (its widget is
+    /// `File` or `Image`); it is set through an upload endpoint, not the DTOs
+    pub fn is_upload(&self) -> bool {
+        self.ui_hints.widget.is_some_and(|w| w.is_upload())
+    }
+
     /// Check if this field has any validations
     pub fn has_validations(&self) -> bool {
         !self.validations.is_empty() || self.required
@@ -281,17 +343,132 @@ impl Field {
 
     /// Check if this field should be included in create DTOs
     pub fn in_create_dto(&self) -> bool {
-        !self.is_primary_key && !self.readonly && self.default_value.is_none()
+        !self.is_primary_key && !self.readonly && self.default_value.is_none() && !self.is_upload()
     }
 
     /// Check if this field should be included in update DTOs
     pub fn in_update_dto(&self) -> bool {
-        !self.is_primary_key && !self.readonly
+        !self.is_primary_key && !self.readonly && !self.is_upload()
     }
 
-    /// Check if this field should be included in response DTOs
+    /// Check if this field should be included in response DTOs (primary
+    /// keys stay even when hidden, so clients can address the record)
     pub fn in_response_dto(&self) -> bool {
-        !self.secret
+        !self.secret && (!self.hidden || self.is_primary_key)
+    }
+
+    /// Check if the field name suggests sensitive data (passwords, tokens,
+    /// API keys, SSNs, ...)
+    pub fn looks_sensitive(&self) -> bool {
+        let base_type = match &self.data_type {
+            DataType::Optional(inner) => inner.as_ref(),
+            other => other,
+        };
+        let text_like = matches!(
+            base_type,
+            DataType::String | DataType::Text | DataType::Bytes
+        );
+        if !text_like {
+            return false;
+        }
+
+        let name = self.name.to_lowercase();
+        name.split('_')
+            .any(|token| SENSITIVE_NAME_TOKENS.contains(&token))
+            || SENSITIVE_NAME_PHRASES
+                .iter()
+                .any(|phrase| name.contains(phrase))
+    }
+
+    /// Check if the field name suggests a password that must be hashed
+    pub fn looks_like_password(&self) -> bool {
+        self.looks_sensitive()
+            && self
+                .name
+                .to_lowercase()
+                .split('_')
+                .any(|token| matches!(token, "password" | "passwd" | "pwd"))
+    }
+
+    /// List the recommended protections missing from a sensitive-looking
+    /// field. Returns an empty list for fields that don't look sensitive.
+    pub fn sensitivity_issues(&self) -> Vec<&'static str> {
+        let mut issues = Vec::new();
+        if !self.looks_sensitive() {
+            return issues;
+        }
+
+        if !self.secret {
+            issues.push("not marked as secret (it will appear in API responses)");
+        }
+        if !self.hidden {
+            issues.push("not hidden from default views");
+        }
+        if self.looks_like_password() {
+            let mentions_hashing = |text: &Option<String>| {
+                text.as_deref()
+                    .is_some_and(|t| t.to_lowercase().contains("hash"))
+            };
+            if !mentions_hashing(&self.description) && !mentions_hashing(&self.ui_hints.help_text) {
+                issues.push("missing hashing guidance");
+            }
+            if !self
+                .validations
+                .iter()
+                .any(|v| matches!(v, Validation::MinLength(_)))
+            {
+                issues.push("no minimum length");
+            }
+        }
+
+        issues
+    }
+
+    /// Apply the recommended settings for a sensitive field: secret, hidden,
+    /// masked input and, for passwords, hashing guidance and a minimum length.
+    pub fn apply_secret_recommendations(&mut self) {
+        self.secret = true;
+        self.hidden = true;
+        self.ui_hints.widget = Some(WidgetType::Password);
+
+        if self.looks_like_password() {
+            if self.ui_hints.help_text.is_none() {
+                self.ui_hints.help_text =
+                    Some("Will be hashed with bcrypt before storage.".to_string());
+            }
+            if !self
+                .validations
+                .iter()
+                .any(|v| matches!(v, Validation::MinLength(_)))
+            {
+                self.validations.push(Validation::MinLength(8));
+            }
+        }
+    }
+
+    /// Get the example value, falling back to a suggestion based on the
+    /// field name and type
+    pub fn example_or_suggested(&self) -> String {
+        match &self.example {
+            Some(example) if !example.trim().is_empty() => example.clone(),
+            _ => self.suggested_example(),
+        }
+    }
+
+    /// Suggest a realistic, faker-style example value for this field.
+    ///
+    /// The suggestion is derived from well-known field names (email, phone,
+    /// first_name, ...) and falls back to a value matching the data type.
+    pub fn suggested_example(&self) -> String {
+        suggest_example(&self.data_type, &self.name.to_lowercase())
+    }
+
+    /// Render the example value (or a suggestion) as a JSON literal.
+    ///
+    /// String-like types are always quoted; numeric, boolean and JSON types
+    /// keep the raw text when it is already valid JSON.
+    pub fn example_json(&self) -> String {
+        example_to_json(&self.data_type, &self.example_or_suggested())
     }
 }
 
@@ -322,6 +499,23 @@ impl Validatable for Field {
             ));
         }
 
+        if let Some(computed) = &self.computed {
+            if self.is_primary_key || self.is_foreign_key {
+                return Err(EngineError::validation(format!(
+                    "Key field '{}' cannot be computed",
+                    self.name
+                )));
+            }
+            if self.default_value.is_some() {
+                return Err(EngineError::validation(format!(
+                    "Computed field '{}' cannot have a default value",
+                    self.name
+                )));
+            }
+            computed.validate()?;
+            computed.check_result_type(&self.data_type)?;
+        }
+
         Ok(())
     }
 }
@@ -346,6 +540,22 @@ impl std::hash::Hash for Field {
     }
 }
 
+/// Name segments (split on `_`) that mark a field as sensitive
+const SENSITIVE_NAME_TOKENS: &[&str] = &[
+    "password", "passwd", "pwd", "secret", "token", "ssn", "cvv", "pin", "otp", "salt",
+];
+
+/// Substrings that mark a field as sensitive
+const SENSITIVE_NAME_PHRASES: &[&str] = &[
+    "api_key",
+    "apikey",
+    "private_key",
+    "access_key",
+    "credit_card",
+    "card_number",
+    "social_security",
+];
+
 // ============================================================================
 // DefaultValue
 // ============================================================================
@@ -723,7 +933,7 @@ impl WidgetType {
             DataType::String => WidgetType::Text,
             DataType::Text => WidgetType::TextArea,
             DataType::Int32 | DataType::Int64 => WidgetType::Number,
-            DataType::Float32 | DataType::Float64 => WidgetType::Number,
+            DataType::Float32 | DataType::Float64 | DataType::Decimal { .. } => WidgetType::Number,
             DataType::Bool => WidgetType::Checkbox,
             DataType::Uuid => WidgetType::Text,
             DataType::DateTime => WidgetType::DateTime,
@@ -738,6 +948,11 @@ impl WidgetType {
         }
     }
 
+    /// Check if the widget uploads a file
+    pub fn is_upload(&self) -> bool {
+        matches!(self, WidgetType::File | WidgetType::Image)
+    }
+
     /// Get the HTML input type attribute
     pub fn html_input_type(&self) -> &'static str {
         match self {
@@ -806,6 +1021,113 @@ fn to_title_case(s: &str) -> String {
         .join(" ")
 }
 
+/// Suggest a faker-style example for a data type, using the (lowercase)
+/// field name as a hint.
+fn suggest_example(data_type: &DataType, name: &str) -> String {
+    match data_type {
+        DataType::Optional(inner) => suggest_example(inner, name),
+        DataType::Array(inner) => {
+            let inner_json = example_to_json(inner, &suggest_example(inner, name));
+            format!("[{}]", inner_json)
+        }
+        DataType::String | DataType::Text => {
+            let by_name: &[(&str, &str)] = &[
+                ("email", "jane.doe@example.com"),
+                ("first_name", "Jane"),
+                ("last_name", "Doe"),
+                ("username", "jane_doe"),
+                ("password", "S3cure!Passw0rd"),
+                ("phone", "+1 555 010 0200"),
+                ("url", "https://example.com"),
+                ("website", "https://example.com"),
+                ("slug", "getting-started"),
+                ("title", "Getting Started"),
+                ("city", "Lisbon"),
+                ("country", "Portugal"),
+                ("address", "221B Baker Street"),
+                ("zip", "94103"),
+                ("postal", "94103"),
+                ("currency", "USD"),
+                ("color", "#3b82f6"),
+                ("status", "active"),
+                ("token", "tok_4f9a2c1e"),
+                ("description", "A short description of the item."),
+                (
+                    "content",
+                    "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+                ),
+                (
+                    "body",
+                    "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+                ),
+                ("name", "Jane Doe"),
+            ];
+            by_name
+                .iter()
+                .find(|(hint, _)| name.contains(hint))
+                .map(|(_, value)| value.to_string())
+                .unwrap_or_else(|| format!("Sample {}", to_title_case(name).to_lowercase()))
+        }
+        DataType::Int32 | DataType::Int64 => {
+            if name.contains("age") {
+                "34".to_string()
+            } else if name.contains("year") {
+                "2024".to_string()
+            } else if name.contains("count") || name.contains("quantity") {
+                "3".to_string()
+            } else {
+                "42".to_string()
+            }
+        }
+        DataType::Float32 | DataType::Float64 | DataType::Decimal { .. } => {
+            if name.contains("price") || name.contains("amount") || name.contains("cost") {
+                "19.99".to_string()
+            } else if name.contains("lat") {
+                "38.7223".to_string()
+            } else if name.contains("lng") || name.contains("lon") {
+                "-9.1393".to_string()
+            } else {
+                "3.14".to_string()
+            }
+        }
+        DataType::Bool => "true".to_string(),
+        DataType::Uuid | DataType::Reference { .. } => {
+            "3f2b8c1e-9d4a-4e6b-8f7c-2a1d5e9b0c47".to_string()
+        }
+        DataType::DateTime => "2024-05-14T09:30:00Z".to_string(),
+        DataType::Date => "2024-05-14".to_string(),
+        DataType::Time => "09:30:00".to_string(),
+        DataType::Json => "{\"key\": \"value\"}".to_string(),
+        DataType::Bytes => "aGVsbG8gd29ybGQ=".to_string(),
+        DataType::Enum { variants, .. } => variants
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "variant".to_string()),
+    }
+}
+
+/// Convert a raw example string into a JSON literal for the given type.
+fn example_to_json(data_type: &DataType, raw: &str) -> String {
+    let raw = raw.trim();
+    let keep_raw = match data_type {
+        DataType::Optional(inner) => return example_to_json(inner, raw),
+        DataType::Int32 | DataType::Int64 | DataType::Float32 | DataType::Float64 => {
+            raw.parse::<f64>().is_ok()
+        }
+        DataType::Bool => raw == "true" || raw == "false",
+        DataType::Json | DataType::Array(_) => {
+            serde_json::from_str::<serde_json::Value>(raw).is_ok()
+        }
+        _ => false,
+    };
+
+    if keep_raw {
+        raw.to_string()
+    } else {
+        serde_json::Value::String(raw.to_string()).to_string()
+    }
+}
+
 /// Check if a string is a valid Rust identifier
 fn is_valid_identifier(s: &str) -> bool {
     if s.is_empty() {
@@ -961,4 +1283,118 @@ mod tests {
             WidgetType::DateTime
         );
     }
+
+    #[test]
+    fn test_looks_sensitive() {
+        assert!(Field::new("password_hash", DataType::String).looks_sensitive());
+        assert!(Field::new("stripe_api_key", DataType::String).looks_sensitive());
+        assert!(Field::new("ssn", DataType::String).looks_sensitive());
+        assert!(
+            Field::new(
+                "refresh_token",
+                DataType::Optional(Box::new(DataType::String))
+            )
+            .looks_sensitive()
+        );
+
+        assert!(!Field::new("shipping_address", DataType::String).looks_sensitive());
+        assert!(!Field::new("token_count", DataType::Int32).looks_sensitive());
+        assert!(!Field::new("is_secret", DataType::Bool).looks_sensitive());
+    }
+
+    #[test]
+    fn test_sensitivity_issues_and_apply() {
+        let mut field = Field::new("password", DataType::String);
+        let issues = field.sensitivity_issues();
+        assert!(issues.iter().any(|i| i.contains("secret")));
+        assert!(issues.iter().any(|i| i.contains("hashing")));
+
+        field.apply_secret_recommendations();
+        assert!(field.secret);
+        assert!(field.hidden);
+        assert_eq!(field.ui_hints.widget, Some(WidgetType::Password));
+        assert!(field.validations.contains(&Validation::MinLength(8)));
+        assert!(field.sensitivity_issues().is_empty());
+
+        let token = Field::new("api_token", DataType::String).secret().hidden();
+        assert!(token.sensitivity_issues().is_empty());
+    }
+
+    #[test]
+    fn test_suggested_example() {
+        let email = Field::new("email", DataType::String);
+        assert!(email.suggested_example().contains("@example.com"));
+
+        let price = Field::new("price", DataType::Float64);
+        assert_eq!(price.suggested_example(), "19.99");
+
+        let flag = Field::new("is_active", DataType::Bool);
+        assert_eq!(flag.suggested_example(), "true");
+    }
+
+    #[test]
+    fn test_example_json() {
+        let name = Field::new("name", DataType::String).with_example("Ada");
+        assert_eq!(name.example_json(), "\"Ada\"");
+
+        let count = Field::new("count", DataType::Int32).with_example("7");
+        assert_eq!(count.example_json(), "7");
+
+        // Non-numeric text for a numeric field is quoted rather than emitted raw
+        let bad = Field::new("count", DataType::Int32).with_example("seven");
+        assert_eq!(bad.example_json(), "\"seven\"");
+
+        let tags = Field::new("tags", DataType::Array(Box::new(DataType::String)));
+        assert!(tags.example_json().starts_with('['));
+    }
+
+    #[test]
+    fn test_example_defaults_when_missing_from_json() {
+        let field = Field::new("title", DataType::String);
+        let mut value = serde_json::to_value(&field).unwrap();
+        value.as_object_mut().unwrap().remove("example");
+        let restored: Field = serde_json::from_value(value).unwrap();
+        assert!(restored.example.is_none());
+        assert_eq!(restored.example_or_suggested(), "Getting Started");
+    }
+
+    #[test]
+    fn test_upload_fields_skip_dtos() {
+        let field = Field::new("avatar", DataType::String).with_widget(WidgetType::Image);
+        assert!(field.is_upload());
+        assert!(!field.in_create_dto());
+        assert!(!field.in_update_dto());
+        assert!(field.in_response_dto());
+
+        let plain = Field::new("data", DataType::Bytes);
+        assert_eq!(
+            WidgetType::for_data_type(&plain.data_type),
+            WidgetType::File
+        );
+        assert!(!plain.is_upload());
+        assert!(plain.in_create_dto());
+    }
+
+    #[test]
+    fn test_response_dto_skips_secret_and_hidden_fields() {
+        assert!(
+            !Field::new("password_hash", DataType::String)
+                .secret()
+                .in_response_dto()
+        );
+        assert!(
+            !Field::new("internal_notes", DataType::Text)
+                .hidden()
+                .in_response_dto()
+        );
+
+        let mut id = Field::new("id", DataType::Uuid).hidden();
+        id.is_primary_key = true;
+        assert!(id.in_response_dto());
+
+        let readonly = Field::new("slug", DataType::String).readonly();
+        assert!(readonly.in_response_dto());
+        assert!(!readonly.in_create_dto());
+        assert!(!readonly.in_update_dto());
+    }
 }
@@ -0,0 +1,244 @@
+//! Reusable field sets (mixins)
+//!
+//! A `FieldSet` names a group of fields once at the project level —
+//! timestamps, audit columns, a postal address — so entities can include the
+//! group instead of repeating its fields. An entity lists the sets it
+//! includes in [`Entity::field_sets`](crate::Entity::field_sets); the fields
+//! themselves stay in the set, so editing a set changes every entity that
+//! includes it.
+//!
+//! Generators see the fields inline:
+//! [`ProjectGraph::expand_field_sets`](crate::ProjectGraph::expand_field_sets)
+//! copies each set's fields into the entities that include it, after their
+//! own fields. An entity's own field wins over a set's field of the same
+//! name, and an earlier set wins over a later one.
+
+use imortal_core::{DataType, EngineError, EngineResult, Validatable};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::field::Field;
+
+// ============================================================================
+// FieldSet
+// ============================================================================
+
+/// A named group of fields that entities include
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldSet {
+    /// Unique identifier
+    pub id: Uuid,
+
+    /// Set name (PascalCase, e.g., "Auditable")
+    pub name: String,
+
+    /// Human-readable description
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// The fields an including entity gets, in order
+    pub fields: Vec<Field>,
+}
+
+impl FieldSet {
+    /// Create a field set with no fields
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            description: None,
+            fields: Vec::new(),
+        }
+    }
+
+    // ========================================================================
+    // Built-in sets
+    // ========================================================================
+
+    /// `created_at` and `updated_at`, set when a row is written
+    pub fn timestamps() -> Self {
+        Self::new("Timestamps")
+            .with_description("When the row was created and last updated")
+            .with_field(Field::created_at())
+            .with_field(Field::updated_at())
+    }
+
+    /// `created_by` and `updated_by`, the users who wrote the row
+    pub fn auditable() -> Self {
+        let user = |name: &str, label: &str| {
+            Field::new(name, DataType::Optional(Box::new(DataType::Uuid)))
+                .with_label(label)
+                .readonly()
+        };
+        Self::new("Auditable")
+            .with_description("Who created and last updated the row")
+            .with_field(user("created_by", "Created By"))
+            .with_field(user("updated_by", "Updated By"))
+    }
+
+    /// A postal address: street, city, region, postal code and country
+    pub fn addressable() -> Self {
+        let mut set = Self::new("Addressable").with_description("A postal address");
+        for (name, label) in [
+            ("street", "Street"),
+            ("city", "City"),
+            ("region", "Region"),
+            ("postal_code", "Postal Code"),
+            ("country", "Country"),
+        ] {
+            set.fields
+                .push(Field::new(name, DataType::String).with_label(label));
+        }
+        set
+    }
+
+    /// The sets offered to every project, before it defines its own
+    pub fn builtins() -> Vec<Self> {
+        vec![Self::timestamps(), Self::auditable(), Self::addressable()]
+    }
+
+    // ========================================================================
+    // Builder methods
+    // ========================================================================
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a field
+    pub fn with_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Names of the set's fields, in order
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    /// Copies of the fields for one including entity.
+    ///
+    /// Each copy's ID is derived from the entity's and the field's, so it is
+    /// the same every time the set is expanded into that entity and differs
+    /// between entities.
+    pub fn fields_for(&self, entity_id: Uuid) -> Vec<Field> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let mut copy = field.clone();
+                copy.id = Uuid::from_u128(entity_id.as_u128() ^ field.id.as_u128());
+                copy
+            })
+            .collect()
+    }
+}
+
+impl Validatable for FieldSet {
+    fn validate(&self) -> EngineResult<()> {
+        if !is_pascal_identifier(&self.name) {
+            return Err(EngineError::Validation(format!(
+                "Field set name '{}' should be a PascalCase identifier",
+                self.name
+            )));
+        }
+        if self.fields.is_empty() {
+            return Err(EngineError::Validation(format!(
+                "Field set '{}' has no fields",
+                self.name
+            )));
+        }
+
+        let mut names = HashSet::new();
+        for field in &self.fields {
+            if field.is_primary_key {
+                return Err(EngineError::Validation(format!(
+                    "Field set '{}' can't hold the primary key '{}'; entities keep their own",
+                    self.name, field.name
+                )));
+            }
+            if !names.insert(field.name.as_str()) {
+                return Err(EngineError::Validation(format!(
+                    "Field set '{}' has two fields named '{}'",
+                    self.name, field.name
+                )));
+            }
+            field.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Letters, digits and underscores, starting with an uppercase letter
+fn is_pascal_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins_validate() {
+        let builtins = FieldSet::builtins();
+        assert_eq!(builtins.len(), 3);
+        for set in &builtins {
+            assert!(set.validate().is_ok(), "{} is invalid", set.name);
+        }
+        assert_eq!(
+            FieldSet::auditable().field_names(),
+            vec!["created_by", "updated_by"]
+        );
+    }
+
+    #[test]
+    fn test_field_set_validation() {
+        assert!(FieldSet::new("Empty").validate().is_err());
+        assert!(
+            FieldSet::new("lowercase")
+                .with_field(Field::new("a", DataType::String))
+                .validate()
+                .is_err()
+        );
+        assert!(
+            FieldSet::new("WithKey")
+                .with_field(Field::primary_key())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            FieldSet::new("Twice")
+                .with_field(Field::new("a", DataType::String))
+                .with_field(Field::new("a", DataType::Int32))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_fields_for_entity() {
+        let set = FieldSet::timestamps();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let for_a = set.fields_for(a);
+        assert_eq!(for_a.len(), 2);
+        assert_eq!(for_a[0].name, "created_at");
+        assert_eq!(
+            for_a.iter().map(|f| f.id).collect::<Vec<_>>(),
+            set.fields_for(a).iter().map(|f| f.id).collect::<Vec<_>>()
+        );
+        assert_ne!(for_a[0].id, set.fields_for(b)[0].id);
+        assert_ne!(for_a[0].id, set.fields[0].id);
+    }
+}
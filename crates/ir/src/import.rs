@@ -0,0 +1,2406 @@
+//! # SQL Import
+//!
+//! Reverse-engineers a SQL DDL dump into entities, fields and relationships,
+//! so an existing database can be brought into a project instead of being
+//! redrawn by hand.
+//!
+//! [`import_sql`] reads the `CREATE TABLE`, `CREATE INDEX`,
+//! `CREATE TYPE … AS ENUM`, `ALTER TABLE` and `COMMENT ON` statements written
+//! by `pg_dump --schema-only`, `mysqldump --no-data` and SQLite's `.schema`.
+//! Data, views, functions and triggers are skipped. Whatever can't be
+//! represented exactly is imported as closely as possible and reported as an
+//! [`ImportWarning`].
+//!
+//! | SQL                                           | Imported as                                |
+//! |-----------------------------------------------|--------------------------------------------|
+//! | table `blog_posts`                            | entity `BlogPost`, same table name         |
+//! | column `authorId`                             | field `author_id`, same column name        |
+//! | `NOT NULL` / `PRIMARY KEY`                    | required field                             |
+//! | integer key (`SERIAL`, `AUTO_INCREMENT`, identity) | `IdType::Serial`                      |
+//! | composite or missing primary key              | generated UUID `id` (+ unique constraint)  |
+//! | `created_at` + `updated_at` timestamps        | timestamps enabled                         |
+//! | `deleted_at` timestamp                        | soft delete enabled                        |
+//! | single-column foreign key                     | many-to-one relationship (one-to-one when the column is unique) |
+//! | `UNIQUE (a, b)` / `CREATE INDEX`              | unique-together set / entity index         |
+//! | `ENUM(…)` column / `CREATE TYPE … AS ENUM`    | project enum                               |
+//! | `COMMENT` / `COMMENT ON`                      | entity and field descriptions              |
+//! | `ENGINE=` / `CHARSET=` / `COLLATE=`, `TABLESPACE` | entity table options                   |
+//!
+//! The dialect is detected from the dump with [`detect_dialect`] unless one
+//! is given.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::import::import_sql;
+//! use imortal_ir::{DatabaseType, IdType};
+//!
+//! let sql = r#"
+//!     CREATE TABLE users (id SERIAL PRIMARY KEY, email VARCHAR(255) NOT NULL UNIQUE);
+//!     CREATE TABLE posts (
+//!         id SERIAL PRIMARY KEY,
+//!         author_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+//!         title TEXT NOT NULL
+//!     );
+//! "#;
+//! let project = import_sql(sql, None).unwrap().into_project("blog");
+//! assert_eq!(project.config.database, DatabaseType::PostgreSQL);
+//!
+//! let post = project.get_entity_by_name("Post").unwrap();
+//! assert_eq!(post.config.id_type, IdType::Serial);
+//! assert!(post.get_field_by_name("author_id").unwrap().is_foreign_key);
+//! assert_eq!(project.relationship_count(), 1);
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+
+use imortal_core::{
+    DataType, DatabaseType, EngineError, EngineResult, IdType, Position, ReferentialAction,
+    RelationType, Validation,
+};
+use uuid::Uuid;
+
+use crate::{
+    DefaultValue, Entity, EntityIndex, EnumDef, Field, ForeignKeyRef, IndexType, ProjectGraph,
+    Relationship, generate_accessor_name, generate_relationship_name,
+};
+
+/// Imported entities per row on the canvas
+const GRID_COLUMNS: usize = 4;
+
+/// Horizontal distance between imported entities
+const GRID_SPACING_X: f32 = 320.0;
+
+/// Vertical distance between rows of imported entities
+const GRID_SPACING_Y: f32 = 280.0;
+
+/// Longest `VARCHAR` kept as a `String`; longer ones become `Text`
+const STRING_MAX_LENGTH: u32 = 255;
+
+// ============================================================================
+// Imported Schema
+// ============================================================================
+
+/// Something in the dump that was imported approximately, or skipped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportWarning {
+    /// The table (or, for OpenAPI imports, the schema or path) the warning
+    /// is about, if any
+    pub table: Option<String>,
+
+    /// What was approximated or skipped
+    pub message: String,
+}
+
+impl fmt::Display for ImportWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.table {
+            Some(table) => write!(f, "{}: {}", table, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// The entities, relationships and enums read from a SQL dump
+#[derive(Debug, Clone)]
+pub struct ImportedSchema {
+    /// The dialect the dump was read as
+    pub dialect: DatabaseType,
+
+    /// One entity per table, in dump order
+    pub entities: Vec<Entity>,
+
+    /// One relationship per single-column foreign key
+    pub relationships: Vec<Relationship>,
+
+    /// Enum types used by the entities' fields
+    pub enums: Vec<EnumDef>,
+
+    /// Everything that couldn't be imported exactly
+    pub warnings: Vec<ImportWarning>,
+}
+
+impl ImportedSchema {
+    /// Add the imported entities, relationships and enums to a project,
+    /// laying the entities out in a grid from `origin`. Returns the new
+    /// entity ids.
+    ///
+    /// Fails without changing the project if an entity or enum name is
+    /// already taken.
+    pub fn apply(&self, project: &mut ProjectGraph, origin: Position) -> EngineResult<Vec<Uuid>> {
+        add_imported(
+            project,
+            origin,
+            &self.entities,
+            &self.relationships,
+            &self.enums,
+        )
+    }
+
+    /// Build a new project that targets the dump's database and holds the
+    /// imported schema
+    pub fn into_project(self, name: impl Into<String>) -> ProjectGraph {
+        let mut project = ProjectGraph::new(name);
+        project.config.database = self.dialect;
+        project.config.db_config.port = self.dialect.default_port();
+        self.apply(&mut project, Position::new(100.0, 100.0))
+            .expect("a new project has no names to clash with");
+        project
+    }
+}
+
+/// Add imported entities, relationships and enums to a project, laying the
+/// entities out in a grid from `origin`. Fails without changing the project
+/// if an entity or enum name is already taken.
+pub(crate) fn add_imported(
+    project: &mut ProjectGraph,
+    origin: Position,
+    entities: &[Entity],
+    relationships: &[Relationship],
+    enums: &[EnumDef],
+) -> EngineResult<Vec<Uuid>> {
+    if let Some(entity) = entities
+        .iter()
+        .find(|e| project.get_entity_by_name(&e.name).is_some())
+    {
+        return Err(EngineError::DuplicateEntity(entity.name.clone()));
+    }
+    if let Some(def) = enums
+        .iter()
+        .find(|d| project.get_enum_by_name(&d.name).is_some())
+    {
+        return Err(EngineError::Validation(format!(
+            "An enum named '{}' already exists",
+            def.name
+        )));
+    }
+
+    for def in enums {
+        project.add_enum(def.clone());
+    }
+
+    let mut ids = Vec::with_capacity(entities.len());
+    for (i, entity) in entities.iter().enumerate() {
+        let mut entity = entity.clone();
+        entity.position = Position::new(
+            origin.x + GRID_SPACING_X * (i % GRID_COLUMNS) as f32,
+            origin.y + GRID_SPACING_Y * (i / GRID_COLUMNS) as f32,
+        );
+        ids.push(project.add_entity(entity));
+    }
+    for relationship in relationships {
+        project.add_relationship(relationship.clone());
+    }
+
+    Ok(ids)
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Read a SQL DDL dump, detecting the dialect unless one is given.
+///
+/// Statements that can't be read are skipped with a warning; the import
+/// only fails when the SQL can't be tokenized (an unterminated string or
+/// comment) or holds no `CREATE TABLE` at all.
+pub fn import_sql(sql: &str, dialect: Option<DatabaseType>) -> EngineResult<ImportedSchema> {
+    let dialect = dialect.unwrap_or_else(|| detect_dialect(sql));
+    let tokens = tokenize(sql, dialect)?;
+
+    let mut reader = SchemaReader::new(sql, dialect);
+    for statement in tokens.split(|t| t.is_punct(";")) {
+        if statement.is_empty() {
+            continue;
+        }
+        if let Err(e) = reader.statement(statement) {
+            let preview: String = span(sql, statement).chars().take(60).collect();
+            reader.warn(None, format!("skipped `{}…`: {}", preview, e));
+        }
+    }
+
+    if reader.tables.is_empty() {
+        return Err(EngineError::Validation(
+            "No CREATE TABLE statements found".to_string(),
+        ));
+    }
+    Ok(reader.finish())
+}
+
+/// Guess which database wrote a dump from dialect-specific syntax, falling
+/// back to PostgreSQL
+pub fn detect_dialect(sql: &str) -> DatabaseType {
+    let upper = sql.to_ascii_uppercase();
+    let score = |markers: &[&str]| markers.iter().filter(|m| upper.contains(*m)).count();
+
+    let mysql = score(&[
+        "`",
+        "AUTO_INCREMENT",
+        "ENGINE=",
+        "UNSIGNED",
+        "DEFAULT CHARSET",
+        "LOCK TABLES",
+    ]);
+    let sqlite = score(&["AUTOINCREMENT", "WITHOUT ROWID", "PRAGMA ", "SQLITE_"]);
+    let postgres = score(&[
+        "SERIAL",
+        "::",
+        "CREATE TYPE",
+        "SEARCH_PATH",
+        "JSONB",
+        "TIMESTAMPTZ",
+        "AS IDENTITY",
+        "PG_CATALOG",
+        "BYTEA",
+    ]);
+
+    if mysql > postgres && mysql > sqlite {
+        DatabaseType::MySQL
+    } else if sqlite > postgres {
+        DatabaseType::SQLite
+    } else {
+        DatabaseType::PostgreSQL
+    }
+}
+
+// ============================================================================
+// Tokenizer
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    /// Keyword or unquoted identifier, as written
+    Word,
+    /// `"quoted"`, `` `quoted` `` or `[quoted]` identifier, unquoted
+    Quoted,
+    /// String literal, unescaped
+    Str,
+    Number,
+    Punct,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    /// Byte range in the source
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn is_kw(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        self.kind == TokenKind::Punct && self.text == punct
+    }
+
+    fn ident(&self) -> Option<String> {
+        matches!(self.kind, TokenKind::Word | TokenKind::Quoted).then(|| self.text.clone())
+    }
+}
+
+/// Multi-character operators, longest first
+const OPERATORS: [&str; 6] = ["::", "||", "<=", ">=", "<>", "!="];
+
+fn tokenize(sql: &str, dialect: DatabaseType) -> EngineResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < sql.len() {
+        let rest = &sql[i..];
+        let c = rest.chars().next().unwrap_or_default();
+        let start = i;
+
+        if c.is_whitespace() {
+            i += c.len_utf8();
+        } else if rest.starts_with("--") || (c == '#' && dialect == DatabaseType::MySQL) {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment
+                .find("*/")
+                .ok_or_else(|| syntax_error(sql, start, "unterminated comment"))?;
+            i += end + 4;
+        } else if c == '\'' {
+            let backslash = dialect == DatabaseType::MySQL;
+            let (text, len) = quoted(rest, '\'', backslash)
+                .ok_or_else(|| syntax_error(sql, start, "unterminated string"))?;
+            i += len;
+            tokens.push(Token::new(TokenKind::Str, text, start, i));
+        } else if c == '"' || c == '`' || (c == '[' && dialect == DatabaseType::SQLite) {
+            let close = if c == '[' { ']' } else { c };
+            let (text, len) = quoted(rest, close, false)
+                .ok_or_else(|| syntax_error(sql, start, "unterminated identifier"))?;
+            i += len;
+            tokens.push(Token::new(TokenKind::Quoted, text, start, i));
+        } else if let Some((text, len)) = dollar_quoted(rest) {
+            i += len;
+            tokens.push(Token::new(TokenKind::Str, text, start, i));
+        } else if c.is_ascii_digit()
+            || (c == '.' && rest[1..].starts_with(|c: char| c.is_ascii_digit()))
+        {
+            i += 1;
+            while i < sql.len() {
+                let b = bytes[i];
+                let exponent_sign = (b == b'+' || b == b'-') && matches!(bytes[i - 1], b'e' | b'E');
+                if b.is_ascii_alphanumeric() || b == b'.' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::new(TokenKind::Number, &sql[start..i], start, i));
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            i += len;
+            tokens.push(Token::new(TokenKind::Word, &sql[start..i], start, i));
+        } else {
+            let len = OPERATORS
+                .iter()
+                .find(|op| rest.starts_with(*op))
+                .map_or(c.len_utf8(), |op| op.len());
+            i += len;
+            tokens.push(Token::new(TokenKind::Punct, &sql[start..i], start, i));
+        }
+    }
+
+    Ok(tokens)
+}
+
+impl Token {
+    fn new(kind: TokenKind, text: impl Into<String>, start: usize, end: usize) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            start,
+            end,
+        }
+    }
+}
+
+/// Read a literal that opens with `rest`'s first character and closes with
+/// `close`, where a doubled `close` stands for itself. Returns the contents
+/// and the literal's length in bytes.
+fn quoted(rest: &str, close: char, backslash_escapes: bool) -> Option<(String, usize)> {
+    let mut text = String::new();
+    let mut chars = rest.char_indices().skip(1).peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if backslash_escapes && c == '\\' {
+            let (_, escaped) = chars.next()?;
+            text.push(match escaped {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                '0' => '\0',
+                other => other,
+            });
+        } else if c == close {
+            if chars.peek().is_some_and(|&(_, next)| next == close) {
+                chars.next();
+                text.push(close);
+            } else {
+                return Some((text, i + c.len_utf8()));
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    None
+}
+
+/// Read a PostgreSQL `$tag$ … $tag$` string
+fn dollar_quoted(rest: &str) -> Option<(String, usize)> {
+    if !rest.starts_with('$') {
+        return None;
+    }
+    let tag_len = rest[1..].find('$')? + 2;
+    let tag = &rest[..tag_len];
+    if !tag[1..tag_len - 1]
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    let body_len = rest[tag_len..].find(tag)?;
+    Some((
+        rest[tag_len..tag_len + body_len].to_string(),
+        tag_len + body_len + tag_len,
+    ))
+}
+
+/// The source text a run of tokens was read from
+fn span<'a>(sql: &'a str, tokens: &[Token]) -> &'a str {
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => &sql[first.start..last.end],
+        _ => "",
+    }
+}
+
+fn syntax_error(sql: &str, offset: usize, message: &str) -> EngineError {
+    let line = sql[..offset.min(sql.len())].matches('\n').count() + 1;
+    EngineError::Validation(format!("{} on line {}", message, line))
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// A cursor over the tokens of one statement, or part of one
+struct Cursor<'a> {
+    sql: &'a str,
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(sql: &'a str, tokens: &'a [Token]) -> Self {
+        Self {
+            sql,
+            tokens,
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += usize::from(token.is_some());
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn rest(&self) -> &'a [Token] {
+        &self.tokens[self.pos.min(self.tokens.len())..]
+    }
+
+    fn error(&self, message: &str) -> EngineError {
+        let offset = self
+            .peek()
+            .or(self.tokens.last())
+            .map_or(0, |token| token.start);
+        syntax_error(self.sql, offset, message)
+    }
+
+    fn at_kw(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_kw(keyword))
+    }
+
+    fn eat_kw(&mut self, keyword: &str) -> bool {
+        let found = self.at_kw(keyword);
+        self.pos += usize::from(found);
+        found
+    }
+
+    /// Consume a run of keywords, only if all of them are next
+    fn eat_kws(&mut self, keywords: &[&str]) -> bool {
+        let found = keywords
+            .iter()
+            .enumerate()
+            .all(|(i, kw)| self.tokens.get(self.pos + i).is_some_and(|t| t.is_kw(kw)));
+        if found {
+            self.pos += keywords.len();
+        }
+        found
+    }
+
+    fn eat_any_kw(&mut self, keywords: &[&str]) -> bool {
+        keywords.iter().any(|kw| self.eat_kw(kw))
+    }
+
+    fn expect_kw(&mut self, keyword: &str) -> EngineResult<()> {
+        if self.eat_kw(keyword) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {}", keyword)))
+        }
+    }
+
+    fn at_punct(&self, punct: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_punct(punct))
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let found = self.at_punct(punct);
+        self.pos += usize::from(found);
+        found
+    }
+
+    fn ident(&mut self) -> EngineResult<String> {
+        match self.peek().and_then(Token::ident) {
+            Some(ident) => {
+                self.pos += 1;
+                Ok(ident)
+            }
+            None => Err(self.error("expected a name")),
+        }
+    }
+
+    /// A dotted name, e.g. `public.users.email`
+    fn name_parts(&mut self) -> EngineResult<Vec<String>> {
+        let mut parts = vec![self.ident()?];
+        while self.eat_punct(".") {
+            parts.push(self.ident()?);
+        }
+        Ok(parts)
+    }
+
+    /// A possibly schema-qualified name: `(schema, name)`
+    fn qualified_name(&mut self) -> EngineResult<(Option<String>, String)> {
+        let mut parts = self.name_parts()?;
+        let name = parts.pop().unwrap_or_default();
+        Ok((parts.pop(), name))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let token = self.peek().filter(|t| t.kind == TokenKind::Str)?;
+        self.pos += 1;
+        Some(token.text.clone())
+    }
+
+    /// A parenthesized, comma-separated list; returns the items' tokens
+    fn group(&mut self) -> EngineResult<Vec<&'a [Token]>> {
+        if !self.eat_punct("(") {
+            return Err(self.error("expected '('"));
+        }
+        let tokens = self.tokens;
+        let mut items = Vec::new();
+        let mut item_start = self.pos;
+        let mut depth = 0;
+
+        while let Some(token) = self.next() {
+            if token.kind != TokenKind::Punct {
+                continue;
+            }
+            match token.text.as_str() {
+                "(" => depth += 1,
+                ")" if depth > 0 => depth -= 1,
+                ")" => {
+                    let item = &tokens[item_start..self.pos - 1];
+                    if !item.is_empty() || !items.is_empty() {
+                        items.push(item);
+                    }
+                    return Ok(items);
+                }
+                "," if depth == 0 => {
+                    items.push(&tokens[item_start..self.pos - 1]);
+                    item_start = self.pos;
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unbalanced parentheses"))
+    }
+
+    /// A parenthesized list of plain column names
+    fn column_list(&mut self) -> EngineResult<Vec<String>> {
+        self.group()?
+            .into_iter()
+            .map(|item| plain_column(item).ok_or_else(|| self.error("expected a column name")))
+            .collect()
+    }
+
+    /// A column type such as `VARCHAR(255)`, `double precision`,
+    /// `timestamp(3) with time zone`, `int unsigned` or `text[]`
+    fn type_spec(&mut self) -> EngineResult<TypeSpec> {
+        let (_, first) = self.qualified_name()?;
+        let mut name = first.to_ascii_lowercase();
+        while let Some(next) = self.peek().filter(|t| t.kind == TokenKind::Word) {
+            let next = next.text.to_ascii_lowercase();
+            let joins = matches!(
+                (name.as_str(), next.as_str()),
+                ("double", "precision")
+                    | (
+                        "character" | "char" | "bit" | "national character",
+                        "varying"
+                    )
+                    | ("national", "character" | "char")
+            );
+            if !joins {
+                break;
+            }
+            name = format!("{} {}", name, next);
+            self.pos += 1;
+        }
+
+        let args = if self.at_punct("(") {
+            let sql = self.sql;
+            self.group()?
+                .into_iter()
+                .map(|item| match item {
+                    [token] if token.kind == TokenKind::Str => token.text.clone(),
+                    _ => span(sql, item).to_string(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        if self.eat_kws(&["WITH", "TIME", "ZONE"]) {
+            name.push_str(" with time zone");
+        } else {
+            self.eat_kws(&["WITHOUT", "TIME", "ZONE"]);
+        }
+
+        let mut unsigned = false;
+        loop {
+            if self.eat_kw("UNSIGNED") {
+                unsigned = true;
+            } else if !self.eat_any_kw(&["SIGNED", "ZEROFILL"]) {
+                break;
+            }
+        }
+
+        let mut array = self.eat_kw("ARRAY");
+        while self.eat_punct("[") {
+            while !self.eat_punct("]") {
+                self.next().ok_or_else(|| self.error("expected ']'"))?;
+            }
+            array = true;
+        }
+
+        Ok(TypeSpec {
+            name,
+            args,
+            unsigned,
+            array,
+        })
+    }
+
+    /// A default value expression: a literal, function call or parenthesized
+    /// expression, with any casts and binary operators
+    fn expression(&mut self) -> EngineResult<&'a [Token]> {
+        let start = self.pos;
+        self.operand()?;
+        loop {
+            if self.eat_punct("::") {
+                self.type_spec()?;
+            } else if self.peek().is_some_and(|t| {
+                t.kind == TokenKind::Punct
+                    && matches!(t.text.as_str(), "+" | "-" | "*" | "/" | "%" | "||")
+            }) {
+                self.pos += 1;
+                self.operand()?;
+            } else {
+                break;
+            }
+        }
+        Ok(&self.tokens[start..self.pos])
+    }
+
+    fn operand(&mut self) -> EngineResult<()> {
+        let token = self.peek().ok_or_else(|| self.error("expected a value"))?;
+        if token.is_punct("(") {
+            self.group()?;
+        } else if token.is_punct("-") || token.is_punct("+") {
+            self.pos += 1;
+            self.operand()?;
+        } else {
+            self.pos += 1;
+            if matches!(token.kind, TokenKind::Word | TokenKind::Quoted) {
+                if self.at_punct("(") {
+                    self.group()?;
+                } else if self.eat_punct("[") {
+                    // ARRAY[...]
+                    while !self.eat_punct("]") {
+                        self.next().ok_or_else(|| self.error("expected ']'"))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `REFERENCES table [(columns)] [ON DELETE …] [ON UPDATE …] [MATCH …]`
+    fn references(&mut self, columns: Vec<String>) -> EngineResult<ForeignKey> {
+        self.expect_kw("REFERENCES")?;
+        let (_, table) = self.qualified_name()?;
+        let ref_columns = if self.at_punct("(") {
+            self.column_list()?
+        } else {
+            Vec::new()
+        };
+
+        let mut fk = ForeignKey {
+            columns,
+            table,
+            ref_columns,
+            on_delete: ReferentialAction::NoAction,
+            on_update: ReferentialAction::NoAction,
+        };
+        loop {
+            if self.eat_kws(&["ON", "DELETE"]) {
+                fk.on_delete = self.action()?;
+            } else if self.eat_kws(&["ON", "UPDATE"]) {
+                fk.on_update = self.action()?;
+            } else if self.eat_kw("MATCH") {
+                self.next();
+            } else {
+                return Ok(fk);
+            }
+        }
+    }
+
+    fn action(&mut self) -> EngineResult<ReferentialAction> {
+        if self.eat_kw("CASCADE") {
+            Ok(ReferentialAction::Cascade)
+        } else if self.eat_kw("RESTRICT") {
+            Ok(ReferentialAction::Restrict)
+        } else if self.eat_kws(&["SET", "NULL"]) {
+            Ok(ReferentialAction::SetNull)
+        } else if self.eat_kws(&["SET", "DEFAULT"]) {
+            Ok(ReferentialAction::SetDefault)
+        } else if self.eat_kws(&["NO", "ACTION"]) {
+            Ok(ReferentialAction::NoAction)
+        } else {
+            Err(self.error("expected a referential action"))
+        }
+    }
+}
+
+/// The column an index or key list item names, unless it is an expression.
+/// Allows a sort order, collation or operator class after the name, and a
+/// MySQL prefix length such as `name(10)`.
+fn plain_column(item: &[Token]) -> Option<String> {
+    let (first, rest) = item.split_first()?;
+    let name = first.ident()?;
+    match rest {
+        [] => Some(name),
+        [next, ..] if next.kind == TokenKind::Word => Some(name),
+        [open, length, close, ..]
+            if open.is_punct("(") && length.kind == TokenKind::Number && close.is_punct(")") =>
+        {
+            Some(name)
+        }
+        _ => None,
+    }
+}
+
+/// Keywords that start a column constraint rather than a type
+const COLUMN_CONSTRAINTS: [&str; 11] = [
+    "CONSTRAINT",
+    "NOT",
+    "NULL",
+    "PRIMARY",
+    "UNIQUE",
+    "DEFAULT",
+    "REFERENCES",
+    "CHECK",
+    "COLLATE",
+    "GENERATED",
+    "AUTOINCREMENT",
+];
+
+// ============================================================================
+// Schema Reader
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct TypeSpec {
+    /// Lowercase type name, e.g. `character varying`
+    name: String,
+    /// Parenthesized arguments: lengths, precisions or enum values
+    args: Vec<String>,
+    unsigned: bool,
+    array: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Column {
+    name: String,
+    ty: Option<TypeSpec>,
+    not_null: bool,
+    unique: bool,
+    auto_increment: bool,
+    default: Option<Vec<Token>>,
+    comment: Option<String>,
+    generated: bool,
+}
+
+#[derive(Debug, Clone)]
+struct ForeignKey {
+    columns: Vec<String>,
+    table: String,
+    /// Empty when the key references the target's primary key implicitly
+    ref_columns: Vec<String>,
+    on_delete: ReferentialAction,
+    on_update: ReferentialAction,
+}
+
+#[derive(Debug, Clone)]
+struct Table {
+    schema: Option<String>,
+    name: String,
+    comment: Option<String>,
+    /// Storage options after the column list, as (keyword, value)
+    options: Vec<(&'static str, String)>,
+    columns: Vec<Column>,
+    primary_key: Vec<String>,
+    unique: Vec<Vec<String>>,
+    indexes: Vec<(Vec<String>, IndexType)>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+impl Table {
+    fn column_mut(&mut self, name: &str) -> Option<&mut Column> {
+        self.columns
+            .iter_mut()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Collects tables and enum types statement by statement, then turns them
+/// into entities in [`finish`](Self::finish)
+struct SchemaReader<'a> {
+    sql: &'a str,
+    dialect: DatabaseType,
+    tables: Vec<Table>,
+    /// `CREATE TYPE … AS ENUM` types by SQL name
+    enum_types: Vec<(String, EnumDef)>,
+    warnings: Vec<ImportWarning>,
+}
+
+impl<'a> SchemaReader<'a> {
+    fn new(sql: &'a str, dialect: DatabaseType) -> Self {
+        Self {
+            sql,
+            dialect,
+            tables: Vec::new(),
+            enum_types: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn warn(&mut self, table: Option<&str>, message: impl Into<String>) {
+        self.warnings.push(ImportWarning {
+            table: table.map(str::to_string),
+            message: message.into(),
+        });
+    }
+
+    fn warn_table(&mut self, table: usize, message: impl Into<String>) {
+        let name = self.tables[table].name.clone();
+        self.warn(Some(&name), message);
+    }
+
+    fn table_index(&self, name: &str) -> Option<usize> {
+        self.tables
+            .iter()
+            .position(|t| t.name.eq_ignore_ascii_case(name))
+    }
+
+    fn statement(&mut self, tokens: &'a [Token]) -> EngineResult<()> {
+        let mut p = Cursor::new(self.sql, tokens);
+
+        if p.eat_kw("CREATE") {
+            p.eat_kws(&["OR", "REPLACE"]);
+            while p.eat_any_kw(&["TEMP", "TEMPORARY", "UNLOGGED", "GLOBAL", "LOCAL"]) {}
+            if p.eat_kw("TABLE") {
+                return self.create_table(p);
+            }
+            let unique = p.eat_kw("UNIQUE");
+            if p.eat_kw("INDEX") {
+                return self.create_index(p, unique);
+            }
+            if p.eat_kw("TYPE") {
+                return self.create_type(p);
+            }
+            // Objects with behavior the generated code won't have
+            if let Some(kind) = p
+                .peek()
+                .filter(|t| {
+                    [
+                        "VIEW",
+                        "MATERIALIZED",
+                        "TRIGGER",
+                        "FUNCTION",
+                        "PROCEDURE",
+                        "RULE",
+                    ]
+                    .iter()
+                    .any(|kw| t.is_kw(kw))
+                })
+                .map(|t| t.text.to_ascii_uppercase())
+            {
+                self.warn(None, format!("skipped CREATE {}", kind));
+            }
+        } else if p.eat_kws(&["ALTER", "TABLE"]) {
+            return self.alter_table(p);
+        } else if p.eat_kws(&["COMMENT", "ON"]) {
+            return self.comment_on(p);
+        }
+        Ok(())
+    }
+
+    fn create_table(&mut self, mut p: Cursor<'a>) -> EngineResult<()> {
+        p.eat_kws(&["IF", "NOT", "EXISTS"]);
+        let (schema, name) = p.qualified_name()?;
+
+        if self.table_index(&name).is_some() {
+            self.warn(
+                Some(&name),
+                "defined more than once; the first definition is kept",
+            );
+            return Ok(());
+        }
+        if !p.at_punct("(") {
+            self.warn(
+                Some(&name),
+                "CREATE TABLE without a column list is not imported",
+            );
+            return Ok(());
+        }
+
+        self.tables.push(Table {
+            schema,
+            name,
+            comment: None,
+            options: Vec::new(),
+            columns: Vec::new(),
+            primary_key: Vec::new(),
+            unique: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+        });
+        let table = self.tables.len() - 1;
+
+        let elements = p.group().and_then(|items| {
+            items
+                .into_iter()
+                .try_for_each(|i| self.table_element(table, i))
+        });
+        if let Err(e) = elements {
+            self.tables.pop();
+            return Err(e);
+        }
+
+        // Table options, e.g. `ENGINE=InnoDB DEFAULT CHARSET=utf8mb4
+        // COMMENT='…'` on MySQL or `TABLESPACE fast` on PostgreSQL
+        while let Some(token) = p.next() {
+            if token.is_kw("COMMENT") {
+                p.eat_punct("=");
+                self.tables[table].comment = p.string();
+                continue;
+            }
+            let key = if token.is_kw("ENGINE") {
+                "engine"
+            } else if token.is_kw("CHARSET") || (token.is_kw("CHARACTER") && p.eat_kw("SET")) {
+                "charset"
+            } else if token.is_kw("COLLATE") {
+                "collation"
+            } else if token.is_kw("TABLESPACE") {
+                "tablespace"
+            } else {
+                continue;
+            };
+            p.eat_punct("=");
+            if let Ok(value) = p.ident() {
+                self.tables[table].options.push((key, value));
+            }
+        }
+        Ok(())
+    }
+
+    /// A column definition or table constraint inside `CREATE TABLE (…)` or
+    /// after `ALTER TABLE … ADD`
+    fn table_element(&mut self, table: usize, item: &'a [Token]) -> EngineResult<()> {
+        let mut p = Cursor::new(self.sql, item);
+        if p.eat_kw("CONSTRAINT") {
+            p.ident()?;
+        }
+
+        if p.eat_kws(&["PRIMARY", "KEY"]) {
+            self.tables[table].primary_key = p.column_list()?;
+        } else if p.eat_kw("UNIQUE") {
+            p.eat_any_kw(&["KEY", "INDEX"]);
+            if !p.at_punct("(") {
+                p.ident()?;
+            }
+            let columns = p.column_list()?;
+            self.tables[table].unique.push(columns);
+        } else if p.eat_kws(&["FOREIGN", "KEY"]) {
+            if !p.at_punct("(") {
+                p.ident()?;
+            }
+            let columns = p.column_list()?;
+            let fk = p.references(columns)?;
+            self.tables[table].foreign_keys.push(fk);
+        } else if p.eat_any_kw(&["KEY", "INDEX"]) {
+            if !p.at_punct("(") && !p.at_kw("USING") {
+                p.ident()?;
+            }
+            let mut index_type = IndexType::BTree;
+            if p.eat_kw("USING") {
+                index_type = self.index_method(table, &p.ident()?);
+            }
+            let items = p.group()?;
+            if p.eat_kw("USING") {
+                index_type = self.index_method(table, &p.ident()?);
+            }
+            self.add_index(table, &items, false, index_type);
+        } else if p.at_kw("FULLTEXT") || p.at_kw("SPATIAL") {
+            self.warn_table(table, "full-text and spatial indexes are not imported");
+        } else if p.at_kw("CHECK") || p.at_kw("EXCLUDE") {
+            let text = span(self.sql, item).to_string();
+            self.warn_table(table, format!("constraint `{}` is not imported", text));
+        } else if p.at_kw("LIKE") {
+            self.warn_table(table, "columns copied with LIKE are not imported");
+        } else {
+            self.column(table, p)?;
+        }
+        Ok(())
+    }
+
+    fn column(&mut self, table: usize, mut p: Cursor<'a>) -> EngineResult<()> {
+        let name = p.ident()?;
+        let ty = if p.at_end() || COLUMN_CONSTRAINTS.iter().any(|kw| p.at_kw(kw)) {
+            None
+        } else {
+            Some(p.type_spec()?)
+        };
+
+        let mut column = Column {
+            name,
+            ty,
+            not_null: false,
+            unique: false,
+            auto_increment: false,
+            default: None,
+            comment: None,
+            generated: false,
+        };
+        let mut primary_key = false;
+
+        while !p.at_end() {
+            if p.eat_kw("CONSTRAINT") {
+                p.ident()?;
+            } else if p.eat_kws(&["NOT", "NULL"]) {
+                column.not_null = true;
+            } else if p.eat_kws(&["PRIMARY", "KEY"]) {
+                primary_key = true;
+                column.not_null = true;
+            } else if p.eat_kw("UNIQUE") {
+                p.eat_kw("KEY");
+                column.unique = true;
+            } else if p.eat_any_kw(&["AUTO_INCREMENT", "AUTOINCREMENT"]) {
+                column.auto_increment = true;
+            } else if p.eat_kw("DEFAULT") {
+                let expression = p.expression()?;
+                if expression.first().is_some_and(|t| t.is_kw("nextval")) {
+                    column.auto_increment = true;
+                } else {
+                    column.default = Some(expression.to_vec());
+                }
+            } else if p.at_kw("REFERENCES") {
+                let fk = p.references(vec![column.name.clone()])?;
+                self.tables[table].foreign_keys.push(fk);
+            } else if p.eat_kw("GENERATED") {
+                p.eat_kw("ALWAYS");
+                p.eat_kws(&["BY", "DEFAULT"]);
+                p.expect_kw("AS")?;
+                if p.eat_kw("IDENTITY") {
+                    column.auto_increment = true;
+                } else {
+                    column.generated = true;
+                }
+                if p.at_punct("(") {
+                    p.group()?;
+                }
+            } else if p.eat_kw("AS") {
+                p.group()?;
+                column.generated = true;
+            } else if p.eat_kw("CHECK") {
+                let check = p.rest();
+                p.group()?;
+                let text = span(self.sql, &check[..check.len() - p.rest().len()]).to_string();
+                self.warn_table(
+                    table,
+                    format!("`{}` CHECK {} is not imported", column.name, text),
+                );
+            } else if p.eat_kw("COMMENT") {
+                column.comment = p.string();
+            } else if p.eat_kw("COLLATE") || p.eat_kws(&["CHARACTER", "SET"]) || p.eat_kw("CHARSET")
+            {
+                p.next();
+            } else if p.eat_kws(&["ON", "UPDATE"]) {
+                // MySQL `ON UPDATE CURRENT_TIMESTAMP`
+                p.expression()?;
+            } else {
+                // NULL, STORED, VIRTUAL, DEFERRABLE, …
+                p.next();
+            }
+        }
+
+        let target = &mut self.tables[table];
+        if primary_key {
+            target.primary_key = vec![column.name.clone()];
+        }
+        target.columns.push(column);
+        Ok(())
+    }
+
+    fn create_index(&mut self, mut p: Cursor<'a>, unique: bool) -> EngineResult<()> {
+        p.eat_kw("CONCURRENTLY");
+        p.eat_kws(&["IF", "NOT", "EXISTS"]);
+        if !p.at_kw("ON") {
+            p.qualified_name()?;
+        }
+        p.expect_kw("ON")?;
+        p.eat_kw("ONLY");
+        let (_, table_name) = p.qualified_name()?;
+        let Some(table) = self.table_index(&table_name) else {
+            self.warn(
+                Some(&table_name),
+                "index on an unknown table is not imported",
+            );
+            return Ok(());
+        };
+
+        let mut index_type = IndexType::BTree;
+        if p.eat_kw("USING") {
+            index_type = self.index_method(table, &p.ident()?);
+        }
+        let items = p.group()?;
+        if p.eat_kw("USING") {
+            index_type = self.index_method(table, &p.ident()?);
+        }
+        if p.at_kw("WHERE") {
+            self.warn_table(table, "partial index imported over the whole table");
+        }
+        self.add_index(table, &items, unique, index_type);
+        Ok(())
+    }
+
+    fn add_index(&mut self, table: usize, items: &[&[Token]], unique: bool, kind: IndexType) {
+        let Some(columns) = items
+            .iter()
+            .map(|item| plain_column(item))
+            .collect::<Option<Vec<_>>>()
+        else {
+            let text: Vec<&str> = items.iter().map(|item| span(self.sql, item)).collect();
+            self.warn_table(
+                table,
+                format!("expression index ({}) is not imported", text.join(", ")),
+            );
+            return;
+        };
+
+        let target = &mut self.tables[table];
+        if unique {
+            target.unique.push(columns);
+        } else {
+            target.indexes.push((columns, kind));
+        }
+    }
+
+    fn index_method(&mut self, table: usize, method: &str) -> IndexType {
+        match method.to_ascii_lowercase().as_str() {
+            "btree" => IndexType::BTree,
+            "hash" => IndexType::Hash,
+            "gin" => IndexType::Gin,
+            "gist" => IndexType::Gist,
+            other => {
+                self.warn_table(table, format!("{} index imported as a B-tree", other));
+                IndexType::BTree
+            }
+        }
+    }
+
+    /// `CREATE TYPE name AS ENUM ('a', 'b')`; other kinds of type are skipped
+    fn create_type(&mut self, mut p: Cursor<'a>) -> EngineResult<()> {
+        let (_, name) = p.qualified_name()?;
+        if !p.eat_kws(&["AS", "ENUM"]) {
+            self.warn(
+                None,
+                format!("skipped type {}; only enum types are imported", name),
+            );
+            return Ok(());
+        }
+
+        let mut variants = Vec::new();
+        for item in p.group()? {
+            match item {
+                [value] if value.kind == TokenKind::Str => variants.push(value.text.clone()),
+                _ => return Err(p.error("expected an enum value")),
+            }
+        }
+        let def = EnumDef::new(to_pascal_case(&name)).with_variants(variants);
+        self.enum_types.push((name, def));
+        Ok(())
+    }
+
+    /// `ALTER TABLE … ADD …` constraints and columns, and the
+    /// `ALTER COLUMN … SET DEFAULT / SET NOT NULL / ADD GENERATED` actions
+    /// pg_dump writes after the tables
+    fn alter_table(&mut self, mut p: Cursor<'a>) -> EngineResult<()> {
+        p.eat_kws(&["IF", "EXISTS"]);
+        p.eat_kw("ONLY");
+        let (_, name) = p.qualified_name()?;
+        let Some(table) = self.table_index(&name) else {
+            return Ok(());
+        };
+
+        let rest = p.rest();
+        let mut depth = 0;
+        let actions = rest.split(|t| {
+            if t.is_punct("(") {
+                depth += 1;
+            } else if t.is_punct(")") {
+                depth -= 1;
+            }
+            depth == 0 && t.is_punct(",")
+        });
+
+        for action in actions {
+            let mut a = Cursor::new(self.sql, action);
+            if a.eat_kw("ADD") {
+                if a.at_kw("CONSTRAINT")
+                    || ["PRIMARY", "UNIQUE", "FOREIGN", "KEY", "INDEX", "CHECK"]
+                        .iter()
+                        .any(|kw| a.at_kw(kw))
+                {
+                    self.table_element(table, a.rest())?;
+                } else {
+                    a.eat_kw("COLUMN");
+                    a.eat_kws(&["IF", "NOT", "EXISTS"]);
+                    self.column(table, a)?;
+                }
+            } else if a.eat_kw("ALTER") {
+                a.eat_kw("COLUMN");
+                let column_name = a.ident()?;
+                let Some(column) = self.tables[table].column_mut(&column_name) else {
+                    continue;
+                };
+                if a.eat_kws(&["SET", "DEFAULT"]) {
+                    let expression = a.expression()?;
+                    if expression.first().is_some_and(|t| t.is_kw("nextval")) {
+                        column.auto_increment = true;
+                    } else {
+                        column.default = Some(expression.to_vec());
+                    }
+                } else if a.eat_kws(&["SET", "NOT", "NULL"]) {
+                    column.not_null = true;
+                } else if a.eat_kws(&["ADD", "GENERATED"]) {
+                    column.auto_increment = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `COMMENT ON TABLE t IS '…'` and `COMMENT ON COLUMN t.c IS '…'`
+    fn comment_on(&mut self, mut p: Cursor<'a>) -> EngineResult<()> {
+        let on_column = if p.eat_kw("TABLE") {
+            false
+        } else if p.eat_kw("COLUMN") {
+            true
+        } else {
+            return Ok(());
+        };
+        let mut parts = p.name_parts()?;
+        p.expect_kw("IS")?;
+        let comment = p.string();
+
+        let column = if on_column { parts.pop() } else { None };
+        let Some(table) = parts.pop().and_then(|name| self.table_index(&name)) else {
+            return Ok(());
+        };
+        match column {
+            Some(column) => {
+                if let Some(column) = self.tables[table].column_mut(&column) {
+                    column.comment = comment;
+                }
+            }
+            None => self.tables[table].comment = comment,
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Building entities
+    // ========================================================================
+
+    fn finish(mut self) -> ImportedSchema {
+        let tables = std::mem::take(&mut self.tables);
+        let mut enums: Vec<EnumDef> = self.enum_types.iter().map(|(_, def)| def.clone()).collect();
+
+        let mut names = HashSet::new();
+        let mut entities = Vec::with_capacity(tables.len());
+        for table in &tables {
+            let base = entity_name(&table.name);
+            let mut name = base.clone();
+            let mut n = 2;
+            while !names.insert(name.clone()) {
+                name = format!("{}{}", base, n);
+                n += 1;
+            }
+            if name != base {
+                self.warn(
+                    Some(&table.name),
+                    format!("imported as {} because {} is taken", name, base),
+                );
+            }
+            entities.push(self.entity(table, name, &mut enums));
+        }
+
+        let relationships = self.relationships(&tables, &mut entities);
+
+        ImportedSchema {
+            dialect: self.dialect,
+            entities,
+            relationships,
+            enums,
+            warnings: self.warnings,
+        }
+    }
+
+    fn entity(&mut self, table: &Table, name: String, enums: &mut Vec<EnumDef>) -> Entity {
+        let mut entity = Entity::new(name).with_table_name(&table.name);
+        entity.fields.clear();
+        entity.config.timestamps = false;
+        entity.description = table.comment.clone();
+        if self.dialect == DatabaseType::PostgreSQL {
+            entity.config.schema = table.schema.clone().filter(|s| s != "public");
+        }
+        for (key, value) in &table.options {
+            let config = &mut entity.config;
+            let setting = match (*key, self.dialect) {
+                ("tablespace", DatabaseType::PostgreSQL) => &mut config.tablespace,
+                ("engine", DatabaseType::MySQL) => &mut config.engine,
+                ("charset", DatabaseType::MySQL) => &mut config.charset,
+                ("collation", DatabaseType::MySQL) => &mut config.collation,
+                _ => continue,
+            };
+            *setting = Some(value.clone());
+        }
+
+        let single_pk = match table.primary_key.as_slice() {
+            [pk] => Some(pk.as_str()),
+            _ => None,
+        };
+        for column in &table.columns {
+            let mut field = self.field(table, column, &entity.name, enums);
+            if single_pk.is_some_and(|pk| pk.eq_ignore_ascii_case(&column.name)) {
+                self.make_primary_key(table, column, &mut field, &mut entity);
+            }
+            entity.add_field(field);
+        }
+
+        if single_pk.is_none() {
+            let key = self.field_names(table, &entity, &table.primary_key);
+            if entity.has_field("id") {
+                self.warn(
+                    Some(&table.name),
+                    "no single-column primary key, and `id` is already a column; add a key by hand",
+                );
+            } else {
+                entity.fields.insert(0, Field::primary_key());
+                if key.is_empty() {
+                    self.warn(Some(&table.name), "no primary key; added a UUID `id` key");
+                } else {
+                    self.warn(
+                        Some(&table.name),
+                        format!(
+                            "composite primary key ({}) imported as a unique constraint next to a UUID `id` key",
+                            key.join(", ")
+                        ),
+                    );
+                    entity.config.unique_together.push(key);
+                }
+            }
+        }
+
+        for columns in &table.unique {
+            let fields = self.field_names(table, &entity, columns);
+            match fields.as_slice() {
+                [] => {}
+                [one] => {
+                    if let Some(field) = entity.get_field_by_name_mut(one) {
+                        field.unique = true;
+                    }
+                }
+                _ if !entity.config.unique_together.contains(&fields) => {
+                    entity.config.unique_together.push(fields);
+                }
+                _ => {}
+            }
+        }
+        for (columns, index_type) in &table.indexes {
+            let fields = self.field_names(table, &entity, columns);
+            match fields.as_slice() {
+                [] => {}
+                [one] if *index_type == IndexType::BTree => {
+                    if let Some(field) = entity.get_field_by_name_mut(one) {
+                        field.indexed = true;
+                    }
+                }
+                _ => entity
+                    .config
+                    .indexes
+                    .push(EntityIndex::new(fields).with_type(*index_type)),
+            }
+        }
+
+        detect_timestamps(&mut entity);
+
+        entity
+    }
+
+    fn field(
+        &mut self,
+        table: &Table,
+        column: &Column,
+        entity_name: &str,
+        enums: &mut Vec<EnumDef>,
+    ) -> Field {
+        let name = to_snake_case(&column.name);
+        let mapped = self.map_column_type(table, column, entity_name, &name, enums);
+
+        let mut field = Field::new(&name, mapped.data_type);
+        field.column_name = column.name.clone();
+        field.required = column.not_null;
+        field.unique = column.unique;
+        field.description = column.comment.clone();
+        if let Some(max) = mapped.max_length {
+            field = field.with_validation(Validation::MaxLength(max));
+        }
+        if let Some(default) = &column.default {
+            field.default_value = default_value(self.sql, default, &field.data_type);
+        }
+        if column.generated {
+            self.warn(
+                Some(&table.name),
+                format!(
+                    "generated column `{}` imported as a plain field",
+                    column.name
+                ),
+            );
+        }
+        field
+    }
+
+    fn map_column_type(
+        &mut self,
+        table: &Table,
+        column: &Column,
+        entity_name: &str,
+        field_name: &str,
+        enums: &mut Vec<EnumDef>,
+    ) -> MappedType {
+        let Some(spec) = &column.ty else {
+            self.warn(
+                Some(&table.name),
+                format!("`{}` has no declared type; imported as String", column.name),
+            );
+            return MappedType::new(DataType::String);
+        };
+
+        let user_enum = self
+            .enum_types
+            .iter()
+            .find(|(sql_name, _)| sql_name.eq_ignore_ascii_case(&spec.name))
+            .map(|(_, def)| def.data_type());
+
+        let mapped = if let Some(data_type) = user_enum {
+            MappedType::new(data_type)
+        } else if spec.name == "enum" && !spec.args.is_empty() {
+            // MySQL inline enum: one project enum per column
+            let name = format!("{}{}", entity_name, to_pascal_case(field_name));
+            let def = EnumDef::new(name).with_variants(spec.args.iter().cloned());
+            let data_type = def.data_type();
+            enums.push(def);
+            MappedType::new(data_type)
+        } else if let Some(mapped) = map_type(spec, self.dialect) {
+            if let Some(note) = &mapped.note {
+                self.warn(Some(&table.name), format!("`{}`: {}", column.name, note));
+            }
+            mapped
+        } else {
+            self.warn(
+                Some(&table.name),
+                format!(
+                    "`{}` has unsupported type {}; imported as String",
+                    column.name, spec.name
+                ),
+            );
+            MappedType::new(DataType::String)
+        };
+
+        if spec.array {
+            MappedType {
+                data_type: DataType::Array(Box::new(mapped.data_type)),
+                max_length: None,
+                ..mapped
+            }
+        } else {
+            mapped
+        }
+    }
+
+    /// Mark `field` as the entity's primary key and pick the id type from
+    /// the column
+    fn make_primary_key(
+        &mut self,
+        table: &Table,
+        column: &Column,
+        field: &mut Field,
+        entity: &mut Entity,
+    ) {
+        field.is_primary_key = true;
+        field.required = true;
+        field.indexed = true;
+        field.readonly = true;
+        field.unique = false;
+
+        let serial = column.auto_increment
+            || column
+                .ty
+                .as_ref()
+                .is_some_and(|ty| ty.name.contains("serial"));
+        entity.config.id_type = match field.data_type {
+            DataType::Int32 | DataType::Int64 => {
+                let implicit_rowid = self.dialect == DatabaseType::SQLite;
+                if !serial && !implicit_rowid {
+                    self.warn(
+                        Some(&table.name),
+                        format!(
+                            "integer key `{}` has no auto-increment; generated code will add one",
+                            column.name
+                        ),
+                    );
+                }
+                field.default_value = None;
+                IdType::Serial
+            }
+            DataType::Uuid => IdType::Uuid,
+            _ => {
+                self.warn(
+                    Some(&table.name),
+                    format!(
+                        "primary key `{}` is not an integer or UUID; imported as a UUID key",
+                        column.name
+                    ),
+                );
+                field.data_type = DataType::Uuid;
+                field.validations.clear();
+                field.default_value = None;
+                IdType::Uuid
+            }
+        };
+    }
+
+    /// The names of the fields that hold `columns`, warning about any the
+    /// table doesn't have
+    fn field_names(&mut self, table: &Table, entity: &Entity, columns: &[String]) -> Vec<String> {
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            match field_for_column(entity, column) {
+                Some(field) => names.push(field.name.clone()),
+                None => {
+                    self.warn(
+                        Some(&table.name),
+                        format!("constraint names unknown column `{}`", column),
+                    );
+                    return Vec::new();
+                }
+            }
+        }
+        names
+    }
+
+    /// One relationship per single-column foreign key, flagging the key's
+    /// field as a foreign key
+    fn relationships(&mut self, tables: &[Table], entities: &mut [Entity]) -> Vec<Relationship> {
+        let mut relationships = Vec::new();
+        let mut names = HashSet::new();
+
+        for (from, table) in tables.iter().enumerate() {
+            for fk in &table.foreign_keys {
+                let [column] = fk.columns.as_slice() else {
+                    self.warn(
+                        Some(&table.name),
+                        format!(
+                            "composite foreign key ({}) → {} is not imported as a relationship",
+                            fk.columns.join(", "),
+                            fk.table
+                        ),
+                    );
+                    continue;
+                };
+                let Some(to) = tables
+                    .iter()
+                    .position(|t| t.name.eq_ignore_ascii_case(&fk.table))
+                else {
+                    self.warn(
+                        Some(&table.name),
+                        format!("`{}` references unknown table {}", column, fk.table),
+                    );
+                    continue;
+                };
+
+                let target = &entities[to];
+                let ref_field = match fk.ref_columns.as_slice() {
+                    [] => target.primary_key(),
+                    [ref_column] => field_for_column(target, ref_column),
+                    _ => None,
+                };
+                let Some(ref_field) = ref_field.map(|f| f.name.clone()) else {
+                    self.warn(
+                        Some(&table.name),
+                        format!("`{}` references an unknown column of {}", column, fk.table),
+                    );
+                    continue;
+                };
+                relationships.extend(link_foreign_key(
+                    entities,
+                    (from, column),
+                    (to, ref_field),
+                    (fk.on_delete, fk.on_update),
+                    &mut names,
+                ));
+            }
+        }
+
+        relationships
+    }
+}
+
+// ============================================================================
+// Type and default mapping
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct MappedType {
+    data_type: DataType,
+    /// `VARCHAR(n)` length, kept as a `MaxLength` validation
+    max_length: Option<usize>,
+    /// How the mapping lost information, if it did
+    note: Option<String>,
+}
+
+impl MappedType {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            max_length: None,
+            note: None,
+        }
+    }
+}
+
+/// Map a built-in SQL type; `None` for types the IR has no equivalent of
+fn map_type(spec: &TypeSpec, dialect: DatabaseType) -> Option<MappedType> {
+    let arg = |i: usize| spec.args.get(i).and_then(|a| a.trim().parse::<u32>().ok());
+
+    let data_type = match spec.name.as_str() {
+        "tinyint" if arg(0) == Some(1) => DataType::Bool,
+        "bit" if arg(0).is_none_or(|n| n == 1) => DataType::Bool,
+        "bool" | "boolean" => DataType::Bool,
+        "tinyint" | "smallint" | "int2" | "mediumint" | "year" => DataType::Int32,
+        "smallserial" | "serial2" | "serial" | "serial4" => DataType::Int32,
+        "int" | "integer" | "int4" if spec.unsigned || dialect == DatabaseType::SQLite => {
+            DataType::Int64
+        }
+        "int" | "integer" | "int4" => DataType::Int32,
+        "bigint" | "int8" | "bigserial" | "serial8" => DataType::Int64,
+        "real" | "float4" => DataType::Float32,
+        "float" if dialect == DatabaseType::MySQL && arg(0).is_none_or(|p| p <= 24) => {
+            DataType::Float32
+        }
+        "float" | "float8" | "double" | "double precision" => DataType::Float64,
+        "decimal" | "numeric" | "dec" => return Some(map_decimal(arg(0), arg(1), dialect)),
+        "money" => DataType::money(),
+        "uuid" | "uniqueidentifier" => DataType::Uuid,
+        "char" | "character" | "nchar" if arg(0) == Some(36) => DataType::Uuid,
+        "char"
+        | "character"
+        | "nchar"
+        | "varchar"
+        | "character varying"
+        | "nvarchar"
+        | "varchar2"
+        | "national character varying"
+        | "citext" => {
+            return Some(match arg(0) {
+                Some(n) if n > STRING_MAX_LENGTH => MappedType::new(DataType::Text),
+                Some(n) if n < STRING_MAX_LENGTH => MappedType {
+                    max_length: Some(n as usize),
+                    ..MappedType::new(DataType::String)
+                },
+                _ => MappedType::new(DataType::String),
+            });
+        }
+        "text" | "tinytext" | "mediumtext" | "longtext" | "clob" | "ntext" => DataType::Text,
+        "json" | "jsonb" => DataType::Json,
+        "bytea" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "binary" | "varbinary" => {
+            DataType::Bytes
+        }
+        "timestamp" | "timestamptz" | "timestamp with time zone" | "datetime" => DataType::DateTime,
+        "date" => DataType::Date,
+        "time" | "timetz" | "time with time zone" => DataType::Time,
+        // SQLite accepts any type name and picks a storage class from it
+        name if dialect == DatabaseType::SQLite => {
+            if name.contains("int") {
+                DataType::Int64
+            } else if ["char", "clob", "text"].iter().any(|s| name.contains(s)) {
+                DataType::Text
+            } else if name.contains("blob") {
+                DataType::Bytes
+            } else if ["real", "floa", "doub"].iter().any(|s| name.contains(s)) {
+                DataType::Float64
+            } else {
+                return None;
+            }
+        }
+        _ => return None,
+    };
+    Some(MappedType::new(data_type))
+}
+
+fn map_decimal(precision: Option<u32>, scale: Option<u32>, dialect: DatabaseType) -> MappedType {
+    let max = u32::from(DataType::MAX_DECIMAL_PRECISION);
+    let (precision, scale) = match precision {
+        Some(precision) => (precision, scale.unwrap_or(0)),
+        // MySQL's DECIMAL defaults to (10, 0); PostgreSQL's is unconstrained
+        None if dialect == DatabaseType::MySQL => (10, 0),
+        None => (max, 10),
+    };
+
+    let mut mapped = MappedType::new(DataType::decimal(
+        precision.min(max) as u8,
+        scale.min(precision.min(max)) as u8,
+    ));
+    if precision > max {
+        mapped.note = Some(format!(
+            "DECIMAL({}, {}) narrowed to the supported precision of {}",
+            precision, scale, max
+        ));
+    }
+    mapped
+}
+
+/// Map a `DEFAULT` expression; `None` for `DEFAULT NULL`. Anything that
+/// isn't a recognizable literal or function is kept as an expression.
+fn default_value(sql: &str, tokens: &[Token], data_type: &DataType) -> Option<DefaultValue> {
+    let mut tokens = tokens;
+    while wraps_in_parens(tokens) {
+        tokens = &tokens[1..tokens.len() - 1];
+    }
+    let expression = || DefaultValue::Expression(span(sql, tokens).to_string());
+
+    // `'draft'::post_status` is just 'draft'
+    let mut depth = 0;
+    let value = tokens
+        .iter()
+        .position(|t| {
+            if t.is_punct("(") {
+                depth += 1;
+            } else if t.is_punct(")") {
+                depth -= 1;
+            }
+            depth == 0 && t.is_punct("::")
+        })
+        .map_or(tokens, |cast| &tokens[..cast]);
+
+    let base = match data_type {
+        DataType::Optional(inner) => inner.as_ref(),
+        other => other,
+    };
+    let parsed = match value {
+        [token] if token.is_kw("NULL") => return None,
+        [token] => match token.kind {
+            TokenKind::Word => keyword_default(&token.text),
+            TokenKind::Number => number_default(&token.text, base),
+            TokenKind::Str => Some(string_default(&token.text, base)),
+            _ => None,
+        },
+        [sign, number] if sign.is_punct("-") && number.kind == TokenKind::Number => {
+            number_default(&format!("-{}", number.text), base)
+        }
+        [function, open, .., close] if open.is_punct("(") && close.is_punct(")") => {
+            match function.text.to_ascii_lowercase().as_str() {
+                "now" | "current_timestamp" | "localtimestamp" | "datetime" => {
+                    Some(DefaultValue::Now)
+                }
+                "gen_random_uuid" | "uuid_generate_v4" | "uuid" => Some(DefaultValue::Uuid),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    Some(parsed.unwrap_or_else(expression))
+}
+
+fn keyword_default(word: &str) -> Option<DefaultValue> {
+    match word.to_ascii_uppercase().as_str() {
+        "TRUE" => Some(DefaultValue::Bool(true)),
+        "FALSE" => Some(DefaultValue::Bool(false)),
+        "CURRENT_TIMESTAMP" | "LOCALTIMESTAMP" | "NOW" => Some(DefaultValue::Now),
+        _ => None,
+    }
+}
+
+fn number_default(text: &str, data_type: &DataType) -> Option<DefaultValue> {
+    match (data_type, text) {
+        (DataType::Bool, "0") => Some(DefaultValue::Bool(false)),
+        (DataType::Bool, "1") => Some(DefaultValue::Bool(true)),
+        _ => text
+            .parse::<i64>()
+            .map(DefaultValue::Int)
+            .or_else(|_| text.parse::<f64>().map(DefaultValue::Float))
+            .ok(),
+    }
+}
+
+/// A quoted default; MySQL quotes numbers too (`DEFAULT '0'`)
+fn string_default(text: &str, data_type: &DataType) -> DefaultValue {
+    let parsed = match data_type {
+        DataType::Bool => match text.to_ascii_lowercase().as_str() {
+            "t" | "true" | "1" => Some(DefaultValue::Bool(true)),
+            "f" | "false" | "0" => Some(DefaultValue::Bool(false)),
+            _ => None,
+        },
+        DataType::Int32 | DataType::Int64 => text.parse().ok().map(DefaultValue::Int),
+        DataType::Float32 | DataType::Float64 | DataType::Decimal { .. } => {
+            text.parse().ok().map(DefaultValue::Float)
+        }
+        DataType::Json if text == "{}" => Some(DefaultValue::EmptyObject),
+        DataType::Json | DataType::Array(_) if text == "[]" => Some(DefaultValue::EmptyArray),
+        DataType::Array(_) if text == "{}" => Some(DefaultValue::EmptyArray),
+        DataType::DateTime if text.eq_ignore_ascii_case("now") => Some(DefaultValue::Now),
+        _ => None,
+    };
+    parsed.unwrap_or_else(|| DefaultValue::String(text.to_string()))
+}
+
+/// Whether the first and last tokens are a matching pair of parentheses
+fn wraps_in_parens(tokens: &[Token]) -> bool {
+    if tokens.len() < 2 || !tokens[0].is_punct("(") || !tokens[tokens.len() - 1].is_punct(")") {
+        return false;
+    }
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_punct("(") {
+            depth += 1;
+        } else if token.is_punct(")") {
+            depth -= 1;
+            if depth == 0 {
+                return i == tokens.len() - 1;
+            }
+        }
+    }
+    false
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Enable timestamps when the entity has `created_at` and `updated_at`
+/// timestamp fields, and soft delete when it has `deleted_at`
+pub(crate) fn detect_timestamps(entity: &mut Entity) {
+    let is_timestamp = |entity: &Entity, name: &str| {
+        entity
+            .get_field_by_name(name)
+            .is_some_and(|f| matches!(f.data_type, DataType::DateTime) && !f.is_primary_key)
+    };
+    if is_timestamp(entity, "created_at") && is_timestamp(entity, "updated_at") {
+        entity.config.timestamps = true;
+        for name in ["created_at", "updated_at"] {
+            if let Some(field) = entity.get_field_by_name_mut(name) {
+                field.readonly = true;
+            }
+        }
+    }
+    if is_timestamp(entity, "deleted_at") {
+        entity.config.soft_delete = true;
+        if let Some(field) = entity.get_field_by_name_mut("deleted_at") {
+            field.readonly = true;
+            field.hidden = true;
+        }
+    }
+}
+
+/// Flag the field of `entities[from]` stored in `column` as a foreign key
+/// to `ref_field` of `entities[to]`, and build the relationship it backs,
+/// named uniquely among `names`. `None` if the entity has no such column.
+pub(crate) fn link_foreign_key(
+    entities: &mut [Entity],
+    (from, column): (usize, &str),
+    (to, ref_field): (usize, String),
+    (on_delete, on_update): (ReferentialAction, ReferentialAction),
+    names: &mut HashSet<String>,
+) -> Option<Relationship> {
+    let target = &entities[to];
+    let (target_id, target_name) = (target.id, target.name.clone());
+    // A key column has the type its generated primary key has
+    let key_type = target
+        .primary_key()
+        .filter(|pk| pk.name == ref_field)
+        .map(|_| match target.config.id_type {
+            IdType::Serial => DataType::Int32,
+            IdType::Uuid => DataType::Uuid,
+            IdType::Cuid | IdType::Ulid => DataType::String,
+        });
+
+    let entity = &mut entities[from];
+    let (entity_id, entity_name) = (entity.id, entity.name.clone());
+    let field = entity
+        .fields
+        .iter_mut()
+        .find(|f| f.column_name.eq_ignore_ascii_case(column))?;
+    field.is_foreign_key = true;
+    field.indexed = true;
+    if let Some(key_type) = key_type {
+        field.data_type = key_type;
+    }
+    field.foreign_key_ref = Some(
+        ForeignKeyRef::new(&target_name)
+            .with_entity_id(target_id)
+            .with_field(&ref_field)
+            .on_delete(on_delete)
+            .on_update(on_update),
+    );
+
+    let relation_type = if field.unique || field.is_primary_key {
+        RelationType::OneToOne
+    } else {
+        RelationType::ManyToOne
+    };
+    let mut name = generate_relationship_name(&entity_name, &target_name);
+    if names.contains(&name) {
+        let accessor = generate_accessor_name(&field.name, &target_name);
+        name = format!("{}{}", name, to_pascal_case(&accessor));
+    }
+    names.insert(name.clone());
+
+    let mut relationship = Relationship::new(entity_id, target_id, relation_type)
+        .with_name(name)
+        .with_from_field(&field.name)
+        .with_to_field(ref_field)
+        .on_delete(on_delete)
+        .on_update(on_update);
+    if !field.required {
+        relationship = relationship.optional();
+    }
+    Some(relationship)
+}
+
+fn field_for_column<'e>(entity: &'e Entity, column: &str) -> Option<&'e Field> {
+    entity
+        .fields
+        .iter()
+        .find(|f| f.column_name.eq_ignore_ascii_case(column))
+}
+
+/// Convert a column name to snake_case: `authorId` → `author_id`,
+/// `UserID` → `user_id`
+pub(crate) fn to_snake_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 4);
+    let mut prev_was_upper = false;
+
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && !prev_was_upper && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+            prev_was_upper = true;
+        } else if c.is_alphanumeric() {
+            result.push(c);
+            prev_was_upper = false;
+        } else if !result.ends_with('_') {
+            result.push('_');
+            prev_was_upper = false;
+        }
+    }
+
+    result
+}
+
+pub(crate) fn to_pascal_case(s: &str) -> String {
+    to_snake_case(s)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The entity name for a table: PascalCase with the last word singular,
+/// e.g. `blog_posts` → `BlogPost`
+pub(crate) fn entity_name(table: &str) -> String {
+    let snake = to_snake_case(table);
+    let (head, last) = match snake.rsplit_once('_') {
+        Some((head, last)) => (format!("{}_", head), last),
+        None => (String::new(), snake.as_str()),
+    };
+    to_pascal_case(&format!("{}{}", head, singularize(last)))
+}
+
+/// Naive English singular
+pub(crate) fn singularize(word: &str) -> String {
+    if word == "people" {
+        "person".to_string()
+    } else if let Some(stem) = word.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if ["sses", "xes", "ches", "shes", "uses", "zes"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        word[..word.len() - 2].to_string()
+    } else if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") {
+        word.to_string()
+    } else if let Some(stem) = word.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSTGRES_DUMP: &str = r#"
+        SET statement_timeout = 0;
+        SELECT pg_catalog.set_config('search_path', '', false);
+
+        CREATE TYPE public.post_status AS ENUM ('draft', 'published');
+
+        CREATE TABLE public.users (
+            id uuid DEFAULT gen_random_uuid() NOT NULL,
+            email character varying(255) NOT NULL,
+            "displayName" character varying(80),
+            created_at timestamp with time zone DEFAULT now() NOT NULL,
+            updated_at timestamp with time zone DEFAULT now() NOT NULL
+        );
+
+        CREATE TABLE public.posts (
+            id integer NOT NULL,
+            author_id uuid NOT NULL,
+            editor_id uuid,
+            status public.post_status DEFAULT 'draft'::public.post_status NOT NULL,
+            tags text[] DEFAULT '{}'::text[] NOT NULL,
+            price numeric(10,2) DEFAULT 0 NOT NULL,
+            body text,
+            CONSTRAINT posts_body_check CHECK (length(body) > 0)
+        );
+
+        CREATE SEQUENCE public.posts_id_seq AS integer START WITH 1;
+        ALTER TABLE ONLY public.posts ALTER COLUMN id SET DEFAULT nextval('public.posts_id_seq'::regclass);
+        ALTER TABLE ONLY public.users ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+        ALTER TABLE ONLY public.posts ADD CONSTRAINT posts_pkey PRIMARY KEY (id);
+        ALTER TABLE ONLY public.posts
+            ADD CONSTRAINT posts_author_id_fkey FOREIGN KEY (author_id) REFERENCES public.users(id) ON DELETE CASCADE;
+        ALTER TABLE ONLY public.posts
+            ADD CONSTRAINT posts_editor_id_fkey FOREIGN KEY (editor_id) REFERENCES public.users(id) ON DELETE SET NULL;
+        CREATE UNIQUE INDEX users_email_key ON public.users USING btree (email);
+        CREATE INDEX posts_tags_idx ON public.posts USING gin (tags);
+        CREATE INDEX posts_lower_body_idx ON public.posts (lower(body));
+        COMMENT ON TABLE public.posts IS 'Blog posts';
+        COMMENT ON COLUMN public.posts.body IS 'Markdown source';
+        CREATE FUNCTION public.touch() RETURNS trigger LANGUAGE plpgsql AS $$
+        BEGIN NEW.updated_at = now(); RETURN NEW; END;
+        $$;
+    "#;
+
+    const MYSQL_DUMP: &str = r#"
+        /*!40101 SET NAMES utf8mb4 */;
+        DROP TABLE IF EXISTS `categories`;
+        CREATE TABLE `categories` (
+          `id` int unsigned NOT NULL AUTO_INCREMENT,
+          `name` varchar(100) COLLATE utf8mb4_unicode_ci NOT NULL COMMENT 'Shown in menus',
+          `active` tinyint(1) NOT NULL DEFAULT '1',
+          PRIMARY KEY (`id`),
+          UNIQUE KEY `categories_name_unique` (`name`)
+        ) ENGINE=InnoDB AUTO_INCREMENT=4 DEFAULT CHARSET=utf8mb4 COMMENT='Product categories';
+
+        CREATE TABLE `products` (
+          `id` bigint NOT NULL AUTO_INCREMENT,
+          `category_id` int unsigned DEFAULT NULL,
+          `sku` char(12) NOT NULL,
+          `size` enum('s','m','l') NOT NULL DEFAULT 'm',
+          `stock` int NOT NULL DEFAULT '0',
+          `deleted_at` datetime DEFAULT NULL,
+          PRIMARY KEY (`id`),
+          KEY `products_category_sku` (`category_id`,`sku`),
+          CONSTRAINT `products_category_fk` FOREIGN KEY (`category_id`) REFERENCES `categories` (`id`)
+        ) ENGINE=InnoDB;
+    "#;
+
+    const SQLITE_SCHEMA: &str = r#"
+        CREATE TABLE tags (id INTEGER PRIMARY KEY AUTOINCREMENT, label TEXT NOT NULL UNIQUE);
+        CREATE TABLE post_tags (
+            post_id INTEGER NOT NULL,
+            tag_id INTEGER NOT NULL REFERENCES tags(id),
+            PRIMARY KEY (post_id, tag_id)
+        );
+        CREATE TABLE "user_profiles" ([user_id] INTEGER PRIMARY KEY REFERENCES tags, bio, rank REAL DEFAULT (0.5));
+    "#;
+
+    fn field<'e>(entity: &'e Entity, name: &str) -> &'e Field {
+        entity
+            .get_field_by_name(name)
+            .unwrap_or_else(|| panic!("{} has no field {}", entity.name, name))
+    }
+
+    #[test]
+    fn test_detect_dialect() {
+        assert_eq!(detect_dialect(POSTGRES_DUMP), DatabaseType::PostgreSQL);
+        assert_eq!(detect_dialect(MYSQL_DUMP), DatabaseType::MySQL);
+        assert_eq!(detect_dialect(SQLITE_SCHEMA), DatabaseType::SQLite);
+        assert_eq!(
+            detect_dialect("CREATE TABLE t (id int);"),
+            DatabaseType::PostgreSQL
+        );
+    }
+
+    #[test]
+    fn test_import_postgres_dump() {
+        let schema = import_sql(POSTGRES_DUMP, None).unwrap();
+        assert_eq!(schema.dialect, DatabaseType::PostgreSQL);
+        assert_eq!(schema.enums.len(), 1);
+        assert_eq!(schema.enums[0].name, "PostStatus");
+
+        let user = &schema.entities[0];
+        assert_eq!(user.name, "User");
+        assert_eq!(user.table_name, "users");
+        assert_eq!(user.config.id_type, IdType::Uuid);
+        assert!(user.config.timestamps);
+        assert!(field(user, "id").is_primary_key);
+        assert!(field(user, "email").unique);
+        let display = field(user, "display_name");
+        assert_eq!(display.column_name, "displayName");
+        assert!(!display.required);
+        assert_eq!(display.validations, vec![Validation::MaxLength(80)]);
+
+        let post = &schema.entities[1];
+        assert_eq!(post.name, "Post");
+        assert_eq!(post.description.as_deref(), Some("Blog posts"));
+        assert_eq!(post.config.id_type, IdType::Serial);
+        assert_eq!(post.config.schema, None);
+        assert_eq!(field(post, "status").data_type, schema.enums[0].data_type());
+        assert_eq!(
+            field(post, "status").default_value,
+            Some(DefaultValue::String("draft".to_string()))
+        );
+        assert_eq!(
+            field(post, "tags").data_type,
+            DataType::Array(Box::new(DataType::Text))
+        );
+        assert_eq!(
+            field(post, "tags").default_value,
+            Some(DefaultValue::EmptyArray)
+        );
+        assert_eq!(field(post, "price").data_type, DataType::decimal(10, 2));
+        assert_eq!(
+            field(post, "price").default_value,
+            Some(DefaultValue::Int(0))
+        );
+        assert_eq!(
+            field(post, "body").description.as_deref(),
+            Some("Markdown source")
+        );
+        assert_eq!(post.config.indexes.len(), 1);
+        assert_eq!(post.config.indexes[0].index_type, IndexType::Gin);
+
+        let author = field(post, "author_id");
+        assert!(author.is_foreign_key);
+        let fk = author.foreign_key_ref.as_ref().unwrap();
+        assert_eq!((fk.entity_id, fk.field_name.as_str()), (user.id, "id"));
+        assert_eq!(fk.on_delete, ReferentialAction::Cascade);
+
+        assert_eq!(schema.relationships.len(), 2);
+        let names: HashSet<&str> = schema
+            .relationships
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 2);
+        let editor = schema
+            .relationships
+            .iter()
+            .find(|r| r.from_field == "editor_id")
+            .unwrap();
+        assert_eq!(editor.relation_type, RelationType::ManyToOne);
+        assert_eq!(editor.on_delete, ReferentialAction::SetNull);
+        assert!(!editor.required);
+
+        let messages: Vec<String> = schema.warnings.iter().map(|w| w.to_string()).collect();
+        assert!(
+            messages.iter().any(|m| m.contains("posts_body_check")),
+            "{:?}",
+            messages
+        );
+        assert!(
+            messages.iter().any(|m| m.contains("lower(body)")),
+            "{:?}",
+            messages
+        );
+        assert!(
+            messages.iter().any(|m| m.contains("CREATE FUNCTION")),
+            "{:?}",
+            messages
+        );
+    }
+
+    #[test]
+    fn test_import_mysql_dump() {
+        let schema = import_sql(MYSQL_DUMP, None).unwrap();
+        assert_eq!(schema.dialect, DatabaseType::MySQL);
+
+        let category = &schema.entities[0];
+        assert_eq!(category.name, "Category");
+        assert_eq!(category.description.as_deref(), Some("Product categories"));
+        assert_eq!(category.config.id_type, IdType::Serial);
+        assert_eq!(category.config.engine.as_deref(), Some("InnoDB"));
+        assert_eq!(category.config.charset.as_deref(), Some("utf8mb4"));
+        assert_eq!(field(category, "id").data_type, DataType::Int64);
+        assert!(field(category, "name").unique);
+        assert_eq!(
+            field(category, "name").description.as_deref(),
+            Some("Shown in menus")
+        );
+        assert_eq!(field(category, "active").data_type, DataType::Bool);
+        assert_eq!(
+            field(category, "active").default_value,
+            Some(DefaultValue::Bool(true))
+        );
+
+        let product = &schema.entities[1];
+        assert!(product.config.soft_delete);
+        assert_eq!(
+            field(product, "stock").default_value,
+            Some(DefaultValue::Int(0))
+        );
+        assert_eq!(field(product, "category_id").default_value, None);
+        assert_eq!(schema.enums.len(), 1);
+        assert_eq!(schema.enums[0].name, "ProductSize");
+        assert_eq!(schema.enums[0].variants, vec!["s", "m", "l"]);
+        assert_eq!(
+            product.config.indexes,
+            vec![EntityIndex::new(["category_id", "sku"])]
+        );
+
+        let relationship = &schema.relationships[0];
+        assert_eq!(relationship.from_entity_id, product.id);
+        assert_eq!(relationship.to_entity_id, category.id);
+        assert_eq!(relationship.on_delete, ReferentialAction::NoAction);
+        assert!(!relationship.required);
+        assert!(schema.warnings.is_empty(), "{:?}", schema.warnings);
+    }
+
+    #[test]
+    fn test_import_sqlite_schema() {
+        let schema = import_sql(SQLITE_SCHEMA, None).unwrap();
+        assert_eq!(schema.dialect, DatabaseType::SQLite);
+
+        // Composite keys get a generated id and keep uniqueness
+        let post_tag = &schema.entities[1];
+        assert_eq!(post_tag.name, "PostTag");
+        assert_eq!(post_tag.fields[0].name, "id");
+        assert!(post_tag.fields[0].is_primary_key);
+        assert_eq!(
+            post_tag.config.unique_together,
+            vec![vec!["post_id".to_string(), "tag_id".to_string()]]
+        );
+
+        // A foreign key that is also the primary key makes a one-to-one
+        let profile = &schema.entities[2];
+        assert_eq!(profile.name, "UserProfile");
+        assert_eq!(profile.config.id_type, IdType::Serial);
+        assert_eq!(field(profile, "bio").data_type, DataType::String);
+        assert_eq!(
+            field(profile, "rank").default_value,
+            Some(DefaultValue::Float(0.5))
+        );
+        let one_to_one = schema
+            .relationships
+            .iter()
+            .find(|r| r.from_entity_id == profile.id)
+            .unwrap();
+        assert_eq!(one_to_one.relation_type, RelationType::OneToOne);
+        assert_eq!(one_to_one.to_field, "id");
+
+        let messages: Vec<String> = schema.warnings.iter().map(|w| w.to_string()).collect();
+        assert!(messages.contains(
+            &"user_profiles: `bio` has no declared type; imported as String".to_string()
+        ));
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.starts_with("post_tags: composite primary key"))
+        );
+    }
+
+    #[test]
+    fn test_into_project_and_apply() {
+        let schema = import_sql(POSTGRES_DUMP, None).unwrap();
+        let project = schema.clone().into_project("imported");
+        assert_eq!(project.entity_count(), 2);
+        assert_eq!(project.relationship_count(), 2);
+        assert!(project.get_enum_by_name("PostStatus").is_some());
+        let positions: HashSet<(i32, i32)> = project
+            .entities()
+            .map(|e| (e.position.x as i32, e.position.y as i32))
+            .collect();
+        assert_eq!(positions.len(), 2);
+
+        // Applying again would duplicate every name
+        let mut project = project;
+        let err = schema.apply(&mut project, Position::zero()).unwrap_err();
+        assert!(matches!(err, EngineError::DuplicateEntity(name) if name == "User"));
+        assert_eq!(project.entity_count(), 2);
+    }
+
+    #[test]
+    fn test_import_errors_and_skips() {
+        assert!(import_sql("SELECT 1;", None).is_err());
+        let err = import_sql("CREATE TABLE t (\n name text DEFAULT 'oops\n);", None).unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{}", err);
+
+        let schema = import_sql(
+            "CREATE TABLE people (id bigserial PRIMARY KEY, x geometry); CREATE TABLE bad (;",
+            Some(DatabaseType::PostgreSQL),
+        )
+        .unwrap();
+        assert_eq!(schema.entities.len(), 1);
+        assert_eq!(schema.entities[0].name, "Person");
+        assert_eq!(field(&schema.entities[0], "x").data_type, DataType::String);
+        assert_eq!(schema.warnings.len(), 2, "{:?}", schema.warnings);
+    }
+
+    #[test]
+    fn test_naming_helpers() {
+        assert_eq!(entity_name("blog_posts"), "BlogPost");
+        assert_eq!(entity_name("categories"), "Category");
+        assert_eq!(entity_name("addresses"), "Address");
+        assert_eq!(entity_name("status"), "Status");
+        assert_eq!(entity_name("OrderItems"), "OrderItem");
+        assert_eq!(to_snake_case("authorId"), "author_id");
+        assert_eq!(to_snake_case("UserID"), "user_id");
+        assert_eq!(to_snake_case("first name"), "first_name");
+    }
+}
@@ -0,0 +1,692 @@
+//! # Entity Inference
+//!
+//! Infers an [`Entity`] from a sample JSON document, such as an API response
+//! the user wants to store.
+//!
+//! The sample may be a single object or an array of objects. With several
+//! objects, a key that is missing or `null` in any of them becomes optional,
+//! and differing value types are widened (`Int32` + `Float64` → `Float64`,
+//! anything irreconcilable → `Json`).
+//!
+//! Scalar values map to column types:
+//!
+//! | JSON value                    | Inferred type           |
+//! |-------------------------------|-------------------------|
+//! | `true` / `false`              | `Bool`                  |
+//! | integer                       | `Int32`, or `Int64` if it doesn't fit |
+//! | decimal                       | `Float64`               |
+//! | UUID string                   | `Uuid`                  |
+//! | RFC 3339 timestamp            | `DateTime`              |
+//! | `YYYY-MM-DD` / `HH:MM:SS`     | `Date` / `Time`         |
+//! | string longer than 255 chars  | `Text`                  |
+//! | other string                  | `String`                |
+//! | array of scalars              | `Array(..)`             |
+//!
+//! Nested objects (and arrays of objects) are reported as [`NestedSample`]s.
+//! Each one is stored as a JSON column by default, or can be switched to a
+//! related entity, in which case [`InferredEntity::apply`] infers and creates
+//! that entity too and links the two with a relationship.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::inference::infer_entity;
+//! use imortal_ir::{DataType, ProjectGraph};
+//!
+//! let inferred = infer_entity("Post", r#"{"title": "Hello", "views": 3}"#).unwrap();
+//! let mut project = ProjectGraph::new("blog");
+//! let post_id = inferred.apply(&mut project, imortal_ir::Position::zero()).unwrap();
+//!
+//! let post = project.get_entity(post_id).unwrap();
+//! assert_eq!(post.get_field_by_name("views").unwrap().data_type, DataType::Int32);
+//! ```
+
+use imortal_core::{DataType, EngineError, EngineResult, Position, Validation};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::{Entity, Field, ProjectGraph, Relationship};
+
+/// Strings longer than this are inferred as `Text` rather than `String`
+const TEXT_THRESHOLD: usize = 255;
+
+/// Horizontal gap between an inferred entity and the related entities
+/// created for its nested objects
+const NESTED_OFFSET_X: f32 = 320.0;
+
+/// Vertical gap between sibling related entities
+const NESTED_OFFSET_Y: f32 = 220.0;
+
+// ============================================================================
+// Inferred Entity
+// ============================================================================
+
+/// How a nested object should be stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NestedMode {
+    /// Keep the object as a JSON column on the parent
+    #[default]
+    JsonColumn,
+    /// Create (or reuse) a related entity and link it with a relationship
+    RelatedEntity,
+}
+
+/// A nested object, or array of objects, found in the sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedSample {
+    /// Suggested name for the related entity (PascalCase, singular)
+    pub entity_name: String,
+
+    /// Whether the key held an array of objects (one-to-many) rather than a
+    /// single object (many-to-one)
+    pub many: bool,
+
+    /// How the nested data should be stored
+    pub mode: NestedMode,
+
+    /// Every object seen under this key, used to infer the related entity
+    pub samples: Vec<Map<String, Value>>,
+}
+
+/// A field inferred from the sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredField {
+    /// Key as it appeared in the JSON
+    pub key: String,
+
+    /// Field name (snake_case identifier derived from the key)
+    pub name: String,
+
+    /// Inferred column type (without the `Optional` wrapper)
+    pub data_type: DataType,
+
+    /// Whether the key was `null` or missing in at least one sample
+    pub nullable: bool,
+
+    /// Validations suggested by the values (e-mail, URL)
+    pub validations: Vec<Validation>,
+
+    /// Set when the value is an object or array of objects
+    pub nested: Option<NestedSample>,
+}
+
+impl InferredField {
+    /// Build the IR field for this inference.
+    ///
+    /// Nested objects become `Json` columns; related-entity nesting is
+    /// handled by [`InferredEntity::apply`] and produces no field here.
+    pub fn to_field(&self) -> Field {
+        let data_type = if self.nested.is_some() {
+            DataType::Json
+        } else {
+            self.data_type.clone()
+        };
+
+        let mut field = Field::new(&self.name, data_type);
+        field.required = !self.nullable;
+        field.validations = self.validations.clone();
+        field
+    }
+}
+
+/// The result of inferring an entity from a JSON sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredEntity {
+    /// Entity name
+    pub name: String,
+
+    /// Inferred fields, in key order
+    pub fields: Vec<InferredField>,
+
+    /// Things the user should know about (skipped keys, widened types)
+    pub notes: Vec<String>,
+}
+
+impl InferredEntity {
+    /// Nested samples, for offering the JSON-column / related-entity choice
+    pub fn nested(&self) -> impl Iterator<Item = &InferredField> {
+        self.fields.iter().filter(|f| f.nested.is_some())
+    }
+
+    /// Columns for the entity: every scalar, plus nested objects kept as
+    /// JSON columns. Nested samples in [`NestedMode::RelatedEntity`] mode
+    /// are left to [`InferredEntity::link_related`].
+    pub fn to_fields(&self) -> Vec<Field> {
+        self.fields
+            .iter()
+            .filter(|f| {
+                !f.nested
+                    .as_ref()
+                    .is_some_and(|n| n.mode == NestedMode::RelatedEntity)
+            })
+            .map(InferredField::to_field)
+            .collect()
+    }
+
+    /// Build the entity on its own (without related entities)
+    pub fn to_entity(&self) -> Entity {
+        let mut entity = Entity::new(&self.name);
+        for field in self.to_fields() {
+            entity.add_field(field);
+        }
+        entity
+    }
+
+    /// Add the entity to a project at `position`, together with the related
+    /// entities and relationships for its nested samples. Returns the ID of
+    /// the new entity.
+    pub fn apply(&self, project: &mut ProjectGraph, position: Position) -> EngineResult<Uuid> {
+        let mut entity = self.to_entity();
+        entity.position = position;
+        let entity_id = project.add_entity(entity);
+        self.link_related(project, entity_id)?;
+        Ok(entity_id)
+    }
+
+    /// Create the related entities for nested samples in
+    /// [`NestedMode::RelatedEntity`] mode and link them to `entity_id`, which
+    /// must already be in the project.
+    ///
+    /// A single nested object becomes a many-to-one relationship (the FK
+    /// lives on this entity); an array of objects becomes one-to-many. A
+    /// related entity whose name already exists in the project is reused
+    /// rather than created again.
+    pub fn link_related(&self, project: &mut ProjectGraph, entity_id: Uuid) -> EngineResult<()> {
+        let position = project
+            .get_entity(entity_id)
+            .map(|e| e.position)
+            .ok_or_else(|| EngineError::EntityNotFound(entity_id.to_string()))?;
+
+        let related = self.fields.iter().filter_map(|f| {
+            f.nested
+                .as_ref()
+                .filter(|n| n.mode == NestedMode::RelatedEntity)
+        });
+
+        for (i, nested) in related.enumerate() {
+            let child_id = match project.get_entity_by_name(&nested.entity_name) {
+                Some(existing) => existing.id,
+                None => {
+                    let refs: Vec<&Map<String, Value>> = nested.samples.iter().collect();
+                    let child = infer_from_samples(&nested.entity_name, &refs);
+                    let child_position = Position::new(
+                        position.x + NESTED_OFFSET_X,
+                        position.y + NESTED_OFFSET_Y * i as f32,
+                    );
+                    child.apply(project, child_position)?
+                }
+            };
+
+            let relationship = if nested.many {
+                Relationship::one_to_many(entity_id, child_id)
+            } else {
+                Relationship::many_to_one(entity_id, child_id)
+            };
+            project.create_relationship_with_fk(relationship)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Inference
+// ============================================================================
+
+/// Infer an entity from JSON text: a single object or a non-empty array of
+/// objects.
+pub fn infer_entity(name: &str, json: &str) -> EngineResult<InferredEntity> {
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| EngineError::validation(format!("Invalid JSON: {}", e)))?;
+
+    let samples: Vec<&Map<String, Value>> = match &value {
+        Value::Object(map) => vec![map],
+        Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_object())
+            .collect::<Option<Vec<_>>>()
+            .filter(|maps| !maps.is_empty())
+            .ok_or_else(|| EngineError::validation("Expected an array of JSON objects"))?,
+        _ => {
+            return Err(EngineError::validation(
+                "Expected a JSON object or an array of objects",
+            ));
+        }
+    };
+
+    Ok(infer_from_samples(name, &samples))
+}
+
+/// Infer an entity from one or more already-parsed objects
+pub fn infer_from_samples(name: &str, samples: &[&Map<String, Value>]) -> InferredEntity {
+    let mut keys: Vec<&String> = Vec::new();
+    for sample in samples {
+        for key in sample.keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    let mut fields: Vec<InferredField> = Vec::new();
+    let mut notes = Vec::new();
+
+    for key in keys {
+        let field_name = field_name_for_key(key);
+        if field_name == "id" {
+            notes.push(format!(
+                "Skipped '{}': the entity already has a UUID primary key",
+                key
+            ));
+            continue;
+        }
+        if fields.iter().any(|f| f.name == field_name) {
+            notes.push(format!(
+                "Skipped '{}': it maps to the same field name as an earlier key",
+                key
+            ));
+            continue;
+        }
+
+        let values: Vec<&Value> = samples.iter().filter_map(|s| s.get(key)).collect();
+        let nullable = values.len() < samples.len() || values.iter().any(|v| v.is_null());
+        let present: Vec<&Value> = values.into_iter().filter(|v| !v.is_null()).collect();
+
+        let (data_type, nested, widened) = infer_values(key, &present);
+        if widened {
+            notes.push(format!(
+                "'{}' has mixed value types; inferred as {}",
+                key,
+                data_type.to_rust_type()
+            ));
+        }
+
+        let validations = if nested.is_none() {
+            suggested_validations(&present)
+        } else {
+            Vec::new()
+        };
+
+        fields.push(InferredField {
+            key: key.clone(),
+            name: field_name,
+            data_type,
+            nullable,
+            validations,
+            nested,
+        });
+    }
+
+    InferredEntity {
+        name: name.to_string(),
+        fields,
+        notes,
+    }
+}
+
+/// Infer the type of one key from its non-null values.
+///
+/// Returns the type, the nested sample (for objects) and whether the values
+/// disagreed and had to be widened.
+fn infer_values(key: &str, values: &[&Value]) -> (DataType, Option<NestedSample>, bool) {
+    if values.is_empty() {
+        return (DataType::String, None, false);
+    }
+
+    // Objects and arrays of objects become nested samples
+    let mut objects = Vec::new();
+    let mut many = false;
+    let mut all_objects = true;
+    for value in values {
+        match value {
+            Value::Object(map) => objects.push(map.clone()),
+            Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object) => {
+                many = true;
+                objects.extend(items.iter().filter_map(|i| i.as_object().cloned()));
+            }
+            _ => all_objects = false,
+        }
+    }
+    if all_objects {
+        let nested = NestedSample {
+            entity_name: entity_name_for_key(key),
+            many,
+            mode: NestedMode::default(),
+            samples: objects,
+        };
+        return (DataType::Json, Some(nested), false);
+    }
+    if !objects.is_empty() {
+        return (DataType::Json, None, true);
+    }
+
+    let mut merged: Option<DataType> = None;
+    let mut widened = false;
+    for value in values {
+        let ty = scalar_type(value);
+        merged = Some(match merged {
+            None => ty,
+            Some(prev) => {
+                let next = merge_types(&prev, &ty);
+                widened |= next == DataType::Json && prev != DataType::Json;
+                next
+            }
+        });
+    }
+
+    (merged.unwrap_or(DataType::String), None, widened)
+}
+
+/// Type of a single non-object value
+fn scalar_type(value: &Value) -> DataType {
+    match value {
+        Value::Null => DataType::String,
+        Value::Bool(_) => DataType::Bool,
+        Value::Number(n) => match n.as_i64() {
+            Some(i) if i32::try_from(i).is_ok() => DataType::Int32,
+            Some(_) => DataType::Int64,
+            None if n.is_u64() => DataType::Int64,
+            None => DataType::Float64,
+        },
+        Value::String(s) => string_type(s),
+        Value::Array(items) => {
+            let element = items
+                .iter()
+                .filter(|v| !v.is_null())
+                .map(scalar_type)
+                .reduce(|a, b| merge_types(&a, &b));
+            match element {
+                Some(DataType::Json) | None => DataType::Json,
+                Some(element) => DataType::Array(Box::new(element)),
+            }
+        }
+        Value::Object(_) => DataType::Json,
+    }
+}
+
+/// Recognise UUIDs, timestamps, dates and times inside strings
+fn string_type(s: &str) -> DataType {
+    if Uuid::parse_str(s).is_ok() && s.len() == 36 {
+        DataType::Uuid
+    } else if chrono::DateTime::parse_from_rfc3339(s).is_ok() {
+        DataType::DateTime
+    } else if chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok() {
+        DataType::Date
+    } else if chrono::NaiveTime::parse_from_str(s, "%H:%M:%S").is_ok() {
+        DataType::Time
+    } else if s.chars().count() > TEXT_THRESHOLD {
+        DataType::Text
+    } else {
+        DataType::String
+    }
+}
+
+/// Widen two inferred types to one that can hold both
+fn merge_types(a: &DataType, b: &DataType) -> DataType {
+    use DataType::*;
+
+    match (a, b) {
+        _ if a == b => a.clone(),
+        (Int32, Int64) | (Int64, Int32) => Int64,
+        (Int32 | Int64, Float64) | (Float64, Int32 | Int64) => Float64,
+        (String, Text) | (Text, String) => Text,
+        // Strings that only sometimes look like UUIDs or dates are strings
+        (String | Text, Uuid | DateTime | Date | Time) => a.clone(),
+        (Uuid | DateTime | Date | Time, String | Text) => b.clone(),
+        (Date, DateTime) | (DateTime, Date) => DateTime,
+        (Array(x), Array(y)) => match merge_types(x, y) {
+            Json => Json,
+            merged => Array(Box::new(merged)),
+        },
+        _ => Json,
+    }
+}
+
+/// E-mail / URL validations when every value looks like one
+fn suggested_validations(values: &[&Value]) -> Vec<Validation> {
+    let strings: Vec<&str> = values.iter().filter_map(|v| v.as_str()).collect();
+    if strings.is_empty() || strings.len() != values.len() {
+        return Vec::new();
+    }
+
+    if strings.iter().all(|s| looks_like_email(s)) {
+        vec![Validation::Email]
+    } else if strings
+        .iter()
+        .all(|s| s.starts_with("http://") || s.starts_with("https://"))
+    {
+        vec![Validation::Url]
+    } else {
+        Vec::new()
+    }
+}
+
+fn looks_like_email(s: &str) -> bool {
+    let Some((local, domain)) = s.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !s.contains(char::is_whitespace)
+        && !domain.contains('@')
+}
+
+// ============================================================================
+// Naming Helpers
+// ============================================================================
+
+/// Turn a JSON key into a snake_case identifier
+fn field_name_for_key(key: &str) -> String {
+    let mut result = String::with_capacity(key.len() + 4);
+    let mut prev_was_lower = false;
+
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && prev_was_lower {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+            prev_was_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else {
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            prev_was_lower = false;
+        }
+    }
+
+    let result = result.trim_end_matches('_').to_string();
+    if result.is_empty() {
+        "field".to_string()
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{}", result)
+    } else {
+        result
+    }
+}
+
+/// Turn a JSON key into a singular PascalCase entity name
+fn entity_name_for_key(key: &str) -> String {
+    let snake = field_name_for_key(key);
+    let singular = if let Some(stem) = snake.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if snake.ends_with("sses") || snake.ends_with("xes") {
+        snake[..snake.len() - 2].to_string()
+    } else if snake.ends_with('s') && !snake.ends_with("ss") && !snake.ends_with("us") {
+        snake[..snake.len() - 1].to_string()
+    } else {
+        snake
+    };
+
+    singular
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::RelationType;
+
+    fn field<'a>(inferred: &'a InferredEntity, name: &str) -> &'a InferredField {
+        inferred.fields.iter().find(|f| f.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_infer_scalar_types() {
+        let inferred = infer_entity(
+            "Order",
+            r#"{
+                "id": 42,
+                "orderNumber": "A-100",
+                "total": 19.99,
+                "quantity": 3,
+                "bigCounter": 9000000000,
+                "paid": true,
+                "customerId": "550e8400-e29b-41d4-a716-446655440000",
+                "placedAt": "2024-05-01T12:30:00Z",
+                "shipDate": "2024-05-03",
+                "tags": ["a", "b"],
+                "email": "jane@example.com",
+                "note": null
+            }"#,
+        )
+        .unwrap();
+
+        assert!(inferred.fields.iter().all(|f| f.name != "id"));
+        assert_eq!(inferred.notes.len(), 1);
+
+        assert_eq!(field(&inferred, "order_number").data_type, DataType::String);
+        assert_eq!(field(&inferred, "total").data_type, DataType::Float64);
+        assert_eq!(field(&inferred, "quantity").data_type, DataType::Int32);
+        assert_eq!(field(&inferred, "big_counter").data_type, DataType::Int64);
+        assert_eq!(field(&inferred, "paid").data_type, DataType::Bool);
+        assert_eq!(field(&inferred, "customer_id").data_type, DataType::Uuid);
+        assert_eq!(field(&inferred, "placed_at").data_type, DataType::DateTime);
+        assert_eq!(field(&inferred, "ship_date").data_type, DataType::Date);
+        assert_eq!(
+            field(&inferred, "tags").data_type,
+            DataType::Array(Box::new(DataType::String))
+        );
+        assert_eq!(
+            field(&inferred, "email").validations,
+            vec![Validation::Email]
+        );
+
+        let note = field(&inferred, "note");
+        assert!(note.nullable);
+        assert!(!field(&inferred, "total").nullable);
+    }
+
+    #[test]
+    fn test_infer_merges_samples() {
+        let inferred = infer_entity(
+            "Reading",
+            r#"[
+                {"value": 1, "label": "a", "unit": "c"},
+                {"value": 2.5, "label": null},
+                {"value": 3, "label": "b", "unit": 7}
+            ]"#,
+        )
+        .unwrap();
+
+        let value = field(&inferred, "value");
+        assert_eq!(value.data_type, DataType::Float64);
+        assert!(!value.nullable);
+
+        assert!(field(&inferred, "label").nullable);
+
+        let unit = field(&inferred, "unit");
+        assert!(unit.nullable);
+        assert_eq!(unit.data_type, DataType::Json);
+        assert!(inferred.notes.iter().any(|n| n.contains("'unit'")));
+    }
+
+    #[test]
+    fn test_infer_rejects_non_objects() {
+        assert!(infer_entity("X", "not json").is_err());
+        assert!(infer_entity("X", "42").is_err());
+        assert!(infer_entity("X", "[]").is_err());
+        assert!(infer_entity("X", "[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_nested_objects_default_to_json_columns() {
+        let inferred = infer_entity(
+            "Post",
+            r#"{"title": "Hi", "author": {"name": "Ann"}, "comments": [{"body": "x"}]}"#,
+        )
+        .unwrap();
+
+        let author = field(&inferred, "author").nested.as_ref().unwrap();
+        assert_eq!(author.entity_name, "Author");
+        assert!(!author.many);
+        let comments = field(&inferred, "comments").nested.as_ref().unwrap();
+        assert_eq!(comments.entity_name, "Comment");
+        assert!(comments.many);
+
+        let entity = inferred.to_entity();
+        assert_eq!(
+            entity.get_field_by_name("author").unwrap().data_type,
+            DataType::Json
+        );
+        assert!(entity.get_field_by_name("comments").is_some());
+    }
+
+    #[test]
+    fn test_apply_creates_related_entities() {
+        let mut inferred = infer_entity(
+            "Post",
+            r#"{"title": "Hi", "author": {"name": "Ann"}, "comments": [{"body": "x"}, {"body": "y", "likes": 2}]}"#,
+        )
+        .unwrap();
+        for field in inferred.fields.iter_mut() {
+            if let Some(nested) = field.nested.as_mut() {
+                nested.mode = NestedMode::RelatedEntity;
+            }
+        }
+
+        let mut project = ProjectGraph::new("blog");
+        let existing_author = project.add_entity(Entity::new("Author"));
+        let post_id = inferred.apply(&mut project, Position::zero()).unwrap();
+
+        assert_eq!(project.entities.len(), 3);
+        let post = project.get_entity(post_id).unwrap();
+        assert!(post.get_field_by_name("author").is_none());
+        assert!(post.get_field_by_name("author_id").is_some());
+
+        let comment = project.get_entity_by_name("Comment").unwrap();
+        assert!(comment.get_field_by_name("post_id").is_some());
+        assert!(!comment.get_field_by_name("likes").unwrap().required);
+
+        assert_eq!(project.relationships.len(), 2);
+        assert!(project.relationships.values().any(|r| {
+            r.relation_type == RelationType::ManyToOne && r.to_entity_id == existing_author
+        }));
+    }
+
+    #[test]
+    fn test_naming_helpers() {
+        assert_eq!(field_name_for_key("firstName"), "first_name");
+        assert_eq!(field_name_for_key("zip-code"), "zip_code");
+        assert_eq!(field_name_for_key("2fa"), "field_2fa");
+        assert_eq!(entity_name_for_key("categories"), "Category");
+        assert_eq!(entity_name_for_key("line_items"), "LineItem");
+        assert_eq!(entity_name_for_key("addresses"), "Address");
+        assert_eq!(entity_name_for_key("status"), "Status");
+    }
+}
@@ -0,0 +1,434 @@
+//! # Auto Layout
+//!
+//! Arranges entities on the canvas as a layered graph, so relationships read
+//! left to right: an entity sits one column to the right of every entity
+//! with a relationship to it (`User → Post → Comment`).
+//!
+//! The layout is computed in four steps:
+//!
+//! 1. **Cycles** are broken by ignoring back edges found in a depth-first
+//!    search, so self-referencing and mutually related entities still get
+//!    a column.
+//! 2. **Columns** come from the longest path to each entity.
+//! 3. **Order** within each column is refined with a few barycenter sweeps,
+//!    which pulls related entities level with each other and cuts down on
+//!    crossing lines.
+//! 4. **Coordinates** stack each column's cards, sized by their fields, and
+//!    centre the columns on each other.
+//!
+//! Each connected group of entities is laid out on its own and the groups
+//! are stacked top to bottom, largest first. Entities with no relationships
+//! at all go in a grid underneath. Ties are broken by entity name, so the
+//! same project always produces the same layout.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::layout::auto_layout;
+//! use imortal_ir::{Entity, ProjectGraph, Relationship};
+//!
+//! let mut project = ProjectGraph::new("blog");
+//! let user = project.add_entity(Entity::new("User"));
+//! let post = project.add_entity(Entity::new("Post"));
+//! project.add_relationship(Relationship::one_to_many(user, post));
+//!
+//! let positions = auto_layout(&project);
+//! assert!(positions[&post].x > positions[&user].x);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::{Entity, Position, ProjectGraph};
+
+/// Top-left corner of the layout
+const ORIGIN: Position = Position { x: 100.0, y: 100.0 };
+
+/// Horizontal space between columns
+const COLUMN_GAP: f32 = 120.0;
+
+/// Vertical space between cards in a column
+const ROW_GAP: f32 = 60.0;
+
+/// Vertical space between connected groups
+const GROUP_GAP: f32 = 120.0;
+
+/// Cards per row in the grid of unrelated entities
+const GRID_COLUMNS: usize = 4;
+
+/// Barycenter sweeps (each one down and back up the columns)
+const ORDERING_SWEEPS: usize = 4;
+
+/// New canvas positions for every entity in `project`, keyed by entity ID
+pub fn auto_layout(project: &ProjectGraph) -> HashMap<Uuid, Position> {
+    let mut entities: Vec<&Entity> = project.entities.values().collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    let index: HashMap<Uuid, usize> = entities
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id, i))
+        .collect();
+
+    let mut edges: Vec<(usize, usize)> = project
+        .relationships
+        .values()
+        .filter_map(|r| Some((*index.get(&r.from_entity_id)?, *index.get(&r.to_entity_id)?)))
+        .filter(|(from, to)| from != to)
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut positions = HashMap::new();
+    let mut top = ORIGIN.y;
+
+    let (mut groups, loners): (Vec<Vec<usize>>, Vec<Vec<usize>>) =
+        connected_groups(entities.len(), &edges)
+            .into_iter()
+            .partition(|group| group.len() > 1);
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+
+    for group in &groups {
+        let height = layout_group(&entities, group, &edges, top, &mut positions);
+        top += height + GROUP_GAP;
+    }
+
+    let loners: Vec<usize> = loners.into_iter().flatten().collect();
+    for row in loners.chunks(GRID_COLUMNS) {
+        let mut x = ORIGIN.x;
+        let mut height: f32 = 0.0;
+        for &i in row {
+            let entity = entities[i];
+            positions.insert(entity.id, Position::new(x, top));
+            x += entity.size.width + COLUMN_GAP;
+            height = height.max(entity.calculate_height());
+        }
+        top += height + ROW_GAP;
+    }
+
+    positions
+}
+
+/// Indices of each connected group of entities, each sorted
+fn connected_groups(count: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut neighbours = vec![Vec::new(); count];
+    for &(from, to) in edges {
+        neighbours[from].push(to);
+        neighbours[to].push(from);
+    }
+
+    let mut seen = vec![false; count];
+    let mut groups = Vec::new();
+    for start in 0..count {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut group = vec![start];
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for &next in &neighbours[node] {
+                if !seen[next] {
+                    seen[next] = true;
+                    group.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        group.sort_unstable();
+        groups.push(group);
+    }
+    groups
+}
+
+/// Lay out one connected group with its top edge at `top`, returning its
+/// height
+fn layout_group(
+    entities: &[&Entity],
+    group: &[usize],
+    edges: &[(usize, usize)],
+    top: f32,
+    positions: &mut HashMap<Uuid, Position>,
+) -> f32 {
+    let members: HashSet<usize> = group.iter().copied().collect();
+    let edges: Vec<(usize, usize)> = edges
+        .iter()
+        .copied()
+        .filter(|(from, _)| members.contains(from))
+        .collect();
+    let edges = acyclic_edges(group, &edges);
+
+    // Columns: longest path from a root
+    let mut column: HashMap<usize, usize> = group.iter().map(|&n| (n, 0)).collect();
+    for &node in &topological_order(group, &edges) {
+        for &(from, to) in edges.iter().filter(|(from, _)| *from == node) {
+            let next = column[&from] + 1;
+            let entry = column.get_mut(&to).expect("edge within group");
+            *entry = (*entry).max(next);
+        }
+    }
+    let column_count = column.values().max().map_or(0, |c| c + 1);
+    let mut columns: Vec<Vec<usize>> = vec![Vec::new(); column_count];
+    for &node in group {
+        columns[column[&node]].push(node);
+    }
+
+    order_columns(&mut columns, &edges);
+
+    // Coordinates: stack each column, then centre the columns vertically
+    let heights: Vec<f32> = columns
+        .iter()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|&n| entities[n].calculate_height())
+                .sum::<f32>()
+                + ROW_GAP * nodes.len().saturating_sub(1) as f32
+        })
+        .collect();
+    let tallest = heights.iter().copied().fold(0.0, f32::max);
+
+    let mut x = ORIGIN.x;
+    for (nodes, height) in columns.iter().zip(&heights) {
+        let mut y = top + (tallest - height) / 2.0;
+        let mut width: f32 = 0.0;
+        for &n in nodes {
+            let entity = entities[n];
+            positions.insert(entity.id, Position::new(x, y));
+            y += entity.calculate_height() + ROW_GAP;
+            width = width.max(entity.size.width);
+        }
+        x += width + COLUMN_GAP;
+    }
+
+    tallest
+}
+
+/// The edges minus the back edges of a depth-first search, visiting nodes
+/// in index (name) order
+fn acyclic_edges(group: &[usize], edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        New,
+        Active,
+        Done,
+    }
+
+    let mut marks: HashMap<usize, Mark> = group.iter().map(|&n| (n, Mark::New)).collect();
+    let mut back: HashSet<(usize, usize)> = HashSet::new();
+
+    for &root in group {
+        if marks[&root] != Mark::New {
+            continue;
+        }
+        // Stack of (node, next outgoing edge to look at)
+        let mut stack = vec![(root, 0)];
+        marks.insert(root, Mark::Active);
+        while let Some((node, next)) = stack.pop() {
+            let outgoing: Vec<usize> = edges
+                .iter()
+                .filter(|(from, _)| *from == node)
+                .map(|(_, to)| *to)
+                .collect();
+            let Some(&to) = outgoing.get(next) else {
+                marks.insert(node, Mark::Done);
+                continue;
+            };
+            stack.push((node, next + 1));
+            match marks[&to] {
+                Mark::New => {
+                    marks.insert(to, Mark::Active);
+                    stack.push((to, 0));
+                }
+                Mark::Active => {
+                    back.insert((node, to));
+                }
+                Mark::Done => {}
+            }
+        }
+    }
+
+    edges
+        .iter()
+        .copied()
+        .filter(|edge| !back.contains(edge))
+        .collect()
+}
+
+/// Nodes ordered so every edge points forward (Kahn's algorithm, ties by
+/// index)
+fn topological_order(group: &[usize], edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut incoming: HashMap<usize, usize> = group.iter().map(|&n| (n, 0)).collect();
+    for (_, to) in edges {
+        *incoming.get_mut(to).expect("edge within group") += 1;
+    }
+
+    let mut ready: Vec<usize> = group.iter().copied().filter(|n| incoming[n] == 0).collect();
+    let mut order = Vec::with_capacity(group.len());
+    while let Some(node) = ready.first().copied() {
+        ready.remove(0);
+        order.push(node);
+        for (_, to) in edges.iter().filter(|(from, _)| *from == node) {
+            let count = incoming.get_mut(to).expect("edge within group");
+            *count -= 1;
+            if *count == 0 {
+                let at = ready.partition_point(|&n| n < *to);
+                ready.insert(at, *to);
+            }
+        }
+    }
+    order
+}
+
+/// Reorder each column by the average row of its neighbours in the column
+/// before (sweeping right) or after (sweeping left)
+fn order_columns(columns: &mut [Vec<usize>], edges: &[(usize, usize)]) {
+    for _ in 0..ORDERING_SWEEPS {
+        for i in 1..columns.len() {
+            let (before, rest) = columns.split_at_mut(i);
+            sort_by_barycenter(&mut rest[0], &before[i - 1], edges, true);
+        }
+        for i in (0..columns.len().saturating_sub(1)).rev() {
+            let (left, right) = columns.split_at_mut(i + 1);
+            sort_by_barycenter(&mut left[i], &right[0], edges, false);
+        }
+    }
+}
+
+fn sort_by_barycenter(
+    column: &mut [usize],
+    fixed: &[usize],
+    edges: &[(usize, usize)],
+    fixed_is_source: bool,
+) {
+    let row: HashMap<usize, usize> = fixed.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let mut keyed: Vec<(f32, usize)> = column
+        .iter()
+        .enumerate()
+        .map(|(current, &node)| {
+            let rows: Vec<usize> = edges
+                .iter()
+                .filter_map(|&(from, to)| {
+                    let (this, other) = if fixed_is_source {
+                        (to, from)
+                    } else {
+                        (from, to)
+                    };
+                    (this == node).then(|| row.get(&other).copied()).flatten()
+                })
+                .collect();
+            let key = if rows.is_empty() {
+                current as f32
+            } else {
+                rows.iter().sum::<usize>() as f32 / rows.len() as f32
+            };
+            (key, node)
+        })
+        .collect();
+    // Stable, so nodes with equal keys keep their current order
+    keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for (slot, (_, node)) in column.iter_mut().zip(keyed) {
+        *slot = node;
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Field, Relationship};
+    use imortal_core::{DataType, Rect};
+
+    fn add(project: &mut ProjectGraph, name: &str) -> Uuid {
+        project.add_entity(Entity::new(name))
+    }
+
+    fn bounds(project: &ProjectGraph, positions: &HashMap<Uuid, Position>) -> Vec<Rect> {
+        project
+            .entities
+            .values()
+            .map(|e| {
+                let mut size = e.size;
+                size.height = e.calculate_height();
+                Rect::new(positions[&e.id], size)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layers_follow_relationships() {
+        let mut project = ProjectGraph::new("blog");
+        let user = add(&mut project, "User");
+        let post = add(&mut project, "Post");
+        let comment = add(&mut project, "Comment");
+        let tag = add(&mut project, "Tag");
+        project.add_relationship(Relationship::one_to_many(user, post));
+        project.add_relationship(Relationship::one_to_many(post, comment));
+        project.add_relationship(Relationship::one_to_many(user, comment));
+
+        let positions = auto_layout(&project);
+        assert_eq!(positions.len(), 4);
+        assert!(positions[&user].x < positions[&post].x);
+        assert!(positions[&post].x < positions[&comment].x);
+        // Unrelated entities go underneath the connected group
+        assert_eq!(positions[&tag].x, ORIGIN.x);
+        assert!(positions[&tag].y > positions[&user].y);
+    }
+
+    #[test]
+    fn test_no_overlaps() {
+        let mut project = ProjectGraph::new("shop");
+        let ids: Vec<Uuid> = (0..12)
+            .map(|i| {
+                let mut entity = Entity::new(format!("Entity{i:02}"));
+                for f in 0..(i % 5) {
+                    entity.add_field(Field::new(format!("field_{f}"), DataType::String));
+                }
+                project.add_entity(entity)
+            })
+            .collect();
+        for i in 1..8 {
+            project.add_relationship(Relationship::one_to_many(ids[i / 2], ids[i]));
+        }
+
+        let positions = auto_layout(&project);
+        let rects = bounds(&project, &positions);
+        for (i, a) in rects.iter().enumerate() {
+            for b in &rects[i + 1..] {
+                assert!(!a.intersects(b), "{a:?} overlaps {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycles_and_self_references() {
+        let mut project = ProjectGraph::new("graph");
+        let a = add(&mut project, "A");
+        let b = add(&mut project, "B");
+        let c = add(&mut project, "C");
+        project.add_relationship(Relationship::one_to_many(a, b));
+        project.add_relationship(Relationship::one_to_many(b, c));
+        project.add_relationship(Relationship::one_to_many(c, a));
+        project.add_relationship(Relationship::one_to_many(b, b));
+
+        let positions = auto_layout(&project);
+        assert!(positions[&a].x < positions[&b].x);
+        assert!(positions[&b].x < positions[&c].x);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut project = ProjectGraph::new("blog");
+        let user = add(&mut project, "User");
+        for name in ["Post", "Profile", "Session", "Audit"] {
+            let id = add(&mut project, name);
+            project.add_relationship(Relationship::one_to_many(user, id));
+        }
+
+        assert_eq!(auto_layout(&project), auto_layout(&project.clone()));
+        assert!(auto_layout(&ProjectGraph::new("empty")).is_empty());
+    }
+}
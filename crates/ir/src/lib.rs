@@ -8,36 +8,86 @@
 //!
 //! - **Entity**: A data model that maps to a database table (e.g., User, Post)
 //! - **Field**: A property of an entity that maps to a column (e.g., email, title)
+//! - **Compatibility**: Flags option combinations that cannot be generated together
+//! - **Computed fields**: Fields derived from others, as generated columns or model getters
 //! - **Relationship**: A connection between two entities (one-to-one, one-to-many, etc.)
 //! - **Endpoint**: An API endpoint configuration for CRUD operations
+//! - **Query**: A custom read-only query exposed as its own GET endpoint
+//! - **EnumDef**: A named enum type shared by fields across the project
+//! - **FieldSet**: A named group of fields (mixin) that entities include
+//! - **Import**: Reverse-engineers entities and relationships from a SQL DDL dump
+//! - **OpenAPI import**: Builds entities and endpoints from an OpenAPI 3 document
+//! - **Inference**: Builds an entity from a sample JSON document
+//! - **Suggestions**: Provider hooks that propose fields, descriptions and validations
+//! - **Templates**: Starter projects (blog, e-commerce, SaaS)
+//! - **Diff**: Lists what changed between two versions of a project
+//! - **Stats**: Project counts and the entity dependency graph
+//! - **Layout**: Arranges entities on the canvas as a layered graph
 //! - **ProjectGraph**: The root container that holds all project data
 //!
 
 // Module declarations
+pub mod compatibility;
+pub mod computed;
+pub mod diff;
 pub mod endpoint;
 pub mod entity;
+pub mod enums;
 pub mod field;
+pub mod field_sets;
+pub mod import;
+pub mod inference;
+pub mod layout;
+pub mod openapi_import;
 pub mod project;
+pub mod query;
 pub mod relationship;
 pub mod relationship_helpers;
 pub mod serialization;
+pub mod stats;
+pub mod suggestions;
+pub mod templates;
 pub mod validation;
+mod yaml;
 
 // Re-export commonly used types at crate root
-pub use endpoint::{CrudOperation, EndpointGroup, EndpointSecurity, OperationType, RateLimit};
-pub use entity::{Entity, EntityConfig};
+pub use compatibility::{Incompatibility, check_compatibility};
+pub use computed::{ComputedField, ComputedOp, ComputedStorage, ComputedToken};
+pub use diff::{Change, ChangeKind, ItemKind, ProjectDiff, diff_projects};
+pub use endpoint::{
+    AggregateKind, ApiVersioning, CrudOperation, ETagSource, EndpointGroup, EndpointSecurity,
+    HttpCaching, ListOptions, NestedPathStyle, OperationType, PathCase, PathPlurality, RateLimit,
+    RealtimeTransport, RelatedAggregate, RouteStyle, is_valid_api_version,
+};
+pub use entity::{Entity, EntityConfig, EntityIndex, IndexType};
+pub use enums::{EnumDef, enum_of};
 pub use field::{DefaultValue, Field, ForeignKeyRef, UiHints, WidgetType};
+pub use field_sets::FieldSet;
+pub use import::{ImportWarning, ImportedSchema, detect_dialect, import_sql};
+pub use inference::{InferredEntity, InferredField, NestedMode, NestedSample, infer_entity};
+pub use layout::auto_layout;
+pub use openapi_import::{ImportedApi, import_openapi};
 pub use project::{
-    AuthConfig, AuthStrategy, CanvasState, DatabaseConfig, ProjectConfig, ProjectGraph,
-    ProjectMeta, ProjectType,
+    ApiStyle, AuthConfig, AuthPrincipal, AuthStrategy, CanvasState, CorsConfig, DatabaseConfig,
+    OAuthProvider, OAuthProviderKind, ObservabilityConfig, Orm, PaginationStyle, ProjectConfig,
+    ProjectGraph, ProjectMeta, ProjectType, UploadConfig, UploadStorage, WebFramework,
+};
+pub use query::{
+    FilterOperator, FilterValue, QueryDefinition, QueryFilter, QueryJoin, QuerySort, SortDirection,
 };
 pub use relationship::{PortPosition, Relationship};
 pub use relationship_helpers::{
-    FkFieldInfo, add_fk_field_for_relationship, calculate_fk_info, create_fk_field,
-    create_fk_field_default, determine_fk_entity, generate_fk_field_name, generate_inverse_name,
-    generate_junction_table_name, generate_relationship_name, has_fk_to_entity,
+    AccessorNames, FkFieldInfo, accessor_names, add_fk_field_for_relationship, calculate_fk_info,
+    create_fk_field, create_fk_field_default, determine_fk_entity, generate_accessor_name,
+    generate_fk_field_name, generate_inverse_name, generate_junction_table_name,
+    generate_relationship_name, has_fk_to_entity, junction_keys,
 };
 pub use serialization::{load_project, save_project};
+pub use stats::{DependencyGraph, ProjectStats};
+pub use suggestions::{
+    RuleBasedProvider, Suggester, Suggestion, SuggestionKind, SuggestionProvider,
+};
+pub use templates::ProjectTemplate;
 pub use validation::{ValidationResult, ValidationRule, Validator};
 
 // Re-export core types that are commonly used with IR
@@ -47,7 +97,7 @@ pub use imortal_core::{
 };
 
 /// Current schema version for project files
-pub const SCHEMA_VERSION: u32 = 1;
+pub const SCHEMA_VERSION: u32 = 2;
 
 /// File extension for Immortal Engine project files
 pub const PROJECT_FILE_EXTENSION: &str = "ieng";
@@ -62,6 +112,8 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Convenient re-exports for common usage
 pub mod prelude {
     pub use crate::{
+        ApiStyle,
+        ApiVersioning,
         AuthStrategy,
         CrudOperation,
         // Re-exported from core
@@ -77,6 +129,8 @@ pub mod prelude {
         IdType,
         // Operations
         OperationType,
+        Orm,
+        PaginationStyle,
         Position,
         ProjectConfig,
         ProjectGraph,
@@ -84,7 +138,9 @@ pub mod prelude {
         ProjectType,
         RelationType,
         Relationship,
+        RouteStyle,
         Size,
+        WebFramework,
         // Relationship helpers
         add_fk_field_for_relationship,
         create_fk_field,
@@ -104,7 +160,7 @@ mod tests {
 
     #[test]
     fn test_schema_version() {
-        assert_eq!(SCHEMA_VERSION, 1);
+        assert_eq!(SCHEMA_VERSION, 2);
     }
 
     #[test]
@@ -0,0 +1,1538 @@
+//! # OpenAPI Import
+//!
+//! Brings an existing API definition into a project: [`import_openapi`]
+//! reads an OpenAPI 3.x document, as YAML or JSON, into entities built from
+//! its component schemas and endpoint groups built from its paths.
+//!
+//! | OpenAPI                                                   | Imported as                                  |
+//! |-----------------------------------------------------------|----------------------------------------------|
+//! | schema `Pet` (or `pets`)                                  | entity `Pet`                                 |
+//! | `CreatePetDto`, `UpdatePetDto`, `PetResponse`, `NewPet`, `PetInput`, … | merged into entity `Pet`        |
+//! | `PetPage`, `PetList`, `PetListItem` and error schemas     | skipped, as envelopes of other schemas       |
+//! | string schema with `enum`, inline `enum` property         | project enum                                 |
+//! | `required` and not nullable                               | required field                               |
+//! | `readOnly`, or only in the response schema                | read-only field                              |
+//! | `format: password`                                        | secret `…_hash` field                        |
+//! | `minLength`, `maxLength`, `minimum`, `maximum`, `pattern`, `format: email`/`uri` | validations           |
+//! | `id` property                                             | primary key (serial when an integer)         |
+//! | `$ref` property, or `<entity>_id` property                | foreign key and many-to-one relationship     |
+//! | `created_at` + `updated_at` / `deleted_at`                | timestamps / soft delete enabled             |
+//! | `GET`/`POST /pets`, `GET`/`PUT`/`PATCH`/`DELETE /pets/{id}` | endpoint group at `/pets` with those operations |
+//! | operation or document `security`                          | authenticated operation                      |
+//!
+//! Other routes, nested resources and schemas that aren't objects are
+//! skipped, and whatever can't be represented exactly is reported as an
+//! [`ImportWarning`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::openapi_import::import_openapi;
+//! use imortal_ir::OperationType;
+//!
+//! let spec = r#"
+//! openapi: 3.0.3
+//! info: {title: Pets, version: 1.0.0}
+//! paths:
+//!   /pets:
+//!     get:
+//!       responses:
+//!         '200':
+//!           description: All pets
+//!           content:
+//!             application/json:
+//!               schema: {type: array, items: {$ref: '#/components/schemas/Pet'}}
+//! components:
+//!   schemas:
+//!     Pet:
+//!       type: object
+//!       required: [id, name]
+//!       properties:
+//!         id: {type: integer, format: int64}
+//!         name: {type: string, maxLength: 80}
+//! "#;
+//! let project = import_openapi(spec).unwrap().into_project("pets");
+//!
+//! let pet = project.get_entity_by_name("Pet").unwrap();
+//! assert!(pet.get_field_by_name("name").unwrap().required);
+//! let endpoint = project.endpoints().next().unwrap();
+//! assert_eq!(endpoint.base_path, "/pets");
+//! assert_eq!(endpoint.enabled_operations()[0].operation_type, OperationType::ReadAll);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use imortal_core::{
+    DataType, EngineError, EngineResult, IdType, Position, ReferentialAction, Validation,
+};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::import::{
+    ImportWarning, add_imported, detect_timestamps, entity_name, link_foreign_key, to_pascal_case,
+    to_snake_case,
+};
+use crate::{
+    DefaultValue, EndpointGroup, EndpointSecurity, Entity, EnumDef, Field, OperationType,
+    ProjectGraph, Relationship, yaml,
+};
+
+/// Prefix of references to component schemas
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// How deep references are followed, so reference cycles end
+const MAX_REF_DEPTH: usize = 8;
+
+// ============================================================================
+// Imported API
+// ============================================================================
+
+/// The entities, relationships, enums and endpoints read from an OpenAPI
+/// document
+#[derive(Debug, Clone)]
+pub struct ImportedApi {
+    /// `info.title`
+    pub title: String,
+
+    /// `info.version`
+    pub version: Option<String>,
+
+    /// `info.description`
+    pub description: Option<String>,
+
+    /// One entity per object schema, with its request and response
+    /// variants merged in
+    pub entities: Vec<Entity>,
+
+    /// One relationship per foreign key
+    pub relationships: Vec<Relationship>,
+
+    /// Enum types used by the entities' fields
+    pub enums: Vec<EnumDef>,
+
+    /// One endpoint group per CRUD resource path
+    pub endpoints: Vec<EndpointGroup>,
+
+    /// Everything that couldn't be imported exactly
+    pub warnings: Vec<ImportWarning>,
+}
+
+impl ImportedApi {
+    /// Add the imported entities, relationships, enums and endpoints to a
+    /// project, laying the entities out in a grid from `origin` with each
+    /// endpoint group beside its entity. Returns the new entity ids.
+    ///
+    /// Fails without changing the project if an entity or enum name is
+    /// already taken.
+    pub fn apply(&self, project: &mut ProjectGraph, origin: Position) -> EngineResult<Vec<Uuid>> {
+        let ids = add_imported(
+            project,
+            origin,
+            &self.entities,
+            &self.relationships,
+            &self.enums,
+        )?;
+        for endpoint in &self.endpoints {
+            let position = project
+                .get_entity(endpoint.entity_id)
+                .map_or(origin, |e| e.position);
+            project.add_endpoint(endpoint.clone().at(position.x + 30.0, position.y + 30.0));
+        }
+        Ok(ids)
+    }
+
+    /// Build a new project holding the imported API, with authentication
+    /// enabled when any operation requires it
+    pub fn into_project(self, name: impl Into<String>) -> ProjectGraph {
+        let mut project = ProjectGraph::new(name);
+        project.meta.description = self.description.clone();
+        if let Some(version) = &self.version {
+            project.meta.version = version.clone();
+        }
+        project.config.auth.enabled = self.endpoints.iter().any(|e| e.requires_auth());
+        self.apply(&mut project, Position::new(100.0, 100.0))
+            .expect("a new project has no names to clash with");
+        project
+    }
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Read an OpenAPI 3.x document, as JSON when it starts with `{` and as
+/// YAML otherwise.
+///
+/// Fails when the document can't be parsed, isn't OpenAPI 3, or has no
+/// object schema to import.
+pub fn import_openapi(source: &str) -> EngineResult<ImportedApi> {
+    let doc: Value = if source.trim_start().starts_with('{') {
+        serde_json::from_str(source)?
+    } else {
+        yaml::parse(source)?
+    };
+
+    let version = match doc.get("openapi") {
+        Some(Value::String(version)) => Some(version.clone()),
+        Some(Value::Number(version)) => Some(version.to_string()),
+        _ => None,
+    };
+    match version {
+        Some(version) if version.starts_with("3.") => {}
+        Some(version) => {
+            return Err(EngineError::Validation(format!(
+                "Unsupported OpenAPI version {}",
+                version
+            )));
+        }
+        None if doc.get("swagger").is_some() => {
+            return Err(EngineError::Validation(
+                "Swagger 2.0 documents aren't supported; convert to OpenAPI 3 first".to_string(),
+            ));
+        }
+        None => {
+            return Err(EngineError::Validation(
+                "Not an OpenAPI document: no `openapi` version".to_string(),
+            ));
+        }
+    }
+
+    let empty = Map::new();
+    let schemas = doc
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let mut reader = ApiReader::new(&doc, schemas);
+    reader.schemas();
+    if reader.entities.is_empty() {
+        return Err(EngineError::Validation(
+            "No object schemas found under components.schemas".to_string(),
+        ));
+    }
+    reader.paths();
+    Ok(reader.finish())
+}
+
+// ============================================================================
+// Schema naming
+// ============================================================================
+
+/// Which payload of a resource a schema describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Variant {
+    /// The resource itself, e.g. `Pet`
+    Plain,
+    /// What the API returns, e.g. `PetResponse`
+    Response,
+    /// What creating takes, e.g. `CreatePetDto`, `NewPet`, `PetInput`
+    Create,
+    /// What updating takes, e.g. `UpdatePetDto`, `PetPatch`
+    Update,
+}
+
+/// The resource a schema name belongs to, and which of its payloads it is:
+/// `CreatePetDto` → (`Pet`, create)
+fn dto_base(name: &str) -> (&str, Variant) {
+    let mut base = name;
+    let mut variant = None;
+    let strip = |s: &str, affix: &str, prefix: bool| -> Option<String> {
+        let rest = if prefix {
+            s.strip_prefix(affix)
+                .filter(|r| r.starts_with(|c: char| c.is_ascii_uppercase()))
+        } else {
+            s.strip_suffix(affix)
+        }?;
+        (!rest.is_empty()).then(|| rest.to_string())
+    };
+
+    for (prefix, v) in [
+        ("Create", Variant::Create),
+        ("New", Variant::Create),
+        ("Update", Variant::Update),
+        ("Patch", Variant::Update),
+    ] {
+        if let Some(rest) = strip(base, prefix, true) {
+            base = &name[name.len() - rest.len()..];
+            variant = Some(v);
+            break;
+        }
+    }
+
+    let mut input = false;
+    'strip: loop {
+        for (suffix, v) in [
+            ("Dto", None),
+            ("DTO", None),
+            ("Response", Some(Variant::Response)),
+            ("Output", Some(Variant::Response)),
+            ("Request", None),
+            ("Input", None),
+            ("Payload", None),
+            ("Body", None),
+            ("Create", Some(Variant::Create)),
+            ("Update", Some(Variant::Update)),
+            ("Patch", Some(Variant::Update)),
+        ] {
+            if let Some(rest) = strip(base, suffix, false) {
+                base = &base[..rest.len()];
+                match v {
+                    Some(v) => {
+                        variant.get_or_insert(v);
+                    }
+                    None => input |= !suffix.eq_ignore_ascii_case("dto"),
+                }
+                continue 'strip;
+            }
+        }
+        break;
+    }
+
+    let variant = variant.unwrap_or(if input {
+        Variant::Create
+    } else {
+        Variant::Plain
+    });
+    (base, variant)
+}
+
+/// Envelopes around lists of another schema, generated from it
+fn is_envelope(name: &str) -> bool {
+    ["Page", "List", "ListItem", "Collection", "Paginated"]
+        .iter()
+        .any(|suffix| name.len() > suffix.len() && name.ends_with(suffix))
+}
+
+fn is_error(name: &str) -> bool {
+    name.contains("Error") || name.contains("Problem")
+}
+
+/// The schema a `$ref` points at, by name
+fn ref_name(schema: &Value) -> Option<&str> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference.strip_prefix(SCHEMA_REF_PREFIX);
+    }
+    // `allOf: [$ref]` is how 3.0 documents a reference with a description
+    match schema.get("allOf").and_then(Value::as_array)?.as_slice() {
+        [only] => ref_name(only),
+        _ => None,
+    }
+}
+
+/// The schema without its null alternative, and whether null is allowed:
+/// `nullable: true` (3.0), `type: [string, "null"]` and `oneOf: [X, {type: null}]` (3.1)
+fn without_null(schema: &Value) -> (Value, bool) {
+    let is_null = |s: &Value| s.get("type").and_then(Value::as_str) == Some("null");
+    let mut schema = schema.clone();
+    let mut nullable = false;
+
+    if let Some(Value::Bool(true)) = schema.get("nullable") {
+        nullable = true;
+    }
+    if let Some(Value::Array(types)) = schema.get("type") {
+        let types: Vec<Value> = types
+            .iter()
+            .filter(|t| t.as_str() != Some("null"))
+            .cloned()
+            .collect();
+        nullable |= types.len() < schema["type"].as_array().map_or(0, Vec::len);
+        schema["type"] = match types.as_slice() {
+            [one] => one.clone(),
+            _ => Value::Array(types),
+        };
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(Value::Array(options)) = schema.get(key)
+            && options.iter().any(is_null)
+        {
+            let rest: Vec<&Value> = options.iter().filter(|o| !is_null(o)).collect();
+            if let [only] = rest.as_slice() {
+                return ((*only).clone(), true);
+            }
+        }
+    }
+    (schema, nullable)
+}
+
+/// A JSON value as the text of an example
+fn example_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// Reader
+// ============================================================================
+
+/// A property of a resource, gathered across its schema variants
+struct Property<'d> {
+    name: &'d str,
+    /// Its schema in the first request payload it appears in, where
+    /// validations are written, or else in the first response
+    schema: &'d Value,
+    /// Variants the property appears in
+    variants: Vec<Variant>,
+    /// Variants that list it as required
+    required_in: Vec<Variant>,
+    required: bool,
+    /// Only returned, never sent
+    read_only: bool,
+    /// Only sent, never returned
+    write_only: bool,
+}
+
+/// A resource path and the CRUD operations found on it
+struct Route<'d> {
+    base_path: String,
+    operations: Vec<(OperationType, &'d Value)>,
+}
+
+struct ApiReader<'d> {
+    doc: &'d Value,
+    schemas: &'d Map<String, Value>,
+    /// Object schema name → index of the entity it was merged into
+    schema_entities: HashMap<&'d str, usize>,
+    /// Enum schema name → index into `enums`
+    schema_enums: HashMap<&'d str, usize>,
+    /// Schemas embedding one resource in another's payload, e.g. `CreateCommentInPostDto`
+    nested_schemas: HashSet<&'d str>,
+    entities: Vec<Entity>,
+    enums: Vec<EnumDef>,
+    /// Foreign keys to link once every entity exists: entity, field, target entity
+    foreign_keys: Vec<(usize, String, usize)>,
+    endpoints: Vec<EndpointGroup>,
+    warnings: Vec<ImportWarning>,
+}
+
+impl<'d> ApiReader<'d> {
+    fn new(doc: &'d Value, schemas: &'d Map<String, Value>) -> Self {
+        Self {
+            doc,
+            schemas,
+            schema_entities: HashMap::new(),
+            schema_enums: HashMap::new(),
+            nested_schemas: HashSet::new(),
+            entities: Vec::new(),
+            enums: Vec::new(),
+            foreign_keys: Vec::new(),
+            endpoints: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn warn(&mut self, subject: Option<&str>, message: impl Into<String>) {
+        self.warnings.push(ImportWarning {
+            table: subject.map(str::to_string),
+            message: message.into(),
+        });
+    }
+
+    /// The schema a reference points at, following chains of references
+    fn resolve(&self, schema: &'d Value) -> &'d Value {
+        let mut schema = schema;
+        for _ in 0..MAX_REF_DEPTH {
+            match schema
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|r| r.strip_prefix(SCHEMA_REF_PREFIX))
+                .and_then(|name| self.schemas.get(name))
+            {
+                Some(target) => schema = target,
+                None => break,
+            }
+        }
+        schema
+    }
+
+    /// Properties and required names of an object schema, including those
+    /// of its `allOf` parts
+    fn object_parts(
+        &self,
+        schema: &'d Value,
+        depth: usize,
+    ) -> (Vec<(&'d str, &'d Value)>, Vec<&'d str>) {
+        let schema = self.resolve(schema);
+        let mut properties: Vec<(&str, &Value)> = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|p| p.iter().map(|(k, v)| (k.as_str(), v)).collect())
+            .unwrap_or_default();
+        let mut required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if depth < MAX_REF_DEPTH
+            && let Some(parts) = schema.get("allOf").and_then(Value::as_array)
+        {
+            for part in parts {
+                let (more, also_required) = self.object_parts(part, depth + 1);
+                for (name, property) in more {
+                    if !properties.iter().any(|(n, _)| *n == name) {
+                        properties.push((name, property));
+                    }
+                }
+                required.extend(also_required);
+            }
+        }
+        (properties, required)
+    }
+
+    fn is_object(&self, schema: &Value) -> bool {
+        schema.get("properties").is_some()
+            || schema.get("allOf").is_some()
+            || schema.get("type").and_then(Value::as_str) == Some("object")
+    }
+
+    // ========================================================================
+    // Schemas
+    // ========================================================================
+
+    fn schemas(&mut self) {
+        let schemas = self.schemas;
+
+        for (name, schema) in schemas {
+            let schema = self.resolve(schema);
+            let Some(variants) = schema.get("enum").and_then(Value::as_array) else {
+                continue;
+            };
+            if !self.is_object(schema) {
+                let def = EnumDef::new(to_pascal_case(name))
+                    .with_variants(variants.iter().map(example_text));
+                self.schema_enums.insert(name, self.enums.len());
+                self.enums.push(def);
+            }
+        }
+
+        // Group the object schemas by the resource they describe
+        let mut groups: Vec<(String, Vec<(Variant, &'d str)>)> = Vec::new();
+        for (name, schema) in schemas {
+            if self.schema_enums.contains_key(name.as_str()) || is_error(name) || is_envelope(name)
+            {
+                continue;
+            }
+            if !self.is_object(self.resolve(schema)) {
+                self.warn(Some(name), "not an object schema; skipped");
+                continue;
+            }
+            let (base, variant) = dto_base(name);
+            let entity = entity_name(base);
+            match groups.iter_mut().find(|(e, _)| *e == entity) {
+                Some((_, members)) => members.push((variant, name)),
+                None => groups.push((entity, vec![(variant, name)])),
+            }
+        }
+
+        // `Create{Child}In{Parent}Dto` embeds a child in its parent's payload
+        let names: HashSet<String> = groups.iter().map(|(e, _)| e.clone()).collect();
+        groups.retain(|(entity, members)| {
+            let nested = entity
+                .match_indices("In")
+                .any(|(i, _)| names.contains(&entity[..i]) && names.contains(&entity[i + 2..]));
+            if nested {
+                self.nested_schemas
+                    .extend(members.iter().map(|(_, name)| *name));
+            }
+            !nested
+        });
+
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+        for (i, (_, members)) in groups.iter().enumerate() {
+            for (_, name) in members {
+                self.schema_entities.insert(name, i);
+            }
+        }
+        for (i, (entity, mut members)) in groups.into_iter().enumerate() {
+            members.sort();
+            let entity = self.entity(i, entity, &members);
+            self.entities.push(entity);
+        }
+    }
+
+    /// Build entity `index` from the schemas describing it
+    fn entity(&mut self, index: usize, name: String, members: &[(Variant, &'d str)]) -> Entity {
+        let mut entity = Entity::new(name);
+        entity.fields.clear();
+        entity.config.timestamps = false;
+        let subject = members[0].1;
+        let schemas = self.schemas;
+
+        let mut properties: Vec<Property<'d>> = Vec::new();
+        for &(variant, schema_name) in members {
+            let schema = &schemas[schema_name];
+            if variant == Variant::Plain && entity.description.is_none() {
+                entity.description = self
+                    .resolve(schema)
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+            }
+            let (parts, required) = self.object_parts(schema, 0);
+            for (name, schema) in parts {
+                let property = match properties.iter_mut().find(|p| p.name == name) {
+                    Some(property) => property,
+                    None => {
+                        properties.push(Property {
+                            name,
+                            schema,
+                            variants: Vec::new(),
+                            required_in: Vec::new(),
+                            required: false,
+                            read_only: false,
+                            write_only: false,
+                        });
+                        properties.last_mut().expect("just pushed")
+                    }
+                };
+                let is_input = |v: &Variant| matches!(v, Variant::Create | Variant::Update);
+                if is_input(&variant) && !property.variants.iter().any(is_input) {
+                    property.schema = schema;
+                }
+                property.variants.push(variant);
+                if required.contains(&name) {
+                    property.required_in.push(variant);
+                }
+            }
+        }
+
+        let has = |v: Variant| members.iter().any(|(m, _)| *m == v);
+        let has_input = has(Variant::Create) || has(Variant::Update);
+        let has_output = has(Variant::Plain) || has(Variant::Response);
+        for property in &mut properties {
+            let input = property
+                .variants
+                .iter()
+                .any(|v| matches!(v, Variant::Create | Variant::Update));
+            let output = property
+                .variants
+                .iter()
+                .any(|v| matches!(v, Variant::Plain | Variant::Response));
+            // Creating decides what is required; the rest follow the output
+            property.required = if property.variants.contains(&Variant::Create) {
+                property.required_in.contains(&Variant::Create)
+            } else {
+                !property.required_in.is_empty()
+            };
+            property.read_only = has_input && output && !input;
+            property.write_only = has_output && input && !output;
+        }
+        for property in &properties {
+            if let Some(field) = self.field(index, &entity.name, subject, property) {
+                if entity.has_field(&field.name) {
+                    self.warn(
+                        Some(subject),
+                        format!("`{}` appears twice; kept the first", field.name),
+                    );
+                    continue;
+                }
+                entity.add_field(field);
+            }
+        }
+
+        let key = entity.fields.iter().position(|f| f.name == "id");
+        match key {
+            Some(key) => self.make_primary_key(subject, &mut entity, key),
+            None => {
+                entity.fields.insert(0, Field::primary_key());
+                self.warn(Some(subject), "no `id` property; added a UUID `id` key");
+            }
+        }
+        detect_timestamps(&mut entity);
+        entity
+    }
+
+    fn field(
+        &mut self,
+        index: usize,
+        entity_name: &str,
+        subject: &str,
+        property: &Property<'d>,
+    ) -> Option<Field> {
+        let (schema, nullable) = without_null(property.schema);
+        let mut name = to_snake_case(property.name);
+
+        // A reference to another resource is a foreign key to it
+        if let Some(&to) = ref_name(&schema).and_then(|t| self.schema_entities.get(t)) {
+            if !name.ends_with("_id") {
+                name.push_str("_id");
+            }
+            let mut field = Field::new(&name, DataType::Uuid);
+            field.required = property.required && !nullable;
+            field.description = property
+                .schema
+                .get("description")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            self.foreign_keys.push((index, name, to));
+            return Some(field);
+        }
+
+        let data_type = self.data_type(entity_name, subject, property.name, &schema)?;
+        let resolved = self.resolve_owned(&schema);
+        let format = resolved.get("format").and_then(Value::as_str).unwrap_or("");
+        let text = |key: &str| {
+            resolved
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        let number = |key: &str| resolved.get(key).and_then(Value::as_f64);
+
+        let mut field = Field::new(&name, data_type);
+        field.required = property.required && !nullable;
+        field.readonly = property.read_only || resolved.get("readOnly") == Some(&Value::Bool(true));
+        field.description = text("description");
+        field.example = resolved
+            .get("examples")
+            .and_then(Value::as_array)
+            .and_then(|e| e.first())
+            .or_else(|| resolved.get("example"))
+            .map(example_text);
+        field.default_value = resolved.get("default").and_then(default_value);
+
+        if let Some(n) = number("minLength") {
+            field.validations.push(Validation::MinLength(n as usize));
+        }
+        if let Some(n) = number("maxLength") {
+            field.validations.push(Validation::MaxLength(n as usize));
+        }
+        if let Some(n) = number("minimum") {
+            field.validations.push(Validation::Min(n));
+        }
+        if let Some(n) = number("maximum") {
+            field.validations.push(Validation::Max(n));
+        }
+        if let Some(regex) = text("pattern") {
+            field.validations.push(Validation::Pattern {
+                regex,
+                message: "must match the required format".to_string(),
+            });
+        }
+        match format {
+            "email" => field.validations.push(Validation::Email),
+            "uri" | "url" => field.validations.push(Validation::Url),
+            _ => {}
+        }
+        if !matches!(field.data_type, DataType::Enum { .. })
+            && let Some(values) = resolved.get("enum").and_then(Value::as_array)
+        {
+            field
+                .validations
+                .push(Validation::OneOf(values.iter().map(example_text).collect()));
+        }
+
+        let write_only =
+            property.write_only || resolved.get("writeOnly") == Some(&Value::Bool(true));
+        if format == "password" || (write_only && field.looks_like_password()) {
+            // Clients send the plain text; the model stores its hash
+            if !field.name.ends_with("_hash") {
+                field.name.push_str("_hash");
+                field.column_name.push_str("_hash");
+            }
+            field.example = None;
+            field.apply_secret_recommendations();
+        }
+        Some(field)
+    }
+
+    fn resolve_owned(&self, schema: &Value) -> Value {
+        match ref_name(schema).and_then(|name| self.schemas.get(name)) {
+            Some(target) => self.resolve(target).clone(),
+            None => schema.clone(),
+        }
+    }
+
+    /// The data type of a property schema; `None` for lists of other
+    /// resources, which relationships express instead
+    fn data_type(
+        &mut self,
+        entity_name: &str,
+        subject: &str,
+        property: &str,
+        schema: &Value,
+    ) -> Option<DataType> {
+        if let Some(target) = ref_name(schema) {
+            if let Some(&e) = self.schema_enums.get(target) {
+                return Some(self.enums[e].data_type());
+            }
+            if self.schema_entities.contains_key(target) || self.nested_schemas.contains(target) {
+                if !self.nested_schemas.contains(target) {
+                    self.warn(
+                        Some(subject),
+                        format!(
+                            "`{}` holds {} records; add a relationship instead",
+                            property, target
+                        ),
+                    );
+                }
+                return None;
+            }
+        }
+        let schema = self.resolve_owned(schema);
+        let format = schema.get("format").and_then(Value::as_str).unwrap_or("");
+
+        let data_type = match schema.get("type").and_then(Value::as_str) {
+            Some("string") => match (format, schema.get("enum").and_then(Value::as_array)) {
+                (_, Some(variants)) => {
+                    let def = EnumDef::new(format!("{}{}", entity_name, to_pascal_case(property)))
+                        .with_variants(variants.iter().map(example_text));
+                    let data_type = def.data_type();
+                    self.enums.push(def);
+                    data_type
+                }
+                ("date-time", _) => DataType::DateTime,
+                ("date", _) => DataType::Date,
+                ("time", _) => DataType::Time,
+                ("uuid", _) => DataType::Uuid,
+                ("byte" | "binary", _) => DataType::Bytes,
+                ("decimal", _) => DataType::money(),
+                _ => DataType::String,
+            },
+            Some("integer") if format == "int64" => DataType::Int64,
+            Some("integer") => DataType::Int32,
+            Some("number") if format == "float" => DataType::Float32,
+            Some("number") => DataType::Float64,
+            Some("boolean") => DataType::Bool,
+            Some("array") => {
+                let items = schema.get("items").cloned().unwrap_or_default();
+                let (items, _) = without_null(&items);
+                if items.get("format").and_then(Value::as_str) == Some("uint8") {
+                    DataType::Bytes
+                } else {
+                    let inner = self.data_type(entity_name, subject, property, &items)?;
+                    DataType::Array(Box::new(inner))
+                }
+            }
+            Some("object") | None => DataType::Json,
+            Some(other) => {
+                self.warn(
+                    Some(subject),
+                    format!(
+                        "`{}` has unsupported type {}; imported as String",
+                        property, other
+                    ),
+                );
+                DataType::String
+            }
+        };
+        Some(data_type)
+    }
+
+    /// Mark `entity.fields[key]` as the entity's primary key and pick the id
+    /// type from its data type
+    fn make_primary_key(&mut self, subject: &str, entity: &mut Entity, key: usize) {
+        let field = entity.fields.remove(key);
+        let mut field = Field {
+            is_primary_key: true,
+            required: true,
+            indexed: true,
+            readonly: true,
+            unique: false,
+            default_value: None,
+            ..field
+        };
+        entity.config.id_type = match field.data_type {
+            DataType::Int32 | DataType::Int64 => IdType::Serial,
+            DataType::Uuid => IdType::Uuid,
+            _ => {
+                self.warn(
+                    Some(subject),
+                    "`id` is not an integer or UUID; imported as a UUID key",
+                );
+                field.data_type = DataType::Uuid;
+                field.validations.clear();
+                IdType::Uuid
+            }
+        };
+        entity.fields.insert(0, field);
+    }
+
+    // ========================================================================
+    // Paths
+    // ========================================================================
+
+    fn paths(&mut self) {
+        let Some(paths) = self.doc.get("paths").and_then(Value::as_object) else {
+            return;
+        };
+
+        let mut routes: Vec<Route<'d>> = Vec::new();
+        for (path, item) in paths {
+            let Some(item) = item.as_object() else {
+                continue;
+            };
+            let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+            let is_param = |s: &&str| s.starts_with('{') && s.ends_with('}');
+            let (base_path, single) = match segments.split_last() {
+                Some((last, rest)) if is_param(last) && !rest.iter().any(is_param) => {
+                    (rest.join("/"), true)
+                }
+                _ if !segments.iter().any(is_param) => (segments.join("/"), false),
+                // Soft-delete restores come with the delete operation
+                Some((&"restore", rest)) if rest.last().is_some_and(is_param) => continue,
+                _ => {
+                    self.warn(Some(path), "not a CRUD resource path; skipped");
+                    continue;
+                }
+            };
+
+            for (method, operation) in item {
+                let operation_type = match (method.as_str(), single) {
+                    ("get", false) => OperationType::ReadAll,
+                    ("post", false) => OperationType::Create,
+                    ("get", true) => OperationType::Read,
+                    ("put" | "patch", true) => OperationType::Update,
+                    ("delete", true) => OperationType::Delete,
+                    ("parameters" | "summary" | "description" | "servers", _) => continue,
+                    _ => {
+                        self.warn(
+                            Some(path),
+                            format!("{} is not a CRUD operation; skipped", method.to_uppercase()),
+                        );
+                        continue;
+                    }
+                };
+                let route = match routes.iter().position(|r| r.base_path == base_path) {
+                    Some(i) => &mut routes[i],
+                    None => {
+                        routes.push(Route {
+                            base_path: base_path.clone(),
+                            operations: Vec::new(),
+                        });
+                        routes.last_mut().expect("just pushed")
+                    }
+                };
+                // PUT and PATCH both update; the first one listed wins
+                if !route.operations.iter().any(|(t, _)| *t == operation_type) {
+                    route.operations.push((operation_type, operation));
+                }
+            }
+        }
+
+        for route in routes {
+            self.endpoint(route);
+        }
+    }
+
+    /// The entity a payload schema carries, looking through arrays and
+    /// list envelopes
+    fn entity_of(&self, schema: &'d Value, depth: usize) -> Option<usize> {
+        if depth > MAX_REF_DEPTH {
+            return None;
+        }
+        if let Some(&entity) = ref_name(schema).and_then(|name| self.schema_entities.get(name)) {
+            return Some(entity);
+        }
+        let schema = self.resolve(schema);
+        if let Some(items) = schema.get("items") {
+            return self.entity_of(items, depth + 1);
+        }
+        let (properties, _) = self.object_parts(schema, 0);
+        properties
+            .iter()
+            .filter(|(_, p)| p.get("items").is_some())
+            .find_map(|(_, p)| self.entity_of(p, depth + 1))
+    }
+
+    /// The entity a route serves: the one its payloads carry, or the one
+    /// named like its last path segment
+    fn route_entity(&self, route: &Route<'d>) -> Option<usize> {
+        let payloads = route.operations.iter().flat_map(|(_, operation)| {
+            let request = operation.pointer("/requestBody/content");
+            let responses = operation
+                .get("responses")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .filter(|(status, _)| status.starts_with('2'))
+                .filter_map(|(_, response)| response.get("content"));
+            request
+                .into_iter()
+                .chain(responses)
+                .filter_map(Value::as_object)
+                .flat_map(|content| content.values())
+                .filter_map(|media| media.get("schema"))
+        });
+        for schema in payloads {
+            if let Some(entity) = self.entity_of(schema, 0) {
+                return Some(entity);
+            }
+        }
+
+        let last = route.base_path.rsplit('/').next()?;
+        let name = entity_name(last);
+        self.entities.iter().position(|e| e.name == name)
+    }
+
+    fn endpoint(&mut self, route: Route<'d>) {
+        let Some(index) = self.route_entity(&route) else {
+            self.warn(
+                Some(&route.base_path),
+                "no schema matches this path; skipped",
+            );
+            return;
+        };
+        let entity = &self.entities[index];
+        let (entity_id, entity_name) = (entity.id, entity.name.clone());
+        if let Some(existing) = self.endpoints.iter().find(|e| e.entity_id == entity_id) {
+            let message = format!(
+                "{} already has endpoints at {}; skipped",
+                entity_name, existing.base_path
+            );
+            self.warn(Some(&route.base_path), message);
+            return;
+        }
+
+        let types: Vec<OperationType> = route.operations.iter().map(|(t, _)| *t).collect();
+        let mut group = EndpointGroup::new(entity_id, &entity_name)
+            .with_base_path(&route.base_path)
+            .with_operations(&types);
+
+        let mut securities = Vec::new();
+        for (operation_type, operation) in &route.operations {
+            let crud = group
+                .get_operation_mut(*operation_type)
+                .expect("every operation type has an entry");
+            crud.operation_id = operation
+                .get("operationId")
+                .and_then(Value::as_str)
+                .filter(|id| *id != crud.default_operation_id(&entity_name))
+                .map(str::to_string);
+            crud.description = operation
+                .get("description")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            if let Some(status) = operation
+                .get("responses")
+                .and_then(Value::as_object)
+                .and_then(|r| {
+                    r.keys()
+                        .find_map(|s| s.parse::<u16>().ok().filter(|s| (200..300).contains(s)))
+                })
+            {
+                crud.success_status = status;
+            }
+            securities.push((*operation_type, self.security(operation)));
+
+            if let Some(tags) = operation.get("tags").and_then(Value::as_array)
+                && group.tags == [entity_name.clone()]
+                && !tags.is_empty()
+            {
+                group.tags = tags
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect();
+            }
+        }
+
+        // One security for the whole group when every operation agrees
+        let first = securities[0].1.clone();
+        if securities.iter().all(|(_, s)| *s == first) {
+            if let Some(security) = first {
+                group.global_security = security;
+            }
+        } else {
+            for (operation_type, security) in securities {
+                if let Some(security) = security {
+                    group.set_operation_security(operation_type, security);
+                }
+            }
+        }
+
+        group.description = group.tags.first().and_then(|tag| {
+            self.doc
+                .get("tags")?
+                .as_array()?
+                .iter()
+                .find(|t| t.get("name").and_then(Value::as_str) == Some(tag))?
+                .get("description")?
+                .as_str()
+                .map(str::to_string)
+        });
+        self.endpoints.push(group);
+    }
+
+    /// The security an operation requires, from its own `security` or the
+    /// document's; `None` when it may be called anonymously
+    fn security(&self, operation: &Value) -> Option<EndpointSecurity> {
+        let requirements = operation
+            .get("security")
+            .or_else(|| self.doc.get("security"))?
+            .as_array()?;
+        // An empty requirement (`{}`) among them allows anonymous calls
+        let secured = !requirements.is_empty()
+            && requirements
+                .iter()
+                .all(|r| r.as_object().is_some_and(|r| !r.is_empty()));
+        if !secured {
+            return None;
+        }
+        let mut security = EndpointSecurity::authenticated();
+        for scope in requirements[0]
+            .as_object()
+            .into_iter()
+            .flat_map(|r| r.values())
+            .filter_map(Value::as_array)
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            security = security.add_scope(scope);
+        }
+        Some(security)
+    }
+
+    // ========================================================================
+    // Finish
+    // ========================================================================
+
+    fn finish(mut self) -> ImportedApi {
+        // `<entity>_id` properties are keys even without a `$ref`
+        let names: Vec<String> = self
+            .entities
+            .iter()
+            .map(|e| to_snake_case(&e.name))
+            .collect();
+        for (index, entity) in self.entities.iter().enumerate() {
+            for field in &entity.fields {
+                let Some(to) = field
+                    .name
+                    .strip_suffix("_id")
+                    .and_then(|prefix| names.iter().position(|n| n == prefix))
+                else {
+                    continue;
+                };
+                let is_key_type = matches!(
+                    field.data_type,
+                    DataType::Uuid | DataType::Int32 | DataType::Int64 | DataType::String
+                );
+                let linked = self
+                    .foreign_keys
+                    .iter()
+                    .any(|(i, name, _)| *i == index && *name == field.name);
+                if is_key_type && !linked && !field.is_primary_key {
+                    self.foreign_keys.push((index, field.name.clone(), to));
+                }
+            }
+        }
+
+        let mut relationships = Vec::new();
+        let mut names = HashSet::new();
+        for (from, field, to) in std::mem::take(&mut self.foreign_keys) {
+            let Some(ref_field) = self.entities[to].primary_key().map(|f| f.name.clone()) else {
+                continue;
+            };
+            relationships.extend(link_foreign_key(
+                &mut self.entities,
+                (from, field.as_str()),
+                (to, ref_field),
+                (ReferentialAction::Restrict, ReferentialAction::Cascade),
+                &mut names,
+            ));
+        }
+
+        let info = self.doc.get("info");
+        let text = |key: &str| {
+            info.and_then(|i| i.get(key))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        ImportedApi {
+            title: text("title").unwrap_or_default(),
+            version: text("version"),
+            description: text("description"),
+            entities: self.entities,
+            relationships,
+            enums: self.enums,
+            endpoints: self.endpoints,
+            warnings: self.warnings,
+        }
+    }
+}
+
+/// A property's `default` as a field default; `None` for defaults the IR
+/// can't hold
+fn default_value(value: &Value) -> Option<DefaultValue> {
+    Some(match value {
+        Value::Null => DefaultValue::Null,
+        Value::Bool(b) => DefaultValue::Bool(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => DefaultValue::Int(i),
+            None => DefaultValue::Float(n.as_f64()?),
+        },
+        Value::String(s) => DefaultValue::String(s.clone()),
+        Value::Array(items) if items.is_empty() => DefaultValue::EmptyArray,
+        Value::Object(map) if map.is_empty() => DefaultValue::EmptyObject,
+        _ => return None,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::RelationType;
+
+    const PETSTORE: &str = r#"
+openapi: 3.0.3
+info:
+  title: Pet Store
+  version: 2.1.0
+  description: Pets and their owners
+security:
+  - bearerAuth: []
+tags:
+  - name: pets
+    description: Everything about pets
+paths:
+  /v1/pets:
+    get:
+      tags: [pets]
+      operationId: listPets
+      security: []
+      responses:
+        '200':
+          description: A page of pets
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/PetPage'
+    post:
+      tags: [pets]
+      description: Add a pet to the store
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/NewPet'
+      responses:
+        '201':
+          description: Created
+  /v1/pets/{petId}:
+    get:
+      tags: [pets]
+      security: []
+      responses:
+        '200':
+          description: A pet
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet'
+    delete:
+      tags: [pets]
+      responses:
+        '204':
+          description: Deleted
+    options:
+      responses:
+        '200':
+          description: Allowed methods
+  /v1/owners:
+    get:
+      responses:
+        '200':
+          description: Owners
+  /v1/owners/{ownerId}/pets:
+    get:
+      responses:
+        '200':
+          description: Pets of an owner
+components:
+  securitySchemes:
+    bearerAuth: {type: http, scheme: bearer}
+  schemas:
+    Species:
+      type: string
+      enum: [dog, cat, bird]
+    Owner:
+      type: object
+      required: [id, email]
+      properties:
+        id: {type: string, format: uuid}
+        email: {type: string, format: email, maxLength: 255}
+        pets:
+          type: array
+          items: {$ref: '#/components/schemas/Pet'}
+    NewPet:
+      type: object
+      required: [name, species, owner]
+      properties:
+        name: {type: string, minLength: 1, maxLength: 80, example: Rex}
+        species: {$ref: '#/components/schemas/Species'}
+        size: {type: string, enum: [s, m, l], default: m}
+        weight: {type: number, format: float, minimum: 0, nullable: true}
+        owner: {$ref: '#/components/schemas/Owner'}
+        password: {type: string, format: password}
+        tags: {type: array, items: {type: string}}
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/NewPet'
+        - type: object
+          required: [id]
+          properties:
+            id: {type: integer, format: int64}
+            created_at: {type: string, format: date-time, readOnly: true}
+            updated_at: {type: string, format: date-time}
+    PetPage:
+      type: object
+      properties:
+        items:
+          type: array
+          items: {$ref: '#/components/schemas/Pet'}
+        total: {type: integer}
+    Error:
+      type: object
+      properties:
+        message: {type: string}
+    Version:
+      type: string
+"#;
+
+    fn field<'e>(entity: &'e Entity, name: &str) -> &'e Field {
+        entity
+            .get_field_by_name(name)
+            .unwrap_or_else(|| panic!("{} has no field {}", entity.name, name))
+    }
+
+    #[test]
+    fn test_dto_base() {
+        assert_eq!(dto_base("Pet"), ("Pet", Variant::Plain));
+        assert_eq!(dto_base("CreatePetDto"), ("Pet", Variant::Create));
+        assert_eq!(dto_base("UpdatePetDto"), ("Pet", Variant::Update));
+        assert_eq!(dto_base("PetResponse"), ("Pet", Variant::Response));
+        assert_eq!(dto_base("NewPet"), ("Pet", Variant::Create));
+        assert_eq!(dto_base("PetInput"), ("Pet", Variant::Create));
+        assert_eq!(dto_base("PetUpdateRequest"), ("Pet", Variant::Update));
+        assert_eq!(dto_base("UpdatePetRequest"), ("Pet", Variant::Update));
+        assert_eq!(dto_base("PetDto"), ("Pet", Variant::Plain));
+        assert_eq!(dto_base("Newsletter"), ("Newsletter", Variant::Plain));
+        assert_eq!(dto_base("Response"), ("Response", Variant::Plain));
+    }
+
+    #[test]
+    fn test_import_petstore() {
+        let api = import_openapi(PETSTORE).unwrap();
+        assert_eq!(api.title, "Pet Store");
+        assert_eq!(api.version.as_deref(), Some("2.1.0"));
+
+        let names: Vec<&str> = api.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Owner", "Pet"]);
+        let owner = &api.entities[0];
+        let pet = &api.entities[1];
+
+        // Pet merges NewPet and Pet; the key comes first
+        assert_eq!(pet.fields[0].name, "id");
+        assert!(pet.fields[0].is_primary_key);
+        assert_eq!(pet.config.id_type, IdType::Serial);
+        assert_eq!(owner.config.id_type, IdType::Uuid);
+
+        let name = field(pet, "name");
+        assert!(name.required);
+        assert_eq!(name.example.as_deref(), Some("Rex"));
+        assert!(name.validations.contains(&Validation::MinLength(1)));
+        assert!(name.validations.contains(&Validation::MaxLength(80)));
+        assert_eq!(
+            field(pet, "species").data_type,
+            api.enums
+                .iter()
+                .find(|e| e.name == "Species")
+                .unwrap()
+                .data_type()
+        );
+        let size = field(pet, "size");
+        assert!(matches!(&size.data_type, DataType::Enum { name, .. } if name == "PetSize"));
+        assert_eq!(size.default_value, Some(DefaultValue::String("m".into())));
+        let weight = field(pet, "weight");
+        assert_eq!(weight.data_type, DataType::Float32);
+        assert!(!weight.required);
+        assert!(weight.validations.contains(&Validation::Min(0.0)));
+        assert_eq!(
+            field(pet, "tags").data_type,
+            DataType::Array(Box::new(DataType::String))
+        );
+        let password = field(pet, "password_hash");
+        assert!(password.secret && password.hidden);
+        assert!(
+            field(owner, "email")
+                .validations
+                .contains(&Validation::Email)
+        );
+
+        // Timestamps, from the read-only and plain timestamp properties
+        assert!(pet.config.timestamps);
+        assert!(field(pet, "created_at").readonly);
+
+        // `owner: $ref Owner` is a key; `pets: [Pet]` is left to it
+        let owner_id = field(pet, "owner_id");
+        assert!(owner_id.is_foreign_key && owner_id.required);
+        assert_eq!(owner_id.data_type, DataType::Uuid);
+        assert!(!owner.has_field("pets"));
+        assert_eq!(api.relationships.len(), 1);
+        assert_eq!(api.relationships[0].relation_type, RelationType::ManyToOne);
+        assert_eq!(api.relationships[0].from_entity_id, pet.id);
+
+        // Endpoint groups at the paths the spec uses; `/v1/owners` has no
+        // payload, so it is matched by name
+        assert_eq!(api.endpoints.len(), 2);
+        let pets = api
+            .endpoints
+            .iter()
+            .find(|e| e.entity_id == pet.id)
+            .unwrap();
+        assert_eq!(pets.base_path, "/v1/pets");
+        assert_eq!(pets.tags, ["pets"]);
+        assert_eq!(pets.description.as_deref(), Some("Everything about pets"));
+        let enabled: Vec<OperationType> = pets
+            .enabled_operations()
+            .iter()
+            .map(|op| op.operation_type)
+            .collect();
+        assert_eq!(
+            enabled,
+            [
+                OperationType::Create,
+                OperationType::Read,
+                OperationType::ReadAll,
+                OperationType::Delete
+            ]
+        );
+        let list = pets.get_operation(OperationType::ReadAll).unwrap();
+        assert_eq!(list.operation_id.as_deref(), Some("listPets"));
+        let create = pets.get_operation(OperationType::Create).unwrap();
+        assert_eq!(
+            create.description.as_deref(),
+            Some("Add a pet to the store")
+        );
+
+        // Reads are public; writes fall back to the document's bearer auth
+        assert!(!pets.effective_security(OperationType::Read).auth_required);
+        assert!(pets.effective_security(OperationType::Create).auth_required);
+        assert!(pets.effective_security(OperationType::Delete).auth_required);
+
+        let warnings: Vec<String> = api.warnings.iter().map(|w| w.to_string()).collect();
+        for expected in [
+            "Version: not an object schema; skipped",
+            "Owner: `pets` holds Pet records; add a relationship instead",
+            "/v1/pets/{petId}: OPTIONS is not a CRUD operation; skipped",
+            "/v1/owners/{ownerId}/pets: not a CRUD resource path; skipped",
+        ] {
+            assert!(warnings.iter().any(|w| w == expected), "{:?}", warnings);
+        }
+        assert!(!warnings.iter().any(|w| w.starts_with("/v1/owners:")));
+    }
+
+    #[test]
+    fn test_into_project_and_apply() {
+        let api = import_openapi(PETSTORE).unwrap();
+        let project = api.clone().into_project("pets");
+        assert_eq!(project.meta.version, "2.1.0");
+        assert!(project.config.auth.enabled);
+        assert_eq!(project.entity_count(), 2);
+        assert_eq!(project.endpoints().count(), 2);
+        assert!(project.get_enum_by_name("Species").is_some());
+
+        let mut existing = ProjectGraph::new("existing");
+        existing.add_entity(Entity::new("Owner"));
+        assert!(api.apply(&mut existing, Position::zero()).is_err());
+        assert_eq!(existing.entity_count(), 1);
+    }
+
+    #[test]
+    fn test_json_and_generated_dtos() {
+        let spec = serde_json::json!({
+            "openapi": "3.1.0",
+            "info": {"title": "Blog", "version": "0.1.0"},
+            "paths": {
+                "/api/users": {
+                    "post": {
+                        "operationId": "create_user",
+                        "requestBody": {"content": {"application/json": {
+                            "schema": {"$ref": "#/components/schemas/CreateUserDto"}
+                        }}},
+                        "responses": {"201": {"description": "Created"}}
+                    }
+                },
+                "/api/users/{id}": {
+                    "patch": {
+                        "security": [{"bearerAuth": ["users:write"]}],
+                        "responses": {"200": {"description": "Updated"}}
+                    },
+                    "post": {"responses": {"200": {"description": "Not CRUD"}}}
+                },
+                "/api/users/{id}/restore": {"post": {"responses": {"200": {"description": "Restored"}}}}
+            },
+            "components": {"schemas": {
+                "CreateUserDto": {
+                    "type": "object",
+                    "required": ["email", "password"],
+                    "properties": {
+                        "email": {"type": "string"},
+                        "password": {"type": "string", "format": "password"},
+                        "bio": {"type": ["string", "null"]}
+                    }
+                },
+                "UpdateUserDto": {
+                    "type": "object",
+                    "properties": {"email": {"type": ["string", "null"]}}
+                },
+                "UserResponse": {
+                    "type": "object",
+                    "required": ["id", "email", "bio", "created_at", "updated_at", "deleted_at"],
+                    "properties": {
+                        "id": {"type": "string", "format": "uuid"},
+                        "email": {"type": "string"},
+                        "bio": {"type": "string"},
+                        "created_at": {"type": "string", "format": "date-time"},
+                        "updated_at": {"type": "string", "format": "date-time"},
+                        "deleted_at": {"type": ["string", "null"], "format": "date-time"}
+                    }
+                },
+                "ErrorResponse": {"type": "object", "properties": {"error": {"type": "string"}}}
+            }}
+        });
+        let api = import_openapi(&spec.to_string()).unwrap();
+        assert_eq!(api.entities.len(), 1);
+        let user = &api.entities[0];
+        assert_eq!(user.name, "User");
+        assert!(field(user, "email").required);
+        assert!(!field(user, "bio").required);
+        assert!(field(user, "password_hash").secret);
+        assert!(field(user, "created_at").readonly);
+        assert!(user.config.timestamps && user.config.soft_delete);
+
+        let users = &api.endpoints[0];
+        assert_eq!(users.base_path, "/api/users");
+        // The generated operation id is the default one, so it isn't kept
+        assert_eq!(
+            users
+                .get_operation(OperationType::Create)
+                .unwrap()
+                .operation_id,
+            None
+        );
+        let update = users.effective_security(OperationType::Update);
+        assert!(update.auth_required);
+        assert_eq!(update.scopes, ["users:write"]);
+        assert!(
+            !users
+                .effective_security(OperationType::Create)
+                .auth_required
+        );
+        assert_eq!(api.warnings.len(), 1, "{:?}", api.warnings);
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let message = |spec: &str| import_openapi(spec).unwrap_err().to_string();
+        assert!(message("swagger: '2.0'\n").contains("Swagger 2.0"));
+        assert!(message("openapi: 2.5\n").contains("Unsupported OpenAPI version 2.5"));
+        assert!(message("title: nothing\n").contains("no `openapi` version"));
+        assert!(message("openapi: 3.0.0\npaths: {}\n").contains("No object schemas"));
+        assert!(message("{\"openapi\": ").contains("EOF"));
+    }
+}
@@ -3,13 +3,20 @@
 //! This module contains the root project structures including `ProjectGraph`,
 //! which is the main container for all project data, and related configuration types.
 
+use crate::enums::{enum_of, retype_enum};
 use crate::relationship_helpers::{
-    add_fk_field_for_relationship, calculate_fk_info, determine_fk_entity, generate_fk_field_name,
+    add_fk_field_for_relationship, add_polymorphic_fields, calculate_fk_info, dependent_entity,
+    determine_fk_entity, generate_fk_field_name, generate_junction_table_name,
     generate_relationship_name,
 };
-use crate::{EndpointGroup, Entity, Relationship};
+use crate::{
+    ApiVersioning, EndpointGroup, Entity, EnumDef, Field, FieldSet, QueryDefinition, RateLimit,
+    Relationship, RouteStyle,
+};
 use chrono::{DateTime, Utc};
-use imortal_core::{DatabaseType, EngineError, EngineResult, Position, Validatable};
+use imortal_core::{
+    DatabaseType, EngineError, EngineResult, Position, ReferentialAction, RelationType, Validatable,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -32,14 +39,29 @@ pub struct ProjectGraph {
     pub config: ProjectConfig,
 
     /// All entities in the project, keyed by ID
+    #[serde(serialize_with = "crate::serialization::sorted_map")]
     pub entities: HashMap<Uuid, Entity>,
 
     /// All relationships between entities, keyed by ID
+    #[serde(serialize_with = "crate::serialization::sorted_map")]
     pub relationships: HashMap<Uuid, Relationship>,
 
     /// API endpoint configurations, keyed by ID
+    #[serde(serialize_with = "crate::serialization::sorted_map")]
     pub endpoints: HashMap<Uuid, EndpointGroup>,
 
+    /// Custom query endpoints built with the query builder, keyed by ID
+    #[serde(default, serialize_with = "crate::serialization::sorted_map")]
+    pub queries: HashMap<Uuid, QueryDefinition>,
+
+    /// Named enum types shared by fields, keyed by ID
+    #[serde(default, serialize_with = "crate::serialization::sorted_map")]
+    pub enums: HashMap<Uuid, EnumDef>,
+
+    /// Field sets (mixins) entities can include, keyed by ID
+    #[serde(default, serialize_with = "crate::serialization::sorted_map")]
+    pub field_sets: HashMap<Uuid, FieldSet>,
+
     /// Canvas state (pan, zoom, etc.)
     pub canvas: CanvasState,
 
@@ -51,6 +73,10 @@ pub struct ProjectGraph {
     #[serde(default)]
     pub selected_relationships: Vec<Uuid>,
 
+    /// Entities pinned to the top of the editor's entity list, in pin order
+    #[serde(default)]
+    pub favorite_entities: Vec<Uuid>,
+
     /// Schema version for migration purposes
     pub schema_version: u32,
 }
@@ -64,9 +90,13 @@ impl ProjectGraph {
             entities: HashMap::new(),
             relationships: HashMap::new(),
             endpoints: HashMap::new(),
+            queries: HashMap::new(),
+            enums: HashMap::new(),
+            field_sets: HashMap::new(),
             canvas: CanvasState::default(),
             selected_entities: Vec::new(),
             selected_relationships: Vec::new(),
+            favorite_entities: Vec::new(),
             schema_version: crate::SCHEMA_VERSION,
         }
     }
@@ -102,6 +132,13 @@ impl ProjectGraph {
 
         for rel_id in relationships_to_remove {
             self.relationships.remove(&rel_id);
+            self.clear_unique_scope(rel_id);
+        }
+        // A removed junction entity demotes its junction back to implicit
+        for rel in self.relationships.values_mut() {
+            if rel.junction_entity_id == Some(id) {
+                rel.junction_entity_id = None;
+            }
         }
         let endpoints_to_remove: Vec<Uuid> = self
             .endpoints
@@ -113,12 +150,38 @@ impl ProjectGraph {
         for endpoint_id in endpoints_to_remove {
             self.endpoints.remove(&endpoint_id);
         }
+        self.queries.retain(|_, q| q.entity_id != id);
         self.selected_entities.retain(|&eid| eid != id);
+        self.favorite_entities.retain(|&eid| eid != id);
 
         self.touch();
         self.entities.remove(&id)
     }
 
+    /// Add a copy of an entity with new IDs, offset from the original and
+    /// drawn above everything else. The copy is named `<name>_copy`, with a
+    /// number appended if that is taken; returns its ID.
+    pub fn duplicate_entity(&mut self, id: Uuid) -> Option<Uuid> {
+        let mut copy = self.entities.get(&id)?.duplicate();
+
+        let (name, table_name) = (copy.name.clone(), copy.table_name.clone());
+        let taken = |project: &Self, name: &str, table: &str| {
+            project
+                .entities
+                .values()
+                .any(|e| e.name == name || e.table_name == table)
+        };
+        let mut n = 1;
+        while taken(self, &copy.name, &copy.table_name) {
+            n += 1;
+            copy.name = format!("{name}{n}");
+            copy.table_name = format!("{table_name}{n}");
+        }
+        copy.z_index = self.max_z_index() + 1;
+
+        Some(self.add_entity(copy))
+    }
+
     /// Get an entity by ID
     pub fn get_entity(&self, id: Uuid) -> Option<&Entity> {
         self.entities.get(&id)
@@ -164,7 +227,9 @@ impl ProjectGraph {
     /// - The relationship's from_field is set correctly
     ///
     /// Returns `Ok((relationship_id, Some(fk_field_id)))` on success,
-    /// or `Ok((relationship_id, None))` for many-to-many relationships (no direct FK)
+    /// or `Ok((relationship_id, None))` for many-to-many relationships (no direct FK).
+    /// Polymorphic relationships have no FK either; their discriminator and
+    /// key fields are added to the "from" entity instead.
     pub fn create_relationship_with_fk(
         &mut self,
         mut relationship: Relationship,
@@ -225,6 +290,13 @@ impl ProjectGraph {
             } else {
                 None
             }
+        } else if relationship.is_polymorphic() {
+            let holder = self
+                .entities
+                .get_mut(&relationship.from_entity_id)
+                .ok_or_else(|| EngineError::EntityNotFound(from_entity.id.to_string()))?;
+            add_polymorphic_fields(holder, &to_entity, &relationship)?;
+            None
         } else {
             // Many-to-many: no direct FK (uses junction table)
             None
@@ -234,6 +306,88 @@ impl ProjectGraph {
         Ok((rel_id, fk_field_id))
     }
 
+    /// Promote the junction table of a many-to-many relationship to an
+    /// entity, so it can carry fields of its own (e.g. `role`, `joined_at`)
+    ///
+    /// The entity is named after both sides (`PostTag`), keeps the junction
+    /// table's name, and gets a many-to-one relationship to each side; its
+    /// rows go when either side's row is deleted, and each pair can be
+    /// linked once. Returns the new entity's ID.
+    pub fn promote_junction(&mut self, relationship_id: Uuid) -> EngineResult<Uuid> {
+        let relationship = self
+            .relationships
+            .get(&relationship_id)
+            .ok_or_else(|| EngineError::RelationshipNotFound(relationship_id.to_string()))?;
+        let Some(table) = relationship.junction_table() else {
+            return Err(EngineError::RelationshipValidation(format!(
+                "'{}' is not a many-to-many relationship",
+                relationship.name
+            )));
+        };
+        if relationship
+            .junction_entity_id
+            .is_some_and(|id| self.entities.contains_key(&id))
+        {
+            return Err(EngineError::RelationshipValidation(format!(
+                "'{}' already has a junction entity",
+                relationship.name
+            )));
+        }
+        let entity = |id: Uuid| {
+            self.entities
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| EngineError::EntityNotFound(id.to_string()))
+        };
+        let from = entity(relationship.from_entity_id)?;
+        let to = entity(relationship.to_entity_id)?;
+
+        let name = format!("{}{}", from.name, to.name);
+        if self.get_entity_by_name(&name).is_some() {
+            return Err(EngineError::DuplicateEntity(name));
+        }
+        let table = if table.is_empty() {
+            generate_junction_table_name(&from.name, &to.name)
+        } else {
+            table.to_string()
+        };
+        let from_key = generate_fk_field_name(&from.name);
+        let mut to_key = generate_fk_field_name(&to.name);
+        if to_key == from_key {
+            to_key = format!("related_{}", to_key);
+        }
+
+        let mut junction = Entity::new(&name)
+            .with_table_name(&table)
+            .with_description(format!("Links {} and {}", from.name, to.name))
+            .with_position(Position::new(
+                (from.position.x + to.position.x) / 2.0,
+                (from.position.y + to.position.y) / 2.0,
+            ));
+        junction.config = junction
+            .config
+            .with_unique_together([from_key.clone(), to_key.clone()]);
+        let junction_id = self.add_entity(junction);
+
+        for (side, key) in [(&from, from_key), (&to, to_key)] {
+            self.create_relationship_with_fk(
+                Relationship::many_to_one(junction_id, side.id)
+                    .with_from_field(key)
+                    .on_delete(ReferentialAction::Cascade),
+            )?;
+        }
+
+        if let Some(relationship) = self.relationships.get_mut(&relationship_id) {
+            relationship.relation_type = RelationType::ManyToMany {
+                junction_table: table,
+            };
+            relationship.junction_entity_id = Some(junction_id);
+            relationship.touch();
+        }
+        self.touch();
+        Ok(junction_id)
+    }
+
     /// Generate a FK field name for a relationship
     pub fn suggest_fk_field_name(&self, target_entity_id: Uuid) -> Option<String> {
         self.entities
@@ -244,10 +398,20 @@ impl ProjectGraph {
     /// Remove a relationship by ID
     pub fn remove_relationship(&mut self, id: Uuid) -> Option<Relationship> {
         self.selected_relationships.retain(|&rid| rid != id);
+        self.clear_unique_scope(id);
         self.touch();
         self.relationships.remove(&id)
     }
 
+    /// Fields unique within a removed relationship are no longer unique
+    fn clear_unique_scope(&mut self, relationship_id: Uuid) {
+        for field in self.entities.values_mut().flat_map(|e| e.fields.iter_mut()) {
+            if field.unique_scope == Some(relationship_id) {
+                field.unique_scope = None;
+            }
+        }
+    }
+
     /// Get a relationship by ID
     pub fn get_relationship(&self, id: Uuid) -> Option<&Relationship> {
         self.relationships.get(&id)
@@ -319,6 +483,311 @@ impl ProjectGraph {
         self.endpoints.len()
     }
 
+    /// New endpoint group for an entity, with its base path derived from
+    /// the project's route style
+    pub fn new_endpoint(&self, entity_id: Uuid, entity_name: &str) -> EndpointGroup {
+        EndpointGroup::new(entity_id, entity_name).with_route_style(&self.config.route_style)
+    }
+
+    /// Switch the route style, re-deriving every base path that still
+    /// follows the old style. Paths edited by hand are kept. Returns the
+    /// number of endpoint groups whose base path changed.
+    pub fn set_route_style(&mut self, style: RouteStyle) -> usize {
+        let old = std::mem::replace(&mut self.config.route_style, style);
+        if old == style {
+            return 0;
+        }
+
+        let mut changed = 0;
+        for endpoint in self.endpoints.values_mut() {
+            if endpoint.base_path == old.base_path(&endpoint.entity_name) {
+                let path = style.base_path(&endpoint.entity_name);
+                if endpoint.base_path != path {
+                    endpoint.base_path = path;
+                    changed += 1;
+                }
+            }
+        }
+        self.touch();
+        changed
+    }
+
+    // ========================================================================
+    // Query Management
+    // ========================================================================
+
+    /// Add a custom query to the project
+    pub fn add_query(&mut self, query: QueryDefinition) -> Uuid {
+        let id = query.id;
+        self.queries.insert(id, query);
+        self.touch();
+        id
+    }
+
+    /// Remove a custom query by ID
+    pub fn remove_query(&mut self, id: Uuid) -> Option<QueryDefinition> {
+        self.touch();
+        self.queries.remove(&id)
+    }
+
+    /// Get a custom query by ID
+    pub fn get_query(&self, id: Uuid) -> Option<&QueryDefinition> {
+        self.queries.get(&id)
+    }
+
+    /// Get a mutable custom query by ID
+    pub fn get_query_mut(&mut self, id: Uuid) -> Option<&mut QueryDefinition> {
+        self.queries.get_mut(&id)
+    }
+
+    /// Get all custom queries
+    pub fn queries(&self) -> impl Iterator<Item = &QueryDefinition> {
+        self.queries.values()
+    }
+
+    // ========================================================================
+    // Enum Management
+    // ========================================================================
+
+    /// Add an enum type to the project
+    pub fn add_enum(&mut self, def: EnumDef) -> Uuid {
+        let id = def.id;
+        self.enums.insert(id, def);
+        self.touch();
+        id
+    }
+
+    /// Remove an enum type by ID. Fields using it keep their inline copy of
+    /// the enum; see [`enum_usages`](Self::enum_usages).
+    pub fn remove_enum(&mut self, id: Uuid) -> Option<EnumDef> {
+        self.touch();
+        self.enums.remove(&id)
+    }
+
+    /// Get an enum type by ID
+    pub fn get_enum(&self, id: Uuid) -> Option<&EnumDef> {
+        self.enums.get(&id)
+    }
+
+    /// Get an enum type by name
+    pub fn get_enum_by_name(&self, name: &str) -> Option<&EnumDef> {
+        self.enums.values().find(|e| e.name == name)
+    }
+
+    /// Get all enum types, sorted by name
+    pub fn enums(&self) -> Vec<&EnumDef> {
+        let mut enums: Vec<&EnumDef> = self.enums.values().collect();
+        enums.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        enums
+    }
+
+    /// Replace an enum type, renaming it and updating the variants of every
+    /// field that uses it. Returns the number of fields updated, or `None`
+    /// when no enum has the definition's ID.
+    pub fn update_enum(&mut self, def: EnumDef) -> Option<usize> {
+        let old_name = self.enums.get(&def.id)?.name.clone();
+
+        let mut updated = 0;
+        for entity in self.entities.values_mut() {
+            for field in &mut entity.fields {
+                if retype_enum(&mut field.data_type, &old_name, &def) {
+                    updated += 1;
+                }
+            }
+        }
+
+        self.enums.insert(def.id, def);
+        self.touch();
+        Some(updated)
+    }
+
+    /// `(entity name, field name)` of every field whose type is the named
+    /// enum, sorted
+    pub fn enum_usages(&self, name: &str) -> Vec<(String, String)> {
+        let mut usages: Vec<(String, String)> = self
+            .entities
+            .values()
+            .flat_map(|entity| {
+                entity
+                    .fields
+                    .iter()
+                    .filter(|f| enum_of(&f.data_type).is_some_and(|(n, _)| n == name))
+                    .map(|f| (entity.name.clone(), f.name.clone()))
+            })
+            .collect();
+        usages.sort();
+        usages
+    }
+
+    // ========================================================================
+    // Field Set Management
+    // ========================================================================
+
+    /// Add a field set to the project
+    pub fn add_field_set(&mut self, set: FieldSet) -> Uuid {
+        let id = set.id;
+        self.field_sets.insert(id, set);
+        self.touch();
+        id
+    }
+
+    /// Remove a field set by ID; entities including it stop doing so
+    pub fn remove_field_set(&mut self, id: Uuid) -> Option<FieldSet> {
+        let set = self.field_sets.remove(&id)?;
+        for entity in self.entities.values_mut() {
+            entity.exclude_field_set(id);
+        }
+        self.touch();
+        Some(set)
+    }
+
+    /// Replace a field set, e.g. after editing its fields. Returns false
+    /// when no set has its ID.
+    pub fn update_field_set(&mut self, set: FieldSet) -> bool {
+        if !self.field_sets.contains_key(&set.id) {
+            return false;
+        }
+        self.field_sets.insert(set.id, set);
+        self.touch();
+        true
+    }
+
+    /// Get a field set by ID
+    pub fn get_field_set(&self, id: Uuid) -> Option<&FieldSet> {
+        self.field_sets.get(&id)
+    }
+
+    /// Get a field set by name
+    pub fn get_field_set_by_name(&self, name: &str) -> Option<&FieldSet> {
+        self.field_sets.values().find(|s| s.name == name)
+    }
+
+    /// Get all field sets, sorted by name
+    pub fn field_sets(&self) -> Vec<&FieldSet> {
+        let mut sets: Vec<&FieldSet> = self.field_sets.values().collect();
+        sets.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        sets
+    }
+
+    /// The field sets an entity includes, in include order (IDs of sets no
+    /// longer in the project are skipped)
+    pub fn included_field_sets(&self, entity: &Entity) -> Vec<&FieldSet> {
+        entity
+            .field_sets
+            .iter()
+            .filter_map(|id| self.field_sets.get(id))
+            .collect()
+    }
+
+    /// Names of the entities including a field set, sorted
+    pub fn field_set_usages(&self, id: Uuid) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .entities
+            .values()
+            .filter(|e| e.field_sets.contains(&id))
+            .map(|e| e.name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// An entity's fields with those of its field sets appended, as
+    /// generators see them. Set fields named like an earlier field are
+    /// skipped.
+    pub fn expanded_fields(&self, entity: &Entity) -> Vec<Field> {
+        let mut fields = entity.fields.clone();
+        for set in self.included_field_sets(entity) {
+            for mut field in set.fields_for(entity.id) {
+                if fields.iter().any(|f| f.name == field.name) {
+                    continue;
+                }
+                field.display_order = fields.len() as i32;
+                fields.push(field);
+            }
+        }
+        fields
+    }
+
+    /// A copy of the project with every field set expanded into the
+    /// entities that include it (see [`expanded_fields`](Self::expanded_fields)).
+    /// The copy's entities include no sets, so expanding it again changes
+    /// nothing.
+    pub fn expand_field_sets(&self) -> ProjectGraph {
+        let mut project = self.clone();
+        for entity in project.entities.values_mut() {
+            if entity.field_sets.is_empty() {
+                continue;
+            }
+            entity.fields = self.expanded_fields(entity);
+            entity.field_sets.clear();
+        }
+        project
+    }
+
+    // ========================================================================
+    // Foreign Key Indexes
+    // ========================================================================
+
+    /// Check whether a foreign key is used to look rows up by their parent:
+    /// nested routes such as `/api/users/:user_id/posts` and related
+    /// aggregates on the parent's list endpoint.
+    pub fn fk_backs_nested_routes(&self, entity_id: Uuid, field: &Field) -> bool {
+        let Some(parent_id) = field.foreign_key_ref.as_ref().map(|fk| fk.entity_id) else {
+            return false;
+        };
+        let parent_exposed = self
+            .endpoint_for_entity(parent_id)
+            .is_some_and(|ep| ep.enabled);
+
+        parent_exposed
+            && self.relationships.values().any(|rel| {
+                matches!(
+                    rel.relation_type,
+                    RelationType::OneToMany | RelationType::ManyToOne
+                ) && dependent_entity(rel, parent_id) == Some(entity_id)
+            })
+    }
+
+    /// Foreign keys that the "always index foreign keys" policy will index
+    /// even though they are not marked `indexed`, as `(entity_id, field_id)`.
+    ///
+    /// Empty when the policy is off.
+    pub fn fk_index_suggestions(&self) -> Vec<(Uuid, Uuid)> {
+        if !self.config.index_foreign_keys {
+            return Vec::new();
+        }
+
+        self.entities
+            .values()
+            .flat_map(|entity| {
+                entity
+                    .fields
+                    .iter()
+                    .filter(|f| f.is_foreign_key && !f.is_primary_key && !f.indexed)
+                    .map(move |f| (entity.id, f.id))
+            })
+            .collect()
+    }
+
+    /// Mark every suggested foreign key as indexed, so the IR matches the
+    /// generated migrations. Returns the number of fields changed.
+    pub fn apply_fk_index_suggestions(&mut self) -> usize {
+        let suggestions = self.fk_index_suggestions();
+        for (entity_id, field_id) in &suggestions {
+            if let Some(field) = self
+                .entities
+                .get_mut(entity_id)
+                .and_then(|e| e.get_field_mut(*field_id))
+            {
+                field.indexed = true;
+            }
+        }
+        if !suggestions.is_empty() {
+            self.touch();
+        }
+        suggestions.len()
+    }
+
     // ========================================================================
     // Selection Management
     // ========================================================================
@@ -387,6 +856,37 @@ impl ProjectGraph {
         self.selected_entities.len() + self.selected_relationships.len()
     }
 
+    // ========================================================================
+    // Favorites
+    // ========================================================================
+
+    /// Whether an entity is pinned as a favorite
+    pub fn is_favorite(&self, id: Uuid) -> bool {
+        self.favorite_entities.contains(&id)
+    }
+
+    /// Pin or unpin an entity, returning whether it is now pinned.
+    /// Unknown entities are never pinned.
+    pub fn toggle_favorite(&mut self, id: Uuid) -> bool {
+        if self.is_favorite(id) {
+            self.favorite_entities.retain(|&eid| eid != id);
+            false
+        } else if self.entities.contains_key(&id) {
+            self.favorite_entities.push(id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Pinned entities that still exist, in pin order
+    pub fn favorite_entities(&self) -> Vec<&Entity> {
+        self.favorite_entities
+            .iter()
+            .filter_map(|id| self.entities.get(id))
+            .collect()
+    }
+
     // ========================================================================
     // Utility Methods
     // ========================================================================
@@ -406,6 +906,8 @@ impl ProjectGraph {
         self.entities.clear();
         self.relationships.clear();
         self.endpoints.clear();
+        self.queries.clear();
+        self.enums.clear();
         self.selected_entities.clear();
         self.selected_relationships.clear();
         self.canvas = CanvasState::default();
@@ -506,6 +1008,41 @@ impl Validatable for ProjectGraph {
             }
         }
 
+        // Validate all custom queries
+        for query in self.queries.values() {
+            if !self.entities.contains_key(&query.entity_id) {
+                return Err(EngineError::EndpointValidation {
+                    endpoint: query.path.clone(),
+                    message: "Query references non-existent entity".to_string(),
+                });
+            }
+            query.validate()?;
+        }
+
+        // Validate enum types and check for duplicate names
+        let mut enum_names = std::collections::HashSet::new();
+        for def in self.enums.values() {
+            def.validate()?;
+            if !enum_names.insert(&def.name) {
+                return Err(EngineError::Validation(format!(
+                    "Duplicate enum '{}'",
+                    def.name
+                )));
+            }
+        }
+
+        // Validate field sets and check for duplicate names
+        let mut set_names = std::collections::HashSet::new();
+        for set in self.field_sets.values() {
+            set.validate()?;
+            if !set_names.insert(&set.name) {
+                return Err(EngineError::Validation(format!(
+                    "Duplicate field set '{}'",
+                    set.name
+                )));
+            }
+        }
+
         Ok(())
     }
 }
@@ -532,12 +1069,24 @@ pub struct ProjectMeta {
     /// Project description
     pub description: Option<String>,
 
-    /// Project author
+    /// Project author(s), comma-separated (e.g. "Jane Doe <jane@example.com>, John Doe")
     pub author: Option<String>,
 
     /// Project version
     pub version: String,
 
+    /// Source repository URL
+    #[serde(default)]
+    pub repository: Option<String>,
+
+    /// SPDX license expression (e.g. "MIT OR Apache-2.0")
+    #[serde(default)]
+    pub license: Option<String>,
+
+    /// Keywords describing the project (crates.io allows at most five)
+    #[serde(default)]
+    pub keywords: Vec<String>,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -557,12 +1106,26 @@ impl ProjectMeta {
             description: None,
             author: None,
             version: "0.1.0".to_string(),
+            repository: None,
+            license: None,
+            keywords: Vec::new(),
             created_at: Utc::now(),
             modified_at: Utc::now(),
             file_path: None,
         }
     }
 
+    /// The individual authors listed in [`author`](Self::author)
+    pub fn authors(&self) -> Vec<&str> {
+        self.author
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .collect()
+    }
+
     /// Set the description
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -580,6 +1143,24 @@ impl ProjectMeta {
         self.version = version.into();
         self
     }
+
+    /// Set the repository URL
+    pub fn with_repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = Some(repository.into());
+        self
+    }
+
+    /// Set the license
+    pub fn with_license(mut self, license: impl Into<String>) -> Self {
+        self.license = Some(license.into());
+        self
+    }
+
+    /// Set the keywords
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
 }
 
 impl Validatable for ProjectMeta {
@@ -627,13 +1208,93 @@ pub struct ProjectConfig {
     /// Enable CORS
     pub cors_enabled: bool,
 
+    /// Origins, methods and credentials the CORS policy allows, when
+    /// `cors_enabled`
+    #[serde(default)]
+    pub cors: CorsConfig,
+
     /// Server host for generated project
     pub server_host: String,
 
     /// Server port for generated project
     pub server_port: u16,
 
+    /// Shape of list responses (page envelope, cursor envelope or bare array)
+    #[serde(default)]
+    pub pagination: PaginationStyle,
+
+    /// Always create an index for foreign key columns, whatever the field's
+    /// own `indexed` flag says
+    #[serde(default = "default_true")]
+    pub index_foreign_keys: bool,
+
+    /// Generate a separate read connection (`DATABASE_READ_URL`) for
+    /// read-replica setups; list/get handlers use it, mutations don't
+    #[serde(default)]
+    pub read_replicas: bool,
+
+    /// Make hard-delete handlers check `RESTRICT` foreign keys first and
+    /// answer 409 with the blocking child records instead of a database error
+    #[serde(default)]
+    pub report_delete_blockers: bool,
+
+    /// Stage destructive incremental migrations (dropped columns and tables,
+    /// narrowing type changes) so the running version keeps working: expand
+    /// and backfill now, drop in a later migration
+    #[serde(default)]
+    pub safe_migrations: bool,
+
+    /// Export OpenTelemetry traces (OTLP) from the generated server, with
+    /// HTTP server spans and per-handler entity/operation attributes
+    #[serde(default)]
+    pub telemetry: bool,
+
+    /// Run a background job queue in the generated server, with a scheduled
+    /// purge of old soft-deleted rows for each entity that soft-deletes
+    #[serde(default)]
+    pub background_jobs: bool,
+
+    /// Also generate TypeScript interfaces for the DTOs and a fetch-based
+    /// client per endpoint group, for JavaScript frontends
+    #[serde(default)]
+    pub typescript_client: bool,
+
+    /// Where files uploaded through `File`/`Image` fields are stored and how
+    /// large an upload may be
+    #[serde(default)]
+    pub uploads: UploadConfig,
+
+    /// Health probes and Prometheus metrics served by the generated server
+    #[serde(default)]
+    pub observability: ObservabilityConfig,
+
+    /// ORM the generated models, handlers and migrations are written for
+    #[serde(default)]
+    pub orm: Orm,
+
+    /// Web framework the generated handlers, router and middleware target
+    #[serde(default)]
+    pub framework: WebFramework,
+
+    /// Whether the generated API is served as REST, GraphQL or both
+    #[serde(default)]
+    pub api_style: ApiStyle,
+
+    /// Plurality, case and nesting of the resource paths the project derives
+    #[serde(default)]
+    pub route_style: RouteStyle,
+
+    /// How API versions are told apart (path prefix, header or not at all)
+    #[serde(default)]
+    pub api_versioning: ApiVersioning,
+
+    /// Rate limit of every REST operation and custom query that sets none
+    /// of its own
+    #[serde(default)]
+    pub rate_limit: Option<RateLimit>,
+
     /// Custom configuration options
+    #[serde(serialize_with = "crate::serialization::sorted_map")]
     pub custom_options: HashMap<String, String>,
 }
 
@@ -683,36 +1344,160 @@ impl ProjectConfig {
         self
     }
 
-    /// Set a custom option
-    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.custom_options.insert(key.into(), value.into());
+    /// Set the list-response pagination style
+    pub fn with_pagination(mut self, pagination: PaginationStyle) -> Self {
+        self.pagination = pagination;
         self
     }
-}
 
-impl Default for ProjectConfig {
-    fn default() -> Self {
-        Self {
-            project_type: ProjectType::RestApi,
-            database: DatabaseType::PostgreSQL,
-            db_config: DatabaseConfig::default(),
-            auth: AuthConfig::default(),
-            package_name: "my_app".to_string(),
-            rust_edition: "2024".to_string(),
-            openapi_enabled: true,
-            cors_enabled: true,
-            server_host: "0.0.0.0".to_string(),
-            server_port: 8080,
-            custom_options: HashMap::new(),
-        }
+    /// Only index foreign keys whose field is marked `indexed`
+    pub fn without_fk_indexes(mut self) -> Self {
+        self.index_foreign_keys = false;
+        self
     }
-}
 
-// ============================================================================
-// DatabaseConfig
-// ============================================================================
+    /// Route reads to a replica connection and writes to the primary
+    pub fn with_read_replicas(mut self) -> Self {
+        self.read_replicas = true;
+        self
+    }
 
-/// Database connection configuration.
+    /// Answer blocked deletes with 409 and the list of blocking children
+    pub fn with_delete_blockers(mut self) -> Self {
+        self.report_delete_blockers = true;
+        self
+    }
+
+    /// Stage destructive changes in incremental migrations
+    pub fn with_safe_migrations(mut self) -> Self {
+        self.safe_migrations = true;
+        self
+    }
+
+    /// Instrument the generated server with OpenTelemetry tracing
+    pub fn with_telemetry(mut self) -> Self {
+        self.telemetry = true;
+        self
+    }
+
+    /// Generate a background job queue and its scheduled jobs
+    pub fn with_background_jobs(mut self) -> Self {
+        self.background_jobs = true;
+        self
+    }
+
+    /// Generate TypeScript types and a fetch client for the REST API
+    pub fn with_typescript_client(mut self) -> Self {
+        self.typescript_client = true;
+        self
+    }
+
+    /// Set the storage and size limit for file uploads
+    pub fn with_uploads(mut self, uploads: UploadConfig) -> Self {
+        self.uploads = uploads;
+        self
+    }
+
+    /// Set the health and metrics endpoints of the generated server
+    pub fn with_observability(mut self, observability: ObservabilityConfig) -> Self {
+        self.observability = observability;
+        self
+    }
+
+    /// Set the ORM backend for generated code
+    pub fn with_orm(mut self, orm: Orm) -> Self {
+        self.orm = orm;
+        self
+    }
+
+    /// Set the web framework for generated code
+    pub fn with_framework(mut self, framework: WebFramework) -> Self {
+        self.framework = framework;
+        self
+    }
+
+    /// Set the API style for generated code
+    pub fn with_api_style(mut self, api_style: ApiStyle) -> Self {
+        self.api_style = api_style;
+        self
+    }
+
+    /// Set the route style for derived resource paths
+    pub fn with_route_style(mut self, route_style: RouteStyle) -> Self {
+        self.route_style = route_style;
+        self
+    }
+
+    /// Set the API versioning strategy
+    pub fn with_api_versioning(mut self, api_versioning: ApiVersioning) -> Self {
+        self.api_versioning = api_versioning;
+        self
+    }
+
+    /// Enable CORS with the given policy
+    pub fn with_cors(mut self, cors: CorsConfig) -> Self {
+        self.cors_enabled = true;
+        self.cors = cors;
+        self
+    }
+
+    /// Set the default rate limit of the REST routes
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Set a custom option
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_options.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        Self {
+            project_type: ProjectType::RestApi,
+            database: DatabaseType::PostgreSQL,
+            db_config: DatabaseConfig::default(),
+            auth: AuthConfig::default(),
+            package_name: "my_app".to_string(),
+            rust_edition: "2024".to_string(),
+            openapi_enabled: true,
+            cors_enabled: true,
+            cors: CorsConfig::default(),
+            server_host: "0.0.0.0".to_string(),
+            server_port: 8080,
+            pagination: PaginationStyle::default(),
+            index_foreign_keys: true,
+            read_replicas: false,
+            report_delete_blockers: false,
+            safe_migrations: false,
+            telemetry: false,
+            background_jobs: false,
+            typescript_client: false,
+            uploads: UploadConfig::default(),
+            observability: ObservabilityConfig::default(),
+            orm: Orm::default(),
+            framework: WebFramework::default(),
+            api_style: ApiStyle::default(),
+            route_style: RouteStyle::default(),
+            api_versioning: ApiVersioning::default(),
+            rate_limit: None,
+            custom_options: HashMap::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// ============================================================================
+// DatabaseConfig
+// ============================================================================
+
+/// Database connection configuration.
 ///
 /// Holds the connection details (host, port, credentials, database name)
 /// and pool settings used to generate the `.env.example` and config module
@@ -913,6 +1698,274 @@ impl std::fmt::Display for ProjectType {
     }
 }
 
+// ============================================================================
+// PaginationStyle
+// ============================================================================
+
+/// Envelope used by generated list endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationStyle {
+    /// `{ items, total, page, per_page, total_pages }` driven by `?page=&per_page=`.
+    ///
+    /// Supports jumping to any page and showing totals, but every request
+    /// runs a `COUNT(*)` and deep pages get slower (the database still scans
+    /// the skipped rows). Rows inserted while paging shift page boundaries.
+    #[default]
+    Offset,
+    /// `{ items, next_cursor, has_more }` driven by `?cursor=&limit=`.
+    ///
+    /// Constant cost per page and stable under concurrent inserts, suited to
+    /// feeds and infinite scroll. There is no total count and no random
+    /// access — clients can only walk forward from the first page.
+    Cursor,
+    /// A plain JSON array with no pagination parameters.
+    ///
+    /// Simplest for clients and tools, but the whole table is returned in one
+    /// response; only suitable for small lookup tables.
+    None,
+}
+
+impl PaginationStyle {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PaginationStyle::Offset => "Page + total",
+            PaginationStyle::Cursor => "Cursor",
+            PaginationStyle::None => "Bare array",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            PaginationStyle::Offset => {
+                "items, total and page numbers; random access, costs a COUNT per request"
+            }
+            PaginationStyle::Cursor => "items and a next-page token; fast at any depth, no totals",
+            PaginationStyle::None => "a plain array of every row; small tables only",
+        }
+    }
+
+    /// Get all pagination styles
+    pub fn all() -> &'static [PaginationStyle] {
+        &[
+            PaginationStyle::Offset,
+            PaginationStyle::Cursor,
+            PaginationStyle::None,
+        ]
+    }
+}
+
+impl std::fmt::Display for PaginationStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+// ============================================================================
+// Orm
+// ============================================================================
+
+/// ORM the generated data layer is written against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Orm {
+    /// SeaORM: async entities and `sea-orm` connection pools.
+    #[default]
+    SeaOrm,
+    /// Diesel: `table!` schema, derive-based models and an r2d2 pool,
+    /// with queries run on Tokio's blocking thread pool.
+    Diesel,
+}
+
+impl Orm {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Orm::SeaOrm => "SeaORM",
+            Orm::Diesel => "Diesel",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            Orm::SeaOrm => "async entities; supports every generator feature",
+            Orm::Diesel => "compile-time checked schema.rs and Diesel-format migrations",
+        }
+    }
+
+    /// Get all ORM backends
+    pub fn all() -> &'static [Orm] {
+        &[Orm::SeaOrm, Orm::Diesel]
+    }
+}
+
+impl std::fmt::Display for Orm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl std::str::FromStr for Orm {
+    type Err = EngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', '_'], "").as_str() {
+            "seaorm" => Ok(Orm::SeaOrm),
+            "diesel" => Ok(Orm::Diesel),
+            _ => Err(EngineError::validation(format!(
+                "Unknown ORM '{}' (expected sea-orm or diesel)",
+                s
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// WebFramework
+// ============================================================================
+
+/// Web framework the generated HTTP layer is written against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WebFramework {
+    /// Axum: tower layers and extractor-based handlers.
+    #[default]
+    Axum,
+    /// Actix Web: `HttpServer`/`App` with `web::scope` route tables.
+    ActixWeb,
+}
+
+impl WebFramework {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WebFramework::Axum => "Axum",
+            WebFramework::ActixWeb => "Actix Web",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            WebFramework::Axum => "tokio/tower based router with tower-http layers",
+            WebFramework::ActixWeb => "actor-runtime server with scope-based route tables",
+        }
+    }
+
+    /// Get all web frameworks
+    pub fn all() -> &'static [WebFramework] {
+        &[WebFramework::Axum, WebFramework::ActixWeb]
+    }
+}
+
+impl std::fmt::Display for WebFramework {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl std::str::FromStr for WebFramework {
+    type Err = EngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "axum" => Ok(WebFramework::Axum),
+            "actix" | "actixweb" => Ok(WebFramework::ActixWeb),
+            _ => Err(EngineError::validation(format!(
+                "Unknown web framework '{}' (expected axum or actix-web)",
+                s
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// ApiStyle
+// ============================================================================
+
+/// How the generated API is exposed to clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiStyle {
+    /// Resource routes under `/api`, one handler per CRUD operation.
+    #[default]
+    Rest,
+    /// A single async-graphql schema served at `/graphql`.
+    Graphql,
+    /// REST routes and the GraphQL schema side by side.
+    Both,
+}
+
+impl ApiStyle {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ApiStyle::Rest => "REST",
+            ApiStyle::Graphql => "GraphQL",
+            ApiStyle::Both => "REST + GraphQL",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            ApiStyle::Rest => "JSON resource routes with OpenAPI docs",
+            ApiStyle::Graphql => "queries, mutations and subscriptions with dataloaders",
+            ApiStyle::Both => "REST routes plus a GraphQL endpoint over the same handlers",
+        }
+    }
+
+    /// Get all API styles
+    pub fn all() -> &'static [ApiStyle] {
+        &[ApiStyle::Rest, ApiStyle::Graphql, ApiStyle::Both]
+    }
+
+    /// Whether REST routes are generated
+    pub fn serves_rest(&self) -> bool {
+        matches!(self, ApiStyle::Rest | ApiStyle::Both)
+    }
+
+    /// Whether the GraphQL schema is generated
+    pub fn serves_graphql(&self) -> bool {
+        matches!(self, ApiStyle::Graphql | ApiStyle::Both)
+    }
+}
+
+impl std::fmt::Display for ApiStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl std::str::FromStr for ApiStyle {
+    type Err = EngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' ', '+'], "")
+            .as_str()
+        {
+            "rest" => Ok(ApiStyle::Rest),
+            "graphql" | "gql" => Ok(ApiStyle::Graphql),
+            "both" | "restgraphql" => Ok(ApiStyle::Both),
+            _ => Err(EngineError::validation(format!(
+                "Unknown API style '{}' (expected rest, graphql or both)",
+                s
+            ))),
+        }
+    }
+}
+
 // ============================================================================
 // AuthConfig
 // ============================================================================
@@ -943,6 +1996,27 @@ pub struct AuthConfig {
 
     /// Available roles in the system
     pub available_roles: Vec<String>,
+
+    /// The entity users sign in as, and which of its fields the generated
+    /// auth reads. `None` keeps the built-in `email` claim.
+    #[serde(default)]
+    pub principal: Option<AuthPrincipal>,
+
+    /// bcrypt cost factor for password hashing (4–31)
+    #[serde(default = "default_password_hash_cost")]
+    pub password_hash_cost: u32,
+
+    /// Issue rotating refresh tokens backed by revocable server-side sessions
+    #[serde(default)]
+    pub refresh_tokens: bool,
+
+    /// Refresh token lifetime in days
+    #[serde(default = "default_refresh_token_expiry_days")]
+    pub refresh_token_expiry_days: u32,
+
+    /// Identity providers users sign in with, for the OAuth2 strategy
+    #[serde(default)]
+    pub oauth_providers: Vec<OAuthProvider>,
 }
 
 impl AuthConfig {
@@ -960,6 +2034,15 @@ impl AuthConfig {
         }
     }
 
+    /// Create config with OAuth2 sign-in through external identity providers
+    pub fn oauth2() -> Self {
+        Self {
+            enabled: true,
+            strategy: AuthStrategy::OAuth2,
+            ..Default::default()
+        }
+    }
+
     /// Create config with session authentication
     pub fn session() -> Self {
         Self {
@@ -1011,24 +2094,227 @@ impl AuthConfig {
         self
     }
 
-    /// Enable password reset
-    pub fn with_password_reset(mut self) -> Self {
-        self.enable_password_reset = true;
+    /// Enable password reset
+    pub fn with_password_reset(mut self) -> Self {
+        self.enable_password_reset = true;
+        self
+    }
+
+    /// Set the principal entity mapping
+    pub fn with_principal(mut self, principal: AuthPrincipal) -> Self {
+        self.principal = Some(principal);
+        self
+    }
+
+    /// Set the bcrypt cost factor
+    pub fn with_password_hash_cost(mut self, cost: u32) -> Self {
+        self.password_hash_cost = cost;
+        self
+    }
+
+    /// Enable refresh tokens and sessions
+    pub fn with_refresh_tokens(mut self) -> Self {
+        self.refresh_tokens = true;
+        self
+    }
+
+    /// Add an identity provider
+    pub fn with_oauth_provider(mut self, provider: OAuthProvider) -> Self {
+        self.oauth_providers.push(provider);
+        self
+    }
+
+    /// Name of the token claim carrying the principal's identifier
+    pub fn identifier_claim(&self) -> &str {
+        self.principal
+            .as_ref()
+            .map_or("email", |p| p.identifier_field.as_str())
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            strategy: AuthStrategy::Jwt,
+            jwt_secret_env: "JWT_SECRET".to_string(),
+            token_expiry_hours: 24,
+            enable_registration: true,
+            enable_password_reset: false,
+            default_roles: vec!["user".to_string()],
+            available_roles: vec!["user".to_string(), "admin".to_string()],
+            principal: None,
+            password_hash_cost: default_password_hash_cost(),
+            refresh_tokens: false,
+            refresh_token_expiry_days: default_refresh_token_expiry_days(),
+            oauth_providers: Vec::new(),
+        }
+    }
+}
+
+fn default_password_hash_cost() -> u32 {
+    12
+}
+
+fn default_refresh_token_expiry_days() -> u32 {
+    30
+}
+
+// ============================================================================
+// AuthPrincipal
+// ============================================================================
+
+/// Maps the auth principal onto an entity of the project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthPrincipal {
+    /// Name of the principal entity (e.g. `User`, `Account`, `Member`)
+    pub entity: String,
+
+    /// Field the principal signs in with, carried in the token claims
+    pub identifier_field: String,
+
+    /// Field storing the password hash
+    pub password_field: String,
+
+    /// Field holding the principal's roles, if they are stored on it
+    #[serde(default)]
+    pub roles_field: Option<String>,
+}
+
+impl AuthPrincipal {
+    /// Map `entity`, signing in by `email` with the hash in `password_hash`
+    pub fn new(entity: impl Into<String>) -> Self {
+        Self {
+            entity: entity.into(),
+            identifier_field: "email".to_string(),
+            password_field: "password_hash".to_string(),
+            roles_field: None,
+        }
+    }
+
+    /// Set the identifier field
+    pub fn with_identifier(mut self, field: impl Into<String>) -> Self {
+        self.identifier_field = field.into();
+        self
+    }
+
+    /// Set the password hash field
+    pub fn with_password_field(mut self, field: impl Into<String>) -> Self {
+        self.password_field = field.into();
+        self
+    }
+
+    /// Set the roles field
+    pub fn with_roles_field(mut self, field: impl Into<String>) -> Self {
+        self.roles_field = Some(field.into());
+        self
+    }
+}
+
+// ============================================================================
+// OAuthProvider
+// ============================================================================
+
+/// Kind of identity provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthProviderKind {
+    /// Google accounts
+    Google,
+    /// GitHub accounts
+    #[serde(rename = "github")]
+    GitHub,
+    /// Any OpenID Connect provider, found through its issuer's discovery document
+    Oidc,
+}
+
+impl OAuthProviderKind {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            OAuthProviderKind::Google => "Google",
+            OAuthProviderKind::GitHub => "GitHub",
+            OAuthProviderKind::Oidc => "OpenID Connect",
+        }
+    }
+
+    /// Scopes requested when the provider names none
+    pub fn default_scopes(&self) -> &'static [&'static str] {
+        match self {
+            OAuthProviderKind::Google | OAuthProviderKind::Oidc => &["openid", "email", "profile"],
+            OAuthProviderKind::GitHub => &["read:user", "user:email"],
+        }
+    }
+
+    /// Get all provider kinds
+    pub fn all() -> &'static [OAuthProviderKind] {
+        &[
+            OAuthProviderKind::Google,
+            OAuthProviderKind::GitHub,
+            OAuthProviderKind::Oidc,
+        ]
+    }
+}
+
+/// An external identity provider users can sign in with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OAuthProvider {
+    /// Kind of provider
+    pub kind: OAuthProviderKind,
+
+    /// Lowercase name, used in the provider's routes and environment
+    /// variables (`google` → `/oauth/google/callback`, `GOOGLE_CLIENT_ID`)
+    pub name: String,
+
+    /// Issuer URL of an OpenID Connect provider
+    #[serde(default)]
+    pub issuer_url: Option<String>,
+
+    /// Scopes to request; empty requests the kind's defaults
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+impl OAuthProvider {
+    /// Sign in with Google
+    pub fn google() -> Self {
+        Self::new(OAuthProviderKind::Google, "google")
+    }
+
+    /// Sign in with GitHub
+    pub fn github() -> Self {
+        Self::new(OAuthProviderKind::GitHub, "github")
+    }
+
+    /// Sign in with the OpenID Connect provider at `issuer_url`
+    pub fn oidc(name: impl Into<String>, issuer_url: impl Into<String>) -> Self {
+        Self {
+            issuer_url: Some(issuer_url.into()),
+            ..Self::new(OAuthProviderKind::Oidc, name)
+        }
+    }
+
+    fn new(kind: OAuthProviderKind, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            issuer_url: None,
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Set the scopes to request
+    pub fn with_scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
         self
     }
-}
 
-impl Default for AuthConfig {
-    fn default() -> Self {
-        Self {
-            enabled: true,
-            strategy: AuthStrategy::Jwt,
-            jwt_secret_env: "JWT_SECRET".to_string(),
-            token_expiry_hours: 24,
-            enable_registration: true,
-            enable_password_reset: false,
-            default_roles: vec!["user".to_string()],
-            available_roles: vec!["user".to_string(), "admin".to_string()],
+    /// The scopes requested, space-separated
+    pub fn scope(&self) -> String {
+        if self.scopes.is_empty() {
+            self.kind.default_scopes().join(" ")
+        } else {
+            self.scopes.join(" ")
         }
     }
 }
@@ -1050,6 +2336,9 @@ pub enum AuthStrategy {
     Session,
     /// API Key authentication
     ApiKey,
+    /// Sign-in through external identity providers, issuing JWTs
+    #[serde(rename = "oauth2")]
+    OAuth2,
 }
 
 impl AuthStrategy {
@@ -1060,6 +2349,7 @@ impl AuthStrategy {
             AuthStrategy::Jwt => "JWT",
             AuthStrategy::Session => "Session",
             AuthStrategy::ApiKey => "API Key",
+            AuthStrategy::OAuth2 => "OAuth2",
         }
     }
 
@@ -1070,6 +2360,7 @@ impl AuthStrategy {
             AuthStrategy::Jwt => "JSON Web Token based stateless authentication",
             AuthStrategy::Session => "Server-side session based authentication",
             AuthStrategy::ApiKey => "API Key based authentication",
+            AuthStrategy::OAuth2 => "Sign in with Google, GitHub or OpenID Connect",
         }
     }
 
@@ -1080,6 +2371,7 @@ impl AuthStrategy {
             AuthStrategy::Jwt,
             AuthStrategy::Session,
             AuthStrategy::ApiKey,
+            AuthStrategy::OAuth2,
         ]
     }
 }
@@ -1090,6 +2382,203 @@ impl std::fmt::Display for AuthStrategy {
     }
 }
 
+// ============================================================================
+// UploadConfig
+// ============================================================================
+
+/// File upload settings for fields edited with a `File` or `Image` widget.
+///
+/// Each such field gets a multipart upload endpoint in the generated API;
+/// the stored file's public URL is written to the field's column.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UploadConfig {
+    /// Where uploaded files are written
+    #[serde(default)]
+    pub storage: UploadStorage,
+
+    /// Largest accepted upload, in megabytes
+    #[serde(default = "default_max_upload_mb")]
+    pub max_size_mb: u32,
+}
+
+impl UploadConfig {
+    /// Store uploads on local disk
+    pub fn local() -> Self {
+        Self::default()
+    }
+
+    /// Store uploads in an S3-compatible bucket
+    pub fn s3() -> Self {
+        Self {
+            storage: UploadStorage::S3,
+            ..Self::default()
+        }
+    }
+
+    /// Set the largest accepted upload, in megabytes
+    pub fn with_max_size_mb(mut self, max_size_mb: u32) -> Self {
+        self.max_size_mb = max_size_mb;
+        self
+    }
+
+    /// Largest accepted upload, in bytes
+    pub fn max_size_bytes(&self) -> u64 {
+        u64::from(self.max_size_mb) * 1024 * 1024
+    }
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            storage: UploadStorage::default(),
+            max_size_mb: default_max_upload_mb(),
+        }
+    }
+}
+
+fn default_max_upload_mb() -> u32 {
+    10
+}
+
+/// Backend the generated project writes uploaded files to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadStorage {
+    /// A directory on the server's disk, served under `/uploads`
+    #[default]
+    Local,
+    /// An S3-compatible bucket (AWS S3, MinIO, R2, ...)
+    S3,
+}
+
+impl UploadStorage {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UploadStorage::Local => "Local Disk",
+            UploadStorage::S3 => "S3-compatible",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            UploadStorage::Local => "Files in a directory on the server, served under /uploads",
+            UploadStorage::S3 => "A bucket on AWS S3, MinIO, R2 or any S3-compatible service",
+        }
+    }
+
+    /// Get all storage backends
+    pub fn all() -> &'static [UploadStorage] {
+        &[UploadStorage::Local, UploadStorage::S3]
+    }
+}
+
+impl std::fmt::Display for UploadStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+// ============================================================================
+// ObservabilityConfig
+// ============================================================================
+
+/// Operational endpoints of the generated server, on top of the `tracing`
+/// logs it always writes.
+///
+/// - health checks: `GET /healthz` (liveness) and `GET /readyz`, which
+///   answers 503 while the database is unreachable
+/// - metrics: request counts and latencies in the Prometheus text format
+///   on `GET /metrics`
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ObservabilityConfig {
+    /// Serve `/healthz` and `/readyz`
+    #[serde(default)]
+    pub health_checks: bool,
+
+    /// Serve Prometheus metrics on `/metrics`
+    #[serde(default)]
+    pub metrics: bool,
+}
+
+impl ObservabilityConfig {
+    /// Health checks and metrics both on
+    pub fn all() -> Self {
+        Self {
+            health_checks: true,
+            metrics: true,
+        }
+    }
+
+    /// Serve `/healthz` and `/readyz`
+    pub fn with_health_checks(mut self) -> Self {
+        self.health_checks = true;
+        self
+    }
+
+    /// Serve Prometheus metrics on `/metrics`
+    pub fn with_metrics(mut self) -> Self {
+        self.metrics = true;
+        self
+    }
+
+    /// Whether any endpoint is served
+    pub fn is_enabled(&self) -> bool {
+        self.health_checks || self.metrics
+    }
+}
+
+// ============================================================================
+// CorsConfig
+// ============================================================================
+
+/// Project-wide CORS policy of the generated server.
+///
+/// Endpoint groups listing their own origins in
+/// [`EndpointSecurity::cors_origins`](crate::EndpointSecurity::cors_origins)
+/// allow those instead on their routes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CorsConfig {
+    /// Origins allowed to call the API from a browser, e.g.
+    /// `https://app.example.com`; empty allows any origin
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+
+    /// HTTP methods cross-origin requests may use; empty allows any
+    #[serde(default)]
+    pub allowed_methods: Vec<String>,
+
+    /// Let browsers send cookies and `Authorization` headers cross-origin;
+    /// needs explicit origins
+    #[serde(default)]
+    pub allow_credentials: bool,
+}
+
+impl CorsConfig {
+    /// Methods a CORS policy can name
+    pub const METHODS: &'static [&'static str] =
+        &["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+
+    /// Allow an origin
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.allowed_origins.push(origin.into());
+        self
+    }
+
+    /// Allow only these methods
+    pub fn with_methods(mut self, methods: &[&str]) -> Self {
+        self.allowed_methods = methods.iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    /// Allow credentials on cross-origin requests
+    pub fn with_credentials(mut self) -> Self {
+        self.allow_credentials = true;
+        self
+    }
+}
+
 // ============================================================================
 // CanvasState
 // ============================================================================
@@ -1199,6 +2688,7 @@ impl Default for CanvasState {
 mod tests {
     use super::*;
     use crate::Entity;
+    use crate::relationship_helpers::junction_keys;
 
     #[test]
     fn test_project_new() {
@@ -1221,6 +2711,24 @@ mod tests {
         assert_eq!(project.get_entity(id).unwrap().name, "User");
     }
 
+    #[test]
+    fn test_project_duplicate_entity() {
+        let mut project = ProjectGraph::new("Test");
+        let id = project.add_entity(Entity::new("User").at(100.0, 100.0));
+
+        let first = project.duplicate_entity(id).unwrap();
+        let second = project.duplicate_entity(id).unwrap();
+        assert!(project.duplicate_entity(Uuid::new_v4()).is_none());
+
+        assert_eq!(project.entity_count(), 3);
+        let (first, second) = (&project.entities[&first], &project.entities[&second]);
+        assert_eq!(first.name, "User_copy");
+        assert_eq!(second.name, "User_copy2");
+        assert_eq!(second.table_name, format!("{}2", first.table_name));
+        assert!(second.z_index > first.z_index);
+        assert_eq!(first.position, Position::new(130.0, 130.0));
+    }
+
     #[test]
     fn test_project_remove_entity() {
         let mut project = ProjectGraph::new("Test");
@@ -1273,6 +2781,224 @@ mod tests {
         assert_eq!(config.database, DatabaseType::PostgreSQL);
         assert!(config.auth.enabled);
         assert_eq!(config.auth.strategy, AuthStrategy::Jwt);
+        assert_eq!(config.pagination, PaginationStyle::Offset);
+    }
+
+    #[test]
+    fn test_pagination_style_defaults_when_missing() {
+        let config = ProjectConfig::new().with_pagination(PaginationStyle::Cursor);
+        let mut json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["pagination"], "cursor");
+
+        json.as_object_mut().unwrap().remove("pagination");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.pagination, PaginationStyle::Offset);
+    }
+
+    #[test]
+    fn test_orm_defaults_to_sea_orm() {
+        let config = ProjectConfig::new().with_orm(Orm::Diesel);
+        let mut json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["orm"], "diesel");
+
+        json.as_object_mut().unwrap().remove("orm");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.orm, Orm::SeaOrm);
+
+        assert_eq!("sea-orm".parse::<Orm>().unwrap(), Orm::SeaOrm);
+        assert_eq!("Diesel".parse::<Orm>().unwrap(), Orm::Diesel);
+        assert!("hibernate".parse::<Orm>().is_err());
+    }
+
+    #[test]
+    fn test_api_style_defaults_to_rest() {
+        let config = ProjectConfig::new().with_api_style(ApiStyle::Both);
+        let mut json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["api_style"], "both");
+
+        json.as_object_mut().unwrap().remove("api_style");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.api_style, ApiStyle::Rest);
+
+        assert_eq!("GraphQL".parse::<ApiStyle>().unwrap(), ApiStyle::Graphql);
+        assert_eq!(
+            "REST + GraphQL".parse::<ApiStyle>().unwrap(),
+            ApiStyle::Both
+        );
+        assert!("soap".parse::<ApiStyle>().is_err());
+        assert!(ApiStyle::Both.serves_rest() && ApiStyle::Both.serves_graphql());
+        assert!(!ApiStyle::Graphql.serves_rest());
+    }
+
+    #[test]
+    fn test_set_route_style_keeps_custom_paths() {
+        let mut project = ProjectGraph::new("Test");
+        let post = project.new_endpoint(Uuid::new_v4(), "BlogPost");
+        assert_eq!(post.base_path, "/api/blog_posts");
+        let post_id = project.add_endpoint(post);
+        let custom_id = project
+            .add_endpoint(EndpointGroup::new(Uuid::new_v4(), "User").with_base_path("/api/people"));
+
+        let style = RouteStyle::default()
+            .with_plurality(crate::PathPlurality::Singular)
+            .with_case(crate::PathCase::Kebab);
+        assert_eq!(project.set_route_style(style), 1);
+        assert_eq!(
+            project.get_endpoint(post_id).unwrap().base_path,
+            "/api/blog-post"
+        );
+        assert_eq!(
+            project.get_endpoint(custom_id).unwrap().base_path,
+            "/api/people"
+        );
+        assert_eq!(
+            project.new_endpoint(Uuid::new_v4(), "Tag").base_path,
+            "/api/tag"
+        );
+
+        let mut json = serde_json::to_value(&project.config).unwrap();
+        assert_eq!(json["route_style"]["case"], "kebab");
+        json.as_object_mut().unwrap().remove("route_style");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.route_style, RouteStyle::default());
+    }
+
+    #[test]
+    fn test_framework_defaults_to_axum() {
+        let config = ProjectConfig::new().with_framework(WebFramework::ActixWeb);
+        let mut json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["framework"], "actix-web");
+
+        json.as_object_mut().unwrap().remove("framework");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.framework, WebFramework::Axum);
+
+        assert_eq!(
+            "actix".parse::<WebFramework>().unwrap(),
+            WebFramework::ActixWeb
+        );
+        assert_eq!("Axum".parse::<WebFramework>().unwrap(), WebFramework::Axum);
+        assert!("rocket".parse::<WebFramework>().is_err());
+    }
+
+    #[test]
+    fn test_telemetry_is_opt_in() {
+        let config = ProjectConfig::new();
+        assert!(!config.telemetry);
+
+        let mut json = serde_json::to_value(config.with_telemetry()).unwrap();
+        assert_eq!(json["telemetry"], true);
+
+        json.as_object_mut().unwrap().remove("telemetry");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert!(!loaded.telemetry);
+    }
+
+    #[test]
+    fn test_background_jobs_are_opt_in() {
+        let config = ProjectConfig::new();
+        assert!(!config.background_jobs);
+
+        let mut json = serde_json::to_value(config.with_background_jobs()).unwrap();
+        assert_eq!(json["background_jobs"], true);
+
+        json.as_object_mut().unwrap().remove("background_jobs");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert!(!loaded.background_jobs);
+    }
+
+    #[test]
+    fn test_typescript_client_is_opt_in() {
+        let config = ProjectConfig::new();
+        assert!(!config.typescript_client);
+
+        let mut json = serde_json::to_value(config.with_typescript_client()).unwrap();
+        assert_eq!(json["typescript_client"], true);
+
+        json.as_object_mut().unwrap().remove("typescript_client");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert!(!loaded.typescript_client);
+    }
+
+    #[test]
+    fn test_observability_config() {
+        let config = ProjectConfig::new();
+        assert!(!config.observability.is_enabled());
+
+        let config = config.with_observability(ObservabilityConfig::default().with_health_checks());
+        assert!(config.observability.health_checks);
+        assert!(!config.observability.metrics);
+
+        let mut json =
+            serde_json::to_value(config.with_observability(ObservabilityConfig::all())).unwrap();
+        assert_eq!(json["observability"]["metrics"], true);
+
+        json.as_object_mut().unwrap().remove("observability");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.observability, ObservabilityConfig::default());
+    }
+
+    #[test]
+    fn test_upload_config() {
+        let config = ProjectConfig::new();
+        assert_eq!(config.uploads, UploadConfig::local());
+        assert_eq!(config.uploads.max_size_bytes(), 10 * 1024 * 1024);
+
+        let config = config.with_uploads(UploadConfig::s3().with_max_size_mb(25));
+        let mut json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["uploads"]["storage"], "s3");
+        assert_eq!(json["uploads"]["max_size_mb"], 25);
+
+        json.as_object_mut().unwrap().remove("uploads");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.uploads, UploadConfig::default());
+    }
+
+    #[test]
+    fn test_fk_index_policy() {
+        let mut project = ProjectGraph::new("Blog");
+        assert!(project.config.index_foreign_keys);
+
+        let user_id = project.add_entity(Entity::new("User"));
+        let post_id = project.add_entity(Entity::new("Post"));
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+
+        let fk = project
+            .get_entity(post_id)
+            .unwrap()
+            .get_field_by_name("user_id")
+            .unwrap();
+        assert!(project.fk_backs_nested_routes(post_id, fk));
+        let fk_id = fk.id;
+
+        // Generated FKs start out indexed
+        assert!(project.fk_index_suggestions().is_empty());
+
+        let post = project.get_entity_mut(post_id).unwrap();
+        post.get_field_mut(fk_id).unwrap().indexed = false;
+        assert_eq!(project.fk_index_suggestions(), vec![(post_id, fk_id)]);
+
+        // With the policy off nothing is suggested
+        project.config.index_foreign_keys = false;
+        assert!(project.fk_index_suggestions().is_empty());
+
+        project.config.index_foreign_keys = true;
+        assert_eq!(project.apply_fk_index_suggestions(), 1);
+        let post = project.get_entity(post_id).unwrap();
+        assert!(post.get_field_by_name("user_id").unwrap().indexed);
+    }
+
+    #[test]
+    fn test_fk_index_policy_defaults_on_when_missing() {
+        let mut json = serde_json::to_value(ProjectConfig::new().without_fk_indexes()).unwrap();
+        assert_eq!(json["index_foreign_keys"], false);
+
+        json.as_object_mut().unwrap().remove("index_foreign_keys");
+        let loaded: ProjectConfig = serde_json::from_value(json).unwrap();
+        assert!(loaded.index_foreign_keys);
     }
 
     #[test]
@@ -1353,6 +3079,69 @@ mod tests {
         assert_eq!(config.token_expiry_hours, 48);
         assert!(config.available_roles.contains(&"editor".to_string()));
         assert!(config.enable_registration);
+        assert_eq!(config.identifier_claim(), "email");
+    }
+
+    #[test]
+    fn test_auth_principal() {
+        let config = AuthConfig::jwt()
+            .with_principal(
+                AuthPrincipal::new("Account")
+                    .with_identifier("username")
+                    .with_password_field("secret_digest")
+                    .with_roles_field("roles"),
+            )
+            .with_password_hash_cost(10);
+
+        let principal = config.principal.as_ref().unwrap();
+        assert_eq!(principal.entity, "Account");
+        assert_eq!(principal.password_field, "secret_digest");
+        assert_eq!(principal.roles_field.as_deref(), Some("roles"));
+        assert_eq!(config.identifier_claim(), "username");
+        assert_eq!(config.password_hash_cost, 10);
+
+        // Configs saved before the mapping existed load with the defaults
+        let mut json = serde_json::to_value(AuthConfig::jwt()).unwrap();
+        let map = json.as_object_mut().unwrap();
+        map.remove("principal");
+        map.remove("password_hash_cost");
+        let loaded: AuthConfig = serde_json::from_value(json).unwrap();
+        assert!(loaded.principal.is_none());
+        assert_eq!(loaded.password_hash_cost, 12);
+    }
+
+    #[test]
+    fn test_auth_refresh_tokens() {
+        assert!(!AuthConfig::jwt().refresh_tokens);
+        let config = AuthConfig::jwt().with_refresh_tokens();
+        assert!(config.refresh_tokens);
+        assert_eq!(config.refresh_token_expiry_days, 30);
+
+        let mut json = serde_json::to_value(&config).unwrap();
+        let map = json.as_object_mut().unwrap();
+        map.remove("refresh_tokens");
+        map.remove("refresh_token_expiry_days");
+        let loaded: AuthConfig = serde_json::from_value(json).unwrap();
+        assert!(!loaded.refresh_tokens);
+        assert_eq!(loaded.refresh_token_expiry_days, 30);
+    }
+
+    #[test]
+    fn test_auth_oauth_providers() {
+        let config = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::github())
+            .with_oauth_provider(
+                OAuthProvider::oidc("okta", "https://example.okta.com").with_scopes(&["openid"]),
+            );
+        assert_eq!(config.strategy, AuthStrategy::OAuth2);
+        assert_eq!(config.oauth_providers[0].scope(), "read:user user:email");
+        assert_eq!(config.oauth_providers[1].scope(), "openid");
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("\"strategy\":\"oauth2\""));
+        assert!(json.contains("\"kind\":\"github\""));
+        let loaded: AuthConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.oauth_providers, config.oauth_providers);
     }
 
     #[test]
@@ -1376,6 +3165,63 @@ mod tests {
         assert!(meta.validate().is_ok());
     }
 
+    #[test]
+    fn test_project_meta_publishing_fields() {
+        let meta = ProjectMeta::new("My Project")
+            .with_author("Jane Doe <jane@example.com>, , John Doe")
+            .with_repository("https://github.com/example/my-project")
+            .with_license("MIT")
+            .with_keywords(vec!["api".into(), "rest".into()]);
+
+        assert_eq!(
+            meta.authors(),
+            vec!["Jane Doe <jane@example.com>", "John Doe"]
+        );
+        assert_eq!(ProjectMeta::new("x").authors(), Vec::<&str>::new());
+
+        // Files saved before these fields existed still load
+        let mut json = serde_json::to_value(&meta).unwrap();
+        for key in ["repository", "license", "keywords"] {
+            json.as_object_mut().unwrap().remove(key);
+        }
+        let old: ProjectMeta = serde_json::from_value(json).unwrap();
+        assert_eq!(old.license, None);
+        assert!(old.keywords.is_empty());
+    }
+
+    #[test]
+    fn test_favorite_entities() {
+        let mut project = ProjectGraph::new("Test");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+
+        assert!(project.toggle_favorite(post));
+        assert!(project.toggle_favorite(user));
+        assert!(!project.toggle_favorite(Uuid::new_v4()));
+        let names: Vec<&str> = project
+            .favorite_entities()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["Post", "User"]);
+
+        // Unpinning, and removing the entity, drop it from the list
+        assert!(!project.toggle_favorite(post));
+        assert!(!project.is_favorite(post));
+        project.remove_entity(user);
+        assert!(project.favorite_entities.is_empty());
+
+        // Saved with the project; older files load without any
+        let tag = project.add_entity(Entity::new("Tag"));
+        project.toggle_favorite(tag);
+        let mut json = serde_json::to_value(&project).unwrap();
+        let loaded: ProjectGraph = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(loaded.favorite_entities, project.favorite_entities);
+        json.as_object_mut().unwrap().remove("favorite_entities");
+        let old: ProjectGraph = serde_json::from_value(json).unwrap();
+        assert!(old.favorite_entities.is_empty());
+    }
+
     #[test]
     fn test_project_entity_at() {
         let mut project = ProjectGraph::new("Test");
@@ -1469,9 +3315,37 @@ mod tests {
     }
 
     #[test]
-    fn test_create_relationship_with_fk_many_to_many() {
+    fn test_unique_scope_pairs_field_with_fk() {
+        use crate::relationship_helpers::scope_key;
         use imortal_core::RelationType;
 
+        let mut project = ProjectGraph::new("Test");
+        let org = Entity::new("Organization");
+        let mut team = Entity::new("Team");
+        let (org_id, team_id) = (org.id, team.id);
+        team.add_field(Field::new("slug", imortal_core::DataType::String));
+        project.add_entity(org);
+        project.add_entity(team);
+
+        let relationship = Relationship::new(org_id, team_id, RelationType::OneToMany);
+        let (rel_id, _) = project.create_relationship_with_fk(relationship).unwrap();
+        let team = project.get_entity_mut(team_id).unwrap();
+        let slug = team.get_field_by_name("slug").unwrap().id;
+        team.get_field_mut(slug).unwrap().unique_scope = Some(rel_id);
+
+        let rel = project.get_relationship(rel_id).unwrap();
+        let team = project.get_entity(team_id).unwrap();
+        assert_eq!(scope_key(rel, team).unwrap().name, "organization_id");
+        assert!(scope_key(rel, project.get_entity(org_id).unwrap()).is_none());
+
+        // The scope goes with the relationship
+        project.remove_relationship(rel_id);
+        let team = project.get_entity(team_id).unwrap();
+        assert_eq!(team.get_field(slug).unwrap().unique_scope, None);
+    }
+
+    #[test]
+    fn test_create_relationship_with_fk_many_to_many() {
         let mut project = ProjectGraph::new("Test");
 
         // Create two entities
@@ -1499,6 +3373,124 @@ mod tests {
         assert!(fk_field_id.is_none());
     }
 
+    #[test]
+    fn test_create_relationship_polymorphic() {
+        let mut project = ProjectGraph::new("Test");
+        let comment_id = project.add_entity(Entity::new("Comment"));
+        let post_id = project.add_entity(Entity::new("Post"));
+        let photo_id = project.add_entity(Entity::new("Photo"));
+
+        for target in [post_id, photo_id] {
+            let (_, fk_field_id) = project
+                .create_relationship_with_fk(Relationship::polymorphic(
+                    comment_id,
+                    target,
+                    "commentable",
+                ))
+                .unwrap();
+            assert!(fk_field_id.is_none());
+        }
+
+        // One discriminator/key pair, shared by both targets and indexed together
+        let comment = project.get_entity(comment_id).unwrap();
+        let discriminator = comment.get_field_by_name("commentable_type").unwrap();
+        assert_eq!(discriminator.data_type, imortal_core::DataType::String);
+        let key = comment.get_field_by_name("commentable_id").unwrap();
+        assert_eq!(key.data_type, imortal_core::DataType::Uuid);
+        assert!(!key.is_foreign_key);
+        assert_eq!(
+            comment
+                .fields
+                .iter()
+                .filter(|f| f.name.starts_with("commentable"))
+                .count(),
+            2
+        );
+        assert_eq!(comment.config.indexes.len(), 1);
+        assert_eq!(
+            comment.config.indexes[0].fields,
+            vec!["commentable_type", "commentable_id"]
+        );
+        assert_eq!(project.relationship_count(), 2);
+    }
+
+    #[test]
+    fn test_promote_junction() {
+        let mut project = ProjectGraph::new("Test");
+        let post = Entity::new("Post").at(0.0, 0.0);
+        let tag = Entity::new("Tag").at(400.0, 200.0);
+        let (post_id, tag_id) = (post.id, tag.id);
+        project.add_entity(post);
+        project.add_entity(tag);
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(post_id, tag_id, "post_tags"))
+            .unwrap();
+
+        let junction_id = project.promote_junction(rel_id).unwrap();
+        let junction = project.get_entity(junction_id).unwrap();
+        assert_eq!(junction.name, "PostTag");
+        assert_eq!(junction.table_name, "post_tags");
+        assert_eq!(junction.position, Position::new(200.0, 100.0));
+        assert_eq!(
+            junction.config.unique_together,
+            vec![vec!["post_id".to_string(), "tag_id".to_string()]]
+        );
+        let rel = project.get_relationship(rel_id).unwrap();
+        assert_eq!(rel.junction_entity_id, Some(junction_id));
+        let (from_key, to_key) = junction_keys(junction, rel).unwrap();
+        assert_eq!(
+            (from_key.name.as_str(), to_key.name.as_str()),
+            ("post_id", "tag_id")
+        );
+        assert_eq!(
+            from_key.foreign_key_ref.as_ref().unwrap().on_delete,
+            ReferentialAction::Cascade
+        );
+        assert_eq!(
+            project
+                .relationships_for_entity(junction_id)
+                .iter()
+                .filter(|r| r.is_many_to_one())
+                .count(),
+            2
+        );
+
+        // Once only, and only for many-to-many
+        assert!(project.promote_junction(rel_id).is_err());
+        let (o2m, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(post_id, tag_id))
+            .unwrap();
+        assert!(project.promote_junction(o2m).is_err());
+
+        // Deleting the junction entity makes the junction implicit again
+        project.remove_entity(junction_id);
+        assert_eq!(
+            project.get_relationship(rel_id).unwrap().junction_entity_id,
+            None
+        );
+    }
+
+    #[test]
+    fn test_promote_self_referential_junction() {
+        let mut project = ProjectGraph::new("Test");
+        let user = Entity::new("User");
+        let user_id = user.id;
+        project.add_entity(user);
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(user_id, user_id, ""))
+            .unwrap();
+
+        let junction_id = project.promote_junction(rel_id).unwrap();
+        let junction = project.get_entity(junction_id).unwrap();
+        assert_eq!(junction.name, "UserUser");
+        assert_eq!(junction.table_name, "user_users");
+        let rel = project.get_relationship(rel_id).unwrap();
+        assert_eq!(rel.junction_table(), Some("user_users"));
+        let (from_key, to_key) = junction_keys(junction, rel).unwrap();
+        assert_eq!(from_key.name, "user_id");
+        assert_eq!(to_key.name, "related_user_id");
+    }
+
     #[test]
     fn test_suggest_fk_field_name() {
         let mut project = ProjectGraph::new("Test");
@@ -1510,4 +3502,121 @@ mod tests {
         let suggested = project.suggest_fk_field_name(user_id);
         assert_eq!(suggested, Some("user_id".to_string()));
     }
+
+    #[test]
+    fn test_update_enum_retypes_fields() {
+        let mut project = ProjectGraph::new("Test");
+        let status = EnumDef::new("PostStatus").with_variants(["draft", "published"]);
+        let status_id = project.add_enum(status.clone());
+
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("status", status.data_type()));
+        post.fields.push(Field::new(
+            "previous",
+            imortal_core::DataType::Optional(Box::new(status.data_type())),
+        ));
+        project.add_entity(post);
+
+        let renamed = EnumDef {
+            name: "ArticleStatus".to_string(),
+            ..status.with_variant("archived")
+        };
+        assert_eq!(project.update_enum(renamed.clone()), Some(2));
+        assert!(project.get_enum_by_name("PostStatus").is_none());
+        assert_eq!(project.get_enum(status_id), Some(&renamed));
+        assert_eq!(
+            project.enum_usages("ArticleStatus"),
+            vec![
+                ("Post".to_string(), "previous".to_string()),
+                ("Post".to_string(), "status".to_string()),
+            ]
+        );
+        assert!(
+            project
+                .get_entity_by_name("Post")
+                .unwrap()
+                .fields
+                .iter()
+                .skip(1)
+                .all(|f| renamed.matches(&f.data_type))
+        );
+
+        assert_eq!(project.update_enum(EnumDef::new("Missing")), None);
+        assert!(project.remove_enum(status_id).is_some());
+        assert_eq!(project.enum_usages("ArticleStatus").len(), 2);
+    }
+
+    #[test]
+    fn test_field_sets() {
+        let mut project = ProjectGraph::new("Test");
+        let timestamps = project.add_field_set(FieldSet::timestamps());
+        let address = project.add_field_set(FieldSet::addressable());
+
+        // The entity's own `city` wins over the set's
+        let mut shop = Entity::new("Shop");
+        shop.fields
+            .push(Field::new("city", imortal_core::DataType::Text));
+        assert!(shop.include_field_set(address));
+        assert!(shop.include_field_set(timestamps));
+        assert!(!shop.include_field_set(timestamps));
+        let shop_id = project.add_entity(shop);
+        project.add_entity(Entity::new("Tag"));
+
+        let shop = project.get_entity(shop_id).unwrap();
+        assert_eq!(project.included_field_sets(shop).len(), 2);
+        assert_eq!(project.field_set_usages(address), vec!["Shop".to_string()]);
+        let names: Vec<String> = project
+            .expanded_fields(shop)
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "id",
+                "city",
+                "street",
+                "region",
+                "postal_code",
+                "country",
+                "created_at",
+                "updated_at"
+            ]
+        );
+
+        let expanded = project.expand_field_sets();
+        let expanded_shop = expanded.get_entity(shop_id).unwrap();
+        assert_eq!(expanded_shop.fields.len(), 8);
+        assert!(expanded_shop.field_sets.is_empty());
+        assert_eq!(
+            expanded
+                .expand_field_sets()
+                .get_entity(shop_id)
+                .unwrap()
+                .fields
+                .len(),
+            8
+        );
+        assert_eq!(expanded.get_entity_by_name("Tag").unwrap().fields.len(), 1);
+
+        // Removing a set removes it from the entities including it
+        assert!(project.remove_field_set(address).is_some());
+        assert_eq!(
+            project.get_entity(shop_id).unwrap().field_sets,
+            vec![timestamps]
+        );
+        assert!(project.validate().is_ok());
+        project.add_field_set(FieldSet::timestamps());
+        assert!(project.validate().is_err());
+    }
+
+    #[test]
+    fn test_project_validation_rejects_duplicate_enums() {
+        let mut project = ProjectGraph::new("Test");
+        project.add_enum(EnumDef::new("Status").with_variant("on"));
+        assert!(project.validate().is_ok());
+
+        project.add_enum(EnumDef::new("Status").with_variant("off"));
+        assert!(project.validate().is_err());
+    }
 }
@@ -0,0 +1,438 @@
+//! Custom query definitions for report-style endpoints
+//!
+//! This module contains the `QueryDefinition` type produced by the visual
+//! query builder. A query starts from a root entity, optionally joins related
+//! entities through existing relationships, filters, sorts and projects
+//! columns, and is exposed as a dedicated `GET` endpoint.
+//!
+//! Field references are written either as `field` (a field of the root
+//! entity) or `Entity.field` (a field of a joined entity).
+
+use imortal_core::{EngineError, EngineResult, Validatable};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::endpoint::EndpointSecurity;
+
+// ============================================================================
+// QueryDefinition
+// ============================================================================
+
+/// A typed, read-only query exposed as a custom GET endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryDefinition {
+    /// Unique identifier
+    pub id: Uuid,
+
+    /// Query name (snake_case, used for the handler and DTO names)
+    pub name: String,
+
+    /// Human-readable description (used in API docs)
+    pub description: Option<String>,
+
+    /// Root entity the query selects from
+    pub entity_id: Uuid,
+
+    /// Root entity name (for display purposes)
+    pub entity_name: String,
+
+    /// Full request path (e.g., "/api/reports/active_users")
+    pub path: String,
+
+    /// Related entities joined into the query
+    pub joins: Vec<QueryJoin>,
+
+    /// Filter conditions (combined with AND)
+    pub filters: Vec<QueryFilter>,
+
+    /// Sort order (applied in sequence)
+    pub sort: Vec<QuerySort>,
+
+    /// Selected columns; empty selects every non-secret root field
+    pub projection: Vec<String>,
+
+    /// Maximum number of rows returned
+    pub limit: Option<u64>,
+
+    /// Security settings for the endpoint
+    pub security: EndpointSecurity,
+
+    /// Whether the endpoint is generated
+    pub enabled: bool,
+}
+
+impl QueryDefinition {
+    /// Create a new query on an entity
+    pub fn new(name: impl Into<String>, entity_id: Uuid, entity_name: impl Into<String>) -> Self {
+        let name = name.into();
+        let path = format!("/api/reports/{}", name);
+
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            description: None,
+            entity_id,
+            entity_name: entity_name.into(),
+            path,
+            joins: Vec::new(),
+            filters: Vec::new(),
+            sort: Vec::new(),
+            projection: Vec::new(),
+            limit: Some(100),
+            security: EndpointSecurity::default(),
+            enabled: true,
+        }
+    }
+
+    // ========================================================================
+    // Builder methods
+    // ========================================================================
+
+    /// Set the request path
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Join a related entity through a relationship
+    pub fn with_join(mut self, join: QueryJoin) -> Self {
+        self.joins.push(join);
+        self
+    }
+
+    /// Add a filter condition
+    pub fn with_filter(mut self, filter: QueryFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Add a sort key
+    pub fn with_sort(mut self, field: impl Into<String>, direction: SortDirection) -> Self {
+        self.sort.push(QuerySort {
+            field: field.into(),
+            direction,
+        });
+        self
+    }
+
+    /// Select a column
+    pub fn select(mut self, field: impl Into<String>) -> Self {
+        self.projection.push(field.into());
+        self
+    }
+
+    /// Set the row limit
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set endpoint security
+    pub fn with_security(mut self, security: EndpointSecurity) -> Self {
+        self.security = security;
+        self
+    }
+
+    // ========================================================================
+    // Utility methods
+    // ========================================================================
+
+    /// Filters whose value comes from a query-string parameter
+    pub fn parameter_filters(&self) -> Vec<&QueryFilter> {
+        self.filters
+            .iter()
+            .filter(|f| matches!(f.value, FilterValue::Param(_)))
+            .collect()
+    }
+
+    /// Every field reference used by the query (filters, sort, projection)
+    pub fn field_refs(&self) -> Vec<&str> {
+        self.filters
+            .iter()
+            .map(|f| f.field.as_str())
+            .chain(self.sort.iter().map(|s| s.field.as_str()))
+            .chain(self.projection.iter().map(|p| p.as_str()))
+            .collect()
+    }
+}
+
+impl Validatable for QueryDefinition {
+    fn validate(&self) -> EngineResult<()> {
+        if self.name.is_empty() {
+            return Err(EngineError::validation("Query name cannot be empty"));
+        }
+
+        if !self.path.starts_with('/') {
+            return Err(EngineError::EndpointValidation {
+                endpoint: self.name.clone(),
+                message: "Query path must start with '/'".to_string(),
+            });
+        }
+
+        for field in self.field_refs() {
+            if field.is_empty() {
+                return Err(EngineError::EndpointValidation {
+                    endpoint: self.name.clone(),
+                    message: "Query references an empty field name".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Joins, filters, sorting
+// ============================================================================
+
+/// A join to a related entity through an existing relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryJoin {
+    /// Relationship used for the join condition
+    pub relationship_id: Uuid,
+
+    /// Name of the joined entity (used as the prefix in field references)
+    pub entity_name: String,
+
+    /// Keep root rows without a match (LEFT JOIN instead of INNER JOIN)
+    pub optional: bool,
+}
+
+impl QueryJoin {
+    /// Create an inner join through a relationship
+    pub fn new(relationship_id: Uuid, entity_name: impl Into<String>) -> Self {
+        Self {
+            relationship_id,
+            entity_name: entity_name.into(),
+            optional: false,
+        }
+    }
+
+    /// Use a LEFT JOIN
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+}
+
+/// A single filter condition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryFilter {
+    /// Field reference (`field` or `Entity.field`)
+    pub field: String,
+
+    /// Comparison operator
+    pub operator: FilterOperator,
+
+    /// Value compared against
+    pub value: FilterValue,
+}
+
+impl QueryFilter {
+    /// Compare a field against a fixed value
+    pub fn literal(
+        field: impl Into<String>,
+        operator: FilterOperator,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            operator,
+            value: FilterValue::Literal(value.into()),
+        }
+    }
+
+    /// Compare a field against an optional query-string parameter
+    pub fn param(
+        field: impl Into<String>,
+        operator: FilterOperator,
+        param: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            operator,
+            value: FilterValue::Param(param.into()),
+        }
+    }
+
+    /// Null check on a field (`IS NULL` / `IS NOT NULL`)
+    pub fn null_check(field: impl Into<String>, is_null: bool) -> Self {
+        Self {
+            field: field.into(),
+            operator: if is_null {
+                FilterOperator::IsNull
+            } else {
+                FilterOperator::IsNotNull
+            },
+            value: FilterValue::None,
+        }
+    }
+}
+
+/// Filter comparison operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterOperator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Contains,
+    IsNull,
+    IsNotNull,
+}
+
+impl FilterOperator {
+    /// Get display symbol
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "=",
+            FilterOperator::Ne => "≠",
+            FilterOperator::Gt => ">",
+            FilterOperator::Gte => "≥",
+            FilterOperator::Lt => "<",
+            FilterOperator::Lte => "≤",
+            FilterOperator::Contains => "contains",
+            FilterOperator::IsNull => "is null",
+            FilterOperator::IsNotNull => "is not null",
+        }
+    }
+
+    /// Get the SeaORM `ColumnTrait` method name
+    pub fn sea_orm_method(&self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "eq",
+            FilterOperator::Ne => "ne",
+            FilterOperator::Gt => "gt",
+            FilterOperator::Gte => "gte",
+            FilterOperator::Lt => "lt",
+            FilterOperator::Lte => "lte",
+            FilterOperator::Contains => "contains",
+            FilterOperator::IsNull => "is_null",
+            FilterOperator::IsNotNull => "is_not_null",
+        }
+    }
+
+    /// Whether the operator takes a value
+    pub fn takes_value(&self) -> bool {
+        !matches!(self, FilterOperator::IsNull | FilterOperator::IsNotNull)
+    }
+
+    /// Get all operators
+    pub fn all() -> &'static [FilterOperator] {
+        &[
+            FilterOperator::Eq,
+            FilterOperator::Ne,
+            FilterOperator::Gt,
+            FilterOperator::Gte,
+            FilterOperator::Lt,
+            FilterOperator::Lte,
+            FilterOperator::Contains,
+            FilterOperator::IsNull,
+            FilterOperator::IsNotNull,
+        ]
+    }
+}
+
+/// Right-hand side of a filter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum FilterValue {
+    /// No value (null checks)
+    None,
+    /// Fixed value, written as it would appear in Rust/JSON (`true`, `42`, `active`)
+    Literal(String),
+    /// Optional query-string parameter; the filter is skipped when absent
+    Param(String),
+}
+
+/// One sort key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuerySort {
+    /// Field reference (`field` or `Entity.field`)
+    pub field: String,
+
+    /// Sort direction
+    pub direction: SortDirection,
+}
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+// ============================================================================
+// Field references
+// ============================================================================
+
+/// Split a field reference into its optional entity prefix and field name
+///
+/// `"email"` → `(None, "email")`, `"Post.title"` → `(Some("Post"), "title")`
+pub fn split_field_ref(field_ref: &str) -> (Option<&str>, &str) {
+    match field_ref.split_once('.') {
+        Some((entity, field)) => (Some(entity), field),
+        None => (None, field_ref),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_builder() {
+        let entity_id = Uuid::new_v4();
+        let query = QueryDefinition::new("active_users", entity_id, "User")
+            .with_filter(QueryFilter::literal(
+                "is_active",
+                FilterOperator::Eq,
+                "true",
+            ))
+            .with_filter(QueryFilter::param("city", FilterOperator::Eq, "city"))
+            .with_sort("created_at", SortDirection::Desc)
+            .select("email")
+            .with_limit(50);
+
+        assert_eq!(query.path, "/api/reports/active_users");
+        assert_eq!(query.filters.len(), 2);
+        assert_eq!(query.parameter_filters().len(), 1);
+        assert_eq!(query.limit, Some(50));
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_query_validation() {
+        let query = QueryDefinition::new("report", Uuid::new_v4(), "User").with_path("reports");
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_split_field_ref() {
+        assert_eq!(split_field_ref("email"), (None, "email"));
+        assert_eq!(split_field_ref("Post.title"), (Some("Post"), "title"));
+    }
+
+    #[test]
+    fn test_filter_operator() {
+        assert_eq!(FilterOperator::Gte.sea_orm_method(), "gte");
+        assert!(!FilterOperator::IsNull.takes_value());
+        assert!(FilterOperator::Contains.takes_value());
+    }
+}
@@ -36,7 +36,15 @@ pub struct Relationship {
     /// Field name on the "to" entity (usually "id")
     pub to_field: String,
 
-    /// Name for the inverse relation (e.g., "posts" for User -> Post)
+    /// Accessor on the entity holding the foreign key, naming the row it
+    /// points at (e.g., "author" for `posts.author_id`); for many-to-many,
+    /// the accessor on the "from" entity. Derived when unset.
+    #[serde(default)]
+    pub accessor_name: Option<String>,
+
+    /// Accessor on the referenced entity, naming the rows that point at it
+    /// (e.g., "posts" for User -> Post); for many-to-many, the accessor on
+    /// the "to" entity. Derived when unset.
     pub inverse_name: Option<String>,
 
     /// Human-readable description
@@ -57,6 +65,17 @@ pub struct Relationship {
     /// Whether this relationship is required (NOT NULL FK)
     pub required: bool,
 
+    /// Whether the parent's create endpoint accepts its children inline
+    /// (one-to-many only), e.g. `POST /api/orders` with embedded `items`
+    #[serde(default)]
+    pub nested_create: bool,
+
+    /// Entity modelling the junction table of a many-to-many relationship,
+    /// once promoted to carry its own fields (e.g. `role`, `joined_at`).
+    /// `None` while the junction is implicit.
+    #[serde(default)]
+    pub junction_entity_id: Option<Uuid>,
+
     /// Whether the relationship is selected in the UI
     pub selected: bool,
 
@@ -78,6 +97,7 @@ impl Relationship {
             relation_type,
             from_field: String::new(),
             to_field: "id".to_string(),
+            accessor_name: None,
             inverse_name: None,
             description: None,
             from_port: PortPosition::Right,
@@ -85,6 +105,8 @@ impl Relationship {
             on_delete: ReferentialAction::Restrict,
             on_update: ReferentialAction::Cascade,
             required: true,
+            nested_create: false,
+            junction_entity_id: None,
             selected: false,
             created_at: Utc::now(),
             modified_at: Utc::now(),
@@ -121,6 +143,25 @@ impl Relationship {
         )
     }
 
+    /// Create one target of a polymorphic association: `association`
+    /// "commentable" stores the target's entity in `commentable_type` and
+    /// its ID in `commentable_id` on the "from" entity
+    pub fn polymorphic(
+        from_entity_id: Uuid,
+        to_entity_id: Uuid,
+        association: impl Into<String>,
+    ) -> Self {
+        let association = association.into();
+        Self::new(
+            from_entity_id,
+            to_entity_id,
+            RelationType::Polymorphic {
+                discriminator: format!("{}_type", association),
+            },
+        )
+        .with_from_field(format!("{}_id", association))
+    }
+
     // ========================================================================
     // Builder methods
     // ========================================================================
@@ -143,6 +184,12 @@ impl Relationship {
         self
     }
 
+    /// Set the accessor name on the foreign key side
+    pub fn with_accessor(mut self, name: impl Into<String>) -> Self {
+        self.accessor_name = Some(name.into());
+        self
+    }
+
     /// Set the inverse relation name
     pub fn with_inverse(mut self, name: impl Into<String>) -> Self {
         self.inverse_name = Some(name.into());
@@ -173,6 +220,18 @@ impl Relationship {
         self
     }
 
+    /// Let the parent's create endpoint accept the children inline
+    pub fn with_nested_create(mut self) -> Self {
+        self.nested_create = true;
+        self
+    }
+
+    /// Link the entity modelling the junction table (many-to-many only)
+    pub fn with_junction_entity(mut self, entity_id: Uuid) -> Self {
+        self.junction_entity_id = Some(entity_id);
+        self
+    }
+
     /// Set port positions for visual display
     pub fn with_ports(mut self, from: PortPosition, to: PortPosition) -> Self {
         self.from_port = from;
@@ -204,6 +263,31 @@ impl Relationship {
         matches!(self.relation_type, RelationType::ManyToMany { .. })
     }
 
+    /// Check if this is one target of a polymorphic association
+    pub fn is_polymorphic(&self) -> bool {
+        matches!(self.relation_type, RelationType::Polymorphic { .. })
+    }
+
+    /// Get the field naming the target entity (if polymorphic)
+    pub fn discriminator(&self) -> Option<&str> {
+        match &self.relation_type {
+            RelationType::Polymorphic { discriminator } => Some(discriminator),
+            _ => None,
+        }
+    }
+
+    /// Get the name of the polymorphic association, e.g. "commentable" for
+    /// `commentable_type` (if polymorphic)
+    pub fn polymorphic_association(&self) -> Option<&str> {
+        let discriminator = self.discriminator()?;
+        Some(
+            discriminator
+                .strip_suffix("_type")
+                .filter(|stem| !stem.is_empty())
+                .unwrap_or(discriminator),
+        )
+    }
+
     /// Check if this relationship requires a junction table
     pub fn requires_junction_table(&self) -> bool {
         self.relation_type.requires_junction_table()
@@ -243,13 +327,17 @@ impl Relationship {
             relation_type: self.relation_type.inverse(),
             from_field: self.to_field.clone(),
             to_field: self.from_field.clone(),
-            inverse_name: Some(self.name.clone()),
+            // The foreign key stays where it is, and so do its accessors
+            accessor_name: self.accessor_name.clone(),
+            inverse_name: self.inverse_name.clone(),
             description: self.description.clone(),
             from_port: self.to_port,
             to_port: self.from_port,
             on_delete: self.on_delete,
             on_update: self.on_update,
             required: self.required,
+            nested_create: false,
+            junction_entity_id: self.junction_entity_id,
             selected: false,
             created_at: Utc::now(),
             modified_at: Utc::now(),
@@ -321,6 +409,34 @@ impl Validatable for Relationship {
             ));
         }
 
+        // Nested creation embeds the "many" side in the "one" side's payload
+        if self.nested_create && !self.is_one_to_many() {
+            return Err(EngineError::RelationshipValidation(
+                "Nested create is only supported for one-to-many relationships".to_string(),
+            ));
+        }
+
+        if let Some(discriminator) = self.discriminator() {
+            if discriminator.is_empty() {
+                return Err(EngineError::RelationshipValidation(
+                    "Discriminator field must be specified for polymorphic relationships"
+                        .to_string(),
+                ));
+            }
+            if discriminator == self.from_field {
+                return Err(EngineError::RelationshipValidation(
+                    "Discriminator and key of a polymorphic relationship must be different fields"
+                        .to_string(),
+                ));
+            }
+        }
+
+        if self.junction_entity_id.is_some() && !self.is_many_to_many() {
+            return Err(EngineError::RelationshipValidation(
+                "Only many-to-many relationships have a junction entity".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -474,6 +590,56 @@ mod tests {
 
         assert!(rel.requires_junction_table());
         assert_eq!(rel.junction_table(), Some("post_tags"));
+        assert_eq!(rel.junction_entity_id, None);
+
+        // Promoted junctions keep their entity across a round trip; older
+        // files have none
+        let junction_id = Uuid::new_v4();
+        let rel = rel.with_junction_entity(junction_id);
+        assert!(rel.validate().is_ok());
+        let mut json = serde_json::to_value(&rel).unwrap();
+        let loaded: Relationship = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(loaded.junction_entity_id, Some(junction_id));
+        json.as_object_mut().unwrap().remove("junction_entity_id");
+        let loaded: Relationship = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.junction_entity_id, None);
+
+        let one_to_many = Relationship::one_to_many(from_id, to_id)
+            .with_from_field("post_id")
+            .with_junction_entity(junction_id);
+        assert!(one_to_many.validate().is_err());
+    }
+
+    #[test]
+    fn test_polymorphic() {
+        let comment_id = Uuid::new_v4();
+        let post_id = Uuid::new_v4();
+
+        let rel = Relationship::polymorphic(comment_id, post_id, "commentable");
+        assert!(rel.is_polymorphic());
+        assert!(!rel.requires_junction_table());
+        assert_eq!(rel.discriminator(), Some("commentable_type"));
+        assert_eq!(rel.from_field, "commentable_id");
+        assert_eq!(rel.polymorphic_association(), Some("commentable"));
+        assert!(rel.validate().is_ok());
+
+        // Both halves of the pair are needed, and they must differ
+        let mut unnamed = rel.clone();
+        unnamed.relation_type = RelationType::Polymorphic {
+            discriminator: String::new(),
+        };
+        assert!(unnamed.validate().is_err());
+        assert!(
+            rel.clone()
+                .with_from_field("commentable_type")
+                .validate()
+                .is_err()
+        );
+
+        assert_eq!(
+            Relationship::one_to_many(post_id, comment_id).discriminator(),
+            None
+        );
     }
 
     #[test]
@@ -518,6 +684,7 @@ mod tests {
         assert_eq!(inverse.to_entity_id, from_id);
         assert!(inverse.is_many_to_one());
         assert_eq!(inverse.name, "posts");
+        assert_eq!(inverse.inverse_name.as_deref(), Some("posts"));
     }
 
     #[test]
@@ -543,6 +710,29 @@ mod tests {
             },
         );
         assert!(invalid_m2m.validate().is_err());
+
+        // Nested create on the "one" side only
+        let nested = Relationship::one_to_many(from_id, to_id)
+            .with_from_field("order_id")
+            .with_nested_create();
+        assert!(nested.validate().is_ok());
+        let nested_m2o = Relationship::many_to_one(from_id, to_id)
+            .with_from_field("order_id")
+            .with_nested_create();
+        assert!(nested_m2o.validate().is_err());
+    }
+
+    #[test]
+    fn test_nested_create_defaults_off_for_older_files() {
+        let rel = Relationship::one_to_many(Uuid::new_v4(), Uuid::new_v4())
+            .with_from_field("order_id")
+            .with_nested_create();
+        assert!(!rel.inverse().nested_create);
+
+        let mut json = serde_json::to_value(&rel).unwrap();
+        json.as_object_mut().unwrap().remove("nested_create");
+        let loaded: Relationship = serde_json::from_value(json).unwrap();
+        assert!(!loaded.nested_create);
     }
 
     #[test]
@@ -12,8 +12,10 @@
 //! - **One-to-Many**: FK is on the "many" side (the "to" entity)
 //! - **Many-to-One**: FK is on the "from" entity
 //! - **Many-to-Many**: A junction table is created with FKs to both entities
+//! - **Polymorphic**: No FK; the "from" entity gets a discriminator naming the
+//!   target entity and a key holding its ID, indexed together
 
-use crate::entity::Entity;
+use crate::entity::{Entity, EntityIndex};
 use crate::field::{Field, ForeignKeyRef};
 use crate::relationship::Relationship;
 use imortal_core::{DataType, EngineError, EngineResult, ReferentialAction, RelationType};
@@ -50,6 +52,26 @@ pub fn generate_inverse_name(entity_name: &str) -> String {
     pluralize(&snake)
 }
 
+/// Generate an accessor name for the row a foreign key points at
+///
+/// Strips the `_id` suffix of the FK field; a key without one gets the
+/// referenced entity appended so the accessor doesn't shadow the field
+///
+/// # Examples
+///
+/// - ("author_id", "User") -> "author"
+/// - ("owner", "User") -> "owner_user"
+/// - ("", "BlogPost") -> "blog_post"
+pub fn generate_accessor_name(fk_field_name: &str, referenced_entity: &str) -> String {
+    let fk = to_snake_case(fk_field_name);
+    let entity = to_snake_case(referenced_entity);
+    match fk.strip_suffix("_id") {
+        Some(stem) if !stem.is_empty() => stem.to_string(),
+        _ if fk.is_empty() => entity,
+        _ => format!("{}_{}", fk, entity),
+    }
+}
+
 /// Generate a relationship name from two entity names
 ///
 /// # Examples
@@ -154,6 +176,9 @@ pub fn determine_fk_entity(relationship: &Relationship) -> Option<Uuid> {
 
         // Many-to-Many: Uses junction table, no direct FK
         RelationType::ManyToMany { .. } => None,
+
+        // Polymorphic: the key may point at any of several tables
+        RelationType::Polymorphic { .. } => None,
     }
 }
 
@@ -208,6 +233,82 @@ pub fn calculate_fk_info(
     })
 }
 
+/// Get the entity whose rows point at `entity_id` through this relationship
+///
+/// Returns the FK-holding side when the other side is `entity_id`, i.e. the
+/// entity whose rows can be counted per `entity_id` row. Returns None for
+/// many-to-many relationships and when `entity_id` holds the FK itself.
+pub fn dependent_entity(relationship: &Relationship, entity_id: Uuid) -> Option<Uuid> {
+    let fk_entity_id = determine_fk_entity(relationship)?;
+    let referenced_id = if fk_entity_id == relationship.from_entity_id {
+        relationship.to_entity_id
+    } else {
+        relationship.from_entity_id
+    };
+
+    (referenced_id == entity_id && fk_entity_id != entity_id).then_some(fk_entity_id)
+}
+
+/// Accessor names of both ends of a relationship
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessorNames {
+    /// Accessor on the FK side, naming the referenced row (e.g., "author")
+    pub accessor: String,
+    /// Entity the accessor is generated on
+    pub accessor_entity_id: Uuid,
+    /// Accessor on the referenced side, naming the rows pointing at it
+    /// (e.g., "posts")
+    pub inverse: String,
+    /// Entity the inverse accessor is generated on
+    pub inverse_entity_id: Uuid,
+}
+
+/// Resolve the accessor names of a relationship
+///
+/// Names stored on the relationship win; otherwise the accessor is derived
+/// from the FK field ([`generate_accessor_name`]) and the inverse from the
+/// FK entity ([`generate_inverse_name`], singular for one-to-one). For
+/// many-to-many the accessor sits on the "from" entity and the inverse on
+/// the "to" entity, both plural.
+pub fn accessor_names(
+    relationship: &Relationship,
+    from_entity: &Entity,
+    to_entity: &Entity,
+) -> AccessorNames {
+    let (holder, referenced) = match determine_fk_entity(relationship) {
+        Some(id) if id == relationship.to_entity_id => (to_entity, from_entity),
+        _ => (from_entity, to_entity),
+    };
+
+    let (accessor, inverse) = if relationship.is_many_to_many() {
+        (
+            generate_inverse_name(&to_entity.name),
+            generate_inverse_name(&from_entity.name),
+        )
+    } else {
+        let fk_field = holder
+            .fields
+            .iter()
+            .find(|f| f.is_foreign_key && f.name == relationship.from_field)
+            .or_else(|| get_fk_field_to_entity(holder, referenced.id))
+            .map_or(relationship.from_field.as_str(), |f| f.name.as_str());
+        let inverse = if relationship.is_one_to_one() {
+            to_snake_case(&holder.name)
+        } else {
+            generate_inverse_name(&holder.name)
+        };
+        (generate_accessor_name(fk_field, &referenced.name), inverse)
+    };
+
+    let stored = |name: &Option<String>| name.clone().filter(|n| !n.trim().is_empty());
+    AccessorNames {
+        accessor: stored(&relationship.accessor_name).unwrap_or(accessor),
+        accessor_entity_id: holder.id,
+        inverse: stored(&relationship.inverse_name).unwrap_or(inverse),
+        inverse_entity_id: referenced.id,
+    }
+}
+
 /// Add FK field to an entity based on relationship
 ///
 /// # Arguments
@@ -253,6 +354,55 @@ pub fn add_fk_field_for_relationship(
     Ok(field_id)
 }
 
+/// Add the discriminator and key fields of a polymorphic relationship to
+/// the entity holding them, with an index over the pair
+///
+/// Fields the entity already has are kept, so every target of an
+/// association can be added the same way. The key takes the type of the
+/// target's primary key.
+pub fn add_polymorphic_fields(
+    entity: &mut Entity,
+    target: &Entity,
+    relationship: &Relationship,
+) -> EngineResult<()> {
+    let Some(discriminator) = relationship.discriminator() else {
+        return Err(EngineError::RelationshipValidation(
+            "Only polymorphic relationships have a discriminator".to_string(),
+        ));
+    };
+    if !entity.has_field(discriminator) {
+        let mut field = Field::new(discriminator, DataType::String);
+        field.required = relationship.required;
+        field.description = Some(format!(
+            "Which entity {} points at",
+            relationship.from_field
+        ));
+        entity.add_field(field);
+    }
+
+    if !entity.has_field(&relationship.from_field) {
+        let key_type = target
+            .primary_key()
+            .map_or(DataType::Uuid, |pk| pk.data_type.clone());
+        let mut field = Field::new(&relationship.from_field, key_type);
+        field.required = relationship.required;
+        field.description = Some(format!("ID of the row {} names", discriminator));
+        entity.add_field(field);
+    }
+
+    let pair = [discriminator.to_string(), relationship.from_field.clone()];
+    if !entity
+        .config
+        .indexes
+        .iter()
+        .any(|index| index.fields == pair)
+    {
+        entity.config.indexes.push(EntityIndex::new(pair));
+    }
+
+    Ok(())
+}
+
 /// Check if an entity already has an FK field referencing another entity
 pub fn has_fk_to_entity(entity: &Entity, target_entity_id: Uuid) -> bool {
     entity.fields.iter().any(|f| {
@@ -275,6 +425,43 @@ pub fn get_fk_field_to_entity(entity: &Entity, target_entity_id: Uuid) -> Option
     })
 }
 
+/// Get the FK fields of a junction entity pointing at the "from" and "to"
+/// entities of a many-to-many relationship, in that order
+///
+/// Self-referential relationships need two distinct keys to the same entity.
+/// Returns None when either key is missing.
+pub fn junction_keys<'a>(
+    junction: &'a Entity,
+    relationship: &Relationship,
+) -> Option<(&'a Field, &'a Field)> {
+    let key_to = |entity_id: Uuid, skip: Option<Uuid>| {
+        junction.fields.iter().find(|f| {
+            f.is_foreign_key
+                && Some(f.id) != skip
+                && f.foreign_key_ref
+                    .as_ref()
+                    .is_some_and(|fk| fk.entity_id == entity_id)
+        })
+    };
+    let from = key_to(relationship.from_entity_id, None)?;
+    let to = key_to(relationship.to_entity_id, Some(from.id))?;
+    Some((from, to))
+}
+
+/// Get the FK field of `entity` that a field unique within `relationship`
+/// is paired with, e.g. `organization_id` for a `slug` unique per
+/// organization
+///
+/// Returns None unless `entity` holds the relationship's foreign key.
+pub fn scope_key<'a>(relationship: &Relationship, entity: &'a Entity) -> Option<&'a Field> {
+    if determine_fk_entity(relationship)? != entity.id {
+        return None;
+    }
+    entity
+        .get_field_by_name(&relationship.from_field)
+        .filter(|f| f.is_column())
+}
+
 /// Remove FK field that references a specific entity
 ///
 /// Returns the removed field, if any
@@ -332,6 +519,9 @@ pub fn validate_relationship_creation(
         RelationType::ManyToMany { .. } => {
             // M:N can have multiple relationships between same entities
         }
+        RelationType::Polymorphic { .. } => {
+            // Targets share the key, which is not a foreign key
+        }
     }
 
     Ok(())
@@ -455,6 +645,54 @@ mod tests {
         assert_eq!(generate_inverse_name("Category"), "categories");
     }
 
+    #[test]
+    fn test_generate_accessor_name() {
+        assert_eq!(generate_accessor_name("author_id", "User"), "author");
+        assert_eq!(generate_accessor_name("owner", "User"), "owner_user");
+        assert_eq!(generate_accessor_name("", "BlogPost"), "blog_post");
+    }
+
+    #[test]
+    fn test_accessor_names() {
+        let user = Entity::new("User");
+        let mut post = Entity::new("Post");
+        post.fields.push(create_fk_field(
+            &user,
+            Some("author_id"),
+            true,
+            ReferentialAction::Cascade,
+            ReferentialAction::Cascade,
+        ));
+
+        let rel = Relationship::one_to_many(user.id, post.id).with_from_field("author_id");
+        let names = accessor_names(&rel, &user, &post);
+        assert_eq!(names.accessor, "author");
+        assert_eq!(names.accessor_entity_id, post.id);
+        assert_eq!(names.inverse, "posts");
+        assert_eq!(names.inverse_entity_id, user.id);
+
+        // The same key seen from the other side resolves the same way
+        let names = accessor_names(&rel.inverse(), &post, &user);
+        assert_eq!(
+            (names.accessor.as_str(), names.inverse.as_str()),
+            ("author", "posts")
+        );
+
+        let renamed = rel.with_accessor("writer").with_inverse("articles");
+        let names = accessor_names(&renamed, &user, &post);
+        assert_eq!(
+            (names.accessor.as_str(), names.inverse.as_str()),
+            ("writer", "articles")
+        );
+
+        let tag = Entity::new("Tag");
+        let m2m = Relationship::many_to_many(post.id, tag.id, "post_tags");
+        let names = accessor_names(&m2m, &post, &tag);
+        assert_eq!(names.accessor, "tags");
+        assert_eq!(names.accessor_entity_id, post.id);
+        assert_eq!(names.inverse, "posts");
+    }
+
     #[test]
     fn test_generate_relationship_name() {
         assert_eq!(generate_relationship_name("User", "Post"), "UserPosts");
@@ -522,6 +760,41 @@ mod tests {
         // Many-to-Many: No direct FK
         let many_to_many = Relationship::many_to_many(from_id, to_id, "users_roles");
         assert_eq!(determine_fk_entity(&many_to_many), None);
+
+        // Polymorphic: No FK, the key may point at several tables
+        let polymorphic = Relationship::polymorphic(from_id, to_id, "commentable");
+        assert_eq!(determine_fk_entity(&polymorphic), None);
+    }
+
+    #[test]
+    fn test_polymorphic_accessor_names() {
+        let comment = Entity::new("Comment");
+        let post = Entity::new("Post");
+        let rel = Relationship::polymorphic(comment.id, post.id, "commentable");
+
+        let names = accessor_names(&rel, &comment, &post);
+        assert_eq!(names.accessor, "commentable");
+        assert_eq!(names.accessor_entity_id, comment.id);
+        assert_eq!(names.inverse, "comments");
+        assert_eq!(names.inverse_entity_id, post.id);
+    }
+
+    #[test]
+    fn test_dependent_entity() {
+        let user_id = Uuid::new_v4();
+        let post_id = Uuid::new_v4();
+
+        // User 1:N Post — posts point at users
+        let one_to_many = Relationship::one_to_many(user_id, post_id);
+        assert_eq!(dependent_entity(&one_to_many, user_id), Some(post_id));
+        assert_eq!(dependent_entity(&one_to_many, post_id), None);
+
+        // Post N:1 User — same direction, declared from the other side
+        let many_to_one = Relationship::many_to_one(post_id, user_id);
+        assert_eq!(dependent_entity(&many_to_one, user_id), Some(post_id));
+
+        let many_to_many = Relationship::many_to_many(user_id, post_id, "user_posts");
+        assert_eq!(dependent_entity(&many_to_many, user_id), None);
     }
 
     #[test]
@@ -2,11 +2,30 @@
 //!
 //! This module provides functionality for saving and loading project files,
 //! including JSON serialization, file I/O, and schema version migration.
-
-use crate::{ProjectGraph, SCHEMA_VERSION};
+//!
+//! ## Schema migrations
+//!
+//! Every file records the [`SCHEMA_VERSION`] it was saved with. Loading an
+//! older file runs the steps in [`SCHEMA_MIGRATIONS`] on its raw JSON, one
+//! version at a time, before deserializing it — so a step can rename, move
+//! or reshape fields the current types would reject or misread. Files saved
+//! by a newer version are refused rather than loaded with data missing.
+//!
+//! When a change to the IR would otherwise break or change the meaning of
+//! existing files, bump [`SCHEMA_VERSION`] and append a step here. Additive
+//! changes don't need one: a `#[serde(default)]` on the new field is enough.
+
+use crate::{
+    CanvasState, EndpointGroup, Entity, EnumDef, Field, FieldSet, ProjectConfig, ProjectGraph,
+    ProjectMeta, QueryDefinition, Relationship, SCHEMA_VERSION,
+};
 use imortal_core::{EngineError, EngineResult};
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use uuid::Uuid;
 
 // ============================================================================
 // Constants
@@ -18,6 +37,21 @@ pub const PROJECT_EXTENSION: &str = "ieng";
 /// Magic bytes for binary format (future use)
 pub const MAGIC_BYTES: &[u8] = b"IENG";
 
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Serialize a map with its keys in order, so saving the same project twice
+/// produces the same file (and regenerated schema snapshots don't churn)
+pub(crate) fn sorted_map<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
 // ============================================================================
 // Project File Wrapper
 // ============================================================================
@@ -52,25 +86,142 @@ impl ProjectFile {
     }
 
     /// Migrate to the latest schema version
+    ///
+    /// Round-trips the file through [`migrate_project_json`]; files read with
+    /// [`load_project_from_string`] have already been migrated.
     pub fn migrate(&mut self) -> EngineResult<()> {
-        while self.schema_version < SCHEMA_VERSION {
-            self.migrate_one_version()?;
+        if !self.needs_migration() {
+            return Ok(());
         }
+        let mut value = serde_json::to_value(&*self)?;
+        migrate_project_json(&mut value)?;
+        *self = serde_json::from_value(value)?;
         Ok(())
     }
+}
 
-    /// Migrate one version at a time
-    fn migrate_one_version(&mut self) -> EngineResult<()> {
-        match self.schema_version {
-            // Add migration logic for each version here
-            // 1 => { /* migrate from v1 to v2 */ self.schema_version = 2; }
-            _ => {
-                // No migration needed or unknown version
-                self.schema_version = SCHEMA_VERSION;
-            }
+// ============================================================================
+// Schema Migrations
+// ============================================================================
+
+/// One upgrade step for project file JSON, from schema `from` to `from + 1`
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaMigration {
+    /// Schema version the step upgrades from
+    pub from: u32,
+
+    /// What the step changes, for logs and release notes
+    pub description: &'static str,
+
+    /// Rewrite the `project` object of the file in place
+    pub migrate: fn(&mut Value) -> EngineResult<()>,
+}
+
+/// Every upgrade step, oldest first, one per schema version
+pub const SCHEMA_MIGRATIONS: &[SchemaMigration] = &[SchemaMigration {
+    from: 1,
+    description: "Move inverse names that named the target's accessor to accessor_name",
+    migrate: migrate_v1_accessor_names,
+}];
+
+/// Schema version a project file's JSON was saved with
+///
+/// Files written before the versioned wrapper existed hold a bare project
+/// graph; they count as schema 1 unless the graph records its own version.
+pub fn file_schema_version(value: &Value) -> u32 {
+    value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .map_or(1, |v| u32::try_from(v).unwrap_or(u32::MAX))
+}
+
+/// Upgrade a project file's JSON in place to [`SCHEMA_VERSION`]
+///
+/// A bare project graph is wrapped in the current file layout first. Returns
+/// the steps that ran, oldest first (empty when the file was current).
+///
+/// # Errors
+///
+/// Fails for files from a newer schema than this build understands, when a
+/// step is missing, or when a step cannot make sense of the file.
+pub fn migrate_project_json(value: &mut Value) -> EngineResult<Vec<&'static SchemaMigration>> {
+    let mut version = file_schema_version(value);
+    if version > SCHEMA_VERSION {
+        return Err(EngineError::InvalidProjectFormat(format!(
+            "the file uses schema version {}, but this version of Immortal Engine reads up to {} — update to open it",
+            version, SCHEMA_VERSION
+        )));
+    }
+
+    if value.get("project").is_none() {
+        let project = value.take();
+        *value = serde_json::json!({
+            "schema_version": version,
+            "project": project,
+            "format_version": 1,
+        });
+    }
+
+    let mut applied = Vec::new();
+    while version < SCHEMA_VERSION {
+        let step = SCHEMA_MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| {
+                EngineError::InvalidProjectFormat(format!(
+                    "no migration from schema version {}",
+                    version
+                ))
+            })?;
+        (step.migrate)(&mut value["project"]).map_err(|e| {
+            EngineError::InvalidProjectFormat(format!(
+                "upgrading from schema version {} failed: {}",
+                version, e
+            ))
+        })?;
+        applied.push(step);
+        version += 1;
+    }
+
+    value["schema_version"] = version.into();
+    if let Some(project) = value["project"].as_object_mut() {
+        project.insert("schema_version".to_string(), version.into());
+    }
+    Ok(applied)
+}
+
+/// Schema 1 → 2: `inverse_name` used to name the accessor on a
+/// relationship's target entity. It now names the accessor on the
+/// referenced entity, with `accessor_name` on the one holding the foreign
+/// key — so for one-to-many relationships, whose target holds the key, the
+/// name moves to `accessor_name`.
+fn migrate_v1_accessor_names(project: &mut Value) -> EngineResult<()> {
+    let Some(relationships) = project
+        .get_mut("relationships")
+        .and_then(Value::as_object_mut)
+    else {
+        return Ok(());
+    };
+
+    for relationship in relationships.values_mut() {
+        let Some(relationship) = relationship.as_object_mut() else {
+            continue;
+        };
+        let target_holds_fk =
+            relationship.get("relation_type").and_then(Value::as_str) == Some("one_to_many");
+        let has_accessor = relationship
+            .get("accessor_name")
+            .is_some_and(|name| !name.is_null());
+        let inverse = relationship
+            .get("inverse_name")
+            .filter(|name| name.is_string())
+            .cloned();
+        if let Some(name) = inverse.filter(|_| target_holds_fk && !has_accessor) {
+            relationship.insert("accessor_name".to_string(), name);
+            relationship.insert("inverse_name".to_string(), Value::Null);
         }
-        Ok(())
     }
+    Ok(())
 }
 
 // ============================================================================
@@ -141,6 +292,10 @@ pub fn save_project_to_compact_string(project: &ProjectGraph) -> EngineResult<St
 
 /// Load a project from a file
 ///
+/// Parse failures come back as [`EngineError::ProjectParse`], pointing at the
+/// line and key that went wrong; [`load_project_with_recovery`] loads what it
+/// can of such a file instead.
+///
 /// # Arguments
 ///
 /// * `path` - The path to load from
@@ -154,47 +309,375 @@ pub fn save_project_to_compact_string(project: &ProjectGraph) -> EngineResult<St
 /// println!("Loaded project: {}", project.meta.name);
 /// ```
 pub fn load_project(path: impl AsRef<Path>) -> EngineResult<ProjectGraph> {
-    let path = path.as_ref();
+    load_project_from_string(&read_project_file(path.as_ref())?)
+}
+
+/// Load a project from a JSON string
+///
+/// Older files (including bare project graphs) are upgraded to the current
+/// schema on the way in; see [`migrate_project_json`].
+pub fn load_project_from_string(json: &str) -> EngineResult<ProjectGraph> {
+    let mut value: Value = serde_json::from_str(json).map_err(syntax_error)?;
+    let exact = migrate_project_json(&mut value)?.is_empty() && !is_bare(json);
+    match ProjectFile::deserialize(&value) {
+        Ok(file) => Ok(file.project),
+        Err(error) => Err(shape_error(json, &value, error, exact)),
+    }
+}
+
+/// Load a project from bytes
+pub fn load_project_from_bytes(bytes: &[u8]) -> EngineResult<ProjectGraph> {
+    let json = std::str::from_utf8(bytes)
+        .map_err(|e| EngineError::InvalidProjectFormat(format!("Invalid UTF-8: {}", e)))?;
+    load_project_from_string(json)
+}
+
+fn read_project_file(path: &Path) -> EngineResult<String> {
     if !path.exists() {
         return Err(EngineError::ProjectNotFound(path.to_path_buf()));
     }
-
-    // Read file contents
-    let json = std::fs::read_to_string(path).map_err(|e| EngineError::FileRead {
+    std::fs::read_to_string(path).map_err(|e| EngineError::FileRead {
         path: path.to_path_buf(),
         message: e.to_string(),
-    })?;
+    })
+}
 
-    // Parse and potentially migrate
-    load_project_from_string(&json).map_err(|e| match e {
-        EngineError::JsonSerialization(je) => EngineError::FileRead {
-            path: path.to_path_buf(),
-            message: format!("Invalid project file format: {}", je),
-        },
-        other => other,
+// ============================================================================
+// Recovery
+// ============================================================================
+
+/// Part of a project file that could not be read, left out of a
+/// [`RecoveredProject`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarantinedSection {
+    /// Dotted path to the section, e.g. `project.entities.<id>.fields[2]`
+    pub key: String,
+
+    /// Line the section starts on, when it can be found in the file
+    pub line: Option<usize>,
+
+    /// Why the section could not be read
+    pub message: String,
+
+    /// The section's JSON as it was in the file, for repairing by hand
+    pub raw: Value,
+}
+
+/// A project loaded from a damaged file, minus the parts that didn't parse
+#[derive(Debug, Clone)]
+pub struct RecoveredProject {
+    /// Everything that could be read
+    pub project: ProjectGraph,
+
+    /// Sections that were left out (or reset to defaults), in file order
+    pub quarantined: Vec<QuarantinedSection>,
+}
+
+impl RecoveredProject {
+    /// Whether the whole file was read
+    pub fn is_complete(&self) -> bool {
+        self.quarantined.is_empty()
+    }
+
+    /// One line per quarantined section, for status bars and logs
+    pub fn warnings(&self) -> Vec<String> {
+        self.quarantined
+            .iter()
+            .map(|section| match section.line {
+                Some(line) => format!("line {}: `{}` {}", line, section.key, section.message),
+                None => format!("`{}` {}", section.key, section.message),
+            })
+            .collect()
+    }
+}
+
+/// Load a project from a file, skipping the parts that don't parse
+///
+/// See [`recover_project_from_string`].
+pub fn load_project_with_recovery(path: impl AsRef<Path>) -> EngineResult<RecoveredProject> {
+    recover_project_from_string(&read_project_file(path.as_ref())?)
+}
+
+/// Load what parses of a project file's JSON
+///
+/// Entities, relationships, endpoints, queries and enums that don't
+/// deserialize are left out — an entity with a broken field keeps its other
+/// fields — along with anything that belonged to a dropped entity. Broken
+/// metadata, configuration and canvas state fall back to defaults. Each
+/// skipped section is reported with its raw JSON.
+///
+/// # Errors
+///
+/// Files that aren't valid JSON, that come from a newer schema, or that hold
+/// no project object can't be recovered and fail as [`load_project`] would.
+pub fn recover_project_from_string(json: &str) -> EngineResult<RecoveredProject> {
+    let mut value: Value = serde_json::from_str(json).map_err(syntax_error)?;
+    migrate_project_json(&mut value)?;
+    let quarantined = quarantine(&mut value, json)?;
+    let file = ProjectFile::deserialize(&value).map_err(|e| shape_error(json, &value, e, false))?;
+    Ok(RecoveredProject {
+        project: file.project,
+        quarantined,
     })
 }
 
-/// Load a project from a JSON string
-pub fn load_project_from_string(json: &str) -> EngineResult<ProjectGraph> {
-    // Try to parse as ProjectFile first
-    if let Ok(mut file) = serde_json::from_str::<ProjectFile>(json) {
-        if file.needs_migration() {
-            file.migrate()?;
+/// A JSON syntax error, with serde_json's own position
+fn syntax_error(error: serde_json::Error) -> EngineError {
+    EngineError::ProjectParse {
+        line: error.line(),
+        column: error.column(),
+        key: None,
+        message: strip_position(&error),
+    }
+}
+
+/// A file that is valid JSON but not a valid project, pinned to the first
+/// section recovery would have to drop
+///
+/// `exact` says the parsed JSON still matches the text (nothing was migrated
+/// or wrapped), so serde_json can report the position itself.
+fn shape_error(json: &str, value: &Value, error: serde_json::Error, exact: bool) -> EngineError {
+    let mut probe = value.clone();
+    let first = quarantine(&mut probe, json).ok().and_then(|sections| {
+        sections
+            .into_iter()
+            .min_by_key(|section| section.line.unwrap_or(usize::MAX))
+    });
+    let position = match &first {
+        Some(section) => locate(json, &section.key),
+        None => None,
+    }
+    .or_else(|| {
+        let error = serde_json::from_str::<ProjectFile>(json).err()?;
+        (exact && error.line() > 0).then(|| (error.line(), error.column()))
+    });
+    let (line, column) = position.unwrap_or((1, 1));
+    match first {
+        Some(section) => EngineError::ProjectParse {
+            line,
+            column,
+            key: Some(section.key),
+            message: section.message,
+        },
+        None => EngineError::ProjectParse {
+            line,
+            column,
+            key: None,
+            message: strip_position(&error),
+        },
+    }
+}
+
+/// serde_json's message without its trailing " at line X column Y"
+fn strip_position(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rfind(" at line ") {
+        Some(at) if error.line() > 0 => message[..at].to_string(),
+        _ => message,
+    }
+}
+
+/// Whether the file holds a bare project graph rather than the wrapper
+fn is_bare(json: &str) -> bool {
+    serde_json::from_str::<Value>(json).is_ok_and(|value| value.get("project").is_none())
+}
+
+/// Remove (or reset) every section of a migrated file that doesn't
+/// deserialize, returning what was taken out in file order
+fn quarantine(file: &mut Value, json: &str) -> EngineResult<Vec<QuarantinedSection>> {
+    let Some(project) = file.get_mut("project").and_then(Value::as_object_mut) else {
+        return Err(EngineError::ProjectParse {
+            line: 1,
+            column: 1,
+            key: Some("project".to_string()),
+            message: "the file holds no project object".to_string(),
+        });
+    };
+
+    let mut sections = Vec::new();
+    let mut quarantine = |key: String, message: String, raw: Value| {
+        sections.push(QuarantinedSection {
+            line: locate(json, &key).map(|(line, _)| line),
+            key,
+            message,
+            raw,
+        });
+    };
+
+    let name = project
+        .get("meta")
+        .and_then(|meta| meta.get("name"))
+        .and_then(Value::as_str)
+        .unwrap_or("Recovered Project")
+        .to_string();
+    let defaults = serde_json::to_value(ProjectGraph::new(name))?;
+
+    reset_if_broken::<ProjectMeta>(project, &defaults, "meta", &mut quarantine);
+    reset_if_broken::<ProjectConfig>(project, &defaults, "config", &mut quarantine);
+
+    let mut dropped_entities = HashSet::new();
+    for (id, mut entity) in take_collection(project, "entities", &mut quarantine) {
+        let key = format!("project.entities.{}", id);
+        if Entity::deserialize(&entity).is_err() {
+            drop_broken_fields(&mut entity, &key, &mut quarantine);
+        }
+        match Entity::deserialize(&entity) {
+            Ok(_) => {
+                project["entities"][&id] = entity;
+            }
+            Err(error) => {
+                quarantine(key, strip_position(&error), entity);
+                dropped_entities.insert(id);
+            }
         }
-        return Ok(file.project);
     }
 
-    // Try to parse as raw ProjectGraph (for backwards compatibility)
-    let project: ProjectGraph = serde_json::from_str(json)?;
-    Ok(project)
+    let belongs_to_dropped = |item: &Value, keys: &[&str]| {
+        keys.iter().any(|key| {
+            item.get(*key)
+                .and_then(Value::as_str)
+                .is_some_and(|id| dropped_entities.contains(id))
+        })
+    };
+    for (collection, owners) in [
+        ("relationships", &["from_entity_id", "to_entity_id"][..]),
+        ("endpoints", &["entity_id"][..]),
+        ("queries", &["entity_id"][..]),
+        ("enums", &[][..]),
+        ("field_sets", &[][..]),
+    ] {
+        for (id, item) in take_collection(project, collection, &mut quarantine) {
+            let key = format!("project.{}.{}", collection, id);
+            let parsed = match collection {
+                "relationships" => Relationship::deserialize(&item).map(drop),
+                "endpoints" => EndpointGroup::deserialize(&item).map(drop),
+                "queries" => QueryDefinition::deserialize(&item).map(drop),
+                "field_sets" => FieldSet::deserialize(&item).map(drop),
+                _ => EnumDef::deserialize(&item).map(drop),
+            };
+            match parsed {
+                Err(error) => quarantine(key, strip_position(&error), item),
+                Ok(()) if belongs_to_dropped(&item, owners) => quarantine(
+                    key,
+                    "belongs to an entity that could not be read".to_string(),
+                    item,
+                ),
+                Ok(()) => {
+                    project[collection][&id] = item;
+                }
+            }
+        }
+    }
+
+    reset_if_broken::<CanvasState>(project, &defaults, "canvas", &mut quarantine);
+    for list in [
+        "selected_entities",
+        "selected_relationships",
+        "favorite_entities",
+    ] {
+        if project.contains_key(list) {
+            reset_if_broken::<Vec<Uuid>>(project, &defaults, list, &mut quarantine);
+        }
+    }
+
+    Ok(sections)
 }
 
-/// Load a project from bytes
-pub fn load_project_from_bytes(bytes: &[u8]) -> EngineResult<ProjectGraph> {
-    let json = std::str::from_utf8(bytes)
-        .map_err(|e| EngineError::InvalidProjectFormat(format!("Invalid UTF-8: {}", e)))?;
-    load_project_from_string(json)
+/// Replace a section that is missing or doesn't deserialize as `T` with its
+/// value from a fresh project
+fn reset_if_broken<T: DeserializeOwned>(
+    project: &mut Map<String, Value>,
+    defaults: &Value,
+    section: &str,
+    quarantine: &mut impl FnMut(String, String, Value),
+) {
+    let message = match project.get(section) {
+        None => "is missing; reset to defaults".to_string(),
+        Some(value) => match T::deserialize(value) {
+            Ok(_) => return,
+            Err(error) => format!("{}; reset to defaults", strip_position(&error)),
+        },
+    };
+    let raw = project
+        .insert(section.to_string(), defaults[section].clone())
+        .unwrap_or(Value::Null);
+    quarantine(format!("project.{}", section), message, raw);
+}
+
+/// Empty a keyed collection and return its entries sorted by key, for
+/// checking one at a time; a collection that isn't an object is
+/// quarantined whole
+fn take_collection(
+    project: &mut Map<String, Value>,
+    collection: &str,
+    quarantine: &mut impl FnMut(String, String, Value),
+) -> Vec<(String, Value)> {
+    let entries = match project.insert(collection.to_string(), Value::Object(Map::new())) {
+        None | Some(Value::Null) => return Vec::new(),
+        Some(Value::Object(entries)) => entries,
+        Some(other) => {
+            quarantine(
+                format!("project.{}", collection),
+                "is not a map of items keyed by ID".to_string(),
+                other,
+            );
+            return Vec::new();
+        }
+    };
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+/// Take the fields that don't deserialize out of an entity's JSON
+fn drop_broken_fields(
+    entity: &mut Value,
+    key: &str,
+    quarantine: &mut impl FnMut(String, String, Value),
+) {
+    let Some(fields) = entity.get_mut("fields").and_then(Value::as_array_mut) else {
+        return;
+    };
+    let mut index = 0;
+    fields.retain(|field| {
+        let keep = match Field::deserialize(field) {
+            Ok(_) => true,
+            Err(error) => {
+                let anchor = field.get("id").and_then(Value::as_str).map_or_else(
+                    || format!("{}.fields[{}]", key, index),
+                    |id| format!("{}.fields.{}", key, id),
+                );
+                quarantine(anchor, strip_position(&error), field.clone());
+                false
+            }
+        };
+        index += 1;
+        keep
+    });
+}
+
+/// Line and column of a section in the file, found by looking for each
+/// segment of its key in turn
+///
+/// Segments that aren't in the text (the `project` wrapper of a bare file,
+/// array indices) are skipped; returns `None` when none of them are found.
+fn locate(json: &str, key: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    let mut found = None;
+    for segment in key.split('.') {
+        let segment = segment.split('[').next().unwrap_or(segment);
+        let needle = format!("\"{}\"", segment);
+        if let Some(at) = json[offset..].find(&needle) {
+            offset += at;
+            found = Some(offset);
+            offset += needle.len();
+        }
+    }
+    let at = found?;
+    let before = &json[..at];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    Some((line, column))
 }
 
 // ============================================================================
@@ -245,6 +728,12 @@ pub fn ensure_extension(path: impl AsRef<Path>) -> PathBuf {
     }
 }
 
+/// Where [`backup_project`] keeps its copy of a project file
+pub fn backup_path(path: impl AsRef<Path>) -> PathBuf {
+    path.as_ref()
+        .with_extension(format!("{}.backup", PROJECT_EXTENSION))
+}
+
 /// Create a backup of a project file before overwriting
 pub fn backup_project(path: impl AsRef<Path>) -> EngineResult<Option<PathBuf>> {
     let path = path.as_ref();
@@ -253,7 +742,7 @@ pub fn backup_project(path: impl AsRef<Path>) -> EngineResult<Option<PathBuf>> {
         return Ok(None);
     }
 
-    let backup_path = path.with_extension(format!("{}.backup", PROJECT_EXTENSION));
+    let backup_path = backup_path(path);
 
     std::fs::copy(path, &backup_path).map_err(|e| EngineError::FileWrite {
         path: backup_path.clone(),
@@ -389,6 +878,34 @@ mod tests {
     use super::*;
     use crate::Entity;
     use tempfile::TempDir;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_saved_maps_are_sorted() {
+        let mut project = ProjectGraph::new("Sorted");
+        for name in ["A", "B", "C", "D", "E", "F"] {
+            project.add_entity(Entity::new(name));
+        }
+        project
+            .config
+            .custom_options
+            .insert("zeta".into(), "1".into());
+        project
+            .config
+            .custom_options
+            .insert("alpha".into(), "2".into());
+
+        let json = save_project_to_string(&project).unwrap();
+        let mut ids: Vec<Uuid> = project.entities.keys().copied().collect();
+        ids.sort();
+        let positions: Vec<usize> = ids
+            .iter()
+            .map(|id| json.find(&format!("\"{}\": {{", id)).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert!(json.find("\"alpha\"").unwrap() < json.find("\"zeta\"").unwrap());
+        assert_eq!(json, save_project_to_string(&project.clone()).unwrap());
+    }
 
     #[test]
     fn test_save_and_load_project() {
@@ -409,6 +926,27 @@ mod tests {
         assert_eq!(loaded.entity_count(), 1);
     }
 
+    #[test]
+    fn test_enums_persist() {
+        let mut project = ProjectGraph::new("Enums");
+        let id = project.add_enum(
+            crate::EnumDef::new("Role")
+                .with_description("What a member may do")
+                .with_variants(["owner", "member"]),
+        );
+
+        let loaded = load_project_from_string(&save_project_to_string(&project).unwrap()).unwrap();
+        assert_eq!(loaded.get_enum(id), project.get_enum(id));
+
+        // Files saved before enums existed still load
+        let mut value: serde_json::Value =
+            serde_json::from_str(&save_project_to_string(&ProjectGraph::new("Old")).unwrap())
+                .unwrap();
+        value["project"].as_object_mut().unwrap().remove("enums");
+        let loaded = load_project_from_string(&value.to_string()).unwrap();
+        assert!(loaded.enums.is_empty());
+    }
+
     #[test]
     fn test_save_and_load_string() {
         let mut project = ProjectGraph::new("String Test");
@@ -444,6 +982,277 @@ mod tests {
         assert!(!file.needs_migration());
     }
 
+    /// A saved file rewritten to look like schema 1
+    fn v1_file(project: &ProjectGraph) -> Value {
+        let mut value: Value =
+            serde_json::from_str(&save_project_to_string(project).unwrap()).unwrap();
+        value["schema_version"] = 1.into();
+        value["project"]["schema_version"] = 1.into();
+        for rel in value["project"]["relationships"]
+            .as_object_mut()
+            .unwrap()
+            .values_mut()
+        {
+            rel.as_object_mut().unwrap().remove("accessor_name");
+        }
+        value
+    }
+
+    #[test]
+    fn test_schema_migrations_cover_every_version() {
+        let froms: Vec<u32> = SCHEMA_MIGRATIONS.iter().map(|m| m.from).collect();
+        let expected: Vec<u32> = (1..SCHEMA_VERSION).collect();
+        assert_eq!(froms, expected);
+    }
+
+    #[test]
+    fn test_migrate_v1_accessor_names() {
+        let mut project = ProjectGraph::new("Blog");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        let has_posts = project
+            .add_relationship(crate::Relationship::one_to_many(user, post).with_inverse("user"));
+        let tagged = project
+            .add_relationship(crate::Relationship::many_to_one(post, tag).with_inverse("posts"));
+
+        let mut value = v1_file(&project);
+        let applied = migrate_project_json(&mut value).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+
+        let loaded: ProjectFile = serde_json::from_value(value).unwrap();
+        let loaded = loaded.project;
+        assert_eq!(loaded.schema_version, SCHEMA_VERSION);
+
+        // The dialog's "user" named Post's accessor, which the target holds
+        let rel = &loaded.relationships[&has_posts];
+        assert_eq!(rel.accessor_name.as_deref(), Some("user"));
+        assert!(rel.inverse_name.is_none());
+
+        // On a many-to-one the target is the referenced entity already
+        let rel = &loaded.relationships[&tagged];
+        assert!(rel.accessor_name.is_none());
+        assert_eq!(rel.inverse_name.as_deref(), Some("posts"));
+
+        // Loading goes through the same steps
+        let json = v1_file(&project).to_string();
+        let loaded = load_project_from_string(&json).unwrap();
+        assert_eq!(
+            loaded.relationships[&has_posts].accessor_name.as_deref(),
+            Some("user")
+        );
+    }
+
+    #[test]
+    fn test_current_files_are_not_migrated() {
+        let mut project = ProjectGraph::new("Current");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+        let id = project
+            .add_relationship(crate::Relationship::one_to_many(user, post).with_inverse("posts"));
+
+        let mut value: Value =
+            serde_json::from_str(&save_project_to_string(&project).unwrap()).unwrap();
+        assert!(migrate_project_json(&mut value).unwrap().is_empty());
+
+        let loaded = load_project_from_string(&save_project_to_string(&project).unwrap()).unwrap();
+        assert_eq!(
+            loaded.relationships[&id].inverse_name.as_deref(),
+            Some("posts")
+        );
+    }
+
+    #[test]
+    fn test_bare_project_graph_is_wrapped_and_migrated() {
+        let mut project = ProjectGraph::new("Bare");
+        project.schema_version = 1;
+        let json = serde_json::to_string(&project).unwrap();
+
+        let mut value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(file_schema_version(&value), 1);
+        migrate_project_json(&mut value).unwrap();
+        assert_eq!(value["project"]["meta"]["name"], "Bare");
+
+        let loaded = load_project_from_string(&json).unwrap();
+        assert_eq!(loaded.meta.name, "Bare");
+        assert_eq!(loaded.schema_version, SCHEMA_VERSION);
+    }
+
+    /// A project with a `Post` whose `title` field has an unknown data type,
+    /// and a `Tag` with no name, plus an endpoint and relationship on `Tag`
+    fn damaged_file() -> (String, ProjectGraph) {
+        use imortal_core::DataType;
+
+        let mut project = ProjectGraph::new("Damaged");
+        let post = project.add_entity(
+            Entity::new("Post")
+                .with_field(Field::new("title", DataType::String))
+                .with_field(Field::new("body", DataType::Text)),
+        );
+        let tag = project.add_entity(Entity::new("Tag"));
+        project.add_relationship(Relationship::many_to_one(post, tag));
+        project.add_endpoint(EndpointGroup::new(tag, "Tag"));
+        project.add_endpoint(EndpointGroup::new(post, "Post"));
+
+        let mut value: Value =
+            serde_json::from_str(&save_project_to_string(&project).unwrap()).unwrap();
+        let entities = &mut value["project"]["entities"];
+        let title = entities[post.to_string()]["fields"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|f| f["name"] == "title")
+            .unwrap();
+        title["data_type"] = "Bogus".into();
+        entities[tag.to_string()]
+            .as_object_mut()
+            .unwrap()
+            .remove("name");
+        value["project"]["canvas"] = "zoomed".into();
+        (serde_json::to_string_pretty(&value).unwrap(), project)
+    }
+
+    #[test]
+    fn test_syntax_error_has_position() {
+        let json = "{\n  \"schema_version\": 2,\n  \"project\": {,}\n}";
+        let err = load_project_from_string(json).unwrap_err();
+        match err {
+            EngineError::ProjectParse {
+                line, column, key, ..
+            } => {
+                assert_eq!((line, column, key), (3, 15, None));
+            }
+            other => panic!("expected a parse error, got {}", other),
+        }
+    }
+
+    #[test]
+    fn test_shape_error_names_the_key() {
+        let (json, project) = damaged_file();
+        let post = project.get_entity_by_name("Post").unwrap();
+        let tag = project.get_entity_by_name("Tag").unwrap().id;
+        let mut value: Value = serde_json::from_str(&json).unwrap();
+        value["project"]["entities"][tag.to_string()]["name"] = "Tag".into();
+        value["project"]["canvas"] = serde_json::to_value(CanvasState::default()).unwrap();
+        let json = serde_json::to_string_pretty(&value).unwrap();
+        let title = post.fields.iter().find(|f| f.name == "title").unwrap();
+
+        let err = load_project_from_string(&json).unwrap_err();
+        let EngineError::ProjectParse {
+            line, key, message, ..
+        } = &err
+        else {
+            panic!("expected a parse error, got {}", err);
+        };
+        assert_eq!(
+            key.as_deref(),
+            Some(format!("project.entities.{}.fields.{}", post.id, title.id).as_str())
+        );
+        assert!(message.contains("Bogus"), "{}", message);
+        let expected = json
+            .lines()
+            .position(|l| l.contains(&format!("\"{}\"", title.id)))
+            .unwrap();
+        assert_eq!(*line, expected + 1);
+        assert!(err.to_string().contains("fields."), "{}", err);
+    }
+
+    #[test]
+    fn test_recover_damaged_project() {
+        let (json, project) = damaged_file();
+        let post = project.get_entity_by_name("Post").unwrap().id;
+        let tag = project.get_entity_by_name("Tag").unwrap().id;
+
+        let recovered = recover_project_from_string(&json).unwrap();
+        assert!(!recovered.is_complete());
+        let loaded = &recovered.project;
+        assert_eq!(loaded.meta.name, "Damaged");
+        assert_eq!(loaded.entities.len(), 1);
+        let names: Vec<_> = loaded.entities[&post]
+            .fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert!(names.contains(&"body") && !names.contains(&"title"));
+        assert!(loaded.relationships.is_empty());
+        assert_eq!(loaded.endpoints.len(), 1);
+        assert!(loaded.endpoints.values().all(|e| e.entity_id == post));
+        assert_eq!(loaded.canvas.zoom, CanvasState::default().zoom);
+
+        let keys: Vec<_> = recovered
+            .quarantined
+            .iter()
+            .map(|s| s.key.split('.').take(2).collect::<Vec<_>>().join("."))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "project.entities",
+                "project.entities",
+                "project.relationships",
+                "project.endpoints",
+                "project.canvas"
+            ]
+        );
+        let tag_section = recovered
+            .quarantined
+            .iter()
+            .find(|s| s.key == format!("project.entities.{}", tag))
+            .unwrap();
+        assert_eq!(tag_section.raw["id"], tag.to_string());
+        assert!(tag_section.line.is_some());
+        assert!(recovered.warnings()[0].starts_with("line "));
+
+        let intact = save_project_to_string(&project).unwrap();
+        assert!(recover_project_from_string(&intact).unwrap().is_complete());
+    }
+
+    #[test]
+    fn test_recover_unrecoverable() {
+        assert!(matches!(
+            recover_project_from_string("{\"project\": [1, 2]}"),
+            Err(EngineError::ProjectParse { .. })
+        ));
+        assert!(matches!(
+            recover_project_from_string("not json"),
+            Err(EngineError::ProjectParse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_newer_schema_is_refused() {
+        let mut value: Value =
+            serde_json::from_str(&save_project_to_string(&ProjectGraph::new("Future")).unwrap())
+                .unwrap();
+        value["schema_version"] = (SCHEMA_VERSION + 1).into();
+
+        let err = load_project_from_string(&value.to_string()).unwrap_err();
+        assert!(
+            matches!(&err, EngineError::InvalidProjectFormat(msg) if msg.contains("update to open it")),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_project_file_migrate() {
+        let mut project = ProjectGraph::new("Typed");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+        let id = project
+            .add_relationship(crate::Relationship::one_to_many(user, post).with_inverse("user"));
+
+        let mut file: ProjectFile = serde_json::from_value(v1_file(&project)).unwrap();
+        assert!(file.needs_migration());
+        file.migrate().unwrap();
+        assert!(!file.needs_migration());
+        assert_eq!(
+            file.project.relationships[&id].accessor_name.as_deref(),
+            Some("user")
+        );
+    }
+
     #[test]
     fn test_default_file_name() {
         assert_eq!(default_file_name("My Project"), "my_project.ieng");
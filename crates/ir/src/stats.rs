@@ -0,0 +1,328 @@
+//! # Project Statistics
+//!
+//! Summary counts for a project and the graph of which entities depend on
+//! which, as printed by `imortal info`.
+//!
+//! An entity depends on another when one of its fields is a foreign key to
+//! it, so the graph follows the FK fields rather than the relationship lines
+//! on the canvas. Many-to-many relationships have no FK on either entity and
+//! are listed separately as links through their junction table.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::stats::DependencyGraph;
+//! use imortal_ir::{Entity, ProjectGraph, Relationship};
+//!
+//! let mut project = ProjectGraph::new("blog");
+//! let user = project.add_entity(Entity::new("User"));
+//! let post = project.add_entity(Entity::new("Post"));
+//! project
+//!     .create_relationship_with_fk(Relationship::one_to_many(user, post))
+//!     .unwrap();
+//!
+//! let graph = DependencyGraph::of(&project);
+//! assert_eq!(graph.to_ascii(), "Post\n└─▶ User (user_id)\nUser\n");
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{ProjectGraph, RelationType};
+
+// ============================================================================
+// ProjectStats
+// ============================================================================
+
+/// Counts of everything in a project
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectStats {
+    /// Number of entities
+    pub entities: usize,
+    /// Number of fields across all entities
+    pub fields: usize,
+    /// Number of relationships
+    pub relationships: usize,
+    /// Relationship counts keyed by type name (e.g. "One to Many")
+    pub relationships_by_type: BTreeMap<&'static str, usize>,
+    /// Number of endpoint groups
+    pub endpoints: usize,
+    /// Number of enabled CRUD routes across all endpoint groups
+    pub routes: usize,
+    /// Number of custom queries
+    pub queries: usize,
+}
+
+impl ProjectStats {
+    /// Collect the counts for a project
+    pub fn of(project: &ProjectGraph) -> Self {
+        let mut relationships_by_type = BTreeMap::new();
+        for relationship in project.relationships.values() {
+            *relationships_by_type
+                .entry(relationship.relation_type.display_name())
+                .or_insert(0) += 1;
+        }
+
+        Self {
+            entities: project.entity_count(),
+            fields: project.entities.values().map(|e| e.field_count()).sum(),
+            relationships: project.relationship_count(),
+            relationships_by_type,
+            endpoints: project.endpoint_count(),
+            routes: project
+                .endpoints
+                .values()
+                .map(|e| e.enabled_operations().len())
+                .sum(),
+            queries: project.queries.len(),
+        }
+    }
+}
+
+// ============================================================================
+// DependencyGraph
+// ============================================================================
+
+/// A foreign key from one entity to another
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dependency {
+    /// Entity holding the foreign key
+    pub from: String,
+    /// Referenced entity
+    pub to: String,
+    /// Name of the FK field
+    pub field: String,
+}
+
+/// A many-to-many relationship between two entities
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JunctionLink {
+    /// First entity (the relationship's source)
+    pub left: String,
+    /// Second entity (the relationship's target)
+    pub right: String,
+    /// Junction table joining them
+    pub junction_table: String,
+}
+
+/// Which entities reference which, sorted by name for stable output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// Project name, used as the DOT graph name
+    pub name: String,
+    /// All entity names
+    pub entities: Vec<String>,
+    /// Foreign keys between entities
+    pub dependencies: Vec<Dependency>,
+    /// Many-to-many links
+    pub junctions: Vec<JunctionLink>,
+}
+
+impl DependencyGraph {
+    /// Build the graph from a project's FK fields and many-to-many relationships
+    pub fn of(project: &ProjectGraph) -> Self {
+        let entity_name = |id| project.get_entity(id).map(|e| e.name.clone());
+
+        let mut entities: Vec<String> = project.entities.values().map(|e| e.name.clone()).collect();
+        entities.sort();
+
+        let mut dependencies: Vec<Dependency> = project
+            .entities
+            .values()
+            .flat_map(|entity| {
+                entity.fields.iter().filter_map(|field| {
+                    let fk = field.foreign_key_ref.as_ref()?;
+                    Some(Dependency {
+                        from: entity.name.clone(),
+                        to: entity_name(fk.entity_id).unwrap_or_else(|| fk.entity_name.clone()),
+                        field: field.name.clone(),
+                    })
+                })
+            })
+            .collect();
+        dependencies.sort();
+
+        let mut junctions: Vec<JunctionLink> = project
+            .relationships
+            .values()
+            .filter_map(|r| {
+                let RelationType::ManyToMany { junction_table } = &r.relation_type else {
+                    return None;
+                };
+                Some(JunctionLink {
+                    left: entity_name(r.from_entity_id)?,
+                    right: entity_name(r.to_entity_id)?,
+                    junction_table: junction_table.clone(),
+                })
+            })
+            .collect();
+        junctions.sort();
+
+        Self {
+            name: project.meta.name.clone(),
+            entities,
+            dependencies,
+            junctions,
+        }
+    }
+
+    /// Entities that reference no other entity
+    pub fn roots(&self) -> Vec<&str> {
+        self.entities
+            .iter()
+            .filter(|name| {
+                !self
+                    .dependencies
+                    .iter()
+                    .any(|d| &d.from == *name && &d.to != *name)
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Render as a Graphviz `digraph`, with edges pointing at the referenced entity
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph {} {{", dot_id(&self.name));
+        out.push_str("    rankdir=LR;\n");
+        out.push_str("    node [shape=box];\n");
+
+        for entity in &self.entities {
+            let _ = writeln!(out, "    {};", dot_id(entity));
+        }
+        for dep in &self.dependencies {
+            let _ = writeln!(
+                out,
+                "    {} -> {} [label={}];",
+                dot_id(&dep.from),
+                dot_id(&dep.to),
+                dot_id(&dep.field)
+            );
+        }
+        for link in &self.junctions {
+            let _ = writeln!(
+                out,
+                "    {} -> {} [label={}, dir=both, style=dashed];",
+                dot_id(&link.left),
+                dot_id(&link.right),
+                dot_id(&link.junction_table)
+            );
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render as plain text: each entity followed by the entities it references
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::new();
+
+        for entity in &self.entities {
+            let _ = writeln!(out, "{}", entity);
+            let deps: Vec<&Dependency> = self
+                .dependencies
+                .iter()
+                .filter(|d| &d.from == entity)
+                .collect();
+            for (i, dep) in deps.iter().enumerate() {
+                let branch = if i + 1 == deps.len() {
+                    "└─"
+                } else {
+                    "├─"
+                };
+                let _ = writeln!(out, "{}▶ {} ({})", branch, dep.to, dep.field);
+            }
+        }
+
+        if !self.junctions.is_empty() {
+            out.push_str("\nMany-to-many:\n");
+            for link in &self.junctions {
+                let _ = writeln!(
+                    out,
+                    "{} ◀─▶ {} (via {})",
+                    link.left, link.right, link.junction_table
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/// Quote an identifier for DOT
+fn dot_id(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EndpointGroup, Entity, Relationship};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("blog");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        let comment = project.add_entity(Entity::new("Comment"));
+        for (from, to) in [(user, post), (post, comment), (user, comment)] {
+            project
+                .create_relationship_with_fk(Relationship::one_to_many(from, to))
+                .unwrap();
+        }
+        project
+            .create_relationship_with_fk(Relationship::many_to_many(post, tag, "post_tags"))
+            .unwrap();
+        project.add_endpoint(EndpointGroup::new(user, "User"));
+        project
+    }
+
+    #[test]
+    fn test_project_stats() {
+        let project = blog();
+        let stats = ProjectStats::of(&project);
+
+        assert_eq!(stats.entities, 4);
+        assert_eq!(stats.relationships, 4);
+        assert_eq!(stats.relationships_by_type.get("One to Many"), Some(&3));
+        assert_eq!(stats.relationships_by_type.get("Many to Many"), Some(&1));
+        assert_eq!(stats.endpoints, 1);
+        assert_eq!(stats.routes, 5);
+        assert_eq!(stats.queries, 0);
+        // Each entity has an id; Post and Comment gain FK fields
+        assert_eq!(stats.fields, 4 + 3);
+    }
+
+    #[test]
+    fn test_dependency_graph_ascii() {
+        let graph = DependencyGraph::of(&blog());
+        assert_eq!(graph.roots(), vec!["Tag", "User"]);
+        assert_eq!(
+            graph.to_ascii(),
+            "Comment\n\
+             ├─▶ Post (post_id)\n\
+             └─▶ User (user_id)\n\
+             Post\n\
+             └─▶ User (user_id)\n\
+             Tag\n\
+             User\n\
+             \n\
+             Many-to-many:\n\
+             Post ◀─▶ Tag (via post_tags)\n"
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph_dot() {
+        let dot = DependencyGraph::of(&blog()).to_dot();
+        assert!(dot.starts_with("digraph \"blog\" {\n"));
+        assert!(dot.contains("    \"Comment\" -> \"Post\" [label=\"post_id\"];\n"));
+        assert!(dot.contains("\"Post\" -> \"Tag\" [label=\"post_tags\", dir=both, style=dashed];"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot_id("a\"b"), "\"a\\\"b\"");
+    }
+}
@@ -0,0 +1,790 @@
+//! # Entity Suggestions
+//!
+//! Hook points for assistants that propose fields, descriptions and
+//! validations while an entity is being designed.
+//!
+//! A [`SuggestionProvider`] looks at an entity (often just its name, while
+//! it is being created) and returns [`Suggestion`]s. The editor shows them
+//! as chips the user can accept or dismiss; accepting one calls
+//! [`Suggestion::apply`]. Providers can be anything — the built-in
+//! [`RuleBasedProvider`] works from well-known entity and field names, and
+//! an external provider (a language model, a company schema catalog, ...)
+//! can be registered next to it on a [`Suggester`].
+//!
+//! Providers don't need to check what the entity already has: the
+//! [`Suggester`] drops suggestions that are already applied and duplicates
+//! offered by more than one provider.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::Entity;
+//! use imortal_ir::suggestions::Suggester;
+//!
+//! let entity = Entity::new("Customer");
+//! let suggestions = Suggester::with_default_providers().suggest(&entity);
+//! assert!(suggestions.iter().any(|s| s.key() == "field:email"));
+//!
+//! let mut entity = entity;
+//! for suggestion in &suggestions {
+//!     suggestion.apply(&mut entity);
+//! }
+//! assert!(entity.has_field("email"));
+//! ```
+
+use std::collections::HashSet;
+
+use imortal_core::{DataType, Validation};
+
+use crate::{DefaultValue, Entity, Field};
+
+// ============================================================================
+// Suggestion
+// ============================================================================
+
+/// What a suggestion would change on the entity
+#[derive(Debug, Clone, PartialEq)]
+pub enum SuggestionKind {
+    /// Add a field
+    Field(Box<Field>),
+    /// Set the entity description
+    EntityDescription(String),
+    /// Set the description of an existing field
+    FieldDescription { field: String, description: String },
+    /// Add a validation rule to an existing field
+    Validation {
+        field: String,
+        validation: Validation,
+    },
+}
+
+/// A single change proposed by a [`SuggestionProvider`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// The proposed change
+    pub kind: SuggestionKind,
+
+    /// Why it was suggested, shown as a tooltip
+    pub reason: String,
+
+    /// Name of the provider that made the suggestion
+    pub source: String,
+}
+
+impl Suggestion {
+    /// Create a suggestion
+    pub fn new(kind: SuggestionKind, reason: impl Into<String>) -> Self {
+        Self {
+            kind,
+            reason: reason.into(),
+            source: String::new(),
+        }
+    }
+
+    /// Suggest adding a field
+    pub fn field(field: Field, reason: impl Into<String>) -> Self {
+        Self::new(SuggestionKind::Field(Box::new(field)), reason)
+    }
+
+    /// Suggest an entity description
+    pub fn entity_description(description: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::new(
+            SuggestionKind::EntityDescription(description.into()),
+            reason,
+        )
+    }
+
+    /// Suggest a description for an existing field
+    pub fn field_description(
+        field: impl Into<String>,
+        description: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            SuggestionKind::FieldDescription {
+                field: field.into(),
+                description: description.into(),
+            },
+            reason,
+        )
+    }
+
+    /// Suggest a validation rule for an existing field
+    pub fn validation(
+        field: impl Into<String>,
+        validation: Validation,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            SuggestionKind::Validation {
+                field: field.into(),
+                validation,
+            },
+            reason,
+        )
+    }
+
+    /// Stable identity used to de-duplicate suggestions and remember
+    /// dismissed ones. Two suggestions with the same key change the same
+    /// thing (`field:email`, `description`, `validation:email:Email`, ...).
+    pub fn key(&self) -> String {
+        match &self.kind {
+            SuggestionKind::Field(field) => format!("field:{}", field.name),
+            SuggestionKind::EntityDescription(_) => "description".to_string(),
+            SuggestionKind::FieldDescription { field, .. } => format!("description:{field}"),
+            SuggestionKind::Validation { field, validation } => {
+                format!("validation:{field}:{}", validation_name(validation))
+            }
+        }
+    }
+
+    /// Short text for the suggestion chip
+    pub fn label(&self) -> String {
+        match &self.kind {
+            SuggestionKind::Field(field) => {
+                format!("+ {}: {}", field.name, field.data_type.display_name())
+            }
+            SuggestionKind::EntityDescription(description) => {
+                format!("Description: {description}")
+            }
+            SuggestionKind::FieldDescription { field, description } => {
+                format!("{field}: {description}")
+            }
+            SuggestionKind::Validation { field, validation } => {
+                format!("{field}: {}", validation.error_message())
+            }
+        }
+    }
+
+    /// Check whether the entity already reflects this suggestion
+    pub fn is_applied(&self, entity: &Entity) -> bool {
+        match &self.kind {
+            SuggestionKind::Field(field) => entity.has_field(&field.name),
+            SuggestionKind::EntityDescription(_) => entity
+                .description
+                .as_deref()
+                .is_some_and(|d| !d.trim().is_empty()),
+            SuggestionKind::FieldDescription { field, .. } => {
+                entity.get_field_by_name(field).is_none_or(|f| {
+                    f.description
+                        .as_deref()
+                        .is_some_and(|d| !d.trim().is_empty())
+                })
+            }
+            SuggestionKind::Validation { field, validation } => {
+                entity.get_field_by_name(field).is_none_or(|f| {
+                    let name = validation_name(validation);
+                    f.validations.iter().any(|v| validation_name(v) == name)
+                })
+            }
+        }
+    }
+
+    /// Apply the suggestion to the entity. Returns `false` when there was
+    /// nothing to do (already applied, or the target field is gone).
+    pub fn apply(&self, entity: &mut Entity) -> bool {
+        if self.is_applied(entity) {
+            return false;
+        }
+        match &self.kind {
+            SuggestionKind::Field(field) => {
+                entity.add_field(field.as_ref().clone());
+            }
+            SuggestionKind::EntityDescription(description) => {
+                entity.description = Some(description.clone());
+            }
+            SuggestionKind::FieldDescription { field, description } => {
+                if let Some(f) = entity.get_field_by_name_mut(field) {
+                    f.description = Some(description.clone());
+                }
+            }
+            SuggestionKind::Validation { field, validation } => {
+                if let Some(f) = entity.get_field_by_name_mut(field) {
+                    f.validations.push(validation.clone());
+                }
+            }
+        }
+        entity.touch();
+        true
+    }
+}
+
+/// Validation variant name, so `MaxLength(50)` and `MaxLength(255)` count
+/// as the same rule
+fn validation_name(validation: &Validation) -> &'static str {
+    match validation {
+        Validation::Required => "Required",
+        Validation::MinLength(_) => "MinLength",
+        Validation::MaxLength(_) => "MaxLength",
+        Validation::Min(_) => "Min",
+        Validation::Max(_) => "Max",
+        Validation::Pattern { .. } => "Pattern",
+        Validation::Email => "Email",
+        Validation::Url => "Url",
+        Validation::Uuid => "Uuid",
+        Validation::Phone => "Phone",
+        Validation::OneOf(_) => "OneOf",
+        Validation::Custom { .. } => "Custom",
+    }
+}
+
+// ============================================================================
+// SuggestionProvider Trait
+// ============================================================================
+
+/// A source of suggestions for an entity.
+///
+/// Implementations are called from the UI thread every time the entity
+/// name changes, so they should answer quickly; a provider backed by a
+/// remote service is expected to cache or pre-fetch its answers.
+pub trait SuggestionProvider: Send + Sync {
+    /// Get the provider name, shown next to its suggestions
+    fn name(&self) -> &'static str;
+
+    /// Suggest changes for the entity. The entity may have no fields yet.
+    fn suggest(&self, entity: &Entity) -> Vec<Suggestion>;
+}
+
+// ============================================================================
+// Suggester
+// ============================================================================
+
+/// Collects suggestions from several providers
+#[derive(Default)]
+pub struct Suggester {
+    providers: Vec<Box<dyn SuggestionProvider>>,
+}
+
+impl Suggester {
+    /// Create a suggester without providers
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+        }
+    }
+
+    /// Create a suggester with the built-in rule-based provider
+    pub fn with_default_providers() -> Self {
+        let mut suggester = Self::new();
+        suggester.add_provider(Box::new(RuleBasedProvider));
+        suggester
+    }
+
+    /// Add a provider. Earlier providers win when two suggest the same change.
+    pub fn add_provider(&mut self, provider: Box<dyn SuggestionProvider>) {
+        self.providers.push(provider);
+    }
+
+    /// Names of the registered providers
+    pub fn provider_names(&self) -> Vec<&'static str> {
+        self.providers.iter().map(|p| p.name()).collect()
+    }
+
+    /// Gather suggestions from every provider, dropping ones the entity
+    /// already reflects and duplicates
+    pub fn suggest(&self, entity: &Entity) -> Vec<Suggestion> {
+        let mut seen = HashSet::new();
+        let mut suggestions = Vec::new();
+        for provider in &self.providers {
+            for mut suggestion in provider.suggest(entity) {
+                if suggestion.is_applied(entity) || !seen.insert(suggestion.key()) {
+                    continue;
+                }
+                suggestion.source = provider.name().to_string();
+                suggestions.push(suggestion);
+            }
+        }
+        suggestions
+    }
+}
+
+// ============================================================================
+// Rule-Based Provider
+// ============================================================================
+
+/// Suggests fields and descriptions from well-known entity names (User,
+/// Product, Order, ...) and validations and descriptions from well-known
+/// field names (email, url, price, ...). Works offline and deterministically.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleBasedProvider;
+
+impl SuggestionProvider for RuleBasedProvider {
+    fn name(&self) -> &'static str {
+        "Rules"
+    }
+
+    fn suggest(&self, entity: &Entity) -> Vec<Suggestion> {
+        let mut suggestions = Vec::new();
+
+        if let Some(rule) = entity_rule(&entity.name) {
+            let reason = format!("Common for {} entities", rule.kind);
+            suggestions.push(Suggestion::entity_description(
+                rule.description,
+                reason.clone(),
+            ));
+            for field in (rule.fields)() {
+                suggestions.push(Suggestion::field(field, reason.clone()));
+            }
+        }
+
+        for field in &entity.fields {
+            if field.is_pk() || field.is_fk() {
+                continue;
+            }
+            let name = field.name.to_lowercase();
+            for validation in field_validations(&name, &field.data_type) {
+                suggestions.push(Suggestion::validation(
+                    &field.name,
+                    validation,
+                    format!("Usual rule for a '{}' field", field.name),
+                ));
+            }
+            if let Some(description) = field_description(&name) {
+                suggestions.push(Suggestion::field_description(
+                    &field.name,
+                    description,
+                    format!("Well-known field name '{}'", field.name),
+                ));
+            }
+        }
+
+        suggestions
+    }
+}
+
+/// Fields and description suggested for a family of entity names
+struct EntityRule {
+    /// Human name of the family, used in the reason text
+    kind: &'static str,
+    /// Last words of entity names in the family (singular, snake_case)
+    names: &'static [&'static str],
+    description: &'static str,
+    fields: fn() -> Vec<Field>,
+}
+
+const ENTITY_RULES: &[EntityRule] = &[
+    EntityRule {
+        kind: "user",
+        names: &[
+            "user", "account", "member", "customer", "person", "employee", "author",
+        ],
+        description: "A person who can sign in to the application",
+        fields: || {
+            vec![
+                email(),
+                string("name", 100),
+                secret_password(),
+                Field::new("email_verified", DataType::Bool)
+                    .required()
+                    .with_default(DefaultValue::Bool(false)),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "post",
+        names: &["post", "article", "page", "story", "entry"],
+        description: "A piece of published content",
+        fields: || {
+            vec![
+                string("title", 200),
+                slug(),
+                Field::new("body", DataType::Text).required(),
+                Field::new("published_at", DataType::DateTime),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "comment",
+        names: &["comment", "reply", "message", "note"],
+        description: "A short text written in reply to something",
+        fields: || {
+            vec![
+                Field::new("body", DataType::Text)
+                    .required()
+                    .with_validation(Validation::MaxLength(5000)),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "review",
+        names: &["review", "rating", "feedback"],
+        description: "A customer's rating with an optional comment",
+        fields: || {
+            vec![
+                Field::new("rating", DataType::Int32)
+                    .required()
+                    .with_validation(Validation::Min(1.0))
+                    .with_validation(Validation::Max(5.0)),
+                Field::new("body", DataType::Text),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "product",
+        names: &["product", "item", "sku", "listing"],
+        description: "Something offered for sale",
+        fields: || {
+            vec![
+                string("name", 200),
+                string("sku", 64).unique(),
+                Field::new("description", DataType::Text),
+                money("price"),
+                Field::new("stock", DataType::Int32)
+                    .required()
+                    .with_default(DefaultValue::Int(0))
+                    .with_validation(Validation::Min(0.0)),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "order",
+        names: &["order", "purchase", "invoice"],
+        description: "A purchase placed by a customer",
+        fields: || {
+            vec![
+                status("pending", &["pending", "paid", "shipped", "cancelled"]),
+                money("total"),
+                Field::new("placed_at", DataType::DateTime)
+                    .required()
+                    .with_default(DefaultValue::Now),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "category",
+        names: &["category", "tag", "label", "topic", "genre"],
+        description: "A label used to group related records",
+        fields: || vec![string("name", 50).unique(), slug()],
+    },
+    EntityRule {
+        kind: "address",
+        names: &["address", "location"],
+        description: "A postal address",
+        fields: || {
+            vec![
+                string("street", 200),
+                string("city", 100),
+                Field::new("postal_code", DataType::String)
+                    .required()
+                    .with_validation(Validation::MaxLength(20)),
+                string("country", 2).with_validation(Validation::MinLength(2)),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "event",
+        names: &["event", "meeting", "appointment", "booking", "reservation"],
+        description: "Something scheduled to happen at a given time",
+        fields: || {
+            vec![
+                string("title", 200),
+                Field::new("starts_at", DataType::DateTime).required(),
+                Field::new("ends_at", DataType::DateTime).required(),
+                Field::new("location", DataType::String),
+            ]
+        },
+    },
+    EntityRule {
+        kind: "company",
+        names: &["company", "organization", "organisation", "team", "tenant"],
+        description: "An organization that groups users",
+        fields: || {
+            vec![
+                string("name", 200),
+                slug(),
+                Field::new("website", DataType::String).with_validation(Validation::Url),
+            ]
+        },
+    },
+];
+
+/// Find the rule for an entity name, matching on its last word
+/// (`BlogPost` → `post`, `OrderItems` → `item`)
+fn entity_rule(entity_name: &str) -> Option<&'static EntityRule> {
+    let snake = to_snake_case(entity_name.trim());
+    let last = snake.rsplit('_').next().unwrap_or_default();
+    if last.is_empty() {
+        return None;
+    }
+    let singular = singularize(last);
+    ENTITY_RULES
+        .iter()
+        .find(|rule| rule.names.contains(&singular.as_str()))
+}
+
+/// Validations usually wanted on a field with this name and type
+fn field_validations(name: &str, data_type: &DataType) -> Vec<Validation> {
+    let base = match data_type {
+        DataType::Optional(inner) => inner.as_ref(),
+        other => other,
+    };
+    let text = matches!(base, DataType::String | DataType::Text);
+    let numeric = matches!(
+        base,
+        DataType::Int32 | DataType::Int64 | DataType::Float32 | DataType::Float64
+    ) || matches!(base, DataType::Decimal { .. });
+    let has_token = |tokens: &[&str]| name.split('_').any(|t| tokens.contains(&t));
+
+    let mut validations = Vec::new();
+    if text {
+        if has_token(&["email"]) {
+            validations.push(Validation::Email);
+        } else if has_token(&["url", "website", "homepage", "link"]) {
+            validations.push(Validation::Url);
+        } else if has_token(&["phone", "mobile", "tel"]) {
+            validations.push(Validation::Phone);
+        } else if has_token(&["password", "passwd"]) {
+            validations.push(Validation::MinLength(8));
+        } else if name == "slug" {
+            validations.push(slug_pattern());
+        }
+        if *base == DataType::String {
+            validations.push(Validation::MaxLength(255));
+        }
+    } else if numeric {
+        if has_token(&["rating", "stars"]) {
+            validations.push(Validation::Min(1.0));
+            validations.push(Validation::Max(5.0));
+        } else if has_token(&[
+            "price", "amount", "total", "cost", "quantity", "stock", "count", "age",
+        ]) {
+            validations.push(Validation::Min(0.0));
+        }
+    }
+    validations
+}
+
+/// Description for a well-known field name
+fn field_description(name: &str) -> Option<&'static str> {
+    let description = match name {
+        "email" => "Email address, used to sign in and for notifications",
+        "name" => "Display name",
+        "first_name" => "Given name",
+        "last_name" => "Family name",
+        "username" => "Unique handle chosen by the user",
+        "password" | "password_hash" => "Password, stored as a bcrypt hash",
+        "phone" | "phone_number" => "Phone number in international format",
+        "title" => "Title shown in lists and headings",
+        "slug" => "URL-friendly identifier, unique per record",
+        "body" | "content" => "Main text content",
+        "description" => "Longer free-text description",
+        "price" => "Unit price in the store currency",
+        "total" => "Total amount in the store currency",
+        "quantity" => "Number of units",
+        "stock" => "Units available for sale",
+        "sku" => "Stock keeping unit code",
+        "status" => "Current lifecycle state",
+        "url" | "website" => "Web address",
+        "published_at" => "When the record was published; empty while it is a draft",
+        "starts_at" => "When it begins",
+        "ends_at" => "When it ends",
+        "expires_at" => "When it stops being valid",
+        "rating" => "Score from 1 to 5",
+        "country" => "ISO 3166-1 alpha-2 country code",
+        _ => return None,
+    };
+    Some(description)
+}
+
+// ============================================================================
+// Field Builders
+// ============================================================================
+
+fn email() -> Field {
+    Field::new("email", DataType::String)
+        .required()
+        .unique()
+        .with_validation(Validation::Email)
+}
+
+fn string(name: &str, max: usize) -> Field {
+    Field::new(name, DataType::String)
+        .required()
+        .with_validation(Validation::MaxLength(max))
+}
+
+fn slug() -> Field {
+    string("slug", 200).unique().with_validation(slug_pattern())
+}
+
+fn slug_pattern() -> Validation {
+    Validation::Pattern {
+        regex: "^[a-z0-9]+(?:-[a-z0-9]+)*$".to_string(),
+        message: "must be lowercase words separated by hyphens".to_string(),
+    }
+}
+
+fn secret_password() -> Field {
+    let mut field = Field::new("password_hash", DataType::String).required();
+    field.apply_secret_recommendations();
+    field
+}
+
+fn money(name: &str) -> Field {
+    Field::new(name, DataType::money())
+        .required()
+        .with_validation(Validation::Min(0.0))
+}
+
+fn status(default: &str, values: &[&str]) -> Field {
+    Field::new("status", DataType::String)
+        .required()
+        .indexed()
+        .with_default(DefaultValue::String(default.to_string()))
+        .with_validation(Validation::OneOf(
+            values.iter().map(|v| v.to_string()).collect(),
+        ))
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.push(c.to_lowercase().next().unwrap());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Naive English singular for the words used in the rules
+fn singularize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if let Some(stem) = word.strip_suffix("sses") {
+        format!("{stem}ss")
+    } else if word.ends_with("ss") || word.ends_with("us") {
+        word.to_string()
+    } else if let Some(stem) = word.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity_rule_matches_last_word_and_plurals() {
+        assert_eq!(entity_rule("User").map(|r| r.kind), Some("user"));
+        assert_eq!(entity_rule("BlogPost").map(|r| r.kind), Some("post"));
+        assert_eq!(entity_rule("Categories").map(|r| r.kind), Some("category"));
+        assert_eq!(entity_rule("Addresses").map(|r| r.kind), Some("address"));
+        assert!(entity_rule("Widget").is_none());
+        assert!(entity_rule("").is_none());
+    }
+
+    #[test]
+    fn test_rule_based_fields_for_new_entity() {
+        let entity = Entity::new("Product");
+        let suggestions = Suggester::with_default_providers().suggest(&entity);
+        let keys: Vec<String> = suggestions.iter().map(|s| s.key()).collect();
+
+        assert!(keys.contains(&"description".to_string()));
+        assert!(keys.contains(&"field:price".to_string()));
+        assert!(keys.contains(&"field:sku".to_string()));
+        // The primary key already exists, so nothing touches it
+        assert!(suggestions.iter().all(|s| s.source == "Rules"));
+    }
+
+    #[test]
+    fn test_suggester_skips_applied_suggestions() {
+        let mut entity = Entity::new("User");
+        entity.description = Some("Anyone".to_string());
+        entity.add_field(email());
+
+        let keys: Vec<String> = Suggester::with_default_providers()
+            .suggest(&entity)
+            .iter()
+            .map(|s| s.key())
+            .collect();
+        assert!(!keys.contains(&"description".to_string()));
+        assert!(!keys.contains(&"field:email".to_string()));
+        assert!(!keys.iter().any(|k| k.starts_with("validation:email:Email")));
+        assert!(keys.contains(&"field:password_hash".to_string()));
+    }
+
+    #[test]
+    fn test_field_validations_and_descriptions() {
+        let mut entity = Entity::new("Widget");
+        entity.add_field(Field::new("website", DataType::String));
+        entity.add_field(Field::new("rating", DataType::Int32));
+
+        let suggestions = Suggester::with_default_providers().suggest(&entity);
+        let keys: Vec<String> = suggestions.iter().map(|s| s.key()).collect();
+        assert!(keys.contains(&"validation:website:Url".to_string()));
+        assert!(keys.contains(&"validation:website:MaxLength".to_string()));
+        assert!(keys.contains(&"validation:rating:Min".to_string()));
+        assert!(keys.contains(&"validation:rating:Max".to_string()));
+        assert!(keys.contains(&"description:website".to_string()));
+        assert!(!keys.iter().any(|k| k.starts_with("validation:id:")));
+    }
+
+    #[test]
+    fn test_apply_suggestions() {
+        let mut entity = Entity::new("Widget");
+        entity.add_field(Field::new("email", DataType::String));
+
+        let add = Suggestion::field(Field::new("name", DataType::String), "test");
+        assert!(add.apply(&mut entity));
+        assert!(entity.has_field("name"));
+        assert!(!add.apply(&mut entity));
+
+        let rule = Suggestion::validation("email", Validation::Email, "test");
+        assert!(rule.apply(&mut entity));
+        assert!(rule.is_applied(&entity));
+
+        let missing = Suggestion::validation("nope", Validation::Email, "test");
+        assert!(!missing.apply(&mut entity));
+
+        let described = Suggestion::entity_description("A widget", "test");
+        assert!(described.apply(&mut entity));
+        assert_eq!(entity.description.as_deref(), Some("A widget"));
+    }
+
+    struct FixedProvider;
+
+    impl SuggestionProvider for FixedProvider {
+        fn name(&self) -> &'static str {
+            "Fixed"
+        }
+
+        fn suggest(&self, _entity: &Entity) -> Vec<Suggestion> {
+            vec![
+                Suggestion::entity_description("From the fixed provider", "test"),
+                Suggestion::field(Field::new("color", DataType::String), "test"),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_custom_provider_and_deduplication() {
+        let mut suggester = Suggester::new();
+        suggester.add_provider(Box::new(FixedProvider));
+        suggester.add_provider(Box::new(RuleBasedProvider));
+        assert_eq!(suggester.provider_names(), vec!["Fixed", "Rules"]);
+
+        let suggestions = suggester.suggest(&Entity::new("Product"));
+        let descriptions: Vec<&Suggestion> = suggestions
+            .iter()
+            .filter(|s| s.key() == "description")
+            .collect();
+        assert_eq!(descriptions.len(), 1);
+        assert_eq!(descriptions[0].source, "Fixed");
+        assert!(suggestions.iter().any(|s| s.key() == "field:color"));
+    }
+}
@@ -0,0 +1,629 @@
+//! # Project Templates
+//!
+//! Starter projects used by `imortal new --template <name>`.
+//!
+//! Each template is a small but complete [`ProjectGraph`]: entities with
+//! realistic fields, relationships created through
+//! [`ProjectGraph::create_relationship_with_fk`] (so foreign key fields are
+//! added the same way the editor adds them), an endpoint group per entity and
+//! a tidy canvas layout. Templates are built fresh on every call, so all IDs
+//! are new.
+//!
+//! | Template    | Entities                                          |
+//! |-------------|---------------------------------------------------|
+//! | `blank`     | none                                              |
+//! | `blog`      | User, Post, Comment, Tag                          |
+//! | `ecommerce` | Customer, Category, Product, Order, OrderItem     |
+//! | `saas`      | Organization, User, Membership, Project, Invitation |
+//!
+//! ## Example
+//!
+//! ```rust
+//! use imortal_ir::templates::ProjectTemplate;
+//!
+//! let template: ProjectTemplate = "blog".parse().unwrap();
+//! let project = template.build("My Blog");
+//! assert_eq!(project.meta.name, "My Blog");
+//! assert!(project.get_entity_by_name("Post").is_some());
+//! ```
+
+use std::str::FromStr;
+
+use imortal_core::{DataType, EngineError, EngineResult, Position, ReferentialAction, Validation};
+use uuid::Uuid;
+
+use crate::relationship_helpers::generate_junction_table_name;
+use crate::{
+    AuthConfig, AuthPrincipal, DefaultValue, EndpointSecurity, Entity, Field, OperationType,
+    ProjectGraph, Relationship,
+};
+
+/// Canvas column width used when laying out template entities
+const GRID_X: f32 = 340.0;
+
+/// Canvas row height used when laying out template entities
+const GRID_Y: f32 = 320.0;
+
+/// Canvas offset of the first entity
+const GRID_ORIGIN: f32 = 100.0;
+
+/// Operations that change data
+const WRITE_OPERATIONS: [OperationType; 3] = [
+    OperationType::Create,
+    OperationType::Update,
+    OperationType::Delete,
+];
+
+// ============================================================================
+// ProjectTemplate
+// ============================================================================
+
+/// A starter project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProjectTemplate {
+    /// An empty project
+    #[default]
+    Blank,
+    /// Users writing posts, with comments and tags
+    Blog,
+    /// A shop with a product catalogue and orders
+    Ecommerce,
+    /// A multi-tenant application with organizations and memberships
+    Saas,
+}
+
+impl ProjectTemplate {
+    /// Get all templates
+    pub fn all() -> &'static [ProjectTemplate] {
+        &[
+            ProjectTemplate::Blank,
+            ProjectTemplate::Blog,
+            ProjectTemplate::Ecommerce,
+            ProjectTemplate::Saas,
+        ]
+    }
+
+    /// Identifier used on the command line
+    pub fn key(&self) -> &'static str {
+        match self {
+            ProjectTemplate::Blank => "blank",
+            ProjectTemplate::Blog => "blog",
+            ProjectTemplate::Ecommerce => "ecommerce",
+            ProjectTemplate::Saas => "saas",
+        }
+    }
+
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProjectTemplate::Blank => "Blank",
+            ProjectTemplate::Blog => "Blog",
+            ProjectTemplate::Ecommerce => "E-commerce",
+            ProjectTemplate::Saas => "SaaS",
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            ProjectTemplate::Blank => "An empty project",
+            ProjectTemplate::Blog => "Users, posts, comments and tags",
+            ProjectTemplate::Ecommerce => "Customers, a product catalogue and orders",
+            ProjectTemplate::Saas => "Organizations, members, projects and invitations",
+        }
+    }
+
+    /// Build a new project from this template
+    pub fn build(&self, name: impl Into<String>) -> ProjectGraph {
+        let name = name.into();
+        let mut project = ProjectGraph::new(name.clone());
+        project.meta.description = Some(self.description().to_string());
+        project.config.package_name = package_name_for(&name);
+
+        let result = match self {
+            ProjectTemplate::Blank => Ok(()),
+            ProjectTemplate::Blog => build_blog(&mut project),
+            ProjectTemplate::Ecommerce => build_ecommerce(&mut project),
+            ProjectTemplate::Saas => build_saas(&mut project),
+        };
+        // Templates only relate entities they have just added
+        result.expect("template relationships reference template entities");
+
+        for entity in project.entities.values_mut() {
+            entity.fit_content();
+        }
+        project
+    }
+}
+
+impl std::fmt::Display for ProjectTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+impl FromStr for ProjectTemplate {
+    type Err = EngineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = s.trim().to_lowercase();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|t| t.key() == key)
+            .ok_or_else(|| {
+                let known: Vec<&str> = Self::all().iter().map(|t| t.key()).collect();
+                EngineError::validation(format!(
+                    "Unknown template '{}' (expected one of: {})",
+                    s,
+                    known.join(", ")
+                ))
+            })
+    }
+}
+
+/// Cargo package name derived from a project name
+fn package_name_for(name: &str) -> String {
+    let package: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if package.is_empty() {
+        "my_app".to_string()
+    } else {
+        package
+    }
+}
+
+// ============================================================================
+// Builders
+// ============================================================================
+
+/// Canvas position of a grid cell
+fn grid_position(col: u8, row: u8) -> Position {
+    Position::new(
+        GRID_ORIGIN + f32::from(col) * GRID_X,
+        GRID_ORIGIN + f32::from(row) * GRID_Y,
+    )
+}
+
+/// Add an entity with timestamps at a grid cell, returning its ID
+fn add_entity(
+    project: &mut ProjectGraph,
+    name: &str,
+    description: &str,
+    (col, row): (u8, u8),
+    fields: Vec<Field>,
+) -> Uuid {
+    let position = grid_position(col, row);
+    let mut entity = Entity::with_timestamps(name)
+        .with_description(description)
+        .with_position(position);
+    for field in fields {
+        entity.add_field(field);
+    }
+    project.add_entity(entity)
+}
+
+/// Add a CRUD endpoint group next to its entity.
+///
+/// `public_reads` leaves list/get open and requires a login for writes;
+/// otherwise every operation requires a login.
+fn add_endpoint(project: &mut ProjectGraph, entity_id: Uuid, public_reads: bool) {
+    let entity = &project.entities[&entity_id];
+    let (name, position) = (entity.name.clone(), entity.position);
+
+    let mut endpoint = project
+        .new_endpoint(entity_id, &name)
+        .at(position.x + 30.0, position.y + 30.0);
+    if public_reads {
+        for op in WRITE_OPERATIONS {
+            endpoint.set_operation_security(op, EndpointSecurity::authenticated());
+        }
+    } else {
+        endpoint = endpoint.secured();
+    }
+    project.add_endpoint(endpoint);
+}
+
+fn email() -> Field {
+    Field::new("email", DataType::String)
+        .required()
+        .unique()
+        .with_validation(Validation::Email)
+}
+
+fn string(name: &str, max: usize) -> Field {
+    Field::new(name, DataType::String)
+        .required()
+        .with_validation(Validation::MaxLength(max))
+}
+
+fn status(default: &str, values: &[&str]) -> Field {
+    Field::new("status", DataType::String)
+        .required()
+        .indexed()
+        .with_default(DefaultValue::String(default.to_string()))
+        .with_validation(Validation::OneOf(
+            values.iter().map(|v| v.to_string()).collect(),
+        ))
+}
+
+/// A required field with the editor's recommended settings for secrets
+fn secret(name: &str) -> Field {
+    let mut field = Field::new(name, DataType::String).required();
+    field.apply_secret_recommendations();
+    field
+}
+
+/// A nullable column the payloads may leave out
+fn optional(name: &str, data_type: DataType) -> Field {
+    Field::new(name, DataType::Optional(Box::new(data_type)))
+}
+
+fn money(name: &str) -> Field {
+    Field::new(name, DataType::money())
+        .required()
+        .with_validation(Validation::Min(0.0))
+}
+
+fn build_blog(project: &mut ProjectGraph) -> EngineResult<()> {
+    let user = add_entity(
+        project,
+        "User",
+        "A registered author or reader",
+        (0, 0),
+        vec![
+            email(),
+            string("username", 50).unique(),
+            secret("password_hash"),
+            optional("bio", DataType::Text),
+        ],
+    );
+    let post = add_entity(
+        project,
+        "Post",
+        "An article written by a user",
+        (1, 0),
+        vec![
+            string("title", 200),
+            string("slug", 200).unique(),
+            Field::new("body", DataType::Text).required(),
+            Field::new("published", DataType::Bool)
+                .required()
+                .with_default(DefaultValue::Bool(false)),
+            optional("published_at", DataType::DateTime),
+        ],
+    );
+    let comment = add_entity(
+        project,
+        "Comment",
+        "A reader's reply to a post",
+        (1, 1),
+        vec![Field::new("body", DataType::Text).required()],
+    );
+    let tag = add_entity(
+        project,
+        "Tag",
+        "A label grouping related posts",
+        (2, 0),
+        vec![string("name", 50).unique()],
+    );
+
+    project.create_relationship_with_fk(Relationship::one_to_many(user, post))?;
+    project.create_relationship_with_fk(
+        Relationship::one_to_many(post, comment).on_delete(ReferentialAction::Cascade),
+    )?;
+    project.create_relationship_with_fk(Relationship::one_to_many(user, comment))?;
+    let (tags, _) = project.create_relationship_with_fk(Relationship::many_to_many(
+        post,
+        tag,
+        generate_junction_table_name("Post", "Tag"),
+    ))?;
+    // A junction entity gives the generated models a path between the two
+    let post_tag = project.promote_junction(tags)?;
+    if let Some(junction) = project.get_entity_mut(post_tag) {
+        junction.position = grid_position(2, 1);
+    }
+
+    add_endpoint(project, user, false);
+    for id in [post, comment, tag, post_tag] {
+        add_endpoint(project, id, true);
+    }
+    Ok(())
+}
+
+fn build_ecommerce(project: &mut ProjectGraph) -> EngineResult<()> {
+    let customer = add_entity(
+        project,
+        "Customer",
+        "Someone who places orders",
+        (0, 0),
+        vec![
+            email(),
+            string("name", 100),
+            optional("phone", DataType::String).with_validation(Validation::Phone),
+        ],
+    );
+    let category = add_entity(
+        project,
+        "Category",
+        "A section of the catalogue",
+        (2, 0),
+        vec![
+            string("name", 100).unique(),
+            optional("description", DataType::Text),
+        ],
+    );
+    let product = add_entity(
+        project,
+        "Product",
+        "An item for sale",
+        (2, 1),
+        vec![
+            string("name", 200),
+            string("sku", 64).unique(),
+            optional("description", DataType::Text),
+            money("price"),
+            Field::new("stock", DataType::Int32)
+                .required()
+                .with_default(DefaultValue::Int(0))
+                .with_validation(Validation::Min(0.0)),
+        ],
+    );
+    let order = add_entity(
+        project,
+        "Order",
+        "A customer's purchase",
+        (0, 1),
+        vec![
+            status(
+                "pending",
+                &["pending", "paid", "shipped", "delivered", "cancelled"],
+            ),
+            money("total"),
+            Field::new("placed_at", DataType::DateTime)
+                .required()
+                .with_default(DefaultValue::Now),
+        ],
+    );
+    let item = add_entity(
+        project,
+        "OrderItem",
+        "A product line within an order",
+        (1, 1),
+        vec![
+            Field::new("quantity", DataType::Int32)
+                .required()
+                .with_validation(Validation::Min(1.0)),
+            money("unit_price"),
+        ],
+    );
+
+    project.create_relationship_with_fk(Relationship::one_to_many(category, product))?;
+    project.create_relationship_with_fk(Relationship::one_to_many(customer, order))?;
+    project.create_relationship_with_fk(
+        Relationship::one_to_many(order, item).on_delete(ReferentialAction::Cascade),
+    )?;
+    project.create_relationship_with_fk(Relationship::one_to_many(product, item))?;
+
+    for id in [category, product] {
+        add_endpoint(project, id, true);
+    }
+    for id in [customer, order, item] {
+        add_endpoint(project, id, false);
+    }
+    Ok(())
+}
+
+fn build_saas(project: &mut ProjectGraph) -> EngineResult<()> {
+    project.config.auth = AuthConfig::jwt()
+        .with_principal(AuthPrincipal::new("User"))
+        .with_role("owner")
+        .with_role("admin")
+        .with_role("member");
+
+    let organization = add_entity(
+        project,
+        "Organization",
+        "A tenant; every other record belongs to one",
+        (1, 0),
+        vec![
+            string("name", 100),
+            string("slug", 100).unique(),
+            status("trial", &["trial", "active", "suspended"]).with_column_name("plan_status"),
+        ],
+    );
+    let user = add_entity(
+        project,
+        "User",
+        "A person who can sign in",
+        (0, 0),
+        vec![email(), string("name", 100), secret("password_hash")],
+    );
+    let membership = add_entity(
+        project,
+        "Membership",
+        "A user's role within an organization",
+        (0, 1),
+        vec![
+            Field::new("role", DataType::String)
+                .required()
+                .with_default(DefaultValue::String("member".to_string()))
+                .with_validation(Validation::OneOf(vec![
+                    "owner".to_string(),
+                    "admin".to_string(),
+                    "member".to_string(),
+                ])),
+        ],
+    );
+    let workspace_project = add_entity(
+        project,
+        "Project",
+        "Work owned by an organization",
+        (2, 0),
+        vec![
+            string("name", 100),
+            optional("description", DataType::Text),
+            Field::new("archived", DataType::Bool)
+                .required()
+                .with_default(DefaultValue::Bool(false)),
+        ],
+    );
+    let invitation = add_entity(
+        project,
+        "Invitation",
+        "A pending invite to join an organization",
+        (1, 1),
+        vec![
+            email(),
+            secret("token").unique(),
+            Field::new("expires_at", DataType::DateTime).required(),
+        ],
+    );
+
+    for child in [membership, workspace_project, invitation] {
+        project.create_relationship_with_fk(
+            Relationship::one_to_many(organization, child).on_delete(ReferentialAction::Cascade),
+        )?;
+    }
+    project.create_relationship_with_fk(
+        Relationship::one_to_many(user, membership).on_delete(ReferentialAction::Cascade),
+    )?;
+
+    for id in [
+        organization,
+        user,
+        membership,
+        workspace_project,
+        invitation,
+    ] {
+        add_endpoint(project, id, false);
+    }
+    for id in [organization, membership, invitation] {
+        if let Some(endpoint) = project.endpoints.values_mut().find(|e| e.entity_id == id) {
+            for op in WRITE_OPERATIONS {
+                endpoint.set_operation_security(
+                    op,
+                    EndpointSecurity::with_roles(vec!["owner".to_string(), "admin".to_string()]),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Validator;
+
+    #[test]
+    fn test_parse_template() {
+        assert_eq!(
+            "blog".parse::<ProjectTemplate>().unwrap(),
+            ProjectTemplate::Blog
+        );
+        assert_eq!(
+            " SaaS ".parse::<ProjectTemplate>().unwrap(),
+            ProjectTemplate::Saas
+        );
+        for template in ProjectTemplate::all() {
+            assert_eq!(
+                template.key().parse::<ProjectTemplate>().unwrap(),
+                *template
+            );
+        }
+
+        let err = "crm".parse::<ProjectTemplate>().unwrap_err();
+        assert!(err.to_string().contains("blog, ecommerce, saas"));
+    }
+
+    #[test]
+    fn test_blank_template() {
+        let project = ProjectTemplate::Blank.build("Empty");
+        assert!(project.is_empty());
+        assert_eq!(project.config.package_name, "empty");
+    }
+
+    #[test]
+    fn test_templates_are_valid() {
+        for template in ProjectTemplate::all() {
+            let project = template.build("My App");
+            let result = Validator::with_default_rules().validate(&project);
+            assert!(
+                !result.has_errors(),
+                "{} template: {:?}",
+                template,
+                result.errors
+            );
+            assert_eq!(project.config.package_name, "my_app");
+            assert_eq!(project.endpoint_count(), project.entity_count());
+            for entity in project.entities.values() {
+                for field in &entity.fields {
+                    assert!(
+                        field.sensitivity_issues().is_empty(),
+                        "{}.{}",
+                        entity.name,
+                        field.name
+                    );
+                    // A nullable column must be nullable in the model too
+                    assert!(
+                        field.required || matches!(field.data_type, DataType::Optional(_)),
+                        "{}.{}",
+                        entity.name,
+                        field.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_blog_template() {
+        let project = ProjectTemplate::Blog.build("blog");
+        assert_eq!(project.entity_count(), 5);
+        assert_eq!(project.relationship_count(), 6);
+
+        let post = project.get_entity_by_name("Post").unwrap();
+        assert!(post.has_field("user_id"));
+        let comment = project.get_entity_by_name("Comment").unwrap();
+        assert!(comment.has_field("post_id"));
+        assert!(comment.has_field("user_id"));
+        let post_tag = project.get_entity_by_name("PostTag").unwrap();
+        assert!(post_tag.has_field("post_id"));
+        assert!(post_tag.has_field("tag_id"));
+
+        // Reads are public, writes need a login
+        let endpoint = project.endpoint_for_entity(post.id).unwrap();
+        let read = endpoint.get_operation(OperationType::ReadAll).unwrap();
+        let create = endpoint.get_operation(OperationType::Create).unwrap();
+        assert!(!read.security.as_ref().is_some_and(|s| s.auth_required));
+        assert!(create.security.as_ref().unwrap().auth_required);
+    }
+
+    #[test]
+    fn test_entities_do_not_overlap() {
+        for template in ProjectTemplate::all() {
+            let project = template.build("p");
+            let entities: Vec<&Entity> = project.entities.values().collect();
+            for (i, a) in entities.iter().enumerate() {
+                for b in &entities[i + 1..] {
+                    assert!(
+                        !a.bounds().intersects(&b.bounds()),
+                        "{} overlaps {} in {}",
+                        a.name,
+                        b.name,
+                        template
+                    );
+                }
+            }
+        }
+    }
+}
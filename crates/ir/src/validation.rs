@@ -3,9 +3,18 @@
 //! This module provides validation capabilities for project graphs,
 //! entities, relationships, and endpoints.
 
-use crate::ProjectGraph;
-use imortal_core::{EngineError, EngineResult};
-use std::collections::HashSet;
+use crate::compatibility::check_compatibility;
+use crate::endpoint::is_valid_api_version;
+use crate::enums::enum_of;
+use crate::query::split_field_ref;
+use crate::relationship_helpers::{accessor_names, calculate_fk_info, junction_keys, scope_key};
+use crate::{
+    AuthStrategy, CorsConfig, Entity, Field, OAuthProviderKind, ProjectGraph, Relationship,
+};
+use heck::{ToPascalCase, ToSnakeCase};
+use imortal_core::{DataType, DatabaseType, EngineError, EngineResult};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
 
 // ============================================================================
 // ValidationResult
@@ -122,6 +131,9 @@ pub struct ValidationError {
 
     /// Suggested fix
     pub suggestion: Option<String>,
+
+    /// The element at fault, for jumping to it in the editor
+    pub target: Option<ValidationTarget>,
 }
 
 impl ValidationError {
@@ -132,6 +144,7 @@ impl ValidationError {
             message: message.into(),
             path: None,
             suggestion: None,
+            target: None,
         }
     }
 
@@ -146,6 +159,12 @@ impl ValidationError {
         self.suggestion = Some(suggestion.into());
         self
     }
+
+    /// Point the error at the element at fault
+    pub fn with_target(mut self, target: ValidationTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
 }
 
 impl std::fmt::Display for ValidationError {
@@ -158,6 +177,64 @@ impl std::fmt::Display for ValidationError {
     }
 }
 
+// ============================================================================
+// ValidationTarget
+// ============================================================================
+
+/// The project element a validation issue is about, by ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationTarget {
+    Entity(Uuid),
+    Field { entity_id: Uuid, field_id: Uuid },
+    Relationship(Uuid),
+    Endpoint(Uuid),
+}
+
+impl ValidationTarget {
+    /// Resolve an issue path such as `entities.User.fields.email` or
+    /// `endpoints.<id>.security` to the element it names.
+    ///
+    /// Entities may be named by name or ID. Paths outside entities,
+    /// relationships and endpoints (project config, queries, enums), and
+    /// paths to elements no longer in the project, resolve to `None`.
+    pub fn from_path(project: &ProjectGraph, path: &str) -> Option<Self> {
+        let mut parts = path.split('.');
+        let kind = parts.next()?;
+        let key = parts.next()?;
+        let key = key.split('[').next().unwrap_or(key);
+
+        match kind {
+            "entities" => {
+                let entity = key
+                    .parse()
+                    .ok()
+                    .and_then(|id| project.get_entity(id))
+                    .or_else(|| project.get_entity_by_name(key))?;
+                if parts.next() == Some("fields")
+                    && let Some(field) = parts.next().and_then(|f| entity.get_field_by_name(f))
+                {
+                    return Some(Self::Field {
+                        entity_id: entity.id,
+                        field_id: field.id,
+                    });
+                }
+                Some(Self::Entity(entity.id))
+            }
+            "relationships" => {
+                let id = key.parse().ok()?;
+                project
+                    .get_relationship(id)
+                    .map(|r| Self::Relationship(r.id))
+            }
+            "endpoints" => {
+                let id = key.parse().ok()?;
+                project.get_endpoint(id).map(|e| Self::Endpoint(e.id))
+            }
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // ValidationErrorCode
 // ============================================================================
@@ -171,6 +248,8 @@ pub enum ValidationErrorCode {
     DuplicateEntityName,
     NoFields,
     NoPrimaryKey,
+    InvalidConstraint,
+    InvalidTableOption,
 
     // Field errors
     EmptyFieldName,
@@ -178,21 +257,52 @@ pub enum ValidationErrorCode {
     DuplicateFieldName,
     InvalidFieldType,
     ForeignKeyMissingReference,
+    DanglingForeignKey,
 
     // Relationship errors
     InvalidRelationship,
     OrphanRelationship,
     DuplicateRelationship,
+    CircularDependency,
     MissingJunctionTable,
+    InvalidJunctionEntity,
+    InvalidAccessorName,
+    InvalidPolymorphic,
 
     // Endpoint errors
     InvalidEndpointPath,
     DuplicateEndpointPath,
     OrphanEndpoint,
+    UnknownRole,
+    InvalidRateLimit,
+    InvalidApiVersioning,
+    InvalidHttpCaching,
+    InvalidCors,
+
+    // Query errors
+    InvalidQuery,
+
+    // Enum errors
+    InvalidEnum,
+
+    // Field set errors
+    InvalidFieldSet,
+
+    // Computed field errors
+    InvalidComputedField,
+
+    // Upload field errors
+    InvalidUploadField,
+
+    // Auth errors
+    InvalidAuthPrincipal,
+    InvalidHashCost,
+    InvalidOAuthProvider,
 
     // Project errors
     EmptyProjectName,
     InvalidProjectName,
+    IncompatibleOptions,
 
     // Generic
     Custom,
@@ -213,6 +323,12 @@ pub struct ValidationWarning {
 
     /// Path to the element
     pub path: Option<String>,
+
+    /// The element the warning is about, for jumping to it in the editor
+    pub target: Option<ValidationTarget>,
+
+    /// Suggested fix
+    pub suggestion: Option<String>,
 }
 
 impl ValidationWarning {
@@ -222,6 +338,8 @@ impl ValidationWarning {
             code,
             message: message.into(),
             path: None,
+            target: None,
+            suggestion: None,
         }
     }
 
@@ -230,6 +348,18 @@ impl ValidationWarning {
         self.path = Some(path.into());
         self
     }
+
+    /// Add a suggestion to the warning
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Point the warning at the element it is about
+    pub fn with_target(mut self, target: ValidationTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
 }
 
 impl std::fmt::Display for ValidationWarning {
@@ -256,6 +386,10 @@ pub enum ValidationWarningCode {
     MissingIndex,
     WeakPassword,
     NoValidation,
+    SensitiveFieldExposed,
+    DeadConfiguration,
+    RenamedIdentifier,
+    UnsupportedColumnType,
     Custom,
 }
 
@@ -299,6 +433,24 @@ impl Validator {
         validator.add_rule(Box::new(RelationshipsRule));
         validator.add_rule(Box::new(EndpointsRule));
         validator.add_rule(Box::new(ProjectMetaRule));
+        validator.add_rule(Box::new(SensitiveFieldsRule));
+        validator.add_rule(Box::new(QueriesRule));
+        validator.add_rule(Box::new(ForeignKeyIndexesRule));
+        validator.add_rule(Box::new(GeneratedNamesRule));
+        validator.add_rule(Box::new(ColumnTypesRule));
+        validator.add_rule(Box::new(EndpointCoverageRule));
+        validator.add_rule(Box::new(AuthPrincipalRule));
+        validator.add_rule(Box::new(OAuthProvidersRule));
+        validator.add_rule(Box::new(CorsRule));
+        validator.add_rule(Box::new(EnumsRule));
+        validator.add_rule(Box::new(FieldSetsRule));
+        validator.add_rule(Box::new(ComputedFieldsRule));
+        validator.add_rule(Box::new(UploadFieldsRule));
+        validator.add_rule(Box::new(TableConstraintsRule));
+        validator.add_rule(Box::new(TableOptionsRule));
+        validator.add_rule(Box::new(CompatibilityRule));
+        validator.add_rule(Box::new(GraphIntegrityRule));
+        validator.add_rule(Box::new(PolymorphicRule));
         validator
     }
 
@@ -316,6 +468,25 @@ impl Validator {
             result.merge(rule_result);
         }
 
+        // Most rules only record a path; resolve it so every issue that
+        // names an element can be jumped to
+        for error in &mut result.errors {
+            if error.target.is_none() {
+                error.target = error
+                    .path
+                    .as_deref()
+                    .and_then(|path| ValidationTarget::from_path(project, path));
+            }
+        }
+        for warning in &mut result.warnings {
+            if warning.target.is_none() {
+                warning.target = warning
+                    .path
+                    .as_deref()
+                    .and_then(|path| ValidationTarget::from_path(project, path));
+            }
+        }
+
         result
     }
 
@@ -368,6 +539,22 @@ impl ValidationRule for EntityNamesRule {
                 );
             }
 
+            if let Some(schema) = entity.config.schema_name()
+                && !is_valid_identifier(schema)
+            {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidEntityName,
+                        format!(
+                            "Schema '{}' of entity '{}' is not a valid identifier",
+                            schema, entity.name
+                        ),
+                    )
+                    .with_path(format!("entities.{}.config.schema", entity.name))
+                    .with_suggestion("Use lowercase letters, numbers and underscores"),
+                );
+            }
+
             let lower_name = entity.name.to_lowercase();
             if seen_names.contains(&lower_name) {
                 result.add_error(
@@ -465,6 +652,27 @@ impl ValidationRule for EntityFieldsRule {
                 }
                 seen_fields.insert(lower_name);
 
+                // Decimal precision/scale out of range
+                if let DataType::Decimal { precision, scale } = field.data_type {
+                    let max = DataType::MAX_DECIMAL_PRECISION;
+                    if precision == 0 || precision > max || scale > precision {
+                        result.add_error(
+                            ValidationError::new(
+                                ValidationErrorCode::InvalidFieldType,
+                                format!(
+                                    "Decimal field '{}' has precision {} and scale {}",
+                                    field.name, precision, scale
+                                ),
+                            )
+                            .with_path(&field_path)
+                            .with_suggestion(format!(
+                                "Use a precision from 1 to {} and a scale no larger than the precision",
+                                max
+                            )),
+                        );
+                    }
+                }
+
                 // Foreign key without reference
                 if field.is_foreign_key && field.foreign_key_ref.is_none() {
                     result.add_error(
@@ -507,6 +715,8 @@ impl ValidationRule for RelationshipsRule {
 
     fn validate(&self, project: &ProjectGraph) -> ValidationResult {
         let mut result = ValidationResult::ok();
+        // Accessor names given by hand, per entity they are generated on
+        let mut accessors: HashMap<(Uuid, String), &str> = HashMap::new();
 
         for relationship in project.relationships.values() {
             let rel_path = format!("relationships.{}", relationship.id);
@@ -520,7 +730,9 @@ impl ValidationRule for RelationshipsRule {
                             relationship.name
                         ),
                     )
-                    .with_path(&rel_path),
+                    .with_path(&rel_path)
+                    .with_target(ValidationTarget::Relationship(relationship.id))
+                    .with_suggestion("Delete the relationship or reconnect it to an entity"),
                 );
             }
 
@@ -533,7 +745,9 @@ impl ValidationRule for RelationshipsRule {
                             relationship.name
                         ),
                     )
-                    .with_path(&rel_path),
+                    .with_path(&rel_path)
+                    .with_target(ValidationTarget::Relationship(relationship.id))
+                    .with_suggestion("Delete the relationship or reconnect it to an entity"),
                 );
             }
 
@@ -550,6 +764,82 @@ impl ValidationRule for RelationshipsRule {
                     }
                 }
             }
+
+            if let Some(junction_id) = relationship.junction_entity_id {
+                let message = match project.entities.get(&junction_id) {
+                    None => Some(format!(
+                        "Relationship '{}' links a junction entity that no longer exists",
+                        relationship.name
+                    )),
+                    Some(junction) if junction_keys(junction, relationship).is_none() => {
+                        Some(format!(
+                            "Junction entity '{}' of '{}' needs a foreign key to each side",
+                            junction.name, relationship.name
+                        ))
+                    }
+                    Some(_) => None,
+                };
+                if let Some(message) = message {
+                    result.add_error(
+                        ValidationError::new(ValidationErrorCode::InvalidJunctionEntity, message)
+                            .with_path(&rel_path)
+                            .with_target(ValidationTarget::Relationship(relationship.id))
+                            .with_suggestion(
+                                "Promote the junction again, or add the missing foreign keys",
+                            ),
+                    );
+                }
+            }
+
+            let (Some(from), Some(to)) = (
+                project.entities.get(&relationship.from_entity_id),
+                project.entities.get(&relationship.to_entity_id),
+            ) else {
+                continue;
+            };
+            let names = accessor_names(relationship, from, to);
+            let named = [
+                (
+                    &relationship.accessor_name,
+                    &names.accessor,
+                    names.accessor_entity_id,
+                ),
+                (
+                    &relationship.inverse_name,
+                    &names.inverse,
+                    names.inverse_entity_id,
+                ),
+            ];
+            for (stored, name, entity_id) in named {
+                if stored.as_deref().is_none_or(|n| n.trim().is_empty()) {
+                    continue;
+                }
+                let entity = if entity_id == from.id { from } else { to };
+                let message = if !is_valid_identifier(name) {
+                    format!("Accessor '{}' is not a valid identifier", name)
+                } else if entity.fields.iter().any(|f| f.name == *name) {
+                    format!(
+                        "Accessor '{}' clashes with field '{}.{}'",
+                        name, entity.name, name
+                    )
+                } else if let Some(other) =
+                    accessors.insert((entity_id, name.clone()), &relationship.name)
+                {
+                    format!(
+                        "Accessor '{}' on '{}' is also used by relationship '{}'",
+                        name, entity.name, other
+                    )
+                } else {
+                    continue;
+                };
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidAccessorName,
+                        format!("Relationship '{}': {}", relationship.name, message),
+                    )
+                    .with_path(&rel_path),
+                );
+            }
         }
 
         // Warning: no relationships
@@ -614,16 +904,66 @@ impl ValidationRule for EndpointsRule {
                 );
             }
 
-            if seen_paths.contains(&endpoint.base_path) {
+            if let Some(version) = &endpoint.api_version
+                && !is_valid_api_version(version)
+            {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidApiVersioning,
+                        format!("API version '{}' is not a valid path segment", version),
+                    )
+                    .with_path(format!("{}.api_version", endpoint_path))
+                    .with_suggestion("Use letters, digits, '.', '-' or '_', e.g. 'v2'"),
+                );
+            }
+
+            // Groups on different versions of one base path are mounted apart
+            let route_path = endpoint.route_path(&project.config.api_versioning);
+            if seen_paths.contains(&route_path) {
                 result.add_error(
                     ValidationError::new(
                         ValidationErrorCode::DuplicateEndpointPath,
-                        format!("Duplicate endpoint path: '{}'", endpoint.base_path),
+                        format!("Duplicate endpoint path: '{}'", route_path),
                     )
                     .with_path(&endpoint_path),
                 );
             }
-            seen_paths.insert(endpoint.base_path.clone());
+            seen_paths.insert(route_path);
+
+            validate_related_aggregates(project, endpoint, &endpoint_path, &mut result);
+            validate_http_caching(project, endpoint, &endpoint_path, &mut result);
+        }
+
+        let versioning = &project.config.api_versioning;
+        if let Some(version) = versioning.default_version()
+            && !is_valid_api_version(version)
+        {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidApiVersioning,
+                    format!(
+                        "Default API version '{}' is not a valid path segment",
+                        version
+                    ),
+                )
+                .with_path("config.api_versioning.default_version")
+                .with_suggestion("Use letters, digits, '.', '-' or '_', e.g. 'v1'"),
+            );
+        }
+        if let Some(header) = versioning.header_name()
+            && (header.is_empty()
+                || !header
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-'))
+        {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidApiVersioning,
+                    format!("'{}' is not a valid HTTP header name", header),
+                )
+                .with_path("config.api_versioning.header")
+                .with_suggestion("Use letters, digits and '-', e.g. 'X-API-Version'"),
+            );
         }
 
         // Warning: entities without endpoints
@@ -650,6 +990,129 @@ impl ValidationRule for EndpointsRule {
     }
 }
 
+/// Check an endpoint's related counts / existence flags
+fn validate_related_aggregates(
+    project: &ProjectGraph,
+    endpoint: &crate::EndpointGroup,
+    endpoint_path: &str,
+    result: &mut ValidationResult,
+) {
+    let entity = project.entities.get(&endpoint.entity_id);
+    let mut seen_fields: HashSet<&str> = HashSet::new();
+
+    for aggregate in &endpoint.related_aggregates {
+        let path = format!("{}.related_aggregates.{}", endpoint_path, aggregate.field);
+
+        if !is_valid_identifier(&aggregate.field) {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidFieldName,
+                    format!(
+                        "Related aggregate name '{}' is not a valid identifier",
+                        aggregate.field
+                    ),
+                )
+                .with_path(&path),
+            );
+        } else if !seen_fields.insert(aggregate.field.as_str())
+            || entity.is_some_and(|e| e.has_field(&aggregate.field))
+        {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::DuplicateFieldName,
+                    format!(
+                        "Related aggregate '{}' clashes with another response field on '{}'",
+                        aggregate.field, endpoint.entity_name
+                    ),
+                )
+                .with_path(&path),
+            );
+        }
+
+        let Some(relationship) = project.relationships.get(&aggregate.relationship_id) else {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::OrphanRelationship,
+                    format!(
+                        "Related aggregate '{}' references a non-existent relationship",
+                        aggregate.field
+                    ),
+                )
+                .with_path(&path),
+            );
+            continue;
+        };
+
+        if crate::relationship_helpers::dependent_entity(relationship, endpoint.entity_id).is_none()
+        {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidRelationship,
+                    format!(
+                        "Related aggregate '{}' needs a relationship whose other side holds a foreign key to '{}'",
+                        aggregate.field, endpoint.entity_name
+                    ),
+                )
+                .with_path(&path)
+                .with_suggestion("Use a one-to-many relationship from this entity"),
+            );
+        }
+    }
+}
+
+/// Check the ETag / Last-Modified options of an endpoint's operations
+fn validate_http_caching(
+    project: &ProjectGraph,
+    endpoint: &crate::EndpointGroup,
+    endpoint_path: &str,
+    result: &mut ValidationResult,
+) {
+    let timestamps = project
+        .entities
+        .get(&endpoint.entity_id)
+        .is_some_and(|e| e.config.timestamps);
+
+    for op in endpoint
+        .operations
+        .iter()
+        .filter(|op| op.caching.is_enabled())
+    {
+        let path = format!(
+            "{}.operations.{}.caching",
+            endpoint_path,
+            op.operation_type.display_name()
+        );
+
+        if !op.operation_type.is_read() {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidHttpCaching,
+                    format!(
+                        "Caching headers on {} '{}' have no effect; only read operations send them",
+                        op.operation_type.display_name(),
+                        endpoint.base_path
+                    ),
+                )
+                .with_path(&path),
+            );
+        } else if op.caching.uses_updated_at() && !timestamps {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidHttpCaching,
+                    format!(
+                        "{} '{}' derives caching headers from updated_at, but '{}' has no timestamps",
+                        op.operation_type.display_name(),
+                        endpoint.base_path,
+                        endpoint.entity_name
+                    ),
+                )
+                .with_path(&path)
+                .with_suggestion("Enable timestamps on the entity, or use a content-hash ETag"),
+            );
+        }
+    }
+}
+
 /// Rule: Validate project metadata
 pub struct ProjectMetaRule;
 
@@ -694,146 +1157,3187 @@ impl ValidationRule for ProjectMetaRule {
     }
 }
 
-// ============================================================================
-// Helper Functions
-// ============================================================================
+/// Rule: Flag fields that look sensitive but aren't protected
+pub struct SensitiveFieldsRule;
 
-/// Check if a string is a valid identifier
-fn is_valid_identifier(s: &str) -> bool {
-    if s.is_empty() {
-        return false;
+impl ValidationRule for SensitiveFieldsRule {
+    fn name(&self) -> &'static str {
+        "sensitive_fields"
     }
 
-    let mut chars = s.chars();
-    let first = chars.next().unwrap();
-
-    // First character must be letter or underscore
-    if !first.is_alphabetic() && first != '_' {
-        return false;
+    fn description(&self) -> &'static str {
+        "Flags password/token/key fields that are not marked secret or lack hashing guidance"
     }
 
-    // Rest must be alphanumeric or underscore
-    chars.all(|c| c.is_alphanumeric() || c == '_')
-}
-
-// ============================================================================
-// Tests
-// ============================================================================
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{Entity, Field};
-    use imortal_core::DataType;
+        for entity in project.entities.values() {
+            for field in &entity.fields {
+                let issues = field.sensitivity_issues();
+                if issues.is_empty() {
+                    continue;
+                }
 
-    #[test]
-    fn test_validation_result_ok() {
-        let result = ValidationResult::ok();
-        assert!(result.valid);
-        assert!(!result.has_errors());
-        assert!(!result.has_warnings());
-    }
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::SensitiveFieldExposed,
+                        format!(
+                            "Field '{}.{}' looks sensitive but is {}",
+                            entity.name,
+                            field.name,
+                            issues.join(", ")
+                        ),
+                    )
+                    .with_path(format!("entities.{}.fields.{}", entity.name, field.name))
+                    .with_suggestion("Mark it as secret to keep it out of API responses"),
+                );
+            }
+        }
 
-    #[test]
-    fn test_validation_result_error() {
-        let error = ValidationError::new(ValidationErrorCode::EmptyEntityName, "Test error");
-        let result = ValidationResult::error(error);
-        assert!(!result.valid);
-        assert!(result.has_errors());
+        result
     }
+}
 
-    #[test]
-    fn test_validation_result_merge() {
-        let mut result1 = ValidationResult::ok();
-        let result2 = ValidationResult::error(ValidationError::new(
-            ValidationErrorCode::EmptyEntityName,
-            "Error",
-        ));
+/// Rule: Flag foreign keys that nested routes look up by but that have
+/// indexing disabled, unless the project indexes every foreign key anyway
+pub struct ForeignKeyIndexesRule;
 
-        result1.merge(result2);
-        assert!(!result1.valid);
-        assert!(result1.has_errors());
+impl ValidationRule for ForeignKeyIndexesRule {
+    fn name(&self) -> &'static str {
+        "fk_indexes"
     }
 
-    #[test]
-    fn test_validation_error_display() {
-        let error = ValidationError::new(ValidationErrorCode::EmptyEntityName, "Name is empty")
-            .with_path("entities.User");
-
-        let display = format!("{}", error);
-        assert!(display.contains("entities.User"));
-        assert!(display.contains("Name is empty"));
+    fn description(&self) -> &'static str {
+        "Flags unindexed foreign keys used by nested endpoints"
     }
 
-    #[test]
-    fn test_validator_with_valid_project() {
-        let mut project = ProjectGraph::new("Test Project");
-        let entity = Entity::with_timestamps("User")
-            .with_field(Field::new("email", DataType::String).required().unique());
-        project.add_entity(entity);
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        if project.config.index_foreign_keys {
+            return result;
+        }
 
-        let validator = Validator::with_default_rules();
-        let result = validator.validate(&project);
+        for entity in project.entities.values() {
+            for field in &entity.fields {
+                if !field.is_foreign_key
+                    || field.indexed
+                    || !project.fk_backs_nested_routes(entity.id, field)
+                {
+                    continue;
+                }
 
-        assert!(result.valid);
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::MissingIndex,
+                        format!(
+                            "Foreign key '{}.{}' is used by nested endpoints but has indexing disabled; lookups by parent will scan the whole table",
+                            entity.name, field.name
+                        ),
+                    )
+                    .with_path(format!("entities.{}.fields.{}", entity.name, field.name))
+                    .with_suggestion("Enable indexing on the field"),
+                );
+            }
+        }
+
+        result
     }
+}
 
-    #[test]
-    fn test_validator_with_invalid_project() {
-        let mut project = ProjectGraph::new("");
-        project.meta.name = String::new();
+/// Rule: Note entity and field names the generated code rewrites to fit Rust
+/// conventions
+pub struct GeneratedNamesRule;
 
-        let validator = Validator::with_default_rules();
-        let result = validator.validate(&project);
+impl ValidationRule for GeneratedNamesRule {
+    fn name(&self) -> &'static str {
+        "generated_names"
+    }
 
-        assert!(!result.valid);
-        assert!(
-            result
-                .errors
-                .iter()
-                .any(|e| e.code == ValidationErrorCode::EmptyProjectName)
-        );
+    fn description(&self) -> &'static str {
+        "Notes entity names that are not PascalCase and field names that are not snake_case"
     }
 
-    #[test]
-    fn test_entity_names_rule() {
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+
+        for entity in project.entities.values() {
+            let entity_path = format!("entities.{}", entity.name);
+            let type_name = entity.name.to_pascal_case();
+            if !entity.name.is_empty() && type_name != entity.name {
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::RenamedIdentifier,
+                        format!(
+                            "Entity '{}' will be generated as '{}'",
+                            entity.name, type_name
+                        ),
+                    )
+                    .with_path(&entity_path)
+                    .with_suggestion(format!("Rename the entity to '{}'", type_name)),
+                );
+            }
+
+            for field in &entity.fields {
+                let field_name = field.name.to_snake_case();
+                if field.name.is_empty() || field_name == field.name {
+                    continue;
+                }
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::RenamedIdentifier,
+                        format!(
+                            "Field '{}.{}' will be generated as '{}'",
+                            entity.name, field.name, field_name
+                        ),
+                    )
+                    .with_path(format!("{}.fields.{}", entity_path, field.name))
+                    .with_suggestion(format!("Rename the field to '{}'", field_name)),
+                );
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Flag column types the project's database can only approximate
+pub struct ColumnTypesRule;
+
+impl ValidationRule for ColumnTypesRule {
+    fn name(&self) -> &'static str {
+        "column_types"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags arrays outside PostgreSQL and decimals on SQLite"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let database = project.config.database;
+        if database == DatabaseType::PostgreSQL {
+            return result;
+        }
+
+        for entity in project.entities.values() {
+            for field in &entity.fields {
+                let field_path = format!("entities.{}.fields.{}", entity.name, field.name);
+                if is_array_type(&field.data_type) {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::UnsupportedColumnType,
+                            format!(
+                                "Field '{}.{}' is an array, which {} has no native column type for — it will be stored as {}",
+                                entity.name,
+                                field.name,
+                                database.display_name(),
+                                field.data_type.to_sql_type(database)
+                            ),
+                        )
+                        .with_path(&field_path),
+                    );
+                }
+                if database == DatabaseType::SQLite && field.data_type.is_decimal() {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::UnsupportedColumnType,
+                            format!(
+                                "Field '{}.{}' is a {}, but SQLite stores NUMERIC values as floating point when they have a fractional part — exact precision is not guaranteed",
+                                entity.name,
+                                field.name,
+                                field.data_type.display_name()
+                            ),
+                        )
+                        .with_path(&field_path)
+                        .with_suggestion("Use PostgreSQL or MySQL for exact decimal storage"),
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Whether a field stores an array, optional or not
+fn is_array_type(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Array(_) => true,
+        DataType::Optional(inner) => is_array_type(inner),
+        _ => false,
+    }
+}
+
+/// Rule: Flag endpoint configuration that refers to things that no longer
+/// exist or can never take effect
+pub struct EndpointCoverageRule;
+
+impl ValidationRule for EndpointCoverageRule {
+    fn name(&self) -> &'static str {
+        "endpoint_coverage"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags dead endpoint configuration: operations on empty entities, unknown roles and zero rate limits"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let known_roles = &project.config.auth.available_roles;
+
+        for endpoint in project.endpoints.values() {
+            let endpoint_path = format!("endpoints.{}", endpoint.id);
+
+            // Orphaned endpoints are reported by `EndpointsRule`
+            if let Some(entity) = project.entities.get(&endpoint.entity_id)
+                && entity.fields.is_empty()
+            {
+                let enabled = endpoint.enabled_operations().len();
+                if enabled > 0 {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "Endpoint '{}' enables {} operation(s) but entity '{}' has no fields",
+                                endpoint.base_path, enabled, entity.name
+                            ),
+                        )
+                        .with_path(&endpoint_path),
+                    );
+                }
+            }
+
+            let mut unknown_roles: Vec<&str> = Vec::new();
+            let securities = std::iter::once(&endpoint.global_security).chain(
+                endpoint
+                    .operations
+                    .iter()
+                    .filter_map(|op| op.security.as_ref()),
+            );
+            for security in securities {
+                for role in &security.roles {
+                    if !known_roles.contains(role) && !unknown_roles.contains(&role.as_str()) {
+                        unknown_roles.push(role);
+                    }
+                }
+            }
+            for role in unknown_roles {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::UnknownRole,
+                        format!(
+                            "Endpoint '{}' requires role '{}', which is not in the auth role registry",
+                            endpoint.base_path, role
+                        ),
+                    )
+                    .with_path(format!("{}.security", endpoint_path))
+                    .with_suggestion(format!(
+                        "Add '{}' to the available roles or remove it from the endpoint",
+                        role
+                    )),
+                );
+            }
+
+            for operation in &endpoint.operations {
+                let Some(limit) = &operation.rate_limit else {
+                    continue;
+                };
+                if limit.requests == 0 || limit.window_seconds == 0 {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidRateLimit,
+                            format!(
+                                "Rate limit on {} '{}' must allow at least one request per window of at least one second (got {} per {}s)",
+                                operation.operation_type.display_name(),
+                                endpoint.base_path,
+                                limit.requests,
+                                limit.window_seconds
+                            ),
+                        )
+                        .with_path(format!(
+                            "{}.operations.{}.rate_limit",
+                            endpoint_path,
+                            operation.operation_type.display_name()
+                        )),
+                    );
+                }
+            }
+        }
+
+        if let Some(limit) = &project.config.rate_limit
+            && (limit.requests == 0 || limit.window_seconds == 0)
+        {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidRateLimit,
+                    format!(
+                        "The default rate limit must allow at least one request per window of at least one second (got {} per {}s)",
+                        limit.requests, limit.window_seconds
+                    ),
+                )
+                .with_path("config.rate_limit"),
+            );
+        }
+
+        result
+    }
+}
+
+/// Rule: Check the auth principal mapping against the entity it names and
+/// the password hashing settings
+pub struct AuthPrincipalRule;
+
+impl ValidationRule for AuthPrincipalRule {
+    fn name(&self) -> &'static str {
+        "auth_principal"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks that the auth principal entity and its mapped fields exist, and that the bcrypt cost is usable"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let auth = &project.config.auth;
+        if !auth.enabled {
+            return result;
+        }
+
+        if !(4..=31).contains(&auth.password_hash_cost) {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidHashCost,
+                    format!(
+                        "Password hash cost {} is outside bcrypt's range of 4 to 31",
+                        auth.password_hash_cost
+                    ),
+                )
+                .with_path("config.auth.password_hash_cost")
+                .with_suggestion("Use the default cost of 12"),
+            );
+        } else if auth.password_hash_cost < 10 {
+            result.add_warning(
+                ValidationWarning::new(
+                    ValidationWarningCode::WeakPassword,
+                    format!(
+                        "Password hash cost {} is low; hashes will be cheap to brute-force",
+                        auth.password_hash_cost
+                    ),
+                )
+                .with_path("config.auth.password_hash_cost"),
+            );
+        }
+
+        let Some(principal) = &auth.principal else {
+            return result;
+        };
+        let Some(entity) = project.get_entity_by_name(&principal.entity) else {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidAuthPrincipal,
+                    format!(
+                        "Auth principal entity '{}' does not exist",
+                        principal.entity
+                    ),
+                )
+                .with_path("config.auth.principal.entity")
+                .with_suggestion("Pick the entity users sign in as"),
+            );
+            return result;
+        };
+
+        let mut mapped = vec![
+            ("identifier_field", &principal.identifier_field),
+            ("password_field", &principal.password_field),
+        ];
+        if let Some(roles) = &principal.roles_field {
+            mapped.push(("roles_field", roles));
+        }
+
+        for (key, name) in mapped {
+            let path = format!("config.auth.principal.{}", key);
+            let Some(field) = entity.get_field_by_name(name) else {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidAuthPrincipal,
+                        format!(
+                            "Auth principal field '{}.{}' does not exist",
+                            entity.name, name
+                        ),
+                    )
+                    .with_path(path),
+                );
+                continue;
+            };
+
+            match key {
+                "identifier_field" if !field.unique && !field.is_primary_key => {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::MissingIndex,
+                            format!(
+                                "Auth identifier '{}.{}' is not unique; two principals could share it",
+                                entity.name, field.name
+                            ),
+                        )
+                        .with_path(path),
+                    );
+                }
+                "password_field" => {
+                    if !matches!(field.data_type, DataType::String | DataType::Text) {
+                        result.add_error(
+                            ValidationError::new(
+                                ValidationErrorCode::InvalidAuthPrincipal,
+                                format!(
+                                    "Auth password field '{}.{}' must be a string to hold a bcrypt hash",
+                                    entity.name, field.name
+                                ),
+                            )
+                            .with_path(path),
+                        );
+                    } else if !field.secret && !field.looks_sensitive() {
+                        // Fields that look sensitive are already reported by `SensitiveFieldsRule`
+                        result.add_warning(
+                            ValidationWarning::new(
+                                ValidationWarningCode::SensitiveFieldExposed,
+                                format!(
+                                    "Auth password field '{}.{}' is not marked secret and would be returned in responses",
+                                    entity.name, field.name
+                                ),
+                            )
+                            .with_path(path),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Check the identity providers of the OAuth2 strategy
+pub struct OAuthProvidersRule;
+
+impl ValidationRule for OAuthProvidersRule {
+    fn name(&self) -> &'static str {
+        "oauth_providers"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks that OAuth2 sign-in names at least one provider, with unique lowercase names and an issuer for OpenID Connect"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let auth = &project.config.auth;
+        if !auth.enabled || auth.strategy != AuthStrategy::OAuth2 {
+            return result;
+        }
+
+        if auth.oauth_providers.is_empty() {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidOAuthProvider,
+                    "OAuth2 sign-in has no identity provider",
+                )
+                .with_path("config.auth.oauth_providers")
+                .with_suggestion("Add Google, GitHub or an OpenID Connect provider"),
+            );
+        }
+
+        let mut names = HashSet::new();
+        for (i, provider) in auth.oauth_providers.iter().enumerate() {
+            let path = format!("config.auth.oauth_providers[{}]", i);
+            let slug = !provider.name.is_empty()
+                && provider
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !slug {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidOAuthProvider,
+                        format!(
+                            "Identity provider name '{}' must be lowercase letters, digits and underscores",
+                            provider.name
+                        ),
+                    )
+                    .with_path(format!("{}.name", path)),
+                );
+            } else if !names.insert(provider.name.as_str()) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidOAuthProvider,
+                        format!("Identity provider '{}' is declared twice", provider.name),
+                    )
+                    .with_path(format!("{}.name", path)),
+                );
+            }
+
+            let issuer = provider.issuer_url.as_deref().unwrap_or("").trim();
+            if provider.kind == OAuthProviderKind::Oidc && issuer.is_empty() {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidOAuthProvider,
+                        format!(
+                            "OpenID Connect provider '{}' has no issuer URL",
+                            provider.name
+                        ),
+                    )
+                    .with_path(format!("{}.issuer_url", path))
+                    .with_suggestion("Use the issuer its discovery document lives under, e.g. https://accounts.example.com"),
+                );
+            } else if !issuer.is_empty() && !issuer.starts_with("https://") {
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::Custom,
+                        format!(
+                            "Issuer URL of '{}' is not HTTPS; tokens would cross the network in the clear",
+                            provider.name
+                        ),
+                    )
+                    .with_path(format!("{}.issuer_url", path)),
+                );
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Check the project's CORS policy and the origins endpoint groups
+/// allow on their own
+pub struct CorsRule;
+
+impl ValidationRule for CorsRule {
+    fn name(&self) -> &'static str {
+        "cors"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks that CORS origins are bare scheme://host[:port] origins, methods are known and credentials name their origins"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let cors = &project.config.cors;
+
+        if project.config.cors_enabled {
+            for (i, origin) in cors.allowed_origins.iter().enumerate() {
+                check_cors_origin(
+                    &mut result,
+                    origin,
+                    format!("config.cors.allowed_origins[{}]", i),
+                );
+            }
+            for (i, method) in cors.allowed_methods.iter().enumerate() {
+                if !CorsConfig::METHODS.contains(&method.as_str()) {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidCors,
+                            format!("'{}' is not an HTTP method CORS can allow", method),
+                        )
+                        .with_path(format!("config.cors.allowed_methods[{}]", i))
+                        .with_suggestion(format!("Use one of {}", CorsConfig::METHODS.join(", "))),
+                    );
+                }
+            }
+            if cors.allow_credentials && cors.allowed_origins.is_empty() {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidCors,
+                        "CORS allows credentials from any origin",
+                    )
+                    .with_path("config.cors.allow_credentials")
+                    .with_suggestion(
+                        "List the origins allowed to send cookies and Authorization headers",
+                    ),
+                );
+            }
+        }
+
+        for endpoint in project.endpoints.values() {
+            let endpoint_path = format!("endpoints.{}", endpoint.id);
+            let security = &endpoint.global_security;
+            if security.cors_enabled && !security.cors_origins.is_empty() {
+                if !project.config.cors_enabled {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "Endpoint '{}' lists CORS origins but CORS is disabled for the project",
+                                endpoint.base_path
+                            ),
+                        )
+                        .with_path(format!("{}.global_security.cors_origins", endpoint_path)),
+                    );
+                }
+                for (i, origin) in security.cors_origins.iter().enumerate() {
+                    check_cors_origin(
+                        &mut result,
+                        origin,
+                        format!("{}.global_security.cors_origins[{}]", endpoint_path, i),
+                    );
+                }
+            }
+            // Preflight requests are answered per path, not per operation
+            for op in &endpoint.operations {
+                if op.security.as_ref().is_some_and(|s| {
+                    !s.cors_origins.is_empty() && s.cors_origins != security.cors_origins
+                }) {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "CORS origins of the {} operation of '{}' are ignored; only the endpoint's own origins apply",
+                                op.operation_type.display_name(),
+                                endpoint.base_path
+                            ),
+                        )
+                        .with_path(format!("{}.operations.{:?}", endpoint_path, op.operation_type)),
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Report `origin` unless it is `scheme://host[:port]`, as browsers send it
+/// in the `Origin` header.
+fn check_cors_origin(result: &mut ValidationResult, origin: &str, path: String) {
+    let host = origin
+        .strip_prefix("https://")
+        .or_else(|| origin.strip_prefix("http://"));
+    let valid =
+        host.is_some_and(|host| !host.is_empty() && !host.contains(['/', '?', '#', ' ', '*']));
+    if !valid {
+        result.add_error(
+            ValidationError::new(
+                ValidationErrorCode::InvalidCors,
+                format!(
+                    "CORS origin '{}' is not a scheme://host[:port] origin",
+                    origin
+                ),
+            )
+            .with_path(path)
+            .with_suggestion("e.g. https://app.example.com, without a path or trailing slash"),
+        );
+    }
+}
+
+/// Rule: Validate custom query definitions
+pub struct QueriesRule;
+
+impl ValidationRule for QueriesRule {
+    fn name(&self) -> &'static str {
+        "queries"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates that custom queries reference existing entities, relationships and fields"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let mut seen_names: HashSet<String> = HashSet::new();
+        let mut seen_paths: HashSet<String> = project
+            .endpoints
+            .values()
+            .map(|e| e.public_path(&project.config.api_versioning))
+            .collect();
+
+        for query in project.queries.values() {
+            let query_path = format!("queries.{}", query.name);
+
+            if !is_valid_identifier(&query.name) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidQuery,
+                        format!("Query name '{}' is not a valid identifier", query.name),
+                    )
+                    .with_path(&query_path)
+                    .with_suggestion("Use snake_case, e.g. 'active_users'"),
+                );
+            }
+            if !seen_names.insert(query.name.clone()) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidQuery,
+                        format!("Duplicate query name: '{}'", query.name),
+                    )
+                    .with_path(&query_path),
+                );
+            }
+
+            if !query.path.starts_with('/') {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidEndpointPath,
+                        format!("Query path '{}' must start with '/'", query.path),
+                    )
+                    .with_path(&query_path),
+                );
+            } else if !seen_paths.insert(query.path.clone()) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::DuplicateEndpointPath,
+                        format!("Query path '{}' is already used", query.path),
+                    )
+                    .with_path(&query_path),
+                );
+            }
+
+            let Some(root) = project.entities.get(&query.entity_id) else {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::OrphanEndpoint,
+                        format!("Query '{}' references non-existent entity", query.name),
+                    )
+                    .with_path(&query_path),
+                );
+                continue;
+            };
+
+            // Joined entities, addressable by name in field references
+            let mut scope = vec![root];
+            for join in &query.joins {
+                let relationship = project.relationships.get(&join.relationship_id);
+                if let Some(polymorphic) = relationship.filter(|r| r.is_polymorphic()) {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidQuery,
+                            format!(
+                                "Query '{}' can't join '{}' through polymorphic relationship '{}', whose key points at several tables",
+                                query.name, join.entity_name, polymorphic.name
+                            ),
+                        )
+                        .with_path(&query_path),
+                    );
+                    continue;
+                }
+                let joined = relationship
+                    .filter(|r| r.from_entity_id == root.id || r.to_entity_id == root.id)
+                    .and_then(|r| {
+                        let other = if r.from_entity_id == root.id {
+                            r.to_entity_id
+                        } else {
+                            r.from_entity_id
+                        };
+                        project.entities.get(&other)
+                    });
+
+                match joined {
+                    Some(entity) => scope.push(entity),
+                    None => result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidQuery,
+                            format!(
+                                "Query '{}' joins '{}' through a relationship that does not involve '{}'",
+                                query.name, join.entity_name, root.name
+                            ),
+                        )
+                        .with_path(&query_path),
+                    ),
+                }
+            }
+
+            for field_ref in query.field_refs() {
+                let (entity_name, field_name) = split_field_ref(field_ref);
+                let entity = match entity_name {
+                    Some(name) => scope.iter().find(|e| e.name == name),
+                    None => Some(&root),
+                };
+
+                let resolved = entity.and_then(|e| e.get_field_by_name(field_name));
+                match resolved {
+                    None => result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidQuery,
+                            format!(
+                                "Query '{}' references unknown field '{}'",
+                                query.name, field_ref
+                            ),
+                        )
+                        .with_path(&query_path),
+                    ),
+                    Some(field)
+                        if field.secret && query.projection.iter().any(|p| p == field_ref) =>
+                    {
+                        result.add_warning(
+                            ValidationWarning::new(
+                                ValidationWarningCode::SensitiveFieldExposed,
+                                format!(
+                                    "Query '{}' returns secret field '{}'",
+                                    query.name, field_ref
+                                ),
+                            )
+                            .with_path(&query_path),
+                        );
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate enum types and the fields that use them
+pub struct EnumsRule;
+
+impl ValidationRule for EnumsRule {
+    fn name(&self) -> &'static str {
+        "enums"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates enum types and flags fields whose variants disagree with their enum"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let mut seen_names: HashSet<&str> = HashSet::new();
+
+        for def in project.enums() {
+            let enum_path = format!("enums.{}", def.name);
+            if let Err(e) = imortal_core::Validatable::validate(def) {
+                result.add_error(
+                    ValidationError::new(ValidationErrorCode::InvalidEnum, e.to_string())
+                        .with_path(&enum_path),
+                );
+            }
+            if !seen_names.insert(&def.name) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidEnum,
+                        format!("Duplicate enum name: '{}'", def.name),
+                    )
+                    .with_path(&enum_path),
+                );
+            }
+            if project.enum_usages(&def.name).is_empty() {
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::DeadConfiguration,
+                        format!("Enum '{}' is not used by any field", def.name),
+                    )
+                    .with_path(&enum_path),
+                );
+            }
+        }
+
+        // Every field of an enum must agree on its variants: with the
+        // project's definition, or with the first field using an inline enum
+        let mut inline: Vec<(&str, &[String], String)> = Vec::new();
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            for field in &entity.fields {
+                let Some((name, variants)) = enum_of(&field.data_type) else {
+                    continue;
+                };
+                let field_path = format!("entities.{}.fields.{}", entity.name, field.name);
+
+                let expected = match project.get_enum_by_name(name) {
+                    Some(def) => Some((def.variants.as_slice(), format!("enum '{}'", name))),
+                    None => match inline.iter().find(|(n, _, _)| *n == name) {
+                        Some((_, variants, first)) => Some((*variants, first.clone())),
+                        None => {
+                            inline.push((name, variants, field_path.clone()));
+                            None
+                        }
+                    },
+                };
+
+                if let Some((expected, source)) = expected
+                    && expected != variants
+                {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidEnum,
+                            format!(
+                                "Field '{}.{}' uses enum '{}' with different variants than {}",
+                                entity.name, field.name, name, source
+                            ),
+                        )
+                        .with_path(&field_path)
+                        .with_suggestion("Pick the enum again in the field dialog"),
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate field sets and the entities that include them
+pub struct FieldSetsRule;
+
+impl ValidationRule for FieldSetsRule {
+    fn name(&self) -> &'static str {
+        "field_sets"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates field sets and flags set fields hidden by an including entity's own"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let mut seen_names: HashSet<&str> = HashSet::new();
+
+        for set in project.field_sets() {
+            let set_path = format!("field_sets.{}", set.name);
+            if let Err(e) = imortal_core::Validatable::validate(set) {
+                result.add_error(
+                    ValidationError::new(ValidationErrorCode::InvalidFieldSet, e.to_string())
+                        .with_path(&set_path),
+                );
+            }
+            if !seen_names.insert(&set.name) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidFieldSet,
+                        format!("Duplicate field set name: '{}'", set.name),
+                    )
+                    .with_path(&set_path),
+                );
+            }
+            if project.field_set_usages(set.id).is_empty() {
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::DeadConfiguration,
+                        format!("Field set '{}' is not included by any entity", set.name),
+                    )
+                    .with_path(&set_path),
+                );
+            }
+        }
+
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            let entity_path = format!("entities.{}", entity.name);
+            for id in &entity.field_sets {
+                let Some(set) = project.get_field_set(*id) else {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidFieldSet,
+                            format!(
+                                "Entity '{}' includes a field set that no longer exists",
+                                entity.name
+                            ),
+                        )
+                        .with_path(&entity_path)
+                        .with_suggestion("Save the entity again to drop the missing set"),
+                    );
+                    continue;
+                };
+
+                for field in &set.fields {
+                    if entity.has_field(&field.name) {
+                        result.add_warning(
+                            ValidationWarning::new(
+                                ValidationWarningCode::DeadConfiguration,
+                                format!(
+                                    "Field '{}' of field set '{}' is hidden by {}'s own '{}' field",
+                                    field.name, set.name, entity.name, field.name
+                                ),
+                            )
+                            .with_path(format!("{}.fields.{}", entity_path, field.name)),
+                        );
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate computed fields
+pub struct ComputedFieldsRule;
+
+impl ValidationRule for ComputedFieldsRule {
+    fn name(&self) -> &'static str {
+        "computed_fields"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates computed field expressions against the fields they read"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            for field in &entity.fields {
+                let Some(computed) = &field.computed else {
+                    continue;
+                };
+                let field_path = format!("entities.{}.fields.{}", entity.name, field.name);
+                let error = |message: String| {
+                    ValidationError::new(ValidationErrorCode::InvalidComputedField, message)
+                        .with_path(&field_path)
+                };
+
+                if let Err(e) = imortal_core::Validatable::validate(field) {
+                    result.add_error(error(e.to_string()));
+                    continue;
+                }
+
+                for name in computed.references() {
+                    let Some(source) = entity.fields.iter().find(|f| f.name == name) else {
+                        result.add_error(
+                            error(format!(
+                                "Computed field '{}' refers to unknown field '{}'",
+                                field.name, name
+                            ))
+                            .with_suggestion(format!(
+                                "Use one of: {}",
+                                entity
+                                    .fields
+                                    .iter()
+                                    .filter(|f| !f.is_computed())
+                                    .map(|f| f.name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )),
+                        );
+                        continue;
+                    };
+                    if source.is_computed() {
+                        result.add_error(error(format!(
+                            "Computed field '{}' refers to computed field '{}'",
+                            field.name, name
+                        )));
+                        continue;
+                    }
+                    if computed.is_stored() {
+                        continue;
+                    }
+
+                    // Getters are plain Rust: operands must already have the
+                    // result's type, and a nullable operand makes the result
+                    // nullable
+                    let base = |dt: &DataType| match dt {
+                        DataType::Optional(inner) => inner.as_ref().clone(),
+                        other => other.clone(),
+                    };
+                    if !computed.is_concat() && base(&source.data_type) != base(&field.data_type) {
+                        result.add_error(error(format!(
+                            "Getter '{}' is {:?} but '{}' is {:?}; arithmetic operands must have the field's type",
+                            field.name, field.data_type, name, source.data_type
+                        )));
+                    }
+                    if !computed.is_concat() && field.required && !source.required {
+                        result.add_error(
+                            error(format!(
+                                "Getter '{}' is required but '{}' is optional",
+                                field.name, name
+                            ))
+                            .with_suggestion("Make the computed field optional"),
+                        );
+                    }
+                }
+
+                if !computed.is_stored() && (field.unique || field.indexed) {
+                    result.add_error(
+                        error(format!(
+                            "Getter '{}' has no column to index or make unique",
+                            field.name
+                        ))
+                        .with_suggestion("Store it as a generated column instead"),
+                    );
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate fields set through file upload endpoints
+pub struct UploadFieldsRule;
+
+impl ValidationRule for UploadFieldsRule {
+    fn name(&self) -> &'static str {
+        "upload_fields"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates that File/Image fields can hold an uploaded file's URL"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let mut any_upload = false;
+
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            for field in entity.fields.iter().filter(|f| f.is_upload()) {
+                any_upload = true;
+                let field_path = format!("entities.{}.fields.{}", entity.name, field.name);
+                let error = |message: String| {
+                    ValidationError::new(ValidationErrorCode::InvalidUploadField, message)
+                        .with_path(&field_path)
+                };
+
+                if field.is_pk() || field.is_fk() || field.is_computed() {
+                    result.add_error(error(format!(
+                        "Upload field '{}' cannot be a key or computed field",
+                        field.name
+                    )));
+                    continue;
+                }
+
+                let base = match &field.data_type {
+                    DataType::Optional(inner) => inner.as_ref(),
+                    other => other,
+                };
+                if !matches!(base, DataType::String | DataType::Text) {
+                    result.add_error(
+                        error(format!(
+                            "Upload field '{}' is {:?}; it stores the file's URL",
+                            field.name, field.data_type
+                        ))
+                        .with_suggestion("Change the field type to String"),
+                    );
+                }
+
+                // Uploads happen after the record exists, so create can't
+                // supply the URL
+                if field.required {
+                    result.add_error(
+                        error(format!(
+                            "Upload field '{}' is required but is only set by its upload endpoint",
+                            field.name
+                        ))
+                        .with_suggestion("Make the field optional"),
+                    );
+                }
+            }
+        }
+
+        if any_upload && project.config.uploads.max_size_mb == 0 {
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::InvalidUploadField,
+                    "Upload size limit is 0 MB, so every upload would be rejected",
+                )
+                .with_path("config.uploads.max_size_mb"),
+            );
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate composite unique constraints and indexes
+pub struct TableConstraintsRule;
+
+impl ValidationRule for TableConstraintsRule {
+    fn name(&self) -> &'static str {
+        "table_constraints"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates entity-level unique constraints and indexes"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let db = project.config.database;
+
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            let config = &entity.config;
+            let lists = config
+                .unique_together
+                .iter()
+                .enumerate()
+                .map(|(i, fields)| (format!("unique_together[{}]", i), fields))
+                .chain(
+                    config
+                        .indexes
+                        .iter()
+                        .enumerate()
+                        .map(|(i, index)| (format!("indexes[{}]", i), &index.fields)),
+                );
+
+            for (key, fields) in lists {
+                let path = format!("entities.{}.config.{}", entity.name, key);
+                let error = |message: String| {
+                    ValidationError::new(ValidationErrorCode::InvalidConstraint, message)
+                        .with_path(&path)
+                };
+
+                if fields.is_empty() {
+                    result.add_error(error("Constraint lists no fields".to_string()));
+                    continue;
+                }
+                let mut seen = HashSet::new();
+                for name in fields {
+                    if !seen.insert(name) {
+                        result.add_error(error(format!("Field '{}' is listed twice", name)));
+                        continue;
+                    }
+                    match entity.get_field_by_name(name) {
+                        None if config.implied_columns().contains(&name.as_str()) => {}
+                        None => result.add_error(error(format!(
+                            "Constraint refers to unknown field '{}.{}'",
+                            entity.name, name
+                        ))),
+                        Some(field) if !field.is_column() => result.add_error(
+                            error(format!(
+                                "Getter '{}' has no column to constrain",
+                                field.name
+                            ))
+                            .with_suggestion("Store it as a generated column instead"),
+                        ),
+                        Some(_) => {}
+                    }
+                }
+            }
+
+            for (i, fields) in config.unique_together.iter().enumerate() {
+                let path = format!("entities.{}.config.unique_together[{}]", entity.name, i);
+                if fields.len() == 1 {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidConstraint,
+                            format!("Unique constraint on '{}' alone", fields[0]),
+                        )
+                        .with_path(&path)
+                        .with_suggestion("Mark the field itself as unique"),
+                    );
+                }
+                if config.unique_together[..i].contains(fields) {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidConstraint,
+                            format!("Duplicate unique constraint on ({})", fields.join(", ")),
+                        )
+                        .with_path(&path),
+                    );
+                }
+            }
+
+            for (i, index) in config.indexes.iter().enumerate() {
+                let path = format!("entities.{}.config.indexes[{}]", entity.name, i);
+                if config.indexes[..i].iter().any(|o| o.fields == index.fields) {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidConstraint,
+                            format!("Duplicate index on ({})", index.fields.join(", ")),
+                        )
+                        .with_path(&path),
+                    );
+                }
+                if !index.index_type.supported_by(db) {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "{} has no {} indexes; ({}) gets a default index instead",
+                                db.display_name(),
+                                index.index_type.display_name(),
+                                index.fields.join(", ")
+                            ),
+                        )
+                        .with_path(&path),
+                    );
+                }
+            }
+
+            for field in &entity.fields {
+                let Some(scope) = field.unique_scope else {
+                    continue;
+                };
+                let path = format!(
+                    "entities.{}.fields.{}.unique_scope",
+                    entity.name, field.name
+                );
+                let error = |message: String| {
+                    ValidationError::new(ValidationErrorCode::InvalidConstraint, message)
+                        .with_path(&path)
+                };
+
+                let Some(rel) = project.get_relationship(scope) else {
+                    result.add_error(
+                        error(format!(
+                            "'{}.{}' is unique within a relationship that does not exist",
+                            entity.name, field.name
+                        ))
+                        .with_suggestion("Pick another relationship or clear the scope"),
+                    );
+                    continue;
+                };
+                match scope_key(rel, entity) {
+                    None => result.add_error(
+                        error(format!(
+                            "'{}' is unique within '{}', which puts no foreign key on {}",
+                            field.name, rel.name, entity.name
+                        ))
+                        .with_suggestion(
+                            "Scope it by a relationship whose foreign key this entity holds",
+                        ),
+                    ),
+                    Some(key) if key.id == field.id => result.add_error(error(format!(
+                        "'{}' cannot be unique within its own relationship",
+                        field.name
+                    ))),
+                    Some(_) if !field.is_column() => result.add_error(error(format!(
+                        "Getter '{}' has no column to constrain",
+                        field.name
+                    ))),
+                    Some(_) if field.unique => result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "'{}.{}' is unique across the table, so its scope '{}' adds nothing",
+                                entity.name, field.name, rel.name
+                            ),
+                        )
+                        .with_path(&path),
+                    ),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate database-specific table options
+pub struct TableOptionsRule;
+
+impl ValidationRule for TableOptionsRule {
+    fn name(&self) -> &'static str {
+        "table_options"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates tablespaces, storage engines and character sets against the target database"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        let db = project.config.database;
+
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        for entity in entities {
+            let config = &entity.config;
+            let path = |key: &str| format!("entities.{}.config.{}", entity.name, key);
+
+            for (key, target, value) in config.table_settings() {
+                if !is_valid_identifier(value) {
+                    result.add_error(
+                        ValidationError::new(
+                            ValidationErrorCode::InvalidTableOption,
+                            format!(
+                                "Table {} '{}' of entity '{}' is not a valid identifier",
+                                key, value, entity.name
+                            ),
+                        )
+                        .with_path(path(key)),
+                    );
+                } else if target != db {
+                    result.add_warning(
+                        ValidationWarning::new(
+                            ValidationWarningCode::DeadConfiguration,
+                            format!(
+                                "The {} of '{}' only applies to {}; it is ignored on {}",
+                                key,
+                                entity.name,
+                                target.display_name(),
+                                db.display_name()
+                            ),
+                        )
+                        .with_path(path(key)),
+                    );
+                }
+            }
+
+            if db != DatabaseType::MySQL {
+                continue;
+            }
+            if let (Some(charset), Some(collation)) =
+                (config.charset_name(), config.collation_name())
+                && !collation
+                    .to_lowercase()
+                    .starts_with(&format!("{}_", charset.to_lowercase()))
+            {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::InvalidTableOption,
+                        format!(
+                            "Collation '{}' of '{}' is not a collation of character set '{}'",
+                            collation, entity.name, charset
+                        ),
+                    )
+                    .with_path(path("collation"))
+                    .with_suggestion(format!("Pick a collation starting with '{}_'", charset)),
+                );
+            }
+            if let Some(engine) = config.engine_name()
+                && !engine.eq_ignore_ascii_case("InnoDB")
+                && !project.relationships_for_entity(entity.id).is_empty()
+            {
+                result.add_warning(
+                    ValidationWarning::new(
+                        ValidationWarningCode::DeadConfiguration,
+                        format!(
+                            "'{}' takes part in relationships, but the {} engine does not enforce foreign keys",
+                            entity.name, engine
+                        ),
+                    )
+                    .with_path(path("engine")),
+                );
+            }
+        }
+
+        result
+    }
+}
+
+/// Rule: The project's options can be generated together
+pub struct CompatibilityRule;
+
+impl ValidationRule for CompatibilityRule {
+    fn name(&self) -> &'static str {
+        "compatibility"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates that the database, project type, API style and defaults work together"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+        for problem in check_compatibility(project, &project.config) {
+            result.add_error(
+                ValidationError::new(ValidationErrorCode::IncompatibleOptions, problem.message)
+                    .with_path(problem.path)
+                    .with_suggestion(problem.suggestion),
+            );
+        }
+        result
+    }
+}
+
+/// Rule: The entity graph can be built as a database schema
+pub struct GraphIntegrityRule;
+
+impl ValidationRule for GraphIntegrityRule {
+    fn name(&self) -> &'static str {
+        "graph_integrity"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates that foreign keys point at existing fields, don't form cycles, and aren't declared twice"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+
+        let mut entities: Vec<&Entity> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        let index: HashMap<Uuid, usize> = entities
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.id, i))
+            .collect();
+
+        // Foreign keys between different entities, as (holder, field, referenced)
+        let mut edges: Vec<(usize, &Field, usize)> = Vec::new();
+        for (from, entity) in entities.iter().enumerate() {
+            for field in entity.fields.iter().filter(|f| f.is_foreign_key) {
+                let Some(fk) = &field.foreign_key_ref else {
+                    continue;
+                };
+                let path = format!("entities.{}.fields.{}", entity.name, field.name);
+                let dangling = |message: String| {
+                    ValidationError::new(ValidationErrorCode::DanglingForeignKey, message)
+                        .with_path(&path)
+                        .with_target(ValidationTarget::Field {
+                            entity_id: entity.id,
+                            field_id: field.id,
+                        })
+                };
+
+                let Some(to) = project
+                    .entities
+                    .get(&fk.entity_id)
+                    .or_else(|| project.get_entity_by_name(&fk.entity_name))
+                else {
+                    result.add_error(
+                        dangling(format!(
+                            "Foreign key '{}.{}' references entity '{}', which no longer exists",
+                            entity.name, field.name, fk.entity_name
+                        ))
+                        .with_suggestion(
+                            "Point it at an existing entity, or remove the foreign key",
+                        ),
+                    );
+                    continue;
+                };
+                let column = if fk.field_name.is_empty() {
+                    "id"
+                } else {
+                    fk.field_name.as_str()
+                };
+                if to.get_field_by_name(column).is_none() {
+                    result.add_error(
+                        dangling(format!(
+                            "Foreign key '{}.{}' references '{}.{}', which no longer exists",
+                            entity.name, field.name, to.name, column
+                        ))
+                        .with_suggestion(format!(
+                            "Point it at an existing field of '{}', usually its primary key",
+                            to.name
+                        )),
+                    );
+                }
+                if to.id != entity.id {
+                    edges.push((from, field, index[&to.id]));
+                }
+            }
+        }
+
+        // Tables in a cycle can't be created one after another
+        let links: Vec<(usize, usize)> = edges.iter().map(|&(from, _, to)| (from, to)).collect();
+        for members in cycles(entities.len(), &links) {
+            let cycle: Vec<_> = edges
+                .iter()
+                .filter(|(from, _, to)| members.contains(from) && members.contains(to))
+                .collect();
+            let (first, field, _) = *cycle[0];
+            let chain: Vec<String> = cycle
+                .iter()
+                .map(|(from, field, to)| {
+                    format!(
+                        "{}.{} → {}",
+                        entities[*from].name, field.name, entities[*to].name
+                    )
+                })
+                .collect();
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::CircularDependency,
+                    format!(
+                        "Foreign keys form a cycle, so no table can be created first: {}",
+                        chain.join(", ")
+                    ),
+                )
+                .with_path(format!("entities.{}.fields.{}", entities[first].name, field.name))
+                .with_target(ValidationTarget::Field {
+                    entity_id: entities[first].id,
+                    field_id: field.id,
+                })
+                .with_suggestion(
+                    "Remove one of these foreign keys, or replace the pair with a many-to-many relationship",
+                ),
+            );
+        }
+
+        // Two relationships storing their link in the same place
+        let mut relationships: Vec<_> = project.relationships.values().collect();
+        relationships.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+        let mut seen: HashMap<(Option<Uuid>, String), &Relationship> = HashMap::new();
+        for relationship in relationships {
+            let (Some(from), Some(to)) = (
+                project.entities.get(&relationship.from_entity_id),
+                project.entities.get(&relationship.to_entity_id),
+            ) else {
+                continue;
+            };
+            let (key, place) = match relationship.junction_table() {
+                Some(junction) => (
+                    (None, junction.to_lowercase()),
+                    format!("junction table '{}'", junction),
+                ),
+                None => {
+                    let Some(fk) = calculate_fk_info(relationship, &from.name, &to.name) else {
+                        continue;
+                    };
+                    let holder = if fk.entity_id == from.id { from } else { to };
+                    (
+                        (Some(fk.entity_id), fk.field_name.to_lowercase()),
+                        format!("foreign key '{}.{}'", holder.name, fk.field_name),
+                    )
+                }
+            };
+            let Some(other) = seen.insert(key, relationship) else {
+                continue;
+            };
+            result.add_error(
+                ValidationError::new(
+                    ValidationErrorCode::DuplicateRelationship,
+                    format!(
+                        "Relationships '{}' and '{}' both use {}",
+                        other.name, relationship.name, place
+                    ),
+                )
+                .with_path(format!("relationships.{}", relationship.id))
+                .with_target(ValidationTarget::Relationship(relationship.id))
+                .with_suggestion("Delete one of them, or give one its own foreign key field"),
+            );
+        }
+
+        result
+    }
+}
+
+/// Rule: Validate polymorphic associations
+///
+/// The targets of an association are relationships from the same entity
+/// sharing a discriminator; they must agree on the key field, which the
+/// entity must have as a plain (not foreign key) column of the type of
+/// every target's primary key.
+pub struct PolymorphicRule;
+
+impl ValidationRule for PolymorphicRule {
+    fn name(&self) -> &'static str {
+        "polymorphic"
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates the discriminator and key fields of polymorphic associations"
+    }
+
+    fn validate(&self, project: &ProjectGraph) -> ValidationResult {
+        let mut result = ValidationResult::ok();
+
+        let mut relationships: Vec<&Relationship> = project
+            .relationships
+            .values()
+            .filter(|r| r.is_polymorphic())
+            .collect();
+        relationships.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+        // Per association (holder, discriminator): its first target
+        let mut associations: HashMap<(Uuid, &str), &Relationship> = HashMap::new();
+        let mut targets: HashSet<(Uuid, &str, Uuid)> = HashSet::new();
+
+        for relationship in relationships {
+            let (Some(holder), Some(target), Some(discriminator)) = (
+                project.entities.get(&relationship.from_entity_id),
+                project.entities.get(&relationship.to_entity_id),
+                relationship.discriminator(),
+            ) else {
+                continue;
+            };
+            let key = relationship.from_field.as_str();
+            let error = |message: String| {
+                ValidationError::new(
+                    ValidationErrorCode::InvalidPolymorphic,
+                    format!("Relationship '{}': {}", relationship.name, message),
+                )
+                .with_path(format!("relationships.{}", relationship.id))
+                .with_target(ValidationTarget::Relationship(relationship.id))
+            };
+
+            if !targets.insert((holder.id, discriminator, target.id)) {
+                result.add_error(
+                    ValidationError::new(
+                        ValidationErrorCode::DuplicateRelationship,
+                        format!(
+                            "'{}' is a target of '{}.{}' twice",
+                            target.name, holder.name, discriminator
+                        ),
+                    )
+                    .with_path(format!("relationships.{}", relationship.id))
+                    .with_target(ValidationTarget::Relationship(relationship.id))
+                    .with_suggestion("Delete one of them"),
+                );
+            }
+
+            match associations.get(&(holder.id, discriminator)) {
+                Some(first) if first.from_field != relationship.from_field => {
+                    result.add_error(
+                        error(format!(
+                            "'{}.{}' is keyed by '{}' here but by '{}' in '{}'",
+                            holder.name, discriminator, key, first.from_field, first.name
+                        ))
+                        .with_suggestion("Use the same key field for every target"),
+                    );
+                }
+                Some(_) => {}
+                None => {
+                    associations.insert((holder.id, discriminator), relationship);
+                }
+            }
+
+            let (Some(_), Some(key_field)) = (
+                holder.get_field_by_name(discriminator),
+                holder.get_field_by_name(key),
+            ) else {
+                let missing: Vec<String> = [discriminator, key]
+                    .into_iter()
+                    .filter(|name| holder.get_field_by_name(name).is_none())
+                    .map(|name| format!("'{}'", name))
+                    .collect();
+                result.add_error(
+                    error(format!(
+                        "'{}' has no field {}",
+                        holder.name,
+                        missing.join(" or ")
+                    ))
+                    .with_suggestion(
+                        "Add the discriminator and key fields, or recreate the relationship",
+                    ),
+                );
+                continue;
+            };
+
+            if key_field.is_foreign_key {
+                result.add_error(
+                    error(format!(
+                        "Key '{}.{}' is a foreign key, so it can only point at one table",
+                        holder.name, key
+                    ))
+                    .with_suggestion("Turn it into a plain field"),
+                );
+            }
+            let key_type = match &key_field.data_type {
+                DataType::Optional(inner) => inner.as_ref(),
+                other => other,
+            };
+            if let Some(pk) = target.primary_key()
+                && pk.data_type != *key_type
+            {
+                result.add_error(
+                    error(format!(
+                        "Key '{}.{}' holds {} but '{}' is keyed by {}",
+                        holder.name, key, key_type, target.name, pk.data_type
+                    ))
+                    .with_suggestion("Give every target the same primary key type"),
+                );
+            }
+        }
+
+        result
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Check if a string is a valid identifier
+fn is_valid_identifier(s: &str) -> bool {
+    if s.is_empty() {
+        return false;
+    }
+
+    let mut chars = s.chars();
+    let first = chars.next().unwrap();
+
+    // First character must be letter or underscore
+    if !first.is_alphabetic() && first != '_' {
+        return false;
+    }
+
+    // Rest must be alphanumeric or underscore
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Groups of nodes that reach each other (strongly connected components with
+/// more than one member), found with Tarjan's algorithm; each group's
+/// members are sorted
+fn cycles(count: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    struct Search<'a> {
+        edges: &'a [(usize, usize)],
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        next: usize,
+        groups: Vec<Vec<usize>>,
+    }
+
+    impl Search<'_> {
+        fn visit(&mut self, node: usize) {
+            self.index[node] = Some(self.next);
+            self.low[node] = self.next;
+            self.next += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            let edges = self.edges;
+            for &(_, to) in edges.iter().filter(|(from, _)| *from == node) {
+                match self.index[to] {
+                    None => {
+                        self.visit(to);
+                        self.low[node] = self.low[node].min(self.low[to]);
+                    }
+                    Some(index) if self.on_stack[to] => {
+                        self.low[node] = self.low[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(self.low[node]) == self.index[node] {
+                let mut group = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    group.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                if group.len() > 1 {
+                    group.sort_unstable();
+                    self.groups.push(group);
+                }
+            }
+        }
+    }
+
+    let mut search = Search {
+        edges,
+        index: vec![None; count],
+        low: vec![0; count],
+        stack: Vec::new(),
+        on_stack: vec![false; count],
+        next: 0,
+        groups: Vec::new(),
+    };
+    for node in 0..count {
+        if search.index[node].is_none() {
+            search.visit(node);
+        }
+    }
+    search.groups.sort();
+    search.groups
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ApiVersioning, AuthConfig, AuthPrincipal, ETagSource, EndpointGroup, EndpointSecurity,
+        Entity, EnumDef, Field, HttpCaching, OAuthProvider, OperationType, QueryDefinition,
+        RateLimit, Relationship, SortDirection,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn test_validation_result_ok() {
+        let result = ValidationResult::ok();
+        assert!(result.valid);
+        assert!(!result.has_errors());
+        assert!(!result.has_warnings());
+    }
+
+    #[test]
+    fn test_validation_result_error() {
+        let error = ValidationError::new(ValidationErrorCode::EmptyEntityName, "Test error");
+        let result = ValidationResult::error(error);
+        assert!(!result.valid);
+        assert!(result.has_errors());
+    }
+
+    #[test]
+    fn test_validation_result_merge() {
+        let mut result1 = ValidationResult::ok();
+        let result2 = ValidationResult::error(ValidationError::new(
+            ValidationErrorCode::EmptyEntityName,
+            "Error",
+        ));
+
+        result1.merge(result2);
+        assert!(!result1.valid);
+        assert!(result1.has_errors());
+    }
+
+    #[test]
+    fn test_validation_error_display() {
+        let error = ValidationError::new(ValidationErrorCode::EmptyEntityName, "Name is empty")
+            .with_path("entities.User");
+
+        let display = format!("{}", error);
+        assert!(display.contains("entities.User"));
+        assert!(display.contains("Name is empty"));
+    }
+
+    #[test]
+    fn test_validator_with_valid_project() {
+        let mut project = ProjectGraph::new("Test Project");
+        let entity = Entity::with_timestamps("User")
+            .with_field(Field::new("email", DataType::String).required().unique());
+        project.add_entity(entity);
+
+        let validator = Validator::with_default_rules();
+        let result = validator.validate(&project);
+
+        assert!(result.valid);
+    }
+
+    #[test]
+    fn test_validator_with_invalid_project() {
+        let mut project = ProjectGraph::new("");
+        project.meta.name = String::new();
+
+        let validator = Validator::with_default_rules();
+        let result = validator.validate(&project);
+
+        assert!(!result.valid);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.code == ValidationErrorCode::EmptyProjectName)
+        );
+    }
+
+    #[test]
+    fn test_entity_names_rule() {
+        let mut project = ProjectGraph::new("Test");
+
+        // Add entity with empty name
+        let mut entity = Entity::new("User");
+        entity.name = String::new();
+        project.entities.insert(entity.id, entity);
+
+        let rule = EntityNamesRule;
+        let result = rule.validate(&project);
+
+        assert!(!result.valid);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.code == ValidationErrorCode::EmptyEntityName)
+        );
+    }
+
+    #[test]
+    fn test_entity_names_rule_schema() {
+        let mut project = ProjectGraph::new("Test");
+        let mut entity = Entity::new("Invoice");
+        entity.config.schema = Some("billing-data".to_string());
+        project.entities.insert(entity.id, entity);
+
+        let result = EntityNamesRule.validate(&project);
+        assert!(result.errors.iter().any(|e| {
+            e.code == ValidationErrorCode::InvalidEntityName && e.message.contains("billing-data")
+        }));
+
+        for entity in project.entities.values_mut() {
+            entity.config.schema = Some("billing".to_string());
+        }
+        assert!(EntityNamesRule.validate(&project).valid);
+    }
+
+    #[test]
+    fn test_entity_fields_rule() {
+        let mut project = ProjectGraph::new("Test");
+
+        // Add entity with no fields
+        let mut entity = Entity::new("User");
+        entity.fields.clear();
+        project.entities.insert(entity.id, entity);
+
+        let rule = EntityFieldsRule;
+        let result = rule.validate(&project);
+
+        assert!(!result.valid);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.code == ValidationErrorCode::NoFields)
+        );
+    }
+
+    #[test]
+    fn test_entity_fields_rule_decimal_bounds() {
+        let mut project = ProjectGraph::new("Test");
+        let entity = Entity::new("Invoice")
+            .with_field(Field::new("total", DataType::money()))
+            .with_field(Field::new("rate", DataType::decimal(4, 6)))
+            .with_field(Field::new("huge", DataType::decimal(40, 2)));
+        project.entities.insert(entity.id, entity);
+
+        let result = EntityFieldsRule.validate(&project);
+        let bad: Vec<&str> = result
+            .errors
+            .iter()
+            .filter(|e| e.code == ValidationErrorCode::InvalidFieldType)
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(bad.len(), 2, "{:?}", bad);
+        assert!(bad.iter().any(|m| m.contains("'rate'")));
+        assert!(bad.iter().any(|m| m.contains("'huge'")));
+    }
+
+    #[test]
+    fn test_sensitive_fields_rule() {
+        let mut project = ProjectGraph::new("Test");
+        let entity = Entity::new("Account")
+            .with_field(Field::new("api_key", DataType::String))
+            .with_field(
+                Field::new("password_hash", DataType::String)
+                    .secret()
+                    .hidden(),
+            );
+        project.add_entity(entity);
+
+        let result = SensitiveFieldsRule.validate(&project);
+
+        // Warnings never make the project invalid
+        assert!(result.valid);
+        let flagged: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.code == ValidationWarningCode::SensitiveFieldExposed)
+            .collect();
+        // api_key is unprotected; password_hash is secret but lacks hashing guidance
+        assert_eq!(flagged.len(), 2);
+        assert!(
+            flagged
+                .iter()
+                .any(|w| w.path.as_deref() == Some("entities.Account.fields.api_key"))
+        );
+    }
+
+    #[test]
+    fn test_queries_rule() {
+        let mut project = ProjectGraph::new("Test");
+        let user = Entity::new("User").with_field(Field::new("email", DataType::String));
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let query = QueryDefinition::new("user_emails", user_id, "User")
+            .select("email")
+            .with_sort("email", SortDirection::Asc);
+        project.add_query(query);
+        assert!(QueriesRule.validate(&project).valid);
+
+        let broken = QueryDefinition::new("broken", user_id, "User")
+            .select("nickname")
+            .select("Post.title");
+        project.add_query(broken);
+
+        let result = QueriesRule.validate(&project);
+        assert!(!result.valid);
+        assert_eq!(
+            result
+                .errors
+                .iter()
+                .filter(|e| e.code == ValidationErrorCode::InvalidQuery)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_endpoints_rule_related_aggregates() {
+        let mut project = ProjectGraph::new("Test");
+        let user = Entity::new("User").with_field(Field::new("email", DataType::String));
+        let post = Entity::new("Post");
+        let (user_id, post_id) = (user.id, post.id);
+        project.add_entity(user);
+        project.add_entity(post);
+
+        let rel = Relationship::one_to_many(user_id, post_id);
+        let rel_id = rel.id;
+        project.add_relationship(rel);
+
+        project.add_endpoint(
+            EndpointGroup::new(user_id, "User")
+                .with_related_count(rel_id, "posts_count")
+                .with_related_exists(rel_id, "has_posts"),
+        );
+        assert!(EndpointsRule.validate(&project).valid);
+
+        // Wrong direction, name clash and dangling relationship
+        project.add_endpoint(
+            EndpointGroup::new(post_id, "Post")
+                .with_base_path("/posts")
+                .with_related_count(rel_id, "users_count"),
+        );
+        project.add_endpoint(
+            EndpointGroup::new(user_id, "User")
+                .with_base_path("/accounts")
+                .with_related_count(rel_id, "email")
+                .with_related_exists(Uuid::new_v4(), "has_orders"),
+        );
+
+        let result = EndpointsRule.validate(&project);
+        let codes: Vec<_> = result.errors.iter().map(|e| e.code).collect();
+        assert!(codes.contains(&ValidationErrorCode::InvalidRelationship));
+        assert!(codes.contains(&ValidationErrorCode::DuplicateFieldName));
+        assert!(codes.contains(&ValidationErrorCode::OrphanRelationship));
+    }
+
+    #[test]
+    fn test_relationships_rule_accessor_names() {
         let mut project = ProjectGraph::new("Test");
+        let user_id = project.add_entity(Entity::new("User"));
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("title", DataType::String));
+        let post_id = project.add_entity(post);
+        project.add_relationship(
+            Relationship::one_to_many(user_id, post_id)
+                .with_name("UserPosts")
+                .with_inverse("posts"),
+        );
+        project.add_relationship(
+            Relationship::one_to_many(user_id, post_id)
+                .with_name("UserDrafts")
+                .with_accessor("title")
+                .with_inverse("posts"),
+        );
 
-        // Add entity with empty name
-        let mut entity = Entity::new("User");
-        entity.name = String::new();
-        project.entities.insert(entity.id, entity);
+        let result = RelationshipsRule.validate(&project);
+        let messages: Vec<&str> = result
+            .errors
+            .iter()
+            .filter(|e| e.code == ValidationErrorCode::InvalidAccessorName)
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("clashes with field 'Post.title'"))
+        );
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("Accessor 'posts' on 'User' is also used"))
+        );
 
-        let rule = EntityNamesRule;
-        let result = rule.validate(&project);
+        for rel in project.relationships.values_mut() {
+            if rel.name == "UserDrafts" {
+                rel.accessor_name = Some("draft author".to_string());
+                rel.inverse_name = Some("drafts".to_string());
+            }
+        }
+        let result = RelationshipsRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("not a valid identifier"));
+    }
 
-        assert!(!result.valid);
+    #[test]
+    fn test_graph_integrity_cycles() {
+        let mut project = ProjectGraph::new("Test");
+        let user = Entity::new("User").with_field(Field::foreign_key("avatar_id", "Image", "id"));
+        let image = Entity::new("Image").with_field(Field::foreign_key("owner_id", "User", "id"));
+        let tree =
+            Entity::new("Category").with_field(Field::foreign_key("parent_id", "Category", "id"));
+        let avatar = user.fields[1].id;
+        let user_id = project.add_entity(user);
+        project.add_entity(image);
+        project.add_entity(tree);
+
+        let result = GraphIntegrityRule.validate(&project);
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        let error = &result.errors[0];
+        assert_eq!(error.code, ValidationErrorCode::CircularDependency);
+        assert!(
+            error
+                .message
+                .ends_with("Image.owner_id → User, User.avatar_id → Image"),
+            "{}",
+            error.message
+        );
+        assert_eq!(
+            error.path.as_deref(),
+            Some("entities.Image.fields.owner_id")
+        );
+
+        project
+            .get_entity_mut(user_id)
+            .unwrap()
+            .fields
+            .retain(|f| f.id != avatar);
+        assert!(GraphIntegrityRule.validate(&project).errors.is_empty());
+    }
+
+    #[test]
+    fn test_graph_integrity_dangling_foreign_keys() {
+        let mut project = ProjectGraph::new("Test");
+        project.add_entity(Entity::new("User"));
+        let post = Entity::new("Post")
+            .with_field(Field::foreign_key("author_id", "User", "uid"))
+            .with_field(Field::foreign_key("blog_id", "Blog", "id"))
+            .with_field(Field::foreign_key("editor_id", "User", ""));
+        let post_id = post.id;
+        let author = post.fields[1].id;
+        project.add_entity(post);
+
+        let result = GraphIntegrityRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Foreign key 'Post.author_id' references 'User.uid', which no longer exists",
+                "Foreign key 'Post.blog_id' references entity 'Blog', which no longer exists",
+            ]
+        );
         assert!(
             result
                 .errors
                 .iter()
-                .any(|e| e.code == ValidationErrorCode::EmptyEntityName)
+                .all(|e| e.code == ValidationErrorCode::DanglingForeignKey)
+        );
+        assert_eq!(
+            result.errors[0].target,
+            Some(ValidationTarget::Field {
+                entity_id: post_id,
+                field_id: author,
+            })
         );
     }
 
     #[test]
-    fn test_entity_fields_rule() {
+    fn test_polymorphic_associations() {
         let mut project = ProjectGraph::new("Test");
+        let comment = project.add_entity(Entity::new("Comment"));
+        let post = project.add_entity(Entity::new("Post"));
+        let photo = project.add_entity(Entity::new("Photo"));
+        // Named so they are checked before the relationships added below
+        for (i, target) in [post, photo].into_iter().enumerate() {
+            project
+                .create_relationship_with_fk(
+                    Relationship::polymorphic(comment, target, "commentable")
+                        .with_name(format!("Commentable{}", i)),
+                )
+                .unwrap();
+        }
+        assert!(PolymorphicRule.validate(&project).errors.is_empty());
+
+        // A target with another key type, listed twice, and a target keyed
+        // by a different field
+        let mut tag = Entity::new("Tag");
+        tag.fields[0].data_type = DataType::Int64;
+        let tag = project.add_entity(tag);
+        project.add_relationship(
+            Relationship::polymorphic(comment, tag, "commentable").with_name("CommentableTag"),
+        );
+        project.add_relationship(
+            Relationship::polymorphic(comment, tag, "commentable").with_name("CommentableTag2"),
+        );
+        project.add_relationship(
+            Relationship::polymorphic(comment, post, "commentable")
+                .with_from_field("post_id")
+                .with_name("CommentablePost"),
+        );
 
-        // Add entity with no fields
-        let mut entity = Entity::new("User");
-        entity.fields.clear();
-        project.entities.insert(entity.id, entity);
+        let result = PolymorphicRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert!(
+            messages.contains(
+                &"Relationship 'CommentableTag': Key 'Comment.commentable_id' holds UUID but 'Tag' is keyed by Big Integer"
+            ),
+            "{:?}",
+            messages
+        );
+        assert!(messages.contains(&"'Tag' is a target of 'Comment.commentable_type' twice"));
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("is keyed by 'post_id' here but by 'commentable_id'")),
+            "{:?}",
+            messages
+        );
+        assert!(
+            messages.contains(&"Relationship 'CommentablePost': 'Comment' has no field 'post_id'")
+        );
+    }
 
-        let rule = EntityFieldsRule;
-        let result = rule.validate(&project);
+    #[test]
+    fn test_graph_integrity_duplicate_relationships() {
+        let mut project = ProjectGraph::new("Test");
+        let user = project.add_entity(Entity::new("User"));
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        let duplicate =
+            project.add_relationship(Relationship::one_to_many(user, post).with_name("UserPosts"));
+        project.add_relationship(Relationship::many_to_one(post, user).with_name("PostUser"));
+        project.add_relationship(
+            Relationship::many_to_one(post, user)
+                .with_name("PostEditor")
+                .with_from_field("editor_id"),
+        );
+        project.add_relationship(
+            Relationship::many_to_many(post, tag, "post_tags").with_name("PostTags"),
+        );
+        project.add_relationship(
+            Relationship::many_to_many(tag, post, "post_tags").with_name("TagPosts"),
+        );
+
+        let result = GraphIntegrityRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Relationships 'PostTags' and 'TagPosts' both use junction table 'post_tags'",
+                "Relationships 'PostUser' and 'UserPosts' both use foreign key 'Post.user_id'",
+            ]
+        );
+        assert!(
+            result
+                .errors
+                .iter()
+                .all(|e| e.code == ValidationErrorCode::DuplicateRelationship)
+        );
+        assert_eq!(
+            result.errors[1].target,
+            Some(ValidationTarget::Relationship(duplicate))
+        );
+    }
+
+    #[test]
+    fn test_target_from_path() {
+        let mut project = ProjectGraph::new("Test");
+        let mut user = Entity::new("User");
+        let field = Field::new("email", DataType::String);
+        let email = field.id;
+        user.add_field(field);
+        let user = project.add_entity(user);
+        let post = project.add_entity(Entity::new("Post"));
+        let rel = project.add_relationship(Relationship::one_to_many(user, post));
+        let group = project.add_endpoint(EndpointGroup::new(user, "User"));
+
+        let resolve = |path: &str| ValidationTarget::from_path(&project, path);
+        assert_eq!(
+            resolve("entities.User"),
+            Some(ValidationTarget::Entity(user))
+        );
+        assert_eq!(
+            resolve(&format!("entities.{}", post)),
+            Some(ValidationTarget::Entity(post))
+        );
+        assert_eq!(
+            resolve("entities.User.fields.email"),
+            Some(ValidationTarget::Field {
+                entity_id: user,
+                field_id: email,
+            })
+        );
+        assert_eq!(
+            resolve("entities.User.config.indexes[0]"),
+            Some(ValidationTarget::Entity(user))
+        );
+        assert_eq!(
+            resolve(&format!("relationships.{}", rel)),
+            Some(ValidationTarget::Relationship(rel))
+        );
+        assert_eq!(
+            resolve(&format!("endpoints.{}.security", group)),
+            Some(ValidationTarget::Endpoint(group))
+        );
+        assert_eq!(resolve("entities.Missing"), None);
+        assert_eq!(resolve(&format!("relationships.{}", Uuid::new_v4())), None);
+        assert_eq!(resolve("config.rate_limit"), None);
+        assert_eq!(resolve("queries.recent"), None);
+
+        // The validator fills in targets rules left out
+        let result = Validator::with_default_rules().validate(&project);
+        let targets: Vec<_> = result
+            .warnings
+            .iter()
+            .filter(|w| w.code == ValidationWarningCode::NoDescription)
+            .map(|w| w.target)
+            .collect();
+        assert!(targets.contains(&Some(ValidationTarget::Entity(user))));
+        assert!(targets.contains(&Some(ValidationTarget::Entity(post))));
+    }
+
+    #[test]
+    fn test_orphan_relationship_target() {
+        let mut project = ProjectGraph::new("Test");
+        let user = project.add_entity(Entity::new("User"));
+        let id = project.add_relationship(Relationship::one_to_many(user, Uuid::new_v4()));
+
+        let result = RelationshipsRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].code,
+            ValidationErrorCode::OrphanRelationship
+        );
+        assert_eq!(
+            result.errors[0].target,
+            Some(ValidationTarget::Relationship(id))
+        );
+    }
+
+    #[test]
+    fn test_junction_entity_checks() {
+        let mut project = ProjectGraph::new("Test");
+        let post = project.add_entity(Entity::new("Post"));
+        let tag = project.add_entity(Entity::new("Tag"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::many_to_many(post, tag, "post_tags"))
+            .unwrap();
+        let junction = project.promote_junction(rel).unwrap();
+        assert!(RelationshipsRule.validate(&project).valid);
+
+        // Losing a key breaks the link to that side
+        let key = project
+            .get_entity(junction)
+            .and_then(|e| e.get_field_by_name("tag_id"))
+            .map(|f| f.id)
+            .unwrap();
+        project.get_entity_mut(junction).unwrap().remove_field(key);
+        let result = RelationshipsRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].code,
+            ValidationErrorCode::InvalidJunctionEntity
+        );
+        assert_eq!(
+            result.errors[0].message,
+            "Junction entity 'PostTag' of 'PostTags' needs a foreign key to each side"
+        );
+
+        project
+            .get_relationship_mut(rel)
+            .unwrap()
+            .junction_entity_id = Some(Uuid::new_v4());
+        let result = RelationshipsRule.validate(&project);
+        assert!(
+            result.errors[0]
+                .message
+                .contains("links a junction entity that no longer exists")
+        );
+    }
+
+    #[test]
+    fn test_endpoints_rule_versions_coexist() {
+        let mut project = ProjectGraph::new("Test");
+        let user_id = project.add_entity(Entity::new("User"));
+        let member_id = project.add_entity(Entity::new("Member"));
+        project.add_endpoint(EndpointGroup::new(user_id, "User").with_version("v1"));
+        project.add_endpoint(
+            EndpointGroup::new(member_id, "Member")
+                .with_base_path("/api/users")
+                .with_version("v2"),
+        );
+        assert!(EndpointsRule.validate(&project).valid);
+
+        // The unversioned group lands on the project default, v1
+        project.config.api_versioning = ApiVersioning::header("v1");
+        project.endpoints.values_mut().for_each(|ep| {
+            if ep.api_version.as_deref() == Some("v2") {
+                ep.api_version = None;
+            }
+        });
+        let result = EndpointsRule.validate(&project);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.code == ValidationErrorCode::DuplicateEndpointPath
+                    && e.message.contains("/api/v1/users"))
+        );
+
+        project.config.api_versioning = ApiVersioning::Header {
+            header: "X API".to_string(),
+            default_version: "v 1".to_string(),
+        };
+        let result = EndpointsRule.validate(&project);
+        assert_eq!(
+            result
+                .errors
+                .iter()
+                .filter(|e| e.code == ValidationErrorCode::InvalidApiVersioning)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_endpoints_rule_http_caching() {
+        let mut project = ProjectGraph::new("Test");
+        let mut user = Entity::new("User");
+        user.config.timestamps = false;
+        let user_id = project.add_entity(user);
+        let mut group = EndpointGroup::new(user_id, "User");
+        for op in &mut group.operations {
+            op.caching = match op.operation_type {
+                OperationType::Read => HttpCaching::etag(ETagSource::ContentHash),
+                OperationType::ReadAll => HttpCaching::last_modified(),
+                OperationType::Update => HttpCaching::etag(ETagSource::ContentHash),
+                _ => HttpCaching::default(),
+            };
+        }
+        project.add_endpoint(group);
+
+        let caching_errors = |project: &ProjectGraph| -> Vec<String> {
+            EndpointsRule
+                .validate(project)
+                .errors
+                .into_iter()
+                .filter(|e| e.code == ValidationErrorCode::InvalidHttpCaching)
+                .map(|e| e.message)
+                .collect()
+        };
+
+        // Last-Modified needs timestamps; update never sends the headers
+        let errors = caching_errors(&project);
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors
+                .iter()
+                .any(|m| m.starts_with("List") && m.contains("updated_at"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|m| m.starts_with("Caching headers on Update"))
+        );
+
+        project.get_entity_mut(user_id).unwrap().config.timestamps = true;
+        assert_eq!(caching_errors(&project).len(), 1);
+    }
+
+    #[test]
+    fn test_fk_indexes_rule() {
+        let mut project = ProjectGraph::new("Test");
+        let user_id = project.add_entity(Entity::new("User"));
+        let post_id = project.add_entity(Entity::new("Post"));
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+        project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        assert!(!ForeignKeyIndexesRule.validate(&project).has_warnings());
+
+        let post = project.get_entity_mut(post_id).unwrap();
+        let fk_id = post.get_field_by_name("user_id").unwrap().id;
+        post.get_field_mut(fk_id).unwrap().indexed = false;
+
+        // The project policy indexes it anyway
+        assert!(!ForeignKeyIndexesRule.validate(&project).has_warnings());
+
+        project.config.index_foreign_keys = false;
+        let result = ForeignKeyIndexesRule.validate(&project);
+        assert!(result.valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, ValidationWarningCode::MissingIndex);
+        assert!(result.warnings[0].message.contains("scan the whole table"));
+    }
+
+    #[test]
+    fn test_generated_names_rule() {
+        let mut project = ProjectGraph::new("Test");
+        project.add_entity(
+            Entity::new("blog_post")
+                .with_field(Field::new("displayName", DataType::String))
+                .with_field(Field::new("title", DataType::String)),
+        );
+
+        let result = GeneratedNamesRule.validate(&project);
+        assert!(result.valid);
+        let messages: Vec<_> = result.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages.contains(&"Entity 'blog_post' will be generated as 'BlogPost'"));
+        assert!(
+            messages.contains(&"Field 'blog_post.displayName' will be generated as 'display_name'")
+        );
+        assert!(
+            result
+                .warnings
+                .iter()
+                .all(|w| w.code == ValidationWarningCode::RenamedIdentifier
+                    && w.suggestion.is_some())
+        );
+    }
+
+    #[test]
+    fn test_column_types_rule() {
+        let mut project = ProjectGraph::new("Test");
+        project.add_entity(
+            Entity::new("Product")
+                .with_field(Field::new(
+                    "tags",
+                    DataType::Array(Box::new(DataType::String)),
+                ))
+                .with_field(Field::new(
+                    "price",
+                    DataType::Decimal {
+                        precision: 10,
+                        scale: 2,
+                    },
+                )),
+        );
+        assert!(!ColumnTypesRule.validate(&project).has_warnings());
+
+        project.config.database = DatabaseType::MySQL;
+        let result = ColumnTypesRule.validate(&project);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(
+            result.warnings[0]
+                .message
+                .contains("'Product.tags' is an array")
+        );
+
+        project.config.database = DatabaseType::SQLite;
+        let result = ColumnTypesRule.validate(&project);
+        assert_eq!(result.warnings.len(), 2);
+        assert!(
+            result
+                .warnings
+                .iter()
+                .all(|w| w.code == ValidationWarningCode::UnsupportedColumnType)
+        );
+    }
+
+    #[test]
+    fn test_endpoint_coverage_rule() {
+        let mut project = ProjectGraph::new("Test");
+        let user_id = project.add_entity(Entity::new("User"));
+        let endpoint_id = project.add_endpoint(EndpointGroup::new(user_id, "User"));
+        let result = EndpointCoverageRule.validate(&project);
+        assert!(result.valid && !result.has_warnings());
+
+        // Operations on an entity whose fields were all removed
+        project.get_entity_mut(user_id).unwrap().fields.clear();
+        let result = EndpointCoverageRule.validate(&project);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].code,
+            ValidationWarningCode::DeadConfiguration
+        );
+
+        // Unknown roles are reported once per endpoint
+        let endpoint = project.endpoints.get_mut(&endpoint_id).unwrap();
+        endpoint.global_security.roles = vec!["admin".to_string(), "auditor".to_string()];
+        let delete = endpoint.get_operation_mut(OperationType::Delete).unwrap();
+        delete.security = Some(EndpointSecurity::with_roles(vec!["auditor".to_string()]));
+        delete.rate_limit = Some(RateLimit::new(0, 60));
+
+        let result = EndpointCoverageRule.validate(&project);
+        let codes: Vec<_> = result.errors.iter().map(|e| e.code).collect();
+        assert_eq!(
+            codes,
+            vec![
+                ValidationErrorCode::UnknownRole,
+                ValidationErrorCode::InvalidRateLimit
+            ]
+        );
+        assert!(result.errors[0].message.contains("'auditor'"));
+
+        project
+            .config
+            .auth
+            .available_roles
+            .push("auditor".to_string());
+        let result = EndpointCoverageRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+
+        project.config.rate_limit = Some(RateLimit::new(10, 0));
+        let result = EndpointCoverageRule.validate(&project);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.errors[1].path.as_deref(), Some("config.rate_limit"));
+    }
+
+    #[test]
+    fn test_enums_rule() {
+        let mut project = ProjectGraph::new("Test");
+        let status = EnumDef::new("Status").with_variants(["draft", "live"]);
+        project.add_enum(status.clone());
+        let result = EnumsRule.validate(&project);
+        assert!(result.valid);
+        assert_eq!(
+            result.warnings[0].code,
+            ValidationWarningCode::DeadConfiguration
+        );
+
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("status", status.data_type()));
+        post.fields.push(Field::new(
+            "stale",
+            status.clone().with_variant("archived").data_type(),
+        ));
+        post.fields.push(Field::new(
+            "mood",
+            EnumDef::new("Mood").with_variant("happy").data_type(),
+        ));
+        post.fields.push(Field::new(
+            "other_mood",
+            EnumDef::new("Mood").with_variant("sad").data_type(),
+        ));
+        project.add_entity(post);
+
+        let result = EnumsRule.validate(&project);
+        assert!(!result.has_warnings());
+        let paths: Vec<_> = result.errors.iter().map(|e| e.path.as_deref()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                Some("entities.Post.fields.stale"),
+                Some("entities.Post.fields.other_mood"),
+            ]
+        );
+        assert!(
+            result.errors[1]
+                .message
+                .contains("entities.Post.fields.mood")
+        );
+
+        project.add_enum(EnumDef::new("bad name"));
+        let result = EnumsRule.validate(&project);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some("enums.bad name"))
+        );
+    }
+
+    #[test]
+    fn test_field_sets_rule() {
+        use crate::FieldSet;
+
+        let mut project = ProjectGraph::new("Test");
+        let timestamps = project.add_field_set(FieldSet::timestamps());
+        let result = FieldSetsRule.validate(&project);
+        assert!(result.valid);
+        assert_eq!(
+            result.warnings[0].code,
+            ValidationWarningCode::DeadConfiguration
+        );
+
+        let mut post = Entity::with_timestamps("Post");
+        post.include_field_set(timestamps);
+        post.include_field_set(Uuid::new_v4());
+        project.add_entity(post);
+        project.add_field_set(FieldSet::new("Empty"));
+
+        let result = FieldSetsRule.validate(&project);
+        let errors: Vec<_> = result.errors.iter().map(|e| e.path.as_deref()).collect();
+        assert_eq!(
+            errors,
+            vec![Some("field_sets.Empty"), Some("entities.Post")]
+        );
+        let hidden: Vec<_> = result
+            .warnings
+            .iter()
+            .filter_map(|w| w.path.as_deref())
+            .filter(|p| p.starts_with("entities."))
+            .collect();
+        assert_eq!(
+            hidden,
+            vec![
+                "entities.Post.fields.created_at",
+                "entities.Post.fields.updated_at"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_computed_fields_rule() {
+        use crate::ComputedField;
+
+        let mut project = ProjectGraph::new("Test");
+        let mut person = Entity::new("Person");
+        person
+            .fields
+            .push(Field::new("first_name", DataType::String).required());
+        person
+            .fields
+            .push(Field::new("last_name", DataType::String));
+        person.fields.push(Field::new("age", DataType::Int32));
+        person.fields.push(
+            Field::new("full_name", DataType::String)
+                .with_computed(ComputedField::generated("first_name || ' ' || last_name")),
+        );
+        person.fields.push(
+            Field::new("label", DataType::String)
+                .with_computed(ComputedField::getter("full_name || nickname")),
+        );
+        person.fields.push(
+            Field::new("next_age", DataType::Int32)
+                .required()
+                .with_computed(ComputedField::getter("age + 1")),
+        );
+        // An optional getter may read required operands of its base type
+        person.fields.push(
+            Field::new("age_next", DataType::Optional(Box::new(DataType::Int32)))
+                .with_computed(ComputedField::getter("age + 1")),
+        );
+        person.fields.push(
+            Field::new("age_cm", DataType::Int64)
+                .indexed()
+                .with_computed(ComputedField::getter("age * 100")),
+        );
+        project.add_entity(person);
+
+        let result = ComputedFieldsRule.validate(&project);
+        let messages: Vec<_> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 5, "{messages:?}");
+        assert!(messages[0].contains("computed field 'full_name'"));
+        assert!(messages[1].contains("unknown field 'nickname'"));
+        assert!(messages[2].contains("'age' is optional"));
+        assert!(messages[3].contains("operands must have the field's type"));
+        assert!(messages[4].contains("no column"));
+        assert!(result.errors.iter().all(|e| {
+            e.path
+                .as_deref()
+                .unwrap()
+                .starts_with("entities.Person.fields.")
+        }));
+    }
+
+    #[test]
+    fn test_upload_fields_rule() {
+        use crate::WidgetType;
+
+        let mut project = ProjectGraph::new("Test");
+        let mut user = Entity::new("User");
+        user.fields
+            .push(Field::new("avatar", DataType::String).with_widget(WidgetType::Image));
+        user.fields.push(
+            Field::new("resume", DataType::Bytes)
+                .required()
+                .with_widget(WidgetType::File),
+        );
+        project.add_entity(user);
+
+        let result = UploadFieldsRule.validate(&project);
+        let messages: Vec<_> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].contains("stores the file's URL"));
+        assert!(messages[1].contains("only set by its upload endpoint"));
+        assert!(result.errors.iter().all(|e| {
+            e.code == ValidationErrorCode::InvalidUploadField
+                && e.path.as_deref() == Some("entities.User.fields.resume")
+        }));
+
+        project.config.uploads.max_size_mb = 0;
+        let result = UploadFieldsRule.validate(&project);
+        assert_eq!(
+            result.errors.last().unwrap().path.as_deref(),
+            Some("config.uploads.max_size_mb")
+        );
+    }
 
+    #[test]
+    fn test_compatibility_rule() {
+        let mut project = ProjectGraph::new("app");
+        project.config.project_type = crate::ProjectType::Fullstack;
+        project.config.api_style = crate::ApiStyle::Graphql;
+
+        let result = CompatibilityRule.validate(&project);
         assert!(!result.valid);
+        let error = &result.errors[0];
+        assert_eq!(error.code, ValidationErrorCode::IncompatibleOptions);
+        assert_eq!(error.path.as_deref(), Some("config.api_style"));
+        assert!(error.suggestion.is_some());
+
+        project.config.api_style = crate::ApiStyle::Both;
+        assert!(CompatibilityRule.validate(&project).valid);
+    }
+
+    #[test]
+    fn test_table_constraints_rule() {
+        use crate::{ComputedField, DatabaseType, EntityConfig, EntityIndex, IndexType};
+
+        let mut project = ProjectGraph::new("Test");
+        project.config.database = DatabaseType::MySQL;
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("user_id", DataType::Uuid));
+        post.fields.push(Field::new("slug", DataType::String));
+        post.fields.push(
+            Field::new("label", DataType::String)
+                .with_computed(ComputedField::getter("slug || '!'")),
+        );
+        post.config = EntityConfig::new()
+            .with_unique_together(["user_id", "slug"])
+            .with_unique_together(["slug"])
+            .with_unique_together(["user_id", "slug"])
+            .with_index(EntityIndex::new(["slug", "created_at"]))
+            .with_index(EntityIndex::new(["slug", "slug", "title"]))
+            .with_index(EntityIndex::new(["label"]).with_type(IndexType::Gin));
+        project.add_entity(post);
+
+        let result = TableConstraintsRule.validate(&project);
+        let messages: Vec<_> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 5, "{messages:?}");
+        assert!(messages[0].contains("listed twice"));
+        assert!(messages[1].contains("unknown field 'Post.title'"));
+        assert!(messages[2].contains("no column"));
+        assert!(messages[3].contains("'slug' alone"));
+        assert!(messages[4].contains("Duplicate unique constraint"));
+        assert_eq!(
+            result.errors[3].path.as_deref(),
+            Some("entities.Post.config.unique_together[1]")
+        );
         assert!(
             result
                 .errors
                 .iter()
-                .any(|e| e.code == ValidationErrorCode::NoFields)
+                .all(|e| e.path.as_deref() != Some("entities.Post.config.indexes[0]"))
+        );
+
+        // GIN is PostgreSQL-only
+        assert_eq!(result.warnings.len(), 1);
+        project.config.database = DatabaseType::PostgreSQL;
+        assert!(TableConstraintsRule.validate(&project).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_table_options_rule() {
+        use crate::EntityConfig;
+
+        let mut project = ProjectGraph::new("Test");
+        project.config.database = DatabaseType::MySQL;
+        let user = Entity::new("User");
+        let mut post = Entity::new("Post");
+        let user_id = user.id;
+        post.config = EntityConfig::new()
+            .with_tablespace("fast")
+            .with_engine("MyISAM")
+            .with_charset("utf8mb4", Some("latin1_swedish_ci"));
+        let post_id = post.id;
+        project.add_entity(user);
+        project.add_entity(post);
+        project
+            .create_relationship_with_fk(Relationship::one_to_many(user_id, post_id))
+            .unwrap();
+
+        let result = TableOptionsRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path.as_deref(),
+            Some("entities.Post.config.collation")
+        );
+        let paths: Vec<_> = result
+            .warnings
+            .iter()
+            .map(|w| w.path.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "entities.Post.config.tablespace",
+                "entities.Post.config.engine"
+            ]
+        );
+
+        // On PostgreSQL the MySQL options are the dead ones
+        project.config.database = DatabaseType::PostgreSQL;
+        let result = TableOptionsRule.validate(&project);
+        assert!(result.errors.is_empty());
+        assert_eq!(result.warnings.len(), 3);
+
+        let post = project.entities.get_mut(&post_id).unwrap();
+        post.config.tablespace = Some("fast disk".to_string());
+        assert_eq!(TableOptionsRule.validate(&project).errors.len(), 1);
+    }
+
+    #[test]
+    fn test_table_constraints_rule_unique_scope() {
+        let mut project = ProjectGraph::new("Test");
+        let org = Entity::new("Organization");
+        let mut team = Entity::new("Team");
+        let (org_id, team_id) = (org.id, team.id);
+        team.fields.push(Field::new("slug", DataType::String));
+        project.add_entity(org);
+        project.add_entity(team);
+        let (rel_id, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(org_id, team_id))
+            .unwrap();
+
+        let scope = |project: &mut ProjectGraph, entity: Uuid, field: &str, rel: Uuid| {
+            let entity = project.entities.get_mut(&entity).unwrap();
+            let field = entity.fields.iter_mut().find(|f| f.name == field).unwrap();
+            field.unique_scope = Some(rel);
+        };
+
+        scope(&mut project, team_id, "slug", rel_id);
+        let result = TableConstraintsRule.validate(&project);
+        assert!(result.errors.is_empty() && result.warnings.is_empty());
+
+        // Already unique on its own
+        let team = project.entities.get_mut(&team_id).unwrap();
+        team.fields
+            .iter_mut()
+            .find(|f| f.name == "slug")
+            .unwrap()
+            .unique = true;
+        let result = TableConstraintsRule.validate(&project);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].message.contains("adds nothing"));
+
+        // Scoped by its own key, by a relationship whose key sits on the
+        // other entity, or by one that is gone
+        scope(&mut project, team_id, "organization_id", rel_id);
+        let mut org = Entity::new("Organization");
+        org.id = org_id;
+        org.fields
+            .push(Field::new("name", DataType::String).unique_within(rel_id));
+        project.entities.insert(org_id, org);
+        scope(&mut project, team_id, "slug", Uuid::new_v4());
+        let result = TableConstraintsRule.validate(&project);
+        let messages: Vec<_> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 3, "{messages:?}");
+        assert!(messages[0].contains("puts no foreign key on Organization"));
+        assert!(messages[1].contains("relationship that does not exist"));
+        assert!(messages[2].contains("'organization_id' cannot be unique within its own"));
+        assert_eq!(
+            result.errors[1].path.as_deref(),
+            Some("entities.Team.fields.slug.unique_scope")
+        );
+    }
+
+    #[test]
+    fn test_auth_principal_rule() {
+        let mut project = ProjectGraph::new("test");
+        let mut account = Entity::new("Account");
+        account
+            .fields
+            .push(Field::new("login", DataType::String).unique());
+        account.fields.push(Field::new("digest", DataType::Int32));
+        project.entities.insert(account.id, account);
+
+        // No mapping: nothing to check
+        assert!(!AuthPrincipalRule.validate(&project).has_errors());
+
+        project.config.auth.principal = Some(AuthPrincipal::new("Member"));
+        let result = AuthPrincipalRule.validate(&project);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("'Member' does not exist"));
+
+        project.config.auth.principal = Some(
+            AuthPrincipal::new("Account")
+                .with_identifier("login")
+                .with_password_field("digest")
+                .with_roles_field("roles"),
+        );
+        let result = AuthPrincipalRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages[0].contains("'Account.digest' must be a string"));
+        assert!(messages[1].contains("'Account.roles' does not exist"));
+
+        // A string hash column that is not secret leaks through responses
+        let account = project.entities.values_mut().next().unwrap();
+        account.get_field_by_name_mut("digest").unwrap().data_type = DataType::String;
+        project.config.auth.principal.as_mut().unwrap().roles_field = None;
+        let result = AuthPrincipalRule.validate(&project);
+        assert!(!result.has_errors());
+        assert_eq!(
+            result.warnings[0].code,
+            ValidationWarningCode::SensitiveFieldExposed
+        );
+
+        // Out-of-range costs are rejected, unless auth is off
+        project.config.auth.password_hash_cost = 40;
+        assert_eq!(
+            AuthPrincipalRule.validate(&project).errors[0].code,
+            ValidationErrorCode::InvalidHashCost
+        );
+        project.config.auth.enabled = false;
+        assert!(AuthPrincipalRule.validate(&project).errors.is_empty());
+    }
+
+    #[test]
+    fn test_oauth_providers_rule() {
+        let mut project = ProjectGraph::new("test");
+        project.config.auth = AuthConfig::jwt();
+        assert!(OAuthProvidersRule.validate(&project).errors.is_empty());
+
+        project.config.auth = AuthConfig::oauth2();
+        let result = OAuthProvidersRule.validate(&project);
+        assert_eq!(
+            result.errors[0].code,
+            ValidationErrorCode::InvalidOAuthProvider
+        );
+        assert!(result.errors[0].message.contains("no identity provider"));
+
+        let mut nameless = OAuthProvider::oidc("Okta", "");
+        nameless.issuer_url = None;
+        project.config.auth = AuthConfig::oauth2()
+            .with_oauth_provider(OAuthProvider::google())
+            .with_oauth_provider(OAuthProvider::google())
+            .with_oauth_provider(nameless)
+            .with_oauth_provider(OAuthProvider::oidc("corp", "http://sso.corp.test"));
+        let result = OAuthProvidersRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 3, "{:?}", messages);
+        assert!(messages[0].contains("'google' is declared twice"));
+        assert!(messages[1].contains("'Okta' must be lowercase"));
+        assert!(messages[2].contains("'Okta' has no issuer URL"));
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].message.contains("'corp' is not HTTPS"));
+    }
+
+    #[test]
+    fn test_cors_rule() {
+        let mut project = ProjectGraph::new("test");
+        assert!(!CorsRule.validate(&project).has_errors());
+
+        project.config.cors = CorsConfig::default()
+            .with_origin("https://app.example.com")
+            .with_origin("https://app.example.com/")
+            .with_origin("app.example.com")
+            .with_methods(&["GET", "FETCH"]);
+        let result = CorsRule.validate(&project);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 3, "{:?}", messages);
+        assert!(messages[0].contains("'https://app.example.com/' is not"));
+        assert!(messages[1].contains("'app.example.com' is not"));
+        assert!(messages[2].contains("'FETCH' is not an HTTP method"));
+
+        project.config.cors = CorsConfig::default().with_credentials();
+        let result = CorsRule.validate(&project);
+        assert_eq!(
+            result.errors[0].path.as_deref(),
+            Some("config.cors.allow_credentials")
+        );
+
+        // A disabled policy is not checked
+        project.config.cors_enabled = false;
+        assert!(!CorsRule.validate(&project).has_errors());
+
+        project.config.cors = CorsConfig::default();
+        project.config.cors_enabled = true;
+        let user = project.add_entity(Entity::new("User"));
+        let mut endpoint = EndpointGroup::new(user, "User");
+        endpoint.global_security =
+            EndpointSecurity::open().add_cors_origin("http://localhost:3000");
+        endpoint.set_operation_security(
+            OperationType::Delete,
+            EndpointSecurity::open().add_cors_origin("https://admin.example.com"),
+        );
+        project.add_endpoint(endpoint);
+        let result = CorsRule.validate(&project);
+        assert!(!result.has_errors());
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].message.contains("Delete operation"));
+
+        project.config.cors_enabled = false;
+        let result = CorsRule.validate(&project);
+        assert_eq!(result.warnings.len(), 2);
+        assert!(
+            result.warnings[0]
+                .message
+                .contains("CORS is disabled for the project")
         );
     }
 
@@ -0,0 +1,658 @@
+//! # YAML Reader
+//!
+//! Reads the YAML that API descriptions are written in into a JSON value,
+//! for [`crate::openapi_import`]. It covers block mappings and sequences,
+//! flow collections (`[a, b]`, `{a: 1}`), plain, quoted and block (`|`, `>`)
+//! scalars, and comments. Anchors, aliases, tags and multi-document streams
+//! are rejected rather than misread.
+//!
+//! Plain scalars resolve like YAML 1.2's core schema: `null`/`~`, `true`/
+//! `false` and numbers become JSON nulls, booleans and numbers; everything
+//! else is a string. Mapping keys are always strings.
+
+use imortal_core::{EngineError, EngineResult};
+use serde_json::{Map, Number, Value};
+
+/// Read a single YAML document
+pub(crate) fn parse(source: &str) -> EngineResult<Value> {
+    let mut reader = Reader::new(source)?;
+    let value = match reader.peek() {
+        Some(line) => {
+            let indent = line.indent;
+            reader.node(indent)?
+        }
+        None => Value::Null,
+    };
+    match reader.peek() {
+        Some(line) => Err(error(line.number, "unexpected indentation")),
+        None => Ok(value),
+    }
+}
+
+fn error(line: usize, message: &str) -> EngineError {
+    EngineError::Validation(format!("{} on line {}", message, line))
+}
+
+// ============================================================================
+// Lines
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct Line<'a> {
+    /// 1-based line number, for errors
+    number: usize,
+    /// Leading spaces
+    indent: usize,
+    /// The content after the indentation, without any comment; empty for
+    /// blank and comment-only lines
+    text: String,
+    /// The whole line, for block scalars
+    raw: &'a str,
+}
+
+struct Reader<'a> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(source: &'a str) -> EngineResult<Self> {
+        let mut lines = Vec::new();
+        let mut started = false;
+        for (i, raw) in source.lines().enumerate() {
+            let number = i + 1;
+            let indent = raw.len() - raw.trim_start_matches(' ').len();
+            let text = strip_comment(&raw[indent..]).to_string();
+
+            if text.starts_with('%') && !started {
+                continue;
+            }
+            if text == "---" || text.starts_with("--- ") || text == "..." {
+                if started {
+                    return Err(error(number, "multiple YAML documents aren't supported"));
+                }
+                continue;
+            }
+            if !text.is_empty() {
+                if raw[indent..].starts_with('\t') {
+                    return Err(error(number, "tabs can't indent YAML"));
+                }
+                started = true;
+            }
+            lines.push(Line {
+                number,
+                indent,
+                text,
+                raw,
+            });
+        }
+        Ok(Self { lines, pos: 0 })
+    }
+
+    /// The next line with content, skipping blank lines
+    fn peek(&mut self) -> Option<&Line<'a>> {
+        while self.lines.get(self.pos).is_some_and(|l| l.text.is_empty()) {
+            self.pos += 1;
+        }
+        self.lines.get(self.pos)
+    }
+
+    /// The node starting on the next line, which is indented by `indent`
+    fn node(&mut self, indent: usize) -> EngineResult<Value> {
+        let line = self.peek().expect("node() is only called before content");
+        let (number, text) = (line.number, line.text.clone());
+        if is_sequence_item(&text) {
+            self.sequence(indent)
+        } else if split_key(&text).is_some() {
+            self.mapping(indent)
+        } else {
+            self.pos += 1;
+            self.inline_value(&text, indent, number)
+        }
+    }
+
+    /// The value nested under a `key:` or `-` with nothing after it
+    fn nested(&mut self, indent: usize) -> EngineResult<Value> {
+        match self.peek() {
+            Some(next) if next.indent > indent => {
+                let indent = next.indent;
+                self.node(indent)
+            }
+            _ => Ok(Value::Null),
+        }
+    }
+
+    fn sequence(&mut self, indent: usize) -> EngineResult<Value> {
+        let mut items = Vec::new();
+        while let Some(line) = self.peek() {
+            if line.indent != indent || !is_sequence_item(&line.text) {
+                break;
+            }
+            let number = line.number;
+            let rest = line.text[1..].trim_start().to_string();
+            let offset = line.text.len() - rest.len();
+
+            let item = if rest.is_empty() {
+                self.pos += 1;
+                self.nested(indent)?
+            } else if is_sequence_item(&rest) || split_key(&rest).is_some() {
+                // `- key: value` starts a mapping indented past the dash
+                let line = &mut self.lines[self.pos];
+                line.indent += offset;
+                line.text = rest;
+                let indent = line.indent;
+                self.node(indent)?
+            } else {
+                self.pos += 1;
+                self.inline_value(&rest, indent + 1, number)?
+            };
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn mapping(&mut self, indent: usize) -> EngineResult<Value> {
+        let mut map = Map::new();
+        while let Some(line) = self.peek() {
+            if line.indent != indent {
+                break;
+            }
+            let number = line.number;
+            let Some((key, rest)) = split_key(&line.text) else {
+                return Err(error(number, "expected `key: value`"));
+            };
+            let rest = rest.to_string();
+            self.pos += 1;
+
+            let value = if rest.is_empty() {
+                match self.peek() {
+                    // A sequence may sit at its key's indentation
+                    Some(next) if next.indent == indent && is_sequence_item(&next.text) => {
+                        self.sequence(indent)?
+                    }
+                    _ => self.nested(indent)?,
+                }
+            } else {
+                self.inline_value(&rest, indent + 1, number)?
+            };
+            if map.insert(key.clone(), value).is_some() {
+                return Err(error(number, &format!("duplicate key `{}`", key)));
+            }
+        }
+        Ok(Value::Object(map))
+    }
+
+    /// A value written after its key or dash, continuing onto following
+    /// lines indented by at least `min_indent` where the syntax allows
+    fn inline_value(
+        &mut self,
+        text: &str,
+        min_indent: usize,
+        number: usize,
+    ) -> EngineResult<Value> {
+        match text.chars().next() {
+            Some('|' | '>') => self.block_scalar(text, min_indent, number),
+            Some('&' | '*') => Err(error(number, "YAML anchors and aliases aren't supported")),
+            Some('!') => Err(error(number, "YAML tags aren't supported")),
+            Some('[' | '{') => {
+                let mut text = text.to_string();
+                while !balanced(&text) {
+                    match self.lines.get(self.pos) {
+                        Some(next) => {
+                            text.push(' ');
+                            text.push_str(&next.text);
+                            self.pos += 1;
+                        }
+                        None => return Err(error(number, "unclosed flow collection")),
+                    }
+                }
+                let mut flow = Flow::new(&text, number);
+                let value = flow.value()?;
+                flow.end()?;
+                Ok(value)
+            }
+            Some(quote @ ('"' | '\'')) => match quoted(text, quote) {
+                Some((value, len)) if text[len..].trim().is_empty() => Ok(Value::String(value)),
+                Some(_) => Err(error(number, "unexpected text after a quoted string")),
+                None => Err(error(number, "unterminated string")),
+            },
+            _ => {
+                // Plain scalars fold onto more-indented lines that follow
+                let mut text = text.to_string();
+                while let Some(next) = self.lines.get(self.pos) {
+                    if next.text.is_empty()
+                        || next.indent < min_indent
+                        || is_sequence_item(&next.text)
+                        || split_key(&next.text).is_some()
+                    {
+                        break;
+                    }
+                    text.push(' ');
+                    text.push_str(&next.text);
+                    self.pos += 1;
+                }
+                Ok(plain_scalar(&text))
+            }
+        }
+    }
+
+    /// A `|` (literal) or `>` (folded) scalar, whose content is the
+    /// following lines indented by at least `min_indent`
+    fn block_scalar(
+        &mut self,
+        header: &str,
+        min_indent: usize,
+        number: usize,
+    ) -> EngineResult<Value> {
+        let folded = header.starts_with('>');
+        let mut chomp = None;
+        for c in header[1..].chars() {
+            match c {
+                '-' | '+' => chomp = Some(c),
+                '1'..='9' => {}
+                _ => return Err(error(number, "invalid block scalar header")),
+            }
+        }
+
+        let mut content: Vec<&str> = Vec::new();
+        let mut block_indent = None;
+        let mut trailing_blank = 0;
+        while let Some(line) = self.lines.get(self.pos) {
+            let blank = line.raw.trim().is_empty();
+            if blank {
+                trailing_blank += 1;
+            } else {
+                let indent = *block_indent.get_or_insert(line.indent);
+                if line.indent < indent.max(min_indent) {
+                    break;
+                }
+                content.extend(std::iter::repeat_n("", trailing_blank));
+                trailing_blank = 0;
+                content.push(&line.raw[indent..]);
+            }
+            self.pos += 1;
+        }
+        // Blank lines after the block belong to whatever follows
+        self.pos -= trailing_blank;
+
+        let mut text = String::new();
+        for (i, line) in content.iter().enumerate() {
+            if i > 0 {
+                let paragraph = line.is_empty() || content[i - 1].is_empty();
+                let indented = line.starts_with(' ') || content[i - 1].starts_with(' ');
+                if !folded || indented || (paragraph && !line.is_empty()) {
+                    text.push('\n');
+                } else if !paragraph {
+                    text.push(' ');
+                }
+            }
+            text.push_str(line);
+        }
+        match chomp {
+            Some('-') => {}
+            _ if content.is_empty() => {}
+            Some('+') => text.push_str(&"\n".repeat(trailing_blank + 1)),
+            _ => text.push('\n'),
+        }
+        Ok(Value::String(text))
+    }
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Split a `key: value` line into the key and the (possibly empty) rest
+fn split_key(text: &str) -> Option<(String, &str)> {
+    let (key, rest) = match text.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let (key, len) = quoted(text, quote)?;
+            let rest = text[len..].trim_start().strip_prefix(':')?;
+            (key, rest)
+        }
+        '[' | '{' | '-' | '?' | '|' | '>' => return None,
+        _ => {
+            let mut depth = 0i32;
+            let bytes = text.as_bytes();
+            let colon = text.char_indices().find_map(|(i, c)| {
+                match c {
+                    '[' | '{' => depth += 1,
+                    ']' | '}' => depth -= 1,
+                    ':' if depth == 0 && bytes.get(i + 1).is_none_or(|b| *b == b' ') => {
+                        return Some(i);
+                    }
+                    _ => {}
+                }
+                None
+            })?;
+            (text[..colon].trim_end().to_string(), &text[colon + 1..])
+        }
+    };
+    if !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((key, rest.trim()))
+}
+
+/// The line up to any `#` comment outside quotes, without trailing spaces
+fn strip_comment(text: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    // Quotes only open a scalar where one starts, not inside plain text
+    let mut at_start = true;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some('"') if c == '\\' => {
+                chars.next();
+            }
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '#' if prev.is_whitespace() => return text[..i].trim_end(),
+                '"' | '\'' if at_start => quote = Some(c),
+                _ => {}
+            },
+        }
+        if quote.is_none() && !c.is_whitespace() {
+            at_start = matches!(c, ':' | '-' | ',' | '[' | '{' | '?');
+        }
+        prev = c;
+    }
+    text.trim_end()
+}
+
+/// Whether every bracket opened in `text` (outside quotes) is closed
+fn balanced(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in text.chars() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    depth <= 0
+}
+
+// ============================================================================
+// Scalars
+// ============================================================================
+
+/// A quoted scalar at the start of `text`, unescaped, with the length of
+/// the quoted text
+fn quoted(text: &str, quote: char) -> Option<(String, usize)> {
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        if c == quote {
+            // '' is an escaped quote inside single quotes
+            if quote == '\'' && text[i + 1..].starts_with('\'') {
+                chars.next();
+                value.push('\'');
+                continue;
+            }
+            return Some((value, i + 1));
+        }
+        if quote == '"' && c == '\\' {
+            let (_, escape) = chars.next()?;
+            match escape {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                '0' => value.push('\0'),
+                'b' => value.push('\u{8}'),
+                'f' => value.push('\u{c}'),
+                'e' => value.push('\u{1b}'),
+                'x' | 'u' | 'U' => {
+                    let digits = match escape {
+                        'x' => 2,
+                        'u' => 4,
+                        _ => 8,
+                    };
+                    let hex: String = (0..digits)
+                        .filter_map(|_| chars.next())
+                        .map(|(_, c)| c)
+                        .collect();
+                    value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                other => value.push(other),
+            }
+        } else {
+            value.push(c);
+        }
+    }
+    None
+}
+
+/// Resolve a plain scalar to null, a boolean, a number or a string
+fn plain_scalar(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => return Value::Null,
+        "true" | "True" | "TRUE" => return Value::Bool(true),
+        "false" | "False" | "FALSE" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(n) = text.parse::<i64>() {
+        return Value::Number(n.into());
+    }
+    let numeric = text.chars().any(|c| c.is_ascii_digit())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    if numeric && let Some(n) = text.parse::<f64>().ok().and_then(Number::from_f64) {
+        return Value::Number(n);
+    }
+    Value::String(text.to_string())
+}
+
+// ============================================================================
+// Flow collections
+// ============================================================================
+
+/// A cursor over a flow collection, e.g. `{type: string, enum: [a, b]}`
+struct Flow<'t> {
+    text: &'t str,
+    pos: usize,
+    line: usize,
+}
+
+impl<'t> Flow<'t> {
+    fn new(text: &'t str, line: usize) -> Self {
+        Self { text, pos: 0, line }
+    }
+
+    fn rest(&self) -> &'t str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_spaces();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn end(&mut self) -> EngineResult<()> {
+        self.skip_spaces();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(error(self.line, "unexpected text after a flow collection"))
+        }
+    }
+
+    fn value(&mut self) -> EngineResult<Value> {
+        self.skip_spaces();
+        match self.rest().chars().next() {
+            Some('[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while !self.eat(']') {
+                    items.push(self.value()?);
+                    if !self.eat(',') && !self.rest().starts_with(']') {
+                        return Err(error(self.line, "expected `,` or `]`"));
+                    }
+                }
+                Ok(Value::Array(items))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let mut map = Map::new();
+                while !self.eat('}') {
+                    let key = match self.scalar(true)? {
+                        Value::String(key) => key,
+                        other => other.to_string(),
+                    };
+                    let value = if self.eat(':') {
+                        self.value()?
+                    } else {
+                        Value::Null
+                    };
+                    map.insert(key, value);
+                    if !self.eat(',') && !self.rest().starts_with('}') {
+                        return Err(error(self.line, "expected `,` or `}`"));
+                    }
+                }
+                Ok(Value::Object(map))
+            }
+            Some('&' | '*') => Err(error(
+                self.line,
+                "YAML anchors and aliases aren't supported",
+            )),
+            _ => self.scalar(false),
+        }
+    }
+
+    /// A quoted or plain scalar; keys also end at `:`
+    fn scalar(&mut self, key: bool) -> EngineResult<Value> {
+        self.skip_spaces();
+        let rest = self.rest();
+        if let Some(quote @ ('"' | '\'')) = rest.chars().next() {
+            let (value, len) =
+                quoted(rest, quote).ok_or_else(|| error(self.line, "unterminated string"))?;
+            self.pos += len;
+            return Ok(Value::String(value));
+        }
+
+        let bytes = rest.as_bytes();
+        let end = rest
+            .char_indices()
+            .find(|&(i, c)| {
+                matches!(c, ',' | ']' | '}')
+                    || (c == ':' && (key || bytes.get(i + 1).is_none_or(|b| *b == b' ')))
+            })
+            .map_or(rest.len(), |(i, _)| i);
+        self.pos += end;
+        let text = rest[..end].trim();
+        Ok(if key {
+            Value::String(text.to_string())
+        } else {
+            plain_scalar(text)
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_block_collections_and_scalars() {
+        let yaml = r#"
+# An API
+openapi: 3.1.0
+info:
+  title: "Pet store: v2"   # quoted because of the colon
+  version: '1.0'
+  x-count: 3
+  x-ratio: 0.5
+  x-flag: true
+  x-none: ~
+servers:
+  - url: http://localhost:8080
+    description: it's local
+tags:
+- name: pets
+- plain item
+paths:
+  /pets/{id}:
+    get:
+      parameters: []
+      responses: {}
+"#;
+        let value = parse(yaml).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "openapi": "3.1.0",
+                "info": {
+                    "title": "Pet store: v2",
+                    "version": "1.0",
+                    "x-count": 3,
+                    "x-ratio": 0.5,
+                    "x-flag": true,
+                    "x-none": null,
+                },
+                "servers": [{"url": "http://localhost:8080", "description": "it's local"}],
+                "tags": [{"name": "pets"}, "plain item"],
+                "paths": {"/pets/{id}": {"get": {"parameters": [], "responses": {}}}},
+            })
+        );
+    }
+
+    #[test]
+    fn test_flow_block_and_folded_scalars() {
+        let yaml = "---\n\
+            required: [name, \"tag\"]\n\
+            schema: {type: string, enum: [a, 'b c'], maxLength: 10}\n\
+            multi: [one,\n  two]\n\
+            literal: |\n  line one\n    indented\n\n  line three\nfolded: >-\n  a long\n  sentence\n\n  next\n\
+            plain: wrapped\n  onto two lines\n\
+            escaped: \"tab\\there \\u00e9\"\n";
+        let value = parse(yaml).unwrap();
+        assert_eq!(value["required"], json!(["name", "tag"]));
+        assert_eq!(
+            value["schema"],
+            json!({"type": "string", "enum": ["a", "b c"], "maxLength": 10})
+        );
+        assert_eq!(value["multi"], json!(["one", "two"]));
+        assert_eq!(value["literal"], "line one\n  indented\n\nline three\n");
+        assert_eq!(value["folded"], "a long sentence\nnext");
+        assert_eq!(value["plain"], "wrapped onto two lines");
+        assert_eq!(value["escaped"], "tab\there é");
+    }
+
+    #[test]
+    fn test_errors() {
+        let message = |yaml: &str| parse(yaml).unwrap_err().to_string();
+        assert!(message("a: 1\n  b: 2\n").contains("line 2"));
+        assert!(message("a: 1\na: 2\n").contains("duplicate key `a`"));
+        assert!(message("a: &anchor 1\nb: *anchor\n").contains("anchors"));
+        assert!(message("a: 1\n---\nb: 2\n").contains("multiple YAML documents"));
+        assert!(message("a: \"open\n").contains("unterminated"));
+        assert_eq!(parse("").unwrap(), Value::Null);
+    }
+}
@@ -0,0 +1,906 @@
+//! # Editor Actions
+//!
+//! The project edits behind the editor's core flows — adding entities and
+//! fields, connecting entities, generating endpoints, duplicating entities,
+//! copying fields between them, including field sets and acting on command
+//! palette picks and
+//! Problems panel clicks — as methods on [`AppState`]. Components
+//! call them through `APP_STATE.write()` and keep the dialog, status bar and
+//! logging concerns to themselves.
+//!
+//! Because these touch only plain state, the flows can be exercised end to
+//! end without a Dioxus runtime; the tests at the bottom of this file walk
+//! through them and check the resulting [`ProjectGraph`](imortal_ir::ProjectGraph).
+
+use imortal_core::{EngineError, EngineResult, Position, Size, Validatable};
+use imortal_ir::validation::ValidationTarget;
+use imortal_ir::{Entity, Field, FieldSet, Relationship};
+use uuid::Uuid;
+
+use crate::components::dialogs::EndpointTab;
+use crate::components::entity_card::{CARD_WIDTH, card_height};
+use crate::hooks::use_canvas::entities_bounds;
+use crate::palette::{PaletteAction, PaletteTarget};
+use crate::problems::Problem;
+use crate::state::{AppState, Dialog, Page, StatusLevel};
+
+impl AppState {
+    /// Add an entity to the project and select it.
+    ///
+    /// Returns the entity ID, or `None` if no project is open.
+    pub fn add_entity(&mut self, entity: Entity) -> Option<Uuid> {
+        let project = self.project.as_mut()?;
+        let id = entity.id;
+        project.entities.insert(id, entity);
+        self.mark_dirty();
+        self.selection.select_entity(id);
+        Some(id)
+    }
+
+    /// Create a blank, numbered entity at a canvas position (the canvas
+    /// "add entity" action), select it and record it in history.
+    pub fn create_entity_at(&mut self, position: Position) -> Option<Uuid> {
+        let count = self.project.as_ref()?.entities.len();
+
+        let mut entity = Entity::new(format!("Entity{}", count + 1));
+        entity.position = position;
+        entity.size = Size::default_entity();
+
+        let id = self.add_entity(entity)?;
+        self.save_to_history("Create entity");
+        Some(id)
+    }
+
+    /// Add a field to an entity and select it.
+    ///
+    /// Returns the field ID, or `None` if the entity does not exist.
+    pub fn add_field(&mut self, entity_id: Uuid, field: Field) -> Option<Uuid> {
+        let entity = self.project.as_mut()?.entities.get_mut(&entity_id)?;
+        let id = field.id;
+        entity.add_field(field);
+        entity.touch();
+        self.mark_dirty();
+        self.selection.field = Some((entity_id, id));
+        Some(id)
+    }
+
+    /// Connect two entities, adding the foreign key field the relationship
+    /// implies.
+    ///
+    /// Returns the relationship ID and the new FK field's ID, if one was
+    /// needed (many-to-many relationships have none).
+    pub fn create_relationship(
+        &mut self,
+        relationship: Relationship,
+    ) -> EngineResult<(Uuid, Option<Uuid>)> {
+        let project = self
+            .project
+            .as_mut()
+            .ok_or_else(|| EngineError::UiState("No project is open".to_string()))?;
+        let created = project.create_relationship_with_fk(relationship)?;
+        self.mark_dirty();
+        Ok(created)
+    }
+
+    /// Model a many-to-many relationship's junction table as an entity of
+    /// its own, so it can carry extra columns, and select it.
+    ///
+    /// Returns the new junction entity's ID.
+    pub fn promote_junction(&mut self, relationship_id: Uuid) -> EngineResult<Uuid> {
+        let project = self
+            .project
+            .as_mut()
+            .ok_or_else(|| EngineError::UiState("No project is open".to_string()))?;
+        let junction = project.promote_junction(relationship_id)?;
+        self.mark_dirty();
+        self.selection.select_entity(junction);
+        Ok(junction)
+    }
+
+    /// Give every entity without an endpoint group a default CRUD group
+    /// (the endpoints page "generate all" action).
+    ///
+    /// Returns how many groups were created.
+    pub fn generate_missing_endpoints(&mut self) -> usize {
+        let Some(project) = self.project.as_mut() else {
+            return 0;
+        };
+
+        let mut uncovered: Vec<(Uuid, String)> = project
+            .entities
+            .values()
+            .filter(|e| project.endpoints.values().all(|ep| ep.entity_id != e.id))
+            .map(|e| (e.id, e.name.clone()))
+            .collect();
+        uncovered.sort_by(|a, b| a.1.cmp(&b.1));
+
+        for (id, name) in &uncovered {
+            let endpoint = project.new_endpoint(*id, name);
+            project.add_endpoint(endpoint);
+        }
+
+        let count = uncovered.len();
+        if count > 0 {
+            self.mark_dirty();
+            self.ui.set_status(
+                format!("Generated endpoints for {} entities", count),
+                StatusLevel::Success,
+            );
+        }
+        count
+    }
+
+    /// Pin an entity to the top of the entity list, or unpin it. Favorites
+    /// are saved with the project.
+    ///
+    /// Returns whether the entity is now pinned.
+    pub fn toggle_favorite_entity(&mut self, entity_id: Uuid) -> bool {
+        let Some(project) = self
+            .project
+            .as_mut()
+            .filter(|p| p.entities.contains_key(&entity_id))
+        else {
+            return false;
+        };
+        let pinned = project.toggle_favorite(entity_id);
+        self.mark_dirty();
+        pinned
+    }
+
+    /// Select an entity and pan the canvas so its card is centered (the
+    /// entity list's click action).
+    ///
+    /// Returns `false` if the entity does not exist.
+    pub fn focus_entity(&mut self, entity_id: Uuid) -> bool {
+        let Some(entity) = self
+            .project
+            .as_ref()
+            .and_then(|p| p.entities.get(&entity_id))
+        else {
+            return false;
+        };
+        let height = card_height(entity, self.canvas.detail()) as f32;
+        let center = Position::new(
+            entity.position.x + CARD_WIDTH as f32 / 2.0,
+            entity.position.y + height / 2.0,
+        );
+
+        self.canvas.center_on(center);
+        self.selection.select_entity(entity_id);
+        true
+    }
+
+    /// Jump to an entity's endpoint group on the Endpoints page (the
+    /// entity card coverage badge action), or offer to create one if the
+    /// entity has none.
+    pub fn open_endpoints_for(&mut self, entity_id: Uuid) {
+        let Some(project) = self.project.as_ref() else {
+            return;
+        };
+        let group = project
+            .endpoints
+            .values()
+            .find(|ep| ep.entity_id == entity_id)
+            .map(|ep| ep.id);
+
+        self.ui.navigate(Page::Endpoints);
+        match group {
+            Some(id) => {
+                self.selection.clear();
+                self.selection.endpoints.insert(id);
+            }
+            None => self.ui.show_dialog(Dialog::NewEndpoint(Some(entity_id))),
+        }
+    }
+
+    /// Act on a command palette pick: go to the page an object lives on,
+    /// select it and center the canvas on it, or run the chosen action.
+    /// Closes the palette.
+    pub fn open_palette_target(&mut self, target: PaletteTarget) {
+        self.ui.palette_open = false;
+
+        match target {
+            PaletteTarget::Entity(id) => {
+                if self.focus_entity(id) {
+                    self.ui.navigate(Page::EntityDesign);
+                }
+            }
+            PaletteTarget::Field(entity_id, field_id) => {
+                if self.focus_entity(entity_id) {
+                    self.selection.field = Some((entity_id, field_id));
+                    self.ui.navigate(Page::EntityDesign);
+                }
+            }
+            PaletteTarget::Relationship(id) => {
+                let Some(project) = &self.project else {
+                    return;
+                };
+                let Some(rel) = project.relationships.get(&id) else {
+                    return;
+                };
+                let ends = [rel.from_entity_id, rel.to_entity_id];
+                if let Some(bounds) =
+                    entities_bounds(ends.iter().filter_map(|e| project.entities.get(e)))
+                {
+                    self.canvas.center_on(bounds.center());
+                }
+                self.selection.clear();
+                self.selection.relationships.insert(id);
+                self.ui.navigate(Page::Relationships);
+            }
+            PaletteTarget::Endpoint(id) => {
+                let Some(entity_id) = self
+                    .project
+                    .as_ref()
+                    .and_then(|p| p.endpoints.get(&id))
+                    .map(|ep| ep.entity_id)
+                else {
+                    return;
+                };
+                self.focus_entity(entity_id);
+                self.selection.clear();
+                self.selection.endpoints.insert(id);
+                self.ui.navigate(Page::Endpoints);
+            }
+            PaletteTarget::Action(action) => self.run_palette_action(action),
+        }
+    }
+
+    /// Jump to the element a Problems panel entry is about: select it, go to
+    /// its page and open its edit dialog — for endpoints, on the tab that
+    /// holds the offending setting. Problems without a target, or whose
+    /// element has since been removed, are ignored.
+    pub fn open_problem(&mut self, problem: &Problem) {
+        let Some(target) = problem.target else {
+            return;
+        };
+        let Some(project) = &self.project else {
+            return;
+        };
+        let (jump, dialog) = match target {
+            ValidationTarget::Entity(id) if project.entities.contains_key(&id) => {
+                (PaletteTarget::Entity(id), Dialog::EditEntity(id))
+            }
+            ValidationTarget::Field {
+                entity_id,
+                field_id,
+            } if project
+                .get_entity(entity_id)
+                .is_some_and(|e| e.get_field(field_id).is_some()) =>
+            {
+                (
+                    PaletteTarget::Field(entity_id, field_id),
+                    Dialog::EditField(entity_id, field_id),
+                )
+            }
+            ValidationTarget::Relationship(id) if project.relationships.contains_key(&id) => (
+                PaletteTarget::Relationship(id),
+                Dialog::EditRelationship(id),
+            ),
+            ValidationTarget::Endpoint(id) if project.endpoints.contains_key(&id) => {
+                (PaletteTarget::Endpoint(id), Dialog::EditEndpoint(id))
+            }
+            _ => return,
+        };
+
+        self.open_palette_target(jump);
+        self.ui.show_dialog(dialog);
+        if let ValidationTarget::Endpoint(_) = target {
+            self.ui.endpoint_tab = problem.path.as_deref().map(EndpointTab::for_path);
+        }
+    }
+
+    fn run_palette_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::GoTo(page) => self.ui.navigate(page),
+            PaletteAction::NewProject => self.ui.show_dialog(Dialog::NewProject),
+            PaletteAction::OpenProject => self.ui.show_dialog(Dialog::OpenProject),
+            PaletteAction::ProjectSettings => self.ui.show_dialog(Dialog::ProjectSettings),
+            PaletteAction::NewEntity => {
+                self.ui.navigate(Page::EntityDesign);
+                self.ui.show_dialog(Dialog::NewEntity);
+            }
+            PaletteAction::NewRelationship => {
+                self.ui.show_dialog(Dialog::NewRelationship(None, None));
+            }
+            PaletteAction::NewEndpoint => self.ui.show_dialog(Dialog::NewEndpoint(None)),
+            PaletteAction::Undo => {
+                self.undo();
+            }
+            PaletteAction::Redo => {
+                self.redo();
+            }
+            PaletteAction::FitToContent => {
+                let bounds = self
+                    .project
+                    .as_ref()
+                    .and_then(|p| entities_bounds(p.entities.values()));
+                if let Some(bounds) = bounds {
+                    self.canvas.fit_rect(bounds);
+                }
+                self.ui.navigate(Page::EntityDesign);
+            }
+            PaletteAction::ToggleHistory => self.ui.toggle_history(),
+            PaletteAction::ToggleHelp => self.ui.toggle_help(),
+            PaletteAction::CycleTheme => self.ui.cycle_theme(),
+        }
+    }
+
+    /// Duplicate entities, select the copies and record the change in
+    /// history; returns the new entity IDs
+    pub fn duplicate_entities(&mut self, ids: &[Uuid]) -> Vec<Uuid> {
+        let count = self.project.as_ref().map_or(0, |p| {
+            ids.iter().filter(|id| p.entities.contains_key(id)).count()
+        });
+        if count == 0 {
+            return Vec::new();
+        }
+        self.save_to_history(if count == 1 {
+            "Duplicate entity"
+        } else {
+            "Duplicate entities"
+        });
+
+        let new_ids: Vec<Uuid> = match &mut self.project {
+            Some(project) => ids
+                .iter()
+                .filter_map(|id| project.duplicate_entity(*id))
+                .collect(),
+            None => Vec::new(),
+        };
+        self.selection.clear();
+        self.selection.entities.extend(new_ids.iter().copied());
+        self.mark_dirty();
+        new_ids
+    }
+
+    /// Copy fields of an entity to the field clipboard, in the entity's
+    /// field order; returns how many were copied
+    pub fn copy_fields(&mut self, entity_id: Uuid, field_ids: &[Uuid]) -> usize {
+        let Some(entity) = self
+            .project
+            .as_ref()
+            .and_then(|p| p.entities.get(&entity_id))
+        else {
+            return 0;
+        };
+        let fields: Vec<Field> = entity
+            .sorted_fields()
+            .into_iter()
+            .filter(|f| field_ids.contains(&f.id))
+            .cloned()
+            .collect();
+        if !fields.is_empty() {
+            self.field_clipboard = fields;
+        }
+        self.field_clipboard.len()
+    }
+
+    /// Copy every field of an entity except its primary key, which the
+    /// target entity will already have
+    pub fn copy_entity_fields(&mut self, entity_id: Uuid) -> usize {
+        let field_ids: Vec<Uuid> = self
+            .project
+            .as_ref()
+            .and_then(|p| p.entities.get(&entity_id))
+            .map(|e| {
+                e.fields
+                    .iter()
+                    .filter(|f| !f.is_primary_key)
+                    .map(|f| f.id)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if field_ids.is_empty() {
+            return 0;
+        }
+        self.copy_fields(entity_id, &field_ids)
+    }
+
+    /// Paste the field clipboard into each of the given entities as one
+    /// undo step; returns the new field IDs
+    pub fn paste_fields(&mut self, entity_ids: &[Uuid]) -> Vec<Uuid> {
+        let exists = self
+            .project
+            .as_ref()
+            .is_some_and(|p| entity_ids.iter().any(|id| p.entities.contains_key(id)));
+        if self.field_clipboard.is_empty() || !exists {
+            return Vec::new();
+        }
+        self.save_to_history(if self.field_clipboard.len() == 1 {
+            "Paste field"
+        } else {
+            "Paste fields"
+        });
+
+        let mut ids = Vec::new();
+        if let Some(project) = &mut self.project {
+            for entity_id in entity_ids {
+                if let Some(entity) = project.entities.get_mut(entity_id) {
+                    ids.extend(entity.paste_fields(&self.field_clipboard));
+                }
+            }
+        }
+        self.mark_dirty();
+        ids
+    }
+
+    /// Set the field sets an entity includes, in order, adding any the
+    /// project doesn't have yet (a built-in picked for the first time)
+    pub fn set_entity_field_sets(&mut self, entity_id: Uuid, sets: &[FieldSet]) -> bool {
+        let Some(project) = &mut self.project else {
+            return false;
+        };
+        if !project.entities.contains_key(&entity_id) {
+            return false;
+        }
+        for set in sets {
+            if project.get_field_set(set.id).is_none() {
+                project.add_field_set(set.clone());
+            }
+        }
+        if let Some(entity) = project.entities.get_mut(&entity_id) {
+            entity.field_sets = sets.iter().map(|s| s.id).collect();
+            entity.touch();
+        }
+        self.mark_dirty();
+        true
+    }
+
+    /// Make a project field set from the field clipboard; returns its ID.
+    ///
+    /// A primary key is left out, and foreign keys become plain columns
+    /// since relationships belong to entities.
+    pub fn field_set_from_clipboard(&mut self, name: &str) -> EngineResult<Uuid> {
+        let name = name.trim();
+        match &self.project {
+            None => return Err(EngineError::validation("No project is open")),
+            Some(project) if project.get_field_set_by_name(name).is_some() => {
+                return Err(EngineError::validation(format!(
+                    "A field set named '{}' already exists",
+                    name
+                )));
+            }
+            Some(_) => {}
+        }
+
+        let mut set = FieldSet::new(name);
+        for field in self.field_clipboard.iter().filter(|f| !f.is_primary_key) {
+            let mut field = field.clone();
+            field.id = Uuid::new_v4();
+            field.is_foreign_key = false;
+            field.foreign_key_ref = None;
+            set.fields.push(field);
+        }
+        set.validate()?;
+
+        self.save_to_history("Create field set");
+        let id = set.id;
+        if let Some(project) = &mut self.project {
+            project.add_field_set(set);
+        }
+        self.mark_dirty();
+        Ok(id)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_codegen::{Generator, GeneratorConfig};
+    use imortal_core::{DataType, RelationType};
+    use imortal_ir::EndpointGroup;
+
+    use crate::problems::Severity;
+
+    /// A fresh editor with an empty project open.
+    fn editor() -> AppState {
+        let mut state = AppState::new();
+        state.new_project("blog");
+        state
+    }
+
+    fn field(name: &str, data_type: DataType) -> Field {
+        let mut field = Field::new(name, data_type);
+        field.required = true;
+        field
+    }
+
+    #[test]
+    fn test_core_flow_produces_generatable_project() {
+        let mut state = editor();
+
+        // Create entities
+        let user = state.add_entity(Entity::new("User")).unwrap();
+        let post = state.add_entity(Entity::new("Post")).unwrap();
+        assert!(state.selection.is_entity_selected(&post));
+
+        // Add fields
+        state
+            .add_field(user, field("email", DataType::String))
+            .unwrap();
+        let title = state
+            .add_field(post, field("title", DataType::String))
+            .unwrap();
+        assert_eq!(state.selection.field, Some((post, title)));
+
+        // Draw a relationship: User has many Posts
+        let (rel_id, fk) = state
+            .create_relationship(Relationship::new(user, post, RelationType::OneToMany))
+            .unwrap();
+
+        // Auto-generate endpoints
+        assert_eq!(state.generate_missing_endpoints(), 2);
+
+        let project = state.project.as_ref().unwrap();
+        let post_entity = project.get_entity(post).unwrap();
+        let fk_field = post_entity.get_field(fk.unwrap()).unwrap();
+        assert_eq!(fk_field.name, "user_id");
+        assert!(fk_field.is_foreign_key);
+        assert!(post_entity.has_field("title"));
+        assert!(project.get_entity(user).unwrap().has_field("email"));
+        assert_eq!(project.relationships[&rel_id].from_field, "user_id");
+        assert_eq!(project.endpoints.len(), 2);
+        assert!(state.is_dirty);
+
+        // Generate code
+        let output = Generator::new(GeneratorConfig::new())
+            .generate(project)
+            .unwrap();
+        let paths: Vec<String> = output
+            .files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert!(paths.iter().any(|p| p == "src/handlers/user.rs"));
+        assert!(paths.iter().any(|p| p == "src/handlers/post.rs"));
+        let model = output
+            .files
+            .iter()
+            .find(|f| f.path.to_string_lossy() == "src/models/post.rs")
+            .unwrap();
+        assert!(model.content.contains("user_id"));
+    }
+
+    #[test]
+    fn test_create_entity_at_numbers_and_records_history() {
+        let mut state = editor();
+        let position = Position::new(120.0, 80.0);
+
+        let first = state.create_entity_at(position).unwrap();
+        let second = state.create_entity_at(position).unwrap();
+
+        let project = state.project.as_ref().unwrap();
+        assert_eq!(project.get_entity(first).unwrap().name, "Entity1");
+        assert_eq!(project.get_entity(second).unwrap().name, "Entity2");
+        assert_eq!(project.get_entity(second).unwrap().position, position);
+        assert!(state.selection.is_entity_selected(&second));
+        assert!(!state.selection.is_entity_selected(&first));
+        assert_eq!(state.history.undo_count(), 2);
+    }
+
+    #[test]
+    fn test_generate_missing_endpoints_skips_covered_entities() {
+        let mut state = editor();
+        let user = state.add_entity(Entity::new("User")).unwrap();
+        state.add_entity(Entity::new("Post")).unwrap();
+
+        let project = state.project.as_mut().unwrap();
+        project.add_endpoint(EndpointGroup::new(user, "User"));
+
+        assert_eq!(state.generate_missing_endpoints(), 1);
+        assert_eq!(state.generate_missing_endpoints(), 0);
+        assert_eq!(state.project.as_ref().unwrap().endpoints.len(), 2);
+    }
+
+    #[test]
+    fn test_open_endpoints_for_selects_group_or_offers_one() {
+        let mut state = editor();
+        let user = state.add_entity(Entity::new("User")).unwrap();
+        let post = state.add_entity(Entity::new("Post")).unwrap();
+        let group = EndpointGroup::new(user, "User");
+        let group_id = group.id;
+        state.project.as_mut().unwrap().add_endpoint(group);
+
+        state.open_endpoints_for(user);
+        assert_eq!(state.ui.active_page, Page::Endpoints);
+        assert!(state.selection.endpoints.contains(&group_id));
+        assert!(state.selection.entities.is_empty());
+        assert!(state.ui.active_dialog.is_none());
+
+        state.open_endpoints_for(post);
+        assert!(matches!(
+            state.ui.active_dialog,
+            Some(Dialog::NewEndpoint(Some(id))) if id == post
+        ));
+    }
+
+    #[test]
+    fn test_favorites_and_focus() {
+        let mut state = editor();
+        let user = state
+            .add_entity(Entity::new("User").at(1000.0, 500.0))
+            .unwrap();
+        state.add_entity(Entity::new("Post")).unwrap();
+        state.is_dirty = false;
+
+        assert!(state.toggle_favorite_entity(user));
+        assert!(state.is_dirty);
+        assert!(state.project.as_ref().unwrap().is_favorite(user));
+        assert!(!state.toggle_favorite_entity(Uuid::new_v4()));
+        assert!(!state.toggle_favorite_entity(user));
+
+        state.canvas.viewport = Size::new(800.0, 600.0);
+        assert!(state.focus_entity(user));
+        assert!(state.selection.is_entity_selected(&user));
+        assert_eq!(state.selection.entities.len(), 1);
+        // The card's top-left sits half a card up and left of the center
+        let corner = state.canvas.canvas_to_screen(Position::new(1000.0, 500.0));
+        assert_eq!(corner.x, 400.0 - CARD_WIDTH as f32 / 2.0);
+        assert!(corner.y < 300.0);
+        assert!(!state.focus_entity(Uuid::new_v4()));
+    }
+
+    #[test]
+    fn test_duplicate_and_paste_fields() {
+        let mut state = editor();
+        let project = state.project.as_mut().unwrap();
+        let product = project.add_entity(Entity::with_timestamps("Product"));
+        let order = project.add_entity(Entity::new("Order"));
+
+        let copies = state.duplicate_entities(&[product]);
+        assert_eq!(copies.len(), 1);
+        assert_eq!(state.selection.entities.len(), 1);
+        assert!(state.selection.is_entity_selected(&copies[0]));
+        assert!(state.history.can_undo());
+
+        // The primary key is left behind when copying a whole entity's fields
+        assert_eq!(state.copy_entity_fields(product), 2);
+        let pasted = state.paste_fields(&[order]);
+        assert_eq!(pasted.len(), 2);
+        let target = &state.project.as_ref().unwrap().entities[&order];
+        assert!(target.has_field("created_at") && target.has_field("updated_at"));
+        assert_eq!(target.fields.len(), 3);
+
+        // Undo restores the entity as it was before the paste
+        assert!(state.undo());
+        assert_eq!(
+            state.project.as_ref().unwrap().entities[&order]
+                .fields
+                .len(),
+            1
+        );
+
+        // Copying nothing keeps the previous clipboard
+        assert_eq!(state.copy_fields(order, &[]), 2);
+    }
+
+    #[test]
+    fn test_field_sets_from_builtins_and_clipboard() {
+        let mut state = editor();
+        let project = state.project.as_mut().unwrap();
+        let product = project.add_entity(Entity::new("Product"));
+        let mut store = Entity::new("Store");
+        store.add_field(Field::new("street", DataType::String));
+        store.add_field(Field::new("city", DataType::String));
+        let store = project.add_entity(store);
+
+        // Picking a built-in adds it to the project
+        let timestamps = FieldSet::timestamps();
+        assert!(state.set_entity_field_sets(product, std::slice::from_ref(&timestamps)));
+        let project = state.project.as_ref().unwrap();
+        assert_eq!(project.field_sets.len(), 1);
+        assert_eq!(project.entities[&product].field_sets, vec![timestamps.id]);
+        assert!(!state.set_entity_field_sets(Uuid::new_v4(), &[]));
+
+        // Copied fields become a set of their own, without the primary key
+        assert_eq!(state.copy_entity_fields(store), 2);
+        let address = state.field_set_from_clipboard("Address").unwrap();
+        let set = state
+            .project
+            .as_ref()
+            .unwrap()
+            .get_field_set(address)
+            .unwrap();
+        assert_eq!(set.field_names(), vec!["street", "city"]);
+        assert!(state.field_set_from_clipboard("Address").is_err());
+        assert!(state.field_set_from_clipboard("lowercase").is_err());
+        assert!(state.history.can_undo());
+    }
+
+    #[test]
+    fn test_open_palette_targets() {
+        let mut state = editor();
+        let user = state
+            .add_entity(Entity::new("User").at(1000.0, 500.0))
+            .unwrap();
+        let post = state.add_entity(Entity::new("Post")).unwrap();
+        let (rel, fk) = state
+            .create_relationship(Relationship::new(user, post, RelationType::OneToMany))
+            .unwrap();
+        let group = EndpointGroup::new(user, "User");
+        let group_id = group.id;
+        state.project.as_mut().unwrap().add_endpoint(group);
+        state.canvas.viewport = Size::new(800.0, 600.0);
+
+        state.ui.palette_open = true;
+        state.open_palette_target(PaletteTarget::Field(post, fk.unwrap()));
+        assert!(!state.ui.palette_open);
+        assert_eq!(state.ui.active_page, Page::EntityDesign);
+        assert_eq!(state.selection.field, Some((post, fk.unwrap())));
+        assert!(state.selection.is_entity_selected(&post));
+
+        state.open_palette_target(PaletteTarget::Relationship(rel));
+        assert_eq!(state.ui.active_page, Page::Relationships);
+        assert!(state.selection.is_relationship_selected(&rel));
+
+        state.open_palette_target(PaletteTarget::Endpoint(group_id));
+        assert_eq!(state.ui.active_page, Page::Endpoints);
+        assert!(state.selection.endpoints.contains(&group_id));
+        // The canvas is left centered on the group's entity
+        let corner = state.canvas.canvas_to_screen(Position::new(1000.0, 500.0));
+        assert_eq!(corner.x, 400.0 - CARD_WIDTH as f32 / 2.0);
+
+        state.open_palette_target(PaletteTarget::Action(PaletteAction::NewEntity));
+        assert_eq!(state.ui.active_dialog, Some(Dialog::NewEntity));
+
+        // Stale targets are ignored
+        state.open_palette_target(PaletteTarget::Entity(Uuid::new_v4()));
+        assert_eq!(state.ui.active_page, Page::EntityDesign);
+    }
+
+    #[test]
+    fn test_open_problem() {
+        let mut state = editor();
+        let user = state.add_entity(Entity::new("User")).unwrap();
+        let post = state.add_entity(Entity::new("Post")).unwrap();
+        let (rel, _) = state
+            .create_relationship(Relationship::new(user, post, RelationType::OneToMany))
+            .unwrap();
+        let group = EndpointGroup::new(user, "User");
+        let group_id = group.id;
+        state.project.as_mut().unwrap().add_endpoint(group);
+        let problem = |target, path: &str| Problem {
+            severity: Severity::Error,
+            code: "Test".into(),
+            message: "test".into(),
+            suggestion: None,
+            path: Some(path.to_string()),
+            target,
+        };
+
+        state.open_problem(&problem(
+            Some(ValidationTarget::Entity(post)),
+            "entities.Post",
+        ));
+        assert_eq!(state.ui.active_page, Page::EntityDesign);
+        assert!(state.selection.is_entity_selected(&post));
+        assert_eq!(state.ui.active_dialog, Some(Dialog::EditEntity(post)));
+
+        state.open_problem(&problem(
+            Some(ValidationTarget::Relationship(rel)),
+            &format!("relationships.{}", rel),
+        ));
+        assert_eq!(state.ui.active_page, Page::Relationships);
+        assert_eq!(state.ui.active_dialog, Some(Dialog::EditRelationship(rel)));
+
+        // Endpoint problems open the dialog on the tab they are about
+        state.open_problem(&problem(
+            Some(ValidationTarget::Endpoint(group_id)),
+            &format!("endpoints.{}.security", group_id),
+        ));
+        assert_eq!(state.ui.active_page, Page::Endpoints);
+        assert_eq!(state.ui.active_dialog, Some(Dialog::EditEndpoint(group_id)));
+        assert_eq!(state.ui.endpoint_tab, Some(EndpointTab::Security));
+
+        // Opening any other dialog starts back on the first tab
+        state.ui.show_dialog(Dialog::EditEndpoint(group_id));
+        assert_eq!(state.ui.endpoint_tab, None);
+
+        // Untargeted and stale problems leave the editor alone
+        state.ui.close_dialog();
+        state.open_problem(&problem(None, "config.rate_limit"));
+        state.open_problem(&problem(
+            Some(ValidationTarget::Entity(Uuid::new_v4())),
+            "entities.Gone",
+        ));
+        assert_eq!(state.ui.active_dialog, None);
+        assert_eq!(state.ui.active_page, Page::Endpoints);
+    }
+
+    #[test]
+    fn test_relationship_to_missing_entity_is_rejected() {
+        let mut state = editor();
+        let user = state.add_entity(Entity::new("User")).unwrap();
+
+        let result = state.create_relationship(Relationship::new(
+            user,
+            Uuid::new_v4(),
+            RelationType::OneToMany,
+        ));
+
+        assert!(result.is_err());
+        assert!(state.project.as_ref().unwrap().relationships.is_empty());
+    }
+
+    #[test]
+    fn test_promote_junction_selects_new_entity() {
+        let mut state = editor();
+        let student = state.add_entity(Entity::new("Student")).unwrap();
+        let course = state.add_entity(Entity::new("Course")).unwrap();
+        let (rel, fk) = state
+            .create_relationship(Relationship::many_to_many(student, course, "enrollments"))
+            .unwrap();
+        assert!(fk.is_none());
+        state.is_dirty = false;
+
+        let junction = state.promote_junction(rel).unwrap();
+        assert!(state.is_dirty);
+        assert!(state.selection.is_entity_selected(&junction));
+        let project = state.project.as_ref().unwrap();
+        assert_eq!(
+            project.get_entity(junction).unwrap().table_name,
+            "enrollments"
+        );
+        assert_eq!(
+            project.relationships[&rel].junction_entity_id,
+            Some(junction)
+        );
+
+        // Once promoted the junction cannot be promoted again
+        assert!(state.promote_junction(rel).is_err());
+    }
+
+    #[test]
+    fn test_actions_without_project_do_nothing() {
+        let mut state = AppState::new();
+
+        assert!(state.add_entity(Entity::new("User")).is_none());
+        assert!(state.create_entity_at(Position::new(0.0, 0.0)).is_none());
+        assert!(
+            state
+                .add_field(Uuid::new_v4(), field("email", DataType::String))
+                .is_none()
+        );
+        assert!(
+            state
+                .create_relationship(Relationship::new(
+                    Uuid::new_v4(),
+                    Uuid::new_v4(),
+                    RelationType::OneToMany,
+                ))
+                .is_err()
+        );
+        assert_eq!(state.generate_missing_endpoints(), 0);
+        assert!(state.promote_junction(Uuid::new_v4()).is_err());
+        assert!(!state.is_dirty);
+    }
+
+    #[test]
+    fn test_undo_reverts_field_added_in_flow() {
+        let mut state = editor();
+        let user = state.add_entity(Entity::new("User")).unwrap();
+        state.save_to_history("Create entity");
+
+        state
+            .add_field(user, field("email", DataType::String))
+            .unwrap();
+        assert!(
+            state
+                .project
+                .as_ref()
+                .unwrap()
+                .get_entity(user)
+                .unwrap()
+                .has_field("email")
+        );
+
+        assert!(state.undo());
+        let project = state.project.as_ref().unwrap();
+        assert!(!project.get_entity(user).unwrap().has_field("email"));
+    }
+}
@@ -6,17 +6,28 @@
 
 use dioxus::prelude::*;
 
+use crate::autosave::{self, AUTOSAVE_INTERVAL, Recovery};
 use crate::components::dialogs::{
     ConfirmDeleteDialog, EndpointDialog, EndpointDialogMode, EntityDialog, EntityDialogMode,
-    FieldDialog, FieldDialogMode, RelationshipDialog, RelationshipDialogMode,
+    FieldDialog, FieldDialogMode, QueryDialog, QueryDialogMode, RelationshipDialog,
+    RelationshipDialogMode,
 };
+use crate::components::{
+    CommandPalette, DiagnosticsOverlay, HelpPanel, HistoryPanel, NumberInput, ProblemsPanel,
+};
+use crate::diagnostics;
 use crate::file_ops;
 use crate::pages::welcome::add_to_recent_projects;
 use crate::pages::{
     CodeGenerationPage, EndpointsPage, EntityDesignPage, ProjectSetupPage, RelationshipsPage,
     WelcomePage,
 };
-use crate::state::{APP_STATE, Dialog, Page, StatusLevel};
+use crate::problems::{self, VALIDATION_DEBOUNCE};
+use crate::session::{self, SessionState};
+use crate::state::{APP_STATE, Dialog, Page, StatusLevel, Theme};
+use imortal_ir::{ProjectGraph, load_project};
+use std::path::PathBuf;
+use std::time::Instant;
 
 // ============================================================================
 // Main App Component
@@ -30,9 +41,167 @@ pub fn App() -> Element {
         tracing::info!("Immortal Engine UI initialized");
     });
 
+    // Restore the last session (project, page, viewport, selection) once
+    use_hook(|| {
+        let saved = session::load_session();
+        let mut state = APP_STATE.write();
+        if let Err(e) = saved.apply(&mut state) {
+            tracing::warn!("{}", e);
+            state.ui.set_status(e, StatusLevel::Warning);
+        }
+
+        // Offer changes autosaved before the app last closed without saving
+        if state.ui.active_dialog.is_none()
+            && let Some(recovery) = autosave::find_recovery(state.project_path.as_deref())
+                .or_else(|| autosave::find_recovery(None))
+        {
+            state.ui.show_dialog(Dialog::RestoreRecovery(recovery));
+        }
+    });
+
+    // Autosave unsaved changes to a recovery file, and remove the file once
+    // the project is saved or closed
+    use_hook(|| {
+        spawn(async move {
+            let mut written: Option<PathBuf> = None;
+            loop {
+                tokio::time::sleep(AUTOSAVE_INTERVAL).await;
+
+                let state = APP_STATE.peek();
+                let target =
+                    state
+                        .project
+                        .as_ref()
+                        .filter(|_| state.is_dirty)
+                        .and_then(|project| {
+                            autosave::recovery_path(state.project_path.as_deref())
+                                .map(|path| (project.clone(), path))
+                        });
+                drop(state);
+
+                // The old file no longer applies once the project is saved,
+                // closed or saved under another name
+                if let Some(old) = &written
+                    && target.as_ref().is_none_or(|(_, path)| path != old)
+                {
+                    autosave::discard_recovery(old);
+                    written = None;
+                }
+                let Some((project, path)) = target else {
+                    continue;
+                };
+                match autosave::write_recovery(&project, &path) {
+                    Ok(()) => written = Some(path),
+                    Err(e) => tracing::warn!("Autosave failed: {}", e),
+                }
+            }
+        })
+    });
+
+    // Persist the session whenever it changes, skipping in-progress drags
+    let mut last_session = use_signal(|| None::<SessionState>);
+    use_effect(move || {
+        let state = APP_STATE.read();
+        if state.canvas.is_panning || state.canvas.dragging_entity.is_some() {
+            return;
+        }
+        let current = SessionState::capture(&state);
+        drop(state);
+
+        if last_session.peek().as_ref() != Some(&current) {
+            session::save_session(&current);
+            last_session.set(Some(current));
+        }
+    });
+
+    // Re-validate the project for the Problems panel once edits settle.
+    // Any state change restarts the wait; drags are checked when they end.
+    let mut last_change = use_signal(|| Some(Instant::now()));
+    use_effect(move || {
+        let _ = APP_STATE.read();
+        last_change.set(Some(Instant::now()));
+    });
+    use_hook(|| {
+        spawn(async move {
+            loop {
+                tokio::time::sleep(VALIDATION_DEBOUNCE / 4).await;
+                let Some(changed) = *last_change.peek() else {
+                    continue;
+                };
+                if changed.elapsed() < VALIDATION_DEBOUNCE {
+                    continue;
+                }
+
+                let state = APP_STATE.peek();
+                if state.canvas.is_panning || state.canvas.dragging_entity.is_some() {
+                    continue;
+                }
+                let found = state
+                    .project
+                    .as_ref()
+                    .map(problems::collect_problems)
+                    .unwrap_or_default();
+                let unchanged = state.problems == found;
+                drop(state);
+
+                last_change.set(None);
+                if !unchanged {
+                    APP_STATE.write().problems = found;
+                }
+            }
+        })
+    });
+
+    // Save once more on exit so the final viewport is not lost mid-drag
+    use_drop(|| {
+        session::save_session(&SessionState::capture(&APP_STATE.peek()));
+    });
+
+    // Memoized so the root only re-renders when the theme itself changes
+    let theme = use_memo(|| APP_STATE.read().ui.theme);
+
     rsx! {
         div {
             class: "app-container h-screen w-screen flex flex-col bg-slate-900 text-slate-100 overflow-hidden",
+            "data-theme": theme().attribute(),
+
+            // F1 toggles the help panel from anywhere in the app
+            onkeydown: move |e: KeyboardEvent| {
+                if e.key() == Key::F1 {
+                    e.prevent_default();
+                    APP_STATE.write().ui.toggle_help();
+                }
+
+                // Ctrl+K - command palette
+                let modifiers = e.modifiers();
+                if let Key::Character(ref c) = e.key()
+                    && (c == "k" || c == "K")
+                    && (modifiers.ctrl() || modifiers.meta())
+                {
+                    e.prevent_default();
+                    APP_STATE.write().ui.toggle_palette();
+                }
+
+                // Ctrl+Shift+D - performance diagnostics overlay
+                if let Key::Character(ref c) = e.key()
+                    && (c == "d" || c == "D")
+                    && (modifiers.ctrl() || modifiers.meta())
+                    && modifiers.shift()
+                {
+                    e.prevent_default();
+                    APP_STATE.write().ui.toggle_diagnostics();
+                }
+
+                // Ctrl+Shift+M - problems panel
+                if let Key::Character(ref c) = e.key()
+                    && (c == "m" || c == "M")
+                    && (modifiers.ctrl() || modifiers.meta())
+                    && modifiers.shift()
+                {
+                    e.prevent_default();
+                    APP_STATE.write().ui.toggle_problems();
+                }
+            },
 
             // Top Toolbar
             Toolbar {}
@@ -49,13 +218,28 @@ pub fn App() -> Element {
 
                 // Right Properties Panel (conditional)
                 PropertiesPanel {}
+
+                // Contextual help sidebar (toggled from the toolbar)
+                HelpPanel {}
+
+                // Undo history browser (toggled from the toolbar)
+                HistoryPanel {}
             }
 
+            // Validation errors and warnings (toggled from the status bar)
+            ProblemsPanel {}
+
             // Status Bar
             StatusBar {}
 
             // Dialog overlay (if active)
             DialogOverlay {}
+
+            // Command palette (Ctrl+K)
+            CommandPalette {}
+
+            // Performance diagnostics (Ctrl+Shift+D)
+            DiagnosticsOverlay {}
         }
     }
 }
@@ -67,12 +251,14 @@ pub fn App() -> Element {
 /// Top toolbar with actions and project info
 #[component]
 fn Toolbar() -> Element {
+    diagnostics::record_render("toolbar");
     let state = APP_STATE.read();
     let has_project = state.has_project();
     let is_dirty = state.is_dirty;
     let project_name = state.project_name().to_string();
     let can_undo = state.history.can_undo();
     let can_redo = state.history.can_redo();
+    let theme = state.ui.theme;
     drop(state);
 
     rsx! {
@@ -188,6 +374,15 @@ fn Toolbar() -> Element {
                         APP_STATE.write().redo();
                     }
                 }
+
+                ToolbarButton {
+                    icon: "🕘",
+                    label: "History",
+                    disabled: !has_project,
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_history();
+                    }
+                }
             }
 
             // Spacer
@@ -213,10 +408,28 @@ fn Toolbar() -> Element {
                 class: "flex items-center gap-1",
 
                 ToolbarButton {
-                    icon: "🌙",
+                    icon: "🔍",
+                    label: "Search",
+                    shortcut: "Ctrl+K",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_palette();
+                    }
+                }
+
+                ToolbarButton {
+                    icon: theme.icon(),
                     label: "Theme",
                     onclick: move |_| {
-                        APP_STATE.write().ui.toggle_dark_mode();
+                        APP_STATE.write().ui.cycle_theme();
+                    }
+                }
+
+                ToolbarButton {
+                    icon: "❔",
+                    label: "Help",
+                    shortcut: "F1",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_help();
                     }
                 }
 
@@ -271,6 +484,7 @@ fn ToolbarButton(
 /// Left sidebar with navigation
 #[component]
 fn Sidebar() -> Element {
+    diagnostics::record_render("sidebar");
     let state = APP_STATE.read();
     let collapsed = state.ui.sidebar_collapsed;
     let current_page = state.ui.active_page;
@@ -433,6 +647,7 @@ fn SidebarItem(page: Page, current: Page, collapsed: bool) -> Element {
 /// Main content area that renders the active page
 #[component]
 fn MainContent() -> Element {
+    diagnostics::record_render("main_content");
     let state = APP_STATE.read();
     let current_page = state.ui.active_page;
     let has_project = state.has_project();
@@ -484,7 +699,10 @@ fn MainContent() -> Element {
 #[component]
 fn SettingsPage() -> Element {
     let state = APP_STATE.read();
-    let dark_mode = state.ui.dark_mode;
+    let theme = state.ui.theme;
+    let restore_session = state.ui.restore_session;
+    let advanced_dialogs = state.ui.advanced_dialogs;
+    let semantic_zoom = state.canvas.semantic_zoom;
     drop(state);
 
     rsx! {
@@ -495,20 +713,95 @@ fn SettingsPage() -> Element {
             div {
                 class: "space-y-4",
 
-                // Dark mode toggle
+                // Theme selector
+                div {
+                    class: "flex items-center justify-between p-4 bg-slate-800 rounded-lg",
+                    div {
+                        h3 { class: "font-medium", "Theme" }
+                        p { class: "text-sm text-slate-400", "Dark, light, or follow the system setting" }
+                    }
+                    div {
+                        class: "flex gap-1",
+                        for option in Theme::ALL {
+                            button {
+                                key: "{option.attribute()}",
+                                class: "px-3 py-2 rounded transition-colors",
+                                class: if option == theme { "bg-indigo-600" } else { "bg-slate-600" },
+                                onclick: move |_| {
+                                    APP_STATE.write().ui.theme = option;
+                                },
+                                "{option.icon()} {option.display_name()}"
+                            }
+                        }
+                    }
+                }
+
+                // Session restore toggle
+                div {
+                    class: "flex items-center justify-between p-4 bg-slate-800 rounded-lg",
+                    div {
+                        h3 { class: "font-medium", "Restore Last Session" }
+                        p { class: "text-sm text-slate-400", "Reopen the last project, page and canvas view on launch" }
+                    }
+                    button {
+                        class: "px-4 py-2 rounded transition-colors",
+                        class: if restore_session { "bg-indigo-600" } else { "bg-slate-600" },
+                        onclick: move |_| {
+                            let mut state = APP_STATE.write();
+                            state.ui.restore_session = !state.ui.restore_session;
+                        },
+                        if restore_session { "On" } else { "Off" }
+                    }
+                }
+
+                // Dialog complexity toggle
                 div {
                     class: "flex items-center justify-between p-4 bg-slate-800 rounded-lg",
                     div {
-                        h3 { class: "font-medium", "Dark Mode" }
-                        p { class: "text-sm text-slate-400", "Use dark theme for the application" }
+                        h3 { class: "font-medium", "Advanced Dialogs" }
+                        p { class: "text-sm text-slate-400", "Show every option in editor dialogs, including validations, foreign keys and UI hints" }
                     }
                     button {
                         class: "px-4 py-2 rounded transition-colors",
-                        class: if dark_mode { "bg-indigo-600" } else { "bg-slate-600" },
+                        class: if advanced_dialogs { "bg-indigo-600" } else { "bg-slate-600" },
                         onclick: move |_| {
-                            APP_STATE.write().ui.toggle_dark_mode();
+                            APP_STATE.write().ui.toggle_advanced_dialogs();
                         },
-                        if dark_mode { "On" } else { "Off" }
+                        if advanced_dialogs { "On" } else { "Off" }
+                    }
+                }
+
+                // Semantic zoom thresholds
+                div {
+                    class: "p-4 bg-slate-800 rounded-lg",
+                    h3 { class: "font-medium", "Semantic Zoom" }
+                    p { class: "text-sm text-slate-400 mb-3", "Simplify entity cards as the canvas zooms out so large diagrams stay readable" }
+                    div {
+                        class: "grid grid-cols-2 gap-4",
+                        NumberInput {
+                            value: (semantic_zoom.names_below * 100.0).round() as f64,
+                            label: "Names only below",
+                            help_text: "Show just entity names and relationship lines",
+                            min: 25.0,
+                            max: 300.0,
+                            step: 5.0,
+                            unit: "%",
+                            on_change: move |value: f64| {
+                                APP_STATE.write().canvas.semantic_zoom.set_names_below(value as f32 / 100.0);
+                            },
+                        }
+                        NumberInput {
+                            value: (semantic_zoom.key_fields_below * 100.0).round() as f64,
+                            label: "Key fields only below",
+                            help_text: "Show primary, foreign and unique keys",
+                            min: 25.0,
+                            max: 300.0,
+                            step: 5.0,
+                            unit: "%",
+                            on_change: move |value: f64| {
+                                APP_STATE.write().canvas.semantic_zoom.set_key_fields_below(value as f32 / 100.0);
+                            },
+                        }
                     }
                 }
 
@@ -531,6 +824,7 @@ fn SettingsPage() -> Element {
 /// Right properties panel for editing selected items
 #[component]
 fn PropertiesPanel() -> Element {
+    diagnostics::record_render("properties");
     let state = APP_STATE.read();
     let collapsed = state.ui.properties_collapsed;
     let has_selection = !state.selection.is_empty();
@@ -592,6 +886,7 @@ fn PropertiesPanel() -> Element {
 /// Bottom status bar
 #[component]
 fn StatusBar() -> Element {
+    diagnostics::record_render("status_bar");
     let state = APP_STATE.read();
     let status = state.ui.status_message.clone();
     let entity_count = state
@@ -605,6 +900,7 @@ fn StatusBar() -> Element {
         .map(|p| p.relationships.len())
         .unwrap_or(0);
     let has_project = state.has_project();
+    let (error_count, warning_count) = problems::counts(&state.problems);
     drop(state);
 
     rsx! {
@@ -633,6 +929,21 @@ fn StatusBar() -> Element {
             if has_project {
                 div {
                     class: "flex items-center gap-4",
+                    button {
+                        class: "flex items-center gap-2 hover:text-slate-200",
+                        title: "Problems (Ctrl+Shift+M)",
+                        onclick: move |_| {
+                            APP_STATE.write().ui.toggle_problems();
+                        },
+                        span {
+                            class: if error_count > 0 { "text-red-400" } else { "" },
+                            "⛔ {error_count}"
+                        }
+                        span {
+                            class: if warning_count > 0 { "text-amber-400" } else { "" },
+                            "⚠️ {warning_count}"
+                        }
+                    }
                     span { "Entities: {entity_count}" }
                     span { "Relationships: {relationship_count}" }
                 }
@@ -648,6 +959,7 @@ fn StatusBar() -> Element {
 /// Modal dialog overlay
 #[component]
 fn DialogOverlay() -> Element {
+    diagnostics::record_render("dialog_overlay");
     let state = APP_STATE.read();
     let dialog = state.ui.active_dialog.clone();
     drop(state);
@@ -677,6 +989,9 @@ fn DialogOverlay() -> Element {
                         Dialog::NewField(_) | Dialog::EditField(_, _) => "max-w-2xl w-full",
                         Dialog::NewRelationship(_, _) | Dialog::EditRelationship(_) => "max-w-2xl w-full",
                         Dialog::NewEndpoint(_) | Dialog::EditEndpoint(_) => "max-w-2xl w-full",
+                        Dialog::NewQuery(_) | Dialog::EditQuery(_) => "max-w-3xl w-full",
+                        Dialog::RecoverProject(_, _) => "max-w-3xl w-full",
+                        Dialog::RestoreRecovery(_) => "max-w-md w-full",
                         _ => "max-w-lg w-full",
                     }
                 ),
@@ -685,6 +1000,12 @@ fn DialogOverlay() -> Element {
                 match dialog {
                     Dialog::NewProject => rsx! { NewProjectDialog {} },
                     Dialog::OpenProject => rsx! { OpenProjectDialog {} },
+                    Dialog::RecoverProject(ref path, ref error) => rsx! {
+                        RecoverProjectDialog { path: path.clone(), error: error.clone() }
+                    },
+                    Dialog::RestoreRecovery(ref recovery) => rsx! {
+                        RestoreRecoveryDialog { recovery: recovery.clone() }
+                    },
                     Dialog::About => rsx! { AboutDialog {} },
                     Dialog::Error(ref msg) => rsx! { ErrorDialog { message: msg.clone() } },
                     Dialog::NewEntity => rsx! {
@@ -739,6 +1060,18 @@ fn DialogOverlay() -> Element {
                             mode: EndpointDialogMode::Edit(endpoint_id),
                         }
                     },
+                    Dialog::NewQuery(entity_id) => rsx! {
+                        QueryDialog {
+                            mode: QueryDialogMode::Create {
+                                entity_id: entity_id,
+                            },
+                        }
+                    },
+                    Dialog::EditQuery(query_id) => rsx! {
+                        QueryDialog {
+                            mode: QueryDialogMode::Edit(query_id),
+                        }
+                    },
                     _ => rsx! {
                         div {
                             class: "p-6",
@@ -835,7 +1168,11 @@ fn OpenProjectDialog() -> Element {
         error_message.set(None);
 
         spawn(async move {
-            match file_ops::open_project(None).await {
+            let Some(path) = file_ops::show_open_dialog().await else {
+                is_loading.set(false);
+                return;
+            };
+            match file_ops::open_project(Some(path.clone())).await {
                 Ok((project, path)) => {
                     let project_name = project.meta.name.clone();
                     let saved_path = path.clone();
@@ -851,10 +1188,14 @@ fn OpenProjectDialog() -> Element {
                     // Track in recent projects
                     add_to_recent_projects(&project_name, &saved_path);
                 }
+                Err(e) if file_ops::is_damaged_file_error(&e) => {
+                    APP_STATE
+                        .write()
+                        .ui
+                        .show_dialog(Dialog::RecoverProject(path, e.to_string()));
+                }
                 Err(e) => {
-                    if !matches!(e, imortal_core::EngineError::Cancelled) {
-                        error_message.set(Some(e.to_string()));
-                    }
+                    error_message.set(Some(e.to_string()));
                 }
             }
             is_loading.set(false);
@@ -909,6 +1250,255 @@ fn OpenProjectDialog() -> Element {
     }
 }
 
+/// Recovery options for a project file that failed to load
+///
+/// Offers the backup kept next to the file, loading whatever still parses,
+/// or fixing the JSON by hand.
+#[component]
+fn RecoverProjectDialog(path: PathBuf, error: String) -> Element {
+    let mut error_message = use_signal(|| error.clone());
+    let mut raw_json = use_signal(|| None::<String>);
+    let backup = file_ops::existing_backup(&path);
+    let file_name = file_ops::display_name(&path);
+
+    let open_backup = {
+        let path = path.clone();
+        let backup = backup.clone();
+        move |_| {
+            let Some(backup) = &backup else { return };
+            match load_project(backup) {
+                Ok(project) => finish_recovery(
+                    project,
+                    path.clone(),
+                    "Opened the backup; save to replace the damaged file".to_string(),
+                    StatusLevel::Warning,
+                    true,
+                ),
+                Err(e) => error_message.set(format!("The backup didn't load either: {}", e)),
+            }
+        }
+    };
+
+    let recover = {
+        let path = path.clone();
+        move |_| match file_ops::recover_project(&path) {
+            Ok((recovered, copy)) => {
+                for warning in recovered.warnings() {
+                    tracing::warn!("Skipped while recovering {}: {}", path.display(), warning);
+                }
+                let (message, level) = match &copy {
+                    Some(copy) => (
+                        format!(
+                            "Recovered the project; skipped {} damaged section(s), kept the original as {}",
+                            recovered.quarantined.len(),
+                            copy.display()
+                        ),
+                        StatusLevel::Warning,
+                    ),
+                    None => (
+                        "Project opened successfully".to_string(),
+                        StatusLevel::Success,
+                    ),
+                };
+                finish_recovery(
+                    recovered.project,
+                    path.clone(),
+                    message,
+                    level,
+                    copy.is_some(),
+                );
+            }
+            Err(e) => error_message.set(e.to_string()),
+        }
+    };
+
+    let edit_raw = {
+        let path = path.clone();
+        move |_| match file_ops::read_raw_project(&path) {
+            Ok(json) => raw_json.set(Some(json)),
+            Err(e) => error_message.set(e.to_string()),
+        }
+    };
+
+    let save_raw = {
+        let path = path.clone();
+        move |_| {
+            let Some(json) = raw_json.read().clone() else {
+                return;
+            };
+            match file_ops::save_raw_project(&path, &json) {
+                Ok(project) => finish_recovery(
+                    project,
+                    path.clone(),
+                    "Project repaired and opened".to_string(),
+                    StatusLevel::Success,
+                    false,
+                ),
+                Err(e) => error_message.set(e.to_string()),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "p-6",
+
+            h2 { class: "text-xl font-bold mb-2", "Couldn't open {file_name}" }
+
+            div {
+                class: "mb-4 p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-red-300 text-sm font-mono break-words",
+                "{error_message}"
+            }
+
+            if let Some(json) = raw_json.read().clone() {
+                textarea {
+                    class: "input font-mono text-xs w-full min-h-[360px] resize-y mb-4",
+                    spellcheck: false,
+                    value: "{json}",
+                    oninput: move |e| raw_json.set(Some(e.value())),
+                }
+
+                div {
+                    class: "flex justify-end gap-2",
+                    button {
+                        class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                        onclick: move |_| raw_json.set(None),
+                        "Back"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-indigo-600 hover:bg-indigo-700 rounded transition-colors",
+                        onclick: save_raw,
+                        "Save & Open"
+                    }
+                }
+            } else {
+                p { class: "text-slate-400 mb-4",
+                    "The file couldn't be read as it is. Recovering loads everything that still parses and skips the rest; the original file is kept alongside."
+                }
+
+                div {
+                    class: "flex justify-end gap-2",
+                    button {
+                        class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                        onclick: move |_| APP_STATE.write().ui.close_dialog(),
+                        "Cancel"
+                    }
+                    button {
+                        class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                        onclick: edit_raw,
+                        "Edit Raw JSON"
+                    }
+                    if backup.is_some() {
+                        button {
+                            class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                            onclick: open_backup,
+                            "Open Backup"
+                        }
+                    }
+                    button {
+                        class: "px-4 py-2 bg-indigo-600 hover:bg-indigo-700 rounded transition-colors",
+                        onclick: recover,
+                        "Recover"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Load a project opened from [`RecoverProjectDialog`]
+///
+/// The project keeps the damaged file's path, so the next save replaces the
+/// file; `dirty` marks projects that differ from what's on disk.
+fn finish_recovery(
+    project: ProjectGraph,
+    path: PathBuf,
+    message: String,
+    level: StatusLevel,
+    dirty: bool,
+) {
+    let name = project.meta.name.clone();
+    let mut state = APP_STATE.write();
+    state.load_project(project, path.clone());
+    if dirty {
+        state.mark_dirty();
+    }
+    state.ui.close_dialog();
+    state.ui.set_status(message, level);
+    drop(state);
+
+    add_to_recent_projects(&name, &path);
+}
+
+/// Offer to restore changes found in an autosave recovery file
+///
+/// Restoring opens the autosaved project (dirty, under its original path);
+/// discarding deletes the recovery file.
+#[component]
+fn RestoreRecoveryDialog(recovery: Recovery) -> Element {
+    let mut error_message = use_signal(|| None::<String>);
+    let name = recovery
+        .project_path
+        .as_ref()
+        .map(file_ops::display_name)
+        .unwrap_or_else(|| "an unsaved project".to_string());
+    let saved_at = recovery.saved_at_display();
+
+    let restore = {
+        let recovery = recovery.clone();
+        move |_| match recovery.load() {
+            Ok(project) => {
+                let mut state = APP_STATE.write();
+                state.open_recovered(project, recovery.project_path.clone());
+                state.ui.close_dialog();
+                state.ui.set_status(
+                    "Restored unsaved changes; save to keep them",
+                    StatusLevel::Warning,
+                );
+            }
+            Err(e) => error_message.set(Some(format!("The autosave couldn't be loaded: {}", e))),
+        }
+    };
+
+    let discard = move |_| {
+        autosave::discard_recovery(&recovery.path);
+        APP_STATE.write().ui.close_dialog();
+    };
+
+    rsx! {
+        div {
+            class: "p-6",
+
+            h2 { class: "text-xl font-bold mb-2", "Restore unsaved changes?" }
+
+            p { class: "text-slate-400 mb-4",
+                "Changes to {name} autosaved at {saved_at} were never saved. This usually means the app closed unexpectedly."
+            }
+
+            if let Some(err) = error_message.read().as_ref() {
+                div {
+                    class: "mb-4 p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-red-300 text-sm",
+                    "{err}"
+                }
+            }
+
+            div {
+                class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                    onclick: discard,
+                    "Discard"
+                }
+                button {
+                    class: "px-4 py-2 bg-indigo-600 hover:bg-indigo-700 rounded transition-colors",
+                    onclick: restore,
+                    "Restore"
+                }
+            }
+        }
+    }
+}
+
 /// About dialog
 #[component]
 fn AboutDialog() -> Element {
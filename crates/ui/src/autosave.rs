@@ -0,0 +1,178 @@
+//! Autosave and Crash Recovery for Immortal Engine
+//!
+//! While a project has unsaved changes, the app writes it every
+//! [`AUTOSAVE_INTERVAL`] to a recovery file next to the `.ieng`
+//! (`blog.ieng` → `blog.ieng.recovery`); projects that were never saved use a
+//! single slot in the user's config directory instead. The file is removed
+//! once the project is saved or closed, so one that is still there at the
+//! next launch holds changes that were lost — usually to a crash — and the
+//! app offers to restore them.
+//!
+//! Recovery files are ordinary project files, so they load with the same
+//! schema upgrades as a `.ieng`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use imortal_core::EngineResult;
+use imortal_ir::ProjectGraph;
+use imortal_ir::serialization::{PROJECT_EXTENSION, load_project, save_project};
+
+/// How often a project with unsaved changes is written to its recovery file
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+// ============================================================================
+// Recovery Files
+// ============================================================================
+
+/// Where the autosave for a project is written.
+///
+/// Saved projects keep theirs next to the project file; unsaved projects
+/// share one in the config directory (`None` if there is no home directory).
+pub fn recovery_path(project_path: Option<&Path>) -> Option<PathBuf> {
+    match project_path {
+        Some(path) => Some(path.with_extension(format!("{}.recovery", PROJECT_EXTENSION))),
+        None => {
+            let home = std::env::var("HOME")
+                .or_else(|_| std::env::var("USERPROFILE"))
+                .ok()?;
+            Some(
+                PathBuf::from(home)
+                    .join(".config")
+                    .join("immortal-engine")
+                    .join(format!("unsaved.{}.recovery", PROJECT_EXTENSION)),
+            )
+        }
+    }
+}
+
+/// Write a project to a recovery file (see [`recovery_path`])
+pub fn write_recovery(project: &ProjectGraph, path: &Path) -> EngineResult<()> {
+    save_project(project, path)
+}
+
+/// Delete a recovery file (a missing file is not an error)
+pub fn discard_recovery(path: &Path) {
+    if let Err(e) = std::fs::remove_file(path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        tracing::warn!("Could not remove {}: {}", path.display(), e);
+    }
+}
+
+/// Delete the recovery files a save made obsolete: the one of the
+/// project's previous location (the unsaved slot if it had none) and any at
+/// the location it was saved to.
+///
+/// Called right after saving rather than left to the next autosave tick,
+/// which never comes if the app is closed first.
+pub fn discard_after_save(previous: Option<&Path>, saved: &Path) {
+    for path in [recovery_path(previous), recovery_path(Some(saved))]
+        .into_iter()
+        .flatten()
+    {
+        discard_recovery(&path);
+    }
+}
+
+/// Unsaved changes found in a recovery file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recovery {
+    /// The recovery file
+    pub path: PathBuf,
+    /// The project file the changes belong to (None for an unsaved project)
+    pub project_path: Option<PathBuf>,
+    /// When the changes were autosaved
+    pub saved_at: SystemTime,
+}
+
+impl Recovery {
+    /// Autosave time in local time, for display
+    pub fn saved_at_display(&self) -> String {
+        chrono::DateTime::<chrono::Local>::from(self.saved_at)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    }
+
+    /// Load the recovered project
+    pub fn load(&self) -> EngineResult<ProjectGraph> {
+        load_project(&self.path)
+    }
+}
+
+/// Look for unsaved changes to a project (or to an unsaved project).
+///
+/// A recovery file older than its project file is stale — the project was
+/// saved afterwards, e.g. by another window — and is ignored.
+pub fn find_recovery(project_path: Option<&Path>) -> Option<Recovery> {
+    let path = recovery_path(project_path)?;
+    let saved_at = std::fs::metadata(&path).ok()?.modified().ok()?;
+
+    if let Some(project_modified) = project_path
+        .and_then(|p| std::fs::metadata(p).ok())
+        .and_then(|m| m.modified().ok())
+        && project_modified > saved_at
+    {
+        return None;
+    }
+
+    Some(Recovery {
+        path,
+        project_path: project_path.map(Path::to_path_buf),
+        saved_at,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::Entity;
+
+    #[test]
+    fn test_recovery_path() {
+        assert_eq!(
+            recovery_path(Some(Path::new("/work/blog.ieng"))),
+            Some(PathBuf::from("/work/blog.ieng.recovery"))
+        );
+    }
+
+    #[test]
+    fn test_recovery_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ieng-autosave-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("blog.ieng");
+
+        let mut project = ProjectGraph::new("blog");
+        save_project(&project, &path).unwrap();
+        assert!(find_recovery(Some(&path)).is_none());
+
+        // Unsaved edits are written next to the project and found again
+        project.add_entity(Entity::new("Post"));
+        let file = recovery_path(Some(&path)).unwrap();
+        write_recovery(&project, &file).unwrap();
+        let recovery = find_recovery(Some(&path)).unwrap();
+        assert_eq!(recovery.path, file);
+        assert_eq!(recovery.project_path.as_deref(), Some(path.as_path()));
+        assert_eq!(recovery.load().unwrap().entities.len(), 1);
+
+        // Saving the project afterwards makes the recovery file stale
+        let later = recovery.saved_at + Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(find_recovery(Some(&path)).is_none());
+
+        discard_recovery(&file);
+        assert!(!file.exists());
+        discard_recovery(&file);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
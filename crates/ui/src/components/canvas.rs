@@ -10,16 +10,26 @@
 //! - **Entity Rendering**: Displays entity cards at their positions
 //! - **Selection**: Click to select entities, shift+click for multi-select
 //! - **Drag & Drop**: Drag entities to reposition them
+//! - **Minimap**: Overview of all entities and the visible area; click or drag
+//!   to navigate
+//! - **Fit / Zoom to Selection**: Frame every entity, or just the selected ones
+//! - **Context Menu**: Right-click an entity or field to duplicate the entity
+//!   or copy/paste fields between entities (also Ctrl+D, Ctrl+C, Ctrl+V)
+
+use std::collections::HashMap;
 
 use dioxus::prelude::*;
-use imortal_core::types::{EntityId, FieldId, Position};
+use imortal_codegen::SchemaFormat;
+use imortal_core::types::{EntityId, FieldId, Position, Rect, Size};
+use imortal_ir::FieldSet;
 use imortal_ir::entity::Entity;
 
 use crate::components::connection::{ConnectionPoint, ConnectionsLayer};
-use crate::components::entity_card::EntityCard;
+use crate::components::entity_card::{EndpointCoverage, EntityCard};
 use crate::components::port::{PortClickInfo, PortType};
+use crate::diagnostics;
 use crate::hooks::use_canvas::{
-    position_from_mouse_event, use_canvas_interactions, zoom_delta_from_wheel,
+    entity_rect, position_from_mouse_event, use_canvas_interactions, zoom_delta_from_wheel,
 };
 use crate::hooks::use_connection::use_connection_drawing;
 use crate::state::{APP_STATE, Dialog};
@@ -43,6 +53,12 @@ pub const CANVAS_BG_COLOR: &str = "#0f172a";
 /// Minimum entities before showing minimap
 pub const MINIMAP_THRESHOLD: usize = 5;
 
+/// Minimap width in screen pixels
+pub const MINIMAP_WIDTH: f32 = 200.0;
+
+/// Minimap height in screen pixels
+pub const MINIMAP_HEIGHT: f32 = 140.0;
+
 /// Arrow key movement step (in canvas pixels)
 pub const ARROW_MOVE_STEP: f32 = 10.0;
 
@@ -108,6 +124,8 @@ pub struct CanvasProps {
 /// Main canvas component for visual entity editing
 #[component]
 pub fn Canvas(props: CanvasProps) -> Element {
+    diagnostics::record_render(diagnostics::CANVAS_REGION);
+
     // Get canvas interactions hook
     let interactions = use_canvas_interactions();
 
@@ -117,6 +135,9 @@ pub fn Canvas(props: CanvasProps) -> Element {
     // Track if space is held (for pan mode)
     let mut space_held = use_signal(|| false);
 
+    // Open entity/field context menu, if any
+    let mut context_menu = use_signal(|| None::<ContextMenuTarget>);
+
     // Get current state
     let state = APP_STATE.read();
     let entities: Vec<Entity> = state
@@ -124,8 +145,35 @@ pub fn Canvas(props: CanvasProps) -> Element {
         .as_ref()
         .map(|p| p.entities.values().cloned().collect())
         .unwrap_or_default();
+    let field_sets: HashMap<EntityId, Vec<FieldSet>> = state
+        .project
+        .as_ref()
+        .map(|p| {
+            p.entities
+                .values()
+                .filter(|e| !e.field_sets.is_empty())
+                .map(|e| {
+                    (
+                        e.id,
+                        p.included_field_sets(e).into_iter().cloned().collect(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let coverage: HashMap<EntityId, EndpointCoverage> = state
+        .project
+        .as_ref()
+        .map(|p| {
+            p.entities
+                .keys()
+                .map(|id| (*id, EndpointCoverage::of(p, *id)))
+                .collect()
+        })
+        .unwrap_or_default();
     let pan = state.canvas.pan;
     let zoom = state.canvas.zoom;
+    let detail = state.canvas.detail();
     let show_grid = state.canvas.show_grid && props.show_grid;
     let grid_size = state.canvas.grid_size;
     let selected_entities = state.selection.entities.clone();
@@ -138,7 +186,11 @@ pub fn Canvas(props: CanvasProps) -> Element {
         .as_ref()
         .map(|p| p.relationships.values().cloned().collect::<Vec<_>>())
         .unwrap_or_default();
+    let visible_rect = state.canvas.visible_rect();
+    let clipboard_count = state.field_clipboard.len();
     drop(state);
+    let show_minimap =
+        props.show_minimap && entities.len() >= MINIMAP_THRESHOLD && visible_rect.size.width > 0.0;
 
     // Calculate transform style
     let transform_style = format!(
@@ -365,12 +417,22 @@ pub fn Canvas(props: CanvasProps) -> Element {
                     select_all_entities();
                 }
 
-                // Ctrl+D - duplicate selected entities
-                Key::Character(ref c) if (c == "d" || c == "D") && is_ctrl => {
+                // Ctrl+D - duplicate selected entities (Ctrl+Shift+D is the diagnostics overlay)
+                Key::Character(ref c) if (c == "d" || c == "D") && is_ctrl && !is_shift => {
                     e.prevent_default();
                     duplicate_selected_entities_on_canvas();
                 }
 
+                // Ctrl+C / Ctrl+V - copy and paste fields between entities
+                Key::Character(ref c) if (c == "c" || c == "C") && is_ctrl => {
+                    e.prevent_default();
+                    copy_selected_fields();
+                }
+                Key::Character(ref c) if (c == "v" || c == "V") && is_ctrl => {
+                    e.prevent_default();
+                    paste_fields_into_selection();
+                }
+
                 _ => {}
             }
         }
@@ -496,6 +558,16 @@ pub fn Canvas(props: CanvasProps) -> Element {
             // Wheel event for zoom
             onwheel: handle_wheel,
 
+            // Track the viewport size, for centering entities
+            onresize: move |e: Event<ResizeData>| {
+                if let Ok(size) = e.get_content_box_size() {
+                    let viewport = Size::new(size.width as f32, size.height as f32);
+                    if APP_STATE.peek().canvas.viewport != viewport {
+                        APP_STATE.write().canvas.viewport = viewport;
+                    }
+                }
+            },
+
             // Double click for new entity
             ondoubleclick: handle_double_click,
 
@@ -540,7 +612,9 @@ pub fn Canvas(props: CanvasProps) -> Element {
                             EntityCard {
                                 key: "{entity.id}",
                                 entity: entity.clone(),
+                                field_sets: field_sets.get(&entity.id).cloned().unwrap_or_default(),
                                 zoom: zoom as f64,
+                                detail: detail,
                                 selected: selected_entities.contains(&entity.id),
                                 dragging: dragging_entity == Some(entity.id),
                                 selected_field: get_selected_field_for_entity(entity.id),
@@ -563,9 +637,27 @@ pub fn Canvas(props: CanvasProps) -> Element {
                                     }
                                 },
                                 on_double_click: handle_entity_double_click,
+                                on_context_menu: move |(entity_id, e): (EntityId, MouseEvent)| {
+                                    let mut state = APP_STATE.write();
+                                    if !state.selection.is_entity_selected(&entity_id) {
+                                        state.selection.select_entity(entity_id);
+                                    }
+                                    state.selection.field = None;
+                                    context_menu.set(Some(ContextMenuTarget::at(&e, entity_id, None)));
+                                },
                                 on_field_select: handle_field_select,
+                                on_field_context_menu: move |(entity_id, field_id, e): (EntityId, FieldId, MouseEvent)| {
+                                    let mut state = APP_STATE.write();
+                                    state.selection.select_entity(entity_id);
+                                    state.selection.field = Some((entity_id, field_id));
+                                    context_menu.set(Some(ContextMenuTarget::at(&e, entity_id, Some(field_id))));
+                                },
                                 on_add_field: handle_add_field,
                                 on_toggle_collapse: handle_toggle_collapse,
+                                coverage: coverage.get(&entity.id).copied(),
+                                on_coverage_click: move |entity_id: EntityId| {
+                                    APP_STATE.write().open_endpoints_for(entity_id);
+                                },
                                 on_port_click: move |info: PortClickInfo| {
                                     if connection_click.is_drawing() {
                                         connection_click.set_hover_target(Some(info.entity_id), Some(info.port_type));
@@ -595,13 +687,33 @@ pub fn Canvas(props: CanvasProps) -> Element {
                     let i2 = interactions.clone();
                     let i3 = interactions.clone();
                     let i4 = interactions.clone();
+                    let i5 = interactions.clone();
                     rsx! {
                         ZoomControls {
                             zoom: zoom as f64,
+                            has_selection: !selected_entities.is_empty(),
                             on_zoom_in: move |_| i1.zoom_in(),
                             on_zoom_out: move |_| i2.zoom_out(),
                             on_zoom_reset: move |_| i3.reset_zoom(),
                             on_fit_content: move |_| i4.fit_to_content(),
+                            on_zoom_selection: move |_| i5.zoom_to_selection(),
+                        }
+                    }
+                }
+            }
+
+            // Minimap overlay, above the zoom controls
+            if show_minimap {
+                {
+                    let navigate = interactions.clone();
+                    rsx! {
+                        Minimap {
+                            entities: entities
+                                .iter()
+                                .map(|e| (e.id, entity_rect(e), selected_entities.contains(&e.id)))
+                                .collect::<Vec<_>>(),
+                            visible: visible_rect,
+                            on_navigate: move |pos: Position| navigate.navigate_to(pos),
                         }
                     }
                 }
@@ -618,6 +730,15 @@ pub fn Canvas(props: CanvasProps) -> Element {
             if entities.is_empty() {
                 CanvasEmptyState {}
             }
+
+            // Entity/field context menu
+            if let Some(target) = context_menu() {
+                CanvasContextMenu {
+                    target: target,
+                    clipboard_count: clipboard_count,
+                    on_close: move |_| context_menu.set(None),
+                }
+            }
         }
     }
 }
@@ -723,8 +844,12 @@ struct ZoomControlsProps {
     on_zoom_out: EventHandler<()>,
     /// Reset zoom callback
     on_zoom_reset: EventHandler<()>,
+    /// Whether any entities are selected
+    has_selection: bool,
     /// Fit to content callback
     on_fit_content: EventHandler<()>,
+    /// Zoom to selection callback
+    on_zoom_selection: EventHandler<()>,
 }
 
 /// Zoom control buttons overlay
@@ -772,6 +897,150 @@ fn ZoomControls(props: ZoomControlsProps) -> Element {
                 onclick: move |_| props.on_fit_content.call(()),
                 "⊡"
             }
+
+            // Zoom to selection button
+            button {
+                class: "w-8 h-8 flex items-center justify-center rounded hover:bg-slate-700 text-slate-300 hover:text-white transition-colors disabled:opacity-40 disabled:pointer-events-none",
+                title: "Zoom to Selection",
+                disabled: !props.has_selection,
+                onclick: move |_| props.on_zoom_selection.call(()),
+                "⌖"
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Minimap Component
+// ============================================================================
+
+/// Maps between the minimap's fixed on-screen box and the canvas region it
+/// shows, matching SVG's `xMidYMid meet` scaling
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MinimapProjection {
+    /// Canvas region shown by the minimap
+    world: Rect,
+    /// Minimap pixels per canvas unit
+    scale: f32,
+    /// Letterbox offset of the world inside the minimap box
+    offset: Position,
+}
+
+impl MinimapProjection {
+    /// Padding around the content, in canvas units
+    const PADDING: f32 = 40.0;
+
+    /// Project a region covering all entities and the visible area
+    fn new(entities: &Rect, visible: &Rect) -> Self {
+        let world = entities.union(visible).expand(Self::PADDING);
+        let scale = (MINIMAP_WIDTH / world.size.width).min(MINIMAP_HEIGHT / world.size.height);
+        let offset = Position::new(
+            (MINIMAP_WIDTH - world.size.width * scale) / 2.0,
+            (MINIMAP_HEIGHT - world.size.height * scale) / 2.0,
+        );
+        Self {
+            world,
+            scale,
+            offset,
+        }
+    }
+
+    /// Convert a point on the minimap to canvas coordinates
+    fn to_canvas(self, point: Position) -> Position {
+        Position::new(
+            self.world.position.x + (point.x - self.offset.x) / self.scale,
+            self.world.position.y + (point.y - self.offset.y) / self.scale,
+        )
+    }
+}
+
+/// Properties for Minimap component
+#[derive(Props, Clone, PartialEq)]
+struct MinimapProps {
+    /// Entity rectangles in canvas coordinates, with their selection state
+    entities: Vec<(EntityId, Rect, bool)>,
+    /// Visible part of the canvas
+    visible: Rect,
+    /// Called with the canvas point to centre on
+    on_navigate: EventHandler<Position>,
+}
+
+/// Overview of the whole canvas with the current viewport outlined
+#[component]
+fn Minimap(props: MinimapProps) -> Element {
+    let mut dragging = use_signal(|| false);
+
+    let content = props
+        .entities
+        .iter()
+        .map(|(_, rect, _)| *rect)
+        .reduce(|acc, rect| acc.union(&rect))
+        .unwrap_or(props.visible);
+    let projection = MinimapProjection::new(&content, &props.visible);
+    let world = projection.world;
+    // Keep outlines about one screen pixel wide whatever the scale
+    let stroke = 1.0 / projection.scale;
+    let visible = props.visible;
+
+    let navigate = move |e: &MouseEvent| {
+        let point = e.element_coordinates();
+        props
+            .on_navigate
+            .call(projection.to_canvas(Position::new(point.x as f32, point.y as f32)));
+    };
+
+    rsx! {
+        div {
+            class: "minimap absolute bottom-16 right-4 bg-slate-900/90 backdrop-blur-sm rounded-lg shadow-lg border border-slate-700 overflow-hidden cursor-pointer",
+            title: "Click or drag to navigate",
+
+            svg {
+                width: "{MINIMAP_WIDTH}",
+                height: "{MINIMAP_HEIGHT}",
+                view_box: "{world.position.x} {world.position.y} {world.size.width} {world.size.height}",
+                preserve_aspect_ratio: "xMidYMid meet",
+                onmousedown: move |e: MouseEvent| {
+                    e.stop_propagation();
+                    dragging.set(true);
+                    navigate(&e);
+                },
+                onmousemove: move |e: MouseEvent| {
+                    if dragging() {
+                        e.stop_propagation();
+                        navigate(&e);
+                    }
+                },
+                onmouseup: move |_| dragging.set(false),
+                onmouseleave: move |_| dragging.set(false),
+                ondoubleclick: move |e: MouseEvent| e.stop_propagation(),
+
+                // Shapes ignore the pointer so coordinates stay relative to the svg
+                g {
+                    pointer_events: "none",
+
+                    for (id, rect, selected) in props.entities.iter().copied() {
+                        rect {
+                            key: "{id}",
+                            x: "{rect.position.x}",
+                            y: "{rect.position.y}",
+                            width: "{rect.size.width}",
+                            height: "{rect.size.height}",
+                            rx: "{6.0 * stroke}",
+                            fill: if selected { "#6366f1" } else { "#475569" },
+                        }
+                    }
+
+                    rect {
+                        x: "{visible.position.x}",
+                        y: "{visible.position.y}",
+                        width: "{visible.size.width}",
+                        height: "{visible.size.height}",
+                        fill: "rgba(148, 163, 184, 0.1)",
+                        stroke: "#94a3b8",
+                        stroke_width: "{stroke}",
+                    }
+                }
+            }
         }
     }
 }
@@ -823,6 +1092,156 @@ fn CanvasInfo(props: CanvasInfoProps) -> Element {
     }
 }
 
+// ============================================================================
+// Context Menu Component
+// ============================================================================
+
+/// What a canvas context menu was opened on, and where
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ContextMenuTarget {
+    /// Menu position in window coordinates
+    position: Position,
+    /// Entity that was right-clicked
+    entity_id: EntityId,
+    /// Field that was right-clicked, if any
+    field_id: Option<FieldId>,
+}
+
+impl ContextMenuTarget {
+    /// Target for a right-click event
+    fn at(e: &MouseEvent, entity_id: EntityId, field_id: Option<FieldId>) -> Self {
+        let point = e.client_coordinates();
+        Self {
+            position: Position::new(point.x as f32, point.y as f32),
+            entity_id,
+            field_id,
+        }
+    }
+}
+
+/// Properties for CanvasContextMenu component
+#[derive(Props, Clone, PartialEq)]
+struct CanvasContextMenuProps {
+    /// What the menu acts on
+    target: ContextMenuTarget,
+    /// Number of fields waiting to be pasted
+    clipboard_count: usize,
+    /// Called when the menu should close
+    on_close: EventHandler<()>,
+}
+
+/// Right-click menu for entities and fields
+#[component]
+fn CanvasContextMenu(props: CanvasContextMenuProps) -> Element {
+    let ContextMenuTarget {
+        position,
+        entity_id,
+        field_id,
+    } = props.target;
+    let on_close = props.on_close;
+    let paste_label = match props.clipboard_count {
+        0 => "Paste Fields".to_string(),
+        1 => "Paste 1 Field".to_string(),
+        n => format!("Paste {n} Fields"),
+    };
+
+    rsx! {
+        // Backdrop: any click outside the menu closes it
+        div {
+            class: "fixed inset-0 z-40",
+            onmousedown: move |e| {
+                e.stop_propagation();
+                on_close.call(());
+            },
+            oncontextmenu: move |e| {
+                e.prevent_default();
+                e.stop_propagation();
+                on_close.call(());
+            },
+        }
+
+        div {
+            class: "context-menu fixed z-50 min-w-48 py-1 bg-slate-800 rounded-lg shadow-xl border border-slate-700 text-sm",
+            style: "left: {position.x}px; top: {position.y}px;",
+            onmousedown: move |e| e.stop_propagation(),
+
+            ContextMenuItem {
+                label: "Duplicate Entity",
+                shortcut: "Ctrl+D",
+                onclick: move |_| {
+                    duplicate_selected_entities_on_canvas();
+                    on_close.call(());
+                },
+            }
+
+            div { class: "my-1 border-t border-slate-700" }
+
+            if let Some(field_id) = field_id {
+                ContextMenuItem {
+                    label: "Copy Field",
+                    shortcut: "Ctrl+C",
+                    onclick: move |_| {
+                        APP_STATE.write().copy_fields(entity_id, &[field_id]);
+                        on_close.call(());
+                    },
+                }
+            } else {
+                ContextMenuItem {
+                    label: "Copy All Fields",
+                    shortcut: "Ctrl+C",
+                    onclick: move |_| {
+                        APP_STATE.write().copy_entity_fields(entity_id);
+                        on_close.call(());
+                    },
+                }
+            }
+
+            ContextMenuItem {
+                label: paste_label,
+                shortcut: "Ctrl+V",
+                disabled: props.clipboard_count == 0,
+                onclick: move |_| {
+                    paste_fields_into_selection();
+                    on_close.call(());
+                },
+            }
+        }
+    }
+}
+
+/// Properties for ContextMenuItem component
+#[derive(Props, Clone, PartialEq)]
+struct ContextMenuItemProps {
+    /// Item text
+    #[props(into)]
+    label: String,
+    /// Keyboard shortcut hint
+    #[props(default)]
+    shortcut: Option<&'static str>,
+    /// Whether the item is greyed out
+    #[props(default = false)]
+    disabled: bool,
+    /// Click callback
+    onclick: EventHandler<()>,
+}
+
+/// A single context menu entry
+#[component]
+fn ContextMenuItem(props: ContextMenuItemProps) -> Element {
+    rsx! {
+        button {
+            class: "w-full flex items-center justify-between gap-6 px-3 py-1.5 text-left text-slate-300 hover:bg-slate-700 hover:text-white disabled:opacity-40 disabled:pointer-events-none",
+            disabled: props.disabled,
+            onclick: move |_| props.onclick.call(()),
+
+            span { "{props.label}" }
+            if let Some(shortcut) = props.shortcut {
+                span { class: "text-xs text-slate-500", "{shortcut}" }
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Canvas Empty State Component
 // ============================================================================
@@ -891,11 +1310,25 @@ pub struct CanvasToolbarProps {
     /// Callback to add new entity
     #[props(default)]
     pub on_add_entity: EventHandler<()>,
+
+    /// Callback to lay out every entity automatically
+    #[props(default)]
+    pub on_auto_arrange: EventHandler<()>,
+
+    /// Callback to export the diagram as SVG or PNG
+    #[props(default)]
+    pub on_export_diagram: EventHandler<()>,
+
+    /// Callback to export the schema as DBML, Mermaid or PlantUML
+    #[props(default)]
+    pub on_export_schema: EventHandler<SchemaFormat>,
 }
 
 /// Toolbar for canvas-specific actions
 #[component]
 pub fn CanvasToolbar(props: CanvasToolbarProps) -> Element {
+    let mut export_open = use_signal(|| false);
+
     rsx! {
         div {
             class: "canvas-toolbar flex items-center gap-2 px-4 py-2 bg-slate-800 border-b border-slate-700",
@@ -908,6 +1341,62 @@ pub fn CanvasToolbar(props: CanvasToolbarProps) -> Element {
                 span { "Add Entity" }
             }
 
+            // Auto arrange
+            button {
+                class: "px-3 py-1.5 text-sm rounded-lg transition-colors flex items-center gap-1.5 text-slate-400 hover:text-white hover:bg-slate-700/50",
+                title: "Lay out entities in columns following their relationships",
+                onclick: move |_| props.on_auto_arrange.call(()),
+                span { "⇶" }
+                span { "Auto Arrange" }
+            }
+
+            // Export menu: the diagram as an image, or the schema as text
+            div {
+                class: "relative",
+                button {
+                    class: "px-3 py-1.5 text-sm rounded-lg transition-colors flex items-center gap-1.5",
+                    class: if export_open() { "bg-slate-700 text-white" } else { "text-slate-400 hover:text-white hover:bg-slate-700/50" },
+                    title: "Export the diagram or the schema",
+                    onclick: move |_| export_open.toggle(),
+                    span { "🖼" }
+                    span { "Export" }
+                    span { class: "text-xs", "▾" }
+                }
+
+                if export_open() {
+                    div {
+                        class: "absolute left-0 top-full mt-1 z-50 w-56 py-1 bg-slate-800 border border-slate-700 rounded-lg shadow-xl text-sm",
+                        button {
+                            class: "w-full px-3 py-1.5 text-left text-slate-300 hover:bg-slate-700",
+                            onclick: move |_| {
+                                export_open.set(false);
+                                props.on_export_diagram.call(());
+                            },
+                            "Diagram (SVG / PNG)…"
+                        }
+                        div { class: "my-1 border-t border-slate-700" }
+                        for format in SchemaFormat::all().iter().copied() {
+                            button {
+                                key: "{format.key()}",
+                                class: "w-full px-3 py-1.5 text-left text-slate-300 hover:bg-slate-700 flex justify-between",
+                                onclick: move |_| {
+                                    export_open.set(false);
+                                    props.on_export_schema.call(format);
+                                },
+                                span { "{format.display_name()} schema…" }
+                                span { class: "text-xs text-slate-500 font-mono", ".{format.extension()}" }
+                            }
+                        }
+                    }
+
+                    // Backdrop to close the menu
+                    div {
+                        class: "fixed inset-0 z-40",
+                        onclick: move |_| export_open.set(false),
+                    }
+                }
+            }
+
             // Separator
             div { class: "w-px h-6 bg-slate-700" }
 
@@ -985,49 +1474,43 @@ fn select_all_entities() {
 /// Duplicate selected entities on the canvas
 fn duplicate_selected_entities_on_canvas() {
     let mut state = APP_STATE.write();
+    let selected: Vec<EntityId> = state.selection.entities.iter().copied().collect();
+    let new_ids = state.duplicate_entities(&selected);
+    drop(state);
 
-    let selected = state.selection.entities.clone();
-    if selected.is_empty() {
-        return;
+    if !new_ids.is_empty() {
+        tracing::info!("Duplicated {} entities", new_ids.len());
     }
+}
 
-    let Some(project) = &mut state.project else {
-        return;
+/// Copy the selected field, or every field of a single selected entity
+fn copy_selected_fields() {
+    let mut state = APP_STATE.write();
+    let selected: Vec<EntityId> = state.selection.entities.iter().copied().collect();
+    let copied = match (state.selection.field, selected.as_slice()) {
+        (Some((entity_id, field_id)), _) => state.copy_fields(entity_id, &[field_id]),
+        (None, [entity_id]) => state.copy_entity_fields(*entity_id),
+        _ => return,
     };
+    drop(state);
 
-    // Collect entities to duplicate
-    let entities_to_duplicate: Vec<imortal_ir::entity::Entity> = selected
-        .iter()
-        .filter_map(|id| project.entities.get(id).cloned())
-        .collect();
-
-    // Create duplicates with offset positions
-    let offset = Position::new(30.0, 30.0);
-    let mut new_ids = Vec::new();
-
-    for entity in entities_to_duplicate {
-        let new_entity = entity
-            .duplicate()
-            .at(entity.position.x + offset.x, entity.position.y + offset.y);
-        let new_id = new_entity.id;
-        project.entities.insert(new_id, new_entity);
-        new_ids.push(new_id);
-    }
-
-    // Select the new entities
-    state.selection.clear();
-    for id in &new_ids {
-        state.selection.add_entity(*id);
-    }
-
-    // Mark as dirty
-    state.is_dirty = true;
+    tracing::info!("Copied {} fields", copied);
+}
 
-    // Save to history
+/// Paste copied fields into the selected field's entity, or every selected
+/// entity
+fn paste_fields_into_selection() {
+    let mut state = APP_STATE.write();
+    let targets: Vec<EntityId> = match state.selection.field {
+        Some((entity_id, _)) => vec![entity_id],
+        None => state.selection.entities.iter().copied().collect(),
+    };
+    let pasted = state.paste_fields(&targets);
     drop(state);
-    APP_STATE.write().save_to_history("Duplicate entities");
 
-    tracing::info!("Duplicated {} entities", new_ids.len());
+    if !pasted.is_empty() {
+        tracing::info!("Pasted {} fields", pasted.len());
+    }
 }
 
 /// Move selected entities by the given offset
@@ -1064,7 +1547,7 @@ fn move_selected_entities(dx: f32, dy: f32) {
     }
 
     // Mark as dirty
-    state.is_dirty = true;
+    state.mark_dirty();
 }
 
 // ============================================================================
@@ -1089,6 +1572,31 @@ mod tests {
         assert!(MINIMAP_THRESHOLD > 0);
     }
 
+    #[test]
+    fn test_minimap_projection() {
+        // 420x220 of content plus padding is 500x300: width-bound at 0.4
+        // scale, leaving 10px of letterbox above and below
+        let entities = Rect::new(Position::new(0.0, 0.0), Size::new(420.0, 100.0));
+        let visible = Rect::new(Position::new(100.0, 20.0), Size::new(200.0, 200.0));
+        let projection = MinimapProjection::new(&entities, &visible);
+
+        assert_eq!(
+            projection.world,
+            Rect::new(Position::new(-40.0, -40.0), Size::new(500.0, 300.0))
+        );
+        assert_eq!(projection.scale, 0.4);
+        assert_eq!(projection.offset, Position::new(0.0, 10.0));
+
+        assert_eq!(
+            projection.to_canvas(Position::new(0.0, 10.0)),
+            Position::new(-40.0, -40.0)
+        );
+        assert_eq!(
+            projection.to_canvas(Position::new(MINIMAP_WIDTH / 2.0, MINIMAP_HEIGHT / 2.0)),
+            Position::new(210.0, 110.0)
+        );
+    }
+
     #[test]
     fn test_arrow_move_constants() {
         assert!(ARROW_MOVE_STEP > 0.0);
@@ -0,0 +1,133 @@
+//! # Command Palette Component
+//!
+//! `Ctrl+K` search box over the project and app actions. Typing filters the
+//! entries from [`crate::palette`] with fuzzy matching; arrow keys move the
+//! highlight, Enter or a click opens the entry and Escape closes the palette.
+
+use dioxus::prelude::*;
+
+use crate::palette::{PaletteItem, PaletteTarget, palette_items, search};
+use crate::state::APP_STATE;
+
+/// Searchable palette of entities, fields, relationships, endpoints and actions
+#[component]
+pub fn CommandPalette() -> Element {
+    let mut query = use_signal(String::new);
+    let mut highlighted = use_signal(|| 0usize);
+
+    let state = APP_STATE.read();
+    if !state.ui.palette_open {
+        return rsx! {};
+    }
+    let items = palette_items(&state);
+    drop(state);
+
+    let results: Vec<PaletteItem> = search(&items, &query()).into_iter().cloned().collect();
+    let targets: Vec<PaletteTarget> = results.iter().map(|item| item.target).collect();
+    let current = highlighted().min(results.len().saturating_sub(1));
+
+    let mut close = move || {
+        query.set(String::new());
+        highlighted.set(0);
+        APP_STATE.write().ui.palette_open = false;
+    };
+    let mut open = move |target: PaletteTarget| {
+        query.set(String::new());
+        highlighted.set(0);
+        APP_STATE.write().open_palette_target(target);
+    };
+
+    rsx! {
+        div {
+            class: "command-palette fixed inset-0 z-50 flex items-start justify-center pt-[15vh]",
+
+            // Backdrop
+            div {
+                class: "absolute inset-0 bg-black/50",
+                onclick: move |_| close(),
+            }
+
+            div {
+                class: "relative w-full max-w-xl mx-4 bg-slate-800 rounded-lg shadow-2xl border border-slate-700 overflow-hidden",
+
+                input {
+                    class: "w-full px-4 py-3 bg-transparent border-b border-slate-700 text-slate-100 placeholder-slate-500 focus:outline-none",
+                    r#type: "text",
+                    placeholder: "Search entities, fields, relationships, endpoints and actions…",
+                    value: "{query}",
+                    autofocus: true,
+                    oninput: move |e| {
+                        query.set(e.value());
+                        highlighted.set(0);
+                    },
+                    onkeydown: move |e: KeyboardEvent| {
+                        match e.key() {
+                            Key::ArrowDown => {
+                                e.prevent_default();
+                                if !targets.is_empty() {
+                                    highlighted.set((current + 1) % targets.len());
+                                }
+                            }
+                            Key::ArrowUp => {
+                                e.prevent_default();
+                                if !targets.is_empty() {
+                                    highlighted.set((current + targets.len() - 1) % targets.len());
+                                }
+                            }
+                            Key::Enter => {
+                                e.prevent_default();
+                                if let Some(target) = targets.get(current) {
+                                    open(*target);
+                                }
+                            }
+                            Key::Escape => {
+                                e.prevent_default();
+                                e.stop_propagation();
+                                close();
+                            }
+                            _ => {}
+                        }
+                    },
+                }
+
+                div {
+                    class: "max-h-96 overflow-y-auto py-1",
+
+                    if results.is_empty() {
+                        div {
+                            class: "px-4 py-6 text-center text-sm text-slate-500",
+                            "No matches"
+                        }
+                    }
+
+                    for (i, item) in results.into_iter().enumerate() {
+                        button {
+                            key: "{i}-{item.title}",
+                            class: if i == current {
+                                "w-full flex items-center gap-3 px-4 py-2 text-left bg-indigo-600/30 text-white"
+                            } else {
+                                "w-full flex items-center gap-3 px-4 py-2 text-left text-slate-300 hover:bg-slate-700/60"
+                            },
+                            onmouseenter: move |_| highlighted.set(i),
+                            onclick: move |_| open(item.target),
+
+                            span {
+                                class: "w-20 shrink-0 text-[10px] uppercase tracking-wide text-slate-500",
+                                "{item.kind()}"
+                            }
+                            span { class: "flex-1 truncate text-sm", "{item.title}" }
+                            span { class: "shrink-0 truncate max-w-[40%] text-xs text-slate-500", "{item.detail}" }
+                        }
+                    }
+                }
+
+                div {
+                    class: "px-4 py-1.5 border-t border-slate-700 text-[11px] text-slate-500 flex gap-4",
+                    span { "↑↓ navigate" }
+                    span { "↵ open" }
+                    span { "Esc close" }
+                }
+            }
+        }
+    }
+}
@@ -20,7 +20,9 @@ use imortal_core::{Position, RelationType};
 use imortal_ir::{PortPosition, Relationship};
 use uuid::Uuid;
 
-use crate::components::entity_card::{CARD_HEADER_HEIGHT, CARD_WIDTH, FIELD_ROW_HEIGHT};
+use crate::components::entity_card::{
+    CARD_HEADER_HEIGHT, CARD_WIDTH, FIELD_ROW_HEIGHT, card_height,
+};
 use crate::state::APP_STATE;
 
 // ============================================================================
@@ -83,6 +85,9 @@ pub mod colors {
 
     /// Many-to-many relationship color
     pub const MANY_TO_MANY: &str = "#f59e0b"; // amber-500
+
+    /// Polymorphic relationship color
+    pub const POLYMORPHIC: &str = "#a855f7"; // purple-500
 }
 
 // ============================================================================
@@ -278,6 +283,7 @@ pub fn ConnectionLine(props: ConnectionLineProps) -> Element {
     // Get entity positions from state
     let state = APP_STATE.read();
     let project = state.project.as_ref();
+    let detail = state.canvas.detail();
 
     let (start_pos, end_pos, from_height, to_height) = if let Some(project) = project {
         let from_entity = project.entities.get(&relationship.from_entity_id);
@@ -285,8 +291,8 @@ pub fn ConnectionLine(props: ConnectionLineProps) -> Element {
 
         match (from_entity, to_entity) {
             (Some(from), Some(to)) => {
-                let from_height = estimate_card_height(from.fields.len(), from.collapsed);
-                let to_height = estimate_card_height(to.fields.len(), to.collapsed);
+                let from_height = card_height(from, detail);
+                let to_height = card_height(to, detail);
                 (from.position, to.position, from_height, to_height)
             }
             _ => return rsx! {}, // Entities not found
@@ -324,14 +330,20 @@ pub fn ConnectionLine(props: ConnectionLineProps) -> Element {
         )
     };
 
-    // Dash pattern for many-to-many
-    let stroke_dasharray = if matches!(relationship.relation_type, RelationType::ManyToMany { .. })
-    {
-        "8,4"
-    } else {
-        ""
+    // Dash pattern for many-to-many, dots for polymorphic (no foreign key)
+    let stroke_dasharray = match relationship.relation_type {
+        RelationType::ManyToMany { .. } => "8,4",
+        RelationType::Polymorphic { .. } => "2,4",
+        _ => "",
     };
 
+    // Polymorphic lines are labelled with their association, so the lines
+    // sharing a discriminator read as one
+    let association = relationship.polymorphic_association().map(str::to_string);
+    let label = association
+        .clone()
+        .unwrap_or_else(|| relationship.display_label());
+
     // Marker IDs
     let marker_id = format!("arrow-{}", relationship_id);
     let _marker_url = format!("url(#{})", marker_id);
@@ -420,7 +432,7 @@ pub fn ConnectionLine(props: ConnectionLineProps) -> Element {
             }
 
             // Relationship label at midpoint
-            if props.selected || *is_hovered.read() {
+            if props.selected || *is_hovered.read() || association.is_some() {
                 {
                     let mid = start.midpoint(&end);
                     rsx! {
@@ -447,7 +459,7 @@ pub fn ConnectionLine(props: ConnectionLineProps) -> Element {
                                 font_size: "11",
                                 fill: "#e2e8f0",
                                 font_family: "ui-monospace, monospace",
-                                "{relationship.display_label()}"
+                                "{label}"
                             }
                         }
                     }
@@ -659,6 +671,7 @@ pub fn relationship_color(relation_type: &RelationType) -> &'static str {
         RelationType::OneToOne => colors::ONE_TO_ONE,
         RelationType::OneToMany | RelationType::ManyToOne => colors::ONE_TO_MANY,
         RelationType::ManyToMany { .. } => colors::MANY_TO_MANY,
+        RelationType::Polymorphic { .. } => colors::POLYMORPHIC,
     }
 }
 
@@ -669,6 +682,7 @@ pub fn relationship_type_label(relation_type: &RelationType) -> &'static str {
         RelationType::OneToMany => "1:N",
         RelationType::ManyToOne => "N:1",
         RelationType::ManyToMany { .. } => "N:M",
+        RelationType::Polymorphic { .. } => "N:1*",
     }
 }
 
@@ -825,6 +839,12 @@ mod tests {
             }),
             colors::MANY_TO_MANY
         );
+        assert_eq!(
+            relationship_color(&RelationType::Polymorphic {
+                discriminator: "commentable_type".to_string()
+            }),
+            colors::POLYMORPHIC
+        );
     }
 
     #[test]
@@ -838,6 +858,12 @@ mod tests {
             }),
             "N:M"
         );
+        assert_eq!(
+            relationship_type_label(&RelationType::Polymorphic {
+                discriminator: "commentable_type".to_string()
+            }),
+            "N:1*"
+        );
     }
 
     #[test]
@@ -0,0 +1,177 @@
+//! # Diagnostics Overlay Component
+//!
+//! Floating debug panel (toggled with `Ctrl+Shift+D`) showing the counters
+//! from [`crate::diagnostics`]: canvas frame rate, state update rate, project
+//! graph size and re-renders per component region. The panel refreshes twice
+//! a second and can show its numbers as a copyable text report for issues.
+
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+use crate::diagnostics::{self, Snapshot, format_bytes};
+use crate::state::APP_STATE;
+
+/// How often the overlay re-reads the counters
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Performance diagnostics overlay
+#[component]
+pub fn DiagnosticsOverlay() -> Element {
+    // Re-runs after every APP_STATE update that reaches subscribers
+    use_effect(|| {
+        let _ = APP_STATE.read();
+        diagnostics::record_state_write();
+    });
+
+    let mut tick = use_signal(|| 0u64);
+    use_hook(|| {
+        spawn(async move {
+            loop {
+                tokio::time::sleep(REFRESH_INTERVAL).await;
+                if diagnostics::is_enabled() {
+                    tick += 1;
+                }
+            }
+        })
+    });
+
+    let mut show_report = use_signal(|| false);
+
+    let _ = tick();
+    let state = APP_STATE.read();
+    if !state.ui.diagnostics_open {
+        return rsx! {};
+    }
+    let snapshot = diagnostics::snapshot(state.project.as_ref(), state.project_revision);
+    drop(state);
+
+    let report = snapshot.report();
+
+    rsx! {
+        aside {
+            class: "diagnostics-overlay fixed left-4 bottom-10 w-80 max-h-[70vh] z-50 bg-slate-900/95 border border-slate-700 rounded-lg shadow-2xl flex flex-col overflow-hidden font-mono text-xs",
+
+            // Header
+            div {
+                class: "h-8 border-b border-slate-700 flex items-center justify-between px-3",
+                span { class: "font-medium text-slate-200", "📈 Diagnostics" }
+                div {
+                    class: "flex items-center gap-1",
+                    button {
+                        class: "px-2 py-0.5 hover:bg-slate-700 rounded text-slate-400",
+                        title: "Reset counters",
+                        onclick: move |_| diagnostics::reset(),
+                        "Reset"
+                    }
+                    button {
+                        class: "px-2 py-0.5 hover:bg-slate-700 rounded text-slate-400",
+                        title: "Show a text report to paste into an issue",
+                        onclick: move |_| show_report.toggle(),
+                        if show_report() { "Stats" } else { "Report" }
+                    }
+                    button {
+                        class: "p-1 hover:bg-slate-700 rounded",
+                        title: "Close diagnostics (Ctrl+Shift+D)",
+                        onclick: move |_| {
+                            APP_STATE.write().ui.toggle_diagnostics();
+                        },
+                        "✕"
+                    }
+                }
+            }
+
+            div {
+                class: "flex-1 overflow-auto p-3",
+                if show_report() {
+                    pre {
+                        class: "whitespace-pre-wrap select-all text-slate-300",
+                        "{report}"
+                    }
+                } else {
+                    DiagnosticsStats { snapshot }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Stats Table
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+struct DiagnosticsStatsProps {
+    snapshot: Snapshot,
+}
+
+/// Counter readout
+#[component]
+fn DiagnosticsStats(props: DiagnosticsStatsProps) -> Element {
+    let s = &props.snapshot;
+
+    rsx! {
+        div {
+            class: "space-y-1 text-slate-300",
+            StatRow { label: "Canvas FPS", value: s.canvas_fps.to_string() }
+            StatRow {
+                label: "State writes",
+                value: format!("{}/s ({} total)", s.state_writes_per_second, s.state_writes),
+            }
+            if let Some(p) = &s.project {
+                StatRow { label: "Serialized project size", value: format_bytes(p.serialized_bytes) }
+                StatRow {
+                    label: "",
+                    value: format!("{} entities · {} fields", p.entities, p.fields),
+                }
+                StatRow {
+                    label: "",
+                    value: format!("{} relationships · {} endpoints", p.relationships, p.endpoints),
+                }
+            } else {
+                StatRow { label: "Project graph", value: "no project".to_string() }
+            }
+        }
+
+        div {
+            class: "mt-3 pt-2 border-t border-slate-700",
+            div {
+                class: "flex justify-between text-slate-500 mb-1",
+                span { "Re-renders" }
+                span { "total · /s" }
+            }
+            if s.regions.is_empty() {
+                p { class: "text-slate-500", "No renders recorded yet" }
+            }
+            for region in s.regions.iter() {
+                div {
+                    key: "{region.name}",
+                    class: if region.per_second >= 30 {
+                        "flex justify-between text-amber-400"
+                    } else {
+                        "flex justify-between text-slate-300"
+                    },
+                    span { "{region.name}" }
+                    span { "{region.renders} · {region.per_second}" }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct StatRowProps {
+    label: &'static str,
+    value: String,
+}
+
+#[component]
+fn StatRow(props: StatRowProps) -> Element {
+    rsx! {
+        div {
+            class: "flex justify-between gap-2",
+            span { class: "text-slate-500", "{props.label}" }
+            span { "{props.value}" }
+        }
+    }
+}
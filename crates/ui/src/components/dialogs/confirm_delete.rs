@@ -12,6 +12,7 @@
 
 use dioxus::prelude::*;
 use imortal_core::types::{EndpointId, EntityId, FieldId, RelationshipId};
+use uuid::Uuid;
 
 use crate::state::{APP_STATE, DeleteTarget, StatusLevel};
 
@@ -83,6 +84,9 @@ pub fn ConfirmDeleteDialog(props: ConfirmDeleteDialogProps) -> Element {
             DeleteTarget::Endpoint(endpoint_id) => {
                 delete_endpoint(*endpoint_id);
             }
+            DeleteTarget::Query(query_id) => {
+                delete_query(*query_id);
+            }
         }
 
         is_deleting.set(false);
@@ -407,6 +411,23 @@ fn get_delete_info(target: &DeleteTarget) -> (&'static str, String, String, bool
                 Vec::new(),
             )
         }
+
+        DeleteTarget::Query(query_id) => {
+            let query_name = state
+                .project
+                .as_ref()
+                .and_then(|p| p.queries.get(query_id))
+                .map(|q| format!("{} ({})", q.name, q.path))
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            (
+                "Delete Query",
+                "Are you sure you want to delete this custom query endpoint?".to_string(),
+                query_name,
+                false,
+                Vec::new(),
+            )
+        }
     }
 }
 
@@ -453,7 +474,7 @@ fn delete_entity(entity_id: EntityId) {
         state.selection.entities.remove(&entity_id);
 
         // Mark dirty and set status
-        state.is_dirty = true;
+        state.mark_dirty();
         state.ui.close_dialog();
         state.ui.set_status(
             &format!("Deleted entity '{}'", entity_name),
@@ -488,7 +509,7 @@ fn delete_field(entity_id: EntityId, field_id: FieldId) {
             }
 
             // Mark dirty and set status
-            state.is_dirty = true;
+            state.mark_dirty();
             state.ui.close_dialog();
             state.ui.set_status(
                 &format!("Deleted field '{}'", field_name),
@@ -514,7 +535,7 @@ fn delete_relationship(relationship_id: RelationshipId) {
         state.selection.relationships.remove(&relationship_id);
 
         // Mark dirty and set status
-        state.is_dirty = true;
+        state.mark_dirty();
         state.ui.close_dialog();
         state
             .ui
@@ -545,7 +566,7 @@ fn delete_endpoint(endpoint_id: EndpointId) {
         state.selection.endpoints.remove(&endpoint_id);
 
         // Mark dirty and set status
-        state.is_dirty = true;
+        state.mark_dirty();
         state.ui.close_dialog();
         state.ui.set_status(
             &format!("Deleted endpoint group '{}'", path),
@@ -558,6 +579,27 @@ fn delete_endpoint(endpoint_id: EndpointId) {
     }
 }
 
+/// Delete a custom query endpoint
+fn delete_query(query_id: Uuid) {
+    let mut state = APP_STATE.write();
+
+    if let Some(project) = &mut state.project {
+        let name = project
+            .remove_query(query_id)
+            .map(|q| q.name)
+            .unwrap_or_default();
+
+        state.mark_dirty();
+        state.ui.close_dialog();
+        state
+            .ui
+            .set_status(&format!("Deleted query '{}'", name), StatusLevel::Success);
+
+        drop(state);
+        APP_STATE.write().save_to_history("Delete query");
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -582,6 +624,7 @@ mod tests {
         let _ = DeleteTarget::Field(entity_id, field_id);
         let _ = DeleteTarget::Relationship(rel_id);
         let _ = DeleteTarget::Endpoint(ep_id);
+        let _ = DeleteTarget::Query(Uuid::new_v4());
 
         assert!(true);
     }
@@ -148,6 +148,18 @@ pub fn get_all_data_types() -> Vec<DataTypeInfo> {
             "f64",
             "DOUBLE PRECISION",
         ),
+        DataTypeInfo::new(
+            DataType::Decimal {
+                precision: 12,
+                scale: 2,
+            },
+            "Decimal",
+            "Exact fixed-point number for money and measurements",
+            "💰",
+            DataTypeCategory::Numeric,
+            "Decimal",
+            "NUMERIC(p,s)",
+        ),
         // Temporal types
         DataTypeInfo::new(
             DataType::DateTime,
@@ -9,6 +9,7 @@
 //! - Set global security (open, authenticated, role-based)
 //! - Configure per-operation security overrides
 //! - Set rate limiting per operation
+//! - Add related-row counts and existence flags to list responses
 //! - Add tags and descriptions for API documentation
 //!
 //! ## Usage
@@ -20,9 +21,14 @@
 //! ```
 
 use dioxus::prelude::*;
-use imortal_ir::{CrudOperation, EndpointGroup, EndpointSecurity, OperationType, RateLimit};
+use imortal_ir::relationship_helpers::dependent_entity;
+use imortal_ir::{
+    AggregateKind, ApiVersioning, CrudOperation, ETagSource, EndpointGroup, EndpointSecurity,
+    HttpCaching, OperationType, RateLimit, RealtimeTransport, RelatedAggregate,
+};
 use uuid::Uuid;
 
+use crate::components::icon::{Icon, IconName};
 use crate::components::inputs::{NumberInput, Select, SelectOption, TextArea, TextInput, Toggle};
 use crate::state::{APP_STATE, StatusLevel};
 
@@ -80,30 +86,45 @@ pub struct EndpointDialogProps {
 // Active Tab
 // ============================================================================
 
+/// Tabs of the endpoint dialog
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ActiveTab {
+pub enum EndpointTab {
     General,
     Operations,
     Security,
     RateLimits,
 }
 
-impl ActiveTab {
+impl EndpointTab {
     fn label(&self) -> &'static str {
         match self {
-            ActiveTab::General => "General",
-            ActiveTab::Operations => "Operations",
-            ActiveTab::Security => "Security",
-            ActiveTab::RateLimits => "Rate Limits",
+            EndpointTab::General => "General",
+            EndpointTab::Operations => "Operations",
+            EndpointTab::Security => "Security",
+            EndpointTab::RateLimits => "Rate Limits",
         }
     }
 
-    fn all() -> &'static [ActiveTab] {
+    /// The tab that edits the part of an endpoint a validation path names,
+    /// e.g. `endpoints.<id>.operations.Create.rate_limit` is on Rate Limits
+    pub fn for_path(path: &str) -> Self {
+        if path.contains(".rate_limit") {
+            EndpointTab::RateLimits
+        } else if path.contains(".security") || path.contains(".global_security") {
+            EndpointTab::Security
+        } else if path.contains(".operations") || path.contains(".related_aggregates") {
+            EndpointTab::Operations
+        } else {
+            EndpointTab::General
+        }
+    }
+
+    fn all() -> &'static [EndpointTab] {
         &[
-            ActiveTab::General,
-            ActiveTab::Operations,
-            ActiveTab::Security,
-            ActiveTab::RateLimits,
+            EndpointTab::General,
+            EndpointTab::Operations,
+            EndpointTab::Security,
+            EndpointTab::RateLimits,
         ]
     }
 }
@@ -115,7 +136,13 @@ impl ActiveTab {
 /// Endpoint configuration dialog component
 #[component]
 pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
-    let mut active_tab = use_signal(|| ActiveTab::General);
+    let mut active_tab = use_signal(|| {
+        APP_STATE
+            .peek()
+            .ui
+            .endpoint_tab
+            .unwrap_or(EndpointTab::General)
+    });
 
     // Get entities for the entity selector
     let state = APP_STATE.read();
@@ -139,6 +166,37 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
         .as_ref()
         .map(|p| p.endpoints.values().map(|ep| ep.entity_id).collect())
         .unwrap_or_default();
+
+    // Relationships whose other side points back at an entity:
+    // (relationship id, listed entity id, related entity name)
+    let aggregate_sources: Vec<(Uuid, Uuid, String)> = state
+        .project
+        .as_ref()
+        .map(|p| {
+            let mut sources = Vec::new();
+            for rel in p.relationships.values() {
+                for listed in [rel.from_entity_id, rel.to_entity_id] {
+                    if let Some(related) =
+                        dependent_entity(rel, listed).and_then(|id| p.entities.get(&id))
+                    {
+                        sources.push((rel.id, listed, related.name.clone()));
+                    }
+                }
+            }
+            sources.sort_by(|a, b| a.2.cmp(&b.2));
+            sources
+        })
+        .unwrap_or_default();
+    let route_style = state
+        .project
+        .as_ref()
+        .map(|p| p.config.route_style)
+        .unwrap_or_default();
+    let api_versioning = state
+        .project
+        .as_ref()
+        .map(|p| p.config.api_versioning.clone())
+        .unwrap_or_default();
     drop(state);
 
     // Initialize form state based on mode
@@ -159,7 +217,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                 .and_then(|p| p.entities.get(&eid))
                 .map(|e| e.name.clone())
                 .unwrap_or_else(|| "Entity".to_string());
-            EndpointGroup::new(eid, entity_name)
+            EndpointGroup::new(eid, entity_name).with_route_style(&route_style)
         }),
     };
 
@@ -178,6 +236,8 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
     let mut description = use_signal(|| default_ep.description.clone().unwrap_or_default());
     let mut tags_str = use_signal(|| default_ep.tags.join(", "));
     let mut is_enabled = use_signal(|| default_ep.enabled);
+    let mut is_realtime = use_signal(|| default_ep.realtime);
+    let mut realtime_transport = use_signal(|| default_ep.realtime_transport);
 
     // Operation enables
     let mut op_create_enabled = use_signal(|| {
@@ -215,6 +275,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
     let mut global_auth_required = use_signal(|| default_ep.global_security.auth_required);
     let mut global_roles = use_signal(|| default_ep.global_security.roles.join(", "));
     let mut global_cors_enabled = use_signal(|| default_ep.global_security.cors_enabled);
+    let mut global_cors_origins = use_signal(|| default_ep.global_security.cors_origins.join(", "));
 
     // Per-operation security overrides (store as strings for the selected op)
     let _selected_op_for_security = use_signal(|| OperationType::Create);
@@ -246,6 +307,24 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
             .collect()
     });
 
+    // ETag / Last-Modified options of the read operations
+    let mut caching: Signal<Vec<(OperationType, HttpCaching)>> = use_signal(|| {
+        [OperationType::Read, OperationType::ReadAll]
+            .into_iter()
+            .map(|op_type| {
+                let caching = default_ep
+                    .get_operation(op_type)
+                    .map(|op| op.caching.clone())
+                    .unwrap_or_default();
+                (op_type, caching)
+            })
+            .collect()
+    });
+
+    // Related counts / existence flags for list responses
+    let mut related_aggregates: Signal<Vec<RelatedAggregate>> =
+        use_signal(|| default_ep.related_aggregates.clone());
+
     // Error message
     let mut error_message: Signal<Option<String>> = use_signal(|| None);
 
@@ -272,6 +351,18 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
         opts
     };
 
+    // Relationships that can be aggregated for the selected entity
+    let aggregate_options: Vec<(Uuid, String)> = Uuid::parse_str(&selected_entity_id.read())
+        .map(|eid| {
+            aggregate_sources
+                .iter()
+                .filter(|(_, listed, _)| *listed == eid)
+                .map(|(rel_id, _, related)| (*rel_id, to_snake_case_plural(related)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let aggregate_rel_ids: Vec<Uuid> = aggregate_options.iter().map(|(id, _)| *id).collect();
+
     // Auto-update base_path when entity changes (create mode only)
     let entities_for_path = entities.clone();
     let mode_is_create = props.mode.is_create();
@@ -325,6 +416,15 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                     op.rate_limit = Some(RateLimit::new(*requests, *window));
                 }
             }
+
+            // Apply caching headers
+            if let Some((_, c)) = caching
+                .read()
+                .iter()
+                .find(|(ot, _)| *ot == op.operation_type)
+            {
+                op.caching = c.clone();
+            }
         }
 
         // Build global security
@@ -340,7 +440,12 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
             roles,
             scopes: Vec::new(),
             cors_enabled: *global_cors_enabled.read(),
-            cors_origins: Vec::new(),
+            cors_origins: global_cors_origins
+                .read()
+                .split(',')
+                .map(|s| s.trim().trim_end_matches('/').to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
             allow_public_preview: false,
         };
 
@@ -368,6 +473,14 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
             Some(desc_val)
         };
 
+        // Drop aggregates that no longer apply to the selected entity
+        let aggregates: Vec<RelatedAggregate> = related_aggregates
+            .read()
+            .iter()
+            .filter(|a| aggregate_rel_ids.contains(&a.relationship_id))
+            .cloned()
+            .collect();
+
         // Get entity name
         let entity_name = {
             let state = APP_STATE.read();
@@ -388,8 +501,11 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                 endpoint.description = desc;
                 endpoint.tags = tags;
                 endpoint.enabled = *is_enabled.read();
+                endpoint.realtime = *is_realtime.read();
+                endpoint.realtime_transport = *realtime_transport.read();
                 endpoint.operations = operations;
                 endpoint.global_security = global_security;
+                endpoint.related_aggregates = aggregates;
 
                 if let Some(project) = &mut state.project {
                     project.add_endpoint(endpoint);
@@ -411,8 +527,11 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                         ep.description = desc;
                         ep.tags = tags;
                         ep.enabled = *is_enabled.read();
+                        ep.realtime = *is_realtime.read();
+                        ep.realtime_transport = *realtime_transport.read();
                         ep.operations = operations;
                         ep.global_security = global_security;
+                        ep.related_aggregates = aggregates;
                     }
                 }
 
@@ -424,7 +543,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
             }
         }
 
-        state.is_dirty = true;
+        state.mark_dirty();
         state.ui.close_dialog();
     };
 
@@ -443,18 +562,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                 h2 {
                     class: "text-xl font-semibold text-white flex items-center gap-2",
                     // API icon
-                    svg {
-                        class: "w-6 h-6 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                        }
-                    }
+                    Icon { name: IconName::Terminal, class: "w-6 h-6 text-indigo-400" }
                     "{title}"
                 }
 
@@ -463,18 +571,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                     onclick: move |_| {
                         APP_STATE.write().ui.close_dialog();
                     },
-                    svg {
-                        class: "w-5 h-5",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M6 18L18 6M6 6l12 12",
-                        }
-                    }
+                    Icon { name: IconName::Close, class: "w-5 h-5" }
                 }
             }
 
@@ -482,7 +579,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
             div {
                 class: "flex border-b border-slate-700",
 
-                for tab in ActiveTab::all().iter() {
+                for tab in EndpointTab::all().iter() {
                     {
                         let t = *tab;
                         rsx! {
@@ -517,7 +614,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                 }
 
                 match *active_tab.read() {
-                    ActiveTab::General => rsx! {
+                    EndpointTab::General => rsx! {
                         GeneralTab {
                             mode_is_create: mode_is_create,
                             entity_options: entity_options.clone(),
@@ -528,8 +625,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                                 if mode_is_create {
                                     if let Ok(eid) = Uuid::parse_str(&v) {
                                         if let Some((_, name)) = entities_for_path.iter().find(|(id, _)| *id == eid) {
-                                            let snake = to_snake_case_plural(name);
-                                            base_path.set(format!("/api/{}", snake));
+                                            base_path.set(route_style.base_path(name));
                                         }
                                     }
                                 }
@@ -538,15 +634,20 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                             on_base_path_change: move |v: String| base_path.set(v),
                             api_version: api_version.read().clone(),
                             on_api_version_change: move |v: String| api_version.set(v),
+                            api_versioning: api_versioning.clone(),
                             description: description.read().clone(),
                             on_description_change: move |v: String| description.set(v),
                             tags: tags_str.read().clone(),
                             on_tags_change: move |v: String| tags_str.set(v),
                             is_enabled: *is_enabled.read(),
                             on_enabled_change: move |v: bool| is_enabled.set(v),
+                            is_realtime: *is_realtime.read(),
+                            on_realtime_change: move |v: bool| is_realtime.set(v),
+                            realtime_transport: *realtime_transport.read(),
+                            on_realtime_transport_change: move |v: RealtimeTransport| realtime_transport.set(v),
                         }
                     },
-                    ActiveTab::Operations => rsx! {
+                    EndpointTab::Operations => rsx! {
                         OperationsTab {
                             create_enabled: *op_create_enabled.read(),
                             on_create_toggle: move |v: bool| op_create_enabled.set(v),
@@ -559,9 +660,29 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                             delete_enabled: *op_delete_enabled.read(),
                             on_delete_toggle: move |v: bool| op_delete_enabled.set(v),
                             base_path: base_path.read().clone(),
+                            aggregate_options: aggregate_options.clone(),
+                            aggregates: related_aggregates.read().clone(),
+                            on_aggregate_toggle: move |(rel_id, kind, on, related): (Uuid, AggregateKind, bool, String)| {
+                                let mut aggregates = related_aggregates.write();
+                                aggregates.retain(|a| !(a.relationship_id == rel_id && a.kind == kind));
+                                if on {
+                                    let field = kind.default_field(&related);
+                                    aggregates.push(match kind {
+                                        AggregateKind::Count => RelatedAggregate::count(rel_id, field),
+                                        AggregateKind::Exists => RelatedAggregate::exists(rel_id, field),
+                                    });
+                                }
+                            },
+                            caching: caching.read().clone(),
+                            on_caching_change: move |(op_type, c): (OperationType, HttpCaching)| {
+                                let mut all = caching.write();
+                                if let Some(entry) = all.iter_mut().find(|(ot, _)| *ot == op_type) {
+                                    entry.1 = c;
+                                }
+                            },
                         }
                     },
-                    ActiveTab::Security => rsx! {
+                    EndpointTab::Security => rsx! {
                         SecurityTab {
                             auth_required: *global_auth_required.read(),
                             on_auth_change: move |v: bool| global_auth_required.set(v),
@@ -569,6 +690,8 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                             on_roles_change: move |v: String| global_roles.set(v),
                             cors_enabled: *global_cors_enabled.read(),
                             on_cors_change: move |v: bool| global_cors_enabled.set(v),
+                            cors_origins: global_cors_origins.read().clone(),
+                            on_cors_origins_change: move |v: String| global_cors_origins.set(v),
                             per_op_overrides: per_op_auth_overrides.read().clone(),
                             on_per_op_override_change: move |(op_type, val): (OperationType, Option<bool>)| {
                                 let mut overrides = per_op_auth_overrides.write();
@@ -578,7 +701,7 @@ pub fn EndpointDialog(props: EndpointDialogProps) -> Element {
                             },
                         }
                     },
-                    ActiveTab::RateLimits => rsx! {
+                    EndpointTab::RateLimits => rsx! {
                         RateLimitsTab {
                             rate_limits: rate_limits.read().clone(),
                             on_rate_limit_change: move |(op_type, val): (OperationType, Option<(u32, u32)>)| {
@@ -639,12 +762,18 @@ struct GeneralTabProps {
     on_base_path_change: EventHandler<String>,
     api_version: String,
     on_api_version_change: EventHandler<String>,
+    /// Project-wide versioning strategy, for the path preview
+    api_versioning: ApiVersioning,
     description: String,
     on_description_change: EventHandler<String>,
     tags: String,
     on_tags_change: EventHandler<String>,
     is_enabled: bool,
     on_enabled_change: EventHandler<bool>,
+    is_realtime: bool,
+    on_realtime_change: EventHandler<bool>,
+    realtime_transport: RealtimeTransport,
+    on_realtime_transport_change: EventHandler<RealtimeTransport>,
 }
 
 #[component]
@@ -690,7 +819,7 @@ fn GeneralTab(props: GeneralTabProps) -> Element {
                     TextInput {
                         label: "API Version (optional)",
                         value: props.api_version.clone(),
-                        placeholder: "v1",
+                        placeholder: props.api_versioning.default_version().unwrap_or("v1").to_string(),
                         on_change: move |v: String| props.on_api_version_change.call(v),
                     }
                 }
@@ -706,6 +835,41 @@ fn GeneralTab(props: GeneralTabProps) -> Element {
                 }
             }
 
+            // Realtime toggle + transport
+            div {
+                class: "grid grid-cols-2 gap-4",
+
+                div {
+                    Toggle {
+                        label: "Realtime",
+                        checked: props.is_realtime,
+                        on_change: move |v: bool| props.on_realtime_change.call(v),
+                    }
+                    p {
+                        class: "mt-1 text-xs text-slate-500",
+                        "Push create, update and delete events to REST streams and GraphQL subscribers."
+                    }
+                }
+
+                div {
+                    Select {
+                        label: "REST Stream",
+                        value: props.realtime_transport.path().to_string(),
+                        options: realtime_transport_options(),
+                        disabled: !props.is_realtime,
+                        on_change: move |v: String| {
+                            if let Some(transport) = RealtimeTransport::all().iter().find(|t| t.path() == v) {
+                                props.on_realtime_transport_change.call(*transport);
+                            }
+                        },
+                    }
+                    p {
+                        class: "mt-1 text-xs text-slate-500",
+                        "Served at {props.base_path}/{props.realtime_transport.path()}."
+                    }
+                }
+            }
+
             // Description
             div {
                 TextArea {
@@ -742,11 +906,8 @@ fn GeneralTab(props: GeneralTabProps) -> Element {
 
                     // Show what the final paths look like
                     {
-                        let bp = if props.api_version.is_empty() {
-                            props.base_path.clone()
-                        } else {
-                            format!("/api/{}{}", props.api_version, props.base_path.trim_start_matches("/api"))
-                        };
+                        let version = Some(props.api_version.as_str()).filter(|v| !v.is_empty());
+                        let bp = props.api_versioning.public_path(&props.base_path, version);
                         rsx! {
                             div {
                                 class: "flex items-center gap-2",
@@ -798,6 +959,13 @@ struct OperationsTabProps {
     delete_enabled: bool,
     on_delete_toggle: EventHandler<bool>,
     base_path: String,
+    /// (relationship id, related collection name) pairs that can be aggregated
+    aggregate_options: Vec<(Uuid, String)>,
+    aggregates: Vec<RelatedAggregate>,
+    on_aggregate_toggle: EventHandler<(Uuid, AggregateKind, bool, String)>,
+    /// ETag / Last-Modified options of Read and List
+    caching: Vec<(OperationType, HttpCaching)>,
+    on_caching_change: EventHandler<(OperationType, HttpCaching)>,
 }
 
 #[component]
@@ -940,6 +1108,132 @@ fn OperationsTab(props: OperationsTabProps) -> Element {
                     }
                 }
             }
+
+            // Related aggregates for the list response
+            if props.read_all_enabled && !props.aggregate_options.is_empty() {
+                div {
+                    class: "pt-3 border-t border-slate-700 space-y-2",
+
+                    h4 {
+                        class: "text-sm font-medium text-slate-300",
+                        "List Aggregates"
+                    }
+                    p {
+                        class: "text-xs text-slate-500",
+                        "Add related counts or existence flags to each list item. Each is loaded with one grouped query per page."
+                    }
+
+                    for (rel_id, related) in props.aggregate_options.iter().cloned() {
+                        {
+                            let has = |kind: AggregateKind| {
+                                props
+                                    .aggregates
+                                    .iter()
+                                    .any(|a| a.relationship_id == rel_id && a.kind == kind)
+                            };
+                            let count_on = has(AggregateKind::Count);
+                            let exists_on = has(AggregateKind::Exists);
+                            let count_field = AggregateKind::Count.default_field(&related);
+                            let exists_field = AggregateKind::Exists.default_field(&related);
+                            let related_for_count = related.clone();
+                            let related_for_exists = related.clone();
+                            rsx! {
+                                div {
+                                    key: "{rel_id}",
+                                    class: "flex items-center gap-6 p-3 rounded-lg bg-slate-800 border border-slate-700",
+
+                                    span {
+                                        class: "flex-1 text-sm text-white",
+                                        "{related}"
+                                    }
+                                    div {
+                                        class: "flex items-center gap-2",
+                                        Toggle {
+                                            checked: count_on,
+                                            on_change: move |v: bool| {
+                                                props.on_aggregate_toggle.call((rel_id, AggregateKind::Count, v, related_for_count.clone()));
+                                            },
+                                        }
+                                        span { class: "font-mono text-xs text-slate-400", "{count_field}" }
+                                    }
+                                    div {
+                                        class: "flex items-center gap-2",
+                                        Toggle {
+                                            checked: exists_on,
+                                            on_change: move |v: bool| {
+                                                props.on_aggregate_toggle.call((rel_id, AggregateKind::Exists, v, related_for_exists.clone()));
+                                            },
+                                        }
+                                        span { class: "font-mono text-xs text-slate-400", "{exists_field}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Conditional request headers for the read operations
+            if props.read_enabled || props.read_all_enabled {
+                div {
+                    class: "pt-3 border-t border-slate-700 space-y-2",
+
+                    h4 {
+                        class: "text-sm font-medium text-slate-300",
+                        "Caching Headers"
+                    }
+                    p {
+                        class: "text-xs text-slate-500",
+                        "Send ETag / Last-Modified and answer matching If-None-Match / If-Modified-Since requests with 304 Not Modified. Options using updated_at need entity timestamps."
+                    }
+
+                    for (op_type, op_caching) in props.caching.iter().cloned() {
+                        if (op_type == OperationType::Read && props.read_enabled)
+                            || (op_type == OperationType::ReadAll && props.read_all_enabled)
+                        {
+                            {
+                                let for_etag = op_caching.clone();
+                                let for_modified = op_caching.clone();
+                                rsx! {
+                                    div {
+                                        key: "{op_type.display_name()}-caching",
+                                        class: "flex items-center gap-6 p-3 rounded-lg bg-slate-800 border border-slate-700",
+
+                                        span {
+                                            class: "flex-1 text-sm text-white",
+                                            "{op_type.display_name()}"
+                                        }
+                                        div {
+                                            class: "w-44",
+                                            Select {
+                                                value: op_caching.etag.map(|e| e.display_name()).unwrap_or("").to_string(),
+                                                options: etag_source_options(),
+                                                on_change: move |v: String| {
+                                                    let mut c = for_etag.clone();
+                                                    c.etag = ETagSource::all().iter().copied().find(|e| e.display_name() == v);
+                                                    props.on_caching_change.call((op_type, c));
+                                                },
+                                            }
+                                        }
+                                        div {
+                                            class: "flex items-center gap-2",
+                                            Toggle {
+                                                checked: op_caching.last_modified,
+                                                on_change: move |v: bool| {
+                                                    let mut c = for_modified.clone();
+                                                    c.last_modified = v;
+                                                    props.on_caching_change.call((op_type, c));
+                                                },
+                                            }
+                                            span { class: "text-xs text-slate-400", "Last-Modified" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -1032,6 +1326,8 @@ struct SecurityTabProps {
     on_roles_change: EventHandler<String>,
     cors_enabled: bool,
     on_cors_change: EventHandler<bool>,
+    cors_origins: String,
+    on_cors_origins_change: EventHandler<String>,
     per_op_overrides: Vec<(OperationType, Option<bool>)>,
     on_per_op_override_change: EventHandler<(OperationType, Option<bool>)>,
 }
@@ -1046,18 +1342,7 @@ fn SecurityTab(props: SecurityTabProps) -> Element {
             div {
                 h3 {
                     class: "text-sm font-semibold text-white mb-3 flex items-center gap-2",
-                    svg {
-                        class: "w-4 h-4 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M9 12l2 2 4-4m5.618-4.016A11.955 11.955 0 0112 2.944a11.955 11.955 0 01-8.618 3.04A12.02 12.02 0 003 9c0 5.591 3.824 10.29 9 11.622 5.176-1.332 9-6.03 9-11.622 0-1.042-.133-2.052-.382-3.016z",
-                        }
-                    }
+                    Icon { name: IconName::Shield, class: "w-4 h-4 text-indigo-400" }
                     "Global Security"
                 }
 
@@ -1105,6 +1390,21 @@ fn SecurityTab(props: SecurityTabProps) -> Element {
                             "Allow cross-origin requests to these endpoints."
                         }
                     }
+
+                    if props.cors_enabled {
+                        div {
+                            TextInput {
+                                label: "Allowed Origins (comma-separated)",
+                                value: props.cors_origins.clone(),
+                                placeholder: "https://admin.example.com",
+                                on_change: move |v: String| props.on_cors_origins_change.call(v),
+                            }
+                            p {
+                                class: "mt-1 text-xs text-slate-500",
+                                "Replaces the project's allowed origins on these routes. Leave empty to use the project's."
+                            }
+                        }
+                    }
                 }
             }
 
@@ -1112,18 +1412,7 @@ fn SecurityTab(props: SecurityTabProps) -> Element {
             div {
                 h3 {
                     class: "text-sm font-semibold text-white mb-3 flex items-center gap-2",
-                    svg {
-                        class: "w-4 h-4 text-amber-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M12 6V4m0 2a2 2 0 100 4m0-4a2 2 0 110 4m-6 8a2 2 0 100-4m0 4a2 2 0 110-4m0 4v2m0-6V4m6 6v10m6-2a2 2 0 100-4m0 4a2 2 0 110-4m0 4v2m0-6V4",
-                        }
-                    }
+                    Icon { name: IconName::Adjustments, class: "w-4 h-4 text-amber-400" }
                     "Per-Operation Overrides"
                 }
 
@@ -1254,18 +1543,7 @@ fn RateLimitsTab(props: RateLimitsTabProps) -> Element {
                 class: "p-4 bg-slate-800/50 rounded-lg border border-slate-700",
                 div {
                     class: "flex items-start gap-3",
-                    svg {
-                        class: "w-5 h-5 text-cyan-400 mt-0.5 flex-shrink-0",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z",
-                        }
-                    }
+                    Icon { name: IconName::Info, class: "w-5 h-5 text-cyan-400 mt-0.5 flex-shrink-0" }
                     div {
                         p {
                             class: "text-sm text-slate-300",
@@ -1452,6 +1730,24 @@ fn RateLimitsTab(props: RateLimitsTabProps) -> Element {
 // Helper Functions
 // ============================================================================
 
+/// Options of the REST stream select, keyed by the route's path segment.
+fn realtime_transport_options() -> Vec<SelectOption> {
+    RealtimeTransport::all()
+        .iter()
+        .map(|t| SelectOption::new(t.path(), t.display_name()))
+        .collect()
+}
+
+fn etag_source_options() -> Vec<SelectOption> {
+    std::iter::once(SelectOption::new("", "No ETag"))
+        .chain(
+            ETagSource::all().iter().map(|e| {
+                SelectOption::new(e.display_name(), format!("ETag: {}", e.display_name()))
+            }),
+        )
+        .collect()
+}
+
 /// Simple snake_case pluralization (matches the IR crate's logic)
 fn to_snake_case_plural(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
@@ -1514,11 +1810,38 @@ mod tests {
 
     #[test]
     fn test_active_tab() {
-        assert_eq!(ActiveTab::General.label(), "General");
-        assert_eq!(ActiveTab::Operations.label(), "Operations");
-        assert_eq!(ActiveTab::Security.label(), "Security");
-        assert_eq!(ActiveTab::RateLimits.label(), "Rate Limits");
-        assert_eq!(ActiveTab::all().len(), 4);
+        assert_eq!(EndpointTab::General.label(), "General");
+        assert_eq!(EndpointTab::Operations.label(), "Operations");
+        assert_eq!(EndpointTab::Security.label(), "Security");
+        assert_eq!(EndpointTab::RateLimits.label(), "Rate Limits");
+        assert_eq!(EndpointTab::all().len(), 4);
+    }
+
+    #[test]
+    fn test_endpoint_tab_for_path() {
+        let id = Uuid::new_v4();
+        let tab = |suffix: &str| EndpointTab::for_path(&format!("endpoints.{}{}", id, suffix));
+        assert_eq!(tab(""), EndpointTab::General);
+        assert_eq!(tab(".api_version"), EndpointTab::General);
+        assert_eq!(tab(".security"), EndpointTab::Security);
+        assert_eq!(
+            tab(".global_security.cors_origins[0]"),
+            EndpointTab::Security
+        );
+        assert_eq!(tab(".operations.Create"), EndpointTab::Operations);
+        assert_eq!(tab(".related_aggregates.posts"), EndpointTab::Operations);
+        assert_eq!(
+            tab(".operations.Create.rate_limit"),
+            EndpointTab::RateLimits
+        );
+    }
+
+    #[test]
+    fn test_realtime_transport_options() {
+        let options = realtime_transport_options();
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].value, "ws");
+        assert_eq!(options[1].label, "Server-Sent Events");
     }
 
     #[test]
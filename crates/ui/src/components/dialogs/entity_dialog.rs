@@ -8,14 +8,33 @@
 //! - Edit existing entities
 //! - Configure entity options (timestamps, soft delete, auditable)
 //! - Select ID type (UUID, Serial, CUID, ULID)
+//! - Set storage options for the project's database (PostgreSQL tablespace,
+//!   MySQL engine, character set and collation)
+//! - Set how many seed records to generate
+//! - Declare composite unique constraints and multi-column indexes
+//! - Include field sets (built-in or saved from copied fields)
+//! - Accept or dismiss suggested fields, descriptions and validations
+//! - Infer fields from a pasted JSON sample (nested objects become JSON
+//!   columns or related entities)
 //! - Validation with error messages
 //!
 
-use dioxus::prelude::*;
-use imortal_core::types::{EntityId, IdType, Position, Size};
-use imortal_ir::entity::{Entity, EntityConfig};
+use std::collections::HashSet;
 
-use crate::components::inputs::{Select, SelectOption, TextArea, TextInput, Toggle};
+use dioxus::prelude::*;
+use imortal_core::types::{DatabaseType, EntityId, IdType, Position, Size};
+use imortal_ir::entity::{Entity, EntityConfig, EntityIndex, IndexType};
+use imortal_ir::inference::{InferredEntity, NestedMode, infer_entity};
+use imortal_ir::suggestions::{Suggestion, SuggestionKind};
+use imortal_ir::{FieldSet, ProjectGraph, enum_of};
+use uuid::Uuid;
+
+use crate::components::help_panel::HelpButton;
+use crate::components::inputs::{
+    Checkbox, NumberInput, Select, SelectOption, TextArea, TextInput, Toggle,
+};
+use crate::components::suggestions::{SuggestionChips, suggestions_for};
+use crate::help::HelpTopic;
 use crate::state::{APP_STATE, StatusLevel};
 
 // ============================================================================
@@ -36,12 +55,28 @@ pub enum EntityDialogMode {
 struct EntityFormState {
     name: String,
     table_name: String,
+    schema: String,
+    tablespace: String,
+    engine: String,
+    charset: String,
+    collation: String,
     description: String,
     timestamps: bool,
     soft_delete: bool,
     auditable: bool,
     generate_api: bool,
     id_type: IdType,
+    seed_count: u32,
+    unique_together: Vec<Vec<String>>,
+    indexes: Vec<EntityIndex>,
+    /// Included field sets, in order
+    field_sets: Vec<Uuid>,
+    /// Name for a field set made from the copied fields
+    new_set_name: String,
+    /// Comma-separated fields of the constraint being added
+    new_constraint_fields: String,
+    /// "unique" or an index type for the constraint being added
+    new_constraint_kind: String,
 }
 
 impl Default for EntityFormState {
@@ -49,12 +84,24 @@ impl Default for EntityFormState {
         Self {
             name: String::new(),
             table_name: String::new(),
+            schema: String::new(),
+            tablespace: String::new(),
+            engine: String::new(),
+            charset: String::new(),
+            collation: String::new(),
             description: String::new(),
             timestamps: true,
             soft_delete: false,
             auditable: false,
             generate_api: true,
             id_type: IdType::Uuid,
+            seed_count: EntityConfig::DEFAULT_SEED_COUNT,
+            unique_together: Vec::new(),
+            indexes: Vec::new(),
+            field_sets: Vec::new(),
+            new_set_name: String::new(),
+            new_constraint_fields: String::new(),
+            new_constraint_kind: "unique".to_string(),
         }
     }
 }
@@ -65,12 +112,22 @@ impl EntityFormState {
         Self {
             name: entity.name.clone(),
             table_name: entity.table_name.clone(),
+            schema: entity.config.schema.clone().unwrap_or_default(),
+            tablespace: entity.config.tablespace.clone().unwrap_or_default(),
+            engine: entity.config.engine.clone().unwrap_or_default(),
+            charset: entity.config.charset.clone().unwrap_or_default(),
+            collation: entity.config.collation.clone().unwrap_or_default(),
             description: entity.description.clone().unwrap_or_default(),
             timestamps: entity.config.timestamps,
             soft_delete: entity.config.soft_delete,
             auditable: entity.config.auditable,
             generate_api: entity.config.generate_api,
             id_type: entity.config.id_type.clone(),
+            seed_count: entity.config.seed_count,
+            unique_together: entity.config.unique_together.clone(),
+            indexes: entity.config.indexes.clone(),
+            field_sets: entity.field_sets.clone(),
+            ..Self::default()
         }
     }
 
@@ -94,9 +151,51 @@ impl EntityFormState {
             );
         }
 
+        // Validate schema if provided
+        if !self.schema.trim().is_empty() && !is_valid_table_name(self.schema.trim()) {
+            errors.push("Schema must be a valid SQL identifier".to_string());
+        }
+
+        // Validate storage options if provided
+        for (label, value) in [
+            ("Tablespace", &self.tablespace),
+            ("Engine", &self.engine),
+            ("Character set", &self.charset),
+            ("Collation", &self.collation),
+        ] {
+            if !value.trim().is_empty() && !is_valid_table_name(value.trim()) {
+                errors.push(format!("{} must be a valid SQL identifier", label));
+            }
+        }
+
         errors
     }
 
+    /// Add the drafted constraint; returns false when no fields are given
+    fn add_constraint(&mut self) -> bool {
+        let fields = parse_field_list(&self.new_constraint_fields);
+        if fields.is_empty() {
+            return false;
+        }
+        match index_type_from_kind(&self.new_constraint_kind) {
+            Some(index_type) => self
+                .indexes
+                .push(EntityIndex::new(fields).with_type(index_type)),
+            None => self.unique_together.push(fields),
+        }
+        self.new_constraint_fields.clear();
+        true
+    }
+
+    /// Include a field set, or leave it out if it is already included
+    fn toggle_field_set(&mut self, id: Uuid) {
+        if let Some(i) = self.field_sets.iter().position(|s| *s == id) {
+            self.field_sets.remove(i);
+        } else {
+            self.field_sets.push(id);
+        }
+    }
+
     /// Check if the form is valid
     fn is_valid(&self) -> bool {
         self.validate().is_empty()
@@ -146,6 +245,68 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
     let mut errors = use_signal(Vec::<String>::new);
     let mut is_saving = use_signal(|| false);
 
+    // "Infer from JSON" mode (create only)
+    let mut from_json = use_signal(|| false);
+    let mut json_sample = use_signal(String::new);
+    let mut inferred = use_signal(|| None::<InferredEntity>);
+    let mut infer_error = use_signal(|| None::<String>);
+
+    // Field sets to choose from, and how many fields are on the clipboard
+    let mut offered_sets = use_signal(|| offered_field_sets(APP_STATE.read().project.as_ref()));
+    let clipboard_count = APP_STATE.read().field_clipboard.len();
+
+    // Enums used by the entity's fields, as (name, variants, shared)
+    let enums_in_use = match &props.mode {
+        EntityDialogMode::Create => Vec::new(),
+        EntityDialogMode::Edit(entity_id) => {
+            let state = APP_STATE.read();
+            state
+                .project
+                .as_ref()
+                .and_then(|p| p.entities.get(entity_id).map(|e| entity_enums(p, e)))
+                .unwrap_or_default()
+        }
+    };
+
+    // Suggestions accepted here are applied to the entity on save; the
+    // entity description goes straight into the form instead
+    let mut accepted = use_signal(Vec::<Suggestion>::new);
+    let mut dismissed = use_signal(HashSet::<String>::new);
+    let mode_for_suggestions = props.mode.clone();
+    let suggestions = use_memo(move || {
+        let state = form_state.read();
+        if state.name.trim().is_empty() {
+            return Vec::new();
+        }
+        let existing = match &mode_for_suggestions {
+            EntityDialogMode::Edit(id) => APP_STATE
+                .read()
+                .project
+                .as_ref()
+                .and_then(|p| p.entities.get(id))
+                .cloned(),
+            EntityDialogMode::Create => None,
+        };
+        let mut entity = existing.unwrap_or_else(|| {
+            if state.timestamps {
+                Entity::with_timestamps(state.name.trim())
+            } else {
+                Entity::new(state.name.trim())
+            }
+        });
+        entity.name = state.name.trim().to_string();
+        entity.description = Some(state.description.clone()).filter(|d| !d.trim().is_empty());
+        for suggestion in accepted.read().iter() {
+            suggestion.apply(&mut entity);
+        }
+
+        let dismissed = dismissed.read();
+        suggestions_for(&entity)
+            .into_iter()
+            .filter(|s| !dismissed.contains(&s.key()))
+            .collect::<Vec<_>>()
+    });
+
     // Auto-generate table name from entity name
     let auto_table_name = use_memo(move || {
         let state = form_state.read();
@@ -199,6 +360,12 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
         errors.set(Vec::new());
 
         let state = form_state.read();
+        let inferred_entity = if *from_json.read() {
+            inferred.read().clone()
+        } else {
+            None
+        };
+        let selected_sets = selected_field_sets(&offered_sets.read(), &state.field_sets);
         let entity_id = match &mode_for_save {
             EntityDialogMode::Create => {
                 // Create new entity
@@ -227,6 +394,14 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                     id_type: state.id_type.clone(),
                     auditable: state.auditable,
                     generate_api: state.generate_api,
+                    schema: schema_option(&state.schema),
+                    tablespace: schema_option(&state.tablespace),
+                    engine: schema_option(&state.engine),
+                    charset: schema_option(&state.charset),
+                    collation: schema_option(&state.collation),
+                    seed_count: state.seed_count,
+                    unique_together: state.unique_together.clone(),
+                    indexes: state.indexes.clone(),
                     ..Default::default()
                 };
 
@@ -234,24 +409,53 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                 entity.position = get_new_entity_position();
                 entity.size = Size::default_entity();
 
+                // Columns inferred from the JSON sample
+                if let Some(inferred) = &inferred_entity {
+                    for field in inferred.to_fields() {
+                        if !entity.has_field(&field.name) {
+                            entity.add_field(field);
+                        }
+                    }
+                }
+
+                for suggestion in accepted.read().iter() {
+                    suggestion.apply(&mut entity);
+                }
+
                 let id = entity.id;
 
                 // Add to project
                 let mut app_state = APP_STATE.write();
-                if let Some(project) = &mut app_state.project {
-                    project.entities.insert(id, entity);
+                let mut link_error = None;
+                app_state.add_entity(entity);
+                app_state.set_entity_field_sets(id, &selected_sets);
+                if let (Some(project), Some(inferred)) = (&mut app_state.project, &inferred_entity)
+                {
+                    link_error = inferred.link_related(project, id).err();
                 }
-                app_state.is_dirty = true;
-                app_state.selection.select_entity(id);
                 app_state.ui.close_dialog();
-                app_state.ui.set_status(
-                    &format!("Created entity '{}'", state.name),
-                    StatusLevel::Success,
-                );
+                match link_error {
+                    Some(e) => app_state.ui.set_status(
+                        &format!(
+                            "Created entity '{}', but linking related entities failed: {}",
+                            state.name, e
+                        ),
+                        StatusLevel::Warning,
+                    ),
+                    None => app_state.ui.set_status(
+                        &format!("Created entity '{}'", state.name),
+                        StatusLevel::Success,
+                    ),
+                }
                 drop(app_state);
 
                 // Save to history
-                APP_STATE.write().save_to_history("Create entity");
+                let action = if inferred_entity.is_some() {
+                    "Create entity from JSON"
+                } else {
+                    "Create entity"
+                };
+                APP_STATE.write().save_to_history(action);
 
                 id
             }
@@ -276,10 +480,22 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                         entity.config.auditable = state.auditable;
                         entity.config.generate_api = state.generate_api;
                         entity.config.id_type = state.id_type.clone();
+                        entity.config.schema = schema_option(&state.schema);
+                        entity.config.tablespace = schema_option(&state.tablespace);
+                        entity.config.engine = schema_option(&state.engine);
+                        entity.config.charset = schema_option(&state.charset);
+                        entity.config.collation = schema_option(&state.collation);
+                        entity.config.seed_count = state.seed_count;
+                        entity.config.unique_together = state.unique_together.clone();
+                        entity.config.indexes = state.indexes.clone();
+                        for suggestion in accepted.read().iter() {
+                            suggestion.apply(entity);
+                        }
                         entity.touch();
                     }
                 }
-                app_state.is_dirty = true;
+                app_state.set_entity_field_sets(*entity_id, &selected_sets);
+                app_state.mark_dirty();
                 app_state.ui.close_dialog();
                 app_state.ui.set_status(
                     &format!("Updated entity '{}'", state.name),
@@ -313,10 +529,41 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
         form_state.write().table_name = value;
     };
 
+    let on_schema_change = move |value: String| {
+        form_state.write().schema = value;
+    };
+
+    let on_tablespace_change = move |value: String| {
+        form_state.write().tablespace = value;
+    };
+
+    let on_engine_change = move |value: String| {
+        form_state.write().engine = value;
+    };
+
+    let on_charset_change = move |value: String| {
+        form_state.write().charset = value;
+    };
+
+    let on_collation_change = move |value: String| {
+        form_state.write().collation = value;
+    };
+
     let on_description_change = move |value: String| {
         form_state.write().description = value;
     };
 
+    let on_accept_suggestion = move |suggestion: Suggestion| match suggestion.kind {
+        SuggestionKind::EntityDescription(description) => {
+            form_state.write().description = description;
+        }
+        _ => accepted.write().push(suggestion),
+    };
+
+    let on_dismiss_suggestion = move |suggestion: Suggestion| {
+        dismissed.write().insert(suggestion.key());
+    };
+
     let on_timestamps_change = move |checked: bool| {
         form_state.write().timestamps = checked;
     };
@@ -333,6 +580,52 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
         form_state.write().generate_api = checked;
     };
 
+    let on_seed_count_change = move |value: f64| {
+        form_state.write().seed_count = value.max(0.0) as u32;
+    };
+
+    let on_constraint_fields_change = move |value: String| {
+        form_state.write().new_constraint_fields = value;
+    };
+
+    let on_constraint_kind_change = move |value: String| {
+        form_state.write().new_constraint_kind = value;
+    };
+
+    let add_constraint = move |_| {
+        form_state.write().add_constraint();
+    };
+
+    let on_new_set_name_change = move |value: String| {
+        form_state.write().new_set_name = value;
+    };
+
+    // Save the copied fields as a project field set and include it
+    let save_clipboard_as_set = move |_| {
+        let name = form_state.read().new_set_name.clone();
+        let mut app_state = APP_STATE.write();
+        match app_state.field_set_from_clipboard(&name) {
+            Ok(id) => {
+                let set = app_state
+                    .project
+                    .as_ref()
+                    .and_then(|p| p.get_field_set(id))
+                    .cloned();
+                drop(app_state);
+                if let Some(set) = set {
+                    offered_sets.write().push(set);
+                }
+                let mut form = form_state.write();
+                form.field_sets.push(id);
+                form.new_set_name.clear();
+            }
+            Err(e) => {
+                drop(app_state);
+                errors.set(vec![e.to_string()]);
+            }
+        }
+    };
+
     let on_id_type_change = move |value: String| {
         let id_type = match value.as_str() {
             "uuid" => IdType::Uuid,
@@ -359,6 +652,37 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
         IdType::Ulid => "ulid",
     };
 
+    // Parse the pasted sample into an inferred entity
+    let analyze_json = move |_| {
+        let name = form_state.read().name.clone();
+        let name = if name.trim().is_empty() {
+            "NewEntity".to_string()
+        } else {
+            name
+        };
+        match infer_entity(&name, &json_sample.read()) {
+            Ok(result) => {
+                if form_state.read().name.trim().is_empty() {
+                    form_state.write().name = name;
+                }
+                inferred.set(Some(result));
+                infer_error.set(None);
+            }
+            Err(e) => {
+                inferred.set(None);
+                infer_error.set(Some(e.to_string()));
+            }
+        }
+    };
+
+    let is_create = matches!(props.mode, EntityDialogMode::Create);
+    let database = APP_STATE
+        .read()
+        .project
+        .as_ref()
+        .map(|p| p.config.database)
+        .unwrap_or_default();
+
     // Determine dialog title
     let title = match &props.mode {
         EntityDialogMode::Create => "Create New Entity",
@@ -385,6 +709,25 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                 h2 { class: "text-xl font-bold", "{title}" }
             }
 
+            // Mode tabs (create only)
+            if is_create {
+                div {
+                    class: "flex gap-1 mb-6 p-1 bg-slate-800 rounded-lg w-fit",
+                    button {
+                        r#type: "button",
+                        class: if !*from_json.read() { "px-3 py-1.5 text-sm rounded-md bg-indigo-600 text-white" } else { "px-3 py-1.5 text-sm rounded-md text-slate-400 hover:text-white" },
+                        onclick: move |_| from_json.set(false),
+                        "Manual"
+                    }
+                    button {
+                        r#type: "button",
+                        class: if *from_json.read() { "px-3 py-1.5 text-sm rounded-md bg-indigo-600 text-white" } else { "px-3 py-1.5 text-sm rounded-md text-slate-400 hover:text-white" },
+                        onclick: move |_| from_json.set(true),
+                        "Infer from JSON"
+                    }
+                }
+            }
+
             // Error messages
             if !error_list.is_empty() {
                 div {
@@ -439,6 +782,15 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                         on_change: on_table_name_change,
                     }
 
+                    // Schema / namespace
+                    TextInput {
+                        value: form.schema.clone(),
+                        label: "Schema",
+                        placeholder: "default",
+                        help_text: "PostgreSQL schema or MySQL database the table lives in (ignored on SQLite)",
+                        on_change: on_schema_change,
+                    }
+
                     // Description
                     TextArea {
                         value: form.description.clone(),
@@ -447,6 +799,61 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                         rows: 3,
                         on_change: on_description_change,
                     }
+
+                    SuggestionChips {
+                        suggestions: suggestions.read().clone(),
+                        on_accept: on_accept_suggestion,
+                        on_dismiss: on_dismiss_suggestion,
+                    }
+
+                    if !accepted.read().is_empty() {
+                        p {
+                            class: "text-xs text-slate-400",
+                            "{accepted_summary(&accepted.read())}"
+                        }
+                    }
+                }
+
+                // JSON sample section
+                if is_create && *from_json.read() {
+                    div {
+                        class: "space-y-4 pt-4 border-t border-slate-700",
+
+                        h3 {
+                            class: "text-sm font-semibold text-slate-400 uppercase tracking-wider",
+                            "JSON Sample"
+                        }
+
+                        TextArea {
+                            value: json_sample.read().clone(),
+                            label: "Paste a JSON object or an array of objects",
+                            placeholder: "{{ \"title\": \"Hello\", \"views\": 3, \"author\": {{ \"name\": \"Ann\" }} }}",
+                            rows: 8,
+                            on_change: move |value: String| json_sample.set(value),
+                        }
+
+                        button {
+                            r#type: "button",
+                            class: "px-3 py-1.5 text-sm bg-slate-700 hover:bg-slate-600 rounded-lg transition-colors",
+                            disabled: json_sample.read().trim().is_empty(),
+                            onclick: analyze_json,
+                            "Analyze"
+                        }
+
+                        if let Some(error) = infer_error.read().as_ref() {
+                            p { class: "text-sm text-red-400", "{error}" }
+                        }
+
+                        if let Some(result) = inferred.read().as_ref() {
+                            InferredFieldsPreview { inferred: result.clone(), on_mode_change: move |(index, mode): (usize, NestedMode)| {
+                                if let Some(result) = inferred.write().as_mut()
+                                    && let Some(nested) = result.fields.get_mut(index).and_then(|f| f.nested.as_mut())
+                                {
+                                    nested.mode = mode;
+                                }
+                            } }
+                        }
+                    }
                 }
 
                 // Configuration Section
@@ -454,8 +861,9 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                     class: "space-y-4 pt-4 border-t border-slate-700",
 
                     h3 {
-                        class: "text-sm font-semibold text-slate-400 uppercase tracking-wider",
+                        class: "text-sm font-semibold text-slate-400 uppercase tracking-wider flex items-center",
                         "Configuration"
+                        HelpButton { topic: HelpTopic::IdStrategies }
                     }
 
                     // ID Type
@@ -503,6 +911,213 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
                             on_change: on_generate_api_change,
                         }
                     }
+
+                    // Storage options for the project's database
+                    if database == DatabaseType::PostgreSQL {
+                        TextInput {
+                            value: form.tablespace.clone(),
+                            label: "Tablespace",
+                            placeholder: "pg_default",
+                            help_text: "PostgreSQL tablespace the table is stored in",
+                            on_change: on_tablespace_change,
+                        }
+                    }
+                    if database == DatabaseType::MySQL {
+                        div {
+                            class: "grid grid-cols-3 gap-3",
+                            TextInput {
+                                value: form.engine.clone(),
+                                label: "Engine",
+                                placeholder: "InnoDB",
+                                help_text: "Storage engine",
+                                on_change: on_engine_change,
+                            }
+                            TextInput {
+                                value: form.charset.clone(),
+                                label: "Character Set",
+                                placeholder: "server default",
+                                help_text: "e.g., utf8mb4",
+                                on_change: on_charset_change,
+                            }
+                            TextInput {
+                                value: form.collation.clone(),
+                                label: "Collation",
+                                placeholder: "charset default",
+                                help_text: "e.g., utf8mb4_unicode_ci",
+                                on_change: on_collation_change,
+                            }
+                        }
+                    }
+
+                    // Seed data
+                    NumberInput {
+                        value: form.seed_count as f64,
+                        label: "Seed Records",
+                        help_text: "Rows generated for this entity when seed data is enabled (0 skips it)",
+                        min: 0.0,
+                        max: 100000.0,
+                        step: 1.0,
+                        on_change: on_seed_count_change,
+                    }
+                }
+
+                // Field sets
+                div {
+                    class: "space-y-3 pt-4 border-t border-slate-700",
+
+                    h3 {
+                        class: "text-sm font-semibold text-slate-400 uppercase tracking-wider",
+                        "Field Sets"
+                    }
+                    p {
+                        class: "text-xs text-slate-500",
+                        "Shared fields kept in one place; edits to a set reach every entity that includes it"
+                    }
+
+                    for set in offered_sets.read().iter() {
+                        {
+                            let id = set.id;
+                            let in_project = APP_STATE
+                                .read()
+                                .project
+                                .as_ref()
+                                .is_some_and(|p| p.get_field_set(id).is_some());
+                            let help = if in_project {
+                                set.field_names().join(", ")
+                            } else {
+                                format!("{} (built-in)", set.field_names().join(", "))
+                            };
+                            rsx! {
+                                Checkbox {
+                                    key: "{id}",
+                                    checked: form.field_sets.contains(&id),
+                                    label: set.name.clone(),
+                                    help_text: help,
+                                    on_change: move |_| form_state.write().toggle_field_set(id),
+                                }
+                            }
+                        }
+                    }
+
+                    if clipboard_count > 0 {
+                        div {
+                            class: "grid grid-cols-[1fr_auto] gap-2 items-end",
+                            TextInput {
+                                value: form.new_set_name.clone(),
+                                label: "New set from copied fields",
+                                placeholder: "e.g., Publishable",
+                                help_text: "{clipboard_count} field(s) on the clipboard",
+                                on_change: on_new_set_name_change,
+                            }
+                            button {
+                                r#type: "button",
+                                class: "px-3 py-2 text-sm bg-slate-700 hover:bg-slate-600 rounded-lg transition-colors",
+                                disabled: form.new_set_name.trim().is_empty(),
+                                onclick: save_clipboard_as_set,
+                                "Save Set"
+                            }
+                        }
+                    }
+                }
+
+                // Composite constraints (edit only, they reference fields)
+                if !is_create {
+                    div {
+                        class: "space-y-3 pt-4 border-t border-slate-700",
+
+                        h3 {
+                            class: "text-sm font-semibold text-slate-400 uppercase tracking-wider",
+                            "Table Constraints"
+                        }
+
+                        for (i, fields) in form.unique_together.iter().enumerate() {
+                            div {
+                                key: "unique-{i}",
+                                class: "flex items-center gap-2 text-sm",
+                                span { class: "text-xs text-emerald-400 w-16", "UNIQUE" }
+                                span { class: "font-mono text-slate-300 flex-1 truncate", "{fields.join(\", \")}" }
+                                button {
+                                    r#type: "button",
+                                    class: "text-slate-500 hover:text-red-400",
+                                    title: "Remove constraint",
+                                    onclick: move |_| {
+                                        form_state.write().unique_together.remove(i);
+                                    },
+                                    "✕"
+                                }
+                            }
+                        }
+
+                        for (i, index) in form.indexes.iter().enumerate() {
+                            div {
+                                key: "index-{i}",
+                                class: "flex items-center gap-2 text-sm",
+                                span { class: "text-xs text-sky-400 w-16", "INDEX" }
+                                span { class: "font-mono text-slate-300 flex-1 truncate", "{index}" }
+                                button {
+                                    r#type: "button",
+                                    class: "text-slate-500 hover:text-red-400",
+                                    title: "Remove index",
+                                    onclick: move |_| {
+                                        form_state.write().indexes.remove(i);
+                                    },
+                                    "✕"
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "grid grid-cols-[1fr_10rem_auto] gap-2 items-end",
+                            TextInput {
+                                value: form.new_constraint_fields.clone(),
+                                label: "Fields",
+                                placeholder: "e.g., user_id, slug",
+                                help_text: "Comma-separated, in column order",
+                                on_change: on_constraint_fields_change,
+                            }
+                            Select {
+                                value: form.new_constraint_kind.clone(),
+                                options: constraint_kind_options(),
+                                label: "Kind",
+                                on_change: on_constraint_kind_change,
+                            }
+                            button {
+                                r#type: "button",
+                                class: "px-3 py-2 text-sm bg-slate-700 hover:bg-slate-600 rounded-lg transition-colors",
+                                disabled: parse_field_list(&form.new_constraint_fields).is_empty(),
+                                onclick: add_constraint,
+                                "Add"
+                            }
+                        }
+                    }
+                }
+
+                // Enums used by the fields (edit only)
+                if !enums_in_use.is_empty() {
+                    div {
+                        class: "space-y-2 pt-4 border-t border-slate-700",
+
+                        h3 {
+                            class: "text-sm font-semibold text-slate-400 uppercase tracking-wider",
+                            "Enum Types"
+                        }
+                        p {
+                            class: "text-xs text-slate-500",
+                            "Edit variants from a field using the enum"
+                        }
+
+                        for (name, variants, shared) in enums_in_use.iter() {
+                            div {
+                                key: "{name}",
+                                class: "flex items-center gap-2 text-sm",
+                                span { class: "font-mono text-indigo-300", "{name}" }
+                                if !shared {
+                                    span { class: "text-xs text-amber-400", "(inline)" }
+                                }
+                                span { class: "text-slate-400 truncate", "{variants}" }
+                            }
+                        }
+                    }
                 }
 
                 // Actions
@@ -536,10 +1151,131 @@ pub fn EntityDialog(props: EntityDialogProps) -> Element {
     }
 }
 
+// ============================================================================
+// Inferred Fields Preview
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+struct InferredFieldsPreviewProps {
+    inferred: InferredEntity,
+    /// Called with (field index, new mode) when a nested object's storage changes
+    on_mode_change: EventHandler<(usize, NestedMode)>,
+}
+
+/// Table of the fields inferred from a JSON sample
+#[component]
+fn InferredFieldsPreview(props: InferredFieldsPreviewProps) -> Element {
+    let fields: Vec<(usize, String, String, bool, Option<NestedMode>)> = props
+        .inferred
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            (
+                i,
+                f.name.clone(),
+                inferred_type_label(f),
+                f.nullable,
+                f.nested.as_ref().map(|n| n.mode),
+            )
+        })
+        .collect();
+
+    rsx! {
+        div {
+            class: "rounded-lg border border-slate-700 overflow-hidden",
+            table {
+                class: "w-full text-sm",
+                thead {
+                    class: "bg-slate-800 text-slate-400 text-xs uppercase",
+                    tr {
+                        th { class: "text-left px-3 py-2", "Field" }
+                        th { class: "text-left px-3 py-2", "Type" }
+                        th { class: "text-left px-3 py-2", "Required" }
+                    }
+                }
+                tbody {
+                    for (index, name, type_label, nullable, nested_mode) in fields {
+                        tr {
+                            key: "{name}",
+                            class: "border-t border-slate-700/60",
+                            td { class: "px-3 py-1.5 font-mono", "{name}" }
+                            td {
+                                class: "px-3 py-1.5",
+                                if let Some(mode) = nested_mode {
+                                    select {
+                                        class: "bg-slate-800 border border-slate-600 rounded px-2 py-0.5 text-xs",
+                                        value: if mode == NestedMode::RelatedEntity { "related" } else { "json" },
+                                        onchange: move |e| {
+                                            let mode = if e.value() == "related" {
+                                                NestedMode::RelatedEntity
+                                            } else {
+                                                NestedMode::JsonColumn
+                                            };
+                                            props.on_mode_change.call((index, mode));
+                                        },
+                                        option { value: "json", "JSON column" }
+                                        option { value: "related", "{type_label}" }
+                                    }
+                                } else {
+                                    span { class: "text-slate-300", "{type_label}" }
+                                }
+                            }
+                            td {
+                                class: "px-3 py-1.5 text-slate-400",
+                                if nullable { "No" } else { "Yes" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !props.inferred.notes.is_empty() {
+                ul {
+                    class: "px-3 py-2 bg-slate-800/60 text-xs text-amber-300 space-y-1",
+                    for note in props.inferred.notes.iter() {
+                        li { "{note}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Type column text for an inferred field; for nested objects, the label of
+/// the related-entity option
+fn inferred_type_label(field: &imortal_ir::InferredField) -> String {
+    match &field.nested {
+        Some(nested) if nested.many => format!("Related: many {}", nested.entity_name),
+        Some(nested) => format!("Related: {}", nested.entity_name),
+        None => field.data_type.display_name(),
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// The enums an entity's fields use, sorted by name, as (name, variants,
+/// whether the project defines it)
+fn entity_enums(project: &ProjectGraph, entity: &Entity) -> Vec<(String, String, bool)> {
+    let mut enums: Vec<(String, String, bool)> = entity
+        .fields
+        .iter()
+        .filter_map(|f| enum_of(&f.data_type))
+        .map(|(name, variants)| {
+            (
+                name.to_string(),
+                variants.join(", "),
+                project.get_enum_by_name(name).is_some(),
+            )
+        })
+        .collect();
+    enums.sort();
+    enums.dedup_by(|a, b| a.0 == b.0);
+    enums
+}
+
 /// Convert a string to snake_case
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -611,6 +1347,87 @@ fn is_valid_identifier(s: &str) -> bool {
     chars.all(|c| c.is_alphanumeric() || c == '_')
 }
 
+/// Summary of the accepted suggestions waiting for save
+fn accepted_summary(accepted: &[Suggestion]) -> String {
+    let fields = accepted
+        .iter()
+        .filter(|s| matches!(s.kind, SuggestionKind::Field(_)))
+        .count();
+    let other = accepted.len() - fields;
+    let mut parts = Vec::new();
+    if fields > 0 {
+        parts.push(format!(
+            "{} field{}",
+            fields,
+            if fields == 1 { "" } else { "s" }
+        ));
+    }
+    if other > 0 {
+        parts.push(format!(
+            "{} field update{}",
+            other,
+            if other == 1 { "" } else { "s" }
+        ));
+    }
+    format!("Will add {} on save", parts.join(" and "))
+}
+
+/// Split a comma-separated field list, dropping blanks
+fn parse_field_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// The project's field sets, then the built-ins it doesn't define yet
+fn offered_field_sets(project: Option<&ProjectGraph>) -> Vec<FieldSet> {
+    let mut sets: Vec<FieldSet> = project
+        .map(|p| p.field_sets().into_iter().cloned().collect())
+        .unwrap_or_default();
+    for builtin in FieldSet::builtins() {
+        if !sets.iter().any(|s| s.name == builtin.name) {
+            sets.push(builtin);
+        }
+    }
+    sets
+}
+
+/// The offered sets the form includes, in inclusion order
+fn selected_field_sets(offered: &[FieldSet], ids: &[Uuid]) -> Vec<FieldSet> {
+    ids.iter()
+        .filter_map(|id| offered.iter().find(|s| s.id == *id).cloned())
+        .collect()
+}
+
+/// Options for the constraint kind select: a unique constraint or one of the
+/// index types
+fn constraint_kind_options() -> Vec<SelectOption> {
+    let mut options = vec![SelectOption::new("unique", "Unique")];
+    options.extend(
+        IndexType::all()
+            .iter()
+            .map(|t| SelectOption::new(t.sql_name(), format!("Index ({})", t.display_name()))),
+    );
+    options
+}
+
+/// The index type for a constraint kind; `None` for a unique constraint
+fn index_type_from_kind(kind: &str) -> Option<IndexType> {
+    IndexType::all()
+        .iter()
+        .copied()
+        .find(|t| t.sql_name() == kind)
+}
+
+/// Convert the schema (or another optional setting) input into the config
+/// value (blank means default)
+fn schema_option(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
+
 /// Check if a string is a valid SQL table name
 fn is_valid_table_name(s: &str) -> bool {
     if s.is_empty() {
@@ -677,6 +1494,41 @@ fn get_new_entity_position() -> Position {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{EnumDef, Field};
+
+    #[test]
+    fn test_entity_enums() {
+        let mut project = ProjectGraph::new("blog");
+        let status = EnumDef::new("PostStatus").with_variants(["draft", "published"]);
+        project.add_enum(status.clone());
+
+        let mut post = Entity::new("Post");
+        post.fields.push(Field::new("status", status.data_type()));
+        post.fields.push(Field::new(
+            "previous_status",
+            DataType::Optional(Box::new(status.data_type())),
+        ));
+        post.fields.push(Field::new(
+            "mood",
+            DataType::Enum {
+                name: "Mood".to_string(),
+                variants: vec!["happy".to_string()],
+            },
+        ));
+
+        assert_eq!(
+            entity_enums(&project, &post),
+            vec![
+                ("Mood".to_string(), "happy".to_string(), false),
+                (
+                    "PostStatus".to_string(),
+                    "draft, published".to_string(),
+                    true
+                ),
+            ]
+        );
+    }
 
     #[test]
     fn test_to_snake_case() {
@@ -716,6 +1568,93 @@ mod tests {
         assert!(!is_valid_table_name("user-posts"));
     }
 
+    #[test]
+    fn test_accepted_summary() {
+        use imortal_core::DataType;
+        use imortal_ir::Field;
+        use imortal_ir::Validation;
+
+        let field = Suggestion::field(Field::new("email", DataType::String), "test");
+        let rule = Suggestion::validation("email", Validation::Email, "test");
+        assert_eq!(
+            accepted_summary(&[field.clone()]),
+            "Will add 1 field on save"
+        );
+        assert_eq!(
+            accepted_summary(&[field.clone(), field, rule]),
+            "Will add 2 fields and 1 field update on save"
+        );
+    }
+
+    #[test]
+    fn test_schema_option() {
+        assert_eq!(schema_option(""), None);
+        assert_eq!(schema_option("   "), None);
+        assert_eq!(schema_option(" billing "), Some("billing".to_string()));
+    }
+
+    #[test]
+    fn test_inferred_type_label() {
+        let inferred = infer_entity(
+            "Post",
+            r#"{"views": 1, "author": {"name": "a"}, "tags": [{"n": 1}]}"#,
+        )
+        .unwrap();
+        let label = |name: &str| {
+            inferred_type_label(inferred.fields.iter().find(|f| f.name == name).unwrap())
+        };
+        assert_eq!(label("author"), "Related: Author");
+        assert_eq!(label("tags"), "Related: many Tag");
+        assert_eq!(label("views"), imortal_core::DataType::Int32.display_name());
+    }
+
+    #[test]
+    fn test_add_constraint() {
+        let mut state = EntityFormState::default();
+        state.new_constraint_fields = " , ".to_string();
+        assert!(!state.add_constraint());
+
+        state.new_constraint_fields = "user_id, slug".to_string();
+        assert!(state.add_constraint());
+        assert_eq!(state.unique_together, vec![vec!["user_id", "slug"]]);
+        assert!(state.new_constraint_fields.is_empty());
+
+        state.new_constraint_fields = "tags".to_string();
+        state.new_constraint_kind = IndexType::Gin.sql_name().to_string();
+        assert!(state.add_constraint());
+        assert_eq!(state.indexes[0].fields, vec!["tags"]);
+        assert_eq!(state.indexes[0].index_type, IndexType::Gin);
+        assert_eq!(constraint_kind_options().len(), 1 + IndexType::all().len());
+    }
+
+    #[test]
+    fn test_offered_field_sets() {
+        let mut project = ProjectGraph::new("blog");
+        let mut timestamps = FieldSet::timestamps();
+        timestamps.fields.pop();
+        project.add_field_set(timestamps.clone());
+        project.add_field_set(FieldSet::new("Publishable"));
+
+        // The project's own sets first, then built-ins it doesn't shadow
+        let offered = offered_field_sets(Some(&project));
+        let names: Vec<_> = offered.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Publishable", "Timestamps", "Auditable", "Addressable"]
+        );
+        assert_eq!(offered[1].id, timestamps.id);
+        assert_eq!(offered_field_sets(None).len(), 3);
+
+        let mut state = EntityFormState::default();
+        state.toggle_field_set(offered[2].id);
+        state.toggle_field_set(offered[1].id);
+        state.toggle_field_set(offered[0].id);
+        state.toggle_field_set(offered[0].id);
+        let selected = selected_field_sets(&offered, &state.field_sets);
+        let names: Vec<_> = selected.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Auditable", "Timestamps"]);
+    }
+
     #[test]
     fn test_form_state_default() {
         let state = EntityFormState::default();
@@ -744,5 +1683,15 @@ mod tests {
         state.name = "User".to_string();
         state.table_name = "123-invalid".to_string();
         assert!(!state.is_valid());
+
+        // Storage options must be identifiers too
+        state.table_name.clear();
+        state.collation = "utf8mb4_unicode_ci".to_string();
+        assert!(state.is_valid());
+        state.engine = "Inno DB".to_string();
+        assert_eq!(
+            state.validate(),
+            vec!["Engine must be a valid SQL identifier"]
+        );
     }
 }
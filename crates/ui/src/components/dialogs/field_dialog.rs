@@ -6,7 +6,8 @@
 //!
 //! - Create new fields with name, data type, and constraints
 //! - Edit existing fields
-//! - Configure field options (required, unique, indexed)
+//! - Configure field options (required, unique, indexed), including
+//!   uniqueness within a relationship's parent
 //! - Set default values
 //! - Configure validations (min/max length, patterns, etc.)
 //! - Foreign key configuration
@@ -14,8 +15,13 @@
 //!
 
 use dioxus::prelude::*;
-use imortal_core::types::{DataType, EntityId, FieldId, ReferentialAction, Validation};
+use imortal_core::Validatable;
+use imortal_core::types::{
+    DataType, EntityId, FieldId, ReferentialAction, RelationshipId, Validation,
+};
 use imortal_ir::field::{DefaultValue, Field, ForeignKeyRef, UiHints, WidgetType};
+use imortal_ir::relationship_helpers::{accessor_names, scope_key};
+use imortal_ir::{ComputedField, ComputedStorage, EnumDef, ProjectGraph};
 
 use crate::components::inputs::{NumberInput, Select, SelectOption, TextArea, TextInput, Toggle};
 use crate::state::{APP_STATE, StatusLevel};
@@ -49,6 +55,8 @@ struct FieldFormState {
     // Constraints
     required: bool,
     unique: bool,
+    /// Relationship the value is unique within, instead of the whole table
+    unique_scope: Option<RelationshipId>,
     indexed: bool,
 
     // Default value
@@ -61,6 +69,11 @@ struct FieldFormState {
     // Validations
     validations: Vec<ValidationConfig>,
 
+    // Computed value
+    is_computed: bool,
+    computed_expression: String,
+    computed_storage: ComputedStorage,
+
     // Foreign key
     is_foreign_key: bool,
     fk_entity_name: String,
@@ -76,6 +89,9 @@ struct FieldFormState {
     hidden: bool,
     readonly: bool,
     secret: bool,
+
+    // Sample data
+    example: String,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -346,7 +362,7 @@ impl FieldTemplate {
             FieldTemplate::Price => FieldFormState {
                 name: "price".to_string(),
                 description: "Monetary amount".to_string(),
-                data_type: DataType::Float64,
+                data_type: DataType::money(),
                 required: true,
                 validations: vec![ValidationConfig::new(ValidationType::Min).with_float(0.0)],
                 has_default: true,
@@ -451,6 +467,7 @@ impl Default for FieldFormState {
             is_array: false,
             required: false,
             unique: false,
+            unique_scope: None,
             indexed: false,
             has_default: false,
             default_type: DefaultValueType::Null,
@@ -458,6 +475,9 @@ impl Default for FieldFormState {
             default_number: 0.0,
             default_bool: false,
             validations: Vec::new(),
+            is_computed: false,
+            computed_expression: String::new(),
+            computed_storage: ComputedStorage::default(),
             is_foreign_key: false,
             fk_entity_name: String::new(),
             fk_field_name: "id".to_string(),
@@ -470,6 +490,7 @@ impl Default for FieldFormState {
             hidden: false,
             readonly: false,
             secret: false,
+            example: String::new(),
         }
     }
 }
@@ -555,6 +576,7 @@ impl FieldFormState {
             is_array,
             required: field.required,
             unique: field.unique,
+            unique_scope: field.unique_scope,
             indexed: field.indexed,
             has_default,
             default_type,
@@ -562,6 +584,17 @@ impl FieldFormState {
             default_number,
             default_bool,
             validations,
+            is_computed: field.computed.is_some(),
+            computed_expression: field
+                .computed
+                .as_ref()
+                .map(|c| c.expression.clone())
+                .unwrap_or_default(),
+            computed_storage: field
+                .computed
+                .as_ref()
+                .map(|c| c.storage)
+                .unwrap_or_default(),
             is_foreign_key: field.is_foreign_key,
             fk_entity_name,
             fk_field_name,
@@ -574,6 +607,23 @@ impl FieldFormState {
             hidden: field.hidden,
             readonly: field.readonly,
             secret: field.secret,
+            example: field.example.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Change the precision and/or scale of a decimal type, keeping the
+    /// scale within the precision
+    fn set_decimal_digits(&mut self, precision: Option<u8>, scale: Option<u8>) {
+        if let DataType::Decimal {
+            precision: current_precision,
+            scale: current_scale,
+        } = self.data_type
+        {
+            let precision = precision
+                .unwrap_or(current_precision)
+                .clamp(1, DataType::MAX_DECIMAL_PRECISION);
+            let scale = scale.unwrap_or(current_scale).min(precision);
+            self.data_type = DataType::decimal(precision, scale);
         }
     }
 
@@ -623,6 +673,18 @@ impl FieldFormState {
             .collect()
     }
 
+    /// Build the computed value, if the field is computed
+    fn build_computed(&self) -> Option<ComputedField> {
+        if !self.is_computed {
+            return None;
+        }
+
+        Some(ComputedField {
+            expression: self.computed_expression.trim().to_string(),
+            storage: self.computed_storage,
+        })
+    }
+
     /// Build foreign key reference
     fn build_foreign_key_ref(&self) -> Option<ForeignKeyRef> {
         if !self.is_foreign_key || self.fk_entity_name.is_empty() {
@@ -655,6 +717,103 @@ impl FieldFormState {
         hints
     }
 
+    /// Build the example value
+    fn build_example(&self) -> Option<String> {
+        if self.example.trim().is_empty() {
+            None
+        } else {
+            Some(self.example.clone())
+        }
+    }
+
+    /// Build a throwaway field mirroring the settings the sensitivity
+    /// heuristics look at
+    fn preview_field(&self) -> Field {
+        let mut field = Field::new(&self.name, self.build_data_type());
+        if !self.description.is_empty() {
+            field.description = Some(self.description.clone());
+        }
+        field.validations = self.build_validations();
+        field.ui_hints = self.build_ui_hints();
+        field.hidden = self.hidden;
+        field.secret = self.secret;
+        field
+    }
+
+    /// Protections missing from a field whose name looks sensitive
+    fn sensitivity_issues(&self) -> Vec<&'static str> {
+        self.preview_field().sensitivity_issues()
+    }
+
+    /// Apply the recommended settings for a sensitive field to the form
+    fn apply_secret_recommendations(&mut self) {
+        let mut field = self.preview_field();
+        field.apply_secret_recommendations();
+
+        self.secret = field.secret;
+        self.hidden = field.hidden;
+        self.widget_type = WidgetType::Password;
+        self.help_text = field.ui_hints.help_text.unwrap_or_default();
+        self.validations = field
+            .validations
+            .iter()
+            .filter_map(ValidationConfig::from_validation)
+            .collect();
+    }
+
+    /// Settings that simple mode doesn't show, listed so the user knows the
+    /// field carries more than the essentials
+    fn advanced_settings(&self) -> Vec<&'static str> {
+        let mut settings = Vec::new();
+        if !self.description.trim().is_empty() {
+            settings.push("description");
+        }
+        if self.is_optional || self.is_array {
+            settings.push("type modifiers");
+        }
+        if self.indexed {
+            settings.push("index");
+        }
+        if self.unique_scope.is_some() {
+            settings.push("uniqueness scope");
+        }
+        if self.has_default {
+            settings.push("default value");
+        }
+        if self.is_computed {
+            settings.push("computed value");
+        }
+        if !self.validations.is_empty() {
+            settings.push("validations");
+        }
+        if self.is_foreign_key {
+            settings.push("foreign key");
+        }
+        let custom_widget = self.widget_type != WidgetType::Text
+            && self.widget_type != WidgetType::for_data_type(&self.data_type);
+        if !self.label.is_empty()
+            || !self.placeholder.is_empty()
+            || !self.help_text.is_empty()
+            || custom_widget
+            || self.hidden
+            || self.readonly
+            || self.secret
+        {
+            settings.push("UI hints");
+        }
+        settings
+    }
+
+    /// The scope to save: none when the value is unique across the table
+    fn build_unique_scope(&self) -> Option<RelationshipId> {
+        self.unique_scope.filter(|_| !self.unique)
+    }
+
+    /// Suggest a faker-style example from the current name and data type
+    fn suggested_example(&self) -> String {
+        Field::new(&self.name, self.build_data_type()).suggested_example()
+    }
+
     /// Validate the form and return errors if any
     fn validate(&self) -> Vec<String> {
         let mut errors = Vec::new();
@@ -672,6 +831,39 @@ impl FieldFormState {
             errors.push("Column name must be a valid SQL identifier".to_string());
         }
 
+        // Validate decimal digits
+        if let DataType::Decimal { precision, scale } = self.data_type {
+            if precision == 0 || precision > DataType::MAX_DECIMAL_PRECISION {
+                errors.push(format!(
+                    "Precision must be between 1 and {}",
+                    DataType::MAX_DECIMAL_PRECISION
+                ));
+            }
+            if scale > precision {
+                errors.push("Scale cannot be larger than the precision".to_string());
+            }
+        }
+
+        // Validate the enum definition
+        if let Some(def) = EnumDef::from_data_type(&self.data_type) {
+            if let Err(e) = def.validate() {
+                errors.push(e.to_string());
+            }
+        }
+
+        // Validate the computed expression
+        if let Some(computed) = self.build_computed() {
+            if let Err(e) = computed
+                .validate()
+                .and_then(|_| computed.check_result_type(&self.build_data_type()))
+            {
+                errors.push(e.to_string());
+            }
+            if self.has_default {
+                errors.push("A computed field cannot have a default value".to_string());
+            }
+        }
+
         // Validate foreign key config
         if self.is_foreign_key && self.fk_entity_name.is_empty() {
             errors.push("Foreign key entity name is required".to_string());
@@ -857,6 +1049,32 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
             .unwrap_or_default()
     });
 
+    // Project enums for the enum picker
+    let enum_options = use_memo(move || {
+        let state = APP_STATE.read();
+        let mut options = vec![SelectOption::new("", "New enum…")];
+        if let Some(project) = &state.project {
+            options.extend(
+                project
+                    .enums()
+                    .into_iter()
+                    .map(|e| SelectOption::new(&e.name, &e.name)),
+            );
+        }
+        options
+    });
+
+    // Relationships whose foreign key this entity holds, for "unique within"
+    let entity_id_for_scopes = props.entity_id;
+    let scope_options = use_memo(move || {
+        let state = APP_STATE.read();
+        state
+            .project
+            .as_ref()
+            .map(|p| unique_scope_options(p, entity_id_for_scopes))
+            .unwrap_or_default()
+    });
+
     // Auto-generate column name from field name
     let auto_column_name = use_memo(move || {
         let state = form_state.read();
@@ -937,6 +1155,7 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 // Set constraints
                 field.required = state.required;
                 field.unique = state.unique;
+                field.unique_scope = state.build_unique_scope();
                 field.indexed = state.indexed;
 
                 // Set default value
@@ -945,6 +1164,9 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 // Set validations
                 field.validations = state.build_validations();
 
+                // Set computed value; clients never write it
+                field.computed = state.build_computed();
+
                 // Set foreign key
                 field.is_foreign_key = state.is_foreign_key;
                 field.foreign_key_ref = state.build_foreign_key_ref();
@@ -952,21 +1174,18 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 // Set UI hints
                 field.ui_hints = state.build_ui_hints();
                 field.hidden = state.hidden;
-                field.readonly = state.readonly;
+                field.readonly = state.readonly || state.is_computed;
                 field.secret = state.secret;
+                field.example = state.build_example();
 
                 let id = field.id;
 
                 // Add to entity
                 let mut app_state = APP_STATE.write();
                 if let Some(project) = &mut app_state.project {
-                    if let Some(entity) = project.entities.get_mut(&entity_id) {
-                        entity.add_field(field);
-                        entity.touch();
-                    }
+                    register_enum(project, &field.data_type);
                 }
-                app_state.is_dirty = true;
-                app_state.selection.field = Some((entity_id, id));
+                app_state.add_field(entity_id, field);
                 app_state.ui.close_dialog();
                 app_state.ui.set_status(
                     &format!("Created field '{}'", state.name),
@@ -983,7 +1202,12 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 // Update existing field
                 let mut app_state = APP_STATE.write();
                 if let Some(project) = &mut app_state.project {
+                    register_enum(project, &state.build_data_type());
                     if let Some(entity) = project.entities.get_mut(&entity_id) {
+                        let renamed_from = entity
+                            .get_field(*field_id)
+                            .map(|f| f.name.clone())
+                            .filter(|old| *old != state.name);
                         if let Some(field) = entity.get_field_mut(*field_id) {
                             field.name = state.name.clone();
                             field.column_name = if state.column_name.is_empty() {
@@ -999,20 +1223,26 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                             field.data_type = state.build_data_type();
                             field.required = state.required;
                             field.unique = state.unique;
+                            field.unique_scope = state.build_unique_scope();
                             field.indexed = state.indexed;
                             field.default_value = state.build_default_value();
                             field.validations = state.build_validations();
+                            field.computed = state.build_computed();
                             field.is_foreign_key = state.is_foreign_key;
                             field.foreign_key_ref = state.build_foreign_key_ref();
                             field.ui_hints = state.build_ui_hints();
                             field.hidden = state.hidden;
-                            field.readonly = state.readonly;
+                            field.readonly = state.readonly || state.is_computed;
                             field.secret = state.secret;
+                            field.example = state.build_example();
+                        }
+                        if let Some(old) = renamed_from {
+                            entity.config.rename_constraint_field(&old, &state.name);
                         }
                         entity.touch();
                     }
                 }
-                app_state.is_dirty = true;
+                app_state.mark_dirty();
                 app_state.ui.close_dialog();
                 app_state.ui.set_status(
                     &format!("Updated field '{}'", state.name),
@@ -1042,6 +1272,7 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
     let widget_type_options = get_widget_type_options();
     let referential_action_options = get_referential_action_options();
     let default_type_options = get_default_type_options();
+    let computed_storage_options = get_computed_storage_options();
     let validation_type_options = get_validation_type_options();
 
     // Determine dialog title
@@ -1057,8 +1288,17 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
 
     let form = form_state.read();
     let error_list = errors.read();
+    let sensitivity_issues = form.sensitivity_issues();
+    let sensitivity_summary = sensitivity_issues.join(", ");
     let saving = *is_saving.read();
-    let current_tab = *active_tab.read();
+    // Simple mode shows only the essentials on a single page
+    let advanced = APP_STATE.read().ui.advanced_dialogs;
+    let current_tab = if advanced {
+        *active_tab.read()
+    } else {
+        "basic"
+    };
+    let hidden_settings = form.advanced_settings().join(", ");
 
     rsx! {
         div {
@@ -1070,6 +1310,13 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 class: "flex items-center gap-3 mb-4",
                 span { class: "text-2xl", "📝" }
                 h2 { class: "text-xl font-bold", "{title}" }
+                button {
+                    r#type: "button",
+                    class: "ml-auto px-2 py-1 text-xs text-slate-400 hover:text-white hover:bg-slate-700 rounded transition-colors",
+                    title: "Switch every dialog between simple and advanced mode (also in Settings)",
+                    onclick: move |_| APP_STATE.write().ui.toggle_advanced_dialogs(),
+                    if advanced { "Simple view" } else { "Show advanced options" }
+                }
             }
 
             // Template picker (only shown in Create mode)
@@ -1134,6 +1381,34 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                 }
             }
 
+            // Sensitive field safety check
+            if !sensitivity_issues.is_empty() {
+                div {
+                    class: "mb-4 p-3 bg-amber-500/10 border border-amber-500/40 rounded-lg flex items-start gap-3",
+
+                    span { class: "text-amber-400", "⚠" }
+                    div {
+                        class: "flex-1 text-sm",
+                        p {
+                            class: "text-amber-200 font-medium",
+                            "This looks like a sensitive field"
+                        }
+                        p {
+                            class: "text-amber-200/70 text-xs mt-0.5",
+                            "It is {sensitivity_summary}."
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        class: "px-3 py-1 text-xs bg-amber-600 hover:bg-amber-700 text-white rounded transition-colors",
+                        onclick: move |_| {
+                            form_state.write().apply_secret_recommendations();
+                        },
+                        "Apply recommended"
+                    }
+                }
+            }
+
             // Error messages
             if !error_list.is_empty() {
                 div {
@@ -1148,33 +1423,35 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
             }
 
             // Tabs
-            div {
-                class: "flex border-b border-slate-700 mb-4",
+            if advanced {
+                div {
+                    class: "flex border-b border-slate-700 mb-4",
 
-                TabButton {
-                    label: "Basic",
-                    active: current_tab == "basic",
-                    onclick: move |_| active_tab.set("basic"),
-                }
-                TabButton {
-                    label: "Constraints",
-                    active: current_tab == "constraints",
-                    onclick: move |_| active_tab.set("constraints"),
-                }
-                TabButton {
-                    label: "Validations",
-                    active: current_tab == "validations",
-                    onclick: move |_| active_tab.set("validations"),
-                }
-                TabButton {
-                    label: "Foreign Key",
-                    active: current_tab == "fk",
-                    onclick: move |_| active_tab.set("fk"),
-                }
-                TabButton {
-                    label: "UI Hints",
-                    active: current_tab == "ui",
-                    onclick: move |_| active_tab.set("ui"),
+                    TabButton {
+                        label: "Basic",
+                        active: current_tab == "basic",
+                        onclick: move |_| active_tab.set("basic"),
+                    }
+                    TabButton {
+                        label: "Constraints",
+                        active: current_tab == "constraints",
+                        onclick: move |_| active_tab.set("constraints"),
+                    }
+                    TabButton {
+                        label: "Validations",
+                        active: current_tab == "validations",
+                        onclick: move |_| active_tab.set("validations"),
+                    }
+                    TabButton {
+                        label: "Foreign Key",
+                        active: current_tab == "fk",
+                        onclick: move |_| active_tab.set("fk"),
+                    }
+                    TabButton {
+                        label: "UI Hints",
+                        active: current_tab == "ui",
+                        onclick: move |_| active_tab.set("ui"),
+                    }
                 }
             }
 
@@ -1210,14 +1487,16 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                         }
 
                         // Column name
-                        TextInput {
-                            value: form.column_name.clone(),
-                            label: "Column Name",
-                            placeholder: auto_column_name.read().clone(),
-                            help_text: "Database column name (auto-generated if empty)",
-                            on_change: move |value: String| {
-                                form_state.write().column_name = value;
-                            },
+                        if advanced {
+                            TextInput {
+                                value: form.column_name.clone(),
+                                label: "Column Name",
+                                placeholder: auto_column_name.read().clone(),
+                                help_text: "Database column name (auto-generated if empty)",
+                                on_change: move |value: String| {
+                                    form_state.write().column_name = value;
+                                },
+                            }
                         }
 
                         // Data type
@@ -1227,42 +1506,175 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                             label: "Data Type",
                             help_text: "The type of data this field stores",
                             on_change: move |value: String| {
-                                form_state.write().data_type = string_to_data_type(&value);
+                                let mut state = form_state.write();
+                                // Re-selecting Decimal keeps the digits already chosen
+                                if data_type_to_string(&state.data_type) != value {
+                                    state.data_type = string_to_data_type(&value);
+                                }
                             },
                         }
 
-                        // Type modifiers
-                        div {
-                            class: "grid grid-cols-2 gap-4",
+                        // Decimal digits
+                        if let DataType::Decimal { precision, scale } = form.data_type {
+                            div {
+                                class: "grid grid-cols-2 gap-4",
 
-                            Toggle {
-                                checked: form.is_optional,
-                                label: "Optional (Nullable)",
-                                help_text: "Allow NULL values",
-                                on_change: move |checked: bool| {
-                                    form_state.write().is_optional = checked;
-                                },
+                                NumberInput {
+                                    value: precision as f64,
+                                    label: "Precision",
+                                    help_text: "Total number of digits",
+                                    min: 1.0,
+                                    max: DataType::MAX_DECIMAL_PRECISION as f64,
+                                    step: 1.0,
+                                    on_change: move |value: f64| {
+                                        form_state.write().set_decimal_digits(Some(value.max(0.0) as u8), None);
+                                    },
+                                }
+
+                                NumberInput {
+                                    value: scale as f64,
+                                    label: "Scale",
+                                    help_text: "Digits after the decimal point",
+                                    min: 0.0,
+                                    max: precision as f64,
+                                    step: 1.0,
+                                    on_change: move |value: f64| {
+                                        form_state.write().set_decimal_digits(None, Some(value.max(0.0) as u8));
+                                    },
+                                }
                             }
+                        }
 
-                            Toggle {
-                                checked: form.is_array,
-                                label: "Array",
-                                help_text: "Multiple values (list)",
-                                on_change: move |checked: bool| {
-                                    form_state.write().is_array = checked;
+                        // Enum name and variants
+                        if let DataType::Enum { name, variants } = &form.data_type {
+                            {
+                                let shared = enum_options
+                                    .read()
+                                    .iter()
+                                    .any(|o| !o.value.is_empty() && o.value == *name);
+                                let picked = if shared { name.clone() } else { String::new() };
+                                let variant_list = variants.join(", ");
+                                let variants_help = if shared {
+                                    "Changes apply to every field using this enum"
+                                } else {
+                                    "Comma-separated"
+                                };
+                                rsx! {
+                                    Select {
+                                        value: picked,
+                                        options: enum_options.read().clone(),
+                                        label: "Shared Enum",
+                                        help_text: "Reuse an enum from the project, or define a new one",
+                                        on_change: move |value: String| {
+                                            let app_state = APP_STATE.read();
+                                            let picked = app_state
+                                                .project
+                                                .as_ref()
+                                                .and_then(|p| p.get_enum_by_name(&value))
+                                                .map(|e| e.data_type());
+                                            form_state.write().data_type = picked.unwrap_or(DataType::Enum {
+                                                name: String::new(),
+                                                variants: Vec::new(),
+                                            });
+                                        },
+                                    }
+
+                                    div {
+                                        class: "grid grid-cols-2 gap-4",
+
+                                        TextInput {
+                                            value: name.clone(),
+                                            label: "Enum Name",
+                                            placeholder: "PostStatus",
+                                            on_change: move |value: String| {
+                                                if let DataType::Enum { name, .. } = &mut form_state.write().data_type {
+                                                    *name = value;
+                                                }
+                                            },
+                                        }
+
+                                        TextInput {
+                                            value: variant_list,
+                                            label: "Variants",
+                                            placeholder: "draft, published",
+                                            help_text: variants_help,
+                                            on_change: move |value: String| {
+                                                if let DataType::Enum { variants, .. } = &mut form_state.write().data_type {
+                                                    *variants = parse_enum_variants(&value);
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Type modifiers and description
+                        if advanced {
+                            div {
+                                class: "grid grid-cols-2 gap-4",
+
+                                Toggle {
+                                    checked: form.is_optional,
+                                    label: "Optional (Nullable)",
+                                    help_text: "Allow NULL values",
+                                    on_change: move |checked: bool| {
+                                        form_state.write().is_optional = checked;
+                                    },
+                                }
+
+                                Toggle {
+                                    checked: form.is_array,
+                                    label: "Array",
+                                    help_text: "Multiple values (list)",
+                                    on_change: move |checked: bool| {
+                                        form_state.write().is_array = checked;
+                                    },
+                                }
+                            }
+
+                            // Description
+                            TextArea {
+                                value: form.description.clone(),
+                                label: "Description",
+                                placeholder: "Describe what this field represents...",
+                                rows: 2,
+                                on_change: move |value: String| {
+                                    form_state.write().description = value;
                                 },
                             }
                         }
 
-                        // Description
-                        TextArea {
-                            value: form.description.clone(),
-                            label: "Description",
-                            placeholder: "Describe what this field represents...",
-                            rows: 2,
-                            on_change: move |value: String| {
-                                form_state.write().description = value;
-                            },
+                        // The two constraints most fields need, in place of the Constraints tab
+                        if !advanced {
+                            div {
+                                class: "grid grid-cols-2 gap-4",
+
+                                Toggle {
+                                    checked: form.required,
+                                    label: "Required",
+                                    help_text: "NOT NULL constraint",
+                                    on_change: move |checked: bool| {
+                                        form_state.write().required = checked;
+                                    },
+                                }
+
+                                Toggle {
+                                    checked: form.unique,
+                                    label: "Unique",
+                                    help_text: "UNIQUE constraint",
+                                    on_change: move |checked: bool| {
+                                        form_state.write().unique = checked;
+                                    },
+                                }
+                            }
+
+                            if !hidden_settings.is_empty() {
+                                p {
+                                    class: "text-xs text-slate-400",
+                                    "Also configured: {hidden_settings}. Use advanced options to review them."
+                                }
+                            }
                         }
                     }
 
@@ -1301,6 +1713,24 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                             }
                         }
 
+                        // Unique per parent, e.g. a slug per organization
+                        if scope_options.read().len() > 1 {
+                            Select {
+                                value: form.unique_scope.map(|id| id.to_string()).unwrap_or_default(),
+                                options: scope_options.read().clone(),
+                                label: "Unique Within",
+                                help_text: if form.unique {
+                                    "Already unique across the whole table"
+                                } else {
+                                    "Only rows sharing this parent must differ; other requests get a 409"
+                                },
+                                disabled: form.unique,
+                                on_change: move |value: String| {
+                                    form_state.write().unique_scope = value.parse().ok();
+                                },
+                            }
+                        }
+
                         // Default value section
                         div {
                             class: "pt-4 border-t border-slate-700 space-y-4",
@@ -1362,6 +1792,52 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                                 }
                             }
                         }
+
+                        // Computed value section
+                        div {
+                            class: "pt-4 border-t border-slate-700 space-y-4",
+
+                            h4 {
+                                class: "text-sm font-medium text-slate-300",
+                                "Computed Value"
+                            }
+
+                            Toggle {
+                                checked: form.is_computed,
+                                label: "Computed",
+                                help_text: "Derived from other fields; clients can't write it",
+                                on_change: move |checked: bool| {
+                                    form_state.write().is_computed = checked;
+                                },
+                            }
+
+                            if form.is_computed {
+                                TextInput {
+                                    value: form.computed_expression.clone(),
+                                    label: "Expression",
+                                    placeholder: "first_name || ' ' || last_name",
+                                    help_text: "Field names, 'text' and numbers joined with || or + - * /",
+                                    on_change: move |value: String| {
+                                        form_state.write().computed_expression = value;
+                                    },
+                                }
+
+                                Select {
+                                    value: form.computed_storage.display_name().to_string(),
+                                    options: computed_storage_options.clone(),
+                                    label: "Computed As",
+                                    help_text: "Generated columns can be indexed, sorted and filtered",
+                                    on_change: move |value: String| {
+                                        if let Some(storage) = ComputedStorage::all()
+                                            .iter()
+                                            .find(|s| s.display_name() == value)
+                                        {
+                                            form_state.write().computed_storage = *storage;
+                                        }
+                                    },
+                                }
+                            }
+                        }
                     }
 
                     // Validations Tab
@@ -1491,6 +1967,34 @@ pub fn FieldDialog(props: FieldDialogProps) -> Element {
                             },
                         }
 
+                        div {
+                            class: "flex items-end gap-2",
+
+                            div {
+                                class: "flex-1",
+                                TextInput {
+                                    value: form.example.clone(),
+                                    label: "Example Value",
+                                    placeholder: form.suggested_example(),
+                                    help_text: "Used in API docs, seed data and generated tests",
+                                    on_change: move |value: String| {
+                                        form_state.write().example = value;
+                                    },
+                                }
+                            }
+
+                            button {
+                                r#type: "button",
+                                class: "px-3 py-2 mb-6 text-sm bg-slate-700 hover:bg-slate-600 rounded transition-colors",
+                                title: "Fill with a realistic value based on the field name and type",
+                                onclick: move |_| {
+                                    let suggestion = form_state.read().suggested_example();
+                                    form_state.write().example = suggestion;
+                                },
+                                "Generate"
+                            }
+                        }
+
                         TextArea {
                             value: form.help_text.clone(),
                             label: "Help Text",
@@ -1807,6 +2311,7 @@ fn get_data_type_options() -> Vec<SelectOption> {
         SelectOption::new("int64", "Integer (64-bit)"),
         SelectOption::new("float32", "Float (32-bit)"),
         SelectOption::new("float64", "Float (64-bit)"),
+        SelectOption::new("decimal", "Decimal (exact)"),
         SelectOption::new("bool", "Boolean"),
         SelectOption::new("uuid", "UUID"),
         SelectOption::new("datetime", "DateTime"),
@@ -1814,6 +2319,7 @@ fn get_data_type_options() -> Vec<SelectOption> {
         SelectOption::new("time", "Time"),
         SelectOption::new("bytes", "Binary (Bytes)"),
         SelectOption::new("json", "JSON"),
+        SelectOption::new("enum", "Enum"),
     ]
 }
 
@@ -1825,6 +2331,7 @@ fn data_type_to_string(dt: &DataType) -> String {
         DataType::Int64 => "int64".to_string(),
         DataType::Float32 => "float32".to_string(),
         DataType::Float64 => "float64".to_string(),
+        DataType::Decimal { .. } => "decimal".to_string(),
         DataType::Bool => "bool".to_string(),
         DataType::Uuid => "uuid".to_string(),
         DataType::DateTime => "datetime".to_string(),
@@ -1835,7 +2342,7 @@ fn data_type_to_string(dt: &DataType) -> String {
         DataType::Optional(inner) => data_type_to_string(inner),
         DataType::Array(inner) => data_type_to_string(inner),
         DataType::Reference { .. } => "uuid".to_string(),
-        DataType::Enum { .. } => "string".to_string(),
+        DataType::Enum { .. } => "enum".to_string(),
     }
 }
 
@@ -1847,6 +2354,7 @@ fn string_to_data_type(s: &str) -> DataType {
         "int64" => DataType::Int64,
         "float32" => DataType::Float32,
         "float64" => DataType::Float64,
+        "decimal" => DataType::money(),
         "bool" => DataType::Bool,
         "uuid" => DataType::Uuid,
         "datetime" => DataType::DateTime,
@@ -1854,10 +2362,71 @@ fn string_to_data_type(s: &str) -> DataType {
         "time" => DataType::Time,
         "bytes" => DataType::Bytes,
         "json" => DataType::Json,
+        "enum" => DataType::Enum {
+            name: String::new(),
+            variants: Vec::new(),
+        },
         _ => DataType::String,
     }
 }
 
+/// Parse a comma-separated variant list, dropping blanks
+fn parse_enum_variants(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// "Unique within" choices for a field of `entity_id`: no scope, then each
+/// relationship whose foreign key the entity holds, named after the parent
+/// (e.g. "Per organization (organization_id)")
+fn unique_scope_options(project: &ProjectGraph, entity_id: EntityId) -> Vec<SelectOption> {
+    let mut options = vec![SelectOption::new("", "— (no scope)")];
+    let Some(entity) = project.entities.get(&entity_id) else {
+        return options;
+    };
+    let mut scopes: Vec<(String, String)> = project
+        .relationships()
+        .filter_map(|rel| {
+            let key = scope_key(rel, entity)?;
+            let from = project.entities.get(&rel.from_entity_id)?;
+            let to = project.entities.get(&rel.to_entity_id)?;
+            let parent = accessor_names(rel, from, to).accessor.replace('_', " ");
+            Some((rel.id.to_string(), format!("Per {} ({})", parent, key.name)))
+        })
+        .collect();
+    scopes.sort_by(|a, b| a.1.cmp(&b.1));
+    options.extend(
+        scopes
+            .iter()
+            .map(|(id, label)| SelectOption::new(id, label)),
+    );
+    options
+}
+
+/// Add the field's enum to the project, or update the project enum of the
+/// same name (which retypes every other field using it)
+fn register_enum(project: &mut ProjectGraph, data_type: &DataType) {
+    let Some(def) = EnumDef::from_data_type(data_type) else {
+        return;
+    };
+    match project.get_enum_by_name(&def.name) {
+        Some(existing) if existing.variants == def.variants => {}
+        Some(existing) => {
+            let updated = EnumDef {
+                variants: def.variants,
+                ..existing.clone()
+            };
+            project.update_enum(updated);
+        }
+        None => {
+            project.add_enum(def);
+        }
+    }
+}
+
 // Widget type conversion functions
 fn get_widget_type_options() -> Vec<SelectOption> {
     vec![
@@ -1991,6 +2560,13 @@ fn get_default_type_options() -> Vec<SelectOption> {
     ]
 }
 
+fn get_computed_storage_options() -> Vec<SelectOption> {
+    ComputedStorage::all()
+        .iter()
+        .map(|s| SelectOption::new(s.display_name(), s.display_name()))
+        .collect()
+}
+
 fn default_type_to_string(dt: &DefaultValueType) -> String {
     match dt {
         DefaultValueType::Null => "null".to_string(),
@@ -2097,6 +2673,46 @@ mod tests {
         assert_eq!(string_to_data_type("int32"), DataType::Int32);
     }
 
+    #[test]
+    fn test_enum_field() {
+        assert_eq!(
+            string_to_data_type(&data_type_to_string(&DataType::Enum {
+                name: "PostStatus".to_string(),
+                variants: vec!["draft".to_string()],
+            })),
+            DataType::Enum {
+                name: String::new(),
+                variants: Vec::new(),
+            }
+        );
+        assert_eq!(
+            parse_enum_variants(" draft, ,published "),
+            vec!["draft", "published"]
+        );
+
+        let mut state = FieldFormState {
+            name: "status".to_string(),
+            data_type: string_to_data_type("enum"),
+            ..Default::default()
+        };
+        assert!(!state.is_valid());
+        state.data_type = EnumDef::new("PostStatus")
+            .with_variants(["draft", "published"])
+            .data_type();
+        assert!(state.is_valid());
+
+        // Saving registers the enum, then updates it in place
+        let mut project = ProjectGraph::new("blog");
+        register_enum(&mut project, &state.build_data_type());
+        let id = project.get_enum_by_name("PostStatus").unwrap().id;
+        register_enum(
+            &mut project,
+            &EnumDef::new("PostStatus").with_variant("draft").data_type(),
+        );
+        assert_eq!(project.enums().len(), 1);
+        assert_eq!(project.get_enum(id).unwrap().variants, vec!["draft"]);
+    }
+
     #[test]
     fn test_widget_type_conversion() {
         assert_eq!(widget_type_to_string(&WidgetType::Text), "text");
@@ -2157,6 +2773,28 @@ mod tests {
         assert!(!state.is_foreign_key);
     }
 
+    #[test]
+    fn test_form_state_advanced_settings() {
+        let mut form = FieldFormState {
+            name: "email".to_string(),
+            required: true,
+            unique: true,
+            ..Default::default()
+        };
+        assert!(form.advanced_settings().is_empty());
+
+        form.indexed = true;
+        form.is_foreign_key = true;
+        form.secret = true;
+        assert_eq!(
+            form.advanced_settings(),
+            vec!["index", "foreign key", "UI hints"]
+        );
+
+        let password = FieldTemplate::Password.apply();
+        assert!(password.advanced_settings().contains(&"validations"));
+    }
+
     #[test]
     fn test_form_state_validation() {
         let mut state = FieldFormState::default();
@@ -2181,6 +2819,60 @@ mod tests {
         assert!(state.is_valid());
     }
 
+    #[test]
+    fn test_form_state_computed() {
+        let mut state = FieldFormState::default();
+        state.name = "full_name".to_string();
+        state.is_computed = true;
+        state.computed_expression = "first_name ||".to_string();
+        assert!(!state.is_valid());
+
+        state.computed_expression = " first_name || ' ' || last_name ".to_string();
+        state.computed_storage = ComputedStorage::Getter;
+        assert!(state.is_valid());
+        assert_eq!(
+            state.build_computed(),
+            Some(ComputedField::getter("first_name || ' ' || last_name"))
+        );
+        assert!(state.advanced_settings().contains(&"computed value"));
+
+        // Concatenation produces text
+        state.data_type = DataType::Int32;
+        assert!(!state.is_valid());
+
+        let mut field = Field::new("full_name", DataType::String);
+        field.computed = Some(ComputedField::generated("first_name || last_name"));
+        let state = FieldFormState::from_field(&field);
+        assert!(state.is_computed);
+        assert_eq!(state.computed_storage, ComputedStorage::Generated);
+        assert_eq!(state.computed_expression, "first_name || last_name");
+    }
+
+    #[test]
+    fn test_form_state_decimal_digits() {
+        let mut state = FieldTemplate::Price.apply();
+        assert_eq!(state.data_type, DataType::decimal(12, 2));
+        assert_eq!(data_type_to_string(&state.data_type), "decimal");
+
+        state.set_decimal_digits(Some(6), None);
+        assert_eq!(state.data_type, DataType::decimal(6, 2));
+
+        // Scale is kept within the precision, precision within rust_decimal's limit
+        state.set_decimal_digits(None, Some(9));
+        assert_eq!(state.data_type, DataType::decimal(6, 6));
+        state.set_decimal_digits(Some(40), None);
+        assert_eq!(state.data_type, DataType::decimal(28, 6));
+        assert!(state.is_valid());
+
+        state.data_type = DataType::decimal(4, 5);
+        assert!(!state.is_valid());
+
+        // Only decimals have digits
+        state.data_type = DataType::Float64;
+        state.set_decimal_digits(Some(4), Some(1));
+        assert_eq!(state.data_type, DataType::Float64);
+    }
+
     #[test]
     fn test_validation_config() {
         let config = ValidationConfig::new(ValidationType::MinLength);
@@ -2214,4 +2906,36 @@ mod tests {
         let dt = state.build_data_type();
         assert!(matches!(dt, DataType::Optional(_)));
     }
+
+    #[test]
+    fn test_unique_scope() {
+        use imortal_ir::{Entity, Relationship};
+
+        let mut project = ProjectGraph::new("saas");
+        let org = project.add_entity(Entity::new("Organization"));
+        let team = project.add_entity(Entity::new("Team"));
+        let (rel, _) = project
+            .create_relationship_with_fk(Relationship::one_to_many(org, team))
+            .unwrap();
+
+        let options = unique_scope_options(&project, team);
+        let labels: Vec<&str> = options.iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            ["— (no scope)", "Per organization (organization_id)"]
+        );
+        assert_eq!(options[1].value, rel.to_string());
+        // The parent holds no key to scope by
+        assert_eq!(unique_scope_options(&project, org).len(), 1);
+
+        let mut state = FieldFormState {
+            unique_scope: Some(rel),
+            ..Default::default()
+        };
+        assert_eq!(state.build_unique_scope(), Some(rel));
+        assert!(state.advanced_settings().contains(&"uniqueness scope"));
+        // Unique across the table wins
+        state.unique = true;
+        assert_eq!(state.build_unique_scope(), None);
+    }
 }
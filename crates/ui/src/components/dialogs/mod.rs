@@ -7,6 +7,7 @@
 //! - **EntityDialog**: Create and edit entities
 //! - **FieldDialog**: Create and edit fields within entities
 //! - **RelationshipDialog**: Create and edit relationships between entities
+//! - **QueryDialog**: Build custom query endpoints visually
 //! - **ConfirmDeleteDialog**: Confirmation dialogs for destructive actions
 //! - **DataTypeSelector**: Enhanced data type selection component
 //! - **ValidationEditor**: Field validation configuration
@@ -35,6 +36,7 @@ pub mod data_type_selector;
 pub mod endpoint_dialog;
 pub mod entity_dialog;
 pub mod field_dialog;
+pub mod query_dialog;
 pub mod relationship_dialog;
 pub mod validation_editor;
 
@@ -44,8 +46,9 @@ pub mod validation_editor;
 
 pub use confirm_delete::ConfirmDeleteDialog;
 pub use data_type_selector::{DataTypeSelector, DataTypeSelectorProps};
-pub use endpoint_dialog::{EndpointDialog, EndpointDialogMode};
+pub use endpoint_dialog::{EndpointDialog, EndpointDialogMode, EndpointTab};
 pub use entity_dialog::{EntityDialog, EntityDialogMode};
 pub use field_dialog::{FieldDialog, FieldDialogMode};
+pub use query_dialog::{QueryDialog, QueryDialogMode};
 pub use relationship_dialog::{RelationshipDialog, RelationshipDialogMode};
 pub use validation_editor::{ValidationEditor, ValidationEditorProps};
@@ -0,0 +1,887 @@
+//! # Query Builder Dialog
+//!
+//! Visual builder for custom query endpoints. The user picks a root entity,
+//! joins related entities through existing relationships, adds filters and
+//! sort keys, chooses the returned columns, and the result is stored as a
+//! [`QueryDefinition`] in the project. The generator turns each query into a
+//! dedicated `GET` endpoint with its own parameters/row DTOs.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! QueryDialog {
+//!     mode: QueryDialogMode::Create { entity_id: Some(id) },
+//! }
+//! ```
+
+use dioxus::prelude::*;
+use imortal_ir::{
+    EndpointSecurity, FilterOperator, FilterValue, ProjectGraph, QueryDefinition, QueryFilter,
+    QueryJoin, QuerySort, SortDirection,
+};
+use uuid::Uuid;
+
+use crate::components::help_panel::HelpButton;
+use crate::components::inputs::{Select, SelectOption, TextInput, Toggle};
+use crate::help::HelpTopic;
+use crate::state::{APP_STATE, StatusLevel};
+
+// ============================================================================
+// Dialog Mode
+// ============================================================================
+
+/// Mode for the query dialog
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryDialogMode {
+    /// Creating a new query
+    Create {
+        /// Pre-selected root entity (optional)
+        entity_id: Option<Uuid>,
+    },
+    /// Editing an existing query
+    Edit(Uuid),
+}
+
+impl QueryDialogMode {
+    /// Get the title for the dialog
+    pub fn title(&self) -> &'static str {
+        match self {
+            QueryDialogMode::Create { .. } => "New Query Endpoint",
+            QueryDialogMode::Edit(_) => "Edit Query Endpoint",
+        }
+    }
+
+    /// Get the submit button text
+    pub fn submit_text(&self) -> &'static str {
+        match self {
+            QueryDialogMode::Create { .. } => "Create Query",
+            QueryDialogMode::Edit(_) => "Save Changes",
+        }
+    }
+}
+
+/// Properties for the QueryDialog component
+#[derive(Props, Clone, PartialEq)]
+pub struct QueryDialogProps {
+    /// Dialog mode (create or edit)
+    pub mode: QueryDialogMode,
+}
+
+// ============================================================================
+// Form State
+// ============================================================================
+
+/// Editable copy of a query definition
+#[derive(Debug, Clone, PartialEq)]
+struct QueryFormState {
+    name: String,
+    description: String,
+    entity_id: Option<Uuid>,
+    path: String,
+    /// Once the user edits the path it no longer follows the name
+    path_edited: bool,
+    joins: Vec<QueryJoin>,
+    filters: Vec<QueryFilter>,
+    sort: Vec<QuerySort>,
+    projection: Vec<String>,
+    limit: String,
+    auth_required: bool,
+    enabled: bool,
+}
+
+impl QueryFormState {
+    fn new(entity_id: Option<Uuid>) -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            entity_id,
+            path: "/api/reports/".to_string(),
+            path_edited: false,
+            joins: Vec::new(),
+            filters: Vec::new(),
+            sort: Vec::new(),
+            projection: Vec::new(),
+            limit: "100".to_string(),
+            auth_required: false,
+            enabled: true,
+        }
+    }
+
+    fn from_query(query: &QueryDefinition) -> Self {
+        Self {
+            name: query.name.clone(),
+            description: query.description.clone().unwrap_or_default(),
+            entity_id: Some(query.entity_id),
+            path: query.path.clone(),
+            path_edited: true,
+            joins: query.joins.clone(),
+            filters: query.filters.clone(),
+            sort: query.sort.clone(),
+            projection: query.projection.clone(),
+            limit: query.limit.map(|l| l.to_string()).unwrap_or_default(),
+            auth_required: query.security.auth_required,
+            enabled: query.enabled,
+        }
+    }
+
+    fn set_name(&mut self, name: String) {
+        if !self.path_edited {
+            self.path = format!("/api/reports/{}", name);
+        }
+        self.name = name;
+    }
+
+    /// Change the root entity; joins and field references no longer apply
+    fn set_entity(&mut self, entity_id: Option<Uuid>) {
+        if self.entity_id != entity_id {
+            self.entity_id = entity_id;
+            self.joins.clear();
+            self.filters.clear();
+            self.sort.clear();
+            self.projection.clear();
+        }
+    }
+
+    fn toggle_join(&mut self, relationship_id: Uuid, entity_name: &str) {
+        if let Some(pos) = self
+            .joins
+            .iter()
+            .position(|j| j.relationship_id == relationship_id)
+        {
+            self.joins.remove(pos);
+            // Drop references to the removed entity
+            let prefix = format!("{}.", entity_name);
+            self.filters.retain(|f| !f.field.starts_with(&prefix));
+            self.sort.retain(|s| !s.field.starts_with(&prefix));
+            self.projection.retain(|p| !p.starts_with(&prefix));
+        } else {
+            self.joins
+                .push(QueryJoin::new(relationship_id, entity_name));
+        }
+    }
+
+    fn toggle_projection(&mut self, field_ref: &str) {
+        if let Some(pos) = self.projection.iter().position(|p| p == field_ref) {
+            self.projection.remove(pos);
+        } else {
+            self.projection.push(field_ref.to_string());
+        }
+    }
+
+    /// Build the query definition, or describe what is missing
+    fn build(&self, entity_name: &str, id: Option<Uuid>) -> Result<QueryDefinition, String> {
+        let entity_id = self.entity_id.ok_or("Please select an entity")?;
+
+        let name = self.name.trim();
+        let valid_name = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid_name {
+            return Err("Query name must be snake_case (e.g. active_users)".to_string());
+        }
+
+        if !self.path.starts_with('/') {
+            return Err("Path must start with '/'".to_string());
+        }
+
+        let limit = match self.limit.trim() {
+            "" => None,
+            raw => Some(
+                raw.parse::<u64>()
+                    .map_err(|_| "Limit must be a positive number".to_string())?,
+            ),
+        };
+
+        let mut query = QueryDefinition::new(name, entity_id, entity_name).with_path(&self.path);
+        if let Some(id) = id {
+            query.id = id;
+        }
+        query.description = Some(self.description.trim().to_string()).filter(|d| !d.is_empty());
+        query.joins = self.joins.clone();
+        query.filters = self.filters.clone();
+        query.sort = self.sort.clone();
+        query.projection = self.projection.clone();
+        query.limit = limit;
+        query.security = if self.auth_required {
+            EndpointSecurity::authenticated()
+        } else {
+            EndpointSecurity::open()
+        };
+        query.enabled = self.enabled;
+
+        Ok(query)
+    }
+
+    /// SQL-like summary shown under the builder
+    fn preview(&self, entity_name: &str) -> String {
+        let columns = if self.projection.is_empty() {
+            "*".to_string()
+        } else {
+            self.projection.join(", ")
+        };
+
+        let mut out = format!("SELECT {} FROM {}", columns, entity_name);
+
+        for join in &self.joins {
+            let kind = if join.optional { "LEFT JOIN" } else { "JOIN" };
+            out.push_str(&format!(" {} {}", kind, join.entity_name));
+        }
+
+        let conditions: Vec<String> = self
+            .filters
+            .iter()
+            .map(|f| match &f.value {
+                FilterValue::Literal(v) => format!("{} {} {}", f.field, f.operator.symbol(), v),
+                FilterValue::Param(p) => format!("{} {} :{}", f.field, f.operator.symbol(), p),
+                FilterValue::None => format!("{} {}", f.field, f.operator.symbol()),
+            })
+            .collect();
+        if !conditions.is_empty() {
+            out.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+
+        let order: Vec<String> = self
+            .sort
+            .iter()
+            .map(|s| match s.direction {
+                SortDirection::Asc => format!("{} ASC", s.field),
+                SortDirection::Desc => format!("{} DESC", s.field),
+            })
+            .collect();
+        if !order.is_empty() {
+            out.push_str(&format!(" ORDER BY {}", order.join(", ")));
+        }
+
+        if !self.limit.trim().is_empty() {
+            out.push_str(&format!(" LIMIT {}", self.limit.trim()));
+        }
+
+        out
+    }
+}
+
+// ============================================================================
+// Project helpers
+// ============================================================================
+
+/// Relationships that can be joined from `root_id`: (relationship id, other entity name).
+/// Polymorphic relationships are left out, as their key points at several tables.
+fn joinable_relationships(project: &ProjectGraph, root_id: Uuid) -> Vec<(Uuid, String)> {
+    let mut list: Vec<(Uuid, String)> = project
+        .relationships
+        .values()
+        .filter(|r| !r.is_polymorphic())
+        .filter_map(|r| {
+            let other = if r.from_entity_id == root_id {
+                r.to_entity_id
+            } else if r.to_entity_id == root_id {
+                r.from_entity_id
+            } else {
+                return None;
+            };
+            project
+                .entities
+                .get(&other)
+                .filter(|e| e.id != root_id)
+                .map(|e| (r.id, e.name.clone()))
+        })
+        .collect();
+    list.sort_by(|a, b| a.1.cmp(&b.1));
+    list
+}
+
+/// Field references available to the query (`field` for the root,
+/// `Entity.field` for joined entities)
+fn available_field_refs(project: &ProjectGraph, root_id: Uuid, joins: &[QueryJoin]) -> Vec<String> {
+    let mut refs = Vec::new();
+
+    if let Some(root) = project.entities.get(&root_id) {
+        refs.extend(root.fields.iter().map(|f| f.name.clone()));
+    }
+
+    for join in joins {
+        if let Some(entity) = project
+            .entities
+            .values()
+            .find(|e| e.name == join.entity_name)
+        {
+            refs.extend(
+                entity
+                    .fields
+                    .iter()
+                    .map(|f| format!("{}.{}", entity.name, f.name)),
+            );
+        }
+    }
+
+    refs
+}
+
+fn operator_value(op: FilterOperator) -> &'static str {
+    op.sea_orm_method()
+}
+
+fn operator_from_value(value: &str) -> FilterOperator {
+    FilterOperator::all()
+        .iter()
+        .copied()
+        .find(|op| op.sea_orm_method() == value)
+        .unwrap_or(FilterOperator::Eq)
+}
+
+// ============================================================================
+// Query Dialog Component
+// ============================================================================
+
+/// Query builder dialog component
+#[component]
+pub fn QueryDialog(props: QueryDialogProps) -> Element {
+    let state = APP_STATE.read();
+    let project = state.project.clone().unwrap_or_default();
+    let existing: Option<QueryDefinition> = match &props.mode {
+        QueryDialogMode::Edit(id) => project.queries.get(id).cloned(),
+        QueryDialogMode::Create { .. } => None,
+    };
+    drop(state);
+
+    let initial = match (&props.mode, &existing) {
+        (_, Some(query)) => QueryFormState::from_query(query),
+        (QueryDialogMode::Create { entity_id }, None) => QueryFormState::new(*entity_id),
+        (QueryDialogMode::Edit(_), None) => QueryFormState::new(None),
+    };
+    let mut form = use_signal(|| initial);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    let current = form.read().clone();
+
+    // Entity options
+    let mut entities: Vec<(Uuid, String)> = project
+        .entities
+        .values()
+        .map(|e| (e.id, e.name.clone()))
+        .collect();
+    entities.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let mut entity_options = vec![SelectOption::disabled("", "Select an entity...")];
+    entity_options.extend(
+        entities
+            .iter()
+            .map(|(id, name)| SelectOption::new(id.to_string(), name.clone())),
+    );
+
+    let entity_name = current
+        .entity_id
+        .and_then(|id| project.entities.get(&id))
+        .map(|e| e.name.clone())
+        .unwrap_or_default();
+
+    let joinable = current
+        .entity_id
+        .map(|id| joinable_relationships(&project, id))
+        .unwrap_or_default();
+
+    let field_refs = current
+        .entity_id
+        .map(|id| available_field_refs(&project, id, &current.joins))
+        .unwrap_or_default();
+    let field_options: Vec<SelectOption> = field_refs
+        .iter()
+        .map(|r| SelectOption::new(r.clone(), r.clone()))
+        .collect();
+    let operator_options: Vec<SelectOption> = FilterOperator::all()
+        .iter()
+        .map(|op| SelectOption::new(operator_value(*op), op.symbol()))
+        .collect();
+    let value_kind_options = vec![
+        SelectOption::new("literal", "Fixed value"),
+        SelectOption::new("param", "Query parameter"),
+    ];
+    let direction_options = vec![
+        SelectOption::new("asc", "Ascending"),
+        SelectOption::new("desc", "Descending"),
+    ];
+
+    let preview = current.preview(if entity_name.is_empty() {
+        "?"
+    } else {
+        &entity_name
+    });
+    let first_field = field_refs.first().cloned().unwrap_or_default();
+    let first_field_for_sort = first_field.clone();
+
+    // Submit
+    let mode_for_submit = props.mode.clone();
+    let on_submit = move |_| {
+        let form_state = form.read().clone();
+        let entity_name = {
+            let state = APP_STATE.read();
+            form_state
+                .entity_id
+                .and_then(|id| state.project.as_ref()?.entities.get(&id))
+                .map(|e| e.name.clone())
+                .unwrap_or_default()
+        };
+
+        let existing_id = match &mode_for_submit {
+            QueryDialogMode::Edit(id) => Some(*id),
+            QueryDialogMode::Create { .. } => None,
+        };
+
+        let query = match form_state.build(&entity_name, existing_id) {
+            Ok(query) => query,
+            Err(err) => {
+                error_message.set(Some(err));
+                return;
+            }
+        };
+
+        let mut state = APP_STATE.write();
+        let name = query.name.clone();
+        if let Some(project) = &mut state.project {
+            project.add_query(query);
+        }
+        state.mark_dirty();
+        state.ui.set_status(
+            match &mode_for_submit {
+                QueryDialogMode::Create { .. } => format!("Created query '{}'", name),
+                QueryDialogMode::Edit(_) => format!("Updated query '{}'", name),
+            },
+            StatusLevel::Success,
+        );
+        state.ui.close_dialog();
+        drop(state);
+        APP_STATE.write().save_to_history("Save query");
+    };
+
+    let title = props.mode.title();
+    let submit_text = props.mode.submit_text();
+
+    rsx! {
+        div {
+            class: "flex flex-col max-h-[85vh]",
+
+            // Header
+            div {
+                class: "px-6 py-4 border-b border-slate-700 flex items-center justify-between",
+                h2 {
+                    class: "text-xl font-semibold text-white flex items-center gap-2",
+                    span { "🔎" }
+                    "{title}"
+                    HelpButton { topic: HelpTopic::CustomQueries }
+                }
+                button {
+                    class: "text-slate-400 hover:text-white transition-colors p-1 rounded hover:bg-slate-700",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.close_dialog();
+                    },
+                    "✕"
+                }
+            }
+
+            // Body
+            div {
+                class: "flex-1 overflow-y-auto px-6 py-4 space-y-5",
+
+                if let Some(err) = error_message.read().as_ref() {
+                    div {
+                        class: "p-3 bg-red-900/30 border border-red-700 rounded-lg text-red-400 text-sm",
+                        "{err}"
+                    }
+                }
+
+                // General
+                div {
+                    class: "grid grid-cols-2 gap-4",
+                    TextInput {
+                        label: "Name",
+                        value: current.name.clone(),
+                        placeholder: "active_users",
+                        required: true,
+                        on_change: move |v: String| form.write().set_name(v),
+                    }
+                    Select {
+                        label: "Entity",
+                        value: current.entity_id.map(|id| id.to_string()).unwrap_or_default(),
+                        options: entity_options,
+                        required: true,
+                        on_change: move |v: String| {
+                            form.write().set_entity(Uuid::parse_str(&v).ok());
+                        },
+                    }
+                    TextInput {
+                        label: "Path",
+                        value: current.path.clone(),
+                        on_change: move |v: String| {
+                            let mut f = form.write();
+                            f.path = v;
+                            f.path_edited = true;
+                        },
+                    }
+                    TextInput {
+                        label: "Max rows",
+                        value: current.limit.clone(),
+                        placeholder: "No limit",
+                        on_change: move |v: String| { form.write().limit = v },
+                    }
+                }
+                TextInput {
+                    label: "Description",
+                    value: current.description.clone(),
+                    placeholder: "Shown in the generated API docs",
+                    on_change: move |v: String| { form.write().description = v },
+                }
+
+                // Joins
+                if !joinable.is_empty() {
+                    div {
+                        h3 { class: "text-sm font-medium text-slate-300 mb-2", "Joins" }
+                        div {
+                            class: "flex flex-wrap gap-2",
+                            for (rel_id, other) in joinable.iter().cloned() {
+                                {
+                                    let join_optional = current.joins.iter().find(|j| j.relationship_id == rel_id).map(|j| j.optional);
+                                    let other_for_toggle = other.clone();
+                                    rsx! {
+                                        div {
+                                            key: "{rel_id}",
+                                            class: "flex items-center gap-1",
+                                            button {
+                                                r#type: "button",
+                                                class: if join_optional.is_some() {
+                                                    "px-3 py-1 rounded text-xs bg-indigo-600 text-white"
+                                                } else {
+                                                    "px-3 py-1 rounded text-xs bg-slate-700 text-slate-300 hover:bg-slate-600"
+                                                },
+                                                onclick: move |_| form.write().toggle_join(rel_id, &other_for_toggle),
+                                                "🔗 {other}"
+                                            }
+                                            if let Some(optional) = join_optional {
+                                                button {
+                                                    r#type: "button",
+                                                    class: "px-2 py-1 rounded text-xs bg-slate-700 text-slate-400 hover:bg-slate-600",
+                                                    title: "Keep rows without a match (LEFT JOIN)",
+                                                    onclick: move |_| {
+                                                        let mut f = form.write();
+                                                        if let Some(j) = f.joins.iter_mut().find(|j| j.relationship_id == rel_id) {
+                                                            j.optional = !j.optional;
+                                                        }
+                                                    },
+                                                    if optional { "left" } else { "inner" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Filters
+                div {
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        h3 { class: "text-sm font-medium text-slate-300", "Filters" }
+                        button {
+                            r#type: "button",
+                            class: "text-xs text-indigo-400 hover:text-indigo-300 disabled:opacity-50",
+                            disabled: field_refs.is_empty(),
+                            onclick: move |_| {
+                                form.write().filters.push(QueryFilter::literal(first_field.clone(), FilterOperator::Eq, ""));
+                            },
+                            "+ Add filter"
+                        }
+                    }
+                    for (i, filter) in current.filters.iter().cloned().enumerate() {
+                        {
+                            let is_param = matches!(filter.value, FilterValue::Param(_));
+                            let value_text = match &filter.value {
+                                FilterValue::Literal(v) | FilterValue::Param(v) => v.clone(),
+                                FilterValue::None => String::new(),
+                            };
+                            rsx! {
+                                div {
+                                    key: "filter-{i}",
+                                    class: "grid grid-cols-[1fr_7rem_8rem_1fr_auto] gap-2 items-center mb-2",
+                                    Select {
+                                        value: filter.field.clone(),
+                                        options: field_options.clone(),
+                                        on_change: move |v: String| { form.write().filters[i].field = v },
+                                    }
+                                    Select {
+                                        value: operator_value(filter.operator).to_string(),
+                                        options: operator_options.clone(),
+                                        on_change: move |v: String| {
+                                            let mut f = form.write();
+                                            let op = operator_from_value(&v);
+                                            f.filters[i].operator = op;
+                                            if !op.takes_value() {
+                                                f.filters[i].value = FilterValue::None;
+                                            } else if f.filters[i].value == FilterValue::None {
+                                                f.filters[i].value = FilterValue::Literal(String::new());
+                                            }
+                                        },
+                                    }
+                                    if filter.operator.takes_value() {
+                                        Select {
+                                            value: if is_param { "param".to_string() } else { "literal".to_string() },
+                                            options: value_kind_options.clone(),
+                                            on_change: move |v: String| {
+                                                let mut f = form.write();
+                                                let text = match &f.filters[i].value {
+                                                    FilterValue::Literal(t) | FilterValue::Param(t) => t.clone(),
+                                                    FilterValue::None => String::new(),
+                                                };
+                                                f.filters[i].value = if v == "param" {
+                                                    FilterValue::Param(text)
+                                                } else {
+                                                    FilterValue::Literal(text)
+                                                };
+                                            },
+                                        }
+                                        TextInput {
+                                            value: value_text,
+                                            placeholder: if is_param { "parameter name" } else { "value" },
+                                            on_change: move |v: String| {
+                                                let mut f = form.write();
+                                                f.filters[i].value = match f.filters[i].value {
+                                                    FilterValue::Param(_) => FilterValue::Param(v),
+                                                    _ => FilterValue::Literal(v),
+                                                };
+                                            },
+                                        }
+                                    } else {
+                                        div {}
+                                        div {}
+                                    }
+                                    button {
+                                        r#type: "button",
+                                        class: "p-1 text-slate-400 hover:text-red-400",
+                                        title: "Remove filter",
+                                        onclick: move |_| {
+                                            form.write().filters.remove(i);
+                                        },
+                                        "✕"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Sort
+                div {
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        h3 { class: "text-sm font-medium text-slate-300", "Sort" }
+                        button {
+                            r#type: "button",
+                            class: "text-xs text-indigo-400 hover:text-indigo-300 disabled:opacity-50",
+                            disabled: field_refs.is_empty(),
+                            onclick: move |_| {
+                                form.write().sort.push(QuerySort {
+                                    field: first_field_for_sort.clone(),
+                                    direction: SortDirection::Asc,
+                                });
+                            },
+                            "+ Add sort"
+                        }
+                    }
+                    for (i, sort) in current.sort.iter().cloned().enumerate() {
+                        div {
+                            key: "sort-{i}",
+                            class: "grid grid-cols-[1fr_10rem_auto] gap-2 items-center mb-2",
+                            Select {
+                                value: sort.field.clone(),
+                                options: field_options.clone(),
+                                on_change: move |v: String| { form.write().sort[i].field = v },
+                            }
+                            Select {
+                                value: if sort.direction == SortDirection::Desc { "desc".to_string() } else { "asc".to_string() },
+                                options: direction_options.clone(),
+                                on_change: move |v: String| {
+                                    form.write().sort[i].direction = if v == "desc" {
+                                        SortDirection::Desc
+                                    } else {
+                                        SortDirection::Asc
+                                    };
+                                },
+                            }
+                            button {
+                                r#type: "button",
+                                class: "p-1 text-slate-400 hover:text-red-400",
+                                title: "Remove sort",
+                                onclick: move |_| {
+                                    form.write().sort.remove(i);
+                                },
+                                "✕"
+                            }
+                        }
+                    }
+                }
+
+                // Projection
+                if !field_refs.is_empty() {
+                    div {
+                        h3 { class: "text-sm font-medium text-slate-300 mb-1", "Returned columns" }
+                        p {
+                            class: "text-xs text-slate-500 mb-2",
+                            "Leave empty to return every non-secret field of the root entity."
+                        }
+                        div {
+                            class: "flex flex-wrap gap-1",
+                            for field_ref in field_refs.iter().cloned() {
+                                {
+                                    let selected = current.projection.contains(&field_ref);
+                                    let label = field_ref.clone();
+                                    rsx! {
+                                        button {
+                                            key: "{field_ref}",
+                                            r#type: "button",
+                                            class: if selected {
+                                                "px-2 py-1 rounded text-xs font-mono bg-indigo-600 text-white"
+                                            } else {
+                                                "px-2 py-1 rounded text-xs font-mono bg-slate-700 text-slate-300 hover:bg-slate-600"
+                                            },
+                                            onclick: move |_| form.write().toggle_projection(&field_ref),
+                                            "{label}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Security
+                div {
+                    class: "grid grid-cols-2 gap-4",
+                    Toggle {
+                        label: "Require authentication",
+                        checked: current.auth_required,
+                        on_change: move |v: bool| { form.write().auth_required = v },
+                    }
+                    Toggle {
+                        label: "Enabled",
+                        checked: current.enabled,
+                        on_change: move |v: bool| { form.write().enabled = v },
+                    }
+                }
+
+                // Preview
+                div {
+                    class: "p-3 bg-slate-900 border border-slate-700 rounded-lg",
+                    div { class: "text-xs text-slate-500 mb-1", "GET {current.path}" }
+                    code { class: "text-xs text-emerald-300 break-all", "{preview}" }
+                }
+            }
+
+            // Footer
+            div {
+                class: "px-6 py-4 border-t border-slate-700 flex items-center justify-between",
+                div {
+                    class: "text-xs text-slate-500",
+                    "Generates a SeaORM query, parameter/row DTOs and OpenAPI docs."
+                }
+                div {
+                    class: "flex items-center gap-3",
+                    button {
+                        class: "px-4 py-2 text-slate-300 hover:text-white hover:bg-slate-700 rounded-lg transition-colors",
+                        onclick: move |_| {
+                            APP_STATE.write().ui.close_dialog();
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "px-6 py-2 bg-indigo-600 hover:bg-indigo-700 text-white rounded-lg font-medium transition-colors",
+                        onclick: on_submit,
+                        "{submit_text}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{Entity, RelationType, Relationship};
+
+    #[test]
+    fn test_dialog_mode() {
+        let create = QueryDialogMode::Create { entity_id: None };
+        assert_eq!(create.title(), "New Query Endpoint");
+        assert_eq!(
+            QueryDialogMode::Edit(Uuid::new_v4()).submit_text(),
+            "Save Changes"
+        );
+    }
+
+    #[test]
+    fn test_form_build_and_preview() {
+        let entity_id = Uuid::new_v4();
+        let mut form = QueryFormState::new(Some(entity_id));
+        form.set_name("top_posts".to_string());
+        form.filters
+            .push(QueryFilter::literal("views", FilterOperator::Gt, "10"));
+        form.sort.push(QuerySort {
+            field: "views".to_string(),
+            direction: SortDirection::Desc,
+        });
+        form.toggle_projection("title");
+
+        assert_eq!(form.path, "/api/reports/top_posts");
+        assert_eq!(
+            form.preview("Post"),
+            "SELECT title FROM Post WHERE views > 10 ORDER BY views DESC LIMIT 100"
+        );
+
+        let query = form.build("Post", None).unwrap();
+        assert_eq!(query.name, "top_posts");
+        assert_eq!(query.limit, Some(100));
+        assert_eq!(QueryFormState::from_query(&query).projection, vec!["title"]);
+
+        form.name = "Top Posts".to_string();
+        assert!(form.build("Post", None).is_err());
+    }
+
+    #[test]
+    fn test_joins_and_field_refs() {
+        let mut project = ProjectGraph::new("test");
+        let user = Entity::new("User");
+        let post = Entity::new("Post");
+        let (user_id, post_id) = (user.id, post.id);
+        project.add_entity(user);
+        project.add_entity(post);
+        let rel = Relationship::new(user_id, post_id, RelationType::OneToMany);
+        let rel_id = rel.id;
+        project.relationships.insert(rel_id, rel);
+
+        let photo = project.add_entity(Entity::new("Photo"));
+        project.add_relationship(Relationship::polymorphic(post_id, photo, "attachable"));
+
+        let joinable = joinable_relationships(&project, post_id);
+        assert_eq!(joinable, vec![(rel_id, "User".to_string())]);
+
+        let mut form = QueryFormState::new(Some(post_id));
+        form.toggle_join(rel_id, "User");
+        form.toggle_projection("User.id");
+        assert!(
+            available_field_refs(&project, post_id, &form.joins).contains(&"User.id".to_string())
+        );
+
+        // Removing the join drops references to the joined entity
+        form.toggle_join(rel_id, "User");
+        assert!(form.joins.is_empty());
+        assert!(form.projection.is_empty());
+    }
+}
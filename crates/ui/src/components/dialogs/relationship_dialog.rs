@@ -7,7 +7,8 @@
 //! - Choose relationship type (1:1, 1:N, N:1, N:M)
 //! - Configure field mappings (from_field, to_field)
 //! - Set referential actions (CASCADE, SET NULL, RESTRICT, etc.)
-//! - Name the relationship and its inverse
+//! - Name the accessors on both ends (e.g. `author` / `posts`)
+//! - Promote a many-to-many junction table to an entity of its own
 //!
 //! ## Usage
 //!
@@ -22,10 +23,13 @@
 
 use dioxus::prelude::*;
 use imortal_core::{ReferentialAction, RelationType};
-use imortal_ir::{Entity, PortPosition, Relationship};
+use imortal_ir::{AccessorNames, Entity, PortPosition, Relationship, accessor_names};
 use uuid::Uuid;
 
+use crate::components::help_panel::HelpButton;
+use crate::components::icon::{Icon, IconName};
 use crate::components::inputs::{Checkbox, Select, SelectOption, TextArea, TextInput};
+use crate::help::HelpTopic;
 use crate::state::APP_STATE;
 
 // ============================================================================
@@ -93,13 +97,19 @@ struct RelationshipFormState {
     relation_type: RelationType,
     from_field: String,
     to_field: String,
+    accessor_name: String,
     inverse_name: String,
     description: String,
     on_delete: ReferentialAction,
     on_update: ReferentialAction,
     required: bool,
+    nested_create: bool,
     from_port: PortPosition,
     to_port: PortPosition,
+    /// Entity modelling the junction table, kept across edits
+    junction_entity_id: Option<Uuid>,
+    /// Polymorphic association name (e.g. "commentable")
+    association: String,
 }
 
 impl Default for RelationshipFormState {
@@ -111,13 +121,17 @@ impl Default for RelationshipFormState {
             relation_type: RelationType::OneToMany,
             from_field: String::new(),
             to_field: "id".to_string(),
+            accessor_name: String::new(),
             inverse_name: String::new(),
             description: String::new(),
             on_delete: ReferentialAction::Restrict,
             on_update: ReferentialAction::Cascade,
             required: true,
+            nested_create: false,
             from_port: PortPosition::Right,
             to_port: PortPosition::Left,
+            junction_entity_id: None,
+            association: String::new(),
         }
     }
 }
@@ -132,13 +146,20 @@ impl RelationshipFormState {
             relation_type: rel.relation_type.clone(),
             from_field: rel.from_field.clone(),
             to_field: rel.to_field.clone(),
+            accessor_name: rel.accessor_name.clone().unwrap_or_default(),
             inverse_name: rel.inverse_name.clone().unwrap_or_default(),
             description: rel.description.clone().unwrap_or_default(),
             on_delete: rel.on_delete.clone(),
             on_update: rel.on_update.clone(),
             required: rel.required,
+            nested_create: rel.nested_create,
             from_port: rel.from_port.clone(),
             to_port: rel.to_port.clone(),
+            junction_entity_id: rel.junction_entity_id,
+            association: rel
+                .polymorphic_association()
+                .unwrap_or_default()
+                .to_string(),
         }
     }
 
@@ -151,11 +172,8 @@ impl RelationshipFormState {
         rel.name = self.name.clone();
         rel.from_field = self.from_field.clone();
         rel.to_field = self.to_field.clone();
-        rel.inverse_name = if self.inverse_name.is_empty() {
-            None
-        } else {
-            Some(self.inverse_name.clone())
-        };
+        rel.accessor_name = non_blank(&self.accessor_name);
+        rel.inverse_name = non_blank(&self.inverse_name);
         rel.description = if self.description.is_empty() {
             None
         } else {
@@ -164,12 +182,37 @@ impl RelationshipFormState {
         rel.on_delete = self.on_delete.clone();
         rel.on_update = self.on_update.clone();
         rel.required = self.required;
+        rel.nested_create =
+            self.nested_create && matches!(self.relation_type, RelationType::OneToMany);
         rel.from_port = self.from_port.clone();
         rel.to_port = self.to_port.clone();
+        if rel.is_many_to_many() {
+            rel.junction_entity_id = self.junction_entity_id;
+        }
+        // A renamed association moves both fields; an unchanged one keeps
+        // the discriminator it was saved with
+        let association = self.association.trim();
+        if rel.is_polymorphic() && rel.polymorphic_association() != Some(association) {
+            rel.relation_type = RelationType::Polymorphic {
+                discriminator: format!("{}_type", association),
+            };
+            rel.from_field = format!("{}_id", association);
+        }
 
         Some(rel)
     }
 
+    /// Accessor names the generator derives when none are entered, along
+    /// with the entities they end up on
+    fn suggested_accessors(&self, entities: &[Entity]) -> Option<AccessorNames> {
+        let mut rel = self.to_relationship()?;
+        rel.accessor_name = None;
+        rel.inverse_name = None;
+        let from = entities.iter().find(|e| e.id == rel.from_entity_id)?;
+        let to = entities.iter().find(|e| e.id == rel.to_entity_id)?;
+        Some(accessor_names(&rel, from, to))
+    }
+
     /// Validate the form state
     fn validate(&self) -> Vec<String> {
         let mut errors = Vec::new();
@@ -190,6 +233,12 @@ impl RelationshipFormState {
             errors.push("Relationship name is required".to_string());
         }
 
+        if matches!(self.relation_type, RelationType::Polymorphic { .. })
+            && self.association.trim().is_empty()
+        {
+            errors.push("Association name is required for polymorphic relationships".to_string());
+        }
+
         errors
     }
 
@@ -262,6 +311,25 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
     let mut errors = use_signal(Vec::<String>::new);
     let mut active_tab = use_signal(|| 0usize);
 
+    // A saved many-to-many relationship, and the entity modelling its
+    // junction table once promoted
+    let junction: Option<(Uuid, Option<String>)> = match &props.mode {
+        RelationshipDialogMode::Edit(rel_id) => {
+            let state = APP_STATE.read();
+            state
+                .project
+                .as_ref()
+                .and_then(|p| {
+                    let rel = p.relationships.get(rel_id)?;
+                    let entity = rel.junction_entity_id.and_then(|id| p.entities.get(&id));
+                    Some((rel, entity.map(|e| e.name.clone())))
+                })
+                .filter(|(rel, _)| rel.is_many_to_many())
+                .map(|(_, name)| (*rel_id, name))
+        }
+        RelationshipDialogMode::Create { .. } => None,
+    };
+
     // Validation
     let validation_errors = form_state.read().validate();
     let is_valid = validation_errors.is_empty();
@@ -288,30 +356,25 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
 
                 match &mode {
                     RelationshipDialogMode::Create { .. } => {
-                        if let Some(project) = &mut app_state.project {
-                            // Use create_relationship_with_fk for auto FK field generation
-                            match project.create_relationship_with_fk(relationship) {
-                                Ok((rel_id, fk_field_id)) => {
-                                    app_state.is_dirty = true;
-                                    if let Some(fk_id) = fk_field_id {
-                                        tracing::info!(
-                                            "Created relationship {} with FK field {}",
-                                            rel_id,
-                                            fk_id
-                                        );
-                                    } else {
-                                        tracing::info!(
-                                            "Created relationship {} (no FK - M:N or existing)",
-                                            rel_id
-                                        );
-                                    }
-                                }
-                                Err(e) => {
-                                    tracing::error!("Failed to create relationship: {}", e);
-                                    errors
-                                        .set(vec![format!("Failed to create relationship: {}", e)]);
-                                    return;
-                                }
+                        // Adds the FK field the relationship implies
+                        match app_state.create_relationship(relationship) {
+                            Ok((rel_id, Some(fk_id))) => {
+                                tracing::info!(
+                                    "Created relationship {} with FK field {}",
+                                    rel_id,
+                                    fk_id
+                                );
+                            }
+                            Ok((rel_id, None)) => {
+                                tracing::info!(
+                                    "Created relationship {} (no FK - M:N, polymorphic or existing)",
+                                    rel_id
+                                );
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to create relationship: {}", e);
+                                errors.set(vec![format!("Failed to create relationship: {}", e)]);
+                                return;
                             }
                         }
                     }
@@ -323,7 +386,7 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                                 updated.id = *rel_id;
                                 updated.created_at = existing.created_at;
                                 *existing = updated;
-                                app_state.is_dirty = true;
+                                app_state.mark_dirty();
                             }
                         }
                     }
@@ -372,6 +435,11 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
             label: "Many-to-Many (N:M)".to_string(),
             disabled: false,
         },
+        SelectOption {
+            value: "polymorphic".to_string(),
+            label: "Polymorphic (N:1 to one of several)".to_string(),
+            disabled: false,
+        },
     ];
 
     // Referential action options
@@ -445,6 +513,41 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
         }
     };
 
+    // Derived accessor names, shown until the user types their own
+    let suggested = form_state.read().suggested_accessors(&entities);
+    let entity_name = |id: Uuid| {
+        entities
+            .iter()
+            .find(|e| e.id == id)
+            .map(|e| e.name.clone())
+            .unwrap_or_default()
+    };
+    let (accessor_placeholder, accessor_help, inverse_placeholder, inverse_help) = match &suggested
+    {
+        Some(names) => {
+            let holder = entity_name(names.accessor_entity_id);
+            let referenced = entity_name(names.inverse_entity_id);
+            (
+                names.accessor.clone(),
+                format!(
+                    "Accessor on {} for the related {} (leave blank for '{}')",
+                    holder, referenced, names.accessor
+                ),
+                names.inverse.clone(),
+                format!(
+                    "Accessor on {} listing the {} rows that point at it (leave blank for '{}')",
+                    referenced, holder, names.inverse
+                ),
+            )
+        }
+        None => (
+            "e.g., author, owner".to_string(),
+            "Accessor on the entity holding the foreign key".to_string(),
+            "e.g., posts, orders, items".to_string(),
+            "Accessor on the referenced entity listing rows that point at it".to_string(),
+        ),
+    };
+
     let to_field_options: Vec<SelectOption> = {
         let state = APP_STATE.read();
         if let (Some(project), Some(to_id)) = (&state.project, form_state.read().to_entity_id) {
@@ -478,18 +581,7 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                 h2 {
                     class: "text-xl font-semibold text-white flex items-center gap-2",
                     // Relationship icon
-                    svg {
-                        class: "w-6 h-6 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1",
-                        }
-                    }
+                    Icon { name: IconName::Link, class: "w-6 h-6 text-indigo-400" }
                     "{props.mode.title()}"
                 }
 
@@ -497,18 +589,7 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                     class: "text-slate-400 hover:text-white transition-colors",
                     onclick: close_dialog,
                     // Close icon
-                    svg {
-                        class: "w-6 h-6",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M6 18L18 6M6 6l12 12",
-                        }
-                    }
+                    Icon { name: IconName::Close, class: "w-6 h-6" }
                 }
             }
 
@@ -614,7 +695,7 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                                 let id = Uuid::parse_str(&v).ok();
                                 form_state.write().to_entity_id = id;
 
-                                // Auto-update relationship name and inverse
+                                // Auto-update relationship name
                                 if let Some(to_id) = id {
                                     let state = APP_STATE.read();
                                     if let Some(project) = &state.project {
@@ -629,12 +710,6 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                                         if form_state.read().name.is_empty() {
                                             form_state.write().name = format!("{}{}", from_name, to_name);
                                         }
-
-                                        // Auto-set inverse name
-                                        if form_state.read().inverse_name.is_empty() {
-                                            let mut fs = form_state.write();
-                                            fs.inverse_name = to_lowercase_first(&from_name);
-                                        }
                                     }
                                 }
                             },
@@ -654,8 +729,9 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
 
                     // Relationship type description
                     div {
-                        class: "p-3 bg-slate-700/50 rounded-lg text-sm",
-                        {relationship_type_description(&form_state.read().relation_type)}
+                        class: "p-3 bg-slate-700/50 rounded-lg text-sm flex items-start justify-between gap-2",
+                        span { {relationship_type_description(&form_state.read().relation_type)} }
+                        HelpButton { topic: HelpTopic::RelationshipTypes }
                     }
 
                     // Required checkbox
@@ -667,6 +743,59 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                         },
                     }
 
+                    // Nested create (one-to-many only)
+                    if matches!(form_state.read().relation_type, RelationType::OneToMany) {
+                        Checkbox {
+                            label: "Create children inline with the parent (nested create payload)",
+                            checked: form_state.read().nested_create,
+                            on_change: move |v: bool| {
+                                form_state.write().nested_create = v;
+                            },
+                        }
+                    }
+
+                    // Association name (polymorphic only)
+                    if matches!(form_state.read().relation_type, RelationType::Polymorphic { .. }) {
+                        TextInput {
+                            label: "Association Name",
+                            value: form_state.read().association.clone(),
+                            placeholder: "e.g., commentable, attachable",
+                            help_text: "The source entity stores the target's entity in <name>_type and its ID in <name>_id. Add one polymorphic relationship per target.",
+                            on_change: move |v: String| {
+                                form_state.write().association = v;
+                            },
+                        }
+                    }
+
+                    // Junction entity (saved many-to-many only)
+                    if let Some((rel_id, junction_name)) = junction.clone() {
+                        div {
+                            class: "p-3 bg-slate-700/50 rounded-lg text-sm flex items-center justify-between gap-2",
+                            if let Some(name) = junction_name {
+                                span { "The junction table is modelled by the " strong { "{name}" } " entity." }
+                            } else {
+                                span { "Promote the junction table to an entity to give links fields of their own and generate link endpoints." }
+                                button {
+                                    class: "px-3 py-1.5 bg-slate-600 hover:bg-slate-500 text-white rounded-lg transition-colors whitespace-nowrap",
+                                    onclick: move |_| {
+                                        let mut app_state = APP_STATE.write();
+                                        match app_state.promote_junction(rel_id) {
+                                            Ok(junction_id) => {
+                                                tracing::info!("Promoted junction of {} to entity {}", rel_id, junction_id);
+                                                app_state.save_to_history("Promote junction to entity");
+                                                app_state.ui.close_dialog();
+                                            }
+                                            Err(e) => {
+                                                errors.set(vec![format!("Failed to promote junction: {}", e)]);
+                                            }
+                                        }
+                                    },
+                                    "Promote to entity"
+                                }
+                            }
+                        }
+                    }
+
                     // Description
                     TextArea {
                         label: "Description",
@@ -728,12 +857,22 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                             },
                         }
 
-                        // Inverse name
+                        // Accessor names (blank = derived suggestion)
                         TextInput {
-                            label: "Inverse Relationship Name",
+                            label: "Accessor Name",
+                            value: form_state.read().accessor_name.clone(),
+                            placeholder: accessor_placeholder,
+                            help_text: accessor_help,
+                            on_change: move |v: String| {
+                                form_state.write().accessor_name = v;
+                            },
+                        }
+
+                        TextInput {
+                            label: "Inverse Accessor Name",
                             value: form_state.read().inverse_name.clone(),
-                            placeholder: "e.g., posts, orders, items",
-                            help_text: "Name for the reverse relationship (for navigation from target to source)",
+                            placeholder: inverse_placeholder,
+                            help_text: inverse_help,
                             on_change: move |v: String| {
                                 form_state.write().inverse_name = v;
                             },
@@ -752,6 +891,7 @@ pub fn RelationshipDialog(props: RelationshipDialogProps) -> Element {
                             p {
                                 "Referential actions determine what happens when the referenced row "
                                 "is deleted or updated. Choose carefully based on your data integrity needs."
+                                HelpButton { topic: HelpTopic::ReferentialActions }
                             }
                         }
 
@@ -1066,6 +1206,7 @@ fn relation_type_to_string(rt: &RelationType) -> String {
         RelationType::OneToMany => "one_to_many".to_string(),
         RelationType::ManyToOne => "many_to_one".to_string(),
         RelationType::ManyToMany { .. } => "many_to_many".to_string(),
+        RelationType::Polymorphic { .. } => "polymorphic".to_string(),
     }
 }
 
@@ -1078,6 +1219,9 @@ fn string_to_relation_type(s: &str) -> RelationType {
         "many_to_many" => RelationType::ManyToMany {
             junction_table: String::new(),
         },
+        "polymorphic" => RelationType::Polymorphic {
+            discriminator: String::new(),
+        },
         _ => RelationType::OneToMany,
     }
 }
@@ -1097,6 +1241,9 @@ fn relationship_type_description(rt: &RelationType) -> &'static str {
         RelationType::ManyToMany { .. } => {
             "Records in both entities can be associated with multiple records in the other. Requires a junction table. Example: Students and Courses."
         }
+        RelationType::Polymorphic { .. } => {
+            "Each record in the source entity points at one record of any of several target entities, named by a type and an ID field. No foreign key is enforced. Example: Comments on Posts or Photos."
+        }
     }
 }
 
@@ -1166,13 +1313,10 @@ fn string_to_port_position(s: &str) -> PortPosition {
     }
 }
 
-/// Convert first character to lowercase
-fn to_lowercase_first(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(c) => c.to_lowercase().collect::<String>() + chars.as_str(),
-    }
+/// `None` for a blank form value
+fn non_blank(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
 }
 
 // ============================================================================
@@ -1214,6 +1358,10 @@ mod tests {
             string_to_relation_type("many_to_many"),
             RelationType::ManyToMany { .. }
         ));
+        assert!(matches!(
+            string_to_relation_type("polymorphic"),
+            RelationType::Polymorphic { .. }
+        ));
     }
 
     #[test]
@@ -1264,15 +1412,6 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn test_to_lowercase_first() {
-        assert_eq!(to_lowercase_first("User"), "user");
-        assert_eq!(to_lowercase_first("Post"), "post");
-        assert_eq!(to_lowercase_first(""), "");
-        assert_eq!(to_lowercase_first("a"), "a");
-        assert_eq!(to_lowercase_first("ABC"), "aBC");
-    }
-
     #[test]
     fn test_form_state_default() {
         let state = RelationshipFormState::default();
@@ -1282,6 +1421,109 @@ mod tests {
         assert!(matches!(state.relation_type, RelationType::OneToMany));
         assert_eq!(state.to_field, "id");
         assert!(state.required);
+        assert!(!state.nested_create);
+    }
+
+    #[test]
+    fn test_form_state_nested_create_only_for_one_to_many() {
+        let mut state = RelationshipFormState::default();
+        state.from_entity_id = Some(Uuid::new_v4());
+        state.to_entity_id = Some(Uuid::new_v4());
+        state.nested_create = true;
+
+        let rel = state.to_relationship().unwrap();
+        assert!(rel.nested_create);
+        assert!(RelationshipFormState::from_relationship(&rel).nested_create);
+
+        state.relation_type = RelationType::ManyToOne;
+        assert!(!state.to_relationship().unwrap().nested_create);
+    }
+
+    #[test]
+    fn test_form_state_keeps_junction_entity() {
+        let junction = Uuid::new_v4();
+        let rel = Relationship::many_to_many(Uuid::new_v4(), Uuid::new_v4(), "enrollments")
+            .with_junction_entity(junction);
+        let mut state = RelationshipFormState::from_relationship(&rel);
+        assert_eq!(
+            state.to_relationship().unwrap().junction_entity_id,
+            Some(junction)
+        );
+
+        state.relation_type = RelationType::ManyToOne;
+        assert_eq!(state.to_relationship().unwrap().junction_entity_id, None);
+    }
+
+    #[test]
+    fn test_form_state_polymorphic_association() {
+        let mut state = RelationshipFormState {
+            name: "CommentPost".to_string(),
+            from_entity_id: Some(Uuid::new_v4()),
+            to_entity_id: Some(Uuid::new_v4()),
+            relation_type: string_to_relation_type("polymorphic"),
+            ..Default::default()
+        };
+        assert!(!state.is_valid());
+
+        state.association = "commentable".to_string();
+        assert!(state.is_valid());
+        let rel = state.to_relationship().unwrap();
+        assert_eq!(rel.discriminator(), Some("commentable_type"));
+        assert_eq!(rel.from_field, "commentable_id");
+
+        // Editing keeps the saved discriminator unless the name changes
+        let mut rel = rel;
+        rel.relation_type = RelationType::Polymorphic {
+            discriminator: "kind".to_string(),
+        };
+        let mut state = RelationshipFormState::from_relationship(&rel);
+        assert_eq!(state.association, "kind");
+        assert_eq!(
+            state.to_relationship().unwrap().discriminator(),
+            Some("kind")
+        );
+        state.association = "attachable".to_string();
+        let rel = state.to_relationship().unwrap();
+        assert_eq!(rel.discriminator(), Some("attachable_type"));
+        assert_eq!(rel.from_field, "attachable_id");
+    }
+
+    #[test]
+    fn test_form_state_accessor_names() {
+        let user = Entity::new("User");
+        let post = Entity::new("Post");
+        let mut state = RelationshipFormState::default();
+        state.from_entity_id = Some(post.id);
+        state.to_entity_id = Some(user.id);
+        state.relation_type = RelationType::ManyToOne;
+        state.from_field = "author_id".to_string();
+        let entities = vec![user.clone(), post.clone()];
+
+        let suggested = state.suggested_accessors(&entities).unwrap();
+        assert_eq!(suggested.accessor, "author");
+        assert_eq!(suggested.accessor_entity_id, post.id);
+        assert_eq!(suggested.inverse, "posts");
+        assert_eq!(suggested.inverse_entity_id, user.id);
+
+        // Blank names are left for the generator to derive
+        let rel = state.to_relationship().unwrap();
+        assert!(rel.accessor_name.is_none());
+        assert!(rel.inverse_name.is_none());
+
+        state.accessor_name = " writer ".to_string();
+        state.inverse_name = "articles".to_string();
+        let rel = state.to_relationship().unwrap();
+        assert_eq!(rel.accessor_name.as_deref(), Some("writer"));
+        assert_eq!(rel.inverse_name.as_deref(), Some("articles"));
+        let restored = RelationshipFormState::from_relationship(&rel);
+        assert_eq!(restored.accessor_name, "writer");
+        assert_eq!(restored.inverse_name, "articles");
+
+        // Suggestions ignore what was typed
+        assert_eq!(
+            state.suggested_accessors(&entities).unwrap().accessor,
+            "author"
+        );
     }
 
     #[test]
@@ -26,6 +26,8 @@ use dioxus::prelude::*;
 use imortal_ir::{CrudOperation, EndpointGroup, EndpointSecurity, OperationType};
 use uuid::Uuid;
 
+use crate::components::icon::{Icon, IconName};
+
 // ============================================================================
 // Endpoint Card Component
 // ============================================================================
@@ -195,18 +197,7 @@ pub fn EndpointCard(props: EndpointCardProps) -> Element {
                                     handler.call(endpoint_id);
                                 }
                             },
-                            svg {
-                                class: "w-4 h-4",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z",
-                                }
-                            }
+                            Icon { name: IconName::Pencil }
                         }
 
                         // Delete button
@@ -219,18 +210,7 @@ pub fn EndpointCard(props: EndpointCardProps) -> Element {
                                     handler.call(endpoint_id);
                                 }
                             },
-                            svg {
-                                class: "w-4 h-4",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16",
-                                }
-                            }
+                            Icon { name: IconName::Trash }
                         }
                     }
                 }
@@ -277,18 +257,7 @@ fn EndpointCardHeader(props: EndpointCardHeaderProps) -> Element {
                                 "bg-slate-700 text-slate-500"
                             }
                         ),
-                        svg {
-                            class: "w-4 h-4",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                            }
-                        }
+                        Icon { name: IconName::Terminal }
                     }
 
                     // Entity name
@@ -314,18 +283,7 @@ fn EndpointCardHeader(props: EndpointCardHeaderProps) -> Element {
                             class: "flex items-center gap-1 px-1.5 py-0.5 bg-amber-900/30 text-amber-400 rounded text-xs",
                             title: "Authentication required",
                             // Lock icon
-                            svg {
-                                class: "w-3 h-3",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M12 15v2m-6 4h12a2 2 0 002-2v-6a2 2 0 00-2-2H6a2 2 0 00-2 2v6a2 2 0 002 2zm10-10V7a4 4 0 00-8 0v4h8z",
-                                }
-                            }
+                            Icon { name: IconName::Lock, class: "w-3 h-3" }
                             "Auth"
                         }
                     }
@@ -432,18 +390,7 @@ fn OperationRow(props: OperationRowProps) -> Element {
                     props.on_toggle.call(!op_enabled);
                 },
                 if op_enabled {
-                    svg {
-                        class: "w-3 h-3 text-white",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "3",
-                            d: "M5 13l4 4L19 7",
-                        }
-                    }
+                    Icon { name: IconName::Check, class: "w-3 h-3 text-white", stroke_width: 3.0 }
                 }
             }
 
@@ -474,18 +421,7 @@ fn OperationRow(props: OperationRowProps) -> Element {
                     span {
                         class: "w-4 h-4 flex items-center justify-center text-amber-400",
                         title: "{roles_display}",
-                        svg {
-                            class: "w-3.5 h-3.5",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M12 15v2m-6 4h12a2 2 0 002-2v-6a2 2 0 00-2-2H6a2 2 0 00-2 2v6a2 2 0 002 2zm10-10V7a4 4 0 00-8 0v4h8z",
-                            }
-                        }
+                        Icon { name: IconName::Lock, class: "w-3.5 h-3.5" }
                     }
                 }
 
@@ -496,18 +432,7 @@ fn OperationRow(props: OperationRowProps) -> Element {
                             span {
                                 class: "w-4 h-4 flex items-center justify-center text-cyan-400",
                                 title: "{rate_limit_display}",
-                                svg {
-                                    class: "w-3.5 h-3.5",
-                                    fill: "none",
-                                    stroke: "currentColor",
-                                    view_box: "0 0 24 24",
-                                    path {
-                                        stroke_linecap: "round",
-                                        stroke_linejoin: "round",
-                                        stroke_width: "2",
-                                        d: "M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z",
-                                    }
-                                }
+                                Icon { name: IconName::Clock, class: "w-3.5 h-3.5" }
                             }
                         }
                     }
@@ -670,32 +595,10 @@ fn EndpointCardFooter(props: EndpointCardFooterProps) -> Element {
                     // Icon
                     match props.security.level {
                         SecurityLevel::Open => rsx! {
-                            svg {
-                                class: "w-3 h-3",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M3.055 11H5a2 2 0 012 2v1a2 2 0 002 2 2 2 0 012 2v2.945M8 3.935V5.5A2.5 2.5 0 0010.5 8h.5a2 2 0 012 2 2 2 0 104 0 2 2 0 012-2h1.064M15 20.488V18a2 2 0 012-2h3.064M21 12a9 9 0 11-18 0 9 9 0 0118 0z",
-                                }
-                            }
+                            Icon { name: IconName::Globe, class: "w-3 h-3" }
                         },
                         _ => rsx! {
-                            svg {
-                                class: "w-3 h-3",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M9 12l2 2 4-4m5.618-4.016A11.955 11.955 0 0112 2.944a11.955 11.955 0 01-8.618 3.04A12.02 12.02 0 003 9c0 5.591 3.824 10.29 9 11.622 5.176-1.332 9-6.03 9-11.622 0-1.042-.133-2.052-.382-3.016z",
-                                }
-                            }
+                            Icon { name: IconName::Shield, class: "w-3 h-3" }
                         },
                     }
 
@@ -706,18 +609,7 @@ fn EndpointCardFooter(props: EndpointCardFooterProps) -> Element {
                 if props.has_rate_limit {
                     span {
                         class: "flex items-center gap-1 px-2 py-0.5 bg-cyan-900/30 text-cyan-400 rounded text-xs",
-                        svg {
-                            class: "w-3 h-3",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z",
-                            }
-                        }
+                        Icon { name: IconName::Clock, class: "w-3 h-3" }
                         "Rate Limited"
                     }
                 }
@@ -781,18 +673,7 @@ pub fn GenerateEndpointsCard(props: GenerateEndpointsCardProps) -> Element {
 
             div {
                 class: "w-12 h-12 mx-auto mb-3 rounded-full bg-indigo-600/20 flex items-center justify-center",
-                svg {
-                    class: "w-6 h-6 text-indigo-400",
-                    fill: "none",
-                    stroke: "currentColor",
-                    view_box: "0 0 24 24",
-                    path {
-                        stroke_linecap: "round",
-                        stroke_linejoin: "round",
-                        stroke_width: "2",
-                        d: "M12 6v6m0 0v6m0-6h6m-6 0H6",
-                    }
-                }
+                Icon { name: IconName::PlusSmall, class: "w-6 h-6 text-indigo-400" }
             }
 
             h4 {
@@ -6,12 +6,16 @@
 //! - Entity name and icon
 //! - Table name (database)
 //! - List of fields with their types
+//! - Included field sets, one read-only row each
 //! - Connection ports for relationships
 //! - Selection and drag states
+//! - Endpoint coverage badge (none/partial/full CRUD, secured or not)
 //!
 //! ## Features
 //!
 //! - Collapsible to show/hide fields
+//! - Semantic zoom: names only when zoomed far out, key fields at medium
+//!   zoom, the full card up close
 //! - Draggable positioning on canvas
 //! - Selection highlighting
 //! - Hover states
@@ -22,9 +26,14 @@
 use dioxus::prelude::*;
 use imortal_core::types::{EntityId, FieldId, Position};
 use imortal_ir::entity::Entity;
+use imortal_ir::field::Field;
+use imortal_ir::{FieldSet, OperationType, ProjectGraph};
+use uuid::Uuid;
 
 use crate::components::field_row::FieldList;
 use crate::components::port::{Port, PortClickInfo, PortType};
+use crate::diagnostics;
+use crate::state::ZoomDetail;
 
 // ============================================================================
 // Constants
@@ -48,6 +57,9 @@ pub const PORT_SIZE: f64 = 12.0;
 /// Maximum visible fields before scrolling
 pub const MAX_VISIBLE_FIELDS: usize = 8;
 
+/// Card height when only the entity name is shown
+pub const NAME_ONLY_HEIGHT: f64 = 64.0;
+
 // ============================================================================
 // Entity Card Component
 // ============================================================================
@@ -62,6 +74,14 @@ pub struct EntityCardProps {
     #[props(default = 1.0)]
     pub zoom: f64,
 
+    /// How much of the card to draw at the current zoom
+    #[props(default = ZoomDetail::Full)]
+    pub detail: ZoomDetail,
+
+    /// Field sets the entity includes, in order
+    #[props(default)]
+    pub field_sets: Vec<FieldSet>,
+
     /// Whether this entity is currently selected
     #[props(default = false)]
     pub selected: bool,
@@ -107,6 +127,10 @@ pub struct EntityCardProps {
     #[props(default)]
     pub on_field_double_click: EventHandler<(EntityId, FieldId)>,
 
+    /// Callback for a field's context menu
+    #[props(default)]
+    pub on_field_context_menu: EventHandler<(EntityId, FieldId, MouseEvent)>,
+
     /// Callback when add field button is clicked
     #[props(default)]
     pub on_add_field: EventHandler<EntityId>,
@@ -130,17 +154,27 @@ pub struct EntityCardProps {
     /// Callback when collapse/expand is toggled
     #[props(default)]
     pub on_toggle_collapse: EventHandler<EntityId>,
+
+    /// Endpoint coverage shown as a header badge (hidden if `None`)
+    #[props(default)]
+    pub coverage: Option<EndpointCoverage>,
+
+    /// Callback when the coverage badge is clicked
+    #[props(default)]
+    pub on_coverage_click: EventHandler<EntityId>,
 }
 
 /// Entity card component for the visual canvas
 #[component]
 pub fn EntityCard(props: EntityCardProps) -> Element {
+    diagnostics::record_render("entity_card");
     let entity = &props.entity;
     let entity_id = entity.id;
     let selected = props.selected;
     let dragging = props.dragging;
     let collapsed = entity.collapsed;
     let interactive = props.interactive;
+    let detail = props.detail;
 
     // Calculate position style
     let x = entity.position.x;
@@ -151,12 +185,7 @@ pub fn EntityCard(props: EntityCardProps) -> Element {
     let card_class = build_card_class(selected, dragging);
 
     // Calculate height based on content
-    let content_height = if collapsed {
-        CARD_HEADER_HEIGHT + 8.0 // Just header with padding
-    } else {
-        let field_count = entity.fields.len().min(MAX_VISIBLE_FIELDS);
-        CARD_HEADER_HEIGHT + (field_count as f64 * FIELD_ROW_HEIGHT) + CARD_FOOTER_HEIGHT + 8.0
-    };
+    let content_height = card_height(entity, detail);
 
     rsx! {
         div {
@@ -235,34 +264,50 @@ pub fn EntityCard(props: EntityCardProps) -> Element {
             }
 
             // Card content
-            div {
-                class: "relative z-10",
-
-                // Header
-                EntityCardHeader {
-                    entity: entity.clone(),
-                    selected: selected,
-                    collapsed: collapsed,
-                    on_toggle_collapse: move |_| props.on_toggle_collapse.call(entity_id),
+            if detail == ZoomDetail::Names {
+                EntityCardName {
+                    name: entity.name.clone(),
+                    field_count: entity.fields.len(),
                 }
+            } else {
+                div {
+                    class: "relative z-10",
 
-                // Body (fields)
-                if !collapsed {
-                    EntityCardBody {
+                    // Header
+                    EntityCardHeader {
                         entity: entity.clone(),
-                        selected_field: props.selected_field,
-                        on_field_click: move |field_id| {
-                            props.on_field_select.call((entity_id, field_id));
-                        },
-                        on_field_double_click: move |field_id| {
-                            props.on_field_double_click.call((entity_id, field_id));
-                        },
+                        selected: selected,
+                        collapsed: collapsed,
+                        coverage: props.coverage,
+                        on_toggle_collapse: move |_| props.on_toggle_collapse.call(entity_id),
+                        on_coverage_click: move |_| props.on_coverage_click.call(entity_id),
+                    }
+
+                    // Body (fields)
+                    if !collapsed {
+                        EntityCardBody {
+                            entity: entity.clone(),
+                            field_sets: if detail == ZoomDetail::Full { props.field_sets.clone() } else { Vec::new() },
+                            key_fields_only: detail == ZoomDetail::KeyFields,
+                            selected_field: props.selected_field,
+                            on_field_click: move |field_id| {
+                                props.on_field_select.call((entity_id, field_id));
+                            },
+                            on_field_double_click: move |field_id| {
+                                props.on_field_double_click.call((entity_id, field_id));
+                            },
+                            on_field_context_menu: move |(field_id, e)| {
+                                props.on_field_context_menu.call((entity_id, field_id, e));
+                            },
+                        }
                     }
 
                     // Footer (add field button)
-                    EntityCardFooter {
-                        entity_id: entity_id,
-                        on_add_field: move |id| props.on_add_field.call(id),
+                    if !collapsed && detail == ZoomDetail::Full {
+                        EntityCardFooter {
+                            entity_id: entity_id,
+                            on_add_field: move |id| props.on_add_field.call(id),
+                        }
                     }
                 }
             }
@@ -274,6 +319,33 @@ pub fn EntityCard(props: EntityCardProps) -> Element {
 // Helper Functions
 // ============================================================================
 
+/// Whether a field is shown on cards at the key fields zoom level
+pub fn is_key_field(field: &Field) -> bool {
+    field.is_primary_key || field.is_foreign_key || field.unique
+}
+
+/// Height of an entity's card at a zoom detail level
+pub fn card_height(entity: &Entity, detail: ZoomDetail) -> f64 {
+    if detail == ZoomDetail::Names {
+        return NAME_ONLY_HEIGHT;
+    }
+    if entity.collapsed {
+        return CARD_HEADER_HEIGHT + 8.0; // Just header with padding
+    }
+
+    if detail == ZoomDetail::KeyFields {
+        let field_count = entity.fields.iter().filter(|f| is_key_field(f)).count();
+        CARD_HEADER_HEIGHT + (field_count.min(MAX_VISIBLE_FIELDS) as f64 * FIELD_ROW_HEIGHT) + 8.0
+    } else {
+        let field_count = entity.fields.len().min(MAX_VISIBLE_FIELDS);
+        let set_count = entity.field_sets.len();
+        CARD_HEADER_HEIGHT
+            + ((field_count + set_count) as f64 * FIELD_ROW_HEIGHT)
+            + CARD_FOOTER_HEIGHT
+            + 8.0
+    }
+}
+
 /// Build the CSS class string for the card
 fn build_card_class(selected: bool, dragging: bool) -> String {
     let mut classes = vec![
@@ -312,6 +384,146 @@ fn build_card_class(selected: bool, dragging: bool) -> String {
     classes.join(" ")
 }
 
+// ============================================================================
+// Endpoint Coverage
+// ============================================================================
+
+/// How much of the CRUD surface an entity's endpoint group exposes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageLevel {
+    /// No endpoint group, or the group is disabled
+    None,
+    /// Some operations are enabled
+    Partial,
+    /// All five operations are enabled
+    Full,
+}
+
+/// Endpoint coverage of one entity, as shown on its card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndpointCoverage {
+    /// The entity's endpoint group, if it has one
+    pub endpoint_id: Option<Uuid>,
+
+    /// Coverage level
+    pub level: CoverageLevel,
+
+    /// Number of enabled operations
+    pub enabled: usize,
+
+    /// Number of enabled operations that require authentication
+    pub secured: usize,
+}
+
+impl EndpointCoverage {
+    /// Coverage of an entity in a project.
+    ///
+    /// Operations only count as secured when the project has auth enabled.
+    pub fn of(project: &ProjectGraph, entity_id: EntityId) -> Self {
+        let Some(group) = project
+            .endpoints
+            .values()
+            .find(|ep| ep.entity_id == entity_id)
+        else {
+            return Self {
+                endpoint_id: None,
+                level: CoverageLevel::None,
+                enabled: 0,
+                secured: 0,
+            };
+        };
+
+        let enabled_ops: Vec<OperationType> = if group.enabled {
+            group
+                .enabled_operations()
+                .iter()
+                .map(|op| op.operation_type)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let secured = if project.config.auth.enabled {
+            enabled_ops
+                .iter()
+                .filter(|op| group.effective_security(**op).auth_required)
+                .count()
+        } else {
+            0
+        };
+        let level = match enabled_ops.len() {
+            0 => CoverageLevel::None,
+            n if n >= ALL_OPERATIONS => CoverageLevel::Full,
+            _ => CoverageLevel::Partial,
+        };
+
+        Self {
+            endpoint_id: Some(group.id),
+            level,
+            enabled: enabled_ops.len(),
+            secured,
+        }
+    }
+
+    /// Whether every enabled operation requires authentication
+    pub fn fully_secured(&self) -> bool {
+        self.enabled > 0 && self.secured == self.enabled
+    }
+
+    /// Short badge text, e.g. "No API", "3/5" or "CRUD"
+    pub fn label(&self) -> String {
+        match self.level {
+            CoverageLevel::None => "No API".to_string(),
+            CoverageLevel::Partial => format!("{}/{}", self.enabled, ALL_OPERATIONS),
+            CoverageLevel::Full => "CRUD".to_string(),
+        }
+    }
+
+    /// Lock icon for the security state (empty when nothing is enabled)
+    pub fn security_icon(&self) -> &'static str {
+        if self.enabled == 0 {
+            ""
+        } else if self.fully_secured() {
+            "🔒"
+        } else if self.secured > 0 {
+            "🔐"
+        } else {
+            "🔓"
+        }
+    }
+
+    /// Tooltip describing the coverage and what clicking does
+    pub fn tooltip(&self) -> String {
+        let coverage = match self.level {
+            CoverageLevel::None if self.endpoint_id.is_some() => {
+                "Endpoint group has no enabled operations".to_string()
+            }
+            CoverageLevel::None => "No endpoints".to_string(),
+            _ => format!(
+                "{} of {} operations enabled, {} secured",
+                self.enabled, ALL_OPERATIONS, self.secured
+            ),
+        };
+        let action = if self.endpoint_id.is_some() {
+            "open on the Endpoints page"
+        } else {
+            "create an endpoint group"
+        };
+        format!("{} — click to {}", coverage, action)
+    }
+
+    /// Badge colour classes: red for none, amber for partial, green for full
+    fn badge_class(&self) -> &'static str {
+        match self.level {
+            CoverageLevel::None => "bg-red-500/15 text-red-300 border-red-500/30",
+            CoverageLevel::Partial => "bg-amber-500/15 text-amber-300 border-amber-500/30",
+            CoverageLevel::Full => "bg-emerald-500/15 text-emerald-300 border-emerald-500/30",
+        }
+    }
+}
+
+/// Number of CRUD operations an endpoint group can expose
+const ALL_OPERATIONS: usize = 5;
+
 // ============================================================================
 // Entity Card Header
 // ============================================================================
@@ -330,8 +542,15 @@ struct EntityCardHeaderProps {
     #[props(default = false)]
     collapsed: bool,
 
+    /// Endpoint coverage badge
+    #[props(default)]
+    coverage: Option<EndpointCoverage>,
+
     /// Callback for collapse toggle
     on_toggle_collapse: EventHandler<()>,
+
+    /// Callback for the coverage badge
+    on_coverage_click: EventHandler<()>,
 }
 
 /// Header section of entity card
@@ -376,6 +595,23 @@ fn EntityCardHeader(props: EntityCardHeaderProps) -> Element {
                 }
             }
 
+            // Endpoint coverage
+            if let Some(coverage) = props.coverage {
+                button {
+                    class: "flex items-center gap-1 px-1.5 py-0.5 rounded border text-[10px] font-medium whitespace-nowrap hover:brightness-125 transition {coverage.badge_class()}",
+                    title: "{coverage.tooltip()}",
+                    onclick: move |e| {
+                        e.stop_propagation();
+                        props.on_coverage_click.call(());
+                    },
+                    onmousedown: move |e| e.stop_propagation(),
+                    span { "{coverage.label()}" }
+                    if !coverage.security_icon().is_empty() {
+                        span { "{coverage.security_icon()}" }
+                    }
+                }
+            }
+
             // Collapse toggle
             button {
                 class: "w-6 h-6 flex items-center justify-center rounded hover:bg-slate-600/50 text-slate-400 hover:text-slate-200 transition-colors",
@@ -394,6 +630,41 @@ fn EntityCardHeader(props: EntityCardHeaderProps) -> Element {
     }
 }
 
+// ============================================================================
+// Entity Card Name (far zoom)
+// ============================================================================
+
+/// Properties for EntityCardName
+#[derive(Props, Clone, PartialEq)]
+struct EntityCardNameProps {
+    /// Entity name
+    name: String,
+
+    /// Number of fields, shown as a hint of the card's size
+    field_count: usize,
+}
+
+/// Name-only card content shown when zoomed far out.
+///
+/// The text is drawn large because the whole layer is scaled down.
+#[component]
+fn EntityCardName(props: EntityCardNameProps) -> Element {
+    let height = NAME_ONLY_HEIGHT;
+
+    rsx! {
+        div {
+            class: "relative z-10 flex items-center justify-center px-4 bg-slate-700/50",
+            style: "height: {height}px;",
+            title: "{props.name} ({props.field_count} fields)",
+
+            span {
+                class: "text-3xl font-bold text-slate-100 truncate",
+                "{props.name}"
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Entity Card Body
 // ============================================================================
@@ -404,6 +675,14 @@ struct EntityCardBodyProps {
     /// The entity
     entity: Entity,
 
+    /// Show only primary, foreign and unique key fields
+    #[props(default = false)]
+    key_fields_only: bool,
+
+    /// Included field sets, shown after the entity's own fields
+    #[props(default)]
+    field_sets: Vec<FieldSet>,
+
     /// Selected field ID
     #[props(default)]
     selected_field: Option<FieldId>,
@@ -413,13 +692,21 @@ struct EntityCardBodyProps {
 
     /// Field double click callback
     on_field_double_click: EventHandler<FieldId>,
+
+    /// Field context menu callback
+    on_field_context_menu: EventHandler<(FieldId, MouseEvent)>,
 }
 
 /// Body section with fields list
 #[component]
 fn EntityCardBody(props: EntityCardBodyProps) -> Element {
     let entity = &props.entity;
-    let fields: Vec<_> = entity.sorted_fields().into_iter().cloned().collect();
+    let fields: Vec<_> = entity
+        .sorted_fields()
+        .into_iter()
+        .filter(|f| !props.key_fields_only || is_key_field(f))
+        .cloned()
+        .collect();
 
     rsx! {
         div {
@@ -432,11 +719,47 @@ fn EntityCardBody(props: EntityCardBodyProps) -> Element {
                 max_visible: MAX_VISIBLE_FIELDS,
                 on_field_click: move |id| props.on_field_click.call(id),
                 on_field_double_click: move |id| props.on_field_double_click.call(id),
+                on_field_context_menu: move |(id, e)| props.on_field_context_menu.call((id, e)),
+            }
+
+            for set in props.field_sets.iter() {
+                FieldSetRow { key: "{set.id}", set: set.clone() }
             }
         }
     }
 }
 
+/// Properties for FieldSetRow
+#[derive(Props, Clone, PartialEq)]
+struct FieldSetRowProps {
+    /// The included set
+    set: FieldSet,
+}
+
+/// An included field set: its name and fields, edited in the set rather
+/// than on the card
+#[component]
+fn FieldSetRow(props: FieldSetRowProps) -> Element {
+    let set = &props.set;
+    let names = set.field_names().join(", ");
+    let tooltip = set
+        .fields
+        .iter()
+        .map(|f| format!("{}: {}", f.name, f.data_type))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    rsx! {
+        div {
+            class: "entity-card-field-set flex items-center gap-2 px-3 h-8 text-xs border-t border-dashed border-slate-700 cursor-default",
+            title: "{set.name}\n{tooltip}",
+            span { class: "text-indigo-400", "⧉" }
+            span { class: "font-medium text-slate-300", "{set.name}" }
+            span { class: "font-mono text-slate-500 truncate", "{names}" }
+        }
+    }
+}
+
 // ============================================================================
 // Entity Card Footer
 // ============================================================================
@@ -609,6 +932,118 @@ mod tests {
         assert!(class.contains("opacity-90"));
     }
 
+    #[test]
+    fn test_card_height_by_detail() {
+        use imortal_core::DataType;
+
+        let mut entity = Entity::new("User");
+        entity.add_field(Field::new("email", DataType::String).unique());
+        entity.add_field(Field::new("name", DataType::String));
+        entity.add_field(Field::new("bio", DataType::Text));
+
+        let full = card_height(&entity, ZoomDetail::Full);
+        let key_fields = card_height(&entity, ZoomDetail::KeyFields);
+        let names = card_height(&entity, ZoomDetail::Names);
+        assert!(names < key_fields && key_fields < full);
+        // id and email
+        assert_eq!(
+            key_fields,
+            CARD_HEADER_HEIGHT + 2.0 * FIELD_ROW_HEIGHT + 8.0
+        );
+
+        // Each included field set takes one row at full detail
+        entity.include_field_set(uuid::Uuid::new_v4());
+        assert_eq!(
+            card_height(&entity, ZoomDetail::Full),
+            full + FIELD_ROW_HEIGHT
+        );
+        assert_eq!(card_height(&entity, ZoomDetail::KeyFields), key_fields);
+
+        entity.collapsed = true;
+        assert_eq!(card_height(&entity, ZoomDetail::Names), NAME_ONLY_HEIGHT);
+        assert!(card_height(&entity, ZoomDetail::Full) < full);
+    }
+
+    fn coverage_project() -> (ProjectGraph, EntityId) {
+        let mut project = ProjectGraph::new("shop");
+        let product = Entity::new("Product");
+        let id = product.id;
+        project.add_entity(product);
+        (project, id)
+    }
+
+    #[test]
+    fn test_coverage_without_endpoint_group() {
+        let (project, id) = coverage_project();
+        let coverage = EndpointCoverage::of(&project, id);
+
+        assert_eq!(coverage.level, CoverageLevel::None);
+        assert_eq!(coverage.endpoint_id, None);
+        assert_eq!(coverage.label(), "No API");
+        assert_eq!(coverage.security_icon(), "");
+        assert!(coverage.tooltip().contains("create an endpoint group"));
+    }
+
+    #[test]
+    fn test_coverage_full_crud_secured() {
+        use imortal_ir::{EndpointGroup, EndpointSecurity};
+
+        let (mut project, id) = coverage_project();
+        let mut group = EndpointGroup::new(id, "Product");
+        group.global_security = EndpointSecurity::authenticated();
+        let group_id = group.id;
+        project.add_endpoint(group);
+
+        let coverage = EndpointCoverage::of(&project, id);
+        assert_eq!(coverage.level, CoverageLevel::Full);
+        assert_eq!(coverage.endpoint_id, Some(group_id));
+        assert_eq!(coverage.label(), "CRUD");
+        assert!(coverage.fully_secured());
+        assert_eq!(coverage.security_icon(), "🔒");
+    }
+
+    #[test]
+    fn test_coverage_partial_and_open() {
+        use imortal_ir::{EndpointGroup, EndpointSecurity};
+
+        let (mut project, id) = coverage_project();
+        let mut group = EndpointGroup::new(id, "Product").read_only();
+        group.global_security = EndpointSecurity::open();
+        project.add_endpoint(group);
+
+        let coverage = EndpointCoverage::of(&project, id);
+        assert_eq!(coverage.level, CoverageLevel::Partial);
+        assert_eq!(coverage.label(), "2/5");
+        assert_eq!(coverage.secured, 0);
+        assert_eq!(coverage.security_icon(), "🔓");
+    }
+
+    #[test]
+    fn test_coverage_ignores_security_when_auth_disabled() {
+        use imortal_ir::{AuthConfig, EndpointGroup, EndpointSecurity};
+
+        let (mut project, id) = coverage_project();
+        project.config.auth = AuthConfig::none();
+        let mut group = EndpointGroup::new(id, "Product");
+        group.global_security = EndpointSecurity::authenticated();
+        project.add_endpoint(group);
+
+        assert_eq!(EndpointCoverage::of(&project, id).secured, 0);
+    }
+
+    #[test]
+    fn test_coverage_of_disabled_group() {
+        use imortal_ir::EndpointGroup;
+
+        let (mut project, id) = coverage_project();
+        project.add_endpoint(EndpointGroup::new(id, "Product").disabled());
+
+        let coverage = EndpointCoverage::of(&project, id);
+        assert_eq!(coverage.level, CoverageLevel::None);
+        assert!(coverage.endpoint_id.is_some());
+        assert!(coverage.tooltip().contains("no enabled operations"));
+    }
+
     #[test]
     fn test_constants() {
         assert!(CARD_WIDTH > 0.0);
@@ -0,0 +1,251 @@
+//! # Entity List Component
+//!
+//! Collapsible sidebar beside the canvas listing every entity alphabetically,
+//! for finding entities once a project outgrows the screen. Typing filters the
+//! list by name; clicking an entry selects it and pans the canvas to center
+//! its card.
+//!
+//! Entities can be pinned with the star button. Pinned entities are listed
+//! first, in the order they were pinned, and are saved with the project.
+
+use dioxus::prelude::*;
+use imortal_ir::ProjectGraph;
+use uuid::Uuid;
+
+use crate::components::inputs::TextInput;
+use crate::state::APP_STATE;
+
+// ============================================================================
+// Rows
+// ============================================================================
+
+/// An entity as listed in the sidebar
+#[derive(Debug, Clone, PartialEq)]
+struct EntityRow {
+    id: Uuid,
+    name: String,
+    field_count: usize,
+    pinned: bool,
+}
+
+/// The pinned and the remaining entities whose name contains `query`
+/// (ignoring case): pinned in pin order, the rest alphabetically.
+fn entity_rows(project: &ProjectGraph, query: &str) -> (Vec<EntityRow>, Vec<EntityRow>) {
+    let query = query.trim().to_lowercase();
+    let row = |entity: &imortal_ir::Entity| EntityRow {
+        id: entity.id,
+        name: entity.name.clone(),
+        field_count: entity.fields.len(),
+        pinned: project.is_favorite(entity.id),
+    };
+    let matches = |name: &str| query.is_empty() || name.to_lowercase().contains(&query);
+
+    let pinned = project
+        .favorite_entities()
+        .into_iter()
+        .filter(|e| matches(&e.name))
+        .map(row)
+        .collect();
+
+    let mut others: Vec<EntityRow> = project
+        .entities
+        .values()
+        .filter(|e| !project.is_favorite(e.id) && matches(&e.name))
+        .map(row)
+        .collect();
+    others.sort_by(|a, b| {
+        a.name
+            .to_lowercase()
+            .cmp(&b.name.to_lowercase())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    (pinned, others)
+}
+
+// ============================================================================
+// Entity List
+// ============================================================================
+
+/// Sidebar listing the project's entities
+#[component]
+pub fn EntityList() -> Element {
+    let mut query = use_signal(String::new);
+
+    let state = APP_STATE.read();
+    let open = state.ui.entity_list_open;
+    let (pinned, others) = state
+        .project
+        .as_ref()
+        .map(|p| entity_rows(p, &query.read()))
+        .unwrap_or_default();
+    let total = state.project.as_ref().map_or(0, |p| p.entities.len());
+    let selected = state.selection.entities.clone();
+    drop(state);
+
+    if !open {
+        return rsx! {
+            aside {
+                class: "entity-list w-10 flex-shrink-0 bg-slate-800 border-r border-slate-700 flex flex-col items-center pt-2",
+                button {
+                    class: "p-1.5 text-slate-400 hover:text-white hover:bg-slate-700 rounded",
+                    title: "Show entity list",
+                    onclick: move |_| APP_STATE.write().ui.toggle_entity_list(),
+                    "»"
+                }
+            }
+        };
+    }
+
+    let empty = pinned.is_empty() && others.is_empty();
+
+    rsx! {
+        aside {
+            class: "entity-list w-56 flex-shrink-0 bg-slate-800 border-r border-slate-700 flex flex-col overflow-hidden",
+
+            // Header
+            div {
+                class: "h-10 border-b border-slate-700 flex items-center justify-between px-3",
+                span { class: "text-sm font-medium", "Entities ({total})" }
+                button {
+                    class: "p-1 text-slate-400 hover:text-white hover:bg-slate-700 rounded",
+                    title: "Hide entity list",
+                    onclick: move |_| APP_STATE.write().ui.toggle_entity_list(),
+                    "«"
+                }
+            }
+
+            // Search
+            div {
+                class: "p-2 border-b border-slate-700",
+                TextInput {
+                    value: query.read().clone(),
+                    placeholder: "Search entities...",
+                    on_change: move |v: String| query.set(v),
+                }
+            }
+
+            div {
+                class: "flex-1 overflow-auto p-2 space-y-0.5",
+
+                if empty {
+                    p {
+                        class: "text-xs text-slate-500 p-2",
+                        if total == 0 {
+                            "No entities yet. Double-click the canvas to add one."
+                        } else {
+                            "No entities match the search."
+                        }
+                    }
+                }
+
+                if !pinned.is_empty() {
+                    div { class: "px-2 pt-1 pb-0.5 text-[10px] uppercase tracking-wide text-slate-500", "Pinned" }
+                    for row in pinned {
+                        EntityListRow {
+                            key: "{row.id}",
+                            selected: selected.contains(&row.id),
+                            row: row,
+                        }
+                    }
+                    if !others.is_empty() {
+                        div { class: "px-2 pt-2 pb-0.5 text-[10px] uppercase tracking-wide text-slate-500", "All entities" }
+                    }
+                }
+
+                for row in others {
+                    EntityListRow {
+                        key: "{row.id}",
+                        selected: selected.contains(&row.id),
+                        row: row,
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Entity List Row
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+struct EntityListRowProps {
+    row: EntityRow,
+    selected: bool,
+}
+
+/// One entity: click to center it on the canvas, star to pin it
+#[component]
+fn EntityListRow(props: EntityListRowProps) -> Element {
+    let id = props.row.id;
+    let pinned = props.row.pinned;
+
+    rsx! {
+        div {
+            class: if props.selected {
+                "group flex items-center gap-2 px-2 py-1.5 rounded cursor-pointer bg-indigo-600/30 text-white"
+            } else {
+                "group flex items-center gap-2 px-2 py-1.5 rounded cursor-pointer text-slate-300 hover:bg-slate-700/60"
+            },
+            title: "Center on canvas",
+            onclick: move |_| {
+                APP_STATE.write().focus_entity(id);
+            },
+
+            span { class: "flex-1 text-sm truncate", "{props.row.name}" }
+            span { class: "text-[10px] text-slate-500 flex-shrink-0", "{props.row.field_count}" }
+            button {
+                class: if pinned {
+                    "text-amber-400 hover:text-amber-300 flex-shrink-0"
+                } else {
+                    "text-slate-600 hover:text-amber-300 opacity-0 group-hover:opacity-100 flex-shrink-0"
+                },
+                title: if pinned { "Unpin" } else { "Pin to top" },
+                onclick: move |e: MouseEvent| {
+                    e.stop_propagation();
+                    APP_STATE.write().toggle_favorite_entity(id);
+                },
+                if pinned { "★" } else { "☆" }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::Entity;
+
+    fn names(rows: &[EntityRow]) -> Vec<&str> {
+        rows.iter().map(|r| r.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_entity_rows_pinned_first_then_alphabetical() {
+        let mut project = ProjectGraph::new("Shop");
+        for name in ["product", "Order", "Customer", "OrderItem"] {
+            project.add_entity(Entity::new(name));
+        }
+        let order_item = project.get_entity_by_name("OrderItem").unwrap().id;
+        let customer = project.get_entity_by_name("Customer").unwrap().id;
+        project.toggle_favorite(order_item);
+        project.toggle_favorite(customer);
+
+        let (pinned, others) = entity_rows(&project, "");
+        assert_eq!(names(&pinned), ["OrderItem", "Customer"]);
+        assert!(pinned.iter().all(|r| r.pinned));
+        assert_eq!(names(&others), ["Order", "product"]);
+
+        let (pinned, others) = entity_rows(&project, " ORDER ");
+        assert_eq!(names(&pinned), ["OrderItem"]);
+        assert_eq!(names(&others), ["Order"]);
+
+        let (pinned, others) = entity_rows(&project, "invoice");
+        assert!(pinned.is_empty() && others.is_empty());
+    }
+}
@@ -73,6 +73,7 @@ pub fn FieldRow(props: FieldRowProps) -> Element {
             ondoubleclick: move |_| props.on_double_click.call(field_id),
             oncontextmenu: move |e| {
                 e.prevent_default();
+                e.stop_propagation();
                 props.on_context_menu.call((field_id, e));
             },
 
@@ -324,6 +325,13 @@ fn get_type_display_info(data_type: &DataType) -> TypeDisplayInfo {
             bg_class: "bg-sky-500/20",
             text_class: "text-sky-300",
         },
+        DataType::Decimal { precision, scale } => TypeDisplayInfo {
+            full_name: format!("Decimal NUMERIC({},{})", precision, scale),
+            name: "Decimal",
+            short_name: "dec",
+            bg_class: "bg-sky-500/20",
+            text_class: "text-sky-300",
+        },
         DataType::Bool => TypeDisplayInfo {
             full_name: "Boolean".to_string(),
             name: "Bool",
@@ -0,0 +1,136 @@
+//! # Help Panel Component
+//!
+//! Floating right-hand sidebar showing contextual documentation from the embedded
+//! [help catalog](crate::help). The panel follows the topic stored in
+//! `UiState::help_topic`; [`HelpButton`]s placed next to options in dialogs
+//! and pages switch the topic and open the panel.
+
+use dioxus::prelude::*;
+
+use crate::help::HelpTopic;
+use crate::state::APP_STATE;
+
+// ============================================================================
+// Help Panel
+// ============================================================================
+
+/// Contextual help sidebar
+#[component]
+pub fn HelpPanel() -> Element {
+    let state = APP_STATE.read();
+    let open = state.ui.help_open;
+    let topic = state.ui.help_topic;
+    drop(state);
+
+    let mut query = use_signal(String::new);
+
+    if !open {
+        return rsx! {};
+    }
+
+    let search = query.read().clone();
+    let topics = HelpTopic::search(&search);
+
+    rsx! {
+        aside {
+            // Floats above dialogs so "?" buttons inside modals stay useful
+            class: "help-panel fixed right-0 top-12 bottom-6 w-80 z-[60] bg-slate-800 border-l border-slate-700 shadow-2xl flex flex-col overflow-hidden",
+
+            // Header
+            div {
+                class: "h-10 border-b border-slate-700 flex items-center justify-between px-3",
+                span { class: "text-sm font-medium", "❔ Help" }
+                button {
+                    class: "p-1 hover:bg-slate-700 rounded",
+                    title: "Close help (F1)",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_help();
+                    },
+                    "✕"
+                }
+            }
+
+            // Search + topic list
+            div {
+                class: "p-3 border-b border-slate-700 space-y-2",
+
+                input {
+                    r#type: "text",
+                    class: "w-full px-3 py-1.5 bg-slate-700 border border-slate-600 rounded text-sm text-white focus:outline-none focus:border-indigo-500 placeholder-slate-500",
+                    placeholder: "Search help...",
+                    value: "{search}",
+                    oninput: move |e| query.set(e.value()),
+                }
+
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for t in topics.iter().copied() {
+                        button {
+                            key: "{t.title()}",
+                            r#type: "button",
+                            class: if t == topic {
+                                "px-2 py-1 rounded text-xs bg-indigo-600 text-white"
+                            } else {
+                                "px-2 py-1 rounded text-xs bg-slate-700 text-slate-300 hover:bg-slate-600"
+                            },
+                            onclick: move |_| {
+                                APP_STATE.write().ui.show_help(t);
+                            },
+                            "{t.icon()} {t.title()}"
+                        }
+                    }
+                    if topics.is_empty() {
+                        span { class: "text-xs text-slate-500", "No topics match \"{search}\"" }
+                    }
+                }
+            }
+
+            // Topic content
+            div {
+                class: "flex-1 overflow-auto p-3 space-y-3",
+
+                h3 {
+                    class: "text-base font-semibold flex items-center gap-2",
+                    span { "{topic.icon()}" }
+                    "{topic.title()}"
+                }
+                p { class: "text-sm text-slate-300 leading-relaxed", "{topic.summary()}" }
+
+                for entry in topic.entries().iter() {
+                    div {
+                        key: "{entry.term}",
+                        class: "p-3 bg-slate-900/50 border border-slate-700 rounded-lg",
+                        h4 { class: "text-sm font-medium text-indigo-300", "{entry.term}" }
+                        p { class: "text-xs text-slate-300 mt-1", "{entry.description}" }
+                        p {
+                            class: "text-xs text-slate-500 mt-1.5",
+                            span { class: "font-semibold text-slate-400", "Generates: " }
+                            "{entry.generates}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Help Button
+// ============================================================================
+
+/// Small "?" button that opens the help panel on a topic
+#[component]
+pub fn HelpButton(topic: HelpTopic) -> Element {
+    rsx! {
+        button {
+            r#type: "button",
+            class: "inline-flex items-center justify-center w-4 h-4 ml-1 rounded-full bg-slate-700 hover:bg-indigo-600 text-[10px] text-slate-300 hover:text-white transition-colors",
+            title: "What does this generate? — {topic.title()}",
+            onclick: move |e: MouseEvent| {
+                e.stop_propagation();
+                APP_STATE.write().ui.show_help(topic);
+            },
+            "?"
+        }
+    }
+}
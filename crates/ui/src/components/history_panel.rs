@@ -0,0 +1,371 @@
+//! # History Panel Component
+//!
+//! Right-hand sidebar listing the undo history as a timeline. Each entry shows
+//! the action label and when it happened; hovering an entry previews the
+//! canvas as it was at that point, and clicking jumps there.
+//!
+//! Jumping back keeps the later states on the redo stack (so the jump can be
+//! undone), unless "discard later" is used, which collapses history at the
+//! chosen point. As with single-step undo, a new edit after jumping back
+//! starts a fresh branch and drops the redo states.
+
+use dioxus::prelude::*;
+use imortal_ir::ProjectGraph;
+
+use crate::state::{APP_STATE, HistorySnapshot, StatusLevel};
+
+// ============================================================================
+// History Panel
+// ============================================================================
+
+/// A row in the history timeline
+#[derive(Debug, Clone, PartialEq)]
+struct TimelineEntry {
+    /// Action label
+    label: String,
+    /// Formatted local time
+    time: String,
+    /// Serialized project at this point (for the preview)
+    project_json: String,
+    /// Steps to move: negative = back (undo), positive = forward (redo)
+    offset: isize,
+}
+
+impl TimelineEntry {
+    fn from_snapshot(snapshot: &HistorySnapshot, offset: isize) -> Self {
+        Self {
+            label: snapshot.action.clone(),
+            time: snapshot
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string(),
+            project_json: snapshot.project_json.clone(),
+            offset,
+        }
+    }
+}
+
+/// Build the timeline rows: undoable states (oldest first), then redoable ones.
+fn timeline(past: &[HistorySnapshot], future: &[&HistorySnapshot]) -> Vec<TimelineEntry> {
+    let back = past.len() as isize;
+    let past_rows = past
+        .iter()
+        .enumerate()
+        .map(|(i, s)| TimelineEntry::from_snapshot(s, i as isize - back));
+    let future_rows = future
+        .iter()
+        .enumerate()
+        .map(|(i, s)| TimelineEntry::from_snapshot(s, i as isize + 1));
+    past_rows.chain(future_rows).collect()
+}
+
+/// Move through history by `offset` steps
+fn jump(offset: isize, collapse: bool) {
+    let mut state = APP_STATE.write();
+    let moved = if offset < 0 {
+        state.rewind_history(offset.unsigned_abs(), collapse)
+    } else {
+        state.fast_forward_history(offset as usize)
+    };
+
+    if moved {
+        let message = if collapse {
+            "Jumped back in history and discarded later changes"
+        } else {
+            "Jumped to history entry"
+        };
+        state.ui.set_status(message, StatusLevel::Info);
+    }
+}
+
+/// Undo history browser sidebar
+#[component]
+pub fn HistoryPanel() -> Element {
+    let state = APP_STATE.read();
+    let open = state.ui.history_open;
+    let future: Vec<&HistorySnapshot> = state.history.future().collect();
+    let entries = timeline(state.history.past(), &future);
+    drop(future);
+    drop(state);
+
+    let mut hovered = use_signal(|| None::<String>);
+
+    if !open {
+        return rsx! {};
+    }
+
+    let (past_entries, future_entries): (Vec<TimelineEntry>, Vec<TimelineEntry>) =
+        entries.into_iter().partition(|e| e.offset < 0);
+
+    rsx! {
+        aside {
+            class: "history-panel fixed right-0 top-12 bottom-6 w-80 z-50 bg-slate-800 border-l border-slate-700 shadow-2xl flex flex-col overflow-hidden",
+            onmouseleave: move |_| hovered.set(None),
+
+            // Header
+            div {
+                class: "h-10 border-b border-slate-700 flex items-center justify-between px-3",
+                span { class: "text-sm font-medium", "🕘 History" }
+                button {
+                    class: "p-1 hover:bg-slate-700 rounded",
+                    title: "Close history",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_history();
+                    },
+                    "✕"
+                }
+            }
+
+            // Preview of the hovered entry
+            div {
+                class: "h-40 border-b border-slate-700 bg-slate-900/60 p-2",
+                if let Some(json) = hovered.read().as_ref() {
+                    HistoryPreview { project_json: json.clone() }
+                } else {
+                    div {
+                        class: "h-full flex items-center justify-center text-xs text-slate-500 text-center px-4",
+                        "Hover an entry to preview the canvas at that point"
+                    }
+                }
+            }
+
+            // Timeline
+            div {
+                class: "flex-1 overflow-auto p-2 space-y-1",
+
+                if past_entries.is_empty() && future_entries.is_empty() {
+                    p { class: "text-xs text-slate-500 p-2", "No history yet. Edits will appear here." }
+                }
+
+                for entry in past_entries {
+                    HistoryRow {
+                        key: "{entry.offset}",
+                        entry: entry.clone(),
+                        on_hover: move |json| hovered.set(Some(json)),
+                    }
+                }
+
+                // Marker for where the project currently is
+                div {
+                    class: "flex items-center gap-2 px-2 py-1.5 text-xs text-indigo-300",
+                    span { "▶" }
+                    span { class: "font-medium", "Current state" }
+                }
+
+                for entry in future_entries {
+                    HistoryRow {
+                        key: "{entry.offset}",
+                        entry: entry.clone(),
+                        on_hover: move |json| hovered.set(Some(json)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// History Row
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+struct HistoryRowProps {
+    entry: TimelineEntry,
+    on_hover: EventHandler<String>,
+}
+
+/// One entry in the timeline with its jump actions
+#[component]
+fn HistoryRow(props: HistoryRowProps) -> Element {
+    let offset = props.entry.offset;
+    let is_redo = offset > 0;
+    let json = props.entry.project_json.clone();
+
+    rsx! {
+        div {
+            class: if is_redo {
+                "group px-2 py-1.5 rounded hover:bg-slate-700/60 opacity-60"
+            } else {
+                "group px-2 py-1.5 rounded hover:bg-slate-700/60"
+            },
+            onmouseenter: move |_| props.on_hover.call(json.clone()),
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-sm text-slate-200 truncate", "{props.entry.label}" }
+                span { class: "text-[10px] text-slate-500 flex-shrink-0", "{props.entry.time}" }
+            }
+
+            div {
+                class: "hidden group-hover:flex gap-1 mt-1",
+                button {
+                    class: "px-2 py-0.5 text-xs rounded bg-indigo-600 hover:bg-indigo-700 text-white",
+                    onclick: move |_| jump(offset, false),
+                    "Jump here"
+                }
+                if !is_redo {
+                    button {
+                        class: "px-2 py-0.5 text-xs rounded bg-slate-700 hover:bg-red-700 text-slate-300",
+                        title: "Jump here and discard everything after it",
+                        onclick: move |_| jump(offset, true),
+                        "Jump & discard later"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Canvas Preview
+// ============================================================================
+
+/// Bounds of all entities in a project as (min_x, min_y, width, height),
+/// padded so boxes don't touch the edge of the preview.
+fn preview_bounds(project: &ProjectGraph) -> (f32, f32, f32, f32) {
+    const PADDING: f32 = 20.0;
+
+    let mut entities = project.entities.values();
+    let Some(first) = entities.next() else {
+        return (0.0, 0.0, 100.0, 100.0);
+    };
+
+    let (mut min_x, mut min_y) = (first.position.x, first.position.y);
+    let (mut max_x, mut max_y) = (
+        first.position.x + first.size.width,
+        first.position.y + first.size.height,
+    );
+    for e in entities {
+        min_x = min_x.min(e.position.x);
+        min_y = min_y.min(e.position.y);
+        max_x = max_x.max(e.position.x + e.size.width);
+        max_y = max_y.max(e.position.y + e.size.height);
+    }
+
+    (
+        min_x - PADDING,
+        min_y - PADDING,
+        max_x - min_x + PADDING * 2.0,
+        max_y - min_y + PADDING * 2.0,
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct HistoryPreviewProps {
+    project_json: String,
+}
+
+/// Miniature, read-only rendering of the canvas stored in a snapshot
+#[component]
+fn HistoryPreview(props: HistoryPreviewProps) -> Element {
+    let Ok(project) = serde_json::from_str::<ProjectGraph>(&props.project_json) else {
+        return rsx! {
+            div { class: "text-xs text-red-400", "Preview unavailable" }
+        };
+    };
+
+    let (x, y, w, h) = preview_bounds(&project);
+    let lines: Vec<(f32, f32, f32, f32)> = project
+        .relationships
+        .values()
+        .filter_map(|r| {
+            let from = project.entities.get(&r.from_entity_id)?.center();
+            let to = project.entities.get(&r.to_entity_id)?.center();
+            Some((from.x, from.y, to.x, to.y))
+        })
+        .collect();
+
+    rsx! {
+        svg {
+            class: "w-full h-full",
+            view_box: "{x} {y} {w} {h}",
+            preserve_aspect_ratio: "xMidYMid meet",
+
+            for (i, (x1, y1, x2, y2)) in lines.into_iter().enumerate() {
+                line {
+                    key: "rel-{i}",
+                    x1: "{x1}",
+                    y1: "{y1}",
+                    x2: "{x2}",
+                    y2: "{y2}",
+                    stroke: "#6366f1",
+                    stroke_width: "3",
+                }
+            }
+
+            for entity in project.entities.values() {
+                g {
+                    key: "{entity.id}",
+                    rect {
+                        x: "{entity.position.x}",
+                        y: "{entity.position.y}",
+                        width: "{entity.size.width}",
+                        height: "{entity.size.height}",
+                        rx: "8",
+                        fill: "#1e293b",
+                        stroke: "#475569",
+                        stroke_width: "2",
+                    }
+                    text {
+                        x: "{entity.position.x + 12.0}",
+                        y: "{entity.position.y + 28.0}",
+                        fill: "#e2e8f0",
+                        font_size: "20",
+                        "{entity.name}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::Position;
+    use imortal_ir::Entity;
+
+    #[test]
+    fn test_timeline_offsets() {
+        let project = ProjectGraph::new("p");
+        let a = HistorySnapshot::new("a", &project).unwrap();
+        let b = HistorySnapshot::new("b", &project).unwrap();
+        let c = HistorySnapshot::new("c", &project).unwrap();
+
+        let entries = timeline(&[a, b], &[&c]);
+        let offsets: Vec<(String, isize)> =
+            entries.into_iter().map(|e| (e.label, e.offset)).collect();
+        assert_eq!(
+            offsets,
+            vec![
+                ("a".to_string(), -2),
+                ("b".to_string(), -1),
+                ("c".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_bounds() {
+        let mut project = ProjectGraph::new("p");
+        assert_eq!(preview_bounds(&project), (0.0, 0.0, 100.0, 100.0));
+
+        let mut a = Entity::new("A");
+        a.position = Position::new(0.0, 0.0);
+        let mut b = Entity::new("B");
+        b.position = Position::new(300.0, 100.0);
+        let (bw, bh) = (b.size.width, b.size.height);
+        project.add_entity(a);
+        project.add_entity(b);
+
+        let (x, y, w, h) = preview_bounds(&project);
+        assert_eq!((x, y), (-20.0, -20.0));
+        assert_eq!(w, 300.0 + bw + 40.0);
+        assert_eq!(h, 100.0 + bh + 40.0);
+    }
+}
@@ -0,0 +1,175 @@
+//! # Icon Component
+//!
+//! The editor's embedded icon set.
+//!
+//! Every outline icon used by the UI lives here as SVG path data compiled into
+//! the binary, so the desktop app never needs an icon font or a CDN. Components
+//! render icons through [`Icon`] instead of carrying their own inline `svg`
+//! blocks; adding a new icon means adding an [`IconName`] variant and its path.
+
+use dioxus::prelude::*;
+
+// ============================================================================
+// Icon Names
+// ============================================================================
+
+/// Icons available in the embedded set (24×24 outline glyphs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconName {
+    /// Checkmark
+    Check,
+    /// Pencil (edit)
+    Pencil,
+    /// Trash can (delete)
+    Trash,
+    /// Terminal window (endpoint)
+    Terminal,
+    /// Closed padlock (authentication)
+    Lock,
+    /// Clock (rate limiting)
+    Clock,
+    /// Globe (public access)
+    Globe,
+    /// Shield with a check (security)
+    Shield,
+    /// Plus sign
+    Plus,
+    /// Plus sign with a shorter stroke, used in empty-state placeholders
+    PlusSmall,
+    /// Chain link (relationship)
+    Link,
+    /// Cross (close)
+    Close,
+    /// Sliders (overrides)
+    Adjustments,
+    /// Information circle
+    Info,
+    /// Folder
+    Folder,
+    /// Lightning bolt (generate)
+    Bolt,
+}
+
+impl IconName {
+    /// Every icon in the set
+    pub const ALL: &'static [IconName] = &[
+        IconName::Check,
+        IconName::Pencil,
+        IconName::Trash,
+        IconName::Terminal,
+        IconName::Lock,
+        IconName::Clock,
+        IconName::Globe,
+        IconName::Shield,
+        IconName::Plus,
+        IconName::PlusSmall,
+        IconName::Link,
+        IconName::Close,
+        IconName::Adjustments,
+        IconName::Info,
+        IconName::Folder,
+        IconName::Bolt,
+    ];
+
+    /// SVG path data for this icon, drawn on a 24×24 view box
+    pub fn path(&self) -> &'static str {
+        match self {
+            IconName::Check => "M5 13l4 4L19 7",
+            IconName::Pencil => {
+                "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z"
+            }
+            IconName::Trash => {
+                "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"
+            }
+            IconName::Terminal => {
+                "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z"
+            }
+            IconName::Lock => {
+                "M12 15v2m-6 4h12a2 2 0 002-2v-6a2 2 0 00-2-2H6a2 2 0 00-2 2v6a2 2 0 002 2zm10-10V7a4 4 0 00-8 0v4h8z"
+            }
+            IconName::Clock => "M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z",
+            IconName::Globe => {
+                "M3.055 11H5a2 2 0 012 2v1a2 2 0 002 2 2 2 0 012 2v2.945M8 3.935V5.5A2.5 2.5 0 0010.5 8h.5a2 2 0 012 2 2 2 0 104 0 2 2 0 012-2h1.064M15 20.488V18a2 2 0 012-2h3.064M21 12a9 9 0 11-18 0 9 9 0 0118 0z"
+            }
+            IconName::Shield => {
+                "M9 12l2 2 4-4m5.618-4.016A11.955 11.955 0 0112 2.944a11.955 11.955 0 01-8.618 3.04A12.02 12.02 0 003 9c0 5.591 3.824 10.29 9 11.622 5.176-1.332 9-6.03 9-11.622 0-1.042-.133-2.052-.382-3.016z"
+            }
+            IconName::Plus => "M12 4v16m8-8H4",
+            IconName::PlusSmall => "M12 6v6m0 0v6m0-6h6m-6 0H6",
+            IconName::Link => {
+                "M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1"
+            }
+            IconName::Close => "M6 18L18 6M6 6l12 12",
+            IconName::Adjustments => {
+                "M12 6V4m0 2a2 2 0 100 4m0-4a2 2 0 110 4m-6 8a2 2 0 100-4m0 4a2 2 0 110-4m0 4v2m0-6V4m6 6v10m6-2a2 2 0 100-4m0 4a2 2 0 110-4m0 4v2m0-6V4"
+            }
+            IconName::Info => "M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z",
+            IconName::Folder => {
+                "M3 7v10a2 2 0 002 2h14a2 2 0 002-2V9a2 2 0 00-2-2h-6l-2-2H5a2 2 0 00-2 2z"
+            }
+            IconName::Bolt => "M13 10V3L4 14h7v7l9-11h-7z",
+        }
+    }
+}
+
+// ============================================================================
+// Icon Component
+// ============================================================================
+
+/// Properties for the Icon component
+#[derive(Props, Clone, PartialEq)]
+pub struct IconProps {
+    /// Which icon to draw
+    pub name: IconName,
+    /// CSS classes for sizing and color (icons inherit `currentColor`)
+    #[props(default = "w-4 h-4".to_string(), into)]
+    pub class: String,
+    /// Stroke width in view-box units
+    #[props(default = 2.0)]
+    pub stroke_width: f32,
+}
+
+/// Outline icon from the embedded set
+#[component]
+pub fn Icon(props: IconProps) -> Element {
+    let path = props.name.path();
+    let stroke_width = props.stroke_width.to_string();
+
+    rsx! {
+        svg {
+            class: "{props.class}",
+            fill: "none",
+            stroke: "currentColor",
+            view_box: "0 0 24 24",
+            path {
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+                stroke_width: "{stroke_width}",
+                d: "{path}",
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_icon_paths_are_unique() {
+        let paths: HashSet<&str> = IconName::ALL.iter().map(|i| i.path()).collect();
+        assert_eq!(paths.len(), IconName::ALL.len());
+    }
+
+    #[test]
+    fn test_icon_paths_start_with_move() {
+        for icon in IconName::ALL {
+            assert!(icon.path().starts_with('M'), "{:?}", icon);
+        }
+    }
+}
@@ -17,6 +17,8 @@
 
 use dioxus::prelude::*;
 
+use crate::components::icon::{Icon, IconName};
+
 // ============================================================================
 // Text Input Component
 // ============================================================================
@@ -751,16 +753,7 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
 
                     // Checkmark
                     if props.checked && !props.indeterminate {
-                        svg {
-                            class: "w-3 h-3 text-white",
-                            fill: "none",
-                            stroke: "currentColor",
-                            stroke_width: "2.5",
-                            view_box: "0 0 24 24",
-                            path {
-                                d: "M5 13l4 4L19 7",
-                            }
-                        }
+                        Icon { name: IconName::Check, class: "w-3 h-3 text-white", stroke_width: 2.5 }
                     }
 
                     // Indeterminate mark
@@ -906,16 +899,7 @@ pub fn Toggle(props: ToggleProps) -> Element {
 
                 // Checkmark
                 if props.checked {
-                    svg {
-                        class: "w-3 h-3 text-white",
-                        fill: "none",
-                        stroke: "currentColor",
-                        stroke_width: "3",
-                        view_box: "0 0 24 24",
-                        path {
-                            d: "M5 13l4 4L19 7",
-                        }
-                    }
+                    Icon { name: IconName::Check, class: "w-3 h-3 text-white", stroke_width: 3.0 }
                 }
             }
 
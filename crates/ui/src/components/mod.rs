@@ -11,6 +11,15 @@
 //! - **Dialogs**: Modal dialogs for entity/field/relationship creation, deletion, etc.
 //! - **Port**: Connection ports on entity cards for relationships
 //! - **Connection**: SVG connection lines for visualizing relationships
+//! - **Help Panel**: Contextual documentation sidebar
+//! - **History Panel**: Undo history timeline with canvas previews
+//! - **Output Preview**: Generated file tree with diffs against the output directory
+//! - **Entity List**: Searchable entity sidebar with pinned favorites
+//! - **Command Palette**: Ctrl+K fuzzy search over the project and app actions
+//! - **Problems Panel**: Live validation errors and warnings that jump to their source
+//! - **Diagnostics Overlay**: Render counts, FPS and project size for perf reports
+//! - **Icon**: Embedded SVG icon set (no icon fonts or CDN)
+//! - **Suggestions**: Accept/dismiss chips for entity suggestions
 //!
 //! ## Component Hierarchy
 //!
@@ -43,14 +52,23 @@
 // ============================================================================
 
 pub mod canvas;
+pub mod command_palette;
 pub mod connection;
+pub mod diagnostics_overlay;
 pub mod dialogs;
 pub mod endpoint_card;
 pub mod entity_card;
+pub mod entity_list;
 pub mod field_row;
+pub mod help_panel;
+pub mod history_panel;
+pub mod icon;
 pub mod inputs;
+pub mod output_preview;
 pub mod port;
+pub mod problems_panel;
 pub mod properties;
+pub mod suggestions;
 
 // ============================================================================
 // Re-exports
@@ -72,6 +90,33 @@ pub use endpoint_card::{
 // Properties panel
 pub use properties::PropertiesPanel;
 
+// Command palette
+pub use command_palette::CommandPalette;
+
+// Diagnostics overlay
+pub use diagnostics_overlay::DiagnosticsOverlay;
+
+// Help panel
+pub use help_panel::{HelpButton, HelpPanel};
+
+// History panel
+pub use history_panel::HistoryPanel;
+
+// Problems panel
+pub use problems_panel::ProblemsPanel;
+
+// Output preview
+pub use output_preview::OutputPreviewPanel;
+
+// Entity list
+pub use entity_list::EntityList;
+
+// Icons
+pub use icon::{Icon, IconName};
+
+// Suggestion chips
+pub use suggestions::{SuggestionChips, install_suggester};
+
 // Port components
 pub use port::{Port, PortClickInfo, PortPair, PortState, PortType};
 
@@ -87,6 +132,6 @@ pub use inputs::{Checkbox, NumberInput, Select, SelectOption, TextArea, TextInpu
 // Re-export dialog components
 pub use dialogs::{
     ConfirmDeleteDialog, DataTypeSelector, EndpointDialog, EndpointDialogMode, EntityDialog,
-    EntityDialogMode, FieldDialog, FieldDialogMode, RelationshipDialog, RelationshipDialogMode,
-    ValidationEditor,
+    EntityDialogMode, FieldDialog, FieldDialogMode, QueryDialog, QueryDialogMode,
+    RelationshipDialog, RelationshipDialogMode, ValidationEditor,
 };
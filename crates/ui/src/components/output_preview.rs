@@ -0,0 +1,649 @@
+//! # Output Preview Component
+//!
+//! Review panel shown on the Code Generation page between generating a
+//! project and writing it. The left side is a file tree of the generated
+//! output, each file tagged as added, changed, unchanged or removed
+//! compared with the output directory; the right side shows the selected
+//! file, syntax-highlighted, or its diff against the copy on disk.
+//!
+//! Every file that would change on disk has a checkbox; unticking it keeps
+//! the file out of the write.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use dioxus::prelude::*;
+use imortal_codegen::preview::compact_diff;
+use imortal_codegen::{DiffLine, FilePreview, FileStatus, FileType, OutputPreview};
+
+/// Unchanged lines kept around each change in a diff
+const DIFF_CONTEXT: usize = 3;
+
+/// Lines rendered in the viewer before the rest is cut off
+const MAX_PREVIEW_LINES: usize = 2000;
+
+// ============================================================================
+// Output Preview Panel
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+pub struct OutputPreviewPanelProps {
+    /// The generation compared with the output directory
+    pub preview: OutputPreview,
+    /// Paths left out of the write
+    pub excluded: Signal<HashSet<PathBuf>>,
+}
+
+/// File tree and viewer for a generation that hasn't been written yet.
+#[component]
+pub fn OutputPreviewPanel(props: OutputPreviewPanelProps) -> Element {
+    let mut excluded = props.excluded;
+    let mut selected: Signal<Option<PathBuf>> = use_signal(|| None);
+    let mut hide_unchanged = use_signal(|| true);
+    let mut show_diff = use_signal(|| true);
+
+    let preview = props.preview;
+    let visible: Vec<&FilePreview> = preview
+        .files
+        .iter()
+        .filter(|f| !*hide_unchanged.read() || f.status.is_change())
+        .collect();
+    let rows = tree_rows(&visible);
+    let unchanged = preview.count(FileStatus::Unchanged);
+    let changes: Vec<PathBuf> = preview
+        .files
+        .iter()
+        .filter(|f| f.status.is_change())
+        .map(|f| f.path.clone())
+        .collect();
+
+    let current = selected
+        .read()
+        .as_ref()
+        .and_then(|path| preview.file(path))
+        .cloned();
+
+    rsx! {
+        div {
+            class: "flex h-[32rem] border border-slate-700 rounded-lg overflow-hidden",
+
+            // ── File tree ────────────────────────────────────────────────
+            div {
+                class: "w-72 flex-shrink-0 flex flex-col border-r border-slate-700 bg-slate-900/40",
+
+                div {
+                    class: "px-3 py-2 flex items-center gap-2 border-b border-slate-700 text-xs",
+                    label {
+                        class: "flex items-center gap-1.5 text-slate-400 cursor-pointer flex-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: *hide_unchanged.read(),
+                            onchange: move |e| hide_unchanged.set(e.checked()),
+                        }
+                        "Hide unchanged ({unchanged})"
+                    }
+                    button {
+                        class: "text-slate-400 hover:text-white",
+                        title: "Write every changed file",
+                        onclick: move |_| excluded.write().clear(),
+                        "All"
+                    }
+                    button {
+                        class: "text-slate-400 hover:text-white",
+                        title: "Write nothing",
+                        onclick: move |_| excluded.set(changes.iter().cloned().collect()),
+                        "None"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-auto py-1",
+
+                    if rows.is_empty() {
+                        p {
+                            class: "px-3 py-2 text-xs text-slate-500",
+                            "Nothing to write — the output directory is up to date."
+                        }
+                    }
+
+                    for row in rows {
+                        match row.file.clone() {
+                            None => rsx! {
+                                div {
+                                    key: "dir-{row.key}",
+                                    class: "py-0.5 pr-2 text-xs text-slate-500 truncate",
+                                    style: "padding-left: {row.indent()}rem",
+                                    "📁 {row.name}"
+                                }
+                            },
+                            Some((path, status)) => {
+                                let is_selected = selected.read().as_deref() == Some(path.as_path());
+                                let included = !excluded.read().contains(&path);
+                                let toggle_path = path.clone();
+                                let select_path = path.clone();
+                                rsx! {
+                                    div {
+                                        key: "file-{row.key}",
+                                        class: if is_selected {
+                                            "flex items-center gap-1.5 py-0.5 pr-2 text-xs cursor-pointer bg-indigo-900/40"
+                                        } else {
+                                            "flex items-center gap-1.5 py-0.5 pr-2 text-xs cursor-pointer hover:bg-slate-800"
+                                        },
+                                        style: "padding-left: {row.indent()}rem",
+                                        onclick: move |_| selected.set(Some(select_path.clone())),
+                                        input {
+                                            r#type: "checkbox",
+                                            disabled: !status.is_change(),
+                                            checked: status.is_change() && included,
+                                            title: "Include in the write",
+                                            onclick: move |e| e.stop_propagation(),
+                                            onchange: move |e| {
+                                                if e.checked() {
+                                                    excluded.write().remove(&toggle_path);
+                                                } else {
+                                                    excluded.write().insert(toggle_path.clone());
+                                                }
+                                            },
+                                        }
+                                        span {
+                                            class: format!(
+                                                "font-mono truncate flex-1 {}",
+                                                if included || !status.is_change() {
+                                                    "text-slate-300"
+                                                } else {
+                                                    "text-slate-500 line-through"
+                                                }
+                                            ),
+                                            "{row.name}"
+                                        }
+                                        span {
+                                            class: "px-1 rounded text-[10px] uppercase {status_class(status)}",
+                                            "{status_marker(status)}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ── Viewer ───────────────────────────────────────────────────
+            div {
+                class: "flex-1 min-w-0 flex flex-col bg-slate-950/60",
+
+                match current {
+                    None => rsx! {
+                        div {
+                            class: "flex-1 flex items-center justify-center text-sm text-slate-500",
+                            "Select a file to preview it."
+                        }
+                    },
+                    Some(file) => {
+                        let (added, removed) = file.line_counts();
+                        let path = file.path.display().to_string();
+                        let diffable = file.status == FileStatus::Changed;
+                        let as_diff = file.status == FileStatus::Removed
+                            || (diffable && *show_diff.read());
+                        let lines = viewer_lines(&file, as_diff);
+                        let truncated = lines.len() > MAX_PREVIEW_LINES;
+                        rsx! {
+                            div {
+                                class: "px-3 py-2 flex items-center gap-3 border-b border-slate-700 text-xs",
+                                span { class: "font-mono text-slate-200 truncate flex-1", "{path}" }
+                                span {
+                                    class: "px-1.5 py-0.5 rounded uppercase {status_class(file.status)}",
+                                    "{file.status.label()}"
+                                }
+                                if file.status.is_change() {
+                                    span { class: "text-emerald-400", "+{added}" }
+                                    span { class: "text-red-400", "−{removed}" }
+                                }
+                                if diffable {
+                                    button {
+                                        class: "px-2 py-0.5 rounded bg-slate-800 text-slate-300 hover:bg-slate-700",
+                                        onclick: move |_| {
+                                            let current = *show_diff.read();
+                                            show_diff.set(!current);
+                                        },
+                                        if *show_diff.read() { "Show file" } else { "Show diff" }
+                                    }
+                                }
+                            }
+
+                            div {
+                                class: "flex-1 overflow-auto font-mono text-xs leading-5",
+                                for (i, line) in lines.into_iter().take(MAX_PREVIEW_LINES).enumerate() {
+                                    ViewerLine { key: "{i}", line, file_type: file.file_type }
+                                }
+                                if truncated {
+                                    p {
+                                        class: "px-3 py-2 text-slate-500 font-sans",
+                                        "Preview cut off after {MAX_PREVIEW_LINES} lines."
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ViewerLineProps {
+    line: ViewerLineKind,
+    file_type: FileType,
+}
+
+/// One line of the viewer: a numbered source line or a diff line
+#[component]
+fn ViewerLine(props: ViewerLineProps) -> Element {
+    let (gutter, row_class, text) = match props.line {
+        ViewerLineKind::Source(number, text) => (number.to_string(), "", text),
+        ViewerLineKind::Diff(DiffLine::Added(text)) => ("+".to_string(), "bg-emerald-900/30", text),
+        ViewerLineKind::Diff(DiffLine::Removed(text)) => ("−".to_string(), "bg-red-900/30", text),
+        ViewerLineKind::Diff(DiffLine::Context(text)) => (String::new(), "", text),
+        ViewerLineKind::Diff(DiffLine::Skipped(count)) => {
+            return rsx! {
+                div {
+                    class: "px-3 bg-slate-800/60 text-slate-500 font-sans",
+                    "⋯ {count} unchanged line(s)"
+                }
+            };
+        }
+    };
+    let tokens = highlight_line(&text, props.file_type);
+
+    rsx! {
+        div {
+            class: "flex {row_class}",
+            span {
+                class: "w-12 flex-shrink-0 pr-2 text-right text-slate-600 select-none",
+                "{gutter}"
+            }
+            pre {
+                class: "flex-1 whitespace-pre",
+                for (i, (token, kind)) in tokens.into_iter().enumerate() {
+                    span { key: "{i}", class: kind.class(), "{token}" }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// File Tree
+// ============================================================================
+
+/// A directory or file row of the tree
+#[derive(Debug, Clone, PartialEq)]
+struct TreeRow {
+    /// Unique key: the path up to and including this row
+    key: String,
+    /// Directory or file name
+    name: String,
+    /// Nesting level, 0 at the output root
+    depth: usize,
+    /// Path and status, for file rows
+    file: Option<(PathBuf, FileStatus)>,
+}
+
+impl TreeRow {
+    /// Left padding in rem
+    fn indent(&self) -> f32 {
+        0.75 + self.depth as f32 * 0.75
+    }
+}
+
+/// Flatten sorted file paths into a tree, adding a row for each directory
+/// the first time it appears
+fn tree_rows(files: &[&FilePreview]) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    let mut open: Vec<String> = Vec::new();
+
+    for file in files {
+        let parts: Vec<String> = file
+            .path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let Some((name, dirs)) = parts.split_last() else {
+            continue;
+        };
+
+        let shared = open.iter().zip(dirs).take_while(|(a, b)| a == b).count();
+        open.truncate(shared);
+        for (depth, dir) in dirs.iter().enumerate().skip(shared) {
+            open.push(dir.clone());
+            rows.push(TreeRow {
+                key: open.join("/"),
+                name: dir.clone(),
+                depth,
+                file: None,
+            });
+        }
+
+        rows.push(TreeRow {
+            key: path_key(&file.path),
+            name: name.clone(),
+            depth: dirs.len(),
+            file: Some((file.path.clone(), file.status)),
+        });
+    }
+    rows
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn status_marker(status: FileStatus) -> &'static str {
+    match status {
+        FileStatus::Added => "A",
+        FileStatus::Changed => "M",
+        FileStatus::Unchanged => "·",
+        FileStatus::Removed => "D",
+    }
+}
+
+fn status_class(status: FileStatus) -> &'static str {
+    match status {
+        FileStatus::Added => "bg-emerald-900/40 text-emerald-300",
+        FileStatus::Changed => "bg-amber-900/40 text-amber-300",
+        FileStatus::Unchanged => "text-slate-500",
+        FileStatus::Removed => "bg-red-900/40 text-red-300",
+    }
+}
+
+// ============================================================================
+// Viewer Lines
+// ============================================================================
+
+/// A line shown in the viewer
+#[derive(Debug, Clone, PartialEq)]
+enum ViewerLineKind {
+    /// Line number and text of the generated file
+    Source(usize, String),
+    /// A line of the diff against the copy on disk
+    Diff(DiffLine),
+}
+
+fn viewer_lines(file: &FilePreview, as_diff: bool) -> Vec<ViewerLineKind> {
+    if as_diff {
+        compact_diff(file.diff(), DIFF_CONTEXT)
+            .into_iter()
+            .map(ViewerLineKind::Diff)
+            .collect()
+    } else {
+        file.content
+            .lines()
+            .enumerate()
+            .map(|(i, line)| ViewerLineKind::Source(i + 1, line.to_string()))
+            .collect()
+    }
+}
+
+// ============================================================================
+// Syntax Highlighting
+// ============================================================================
+
+/// Kind of a highlighted token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Plain,
+    Keyword,
+    String,
+    Number,
+    Comment,
+}
+
+impl TokenKind {
+    fn class(&self) -> &'static str {
+        match self {
+            TokenKind::Plain => "text-slate-300",
+            TokenKind::Keyword => "text-violet-400",
+            TokenKind::String => "text-emerald-300",
+            TokenKind::Number => "text-amber-300",
+            TokenKind::Comment => "text-slate-500 italic",
+        }
+    }
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+
+const SQL_KEYWORDS: &[&str] = &[
+    "ADD",
+    "ALTER",
+    "AND",
+    "BIGINT",
+    "BOOLEAN",
+    "BY",
+    "CASCADE",
+    "CHECK",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "DEFAULT",
+    "DELETE",
+    "DROP",
+    "EXISTS",
+    "FOREIGN",
+    "FROM",
+    "IF",
+    "INDEX",
+    "INSERT",
+    "INTEGER",
+    "INTO",
+    "KEY",
+    "NOT",
+    "NULL",
+    "ON",
+    "OR",
+    "PRIMARY",
+    "REFERENCES",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "TEXT",
+    "TIMESTAMP",
+    "UNIQUE",
+    "UPDATE",
+    "UUID",
+    "VALUES",
+    "VARCHAR",
+    "WHERE",
+];
+
+const DATA_KEYWORDS: &[&str] = &["true", "false", "null"];
+
+/// Split one line into coloured tokens. Each line is highlighted on its
+/// own, so block comments and multi-line strings only colour their first
+/// line.
+fn highlight_line(line: &str, file_type: FileType) -> Vec<(String, TokenKind)> {
+    let comment = match file_type {
+        FileType::Rust | FileType::Json => "//",
+        FileType::Sql => "--",
+        FileType::Toml | FileType::Yaml | FileType::Env => "#",
+        FileType::Markdown | FileType::Other => return vec![(line.to_string(), TokenKind::Plain)],
+    };
+    if file_type == FileType::Toml && line.trim_start().starts_with('[') {
+        return vec![(line.to_string(), TokenKind::Keyword)];
+    }
+
+    let mut tokens: Vec<(String, TokenKind)> = Vec::new();
+    let mut push = |text: &str, kind: TokenKind| match tokens.last_mut() {
+        Some((last, last_kind)) if *last_kind == kind => last.push_str(text),
+        _ => tokens.push((text.to_string(), kind)),
+    };
+
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with(comment) {
+            push(rest, TokenKind::Comment);
+            break;
+        }
+
+        let len = if c == '"' || (c == '\'' && file_type == FileType::Sql) {
+            let end = string_end(rest, c);
+            push(&rest[..end], TokenKind::String);
+            end
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '.'))
+                .unwrap_or(rest.len());
+            push(&rest[..end], TokenKind::Number);
+            end
+        } else if c.is_alphabetic() || c == '_' {
+            let end = rest
+                .find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            let keyword = match file_type {
+                FileType::Rust => RUST_KEYWORDS.contains(&word),
+                FileType::Sql => SQL_KEYWORDS.contains(&word.to_ascii_uppercase().as_str()),
+                _ => DATA_KEYWORDS.contains(&word),
+            };
+            push(
+                word,
+                if keyword {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Plain
+                },
+            );
+            end
+        } else {
+            push(&rest[..c.len_utf8()], TokenKind::Plain);
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+/// Byte length of the string literal at the start of `text`, including its
+/// quotes; an unterminated string runs to the end of the line
+fn string_end(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + c.len_utf8();
+        }
+    }
+    text.len()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, status: FileStatus) -> FilePreview {
+        FilePreview {
+            path: PathBuf::from(path),
+            status,
+            file_type: FileType::Rust,
+            content: String::new(),
+            existing: None,
+        }
+    }
+
+    #[test]
+    fn test_tree_rows() {
+        let files = [
+            file("Cargo.toml", FileStatus::Added),
+            file("src/handlers/user.rs", FileStatus::Changed),
+            file("src/handlers/tag.rs", FileStatus::Removed),
+            file("src/main.rs", FileStatus::Unchanged),
+        ];
+        let refs: Vec<&FilePreview> = files.iter().collect();
+
+        // Indented names, directories ending in `/`
+        let rows: Vec<String> = tree_rows(&refs)
+            .iter()
+            .map(|r| {
+                let slash = if r.file.is_some() { "" } else { "/" };
+                format!("{}{}{}", "  ".repeat(r.depth), r.name, slash)
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                "Cargo.toml",
+                "src/",
+                "  handlers/",
+                "    user.rs",
+                "    tag.rs",
+                "  main.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_rust() {
+        let tokens = highlight_line("pub fn max() -> u32 { 42 } // \"answer\"", FileType::Rust);
+        assert_eq!(tokens[0], ("pub".to_string(), TokenKind::Keyword));
+        assert!(tokens.contains(&("42".to_string(), TokenKind::Number)));
+        assert_eq!(
+            tokens.last().unwrap(),
+            &("// \"answer\"".to_string(), TokenKind::Comment)
+        );
+
+        let tokens = highlight_line(r#"let s = "a \" b";"#, FileType::Rust);
+        assert!(tokens.contains(&(r#""a \" b""#.to_string(), TokenKind::String)));
+    }
+
+    #[test]
+    fn test_highlight_sql_and_toml() {
+        let tokens = highlight_line("create table users ('x'); -- note", FileType::Sql);
+        assert_eq!(tokens[0], ("create".to_string(), TokenKind::Keyword));
+        assert!(tokens.contains(&("'x'".to_string(), TokenKind::String)));
+        assert_eq!(tokens.last().unwrap().1, TokenKind::Comment);
+
+        assert_eq!(
+            highlight_line("[dependencies]", FileType::Toml),
+            vec![("[dependencies]".to_string(), TokenKind::Keyword)]
+        );
+        assert_eq!(
+            highlight_line("# Title", FileType::Markdown),
+            vec![("# Title".to_string(), TokenKind::Plain)]
+        );
+    }
+
+    #[test]
+    fn test_viewer_lines() {
+        let mut changed = file("src/lib.rs", FileStatus::Changed);
+        changed.content = "a\nb\n".to_string();
+        changed.existing = Some("a\nc\n".to_string());
+
+        assert_eq!(
+            viewer_lines(&changed, false),
+            vec![
+                ViewerLineKind::Source(1, "a".to_string()),
+                ViewerLineKind::Source(2, "b".to_string()),
+            ]
+        );
+        assert_eq!(
+            viewer_lines(&changed, true),
+            vec![
+                ViewerLineKind::Diff(DiffLine::Context("a".to_string())),
+                ViewerLineKind::Diff(DiffLine::Removed("c".to_string())),
+                ViewerLineKind::Diff(DiffLine::Added("b".to_string())),
+            ]
+        );
+    }
+}
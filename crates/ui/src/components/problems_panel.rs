@@ -0,0 +1,114 @@
+//! # Problems Panel Component
+//!
+//! Bottom panel (toggled from the status bar or with `Ctrl+Shift+M`) listing
+//! the project's validation errors and warnings from [`crate::problems`].
+//! The list follows edits as they happen; clicking a problem selects the
+//! entity, field, relationship or endpoint it is about and opens its edit
+//! dialog.
+
+use dioxus::prelude::*;
+
+use crate::problems::{Problem, Severity, counts};
+use crate::state::APP_STATE;
+
+/// Validation problems docked above the status bar
+#[component]
+pub fn ProblemsPanel() -> Element {
+    let mut show_warnings = use_signal(|| true);
+
+    let state = APP_STATE.read();
+    if !state.ui.problems_open || !state.has_project() {
+        return rsx! {};
+    }
+    let (error_count, warning_count) = counts(&state.problems);
+    let problems: Vec<Problem> = state
+        .problems
+        .iter()
+        .filter(|p| show_warnings() || p.severity == Severity::Error)
+        .cloned()
+        .collect();
+    drop(state);
+
+    rsx! {
+        section {
+            class: "problems-panel h-48 bg-slate-800 border-t border-slate-700 flex flex-col shrink-0",
+
+            // Header
+            div {
+                class: "h-8 border-b border-slate-700 flex items-center gap-3 px-3 text-xs",
+                span { class: "font-medium text-sm text-slate-200", "Problems" }
+                span { class: "text-red-400", "{error_count} errors" }
+                span { class: "text-amber-400", "{warning_count} warnings" }
+
+                div { class: "flex-1" }
+
+                label {
+                    class: "flex items-center gap-1 text-slate-400 cursor-pointer",
+                    input {
+                        r#type: "checkbox",
+                        checked: show_warnings(),
+                        onchange: move |e| show_warnings.set(e.checked()),
+                    }
+                    "Show warnings"
+                }
+                button {
+                    class: "p-1 hover:bg-slate-700 rounded",
+                    title: "Close problems",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.toggle_problems();
+                    },
+                    "✕"
+                }
+            }
+
+            // Problem list
+            div {
+                class: "flex-1 overflow-y-auto py-1 text-sm",
+
+                if problems.is_empty() {
+                    div {
+                        class: "px-4 py-6 text-center text-slate-500",
+                        if error_count + warning_count == 0 {
+                            "No problems found"
+                        } else {
+                            "No errors — warnings are hidden"
+                        }
+                    }
+                }
+
+                for (i, problem) in problems.into_iter().enumerate() {
+                    ProblemRow { key: "{i}-{problem.code}", problem }
+                }
+            }
+        }
+    }
+}
+
+/// One clickable problem
+#[component]
+fn ProblemRow(problem: Problem) -> Element {
+    let clickable = problem.target.is_some();
+    let path = problem.path.clone().unwrap_or_default();
+    let title = problem.suggestion.clone().unwrap_or_default();
+    let icon = problem.severity.icon();
+    let message = problem.message.clone();
+
+    rsx! {
+        button {
+            class: if clickable {
+                "w-full flex items-baseline gap-2 px-3 py-1 text-left text-slate-300 hover:bg-slate-700/60"
+            } else {
+                "w-full flex items-baseline gap-2 px-3 py-1 text-left text-slate-400 cursor-default"
+            },
+            title: "{title}",
+            disabled: !clickable,
+            onclick: move |_| {
+                APP_STATE.write().open_problem(&problem);
+            },
+
+            span { class: "shrink-0", "{icon}" }
+            span { class: "flex-1 truncate", "{message}" }
+            span { class: "shrink-0 truncate max-w-[40%] text-xs text-slate-500 font-mono", "{path}" }
+        }
+    }
+}
@@ -7,6 +7,7 @@
 //! - **Dynamic content** based on selection state
 //! - **Entity properties**: Name, table name, description, configuration
 //! - **Field list** with inline editing, reordering, and quick actions
+//! - **Field sets**: read-only groups of the fields an entity includes
 //! - **Field properties**: Type, constraints, validations, foreign key info
 //! - **Position & Size** display for selected entities
 //! - **Relationships view**: Shows connections to other entities
@@ -25,10 +26,12 @@
 
 use dioxus::prelude::*;
 use imortal_core::types::DataType;
+use imortal_ir::FieldSet;
 use imortal_ir::entity::Entity;
 use imortal_ir::field::Field;
 use uuid::Uuid;
 
+use crate::components::field_row::CompactFieldRow;
 use crate::components::inputs::{Select, SelectOption, TextArea, TextInput, Toggle};
 use crate::state::{APP_STATE, DeleteTarget, Dialog, Page};
 
@@ -99,6 +102,16 @@ pub fn PropertiesPanel(props: PropertiesPanelProps) -> Element {
         })
     });
 
+    // Field sets the selected entity includes
+    let field_sets: Vec<FieldSet> = match (&selected_entity, &state.project) {
+        (Some(entity), Some(project)) => project
+            .included_field_sets(entity)
+            .into_iter()
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+
     // Get relationships for selected entity
     let relationships: Vec<(String, String, String)> = if let Some(ref entity) = selected_entity {
         state
@@ -192,6 +205,7 @@ pub fn PropertiesPanel(props: PropertiesPanelProps) -> Element {
                 } else if let Some(entity) = selected_entity {
                     EntityPropertiesPanel {
                         entity: entity,
+                        field_sets: field_sets,
                         relationships: relationships,
                     }
                 } else {
@@ -414,12 +428,17 @@ fn ProjectOverview() -> Element {
                     ShortcutRow { keys: "Escape", action: "Clear selection" }
                     ShortcutRow { keys: "Ctrl+A", action: "Select all" }
                     ShortcutRow { keys: "Ctrl+D", action: "Duplicate" }
+                    ShortcutRow { keys: "Ctrl+C", action: "Copy fields" }
+                    ShortcutRow { keys: "Ctrl+V", action: "Paste fields" }
                     ShortcutRow { keys: "Ctrl+Z", action: "Undo" }
                     ShortcutRow { keys: "Ctrl+Y", action: "Redo" }
+                    ShortcutRow { keys: "Ctrl+K", action: "Search / commands" }
+                    ShortcutRow { keys: "Ctrl+Shift+M", action: "Problems panel" }
                     ShortcutRow { keys: "Arrow keys", action: "Move selected" }
                     ShortcutRow { keys: "Shift+Arrows", action: "Move faster" }
                     ShortcutRow { keys: "Space+Drag", action: "Pan canvas" }
                     ShortcutRow { keys: "Scroll", action: "Zoom" }
+                    ShortcutRow { keys: "Ctrl+Shift+D", action: "Diagnostics overlay" }
                 }
             }
         }
@@ -433,6 +452,7 @@ fn ProjectOverview() -> Element {
 #[derive(Props, Clone, PartialEq)]
 struct EntityPropertiesPanelProps {
     entity: Entity,
+    field_sets: Vec<FieldSet>,
     relationships: Vec<(String, String, String)>,
 }
 
@@ -637,6 +657,35 @@ fn EntityPropertiesPanel(props: EntityPropertiesPanelProps) -> Element {
                 }
             }
 
+            // Included field sets, edited from the entity dialog
+            if !props.field_sets.is_empty() {
+                Section {
+                    title: "Field Sets",
+                    icon: "⧉",
+                    default_open: true,
+                    badge: Some(props.field_sets.len().to_string()),
+
+                    div {
+                        class: "space-y-2",
+
+                        for set in props.field_sets.iter() {
+                            div {
+                                key: "{set.id}",
+                                class: "px-3 py-2 bg-slate-700/30 border border-dashed border-slate-600 rounded-lg space-y-1",
+                                title: "Shared with other entities; change which sets are included from Edit Entity",
+                                div {
+                                    class: "text-sm font-medium text-slate-300",
+                                    "{set.name}"
+                                }
+                                for field in set.fields.iter() {
+                                    CompactFieldRow { key: "{field.id}", field: field.clone() }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // Relationships
             if !relationships.is_empty() {
                 Section {
@@ -838,6 +887,7 @@ fn FieldPropertiesPanel(props: FieldPropertiesPanelProps) -> Element {
         SelectOption::new("Int64", "Int64"),
         SelectOption::new("Float32", "Float32"),
         SelectOption::new("Float64", "Float64"),
+        SelectOption::new("Decimal", "Decimal"),
         SelectOption::new("Bool", "Boolean"),
         SelectOption::new("Uuid", "UUID"),
         SelectOption::new("DateTime", "DateTime"),
@@ -848,7 +898,7 @@ fn FieldPropertiesPanel(props: FieldPropertiesPanelProps) -> Element {
     ];
 
     let current_type = format!("{:?}", field.data_type)
-        .split('(')
+        .split(['(', ' '])
         .next()
         .unwrap_or("String")
         .to_string();
@@ -949,6 +999,7 @@ fn FieldPropertiesPanel(props: FieldPropertiesPanelProps) -> Element {
                                 "Int64" => DataType::Int64,
                                 "Float32" => DataType::Float32,
                                 "Float64" => DataType::Float64,
+                                "Decimal" => DataType::money(),
                                 "Bool" => DataType::Bool,
                                 "Uuid" => DataType::Uuid,
                                 "DateTime" => DataType::DateTime,
@@ -1422,46 +1473,13 @@ fn ActionButton(props: ActionButtonProps) -> Element {
 // ============================================================================
 
 fn duplicate_entity(entity_id: Uuid) {
-    let mut state = APP_STATE.write();
-    if let Some(project) = &mut state.project {
-        if let Some(entity) = project.entities.get(&entity_id).cloned() {
-            let new_entity = entity
-                .duplicate()
-                .at(entity.position.x + 30.0, entity.position.y + 30.0);
-            let new_id = new_entity.id;
-            project.entities.insert(new_id, new_entity);
-            state.selection.select_entity(new_id);
-            state.is_dirty = true;
-        }
-    }
-    drop(state);
-    APP_STATE.write().save_to_history("Duplicate entity");
+    APP_STATE.write().duplicate_entities(&[entity_id]);
 }
 
 fn duplicate_selected_entities() {
     let mut state = APP_STATE.write();
     let selected: Vec<_> = state.selection.entities.iter().copied().collect();
-
-    if let Some(project) = &mut state.project {
-        let mut new_ids = Vec::new();
-        for id in selected {
-            if let Some(entity) = project.entities.get(&id).cloned() {
-                let new_entity = entity
-                    .duplicate()
-                    .at(entity.position.x + 30.0, entity.position.y + 30.0);
-                let new_id = new_entity.id;
-                project.entities.insert(new_id, new_entity);
-                new_ids.push(new_id);
-            }
-        }
-        state.selection.clear();
-        for id in new_ids {
-            state.selection.add_entity(id);
-        }
-        state.is_dirty = true;
-    }
-    drop(state);
-    APP_STATE.write().save_to_history("Duplicate entities");
+    state.duplicate_entities(&selected);
 }
 
 fn align_entities_horizontal() {
@@ -1486,7 +1504,7 @@ fn align_entities_horizontal() {
                 entity.touch();
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     }
     drop(state);
     APP_STATE.write().save_to_history("Align horizontal");
@@ -1514,7 +1532,7 @@ fn align_entities_vertical() {
                 entity.touch();
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     }
     drop(state);
     APP_STATE.write().save_to_history("Align vertical");
@@ -1541,7 +1559,7 @@ fn align_entities_left() {
                 entity.touch();
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     }
     drop(state);
     APP_STATE.write().save_to_history("Align left");
@@ -1568,7 +1586,7 @@ fn align_entities_right() {
                 entity.touch();
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     }
     drop(state);
     APP_STATE.write().save_to_history("Align right");
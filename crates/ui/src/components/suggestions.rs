@@ -0,0 +1,138 @@
+//! # Suggestion Chips
+//!
+//! Shows [`Suggestion`]s for the entity being edited as chips that can be
+//! accepted or dismissed.
+//!
+//! Suggestions come from a process-wide [`Suggester`]. By default it holds
+//! the offline [`RuleBasedProvider`](imortal_ir::RuleBasedProvider); an
+//! application embedding the editor can register its own providers (for
+//! example one backed by a language model) with [`install_suggester`]
+//! before calling [`launch`](crate::launch). Building without the `assist`
+//! feature turns suggestions off entirely.
+
+use std::sync::OnceLock;
+
+use dioxus::prelude::*;
+use imortal_ir::{Entity, Suggester, Suggestion, SuggestionKind};
+
+/// The suggester used by the editor, set once
+static SUGGESTER: OnceLock<Suggester> = OnceLock::new();
+
+/// Replace the default suggester. Must be called before the first dialog
+/// asks for suggestions; returns the suggester back if one is already set.
+pub fn install_suggester(suggester: Suggester) -> Result<(), Suggester> {
+    SUGGESTER.set(suggester)
+}
+
+/// Suggestions for the entity, empty when the `assist` feature is off
+#[cfg(feature = "assist")]
+pub fn suggestions_for(entity: &Entity) -> Vec<Suggestion> {
+    SUGGESTER
+        .get_or_init(Suggester::with_default_providers)
+        .suggest(entity)
+}
+
+/// Suggestions for the entity, empty when the `assist` feature is off
+#[cfg(not(feature = "assist"))]
+pub fn suggestions_for(_entity: &Entity) -> Vec<Suggestion> {
+    Vec::new()
+}
+
+/// Icon shown in front of each kind of suggestion
+fn kind_icon(kind: &SuggestionKind) -> &'static str {
+    match kind {
+        SuggestionKind::Field(_) => "➕",
+        SuggestionKind::EntityDescription(_) | SuggestionKind::FieldDescription { .. } => "📝",
+        SuggestionKind::Validation { .. } => "✓",
+    }
+}
+
+// ============================================================================
+// Component
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SuggestionChipsProps {
+    /// Suggestions to show, in order
+    pub suggestions: Vec<Suggestion>,
+
+    /// Called when a chip is accepted
+    pub on_accept: EventHandler<Suggestion>,
+
+    /// Called when a chip is dismissed
+    pub on_dismiss: EventHandler<Suggestion>,
+}
+
+/// Row of accept/dismiss chips. Renders nothing without suggestions.
+#[component]
+pub fn SuggestionChips(props: SuggestionChipsProps) -> Element {
+    if props.suggestions.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "space-y-2",
+
+            p {
+                class: "text-xs font-semibold text-slate-400 uppercase tracking-wider",
+                "Suggestions"
+            }
+
+            div {
+                class: "flex flex-wrap gap-2",
+
+                for suggestion in props.suggestions.iter().cloned() {
+                    {
+                        let key = suggestion.key();
+                        let title = format!("{} ({})", suggestion.reason, suggestion.source);
+                        let for_accept = suggestion.clone();
+                        let for_dismiss = suggestion.clone();
+                        rsx! {
+                            div {
+                                key: "{key}",
+                                class: "flex items-center gap-1 pl-2 pr-1 py-1 text-xs bg-indigo-500/20 border border-indigo-500/50 rounded-full text-indigo-300",
+                                title: "{title}",
+
+                                button {
+                                    r#type: "button",
+                                    class: "flex items-center gap-1 max-w-xs hover:text-white",
+                                    onclick: move |_| props.on_accept.call(for_accept.clone()),
+                                    span { "{kind_icon(&suggestion.kind)}" }
+                                    span { class: "truncate", "{suggestion.label()}" }
+                                }
+
+                                button {
+                                    r#type: "button",
+                                    class: "px-1 text-slate-400 hover:text-white",
+                                    title: "Dismiss",
+                                    onclick: move |_| props.on_dismiss.call(for_dismiss.clone()),
+                                    "×"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestions_for_known_entity() {
+        let suggestions = suggestions_for(&Entity::new("Customer"));
+        if cfg!(feature = "assist") {
+            assert!(suggestions.iter().any(|s| s.key() == "field:email"));
+        } else {
+            assert!(suggestions.is_empty());
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! # Diagnostics
+//!
+//! Lightweight performance counters behind the diagnostics overlay
+//! (`Ctrl+Shift+D`): re-renders per component region, the rate of
+//! `APP_STATE` updates, the canvas frame rate and the size of the project
+//! graph. Its serialized size is measured once per project revision, not on
+//! every refresh.
+//!
+//! Counters live in a process-wide registry instead of signals, so recording
+//! a render never schedules another one. Recording is a no-op while the
+//! overlay is closed, and opening it starts from zero.
+//!
+//! [`Snapshot::report`] formats the numbers as plain text suitable for
+//! pasting into an issue.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use imortal_ir::ProjectGraph;
+
+/// Region name the canvas records its renders under; its render rate is the
+/// canvas frame rate.
+pub const CANVAS_REGION: &str = "canvas";
+
+/// Window over which per-second rates are measured
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Whether counters are currently being recorded
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Global counter registry
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry::new());
+
+// ============================================================================
+// Recording
+// ============================================================================
+
+/// Turn recording on or off. Enabling clears any previous counts.
+pub fn set_enabled(enabled: bool) {
+    if enabled && !ENABLED.load(Ordering::Relaxed) {
+        reset();
+    }
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether recording is on (i.e. the overlay is open)
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Count one render of a component region
+pub fn record_render(region: &'static str) {
+    if is_enabled() {
+        with_registry(|r| r.record_render(region, Instant::now()));
+    }
+}
+
+/// Count one `APP_STATE` update seen by subscribers
+pub fn record_state_write() {
+    if is_enabled() {
+        with_registry(|r| r.record_state_write(Instant::now()));
+    }
+}
+
+/// Clear all counters
+pub fn reset() {
+    with_registry(|r| *r = Registry::new());
+}
+
+/// Current counters, plus the footprint of `project` if one is open.
+///
+/// `revision` is the project's
+/// [`project_revision`](crate::state::AppState::project_revision); the
+/// footprint is only measured again once it changes.
+pub fn snapshot(project: Option<&ProjectGraph>, revision: u64) -> Snapshot {
+    with_registry(|r| {
+        let mut snapshot = r.snapshot(Instant::now());
+        snapshot.project = project.map(|p| r.footprint(p, revision));
+        snapshot
+    })
+}
+
+fn with_registry<T>(f: impl FnOnce(&mut Registry) -> T) -> T {
+    // A panic while holding the lock only loses counts; keep going
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut registry)
+}
+
+// ============================================================================
+// Counters
+// ============================================================================
+
+/// Event count with a sliding one-second window for the rate
+#[derive(Debug)]
+struct RateCounter {
+    total: u64,
+    recent: VecDeque<Instant>,
+}
+
+impl RateCounter {
+    const fn new() -> Self {
+        Self {
+            total: 0,
+            recent: VecDeque::new(),
+        }
+    }
+
+    fn record(&mut self, now: Instant) {
+        self.total += 1;
+        self.recent.push_back(now);
+        self.prune(now);
+    }
+
+    /// Events in the last second
+    fn rate(&mut self, now: Instant) -> usize {
+        self.prune(now);
+        self.recent.len()
+    }
+
+    fn prune(&mut self, now: Instant) {
+        while let Some(&t) = self.recent.front() {
+            if now.saturating_duration_since(t) < WINDOW {
+                break;
+            }
+            self.recent.pop_front();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Registry {
+    renders: BTreeMap<&'static str, RateCounter>,
+    state_writes: RateCounter,
+    /// Last footprint measured, with the project revision it belongs to
+    footprint: Option<(u64, GraphFootprint)>,
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            renders: BTreeMap::new(),
+            state_writes: RateCounter::new(),
+            footprint: None,
+        }
+    }
+
+    fn record_render(&mut self, region: &'static str, now: Instant) {
+        self.renders
+            .entry(region)
+            .or_insert_with(RateCounter::new)
+            .record(now);
+    }
+
+    fn record_state_write(&mut self, now: Instant) {
+        self.state_writes.record(now);
+    }
+
+    fn footprint(&mut self, project: &ProjectGraph, revision: u64) -> GraphFootprint {
+        match &self.footprint {
+            Some((measured, footprint)) if *measured == revision => footprint.clone(),
+            _ => {
+                let footprint = GraphFootprint::of(project);
+                self.footprint = Some((revision, footprint.clone()));
+                footprint
+            }
+        }
+    }
+
+    fn snapshot(&mut self, now: Instant) -> Snapshot {
+        let regions: Vec<RegionStats> = self
+            .renders
+            .iter_mut()
+            .map(|(name, counter)| RegionStats {
+                name,
+                renders: counter.total,
+                per_second: counter.rate(now),
+            })
+            .collect();
+        let canvas_fps = regions
+            .iter()
+            .find(|r| r.name == CANVAS_REGION)
+            .map_or(0, |r| r.per_second);
+
+        Snapshot {
+            regions,
+            state_writes: self.state_writes.total,
+            state_writes_per_second: self.state_writes.rate(now),
+            canvas_fps,
+            project: None,
+        }
+    }
+}
+
+// ============================================================================
+// Snapshot
+// ============================================================================
+
+/// Render counts for one component region
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionStats {
+    /// Region name (e.g. "toolbar", "canvas")
+    pub name: &'static str,
+    /// Renders since recording started
+    pub renders: u64,
+    /// Renders in the last second
+    pub per_second: usize,
+}
+
+/// Size of the open project graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphFootprint {
+    /// Number of entities
+    pub entities: usize,
+    /// Number of fields across all entities
+    pub fields: usize,
+    /// Number of relationships
+    pub relationships: usize,
+    /// Number of endpoint groups
+    pub endpoints: usize,
+    /// Size of the project serialized as JSON, in bytes. Only a rough
+    /// indication of its heap usage
+    pub serialized_bytes: usize,
+}
+
+impl GraphFootprint {
+    /// Measure a project graph
+    pub fn of(project: &ProjectGraph) -> Self {
+        Self {
+            entities: project.entities.len(),
+            fields: project.entities.values().map(|e| e.fields.len()).sum(),
+            relationships: project.relationships.len(),
+            endpoints: project.endpoints.len(),
+            serialized_bytes: serde_json::to_vec(project).map_or(0, |v| v.len()),
+        }
+    }
+}
+
+/// Point-in-time view of every counter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// Per-region render counts, sorted by name
+    pub regions: Vec<RegionStats>,
+    /// `APP_STATE` updates since recording started
+    pub state_writes: u64,
+    /// `APP_STATE` updates in the last second
+    pub state_writes_per_second: usize,
+    /// Canvas renders in the last second
+    pub canvas_fps: usize,
+    /// Footprint of the open project, if any
+    pub project: Option<GraphFootprint>,
+}
+
+impl Snapshot {
+    /// Plain-text report for attaching to a performance issue
+    pub fn report(&self) -> String {
+        let mut out = format!(
+            "Immortal Engine {} diagnostics ({} {})\n",
+            crate::VERSION,
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+        out.push_str(&format!("canvas fps: {}\n", self.canvas_fps));
+        out.push_str(&format!(
+            "state writes: {} total, {}/s\n",
+            self.state_writes, self.state_writes_per_second
+        ));
+
+        match &self.project {
+            Some(p) => out.push_str(&format!(
+                "project graph: {} entities, {} fields, {} relationships, {} endpoint groups, {} serialized\n",
+                p.entities,
+                p.fields,
+                p.relationships,
+                p.endpoints,
+                format_bytes(p.serialized_bytes)
+            )),
+            None => out.push_str("project graph: no project open\n"),
+        }
+
+        out.push_str("renders:\n");
+        for region in &self.regions {
+            out.push_str(&format!(
+                "  {:<16} {:>8} total {:>5}/s\n",
+                region.name, region.renders, region.per_second
+            ));
+        }
+        out
+    }
+}
+
+/// Human-readable byte count (B / KB / MB)
+pub fn format_bytes(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < KB {
+        format!("{} B", bytes)
+    } else if b < KB * KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{:.1} MB", b / (KB * KB))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_counter_drops_events_outside_window() {
+        let start = Instant::now();
+        let mut counter = RateCounter::new();
+        counter.record(start);
+        counter.record(start + Duration::from_millis(400));
+        counter.record(start + Duration::from_millis(900));
+
+        assert_eq!(counter.rate(start + Duration::from_millis(950)), 3);
+        assert_eq!(counter.rate(start + Duration::from_millis(1500)), 1);
+        assert_eq!(counter.rate(start + Duration::from_secs(3)), 0);
+        assert_eq!(counter.total, 3);
+    }
+
+    #[test]
+    fn test_registry_snapshot_reports_regions_and_canvas_fps() {
+        let now = Instant::now();
+        let mut registry = Registry::new();
+        registry.record_render("toolbar", now);
+        for _ in 0..5 {
+            registry.record_render(CANVAS_REGION, now);
+        }
+        registry.record_state_write(now);
+        registry.record_state_write(now);
+
+        let snapshot = registry.snapshot(now);
+        let names: Vec<&str> = snapshot.regions.iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["canvas", "toolbar"]);
+        assert_eq!(snapshot.canvas_fps, 5);
+        assert_eq!(snapshot.state_writes, 2);
+        assert_eq!(snapshot.state_writes_per_second, 2);
+
+        let later = registry.snapshot(now + Duration::from_secs(2));
+        assert_eq!(later.canvas_fps, 0);
+        assert_eq!(later.regions[0].renders, 5);
+    }
+
+    #[test]
+    fn test_report_includes_graph_footprint() {
+        let mut project = ProjectGraph::new("perf");
+        let mut entity = imortal_ir::Entity::new("Post");
+        entity.fields.push(imortal_ir::Field::new(
+            "title",
+            imortal_core::DataType::String,
+        ));
+        project.add_entity(entity);
+
+        let mut registry = Registry::new();
+        let mut snapshot = registry.snapshot(Instant::now());
+        snapshot.project = Some(GraphFootprint::of(&project));
+
+        let footprint = snapshot.project.as_ref().unwrap();
+        assert_eq!(footprint.entities, 1);
+        assert!(footprint.fields >= 1);
+        assert!(footprint.serialized_bytes > 0);
+
+        let report = snapshot.report();
+        assert!(report.contains("canvas fps: 0"));
+        assert!(report.contains("1 entities"));
+        assert!(report.contains(" serialized\n"));
+        assert!(report.contains(crate::VERSION));
+    }
+
+    #[test]
+    fn test_footprint_is_measured_once_per_revision() {
+        let mut project = ProjectGraph::new("perf");
+        let mut registry = Registry::new();
+        let first = registry.footprint(&project, 1);
+
+        // Same revision: the cached figure, even though the graph changed
+        project.add_entity(imortal_ir::Entity::new("Post"));
+        assert_eq!(registry.footprint(&project, 1), first);
+
+        let second = registry.footprint(&project, 2);
+        assert_eq!(second.entities, 1);
+        assert!(second.serialized_bytes > first.serialized_bytes);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
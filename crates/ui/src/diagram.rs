@@ -0,0 +1,374 @@
+//! # Diagram Export
+//!
+//! Renders the project's entity-relationship diagram — the entity cards and
+//! relationship lines, where they sit on the canvas — to a standalone SVG,
+//! or rasterizes that SVG to PNG, for dropping into design docs.
+//!
+//! Cards and lines share the canvas geometry ([`card_height`],
+//! [`calculate_port_position`], [`calculate_bezier_path`]) so the export
+//! matches what is on screen, but are drawn on a light background that
+//! reads well on a page.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use imortal_core::{EngineError, EngineResult, RelationType};
+use imortal_ir::{Entity, ProjectGraph};
+
+use crate::components::connection::{
+    ARROW_SIZE, STROKE_WIDTH, bezier_path_data, calculate_bezier_path, calculate_port_position,
+    relationship_color, relationship_type_label,
+};
+use crate::components::entity_card::{
+    CARD_HEADER_HEIGHT, CARD_WIDTH, FIELD_ROW_HEIGHT, MAX_VISIBLE_FIELDS, card_height,
+};
+use crate::state::ZoomDetail;
+
+/// Space around the diagram
+const PADDING: f32 = 40.0;
+
+/// Pixels per canvas unit in PNG exports (2x for sharp text on hi-dpi)
+const PNG_SCALE: f32 = 2.0;
+
+/// Fonts for the exported text; PNG rendering falls back to any sans-serif
+const FONT_FAMILY: &str = "Inter, Segoe UI, Helvetica, Arial, sans-serif";
+
+// ============================================================================
+// Format
+// ============================================================================
+
+/// File formats the diagram can be exported as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagramFormat {
+    Svg,
+    Png,
+}
+
+impl DiagramFormat {
+    /// File extension, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            DiagramFormat::Svg => "svg",
+            DiagramFormat::Png => "png",
+        }
+    }
+
+    /// Pick the format from a file's extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "svg" => Some(DiagramFormat::Svg),
+            "png" => Some(DiagramFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Render the diagram as an SVG document
+pub fn render_svg(project: &ProjectGraph) -> String {
+    let mut entities: Vec<&Entity> = project.entities.values().collect();
+    entities.sort_by_key(|e| (e.z_index, e.name.clone()));
+
+    let mut lines = Vec::new();
+    for rel in project.relationships.values() {
+        let (Some(from), Some(to)) = (
+            project.entities.get(&rel.from_entity_id),
+            project.entities.get(&rel.to_entity_id),
+        ) else {
+            continue;
+        };
+        let start = calculate_port_position(
+            from.position,
+            rel.from_port,
+            CARD_WIDTH,
+            card_height(from, ZoomDetail::Full),
+        );
+        let end = calculate_port_position(
+            to.position,
+            rel.to_port,
+            CARD_WIDTH,
+            card_height(to, ZoomDetail::Full),
+        );
+        let (c1, c2) = calculate_bezier_path(start, end, rel.from_port, rel.to_port);
+        lines.push((rel, [start, c1, c2, end]));
+    }
+
+    // The curves stay inside their control points, so those bound them
+    let card_corners = entities.iter().flat_map(|e| {
+        let height = card_height(e, ZoomDetail::Full) as f32;
+        [
+            (e.position.x, e.position.y),
+            (e.position.x + CARD_WIDTH as f32, e.position.y + height),
+        ]
+    });
+    let curve_points = lines
+        .iter()
+        .flat_map(|(_, points)| points.map(|p| (p.x, p.y)));
+    let (min_x, min_y, max_x, max_y) = card_corners.chain(curve_points).fold(
+        (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+        |(x0, y0, x1, y1), (x, y)| (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+    );
+    let (min_x, min_y, width, height) = if min_x > max_x {
+        (0.0, 0.0, 200.0, 100.0)
+    } else {
+        (
+            min_x - PADDING,
+            min_y - PADDING,
+            max_x - min_x + PADDING * 2.0,
+            max_y - min_y + PADDING * 2.0,
+        )
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="{min_x} {min_y} {width} {height}" font-family="{FONT_FAMILY}">"#
+    );
+    let _ = writeln!(svg, "<title>{}</title>", escape(&project.meta.name));
+    let _ = writeln!(
+        svg,
+        r##"<rect x="{min_x}" y="{min_y}" width="{width}" height="{height}" fill="#ffffff"/>"##
+    );
+
+    // One arrowhead per line colour
+    let mut colors: Vec<&str> = lines
+        .iter()
+        .map(|(rel, _)| relationship_color(&rel.relation_type))
+        .collect();
+    colors.sort_unstable();
+    colors.dedup();
+    svg.push_str("<defs>\n");
+    for color in &colors {
+        let _ = writeln!(
+            svg,
+            r#"<marker id="arrow-{id}" markerWidth="{s}" markerHeight="{s}" refX="{rx}" refY="{ry}" orient="auto" markerUnits="userSpaceOnUse"><path d="M 0 0 L {s} {ry} L 0 {s} Z" fill="{color}"/></marker>"#,
+            id = color.trim_start_matches('#'),
+            s = ARROW_SIZE,
+            rx = ARROW_SIZE - 2.0,
+            ry = ARROW_SIZE / 2.0,
+        );
+    }
+    svg.push_str("</defs>\n");
+
+    for (rel, [start, c1, c2, end]) in &lines {
+        let color = relationship_color(&rel.relation_type);
+        let dash = match rel.relation_type {
+            RelationType::ManyToMany { .. } => r#" stroke-dasharray="8,4""#,
+            RelationType::Polymorphic { .. } => r#" stroke-dasharray="2,4""#,
+            _ => "",
+        };
+        let _ = writeln!(
+            svg,
+            r#"<path d="{d}" stroke="{color}" stroke-width="{STROKE_WIDTH}" fill="none"{dash} marker-end="url(#arrow-{id})"/>"#,
+            d = bezier_path_data(*start, *end, *c1, *c2),
+            id = color.trim_start_matches('#'),
+        );
+
+        let mid = start.midpoint(end);
+        let label = relationship_type_label(&rel.relation_type);
+        let _ = writeln!(
+            svg,
+            r##"<g transform="translate({x}, {y})"><rect x="-16" y="-9" width="32" height="18" rx="9" fill="#ffffff" stroke="{color}"/><text y="4" font-size="10" text-anchor="middle" fill="{color}">{label}</text></g>"##,
+            x = mid.x,
+            y = mid.y,
+        );
+    }
+
+    for entity in entities {
+        render_card(&mut svg, entity);
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draw one entity card: a header with the name, then a row per field
+fn render_card(svg: &mut String, entity: &Entity) {
+    let (x, y) = (entity.position.x, entity.position.y);
+    let width = CARD_WIDTH as f32;
+    let height = card_height(entity, ZoomDetail::Full) as f32;
+    let header = CARD_HEADER_HEIGHT as f32;
+    let row = FIELD_ROW_HEIGHT as f32;
+
+    let _ = writeln!(svg, "<g>");
+    let _ = writeln!(
+        svg,
+        r##"<rect x="{x}" y="{y}" width="{width}" height="{height}" rx="8" fill="#ffffff" stroke="#cbd5e1"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"<path d="M {x} {hy} V {r} Q {x} {y} {r2} {y} H {r3} Q {x2} {y} {x2} {r} V {hy} Z" fill="#eef2ff" stroke="#cbd5e1"/>"##,
+        hy = y + header,
+        r = y + 8.0,
+        r2 = x + 8.0,
+        r3 = x + width - 8.0,
+        x2 = x + width,
+    );
+    let _ = writeln!(
+        svg,
+        r##"<text x="{tx}" y="{ty}" font-size="14" font-weight="600" fill="#1e293b">{name}</text>"##,
+        tx = x + 12.0,
+        ty = y + header / 2.0 + 5.0,
+        name = escape(&entity.name),
+    );
+
+    if !entity.collapsed {
+        for (i, field) in entity.fields.iter().take(MAX_VISIBLE_FIELDS).enumerate() {
+            let baseline = y + header + row * i as f32 + row / 2.0 + 4.0;
+            let marker = if field.is_primary_key {
+                "PK "
+            } else if field.is_foreign_key {
+                "FK "
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                svg,
+                r##"<text x="{tx}" y="{baseline}" font-size="12" fill="#334155"><tspan fill="#b45309" font-size="10">{marker}</tspan>{name}</text>"##,
+                tx = x + 12.0,
+                name = escape(&field.name),
+            );
+            let _ = writeln!(
+                svg,
+                r##"<text x="{tx}" y="{baseline}" font-size="11" text-anchor="end" fill="#64748b">{ty}</text>"##,
+                tx = x + width - 12.0,
+                ty = escape(&field.data_type.to_string()),
+            );
+        }
+
+        let hidden = entity.fields.len().saturating_sub(MAX_VISIBLE_FIELDS);
+        if hidden > 0 {
+            let shown = entity.fields.len().min(MAX_VISIBLE_FIELDS) as f32;
+            let _ = writeln!(
+                svg,
+                r##"<text x="{tx}" y="{ty}" font-size="11" text-anchor="middle" fill="#64748b">+{hidden} more fields</text>"##,
+                tx = x + width / 2.0,
+                ty = y + header + row * shown + 22.0,
+            );
+        }
+    }
+
+    let _ = writeln!(svg, "</g>");
+}
+
+/// Render the diagram to PNG bytes, at twice the canvas resolution
+pub fn render_png(project: &ProjectGraph) -> EngineResult<Vec<u8>> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options {
+        font_family: "sans-serif".to_string(),
+        ..Default::default()
+    };
+    options.fontdb_mut().load_system_fonts();
+
+    let tree = usvg::Tree::from_str(&render_svg(project), &options)
+        .map_err(|e| EngineError::Internal(format!("Failed to build the diagram: {}", e)))?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(PNG_SCALE)
+        .ok_or_else(|| EngineError::Internal("The diagram is too large to render".to_string()))?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| EngineError::Internal("The diagram is too large to render".to_string()))?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(PNG_SCALE, PNG_SCALE),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .map_err(|e| EngineError::Internal(format!("Failed to encode PNG: {}", e)))
+}
+
+/// Write the diagram to a file, in the format its extension names
+pub fn export_diagram(project: &ProjectGraph, path: &Path) -> EngineResult<DiagramFormat> {
+    let format = DiagramFormat::from_path(path).ok_or_else(|| EngineError::FileWrite {
+        path: path.to_path_buf(),
+        message: "Diagrams can be exported as .svg or .png".to_string(),
+    })?;
+    let bytes = match format {
+        DiagramFormat::Svg => render_svg(project).into_bytes(),
+        DiagramFormat::Png => render_png(project)?,
+    };
+    std::fs::write(path, bytes).map_err(|e| EngineError::FileWrite {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    Ok(format)
+}
+
+/// Escape text for SVG element content
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::DataType;
+    use imortal_ir::{Field, Relationship};
+
+    fn blog() -> ProjectGraph {
+        let mut project = ProjectGraph::new("Blog <draft>");
+        let mut user = Entity::new("User").at(0.0, 0.0);
+        user.add_field(Field::new("email", DataType::String));
+        let user = project.add_entity(user);
+        let post = project.add_entity(Entity::new("Post").at(400.0, 300.0));
+        project.add_relationship(Relationship::new(user, post, RelationType::OneToMany));
+        project
+    }
+
+    #[test]
+    fn test_diagram_format_from_path() {
+        assert_eq!(
+            DiagramFormat::from_path(Path::new("erd.SVG")),
+            Some(DiagramFormat::Svg)
+        );
+        assert_eq!(
+            DiagramFormat::from_path(Path::new("docs/erd.png")),
+            Some(DiagramFormat::Png)
+        );
+        assert_eq!(DiagramFormat::from_path(Path::new("erd.pdf")), None);
+        assert_eq!(DiagramFormat::from_path(Path::new("erd")), None);
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let svg = render_svg(&blog());
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("<title>Blog &lt;draft&gt;</title>"));
+        assert!(svg.contains(">User</text>"));
+        assert!(svg.contains(">Post</text>"));
+        assert!(svg.contains(">email</text>"));
+        assert!(svg.contains(">1:N</text>"));
+        assert_eq!(svg.matches("marker-end=").count(), 1);
+
+        // The view box covers both cards plus padding
+        let post_bottom = 300.0 + card_height(&Entity::new("Post"), ZoomDetail::Full) as f32;
+        let view_box = format!(
+            r#"viewBox="{} {} {} {}""#,
+            -PADDING,
+            -PADDING,
+            400.0 + CARD_WIDTH as f32 + PADDING * 2.0,
+            post_bottom + PADDING * 2.0
+        );
+        assert!(svg.contains(&view_box), "{}", svg);
+    }
+
+    #[test]
+    fn test_render_png() {
+        let png = render_png(&blog()).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
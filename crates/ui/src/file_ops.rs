@@ -4,13 +4,20 @@
 //! for native file dialogs. It integrates with the application state and
 //! the IR serialization module.
 
+use imortal_codegen::SchemaFormat;
 use imortal_core::{EngineError, EngineResult};
 use imortal_ir::{
     ProjectGraph,
-    serialization::{PROJECT_EXTENSION, load_project, save_project},
+    serialization::{
+        PROJECT_EXTENSION, RecoveredProject, backup_path, load_project, load_project_with_recovery,
+        save_project,
+    },
 };
 use rfd::AsyncFileDialog;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::autosave;
+use crate::diagram::DiagramFormat;
 
 // ============================================================================
 // File Filter Constants
@@ -89,6 +96,66 @@ pub async fn show_save_dialog(
     Some(ensure_extension(file.path().to_path_buf()))
 }
 
+/// Open a file dialog to choose where to save a diagram export
+///
+/// The chosen extension (`.svg` or `.png`) picks the format; a name without
+/// one is saved as SVG.
+pub async fn show_diagram_save_dialog(
+    project_name: &str,
+    starting_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    let mut dialog = AsyncFileDialog::new()
+        .set_title("Export Diagram")
+        .add_filter("SVG Image", &["svg"])
+        .add_filter("PNG Image", &["png"])
+        .set_file_name(format!(
+            "{}-erd.svg",
+            project_name.replace(' ', "_").to_lowercase()
+        ));
+    if let Some(dir) = starting_dir.and_then(Path::parent)
+        && dir.exists()
+    {
+        dialog = dialog.set_directory(dir);
+    }
+
+    let path = dialog.save_file().await?.path().to_path_buf();
+    if DiagramFormat::from_path(&path).is_some() {
+        Some(path)
+    } else {
+        Some(path.with_extension(DiagramFormat::Svg.extension()))
+    }
+}
+
+/// Open a file dialog to choose where to save a schema export
+///
+/// A name without an extension gets the format's (`.dbml`, `.mmd`, `.puml`).
+pub async fn show_schema_save_dialog(
+    project_name: &str,
+    format: SchemaFormat,
+    starting_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    let mut dialog = AsyncFileDialog::new()
+        .set_title(format!("Export {} Schema", format.display_name()))
+        .add_filter(format.display_name(), &[format.extension()])
+        .set_file_name(format!(
+            "{}.{}",
+            project_name.replace(' ', "_").to_lowercase(),
+            format.extension()
+        ));
+    if let Some(dir) = starting_dir.and_then(Path::parent)
+        && dir.exists()
+    {
+        dialog = dialog.set_directory(dir);
+    }
+
+    let path = dialog.save_file().await?.path().to_path_buf();
+    if path.extension().is_some() {
+        Some(path)
+    } else {
+        Some(path.with_extension(format.extension()))
+    }
+}
+
 /// Open a file dialog to select an export directory
 ///
 /// Returns the selected directory path, or None if the dialog was cancelled.
@@ -126,12 +193,14 @@ pub async fn open_project(path: Option<PathBuf>) -> EngineResult<(ProjectGraph,
 /// If `path` is `None`, shows a save dialog. The dialog will start in
 /// `hint_dir` if provided (e.g. the last saved directory).
 ///
-/// The returned path always has the `.ieng` extension.
+/// The returned path always has the `.ieng` extension. Autosave recovery
+/// files the save made obsolete are removed.
 pub async fn save_project_to_file(
     project: &ProjectGraph,
     path: Option<PathBuf>,
     hint_dir: Option<PathBuf>,
 ) -> EngineResult<PathBuf> {
+    let previous = path.clone();
     let file_path = match path {
         Some(p) => ensure_extension(p),
         None => {
@@ -143,13 +212,15 @@ pub async fn save_project_to_file(
     };
 
     save_project(project, &file_path)?;
+    autosave::discard_after_save(previous.as_deref(), &file_path);
     Ok(file_path)
 }
 
 /// Save a project with a new name/location (Save As)
 ///
 /// Always opens a save dialog. If the project was previously saved,
-/// the dialog starts in the same directory.
+/// the dialog starts in the same directory. Autosave recovery files the save
+/// made obsolete are removed.
 pub async fn save_project_as(
     project: &ProjectGraph,
     current_path: Option<&std::path::Path>,
@@ -159,9 +230,82 @@ pub async fn save_project_as(
         .ok_or_else(|| EngineError::Cancelled)?;
 
     save_project(project, &file_path)?;
+    autosave::discard_after_save(current_path, &file_path);
     Ok(file_path)
 }
 
+// ============================================================================
+// Damaged Project Recovery
+// ============================================================================
+
+/// Whether a load error means the file was read but its contents are
+/// damaged, so the recovery options apply
+pub fn is_damaged_file_error(error: &EngineError) -> bool {
+    matches!(
+        error,
+        EngineError::ProjectParse { .. } | EngineError::InvalidProjectFormat(_)
+    )
+}
+
+/// The backup kept next to a project file, if there is one
+pub fn existing_backup(path: &Path) -> Option<PathBuf> {
+    Some(backup_path(path)).filter(|backup| backup.is_file())
+}
+
+/// Where the untouched copy of a damaged project file is kept
+pub fn damaged_copy_path(path: &Path) -> PathBuf {
+    path.with_extension(format!("{}.damaged", PROJECT_EXTENSION))
+}
+
+/// Copy a damaged project file aside before it is overwritten, unless a
+/// copy already exists (so the first, most complete one is kept)
+fn keep_damaged_copy(path: &Path) -> EngineResult<PathBuf> {
+    let copy = damaged_copy_path(path);
+    if !copy.exists() {
+        std::fs::copy(path, &copy).map_err(|e| EngineError::FileWrite {
+            path: copy.clone(),
+            message: format!("Failed to keep a copy of the damaged file: {}", e),
+        })?;
+    }
+    Ok(copy)
+}
+
+/// Load what parses of a damaged project file
+///
+/// When anything has to be left out, the file is copied aside first (see
+/// [`damaged_copy_path`]) so saving the recovered project loses nothing;
+/// the copy's path is returned with the project.
+pub fn recover_project(path: &Path) -> EngineResult<(RecoveredProject, Option<PathBuf>)> {
+    let recovered = load_project_with_recovery(path)?;
+    let copy = if recovered.is_complete() {
+        None
+    } else {
+        Some(keep_damaged_copy(path)?)
+    };
+    Ok((recovered, copy))
+}
+
+/// Read a project file's text for the raw JSON editor
+pub fn read_raw_project(path: &Path) -> EngineResult<String> {
+    std::fs::read_to_string(path).map_err(|e| EngineError::FileRead {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// Write hand-edited JSON over a damaged project file and load it
+///
+/// The edits are written even if they still don't parse, so they aren't
+/// lost; the original file is copied aside before the first write.
+pub fn save_raw_project(path: &Path, json: &str) -> EngineResult<ProjectGraph> {
+    keep_damaged_copy(path)?;
+    std::fs::write(path, json).map_err(|e| EngineError::FileWrite {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    load_project(path)
+}
+
 // ============================================================================
 // Recent Projects
 // ============================================================================
@@ -361,6 +505,57 @@ mod tests {
         assert!(!is_project_file(&invalid));
     }
 
+    #[test]
+    fn test_save_discards_recovery_file() {
+        let dir = std::env::temp_dir().join(format!("ieng-save-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("blog.ieng");
+        let project = ProjectGraph::new("blog");
+
+        // An autosave from before the save must not be offered on next launch
+        let recovery = autosave::recovery_path(Some(&path)).unwrap();
+        autosave::write_recovery(&project, &recovery).unwrap();
+        let saved =
+            tokio_test::block_on(save_project_to_file(&project, Some(path.clone()), None)).unwrap();
+        assert_eq!(saved, path);
+        assert!(!recovery.exists());
+        assert!(autosave::find_recovery(Some(&path)).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_damaged_project_recovery() {
+        let dir = std::env::temp_dir().join(format!("ieng-recover-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("shop.ieng");
+
+        let mut project = ProjectGraph::new("Shop");
+        project.add_entity(imortal_ir::Entity::new("Order"));
+        save_project(&project, &path).unwrap();
+        let intact = read_raw_project(&path).unwrap();
+        let damaged = intact.replacen("\"name\": \"Order\"", "\"name\": 7", 1);
+        std::fs::write(&path, &damaged).unwrap();
+
+        let err = load_project(&path).unwrap_err();
+        assert!(is_damaged_file_error(&err), "{}", err);
+        assert!(existing_backup(&path).is_none());
+
+        let (recovered, copy) = recover_project(&path).unwrap();
+        assert!(recovered.project.entities.is_empty());
+        assert_eq!(recovered.quarantined.len(), 1);
+        assert_eq!(copy.as_deref(), Some(damaged_copy_path(&path).as_path()));
+
+        let project = save_raw_project(&path, &intact).unwrap();
+        assert_eq!(project.entities.len(), 1);
+        assert_eq!(
+            read_raw_project(&damaged_copy_path(&path)).unwrap(),
+            damaged
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_recent_project() {
         let path = PathBuf::from("/tmp/test.ieng");
@@ -0,0 +1,376 @@
+//! # Embedded Help Catalog
+//!
+//! Contextual documentation for the concepts users configure in the editor
+//! (relationship types, referential actions, ID strategies, auth strategies,
+//! ...). Each topic explains the available options and what the generator
+//! emits for them, so users don't have to leave the app to find out.
+//!
+//! The catalog is plain static data; the [`HelpPanel`](crate::components::HelpPanel)
+//! renders whichever topic is currently focused in [`UiState`](crate::state::UiState).
+
+// ============================================================================
+// HelpTopic
+// ============================================================================
+
+/// A concept with an entry in the help catalog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HelpTopic {
+    /// Overview of the editor and the help panel itself
+    #[default]
+    Overview,
+    /// One-to-one, one-to-many, many-to-one, many-to-many
+    RelationshipTypes,
+    /// ON DELETE / ON UPDATE behaviour of foreign keys
+    ReferentialActions,
+    /// Primary key strategies (UUID, serial, CUID, ULID)
+    IdStrategies,
+    /// Authentication strategies (JWT, session, API key)
+    AuthStrategies,
+    /// Automatic created_at / updated_at columns
+    Timestamps,
+    /// Soft delete via deleted_at
+    SoftDelete,
+    /// Endpoint security (auth required, roles)
+    EndpointSecurity,
+    /// Query builder endpoints (joins, filters, projection)
+    CustomQueries,
+}
+
+/// One option within a help topic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HelpEntry {
+    /// Option name as shown in the editor
+    pub term: &'static str,
+    /// What the option means
+    pub description: &'static str,
+    /// What the generator emits for it
+    pub generates: &'static str,
+}
+
+impl HelpTopic {
+    /// All topics in display order
+    pub fn all() -> &'static [HelpTopic] {
+        &[
+            HelpTopic::Overview,
+            HelpTopic::RelationshipTypes,
+            HelpTopic::ReferentialActions,
+            HelpTopic::IdStrategies,
+            HelpTopic::AuthStrategies,
+            HelpTopic::Timestamps,
+            HelpTopic::SoftDelete,
+            HelpTopic::EndpointSecurity,
+            HelpTopic::CustomQueries,
+        ]
+    }
+
+    /// Topic title
+    pub fn title(&self) -> &'static str {
+        match self {
+            HelpTopic::Overview => "Getting Started",
+            HelpTopic::RelationshipTypes => "Relationship Types",
+            HelpTopic::ReferentialActions => "Referential Actions",
+            HelpTopic::IdStrategies => "ID Strategies",
+            HelpTopic::AuthStrategies => "Authentication Strategies",
+            HelpTopic::Timestamps => "Timestamps",
+            HelpTopic::SoftDelete => "Soft Delete",
+            HelpTopic::EndpointSecurity => "Endpoint Security",
+            HelpTopic::CustomQueries => "Custom Queries",
+        }
+    }
+
+    /// Icon/emoji for the topic
+    pub fn icon(&self) -> &'static str {
+        match self {
+            HelpTopic::Overview => "📖",
+            HelpTopic::RelationshipTypes => "🔗",
+            HelpTopic::ReferentialActions => "⛓️",
+            HelpTopic::IdStrategies => "🔑",
+            HelpTopic::AuthStrategies => "🔐",
+            HelpTopic::Timestamps => "🕒",
+            HelpTopic::SoftDelete => "🗑️",
+            HelpTopic::EndpointSecurity => "🛡️",
+            HelpTopic::CustomQueries => "🔎",
+        }
+    }
+
+    /// One-paragraph introduction to the topic
+    pub fn summary(&self) -> &'static str {
+        match self {
+            HelpTopic::Overview => {
+                "Design entities on the canvas, connect them with relationships, expose them \
+                 through endpoints, then generate a complete Axum + SeaORM project. Click any \
+                 ? icon next to an option to see what it means here."
+            }
+            HelpTopic::RelationshipTypes => {
+                "A relationship describes how records of two entities refer to each other. \
+                 The type decides where the foreign key lives and which SeaORM relations are \
+                 generated."
+            }
+            HelpTopic::ReferentialActions => {
+                "Referential actions control what the database does to child rows when the \
+                 referenced parent row is deleted or its key is updated. They become the \
+                 ON DELETE / ON UPDATE clauses of the foreign key constraint."
+            }
+            HelpTopic::IdStrategies => {
+                "The ID strategy picks the primary key type for an entity. It affects the SQL \
+                 column type, the Rust type in models and DTOs, and how new IDs are created."
+            }
+            HelpTopic::AuthStrategies => {
+                "The authentication strategy decides how clients prove who they are. Secured \
+                 endpoints reject requests that fail the check with 401 Unauthorized."
+            }
+            HelpTopic::Timestamps => {
+                "With timestamps enabled the entity gets created_at and updated_at columns that \
+                 the generated handlers maintain automatically."
+            }
+            HelpTopic::SoftDelete => {
+                "Soft delete keeps rows in the table and marks them as deleted instead of \
+                 removing them, so data can be restored or audited later."
+            }
+            HelpTopic::EndpointSecurity => {
+                "Each CRUD operation can require authentication and restrict access to roles. \
+                 Group-level security applies to every operation unless overridden."
+            }
+            HelpTopic::CustomQueries => {
+                "Custom queries are read-only report endpoints designed with the query builder. \
+                 Each one starts from an entity, can join related entities and becomes its own \
+                 GET route."
+            }
+        }
+    }
+
+    /// The options covered by this topic
+    pub fn entries(&self) -> &'static [HelpEntry] {
+        match self {
+            HelpTopic::Overview => &[
+                HelpEntry {
+                    term: "Entities",
+                    description: "Tables in your database, each with typed fields.",
+                    generates: "A SeaORM model, Create/Update/Response DTOs and a SQL migration.",
+                },
+                HelpEntry {
+                    term: "Relationships",
+                    description: "Links between entities, drawn by connecting ports on the canvas.",
+                    generates: "Foreign key columns, constraints and SeaORM Related impls.",
+                },
+                HelpEntry {
+                    term: "Endpoints",
+                    description: "CRUD operations exposed over HTTP for an entity.",
+                    generates: "Axum handlers and routes under the configured base path.",
+                },
+            ],
+            HelpTopic::RelationshipTypes => &[
+                HelpEntry {
+                    term: "One to One",
+                    description: "Each record relates to at most one record on the other side (User ↔ Profile).",
+                    generates: "A unique foreign key column on the target table and has_one / belongs_to relations.",
+                },
+                HelpEntry {
+                    term: "One to Many",
+                    description: "One parent owns many children (User → Posts).",
+                    generates: "A foreign key column on the child table and a has_many relation on the parent.",
+                },
+                HelpEntry {
+                    term: "Many to One",
+                    description: "The inverse of one-to-many, seen from the child (Post → User).",
+                    generates: "A foreign key column on this entity and a belongs_to relation.",
+                },
+                HelpEntry {
+                    term: "Many to Many",
+                    description: "Records on both sides relate to many records on the other (Posts ↔ Tags).",
+                    generates: "A junction table with two foreign keys and a composite primary key.",
+                },
+                HelpEntry {
+                    term: "Polymorphic",
+                    description: "A record points at one record of any of several entities (Comment → Post or Photo). Add one relationship per target.",
+                    generates: "Type and ID columns with an index but no foreign key, plus a target enum and lookup helpers.",
+                },
+            ],
+            HelpTopic::ReferentialActions => &[
+                HelpEntry {
+                    term: "Cascade",
+                    description: "Deleting the parent deletes its children; updating the key updates them.",
+                    generates: "ON DELETE CASCADE / ON UPDATE CASCADE.",
+                },
+                HelpEntry {
+                    term: "Set Null",
+                    description: "Children keep existing but their foreign key becomes NULL. The column must be optional.",
+                    generates: "ON DELETE SET NULL.",
+                },
+                HelpEntry {
+                    term: "Restrict",
+                    description: "Deleting a parent that still has children fails immediately.",
+                    generates: "ON DELETE RESTRICT — the API returns a conflict error instead.",
+                },
+                HelpEntry {
+                    term: "No Action",
+                    description: "Like Restrict, but the check is deferred to the end of the statement.",
+                    generates: "ON DELETE NO ACTION.",
+                },
+                HelpEntry {
+                    term: "Set Default",
+                    description: "Children fall back to the column's default value.",
+                    generates: "ON DELETE SET DEFAULT. The foreign key needs a default value.",
+                },
+            ],
+            HelpTopic::IdStrategies => &[
+                HelpEntry {
+                    term: "UUID",
+                    description: "Random 128-bit identifiers. Safe to expose and to generate anywhere.",
+                    generates: "A UUID column and uuid::Uuid in models and DTOs.",
+                },
+                HelpEntry {
+                    term: "Serial",
+                    description: "Auto-incrementing integers assigned by the database. Compact but guessable.",
+                    generates: "SERIAL (INT AUTO_INCREMENT on MySQL) and i32 in Rust.",
+                },
+                HelpEntry {
+                    term: "CUID",
+                    description: "Collision-resistant string IDs that are URL-safe.",
+                    generates: "VARCHAR(30) column and String in Rust.",
+                },
+                HelpEntry {
+                    term: "ULID",
+                    description: "Lexicographically sortable IDs — ordering by ID follows creation time.",
+                    generates: "VARCHAR(26) column and String in Rust.",
+                },
+            ],
+            HelpTopic::AuthStrategies => &[
+                HelpEntry {
+                    term: "JWT",
+                    description: "Stateless signed tokens sent in the Authorization header.",
+                    generates: "Login/register handlers, Claims type and an auth middleware that verifies tokens.",
+                },
+                HelpEntry {
+                    term: "Session",
+                    description: "Server-side sessions identified by a cookie.",
+                    generates: "Cookie support (axum-extra) and bcrypt password hashing.",
+                },
+                HelpEntry {
+                    term: "API Key",
+                    description: "Static keys for machine-to-machine clients.",
+                    generates: "Header extraction (axum-extra) and key verification helpers.",
+                },
+                HelpEntry {
+                    term: "None",
+                    description: "No authentication; every endpoint is public.",
+                    generates: "No auth module or middleware.",
+                },
+            ],
+            HelpTopic::Timestamps => &[
+                HelpEntry {
+                    term: "created_at",
+                    description: "Set once when the record is inserted.",
+                    generates: "A NOT NULL column defaulting to CURRENT_TIMESTAMP, excluded from input DTOs.",
+                },
+                HelpEntry {
+                    term: "updated_at",
+                    description: "Refreshed on every update.",
+                    generates: "A NOT NULL timestamp column set by the update handler.",
+                },
+            ],
+            HelpTopic::SoftDelete => &[
+                HelpEntry {
+                    term: "deleted_at",
+                    description: "NULL for live rows; set to the deletion time when deleted.",
+                    generates: "A nullable deleted_at column; DELETE handlers set it instead of removing the row.",
+                },
+                HelpEntry {
+                    term: "Restoring",
+                    description: "Clearing deleted_at brings a row back.",
+                    generates: "Nothing extra — the column is part of the model, so it can be reset directly.",
+                },
+            ],
+            HelpTopic::EndpointSecurity => &[
+                HelpEntry {
+                    term: "Auth required",
+                    description: "Callers must be authenticated.",
+                    generates: "The route is wrapped in the auth middleware; failures return 401.",
+                },
+                HelpEntry {
+                    term: "Roles",
+                    description: "Only users with one of the listed roles may call the endpoint.",
+                    generates: "A role check against the token claims; failures return 403.",
+                },
+                HelpEntry {
+                    term: "Public",
+                    description: "Anyone can call the endpoint.",
+                    generates: "The route is mounted without auth middleware.",
+                },
+            ],
+            HelpTopic::CustomQueries => &[
+                HelpEntry {
+                    term: "Joins",
+                    description: "Pull in an entity connected to the root by a relationship. Its fields are referenced as Entity.field.",
+                    generates: "A .join(JoinType::InnerJoin, ...) on the SeaORM relation; \"left\" joins use LeftJoin and optional columns.",
+                },
+                HelpEntry {
+                    term: "Filters",
+                    description: "Conditions combined with AND. A fixed value is baked in; a query parameter is read from the URL and skipped when absent.",
+                    generates: "A {Query}Params struct and .filter(Column.op(value)) calls.",
+                },
+                HelpEntry {
+                    term: "Returned columns",
+                    description: "The fields included in each row. Leave empty for every non-secret root field.",
+                    generates: "A {Query}Row struct deriving FromQueryResult, filled via column_as.",
+                },
+                HelpEntry {
+                    term: "Max rows",
+                    description: "Upper bound on rows returned; callers may ask for fewer with ?limit=.",
+                    generates: ".limit(params.limit.unwrap_or(max).min(max)).",
+                },
+            ],
+        }
+    }
+
+    /// Check whether the topic mentions the query (case-insensitive)
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+
+        self.title().to_lowercase().contains(&query)
+            || self.summary().to_lowercase().contains(&query)
+            || self.entries().iter().any(|e| {
+                e.term.to_lowercase().contains(&query)
+                    || e.description.to_lowercase().contains(&query)
+                    || e.generates.to_lowercase().contains(&query)
+            })
+    }
+
+    /// Topics matching a search query, in display order
+    pub fn search(query: &str) -> Vec<HelpTopic> {
+        Self::all()
+            .iter()
+            .copied()
+            .filter(|topic| topic.matches(query))
+            .collect()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_topic_has_content() {
+        for topic in HelpTopic::all() {
+            assert!(!topic.title().is_empty());
+            assert!(!topic.summary().is_empty());
+            assert!(!topic.entries().is_empty(), "{:?} has no entries", topic);
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        assert_eq!(HelpTopic::search("").len(), HelpTopic::all().len());
+        assert!(HelpTopic::search("cascade").contains(&HelpTopic::ReferentialActions));
+        assert!(HelpTopic::search("ULID").contains(&HelpTopic::IdStrategies));
+        assert!(HelpTopic::search("no such concept").is_empty());
+    }
+}
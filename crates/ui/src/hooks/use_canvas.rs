@@ -8,7 +8,8 @@
 //! - Coordinate transformations
 
 use dioxus::prelude::*;
-use imortal_core::types::{EntityId, Position};
+use imortal_core::types::{EntityId, Position, Rect, Size};
+use imortal_ir::entity::Entity;
 
 use crate::state::APP_STATE;
 
@@ -264,10 +265,39 @@ impl CanvasInteractions {
 
     /// Fit all entities in view
     pub fn fit_to_content(&self) {
-        // Future: Calculate bounding box of all entities and fit to viewport
         let mut state = APP_STATE.write();
-        state.canvas.pan = Position::zero();
-        state.canvas.zoom = 1.0;
+        let bounds = state
+            .project
+            .as_ref()
+            .and_then(|p| entities_bounds(p.entities.values()));
+
+        match bounds {
+            Some(bounds) => state.canvas.fit_rect(bounds),
+            None => state.canvas.reset_view(),
+        }
+    }
+
+    /// Fit the selected entities in view; does nothing without a selection
+    pub fn zoom_to_selection(&self) {
+        let mut state = APP_STATE.write();
+        let bounds = state.project.as_ref().and_then(|p| {
+            entities_bounds(
+                state
+                    .selection
+                    .entities
+                    .iter()
+                    .filter_map(|id| p.entities.get(id)),
+            )
+        });
+
+        if let Some(bounds) = bounds {
+            state.canvas.fit_rect(bounds);
+        }
+    }
+
+    /// Pan so a canvas point sits in the middle of the viewport
+    pub fn navigate_to(&self, canvas_pos: Position) {
+        APP_STATE.write().canvas.center_on(canvas_pos);
     }
 
     // ========================================================================
@@ -474,6 +504,22 @@ pub fn position_from_pointer_data(data: &PointerData) -> Position {
     Position::new(coords.x as f32, coords.y as f32)
 }
 
+/// On-canvas rectangle of an entity card, using its content height
+pub fn entity_rect(entity: &Entity) -> Rect {
+    Rect::new(
+        entity.position,
+        Size::new(entity.size.width, entity.calculate_height()),
+    )
+}
+
+/// Bounding box of a set of entity cards, or `None` if the set is empty
+pub fn entities_bounds<'a>(entities: impl IntoIterator<Item = &'a Entity>) -> Option<Rect> {
+    entities
+        .into_iter()
+        .map(entity_rect)
+        .reduce(|acc, rect| acc.union(&rect))
+}
+
 /// Calculate wheel delta for zoom
 pub fn zoom_delta_from_wheel(e: &WheelEvent) -> f32 {
     // WheelDelta is an enum containing Vector3D types, extract the y component
@@ -563,6 +609,21 @@ mod tests {
         assert_eq!(snapped.y, 120.0); // 127 rounds to 120
     }
 
+    #[test]
+    fn test_entities_bounds() {
+        assert!(entities_bounds(std::iter::empty()).is_none());
+
+        let mut a = Entity::new("A");
+        a.position = Position::new(-50.0, 20.0);
+        let mut b = Entity::new("B");
+        b.position = Position::new(300.0, 400.0);
+
+        let bounds = entities_bounds([&a, &b]).unwrap();
+        assert_eq!(bounds.position, Position::new(-50.0, 20.0));
+        assert_eq!(bounds.size.width, 350.0 + b.size.width);
+        assert_eq!(bounds.size.height, 380.0 + b.calculate_height());
+    }
+
     #[test]
     fn test_zoom_constants() {
         assert!(MIN_ZOOM > 0.0);
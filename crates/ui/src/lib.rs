@@ -17,12 +17,21 @@
 // Modules
 // ============================================================================
 
+pub mod actions;
 pub mod app;
+pub mod autosave;
 pub mod components;
+pub mod diagnostics;
+pub mod diagram;
 pub mod file_ops;
+pub mod help;
 pub mod hooks;
 pub mod pages;
+pub mod palette;
+pub mod problems;
+pub mod session;
 pub mod state;
+pub mod template_watch;
 
 // ============================================================================
 // Re-exports
@@ -39,16 +48,20 @@ pub use file_ops::{
     show_export_directory_dialog, show_open_dialog, show_save_dialog,
 };
 pub use pages::{EndpointsPage, ProjectSetupPage, WelcomePage};
+pub use session::{SessionState, load_session, save_session};
 pub use state::{
     APP_STATE, AppState, CanvasState, ConnectionPort, DeleteTarget, Dialog, History,
-    HistorySnapshot, Page, Selection, StatusLevel, StatusMessage, UiState, init_app_state,
+    HistorySnapshot, Page, Selection, SemanticZoom, StatusLevel, StatusMessage, Theme, UiState,
+    ZoomDetail, init_app_state,
 };
 
 // Re-export components
 pub use components::{
     Canvas, CanvasToolbar, Checkbox, EndpointCard, EntityCard, FieldRow, GenerateEndpointsCard,
-    NumberInput, PropertiesPanel, Select, SelectOption, TextArea, TextInput, Toggle,
+    HelpButton, HelpPanel, Icon, IconName, NumberInput, PropertiesPanel, Select, SelectOption,
+    TextArea, TextInput, Toggle,
 };
+pub use help::HelpTopic;
 
 // Re-export hooks
 pub use hooks::{CanvasInteractions, DragState, PanState, use_canvas_interactions};
@@ -67,7 +80,9 @@ pub const NAME: &str = "Immortal Engine";
 pub const TITLE: &str = "Immortal Engine - Visual Code Generator";
 
 /// CSS styles for the application
-/// This is the compiled Tailwind CSS included at build time
+/// This is the compiled Tailwind CSS included at build time. Together with the
+/// embedded icon set in [`components::icon`], the app needs no network access
+/// for fonts, icons or styles.
 const STYLES: &str = include_str!("../../../assets/styles/main.css");
 
 // ============================================================================
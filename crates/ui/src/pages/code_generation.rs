@@ -5,21 +5,42 @@
 //! - Review a summary of what will be generated (entities, endpoints, auth, DB)
 //! - Select an output directory for the generated project
 //! - Run the code generator and see real-time progress
+//! - Review the generated files before writing: a file tree with
+//!   highlighted previews, diffs against what is already in the output
+//!   directory, and a checkbox to leave any file out of the write
 //! - View the list of generated files organized by category
 //! - Open the output directory or copy the path
-//! - See warnings and suggestions from the generator
+//! - Search and filter warnings from the generator, and jump to the entity,
+//!   field or endpoint each one is about
+//! - List option combinations that cannot be generated together (e.g. a
+//!   GraphQL-only fullstack project), which block generation until fixed
+//! - Check the project is ready (validation, primary keys, auth principal,
+//!   tested connection, output directory), scored as a health percentage,
+//!   with a link to fix each open item
+//! - Render with the `templates/` overrides next to the project file, and in
+//!   dev mode watch them: edits re-render the preview and syntax errors are
+//!   shown inline (see [`template_watch`](crate::template_watch))
 //!
 //! This page ties together the entire Immortal Engine workflow — it's the final
 //! step where the visual design becomes a real, runnable Rust project.
 
 use dioxus::prelude::*;
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::time::Instant;
 
-use imortal_codegen::{FileType, GeneratedProject, GenerationSummary, Generator, GeneratorConfig};
-use imortal_ir::ProjectType;
+use imortal_codegen::{
+    FileType, GenerationPlan, GenerationSummary, GenerationWarning, Generator, GeneratorConfig,
+    WarningSeverity, WorkspaceMember,
+};
+use imortal_ir::validation::ValidationTarget;
+use imortal_ir::{Incompatibility, ProjectGraph, ProjectType, Validator, check_compatibility};
+use uuid::Uuid;
 
+use crate::components::OutputPreviewPanel;
 use crate::file_ops;
-use crate::state::{APP_STATE, StatusLevel};
+use crate::state::{APP_STATE, Page, StatusLevel};
+use crate::template_watch;
 
 // ============================================================================
 // Code Generation Page Component
@@ -34,15 +55,186 @@ pub fn CodeGenerationPage() -> Element {
     let mut generation_result: Signal<Option<Result<GenerationSummary, String>>> =
         use_signal(|| None);
     let mut generated_files: Signal<Vec<GeneratedFileInfo>> = use_signal(Vec::new);
-    let mut warnings: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut warnings: Signal<Vec<GenerationWarning>> = use_signal(Vec::new);
+    let mut warning_query = use_signal(String::new);
+    let mut warning_severity: Signal<Option<WarningSeverity>> = use_signal(|| None);
     let mut show_files = use_signal(|| false);
+    let mut pending: Signal<Option<PendingWrite>> = use_signal(|| None);
+    let mut excluded: Signal<HashSet<PathBuf>> = use_signal(HashSet::new);
 
     // ── Generator options ────────────────────────────────────────────────
     let mut gen_tests = use_signal(|| true);
     let mut gen_docs = use_signal(|| true);
     let mut gen_migrations = use_signal(|| true);
+    let mut gen_docker = use_signal(|| false);
+    let mut gen_seeds = use_signal(|| false);
+    let mut gen_load_tests = use_signal(|| false);
+    let mut gen_client_sdk = use_signal(|| false);
+    let mut gen_workspace_member = use_signal(|| false);
     let mut overwrite = use_signal(|| true);
 
+    // ── Template dev mode ────────────────────────────────────────────────
+    let mut watch_templates = use_signal(|| false);
+    let mut template_errors: Signal<Vec<String>> = use_signal(Vec::new);
+
+    // ── Plan handler ─────────────────────────────────────────────────────
+    // Generates into a preview of the write to `dir`, off the UI thread. A
+    // re-render (after a template change) keeps the files the user left
+    // out, and reports failures as template errors instead of replacing
+    // the preview.
+    let plan_output = move |dir: PathBuf, rerender: bool| async move {
+        // Read project from state
+        let state = APP_STATE.read();
+        let project = match &state.project {
+            Some(p) => p.clone(),
+            None => {
+                is_generating.set(false);
+                generation_result.set(Some(Err("No project loaded".to_string())));
+                return;
+            }
+        };
+        let templates = template_watch::templates_dir(state.project_path.as_deref());
+        drop(state);
+
+        // Build generator config
+        let config = GeneratorConfig::new().with_output_dir(&dir);
+
+        let mut config = config;
+        if !*gen_tests.peek() {
+            config = config.without_tests();
+        }
+        if !*gen_docs.peek() {
+            config = config.without_docs();
+        }
+        if !*gen_migrations.peek() {
+            config = config.without_migrations();
+        }
+        if *gen_docker.peek() {
+            config = config.with_docker();
+        }
+        if *gen_seeds.peek() {
+            config = config.with_seeds();
+        }
+        if *gen_load_tests.peek() {
+            config = config.with_load_tests();
+        }
+        if *gen_client_sdk.peek() {
+            config = config.with_client_sdk();
+        }
+        let workspace_member = *gen_workspace_member.peek();
+        if workspace_member {
+            config = config.as_workspace_member();
+        }
+        if *overwrite.peek() {
+            config = config.allow_overwrite();
+        }
+        if let Some(templates) = templates {
+            config = config.with_templates_dir(templates);
+        }
+
+        // Run generator
+        let planned = tokio::task::spawn_blocking(move || Generator::new(config).plan(&project))
+            .await
+            .unwrap_or_else(|e| Err(imortal_core::EngineError::internal(e.to_string())));
+        match planned {
+            Ok(plan) => {
+                // Check the enclosing workspace before writing anything
+                let member = if workspace_member {
+                    match WorkspaceMember::locate(&dir, &plan.project.name) {
+                        Ok(member) => Some(member),
+                        Err(e) => {
+                            generation_result.set(Some(Err(e.to_string())));
+                            APP_STATE.write().ui.set_status(
+                                format!("Code generation failed: {}", e),
+                                StatusLevel::Error,
+                            );
+                            is_generating.set(false);
+                            return;
+                        }
+                    }
+                } else {
+                    None
+                };
+
+                // Nothing is written until the preview is confirmed;
+                // without Overwrite, files that would replace existing
+                // ones start out excluded
+                APP_STATE.write().ui.set_status(
+                    format!("Review before writing: {}", plan.summary()),
+                    StatusLevel::Info,
+                );
+                warnings.set(plan.project.warnings.clone());
+                if rerender {
+                    template_errors.set(Vec::new());
+                } else {
+                    excluded.set(plan.conflicting_paths());
+                }
+                pending.set(Some(PendingWrite { plan, member }));
+            }
+            Err(e) if rerender => template_errors.set(vec![e.to_string()]),
+            Err(e) => {
+                generation_result.set(Some(Err(format!("Generation failed: {}", e))));
+                APP_STATE
+                    .write()
+                    .ui
+                    .set_status(format!("Code generation failed: {}", e), StatusLevel::Error);
+            }
+        }
+
+        is_generating.set(false);
+    };
+
+    // Watch the templates directory while dev mode is on. The directory is
+    // read off the UI thread, and a burst of saves re-renders once.
+    use_hook(|| {
+        spawn(async move {
+            let mut watcher = template_watch::Watcher::default();
+            loop {
+                tokio::time::sleep(template_watch::POLL_INTERVAL).await;
+                let dir = if *watch_templates.peek() {
+                    template_watch::templates_dir(APP_STATE.peek().project_path.as_deref())
+                } else {
+                    None
+                };
+                let Some(dir) = dir else {
+                    watcher = template_watch::Watcher::default();
+                    continue;
+                };
+
+                let stamped = dir.clone();
+                let Ok(stamp) =
+                    tokio::task::spawn_blocking(move || template_watch::Stamp::of(&stamped)).await
+                else {
+                    continue;
+                };
+                let change = watcher.poll(stamp, Instant::now());
+                if change == template_watch::Change::None {
+                    continue;
+                }
+
+                let checked = dir.clone();
+                let errors =
+                    tokio::task::spawn_blocking(move || template_watch::template_errors(&checked))
+                        .await
+                        .unwrap_or_default();
+                let parses = errors.is_empty();
+                template_errors.set(errors);
+
+                // Turning the watch on only checks the templates; the open
+                // preview is re-rendered once they change
+                let target = pending.peek().as_ref().map(|p| p.plan.output_dir.clone());
+                if parses
+                    && change == template_watch::Change::Settled
+                    && !*is_generating.peek()
+                    && let Some(dir) = target
+                {
+                    is_generating.set(true);
+                    plan_output(dir, true).await;
+                }
+            }
+        })
+    });
+
     // ── Read project info ────────────────────────────────────────────────
     let state = APP_STATE.read();
     let has_project = state.project.is_some();
@@ -58,6 +250,7 @@ pub fn CodeGenerationPage() -> Element {
                 imortal_ir::AuthStrategy::Jwt => "JWT",
                 imortal_ir::AuthStrategy::Session => "Session",
                 imortal_ir::AuthStrategy::ApiKey => "API Key",
+                imortal_ir::AuthStrategy::OAuth2 => "OAuth2",
                 imortal_ir::AuthStrategy::None => "None",
             }
         } else {
@@ -77,6 +270,28 @@ pub fn CodeGenerationPage() -> Element {
         }
     });
 
+    // Option combinations the generator would refuse
+    let problems: Vec<Incompatibility> = state
+        .project
+        .as_ref()
+        .map(|p| check_compatibility(p, &p.config))
+        .unwrap_or_default();
+
+    // Readiness checklist
+    let checks: Vec<ReadinessCheck> = state
+        .project
+        .as_ref()
+        .map(|p| {
+            let connection_tested = state
+                .tested_connection
+                .as_ref()
+                .is_some_and(|tested| tested.matches(&p.config));
+            readiness_checks(p, connection_tested, output_dir.read().is_some())
+        })
+        .unwrap_or_default();
+
+    let has_templates = template_watch::templates_dir(state.project_path.as_deref()).is_some();
+
     drop(state);
 
     // ── No project state ─────────────────────────────────────────────────
@@ -106,7 +321,7 @@ pub fn CodeGenerationPage() -> Element {
     let summary = project_summary.unwrap();
 
     // ── Select output directory handler ───────────────────────────────────
-    let on_select_dir = move |_| {
+    let choose_output_dir = move || {
         spawn(async move {
             if let Some(dir) = file_ops::show_export_directory_dialog().await {
                 output_dir.set(Some(dir));
@@ -114,9 +329,17 @@ pub fn CodeGenerationPage() -> Element {
                 generation_result.set(None);
                 generated_files.set(Vec::new());
                 warnings.set(Vec::new());
+                pending.set(None);
             }
         });
     };
+    let on_select_dir = move |_| choose_output_dir();
+
+    // ── Readiness fix handler ────────────────────────────────────────────
+    let on_fix = move |link: FixLink| match link {
+        FixLink::OutputDirectory => choose_output_dir(),
+        link => navigate_to_fix(link),
+    };
 
     // ── Generate handler ─────────────────────────────────────────────────
     let on_generate = move |_| {
@@ -129,93 +352,59 @@ pub fn CodeGenerationPage() -> Element {
         generation_result.set(None);
         generated_files.set(Vec::new());
         warnings.set(Vec::new());
+        pending.set(None);
 
-        spawn(async move {
-            // Read project from state
-            let state = APP_STATE.read();
-            let project = match &state.project {
-                Some(p) => p.clone(),
-                None => {
-                    is_generating.set(false);
-                    generation_result.set(Some(Err("No project loaded".to_string())));
-                    return;
-                }
-            };
-            drop(state);
-
-            // Build generator config
-            let config = GeneratorConfig::new().with_output_dir(&dir);
+        spawn(plan_output(dir, false));
+    };
 
-            let mut config = config;
-            if !*gen_tests.peek() {
-                config = config.without_tests();
-            }
-            if !*gen_docs.peek() {
-                config = config.without_docs();
-            }
-            if !*gen_migrations.peek() {
-                config = config.without_migrations();
-            }
-            if *overwrite.peek() {
-                config = config.allow_overwrite();
+    // ── Write handler ────────────────────────────────────────────────────
+    let on_write = move |_| {
+        let Some(PendingWrite { plan, member }) = pending.take() else {
+            return;
+        };
+        let output = &plan.project;
+        let dir = &plan.output_dir;
+
+        // Write the included files, then list the crate in its workspace
+        let written = plan.write(&excluded.peek()).and_then(|count| {
+            member
+                .as_ref()
+                .map_or(Ok(false), WorkspaceMember::register)
+                .map(|_| count)
+        });
+        match written {
+            Ok(count) => {
+                let summary = imortal_codegen::summarize(output);
+
+                // Collect file info for display
+                let files: Vec<GeneratedFileInfo> = output
+                    .files
+                    .iter()
+                    .map(|f| GeneratedFileInfo {
+                        path: f.path.to_string_lossy().to_string(),
+                        file_type: format!("{:?}", f.file_type),
+                        size: f.content.len(),
+                        category: categorize_file(&f.path.to_string_lossy()),
+                    })
+                    .collect();
+
+                generated_files.set(files);
+                generation_result.set(Some(Ok(summary)));
+
+                // Update status bar
+                APP_STATE.write().ui.set_status(
+                    format!("Wrote {} files to {}", count, dir.display()),
+                    StatusLevel::Success,
+                );
             }
-
-            // Run generator
-            let generator = Generator::new(config);
-            match generator.generate(&project) {
-                Ok(output) => {
-                    // Write files to disk
-                    match output.write_to_disk(&dir) {
-                        Ok(_) => {
-                            let summary = imortal_codegen::summarize(&output);
-
-                            // Collect file info for display
-                            let files: Vec<GeneratedFileInfo> = output
-                                .files
-                                .iter()
-                                .map(|f| GeneratedFileInfo {
-                                    path: f.path.to_string_lossy().to_string(),
-                                    file_type: format!("{:?}", f.file_type),
-                                    size: f.content.len(),
-                                    category: categorize_file(&f.path.to_string_lossy()),
-                                })
-                                .collect();
-
-                            generated_files.set(files);
-                            warnings.set(output.warnings.clone());
-                            generation_result.set(Some(Ok(summary)));
-
-                            // Update status bar
-                            APP_STATE.write().ui.set_status(
-                                format!(
-                                    "Generated {} files to {}",
-                                    output.file_count(),
-                                    dir.display()
-                                ),
-                                StatusLevel::Success,
-                            );
-                        }
-                        Err(e) => {
-                            generation_result
-                                .set(Some(Err(format!("Failed to write files: {}", e))));
-                            APP_STATE.write().ui.set_status(
-                                format!("Code generation failed: {}", e),
-                                StatusLevel::Error,
-                            );
-                        }
-                    }
-                }
-                Err(e) => {
-                    generation_result.set(Some(Err(format!("Generation failed: {}", e))));
-                    APP_STATE
-                        .write()
-                        .ui
-                        .set_status(format!("Code generation failed: {}", e), StatusLevel::Error);
-                }
+            Err(e) => {
+                generation_result.set(Some(Err(format!("Failed to write files: {}", e))));
+                APP_STATE
+                    .write()
+                    .ui
+                    .set_status(format!("Code generation failed: {}", e), StatusLevel::Error);
             }
-
-            is_generating.set(false);
-        });
+        }
     };
 
     // ── Open output directory ────────────────────────────────────────────
@@ -232,8 +421,34 @@ pub fn CodeGenerationPage() -> Element {
 
     // ── Derived state ────────────────────────────────────────────────────
     let has_output_dir = output_dir.read().is_some();
-    let can_generate = has_output_dir && !*is_generating.read();
+    let blocked = checks.iter().any(|c| c.status == CheckStatus::Blocking);
+    let check_warnings = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Warning)
+        .count();
+    let score = health_score(&checks);
+    let can_generate = has_output_dir && problems.is_empty() && !blocked && !*is_generating.read();
     let has_result = generation_result.read().is_some();
+    let review = pending.read().as_ref().map(|p| {
+        (
+            p.plan.preview.clone(),
+            p.plan.summary(),
+            p.plan.conflicts().count(),
+        )
+    });
+    let write_count = review.as_ref().map_or(0, |(preview, _, _)| {
+        let excluded = excluded.read();
+        preview
+            .files
+            .iter()
+            .filter(|f| f.status.is_change() && !excluded.contains(&f.path))
+            .count()
+    });
+    let shown_template_errors = if *watch_templates.read() {
+        template_errors.read().clone()
+    } else {
+        Vec::new()
+    };
     let is_success = generation_result
         .read()
         .as_ref()
@@ -386,12 +601,59 @@ pub fn CodeGenerationPage() -> Element {
                             on_change: move |v: bool| gen_migrations.set(v),
                         }
 
+                        OptionToggle {
+                            label: "Docker",
+                            description: "Dockerfile and docker-compose",
+                            checked: *gen_docker.read(),
+                            on_change: move |v: bool| gen_docker.set(v),
+                        }
+
+                        OptionToggle {
+                            label: "Seed Data",
+                            description: "Sample rows and a seed binary",
+                            checked: *gen_seeds.read(),
+                            on_change: move |v: bool| gen_seeds.set(v),
+                        }
+
+                        OptionToggle {
+                            label: "Load Tests",
+                            description: "k6 script ramped to rate limits",
+                            checked: *gen_load_tests.read(),
+                            on_change: move |v: bool| gen_load_tests.set(v),
+                        }
+
+                        OptionToggle {
+                            label: "Client SDK",
+                            description: "Typed Rust client crate",
+                            checked: *gen_client_sdk.read(),
+                            on_change: move |v: bool| gen_client_sdk.set(v),
+                        }
+
+                        OptionToggle {
+                            label: "Workspace Member",
+                            description: "Add to the enclosing Cargo workspace",
+                            checked: *gen_workspace_member.read(),
+                            on_change: move |v: bool| gen_workspace_member.set(v),
+                        }
+
                         OptionToggle {
                             label: "Overwrite",
                             description: "Replace existing files",
                             checked: *overwrite.read(),
                             on_change: move |v: bool| overwrite.set(v),
                         }
+
+                        if has_templates {
+                            OptionToggle {
+                                label: "Watch Templates",
+                                description: "Dev mode: re-render on edits to templates/",
+                                checked: *watch_templates.read(),
+                                on_change: move |v: bool| {
+                                    watch_templates.set(v);
+                                    template_errors.set(Vec::new());
+                                },
+                            }
+                        }
                     }
                 }
 
@@ -451,6 +713,65 @@ pub fn CodeGenerationPage() -> Element {
                     }
                 }
 
+                // ── Readiness Checklist ──────────────────────────────────
+                div {
+                    class: "bg-slate-800 rounded-xl border border-slate-700 p-6 mb-6",
+
+                    div {
+                        class: "flex items-center justify-between mb-4",
+                        h2 {
+                            class: "text-lg font-semibold text-white flex items-center gap-2",
+                            span { "🩺" }
+                            "Readiness Checklist"
+                        }
+                        span {
+                            class: format!(
+                                "px-3 py-1 rounded-lg text-sm font-semibold {}",
+                                if blocked {
+                                    "bg-red-900/30 text-red-300"
+                                } else if check_warnings > 0 {
+                                    "bg-amber-900/30 text-amber-300"
+                                } else {
+                                    "bg-emerald-900/30 text-emerald-300"
+                                }
+                            ),
+                            title: "Health score: passed items count fully, warnings half",
+                            "Health {score}%"
+                        }
+                    }
+
+                    div {
+                        class: "space-y-1",
+                        for check in checks.iter().cloned() {
+                            ReadinessRow {
+                                key: "{check.label}",
+                                check,
+                                on_fix: on_fix,
+                            }
+                        }
+                    }
+                }
+
+                // ── Problems ─────────────────────────────────────────────
+                if !problems.is_empty() {
+                    div {
+                        class: "bg-red-900/20 border border-red-700/50 rounded-xl p-6 mb-6",
+
+                        h3 {
+                            class: "text-sm font-semibold text-red-300 flex items-center gap-2 mb-3",
+                            span { "⛔" }
+                            "Problems ({problems.len()})"
+                        }
+
+                        div {
+                            class: "space-y-2",
+                            for (i, problem) in problems.iter().cloned().enumerate() {
+                                ProblemRow { key: "{i}", problem }
+                            }
+                        }
+                    }
+                }
+
                 // ── Generate Button ──────────────────────────────────────
                 div {
                     class: "mb-6",
@@ -485,6 +806,101 @@ pub fn CodeGenerationPage() -> Element {
                             class: "mt-2 text-center text-sm text-slate-500",
                             "Select an output directory above before generating."
                         }
+                    } else if !problems.is_empty() {
+                        p {
+                            class: "mt-2 text-center text-sm text-slate-500",
+                            "Fix the problems above before generating."
+                        }
+                    } else if blocked {
+                        p {
+                            class: "mt-2 text-center text-sm text-slate-500",
+                            "Fix the blocking items on the readiness checklist before generating."
+                        }
+                    } else if check_warnings > 0 {
+                        p {
+                            class: "mt-2 text-center text-sm text-amber-400/70",
+                            "The readiness checklist has {check_warnings} warning(s); the project can still be generated."
+                        }
+                    }
+                }
+
+                // ── Template Errors (dev mode) ───────────────────────────
+                if !shown_template_errors.is_empty() {
+                    div {
+                        class: "bg-red-900/20 border border-red-700/50 rounded-xl p-6 mb-6",
+
+                        h3 {
+                            class: "text-sm font-semibold text-red-300 flex items-center gap-2 mb-1",
+                            span { "⛔" }
+                            "Template Errors ({shown_template_errors.len()})"
+                        }
+                        p {
+                            class: "text-xs text-red-400/70 mb-3",
+                            "The preview shows the last version of the templates that rendered."
+                        }
+
+                        div {
+                            class: "space-y-2",
+                            for error in shown_template_errors.iter() {
+                                p {
+                                    class: "text-red-400 text-sm bg-red-900/30 p-3 rounded-lg font-mono",
+                                    "{error}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Review Before Writing ────────────────────────────────
+                if let Some((preview, summary_line, conflicts)) = review {
+                    {
+                        rsx! {
+                            div {
+                                class: "bg-slate-800 rounded-xl border border-slate-700 p-6 mb-6",
+
+                                div {
+                                    class: "flex items-center justify-between gap-4 mb-4",
+                                    div {
+                                        h2 {
+                                            class: "text-lg font-semibold text-white flex items-center gap-2",
+                                            span { "🔍" }
+                                            "Review Changes"
+                                        }
+                                        p {
+                                            class: "text-sm text-slate-400",
+                                            "{summary_line}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex gap-2",
+                                        button {
+                                            class: "px-4 py-2 bg-slate-700 hover:bg-slate-600 text-white rounded-lg text-sm transition-colors",
+                                            onclick: move |_| pending.set(None),
+                                            "Discard"
+                                        }
+                                        button {
+                                            class: if write_count > 0 {
+                                                "px-4 py-2 bg-emerald-600 hover:bg-emerald-700 text-white rounded-lg text-sm font-medium transition-colors"
+                                            } else {
+                                                "px-4 py-2 bg-slate-700 text-slate-400 rounded-lg text-sm font-medium cursor-not-allowed"
+                                            },
+                                            disabled: write_count == 0,
+                                            onclick: on_write,
+                                            "Write {write_count} file(s)"
+                                        }
+                                    }
+                                }
+
+                                if conflicts > 0 {
+                                    p {
+                                        class: "mb-3 text-sm text-amber-400/80",
+                                        "⚠ {conflicts} file(s) would replace or delete existing ones. They are left out until you tick them or enable Overwrite."
+                                    }
+                                }
+
+                                OutputPreviewPanel { preview, excluded }
+                            }
+                        }
                     }
                 }
 
@@ -541,6 +957,16 @@ pub fn CodeGenerationPage() -> Element {
                                     }
                                 }
 
+                                {
+                                    let time_str = gen_summary.timings.describe();
+                                    rsx! {
+                                        p {
+                                            class: "text-xs text-emerald-400/70 mb-2",
+                                            "Generated in {time_str}"
+                                        }
+                                    }
+                                }
+
                                 // Action buttons
                                 div {
                                     class: "flex flex-wrap gap-3 mt-4 pt-4 border-t border-emerald-700/30",
@@ -642,24 +1068,67 @@ pub fn CodeGenerationPage() -> Element {
 
                 // ── Warnings ─────────────────────────────────────────────
                 if !warnings.read().is_empty() {
-                    div {
-                        class: "bg-amber-900/20 border border-amber-700/50 rounded-xl p-6 mb-6",
+                    {
+                        let all = warnings.read().clone();
+                        let total = all.len();
+                        let query = warning_query.read().clone();
+                        let severity = *warning_severity.read();
+                        let visible = filter_warnings(&all, &query, severity);
+                        let shown = visible.len();
+                        rsx! {
+                            div {
+                                class: "bg-amber-900/20 border border-amber-700/50 rounded-xl p-6 mb-6",
 
-                        h3 {
-                            class: "text-sm font-semibold text-amber-300 mb-3 flex items-center gap-2",
-                            span { "⚠️" }
-                            "Warnings ({warnings.read().len()})"
-                        }
+                                div {
+                                    class: "flex items-center justify-between gap-4 mb-3",
 
-                        div {
-                            class: "space-y-2",
+                                    h3 {
+                                        class: "text-sm font-semibold text-amber-300 flex items-center gap-2",
+                                        span { "⚠️" }
+                                        if shown == total {
+                                            "Warnings ({total})"
+                                        } else {
+                                            "Warnings ({shown} of {total})"
+                                        }
+                                    }
+
+                                    // Severity filter
+                                    div {
+                                        class: "flex items-center gap-1",
+                                        for (label, value) in [
+                                            ("All", None),
+                                            ("Warnings", Some(WarningSeverity::Warning)),
+                                            ("Info", Some(WarningSeverity::Info)),
+                                        ] {
+                                            button {
+                                                key: "{label}",
+                                                class: if severity == value {
+                                                    "px-2 py-1 text-xs rounded bg-amber-700/60 text-amber-100"
+                                                } else {
+                                                    "px-2 py-1 text-xs rounded text-amber-300/70 hover:bg-amber-800/40"
+                                                },
+                                                onclick: move |_| warning_severity.set(value),
+                                                "{label}"
+                                            }
+                                        }
+                                    }
+                                }
+
+                                // Search
+                                input {
+                                    class: "w-full mb-3 px-3 py-1.5 bg-slate-900/60 border border-amber-800/50 rounded text-sm text-slate-200 placeholder-slate-500 focus:outline-none focus:border-amber-500",
+                                    r#type: "text",
+                                    placeholder: "Filter warnings...",
+                                    value: "{query}",
+                                    oninput: move |e| warning_query.set(e.value()),
+                                }
 
-                            for (i, warning) in warnings.read().iter().enumerate() {
                                 div {
-                                    key: "{i}",
-                                    class: "flex items-start gap-2 text-sm",
-                                    span { class: "text-amber-500 mt-0.5 flex-shrink-0", "•" }
-                                    span { class: "text-amber-200/80", "{warning}" }
+                                    class: "space-y-2",
+
+                                    for (i, warning) in visible.into_iter().enumerate() {
+                                        WarningRow { key: "{i}", warning }
+                                    }
                                 }
                             }
                         }
@@ -847,6 +1316,12 @@ struct ProjectSummary {
     field_count: usize,
 }
 
+/// A generation waiting for its preview to be confirmed
+struct PendingWrite {
+    plan: GenerationPlan,
+    member: Option<WorkspaceMember>,
+}
+
 #[derive(Debug, Clone)]
 struct GeneratedFileInfo {
     path: String,
@@ -855,10 +1330,195 @@ struct GeneratedFileInfo {
     category: String,
 }
 
+/// Outcome of a readiness check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    /// Nothing to fix
+    Passed,
+    /// Generation works, but the output may not be what was intended
+    Warning,
+    /// Generation is disabled until this is fixed
+    Blocking,
+}
+
+/// Where a readiness check is fixed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixLink {
+    /// The project settings
+    ProjectSetup,
+    /// An entity on the design canvas
+    Entity(Uuid),
+    /// A field of an entity: `(entity_id, field_id)`
+    Field(Uuid, Uuid),
+    /// A relationship in the relationship manager
+    Relationship(Uuid),
+    /// An endpoint group
+    Endpoint(Uuid),
+    /// The output directory picker on this page
+    OutputDirectory,
+}
+
+impl From<ValidationTarget> for FixLink {
+    fn from(target: ValidationTarget) -> Self {
+        match target {
+            ValidationTarget::Entity(id) => Self::Entity(id),
+            ValidationTarget::Field {
+                entity_id,
+                field_id,
+            } => Self::Field(entity_id, field_id),
+            ValidationTarget::Relationship(id) => Self::Relationship(id),
+            ValidationTarget::Endpoint(id) => Self::Endpoint(id),
+        }
+    }
+}
+
+/// One item of the readiness checklist
+#[derive(Debug, Clone, PartialEq)]
+struct ReadinessCheck {
+    label: &'static str,
+    status: CheckStatus,
+    detail: String,
+    fix: Option<FixLink>,
+}
+
+impl ReadinessCheck {
+    fn new(label: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            label,
+            status,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn fix(mut self, link: impl Into<Option<FixLink>>) -> Self {
+        self.fix = link.into();
+        self
+    }
+}
+
 // ============================================================================
 // Sub-Components
 // ============================================================================
 
+#[derive(Props, Clone, PartialEq)]
+struct WarningRowProps {
+    warning: GenerationWarning,
+}
+
+/// A single generation warning, clickable when it links to a model element.
+#[component]
+fn WarningRow(props: WarningRowProps) -> Element {
+    let warning = props.warning.clone();
+    let linked = warning.has_source();
+    let (marker, marker_class) = match warning.severity {
+        WarningSeverity::Warning => ("•", "text-amber-500"),
+        WarningSeverity::Info => ("ℹ", "text-sky-400"),
+    };
+    let label = warning.code.label();
+    let message = warning.message.clone();
+    let suggestion = warning.suggestion.clone();
+
+    rsx! {
+        div {
+            class: if linked {
+                "flex items-start gap-2 text-sm rounded px-2 py-1 -mx-2 cursor-pointer hover:bg-amber-800/20"
+            } else {
+                "flex items-start gap-2 text-sm px-2 py-1 -mx-2"
+            },
+            title: if linked { "Go to source" } else { "" },
+            onclick: move |_| {
+                if linked {
+                    navigate_to_warning_source(&warning);
+                }
+            },
+            span { class: "{marker_class} mt-0.5 flex-shrink-0", "{marker}" }
+            div {
+                class: "flex-1 min-w-0",
+                div {
+                    span {
+                        class: "mr-2 px-1.5 py-0.5 text-[10px] uppercase tracking-wide rounded bg-slate-800 text-slate-400",
+                        "{label}"
+                    }
+                    span { class: "text-amber-200/80", "{message}" }
+                }
+                if let Some(suggestion) = suggestion {
+                    p { class: "text-xs text-slate-400 mt-0.5", "→ {suggestion}" }
+                }
+            }
+            if linked {
+                span { class: "text-xs text-amber-400/70 flex-shrink-0", "Go to →" }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ProblemRowProps {
+    problem: Incompatibility,
+}
+
+/// An option combination that blocks generation, linking to where it is
+/// fixed: the field it is on, or the project settings.
+#[component]
+fn ProblemRow(props: ProblemRowProps) -> Element {
+    let problem = props.problem.clone();
+    let message = problem.message.clone();
+    let suggestion = problem.suggestion.clone();
+
+    rsx! {
+        div {
+            class: "flex items-start gap-2 text-sm rounded px-2 py-1 -mx-2 cursor-pointer hover:bg-red-800/20",
+            title: "Go to source",
+            onclick: move |_| navigate_to_problem_source(&problem),
+            span { class: "text-red-500 mt-0.5 flex-shrink-0", "•" }
+            div {
+                class: "flex-1 min-w-0",
+                p { class: "text-red-200/80", "{message}" }
+                p { class: "text-xs text-slate-400 mt-0.5", "→ {suggestion}" }
+            }
+            span { class: "text-xs text-red-400/70 flex-shrink-0", "Go to →" }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ReadinessRowProps {
+    check: ReadinessCheck,
+    on_fix: EventHandler<FixLink>,
+}
+
+/// One readiness check, with a button to fix it when it hasn't passed.
+#[component]
+fn ReadinessRow(props: ReadinessRowProps) -> Element {
+    let check = props.check.clone();
+    let (marker, marker_class) = match check.status {
+        CheckStatus::Passed => ("✓", "text-emerald-400"),
+        CheckStatus::Warning => ("!", "text-amber-400"),
+        CheckStatus::Blocking => ("✕", "text-red-400"),
+    };
+    let fix = check.fix.filter(|_| check.status != CheckStatus::Passed);
+
+    rsx! {
+        div {
+            class: "flex items-start gap-3 text-sm px-2 py-1.5 -mx-2 rounded",
+            span { class: "{marker_class} font-bold mt-0.5 w-4 flex-shrink-0 text-center", "{marker}" }
+            div {
+                class: "flex-1 min-w-0",
+                p { class: "text-slate-200", "{check.label}" }
+                p { class: "text-xs text-slate-400 mt-0.5", "{check.detail}" }
+            }
+            if let Some(link) = fix {
+                button {
+                    class: "px-2 py-1 text-xs rounded bg-slate-700 hover:bg-slate-600 text-slate-200 transition-colors flex-shrink-0",
+                    onclick: move |_| props.on_fix.call(link),
+                    "Fix →"
+                }
+            }
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 struct SummaryCardProps {
     icon: &'static str,
@@ -1026,6 +1686,222 @@ fn WhatItem(props: WhatItemProps) -> Element {
 // ============================================================================
 
 /// Format a byte size into a human-readable string.
+/// Keep the warnings matching the search text and severity filter.
+fn filter_warnings(
+    warnings: &[GenerationWarning],
+    query: &str,
+    severity: Option<WarningSeverity>,
+) -> Vec<GenerationWarning> {
+    warnings
+        .iter()
+        .filter(|w| severity.is_none_or(|s| w.severity == s))
+        .filter(|w| w.matches(query))
+        .cloned()
+        .collect()
+}
+
+/// Select the model element a warning is about and switch to its page.
+fn navigate_to_warning_source(warning: &GenerationWarning) {
+    let mut state = APP_STATE.write();
+    if let Some(endpoint_id) = warning.endpoint_id {
+        state.selection.clear();
+        state.selection.endpoints.insert(endpoint_id);
+        state.ui.navigate(Page::Endpoints);
+    } else if let Some(entity_id) = warning.entity_id {
+        state.selection.select_entity(entity_id);
+        state.selection.field = warning.field_id.map(|field_id| (entity_id, field_id));
+        state.ui.navigate(Page::EntityDesign);
+    }
+}
+
+/// Select the field a compatibility problem is on, or open the project
+/// settings for project-wide ones.
+fn navigate_to_problem_source(problem: &Incompatibility) {
+    let mut state = APP_STATE.write();
+    if let Some(entity_id) = problem.entity_id {
+        state.selection.select_entity(entity_id);
+        state.selection.field = problem.field_id.map(|field_id| (entity_id, field_id));
+        state.ui.navigate(Page::EntityDesign);
+    } else {
+        state.ui.navigate(Page::ProjectSetup);
+    }
+}
+
+/// Select the element a readiness check is about and switch to the page
+/// where it is fixed.
+fn navigate_to_fix(link: FixLink) {
+    let mut state = APP_STATE.write();
+    match link {
+        FixLink::ProjectSetup => state.ui.navigate(Page::ProjectSetup),
+        FixLink::Entity(entity_id) => {
+            state.selection.select_entity(entity_id);
+            state.ui.navigate(Page::EntityDesign);
+        }
+        FixLink::Field(entity_id, field_id) => {
+            state.selection.select_entity(entity_id);
+            state.selection.field = Some((entity_id, field_id));
+            state.ui.navigate(Page::EntityDesign);
+        }
+        FixLink::Relationship(id) => {
+            state.selection.select_relationship(id);
+            state.ui.navigate(Page::Relationships);
+        }
+        FixLink::Endpoint(id) => {
+            state.selection.clear();
+            state.selection.endpoints.insert(id);
+            state.ui.navigate(Page::Endpoints);
+        }
+        // Chosen on this page, by the caller
+        FixLink::OutputDirectory => {}
+    }
+}
+
+/// Check the project is ready to generate: validation is clean, every
+/// entity has a primary key, the auth principal is mapped (with auth on),
+/// the current connection settings passed a test, and an output directory
+/// is chosen.
+fn readiness_checks(
+    project: &ProjectGraph,
+    connection_tested: bool,
+    has_output_dir: bool,
+) -> Vec<ReadinessCheck> {
+    use CheckStatus::*;
+
+    let mut checks = Vec::new();
+
+    // Validation
+    let result = Validator::with_default_rules().validate(project);
+    checks.push(if let Some(error) = result.errors.first() {
+        ReadinessCheck::new(
+            "Validation",
+            Blocking,
+            format!("{} error(s): {}", result.errors.len(), error.message),
+        )
+        .fix(error.target.map(FixLink::from))
+    } else if let Some(warning) = result.warnings.first() {
+        ReadinessCheck::new(
+            "Validation",
+            Warning,
+            format!("{} warning(s): {}", result.warnings.len(), warning.message),
+        )
+        .fix(warning.target.map(FixLink::from))
+    } else {
+        ReadinessCheck::new("Validation", Passed, "No errors or warnings")
+    });
+
+    // Primary keys
+    let mut keyless: Vec<_> = project
+        .entities
+        .values()
+        .filter(|e| e.primary_key().is_none())
+        .collect();
+    keyless.sort_by(|a, b| a.name.cmp(&b.name));
+    checks.push(match keyless.first() {
+        Some(first) => {
+            let names: Vec<&str> = keyless.iter().map(|e| e.name.as_str()).collect();
+            ReadinessCheck::new(
+                "Primary keys",
+                Blocking,
+                format!("No primary key on {}", names.join(", ")),
+            )
+            .fix(FixLink::Entity(first.id))
+        }
+        None => ReadinessCheck::new("Primary keys", Passed, "Every entity has a primary key"),
+    });
+
+    // Auth principal
+    let auth = &project.config.auth;
+    if auth.enabled {
+        let check = match &auth.principal {
+            None => ReadinessCheck::new(
+                "Auth principal",
+                Warning,
+                "Not mapped to an entity; the generated auth uses the built-in email claim",
+            )
+            .fix(FixLink::ProjectSetup),
+            Some(principal) => match project.get_entity_by_name(&principal.entity) {
+                None => ReadinessCheck::new(
+                    "Auth principal",
+                    Blocking,
+                    format!("Entity '{}' does not exist", principal.entity),
+                )
+                .fix(FixLink::ProjectSetup),
+                Some(entity) => {
+                    let missing: Vec<&str> =
+                        [&principal.identifier_field, &principal.password_field]
+                            .into_iter()
+                            .chain(&principal.roles_field)
+                            .filter(|name| entity.get_field_by_name(name).is_none())
+                            .map(String::as_str)
+                            .collect();
+                    if missing.is_empty() {
+                        ReadinessCheck::new(
+                            "Auth principal",
+                            Passed,
+                            format!(
+                                "{} signs in with '{}'",
+                                entity.name, principal.identifier_field
+                            ),
+                        )
+                    } else {
+                        ReadinessCheck::new(
+                            "Auth principal",
+                            Blocking,
+                            format!("{} has no field {}", entity.name, missing.join(", ")),
+                        )
+                        .fix(FixLink::Entity(entity.id))
+                    }
+                }
+            },
+        };
+        checks.push(check);
+    }
+
+    // Database connection
+    checks.push(if connection_tested {
+        ReadinessCheck::new(
+            "Database connection",
+            Passed,
+            "Tested with the current settings",
+        )
+    } else {
+        ReadinessCheck::new(
+            "Database connection",
+            Warning,
+            "Not tested with the current settings",
+        )
+        .fix(FixLink::ProjectSetup)
+    });
+
+    // Output directory
+    checks.push(if has_output_dir {
+        ReadinessCheck::new("Output directory", Passed, "Chosen")
+    } else {
+        ReadinessCheck::new("Output directory", Blocking, "No directory chosen")
+            .fix(FixLink::OutputDirectory)
+    });
+
+    checks
+}
+
+/// Health score of a checklist, in percent: passed items count fully,
+/// warnings half, blocking items not at all.
+fn health_score(checks: &[ReadinessCheck]) -> u8 {
+    if checks.is_empty() {
+        return 100;
+    }
+    let points: usize = checks
+        .iter()
+        .map(|c| match c.status {
+            CheckStatus::Passed => 2,
+            CheckStatus::Warning => 1,
+            CheckStatus::Blocking => 0,
+        })
+        .sum();
+    // Rounded to the nearest percent
+    ((points * 100 + checks.len()) / (checks.len() * 2)) as u8
+}
+
 fn format_size(bytes: usize) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
@@ -1048,6 +1924,8 @@ fn categorize_file(path: &str) -> String {
         "Authentication".to_string()
     } else if path.starts_with("migrations/") || path.starts_with("backend/migrations/") {
         "SQL Migrations".to_string()
+    } else if path.starts_with("seeds/") || path.starts_with("backend/seeds/") {
+        "Seed Data".to_string()
     } else if path.starts_with("tests/") || path.starts_with("backend/tests/") {
         "Tests".to_string()
     } else if path.starts_with("frontend/") {
@@ -1082,6 +1960,7 @@ fn get_categories_owned(files: &[GeneratedFileInfo]) -> Vec<(String, Vec<Generat
         "Routes",
         "Authentication",
         "SQL Migrations",
+        "Seed Data",
         "Tests",
         "Shared Types",
         "Frontend (Dioxus)",
@@ -1137,6 +2016,111 @@ mod tests {
         assert_eq!(format_size(1048576), "1.0 MB");
     }
 
+    #[test]
+    fn test_filter_warnings() {
+        use imortal_codegen::WarningCode;
+
+        let warnings = vec![
+            GenerationWarning::new(WarningCode::SensitiveFieldExposed, "Field 'User.token'"),
+            GenerationWarning::new(WarningCode::RenamedIdentifier, "Field 'User.firstName'").info(),
+        ];
+
+        assert_eq!(filter_warnings(&warnings, "", None).len(), 2);
+        assert_eq!(filter_warnings(&warnings, "token", None).len(), 1);
+        assert_eq!(
+            filter_warnings(&warnings, "", Some(WarningSeverity::Info))[0].code,
+            WarningCode::RenamedIdentifier
+        );
+        assert!(filter_warnings(&warnings, "token", Some(WarningSeverity::Info)).is_empty());
+    }
+
+    #[test]
+    fn test_readiness_checks() {
+        use imortal_ir::{AuthPrincipal, Entity, Field};
+
+        let mut project = ProjectGraph::new("app");
+        project.config.auth.enabled = false;
+        let user = Entity::new("User");
+        let user_id = user.id;
+        project.add_entity(user);
+
+        let checks = readiness_checks(&project, true, true);
+        assert_eq!(checks.len(), 4);
+        // A lone entity only draws validation warnings (no endpoints, ...)
+        assert_eq!(checks[0].label, "Validation");
+        assert!(checks.iter().all(|c| c.status != CheckStatus::Blocking));
+        assert!(checks[1..].iter().all(|c| c.status == CheckStatus::Passed));
+
+        // Untested connection warns, a missing output directory blocks
+        let checks = readiness_checks(&project, false, false);
+        let connection = checks
+            .iter()
+            .find(|c| c.label == "Database connection")
+            .unwrap();
+        assert_eq!(connection.status, CheckStatus::Warning);
+        assert_eq!(connection.fix, Some(FixLink::ProjectSetup));
+        let output = checks
+            .iter()
+            .find(|c| c.label == "Output directory")
+            .unwrap();
+        assert_eq!(output.status, CheckStatus::Blocking);
+        assert_eq!(output.fix, Some(FixLink::OutputDirectory));
+
+        // Auth adds the principal check, blocking on an unknown field
+        project.config.auth.enabled = true;
+        let principal = |project: &ProjectGraph| {
+            readiness_checks(project, true, true)
+                .into_iter()
+                .find(|c| c.label == "Auth principal")
+                .unwrap()
+        };
+        assert_eq!(principal(&project).status, CheckStatus::Warning);
+        project.config.auth.principal = Some(AuthPrincipal::new("User"));
+        let check = principal(&project);
+        assert_eq!(check.status, CheckStatus::Blocking);
+        assert_eq!(check.fix, Some(FixLink::Entity(user_id)));
+        let entity = project.entities.get_mut(&user_id).unwrap();
+        entity.add_field(Field::new("email", imortal_core::DataType::String));
+        entity.add_field(Field::new("password_hash", imortal_core::DataType::String));
+        assert_eq!(principal(&project).status, CheckStatus::Passed);
+    }
+
+    #[test]
+    fn test_readiness_checks_primary_keys() {
+        use imortal_ir::Entity;
+
+        let mut project = ProjectGraph::new("app");
+        let mut entity = Entity::new("Tag");
+        entity.fields.retain(|f| !f.is_primary_key);
+        let tag_id = entity.id;
+        project.add_entity(entity);
+
+        let checks = readiness_checks(&project, true, true);
+        let keys = checks.iter().find(|c| c.label == "Primary keys").unwrap();
+        assert_eq!(keys.status, CheckStatus::Blocking);
+        assert_eq!(keys.fix, Some(FixLink::Entity(tag_id)));
+        assert!(keys.detail.contains("Tag"));
+    }
+
+    #[test]
+    fn test_health_score() {
+        let check = |status| ReadinessCheck::new("Check", status, "");
+        assert_eq!(health_score(&[]), 100);
+        assert_eq!(
+            health_score(&[check(CheckStatus::Passed), check(CheckStatus::Warning)]),
+            75
+        );
+        assert_eq!(
+            health_score(&[
+                check(CheckStatus::Passed),
+                check(CheckStatus::Passed),
+                check(CheckStatus::Blocking),
+            ]),
+            67
+        );
+        assert_eq!(health_score(&[check(CheckStatus::Blocking)]), 0);
+    }
+
     #[test]
     fn test_categorize_file() {
         assert_eq!(categorize_file("src/models/user.rs"), "Models (SeaORM)");
@@ -1147,6 +2131,7 @@ mod tests {
             categorize_file("migrations/001_users.sql"),
             "SQL Migrations"
         );
+        assert_eq!(categorize_file("seeds/001_users.sql"), "Seed Data");
         assert_eq!(categorize_file("tests/api_tests.rs"), "Tests");
         assert_eq!(categorize_file("frontend/src/main.rs"), "Frontend (Dioxus)");
         assert_eq!(categorize_file("shared/src/lib.rs"), "Shared Types");
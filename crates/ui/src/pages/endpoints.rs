@@ -9,6 +9,7 @@
 //! - Rate limiting per operation
 //! - Auto-generation of endpoints for uncovered entities
 //! - Integrated (nested) endpoint previews based on relationships
+//! - Custom query endpoints built with the query builder
 //!
 //! ## Usage
 //!
@@ -21,11 +22,17 @@
 //! - View and edit security and rate-limiting in the properties panel
 
 use dioxus::prelude::*;
-use imortal_ir::{EndpointGroup, EndpointSecurity, Entity, OperationType, Relationship};
+use imortal_ir::{
+    EndpointGroup, EndpointSecurity, Entity, OperationType, QueryDefinition, Relationship,
+    RouteStyle,
+};
 use uuid::Uuid;
 
 use crate::components::endpoint_card::{EndpointCard, GenerateEndpointsCard, http_method_class};
+use crate::components::help_panel::HelpButton;
+use crate::components::icon::{Icon, IconName};
 use crate::components::inputs::{Select, SelectOption, TextInput, Toggle};
+use crate::help::HelpTopic;
 use crate::state::{APP_STATE, DeleteTarget, Dialog, StatusLevel};
 
 // ============================================================================
@@ -71,6 +78,16 @@ pub fn EndpointsPage() -> Element {
         .map(|p| p.relationships.values().cloned().collect())
         .unwrap_or_default();
 
+    let queries: Vec<QueryDefinition> = state
+        .project
+        .as_ref()
+        .map(|p| {
+            let mut list: Vec<QueryDefinition> = p.queries.values().cloned().collect();
+            list.sort_by(|a, b| a.name.cmp(&b.name));
+            list
+        })
+        .unwrap_or_default();
+
     let selected_endpoints = state.selection.endpoints.clone();
 
     // Auth config
@@ -80,6 +97,12 @@ pub fn EndpointsPage() -> Element {
         .map(|p| p.config.auth.enabled)
         .unwrap_or(false);
 
+    let route_style = state
+        .project
+        .as_ref()
+        .map(|p| p.config.route_style)
+        .unwrap_or_default();
+
     drop(state);
 
     // Entities without endpoints
@@ -120,7 +143,8 @@ pub fn EndpointsPage() -> Element {
         .collect();
 
     // Build integrated (nested) endpoints from relationships — always computed
-    let initial_integrated = build_integrated_endpoints(&endpoints, &relationships, &entities);
+    let initial_integrated =
+        build_integrated_endpoints(&endpoints, &relationships, &entities, &route_style);
     let mut integrated_endpoints: Signal<Vec<IntegratedEndpoint>> =
         use_signal(|| initial_integrated);
 
@@ -171,7 +195,7 @@ pub fn EndpointsPage() -> Element {
                 }
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     };
 
     let on_toggle_enabled = move |(ep_id, enabled): (Uuid, bool)| {
@@ -181,31 +205,14 @@ pub fn EndpointsPage() -> Element {
                 ep.enabled = enabled;
             }
         }
-        state.is_dirty = true;
+        state.mark_dirty();
     };
 
     // Auto-generate endpoints for all uncovered entities
-    let entities_for_generate = entities.clone();
-    let endpoint_entity_ids_for_gen = endpoint_entity_ids.clone();
     let on_generate_all = move |_| {
-        let mut state = APP_STATE.write();
-        if let Some(project) = &mut state.project {
-            let mut count = 0;
-            for entity in &entities_for_generate {
-                if !endpoint_entity_ids_for_gen.contains(&entity.id) {
-                    let endpoint = EndpointGroup::new(entity.id, &entity.name);
-                    project.add_endpoint(endpoint);
-                    count += 1;
-                }
-            }
-            if count > 0 {
-                tracing::info!("Auto-generated endpoints for {} entities", count);
-                state.is_dirty = true;
-                state.ui.set_status(
-                    format!("Generated endpoints for {} entities", count),
-                    StatusLevel::Success,
-                );
-            }
+        let count = APP_STATE.write().generate_missing_endpoints();
+        if count > 0 {
+            tracing::info!("Auto-generated endpoints for {} entities", count);
         }
     };
 
@@ -216,7 +223,7 @@ pub fn EndpointsPage() -> Element {
             for ep in project.endpoints.values_mut() {
                 ep.global_security.auth_required = true;
             }
-            state.is_dirty = true;
+            state.mark_dirty();
             state.ui.set_status(
                 "All endpoints now require authentication".to_string(),
                 StatusLevel::Success,
@@ -230,7 +237,7 @@ pub fn EndpointsPage() -> Element {
             for ep in project.endpoints.values_mut() {
                 ep.global_security = EndpointSecurity::open();
             }
-            state.is_dirty = true;
+            state.mark_dirty();
             state.ui.set_status(
                 "All endpoints set to public".to_string(),
                 StatusLevel::Success,
@@ -363,13 +370,13 @@ pub fn EndpointsPage() -> Element {
                                             let entity_list: Vec<(Uuid, String)> = project.entities.values().map(|e| (e.id, e.name.clone())).collect();
                                             for (eid, ename) in &entity_list {
                                                 if !existing.contains(eid) {
-                                                    let endpoint = EndpointGroup::new(*eid, ename);
+                                                    let endpoint = project.new_endpoint(*eid, ename);
                                                     project.add_endpoint(endpoint);
                                                     count += 1;
                                                 }
                                             }
                                             if count > 0 {
-                                                state.is_dirty = true;
+                                                state.mark_dirty();
                                                 state.ui.set_status(format!("Generated endpoints for {} entities", count), StatusLevel::Success);
                                             }
                                         }
@@ -478,6 +485,17 @@ pub fn EndpointsPage() -> Element {
                             }
                         }
 
+                        // Custom query endpoints
+                        if !entities.is_empty() {
+                            div {
+                                class: "mt-6",
+                                CustomQueriesSection {
+                                    queries: queries.clone(),
+                                    auth_enabled: auth_enabled,
+                                }
+                            }
+                        }
+
                         // Relationship-based (nested) endpoints
                         if *show_integrated.read() && !integrated_endpoints.read().is_empty() {
                             div {
@@ -681,6 +699,7 @@ fn build_integrated_endpoints(
     endpoints: &[EndpointGroup],
     relationships: &[Relationship],
     entities: &[Entity],
+    route_style: &RouteStyle,
 ) -> Vec<IntegratedEndpoint> {
     let mut integrated = Vec::new();
 
@@ -713,10 +732,11 @@ fn build_integrated_endpoints(
         let parent_singular = to_snake_case(&parent_name);
         let child_singular = to_snake_case(&child_name);
         let child_plural = to_snake_case_plural(&child_name);
-        let base = format!(
-            "{}/:{}_{}/{}",
-            parent_ep.base_path, parent_singular, "id", child_plural
-        );
+        let path = |tail: &str| {
+            route_style.nested_path(&parent_ep.base_path, &parent_name, &child_name, tail)
+        };
+        let base = path("");
+        let child_item = path(&format!("/:{}", route_style.id_param(&child_name)));
         let rel_name = rel.name.clone();
         let pair_key = format!("{}_{}", parent_singular, child_singular);
 
@@ -767,7 +787,7 @@ fn build_integrated_endpoints(
                 child_name, parent_name, child_name, parent_name
             ),
             method: "GET".to_string(),
-            path: format!("{}/:{}_id", base, child_singular),
+            path: child_item.clone(),
             parent_entity: parent_name.clone(),
             child_entity: child_name.clone(),
             relationship_name: rel_name.clone(),
@@ -785,7 +805,7 @@ fn build_integrated_endpoints(
                 child_name, parent_name, child_name, parent_name
             ),
             method: "DELETE".to_string(),
-            path: format!("{}/:{}_id", base, child_singular),
+            path: child_item.clone(),
             parent_entity: parent_name.clone(),
             child_entity: child_name.clone(),
             relationship_name: rel_name.clone(),
@@ -803,7 +823,7 @@ fn build_integrated_endpoints(
                 child_name, parent_name
             ),
             method: "GET".to_string(),
-            path: format!("{}/count", base),
+            path: path("/count"),
             parent_entity: parent_name.clone(),
             child_entity: child_name.clone(),
             relationship_name: rel_name.clone(),
@@ -847,18 +867,7 @@ fn EndpointsToolbar(props: EndpointsToolbarProps) -> Element {
                 h2 {
                     class: "text-lg font-semibold text-white flex items-center gap-2",
                     // API endpoint icon
-                    svg {
-                        class: "w-5 h-5 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                        }
-                    }
+                    Icon { name: IconName::Terminal, class: "w-5 h-5 text-indigo-400" }
                     "Endpoints"
                 }
 
@@ -973,18 +982,7 @@ fn EndpointsToolbar(props: EndpointsToolbarProps) -> Element {
                     button {
                         class: "px-3 py-1.5 bg-emerald-600 hover:bg-emerald-700 text-white text-sm rounded transition-colors flex items-center gap-1",
                         onclick: move |_| props.on_generate_all.call(()),
-                        svg {
-                            class: "w-4 h-4",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M13 10V3L4 14h7v7l9-11h-7z",
-                            }
-                        }
+                        Icon { name: IconName::Bolt }
                         "Auto-Generate"
                     }
                 }
@@ -994,18 +992,7 @@ fn EndpointsToolbar(props: EndpointsToolbarProps) -> Element {
                     button {
                         class: "px-3 py-1.5 bg-red-600 hover:bg-red-700 text-white text-sm rounded transition-colors flex items-center gap-1",
                         onclick: move |_| props.on_delete.call(()),
-                        svg {
-                            class: "w-4 h-4",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16",
-                            }
-                        }
+                        Icon { name: IconName::Trash }
                         "Delete"
                     }
                 }
@@ -1014,18 +1001,7 @@ fn EndpointsToolbar(props: EndpointsToolbarProps) -> Element {
                 button {
                     class: "px-3 py-1.5 bg-indigo-600 hover:bg-indigo-700 text-white text-sm rounded transition-colors flex items-center gap-1",
                     onclick: move |_| props.on_create.call(()),
-                    svg {
-                        class: "w-4 h-4",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M12 4v16m8-8H4",
-                        }
-                    }
+                    Icon { name: IconName::Plus }
                     "New Endpoint"
                 }
             }
@@ -1220,18 +1196,7 @@ fn EndpointsListView(props: EndpointsListViewProps) -> Element {
                                             e.stop_propagation();
                                             props.on_edit.call(ep_id);
                                         },
-                                        svg {
-                                            class: "w-4 h-4",
-                                            fill: "none",
-                                            stroke: "currentColor",
-                                            view_box: "0 0 24 24",
-                                            path {
-                                                stroke_linecap: "round",
-                                                stroke_linejoin: "round",
-                                                stroke_width: "2",
-                                                d: "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z",
-                                            }
-                                        }
+                                        Icon { name: IconName::Pencil }
                                     }
 
                                     button {
@@ -1241,18 +1206,7 @@ fn EndpointsListView(props: EndpointsListViewProps) -> Element {
                                             e.stop_propagation();
                                             props.on_delete.call(ep_id);
                                         },
-                                        svg {
-                                            class: "w-4 h-4",
-                                            fill: "none",
-                                            stroke: "currentColor",
-                                            view_box: "0 0 24 24",
-                                            path {
-                                                stroke_linecap: "round",
-                                                stroke_linejoin: "round",
-                                                stroke_width: "2",
-                                                d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16",
-                                            }
-                                        }
+                                        Icon { name: IconName::Trash }
                                     }
                                 }
                             }
@@ -1303,18 +1257,7 @@ fn IntegratedEndpointsSection(props: IntegratedEndpointsSectionProps) -> Element
 
                 div {
                     class: "flex items-center gap-2",
-                    svg {
-                        class: "w-5 h-5 text-emerald-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1",
-                        }
-                    }
+                    Icon { name: IconName::Link, class: "w-5 h-5 text-emerald-400" }
                     h3 {
                         class: "font-semibold text-white",
                         "Relationship Endpoints"
@@ -1425,18 +1368,7 @@ fn IntegratedEndpointsSection(props: IntegratedEndpointsSectionProps) -> Element
                                                     onclick: move |_| props.on_toggle.call(ep_id.clone()),
 
                                                     if is_enabled {
-                                                        svg {
-                                                            class: "w-3 h-3 text-white",
-                                                            fill: "none",
-                                                            stroke: "currentColor",
-                                                            view_box: "0 0 24 24",
-                                                            path {
-                                                                stroke_linecap: "round",
-                                                                stroke_linejoin: "round",
-                                                                stroke_width: "3",
-                                                                d: "M5 13l4 4L19 7",
-                                                            }
-                                                        }
+                                                        Icon { name: IconName::Check, class: "w-3 h-3 text-white", stroke_width: 3.0 }
                                                     }
                                                 }
 
@@ -1595,6 +1527,142 @@ fn AuthEndpointsSection(props: AuthEndpointsSectionProps) -> Element {
     }
 }
 
+// ============================================================================
+// Custom Queries Section
+// ============================================================================
+
+#[derive(Props, Clone, PartialEq)]
+struct CustomQueriesSectionProps {
+    queries: Vec<QueryDefinition>,
+    auth_enabled: bool,
+}
+
+#[component]
+fn CustomQueriesSection(props: CustomQueriesSectionProps) -> Element {
+    rsx! {
+        div {
+            class: "rounded-xl border border-emerald-700/30 bg-emerald-900/10 overflow-hidden",
+
+            // Header
+            div {
+                class: "px-4 py-3 border-b border-emerald-700/30 flex items-center gap-2",
+
+                span { class: "text-lg", "🔎" }
+
+                h3 {
+                    class: "font-semibold text-emerald-300",
+                    "Custom Queries"
+                }
+
+                span {
+                    class: "px-2 py-0.5 bg-emerald-900/30 text-emerald-400 rounded text-xs",
+                    "{props.queries.len()} queries"
+                }
+
+                div { class: "flex-1" }
+
+                button {
+                    class: "px-3 py-1 bg-emerald-700 hover:bg-emerald-600 text-white rounded text-xs font-medium transition-colors",
+                    onclick: move |_| {
+                        APP_STATE.write().ui.show_dialog(Dialog::NewQuery(None));
+                    },
+                    "+ New Query"
+                }
+            }
+
+            if props.queries.is_empty() {
+                div {
+                    class: "px-4 py-3 text-xs text-slate-400",
+                    "Build read-only report endpoints with joins, filters and sorting. "
+                    "Each query becomes its own GET route with typed parameters and rows."
+                }
+            }
+
+            // Query list
+            div {
+                class: "divide-y divide-emerald-700/10",
+
+                for query in props.queries.iter() {
+                    {
+                        let query_id = query.id;
+                        let secured = props.auth_enabled && query.security.auth_required;
+                        let summary = format!(
+                            "{} · {} joins · {} filters",
+                            query.entity_name,
+                            query.joins.len(),
+                            query.filters.len()
+                        );
+                        rsx! {
+                            div {
+                                key: "{query_id}",
+                                class: format!(
+                                    "px-4 py-3 flex items-center gap-3 {}",
+                                    if query.enabled { "" } else { "opacity-50" }
+                                ),
+
+                                span {
+                                    class: format!(
+                                        "px-2 py-0.5 rounded text-xs font-bold min-w-[56px] text-center {}",
+                                        http_method_class("GET")
+                                    ),
+                                    "GET"
+                                }
+
+                                span {
+                                    class: "font-mono text-sm text-emerald-200 min-w-[220px]",
+                                    "{query.path}"
+                                }
+
+                                span {
+                                    class: "text-sm text-white font-medium min-w-[120px]",
+                                    "{query.name}"
+                                }
+
+                                span {
+                                    class: "text-xs text-slate-400 flex-1 truncate",
+                                    "{summary}"
+                                }
+
+                                span {
+                                    class: format!(
+                                        "px-2 py-0.5 rounded text-xs {}",
+                                        if secured {
+                                            "bg-amber-900/30 text-amber-400"
+                                        } else {
+                                            "bg-green-900/30 text-green-400"
+                                        }
+                                    ),
+                                    if secured { "🔒 Auth" } else { "🌐 Public" }
+                                }
+
+                                button {
+                                    class: "p-1 text-slate-400 hover:text-white rounded hover:bg-slate-700",
+                                    title: "Edit query",
+                                    onclick: move |_| {
+                                        APP_STATE.write().ui.show_dialog(Dialog::EditQuery(query_id));
+                                    },
+                                    "✏️"
+                                }
+
+                                button {
+                                    class: "p-1 text-slate-400 hover:text-red-400 rounded hover:bg-slate-700",
+                                    title: "Delete query",
+                                    onclick: move |_| {
+                                        APP_STATE.write().ui.show_dialog(
+                                            Dialog::ConfirmDelete(DeleteTarget::Query(query_id)),
+                                        );
+                                    },
+                                    "🗑️"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Properties Panel Component
 // ============================================================================
@@ -1616,18 +1684,7 @@ fn EndpointPropertiesPanel(props: EndpointPropertiesPanelProps) -> Element {
                     class: "text-center py-12",
                     div {
                         class: "w-12 h-12 mx-auto mb-4 rounded-full bg-slate-700 flex items-center justify-center",
-                        svg {
-                            class: "w-6 h-6 text-slate-500",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                            }
-                        }
+                        Icon { name: IconName::Terminal, class: "w-6 h-6 text-slate-500" }
                     }
                     h3 {
                         class: "text-sm font-medium text-slate-300 mb-1",
@@ -1719,18 +1776,7 @@ fn EndpointPropertiesPanel(props: EndpointPropertiesPanelProps) -> Element {
                                 "bg-slate-700 text-slate-500"
                             }
                         ),
-                        svg {
-                            class: "w-4 h-4",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                            }
-                        }
+                        Icon { name: IconName::Terminal }
                     }
                     div {
                         h3 {
@@ -1858,8 +1904,9 @@ fn EndpointPropertiesPanel(props: EndpointPropertiesPanelProps) -> Element {
             div {
                 class: "space-y-2",
                 h4 {
-                    class: "text-sm font-medium text-slate-300",
+                    class: "text-sm font-medium text-slate-300 flex items-center",
                     "Global Security"
+                    HelpButton { topic: HelpTopic::EndpointSecurity }
                 }
 
                 div {
@@ -1990,18 +2037,7 @@ fn EmptyState() -> Element {
 
                 div {
                     class: "w-16 h-16 mx-auto mb-4 rounded-full bg-indigo-900/20 flex items-center justify-center",
-                    svg {
-                        class: "w-8 h-8 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z",
-                        }
-                    }
+                    Icon { name: IconName::Terminal, class: "w-8 h-8 text-indigo-400" }
                 }
 
                 h3 {
@@ -2057,18 +2093,7 @@ fn NoProjectState() -> Element {
 
                 div {
                     class: "w-20 h-20 mx-auto mb-6 rounded-full bg-slate-800 flex items-center justify-center",
-                    svg {
-                        class: "w-10 h-10 text-slate-600",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M3 7v10a2 2 0 002 2h14a2 2 0 002-2V9a2 2 0 00-2-2h-6l-2-2H5a2 2 0 00-2 2z",
-                        }
-                    }
+                    Icon { name: IconName::Folder, class: "w-10 h-10 text-slate-600" }
                 }
 
                 h2 {
@@ -2285,7 +2310,12 @@ mod tests {
         let endpoints = vec![];
         let relationships = vec![];
         let entities = vec![];
-        let result = build_integrated_endpoints(&endpoints, &relationships, &entities);
+        let result = build_integrated_endpoints(
+            &endpoints,
+            &relationships,
+            &entities,
+            &RouteStyle::default(),
+        );
         assert!(result.is_empty());
     }
 
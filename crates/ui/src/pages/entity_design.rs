@@ -4,6 +4,7 @@
 //!
 //! This page integrates:
 //! - **CanvasToolbar**: Top toolbar with canvas-specific actions
+//! - **EntityList**: Collapsible sidebar to search, pin and jump to entities
 //! - **Canvas**: The main visual editor with pan/zoom and entity cards
 //! - **PropertiesPanel**: Side panel for editing selected entity/field properties
 //!
@@ -11,14 +12,14 @@
 //!
 //! ```text
 //! ┌─────────────────────────────────────────────────────────────┐
-//! │ CanvasToolbar (Add Entity, Grid Toggle, Snap Toggle)        │
-//! ├─────────────────────────────────────────────────┬───────────┤
-//! │                                                 │           │
-//! │                                                 │ Properties│
-//! │                  Canvas                         │   Panel   │
-//! │          (Entity Cards, Grid, Zoom)             │           │
-//! │                                                 │           │
-//! └─────────────────────────────────────────────────┴───────────┘
+//! │ CanvasToolbar (Add Entity, Auto Arrange, Export, Grid, Snap)│
+//! ├──────────┬──────────────────────────────────────┬───────────┤
+//! │          │                                      │           │
+//! │  Entity  │                                      │ Properties│
+//! │   List   │             Canvas                   │   Panel   │
+//! │          │     (Entity Cards, Grid, Zoom)       │           │
+//! │          │                                      │           │
+//! └──────────┴──────────────────────────────────────┴───────────┘
 //! ```
 //!
 //! ## Features
@@ -29,15 +30,23 @@
 //! - Pan with middle mouse button or space+drag
 //! - Zoom with mouse wheel
 //! - Toggle grid and snap-to-grid
+//! - Auto-arrange entities into columns that follow their relationships
+//! - Find entities by name, pin favorites and center the canvas on one
 //!
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use dioxus::prelude::*;
-use imortal_core::types::{EntityId, FieldId, Position, Size};
-use imortal_ir::entity::Entity;
+use imortal_codegen::{SchemaFormat, export_schema};
+use imortal_core::types::{EntityId, FieldId, Position};
+use imortal_ir::auto_layout;
 
 use crate::components::canvas::{Canvas, CanvasToolbar};
+use crate::components::entity_list::EntityList;
 use crate::components::properties::PropertiesPanel;
-use crate::state::{APP_STATE, Dialog};
+use crate::state::{APP_STATE, Dialog, StatusLevel};
+use crate::{diagram, file_ops};
 
 // ============================================================================
 // Entity Design Page Component
@@ -83,6 +92,15 @@ pub fn EntityDesignPage() -> Element {
         APP_STATE.write().ui.show_dialog(Dialog::NewEntity);
     };
 
+    // Handle auto arrange button click
+    let handle_auto_arrange = move |_| auto_arrange_entities();
+
+    // Handle export diagram button click
+    let handle_export_diagram = move |_| export_diagram();
+
+    // Handle export schema menu items
+    let handle_export_schema = move |format| export_schema_as(format);
+
     // Handle entity selection on canvas
     let handle_entity_select = move |entity_id: EntityId| {
         tracing::debug!("Entity selected: {:?}", entity_id);
@@ -127,12 +145,18 @@ pub fn EntityDesignPage() -> Element {
                 on_toggle_grid: handle_toggle_grid,
                 on_toggle_snap: handle_toggle_snap,
                 on_add_entity: handle_add_entity,
+                on_auto_arrange: handle_auto_arrange,
+                on_export_diagram: handle_export_diagram,
+                on_export_schema: handle_export_schema,
             }
 
-            // Main content area (canvas + properties)
+            // Main content area (entity list + canvas + properties)
             div {
                 class: "flex flex-1 overflow-hidden",
 
+                // Entity list
+                EntityList {}
+
                 // Canvas area
                 div {
                     class: "flex-1 relative",
@@ -142,7 +166,7 @@ pub fn EntityDesignPage() -> Element {
                         pan_enabled: true,
                         zoom_enabled: true,
                         drag_enabled: true,
-                        show_minimap: false,
+                        show_minimap: true,
                         show_zoom_controls: true,
                         on_entity_select: handle_entity_select,
                         on_entity_move: handle_entity_move,
@@ -167,42 +191,158 @@ pub fn EntityDesignPage() -> Element {
 // Helper Functions
 // ============================================================================
 
-/// Create a new entity at the given canvas position
-fn create_entity_at_position(position: Position) {
+/// Frames in the auto-arrange animation
+const ARRANGE_FRAMES: u32 = 20;
+
+/// Time between auto-arrange animation frames
+const ARRANGE_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Move every entity to its auto-layout position, easing the cards (and
+/// their relationship lines) there over a few frames
+fn auto_arrange_entities() {
     let mut state = APP_STATE.write();
+    let Some(project) = &state.project else {
+        return;
+    };
 
-    // Check if we have a project
-    let Some(project) = &mut state.project else {
-        tracing::warn!("No project to add entity to");
+    let targets = auto_layout(project);
+    let starts: HashMap<EntityId, Position> = project
+        .entities
+        .values()
+        .map(|e| (e.id, e.position))
+        .collect();
+    if targets
+        .iter()
+        .all(|(id, target)| starts.get(id) == Some(target))
+    {
+        return;
+    }
+
+    state.save_to_history("Auto arrange");
+    state.mark_dirty();
+    // The layout starts near the canvas origin, so bring that into view
+    state.canvas.pan = Position::zero();
+    drop(state);
+
+    tracing::info!("Auto-arranging {} entities", targets.len());
+    spawn(async move {
+        for frame in 1..=ARRANGE_FRAMES {
+            let t = ease_out_cubic(frame as f32 / ARRANGE_FRAMES as f32);
+            {
+                let mut state = APP_STATE.write();
+                let Some(project) = &mut state.project else {
+                    return;
+                };
+                for (id, target) in &targets {
+                    if let Some(entity) = project.entities.get_mut(id)
+                        && let Some(start) = starts.get(id)
+                    {
+                        entity.position = start.lerp(target, t);
+                    }
+                }
+            }
+            if frame < ARRANGE_FRAMES {
+                tokio::time::sleep(ARRANGE_FRAME_INTERVAL).await;
+            }
+        }
+    });
+}
+
+/// Ask where to save the diagram, then write it as SVG or PNG (by the
+/// chosen file's extension)
+fn export_diagram() {
+    let Some(project) = APP_STATE.read().project.clone() else {
         return;
     };
+    let start_dir = APP_STATE.read().project_path.clone();
 
-    // Generate a unique name
-    let entity_count = project.entities.len();
-    let name = format!("Entity{}", entity_count + 1);
+    spawn(async move {
+        let Some(path) =
+            file_ops::show_diagram_save_dialog(&project.meta.name, start_dir.as_deref()).await
+        else {
+            return;
+        };
 
-    // Create the entity
-    let mut entity = Entity::new(&name);
-    entity.position = position;
-    entity.size = Size::default_entity();
+        let result = diagram::export_diagram(&project, &path);
+        let mut state = APP_STATE.write();
+        match result {
+            Ok(_) => {
+                tracing::info!("Exported diagram to {}", path.display());
+                state.ui.set_status(
+                    format!("Diagram exported to {}", path.display()),
+                    StatusLevel::Success,
+                );
+            }
+            Err(e) => {
+                tracing::error!("Failed to export diagram: {}", e);
+                state.ui.set_status(
+                    format!("Failed to export diagram: {}", e),
+                    StatusLevel::Error,
+                );
+            }
+        }
+    });
+}
 
-    // Add to project
-    let entity_id = entity.id;
-    project.entities.insert(entity_id, entity);
+/// Ask where to save the schema in a text format, then write it there
+fn export_schema_as(format: SchemaFormat) {
+    let Some(project) = APP_STATE.read().project.clone() else {
+        return;
+    };
+    let start_dir = APP_STATE.read().project_path.clone();
+
+    spawn(async move {
+        let Some(path) =
+            file_ops::show_schema_save_dialog(&project.meta.name, format, start_dir.as_deref())
+                .await
+        else {
+            return;
+        };
 
-    // Select the new entity
-    state.selection.select_entity(entity_id);
+        let result = std::fs::write(&path, export_schema(&project, format));
+        let mut state = APP_STATE.write();
+        match result {
+            Ok(()) => {
+                tracing::info!(
+                    "Exported {} schema to {}",
+                    format.display_name(),
+                    path.display()
+                );
+                state.ui.set_status(
+                    format!(
+                        "{} schema exported to {}",
+                        format.display_name(),
+                        path.display()
+                    ),
+                    StatusLevel::Success,
+                );
+            }
+            Err(e) => {
+                tracing::error!("Failed to export schema: {}", e);
+                state.ui.set_status(
+                    format!("Failed to export schema: {}", e),
+                    StatusLevel::Error,
+                );
+            }
+        }
+    });
+}
 
-    // Mark as dirty
-    state.is_dirty = true;
+/// Easing for the auto-arrange animation: fast start, gentle landing
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t.clamp(0.0, 1.0)).powi(3)
+}
 
-    // Save to history
-    drop(state);
-    APP_STATE.write().save_to_history("Create entity");
+/// Create a new entity at the given canvas position
+fn create_entity_at_position(position: Position) {
+    let Some(entity_id) = APP_STATE.write().create_entity_at(position) else {
+        tracing::warn!("No project to add entity to");
+        return;
+    };
 
     tracing::info!(
-        "Created entity '{}' at ({}, {})",
-        name,
+        "Created entity {} at ({}, {})",
+        entity_id,
         position.x,
         position.y
     );
@@ -230,7 +370,7 @@ fn delete_selected_entities() {
     state.selection.clear();
 
     // Mark as dirty
-    state.is_dirty = true;
+    state.mark_dirty();
 
     // Save to history
     drop(state);
@@ -243,47 +383,9 @@ fn delete_selected_entities() {
 #[allow(dead_code)]
 fn duplicate_selected_entities() {
     let mut state = APP_STATE.write();
-
-    let selected = state.selection.entities.clone();
-    if selected.is_empty() {
-        return;
-    }
-
-    let Some(project) = &mut state.project else {
-        return;
-    };
-
-    // Collect entities to duplicate
-    let entities_to_duplicate: Vec<Entity> = selected
-        .iter()
-        .filter_map(|id| project.entities.get(id).cloned())
-        .collect();
-
-    // Create duplicates with offset positions
-    let offset = Position::new(20.0, 20.0);
-    let mut new_ids = Vec::new();
-
-    for entity in entities_to_duplicate {
-        let new_entity = entity
-            .duplicate()
-            .at(entity.position.x + offset.x, entity.position.y + offset.y);
-        let new_id = new_entity.id;
-        project.entities.insert(new_id, new_entity);
-        new_ids.push(new_id);
-    }
-
-    // Select the new entities
-    state.selection.clear();
-    for id in &new_ids {
-        state.selection.add_entity(*id);
-    }
-
-    // Mark as dirty
-    state.is_dirty = true;
-
-    // Save to history
+    let selected: Vec<EntityId> = state.selection.entities.iter().copied().collect();
+    let new_ids = state.duplicate_entities(&selected);
     drop(state);
-    APP_STATE.write().save_to_history("Duplicate entities");
 
     tracing::info!("Duplicated {} entities", new_ids.len());
 }
@@ -319,7 +421,7 @@ fn align_entities_horizontal() {
     }
 
     // Mark as dirty
-    state.is_dirty = true;
+    state.mark_dirty();
 
     // Save to history
     drop(state);
@@ -357,7 +459,7 @@ fn align_entities_vertical() {
     }
 
     // Mark as dirty
-    state.is_dirty = true;
+    state.mark_dirty();
 
     // Save to history
     drop(state);
@@ -377,4 +479,12 @@ mod tests {
         // Just verify the module compiles
         assert!(true);
     }
+
+    #[test]
+    fn test_ease_out_cubic() {
+        assert_eq!(ease_out_cubic(0.0), 0.0);
+        assert_eq!(ease_out_cubic(1.0), 1.0);
+        assert_eq!(ease_out_cubic(2.0), 1.0);
+        assert!(ease_out_cubic(0.5) > 0.5);
+    }
 }
@@ -6,9 +6,17 @@
 
 use dioxus::prelude::*;
 
+use crate::autosave;
+use crate::components::HelpButton;
 use crate::file_ops;
-use crate::state::{APP_STATE, Page, StatusLevel};
-use imortal_ir::{AuthStrategy, DatabaseConfig, DatabaseType, ProjectConfig, ProjectType};
+use crate::help::HelpTopic;
+use crate::state::{APP_STATE, Page, StatusLevel, TestedConnection};
+use imortal_ir::{
+    ApiStyle, ApiVersioning, AuthStrategy, CorsConfig, DatabaseConfig, DatabaseType,
+    NestedPathStyle, OAuthProvider, OAuthProviderKind, ObservabilityConfig, Orm, PaginationStyle,
+    PathCase, PathPlurality, ProjectConfig, ProjectMeta, ProjectType, RateLimit, UploadConfig,
+    UploadStorage, WebFramework,
+};
 
 // ============================================================================
 // Project Setup Page Component
@@ -20,16 +28,19 @@ pub fn ProjectSetupPage() -> Element {
     // Get current project config from state
     let state = APP_STATE.read();
     let project = state.project.as_ref();
+    let fk_index_suggestions = project.map(|p| p.fk_index_suggestions().len()).unwrap_or(0);
 
-    let (initial_name, initial_desc, initial_config) = match project {
+    let (initial_name, initial_desc, initial_meta, initial_config) = match project {
         Some(p) => (
             p.meta.name.clone(),
             p.meta.description.clone().unwrap_or_default(),
+            p.meta.clone(),
             p.config.clone(),
         ),
         None => (
             "New Project".to_string(),
             String::new(),
+            ProjectMeta::new("New Project"),
             ProjectConfig::default(),
         ),
     };
@@ -38,7 +49,48 @@ pub fn ProjectSetupPage() -> Element {
     // Form state signals
     let mut project_name = use_signal(|| initial_name);
     let mut project_description = use_signal(|| initial_desc);
+    let mut project_version = use_signal(|| initial_meta.version.clone());
+    let mut project_authors = use_signal(|| initial_meta.author.clone().unwrap_or_default());
+    let mut project_license = use_signal(|| initial_meta.license.clone().unwrap_or_default());
+    let mut project_repository = use_signal(|| initial_meta.repository.clone().unwrap_or_default());
+    let mut project_keywords = use_signal(|| initial_meta.keywords.join(", "));
     let mut project_type = use_signal(|| initial_config.project_type);
+    let mut pagination = use_signal(|| initial_config.pagination);
+    let mut orm = use_signal(|| initial_config.orm);
+    let mut framework = use_signal(|| initial_config.framework);
+    let mut api_style = use_signal(|| initial_config.api_style);
+    let mut route_style = use_signal(|| initial_config.route_style);
+    let mut api_versioning = use_signal(|| initial_config.api_versioning.clone());
+    let mut api_default_version = use_signal(|| {
+        initial_config
+            .api_versioning
+            .default_version()
+            .unwrap_or("v1")
+            .to_string()
+    });
+    let mut api_version_header = use_signal(|| {
+        initial_config
+            .api_versioning
+            .header_name()
+            .unwrap_or(ApiVersioning::DEFAULT_HEADER)
+            .to_string()
+    });
+    let mut index_foreign_keys = use_signal(|| initial_config.index_foreign_keys);
+    let mut read_replicas = use_signal(|| initial_config.read_replicas);
+    let mut report_delete_blockers = use_signal(|| initial_config.report_delete_blockers);
+    let mut safe_migrations = use_signal(|| initial_config.safe_migrations);
+    let mut telemetry = use_signal(|| initial_config.telemetry);
+    let mut health_checks = use_signal(|| initial_config.observability.health_checks);
+    let mut metrics = use_signal(|| initial_config.observability.metrics);
+    let mut background_jobs = use_signal(|| initial_config.background_jobs);
+    let mut typescript_client = use_signal(|| initial_config.typescript_client);
+    let mut upload_storage = use_signal(|| initial_config.uploads.storage);
+    let mut upload_max_mb = use_signal(|| initial_config.uploads.max_size_mb);
+    let mut rate_limit = use_signal(|| initial_config.rate_limit.clone());
+    let mut cors_enabled = use_signal(|| initial_config.cors_enabled);
+    let mut cors_origins = use_signal(|| initial_config.cors.allowed_origins.join(", "));
+    let mut cors_methods = use_signal(|| initial_config.cors.allowed_methods.clone());
+    let mut cors_credentials = use_signal(|| initial_config.cors.allow_credentials);
     let mut database_type = use_signal(|| initial_config.database);
     let mut db_host = use_signal(|| initial_config.db_config.host.clone());
     let mut db_port = use_signal(|| initial_config.db_config.port);
@@ -51,6 +103,9 @@ pub fn ProjectSetupPage() -> Element {
     let mut auth_enabled = use_signal(|| initial_config.auth.enabled);
     let mut auth_strategy = use_signal(|| initial_config.auth.strategy);
     let mut token_expiry_hours = use_signal(|| initial_config.auth.token_expiry_hours);
+    let mut refresh_tokens = use_signal(|| initial_config.auth.refresh_tokens);
+    let mut oauth_providers = use_signal(|| initial_config.auth.oauth_providers.clone());
+    let identifier_claim = initial_config.auth.identifier_claim().to_string();
     let mut package_name = use_signal(|| initial_config.package_name.clone());
 
     // Project save location
@@ -111,10 +166,65 @@ pub fn ProjectSetupPage() -> Element {
             project.meta.name = project_name.read().clone();
             project.meta.description =
                 Some(project_description.read().clone()).filter(|s| !s.is_empty());
+            let version = project_version.read().trim().to_string();
+            if !version.is_empty() {
+                project.meta.version = version;
+            }
+            let optional = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+            project.meta.author = optional(&project_authors.read());
+            project.meta.license = optional(&project_license.read());
+            project.meta.repository = optional(&project_repository.read());
+            project.meta.keywords = project_keywords
+                .read()
+                .split(',')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect();
             project.touch();
 
             // Update project config
             project.config.project_type = *project_type.read();
+            project.config.pagination = *pagination.read();
+            project.config.orm = *orm.read();
+            project.config.framework = *framework.read();
+            project.config.api_style = *api_style.read();
+            project.set_route_style(*route_style.read());
+            let version = api_default_version.read().trim().to_string();
+            project.config.api_versioning = match &*api_versioning.read() {
+                ApiVersioning::None => ApiVersioning::None,
+                ApiVersioning::PathPrefix { .. } => ApiVersioning::path_prefix(version),
+                ApiVersioning::Header { .. } => ApiVersioning::Header {
+                    header: api_version_header.read().trim().to_string(),
+                    default_version: version,
+                },
+            };
+            project.config.index_foreign_keys = *index_foreign_keys.read();
+            project.config.read_replicas = *read_replicas.read();
+            project.config.report_delete_blockers = *report_delete_blockers.read();
+            project.config.safe_migrations = *safe_migrations.read();
+            project.config.telemetry = *telemetry.read();
+            project.config.observability = ObservabilityConfig {
+                health_checks: *health_checks.read(),
+                metrics: *metrics.read(),
+            };
+            project.config.background_jobs = *background_jobs.read();
+            project.config.typescript_client = *typescript_client.read();
+            project.config.uploads = UploadConfig {
+                storage: *upload_storage.read(),
+                max_size_mb: *upload_max_mb.read(),
+            };
+            project.config.rate_limit = rate_limit.read().clone();
+            project.config.cors_enabled = *cors_enabled.read();
+            project.config.cors = CorsConfig {
+                allowed_origins: cors_origins
+                    .read()
+                    .split(',')
+                    .map(|origin| origin.trim().trim_end_matches('/').to_string())
+                    .filter(|origin| !origin.is_empty())
+                    .collect(),
+                allowed_methods: cors_methods.read().clone(),
+                allow_credentials: *cors_credentials.read(),
+            };
             project.config.database = *database_type.read();
             project.config.db_config = DatabaseConfig {
                 host: db_host.read().clone(),
@@ -131,6 +241,8 @@ pub fn ProjectSetupPage() -> Element {
             project.config.auth.enabled = *auth_enabled.read();
             project.config.auth.strategy = *auth_strategy.read();
             project.config.auth.token_expiry_hours = *token_expiry_hours.read();
+            project.config.auth.refresh_tokens = *refresh_tokens.read();
+            project.config.auth.oauth_providers = oauth_providers.read().clone();
             project.config.package_name = package_name.read().clone();
         }
 
@@ -157,6 +269,15 @@ pub fn ProjectSetupPage() -> Element {
             warnings.push("Package name is empty.".to_string());
         }
 
+        // API version check
+        if *api_versioning.read() != ApiVersioning::None
+            && !imortal_ir::is_valid_api_version(api_default_version.read().trim())
+        {
+            warnings.push(
+                "Default API version must use only letters, digits, '.', '-' and '_'.".to_string(),
+            );
+        }
+
         // Database connection checks
         match current_db_type {
             DatabaseType::SQLite => {
@@ -222,6 +343,38 @@ pub fn ProjectSetupPage() -> Element {
         APP_STATE.write().ui.navigate(Page::EntityDesign);
     }
 
+    // Example paths for the selected route style
+    let route_preview = {
+        let style = *route_style.read();
+        format!(
+            "{}  ·  {}",
+            style.base_path("BlogPost"),
+            style.nested_path(&style.base_path("User"), "User", "BlogPost", "")
+        )
+    };
+
+    // Example path and header for the selected versioning strategy
+    let versioning_preview = {
+        let version = api_default_version.read().trim().to_string();
+        let base_path = route_style.read().base_path("BlogPost");
+        match &*api_versioning.read() {
+            ApiVersioning::None => {
+                format!("{}  (groups may still set their own version)", base_path)
+            }
+            ApiVersioning::PathPrefix { .. } => {
+                ApiVersioning::path_prefix(version).public_path(&base_path, None)
+            }
+            ApiVersioning::Header { .. } => {
+                format!(
+                    "{}  ·  {}: {}",
+                    base_path,
+                    api_version_header.read().trim(),
+                    version
+                )
+            }
+        }
+    };
+
     rsx! {
         div {
             class: "project-setup-page h-full overflow-auto",
@@ -336,6 +489,83 @@ pub fn ProjectSetupPage() -> Element {
                             }
                         }
 
+                        // Version
+                        FormField {
+                            label: "Version",
+                            required: false,
+                            hint: "Semantic version for Cargo.toml and the OpenAPI spec",
+
+                            input {
+                                class: "input",
+                                r#type: "text",
+                                value: "{project_version}",
+                                placeholder: "0.1.0",
+                                oninput: move |e| project_version.set(e.value()),
+                            }
+                        }
+
+                        // Authors
+                        FormField {
+                            label: "Authors",
+                            required: false,
+                            hint: "Comma-separated, e.g. Jane Doe <jane@example.com>",
+
+                            input {
+                                class: "input",
+                                r#type: "text",
+                                value: "{project_authors}",
+                                placeholder: "Jane Doe <jane@example.com>",
+                                oninput: move |e| project_authors.set(e.value()),
+                            }
+                        }
+
+                        // License & Repository
+                        div {
+                            class: "grid grid-cols-2 gap-4",
+
+                            FormField {
+                                label: "License",
+                                required: false,
+                                hint: "SPDX expression",
+
+                                input {
+                                    class: "input",
+                                    r#type: "text",
+                                    value: "{project_license}",
+                                    placeholder: "MIT OR Apache-2.0",
+                                    oninput: move |e| project_license.set(e.value()),
+                                }
+                            }
+
+                            FormField {
+                                label: "Repository",
+                                required: false,
+
+                                input {
+                                    class: "input",
+                                    r#type: "url",
+                                    value: "{project_repository}",
+                                    placeholder: "https://github.com/you/my-awesome-api",
+                                    oninput: move |e| project_repository.set(e.value()),
+                                }
+                            }
+                        }
+
+                        // Keywords
+                        FormField {
+                            label: "Keywords",
+                            required: false,
+                            hint: "Comma-separated; crates.io accepts up to five",
+
+                            input {
+                                class: "input",
+                                r#type: "text",
+                                value: "{project_keywords}",
+                                placeholder: "api, rest, backend",
+                                oninput: move |e| project_keywords.set(e.value()),
+                            }
+                        }
+
                         // Package Name
                         FormField {
                             label: "Package Name",
@@ -405,7 +635,7 @@ pub fn ProjectSetupPage() -> Element {
                                                 // Update both local state and global state
                                                 project_location.set(Some(path.clone()));
                                                 let mut state = APP_STATE.write();
-                                                state.project_path = Some(path.clone());
+                                                let previous = state.project_path.replace(path.clone());
 
                                                 // Also save the project immediately
                                                 if let Some(project) = &state.project {
@@ -415,6 +645,7 @@ pub fn ProjectSetupPage() -> Element {
 
                                                     match imortal_ir::save_project(&project_clone, &path_clone) {
                                                         Ok(_) => {
+                                                            autosave::discard_after_save(previous.as_deref(), &path_clone);
                                                             let mut state = APP_STATE.write();
                                                             state.mark_saved(Some(path_clone.clone()));
                                                             state.ui.set_status(
@@ -484,6 +715,251 @@ pub fn ProjectSetupPage() -> Element {
                                 onclick: move |_| project_type.set(ProjectType::Fullstack),
                             }
                         }
+
+                        // TypeScript client
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "TypeScript Client" }
+                                p { class: "text-sm text-slate-400", "Also write TypeScript interfaces for every DTO and a fetch client per endpoint group to frontend/ts-client/, for JavaScript frontends." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *typescript_client.read(),
+                                onchange: move |enabled| typescript_client.set(enabled),
+                            }
+                        }
+                    }
+
+                    // List Responses Section
+                    FormSection {
+                        title: "List Responses",
+                        description: "Envelope returned by every list endpoint, client and table",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+
+                            for style in PaginationStyle::all().iter().copied() {
+                                ProjectTypeCard {
+                                    key: "{style:?}",
+                                    selected: *pagination.read() == style,
+                                    icon: match style {
+                                        PaginationStyle::Offset => "📄",
+                                        PaginationStyle::Cursor => "➡️",
+                                        PaginationStyle::None => "📋",
+                                    },
+                                    title: style.display_name(),
+                                    description: style.description(),
+                                    onclick: move |_| pagination.set(style),
+                                }
+                            }
+                        }
+                    }
+
+                    // ORM Section
+                    FormSection {
+                        title: "ORM",
+                        description: "Data layer the models, handlers and migrations are written for",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+
+                            for backend in Orm::all().iter().copied() {
+                                ProjectTypeCard {
+                                    key: "{backend:?}",
+                                    selected: *orm.read() == backend,
+                                    icon: match backend {
+                                        Orm::SeaOrm => "🌊",
+                                        Orm::Diesel => "⛽",
+                                    },
+                                    title: backend.display_name(),
+                                    description: backend.description(),
+                                    onclick: move |_| orm.set(backend),
+                                }
+                            }
+                        }
+                    }
+
+                    // Web Framework Section
+                    FormSection {
+                        title: "Web Framework",
+                        description: "HTTP framework the handlers, routes and middleware are written for",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+
+                            for backend in WebFramework::all().iter().copied() {
+                                ProjectTypeCard {
+                                    key: "{backend:?}",
+                                    selected: *framework.read() == backend,
+                                    icon: match backend {
+                                        WebFramework::Axum => "🗼",
+                                        WebFramework::ActixWeb => "🎭",
+                                    },
+                                    title: backend.display_name(),
+                                    description: backend.description(),
+                                    onclick: move |_| framework.set(backend),
+                                }
+                            }
+                        }
+                    }
+
+                    // API Style Section
+                    FormSection {
+                        title: "API Style",
+                        description: "How the generated server exposes your entities",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+
+                            for style in ApiStyle::all().iter().copied() {
+                                ProjectTypeCard {
+                                    key: "{style:?}",
+                                    selected: *api_style.read() == style,
+                                    icon: match style {
+                                        ApiStyle::Rest => "🔗",
+                                        ApiStyle::Graphql => "◈",
+                                        ApiStyle::Both => "🔀",
+                                    },
+                                    title: style.display_name(),
+                                    description: style.description(),
+                                    onclick: move |_| api_style.set(style),
+                                }
+                            }
+                        }
+                    }
+
+                    // Resource Paths Section
+                    FormSection {
+                        title: "Resource Paths",
+                        description: "How endpoint paths are derived from entity names; paths edited by hand are kept",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+
+                            FormField {
+                                label: "Plurality",
+                                required: false,
+                                div {
+                                    class: "flex gap-2",
+                                    for plurality in PathPlurality::all().iter().copied() {
+                                        button {
+                                            key: "{plurality:?}",
+                                            class: if route_style.read().plurality == plurality { "px-3 py-1.5 rounded-lg text-sm bg-indigo-600 text-white" } else { "px-3 py-1.5 rounded-lg text-sm bg-slate-700 hover:bg-slate-600 text-slate-300" },
+                                            r#type: "button",
+                                            onclick: move |_| route_style.with_mut(|s| s.plurality = plurality),
+                                            "{plurality.display_name()}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            FormField {
+                                label: "Case",
+                                required: false,
+                                div {
+                                    class: "flex gap-2",
+                                    for case in PathCase::all().iter().copied() {
+                                        button {
+                                            key: "{case:?}",
+                                            class: if route_style.read().case == case { "px-3 py-1.5 rounded-lg text-sm bg-indigo-600 text-white" } else { "px-3 py-1.5 rounded-lg text-sm bg-slate-700 hover:bg-slate-600 text-slate-300" },
+                                            r#type: "button",
+                                            onclick: move |_| route_style.with_mut(|s| s.case = case),
+                                            "{case.display_name()}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            FormField {
+                                label: "Child Collections",
+                                required: false,
+                                div {
+                                    class: "flex gap-2",
+                                    for nesting in NestedPathStyle::all().iter().copied() {
+                                        button {
+                                            key: "{nesting:?}",
+                                            class: if route_style.read().nesting == nesting { "px-3 py-1.5 rounded-lg text-sm bg-indigo-600 text-white" } else { "px-3 py-1.5 rounded-lg text-sm bg-slate-700 hover:bg-slate-600 text-slate-300" },
+                                            r#type: "button",
+                                            onclick: move |_| route_style.with_mut(|s| s.nesting = nesting),
+                                            "{nesting.display_name()}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        p {
+                            class: "text-xs text-slate-500 font-mono",
+                            "{route_preview}"
+                        }
+                    }
+
+                    // API Versioning Section
+                    FormSection {
+                        title: "API Versioning",
+                        description: "Where clients name the API version; endpoint groups on different versions are served side by side",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+
+                            FormField {
+                                label: "Strategy",
+                                required: false,
+                                div {
+                                    class: "flex gap-2",
+                                    for strategy in [ApiVersioning::None, ApiVersioning::path_prefix(""), ApiVersioning::header("")] {
+                                        button {
+                                            key: "{strategy.display_name()}",
+                                            class: if api_versioning.read().display_name() == strategy.display_name() { "px-3 py-1.5 rounded-lg text-sm bg-indigo-600 text-white" } else { "px-3 py-1.5 rounded-lg text-sm bg-slate-700 hover:bg-slate-600 text-slate-300" },
+                                            r#type: "button",
+                                            onclick: {
+                                                let strategy = strategy.clone();
+                                                move |_| api_versioning.set(strategy.clone())
+                                            },
+                                            "{strategy.display_name()}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            if *api_versioning.read() != ApiVersioning::None {
+                                FormField {
+                                    label: "Default Version",
+                                    required: true,
+                                    hint: "Used by endpoint groups without their own version",
+
+                                    input {
+                                        class: "input",
+                                        r#type: "text",
+                                        value: "{api_default_version}",
+                                        placeholder: "v1",
+                                        oninput: move |e| api_default_version.set(e.value()),
+                                    }
+                                }
+                            }
+
+                            if api_versioning.read().header_name().is_some() {
+                                FormField {
+                                    label: "Version Header",
+                                    required: true,
+
+                                    input {
+                                        class: "input",
+                                        r#type: "text",
+                                        value: "{api_version_header}",
+                                        placeholder: ApiVersioning::DEFAULT_HEADER,
+                                        oninput: move |e| api_version_header.set(e.value()),
+                                    }
+                                }
+                            }
+                        }
+
+                        p {
+                            class: "text-xs text-slate-500 font-mono",
+                            "{versioning_preview}"
+                        }
                     }
 
                     // Database Section
@@ -535,6 +1011,325 @@ pub fn ProjectSetupPage() -> Element {
                                 },
                             }
                         }
+
+                        // Foreign key index policy
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Always Index Foreign Keys" }
+                                p { class: "text-sm text-slate-400", "Create an index for every foreign key column in migrations, even if the field opts out" }
+                                if *index_foreign_keys.read() && fk_index_suggestions > 0 {
+                                    button {
+                                        class: "mt-2 text-xs text-indigo-400 hover:text-indigo-300",
+                                        onclick: move |_| {
+                                            let mut state = APP_STATE.write();
+                                            if let Some(project) = state.project.as_mut() {
+                                                project.apply_fk_index_suggestions();
+                                                state.mark_dirty();
+                                            }
+                                        },
+                                        "{fk_index_suggestions} foreign key(s) not marked indexed — mark them to match"
+                                    }
+                                }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *index_foreign_keys.read(),
+                                onchange: move |enabled| index_foreign_keys.set(enabled),
+                            }
+                        }
+
+                        // Read/write splitting
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Read Replica Support" }
+                                p { class: "text-sm text-slate-400", "Serve list and get requests from DATABASE_READ_URL; writes stay on DATABASE_URL. Replica lag means a read right after a write may be stale." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *read_replicas.read(),
+                                onchange: move |enabled| read_replicas.set(enabled),
+                            }
+                        }
+
+                        // Explain RESTRICT failures on delete
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Explain Blocked Deletes" }
+                                p { class: "text-sm text-slate-400", "When a RESTRICT foreign key would refuse a delete, answer 409 Conflict listing the referencing records instead of a database error." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *report_delete_blockers.read(),
+                                onchange: move |enabled| report_delete_blockers.set(enabled),
+                            }
+                        }
+
+                        // Staged destructive migrations
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Safe Migrations" }
+                                p { class: "text-sm text-slate-400", "Stage destructive schema changes for zero-downtime deploys: narrowing type changes add, backfill and swap a new column, and dropped columns and tables are left for a later migration." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *safe_migrations.read(),
+                                onchange: move |enabled| safe_migrations.set(enabled),
+                            }
+                        }
+
+                        // OpenTelemetry tracing
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "OpenTelemetry Tracing" }
+                                p { class: "text-sm text-slate-400", "Export traces over OTLP (OTEL_EXPORTER_OTLP_ENDPOINT) with an HTTP span per request and a handler span tagged with entity, operation and the caller's role." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *telemetry.read(),
+                                onchange: move |enabled| telemetry.set(enabled),
+                            }
+                        }
+
+                        // Health probes
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Health Checks" }
+                                p { class: "text-sm text-slate-400", "Serve /healthz for liveness and /readyz, which answers 503 while the database is unreachable." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *health_checks.read(),
+                                onchange: move |enabled| health_checks.set(enabled),
+                            }
+                        }
+
+                        // Prometheus metrics
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Prometheus Metrics" }
+                                p { class: "text-sm text-slate-400", "Count requests and record their latencies, exported on /metrics for Prometheus to scrape." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *metrics.read(),
+                                onchange: move |enabled| metrics.set(enabled),
+                            }
+                        }
+
+                        // Background jobs
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg mt-4",
+
+                            div {
+                                h4 { class: "font-medium", "Background Jobs" }
+                                p { class: "text-sm text-slate-400", "Run a job queue on tokio worker tasks (JOB_WORKERS), with a scheduled purge of old soft-deleted rows for each entity that soft-deletes. SeaORM only." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *background_jobs.read(),
+                                onchange: move |enabled| background_jobs.set(enabled),
+                            }
+                        }
+                    }
+
+                    // File Uploads Section
+                    FormSection {
+                        title: "File Uploads",
+                        description: "Where files sent to the upload routes of File and Image fields are stored",
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+
+                            for storage in UploadStorage::all().iter().copied() {
+                                ProjectTypeCard {
+                                    key: "{storage:?}",
+                                    selected: *upload_storage.read() == storage,
+                                    icon: match storage {
+                                        UploadStorage::Local => "💾",
+                                        UploadStorage::S3 => "🪣",
+                                    },
+                                    title: storage.display_name(),
+                                    description: storage.description(),
+                                    onclick: move |_| upload_storage.set(storage),
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "mt-4",
+                            label {
+                                class: "block text-slate-400 mb-1 text-sm",
+                                "Max Upload Size (MB)"
+                            }
+                            div {
+                                class: "flex items-center gap-3",
+                                input {
+                                    class: "w-32 px-3 py-1.5 bg-slate-700 border border-slate-600 rounded font-mono text-sm text-slate-200 focus:outline-none focus:border-indigo-500",
+                                    r#type: "number",
+                                    min: "1",
+                                    max: "1024",
+                                    value: "{upload_max_mb}",
+                                    oninput: move |e| {
+                                        if let Ok(mb) = e.value().parse::<u32>() {
+                                            upload_max_mb.set(mb);
+                                        }
+                                    },
+                                }
+                                span {
+                                    class: "text-xs text-slate-500",
+                                    "Larger files are rejected with 400 Bad Request."
+                                }
+                            }
+                        }
+                    }
+
+                    // CORS Section
+                    FormSection {
+                        title: "CORS",
+                        description: "Which browser origins may call the API; endpoint groups listing their own origins keep them",
+
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg",
+
+                            div {
+                                h4 { class: "font-medium", "Enable CORS" }
+                                p { class: "text-sm text-slate-400", "Answer preflight requests and add Access-Control-Allow-* headers for allowed origins." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: *cors_enabled.read(),
+                                onchange: move |enabled| cors_enabled.set(enabled),
+                            }
+                        }
+
+                        if *cors_enabled.read() {
+                            FormField {
+                                label: "Allowed Origins",
+                                required: false,
+                                hint: "Comma-separated, e.g. https://app.example.com. Leave empty to allow any origin.",
+                                input {
+                                    class: "input font-mono",
+                                    r#type: "text",
+                                    placeholder: "https://app.example.com, http://localhost:3000",
+                                    value: "{cors_origins}",
+                                    oninput: move |e| cors_origins.set(e.value()),
+                                }
+                            }
+
+                            div {
+                                class: "mt-4",
+                                label {
+                                    class: "block text-slate-400 mb-2 text-sm",
+                                    "Allowed Methods (none checked allows any)"
+                                }
+                                div {
+                                    class: "flex flex-wrap gap-4",
+                                    for method in CorsConfig::METHODS.iter().copied() {
+                                        label {
+                                            key: "{method}",
+                                            class: "flex items-center gap-2 cursor-pointer",
+                                            input {
+                                                r#type: "checkbox",
+                                                class: "w-4 h-4 accent-indigo-500",
+                                                checked: cors_methods.read().iter().any(|m| m == method),
+                                                onchange: move |e| {
+                                                    let mut methods = cors_methods.write();
+                                                    methods.retain(|m| m != method);
+                                                    if e.checked() {
+                                                        methods.push(method.to_string());
+                                                    }
+                                                },
+                                            }
+                                            span { class: "text-sm text-slate-300 font-mono", "{method}" }
+                                        }
+                                    }
+                                }
+                            }
+
+                            label {
+                                class: "flex items-center gap-2 cursor-pointer mt-4",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "w-4 h-4 accent-indigo-500",
+                                    checked: *cors_credentials.read(),
+                                    onchange: move |e| cors_credentials.set(e.checked()),
+                                }
+                                span {
+                                    class: "text-sm text-slate-300",
+                                    "Allow credentials (cookies and Authorization headers; needs explicit origins)"
+                                }
+                            }
+                        }
+                    }
+
+                    // Rate Limiting Section
+                    FormSection {
+                        title: "Rate Limiting",
+                        description: "Default request quota of every REST route and custom query; operations with their own rate limit keep it",
+
+                        div {
+                            class: "flex items-center justify-between p-4 bg-slate-800/50 rounded-lg",
+
+                            div {
+                                h4 { class: "font-medium", "Default Rate Limit" }
+                                p { class: "text-sm text-slate-400", "Count requests per signed-in user (per client IP without auth) and answer 429 Too Many Requests once the quota is used up." }
+                            }
+
+                            ToggleSwitch {
+                                enabled: rate_limit.read().is_some(),
+                                onchange: move |enabled: bool| {
+                                    rate_limit.set(enabled.then(RateLimit::permissive));
+                                },
+                            }
+                        }
+
+                        if let Some(limit) = rate_limit.read().clone() {
+                            div {
+                                class: "flex items-center gap-3 mt-4",
+                                input {
+                                    class: "w-24 px-3 py-1.5 bg-slate-700 border border-slate-600 rounded font-mono text-sm text-slate-200 focus:outline-none focus:border-indigo-500",
+                                    r#type: "number",
+                                    min: "1",
+                                    value: "{limit.requests}",
+                                    oninput: move |e| {
+                                        if let Ok(requests) = e.value().parse::<u32>()
+                                            && let Some(limit) = rate_limit.write().as_mut()
+                                        {
+                                            limit.requests = requests;
+                                        }
+                                    },
+                                }
+                                span { class: "text-sm text-slate-400", "requests per" }
+                                input {
+                                    class: "w-24 px-3 py-1.5 bg-slate-700 border border-slate-600 rounded font-mono text-sm text-slate-200 focus:outline-none focus:border-indigo-500",
+                                    r#type: "number",
+                                    min: "1",
+                                    value: "{limit.window_seconds}",
+                                    oninput: move |e| {
+                                        if let Ok(seconds) = e.value().parse::<u32>()
+                                            && let Some(limit) = rate_limit.write().as_mut()
+                                        {
+                                            limit.window_seconds = seconds;
+                                        }
+                                    },
+                                }
+                                span { class: "text-sm text-slate-400", "seconds" }
+                            }
+                        }
                     }
 
                     // Database Connection Details
@@ -881,6 +1676,14 @@ pub fn ProjectSetupPage() -> Element {
                                         let user = db_username.read().clone();
                                         let pass = db_password.read().clone();
                                         let dbname = db_name.read().clone();
+                                        let tested = TestedConnection {
+                                            database: current_db_type,
+                                            host: host.clone(),
+                                            port,
+                                            username: user.clone(),
+                                            password: pass.clone(),
+                                            database_name: dbname.clone(),
+                                        };
 
                                         test_loading.set(true);
                                         test_status.set(None);
@@ -1008,7 +1811,10 @@ pub fn ProjectSetupPage() -> Element {
                                             };
 
                                             match result {
-                                                Ok(msg) => test_status.set(Some((true, msg))),
+                                                Ok(msg) => {
+                                                    APP_STATE.write().tested_connection = Some(tested);
+                                                    test_status.set(Some((true, msg)));
+                                                }
                                                 Err(msg) => test_status.set(Some((false, msg))),
                                             }
 
@@ -1139,6 +1945,11 @@ pub fn ProjectSetupPage() -> Element {
 
                         // Auth Strategy (shown when auth is enabled)
                         if *auth_enabled.read() {
+                            div {
+                                class: "flex items-center mt-4 text-xs text-slate-400",
+                                "Which strategy should I pick?"
+                                HelpButton { topic: HelpTopic::AuthStrategies }
+                            }
                             div {
                                 class: "grid grid-cols-1 md:grid-cols-2 gap-4 mt-4",
 
@@ -1157,10 +1968,96 @@ pub fn ProjectSetupPage() -> Element {
                                     description: "Traditional session cookies. Best for server-rendered applications.",
                                     onclick: move |_| auth_strategy.set(AuthStrategy::Session),
                                 }
+
+                                AuthStrategyCard {
+                                    selected: *auth_strategy.read() == AuthStrategy::OAuth2,
+                                    icon: "🌐",
+                                    name: "OAuth2 / Social Login",
+                                    description: "Sign in with Google, GitHub or any OpenID Connect provider, then JWT tokens.",
+                                    onclick: move |_| auth_strategy.set(AuthStrategy::OAuth2),
+                                }
+                            }
+
+                            // OAuth2 providers
+                            if *auth_strategy.read() == AuthStrategy::OAuth2 {
+                                div {
+                                    class: "mt-4 p-4 bg-slate-800/30 rounded-lg border border-slate-700",
+
+                                    h4 {
+                                        class: "font-medium mb-3 text-sm",
+                                        "Identity Providers"
+                                    }
+
+                                    div {
+                                        class: "flex items-center gap-6 mb-4",
+                                        for kind in [OAuthProviderKind::Google, OAuthProviderKind::GitHub] {
+                                            label {
+                                                key: "{kind.display_name()}",
+                                                class: "flex items-center gap-2 cursor-pointer",
+                                                input {
+                                                    r#type: "checkbox",
+                                                    class: "w-4 h-4 accent-indigo-500",
+                                                    checked: oauth_providers.read().iter().any(|p| p.kind == kind),
+                                                    onchange: move |e| {
+                                                        let mut providers = oauth_providers.write();
+                                                        if !e.checked() {
+                                                            providers.retain(|p| p.kind != kind);
+                                                        } else if kind == OAuthProviderKind::Google {
+                                                            providers.push(OAuthProvider::google());
+                                                        } else {
+                                                            providers.push(OAuthProvider::github());
+                                                        }
+                                                    },
+                                                }
+                                                span {
+                                                    class: "text-sm text-slate-300",
+                                                    "{kind.display_name()}"
+                                                }
+                                            }
+                                        }
+                                    }
+
+                                    // Generic OpenID Connect provider, on when an issuer is set
+                                    div {
+                                        label {
+                                            class: "block text-slate-400 mb-1 text-sm",
+                                            "OpenID Connect issuer URL"
+                                        }
+                                        input {
+                                            class: "w-full px-3 py-1.5 bg-slate-700 border border-slate-600 rounded font-mono text-sm text-slate-200 focus:outline-none focus:border-indigo-500",
+                                            r#type: "url",
+                                            placeholder: "https://login.example.com (optional)",
+                                            value: oauth_providers
+                                                .read()
+                                                .iter()
+                                                .find(|p| p.kind == OAuthProviderKind::Oidc)
+                                                .and_then(|p| p.issuer_url.clone())
+                                                .unwrap_or_default(),
+                                            oninput: move |e| {
+                                                let issuer = e.value().trim().to_string();
+                                                let mut providers = oauth_providers.write();
+                                                if issuer.is_empty() {
+                                                    providers.retain(|p| p.kind != OAuthProviderKind::Oidc);
+                                                } else if let Some(provider) = providers
+                                                    .iter_mut()
+                                                    .find(|p| p.kind == OAuthProviderKind::Oidc)
+                                                {
+                                                    provider.issuer_url = Some(issuer);
+                                                } else {
+                                                    providers.push(OAuthProvider::oidc("oidc", issuer));
+                                                }
+                                            },
+                                        }
+                                        p {
+                                            class: "text-xs text-slate-500 mt-1",
+                                            "Each provider reads its client ID and secret from <NAME>_CLIENT_ID and <NAME>_CLIENT_SECRET; register OAUTH_REDIRECT_BASE_URL followed by /api/auth/oauth/<name>/callback as its redirect URI."
+                                        }
+                                    }
+                                }
                             }
 
-                            // JWT Options
-                            if *auth_strategy.read() == AuthStrategy::Jwt {
+                            // JWT Options (OAuth2 signs users in with JWT tokens too)
+                            if matches!(*auth_strategy.read(), AuthStrategy::Jwt | AuthStrategy::OAuth2) {
                                 div {
                                     class: "mt-4 p-4 bg-slate-800/30 rounded-lg border border-slate-700",
 
@@ -1197,6 +2094,28 @@ pub fn ProjectSetupPage() -> Element {
                                         }
                                     }
 
+                                    // Refresh tokens toggle
+                                    div {
+                                        class: "mb-4",
+                                        label {
+                                            class: "flex items-center gap-2 cursor-pointer",
+                                            input {
+                                                r#type: "checkbox",
+                                                class: "w-4 h-4 accent-indigo-500",
+                                                checked: *refresh_tokens.read(),
+                                                onchange: move |e| refresh_tokens.set(e.checked()),
+                                            }
+                                            span {
+                                                class: "text-sm text-slate-300",
+                                                "Refresh tokens"
+                                            }
+                                        }
+                                        p {
+                                            class: "text-xs text-slate-500 mt-1",
+                                            "Rotating refresh tokens and revocable sessions, with refresh, logout and logout-all endpoints."
+                                        }
+                                    }
+
                                     // Environment variable (static display)
                                     div {
                                         class: "mb-4 p-3 bg-slate-900/50 rounded-lg",
@@ -1239,10 +2158,10 @@ pub fn ProjectSetupPage() -> Element {
 
                                             div {
                                                 class: "flex items-start gap-3 p-2 rounded bg-slate-800/50",
-                                                span { class: "font-mono text-indigo-400 min-w-[60px]", "email" }
+                                                span { class: "font-mono text-indigo-400 min-w-[60px]", "{identifier_claim}" }
                                                 div {
-                                                    p { class: "text-slate-300", "User Email" }
-                                                    p { class: "text-xs text-slate-500", "The user's email address at time of token creation" }
+                                                    p { class: "text-slate-300", "Sign-in Identifier" }
+                                                    p { class: "text-xs text-slate-500", "The user's {identifier_claim} at time of token creation" }
                                                 }
                                             }
 
@@ -19,13 +19,14 @@
 
 use dioxus::prelude::*;
 use imortal_core::{ReferentialAction, RelationType};
-use imortal_ir::{Entity, Relationship};
+use imortal_ir::{Entity, Relationship, accessor_names};
 use uuid::Uuid;
 
 use crate::components::Canvas;
 use crate::components::connection::{
     ConnectionsLayer, relationship_color, relationship_type_label,
 };
+use crate::components::icon::{Icon, IconName};
 use crate::components::inputs::{Select, SelectOption, TextInput};
 use crate::state::{APP_STATE, DeleteTarget, Dialog};
 
@@ -193,7 +194,7 @@ pub fn RelationshipsPage() -> Element {
             }
 
             if count > 0 {
-                state.is_dirty = true;
+                state.mark_dirty();
                 tracing::info!("Auto-detected {} relationship(s) from FK fields", count);
             }
         }
@@ -237,6 +238,7 @@ pub fn RelationshipsPage() -> Element {
                 RelationshipFilter::ManyToMany => {
                     matches!(r.relation_type, RelationType::ManyToMany { .. })
                 }
+                RelationshipFilter::Polymorphic => r.is_polymorphic(),
             };
 
             // Apply search filter
@@ -315,6 +317,11 @@ pub fn RelationshipsPage() -> Element {
             label: "Many-to-Many (N:M)".to_string(),
             disabled: false,
         },
+        SelectOption {
+            value: "polymorphic".to_string(),
+            label: "Polymorphic (N:1*)".to_string(),
+            disabled: false,
+        },
     ];
 
     if !has_project {
@@ -451,6 +458,7 @@ enum RelationshipFilter {
     OneToMany,
     ManyToOne,
     ManyToMany,
+    Polymorphic,
 }
 
 fn filter_to_string(filter: &RelationshipFilter) -> String {
@@ -460,6 +468,7 @@ fn filter_to_string(filter: &RelationshipFilter) -> String {
         RelationshipFilter::OneToMany => "one_to_many".to_string(),
         RelationshipFilter::ManyToOne => "many_to_one".to_string(),
         RelationshipFilter::ManyToMany => "many_to_many".to_string(),
+        RelationshipFilter::Polymorphic => "polymorphic".to_string(),
     }
 }
 
@@ -469,6 +478,7 @@ fn string_to_filter(s: &str) -> RelationshipFilter {
         "one_to_many" => RelationshipFilter::OneToMany,
         "many_to_one" => RelationshipFilter::ManyToOne,
         "many_to_many" => RelationshipFilter::ManyToMany,
+        "polymorphic" => RelationshipFilter::Polymorphic,
         _ => RelationshipFilter::All,
     }
 }
@@ -500,18 +510,7 @@ fn RelationshipsToolbar(props: RelationshipsToolbarProps) -> Element {
                 h2 {
                     class: "text-lg font-semibold text-white flex items-center gap-2",
                     // Link icon
-                    svg {
-                        class: "w-5 h-5 text-indigo-400",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1",
-                        }
-                    }
+                    Icon { name: IconName::Link, class: "w-5 h-5 text-indigo-400" }
                     "Relationships"
                 }
 
@@ -571,18 +570,7 @@ fn RelationshipsToolbar(props: RelationshipsToolbarProps) -> Element {
                         class: "px-3 py-1.5 bg-red-600 hover:bg-red-700 text-white text-sm rounded transition-colors flex items-center gap-1",
                         onclick: move |_| props.on_delete.call(()),
                         // Trash icon
-                        svg {
-                            class: "w-4 h-4",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16",
-                            }
-                        }
+                        Icon { name: IconName::Trash }
                         "Delete"
                     }
                 }
@@ -592,18 +580,7 @@ fn RelationshipsToolbar(props: RelationshipsToolbarProps) -> Element {
                     class: "px-3 py-1.5 bg-indigo-600 hover:bg-indigo-700 text-white text-sm rounded transition-colors flex items-center gap-1",
                     onclick: move |_| props.on_create.call(()),
                     // Plus icon
-                    svg {
-                        class: "w-4 h-4",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M12 4v16m8-8H4",
-                        }
-                    }
+                    Icon { name: IconName::Plus }
                     "New Relationship"
                 }
             }
@@ -678,18 +655,7 @@ fn RelationshipsCanvas(props: RelationshipsCanvasProps) -> Element {
                         // Icon
                         div {
                             class: "w-16 h-16 mx-auto mb-4 rounded-full bg-indigo-900/50 flex items-center justify-center",
-                            svg {
-                                class: "w-8 h-8 text-indigo-400",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1",
-                                }
-                            }
+                            Icon { name: IconName::Link, class: "w-8 h-8 text-indigo-400" }
                         }
                         h3 {
                             class: "text-xl font-semibold text-white mb-2",
@@ -855,18 +821,7 @@ fn RelationshipsList(props: RelationshipsListProps) -> Element {
                                             e.stop_propagation();
                                             props.on_edit.call(rel_id);
                                         },
-                                        svg {
-                                            class: "w-4 h-4",
-                                            fill: "none",
-                                            stroke: "currentColor",
-                                            view_box: "0 0 24 24",
-                                            path {
-                                                stroke_linecap: "round",
-                                                stroke_linejoin: "round",
-                                                stroke_width: "2",
-                                                d: "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z",
-                                            }
-                                        }
+                                        Icon { name: IconName::Pencil }
                                     }
 
                                     // Delete button
@@ -877,18 +832,7 @@ fn RelationshipsList(props: RelationshipsListProps) -> Element {
                                             e.stop_propagation();
                                             props.on_delete.call(rel_id);
                                         },
-                                        svg {
-                                            class: "w-4 h-4",
-                                            fill: "none",
-                                            stroke: "currentColor",
-                                            view_box: "0 0 24 24",
-                                            path {
-                                                stroke_linecap: "round",
-                                                stroke_linejoin: "round",
-                                                stroke_width: "2",
-                                                d: "M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16",
-                                            }
-                                        }
+                                        Icon { name: IconName::Trash }
                                     }
                                 }
                             }
@@ -921,18 +865,7 @@ fn RelationshipPropertiesPanel(props: RelationshipPropertiesPanelProps) -> Eleme
                     class: "text-center py-12",
                     div {
                         class: "w-12 h-12 mx-auto mb-4 rounded-full bg-slate-700 flex items-center justify-center",
-                        svg {
-                            class: "w-6 h-6 text-slate-500",
-                            fill: "none",
-                            stroke: "currentColor",
-                            view_box: "0 0 24 24",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                stroke_width: "2",
-                                d: "M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z",
-                            }
-                        }
+                        Icon { name: IconName::Info, class: "w-6 h-6 text-slate-500" }
                     }
                     h3 {
                         class: "text-sm font-medium text-slate-300 mb-1",
@@ -977,6 +910,10 @@ fn RelationshipPropertiesPanel(props: RelationshipPropertiesPanelProps) -> Eleme
 
     let from_name = get_entity_name(rel.from_entity_id);
     let to_name = get_entity_name(rel.to_entity_id);
+    let entity = |id: Uuid| props.entities.iter().find(|e| e.id == id);
+    let accessors = entity(rel.from_entity_id)
+        .zip(entity(rel.to_entity_id))
+        .map(|(from, to)| accessor_names(&rel, from, to));
     let type_label = relationship_type_label(&rel.relation_type);
     let type_color = relationship_color(&rel.relation_type);
 
@@ -1116,17 +1053,30 @@ fn RelationshipPropertiesPanel(props: RelationshipPropertiesPanelProps) -> Eleme
                 }
             }
 
-            // Inverse name
-            if let Some(inverse) = &rel.inverse_name {
+            // Accessor names (derived ones are dimmed)
+            if let Some(names) = &accessors {
                 div {
                     class: "p-3 bg-slate-700/50 rounded-lg",
                     div {
                         class: "text-xs text-slate-500 mb-1",
-                        "Inverse Relationship Name"
+                        "Accessors"
                     }
                     div {
-                        class: "font-mono text-indigo-400",
-                        "{inverse}"
+                        class: "font-mono text-sm space-y-1",
+                        div {
+                            span { class: "text-slate-400", "{get_entity_name(names.accessor_entity_id)}." }
+                            span {
+                                class: if rel.accessor_name.is_some() { "text-indigo-400" } else { "text-slate-500" },
+                                "{names.accessor}"
+                            }
+                        }
+                        div {
+                            span { class: "text-slate-400", "{get_entity_name(names.inverse_entity_id)}." }
+                            span {
+                                class: if rel.inverse_name.is_some() { "text-indigo-400" } else { "text-slate-500" },
+                                "{names.inverse}"
+                            }
+                        }
                     }
                 }
             }
@@ -1192,18 +1142,7 @@ fn NoProjectState() -> Element {
                 // Icon
                 div {
                     class: "w-20 h-20 mx-auto mb-6 rounded-full bg-slate-800 flex items-center justify-center",
-                    svg {
-                        class: "w-10 h-10 text-slate-600",
-                        fill: "none",
-                        stroke: "currentColor",
-                        view_box: "0 0 24 24",
-                        path {
-                            stroke_linecap: "round",
-                            stroke_linejoin: "round",
-                            stroke_width: "2",
-                            d: "M3 7v10a2 2 0 002 2h14a2 2 0 002-2V9a2 2 0 00-2-2h-6l-2-2H5a2 2 0 00-2 2z",
-                        }
-                    }
+                    Icon { name: IconName::Folder, class: "w-10 h-10 text-slate-600" }
                 }
                 h2 {
                     class: "text-2xl font-bold text-white mb-2",
@@ -1320,6 +1259,10 @@ mod tests {
             filter_to_string(&RelationshipFilter::ManyToMany),
             "many_to_many"
         );
+        assert_eq!(
+            filter_to_string(&RelationshipFilter::Polymorphic),
+            "polymorphic"
+        );
 
         assert_eq!(string_to_filter("all"), RelationshipFilter::All);
         assert_eq!(string_to_filter("one_to_one"), RelationshipFilter::OneToOne);
@@ -1335,6 +1278,10 @@ mod tests {
             string_to_filter("many_to_many"),
             RelationshipFilter::ManyToMany
         );
+        assert_eq!(
+            string_to_filter("polymorphic"),
+            RelationshipFilter::Polymorphic
+        );
         assert_eq!(string_to_filter("invalid"), RelationshipFilter::All);
     }
 
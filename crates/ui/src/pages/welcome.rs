@@ -184,7 +184,7 @@ pub fn WelcomePage() -> Element {
                         on_open: move |path: String| {
                             let path_buf = PathBuf::from(&path);
                             spawn(async move {
-                                match file_ops::open_project(Some(path_buf)).await {
+                                match file_ops::open_project(Some(path_buf.clone())).await {
                                     Ok((project, saved_path)) => {
                                         let name = project.meta.name.clone();
                                         let mut state = APP_STATE.write();
@@ -198,6 +198,11 @@ pub fn WelcomePage() -> Element {
                                         add_to_recent_projects(&name, &saved_path);
                                         recent_projects.set(load_recent_projects());
                                     }
+                                    Err(e) if file_ops::is_damaged_file_error(&e) => {
+                                        APP_STATE.write().ui.show_dialog(
+                                            Dialog::RecoverProject(path_buf, e.to_string()),
+                                        );
+                                    }
                                     Err(e) => {
                                         APP_STATE.write().ui.set_status(
                                             format!("Failed to open project: {}", e),
@@ -0,0 +1,408 @@
+//! # Command Palette
+//!
+//! Everything the `Ctrl+K` palette can jump to — entities, fields,
+//! relationships, endpoint groups and app actions — and the fuzzy matching
+//! that ranks them against what the user types. The palette component in
+//! [`crate::components::command_palette`] renders the results and hands the
+//! chosen [`PaletteTarget`] to [`AppState::open_palette_target`].
+
+use uuid::Uuid;
+
+use crate::state::{AppState, Page};
+
+/// Most results shown at once
+pub const MAX_RESULTS: usize = 50;
+
+// ============================================================================
+// Items
+// ============================================================================
+
+/// What choosing a palette entry opens or does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteTarget {
+    /// Select an entity on the canvas
+    Entity(Uuid),
+    /// Select a field: (entity_id, field_id)
+    Field(Uuid, Uuid),
+    /// Select a relationship on the Relationships page
+    Relationship(Uuid),
+    /// Select an endpoint group on the Endpoints page
+    Endpoint(Uuid),
+    /// Run an app action
+    Action(PaletteAction),
+}
+
+/// App actions offered in the palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Switch to a page
+    GoTo(Page),
+    NewProject,
+    OpenProject,
+    ProjectSettings,
+    NewEntity,
+    NewRelationship,
+    NewEndpoint,
+    Undo,
+    Redo,
+    FitToContent,
+    ToggleHistory,
+    ToggleHelp,
+    CycleTheme,
+}
+
+impl PaletteAction {
+    /// Title shown in the palette
+    pub fn title(&self) -> String {
+        match self {
+            Self::GoTo(page) => format!("Go to {}", page.display_name()),
+            Self::NewProject => "New Project".to_string(),
+            Self::OpenProject => "Open Project".to_string(),
+            Self::ProjectSettings => "Project Settings".to_string(),
+            Self::NewEntity => "New Entity".to_string(),
+            Self::NewRelationship => "New Relationship".to_string(),
+            Self::NewEndpoint => "New Endpoint Group".to_string(),
+            Self::Undo => "Undo".to_string(),
+            Self::Redo => "Redo".to_string(),
+            Self::FitToContent => "Fit Canvas to Content".to_string(),
+            Self::ToggleHistory => "Toggle History".to_string(),
+            Self::ToggleHelp => "Toggle Help".to_string(),
+            Self::CycleTheme => "Switch Theme".to_string(),
+        }
+    }
+
+    /// Keyboard shortcut, shown as a hint
+    pub fn shortcut(&self) -> Option<&'static str> {
+        match self {
+            Self::NewProject => Some("Ctrl+N"),
+            Self::OpenProject => Some("Ctrl+O"),
+            Self::Undo => Some("Ctrl+Z"),
+            Self::Redo => Some("Ctrl+Y"),
+            Self::ToggleHelp => Some("F1"),
+            _ => None,
+        }
+    }
+
+    /// Whether the action makes sense in the current state
+    fn available(&self, state: &AppState) -> bool {
+        match self {
+            Self::GoTo(page) => !page.requires_project() || state.has_project(),
+            Self::NewProject | Self::OpenProject | Self::ToggleHelp | Self::CycleTheme => true,
+            Self::Undo => state.history.can_undo(),
+            Self::Redo => state.history.can_redo(),
+            _ => state.has_project(),
+        }
+    }
+}
+
+/// Every action, in the order shown for an empty query
+const ACTIONS: [PaletteAction; 19] = [
+    PaletteAction::NewEntity,
+    PaletteAction::NewRelationship,
+    PaletteAction::NewEndpoint,
+    PaletteAction::GoTo(Page::EntityDesign),
+    PaletteAction::GoTo(Page::Relationships),
+    PaletteAction::GoTo(Page::Endpoints),
+    PaletteAction::GoTo(Page::CodeGeneration),
+    PaletteAction::GoTo(Page::ProjectSetup),
+    PaletteAction::GoTo(Page::Settings),
+    PaletteAction::GoTo(Page::Welcome),
+    PaletteAction::FitToContent,
+    PaletteAction::Undo,
+    PaletteAction::Redo,
+    PaletteAction::ToggleHistory,
+    PaletteAction::ToggleHelp,
+    PaletteAction::CycleTheme,
+    PaletteAction::ProjectSettings,
+    PaletteAction::NewProject,
+    PaletteAction::OpenProject,
+];
+
+/// A single palette entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteItem {
+    /// What choosing the entry does
+    pub target: PaletteTarget,
+    /// Main text, matched against the query
+    pub title: String,
+    /// Secondary text (type, owner), also matched at a lower weight
+    pub detail: String,
+}
+
+impl PaletteItem {
+    fn new(target: PaletteTarget, title: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            target,
+            title: title.into(),
+            detail: detail.into(),
+        }
+    }
+
+    /// Short label for the kind of entry
+    pub fn kind(&self) -> &'static str {
+        match self.target {
+            PaletteTarget::Entity(_) => "Entity",
+            PaletteTarget::Field(..) => "Field",
+            PaletteTarget::Relationship(_) => "Relationship",
+            PaletteTarget::Endpoint(_) => "Endpoint",
+            PaletteTarget::Action(_) => "Action",
+        }
+    }
+
+    /// How well the entry matches a query, or `None` if it doesn't
+    fn score(&self, query: &str) -> Option<i32> {
+        let title = fuzzy_score(query, &self.title);
+        let detail = fuzzy_score(query, &self.detail).map(|s| s / 2);
+        title.max(detail)
+    }
+}
+
+/// All palette entries for the current state: entities, relationships,
+/// endpoint groups and fields of the open project (each sorted by name),
+/// then the actions that are currently available
+pub fn palette_items(state: &AppState) -> Vec<PaletteItem> {
+    let mut items = Vec::new();
+
+    if let Some(project) = &state.project {
+        let mut entities: Vec<_> = project.entities.values().collect();
+        entities.sort_by(|a, b| a.name.cmp(&b.name));
+        let entity_name = |id: Uuid| {
+            project
+                .entities
+                .get(&id)
+                .map_or("?", |e: &imortal_ir::Entity| e.name.as_str())
+        };
+
+        for entity in &entities {
+            let detail = format!("{} · {} fields", entity.table_name, entity.fields.len());
+            items.push(PaletteItem::new(
+                PaletteTarget::Entity(entity.id),
+                &entity.name,
+                detail,
+            ));
+        }
+
+        let mut relationships: Vec<_> = project.relationships.values().collect();
+        relationships.sort_by(|a, b| a.name.cmp(&b.name));
+        for rel in relationships {
+            let detail = format!(
+                "{} {} {} · {}",
+                entity_name(rel.from_entity_id),
+                rel.relation_type.arrow_symbol(),
+                entity_name(rel.to_entity_id),
+                rel.relation_type.display_name()
+            );
+            items.push(PaletteItem::new(
+                PaletteTarget::Relationship(rel.id),
+                &rel.name,
+                detail,
+            ));
+        }
+
+        let mut endpoints: Vec<_> = project.endpoints.values().collect();
+        endpoints.sort_by(|a, b| a.entity_name.cmp(&b.entity_name));
+        for endpoint in endpoints {
+            let detail = format!("{} endpoints", endpoint.entity_name);
+            items.push(PaletteItem::new(
+                PaletteTarget::Endpoint(endpoint.id),
+                endpoint.full_base_path(),
+                detail,
+            ));
+        }
+
+        for entity in &entities {
+            for field in entity.sorted_fields() {
+                items.push(PaletteItem::new(
+                    PaletteTarget::Field(entity.id, field.id),
+                    format!("{}.{}", entity.name, field.name),
+                    field.data_type.display_name(),
+                ));
+            }
+        }
+    }
+
+    for action in ACTIONS.iter().filter(|a| a.available(state)) {
+        let detail = action.shortcut().unwrap_or_default();
+        items.push(PaletteItem::new(
+            PaletteTarget::Action(*action),
+            action.title(),
+            detail,
+        ));
+    }
+
+    items
+}
+
+/// Entries matching a query, best first, at most [`MAX_RESULTS`].
+///
+/// An empty query lists everything except fields, which would crowd out
+/// the rest in any real project.
+pub fn search<'a>(items: &'a [PaletteItem], query: &str) -> Vec<&'a PaletteItem> {
+    if query.trim().is_empty() {
+        return items
+            .iter()
+            .filter(|item| !matches!(item.target, PaletteTarget::Field(..)))
+            .take(MAX_RESULTS)
+            .collect();
+    }
+
+    let mut scored: Vec<(i32, &PaletteItem)> = items
+        .iter()
+        .filter_map(|item| item.score(query).map(|score| (score, item)))
+        .collect();
+    // Stable, so equal scores keep the palette_items order
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.title.len().cmp(&b.1.title.len())));
+    scored
+        .into_iter()
+        .take(MAX_RESULTS)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+// ============================================================================
+// Fuzzy Matching
+// ============================================================================
+
+/// Score how well `text` matches `query` as a case-insensitive subsequence,
+/// ignoring whitespace in the query, or `None` if it doesn't match.
+///
+/// Matches at word starts (after `_`, `.`, `/`, spaces or a camelCase
+/// hump) and runs of consecutive characters score higher, as do prefix and
+/// exact matches, so `ue` ranks `User.email` above `Queue`.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    // Greedy matching from the first hit can miss a better alignment
+    // later on (`email` in `User.email`), so try every starting point
+    let chars: Vec<char> = text.chars().collect();
+    let mut score = (0..chars.len())
+        .filter_map(|start| match_from(&query, &chars, start))
+        .max()?;
+
+    let lower = text.to_lowercase();
+    let query: String = query.into_iter().collect();
+    if lower == query {
+        score += 20;
+    } else if lower.starts_with(&query) {
+        score += 10;
+    }
+    Some(score)
+}
+
+/// Greedily match `query` against `chars[start..]`, the first character at
+/// `start` itself
+fn match_from(query: &[char], chars: &[char], start: usize) -> Option<i32> {
+    let same = |c: char, q: char| c.to_lowercase().eq(std::iter::once(q));
+    if !same(chars[start], query[0]) {
+        return None;
+    }
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut last_match: Option<usize> = None;
+    for (i, &c) in chars.iter().enumerate().skip(start) {
+        if next == query.len() {
+            break;
+        }
+        if !same(c, query[next]) {
+            continue;
+        }
+
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == i) {
+            score += 5;
+        }
+        let word_start = match i.checked_sub(1).map(|p| chars[p]) {
+            None => true,
+            Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase()),
+        };
+        if word_start {
+            score += 8;
+        }
+        last_match = Some(i);
+        next += 1;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_core::{DataType, RelationType};
+    use imortal_ir::{Entity, Field, Relationship};
+
+    fn titles(results: &[&PaletteItem]) -> Vec<String> {
+        results.iter().map(|item| item.title.clone()).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(fuzzy_score("xyz", "User").is_none());
+        assert!(fuzzy_score("usr", "User").is_some());
+        assert!(fuzzy_score("user email", "User.email").is_some());
+
+        // Word starts and runs beat scattered matches
+        assert!(fuzzy_score("ue", "User.email") > fuzzy_score("ue", "Queue"));
+        assert!(fuzzy_score("bp", "BlogPost") > fuzzy_score("bp", "Subpath"));
+        // Exact beats prefix beats elsewhere
+        assert!(fuzzy_score("post", "Post") > fuzzy_score("post", "Posts"));
+        assert!(fuzzy_score("post", "Posts") > fuzzy_score("post", "BlogPost"));
+    }
+
+    #[test]
+    fn test_palette_items_and_search() {
+        let mut state = AppState::new();
+        assert!(
+            palette_items(&state)
+                .iter()
+                .all(|item| matches!(item.target, PaletteTarget::Action(_)))
+        );
+
+        state.new_project("Blog");
+        let project = state.project.as_mut().unwrap();
+        let user = project
+            .add_entity(Entity::new("User").with_field(Field::new("email", DataType::String)));
+        let post = project.add_entity(Entity::new("Post"));
+        let rel = project.add_relationship(Relationship::new(user, post, RelationType::OneToMany));
+        let endpoint = project.new_endpoint(user, "User");
+        project.add_endpoint(endpoint);
+
+        let items = palette_items(&state);
+        assert!(
+            items
+                .iter()
+                .any(|i| i.target == PaletteTarget::Relationship(rel))
+        );
+        assert!(items.iter().any(|i| i.kind() == "Endpoint"));
+        // Undo is offered only when there is something to undo
+        assert!(
+            !items
+                .iter()
+                .any(|i| i.target == PaletteTarget::Action(PaletteAction::Undo))
+        );
+
+        // Fields are searchable but left out of the empty listing
+        let all = search(&items, "");
+        assert!(!titles(&all).contains(&"User.email".to_string()));
+        let found = search(&items, "email");
+        assert_eq!(found[0].title, "User.email");
+        assert!(matches!(found[0].target, PaletteTarget::Field(e, _) if e == user));
+
+        let found = search(&items, "post");
+        assert_eq!(found[0].target, PaletteTarget::Entity(post));
+        assert!(search(&items, "zzz").is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! # Problems
+//!
+//! Project-wide validation for the Problems panel. [`collect_problems`] runs
+//! [`Validator`] with the default rules and flattens its errors and warnings
+//! into one list, errors first. The app re-runs it once edits have settled
+//! for [`VALIDATION_DEBOUNCE`] and stores the result in
+//! [`AppState::problems`](crate::state::AppState::problems); clicking a
+//! problem hands it to [`AppState::open_problem`](crate::state::AppState::open_problem).
+
+use std::time::Duration;
+
+use imortal_ir::validation::ValidationTarget;
+use imortal_ir::{ProjectGraph, Validator};
+
+/// How long the project must go unedited before it is validated again
+pub const VALIDATION_DEBOUNCE: Duration = Duration::from_millis(400);
+
+// ============================================================================
+// Problem
+// ============================================================================
+
+/// Whether a problem blocks code generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    /// Icon shown next to the problem
+    pub fn icon(self) -> &'static str {
+        match self {
+            Severity::Error => "⛔",
+            Severity::Warning => "⚠️",
+        }
+    }
+}
+
+/// One validation error or warning, as listed in the Problems panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub severity: Severity,
+    /// Validation code, e.g. `NoPrimaryKey`
+    pub code: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+    /// Path to the element, e.g. `entities.User.fields.email`
+    pub path: Option<String>,
+    /// Element to select when the problem is clicked
+    pub target: Option<ValidationTarget>,
+}
+
+/// Validate the project and list its problems, errors first
+pub fn collect_problems(project: &ProjectGraph) -> Vec<Problem> {
+    let result = Validator::with_default_rules().validate(project);
+
+    let errors = result.errors.into_iter().map(|e| Problem {
+        severity: Severity::Error,
+        code: format!("{:?}", e.code),
+        message: e.message,
+        suggestion: e.suggestion,
+        path: e.path,
+        target: e.target,
+    });
+    let warnings = result.warnings.into_iter().map(|w| Problem {
+        severity: Severity::Warning,
+        code: format!("{:?}", w.code),
+        message: w.message,
+        suggestion: w.suggestion,
+        path: w.path,
+        target: w.target,
+    });
+
+    errors.chain(warnings).collect()
+}
+
+/// Number of (errors, warnings) in a problem list
+pub fn counts(problems: &[Problem]) -> (usize, usize) {
+    let errors = problems
+        .iter()
+        .filter(|p| p.severity == Severity::Error)
+        .count();
+    (errors, problems.len() - errors)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::Entity;
+
+    #[test]
+    fn test_collect_problems() {
+        let mut project = ProjectGraph::new("blog");
+        let user = project.add_entity(Entity::new("User"));
+        let mut post = Entity::new("Post");
+        post.fields.clear();
+        let post = project.add_entity(post);
+
+        let problems = collect_problems(&project);
+        let (errors, warnings) = counts(&problems);
+        assert!(errors > 0);
+        assert!(warnings > 0);
+        assert_eq!(errors + warnings, problems.len());
+
+        // Errors come before warnings
+        let first_warning = problems
+            .iter()
+            .position(|p| p.severity == Severity::Warning)
+            .unwrap();
+        assert!(
+            problems[first_warning..]
+                .iter()
+                .all(|p| p.severity == Severity::Warning)
+        );
+
+        let no_fields = problems.iter().find(|p| p.code == "NoFields").unwrap();
+        assert_eq!(no_fields.severity, Severity::Error);
+        assert_eq!(no_fields.target, Some(ValidationTarget::Entity(post)));
+        assert!(
+            problems
+                .iter()
+                .any(|p| p.target == Some(ValidationTarget::Entity(user)))
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Session Persistence for Immortal Engine
+//!
+//! Remembers where the user left off — the open project, the active page,
+//! the canvas viewport and the entity selection — so the next launch can
+//! pick up from the same spot instead of the Welcome page. Editor
+//! preferences that aren't tied to a project, such as the theme, simple vs
+//! advanced dialogs and the semantic zoom thresholds, are stored here too
+//! and apply even when restoring is off.
+//!
+//! The session is written next to the recent projects list in the user's
+//! config directory and can be disabled from the Settings page.
+
+use std::path::PathBuf;
+
+use imortal_core::Position;
+use imortal_ir::serialization::load_project;
+use uuid::Uuid;
+
+use crate::file_ops;
+use crate::state::{AppState, Dialog, Page, SemanticZoom, StatusLevel, Theme};
+
+// ============================================================================
+// Session State
+// ============================================================================
+
+/// Snapshot of the editor session persisted between launches
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct SessionState {
+    /// Whether the session should be restored on startup
+    pub restore_on_launch: bool,
+    /// Path to the project that was open (None if no saved project was open)
+    pub project_path: Option<PathBuf>,
+    /// Page that was active
+    pub active_page: Page,
+    /// Canvas pan offset (x)
+    pub pan_x: f32,
+    /// Canvas pan offset (y)
+    pub pan_y: f32,
+    /// Canvas zoom level
+    pub zoom: f32,
+    /// Selected entity IDs
+    pub selected_entities: Vec<Uuid>,
+    /// Colour theme
+    pub theme: Theme,
+    /// Whether dialogs open in advanced mode
+    pub advanced_dialogs: bool,
+    /// Zoom below which entity cards show only their names
+    pub names_below: f32,
+    /// Zoom below which entity cards show only key fields
+    pub key_fields_below: f32,
+}
+
+impl Default for SessionState {
+    fn default() -> Self {
+        Self {
+            restore_on_launch: true,
+            project_path: None,
+            active_page: Page::Welcome,
+            pan_x: 0.0,
+            pan_y: 0.0,
+            zoom: 1.0,
+            selected_entities: Vec::new(),
+            theme: Theme::default(),
+            advanced_dialogs: false,
+            names_below: SemanticZoom::default().names_below,
+            key_fields_below: SemanticZoom::default().key_fields_below,
+        }
+    }
+}
+
+impl SessionState {
+    /// Capture the current session from the application state.
+    ///
+    /// Unsaved projects have no path to reopen, so they are recorded as
+    /// "no project" and the restored session starts on the Welcome page.
+    pub fn capture(state: &AppState) -> Self {
+        let project_path = state.project.as_ref().and(state.project_path.clone());
+
+        let active_page = if project_path.is_none() && state.ui.active_page.requires_project() {
+            Page::Welcome
+        } else {
+            state.ui.active_page
+        };
+
+        let mut selected_entities: Vec<Uuid> = state.selection.entities.iter().copied().collect();
+        selected_entities.sort();
+
+        Self {
+            restore_on_launch: state.ui.restore_session,
+            project_path,
+            active_page,
+            pan_x: state.canvas.pan.x,
+            pan_y: state.canvas.pan.y,
+            zoom: state.canvas.zoom,
+            selected_entities,
+            theme: state.ui.theme,
+            advanced_dialogs: state.ui.advanced_dialogs,
+            names_below: state.canvas.semantic_zoom.names_below,
+            key_fields_below: state.canvas.semantic_zoom.key_fields_below,
+        }
+    }
+
+    /// Apply this session to the application state.
+    ///
+    /// Reopens the project (if any), then restores the page, viewport and
+    /// selection. Selected entities that no longer exist in the project are
+    /// dropped. Returns an error message if the project could not be reopened;
+    /// for a damaged file the recovery dialog is opened as well.
+    pub fn apply(&self, state: &mut AppState) -> Result<(), String> {
+        state.ui.restore_session = self.restore_on_launch;
+        state.ui.theme = self.theme;
+        state.ui.advanced_dialogs = self.advanced_dialogs;
+        state
+            .canvas
+            .semantic_zoom
+            .set_key_fields_below(self.key_fields_below);
+        state.canvas.semantic_zoom.set_names_below(self.names_below);
+        if !self.restore_on_launch {
+            return Ok(());
+        }
+
+        let Some(path) = &self.project_path else {
+            if !self.active_page.requires_project() {
+                state.ui.navigate(self.active_page);
+            }
+            return Ok(());
+        };
+
+        let project = match load_project(path) {
+            Ok(project) => project,
+            Err(e) => {
+                if file_ops::is_damaged_file_error(&e) {
+                    state
+                        .ui
+                        .show_dialog(Dialog::RecoverProject(path.clone(), e.to_string()));
+                }
+                return Err(format!("Could not reopen {}: {}", path.display(), e));
+            }
+        };
+
+        let selected = self
+            .selected_entities
+            .iter()
+            .copied()
+            .filter(|id| project.entities.contains_key(id))
+            .collect();
+
+        state.load_project(project, path.clone());
+        state.selection.entities = selected;
+        state.canvas.pan = Position::new(self.pan_x, self.pan_y);
+        state.canvas.zoom = self.zoom.clamp(0.25, 3.0);
+        state.ui.navigate(self.active_page);
+        state.ui.set_status(
+            format!("Restored last session ({})", path.display()),
+            StatusLevel::Info,
+        );
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Persistence
+// ============================================================================
+
+/// Get the path to the session JSON file.
+///
+/// Stored alongside `recent_projects.json` in the user's config directory.
+fn session_path() -> Option<PathBuf> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()?;
+    Some(
+        PathBuf::from(home)
+            .join(".config")
+            .join("immortal-engine")
+            .join("session.json"),
+    )
+}
+
+/// Load the last saved session, falling back to defaults if none exists
+/// or the file cannot be parsed.
+pub fn load_session() -> SessionState {
+    session_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Save the session to the config file.
+pub fn save_session(session: &SessionState) {
+    let Some(path) = session_path() else {
+        return;
+    };
+
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    if let Ok(json) = serde_json::to_string_pretty(session) {
+        let _ = std::fs::write(&path, json);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use imortal_ir::{Entity, ProjectGraph};
+
+    #[test]
+    fn test_session_round_trip() {
+        let session = SessionState {
+            restore_on_launch: false,
+            project_path: Some(PathBuf::from("/tmp/blog.ieng")),
+            active_page: Page::Relationships,
+            pan_x: 120.0,
+            pan_y: -40.0,
+            zoom: 1.5,
+            selected_entities: vec![Uuid::new_v4()],
+            theme: Theme::Light,
+            advanced_dialogs: true,
+            names_below: 0.4,
+            key_fields_below: 0.9,
+        };
+
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: SessionState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_session_defaults_for_missing_fields() {
+        let restored: SessionState = serde_json::from_str("{}").unwrap();
+        assert!(restored.restore_on_launch);
+        assert_eq!(restored.active_page, Page::Welcome);
+        assert_eq!(restored.zoom, 1.0);
+        assert_eq!(restored.theme, Theme::Dark);
+        assert!(!restored.advanced_dialogs);
+        assert_eq!(restored.names_below, 0.5);
+        assert_eq!(restored.key_fields_below, 0.8);
+    }
+
+    #[test]
+    fn test_capture_unsaved_project_falls_back_to_welcome() {
+        let mut state = AppState::new();
+        state.project = Some(ProjectGraph::new("draft"));
+        state.ui.navigate(Page::EntityDesign);
+
+        let session = SessionState::capture(&state);
+        assert!(session.project_path.is_none());
+        assert_eq!(session.active_page, Page::Welcome);
+    }
+
+    #[test]
+    fn test_apply_restores_saved_project() {
+        let dir = std::env::temp_dir().join(format!("ieng-session-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("blog.ieng");
+
+        let mut project = ProjectGraph::new("blog");
+        let entity = Entity::new("Post");
+        let entity_id = entity.id;
+        project.add_entity(entity);
+        imortal_ir::serialization::save_project(&project, &path).unwrap();
+
+        let session = SessionState {
+            project_path: Some(path.clone()),
+            active_page: Page::Endpoints,
+            pan_x: 50.0,
+            pan_y: 25.0,
+            zoom: 2.0,
+            selected_entities: vec![entity_id, Uuid::new_v4()],
+            ..Default::default()
+        };
+
+        let mut state = AppState::new();
+        session.apply(&mut state).unwrap();
+
+        assert_eq!(state.project_path.as_deref(), Some(path.as_path()));
+        assert_eq!(state.ui.active_page, Page::Endpoints);
+        assert_eq!(state.canvas.pan, Position::new(50.0, 25.0));
+        assert_eq!(state.canvas.zoom, 2.0);
+        assert_eq!(state.selection.entities.len(), 1);
+        assert!(state.selection.entities.contains(&entity_id));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_apply_disabled_does_nothing() {
+        let session = SessionState {
+            restore_on_launch: false,
+            project_path: Some(PathBuf::from("/nonexistent/project.ieng")),
+            theme: Theme::System,
+            advanced_dialogs: true,
+            names_below: 0.3,
+            ..Default::default()
+        };
+
+        let mut state = AppState::new();
+        assert!(session.apply(&mut state).is_ok());
+        assert!(!state.has_project());
+        assert!(!state.ui.restore_session);
+        // Preferences still apply when the session itself isn't restored
+        assert_eq!(state.ui.theme, Theme::System);
+        assert!(state.ui.advanced_dialogs);
+        assert_eq!(state.canvas.semantic_zoom.names_below, 0.3);
+        assert_eq!(state.ui.active_page, Page::Welcome);
+    }
+}
@@ -5,17 +5,23 @@
 //! canvas state, and history for undo/redo operations.
 
 use dioxus::prelude::*;
-use imortal_core::{EngineError, EngineResult, Position};
-use imortal_ir::ProjectGraph;
+use imortal_core::{EngineError, EngineResult, Position, Rect, Size};
+use imortal_ir::{DatabaseType, Field, ProjectConfig, ProjectGraph};
 use std::collections::HashSet;
 use uuid::Uuid;
 
+use crate::autosave::Recovery;
+use crate::components::dialogs::EndpointTab;
+use crate::help::HelpTopic;
+use crate::problems::Problem;
+
 // ============================================================================
 // Page Navigation
 // ============================================================================
 
 /// Application pages/views
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Page {
     /// Welcome/landing page
     #[default]
@@ -198,6 +204,10 @@ pub struct CanvasState {
     pub grid_size: f32,
     /// Whether to show grid
     pub show_grid: bool,
+    /// Zoom thresholds at which entity cards drop detail
+    pub semantic_zoom: SemanticZoom,
+    /// On-screen size of the canvas, kept up to date as it resizes
+    pub viewport: Size,
 }
 
 impl Default for CanvasState {
@@ -214,6 +224,8 @@ impl Default for CanvasState {
             snap_to_grid: true,
             grid_size: 20.0,
             show_grid: true,
+            semantic_zoom: SemanticZoom::default(),
+            viewport: Size::zero(),
         }
     }
 }
@@ -246,6 +258,42 @@ impl CanvasState {
         self.pan = center;
     }
 
+    /// Pan so a canvas point sits in the middle of the viewport
+    pub fn center_on(&mut self, point: Position) {
+        self.pan = Position::new(
+            self.viewport.width / 2.0 - point.x * self.zoom,
+            self.viewport.height / 2.0 - point.y * self.zoom,
+        );
+    }
+
+    /// Zoom and pan so a canvas rectangle fills the viewport with a margin.
+    ///
+    /// Never zooms past 100%, so fitting a single small entity centres it
+    /// rather than blowing it up.
+    pub fn fit_rect(&mut self, rect: Rect) {
+        const MARGIN: f32 = 40.0;
+
+        let available_w = (self.viewport.width - MARGIN * 2.0).max(1.0);
+        let available_h = (self.viewport.height - MARGIN * 2.0).max(1.0);
+        if self.viewport.width > 0.0 && self.viewport.height > 0.0 {
+            let zoom_w = available_w / rect.size.width.max(1.0);
+            let zoom_h = available_h / rect.size.height.max(1.0);
+            self.zoom = zoom_w.min(zoom_h).clamp(0.25, 1.0);
+        }
+        self.center_on(rect.center());
+    }
+
+    /// The part of the canvas currently on screen, in canvas coordinates
+    pub fn visible_rect(&self) -> Rect {
+        Rect::new(
+            self.screen_to_canvas(Position::zero()),
+            Size::new(
+                self.viewport.width / self.zoom,
+                self.viewport.height / self.zoom,
+            ),
+        )
+    }
+
     /// Convert screen coordinates to canvas coordinates
     pub fn screen_to_canvas(&self, screen_pos: Position) -> Position {
         Position::new(
@@ -262,6 +310,11 @@ impl CanvasState {
         )
     }
 
+    /// How much of each entity card to draw at the current zoom
+    pub fn detail(&self) -> ZoomDetail {
+        self.semantic_zoom.detail_at(self.zoom)
+    }
+
     /// Snap position to grid if enabled
     pub fn snap_position(&self, pos: Position) -> Position {
         if self.snap_to_grid {
@@ -299,6 +352,66 @@ impl CanvasState {
     }
 }
 
+/// How much of an entity card is drawn, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ZoomDetail {
+    /// Entity names only
+    Names,
+    /// Primary, foreign and unique key fields
+    KeyFields,
+    /// The full card with every field
+    Full,
+}
+
+/// Zoom levels at which the canvas switches between card detail levels.
+///
+/// Zoomed far out, field rows are too small to read and only clutter the
+/// diagram, so cards shrink to their names and the relationship lines carry
+/// the structure. Both thresholds are zoom factors (1.0 = 100%).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SemanticZoom {
+    /// Below this zoom, cards show only the entity name
+    pub names_below: f32,
+    /// Below this zoom, cards show only key fields
+    pub key_fields_below: f32,
+}
+
+impl Default for SemanticZoom {
+    fn default() -> Self {
+        Self {
+            names_below: 0.5,
+            key_fields_below: 0.8,
+        }
+    }
+}
+
+impl SemanticZoom {
+    /// Detail level for a zoom factor
+    pub fn detail_at(&self, zoom: f32) -> ZoomDetail {
+        if zoom < self.names_below {
+            ZoomDetail::Names
+        } else if zoom < self.key_fields_below {
+            ZoomDetail::KeyFields
+        } else {
+            ZoomDetail::Full
+        }
+    }
+
+    /// Set the names threshold, raising the key fields threshold if needed
+    /// so the levels stay in order
+    pub fn set_names_below(&mut self, zoom: f32) {
+        self.names_below = zoom.clamp(0.25, 3.0);
+        self.key_fields_below = self.key_fields_below.max(self.names_below);
+    }
+
+    /// Set the key fields threshold, lowering the names threshold if needed
+    /// so the levels stay in order
+    pub fn set_key_fields_below(&mut self, zoom: f32) {
+        self.key_fields_below = zoom.clamp(0.25, 3.0);
+        self.names_below = self.names_below.min(self.key_fields_below);
+    }
+}
+
 /// Connection port on an entity card
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConnectionPort {
@@ -308,6 +421,65 @@ pub enum ConnectionPort {
     Output,
 }
 
+// ============================================================================
+// Theme
+// ============================================================================
+
+/// Colour theme of the editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// Dark slate palette
+    #[default]
+    Dark,
+    /// Light palette
+    Light,
+    /// Follow the operating system's light/dark preference
+    System,
+}
+
+impl Theme {
+    /// Every theme, in toggle order
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::System];
+
+    /// Get the display name for this theme
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::System => "System",
+        }
+    }
+
+    /// Get the icon emoji for this theme (for UI display)
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Theme::Dark => "🌙",
+            Theme::Light => "☀️",
+            Theme::System => "🖥️",
+        }
+    }
+
+    /// Value of the `data-theme` attribute the stylesheet keys its colour
+    /// variables on
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::System => "system",
+        }
+    }
+
+    /// The theme after this one when toggling from the toolbar
+    pub fn next(&self) -> Self {
+        match self {
+            Theme::Dark => Theme::Light,
+            Theme::Light => Theme::System,
+            Theme::System => Theme::Dark,
+        }
+    }
+}
+
 // ============================================================================
 // UI State
 // ============================================================================
@@ -325,10 +497,30 @@ pub struct UiState {
     pub active_dialog: Option<Dialog>,
     /// Status bar message
     pub status_message: Option<StatusMessage>,
-    /// Whether dark mode is enabled
-    pub dark_mode: bool,
+    /// Colour theme
+    pub theme: Theme,
     /// Whether the app is in fullscreen
     pub fullscreen: bool,
+    /// Whether the help panel is open
+    pub help_open: bool,
+    /// Topic shown in the help panel
+    pub help_topic: HelpTopic,
+    /// Whether the last session is restored on launch
+    pub restore_session: bool,
+    /// Whether the history browser is open
+    pub history_open: bool,
+    /// Whether dialogs show every option rather than just the essentials
+    pub advanced_dialogs: bool,
+    /// Whether the performance diagnostics overlay is open
+    pub diagnostics_open: bool,
+    /// Whether the entity list beside the canvas is expanded
+    pub entity_list_open: bool,
+    /// Whether the command palette (Ctrl+K) is open
+    pub palette_open: bool,
+    /// Whether the Problems panel is open
+    pub problems_open: bool,
+    /// Tab the endpoint dialog opens on, set when jumping to a problem
+    pub endpoint_tab: Option<EndpointTab>,
 }
 
 impl Default for UiState {
@@ -339,8 +531,18 @@ impl Default for UiState {
             active_page: Page::Welcome,
             active_dialog: None,
             status_message: None,
-            dark_mode: true, // Default to dark mode
+            theme: Theme::Dark,
             fullscreen: false,
+            help_open: false,
+            help_topic: HelpTopic::default(),
+            restore_session: true,
+            history_open: false,
+            advanced_dialogs: false,
+            diagnostics_open: false,
+            entity_list_open: true,
+            palette_open: false,
+            problems_open: false,
+            endpoint_tab: None,
         }
     }
 }
@@ -359,6 +561,7 @@ impl UiState {
     /// Show a dialog
     pub fn show_dialog(&mut self, dialog: Dialog) {
         self.active_dialog = Some(dialog);
+        self.endpoint_tab = None;
     }
 
     /// Close the current dialog
@@ -389,9 +592,51 @@ impl UiState {
         self.properties_collapsed = !self.properties_collapsed;
     }
 
-    /// Toggle dark mode
-    pub fn toggle_dark_mode(&mut self) {
-        self.dark_mode = !self.dark_mode;
+    /// Switch to the next theme (dark → light → system)
+    pub fn cycle_theme(&mut self) {
+        self.theme = self.theme.next();
+    }
+
+    /// Toggle the help panel
+    pub fn toggle_help(&mut self) {
+        self.help_open = !self.help_open;
+    }
+
+    /// Toggle the history browser
+    pub fn toggle_history(&mut self) {
+        self.history_open = !self.history_open;
+    }
+
+    /// Expand or collapse the entity list beside the canvas
+    pub fn toggle_entity_list(&mut self) {
+        self.entity_list_open = !self.entity_list_open;
+    }
+
+    /// Open or close the command palette
+    pub fn toggle_palette(&mut self) {
+        self.palette_open = !self.palette_open;
+    }
+
+    /// Open or close the Problems panel
+    pub fn toggle_problems(&mut self) {
+        self.problems_open = !self.problems_open;
+    }
+
+    /// Toggle the diagnostics overlay, recording counters only while it is open
+    pub fn toggle_diagnostics(&mut self) {
+        self.diagnostics_open = !self.diagnostics_open;
+        crate::diagnostics::set_enabled(self.diagnostics_open);
+    }
+
+    /// Switch dialogs between simple and advanced mode
+    pub fn toggle_advanced_dialogs(&mut self) {
+        self.advanced_dialogs = !self.advanced_dialogs;
+    }
+
+    /// Open the help panel on a specific topic
+    pub fn show_help(&mut self, topic: HelpTopic) {
+        self.help_topic = topic;
+        self.help_open = true;
     }
 }
 
@@ -402,6 +647,10 @@ pub enum Dialog {
     NewProject,
     /// Open project dialog
     OpenProject,
+    /// Recovery options for a project file that failed to load
+    RecoverProject(std::path::PathBuf, String), // path, load error
+    /// Offer to restore changes autosaved before the app last closed
+    RestoreRecovery(Recovery),
     /// Save project as dialog
     SaveProjectAs,
     /// New entity dialog
@@ -420,6 +669,10 @@ pub enum Dialog {
     NewEndpoint(Option<Uuid>), // entity_id
     /// Edit endpoint dialog
     EditEndpoint(Uuid), // endpoint_id
+    /// New custom query (with optional pre-selected root entity)
+    NewQuery(Option<Uuid>), // entity_id
+    /// Edit custom query
+    EditQuery(Uuid), // query_id
     /// Delete confirmation dialog
     ConfirmDelete(DeleteTarget),
     /// Export/generate code dialog
@@ -440,6 +693,7 @@ pub enum DeleteTarget {
     Field(Uuid, Uuid), // entity_id, field_id
     Relationship(Uuid),
     Endpoint(Uuid),
+    Query(Uuid),
 }
 
 /// Status message for the status bar
@@ -553,6 +807,54 @@ impl History {
     pub fn redo_count(&self) -> usize {
         self.future.len()
     }
+
+    /// Undoable snapshots, oldest first
+    pub fn past(&self) -> &[HistorySnapshot] {
+        &self.past
+    }
+
+    /// Redoable snapshots, next redo first
+    pub fn future(&self) -> impl Iterator<Item = &HistorySnapshot> {
+        self.future.iter().rev()
+    }
+
+    /// Undo `steps` actions at once, returning the snapshot to restore.
+    ///
+    /// Everything after the target (including `current`) stays on the redo
+    /// stack, so the jump can be reversed with [`fast_forward`](Self::fast_forward).
+    pub fn rewind(&mut self, steps: usize, current: HistorySnapshot) -> Option<HistorySnapshot> {
+        if steps == 0 || steps > self.past.len() {
+            return None;
+        }
+        self.future.push(current);
+        for _ in 1..steps {
+            let skipped = self.past.pop()?;
+            self.future.push(skipped);
+        }
+        self.past.pop()
+    }
+
+    /// Redo `steps` actions at once, returning the snapshot to restore.
+    pub fn fast_forward(
+        &mut self,
+        steps: usize,
+        current: HistorySnapshot,
+    ) -> Option<HistorySnapshot> {
+        if steps == 0 || steps > self.future.len() {
+            return None;
+        }
+        self.past.push(current);
+        for _ in 1..steps {
+            let skipped = self.future.pop()?;
+            self.past.push(skipped);
+        }
+        self.future.pop()
+    }
+
+    /// Drop the redo stack, making the current state the end of history
+    pub fn discard_future(&mut self) {
+        self.future.clear();
+    }
 }
 
 /// A snapshot of project state for history
@@ -562,6 +864,8 @@ pub struct HistorySnapshot {
     pub action: String,
     /// Serialized project state
     pub project_json: String,
+    /// When the snapshot was taken
+    pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
 impl HistorySnapshot {
@@ -573,6 +877,7 @@ impl HistorySnapshot {
         Ok(Self {
             action: action.into(),
             project_json,
+            timestamp: chrono::Utc::now(),
         })
     }
 
@@ -583,6 +888,41 @@ impl HistorySnapshot {
     }
 }
 
+// ============================================================================
+// Tested Connection
+// ============================================================================
+
+/// Database settings that last passed the connection test in Project Setup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestedConnection {
+    pub database: DatabaseType,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database_name: String,
+}
+
+impl TestedConnection {
+    /// The connection settings of `config`
+    pub fn of(config: &ProjectConfig) -> Self {
+        let db = &config.db_config;
+        Self {
+            database: config.database,
+            host: db.host.clone(),
+            port: db.port,
+            username: db.username.clone(),
+            password: db.password.clone(),
+            database_name: db.database_name.clone(),
+        }
+    }
+
+    /// Whether `config` still connects with the tested settings
+    pub fn matches(&self, config: &ProjectConfig) -> bool {
+        *self == Self::of(config)
+    }
+}
+
 // ============================================================================
 // Application State
 // ============================================================================
@@ -596,6 +936,10 @@ pub struct AppState {
     pub project_path: Option<std::path::PathBuf>,
     /// Whether the project has unsaved changes
     pub is_dirty: bool,
+    /// Counts changes to the project: bumped by every edit (see
+    /// [`mark_dirty`](Self::mark_dirty)) and when a project is opened or
+    /// closed, so derived figures know when to recompute
+    pub project_revision: u64,
     /// Selection state
     pub selection: Selection,
     /// Canvas state
@@ -604,6 +948,12 @@ pub struct AppState {
     pub ui: UiState,
     /// History for undo/redo
     pub history: History,
+    /// Connection settings that passed the last connection test
+    pub tested_connection: Option<TestedConnection>,
+    /// Fields copied with Copy Field(s), kept across projects for pasting
+    pub field_clipboard: Vec<Field>,
+    /// Validation issues from the last (debounced) check of the project
+    pub problems: Vec<Problem>,
 }
 
 impl Default for AppState {
@@ -612,10 +962,14 @@ impl Default for AppState {
             project: None,
             project_path: None,
             is_dirty: false,
+            project_revision: 0,
             selection: Selection::new(),
             canvas: CanvasState::new(),
             ui: UiState::new(),
             history: History::new(),
+            tested_connection: None,
+            field_clipboard: Vec::new(),
+            problems: Vec::new(),
         }
     }
 }
@@ -643,10 +997,11 @@ impl AppState {
     pub fn new_project(&mut self, name: impl Into<String>) {
         self.project = Some(ProjectGraph::new(name));
         self.project_path = None;
-        self.is_dirty = true;
+        self.mark_dirty();
         self.selection.clear();
         self.canvas.reset_view();
         self.history.clear();
+        self.tested_connection = None;
         self.ui.navigate(Page::ProjectSetup);
     }
 
@@ -655,9 +1010,24 @@ impl AppState {
         self.project = Some(project);
         self.project_path = Some(path);
         self.is_dirty = false;
+        self.project_revision = self.project_revision.wrapping_add(1);
+        self.selection.clear();
+        self.canvas.reset_view();
+        self.history.clear();
+        self.tested_connection = None;
+        self.ui.navigate(Page::EntityDesign);
+    }
+
+    /// Open a project restored from an autosave. It differs from what is on
+    /// disk (if it was ever saved), so it starts out dirty.
+    pub fn open_recovered(&mut self, project: ProjectGraph, path: Option<std::path::PathBuf>) {
+        self.project = Some(project);
+        self.project_path = path;
+        self.mark_dirty();
         self.selection.clear();
         self.canvas.reset_view();
         self.history.clear();
+        self.tested_connection = None;
         self.ui.navigate(Page::EntityDesign);
     }
 
@@ -666,9 +1036,11 @@ impl AppState {
         self.project = None;
         self.project_path = None;
         self.is_dirty = false;
+        self.project_revision = self.project_revision.wrapping_add(1);
         self.selection.clear();
         self.canvas.reset_view();
         self.history.clear();
+        self.tested_connection = None;
         self.ui.navigate(Page::Welcome);
     }
 
@@ -683,6 +1055,7 @@ impl AppState {
     /// Mark project as dirty (has unsaved changes)
     pub fn mark_dirty(&mut self) {
         self.is_dirty = true;
+        self.project_revision = self.project_revision.wrapping_add(1);
     }
 
     /// Save current state to history before making changes
@@ -701,7 +1074,7 @@ impl AppState {
                 if let Some(previous) = self.history.undo(current) {
                     if let Ok(restored) = previous.restore() {
                         self.project = Some(restored);
-                        self.is_dirty = true;
+                        self.mark_dirty();
                         return true;
                     }
                 }
@@ -717,7 +1090,7 @@ impl AppState {
                 if let Some(next) = self.history.redo(current) {
                     if let Ok(restored) = next.restore() {
                         self.project = Some(restored);
-                        self.is_dirty = true;
+                        self.mark_dirty();
                         return true;
                     }
                 }
@@ -726,6 +1099,52 @@ impl AppState {
         false
     }
 
+    /// Jump back `steps` entries in the history.
+    ///
+    /// With `collapse`, the states after the target are discarded instead of
+    /// staying available for redo.
+    pub fn rewind_history(&mut self, steps: usize, collapse: bool) -> bool {
+        let Some(project) = &self.project else {
+            return false;
+        };
+        let Ok(current) = HistorySnapshot::new("current", project) else {
+            return false;
+        };
+        let Some(target) = self.history.rewind(steps, current) else {
+            return false;
+        };
+        let Ok(restored) = target.restore() else {
+            return false;
+        };
+
+        if collapse {
+            self.history.discard_future();
+        }
+        self.project = Some(restored);
+        self.mark_dirty();
+        true
+    }
+
+    /// Jump forward `steps` entries in the redo history.
+    pub fn fast_forward_history(&mut self, steps: usize) -> bool {
+        let Some(project) = &self.project else {
+            return false;
+        };
+        let Ok(current) = HistorySnapshot::new("current", project) else {
+            return false;
+        };
+        let Some(target) = self.history.fast_forward(steps, current) else {
+            return false;
+        };
+        let Ok(restored) = target.restore() else {
+            return false;
+        };
+
+        self.project = Some(restored);
+        self.mark_dirty();
+        true
+    }
+
     /// Get window title
     pub fn window_title(&self) -> String {
         let name = self.project_name();
@@ -797,6 +1216,53 @@ pub fn use_canvas_state() -> CanvasState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tested_connection_matches_until_settings_change() {
+        let mut config = ProjectConfig::default();
+        let tested = TestedConnection::of(&config);
+        assert!(tested.matches(&config));
+
+        // Pool sizes don't affect whether the server accepts us
+        config.db_config.max_connections += 5;
+        assert!(tested.matches(&config));
+
+        config.db_config.password = "changed".to_string();
+        assert!(!tested.matches(&config));
+
+        let mut state = AppState::new();
+        state.tested_connection = Some(tested);
+        state.new_project("Other");
+        assert!(state.tested_connection.is_none());
+    }
+
+    #[test]
+    fn test_toggle_diagnostics_controls_recording() {
+        let mut ui = UiState::new();
+        assert!(!ui.diagnostics_open);
+
+        ui.toggle_diagnostics();
+        assert!(ui.diagnostics_open);
+        assert!(crate::diagnostics::is_enabled());
+
+        ui.toggle_diagnostics();
+        assert!(!ui.diagnostics_open);
+        assert!(!crate::diagnostics::is_enabled());
+    }
+
+    #[test]
+    fn test_show_help_opens_panel_on_topic() {
+        let mut ui = UiState::new();
+        assert!(!ui.help_open);
+
+        ui.show_help(HelpTopic::ReferentialActions);
+        assert!(ui.help_open);
+        assert_eq!(ui.help_topic, HelpTopic::ReferentialActions);
+
+        ui.toggle_help();
+        assert!(!ui.help_open);
+        assert_eq!(ui.help_topic, HelpTopic::ReferentialActions);
+    }
+
     #[test]
     fn test_selection() {
         let mut selection = Selection::new();
@@ -847,6 +1313,26 @@ mod tests {
         assert_eq!(canvas.zoom, 1.0);
     }
 
+    #[test]
+    fn test_semantic_zoom_detail() {
+        let mut canvas = CanvasState::new();
+        assert_eq!(canvas.detail(), ZoomDetail::Full);
+
+        canvas.zoom = 0.6;
+        assert_eq!(canvas.detail(), ZoomDetail::KeyFields);
+
+        canvas.zoom = 0.3;
+        assert_eq!(canvas.detail(), ZoomDetail::Names);
+
+        canvas.semantic_zoom.set_key_fields_below(0.25);
+        assert_eq!(canvas.semantic_zoom.names_below, 0.25);
+        assert_eq!(canvas.detail(), ZoomDetail::Full);
+
+        canvas.semantic_zoom.set_names_below(1.0);
+        assert_eq!(canvas.semantic_zoom.key_fields_below, 1.0);
+        assert_eq!(canvas.detail(), ZoomDetail::Names);
+    }
+
     #[test]
     fn test_canvas_coordinate_conversion() {
         let mut canvas = CanvasState::new();
@@ -863,6 +1349,40 @@ mod tests {
         assert_eq!(back.y, screen.y);
     }
 
+    #[test]
+    fn test_canvas_center_on() {
+        let mut canvas = CanvasState::new();
+        canvas.viewport = Size::new(800.0, 600.0);
+        canvas.zoom = 2.0;
+
+        let point = Position::new(500.0, -100.0);
+        canvas.center_on(point);
+        assert_eq!(canvas.canvas_to_screen(point), Position::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn test_canvas_fit_rect() {
+        let mut canvas = CanvasState::new();
+        canvas.viewport = Size::new(880.0, 680.0);
+
+        // Twice the usable area wide: zoom out to 50% and centre
+        let rect = Rect::new(Position::new(-200.0, 100.0), Size::new(1600.0, 400.0));
+        canvas.fit_rect(rect);
+        assert_eq!(canvas.zoom, 0.5);
+        assert_eq!(
+            canvas.canvas_to_screen(rect.center()),
+            Position::new(440.0, 340.0)
+        );
+
+        let visible = canvas.visible_rect();
+        assert_eq!(visible.size, Size::new(1760.0, 1360.0));
+        assert_eq!(visible.center(), rect.center());
+
+        // Small content is centred at 100% rather than magnified
+        canvas.fit_rect(Rect::new(Position::zero(), Size::new(100.0, 50.0)));
+        assert_eq!(canvas.zoom, 1.0);
+    }
+
     #[test]
     fn test_ui_state() {
         let mut ui = UiState::new();
@@ -878,6 +1398,18 @@ mod tests {
         assert!(ui.active_dialog.is_none());
     }
 
+    #[test]
+    fn test_theme_cycle() {
+        let mut ui = UiState::new();
+        assert_eq!(ui.theme, Theme::Dark);
+
+        for expected in [Theme::Light, Theme::System, Theme::Dark] {
+            ui.cycle_theme();
+            assert_eq!(ui.theme, expected);
+        }
+        assert_eq!(serde_json::to_string(&Theme::System).unwrap(), "\"system\"");
+    }
+
     #[test]
     fn test_history() {
         let mut history = History::new();
@@ -897,6 +1429,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_history_rewind_and_fast_forward() {
+        let mut history = History::new();
+        for name in ["v1", "v2", "v3"] {
+            let project = ProjectGraph::new(name);
+            history.push(HistorySnapshot::new(name, &project).unwrap());
+        }
+        let current = HistorySnapshot::new("current", &ProjectGraph::new("v4")).unwrap();
+
+        // Jump two steps back: lands on v2, v3 and current become redoable
+        let target = history.rewind(2, current).unwrap();
+        assert_eq!(target.action, "v2");
+        assert_eq!(history.undo_count(), 1);
+        let redo: Vec<&str> = history.future().map(|s| s.action.as_str()).collect();
+        assert_eq!(redo, vec!["v3", "current"]);
+
+        // And forward again to the end
+        let target = history.fast_forward(2, target).unwrap();
+        assert_eq!(target.action, "current");
+        assert_eq!(history.undo_count(), 3);
+        assert!(!history.can_redo());
+
+        // Out-of-range jumps leave history untouched
+        assert!(history.rewind(4, target.clone()).is_none());
+        assert!(history.fast_forward(1, target).is_none());
+        assert_eq!(history.undo_count(), 3);
+    }
+
+    #[test]
+    fn test_rewind_history_collapse() {
+        let mut state = AppState::new();
+        state.new_project("v1");
+        state.save_to_history("v1");
+        state.project.as_mut().unwrap().meta.name = "v2".to_string();
+        state.save_to_history("v2");
+        state.project.as_mut().unwrap().meta.name = "v3".to_string();
+
+        assert!(state.rewind_history(2, true));
+        assert_eq!(state.project_name(), "v1");
+        assert!(!state.history.can_redo());
+        assert!(!state.history.can_undo());
+    }
+
     #[test]
     fn test_app_state() {
         let mut state = AppState::new();
@@ -911,8 +1486,16 @@ mod tests {
         state.mark_saved(None);
         assert!(!state.is_dirty);
 
+        // Edits and closing change the revision; saving does not
+        let revision = state.project_revision;
+        state.mark_dirty();
+        assert_eq!(state.project_revision, revision + 1);
+        state.mark_saved(None);
+        assert_eq!(state.project_revision, revision + 1);
+
         state.close_project();
         assert!(!state.has_project());
+        assert_eq!(state.project_revision, revision + 2);
     }
 
     #[test]
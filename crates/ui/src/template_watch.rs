@@ -0,0 +1,210 @@
+//! # Template Watching
+//!
+//! Dev mode for template authors. While "Watch Templates" is on in the Code
+//! Generation page, the `templates/` directory next to the project file is
+//! polled every [`POLL_INTERVAL`]. Once a change has settled for
+//! [`SETTLE`] the open preview is re-rendered, and overrides that fail to
+//! parse are listed on the page until they are fixed. This covers every
+//! built-in template, the model, handler, router and migration ones included
+//! (see [`imortal_codegen::templates`]).
+//!
+//! Polling compares the name, size and modification time of every file, so
+//! it needs no platform file-system events and catches saves made by any
+//! editor. The page reads the directory and renders off the UI thread.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use imortal_codegen::templates::{self, project_templates_dir};
+
+/// How often the templates directory is checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long the templates must go unchanged before the preview is
+/// re-rendered, so that saving several files renders once
+pub const SETTLE: Duration = Duration::from_millis(500);
+
+/// The templates directory of a project saved at `project_path`, if there
+/// is one.
+pub fn templates_dir(project_path: Option<&Path>) -> Option<PathBuf> {
+    project_path
+        .map(project_templates_dir)
+        .filter(|dir| dir.is_dir())
+}
+
+/// What the files of a templates directory looked like when it was polled.
+/// Two stamps differ once a file was added, removed or saved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stamp(Vec<(PathBuf, u64, Option<SystemTime>)>);
+
+impl Stamp {
+    /// Stamp the files under `dir` (empty if it is missing)
+    pub fn of(dir: &Path) -> Self {
+        let mut files = Vec::new();
+        collect_files(dir, &mut files);
+        files.sort();
+        Self(files)
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, u64, Option<SystemTime>)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push((path, metadata.len(), metadata.modified().ok()));
+        }
+    }
+}
+
+/// What a poll of the templates directory calls for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Nothing new, or a change that is still settling
+    None,
+    /// The first look since watching started: check, but do not re-render
+    First,
+    /// The templates changed and then stayed the same for [`SETTLE`]
+    Settled,
+}
+
+/// Debounces the stamps of successive polls.
+#[derive(Debug, Default)]
+pub struct Watcher {
+    /// The stamp last acted on
+    settled: Option<Stamp>,
+    /// A newer stamp, and when it was first seen
+    changed: Option<(Stamp, Instant)>,
+}
+
+impl Watcher {
+    /// Record the stamp a poll at `now` found
+    pub fn poll(&mut self, stamp: Stamp, now: Instant) -> Change {
+        if self.settled.is_none() {
+            self.settled = Some(stamp);
+            return Change::First;
+        }
+        if self.settled.as_ref() == Some(&stamp) {
+            self.changed = None;
+            return Change::None;
+        }
+        match &self.changed {
+            Some((changed, since)) if *changed == stamp => {
+                if now.duration_since(*since) < SETTLE {
+                    return Change::None;
+                }
+                self.settled = Some(stamp);
+                self.changed = None;
+                Change::Settled
+            }
+            _ => {
+                self.changed = Some((stamp, now));
+                Change::None
+            }
+        }
+    }
+}
+
+/// The errors to show for the templates in `dir`, one line each; empty when
+/// every override parses.
+pub fn template_errors(dir: &Path) -> Vec<String> {
+    templates::check(dir)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_dir() {
+        let dir = std::env::temp_dir().join(format!("ieng-templates-{}", uuid::Uuid::new_v4()));
+        let project = dir.join("blog.ieng");
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(templates_dir(Some(&project)), None);
+        assert_eq!(templates_dir(None), None);
+
+        std::fs::create_dir_all(dir.join("templates")).unwrap();
+        assert_eq!(templates_dir(Some(&project)), Some(dir.join("templates")));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stamp_follows_changes() {
+        let dir = std::env::temp_dir().join(format!("ieng-templates-{}", uuid::Uuid::new_v4()));
+        let file = dir.join("deploy").join("Dockerfile.jinja");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        assert_eq!(Stamp::of(&dir.join("missing")), Stamp::default());
+
+        std::fs::write(&file, "FROM {{ image }}\n").unwrap();
+        let stamp = Stamp::of(&dir);
+        assert_eq!(Stamp::of(&dir), stamp);
+        assert!(template_errors(&dir).is_empty());
+
+        std::fs::write(&file, "FROM {{ image\n").unwrap();
+        assert_ne!(Stamp::of(&dir), stamp);
+        let errors = template_errors(&dir);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Dockerfile.jinja"), "{:?}", errors);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_source_templates_are_checked() {
+        let dir = std::env::temp_dir().join(format!("ieng-templates-{}", uuid::Uuid::new_v4()));
+        let model = dir.join("rust").join("model.rs.jinja");
+        let migration = dir.join("migrations").join("create_table.sql.jinja");
+        std::fs::create_dir_all(model.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(migration.parent().unwrap()).unwrap();
+
+        std::fs::write(&model, "pub struct Model {\n{% for column in columns %}\n").unwrap();
+        std::fs::write(&migration, "CREATE TABLE {{ table }} ();\n").unwrap();
+        let errors = template_errors(&dir);
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].contains("model.rs.jinja"), "{:?}", errors);
+
+        std::fs::write(&migration, "CREATE TABLE {{ table ();\n").unwrap();
+        assert_eq!(template_errors(&dir).len(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watcher_settles_bursts() {
+        let stamp = |size| Stamp(vec![(PathBuf::from("models.rs.jinja"), size, None)]);
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        let mut watcher = Watcher::default();
+        assert_eq!(watcher.poll(stamp(1), at(0)), Change::First);
+        assert_eq!(watcher.poll(stamp(1), at(250)), Change::None);
+
+        // Three saves in a row render once, after the last one settles
+        assert_eq!(watcher.poll(stamp(2), at(500)), Change::None);
+        assert_eq!(watcher.poll(stamp(3), at(750)), Change::None);
+        assert_eq!(watcher.poll(stamp(4), at(1000)), Change::None);
+        assert_eq!(watcher.poll(stamp(4), at(1250)), Change::None);
+        assert_eq!(watcher.poll(stamp(4), at(1500)), Change::Settled);
+        assert_eq!(watcher.poll(stamp(4), at(1750)), Change::None);
+
+        // Saving the file back before it settles is no change
+        assert_eq!(watcher.poll(stamp(5), at(2000)), Change::None);
+        assert_eq!(watcher.poll(stamp(4), at(2250)), Change::None);
+        assert_eq!(watcher.poll(stamp(4), at(3000)), Change::None);
+    }
+}